@@ -57,17 +57,19 @@ pub struct CoreTokenizer {
 impl CoreTokenizer {
 	/// Create a new [`CoreTokenizer`] with the given [`AtomicByteTendril`] as it's backing store
 	pub fn new(text: AtomicByteTendril) -> Self {
-		let mut tokenizer = Self {
-			text,
-			offset: 0,
-			eof: false,
-			current: 0,
-			position: Position::sof(),
-		};
-
-		// If there was a case where somehow a tokenizer was made with an empty file
-		// then ensure we're at the EOF right away to prevent any issues
-		if tokenizer.text.is_empty() {
+		Self::new_at(text, 0, Position::sof())
+	}
+
+	/// Create a new [`CoreTokenizer`] that starts tokenizing the given [`AtomicByteTendril`] at
+	/// `offset`, which is at `position` in the text
+	///
+	/// This is used to re-tokenize only part of a file, such as a region that has been edited.
+	pub fn new_at(text: AtomicByteTendril, offset: u32, position: Position) -> Self {
+		let mut tokenizer = Self { text, offset, eof: false, current: 0, position };
+
+		// If there was a case where somehow a tokenizer was made with an empty file, or starting
+		// past the end of it, then ensure we're at the EOF right away to prevent any issues
+		if tokenizer.offset as usize >= tokenizer.text.len() {
 			tokenizer.eof = true;
 		} else {
 			tokenizer.current = tokenizer.text[tokenizer.offset as usize]
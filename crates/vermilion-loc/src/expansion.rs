@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Source maps for macro expansions
+//!
+//! When a macro is expanded, the text it produces doesn't exist anywhere in the source, so the
+//! things made from it are located at the macro usage instead. To still be able to point back at
+//! the macro definition, each expansion is recorded in an [`Expansions`] table and every
+//! [`Location`] produced by it carries its [`ExpansionId`].
+//!
+//! Expansions nest, a macro used inside of another macro's body has a call site that is itself
+//! inside of an expansion, walking these call sites outwards gives the full expansion stack.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::{FileId, Location};
+
+/// Represents a unique ID for a single macro expansion in an [`Expansions`] table
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ExpansionId(usize);
+
+/// A single expansion of a macro
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Expansion {
+	/// The name of the macro that was expanded
+	name:       String,
+	/// Where the macro was used, including any arguments
+	call_site:  Location<()>,
+	/// Where the macro was defined, `None` if it was not defined in a source file, such as
+	/// macros that are predefined or given on the command line
+	definition: Option<Location<()>>,
+}
+
+/// The table of all macro expansions performed, indexed by [`ExpansionId`]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Expansions {
+	expansions: Vec<Expansion>,
+	/// The expansions of the macro usages written in the source, by the file and offset they
+	/// start at
+	usages:     BTreeMap<(FileId, u32), ExpansionId>,
+}
+
+/// An iterator over the expansion stack of a [`Location`], from the innermost expansion outwards
+pub struct ExpansionStack<'a> {
+	expansions: &'a Expansions,
+	next:       Option<ExpansionId>,
+}
+
+impl ExpansionId {
+	/// Get the raw value of this [`ExpansionId`]
+	#[inline(always)]
+	pub const fn raw(&self) -> &usize {
+		&self.0
+	}
+}
+
+impl Display for ExpansionId {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		Display::fmt(&self.0, f)
+	}
+}
+
+impl Expansion {
+	/// Create a new [`Expansion`]
+	pub fn new<Str>(name: Str, call_site: Location<()>, definition: Option<Location<()>>) -> Self
+	where
+		Str: Into<String>,
+	{
+		Self { name: name.into(), call_site, definition }
+	}
+
+	#[inline(always)]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	#[inline(always)]
+	pub const fn call_site(&self) -> &Location<()> {
+		&self.call_site
+	}
+
+	#[inline(always)]
+	pub const fn definition(&self) -> Option<&Location<()>> {
+		self.definition.as_ref()
+	}
+
+	/// The expansion that the call site of this expansion is in, if any
+	#[inline(always)]
+	pub const fn parent(&self) -> Option<ExpansionId> {
+		self.call_site.expansion()
+	}
+}
+
+impl Expansions {
+	pub const fn new() -> Self {
+		Self { expansions: Vec::new(), usages: BTreeMap::new() }
+	}
+
+	/// Record a new expansion, returning the [`ExpansionId`] to attach to everything it produces
+	pub fn push(&mut self, expansion: Expansion) -> ExpansionId {
+		let id = ExpansionId(self.expansions.len());
+
+		let call_site = &expansion.call_site;
+		if !call_site.is_expanded() {
+			self.usages
+				.entry((*call_site.file_id(), *call_site.begin()))
+				.or_insert(id);
+		}
+
+		self.expansions.push(expansion);
+		id
+	}
+
+	pub fn get(&self, id: ExpansionId) -> Option<&Expansion> {
+		self.expansions.get(id.0)
+	}
+
+	pub fn len(&self) -> usize {
+		self.expansions.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.expansions.is_empty()
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = (ExpansionId, &Expansion)> {
+		self.expansions
+			.iter()
+			.enumerate()
+			.map(|(idx, expansion)| (ExpansionId(idx), expansion))
+	}
+
+	/// The expansions that produced `location`, from the innermost expansion outwards
+	pub fn stack<T>(&self, location: &Location<T>) -> ExpansionStack<'_> {
+		ExpansionStack { expansions: self, next: location.expansion() }
+	}
+
+	/// Find where `location` was written in the source, this is the call site of its outermost
+	/// expansion, or `location` itself if it was not produced by a macro
+	pub fn source_location<T>(&self, location: &Location<T>) -> Location<()> {
+		self.stack(location)
+			.last()
+			.map_or_else(|| location.with_inner(()), |expansion| expansion.call_site)
+	}
+
+	/// Find the macro usage written in `file` that covers the byte `offset`, which is used to go
+	/// from a macro usage to its definition
+	pub fn usage_at(&self, file: FileId, offset: u32) -> Option<&Expansion> {
+		// NOTE(aki): Macro usages written in the source never overlap, the ones in the arguments of
+		// another usage are in its expansion, so only the last one starting before `offset` can
+		// cover it
+		let (_, id) = self.usages.range(..=(file, offset)).next_back()?;
+		let expansion = self.get(*id)?;
+		let call_site = &expansion.call_site;
+
+		(*call_site.file_id() == file && offset < *call_site.end()).then_some(expansion)
+	}
+}
+
+impl<'a> Iterator for ExpansionStack<'a> {
+	type Item = &'a Expansion;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let expansion = self.expansions.get(self.next?)?;
+		self.next = expansion.parent();
+
+		Some(expansion)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Span;
+
+	fn location(file: usize, begin: u32, end: u32) -> Location<()> {
+		Location::new(FileId::new(file), (), Span::new(begin, end, 0, begin))
+	}
+
+	#[test]
+	fn test_expansion_stack() {
+		let mut expansions = Expansions::new();
+
+		let outer = expansions.push(Expansion::new(
+			"OUTER",
+			location(0, 10, 16),
+			Some(location(1, 8, 13)),
+		));
+		let inner = expansions.push(Expansion::new(
+			"INNER",
+			location(0, 10, 16).with_expansion(Some(outer)),
+			None,
+		));
+
+		let token = location(0, 10, 16).with_expansion(Some(inner));
+		let stack = expansions
+			.stack(&token)
+			.map(Expansion::name)
+			.collect::<Vec<_>>();
+
+		assert_eq!(stack, ["INNER", "OUTER"]);
+		assert_eq!(expansions.source_location(&token), location(0, 10, 16));
+		assert_eq!(
+			expansions
+				.get(outer)
+				.and_then(Expansion::definition)
+				.map(|definition| *definition.file_id()),
+			Some(FileId::new(1usize))
+		);
+	}
+
+	#[test]
+	fn test_usage_at() {
+		let mut expansions = Expansions::new();
+
+		let outer = expansions.push(Expansion::new("OUTER", location(0, 10, 16), None));
+		expansions.push(Expansion::new(
+			"INNER",
+			location(0, 10, 16).with_expansion(Some(outer)),
+			None,
+		));
+
+		assert_eq!(
+			expansions
+				.usage_at(FileId::new(0usize), 12)
+				.map(Expansion::name),
+			Some("OUTER")
+		);
+		assert!(expansions.usage_at(FileId::new(0usize), 16).is_none());
+		assert!(expansions.usage_at(FileId::new(1usize), 12).is_none());
+
+		// Usages are found among many others, whatever order they were recorded in
+		for begin in (20..100).step_by(10).rev() {
+			expansions.push(Expansion::new(
+				format!("M{begin}"),
+				location(begin as usize % 3, begin, begin + 4),
+				None,
+			));
+		}
+		assert_eq!(
+			expansions
+				.usage_at(FileId::new(1usize), 42)
+				.map(Expansion::name),
+			Some("M40")
+		);
+		assert!(expansions.usage_at(FileId::new(1usize), 45).is_none());
+		assert!(expansions.usage_at(FileId::new(0usize), 42).is_none());
+	}
+
+	#[test]
+	fn test_location_expansion() {
+		let plain = location(0, 0, 4);
+		let expanded = plain.with_expansion(Some(ExpansionId(0)));
+
+		assert!(!plain.is_expanded());
+		assert!(expanded.is_expanded());
+		assert_ne!(plain, expanded);
+		assert_eq!(expanded.with_inner(1u8).expansion(), Some(ExpansionId(0)));
+	}
+}
@@ -5,11 +5,13 @@
 // #![warn(missing_docs)]
 // #![warn(clippy::missing_docs_in_private_items)]
 
+pub mod expansion;
 pub mod loc;
 pub mod position;
 pub mod span;
 
 pub use crate::{
+	expansion::{Expansion, ExpansionId, Expansions},
 	loc::{FileId, Location},
 	position::Position,
 	span::{Span, Spanned},
@@ -6,7 +6,7 @@ use std::{
 	ops::{Deref, DerefMut},
 };
 
-use crate::{Span, span::ThinSpan};
+use crate::{Span, expansion::ExpansionId, span::ThinSpan};
 
 /// Represents a unique ID for mapping locations and other constructs
 /// to a specific file that has been loaded File
@@ -21,11 +21,17 @@ pub struct FileId(usize);
 /// they originate from, allowing things like "Goto Definition" support and other diagnostic
 /// facilities.
 ///
+/// Things produced by a macro expansion are located at the macro usage, and also carry the
+/// [`ExpansionId`] of the expansion they came from, which can be looked up in an
+/// [`Expansions`] table to find the macro definition and any enclosing expansions.
+///
 /// [`Spanned<T>`]: crate::span::Spanned<T>
+/// [`Expansions`]: crate::expansion::Expansions
 pub struct Location<T> {
-	id:    FileId,
-	inner: T,
-	span:  Span,
+	id:        FileId,
+	inner:     T,
+	span:      Span,
+	expansion: Option<ExpansionId>,
 }
 
 impl FileId {
@@ -75,7 +81,25 @@ impl Display for FileId {
 impl<T> Location<T> {
 	#[inline(always)]
 	pub const fn new(id: FileId, inner: T, span: Span) -> Self {
-		Self { id, inner, span }
+		Self { id, inner, span, expansion: None }
+	}
+
+	/// Mark this location as being produced by the given macro expansion
+	#[inline(always)]
+	pub const fn with_expansion(mut self, expansion: Option<ExpansionId>) -> Self {
+		self.expansion = expansion;
+		self
+	}
+
+	/// Attach a different value to the same location, keeping the file, span, and expansion
+	#[inline(always)]
+	pub const fn with_inner<U>(&self, inner: U) -> Location<U> {
+		Location {
+			id: self.id,
+			inner,
+			span: self.span,
+			expansion: self.expansion,
+		}
 	}
 
 	#[inline(always)]
@@ -98,6 +122,17 @@ impl<T> Location<T> {
 		&self.span
 	}
 
+	/// The macro expansion that produced this, `None` if it was written directly in the source
+	#[inline(always)]
+	pub const fn expansion(&self) -> Option<ExpansionId> {
+		self.expansion
+	}
+
+	#[inline(always)]
+	pub const fn is_expanded(&self) -> bool {
+		self.expansion.is_some()
+	}
+
 	#[inline(always)]
 	pub const fn thin_span(&self) -> ThinSpan {
 		self.span.get_thin()
@@ -130,9 +165,10 @@ where
 {
 	fn clone(&self) -> Self {
 		Self {
-			id:    self.id,
-			inner: self.inner.clone(),
-			span:  self.span,
+			id:        self.id,
+			inner:     self.inner.clone(),
+			span:      self.span,
+			expansion: self.expansion,
 		}
 	}
 }
@@ -169,6 +205,7 @@ where
 		self.id.hash(state);
 		self.inner.hash(state);
 		self.span.hash(state);
+		self.expansion.hash(state);
 	}
 }
 
@@ -178,7 +215,10 @@ where
 {
 	#[inline(always)]
 	fn eq(&self, other: &Self) -> bool {
-		self.id == other.id && self.inner == other.inner && self.span == other.span
+		self.id == other.id &&
+			self.inner == other.inner &&
+			self.span == other.span &&
+			self.expansion == other.expansion
 	}
 }
 
@@ -191,7 +231,12 @@ where
 	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
 		match self.id.partial_cmp(&other.id) {
 			Some(std::cmp::Ordering::Equal) => match self.span.partial_cmp(&other.span) {
-				Some(std::cmp::Ordering::Equal) => self.inner.partial_cmp(&other.inner),
+				Some(std::cmp::Ordering::Equal) => {
+					match self.expansion.partial_cmp(&other.expansion) {
+						Some(std::cmp::Ordering::Equal) => self.inner.partial_cmp(&other.inner),
+						ord => ord,
+					}
+				},
 				ord => ord,
 			},
 			ord => ord,
@@ -206,7 +251,10 @@ where
 	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
 		match self.id.cmp(&other.id) {
 			std::cmp::Ordering::Equal => match self.span.cmp(&other.span) {
-				std::cmp::Ordering::Equal => self.inner.cmp(&other.inner),
+				std::cmp::Ordering::Equal => match self.expansion.cmp(&other.expansion) {
+					std::cmp::Ordering::Equal => self.inner.cmp(&other.inner),
+					ord => ord,
+				},
 				ord => ord,
 			},
 			ord => ord,
@@ -232,6 +280,7 @@ where
 			.field("id", &self.id)
 			.field("inner", &self.inner)
 			.field("span", &self.span)
+			.field("expansion", &self.expansion)
 			.finish()
 	}
 }
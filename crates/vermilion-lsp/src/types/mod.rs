@@ -6085,6 +6085,11 @@ impl Registration {
 		Self { id, method, register_options: None }
 	}
 
+	pub fn with_register_options(mut self, register_options: LspAny) -> Self {
+		self.register_options = Some(register_options);
+		self
+	}
+
 	/// The id used to register the request. The id can be used to deregister the request again.
 	pub fn id(&self) -> &String {
 		&self.id
@@ -56,7 +56,7 @@ pub struct DidChangeConfigurationRegistrationOptions {
 #[serde(rename_all = "camelCase")]
 pub struct DidChangeWatchedFilesRegistrationOptions {
 	/// The watchers to register
-	pub changes: Vec<FileSystemWatcher>,
+	pub watchers: Vec<FileSystemWatcher>,
 }
 
 /// Value-object describing what options formatting should use.
@@ -8,6 +8,7 @@ vermilion_diagnostics::define_diagnostics_table! {
 vermilion_diagnostics::define_diagnostics_table! {
 	lint, "./messages/lint", system_verilog,
 	0000 => "Placeholder",
+	0001 => "Implicit truncation",
 }
 
 vermilion_diagnostics::define_diagnostics_table! {
@@ -18,6 +19,14 @@ vermilion_diagnostics::define_diagnostics_table! {
 vermilion_diagnostics::define_diagnostics_table! {
 	error, "./messages/error", system_verilog,
 	0000 => "Placeholder",
+	0001 => "Unsupported in the selected language standard",
+	0002 => "Mismatched end label",
+	0003 => "Syntax error",
+	0004 => "Preprocessor error",
+	0005 => "Undeclared identifier",
+	0006 => "Duplicate declaration",
+	0007 => "Not a constant expression",
+	0008 => "Elaboration error",
 }
 
 vermilion_diagnostics::define_diagnostics_table! {
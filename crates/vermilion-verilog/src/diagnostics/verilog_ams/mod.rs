@@ -8,6 +8,7 @@ vermilion_diagnostics::define_diagnostics_table! {
 vermilion_diagnostics::define_diagnostics_table! {
 	lint, "./messages/lint", verilog_ams,
 	0000 => "Placeholder",
+	0001 => "Implicit truncation",
 }
 
 vermilion_diagnostics::define_diagnostics_table! {
@@ -18,6 +19,13 @@ vermilion_diagnostics::define_diagnostics_table! {
 vermilion_diagnostics::define_diagnostics_table! {
 	error, "./messages/error", verilog_ams,
 	0000 => "Placeholder",
+	0001 => "Unsupported in the selected language standard",
+	0002 => "Syntax error",
+	0003 => "Preprocessor error",
+	0004 => "Undeclared identifier",
+	0005 => "Duplicate declaration",
+	0006 => "Not a constant expression",
+	0007 => "Elaboration error",
 }
 
 vermilion_diagnostics::define_diagnostics_table! {
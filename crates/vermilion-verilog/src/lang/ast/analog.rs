@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{DataType, Declarator, Expression},
+	types::Identifier,
+};
+
+/// A nature declaration, e.g. `nature Voltage; units = "V"; access = V; endnature`
+///
+/// Verilog-AMS 2.4 § 3.6.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct Nature {
+	pub name:       Spanned<Identifier>,
+	/// The nature or discipline flow/potential this nature is derived from, if any
+	pub parent:     Option<Spanned<Expression>>,
+	pub attributes: Vec<Spanned<NatureAttribute>>,
+}
+
+/// A `name = value;` attribute of a nature, e.g. `abstol = 1e-6;`
+#[derive(Clone, Debug, PartialEq)]
+pub struct NatureAttribute {
+	pub name:  Spanned<Identifier>,
+	pub value: Spanned<Expression>,
+}
+
+/// A discipline declaration, e.g. `discipline electrical; potential Voltage; enddiscipline`
+///
+/// Verilog-AMS 2.4 § 3.6.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct Discipline {
+	pub name:  Spanned<Identifier>,
+	pub items: Vec<Spanned<DisciplineItem>>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum NatureBinding {
+	Potential,
+	Flow,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DisciplineDomain {
+	Continuous,
+	Discrete,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DisciplineItem {
+	/// `potential Voltage;` or `flow Current;`
+	Binding {
+		binding: NatureBinding,
+		nature:  Spanned<Identifier>,
+	},
+	/// `domain continuous;`
+	Domain(DisciplineDomain),
+	/// An override of an attribute of a bound nature, e.g. `potential.abstol = 1u;`
+	Attribute {
+		binding:   NatureBinding,
+		attribute: NatureAttribute,
+	},
+}
+
+/// A net declared with a discipline, e.g. `electrical [3:0] a, b;`
+///
+/// Verilog-AMS 2.4 § 3.6.4
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisciplineNet {
+	pub discipline:  Spanned<Identifier>,
+	pub data_type:   DataType,
+	pub declarators: Vec<Spanned<Declarator>>,
+}
+
+/// A named branch, e.g. `branch (p, n) res, cap;`
+///
+/// Verilog-AMS 2.4 § 3.12.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct Branch {
+	/// The one or two terminals of the branch, a single terminal branch is to ground
+	pub terminals: Vec<Spanned<BranchTerminal>>,
+	pub names:     Vec<Spanned<Identifier>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BranchTerminal {
+	Net(Spanned<Expression>),
+	/// `<port>`, the branch through a port of the module
+	Port(Spanned<Expression>),
+}
+
+/// A `from` or `exclude` range restricting the value of a parameter
+///
+/// Verilog-AMS 2.4 § 3.4.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValueRange {
+	/// Set for `exclude` ranges
+	pub exclude: bool,
+	pub bounds:  ValueRangeBounds,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValueRangeBounds {
+	/// `[low:high]`, `(low:high)` or any mix of the two, the bounds may be `inf` or `-inf`
+	Interval {
+		low:            Spanned<Expression>,
+		low_inclusive:  bool,
+		high:           Spanned<Expression>,
+		high_inclusive: bool,
+	},
+	/// A single excluded value, or the set of permitted values of a string parameter
+	Value(Spanned<Expression>),
+}
@@ -0,0 +1,212 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{DataType, EventControl, Expression, Item, Statement},
+	types::Identifier,
+};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AssertionKind {
+	Assert,
+	Assume,
+	Cover,
+	/// `cover sequence`
+	CoverSequence,
+	Restrict,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AssertionTiming {
+	/// `assert (expression)`
+	Immediate,
+	/// `assert #0 (expression)`
+	Deferred, // Added: IEEE 1800-2009
+	/// `assert final (expression)`
+	Final, // Added: IEEE 1800-2012
+	/// `assert property (property)`
+	Concurrent,
+}
+
+/// An immediate, deferred, or concurrent assertion
+///
+/// IEEE 1800-2017 § A.6.10, § A.2.10
+#[derive(Clone, Debug, PartialEq)]
+pub struct Assertion {
+	/// The label of an assertion declared as a module item, statement labels are kept on the
+	/// statement itself
+	pub label:    Option<Spanned<Identifier>>,
+	pub kind:     AssertionKind,
+	pub timing:   AssertionTiming,
+	/// The asserted property, immediate assertions only hold a single expression
+	pub property: Spanned<PropertySpec>,
+	pub pass:     Option<Box<Spanned<Statement>>>,
+	pub fail:     Option<Box<Spanned<Statement>>>,
+}
+
+/// IEEE 1800-2017 § A.2.10
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropertySpec {
+	pub clock:    Option<Spanned<EventControl>>,
+	/// The `disable iff (...)` condition
+	pub disable:  Option<Spanned<Expression>>,
+	pub property: Spanned<PropertyExpression>,
+}
+
+/// A property or sequence expression
+///
+/// IEEE 1800-2017 § A.2.10
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropertyExpression {
+	/// A boolean expression, or a sequence or property instance
+	Expression(Expression),
+	/// `lhs ##delay rhs` or `##delay rhs`
+	Delay {
+		lhs:   Option<Box<Spanned<Self>>>,
+		delay: Spanned<CycleRange>,
+		rhs:   Box<Spanned<Self>>,
+	},
+	/// `operand[*range]`, `operand[=range]`, or `operand[->range]`
+	Repetition {
+		operand: Box<Spanned<Self>>,
+		kind:    RepetitionKind,
+		range:   Spanned<CycleRange>,
+	},
+	Binary {
+		operator: Spanned<PropertyOperator>,
+		lhs:      Box<Spanned<Self>>,
+		rhs:      Box<Spanned<Self>>,
+	},
+	Unary {
+		operator: Spanned<PropertyUnaryOperator>,
+		/// The range of a ranged operator, e.g. `s_eventually [1:3]`
+		range:    Option<Spanned<CycleRange>>,
+		operand:  Box<Spanned<Self>>,
+	},
+	/// `@(clock) property`
+	Clocked {
+		clock:    Spanned<EventControl>,
+		property: Box<Spanned<Self>>,
+	},
+	If {
+		condition: Spanned<Expression>,
+		if_true:   Box<Spanned<Self>>,
+		if_false:  Option<Box<Spanned<Self>>>,
+	},
+	/// `accept_on (condition) property` and friends
+	Abort {
+		kind:      AbortKind,
+		condition: Spanned<Expression>,
+		property:  Box<Spanned<Self>>,
+	}, // Added: IEEE 1800-2009
+	Parenthesized(Box<Spanned<Self>>),
+}
+
+/// A cycle delay or repetition count
+#[derive(Clone, Debug, PartialEq)]
+pub enum CycleRange {
+	/// `n`
+	Single(Spanned<Expression>),
+	/// `[min:max]`, where `max` may be `$`
+	Range { min: Spanned<Expression>, max: Spanned<Expression> },
+	/// `[*]`, shorthand for `[*0:$]`
+	ZeroOrMore,
+	/// `[+]`, shorthand for `[*1:$]`
+	OneOrMore,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RepetitionKind {
+	/// `[*n]`
+	Consecutive,
+	/// `[=n]`
+	NonConsecutive,
+	/// `[->n]`
+	GoTo,
+}
+
+/// IEEE 1800-2017 § 16.12, Table 16-3
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PropertyOperator {
+	And,
+	Or,
+	Intersect,
+	Within,
+	Throughout,
+	/// `|->`
+	OverlappingImplication,
+	/// `|=>`
+	NonOverlappingImplication,
+	/// `#-#`
+	OverlappingFollowedBy, // Added: IEEE 1800-2009
+	/// `#=#`
+	NonOverlappingFollowedBy, // Added: IEEE 1800-2009
+	Iff,        // Added: IEEE 1800-2009
+	Implies,    // Added: IEEE 1800-2009
+	Until,      // Added: IEEE 1800-2009
+	SUntil,     // Added: IEEE 1800-2009
+	UntilWith,  // Added: IEEE 1800-2009
+	SUntilWith, // Added: IEEE 1800-2009
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PropertyUnaryOperator {
+	Not,
+	FirstMatch,
+	Strong,      // Added: IEEE 1800-2009
+	Weak,        // Added: IEEE 1800-2009
+	NextTime,    // Added: IEEE 1800-2009
+	SNextTime,   // Added: IEEE 1800-2009
+	Always,      // Added: IEEE 1800-2009
+	SAlways,     // Added: IEEE 1800-2009
+	Eventually,  // Added: IEEE 1800-2009
+	SEventually, // Added: IEEE 1800-2009
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AbortKind {
+	AcceptOn,
+	RejectOn,
+	SyncAcceptOn,
+	SyncRejectOn,
+}
+
+/// IEEE 1800-2017 § A.2.10
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropertyDeclaration {
+	pub name:      Spanned<Identifier>,
+	pub ports:     Vec<Spanned<AssertionPort>>,
+	/// Assertion variable declarations
+	pub variables: Vec<Spanned<Item>>,
+	pub property:  Spanned<PropertySpec>,
+}
+
+/// IEEE 1800-2017 § A.2.10
+#[derive(Clone, Debug, PartialEq)]
+pub struct SequenceDeclaration {
+	pub name:      Spanned<Identifier>,
+	pub ports:     Vec<Spanned<AssertionPort>>,
+	/// Assertion variable declarations
+	pub variables: Vec<Spanned<Item>>,
+	/// The sequence, which never has a `disable iff` condition
+	pub sequence:  Spanned<PropertySpec>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AssertionPortType {
+	Untyped,
+	Sequence,
+	Property,
+	Data(DataType),
+}
+
+/// A formal argument of a property, sequence, or let declaration
+///
+/// IEEE 1800-2017 § A.2.10
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssertionPort {
+	pub port_type: AssertionPortType,
+	pub name:      Spanned<Identifier>,
+	pub default:   Option<Spanned<Expression>>,
+}
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{Connections, Expression, Item, Lifetime, ParameterDeclaration},
+	types::Identifier,
+};
+
+/// IEEE 1800-2017 § A.1.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct Class {
+	pub is_virtual: bool,
+	/// Set for `interface class` declarations
+	pub interface:  bool, // Added: IEEE 1800-2012
+	pub lifetime:   Option<Lifetime>,
+	pub name:       Spanned<Identifier>,
+	/// The `#(...)` parameter port list, if any
+	pub parameters: Option<Vec<Spanned<ParameterDeclaration>>>,
+	/// The base classes, interface classes may extend more than one
+	pub extends:    Vec<Spanned<ClassType>>,
+	/// The arguments passed to the base class constructor, e.g. `extends base(1, 2)`
+	pub arguments:  Option<Vec<Spanned<Expression>>>,
+	pub implements: Vec<Spanned<ClassType>>, // Added: IEEE 1800-2012
+	pub items:      Vec<Spanned<ClassItem>>,
+}
+
+/// A reference to a class, e.g. `pkg::base #(8)`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassType {
+	pub path:       Vec<Spanned<Identifier>>,
+	pub parameters: Option<Spanned<Connections>>,
+}
+
+/// IEEE 1800-2017 § A.1.9
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ClassQualifier {
+	Static,
+	Protected,
+	Local,
+	Rand,
+	RandC,
+	Virtual,
+	/// `pure virtual`
+	PureVirtual,
+	Extern,
+}
+
+/// A class property, method, constraint, or other class item
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassItem {
+	pub qualifiers: Vec<ClassQualifier>,
+	pub item:       Item,
+}
+
+/// IEEE 1800-2017 § A.1.10
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constraint {
+	pub name:  Spanned<Identifier>,
+	/// The constraint block, `None` for a constraint prototype
+	pub items: Option<Vec<Spanned<ConstraintItem>>>,
+}
+
+/// IEEE 1800-2017 § A.1.10
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConstraintItem {
+	Expression {
+		soft:       bool, // Added: IEEE 1800-2012
+		expression: Spanned<Expression>,
+	},
+	/// `expression dist { items }`
+	Dist {
+		soft:       bool, // Added: IEEE 1800-2012
+		expression: Spanned<Expression>,
+		items:      Vec<Spanned<DistItem>>,
+	},
+	/// `condition -> constraints`
+	Implication {
+		condition:   Spanned<Expression>,
+		constraints: Vec<Spanned<Self>>,
+	},
+	If {
+		condition: Spanned<Expression>,
+		if_true:   Vec<Spanned<Self>>,
+		if_false:  Option<Vec<Spanned<Self>>>,
+	},
+	Foreach {
+		array:       Spanned<Expression>,
+		variables:   Vec<Option<Spanned<Identifier>>>,
+		constraints: Vec<Spanned<Self>>,
+	},
+	/// `solve a, b before c;`
+	SolveBefore {
+		solve:  Vec<Spanned<Expression>>,
+		before: Vec<Spanned<Expression>>,
+	},
+	/// `unique { a, b };`
+	Unique(Vec<Spanned<Expression>>), // Added: IEEE 1800-2012
+	/// `disable soft a;`
+	DisableSoft(Spanned<Expression>), // Added: IEEE 1800-2012
+}
+
+/// A value or range in a distribution, along with its weight
+///
+/// IEEE 1800-2017 § A.1.10
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistItem {
+	pub value:  Spanned<Expression>,
+	pub weight: Option<DistWeight>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DistWeight {
+	/// `:= weight`, the weight applies to each value in the range
+	PerValue(Spanned<Expression>),
+	/// `:/ weight`, the weight is divided across the range
+	PerRange(Spanned<Expression>),
+}
+
+impl ClassItem {
+	pub fn has_qualifier(&self, qualifier: ClassQualifier) -> bool {
+		self.qualifiers.contains(&qualifier)
+	}
+}
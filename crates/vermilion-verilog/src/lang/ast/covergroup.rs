@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{CycleRange, EventControl, Expression, PortDeclaration, RepetitionKind},
+	types::Identifier,
+};
+
+/// IEEE 1800-2017 § A.2.11
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoverGroup {
+	pub name:  Spanned<Identifier>,
+	pub ports: Option<Vec<Spanned<PortDeclaration>>>,
+	pub event: Option<Spanned<CoverageEvent>>,
+	pub items: Vec<Spanned<CoverageItem>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CoverageEvent {
+	/// `@(posedge clk)`
+	Clocking(EventControl),
+	/// `with function sample (ports)`
+	Sample(Vec<Spanned<PortDeclaration>>), // Added: IEEE 1800-2009
+}
+
+/// IEEE 1800-2017 § A.2.11
+#[derive(Clone, Debug, PartialEq)]
+pub enum CoverageItem {
+	Option(CoverageOption),
+	Coverpoint(Coverpoint),
+	Cross(Cross),
+}
+
+/// `option.name = value;` or `type_option.name = value;`
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoverageOption {
+	pub type_option: bool,
+	pub name:        Spanned<Identifier>,
+	pub value:       Spanned<Expression>,
+}
+
+/// IEEE 1800-2017 § A.2.11
+#[derive(Clone, Debug, PartialEq)]
+pub struct Coverpoint {
+	pub label:      Option<Spanned<Identifier>>,
+	pub expression: Spanned<Expression>,
+	pub iff:        Option<Spanned<Expression>>,
+	/// The bins of the coverpoint, `None` if it was terminated with a `;`
+	pub bins:       Option<Vec<Spanned<BinsItem>>>,
+}
+
+/// IEEE 1800-2017 § A.2.11
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cross {
+	pub label: Option<Spanned<Identifier>>,
+	pub items: Vec<Spanned<Identifier>>,
+	pub iff:   Option<Spanned<Expression>>,
+	/// The bins of the cross, `None` if it was terminated with a `;`
+	pub bins:  Option<Vec<Spanned<BinsItem>>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BinsItem {
+	Option(CoverageOption),
+	Bins(Box<Bins>),
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum BinsKind {
+	Bins,
+	IllegalBins,
+	IgnoreBins,
+}
+
+/// IEEE 1800-2017 § A.2.11
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bins {
+	pub wildcard: bool,
+	pub kind:     BinsKind,
+	pub name:     Spanned<Identifier>,
+	/// Set for array bins, e.g. `bins a[] = ...` or `bins a[4] = ...`
+	pub array:    bool,
+	pub size:     Option<Spanned<Expression>>,
+	pub values:   BinsValues,
+	/// The `with (...)` filter expression
+	pub with:     Option<Spanned<Expression>>, // Added: IEEE 1800-2009
+	pub iff:      Option<Spanned<Expression>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BinsValues {
+	/// `{ 1, [2:3] }`
+	Set(Vec<Spanned<Expression>>),
+	/// `( 1 => 2 ), ( 3 => 4 )`
+	Transitions(Vec<Vec<Spanned<TransitionItem>>>),
+	/// `binsof(a) intersect { 1 } && binsof(b)`
+	Select(Spanned<BinsSelect>),
+	Default,
+	/// `default sequence`
+	DefaultSequence,
+}
+
+/// A single step in a bins transition, e.g. the `1, 2 [*3]` in `(0 => 1, 2 [*3])`
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransitionItem {
+	pub values:     Vec<Spanned<Expression>>,
+	pub repetition: Option<(RepetitionKind, Spanned<CycleRange>)>,
+}
+
+/// IEEE 1800-2017 § A.2.11
+#[derive(Clone, Debug, PartialEq)]
+pub enum BinsSelect {
+	BinsOf {
+		target:    Spanned<Expression>,
+		intersect: Option<Vec<Spanned<Expression>>>,
+	},
+	Not(Box<Spanned<Self>>),
+	And(Box<Spanned<Self>>, Box<Spanned<Self>>),
+	Or(Box<Spanned<Self>>, Box<Spanned<Self>>),
+	Parenthesized(Box<Spanned<Self>>),
+}
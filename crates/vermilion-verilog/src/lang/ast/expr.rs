@@ -0,0 +1,335 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_lang::AtomicByteTendril;
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{Attribute, ConstraintItem, DataType, Signing},
+	types::{
+		BasedLiteralSpecifier, Identifier, SingleQuotedString, SystemFunc, TextMacro, TimeUnit,
+		TripleQuotedString,
+	},
+};
+
+/// IEEE 1364-2005 § A.8.3
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expression {
+	Literal(Literal),
+	Identifier(Identifier),
+	/// A hierarchical name component, e.g. the `.b` in `a.b`
+	Member {
+		target: Box<Spanned<Self>>,
+		member: Spanned<Identifier>,
+	},
+	/// A bit, part, or indexed part select, e.g. `a[1]`, `a[3:0]`, `a[i+:4]`
+	Select {
+		target: Box<Spanned<Self>>,
+		select: Box<Spanned<Select>>,
+	},
+	Concatenation(Vec<Spanned<Self>>),
+	Replication {
+		count: Box<Spanned<Self>>,
+		items: Vec<Spanned<Self>>,
+	},
+	Call {
+		target:     Box<Spanned<Self>>,
+		attributes: Vec<Spanned<Attribute>>,
+		arguments:  Vec<Spanned<Self>>,
+	},
+	SystemCall {
+		name:      Spanned<SystemFunc>,
+		/// Arguments to system calls may be omitted, e.g. `$display(a,,b)`
+		arguments: Vec<Option<Spanned<Self>>>,
+	},
+	Unary {
+		operator:   Spanned<UnaryOperator>,
+		attributes: Vec<Spanned<Attribute>>,
+		operand:    Box<Spanned<Self>>,
+	},
+	Binary {
+		operator:   Spanned<BinaryOperator>,
+		attributes: Vec<Spanned<Attribute>>,
+		lhs:        Box<Spanned<Self>>,
+		rhs:        Box<Spanned<Self>>,
+	},
+	Conditional {
+		attributes: Vec<Spanned<Attribute>>,
+		condition:  Box<Spanned<Self>>,
+		if_true:    Box<Spanned<Self>>,
+		if_false:   Box<Spanned<Self>>,
+	},
+	MinTypMax {
+		min: Box<Spanned<Self>>,
+		typ: Box<Spanned<Self>>,
+		max: Box<Spanned<Self>>,
+	},
+	/// An expression wrapped in parentheses
+	Parenthesized(Box<Spanned<Self>>),
+	/// A text macro usage that has not been expanded, e.g. `` `WIDTH ``
+	MacroUsage(TextMacro),
+	/// An assignment used as an expression, e.g. `(a = b)` or `a += 1`
+	Assign {
+		lvalue:   Box<Spanned<Self>>,
+		operator: Spanned<AssignmentOperator>,
+		value:    Box<Spanned<Self>>,
+	}, // Added: IEEE 1800-2005
+	/// `++a`, `a--`, etc.
+	IncDec {
+		operator: Spanned<IncDecOperator>,
+		prefix:   bool,
+		operand:  Box<Spanned<Self>>,
+	}, // Added: IEEE 1800-2005
+	/// A class or package scoped name, e.g. `pkg::name`
+	Scope {
+		scope:  Box<Spanned<Self>>,
+		member: Spanned<Identifier>,
+	}, // Added: IEEE 1800-2005
+	/// `target'(value)`
+	Cast {
+		target: Box<Spanned<CastTarget>>,
+		value:  Box<Spanned<Self>>,
+	}, // Added: IEEE 1800-2005
+	/// `'{a, b}`, `'{default: 0}`, or `type_t'{...}`
+	AssignmentPattern {
+		data_type: Option<Box<Spanned<Self>>>,
+		items:     Vec<Spanned<PatternItem>>,
+	}, // Added: IEEE 1800-2005
+	/// `expression inside { set }`
+	Inside {
+		expression: Box<Spanned<Self>>,
+		set:        Vec<Spanned<Self>>,
+	}, // Added: IEEE 1800-2005
+	/// `[low:high]` in a set membership or distribution
+	ValueRange {
+		low:  Box<Spanned<Self>>,
+		high: Box<Spanned<Self>>,
+	}, // Added: IEEE 1800-2005
+	/// `[center +/- tolerance]` or `[center +%- tolerance]`
+	ToleranceRange {
+		center:    Box<Spanned<Self>>,
+		relative:  bool,
+		tolerance: Box<Spanned<Self>>,
+	}, // Added: IEEE 1800-2023
+	/// `{<< 8 {a, b}}` or `{>> {a}}`
+	Streaming {
+		direction: StreamDirection,
+		slice:     Option<Box<Spanned<Self>>>,
+		items:     Vec<Spanned<Self>>,
+	}, // Added: IEEE 1800-2005
+	/// `new`, `new(args)`, `new[size]`, or `new[size](init)`
+	New {
+		size:      Option<Box<Spanned<Self>>>,
+		arguments: Vec<Spanned<Self>>,
+	}, // Added: IEEE 1800-2005
+	This,  // Added: IEEE 1800-2005
+	Super, // Added: IEEE 1800-2005
+	Null,  // Added: IEEE 1800-2005
+	/// `$` as a queue index or unbounded range
+	Unbounded, // Added: IEEE 1800-2005
+	/// A data type used where an expression is expected, e.g. the `int` in `$bits(int)`
+	DataType(Box<DataType>), // Added: IEEE 1800-2005
+	/// An array manipulation method call with an iterator expression, e.g. `q.find with (item > 1)`
+	With {
+		target:     Box<Spanned<Self>>,
+		expression: Box<Spanned<Self>>,
+	}, // Added: IEEE 1800-2005
+	/// `obj.randomize() with { constraints }`
+	RandomizeWith {
+		call:        Box<Spanned<Self>>,
+		constraints: Vec<Spanned<ConstraintItem>>,
+	}, // Added: IEEE 1800-2005
+}
+
+/// The target type of a cast
+///
+/// IEEE 1800-2017 § A.8.4
+#[derive(Clone, Debug, PartialEq)]
+pub enum CastTarget {
+	/// A builtin type, e.g. `int'(x)`
+	Type(DataType),
+	/// A size or user defined type, e.g. `8'(x)` or `word_t'(x)`
+	Expression(Expression),
+	Signing(Signing),
+	Const,
+}
+
+/// An item in an assignment pattern
+///
+/// IEEE 1800-2017 § A.6.7.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum PatternItem {
+	Positional(Spanned<Expression>),
+	/// `key: value`, where the key may be a member name, index, or type
+	Keyed {
+		key:   Spanned<Expression>,
+		value: Spanned<Expression>,
+	},
+	/// `default: value`
+	Default(Spanned<Expression>),
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum StreamDirection {
+	/// `{<< ...}`
+	Left,
+	/// `{>> ...}`
+	Right,
+}
+
+/// IEEE 1800-2017 § A.6.2
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AssignmentOperator {
+	Assign,
+	Add,
+	Subtract,
+	Multiply,
+	Divide,
+	Modulus,
+	And,
+	Or,
+	Xor,
+	ShiftLeft,
+	ShiftRight,
+	ArithmeticShiftLeft,
+	ArithmeticShiftRight,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum IncDecOperator {
+	Increment,
+	Decrement,
+}
+
+/// IEEE 1364-2005 § A.8.7
+#[derive(Clone, Debug, PartialEq)]
+pub enum Literal {
+	/// An unsized decimal number, e.g. `42`
+	Unsigned(AtomicByteTendril),
+	/// A based number, e.g. `8'hFF` or `'b1`
+	Based {
+		size:      Option<AtomicByteTendril>,
+		specifier: BasedLiteralSpecifier,
+		value:     AtomicByteTendril,
+	},
+	Real(f64),
+	String(SingleQuotedString),
+	/// `'0`, `'1`, `'x`, or `'z`, holding the lowercase digit
+	UnbasedUnsized(u8), // Added: IEEE 1800-2005
+	/// A time literal, e.g. `1ns` or `2.5ps`
+	Time {
+		value: AtomicByteTendril,
+		unit:  TimeUnit,
+	}, // Added: IEEE 1800-2005
+	TripleQuotedString(TripleQuotedString), // Added: IEEE 1800-2023
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Select {
+	/// `[index]`
+	Bit(Spanned<Expression>),
+	/// `[msb:lsb]`
+	Range { msb: Spanned<Expression>, lsb: Spanned<Expression> },
+	/// `[base+:width]`
+	IndexedUp {
+		base:  Spanned<Expression>,
+		width: Spanned<Expression>,
+	}, // Added: IEEE 1364-2001
+	/// `[base-:width]`
+	IndexedDown {
+		base:  Spanned<Expression>,
+		width: Spanned<Expression>,
+	}, // Added: IEEE 1364-2001
+}
+
+/// IEEE 1364-2005 § A.8.6
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UnaryOperator {
+	Plus,
+	Minus,
+	LogicalNot,
+	BitwiseNot,
+	ReductionAnd,
+	ReductionNand,
+	ReductionOr,
+	ReductionNor,
+	ReductionXor,
+	ReductionXnor,
+}
+
+/// IEEE 1364-2005 § A.8.6
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum BinaryOperator {
+	Add,
+	Subtract,
+	Multiply,
+	Divide,
+	Modulus,
+	Power, // Added: IEEE 1364-2001
+	LogicalEquality,
+	LogicalInequality,
+	CaseEquality,
+	CaseInequality,
+	LogicalAnd,
+	LogicalOr,
+	LessThan,
+	LessThanEqual,
+	GreaterThan,
+	GreaterThanEqual,
+	BitwiseAnd,
+	BitwiseOr,
+	BitwiseXor,
+	BitwiseXnor,
+	ShiftLeft,
+	ShiftRight,
+	ArithmeticShiftLeft,  // Added: IEEE 1364-2001
+	ArithmeticShiftRight, // Added: IEEE 1364-2001
+	WildcardEquality,     // Added: IEEE 1800-2005
+	WildcardInequality,   // Added: IEEE 1800-2005
+	/// `->`, only valid in constraints and as a logical operator in IEEE 1800-2009 and later
+	LogicalImplication, // Added: IEEE 1800-2005
+	/// `<->`
+	LogicalEquivalence, // Added: IEEE 1800-2009
+}
+
+impl Expression {
+	/// Returns the identifier if this expression is a simple, non-hierarchical name
+	pub fn as_identifier(&self) -> Option<&Identifier> {
+		match self {
+			Self::Identifier(ident) => Some(ident),
+			_ => None,
+		}
+	}
+}
+
+impl BinaryOperator {
+	/// The binding power of the operator, higher values bind tighter
+	///
+	/// The logical implication and equivalence operators bind looser than the conditional
+	/// operator and are handled separately by the parser.
+	///
+	/// IEEE 1364-2005 § 5.1.2, Table 5-4, IEEE 1800-2017 § 11.3.2, Table 11-2
+	pub fn precedence(&self) -> u8 {
+		match self {
+			Self::Power => 11,
+			Self::Multiply | Self::Divide | Self::Modulus => 10,
+			Self::Add | Self::Subtract => 9,
+			Self::ShiftLeft |
+			Self::ShiftRight |
+			Self::ArithmeticShiftLeft |
+			Self::ArithmeticShiftRight => 8,
+			Self::LessThan | Self::LessThanEqual | Self::GreaterThan | Self::GreaterThanEqual => 7,
+			Self::LogicalEquality |
+			Self::LogicalInequality |
+			Self::CaseEquality |
+			Self::CaseInequality |
+			Self::WildcardEquality |
+			Self::WildcardInequality => 6,
+			Self::BitwiseAnd => 5,
+			Self::BitwiseXor | Self::BitwiseXnor => 4,
+			Self::BitwiseOr => 3,
+			Self::LogicalAnd => 2,
+			Self::LogicalOr => 1,
+			Self::LogicalImplication | Self::LogicalEquivalence => 0,
+		}
+	}
+}
@@ -0,0 +1,674 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{
+		Assertion, AssertionPort, Assignment, Attribute, Branch, Class, Constraint, CoverGroup,
+		Delay, DisciplineNet, EventControl, Expression, PropertyDeclaration, SequenceDeclaration,
+		SpecifyItem, SpecparamDeclaration, Statement, ValueRange,
+	},
+	types::{
+		ChargeStrength, DriveStrength, EdgeIdentifier, HighStrength, Identifier, LowStrength,
+		NetType, SingleQuotedString,
+	},
+};
+
+/// A module, generate, or block item along with any attributes attached to it
+///
+/// IEEE 1364-2005 § A.1.4
+#[derive(Clone, Debug, PartialEq)]
+pub struct Item {
+	pub attributes: Vec<Spanned<Attribute>>,
+	pub kind:       ItemKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ItemKind {
+	Port(PortDeclaration),
+	Net(NetDeclaration),
+	Variable(VariableDeclaration),
+	Parameter(ParameterDeclaration),
+	Specparam(SpecparamDeclaration),
+	Genvar(Vec<Spanned<Identifier>>), // Added: IEEE 1364-2001
+	Defparam(Vec<Spanned<Assignment>>),
+	ContinuousAssign(ContinuousAssign),
+	Gate(GateInstantiation),
+	Instantiation(Instantiation),
+	Initial(Spanned<Statement>),
+	Always(Spanned<Statement>),
+	Task(Task),
+	Function(Function),
+	GenerateRegion(Vec<Spanned<Item>>), // Added: IEEE 1364-2001
+	GenerateFor(Box<GenerateFor>),      // Added: IEEE 1364-2001
+	GenerateIf(GenerateIf),             // Added: IEEE 1364-2001
+	GenerateCase(GenerateCase),         // Added: IEEE 1364-2001
+	GenerateBlock(GenerateBlock),       // Added: IEEE 1364-2001
+	Specify(Vec<Spanned<SpecifyItem>>),
+	AlwaysComb(Spanned<Statement>),      // Added: IEEE 1800-2005
+	AlwaysFF(Spanned<Statement>),        // Added: IEEE 1800-2005
+	AlwaysLatch(Spanned<Statement>),     // Added: IEEE 1800-2005
+	Final(Spanned<Statement>),           // Added: IEEE 1800-2005
+	Typedef(Typedef),                    // Added: IEEE 1800-2005
+	Import(Vec<Spanned<PackageImport>>), // Added: IEEE 1800-2005
+	/// An empty list of exports is `export *::*`
+	Export(Vec<Spanned<PackageImport>>), // Added: IEEE 1800-2009
+	Modport(Vec<Spanned<ModportDeclaration>>), // Added: IEEE 1800-2005
+	Class(Class),                        // Added: IEEE 1800-2005
+	Constraint(Constraint),              // Added: IEEE 1800-2005
+	/// An `extern` or `pure virtual` task or function without a body
+	Prototype(SubroutinePrototype), // Added: IEEE 1800-2005
+	Assertion(Assertion),                // Added: IEEE 1800-2005
+	Property(PropertyDeclaration),       // Added: IEEE 1800-2005
+	Sequence(SequenceDeclaration),       // Added: IEEE 1800-2005
+	Let(LetDeclaration),                 // Added: IEEE 1800-2009
+	CoverGroup(CoverGroup),              // Added: IEEE 1800-2005
+	Clocking(ClockingBlock),             // Added: IEEE 1800-2005
+	/// `default clocking name;`
+	DefaultClocking(Spanned<Identifier>), // Added: IEEE 1800-2005
+	/// `default disable iff expression;`
+	DefaultDisable(Spanned<Expression>), // Added: IEEE 1800-2005
+	TimeUnits(TimeUnits),                // Added: IEEE 1800-2005
+	Bind(Bind),                          // Added: IEEE 1800-2005
+	Dpi(DpiDeclaration),                 // Added: IEEE 1800-2005
+	DisciplineNet(DisciplineNet),        // Added: Verilog-AMS 2009
+	Ground(Vec<Spanned<Identifier>>),    // Added: Verilog-AMS 2009
+	Branch(Branch),                      // Added: Verilog-AMS 2009
+	Analog(Spanned<Statement>),          // Added: Verilog-AMS 2009
+	AnalogInitial(Spanned<Statement>),   // Added: Verilog-AMS 2009
+	AnalogFunction(Function),            // Added: Verilog-AMS 2009
+	/// An item that failed to parse, the error is reported in the diagnostics
+	Error,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Signing {
+	Signed,
+	Unsigned,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum IntegerVectorType {
+	Reg,
+	Bit,   // Added: IEEE 1800-2005
+	Logic, // Added: IEEE 1800-2005
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum IntegerAtomType {
+	Integer,
+	Time,
+	Byte,     // Added: IEEE 1800-2005
+	ShortInt, // Added: IEEE 1800-2005
+	Int,      // Added: IEEE 1800-2005
+	LongInt,  // Added: IEEE 1800-2005
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum NonIntegerType {
+	Real,
+	Realtime,
+	ShortReal, // Added: IEEE 1800-2005
+}
+
+/// The data type of a declaration
+///
+/// IEEE 1800-2017 § A.2.2.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataType {
+	/// No explicit type keyword, only an optional signing and packed range
+	Implicit {
+		signing: Option<Signing>,
+		packed:  Vec<Spanned<Dimension>>,
+	},
+	IntegerVector {
+		kind:    IntegerVectorType,
+		signing: Option<Signing>,
+		packed:  Vec<Spanned<Dimension>>,
+	},
+	IntegerAtom {
+		kind:    IntegerAtomType,
+		signing: Option<Signing>,
+	},
+	NonInteger(NonIntegerType),
+	Event,
+	String,                  // Added: IEEE 1800-2005
+	CHandle,                 // Added: IEEE 1800-2005
+	Void,                    // Added: IEEE 1800-2005
+	Struct(Box<StructType>), // Added: IEEE 1800-2005
+	Enum(Box<EnumType>),     // Added: IEEE 1800-2005
+	/// A reference to a user defined type, e.g. `pkg::word_t [1:0]` or `fifo#(8)`
+	Named {
+		path:       Vec<Spanned<Identifier>>,
+		parameters: Option<Spanned<Connections>>,
+		packed:     Vec<Spanned<Dimension>>,
+	}, // Added: IEEE 1800-2005
+	/// `virtual [interface] name[.modport]`
+	VirtualInterface {
+		name:       Spanned<Identifier>,
+		parameters: Option<Spanned<Connections>>,
+		modport:    Option<Spanned<Identifier>>,
+	}, // Added: IEEE 1800-2005
+	/// An interface port, `interface[.modport]` or `name.modport`
+	Interface {
+		name:    Option<Spanned<Identifier>>,
+		modport: Option<Spanned<Identifier>>,
+	}, // Added: IEEE 1800-2005
+	/// `type`, the type of a type parameter
+	Type, // Added: IEEE 1800-2005
+}
+
+/// IEEE 1800-2017 § A.2.5
+#[derive(Clone, Debug, PartialEq)]
+pub enum Dimension {
+	/// `[msb:lsb]`
+	Range { msb: Spanned<Expression>, lsb: Spanned<Expression> },
+	/// `[size]`
+	Size(Spanned<Expression>), // Added: IEEE 1800-2005
+	/// `[]`, a dynamic array
+	Unsized, // Added: IEEE 1800-2005
+	/// `[$]` or `[$:bound]`
+	Queue(Option<Spanned<Expression>>), // Added: IEEE 1800-2005
+	/// `[*]` or `[index_type]`, an associative array
+	Associative(Option<Box<DataType>>), // Added: IEEE 1800-2005
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum StructKind {
+	Struct,
+	Union,
+}
+
+/// A `struct` or `union` type
+///
+/// IEEE 1800-2017 § A.2.2.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct StructType {
+	pub kind:       StructKind,
+	pub tagged:     bool,
+	pub packed:     bool,
+	pub signing:    Option<Signing>,
+	pub members:    Vec<Spanned<StructMember>>,
+	/// The packed dimensions following the closing `}`
+	pub dimensions: Vec<Spanned<Dimension>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StructMember {
+	pub attributes:  Vec<Spanned<Attribute>>,
+	pub random:      Option<RandomQualifier>,
+	pub data_type:   DataType,
+	pub declarators: Vec<Spanned<Declarator>>,
+}
+
+/// An `enum` type
+///
+/// IEEE 1800-2017 § A.2.2.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumType {
+	pub base:       Option<Box<DataType>>,
+	pub members:    Vec<Spanned<EnumMember>>,
+	/// The packed dimensions following the closing `}`
+	pub dimensions: Vec<Spanned<Dimension>>,
+}
+
+/// A single enum name, e.g. `A`, `B = 2`, or `C[4] = 8`
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumMember {
+	pub name:  Spanned<Identifier>,
+	pub range: Option<Spanned<Dimension>>,
+	pub value: Option<Spanned<Expression>>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RandomQualifier {
+	Rand,
+	RandC,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Lifetime {
+	Static,
+	Automatic,
+}
+
+/// A single declared name, e.g. the `b [3:0] = 0` in `reg a, b [3:0] = 0;`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Declarator {
+	pub name:       Spanned<Identifier>,
+	pub dimensions: Vec<Spanned<Dimension>>,
+	pub init:       Option<Spanned<Expression>>,
+	/// The `from` and `exclude` ranges of a parameter value
+	pub ranges:     Vec<Spanned<ValueRange>>, // Added: Verilog-AMS 2009
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PortDirection {
+	Input,
+	Output,
+	InOut,
+	Ref, // Added: IEEE 1800-2005
+}
+
+/// IEEE 1364-2005 § A.2.1.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct PortDeclaration {
+	/// Attributes attached to ANSI style port declarations
+	pub attributes:  Vec<Spanned<Attribute>>,
+	/// The port direction, which may be omitted for SystemVerilog ANSI style ports
+	pub direction:   Option<PortDirection>,
+	pub discipline:  Option<Spanned<Identifier>>, // Added: Verilog-AMS 2009
+	pub net_type:    Option<NetType>,
+	/// Set for `var` ports and `const ref` subroutine ports
+	pub var:         bool,
+	pub data_type:   DataType,
+	pub declarators: Vec<Spanned<Declarator>>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Strength {
+	Drive(DriveStrength),
+	Charge(ChargeStrength),
+	/// A single `strength0` as found on `pulldown` gates
+	Low(LowStrength),
+	/// A single `strength1` as found on `pullup` gates
+	High(HighStrength),
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum VectorKind {
+	Vectored,
+	Scalared,
+}
+
+/// IEEE 1364-2005 § A.2.1.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct NetDeclaration {
+	pub net_type:    NetType,
+	pub strength:    Option<Strength>,
+	pub vector:      Option<VectorKind>,
+	pub data_type:   DataType,
+	pub delay:       Option<Spanned<Delay>>,
+	pub declarators: Vec<Spanned<Declarator>>,
+}
+
+/// IEEE 1364-2005 § A.2.1.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariableDeclaration {
+	pub constant:    bool,             // Added: IEEE 1800-2005
+	pub lifetime:    Option<Lifetime>, // Added: IEEE 1800-2005
+	pub data_type:   DataType,
+	pub declarators: Vec<Spanned<Declarator>>,
+}
+
+/// A `parameter` or `localparam` declaration
+///
+/// IEEE 1364-2005 § A.2.1.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParameterDeclaration {
+	pub local:       bool,
+	pub data_type:   DataType,
+	pub declarators: Vec<Spanned<Declarator>>,
+}
+
+/// IEEE 1364-2005 § A.6.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContinuousAssign {
+	pub strength:    Option<Strength>,
+	pub delay:       Option<Spanned<Delay>>,
+	pub assignments: Vec<Spanned<Assignment>>,
+}
+
+/// IEEE 1364-2005 § A.3.4
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum GateType {
+	Cmos,
+	Rcmos,
+	BufIf0,
+	BufIf1,
+	NotIf0,
+	NotIf1,
+	Nmos,
+	Pmos,
+	Rnmos,
+	Rpmos,
+	And,
+	Nand,
+	Or,
+	Nor,
+	Xor,
+	Xnor,
+	Buf,
+	Not,
+	TranIf0,
+	TranIf1,
+	RtranIf0,
+	RtranIf1,
+	Tran,
+	Rtran,
+	Pulldown,
+	Pullup,
+}
+
+/// IEEE 1364-2005 § A.3.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct GateInstantiation {
+	pub gate:      GateType,
+	pub strength:  Option<Strength>,
+	pub delay:     Option<Spanned<Delay>>,
+	pub instances: Vec<Spanned<GateInstance>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GateInstance {
+	pub name:      Option<Spanned<Identifier>>,
+	pub range:     Option<Spanned<Dimension>>,
+	pub terminals: Vec<Spanned<Expression>>,
+}
+
+/// A module or UDP instantiation, the two can not be told apart syntactically
+///
+/// IEEE 1364-2005 § A.4.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instantiation {
+	pub module:     Spanned<Identifier>,
+	pub strength:   Option<Strength>,
+	pub parameters: Option<Spanned<Connections>>,
+	pub instances:  Vec<Spanned<Instance>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instance {
+	pub name:        Option<Spanned<Identifier>>,
+	pub range:       Option<Spanned<Dimension>>,
+	pub connections: Connections,
+}
+
+/// Parameter value assignments or port connections
+#[derive(Clone, Debug, PartialEq)]
+pub enum Connections {
+	/// Connections by position, empty positions are permitted for ports
+	Ordered(Vec<Option<Spanned<Expression>>>),
+	/// Connections by name, e.g. `.a(b)`
+	Named(Vec<Spanned<NamedConnection>>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NamedConnection {
+	pub attributes: Vec<Spanned<Attribute>>,
+	/// The name of the port or parameter, `None` for a `.*` wildcard connection
+	pub name:       Option<Spanned<Identifier>>,
+	pub value:      Option<Spanned<Expression>>,
+	/// Set for `.name` connections without a parenthesized value
+	pub implicit:   bool, // Added: IEEE 1800-2005
+}
+
+/// IEEE 1364-2005 § A.2.7
+#[derive(Clone, Debug, PartialEq)]
+pub struct Task {
+	pub automatic: bool,
+	/// The class scope of an out-of-block method definition, e.g. the `cls` in `cls::run`
+	pub scope:     Option<Spanned<Identifier>>, // Added: IEEE 1800-2005
+	pub name:      Spanned<Identifier>,
+	/// The ANSI-style port list, if any
+	pub ports:     Option<Vec<Spanned<PortDeclaration>>>,
+	pub items:     Vec<Spanned<Item>>,
+	pub body:      Vec<Spanned<Statement>>,
+}
+
+/// IEEE 1364-2005 § A.2.6
+#[derive(Clone, Debug, PartialEq)]
+pub struct Function {
+	pub automatic:   bool,
+	pub return_type: DataType,
+	/// The class scope of an out-of-block method definition, e.g. the `cls` in `cls::new`
+	pub scope:       Option<Spanned<Identifier>>, // Added: IEEE 1800-2005
+	pub name:        Spanned<Identifier>,
+	/// The ANSI-style port list, if any
+	pub ports:       Option<Vec<Spanned<PortDeclaration>>>,
+	pub items:       Vec<Spanned<Item>>,
+	pub body:        Vec<Spanned<Statement>>,
+}
+
+/// A generate block, either a `begin`/`end` block or a single generate item
+///
+/// IEEE 1364-2005 § A.4.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenerateBlock {
+	pub name:  Option<Spanned<Identifier>>,
+	pub items: Vec<Spanned<Item>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenerateFor {
+	/// Set if the loop variable is declared inline, e.g. `for (genvar i = 0; ...)`
+	pub genvar:    bool, // Added: IEEE 1800-2005
+	pub init:      Spanned<Assignment>,
+	pub condition: Spanned<Expression>,
+	/// The loop step, either an assignment or an increment/decrement expression
+	pub step:      Spanned<Expression>,
+	pub block:     Spanned<GenerateBlock>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenerateIf {
+	pub condition: Spanned<Expression>,
+	pub if_true:   Option<Spanned<GenerateBlock>>,
+	pub if_false:  Option<Spanned<GenerateBlock>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenerateCase {
+	pub expression: Spanned<Expression>,
+	pub items:      Vec<Spanned<GenerateCaseItem>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenerateCaseItem {
+	/// The labels of the case item, empty for the `default` item
+	pub labels: Vec<Spanned<Expression>>,
+	pub block:  Option<Spanned<GenerateBlock>>,
+}
+
+/// IEEE 1800-2017 § A.2.1.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct Typedef {
+	/// The defined type, or `None` for a forward declaration like `typedef class c;`
+	pub data_type:  Option<DataType>,
+	pub name:       Spanned<Identifier>,
+	pub dimensions: Vec<Spanned<Dimension>>,
+}
+
+/// A single `package::item` or `package::*` import
+///
+/// IEEE 1800-2017 § A.2.1.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct PackageImport {
+	pub package: Spanned<Identifier>,
+	/// The imported item, or `None` for a wildcard import
+	pub item:    Option<Spanned<Identifier>>,
+}
+
+/// IEEE 1800-2017 § A.2.9
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModportDeclaration {
+	pub name:  Spanned<Identifier>,
+	pub ports: Vec<Spanned<ModportPort>>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ModportPortKind {
+	Direction(PortDirection),
+	Import,
+	Export,
+	Clocking,
+}
+
+/// A single modport port, e.g. `input a`, `.b(expr)`, or `import task t`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModportPort {
+	pub kind:       ModportPortKind,
+	pub name:       Spanned<Identifier>,
+	/// The expression of a `.name(expression)` modport port
+	pub expression: Option<Spanned<Expression>>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SubroutineKind {
+	Task,
+	Function,
+}
+
+/// A task or function header without a body
+///
+/// IEEE 1800-2017 § A.2.7
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubroutinePrototype {
+	pub kind:        SubroutineKind,
+	/// The return type, this is [`DataType::Void`] for tasks
+	pub return_type: DataType,
+	pub name:        Spanned<Identifier>,
+	pub ports:       Option<Vec<Spanned<PortDeclaration>>>,
+}
+
+/// `let name(ports) = expression;`
+///
+/// IEEE 1800-2017 § A.2.12
+#[derive(Clone, Debug, PartialEq)]
+pub struct LetDeclaration {
+	pub name:       Spanned<Identifier>,
+	pub ports:      Vec<Spanned<AssertionPort>>,
+	pub expression: Spanned<Expression>,
+}
+
+/// `timeunit` and `timeprecision` declarations
+///
+/// IEEE 1800-2017 § A.1.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeUnits {
+	pub unit:      Option<Spanned<Expression>>,
+	pub precision: Option<Spanned<Expression>>,
+}
+
+/// IEEE 1800-2017 § A.1.4
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bind {
+	/// The module or instance being bound into
+	pub target:        Spanned<Expression>,
+	/// The instances of `target` to bind into, e.g. the `a, b` in `bind m : a, b ...`
+	pub instances:     Vec<Spanned<Expression>>,
+	pub instantiation: Instantiation,
+}
+
+/// IEEE 1800-2017 § A.6.11
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClockingBlock {
+	pub default: bool,
+	pub global:  bool, // Added: IEEE 1800-2009
+	pub name:    Option<Spanned<Identifier>>,
+	pub event:   Spanned<EventControl>,
+	pub items:   Vec<Spanned<ClockingItem>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClockingItem {
+	/// `default input #1 output #2;`
+	Default {
+		input:  Option<Spanned<ClockingSkew>>,
+		output: Option<Spanned<ClockingSkew>>,
+	},
+	/// `input #1 a, b = top.c;`
+	Signals {
+		direction:   ClockingDirection,
+		input_skew:  Option<Spanned<ClockingSkew>>,
+		output_skew: Option<Spanned<ClockingSkew>>,
+		signals:     Vec<Spanned<ClockingSignal>>,
+	},
+	/// A property, sequence, or let declaration inside of the clocking block
+	Item(Box<Item>),
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ClockingDirection {
+	Input,
+	Output,
+	InputOutput,
+	InOut,
+}
+
+/// IEEE 1800-2017 § A.6.11
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClockingSkew {
+	pub edge:  Option<EdgeIdentifier>,
+	pub delay: Option<Spanned<Delay>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClockingSignal {
+	pub name:  Spanned<Identifier>,
+	pub value: Option<Spanned<Expression>>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DpiDirection {
+	Import,
+	Export,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DpiProperty {
+	Context,
+	Pure,
+}
+
+/// A DPI `import` or `export` of a task or function
+///
+/// IEEE 1800-2017 § A.2.6
+#[derive(Clone, Debug, PartialEq)]
+pub struct DpiDeclaration {
+	pub direction: DpiDirection,
+	/// The DPI spec string, e.g. `"DPI-C"`
+	pub spec:      SingleQuotedString,
+	pub property:  Option<DpiProperty>,
+	/// The foreign name given with `c_name = `, if any
+	pub c_name:    Option<Spanned<Identifier>>,
+	/// The imported subroutine, exports only carry the kind and name
+	pub prototype: SubroutinePrototype,
+}
+
+impl Item {
+	pub fn new(kind: ItemKind) -> Self {
+		Self { attributes: Vec::new(), kind }
+	}
+}
+
+impl DataType {
+	/// The packed dimensions of the type, if it has any
+	pub fn packed(&self) -> &[Spanned<Dimension>] {
+		match self {
+			Self::Implicit { packed, .. } |
+			Self::IntegerVector { packed, .. } |
+			Self::Named { packed, .. } => packed,
+			Self::Struct(struct_type) => &struct_type.dimensions,
+			Self::Enum(enum_type) => &enum_type.dimensions,
+			_ => &[],
+		}
+	}
+
+	/// The explicit signing of the type, if any
+	pub fn signing(&self) -> Option<Signing> {
+		match self {
+			Self::Implicit { signing, .. } |
+			Self::IntegerVector { signing, .. } |
+			Self::IntegerAtom { signing, .. } => *signing,
+			Self::Struct(struct_type) => struct_type.signing,
+			_ => None,
+		}
+	}
+}
+
+impl Default for DataType {
+	fn default() -> Self {
+		Self::Implicit { signing: None, packed: Vec::new() }
+	}
+}
@@ -0,0 +1,275 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_diagnostics::StringDiagnostic;
+use vermilion_loc::Spanned;
+
+use crate::lang::{cst::SyntaxNode, preprocessor::SourceMap, types::Identifier};
+
+pub mod analog;
+pub mod assertion;
+pub mod class;
+pub mod covergroup;
+pub mod expr;
+pub mod item;
+pub mod specify;
+pub mod stmt;
+pub mod udp;
+
+pub use self::{
+	analog::*, assertion::*, class::*, covergroup::*, expr::*, item::*, specify::*, stmt::*, udp::*,
+};
+
+/// The root of a parsed Verilog source file
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ast {
+	descriptions: Vec<Spanned<Description>>,
+	/// Problems found while parsing, such as syntax errors that were recovered from or constructs
+	/// from a later standard
+	diagnostics:  Vec<StringDiagnostic>,
+	/// The lossless concrete syntax tree the AST was parsed from
+	syntax:       SyntaxNode,
+	/// Where the text macros used in the file were defined
+	source_map:   SourceMap,
+}
+
+/// A top-level construct in a source file
+///
+/// IEEE 1364-2005 § A.1.1, IEEE 1800-2017 § A.1.2
+#[derive(Clone, Debug, PartialEq)]
+pub enum Description {
+	/// A module, interface, or program declaration
+	Module(Module),
+	Primitive(Udp),
+	Config(Config),   // Added: IEEE 1364-2001
+	Package(Package), // Added: IEEE 1800-2005
+	Class(Class),     // Added: IEEE 1800-2005
+	/// An item declared directly in the compilation unit scope
+	Item(Box<Item>), // Added: IEEE 1800-2005
+	Nature(Nature),   // Added: Verilog-AMS 2009
+	Discipline(Discipline), // Added: Verilog-AMS 2009
+	/// A top-level construct that failed to parse, the error is reported in the diagnostics
+	Error,
+}
+
+/// An attribute specification, e.g. the `full_case` in `(* full_case *)`
+///
+/// IEEE 1364-2005 § A.9.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attribute {
+	pub name:  Spanned<Identifier>,
+	pub value: Option<Spanned<Expression>>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ModuleKeyword {
+	Module,
+	MacroModule,
+	Interface, // Added: IEEE 1800-2005
+	Program,   // Added: IEEE 1800-2005
+}
+
+/// IEEE 1364-2005 § A.1.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct Module {
+	pub attributes: Vec<Spanned<Attribute>>,
+	pub keyword:    ModuleKeyword,
+	pub lifetime:   Option<Lifetime>, // Added: IEEE 1800-2005
+	pub name:       Spanned<Identifier>,
+	/// Package imports in the module header
+	pub imports:    Vec<Spanned<PackageImport>>, // Added: IEEE 1800-2009
+	/// The `#(...)` parameter port list, if any
+	pub parameters: Option<Vec<Spanned<ParameterDeclaration>>>,
+	pub ports:      Ports,
+	pub items:      Vec<Spanned<Item>>,
+}
+
+/// The port list in a module header
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Ports {
+	/// The module has no port list at all
+	#[default]
+	None,
+	/// A list of port references, with the directions declared in the module body
+	NonAnsi(Vec<Spanned<Port>>),
+	/// A list of full port declarations
+	Ansi(Vec<Spanned<PortDeclaration>>), // Added: IEEE 1364-2001
+}
+
+/// A non-ANSI style module port
+///
+/// IEEE 1364-2005 § A.1.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct Port {
+	/// The explicit port name for `.name(expr)` style ports
+	pub name:       Option<Spanned<Identifier>>,
+	pub expression: Option<Spanned<Expression>>,
+}
+
+/// IEEE 1800-2017 § A.1.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct Package {
+	pub attributes: Vec<Spanned<Attribute>>,
+	pub lifetime:   Option<Lifetime>,
+	pub name:       Spanned<Identifier>,
+	pub items:      Vec<Spanned<Item>>,
+}
+
+/// IEEE 1364-2005 § A.1.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+	pub name:   Spanned<Identifier>,
+	pub design: Vec<Spanned<CellReference>>,
+	pub rules:  Vec<Spanned<ConfigRule>>,
+}
+
+/// A `[library.]cell[:config]` reference
+#[derive(Clone, Debug, PartialEq)]
+pub struct CellReference {
+	pub library: Option<Spanned<Identifier>>,
+	pub cell:    Spanned<Identifier>,
+	pub config:  bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigRuleTarget {
+	Default,
+	Instance(Vec<Spanned<Identifier>>),
+	Cell(CellReference),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigRuleAction {
+	LibList(Vec<Spanned<Identifier>>),
+	Use(CellReference),
+}
+
+/// IEEE 1364-2005 § A.1.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigRule {
+	pub target: ConfigRuleTarget,
+	pub action: ConfigRuleAction,
+}
+
+impl Ast {
+	pub fn new(
+		descriptions: Vec<Spanned<Description>>,
+		diagnostics: Vec<StringDiagnostic>,
+		syntax: SyntaxNode,
+	) -> Self {
+		Self {
+			descriptions,
+			diagnostics,
+			syntax,
+			source_map: SourceMap::default(),
+		}
+	}
+
+	/// Attach the [`SourceMap`] of the preprocessed file
+	pub fn with_source_map(self, source_map: SourceMap) -> Self {
+		Self { source_map, ..self }
+	}
+
+	pub fn descriptions(&self) -> &[Spanned<Description>] {
+		&self.descriptions
+	}
+
+	pub fn diagnostics(&self) -> &[StringDiagnostic] {
+		&self.diagnostics
+	}
+
+	pub fn syntax(&self) -> &SyntaxNode {
+		&self.syntax
+	}
+
+	pub fn source_map(&self) -> &SourceMap {
+		&self.source_map
+	}
+
+	pub(crate) fn into_parts(
+		self,
+	) -> (
+		Vec<Spanned<Description>>,
+		Vec<StringDiagnostic>,
+		SyntaxNode,
+		SourceMap,
+	) {
+		(
+			self.descriptions,
+			self.diagnostics,
+			self.syntax,
+			self.source_map,
+		)
+	}
+
+	/// Iterate over all of the modules, interfaces, and programs declared in this file
+	pub fn modules(&self) -> impl Iterator<Item = &Module> {
+		self.descriptions
+			.iter()
+			.filter_map(|description| match description.inner() {
+				Description::Module(module) => Some(module),
+				_ => None,
+			})
+	}
+}
+
+impl Description {
+	/// The name of the description, compilation unit items and errors are not named
+	pub fn name(&self) -> Option<&Spanned<Identifier>> {
+		match self {
+			Self::Module(module) => Some(&module.name),
+			Self::Primitive(udp) => Some(&udp.name),
+			Self::Config(config) => Some(&config.name),
+			Self::Package(package) => Some(&package.name),
+			Self::Class(class) => Some(&class.name),
+			Self::Nature(nature) => Some(&nature.name),
+			Self::Discipline(discipline) => Some(&discipline.name),
+			Self::Item(_) | Self::Error => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use vermilion_loc::Span;
+
+	use super::*;
+
+	#[test]
+	fn test_ast_modules() {
+		let ident =
+			|name: &str| Spanned::new(Identifier::Simple(name.as_bytes().into()), Span::empty());
+
+		let ast = Ast::new(
+			vec![
+				Spanned::new(
+					Description::Module(Module {
+						attributes: Vec::new(),
+						keyword:    ModuleKeyword::Module,
+						lifetime:   None,
+						name:       ident("a"),
+						imports:    Vec::new(),
+						parameters: None,
+						ports:      Ports::None,
+						items:      Vec::new(),
+					}),
+					Span::empty(),
+				),
+				Spanned::new(
+					Description::Config(Config {
+						name:   ident("b"),
+						design: Vec::new(),
+						rules:  Vec::new(),
+					}),
+					Span::empty(),
+				),
+			],
+			Vec::new(),
+			SyntaxNode::default(),
+		);
+
+		assert_eq!(ast.descriptions().len(), 2);
+		assert_eq!(ast.modules().count(), 1);
+		assert_eq!(ast.descriptions()[1].name(), Some(&ident("b")));
+		assert!(ast.diagnostics().is_empty());
+	}
+}
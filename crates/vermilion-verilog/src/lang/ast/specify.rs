@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_lang::AtomicByteTendril;
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{Dimension, Expression},
+	types::{EdgeIdentifier, Identifier, SystemFunc},
+};
+
+/// IEEE 1364-2005 § A.7.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum SpecifyItem {
+	Specparam(SpecparamDeclaration),
+	PulseStyle {
+		kind:    PulseStyle,
+		outputs: Vec<Spanned<Expression>>,
+	}, // Added: IEEE 1364-2001
+	ShowCancelled {
+		show:    bool,
+		outputs: Vec<Spanned<Expression>>,
+	}, // Added: IEEE 1364-2001
+	Path(PathDeclaration),
+	TimingCheck(TimingCheck),
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PulseStyle {
+	OnEvent,
+	OnDetect,
+}
+
+/// IEEE 1364-2005 § A.2.2.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpecparamDeclaration {
+	pub range:       Option<Spanned<Dimension>>,
+	pub assignments: Vec<Spanned<SpecparamAssignment>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpecparamAssignment {
+	pub name:  Spanned<Identifier>,
+	pub value: SpecparamValue,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SpecparamValue {
+	Value(Spanned<Expression>),
+	/// A `PATHPULSE$` pulse control value, e.g. `(reject, error)`
+	PulseControl {
+		reject: Spanned<Expression>,
+		error:  Option<Spanned<Expression>>,
+	},
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PathConnection {
+	/// `=>`
+	Parallel,
+	/// `*>`
+	Full,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Polarity {
+	Positive,
+	Negative,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PathCondition {
+	If(Spanned<Expression>),
+	IfNone,
+}
+
+/// IEEE 1364-2005 § A.7.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathDeclaration {
+	pub condition:   Option<PathCondition>,
+	pub edge:        Option<EdgeIdentifier>,
+	pub inputs:      Vec<Spanned<Expression>>,
+	pub polarity:    Option<Polarity>,
+	pub connection:  PathConnection,
+	pub outputs:     Vec<Spanned<Expression>>,
+	/// The data source expression and polarity of edge sensitive paths
+	pub data_source: Option<(Option<Polarity>, Spanned<Expression>)>,
+	pub delays:      Vec<Spanned<Expression>>,
+}
+
+/// IEEE 1364-2005 § A.7.5
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingCheck {
+	pub name:      Spanned<SystemFunc>,
+	/// The arguments to the timing check, some of which may be omitted
+	pub arguments: Vec<Option<Spanned<TimingCheckEvent>>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimingCheckEdge {
+	Edge(EdgeIdentifier),
+	/// `edge [01, 10]`
+	Descriptors(Vec<Spanned<AtomicByteTendril>>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingCheckEvent {
+	pub edge:       Option<TimingCheckEdge>,
+	pub expression: Spanned<Expression>,
+	/// The `&&&` condition for the event, if any
+	pub condition:  Option<Spanned<Expression>>,
+}
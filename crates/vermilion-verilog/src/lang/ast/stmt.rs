@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{Assertion, Attribute, DataType, Expression, Item},
+	types::{EdgeIdentifier, Identifier, SystemFunc},
+};
+
+/// A procedural statement along with any attributes attached to it
+///
+/// IEEE 1364-2005 § A.6.4
+#[derive(Clone, Debug, PartialEq)]
+pub struct Statement {
+	pub attributes: Vec<Spanned<Attribute>>,
+	/// The statement label, e.g. the `check` in `check: assert (a);`
+	pub label:      Option<Spanned<Identifier>>, // Added: IEEE 1800-2005
+	pub kind:       StatementKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StatementKind {
+	/// A lone `;`
+	Null,
+	Blocking(Assignment),
+	NonBlocking(Assignment),
+	/// `assign lvalue = value;`
+	ProceduralAssign(Assignment),
+	Deassign(Spanned<Expression>),
+	Force(Assignment),
+	Release(Spanned<Expression>),
+	Case(Case),
+	If {
+		qualifier: Option<CaseQualifier>, // Added: IEEE 1800-2005
+		condition: Spanned<Expression>,
+		if_true:   Box<Spanned<Statement>>,
+		if_false:  Option<Box<Spanned<Statement>>>,
+	},
+	Disable(Spanned<Expression>),
+	EventTrigger(Spanned<Expression>),
+	Forever(Box<Spanned<Statement>>),
+	Repeat {
+		count: Spanned<Expression>,
+		body:  Box<Spanned<Statement>>,
+	},
+	While {
+		condition: Spanned<Expression>,
+		body:      Box<Spanned<Statement>>,
+	},
+	For {
+		init:      Vec<Spanned<ForInit>>,
+		/// The loop condition, which may be omitted in SystemVerilog
+		condition: Option<Spanned<Expression>>,
+		/// The loop step, Verilog only permits a single assignment
+		step:      Vec<Spanned<Expression>>,
+		body:      Box<Spanned<Statement>>,
+	},
+	Block(Block),
+	TimingControl {
+		control:   Spanned<TimingControl>,
+		statement: Box<Spanned<Statement>>,
+	},
+	SystemTaskEnable {
+		name:      Spanned<SystemFunc>,
+		arguments: Vec<Option<Spanned<Expression>>>,
+	},
+	TaskEnable {
+		name:      Spanned<Expression>,
+		arguments: Vec<Spanned<Expression>>,
+	},
+	Wait {
+		condition: Spanned<Expression>,
+		statement: Box<Spanned<Statement>>,
+	},
+	/// An expression evaluated for its side effects, e.g. `i++;` or `void'(f());`
+	Expression(Spanned<Expression>), // Added: IEEE 1800-2005
+	DoWhile {
+		body:      Box<Spanned<Statement>>,
+		condition: Spanned<Expression>,
+	}, // Added: IEEE 1800-2005
+	/// `foreach (array[i, j]) body`, skipped loop variables are `None`
+	Foreach {
+		array:     Spanned<Expression>,
+		variables: Vec<Option<Spanned<Identifier>>>,
+		body:      Box<Spanned<Statement>>,
+	}, // Added: IEEE 1800-2005
+	Return(Option<Spanned<Expression>>), // Added: IEEE 1800-2005
+	Break,                               // Added: IEEE 1800-2005
+	Continue,                            // Added: IEEE 1800-2005
+	WaitFork,                            // Added: IEEE 1800-2005
+	DisableFork,                         // Added: IEEE 1800-2005
+	/// `->> name;`
+	EventTriggerNb {
+		control: Option<Spanned<TimingControl>>,
+		name:    Spanned<Expression>,
+	}, // Added: IEEE 1800-2005
+	Assertion(Box<Assertion>),           // Added: IEEE 1800-2005
+	/// A direct branch contribution, e.g. `V(p, n) <+ r * I(p, n);`
+	Contribution {
+		target: Spanned<Expression>,
+		value:  Spanned<Expression>,
+	}, // Added: Verilog-AMS 2009
+	/// An indirect branch assignment, e.g. `V(out) : V(in) == 0;`
+	IndirectContribution {
+		target:   Spanned<Expression>,
+		equation: Spanned<Expression>,
+	}, // Added: Verilog-AMS 2009
+	/// A statement that failed to parse, the error is reported in the diagnostics
+	Error,
+}
+
+/// The initializer of a `for` loop, possibly declaring the loop variable
+///
+/// IEEE 1800-2017 § A.6.8
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForInit {
+	/// The type of a loop variable declared in the initializer
+	pub data_type:  Option<DataType>,
+	pub assignment: Spanned<Assignment>,
+}
+
+/// IEEE 1800-2017 § A.6.6
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CaseQualifier {
+	Unique,
+	Unique0, // Added: IEEE 1800-2009
+	Priority,
+}
+
+/// An assignment, with an optional intra-assignment timing control
+#[derive(Clone, Debug, PartialEq)]
+pub struct Assignment {
+	pub lvalue:  Spanned<Expression>,
+	pub control: Option<Spanned<TimingControl>>,
+	pub value:   Spanned<Expression>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CaseKind {
+	Case,
+	CaseX,
+	CaseZ,
+}
+
+/// IEEE 1364-2005 § A.6.7
+#[derive(Clone, Debug, PartialEq)]
+pub struct Case {
+	pub qualifier:  Option<CaseQualifier>, // Added: IEEE 1800-2005
+	pub kind:       CaseKind,
+	/// Set for `case (...) inside` set membership case statements
+	pub inside:     bool, // Added: IEEE 1800-2005
+	pub expression: Spanned<Expression>,
+	pub items:      Vec<Spanned<CaseItem>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CaseItem {
+	/// The labels of the case item, empty for the `default` item
+	pub labels:    Vec<Spanned<Expression>>,
+	pub statement: Spanned<Statement>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum BlockKind {
+	/// `begin`/`end`
+	Sequential,
+	/// `fork`/`join`
+	Parallel,
+	/// `fork`/`join_any`
+	ParallelAny, // Added: IEEE 1800-2005
+	/// `fork`/`join_none`
+	ParallelNone, // Added: IEEE 1800-2005
+}
+
+/// IEEE 1364-2005 § A.6.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct Block {
+	pub kind:       BlockKind,
+	pub name:       Option<Spanned<Identifier>>,
+	/// The block item declarations, only permitted for named blocks prior to SystemVerilog
+	pub items:      Vec<Spanned<Item>>,
+	pub statements: Vec<Spanned<Statement>>,
+}
+
+/// IEEE 1364-2005 § A.6.5
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimingControl {
+	Delay(Delay),
+	Event(EventControl),
+	/// `repeat (count) @(event)`, only valid as an intra-assignment control
+	Repeat {
+		count: Spanned<Expression>,
+		event: Spanned<EventControl>,
+	},
+	/// `##count`, a cycle delay relative to the default clocking
+	Cycle(Spanned<Expression>), // Added: IEEE 1800-2005
+}
+
+/// A delay value, e.g. `#5` or `#(1:2:3, 4)`
+///
+/// IEEE 1364-2005 § A.2.2.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct Delay {
+	pub values: Vec<Spanned<Expression>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EventControl {
+	/// `@name`
+	Identifier(Spanned<Expression>),
+	/// `@(a or posedge b)`
+	Expression(Vec<Spanned<EventExpression>>),
+	/// `@*` or `@(*)`
+	Wildcard, // Added: IEEE 1364-2001
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventExpression {
+	pub edge:       Option<EdgeIdentifier>,
+	pub expression: Spanned<Expression>,
+	/// The `iff` guard of the event, e.g. `@(posedge clk iff enable)`
+	pub iff:        Option<Spanned<Expression>>, // Added: IEEE 1800-2005
+}
+
+impl Statement {
+	pub fn new(kind: StatementKind) -> Self {
+		Self { attributes: Vec::new(), label: None, kind }
+	}
+}
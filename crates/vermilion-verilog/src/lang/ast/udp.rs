@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{Attribute, Expression, Item},
+	types::Identifier,
+};
+
+/// A user defined primitive
+///
+/// IEEE 1364-2005 § A.5.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct Udp {
+	pub attributes:   Vec<Spanned<Attribute>>,
+	pub name:         Spanned<Identifier>,
+	/// The port names for non-ANSI UDPs
+	pub ports:        Vec<Spanned<Identifier>>,
+	/// The port and `reg` declarations, either from the header or the body
+	pub declarations: Vec<Spanned<Item>>,
+	/// The `initial q = 1'b0;` statement for sequential UDPs
+	pub initial:      Option<Spanned<UdpInitial>>,
+	pub table:        Vec<Spanned<UdpTableEntry>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UdpInitial {
+	pub name:  Spanned<Identifier>,
+	pub value: Spanned<Expression>,
+}
+
+/// A single symbol in a UDP table
+///
+/// IEEE 1364-2005 § A.5.3
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UdpSymbol {
+	/// `0`, `1`, `x`, `?`, `b`
+	Level(u8),
+	/// `r`, `f`, `p`, `n`, `*`
+	Edge(u8),
+	/// `(vw)`
+	Transition(u8, u8),
+	/// `-`, only valid as the next state of a sequential UDP
+	NoChange,
+}
+
+/// IEEE 1364-2005 § A.5.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct UdpTableEntry {
+	pub inputs:  Vec<Spanned<UdpSymbol>>,
+	/// The current state for sequential UDPs
+	pub current: Option<Spanned<UdpSymbol>>,
+	pub output:  Spanned<UdpSymbol>,
+}
+
+impl Udp {
+	/// A UDP is sequential if its table has current state columns
+	pub fn is_sequential(&self) -> bool {
+		self.table.iter().any(|entry| entry.current.is_some())
+	}
+}
@@ -0,0 +1,310 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! A lossless concrete syntax tree
+//!
+//! Unlike the [`Ast`](crate::lang::ast::Ast), which only holds the meaning of the source, the
+//! concrete syntax tree holds every token of the source file, including whitespace, newlines,
+//! comments, and compiler directives, so the exact source text can always be reproduced from it.
+//!
+//! The nodes of the tree line up with the spans of the descriptions, items, and statements of the
+//! AST, tokens are owned by the innermost node that covers them. Whitespace and comments between
+//! two nodes belong to the node enclosing both.
+
+use std::cmp::Reverse;
+
+use vermilion_lang::AtomicByteTendril;
+use vermilion_loc::{Span, Spanned};
+
+use crate::lang::{
+	incremental::{Relocate, Shift},
+	tokenizer::token::Token,
+};
+
+/// The kind of a [`SyntaxNode`]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum NodeKind {
+	/// The whole source file
+	#[default]
+	Root,
+	/// A top-level construct, such as a module or package
+	Description,
+	Item,
+	Statement,
+	/// A construct that failed to parse, the error is reported in the diagnostics
+	Error,
+}
+
+/// A node of the concrete syntax tree, along with all of the nodes and tokens inside of it
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SyntaxNode {
+	kind:     NodeKind,
+	span:     Span,
+	children: Vec<SyntaxElement>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SyntaxElement {
+	Node(SyntaxNode),
+	Token(SyntaxToken),
+}
+
+/// A single token of the source file, along with the exact text it was produced from
+#[derive(Clone, Debug, PartialEq)]
+pub struct SyntaxToken {
+	token: Spanned<Token>,
+	text:  AtomicByteTendril,
+}
+
+impl SyntaxNode {
+	/// Build the tree for the `root` region of `source` out of its full token stream and the
+	/// spans of the nodes found by the parser
+	///
+	/// The nodes may be given in any order, but must not partially overlap. Nodes with identical
+	/// spans are nested in the reverse order that they were given, so a parent must come after
+	/// its children. Any bytes of `source` not covered by a token are kept as [`Token::Invalid`].
+	pub(crate) fn build(
+		source: &AtomicByteTendril,
+		tokens: &[Spanned<Token>],
+		mut nodes: Vec<Spanned<NodeKind>>,
+		root: Span,
+	) -> Self {
+		nodes.reverse();
+		nodes.sort_by_key(|node| (*node.span().begin(), Reverse(*node.span().end())));
+
+		let mut offset = *root.begin();
+		let root = Self {
+			kind:     NodeKind::Root,
+			span:     Span::from_position(offset, source.len32(), root.get_position()),
+			children: Vec::new(),
+		};
+
+		let mut builder = Builder {
+			stack: vec![root],
+			nodes: nodes.into_iter().peekable(),
+		};
+
+		for token in tokens {
+			let span = *token.span();
+
+			if *span.begin() > offset {
+				let gap = Span::from_position(offset, *span.begin(), span.get_position());
+				builder.token(SyntaxToken::new(
+					Spanned::new(Token::Invalid(None), gap),
+					source,
+				));
+			}
+
+			builder.token(SyntaxToken::new(token.clone(), source));
+			offset = offset.max(*span.end());
+		}
+
+		if source.len32() > offset {
+			let gap = span_at(tokens.last(), offset, source.len32());
+			builder.token(SyntaxToken::new(
+				Spanned::new(Token::Invalid(None), gap),
+				source,
+			));
+		}
+
+		builder.finish()
+	}
+
+	pub fn kind(&self) -> NodeKind {
+		self.kind
+	}
+
+	pub fn span(&self) -> &Span {
+		&self.span
+	}
+
+	pub fn children(&self) -> &[SyntaxElement] {
+		&self.children
+	}
+
+	/// Iterate over the nodes directly inside of this node
+	pub fn nodes(&self) -> impl Iterator<Item = &Self> {
+		self.children.iter().filter_map(|child| match child {
+			SyntaxElement::Node(node) => Some(node),
+			SyntaxElement::Token(_) => None,
+		})
+	}
+
+	/// Iterate over all of the tokens inside of this node, in source order
+	pub fn tokens(&self) -> Box<dyn Iterator<Item = &SyntaxToken> + '_> {
+		Box::new(self.children.iter().flat_map(|child| match child {
+			SyntaxElement::Node(node) => node.tokens(),
+			SyntaxElement::Token(token) => Box::new(std::iter::once(token)),
+		}))
+	}
+
+	/// Find the token covering the given byte offset
+	pub fn token_at(&self, offset: u32) -> Option<&SyntaxToken> {
+		self.tokens()
+			.find(|token| *token.span().begin() <= offset && offset < *token.span().end())
+	}
+
+	/// Reproduce the source text covered by this node
+	pub fn text(&self) -> Vec<u8> {
+		self.tokens()
+			.flat_map(|token| token.text().iter().copied())
+			.collect()
+	}
+
+	/// Replace the children of this root node covered by the re-parsed `region` with its own
+	///
+	/// Children from the old offset `tail` onwards are kept, moved along by `shift`, anything
+	/// from the start of `region` up to `tail` is dropped.
+	pub(crate) fn splice(&mut self, region: Self, tail: Option<u32>, shift: &Shift) {
+		let begin = *region.span.begin();
+		let mut end = *region.span.end();
+
+		let mut children = Vec::with_capacity(self.children.len());
+		let mut rest = Vec::new();
+		for child in self.children.drain(..) {
+			let offset = *child.span().begin();
+
+			if offset < begin {
+				children.push(child);
+			} else if let Some(tail) = tail &&
+				offset >= tail
+			{
+				rest.push(child);
+			}
+		}
+
+		children.extend(region.children);
+		for mut child in rest {
+			child.relocate(shift);
+			end = end.max(*child.span().end());
+			children.push(child);
+		}
+
+		self.children = children;
+		self.span = Span::from_position(*self.span.begin(), end, self.span.get_position());
+	}
+}
+
+impl SyntaxElement {
+	pub fn span(&self) -> &Span {
+		match self {
+			Self::Node(node) => node.span(),
+			Self::Token(token) => token.span(),
+		}
+	}
+}
+
+impl Relocate for SyntaxNode {
+	fn relocate(&mut self, shift: &Shift) {
+		self.span.relocate(shift);
+		self.children.relocate(shift);
+	}
+}
+
+impl Relocate for SyntaxElement {
+	fn relocate(&mut self, shift: &Shift) {
+		match self {
+			Self::Node(node) => node.relocate(shift),
+			Self::Token(token) => token.token.relocate(shift),
+		}
+	}
+}
+
+impl SyntaxToken {
+	fn new(token: Spanned<Token>, source: &AtomicByteTendril) -> Self {
+		let begin = (*token.span().begin()).min(source.len32());
+		let end = (*token.span().end()).clamp(begin, source.len32());
+
+		Self { text: source.subtendril(begin, end - begin), token }
+	}
+
+	pub fn token(&self) -> &Token {
+		self.token.inner()
+	}
+
+	pub fn span(&self) -> &Span {
+		self.token.span()
+	}
+
+	pub fn text(&self) -> &AtomicByteTendril {
+		&self.text
+	}
+
+	/// Returns true if this token has no meaning to the parser, such as whitespace and comments
+	pub fn is_trivia(&self) -> bool {
+		matches!(
+			self.token.inner(),
+			Token::Whitespace(_) | Token::Newline(_) | Token::Comment(_)
+		)
+	}
+}
+
+/// A span from `begin` to `end` positioned at `token`, used for spans that don't match a token
+fn span_at(token: Option<&Spanned<Token>>, begin: u32, end: u32) -> Span {
+	match token {
+		Some(token) => Span::from_position(begin, end, token.span().get_position()),
+		None => Span::new(begin, end, 0, 0),
+	}
+}
+
+/// Assembles the tree by keeping a stack of the nodes that are currently open
+struct Builder<I: Iterator<Item = Spanned<NodeKind>>> {
+	stack: Vec<SyntaxNode>,
+	nodes: std::iter::Peekable<I>,
+}
+
+impl<I: Iterator<Item = Spanned<NodeKind>>> Builder<I> {
+	fn token(&mut self, token: SyntaxToken) {
+		let begin = *token.span().begin();
+
+		self.close(begin);
+		while let Some(node) = self.nodes.next_if(|node| *node.span().begin() <= begin) {
+			self.open(node);
+			self.close(begin);
+		}
+
+		self.push(SyntaxElement::Token(token));
+	}
+
+	/// Close every open node that ends at or before `offset`
+	fn close(&mut self, offset: u32) {
+		while self.stack.len() > 1 &&
+			self.stack
+				.last()
+				.is_some_and(|node| *node.span.end() <= offset)
+		{
+			if let Some(node) = self.stack.pop() {
+				self.push(SyntaxElement::Node(node));
+			}
+		}
+	}
+
+	fn open(&mut self, node: Spanned<NodeKind>) {
+		self.stack.push(SyntaxNode {
+			kind:     *node.inner(),
+			span:     *node.span(),
+			children: Vec::new(),
+		});
+	}
+
+	fn push(&mut self, element: SyntaxElement) {
+		if let Some(parent) = self.stack.last_mut() {
+			parent.children.push(element);
+		}
+	}
+
+	fn finish(mut self) -> SyntaxNode {
+		self.close(u32::MAX);
+
+		// NOTE(aki): Nodes left over at the end of the file are empty, such as an error at EOF
+		let remaining = self.nodes.by_ref().collect::<Vec<_>>();
+		for node in remaining {
+			self.open(node);
+			self.close(u32::MAX);
+		}
+
+		self.stack.pop().unwrap_or_default()
+	}
+}
+
+#[cfg(test)]
+mod tests;
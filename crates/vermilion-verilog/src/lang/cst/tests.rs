@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+use crate::{LanguageStd, lang::parser::VerilogParser};
+
+fn parse(std: LanguageStd, input: &str) -> SyntaxNode {
+	#[allow(
+		clippy::unwrap_used,
+		reason = "We are within tests, and while this shouldn't explode, if it does here that's \
+		          fine"
+	)]
+	let parser = VerilogParser::new(std, input.as_bytes().into()).unwrap();
+
+	parser.parse().syntax().clone()
+}
+
+/// Flatten the tree into the kinds and text of its nodes, depth first
+fn outline(node: &SyntaxNode) -> Vec<(NodeKind, String)> {
+	let mut nodes = vec![(
+		node.kind(),
+		String::from_utf8_lossy(&node.text()).into_owned(),
+	)];
+	for child in node.nodes() {
+		nodes.extend(outline(child));
+	}
+
+	nodes
+}
+
+#[test]
+fn test_cst_empty() {
+	let syntax = parse(LanguageStd::Vl05, "");
+
+	assert_eq!(syntax.kind(), NodeKind::Root);
+	assert!(syntax.children().is_empty());
+}
+
+#[test]
+fn test_cst_round_trip() {
+	let input = concat!(
+		"// leading comment\n",
+		"`timescale 1ns / 1ps\n",
+		"module m #(parameter W = 8) (input [W-1:0] a);\n",
+		"\t/* block\n\t comment */ wire  b ;\r\n",
+		"\tinitial begin\n\t\tb = $clog2(a) + 8'hFF; // trailing\n\tend\n",
+		"endmodule   \n",
+	);
+
+	for std in [LanguageStd::Vl95, LanguageStd::Vl05, LanguageStd::Sv17] {
+		assert_eq!(String::from_utf8_lossy(&parse(std, input).text()), input);
+	}
+}
+
+#[test]
+fn test_cst_round_trip_errors() {
+	let input = "module m;\n\twire ;\n\tinitial begin a = ; end\n";
+
+	assert_eq!(
+		String::from_utf8_lossy(&parse(LanguageStd::Sv17, input).text()),
+		input
+	);
+}
+
+#[test]
+fn test_cst_nodes() {
+	let syntax = parse(
+		LanguageStd::Vl05,
+		"module m;\n\t// comment\n\twire a;\n\tinitial a = 1;\nendmodule\n",
+	);
+
+	assert_eq!(
+		outline(&syntax)[1..],
+		[
+			(
+				NodeKind::Description,
+				"module m;\n\t// comment\n\twire a;\n\tinitial a = 1;\nendmodule".to_string()
+			),
+			(NodeKind::Item, "wire a;".to_string()),
+			(NodeKind::Item, "initial a = 1;".to_string()),
+			(NodeKind::Statement, "a = 1;".to_string()),
+		]
+	);
+
+	// Trivia between items belongs to the enclosing node
+	let Some(SyntaxElement::Node(module)) = syntax.children().first() else {
+		panic!("Expected a node, got {:#?}", syntax.children());
+	};
+	assert!(module.children().iter().any(|child| matches!(
+		child,
+		SyntaxElement::Token(token) if matches!(token.token(), Token::Comment(_))
+	)));
+}
+
+#[test]
+fn test_cst_error_nodes() {
+	let syntax = parse(LanguageStd::Vl05, "module m; wire ; wire b; endmodule");
+
+	assert_eq!(
+		outline(&syntax)[2..],
+		[(NodeKind::Error, "wire ;".to_string()), (NodeKind::Item, "wire b;".to_string()),]
+	);
+}
+
+#[test]
+fn test_cst_token_at() {
+	let syntax = parse(LanguageStd::Vl05, "module m; wire a; endmodule");
+
+	let token = syntax
+		.token_at(15)
+		.unwrap_or_else(|| panic!("No token at 15"));
+	assert_eq!(&token.text()[..], b"a");
+	assert!(!token.is_trivia());
+
+	assert!(syntax.token_at(9).is_some_and(SyntaxToken::is_trivia));
+	assert!(syntax.token_at(27).is_none());
+}
@@ -768,6 +768,26 @@ pub fn get_builtin_directive(ident: &str, std: LanguageStd) -> Option<BuiltinDir
 	}
 }
 
+/// Get every builtin directive in the given standard, in no particular order
+pub fn builtin_directives(std: LanguageStd) -> impl Iterator<Item = BuiltinDirective> {
+	let map = match std {
+		LanguageStd::Vl95 => Some(&VERILOG_95_BUILTIN_DIRECTIVE_MAP),
+		LanguageStd::Vl01 => Some(&VERILOG_01_BUILTIN_DIRECTIVE_MAP),
+		LanguageStd::Vl05 => Some(&VERILOG_05_BUILTIN_DIRECTIVE_MAP),
+		LanguageStd::Sv05 => Some(&SYSTEM_VERILOG_05_BUILTIN_DIRECTIVE_MAP),
+		LanguageStd::Sv09 => Some(&SYSTEM_VERILOG_09_BUILTIN_DIRECTIVE_MAP),
+		LanguageStd::Sv12 => Some(&SYSTEM_VERILOG_12_BUILTIN_DIRECTIVE_MAP),
+		LanguageStd::Sv17 => Some(&SYSTEM_VERILOG_17_BUILTIN_DIRECTIVE_MAP),
+		LanguageStd::Sv23 => Some(&SYSTEM_VERILOG_23_BUILTIN_DIRECTIVE_MAP),
+		LanguageStd::Vams09 => Some(&VERILOG_AMS_09_BUILTIN_DIRECTIVE_MAP),
+		LanguageStd::Vams14 => Some(&VERILOG_AMS_14_BUILTIN_DIRECTIVE_MAP),
+		LanguageStd::Vams23 => Some(&VERILOG_AMS_23_BUILTIN_DIRECTIVE_MAP),
+		_ => None,
+	};
+
+	map.into_iter().flat_map(|map| map.values().copied())
+}
+
 /// Check to see if the given identifier is a builtin directive in a future Verilog standard,
 /// returning the standard version if so.
 #[inline(always)]
@@ -821,43 +841,50 @@ pub fn is_builtin_directive(ident: &str) -> LanguageStd {
 	supported
 }
 
+impl BuiltinDirective {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::BeginKeywords => "begin_keywords", // Added: IEEE 1364-2005
+			Self::CellDefine => "celldefine",
+			Self::DefaultDecayTime => "default_decay_time",
+			Self::DefaultDiscipline => "default_discipline", // Added: Verilog-AMS 2009
+			Self::DefaultNetType => "default_nettype",
+			Self::DefaultTransition => "default_transition", // Added: Verilog-AMS 2009
+			Self::DefaultTriRegStrength => "default_trireg_strength",
+			Self::Define => "define",
+			Self::DelayModeDistributed => "delay_mode_distributed",
+			Self::DelayModePath => "delay_mode_path",
+			Self::DelayModeUnit => "delay_mode_unit",
+			Self::DelayModeZero => "delay_mode_zero",
+			Self::Else => "else",
+			Self::ElsIf => "elsif", // Added: IEEE 1364-2001
+			Self::EndCellDefine => "endcelldefine",
+			Self::EndIf => "endif",
+			Self::EndKeywords => "end_keywords", // Added: IEEE 1364-2005
+			Self::IfDef => "ifdef",
+			Self::IfNotDef => "ifndef", // Added: IEEE 1364-2001
+			Self::Include => "include",
+			Self::Line => "line", // Added: IEEE 1364-2001
+			Self::NoUnconnectedDrive => "nounconnected_drive",
+			Self::Pragma => "pragma", // Added: IEEE 1364-2005
+			Self::ResetAll => "resetall",
+			Self::TimeScale => "timescale",
+			Self::UnconnectedDrive => "unconnected_drive",
+			Self::Undef => "undef",
+			Self::UndefineAll => "undefineall", // Added: IEEE 1800-2009
+		}
+	}
+
+	/// The standard the directive was added in
+	pub fn added(&self) -> LanguageStd {
+		is_builtin_directive(self.as_str()).earliest()
+	}
+}
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 impl Display for BuiltinDirective {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(
-			f,
-			"Directive({})",
-			match self {
-				Self::BeginKeywords => "begin_keywords", // Added: IEEE 1364-2005
-				Self::CellDefine => "celldefine",
-				Self::DefaultDecayTime => "default_decay_time",
-				Self::DefaultDiscipline => "default_discipline", // Added: Verilog-AMS 2009
-				Self::DefaultNetType => "default_nettype",
-				Self::DefaultTransition => "default_transition", // Added: Verilog-AMS 2009
-				Self::DefaultTriRegStrength => "default_trireg_strength",
-				Self::Define => "define",
-				Self::DelayModeDistributed => "delay_mode_distributed",
-				Self::DelayModePath => "delay_mode_path",
-				Self::DelayModeUnit => "delay_mode_unit",
-				Self::DelayModeZero => "delay_mode_zero",
-				Self::Else => "else",
-				Self::ElsIf => "elsif", // Added: IEEE 1364-2001
-				Self::EndCellDefine => "endcelldefine",
-				Self::EndIf => "endif",
-				Self::EndKeywords => "end_keywords", // Added: IEEE 1364-2005
-				Self::IfDef => "ifdef",
-				Self::IfNotDef => "ifndef", // Added: IEEE 1364-2001
-				Self::Include => "include",
-				Self::Line => "line", // Added: IEEE 1364-2001
-				Self::NoUnconnectedDrive => "nounconnected_drive",
-				Self::Pragma => "pragma", // Added: IEEE 1364-2005
-				Self::ResetAll => "resetall",
-				Self::TimeScale => "timescale",
-				Self::UnconnectedDrive => "unconnected_drive",
-				Self::Undef => "undef",
-				Self::UndefineAll => "undefineall", // Added: IEEE 1800-2009
-			}
-		)
+		write!(f, "Directive({})", self.as_str())
 	}
 }
 
@@ -1047,4 +1074,16 @@ mod test {
 
 		// Verilog-AMS 2009, 2014, and 2023 all have the same directives
 	}
+
+	#[test]
+	fn test_directive_added() {
+		assert_eq!(BuiltinDirective::Define.added(), LanguageStd::Vl95);
+		assert_eq!(BuiltinDirective::IfNotDef.added(), LanguageStd::Vl01);
+		assert_eq!(BuiltinDirective::Pragma.added(), LanguageStd::Vl05);
+		assert_eq!(BuiltinDirective::UndefineAll.added(), LanguageStd::Sv09);
+		assert_eq!(
+			BuiltinDirective::DefaultDiscipline.added(),
+			LanguageStd::Vams09
+		);
+	}
 }
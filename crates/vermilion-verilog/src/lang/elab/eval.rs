@@ -0,0 +1,1827 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::BTreeMap;
+
+use vermilion_diagnostics::StringDiagnostic;
+use vermilion_loc::{FileId, Location, Span, Spanned};
+
+use crate::{
+	LanguageStd,
+	lang::{
+		ast::{
+			AssignmentOperator, BinaryOperator, CaseKind, CastTarget, DataType, Dimension,
+			EnumType, Expression, Function, IncDecOperator, IntegerAtomType, IntegerVectorType,
+			Item, ItemKind, Literal, NonIntegerType, Package, PortDirection, Select, Signing,
+			Statement, StatementKind, StructKind,
+		},
+		elab::{
+			not_constant_code,
+			value::{Logic, LogicVec, Value},
+		},
+		sysfuncs::BuiltinSysFunc,
+		types::SystemFunc,
+	},
+};
+
+/// The number of statements a single constant function call may execute
+const STEP_LIMIT: u32 = 1 << 20;
+/// The depth of nested constant function calls
+const CALL_LIMIT: u32 = 256;
+
+/// The type of a parameter, constant function variable, or typedef, as far as constant
+/// evaluation is concerned
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValueType {
+	/// An integral type, the range is that of a single packed dimension, or `[width-1:0]` for
+	/// types with more than one
+	Vector {
+		msb:       i64,
+		lsb:       i64,
+		signed:    bool,
+		two_state: bool,
+	},
+	Real,
+	ShortReal,
+	String,
+}
+
+/// A named constant, or a variable of a constant function
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constant {
+	pub value:     Value,
+	/// The declared type of the constant, `None` for parameters without a type or range that
+	/// take the type of their value
+	pub data_type: Option<ValueType>,
+}
+
+/// The names declared by a single scope
+#[derive(Clone, Debug, Default)]
+pub(super) struct Frame<'a> {
+	constants: BTreeMap<String, Constant>,
+	types:     BTreeMap<String, Option<ValueType>>,
+	functions: BTreeMap<String, (FileId, &'a Function)>,
+	/// Imported packages, with the imported name or `None` for a wildcard import
+	imports:   Vec<(String, Option<String>)>,
+}
+
+#[derive(Clone, Debug)]
+enum PackageState<'a> {
+	Pending(FileId, &'a Package),
+	Evaluating,
+	Done(Frame<'a>),
+}
+
+/// How a statement of a constant function completed
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Flow {
+	Next,
+	Break,
+	Continue,
+	Return,
+}
+
+/// Evaluates constant expressions, looking names up through a stack of scopes
+///
+/// Constant expressions are made of literals, parameters, local parameters, genvars, enum
+/// members, constant system functions, and calls to constant functions, which are run by
+/// interpreting their bodies. Problems are reported as diagnostics, and the expression that
+/// caused them evaluates to `None`.
+///
+/// IEEE 1800-2017 § 11.2.1, § 13.4.3
+#[derive(Clone, Debug)]
+pub struct Evaluator<'a> {
+	std:         LanguageStd,
+	file:        FileId,
+	frames:      Vec<Frame<'a>>,
+	packages:    BTreeMap<String, PackageState<'a>>,
+	/// The value of the last `return` statement of the running constant function
+	returned:    Option<Value>,
+	steps:       u32,
+	calls:       u32,
+	diagnostics: Vec<Location<StringDiagnostic>>,
+}
+
+impl ValueType {
+	/// `integer`, and the type of genvars
+	pub const INTEGER: Self = Self::Vector {
+		msb:       31,
+		lsb:       0,
+		signed:    true,
+		two_state: false,
+	};
+
+	/// The width of the type in bits
+	pub fn width(&self) -> u32 {
+		match self {
+			Self::Vector { msb, lsb, .. } => {
+				(msb.abs_diff(*lsb) + 1).try_into().unwrap_or(u32::MAX)
+			},
+			Self::Real => 64,
+			Self::ShortReal => 32,
+			Self::String => 0,
+		}
+	}
+
+	/// Convert `value` to this type, truncating or extending integral values
+	///
+	/// IEEE 1800-2017 § 6.24
+	pub fn cast(&self, value: &Value) -> Value {
+		match self {
+			Self::Vector { signed, two_state, .. } => {
+				let vec = match value {
+					Value::Vector(vec) => vec.resize(self.width()).with_signed(*signed),
+					Value::Real(value) => LogicVec::from_f64(*value, self.width(), *signed),
+				};
+				Value::Vector(if *two_state {
+					to_two_state(&vec)
+				} else {
+					vec
+				})
+			},
+			Self::Real => Value::Real(value.to_f64()),
+			Self::ShortReal => Value::Real(f64::from(value.to_f64() as f32)),
+			Self::String => value.clone(),
+		}
+	}
+
+	/// The initial value of a variable of this type
+	///
+	/// IEEE 1800-2017 § 6.8, Table 6-7
+	pub fn default_value(&self) -> Value {
+		match self {
+			Self::Vector { signed, two_state, .. } => Value::Vector(LogicVec::filled(
+				self.width(),
+				*signed,
+				if *two_state {
+					Logic::Zero
+				} else {
+					Logic::X
+				},
+			)),
+			Self::Real | Self::ShortReal => Value::Real(0.0),
+			Self::String => Value::Vector(LogicVec::new(8, false)),
+		}
+	}
+
+	/// The offset from the least significant bit of the bit at `idx` of the range of this type
+	fn offset(&self, idx: i64) -> i64 {
+		match self {
+			Self::Vector { msb, lsb, .. } if msb < lsb => lsb - idx,
+			Self::Vector { lsb, .. } => idx - lsb,
+			_ => idx,
+		}
+	}
+}
+
+impl<'a> Evaluator<'a> {
+	pub fn new(std: LanguageStd, file: FileId) -> Self {
+		Self {
+			std,
+			file,
+			frames: vec![Frame::default()],
+			packages: BTreeMap::new(),
+			returned: None,
+			steps: 0,
+			calls: 0,
+			diagnostics: Vec::new(),
+		}
+	}
+
+	/// Make the parameters, types, and functions of `package` available to `pkg::name` and
+	/// imports, the package is evaluated the first time it is used
+	pub fn add_package(&mut self, file: FileId, package: &'a Package) {
+		self.packages.insert(
+			package.name.inner().as_str().to_string(),
+			PackageState::Pending(file, package),
+		);
+	}
+
+	/// Problems found while evaluating
+	pub fn diagnostics(&self) -> &[Location<StringDiagnostic>] {
+		&self.diagnostics
+	}
+
+	pub(super) fn take_diagnostics(&mut self) -> Vec<Location<StringDiagnostic>> {
+		std::mem::take(&mut self.diagnostics)
+	}
+
+	/// The file that the spans of evaluated expressions are in
+	pub fn set_file(&mut self, file: FileId) {
+		self.file = file;
+	}
+
+	pub fn push_scope(&mut self) {
+		self.frames.push(Frame::default());
+	}
+
+	pub fn pop_scope(&mut self) {
+		if self.frames.len() > 1 {
+			self.frames.pop();
+		}
+	}
+
+	/// Replace the scopes, returning the previous ones
+	pub(super) fn replace_frames(&mut self, frames: Vec<Frame<'a>>) -> Vec<Frame<'a>> {
+		std::mem::replace(&mut self.frames, frames)
+	}
+
+	/// The innermost scope
+	pub(super) fn frame(&self) -> Frame<'a> {
+		self.frames.last().cloned().unwrap_or_default()
+	}
+
+	/// Declare `name` in the innermost scope
+	pub fn define(&mut self, name: &str, constant: Constant) {
+		if let Some(frame) = self.frames.last_mut() {
+			frame.constants.insert(name.to_string(), constant);
+		}
+	}
+
+	/// Declare the type `name` in the innermost scope, `None` for types that are not integral or
+	/// real
+	pub fn define_type(&mut self, name: &str, data_type: Option<ValueType>) {
+		if let Some(frame) = self.frames.last_mut() {
+			frame.types.insert(name.to_string(), data_type);
+		}
+	}
+
+	/// Import all of `package`, or only `item`, into the innermost scope
+	pub fn import(&mut self, package: &str, item: Option<&str>) {
+		self.load_package(package);
+		if let Some(frame) = self.frames.last_mut() {
+			frame
+				.imports
+				.push((package.to_string(), item.map(str::to_string)));
+		}
+	}
+
+	/// Look `name` up through the scopes and their imports
+	pub fn lookup(&self, name: &str) -> Option<&Constant> {
+		self.frames.iter().rev().find_map(|frame| {
+			frame.constants.get(name).or_else(|| {
+				frame
+					.imports
+					.iter()
+					.filter(|(_, item)| item.as_deref().is_none_or(|item| item == name))
+					.find_map(|(package, _)| self.package_frame(package)?.constants.get(name))
+			})
+		})
+	}
+
+	/// Look the type `name` up through the scopes and their imports
+	pub fn lookup_type(&self, name: &str) -> Option<Option<ValueType>> {
+		self.frames.iter().rev().find_map(|frame| {
+			frame.types.get(name).copied().or_else(|| {
+				frame
+					.imports
+					.iter()
+					.filter(|(_, item)| item.as_deref().is_none_or(|item| item == name))
+					.find_map(|(package, _)| self.package_frame(package)?.types.get(name).copied())
+			})
+		})
+	}
+
+	/// Declare the parameters, types, enum members, functions, and imports of the items
+	pub fn declare_items(&mut self, items: &'a [Spanned<Item>]) {
+		for item in items {
+			self.declare_item(item.inner());
+		}
+	}
+
+	/// Declare the parameters, types, enum members, functions, and imports of a single item,
+	/// other items are ignored
+	pub fn declare_item(&mut self, item: &'a Item) {
+		match &item.kind {
+			ItemKind::Parameter(parameter) => {
+				if parameter.data_type == DataType::Type {
+					for declarator in &parameter.declarators {
+						let data_type =
+							declarator
+								.inner()
+								.init
+								.as_ref()
+								.and_then(|init| match init.inner() {
+									Expression::DataType(data_type) => self.value_type(data_type),
+									Expression::Identifier(name) => {
+										self.lookup_type(name.as_str()).flatten()
+									},
+									_ => None,
+								});
+						self.define_type(declarator.inner().name.inner().as_str(), data_type);
+					}
+					return;
+				}
+
+				self.declare_enums(&parameter.data_type);
+				let data_type = self.value_type(&parameter.data_type);
+				for declarator in &parameter.declarators {
+					let declarator = declarator.inner();
+					let Some(init) = &declarator.init else {
+						continue;
+					};
+
+					if let Some(value) = self.evaluate_as(&parameter.data_type, init) {
+						self.define(
+							declarator.name.inner().as_str(),
+							Constant { value, data_type },
+						);
+					}
+				}
+			},
+			ItemKind::Typedef(typedef) => {
+				if let Some(data_type) = &typedef.data_type {
+					self.declare_enums(data_type);
+					let value_type = if typedef.dimensions.is_empty() {
+						self.value_type(data_type)
+					} else {
+						None
+					};
+					self.define_type(typedef.name.inner().as_str(), value_type);
+				}
+			},
+			ItemKind::Net(net) => self.declare_enums(&net.data_type),
+			ItemKind::Variable(variable) => self.declare_enums(&variable.data_type),
+			ItemKind::Function(function) => {
+				let file = self.file;
+				if function.scope.is_none() &&
+					let Some(frame) = self.frames.last_mut()
+				{
+					frame
+						.functions
+						.insert(function.name.inner().as_str().to_string(), (file, function));
+				}
+			},
+			ItemKind::Import(imports) => {
+				for import in imports {
+					let import = import.inner();
+					self.import(
+						import.package.inner().as_str(),
+						import.item.as_ref().map(|item| item.inner().as_str()),
+					);
+				}
+			},
+			_ => (),
+		}
+	}
+
+	/// Evaluate a constant expression
+	pub fn evaluate(&mut self, expr: &Spanned<Expression>) -> Option<Value> {
+		let span = *expr.span();
+
+		match expr.inner() {
+			Expression::Literal(literal) => self.literal(literal, span),
+			Expression::Identifier(name) => match self.lookup(name.as_str()) {
+				Some(constant) => Some(constant.value.clone()),
+				None => self.error(format!("`{}` is not a constant", name.as_str()), span),
+			},
+			Expression::Scope { scope, member } => {
+				let package = match scope.inner() {
+					Expression::Identifier(package) => package.as_str(),
+					_ => return self.not_constant(span),
+				};
+				self.load_package(package);
+
+				match self
+					.package_frame(package)
+					.and_then(|frame| frame.constants.get(member.inner().as_str()))
+				{
+					Some(constant) => Some(constant.value.clone()),
+					None => self.error(
+						format!("`{package}::{}` is not a constant", member.inner().as_str()),
+						span,
+					),
+				}
+			},
+			Expression::Parenthesized(inner) => self.evaluate(inner),
+			Expression::MinTypMax { typ, .. } => self.evaluate(typ),
+			Expression::Unary { operator, operand, .. } => {
+				let operand = self.evaluate(operand)?;
+				match Value::unary(*operator.inner(), &operand) {
+					Some(value) => Some(value),
+					None => self.error("Operator is not valid for real operands".to_string(), span),
+				}
+			},
+			Expression::Binary { operator, lhs, rhs, .. } => {
+				let lhs = self.evaluate(lhs)?;
+				let rhs = self.evaluate(rhs)?;
+				match Value::binary(*operator.inner(), &lhs, &rhs) {
+					Some(value) => Some(value),
+					None => self.error("Operator is not valid for real operands".to_string(), span),
+				}
+			},
+			Expression::Conditional { condition, if_true, if_false, .. } => {
+				match self.evaluate(condition)?.truth() {
+					Logic::One => self.evaluate(if_true),
+					Logic::Zero => self.evaluate(if_false),
+					_ => {
+						let if_true = self.evaluate(if_true)?;
+						let if_false = self.evaluate(if_false)?;
+						Some(Value::merge(&if_true, &if_false))
+					},
+				}
+			},
+			Expression::Concatenation(items) => {
+				let parts = self.vectors(items)?;
+				Some(Value::Vector(LogicVec::concat(&parts)))
+			},
+			Expression::Replication { count, items } => {
+				let count = self.evaluate(count)?.to_i64();
+				let Some(count) = count.filter(|count| (0..=u32::MAX.into()).contains(count))
+				else {
+					return self.error("Invalid replication count".to_string(), span);
+				};
+
+				let parts = self.vectors(items)?;
+				let part = LogicVec::concat(&parts);
+				// NOTE(aki): A zero replication has no bits, which can only be part of a larger
+				// concatenation, so the single bit it evaluates to here is a stand in
+				Some(Value::Vector(LogicVec::concat(std::iter::repeat_n(
+					&part,
+					count as usize,
+				))))
+			},
+			Expression::Select { target, select } => self.select(target, select, span),
+			Expression::SystemCall { name, arguments } => self.system_call(name, arguments, span),
+			Expression::Call { target, arguments, .. } => self.call(target, arguments, span),
+			Expression::Cast { target, value } => {
+				let value = self.evaluate(value)?;
+				match target.inner() {
+					CastTarget::Type(data_type) => match self.value_type(data_type) {
+						Some(data_type) => Some(data_type.cast(&value)),
+						None => self.not_constant(span),
+					},
+					CastTarget::Expression(Expression::Identifier(name))
+						if self.lookup_type(name.as_str()).is_some() =>
+					{
+						match self.lookup_type(name.as_str()).flatten() {
+							Some(data_type) => Some(data_type.cast(&value)),
+							None => self.not_constant(span),
+						}
+					},
+					CastTarget::Expression(size) => {
+						let size = Spanned::new(size.clone(), *target.span());
+						match self.evaluate(&size)?.to_i64() {
+							Some(width @ 1..=0xFFFF_FFFF) => Some(Value::Vector(
+								value
+									.to_vector()
+									.resize(width as u32)
+									.with_signed(value.is_signed()),
+							)),
+							_ => self.error("Invalid size cast".to_string(), *target.span()),
+						}
+					},
+					CastTarget::Signing(signing) => Some(Value::Vector(
+						value.to_vector().with_signed(*signing == Signing::Signed),
+					)),
+					CastTarget::Const => Some(value),
+				}
+			},
+			Expression::Inside { expression, set } => {
+				let value = self.evaluate(expression)?;
+				let mut result = Logic::Zero;
+				for item in set {
+					let bit = match item.inner() {
+						Expression::ValueRange { low, high } => {
+							let low = self.evaluate(low)?;
+							let high = self.evaluate(high)?;
+							let above =
+								Value::binary(BinaryOperator::GreaterThanEqual, &value, &low);
+							let below = Value::binary(BinaryOperator::LessThanEqual, &value, &high);
+							match (above, below) {
+								(Some(above), Some(below)) => {
+									Value::binary(BinaryOperator::LogicalAnd, &above, &below)
+										.map_or(Logic::X, |bit| bit.truth())
+								},
+								_ => Logic::X,
+							}
+						},
+						_ => {
+							let item = self.evaluate(item)?;
+							let operator = if item.is_real() || value.is_real() {
+								BinaryOperator::LogicalEquality
+							} else {
+								BinaryOperator::WildcardEquality
+							};
+							Value::binary(operator, &value, &item)
+								.map_or(Logic::X, |bit| bit.truth())
+						},
+					};
+
+					match bit {
+						Logic::One => return Some(Value::from_bool(true)),
+						Logic::Zero => (),
+						_ => result = Logic::X,
+					}
+				}
+
+				Some(Value::Vector(LogicVec::from_logic(result)))
+			},
+			Expression::Assign { lvalue, operator, value } => {
+				let value = self.evaluate(value)?;
+				let value = match operator.inner() {
+					AssignmentOperator::Assign => value,
+					operator => {
+						let current = self.evaluate(lvalue)?;
+						match Value::binary(compound_operator(*operator), &current, &value) {
+							Some(value) => value,
+							None => {
+								return self.error(
+									"Operator is not valid for real operands".to_string(),
+									span,
+								);
+							},
+						}
+					},
+				};
+				self.assign(lvalue, value)
+			},
+			Expression::IncDec { operator, prefix, operand } => {
+				let current = self.evaluate(operand)?;
+				let operator = match operator.inner() {
+					IncDecOperator::Increment => BinaryOperator::Add,
+					IncDecOperator::Decrement => BinaryOperator::Subtract,
+				};
+				let one = if current.is_real() {
+					Value::Real(1.0)
+				} else {
+					Value::integer(1)
+				};
+				let next = Value::binary(operator, &current, &one)?;
+				let next = self.assign(operand, next)?;
+
+				Some(if *prefix {
+					next
+				} else {
+					current
+				})
+			},
+			_ => self.not_constant(span),
+		}
+	}
+
+	/// Evaluate the value of a declaration of `data_type`, an unbased unsized literal fills the
+	/// whole width of the type
+	///
+	/// Parameters with a range but no type are unsigned unless declared `signed`, and those with
+	/// neither keep the type of their value.
+	///
+	/// IEEE 1800-2017 § 5.7.1, § 6.20.2
+	pub fn evaluate_as(
+		&mut self,
+		data_type: &DataType,
+		expr: &Spanned<Expression>,
+	) -> Option<Value> {
+		let value_type = self.value_type(data_type);
+
+		if let (
+			Some(value_type @ ValueType::Vector { signed, .. }),
+			Expression::Literal(Literal::UnbasedUnsized(digit)),
+		) = (value_type, expr.inner())
+		{
+			return Some(Value::Vector(LogicVec::filled(
+				value_type.width(),
+				signed,
+				unbased_digit(*digit),
+			)));
+		}
+
+		let value = self.evaluate(expr)?;
+		Some(self.cast_to(data_type, value))
+	}
+
+	/// Convert `value` to `data_type`, see [`Evaluator::evaluate_as`]
+	pub fn cast_to(&mut self, data_type: &DataType, value: Value) -> Value {
+		match (data_type, self.value_type(data_type)) {
+			(_, Some(value_type)) => value_type.cast(&value),
+			(DataType::Implicit { signing: Some(signing), .. }, None) => {
+				Value::Vector(value.to_vector().with_signed(*signing == Signing::Signed))
+			},
+			_ => value,
+		}
+	}
+
+	/// The type of `data_type`, or `None` for parameters without an explicit type and types that
+	/// are not integral or real
+	pub fn value_type(&mut self, data_type: &DataType) -> Option<ValueType> {
+		let vector = |width: u32, signed: bool, two_state: bool| ValueType::Vector {
+			msb: i64::from(width) - 1,
+			lsb: 0,
+			signed,
+			two_state,
+		};
+
+		let (base, signed, two_state) = match data_type {
+			DataType::Implicit { packed, .. } if packed.is_empty() => return None,
+			DataType::Implicit { signing, .. } => (1, *signing == Some(Signing::Signed), false),
+			DataType::IntegerVector { kind, signing, .. } => (
+				1,
+				*signing == Some(Signing::Signed),
+				*kind == IntegerVectorType::Bit,
+			),
+			DataType::IntegerAtom { kind, signing } => {
+				let (width, two_state) = match kind {
+					IntegerAtomType::Integer => (32, false),
+					IntegerAtomType::Time => (64, false),
+					IntegerAtomType::Byte => (8, true),
+					IntegerAtomType::ShortInt => (16, true),
+					IntegerAtomType::Int => (32, true),
+					IntegerAtomType::LongInt => (64, true),
+				};
+				let signed = signing.map_or(*kind != IntegerAtomType::Time, |signing| {
+					signing == Signing::Signed
+				});
+				return Some(vector(width, signed, two_state));
+			},
+			DataType::NonInteger(NonIntegerType::ShortReal) => return Some(ValueType::ShortReal),
+			DataType::NonInteger(_) => return Some(ValueType::Real),
+			DataType::String => return Some(ValueType::String),
+			DataType::Struct(struct_type) if struct_type.packed => {
+				let mut widths = Vec::new();
+				let mut two_state = true;
+				for member in &struct_type.members {
+					let member = member.inner();
+					let member_type = self.value_type(&member.data_type)?;
+					two_state &= matches!(member_type, ValueType::Vector { two_state: true, .. });
+					widths.extend(member.declarators.iter().map(|_| member_type.width()));
+				}
+				let width = match struct_type.kind {
+					StructKind::Struct => widths.iter().sum(),
+					StructKind::Union => widths.iter().copied().max().unwrap_or(1),
+				};
+				(
+					width,
+					struct_type.signing == Some(Signing::Signed),
+					two_state,
+				)
+			},
+			DataType::Enum(enum_type) => {
+				let base = match &enum_type.base {
+					Some(base) => self.value_type(base)?,
+					None => vector(32, true, true),
+				};
+				let ValueType::Vector { signed, two_state, .. } = base else {
+					return None;
+				};
+				(base.width(), signed, two_state)
+			},
+			DataType::Named { path, .. } => {
+				let base = match path.as_slice() {
+					[name] => self.lookup_type(name.inner().as_str()).flatten()?,
+					[package, name] => {
+						self.load_package(package.inner().as_str());
+						(*self
+							.package_frame(package.inner().as_str())?
+							.types
+							.get(name.inner().as_str())?)?
+					},
+					_ => return None,
+				};
+				if data_type.packed().is_empty() {
+					return Some(base);
+				}
+				let ValueType::Vector { signed, two_state, .. } = base else {
+					return None;
+				};
+				(base.width(), signed, two_state)
+			},
+			_ => return None,
+		};
+
+		let mut ranges = Vec::new();
+		for dimension in data_type.packed() {
+			let range = match dimension.inner() {
+				Dimension::Range { msb, lsb } => {
+					(self.evaluate(msb)?.to_i64()?, self.evaluate(lsb)?.to_i64()?)
+				},
+				Dimension::Size(size) => (self.evaluate(size)?.to_i64()? - 1, 0),
+				_ => return None,
+			};
+			ranges.push(range);
+		}
+
+		match ranges.as_slice() {
+			[] => Some(vector(base, signed, two_state)),
+			[(msb, lsb)] if base == 1 => {
+				Some(ValueType::Vector { msb: *msb, lsb: *lsb, signed, two_state })
+			},
+			_ => {
+				let width = ranges.iter().try_fold(u64::from(base), |acc, (msb, lsb)| {
+					acc.checked_mul(msb.abs_diff(*lsb) + 1)
+				})?;
+				Some(vector(u32::try_from(width).ok()?, signed, two_state))
+			},
+		}
+	}
+
+	/// Declare the members of any enum in `data_type`, each member is one more than the last
+	/// unless given a value
+	///
+	/// IEEE 1800-2017 § 6.19
+	fn declare_enums(&mut self, data_type: &DataType) {
+		let DataType::Enum(enum_type) = data_type else {
+			return;
+		};
+		let EnumType { base, members, .. } = enum_type.as_ref();
+
+		let base = match base {
+			Some(base) => self.value_type(base),
+			None => Some(ValueType::Vector {
+				msb:       31,
+				lsb:       0,
+				signed:    true,
+				two_state: true,
+			}),
+		};
+		let data_type = self.value_type(data_type).or(base);
+
+		let mut next = Value::integer(0);
+		for member in members {
+			let member = member.inner();
+			let name = member.name.inner().as_str();
+
+			// `name[N]` and `name[N:M]` declare a sequence of numbered members
+			let names = match member.range.as_ref().map(Spanned::inner) {
+				None => vec![name.to_string()],
+				Some(Dimension::Size(size)) => {
+					let Some(size) = self.evaluate(size).and_then(|size| size.to_i64()) else {
+						continue;
+					};
+					(0..size).map(|idx| format!("{name}{idx}")).collect()
+				},
+				Some(Dimension::Range { msb, lsb }) => {
+					let (Some(first), Some(last)) = (
+						self.evaluate(msb).and_then(|msb| msb.to_i64()),
+						self.evaluate(lsb).and_then(|lsb| lsb.to_i64()),
+					) else {
+						continue;
+					};
+					if first <= last {
+						(first..=last).map(|idx| format!("{name}{idx}")).collect()
+					} else {
+						(last..=first)
+							.rev()
+							.map(|idx| format!("{name}{idx}"))
+							.collect()
+					}
+				},
+				Some(_) => continue,
+			};
+
+			if let Some(value) = &member.value {
+				match self.evaluate(value) {
+					Some(value) => next = value,
+					None => continue,
+				}
+			}
+
+			for name in names {
+				let value =
+					data_type.map_or_else(|| next.clone(), |data_type| data_type.cast(&next));
+				self.define(&name, Constant { value: value.clone(), data_type });
+				next =
+					Value::binary(BinaryOperator::Add, &value, &Value::integer(1)).unwrap_or(value);
+			}
+		}
+	}
+
+	fn literal(&mut self, literal: &Literal, span: Span) -> Option<Value> {
+		Some(match literal {
+			Literal::Unsigned(digits) => {
+				Value::Vector(LogicVec::parse_decimal(tendril_str(digits)))
+			},
+			Literal::Based { size, specifier, value } => Value::Vector(LogicVec::parse_based(
+				size.as_ref().map(tendril_str),
+				specifier.specifier(),
+				specifier.is_signed(),
+				tendril_str(value),
+			)),
+			Literal::Real(value) => Value::Real(*value),
+			Literal::String(string) => {
+				Value::Vector(LogicVec::from_bytes(&unescape(string.as_str())))
+			},
+			Literal::TripleQuotedString(string) => {
+				Value::Vector(LogicVec::from_bytes(&unescape(string.as_str())))
+			},
+			Literal::UnbasedUnsized(digit) => {
+				Value::Vector(LogicVec::from_logic(unbased_digit(*digit)))
+			},
+			Literal::Time { .. } => return self.not_constant(span),
+		})
+	}
+
+	/// Evaluate the items of a concatenation, which must all be integral
+	fn vectors(&mut self, items: &[Spanned<Expression>]) -> Option<Vec<LogicVec>> {
+		let mut parts = Vec::with_capacity(items.len());
+		for item in items {
+			match self.evaluate(item)? {
+				Value::Vector(vec) => parts.push(vec),
+				Value::Real(_) => {
+					return self.error(
+						"Real values can not be concatenated".to_string(),
+						*item.span(),
+					);
+				},
+			}
+		}
+
+		Some(parts)
+	}
+
+	/// The declared type of the name an expression selects from, if any
+	fn target_type(&self, target: &Expression) -> Option<ValueType> {
+		match target {
+			Expression::Identifier(name) => self.lookup(name.as_str())?.data_type,
+			_ => None,
+		}
+	}
+
+	/// The offset of the least significant selected bit and the number of bits selected, `None`
+	/// if an index is unknown
+	fn select_range(
+		&mut self,
+		data_type: Option<ValueType>,
+		select: &Spanned<Select>,
+	) -> Option<Option<(i64, u32)>> {
+		let data_type = data_type.unwrap_or(ValueType::Vector {
+			msb:       1,
+			lsb:       0,
+			signed:    false,
+			two_state: false,
+		});
+		let index = |evaluator: &mut Self, expr: &Spanned<Expression>| -> Option<Option<i64>> {
+			Some(evaluator.evaluate(expr)?.to_i64())
+		};
+
+		Some(match select.inner() {
+			Select::Bit(idx) => index(self, idx)?.map(|idx| (data_type.offset(idx), 1)),
+			Select::Range { msb, lsb } => match (index(self, msb)?, index(self, lsb)?) {
+				(Some(msb), Some(lsb)) => Some((
+					data_type.offset(msb).min(data_type.offset(lsb)),
+					(msb.abs_diff(lsb) + 1).try_into().ok()?,
+				)),
+				_ => None,
+			},
+			Select::IndexedUp { base, width } | Select::IndexedDown { base, width } => {
+				let (Some(base), Some(width)) = (index(self, base)?, index(self, width)?) else {
+					return Some(None);
+				};
+				let last = if matches!(select.inner(), Select::IndexedUp { .. }) {
+					base + width - 1
+				} else {
+					base - width + 1
+				};
+				Some((
+					data_type.offset(base).min(data_type.offset(last)),
+					width.try_into().ok()?,
+				))
+			},
+		})
+	}
+
+	fn select(
+		&mut self,
+		target: &Spanned<Expression>,
+		select: &Spanned<Select>,
+		span: Span,
+	) -> Option<Value> {
+		let data_type = self.target_type(target.inner());
+		let Value::Vector(value) = self.evaluate(target)? else {
+			return self.error("Real values can not be selected from".to_string(), span);
+		};
+
+		let data_type = data_type.or(Some(ValueType::Vector {
+			msb:       i64::from(value.width()) - 1,
+			lsb:       0,
+			signed:    false,
+			two_state: false,
+		}));
+		match self.select_range(data_type, select) {
+			Some(Some((lsb, width))) => Some(Value::Vector(value.select(lsb, width))),
+			Some(None) => {
+				let width = match select.inner() {
+					Select::Bit(_) => 1,
+					_ => value.width(),
+				};
+				Some(Value::Vector(LogicVec::filled(width, false, Logic::X)))
+			},
+			None => self.error("Invalid select".to_string(), *select.span()),
+		}
+	}
+
+	/// Assign to a variable of a constant function, or a bit or part select of one
+	fn assign(&mut self, lvalue: &Spanned<Expression>, value: Value) -> Option<Value> {
+		let span = *lvalue.span();
+
+		let (name, select) = match lvalue.inner() {
+			Expression::Identifier(name) => (name.as_str(), None),
+			Expression::Select { target, select } => match target.inner() {
+				Expression::Identifier(name) => (name.as_str(), Some(select)),
+				_ => return self.not_constant(span),
+			},
+			_ => return self.not_constant(span),
+		};
+
+		let Some(current) = self.lookup(name).cloned() else {
+			return self.error(
+				format!("`{name}` is not a variable of a constant function"),
+				span,
+			);
+		};
+
+		let value = match select {
+			None => current
+				.data_type
+				.map_or_else(|| value.clone(), |data_type| data_type.cast(&value)),
+			Some(select) => {
+				let Value::Vector(mut vec) = current.value.clone() else {
+					return self.error("Real values can not be selected from".to_string(), span);
+				};
+				let data_type = current.data_type.or(Some(ValueType::Vector {
+					msb:       i64::from(vec.width()) - 1,
+					lsb:       0,
+					signed:    false,
+					two_state: false,
+				}));
+				// NOTE(aki): Writes to unknown indices are ignored
+				if let Some((lsb, width)) = self.select_range(data_type, select)? {
+					vec.assign(lsb, &value.to_vector().resize(width));
+				}
+				Value::Vector(vec)
+			},
+		};
+
+		for frame in self.frames.iter_mut().rev() {
+			if let Some(constant) = frame.constants.get_mut(name) {
+				constant.value = value.clone();
+				break;
+			}
+		}
+
+		Some(value)
+	}
+
+	/// Constant system functions
+	///
+	/// IEEE 1800-2017 § 11.2.1, § 20
+	fn system_call(
+		&mut self,
+		name: &Spanned<SystemFunc>,
+		arguments: &[Option<Spanned<Expression>>],
+		span: Span,
+	) -> Option<Value> {
+		let function = match name.inner() {
+			SystemFunc::Builtin(function) if is_constant_system_function(*function) => function,
+			_ => {
+				return self.error(
+					format!(
+						"`${}` is not a constant system function",
+						name.inner().as_str()
+					),
+					span,
+				);
+			},
+		};
+
+		let Some(first) = arguments.first().and_then(Option::as_ref) else {
+			return self.error(
+				format!("`${}` expects an argument", name.inner().as_str()),
+				span,
+			);
+		};
+
+		// Array query functions and `$bits` also accept types and take the type of names
+		match function {
+			BuiltinSysFunc::Bits |
+			BuiltinSysFunc::Size |
+			BuiltinSysFunc::Left |
+			BuiltinSysFunc::Right |
+			BuiltinSysFunc::Low |
+			BuiltinSysFunc::High |
+			BuiltinSysFunc::Increment |
+			BuiltinSysFunc::Dimensions |
+			BuiltinSysFunc::UnpackedDimensions => {
+				let data_type = match first.inner() {
+					Expression::DataType(data_type) => self.value_type(data_type),
+					Expression::Identifier(name) if self.lookup_type(name.as_str()).is_some() => {
+						self.lookup_type(name.as_str()).flatten()
+					},
+					expr => match self.target_type(expr) {
+						Some(data_type) => Some(data_type),
+						None => {
+							let value = self.evaluate(first)?;
+							Some(match value {
+								Value::Real(_) => ValueType::Real,
+								Value::Vector(vec) => ValueType::Vector {
+									msb:       i64::from(vec.width()) - 1,
+									lsb:       0,
+									signed:    vec.is_signed(),
+									two_state: false,
+								},
+							})
+						},
+					},
+				};
+				let Some(data_type) = data_type else {
+					return self.not_constant(*first.span());
+				};
+
+				let ValueType::Vector { msb, lsb, .. } = data_type else {
+					return match function {
+						BuiltinSysFunc::Bits => Some(Value::integer(data_type.width().into())),
+						_ => self.error("Real values have no dimensions".to_string(), span),
+					};
+				};
+				return Some(Value::integer(match function {
+					BuiltinSysFunc::Bits | BuiltinSysFunc::Size => data_type.width().into(),
+					BuiltinSysFunc::Left => msb,
+					BuiltinSysFunc::Right => lsb,
+					BuiltinSysFunc::Low => msb.min(lsb),
+					BuiltinSysFunc::High => msb.max(lsb),
+					BuiltinSysFunc::Increment => {
+						if msb >= lsb {
+							1
+						} else {
+							-1
+						}
+					},
+					BuiltinSysFunc::Dimensions => 1,
+					_ => 0,
+				}));
+			},
+			_ => (),
+		}
+
+		let mut values = Vec::with_capacity(arguments.len());
+		for argument in arguments {
+			match argument {
+				Some(argument) => values.push(self.evaluate(argument)?),
+				None => return self.not_constant(span),
+			}
+		}
+		let value = &values[0];
+		let real = |op: fn(f64) -> f64| Some(Value::Real(op(value.to_f64())));
+		let count = |count: u32| Some(Value::integer(count.into()));
+
+		match function {
+			BuiltinSysFunc::Clog2 => {
+				let vec = value.to_vector();
+				if !vec.is_known() {
+					return Some(Value::Vector(LogicVec::filled(32, true, Logic::X)));
+				}
+				// ceil(log2(n)) is the width needed to hold n - 1
+				let minus_one = Value::binary(
+					BinaryOperator::Subtract,
+					&Value::Vector(vec.clone().with_signed(false)),
+					&Value::Vector(LogicVec::from_u64(1, vec.width(), false)),
+				)?
+				.to_vector();
+				let bits = if vec.truth() == Logic::Zero {
+					0
+				} else {
+					(0..minus_one.width())
+						.rev()
+						.find(|idx| minus_one.bit(*idx) == Logic::One)
+						.map_or(0, |idx| idx + 1)
+				};
+				count(bits)
+			},
+			BuiltinSysFunc::Signed => Some(Value::Vector(value.to_vector().with_signed(true))),
+			BuiltinSysFunc::Unsigned => Some(Value::Vector(value.to_vector().with_signed(false))),
+			BuiltinSysFunc::Itor => Some(Value::Real(value.to_f64())),
+			BuiltinSysFunc::Rtoi => Some(Value::Vector(LogicVec::from_f64(
+				value.to_f64().trunc(),
+				32,
+				true,
+			))),
+			BuiltinSysFunc::RealToBits => Some(Value::Vector(LogicVec::from_u64(
+				value.to_f64().to_bits(),
+				64,
+				false,
+			))),
+			BuiltinSysFunc::BitsToReal => {
+				let bits = value
+					.to_vector()
+					.resize(64)
+					.with_signed(false)
+					.to_u64()
+					.unwrap_or(0);
+				Some(Value::Real(f64::from_bits(bits)))
+			},
+			BuiltinSysFunc::ShortRealToBits => Some(Value::Vector(LogicVec::from_u64(
+				(value.to_f64() as f32).to_bits().into(),
+				32,
+				false,
+			))),
+			BuiltinSysFunc::BitsToShortReal => {
+				let bits = value
+					.to_vector()
+					.resize(32)
+					.with_signed(false)
+					.to_u64()
+					.unwrap_or(0);
+				Some(Value::Real(f32::from_bits(bits as u32).into()))
+			},
+			BuiltinSysFunc::Ln => real(f64::ln),
+			BuiltinSysFunc::Log10 => real(f64::log10),
+			BuiltinSysFunc::Exp => real(f64::exp),
+			BuiltinSysFunc::Sqrt => real(f64::sqrt),
+			BuiltinSysFunc::Floor => real(f64::floor),
+			BuiltinSysFunc::Ceil => real(f64::ceil),
+			BuiltinSysFunc::Sin => real(f64::sin),
+			BuiltinSysFunc::Cos => real(f64::cos),
+			BuiltinSysFunc::Tan => real(f64::tan),
+			BuiltinSysFunc::Asin => real(f64::asin),
+			BuiltinSysFunc::Acos => real(f64::acos),
+			BuiltinSysFunc::Atan => real(f64::atan),
+			BuiltinSysFunc::Sinh => real(f64::sinh),
+			BuiltinSysFunc::Cosh => real(f64::cosh),
+			BuiltinSysFunc::Tanh => real(f64::tanh),
+			BuiltinSysFunc::Asinh => real(f64::asinh),
+			BuiltinSysFunc::Acosh => real(f64::acosh),
+			BuiltinSysFunc::Atanh => real(f64::atanh),
+			BuiltinSysFunc::Pow | BuiltinSysFunc::Atan2 | BuiltinSysFunc::Hypot => {
+				let Some(other) = values.get(1) else {
+					return self.error(
+						format!("`${}` expects two arguments", name.inner().as_str()),
+						span,
+					);
+				};
+				let (lhs, rhs) = (value.to_f64(), other.to_f64());
+				Some(Value::Real(match function {
+					BuiltinSysFunc::Pow => lhs.powf(rhs),
+					BuiltinSysFunc::Atan2 => lhs.atan2(rhs),
+					_ => lhs.hypot(rhs),
+				}))
+			},
+			BuiltinSysFunc::CountOnes => count(value.to_vector().count(Logic::One)),
+			BuiltinSysFunc::CountBits => {
+				let vec = value.to_vector();
+				let mut controls = values[1..]
+					.iter()
+					.map(|control| control.to_vector().bit(0))
+					.collect::<Vec<_>>();
+				controls.sort();
+				controls.dedup();
+				count(controls.iter().map(|control| vec.count(*control)).sum())
+			},
+			BuiltinSysFunc::OneHot => {
+				Some(Value::from_bool(value.to_vector().count(Logic::One) == 1))
+			},
+			BuiltinSysFunc::OneHot0 => {
+				Some(Value::from_bool(value.to_vector().count(Logic::One) <= 1))
+			},
+			BuiltinSysFunc::IsUnknown => Some(Value::from_bool(!value.to_vector().is_known())),
+			_ => self.error(
+				format!(
+					"`${}` is not a constant system function",
+					name.inner().as_str()
+				),
+				span,
+			),
+		}
+	}
+
+	/// Call a constant function
+	///
+	/// IEEE 1800-2017 § 13.4.3
+	fn call(
+		&mut self,
+		target: &Spanned<Expression>,
+		arguments: &[Spanned<Expression>],
+		span: Span,
+	) -> Option<Value> {
+		let found = match target.inner() {
+			Expression::Identifier(name) => self.find_function(name.as_str()),
+			Expression::Scope { scope, member } => match scope.inner() {
+				Expression::Identifier(package) => {
+					let package = package.as_str();
+					self.load_package(package);
+					self.package_frame(package)
+						.and_then(|frame| frame.functions.get(member.inner().as_str()))
+						.map(|(file, function)| (*file, *function, Some(package.to_string())))
+				},
+				_ => None,
+			},
+			_ => None,
+		};
+		let Some((file, function, package)) = found else {
+			return self.error("Not a constant function".to_string(), *target.span());
+		};
+
+		let mut values = Vec::with_capacity(arguments.len());
+		for argument in arguments {
+			values.push(self.evaluate(argument)?);
+		}
+
+		if self.calls >= CALL_LIMIT {
+			return self.error(
+				format!(
+					"Constant function `{}` recursed more than {CALL_LIMIT} times",
+					function.name.inner().as_str()
+				),
+				span,
+			);
+		}
+		if self.calls == 0 {
+			self.steps = 0;
+		}
+
+		// NOTE(aki): Constant functions only see the scope they were declared in, which for package
+		// functions is the package, and for everything else is still on the scope stack
+		let frames = match &package {
+			Some(package) => vec![self.package_frame(package).cloned().unwrap_or_default()],
+			None => {
+				let depth = self
+					.frames
+					.iter()
+					.rposition(|frame| frame.functions.contains_key(function.name.inner().as_str()))
+					.unwrap_or(0);
+				self.frames[..=depth].to_vec()
+			},
+		};
+		let saved = (std::mem::replace(&mut self.frames, frames), self.file);
+		self.file = file;
+		self.calls += 1;
+
+		let result = self.run_function(function, values, span);
+
+		self.calls -= 1;
+		self.frames = saved.0;
+		self.file = saved.1;
+
+		result
+	}
+
+	fn find_function(&self, name: &str) -> Option<(FileId, &'a Function, Option<String>)> {
+		self.frames.iter().rev().find_map(|frame| {
+			frame
+				.functions
+				.get(name)
+				.map(|(file, function)| (*file, *function, None))
+				.or_else(|| {
+					frame
+						.imports
+						.iter()
+						.filter(|(_, item)| item.as_deref().is_none_or(|item| item == name))
+						.find_map(|(package, _)| {
+							let (file, function) =
+								self.package_frame(package)?.functions.get(name)?;
+							Some((*file, *function, Some(package.clone())))
+						})
+				})
+		})
+	}
+
+	fn run_function(
+		&mut self,
+		function: &'a Function,
+		values: Vec<Value>,
+		span: Span,
+	) -> Option<Value> {
+		self.frames.push(Frame::default());
+
+		let name = function.name.inner().as_str();
+		let return_type = match &function.return_type {
+			DataType::Void => None,
+			DataType::Implicit { packed, signing } if packed.is_empty() => {
+				Some(ValueType::Vector {
+					msb:       0,
+					lsb:       0,
+					signed:    *signing == Some(Signing::Signed),
+					two_state: false,
+				})
+			},
+			data_type => self.value_type(data_type),
+		};
+		if let Some(return_type) = return_type {
+			self.define(
+				name,
+				Constant {
+					value:     return_type.default_value(),
+					data_type: Some(return_type),
+				},
+			);
+		}
+
+		// The inputs are either declared in the header or as the first items of the body
+		let ports = function
+			.ports
+			.iter()
+			.flatten()
+			.map(Spanned::inner)
+			.chain(
+				function
+					.items
+					.iter()
+					.filter_map(|item| match &item.inner().kind {
+						ItemKind::Port(port) => Some(port),
+						_ => None,
+					}),
+			)
+			.collect::<Vec<_>>();
+
+		let mut values = values.into_iter();
+		let mut direction = PortDirection::Input;
+		let mut data_type = &DataType::default();
+		for port in ports {
+			// NOTE(aki): ANSI ports without a direction or type take those of the previous port
+			direction = port.direction.unwrap_or(direction);
+			if port.direction.is_some() || port.data_type != DataType::default() {
+				data_type = &port.data_type;
+			}
+
+			let value_type = match data_type {
+				DataType::Implicit { packed, signing } if packed.is_empty() => ValueType::Vector {
+					msb:       0,
+					lsb:       0,
+					signed:    *signing == Some(Signing::Signed),
+					two_state: false,
+				},
+				data_type => match self.value_type(data_type) {
+					Some(value_type) => value_type,
+					None => ValueType::INTEGER,
+				},
+			};
+
+			for declarator in &port.declarators {
+				let value = match direction {
+					PortDirection::Input => values.next().map_or_else(
+						|| value_type.default_value(),
+						|value| value_type.cast(&value),
+					),
+					_ => value_type.default_value(),
+				};
+				self.define(
+					declarator.inner().name.inner().as_str(),
+					Constant { value, data_type: Some(value_type) },
+				);
+			}
+		}
+
+		let mut result = self.declare_variables(&function.items);
+		if result.is_some() {
+			self.returned = None;
+			for statement in &function.body {
+				match self.execute(statement) {
+					Some(Flow::Return) => break,
+					Some(_) => (),
+					None => {
+						result = None;
+						break;
+					},
+				}
+			}
+		}
+
+		let value = self
+			.returned
+			.take()
+			.or_else(|| self.lookup(name).map(|constant| constant.value.clone()));
+		self.frames.pop();
+
+		result?;
+		match (value, return_type) {
+			(Some(value), Some(return_type)) => Some(return_type.cast(&value)),
+			(_, None) => Some(Value::integer(0)),
+			_ => self.not_constant(span),
+		}
+	}
+
+	/// Declare the variables, parameters, and types of a constant function or block
+	fn declare_variables(&mut self, items: &'a [Spanned<Item>]) -> Option<()> {
+		for item in items {
+			match &item.inner().kind {
+				ItemKind::Variable(variable) => {
+					self.declare_enums(&variable.data_type);
+					let value_type = self
+						.value_type(&variable.data_type)
+						.unwrap_or(ValueType::INTEGER);
+					for declarator in &variable.declarators {
+						let declarator = declarator.inner();
+						let value = match &declarator.init {
+							Some(init) => value_type.cast(&self.evaluate(init)?),
+							None => value_type.default_value(),
+						};
+						self.define(
+							declarator.name.inner().as_str(),
+							Constant { value, data_type: Some(value_type) },
+						);
+					}
+				},
+				ItemKind::Port(_) => (),
+				_ => self.declare_item(item.inner()),
+			}
+		}
+
+		Some(())
+	}
+
+	/// Run a statement of a constant function, returning `None` if it could not be evaluated
+	fn execute(&mut self, statement: &'a Spanned<Statement>) -> Option<Flow> {
+		let span = *statement.span();
+
+		self.steps += 1;
+		if self.steps > STEP_LIMIT {
+			// NOTE(aki): Only reported once, the remaining statements of the call stop silently
+			if self.steps == STEP_LIMIT + 1 {
+				return self.error(
+					format!("Constant function did not finish within {STEP_LIMIT} statements"),
+					span,
+				);
+			}
+			return None;
+		}
+
+		match &statement.inner().kind {
+			StatementKind::Null | StatementKind::SystemTaskEnable { .. } => Some(Flow::Next),
+			StatementKind::Blocking(assignment) => {
+				let value = self.evaluate(&assignment.value)?;
+				self.assign(&assignment.lvalue, value)?;
+				Some(Flow::Next)
+			},
+			StatementKind::Expression(expr) => {
+				self.evaluate(expr)?;
+				Some(Flow::Next)
+			},
+			StatementKind::If { condition, if_true, if_false, .. } => {
+				if self.evaluate(condition)?.truth() == Logic::One {
+					self.execute(if_true)
+				} else if let Some(if_false) = if_false {
+					self.execute(if_false)
+				} else {
+					Some(Flow::Next)
+				}
+			},
+			StatementKind::Case(case) => {
+				let value = self.evaluate(&case.expression)?;
+				let mut default = None;
+				for item in &case.items {
+					let item = item.inner();
+					if item.labels.is_empty() {
+						default = Some(&item.statement);
+						continue;
+					}
+
+					for label in &item.labels {
+						let label = self.evaluate(label)?;
+						if case_matches(case.kind, case.inside, &value, &label) {
+							return self.execute(&item.statement);
+						}
+					}
+				}
+
+				match default {
+					Some(statement) => self.execute(statement),
+					None => Some(Flow::Next),
+				}
+			},
+			StatementKind::For { init, condition, step, body } => {
+				self.frames.push(Frame::default());
+				let flow = self.run_for(init, condition.as_ref(), step, body);
+				self.frames.pop();
+				flow
+			},
+			StatementKind::While { condition, body } => {
+				while self.evaluate(condition)?.truth() == Logic::One {
+					match self.execute(body)? {
+						Flow::Break => break,
+						Flow::Return => return Some(Flow::Return),
+						_ => (),
+					}
+				}
+				Some(Flow::Next)
+			},
+			StatementKind::DoWhile { body, condition } => {
+				loop {
+					match self.execute(body)? {
+						Flow::Break => break,
+						Flow::Return => return Some(Flow::Return),
+						_ => (),
+					}
+					if self.evaluate(condition)?.truth() != Logic::One {
+						break;
+					}
+				}
+				Some(Flow::Next)
+			},
+			StatementKind::Repeat { count, body } => {
+				let count = self.evaluate(count)?.to_i64().unwrap_or(0);
+				for _ in 0..count {
+					match self.execute(body)? {
+						Flow::Break => break,
+						Flow::Return => return Some(Flow::Return),
+						_ => (),
+					}
+				}
+				Some(Flow::Next)
+			},
+			StatementKind::Forever(body) => loop {
+				match self.execute(body)? {
+					Flow::Break => return Some(Flow::Next),
+					Flow::Return => return Some(Flow::Return),
+					_ => (),
+				}
+			},
+			StatementKind::Block(block) => {
+				self.frames.push(Frame::default());
+				let mut flow = self.declare_variables(&block.items).map(|_| Flow::Next);
+				for statement in &block.statements {
+					flow = self.execute(statement);
+					if flow != Some(Flow::Next) {
+						break;
+					}
+				}
+				self.frames.pop();
+				flow
+			},
+			StatementKind::Return(value) => {
+				if let Some(value) = value {
+					self.returned = Some(self.evaluate(value)?);
+				}
+				Some(Flow::Return)
+			},
+			StatementKind::Break => Some(Flow::Break),
+			StatementKind::Continue => Some(Flow::Continue),
+			_ => self.error(
+				"Statement is not allowed in a constant function".to_string(),
+				span,
+			),
+		}
+	}
+
+	fn run_for(
+		&mut self,
+		init: &'a [Spanned<crate::lang::ast::ForInit>],
+		condition: Option<&'a Spanned<Expression>>,
+		step: &'a [Spanned<Expression>],
+		body: &'a Spanned<Statement>,
+	) -> Option<Flow> {
+		for init in init {
+			let init = init.inner();
+			let value = self.evaluate(&init.assignment.inner().value)?;
+			match &init.data_type {
+				Some(data_type) => {
+					let value_type = self.value_type(data_type).unwrap_or(ValueType::INTEGER);
+					if let Expression::Identifier(name) = init.assignment.inner().lvalue.inner() {
+						self.define(
+							name.as_str(),
+							Constant {
+								value:     value_type.cast(&value),
+								data_type: Some(value_type),
+							},
+						);
+					}
+				},
+				None => {
+					self.assign(&init.assignment.inner().lvalue, value)?;
+				},
+			}
+		}
+
+		loop {
+			if let Some(condition) = condition &&
+				self.evaluate(condition)?.truth() != Logic::One
+			{
+				break;
+			}
+
+			match self.execute(body)? {
+				Flow::Break => break,
+				Flow::Return => return Some(Flow::Return),
+				_ => (),
+			}
+
+			for step in step {
+				self.evaluate(step)?;
+			}
+		}
+
+		Some(Flow::Next)
+	}
+
+	/// Evaluate the items of a package the first time it is used
+	fn load_package(&mut self, name: &str) {
+		let Some(PackageState::Pending(file, package)) = self.packages.get(name).cloned() else {
+			return;
+		};
+		self.packages
+			.insert(name.to_string(), PackageState::Evaluating);
+
+		let frames = self.replace_frames(vec![Frame::default()]);
+		let saved_file = std::mem::replace(&mut self.file, file);
+		self.declare_items(&package.items);
+		let frame = self.frame();
+		self.frames = frames;
+		self.file = saved_file;
+
+		self.packages
+			.insert(name.to_string(), PackageState::Done(frame));
+	}
+
+	fn package_frame(&self, name: &str) -> Option<&Frame<'a>> {
+		match self.packages.get(name)? {
+			PackageState::Done(frame) => Some(frame),
+			_ => None,
+		}
+	}
+
+	fn not_constant(&mut self, span: Span) -> Option<Value> {
+		self.error("Not a constant expression".to_string(), span)
+	}
+
+	fn error<T>(&mut self, message: String, span: Span) -> Option<T> {
+		self.diagnostics.push(Location::new(
+			self.file,
+			StringDiagnostic::new(not_constant_code(self.std), message, Some(span)),
+			span,
+		));
+
+		None
+	}
+}
+
+/// The binary operator of a compound assignment operator like `+=`
+/// Whether a builtin system function may appear in a constant expression
+///
+/// IEEE 1800-2017 § 11.2.1
+fn is_constant_system_function(function: BuiltinSysFunc) -> bool {
+	matches!(
+		function,
+		BuiltinSysFunc::Acos |
+			BuiltinSysFunc::Acosh |
+			BuiltinSysFunc::Asin |
+			BuiltinSysFunc::Asinh |
+			BuiltinSysFunc::Atan |
+			BuiltinSysFunc::Atan2 |
+			BuiltinSysFunc::Atanh |
+			BuiltinSysFunc::Bits |
+			BuiltinSysFunc::BitsToReal |
+			BuiltinSysFunc::BitsToShortReal |
+			BuiltinSysFunc::Ceil |
+			BuiltinSysFunc::Clog2 |
+			BuiltinSysFunc::Cos |
+			BuiltinSysFunc::Cosh |
+			BuiltinSysFunc::CountBits |
+			BuiltinSysFunc::CountOnes |
+			BuiltinSysFunc::Dimensions |
+			BuiltinSysFunc::Exp |
+			BuiltinSysFunc::Floor |
+			BuiltinSysFunc::High |
+			BuiltinSysFunc::Hypot |
+			BuiltinSysFunc::Increment |
+			BuiltinSysFunc::IsUnknown |
+			BuiltinSysFunc::Itor |
+			BuiltinSysFunc::Left |
+			BuiltinSysFunc::Ln |
+			BuiltinSysFunc::Log10 |
+			BuiltinSysFunc::Low |
+			BuiltinSysFunc::OneHot |
+			BuiltinSysFunc::OneHot0 |
+			BuiltinSysFunc::Pow |
+			BuiltinSysFunc::RealToBits |
+			BuiltinSysFunc::Right |
+			BuiltinSysFunc::Rtoi |
+			BuiltinSysFunc::ShortRealToBits |
+			BuiltinSysFunc::Signed |
+			BuiltinSysFunc::Sin |
+			BuiltinSysFunc::Sinh |
+			BuiltinSysFunc::Size |
+			BuiltinSysFunc::Sqrt |
+			BuiltinSysFunc::Tan |
+			BuiltinSysFunc::Tanh |
+			BuiltinSysFunc::UnpackedDimensions |
+			BuiltinSysFunc::Unsigned
+	)
+}
+
+fn compound_operator(operator: AssignmentOperator) -> BinaryOperator {
+	match operator {
+		AssignmentOperator::Assign | AssignmentOperator::Add => BinaryOperator::Add,
+		AssignmentOperator::Subtract => BinaryOperator::Subtract,
+		AssignmentOperator::Multiply => BinaryOperator::Multiply,
+		AssignmentOperator::Divide => BinaryOperator::Divide,
+		AssignmentOperator::Modulus => BinaryOperator::Modulus,
+		AssignmentOperator::And => BinaryOperator::BitwiseAnd,
+		AssignmentOperator::Or => BinaryOperator::BitwiseOr,
+		AssignmentOperator::Xor => BinaryOperator::BitwiseXor,
+		AssignmentOperator::ShiftLeft => BinaryOperator::ShiftLeft,
+		AssignmentOperator::ShiftRight => BinaryOperator::ShiftRight,
+		AssignmentOperator::ArithmeticShiftLeft => BinaryOperator::ArithmeticShiftLeft,
+		AssignmentOperator::ArithmeticShiftRight => BinaryOperator::ArithmeticShiftRight,
+	}
+}
+
+/// Compare a case expression with a case item label, `casez` ignores `z` bits and `casex`
+/// ignores `x` and `z` bits of either side
+///
+/// IEEE 1800-2017 § 12.5.1
+pub(super) fn case_matches(kind: CaseKind, inside: bool, value: &Value, label: &Value) -> bool {
+	if value.is_real() || label.is_real() {
+		return value.to_f64() == label.to_f64();
+	}
+
+	let operator = if inside {
+		BinaryOperator::WildcardEquality
+	} else {
+		BinaryOperator::CaseEquality
+	};
+	if kind == CaseKind::Case || inside {
+		return Value::binary(operator, value, label).is_some_and(|bit| bit.truth() == Logic::One);
+	}
+
+	let (value, label) = (value.to_vector(), label.to_vector());
+	let width = value.width().max(label.width());
+	let signed = value.is_signed() && label.is_signed();
+	let (value, label) = (
+		value.with_signed(signed).resize(width),
+		label.with_signed(signed).resize(width),
+	);
+
+	(0..width).all(|idx| {
+		let (lhs, rhs) = (value.bit(idx), label.bit(idx));
+		let ignored = |bit: Logic| bit == Logic::Z || (kind == CaseKind::CaseX && bit == Logic::X);
+		lhs == rhs || ignored(lhs) || ignored(rhs)
+	})
+}
+
+fn to_two_state(vec: &LogicVec) -> LogicVec {
+	let mut result = vec.clone();
+	for idx in 0..vec.width() {
+		if !vec.bit(idx).is_known() {
+			result.set_bit(idx, Logic::Zero);
+		}
+	}
+
+	result
+}
+
+fn unbased_digit(digit: u8) -> Logic {
+	match digit {
+		b'0' => Logic::Zero,
+		b'1' => Logic::One,
+		b'z' => Logic::Z,
+		_ => Logic::X,
+	}
+}
+
+fn tendril_str(tendril: &vermilion_lang::AtomicByteTendril) -> &str {
+	std::str::from_utf8(tendril).unwrap_or_default()
+}
+
+/// The bytes of a string literal with its escape sequences replaced
+///
+/// IEEE 1800-2017 § 5.9.1
+fn unescape(string: &str) -> Vec<u8> {
+	let mut bytes = Vec::with_capacity(string.len());
+	let mut chars = string.bytes().peekable();
+
+	while let Some(byte) = chars.next() {
+		if byte != b'\\' {
+			bytes.push(byte);
+			continue;
+		}
+
+		match chars.next() {
+			Some(b'n') => bytes.push(b'\n'),
+			Some(b't') => bytes.push(b'\t'),
+			Some(b'v') => bytes.push(0x0B),
+			Some(b'f') => bytes.push(0x0C),
+			Some(b'a') => bytes.push(0x07),
+			// A line continuation is not part of the string
+			Some(b'\n') => (),
+			Some(digit @ b'0'..=b'7') => {
+				let mut value = u32::from(digit - b'0');
+				for _ in 0..2 {
+					match chars.peek() {
+						Some(digit @ b'0'..=b'7') => {
+							value = value * 8 + u32::from(digit - b'0');
+							chars.next();
+						},
+						_ => break,
+					}
+				}
+				bytes.push(value as u8);
+			},
+			Some(b'x') => {
+				let mut value = 0;
+				for _ in 0..2 {
+					match chars
+						.peek()
+						.and_then(|digit| char::from(*digit).to_digit(16))
+					{
+						Some(digit) => {
+							value = value * 16 + digit;
+							chars.next();
+						},
+						None => break,
+					}
+				}
+				bytes.push(value as u8);
+			},
+			Some(other) => bytes.push(other),
+			None => bytes.push(b'\\'),
+		}
+	}
+
+	bytes
+}
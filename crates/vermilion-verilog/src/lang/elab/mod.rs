@@ -0,0 +1,1276 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use vermilion_diagnostics::{Code, StringDiagnostic};
+use vermilion_loc::{FileId, Location, Span, Spanned};
+
+use crate::{
+	LanguageStd,
+	diagnostics::{system_verilog, verilog, verilog_ams},
+	lang::ast::{
+		Ast, Connections, DataType, Description, Dimension, Expression, GenerateBlock,
+		GenerateCase, GenerateFor, GenerateIf, Instantiation, Item, ItemKind, Module,
+		ParameterDeclaration, Select,
+	},
+};
+
+mod eval;
+mod value;
+
+use self::eval::{Frame, case_matches};
+pub use self::{
+	eval::{Constant, Evaluator, ValueType},
+	value::{Logic, LogicVec, Value},
+};
+
+/// The deepest instance hierarchy that is elaborated, anything deeper is most likely an
+/// unbounded recursive instantiation
+const DEPTH_LIMIT: usize = 256;
+/// The most iterations of a single generate loop, or elements of an instance array
+const ITERATION_LIMIT: i64 = 1 << 16;
+/// The number of times the design is elaborated while `defparam` overrides change the result
+const DEFPARAM_PASSES: usize = 8;
+
+/// What an [`Instance`] in an elaborated design is
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum InstanceKind {
+	/// An instance of the named module, interface, or program
+	Module(String),
+	/// An instance of the named user defined primitive
+	Primitive(String),
+	/// An instance of a definition that could not be found
+	Missing(String),
+	/// A generate block, or a single iteration of a generate loop
+	Generate,
+}
+
+/// A module instance or generate block of an elaborated design
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instance {
+	/// The instance or generate block name, e.g. `u1`, `u[3]`, or `genblk1`, the top instance is
+	/// named after its module
+	pub name:       String,
+	pub kind:       InstanceKind,
+	/// The declaration of the instance or generate block
+	pub location:   Location<()>,
+	/// The parameters, local parameters, and generate loop variables declared directly in this
+	/// scope, in declaration order
+	pub parameters: Vec<(String, Value)>,
+	pub children:   Vec<Self>,
+}
+
+/// A `defparam` found while elaborating
+#[derive(Clone, Debug)]
+struct Defparam {
+	/// The hierarchical path of the instance, starting with the top instance
+	path:     Vec<String>,
+	name:     String,
+	value:    Value,
+	location: Location<()>,
+}
+
+/// The `defparam` overrides by instance path and parameter name
+type Defparams = BTreeMap<Vec<String>, BTreeMap<String, Value>>;
+
+/// A parameter value assignment, which for type parameters is a type
+#[derive(Clone, Debug)]
+enum Override {
+	Value(Value),
+	Type(Option<ValueType>),
+}
+
+/// The parameter value assignments of a module instance
+#[derive(Clone, Debug, Default)]
+struct Overrides<'e> {
+	ordered:   Vec<Option<Override>>,
+	named:     BTreeMap<String, Override>,
+	defparams: Option<&'e BTreeMap<String, Value>>,
+	/// The position of the next overridable parameter
+	position:  usize,
+	/// Set if the module has a parameter port list, making body parameters local
+	header:    bool,
+}
+
+/// Elaborates a design from a chosen top module
+///
+/// Parameters are evaluated with any `#(...)` value assignments and `defparam` overrides
+/// applied, generate loops are unrolled, and generate conditionals and cases are resolved,
+/// building the tree of module instances and generate blocks below the top module.
+///
+/// As a `defparam` may change the design that contains it, the design is elaborated again with
+/// the overrides that were found until they no longer change.
+///
+/// IEEE 1800-2017 § 23.10, § 27
+#[derive(Clone, Debug)]
+pub struct Elaborator<'a> {
+	std:         LanguageStd,
+	/// The modules, interfaces, and programs by name
+	definitions: BTreeMap<String, (FileId, &'a Module)>,
+	primitives:  BTreeSet<String>,
+	files:       Vec<(FileId, &'a Ast)>,
+	diagnostics: Vec<Location<StringDiagnostic>>,
+}
+
+/// The state of a single elaboration pass
+struct Builder<'e, 'a> {
+	elaborator:  &'e Elaborator<'a>,
+	evaluator:   Evaluator<'a>,
+	defparams:   &'e Defparams,
+	found:       Vec<Defparam>,
+	units:       BTreeMap<FileId, Frame<'a>>,
+	file:        FileId,
+	/// The hierarchical path of the scope being elaborated
+	path:        Vec<String>,
+	diagnostics: Vec<Location<StringDiagnostic>>,
+}
+
+impl Instance {
+	/// The module, interface, program, or primitive this is an instance of
+	pub fn definition(&self) -> Option<&str> {
+		match &self.kind {
+			InstanceKind::Module(name) |
+			InstanceKind::Primitive(name) |
+			InstanceKind::Missing(name) => Some(name),
+			InstanceKind::Generate => None,
+		}
+	}
+
+	pub fn child(&self, name: &str) -> Option<&Self> {
+		self.children.iter().find(|child| child.name == name)
+	}
+
+	/// Follow a hierarchical path of instance and generate block names down from this instance
+	pub fn find<'p>(&self, path: impl IntoIterator<Item = &'p str>) -> Option<&Self> {
+		path.into_iter()
+			.try_fold(self, |instance, name| instance.child(name))
+	}
+
+	pub fn parameter(&self, name: &str) -> Option<&Value> {
+		self.parameters
+			.iter()
+			.find_map(|(param, value)| (param == name).then_some(value))
+	}
+}
+
+impl<'a> Elaborator<'a> {
+	pub fn new(std: LanguageStd) -> Self {
+		Self {
+			std,
+			definitions: BTreeMap::new(),
+			primitives: BTreeSet::new(),
+			files: Vec::new(),
+			diagnostics: Vec::new(),
+		}
+	}
+
+	/// Add the definitions, packages, and compilation unit items of a source file
+	pub fn add_file(&mut self, file: FileId, ast: &'a Ast) {
+		for description in ast.descriptions() {
+			match description.inner() {
+				Description::Module(module) => {
+					self.definitions
+						.insert(module.name.inner().as_str().to_string(), (file, module));
+				},
+				Description::Primitive(udp) => {
+					self.primitives
+						.insert(udp.name.inner().as_str().to_string());
+				},
+				_ => (),
+			}
+		}
+
+		self.files.push((file, ast));
+	}
+
+	/// Problems found by the last call to [`Elaborator::elaborate`]
+	pub fn diagnostics(&self) -> &[Location<StringDiagnostic>] {
+		&self.diagnostics
+	}
+
+	/// The modules, interfaces, and programs that are not instantiated anywhere, in name order
+	pub fn top_modules(&self) -> Vec<&str> {
+		let mut instantiated = BTreeSet::new();
+		for (_, module) in self.definitions.values() {
+			collect_instantiated(&module.items, &mut instantiated);
+		}
+
+		self.definitions
+			.keys()
+			.map(String::as_str)
+			.filter(|name| !instantiated.contains(name))
+			.collect()
+	}
+
+	/// Elaborate the design below the module, interface, or program named `top`, returning
+	/// `None` if there is no such definition
+	pub fn elaborate(&mut self, top: &str) -> Option<Instance> {
+		let (file, module) = *self.definitions.get(top)?;
+
+		let mut defparams = Defparams::new();
+		for pass in 1..=DEFPARAM_PASSES {
+			let mut builder = Builder::new(self, &defparams, file);
+			let root = builder.instantiate(
+				top.to_string(),
+				file,
+				module,
+				Location::new(file, (), *module.name.span()),
+				Overrides::default(),
+			);
+			let Builder { found, mut diagnostics, .. } = builder;
+
+			let mut next = Defparams::new();
+			for defparam in &found {
+				next.entry(defparam.path.clone())
+					.or_default()
+					.insert(defparam.name.clone(), defparam.value.clone());
+			}
+
+			if next == defparams || pass == DEFPARAM_PASSES {
+				for defparam in found {
+					let target = root
+						.find(defparam.path.iter().skip(1).map(String::as_str))
+						.filter(|_| defparam.path.first() == Some(&root.name))
+						.filter(|instance| matches!(instance.kind, InstanceKind::Module(_)));
+					if target.is_none_or(|instance| instance.parameter(&defparam.name).is_none()) {
+						diagnostics.push(defparam.location.with_inner(StringDiagnostic::new(
+							elaboration_code(self.std),
+							format!(
+								"`defparam` target `{}.{}` was not found",
+								defparam.path.join("."),
+								defparam.name
+							),
+							Some(*defparam.location.span()),
+						)));
+					}
+				}
+
+				self.diagnostics = diagnostics;
+				return Some(root);
+			}
+
+			defparams = next;
+		}
+
+		None
+	}
+}
+
+impl<'e, 'a> Builder<'e, 'a> {
+	fn new(elaborator: &'e Elaborator<'a>, defparams: &'e Defparams, file: FileId) -> Self {
+		let mut evaluator = Evaluator::new(elaborator.std, file);
+		for (file, ast) in &elaborator.files {
+			for description in ast.descriptions() {
+				if let Description::Package(package) = description.inner() {
+					evaluator.add_package(*file, package);
+				}
+			}
+		}
+
+		// Each file's compilation unit scope is the outermost scope of its modules
+		let mut units = BTreeMap::new();
+		for (file, ast) in &elaborator.files {
+			evaluator.set_file(*file);
+			let saved = evaluator.replace_frames(vec![Frame::default()]);
+			for description in ast.descriptions() {
+				if let Description::Item(item) = description.inner() {
+					evaluator.declare_item(item);
+				}
+			}
+			units.insert(*file, evaluator.frame());
+			evaluator.replace_frames(saved);
+		}
+
+		Self {
+			elaborator,
+			evaluator,
+			defparams,
+			found: Vec::new(),
+			units,
+			file,
+			path: Vec::new(),
+			diagnostics: Vec::new(),
+		}
+	}
+
+	fn instantiate(
+		&mut self,
+		name: String,
+		file: FileId,
+		module: &'a Module,
+		location: Location<()>,
+		mut overrides: Overrides<'e>,
+	) -> Instance {
+		let mut instance = Instance {
+			name,
+			kind: InstanceKind::Module(module.name.inner().as_str().to_string()),
+			location,
+			parameters: Vec::new(),
+			children: Vec::new(),
+		};
+
+		self.path.push(instance.name.clone());
+		let unit = self.units.get(&file).cloned().unwrap_or_default();
+		let frames = self.evaluator.replace_frames(vec![unit]);
+		let saved_file = self.file;
+		self.file = file;
+		self.evaluator.set_file(file);
+		self.evaluator.push_scope();
+
+		for import in &module.imports {
+			let import = import.inner();
+			self.evaluator.import(
+				import.package.inner().as_str(),
+				import.item.as_ref().map(|item| item.inner().as_str()),
+			);
+		}
+
+		overrides.header = module.parameters.is_some();
+		overrides.defparams = self.defparams.get(&self.path);
+		for parameter in module.parameters.iter().flatten() {
+			self.parameter(parameter.inner(), Some(&mut overrides), true, &mut instance);
+		}
+
+		let mut constructs = 0;
+		self.items(
+			&module.items,
+			Some(&mut overrides),
+			&mut instance,
+			&mut constructs,
+		);
+
+		self.diagnostics.extend(self.evaluator.take_diagnostics());
+		self.evaluator.replace_frames(frames);
+		self.file = saved_file;
+		self.evaluator.set_file(saved_file);
+		self.path.pop();
+
+		instance
+	}
+
+	/// Elaborate the items of a module or generate block, `constructs` counts the generate
+	/// constructs of the scope for naming unnamed generate blocks
+	fn items(
+		&mut self,
+		items: &'a [Spanned<Item>],
+		mut overrides: Option<&mut Overrides<'e>>,
+		instance: &mut Instance,
+		constructs: &mut u32,
+	) {
+		for item in items {
+			match &item.inner().kind {
+				ItemKind::Parameter(parameter) => {
+					self.parameter(parameter, overrides.as_deref_mut(), false, instance);
+				},
+				ItemKind::GenerateRegion(items) => {
+					self.items(items, overrides.as_deref_mut(), instance, constructs);
+				},
+				ItemKind::Defparam(assignments) => {
+					for assignment in assignments {
+						self.defparam(&assignment.inner().lvalue, &assignment.inner().value);
+					}
+				},
+				ItemKind::Instantiation(instantiation) => {
+					self.instantiation(instantiation, instance);
+				},
+				ItemKind::GenerateFor(generate) => {
+					*constructs += 1;
+					self.generate_for(generate, *constructs, instance);
+				},
+				ItemKind::GenerateIf(generate) => {
+					*constructs += 1;
+					self.generate_if(generate, *constructs, instance);
+				},
+				ItemKind::GenerateCase(generate) => {
+					*constructs += 1;
+					self.generate_case(generate, *constructs, instance);
+				},
+				ItemKind::GenerateBlock(block) => {
+					*constructs += 1;
+					let child = self.generate_block(block, *constructs, *item.span(), None);
+					instance.children.push(child);
+				},
+				_ => self.evaluator.declare_item(item.inner()),
+			}
+		}
+	}
+
+	/// Evaluate the declarators of a parameter declaration, applying any overrides
+	///
+	/// In modules with a parameter port list, only the parameters of the list can be
+	/// overridden.
+	///
+	/// IEEE 1800-2017 § 6.20.1, § 23.10
+	fn parameter(
+		&mut self,
+		parameter: &'a ParameterDeclaration,
+		overrides: Option<&mut Overrides<'e>>,
+		in_header: bool,
+		instance: &mut Instance,
+	) {
+		let mut overrides =
+			overrides.filter(|overrides| !parameter.local && (in_header || !overrides.header));
+
+		for declarator in &parameter.declarators {
+			let declarator = declarator.inner();
+			let name = declarator.name.inner().as_str();
+
+			let assigned = overrides.as_deref_mut().and_then(|overrides| {
+				let ordered = overrides.ordered.get(overrides.position).cloned().flatten();
+				overrides.position += 1;
+
+				overrides
+					.defparams
+					.and_then(|defparams| defparams.get(name))
+					.map(|value| Override::Value(value.clone()))
+					.or(ordered)
+					.or_else(|| overrides.named.get(name).cloned())
+			});
+
+			if parameter.data_type == DataType::Type {
+				let data_type = match assigned {
+					Some(Override::Type(data_type)) => data_type,
+					_ => match declarator.init.as_ref().map(Spanned::inner) {
+						Some(Expression::DataType(data_type)) => {
+							self.evaluator.value_type(data_type)
+						},
+						Some(Expression::Identifier(name)) => {
+							self.evaluator.lookup_type(name.as_str()).flatten()
+						},
+						_ => None,
+					},
+				};
+				self.evaluator.define_type(name, data_type);
+				continue;
+			}
+
+			let value = match assigned {
+				Some(Override::Value(value)) => {
+					Some(self.evaluator.cast_to(&parameter.data_type, value))
+				},
+				_ => declarator
+					.init
+					.as_ref()
+					.and_then(|init| self.evaluator.evaluate_as(&parameter.data_type, init)),
+			};
+
+			if let Some(value) = value {
+				let data_type = self.evaluator.value_type(&parameter.data_type);
+				self.evaluator
+					.define(name, Constant { value: value.clone(), data_type });
+				instance.parameters.push((name.to_string(), value));
+			}
+		}
+	}
+
+	/// Record a `defparam`, relative to the current scope unless it starts with the name of the
+	/// top instance
+	///
+	/// IEEE 1800-2017 § 23.10.1
+	fn defparam(&mut self, lvalue: &Spanned<Expression>, value: &Spanned<Expression>) {
+		let Some(value) = self.evaluator.evaluate(value) else {
+			return;
+		};
+
+		let mut components = Vec::new();
+		if !self.hierarchical_path(lvalue.inner(), &mut components) {
+			self.error("Invalid `defparam` target".to_string(), *lvalue.span());
+			return;
+		}
+		let Some(name) = components.pop() else {
+			return;
+		};
+
+		let path = if components.first() == self.path.first() {
+			components
+		} else {
+			self.path.iter().cloned().chain(components).collect()
+		};
+
+		self.found.push(Defparam {
+			path,
+			name,
+			value,
+			location: Location::new(self.file, (), *lvalue.span()),
+		});
+	}
+
+	/// Flatten a hierarchical name into its components, with any constant bit selects as part
+	/// of the component, e.g. `g[1].u.W`
+	fn hierarchical_path(&mut self, expr: &Expression, components: &mut Vec<String>) -> bool {
+		match expr {
+			Expression::Identifier(name) => {
+				components.push(name.as_str().to_string());
+				true
+			},
+			Expression::Member { target, member } => {
+				let found = self.hierarchical_path(target.inner(), components);
+				components.push(member.inner().as_str().to_string());
+				found
+			},
+			Expression::Select { target, select } => {
+				let Select::Bit(index) = select.inner() else {
+					return false;
+				};
+				if !self.hierarchical_path(target.inner(), components) {
+					return false;
+				}
+				let Some(index) = self
+					.evaluator
+					.evaluate(index)
+					.and_then(|index| index.to_i64())
+				else {
+					return false;
+				};
+				if let Some(last) = components.last_mut() {
+					last.push_str(&format!("[{index}]"));
+				}
+				true
+			},
+			_ => false,
+		}
+	}
+
+	fn instantiation(&mut self, instantiation: &'a Instantiation, parent: &mut Instance) {
+		let definition = instantiation.module.inner().as_str();
+		let module = self.elaborator.definitions.get(definition).copied();
+
+		// Parameter values are evaluated in the scope of the instantiation
+		let mut overrides = Overrides::default();
+		match instantiation.parameters.as_ref().map(Spanned::inner) {
+			Some(Connections::Ordered(values)) => {
+				overrides.ordered = values
+					.iter()
+					.map(|value| value.as_ref().and_then(|value| self.override_value(value)))
+					.collect();
+			},
+			Some(Connections::Named(values)) => {
+				for connection in values {
+					let connection = connection.inner();
+					if let (Some(name), Some(value)) = (&connection.name, &connection.value) &&
+						let Some(value) = self.override_value(value)
+					{
+						overrides
+							.named
+							.insert(name.inner().as_str().to_string(), value);
+					}
+				}
+			},
+			None => (),
+		}
+
+		for instance in &instantiation.instances {
+			// NOTE(aki): Only primitive instances may be unnamed, and can't be referred to
+			let Some(name) = &instance.inner().name else {
+				continue;
+			};
+			let name = name.inner().as_str();
+			let location = Location::new(self.file, (), *instance.span());
+
+			let names = match &instance.inner().range {
+				None => vec![name.to_string()],
+				Some(range) => match self.array_indices(range) {
+					Some(indices) => indices.map(|idx| format!("{name}[{idx}]")).collect(),
+					None => continue,
+				},
+			};
+
+			for name in names {
+				let child = match module {
+					Some((file, module)) if self.path.len() < DEPTH_LIMIT => {
+						self.instantiate(name, file, module, location, overrides.clone())
+					},
+					Some(_) => {
+						self.error(
+							format!("`{name}` is more than {DEPTH_LIMIT} instances deep"),
+							*instance.span(),
+						);
+						continue;
+					},
+					None => Instance {
+						name,
+						kind: if self.elaborator.primitives.contains(definition) {
+							InstanceKind::Primitive(definition.to_string())
+						} else {
+							InstanceKind::Missing(definition.to_string())
+						},
+						location,
+						parameters: Vec::new(),
+						children: Vec::new(),
+					},
+				};
+				parent.children.push(child);
+			}
+		}
+	}
+
+	/// Evaluate a parameter value assignment, which is a type for type parameters
+	fn override_value(&mut self, value: &Spanned<Expression>) -> Option<Override> {
+		match value.inner() {
+			Expression::DataType(data_type) => {
+				Some(Override::Type(self.evaluator.value_type(data_type)))
+			},
+			Expression::Identifier(name) if self.evaluator.lookup_type(name.as_str()).is_some() => {
+				Some(Override::Type(
+					self.evaluator.lookup_type(name.as_str()).flatten(),
+				))
+			},
+			_ => self.evaluator.evaluate(value).map(Override::Value),
+		}
+	}
+
+	/// The indices of an instance array, from the left bound to the right
+	///
+	/// IEEE 1800-2017 § 23.3.3.5
+	fn array_indices(
+		&mut self,
+		range: &Spanned<Dimension>,
+	) -> Option<Box<dyn Iterator<Item = i64>>> {
+		let (left, right) = match range.inner() {
+			Dimension::Range { msb, lsb } => (
+				self.evaluator.evaluate(msb)?.to_i64()?,
+				self.evaluator.evaluate(lsb)?.to_i64()?,
+			),
+			Dimension::Size(size) => (0, self.evaluator.evaluate(size)?.to_i64()? - 1),
+			_ => return None,
+		};
+
+		if left.abs_diff(right) >= ITERATION_LIMIT as u64 {
+			self.error(
+				format!("Instance arrays may have at most {ITERATION_LIMIT} elements"),
+				*range.span(),
+			);
+			return None;
+		}
+
+		Some(if left <= right {
+			Box::new(left..=right)
+		} else {
+			Box::new((right..=left).rev())
+		})
+	}
+
+	/// Unroll a generate loop, each iteration is a generate block with a local parameter holding
+	/// the value of the loop variable
+	///
+	/// IEEE 1800-2017 § 27.4
+	fn generate_for(&mut self, generate: &'a GenerateFor, number: u32, parent: &mut Instance) {
+		let init = generate.init.inner();
+		let Expression::Identifier(genvar) = init.lvalue.inner() else {
+			self.error(
+				"Invalid generate loop variable".to_string(),
+				*init.lvalue.span(),
+			);
+			return;
+		};
+		let genvar = genvar.as_str();
+
+		let Some(value) = self.evaluator.evaluate(&init.value) else {
+			return;
+		};
+
+		self.evaluator.push_scope();
+		self.evaluator.define(
+			genvar,
+			Constant {
+				value:     ValueType::INTEGER.cast(&value),
+				data_type: Some(ValueType::INTEGER),
+			},
+		);
+
+		let mut iterations = 0;
+		while let Some(condition) = self.evaluator.evaluate(&generate.condition) {
+			if condition.truth() != Logic::One {
+				break;
+			}
+
+			iterations += 1;
+			if iterations > ITERATION_LIMIT {
+				self.error(
+					format!("Generate loop did not finish within {ITERATION_LIMIT} iterations"),
+					*generate.condition.span(),
+				);
+				break;
+			}
+
+			let Some(value) = self
+				.evaluator
+				.lookup(genvar)
+				.map(|constant| constant.value.clone())
+			else {
+				break;
+			};
+			let mut child = self.generate_block(
+				generate.block.inner(),
+				number,
+				*generate.block.span(),
+				Some(&value),
+			);
+			child.parameters.insert(0, (genvar.to_string(), value));
+			parent.children.push(child);
+
+			if self.evaluator.evaluate(&generate.step).is_none() {
+				break;
+			}
+		}
+
+		self.evaluator.pop_scope();
+	}
+
+	/// Elaborate the branch of a generate conditional whose condition is true
+	///
+	/// An `else if` is part of the same construct, so its block is numbered the same.
+	///
+	/// IEEE 1800-2017 § 27.5
+	fn generate_if(&mut self, generate: &'a GenerateIf, number: u32, parent: &mut Instance) {
+		let Some(condition) = self.evaluator.evaluate(&generate.condition) else {
+			return;
+		};
+
+		let block = if condition.truth() == Logic::One {
+			&generate.if_true
+		} else {
+			&generate.if_false
+		};
+		let Some(block) = block else {
+			return;
+		};
+
+		if block.inner().name.is_none() &&
+			let [item] = block.inner().items.as_slice()
+		{
+			match &item.inner().kind {
+				ItemKind::GenerateIf(nested) => return self.generate_if(nested, number, parent),
+				ItemKind::GenerateCase(nested) => {
+					return self.generate_case(nested, number, parent);
+				},
+				_ => (),
+			}
+		}
+
+		let child = self.generate_block(block.inner(), number, *block.span(), None);
+		parent.children.push(child);
+	}
+
+	/// Elaborate the first generate case item with a label matching the case expression
+	///
+	/// IEEE 1800-2017 § 27.5
+	fn generate_case(&mut self, generate: &'a GenerateCase, number: u32, parent: &mut Instance) {
+		let Some(value) = self.evaluator.evaluate(&generate.expression) else {
+			return;
+		};
+
+		let mut default = None;
+		let mut selected = None;
+		'items: for item in &generate.items {
+			let item = item.inner();
+			if item.labels.is_empty() {
+				default = Some(&item.block);
+				continue;
+			}
+
+			for label in &item.labels {
+				let Some(label) = self.evaluator.evaluate(label) else {
+					return;
+				};
+				if case_matches(crate::lang::ast::CaseKind::Case, false, &value, &label) {
+					selected = Some(&item.block);
+					break 'items;
+				}
+			}
+		}
+
+		if let Some(Some(block)) = selected.or(default) {
+			let child = self.generate_block(block.inner(), number, *block.span(), None);
+			parent.children.push(child);
+		}
+	}
+
+	/// Elaborate a generate block in a new scope, unnamed blocks are named `genblk<number>`
+	///
+	/// IEEE 1800-2017 § 27.6
+	fn generate_block(
+		&mut self,
+		block: &'a GenerateBlock,
+		number: u32,
+		span: Span,
+		index: Option<&Value>,
+	) -> Instance {
+		let name = block.name.as_ref().map_or_else(
+			|| format!("genblk{number}"),
+			|name| name.inner().as_str().to_string(),
+		);
+		let name = match index {
+			Some(index) => format!("{name}[{index}]"),
+			None => name,
+		};
+
+		let mut instance = Instance {
+			name,
+			kind: InstanceKind::Generate,
+			location: Location::new(self.file, (), span),
+			parameters: Vec::new(),
+			children: Vec::new(),
+		};
+
+		self.path.push(instance.name.clone());
+		self.evaluator.push_scope();
+		let mut constructs = 0;
+		self.items(&block.items, None, &mut instance, &mut constructs);
+		self.evaluator.pop_scope();
+		self.path.pop();
+
+		instance
+	}
+
+	fn error(&mut self, message: String, span: Span) {
+		// NOTE(aki): Keep the diagnostics in source order with the ones from the evaluator
+		self.diagnostics.extend(self.evaluator.take_diagnostics());
+		self.diagnostics.push(Location::new(
+			self.file,
+			StringDiagnostic::new(elaboration_code(self.elaborator.std), message, Some(span)),
+			span,
+		));
+	}
+}
+
+/// Collect the names of the definitions instantiated by the items
+fn collect_instantiated<'a>(items: &'a [Spanned<Item>], names: &mut BTreeSet<&'a str>) {
+	for item in items {
+		match &item.inner().kind {
+			ItemKind::Instantiation(instantiation) => {
+				names.insert(instantiation.module.inner().as_str());
+			},
+			ItemKind::Bind(bind) => {
+				names.insert(bind.instantiation.module.inner().as_str());
+			},
+			ItemKind::GenerateRegion(items) => collect_instantiated(items, names),
+			ItemKind::GenerateBlock(block) => collect_instantiated(&block.items, names),
+			ItemKind::GenerateFor(generate) => {
+				collect_instantiated(&generate.block.inner().items, names)
+			},
+			ItemKind::GenerateIf(generate) => {
+				for block in [&generate.if_true, &generate.if_false]
+					.into_iter()
+					.flatten()
+				{
+					collect_instantiated(&block.inner().items, names);
+				}
+			},
+			ItemKind::GenerateCase(generate) => {
+				for item in &generate.items {
+					if let Some(block) = &item.inner().block {
+						collect_instantiated(&block.inner().items, names);
+					}
+				}
+			},
+			_ => (),
+		}
+	}
+}
+
+/// The diagnostic code for expressions that are not constant in `std`
+fn not_constant_code(std: LanguageStd) -> Code {
+	if LanguageStd::SYSTEM_VERILOG_STDS.contains(std) {
+		system_verilog::E0007
+	} else if LanguageStd::VERILOG_AMS_STDS.contains(std) {
+		verilog_ams::E0006
+	} else {
+		verilog::E0006
+	}
+}
+
+/// The diagnostic code for problems elaborating the design in `std`
+fn elaboration_code(std: LanguageStd) -> Code {
+	if LanguageStd::SYSTEM_VERILOG_STDS.contains(std) {
+		system_verilog::E0008
+	} else if LanguageStd::VERILOG_AMS_STDS.contains(std) {
+		verilog_ams::E0007
+	} else {
+		verilog::E0007
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use vermilion_diagnostics::Diagnostic;
+
+	use super::*;
+	use crate::lang::parser::VerilogParser;
+
+	fn parse(std: LanguageStd, sources: &[&str]) -> Vec<Ast> {
+		sources
+			.iter()
+			.map(|source| {
+				let ast = VerilogParser::new(std, source.as_bytes().into())
+					.unwrap_or_else(|err| panic!("Failed to create parser: {err}"))
+					.parse();
+				assert!(
+					ast.diagnostics().is_empty(),
+					"Unexpected parse errors: {:#?}",
+					ast.diagnostics()
+				);
+				ast
+			})
+			.collect()
+	}
+
+	fn elaborate(std: LanguageStd, asts: &[Ast], top: &str) -> (Instance, Vec<String>) {
+		let mut elaborator = Elaborator::new(std);
+		for (idx, ast) in asts.iter().enumerate() {
+			elaborator.add_file(FileId::new(idx), ast);
+		}
+
+		let root = elaborator
+			.elaborate(top)
+			.unwrap_or_else(|| panic!("No module named `{top}`"));
+		let messages = elaborator
+			.diagnostics()
+			.iter()
+			.map(|diagnostic| diagnostic.inner().message().to_string())
+			.collect();
+
+		(root, messages)
+	}
+
+	/// Evaluate `expr` as the value of a local parameter
+	fn evaluate(std: LanguageStd, expr: &str) -> String {
+		let asts = parse(
+			std,
+			&[&format!("module m;\n\tlocalparam P = {expr};\nendmodule\n")],
+		);
+		let (root, messages) = elaborate(std, &asts, "m");
+		assert!(messages.is_empty(), "Unexpected diagnostics: {messages:#?}");
+
+		root.parameter("P")
+			.unwrap_or_else(|| panic!("`P` was not evaluated"))
+			.to_string()
+	}
+
+	fn names(instance: &Instance) -> Vec<&str> {
+		instance
+			.children
+			.iter()
+			.map(|child| child.name.as_str())
+			.collect()
+	}
+
+	#[test]
+	fn test_literals() {
+		assert_eq!(evaluate(LanguageStd::Vl05, "42"), "42");
+		assert_eq!(evaluate(LanguageStd::Vl05, "8'hff"), "255");
+		assert_eq!(evaluate(LanguageStd::Vl05, "8'shff"), "-1");
+		assert_eq!(evaluate(LanguageStd::Vl05, "4'b1x0z"), "4'b1x0z");
+		assert_eq!(evaluate(LanguageStd::Vl05, "8'bz"), "8'bzzzzzzzz");
+		assert_eq!(
+			evaluate(LanguageStd::Vl05, "'hx"),
+			format!("32'b{}", "x".repeat(32))
+		);
+		assert_eq!(evaluate(LanguageStd::Vl05, "16'd1_000"), "1000");
+		assert_eq!(evaluate(LanguageStd::Vl05, "\"AB\""), "16706");
+		assert_eq!(evaluate(LanguageStd::Vl05, "1.5e3"), "1500.0");
+		assert_eq!(
+			evaluate(
+				LanguageStd::Vl05,
+				"128'hffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff"
+			),
+			"340282366920938463463374607431768211455"
+		);
+	}
+
+	#[test]
+	fn test_arithmetic() {
+		assert_eq!(evaluate(LanguageStd::Vl05, "3 + 4 * 2"), "11");
+		assert_eq!(evaluate(LanguageStd::Vl05, "-7 / 2"), "-3");
+		assert_eq!(evaluate(LanguageStd::Vl05, "-7 % 2"), "-1");
+		assert_eq!(
+			evaluate(LanguageStd::Vl05, "7 / 0"),
+			format!("32'sb{}", "x".repeat(32))
+		);
+		assert_eq!(evaluate(LanguageStd::Vl05, "2 ** 10"), "1024");
+		assert_eq!(evaluate(LanguageStd::Vl05, "2 ** -1"), "0");
+		assert_eq!(evaluate(LanguageStd::Vl05, "4'd15 + 4'd1"), "0");
+		assert_eq!(
+			evaluate(LanguageStd::Vl05, "4'b1x00 + 1"),
+			format!("32'b{}", "x".repeat(32))
+		);
+		assert_eq!(evaluate(LanguageStd::Vl05, "1 << 40"), "0");
+		assert_eq!(evaluate(LanguageStd::Vl05, "64'd1 << 40"), "1099511627776");
+		assert_eq!(evaluate(LanguageStd::Vl05, "-16 >>> 2"), "-4");
+		assert_eq!(evaluate(LanguageStd::Vl05, "8'sb1000_0000 >>> 4"), "-8");
+		assert_eq!(evaluate(LanguageStd::Vl05, "8'b1000_0000 >>> 4"), "8");
+		assert_eq!(evaluate(LanguageStd::Vl05, "1.5 * 2"), "3.0");
+		assert_eq!(
+			evaluate(LanguageStd::Vl05, "100'd1 << 99"),
+			"633825300114114700748351602688"
+		);
+	}
+
+	#[test]
+	fn test_four_state_logic() {
+		assert_eq!(evaluate(LanguageStd::Vl05, "4'b1x0z & 4'b1100"), "4'b1x00");
+		assert_eq!(evaluate(LanguageStd::Vl05, "4'b1x0z | 4'b0011"), "4'b1x11");
+		assert_eq!(evaluate(LanguageStd::Vl05, "~4'b10xz"), "4'b01xx");
+		assert_eq!(evaluate(LanguageStd::Vl05, "&4'b1111"), "1");
+		assert_eq!(evaluate(LanguageStd::Vl05, "|4'b00x0"), "1'bx");
+		assert_eq!(evaluate(LanguageStd::Vl05, "|4'b01x0"), "1");
+		assert_eq!(evaluate(LanguageStd::Vl05, "4'b1x00 == 4'b1x00"), "1'bx");
+		assert_eq!(evaluate(LanguageStd::Vl05, "4'b1x00 == 4'b0x00"), "0");
+		assert_eq!(evaluate(LanguageStd::Vl05, "4'b1x00 === 4'b1x00"), "1");
+		assert_eq!(evaluate(LanguageStd::Sv17, "4'b1100 ==? 4'b1x0z"), "1");
+		assert_eq!(evaluate(LanguageStd::Vl05, "-1 < 0"), "1");
+		assert_eq!(evaluate(LanguageStd::Vl05, "-1 < 32'd0"), "0");
+		assert_eq!(
+			evaluate(LanguageStd::Vl05, "1'bx ? 4'b1100 : 4'b1010"),
+			"4'b1xx0"
+		);
+		assert_eq!(evaluate(LanguageStd::Vl05, "{2'b10, 4'hf}"), "47");
+		assert_eq!(evaluate(LanguageStd::Vl05, "{3{2'b10}}"), "42");
+	}
+
+	#[test]
+	fn test_system_functions() {
+		assert_eq!(evaluate(LanguageStd::Vl05, "$clog2(1)"), "0");
+		assert_eq!(evaluate(LanguageStd::Vl05, "$clog2(256)"), "8");
+		assert_eq!(evaluate(LanguageStd::Vl05, "$clog2(257)"), "9");
+		assert_eq!(evaluate(LanguageStd::Vl05, "$clog2(0)"), "0");
+		assert_eq!(evaluate(LanguageStd::Vl05, "$signed(4'b1111)"), "-1");
+		assert_eq!(evaluate(LanguageStd::Vl05, "$unsigned(-1)"), "4294967295");
+		assert_eq!(evaluate(LanguageStd::Vl05, "$rtoi(2.9)"), "2");
+		assert_eq!(evaluate(LanguageStd::Vl05, "$itor(3)"), "3.0");
+		assert_eq!(evaluate(LanguageStd::Vl05, "$sqrt(16.0)"), "4.0");
+		assert_eq!(evaluate(LanguageStd::Sv17, "$pow(2, 3)"), "8.0");
+		assert_eq!(evaluate(LanguageStd::Sv17, "$bits(logic [7:0])"), "8");
+		assert_eq!(evaluate(LanguageStd::Sv17, "$bits(4'b1010 + 8'd1)"), "8");
+		assert_eq!(evaluate(LanguageStd::Sv17, "$countones(8'b1011_0001)"), "4");
+		assert_eq!(evaluate(LanguageStd::Sv17, "$onehot(8'b0001_0000)"), "1");
+		assert_eq!(evaluate(LanguageStd::Sv17, "$isunknown(4'b10z1)"), "1");
+	}
+
+	#[test]
+	fn test_typed_parameters() {
+		let asts = parse(
+			LanguageStd::Sv17,
+			&["typedef logic [11:0] word_t;\nmodule m;\n\tparameter [3:0] A = \
+			   5'h1f;\n\tparameter signed [7:0] B = 8'hff;\n\tparameter int C = \
+			   2.5;\n\tparameter logic [7:0] D = '1;\n\tparameter real E = 3;\n\tlocalparam F = \
+			   $bits(word_t);\n\tlocalparam G = $left(A);\n\ttypedef enum { RED, GREEN = 5, BLUE \
+			   } color_t;\n\tlocalparam H = BLUE;\nendmodule\n"],
+		);
+		let (root, messages) = elaborate(LanguageStd::Sv17, &asts, "m");
+		assert!(messages.is_empty(), "Unexpected diagnostics: {messages:#?}");
+
+		let values = root
+			.parameters
+			.iter()
+			.map(|(name, value)| format!("{name}={value}"))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			values,
+			["A=15", "B=-1", "C=3", "D=255", "E=3.0", "F=12", "G=3", "H=6"]
+		);
+	}
+
+	#[test]
+	fn test_constant_functions() {
+		let asts = parse(
+			LanguageStd::Sv17,
+			&["package p;\n\tparameter W = 12;\n\tfunction automatic int twice(int \
+			   x);\n\t\treturn 2 * x;\n\tendfunction\nendpackage\nmodule m;\n\timport \
+			   p::*;\n\tfunction integer log2;\n\t\tinput integer value;\n\t\tbegin\n\t\t\tlog2 \
+			   = 0;\n\t\t\twhile (value > 1) begin\n\t\t\t\tvalue = value >> 1;\n\t\t\t\tlog2 = \
+			   log2 + 1;\n\t\t\tend\n\t\tend\n\tendfunction\n\tfunction automatic int sum(int \
+			   n);\n\t\tint total = 0;\n\t\tfor (int i = 1; i <= n; i++) begin\n\t\t\tif (i == \
+			   4) continue;\n\t\t\ttotal += i;\n\t\tend\n\t\treturn \
+			   total;\n\tendfunction\n\tlocalparam A = log2(1024);\n\tlocalparam B = \
+			   sum(5);\n\tlocalparam C = twice(W);\n\tlocalparam D = p::W + 1;\nendmodule\n"],
+		);
+		let (root, messages) = elaborate(LanguageStd::Sv17, &asts, "m");
+		assert!(messages.is_empty(), "Unexpected diagnostics: {messages:#?}");
+
+		assert_eq!(root.parameter("A"), Some(&Value::integer(10)));
+		assert_eq!(
+			root.parameter("B").map(Value::to_string).as_deref(),
+			Some("11")
+		);
+		assert_eq!(
+			root.parameter("C").map(Value::to_string).as_deref(),
+			Some("24")
+		);
+		assert_eq!(
+			root.parameter("D").map(Value::to_string).as_deref(),
+			Some("13")
+		);
+	}
+
+	#[test]
+	fn test_parameter_overrides() {
+		let asts = parse(
+			LanguageStd::Vl05,
+			&[
+				"module top;\n\tleaf #(8, 3) a ();\n\tleaf #(.DEPTH(7)) b ();\n\tleaf c \
+				 ();\n\tdefparam c.WIDTH = 16;\n\tmid d ();\nendmodule\n",
+				"module leaf;\n\tparameter WIDTH = 4;\n\tparameter DEPTH = 2;\n\tlocalparam BITS \
+				 = WIDTH * DEPTH;\nendmodule\nmodule mid;\n\tleaf #(.WIDTH(1)) e ();\n\tdefparam \
+				 top.d.e.DEPTH = 9;\nendmodule\n",
+			],
+		);
+		let (root, messages) = elaborate(LanguageStd::Vl05, &asts, "top");
+		assert!(messages.is_empty(), "Unexpected diagnostics: {messages:#?}");
+
+		let bits = |path: &[&str]| {
+			root.find(path.iter().copied())
+				.and_then(|instance| instance.parameter("BITS"))
+				.map(Value::to_string)
+				.unwrap_or_else(|| panic!("No instance {path:?}"))
+		};
+		assert_eq!(bits(&["a"]), "24");
+		assert_eq!(bits(&["b"]), "28");
+		assert_eq!(bits(&["c"]), "32");
+		assert_eq!(bits(&["d", "e"]), "9");
+	}
+
+	#[test]
+	fn test_parameter_port_list() {
+		let asts = parse(
+			LanguageStd::Sv17,
+			&["module top;\n\tleaf #(5, 6) a ();\n\tleaf #(.T(logic [9:0])) b \
+			   ();\nendmodule\nmodule leaf #(parameter A = 1, type T = byte, parameter B = 2) \
+			   ();\n\tparameter C = 3;\n\tlocalparam W = $bits(T);\nendmodule\n"],
+		);
+		let (root, messages) = elaborate(LanguageStd::Sv17, &asts, "top");
+		assert!(messages.is_empty(), "Unexpected diagnostics: {messages:#?}");
+
+		let values = |name: &str| {
+			root.child(name)
+				.map(|instance| {
+					instance
+						.parameters
+						.iter()
+						.map(|(name, value)| format!("{name}={value}"))
+						.collect::<Vec<_>>()
+				})
+				.unwrap_or_default()
+		};
+		// NOTE: The `type T` parameter is skipped by the ordered assignment of `6` to `B`
+		assert_eq!(values("a"), ["A=5", "B=2", "C=3", "W=8"]);
+		assert_eq!(values("b"), ["A=1", "B=2", "C=3", "W=10"]);
+	}
+
+	#[test]
+	fn test_generate_blocks() {
+		let asts = parse(
+			LanguageStd::Sv17,
+			&[
+				"module top #(parameter N = 3, parameter MODE = 1) ();\n\tgenvar i;\n\tfor (i = \
+				 0; i < N; i = i + 1) begin : lane\n\t\tlocalparam OFFSET = i * 8;\n\t\tleaf u \
+				 ();\n\tend\n\tif (MODE == 0) begin : zero\n\tend else if (MODE == 1) begin : \
+				 one\n\t\tleaf u ();\n\tend else begin\n\tend\n\tcase (N)\n\t\t1, 2: begin : tiny \
+				 end\n\t\tdefault: begin leaf v (); end\n\tendcase\n\tfor (genvar j = 4; j > 0; \
+				 j--) begin\n\tend\n\tleaf arr [1:0] ();\nendmodule\nmodule leaf;\nendmodule\n",
+			],
+		);
+		let (root, messages) = elaborate(LanguageStd::Sv17, &asts, "top");
+		assert!(messages.is_empty(), "Unexpected diagnostics: {messages:#?}");
+
+		assert_eq!(
+			names(&root),
+			[
+				"lane[0]",
+				"lane[1]",
+				"lane[2]",
+				"one",
+				"genblk3",
+				"genblk4[4]",
+				"genblk4[3]",
+				"genblk4[2]",
+				"genblk4[1]",
+				"arr[1]",
+				"arr[0]"
+			]
+		);
+
+		let lane = root
+			.child("lane[2]")
+			.unwrap_or_else(|| panic!("No `lane[2]`"));
+		assert_eq!(lane.kind, InstanceKind::Generate);
+		assert_eq!(lane.parameter("i"), Some(&Value::integer(2)));
+		assert_eq!(lane.parameter("OFFSET"), Some(&Value::integer(16)));
+		assert_eq!(names(lane), ["u"]);
+		assert_eq!(lane.children[0].definition(), Some("leaf"));
+
+		assert_eq!(
+			root.find(["genblk3", "v"]).map(|instance| &instance.kind),
+			Some(&InstanceKind::Module("leaf".to_string()))
+		);
+	}
+
+	#[test]
+	fn test_recursive_generate() {
+		let asts = parse(
+			LanguageStd::Vl05,
+			&["module tree;\n\tparameter DEPTH = 2;\n\tgenerate\n\t\tif (DEPTH > 0) begin : \
+			   node\n\t\t\ttree #(DEPTH - 1) left ();\n\t\t\ttree #(DEPTH - 1) right \
+			   ();\n\t\tend\n\tendgenerate\nendmodule\n"],
+		);
+		let (root, messages) = elaborate(LanguageStd::Vl05, &asts, "tree");
+		assert!(messages.is_empty(), "Unexpected diagnostics: {messages:#?}");
+
+		let leaf = root
+			.find(["node", "right", "node", "left"])
+			.unwrap_or_else(|| panic!("No leaf instance"));
+		assert_eq!(leaf.parameter("DEPTH"), Some(&Value::integer(0)));
+		assert!(leaf.children.is_empty());
+	}
+
+	#[test]
+	fn test_top_modules() {
+		let asts = parse(
+			LanguageStd::Vl05,
+			&[
+				"module a;\n\tb u ();\nendmodule\nmodule b;\nendmodule\n",
+				"module c;\n\tgenerate if (1) begin : g\n\t\tb u ();\n\tend \
+				 endgenerate\nendmodule\n",
+			],
+		);
+		let mut elaborator = Elaborator::new(LanguageStd::Vl05);
+		for (idx, ast) in asts.iter().enumerate() {
+			elaborator.add_file(FileId::new(idx), ast);
+		}
+
+		assert_eq!(elaborator.top_modules(), ["a", "c"]);
+		assert!(elaborator.elaborate("missing").is_none());
+	}
+
+	#[test]
+	fn test_diagnostics() {
+		let source = "module m;\n\twire w;\n\tlocalparam A = w + 1;\n\tlocalparam B = \
+		              $random;\n\tgenvar i;\n\tfor (i = 0; i >= 0; i = i + 1) begin : \
+		              g\n\tend\n\tdefparam u.W = 1;\nendmodule\n";
+		let asts = parse(LanguageStd::Vl05, &[source]);
+		let (root, messages) = elaborate(LanguageStd::Vl05, &asts, "m");
+
+		assert_eq!(
+			messages,
+			[
+				"`w` is not a constant",
+				"`$random` is not a constant system function",
+				"Generate loop did not finish within 65536 iterations",
+				"`defparam` target `m.u.W` was not found",
+			]
+		);
+		assert_eq!(root.children.len(), ITERATION_LIMIT as usize);
+
+		let mut elaborator = Elaborator::new(LanguageStd::Vl05);
+		elaborator.add_file(FileId::new(0usize), &asts[0]);
+		elaborator.elaborate("m");
+		let diagnostic = &elaborator.diagnostics()[0];
+		assert_eq!(diagnostic.inner().code(), verilog::E0006);
+		assert_eq!(
+			*diagnostic.begin() as usize,
+			source
+				.find("w + 1")
+				.unwrap_or_else(|| panic!("Missing expression"))
+		);
+	}
+}
@@ -0,0 +1,1098 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::{
+	cmp::Ordering,
+	fmt::{self, Display},
+};
+
+use crate::lang::{
+	ast::{BinaryOperator, UnaryOperator},
+	types::BaseSpecifier,
+};
+
+const WORD_BITS: u32 = u64::BITS;
+
+/// A single 4-state bit
+///
+/// IEEE 1800-2017 § 6.3.1
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Logic {
+	Zero,
+	One,
+	X,
+	Z,
+}
+
+/// A 4-state bit vector of any width
+///
+/// The bits are stored as two planes using the VPI `aval`/`bval` encoding, where `0` is
+/// `(0, 0)`, `1` is `(1, 0)`, `z` is `(0, 1)`, and `x` is `(1, 1)`.
+///
+/// IEEE 1800-2017 § 38.15
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LogicVec {
+	width:  u32,
+	signed: bool,
+	aval:   Vec<u64>,
+	bval:   Vec<u64>,
+}
+
+/// The value of a constant expression
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+	/// An integral value, string literals are vectors of 8-bit characters
+	Vector(LogicVec),
+	Real(f64),
+}
+
+impl Logic {
+	fn from_planes(aval: bool, bval: bool) -> Self {
+		match (aval, bval) {
+			(false, false) => Self::Zero,
+			(true, false) => Self::One,
+			(false, true) => Self::Z,
+			(true, true) => Self::X,
+		}
+	}
+
+	fn planes(self) -> (bool, bool) {
+		match self {
+			Self::Zero => (false, false),
+			Self::One => (true, false),
+			Self::Z => (false, true),
+			Self::X => (true, true),
+		}
+	}
+
+	pub fn is_known(self) -> bool {
+		matches!(self, Self::Zero | Self::One)
+	}
+
+	pub fn as_char(self) -> char {
+		match self {
+			Self::Zero => '0',
+			Self::One => '1',
+			Self::X => 'x',
+			Self::Z => 'z',
+		}
+	}
+}
+
+impl From<bool> for Logic {
+	fn from(value: bool) -> Self {
+		if value {
+			Self::One
+		} else {
+			Self::Zero
+		}
+	}
+}
+
+impl LogicVec {
+	/// A vector of `width` zero bits, vectors are always at least one bit wide
+	pub fn new(width: u32, signed: bool) -> Self {
+		let width = width.max(1);
+		let words = words(width);
+
+		Self {
+			width,
+			signed,
+			aval: vec![0; words],
+			bval: vec![0; words],
+		}
+	}
+
+	/// A vector of `width` bits all set to `bit`
+	pub fn filled(width: u32, signed: bool, bit: Logic) -> Self {
+		let mut vec = Self::new(width, signed);
+		let (aval, bval) = bit.planes();
+		vec.aval.fill(if aval {
+			u64::MAX
+		} else {
+			0
+		});
+		vec.bval.fill(if bval {
+			u64::MAX
+		} else {
+			0
+		});
+		vec.normalize();
+
+		vec
+	}
+
+	pub fn from_u64(value: u64, width: u32, signed: bool) -> Self {
+		let mut vec = Self::new(width, signed);
+		vec.aval[0] = value;
+		vec.normalize();
+
+		vec
+	}
+
+	pub fn from_i64(value: i64, width: u32, signed: bool) -> Self {
+		let mut vec = Self::new(width, signed);
+		vec.aval[0] = value as u64;
+		if value < 0 {
+			vec.aval[1..].fill(u64::MAX);
+		}
+		vec.normalize();
+
+		vec
+	}
+
+	/// A single unsigned bit
+	pub fn from_logic(bit: Logic) -> Self {
+		Self::filled(1, false, bit)
+	}
+
+	/// Convert a real number to an integral value, rounding away from zero
+	///
+	/// Infinities and NaN have no integral value and convert to all `x` bits.
+	///
+	/// IEEE 1800-2017 § 6.12.1
+	pub fn from_f64(value: f64, width: u32, signed: bool) -> Self {
+		if !value.is_finite() {
+			return Self::filled(width, signed, Logic::X);
+		}
+
+		let mut vec = Self::new(width, signed);
+		let word_scale = 2f64.powi(WORD_BITS as i32);
+		let mut magnitude = value.round().abs();
+		for word in &mut vec.aval {
+			*word = (magnitude % word_scale) as u64;
+			magnitude = (magnitude / word_scale).floor();
+		}
+		if value.round() < 0.0 {
+			vec.aval = negate(&vec.aval);
+		}
+		vec.normalize();
+
+		vec
+	}
+
+	/// The bytes of a string literal, with the first character in the most significant byte
+	///
+	/// IEEE 1800-2017 § 5.9
+	pub fn from_bytes(bytes: &[u8]) -> Self {
+		let width = u32::try_from(bytes.len() * 8).unwrap_or(u32::MAX);
+		let mut vec = Self::new(width, false);
+		for (idx, byte) in bytes.iter().rev().enumerate() {
+			let bit = idx * 8;
+			vec.aval[bit / 64] |= u64::from(*byte) << (bit % 64);
+		}
+
+		vec
+	}
+
+	pub fn width(&self) -> u32 {
+		self.width
+	}
+
+	pub fn is_signed(&self) -> bool {
+		self.signed
+	}
+
+	pub fn with_signed(mut self, signed: bool) -> Self {
+		self.signed = signed;
+		self
+	}
+
+	/// The bit at `idx`, where bit `0` is the least significant, bits past the end are `x`
+	pub fn bit(&self, idx: u32) -> Logic {
+		if idx >= self.width {
+			return Logic::X;
+		}
+
+		let (word, bit) = ((idx / WORD_BITS) as usize, idx % WORD_BITS);
+		Logic::from_planes(
+			(self.aval[word] >> bit) & 1 != 0,
+			(self.bval[word] >> bit) & 1 != 0,
+		)
+	}
+
+	/// Set the bit at `idx`, bits past the end are ignored
+	pub fn set_bit(&mut self, idx: u32, bit: Logic) {
+		if idx >= self.width {
+			return;
+		}
+
+		let (word, offset) = ((idx / WORD_BITS) as usize, idx % WORD_BITS);
+		let (aval, bval) = bit.planes();
+		self.aval[word] = (self.aval[word] & !(1 << offset)) | (u64::from(aval) << offset);
+		self.bval[word] = (self.bval[word] & !(1 << offset)) | (u64::from(bval) << offset);
+	}
+
+	/// Returns true if none of the bits are `x` or `z`
+	pub fn is_known(&self) -> bool {
+		self.bval.iter().all(|word| *word == 0)
+	}
+
+	/// Returns true if the vector is signed and its most significant bit is set
+	pub fn is_negative(&self) -> bool {
+		self.signed && self.bit(self.width - 1) == Logic::One
+	}
+
+	/// The value as an unsigned integer, if it is known and fits
+	pub fn to_u64(&self) -> Option<u64> {
+		if !self.is_known() || self.is_negative() || self.aval[1..].iter().any(|word| *word != 0) {
+			return None;
+		}
+
+		Some(self.aval[0])
+	}
+
+	/// The value as a signed integer, if it is known and fits
+	pub fn to_i64(&self) -> Option<i64> {
+		if !self.is_known() {
+			return None;
+		}
+
+		// NOTE(aki): Extending to whole words keeps the fill in the upper words intact
+		let extended = self.resize(self.width.div_ceil(WORD_BITS).max(1) * WORD_BITS + WORD_BITS);
+		let fill = if self.is_negative() {
+			u64::MAX
+		} else {
+			0
+		};
+		if extended.aval[1..].iter().any(|word| *word != fill) {
+			return None;
+		}
+
+		// NOTE(aki): The sign of the low word has to match the extension for the value to fit
+		let value = extended.aval[0] as i64;
+		((value < 0) == self.is_negative()).then_some(value)
+	}
+
+	/// The value as a real number, with any `x` or `z` bits treated as `0`
+	///
+	/// IEEE 1800-2017 § 6.12.2
+	pub fn to_f64(&self) -> f64 {
+		let mut known = self.clone();
+		for (aval, bval) in known.aval.iter_mut().zip(&mut known.bval) {
+			*aval &= !*bval;
+			*bval = 0;
+		}
+
+		let negative = known.is_negative();
+		known.magnitude().iter().rev().fold(0.0, |acc, word| {
+			acc * 2f64.powi(WORD_BITS as i32) + *word as f64
+		}) * if negative {
+			-1.0
+		} else {
+			1.0
+		}
+	}
+
+	/// Truncate or extend the vector to `width` bits, signed vectors are sign extended
+	pub fn resize(&self, width: u32) -> Self {
+		let mut vec = Self::new(width, self.signed);
+		let shared = vec.aval.len().min(self.aval.len());
+		vec.aval[..shared].copy_from_slice(&self.aval[..shared]);
+		vec.bval[..shared].copy_from_slice(&self.bval[..shared]);
+
+		if vec.width > self.width && self.signed {
+			let fill = self.bit(self.width - 1);
+			if fill != Logic::Zero {
+				for idx in self.width..vec.width {
+					vec.set_bit(idx, fill);
+				}
+			}
+		}
+		vec.normalize();
+
+		vec
+	}
+
+	/// The truth value of the vector, `1` if any bit is `1`, `0` if all bits are `0`, and `x`
+	/// otherwise
+	///
+	/// IEEE 1800-2017 § 12.4
+	pub fn truth(&self) -> Logic {
+		if self
+			.aval
+			.iter()
+			.zip(&self.bval)
+			.any(|(aval, bval)| aval & !bval != 0)
+		{
+			Logic::One
+		} else if self.is_known() {
+			Logic::Zero
+		} else {
+			Logic::X
+		}
+	}
+
+	/// The number of bits that are `bit`
+	pub fn count(&self, bit: Logic) -> u32 {
+		(0..self.width).filter(|idx| self.bit(*idx) == bit).count() as u32
+	}
+
+	/// Concatenate the vectors, the first being the most significant
+	///
+	/// IEEE 1800-2017 § 11.4.12
+	pub fn concat<'a>(parts: impl IntoIterator<Item = &'a Self>) -> Self {
+		let parts = parts.into_iter().collect::<Vec<_>>();
+		let width = parts.iter().map(|part| part.width).sum();
+		let mut vec = Self::new(width, false);
+
+		let mut offset = 0;
+		for part in parts.iter().rev() {
+			for idx in 0..part.width {
+				vec.set_bit(offset + idx, part.bit(idx));
+			}
+			offset += part.width;
+		}
+
+		vec
+	}
+
+	/// Select `width` bits starting at bit `lsb`, bits outside of the vector are `x`
+	///
+	/// IEEE 1800-2017 § 11.5.1
+	pub fn select(&self, lsb: i64, width: u32) -> Self {
+		let mut vec = Self::new(width, false);
+		for idx in 0..vec.width {
+			let bit = u32::try_from(lsb + i64::from(idx))
+				.map(|bit| self.bit(bit))
+				.unwrap_or(Logic::X);
+			vec.set_bit(idx, bit);
+		}
+
+		vec
+	}
+
+	/// Replace the `value.width()` bits starting at bit `lsb`, bits outside of the vector are
+	/// ignored
+	pub fn assign(&mut self, lsb: i64, value: &Self) {
+		for idx in 0..value.width {
+			if let Ok(bit) = u32::try_from(lsb + i64::from(idx)) {
+				self.set_bit(bit, value.bit(idx));
+			}
+		}
+	}
+
+	/// Parse the digits of an unsized decimal number, e.g. `42`, which is a signed integer of at
+	/// least 32 bits
+	///
+	/// IEEE 1800-2017 § 5.7.1
+	pub fn parse_decimal(digits: &str) -> Self {
+		let mut words = vec![0];
+		for digit in digits.bytes().filter(u8::is_ascii_digit) {
+			mul_add(&mut words, 10, u64::from(digit - b'0'));
+		}
+
+		let width = significant_bits(&words) + 1;
+		let mut vec = Self::new(width.max(32), true);
+		let len = vec.aval.len().min(words.len());
+		vec.aval[..len].copy_from_slice(&words[..len]);
+
+		vec
+	}
+
+	/// Parse the size, base, and digits of a based number, e.g. `8'shF0` or `'bx`
+	///
+	/// Unsized numbers are at least 32 bits wide, and numbers are extended with `x` or `z` if
+	/// their most significant digit is `x` or `z`.
+	///
+	/// IEEE 1800-2017 § 5.7.1
+	pub fn parse_based(
+		size: Option<&str>,
+		base: BaseSpecifier,
+		signed: bool,
+		digits: &str,
+	) -> Self {
+		let digits = digits
+			.bytes()
+			.filter(|digit| *digit != b'_')
+			.collect::<Vec<_>>();
+
+		let bits = match base {
+			BaseSpecifier::Decimal => decimal_digits(&digits),
+			_ => {
+				let per_digit = match base {
+					BaseSpecifier::Binary => 1,
+					BaseSpecifier::Octal => 3,
+					_ => 4,
+				};
+
+				let mut bits = Vec::with_capacity(digits.len() * per_digit);
+				for digit in digits.iter().rev() {
+					let (value, fill) = match digit.to_ascii_lowercase() {
+						b'x' => (0, Some(Logic::X)),
+						b'z' | b'?' => (0, Some(Logic::Z)),
+						digit => (char::from(digit).to_digit(16).unwrap_or(0), None),
+					};
+					bits.extend(
+						(0..per_digit)
+							.map(|bit| fill.unwrap_or(Logic::from((value >> bit) & 1 != 0))),
+					);
+				}
+
+				bits
+			},
+		};
+
+		let size = size.and_then(|size| size.replace('_', "").parse::<u32>().ok());
+		let width = size.unwrap_or_else(|| u32::try_from(bits.len()).unwrap_or(u32::MAX).max(32));
+		let fill = match bits.last() {
+			Some(bit @ (Logic::X | Logic::Z)) => *bit,
+			_ => Logic::Zero,
+		};
+
+		let mut vec = Self::filled(width, signed, fill);
+		for (idx, bit) in bits.iter().take(vec.width as usize).enumerate() {
+			vec.set_bit(idx as u32, *bit);
+		}
+
+		vec
+	}
+
+	fn normalize(&mut self) {
+		let used = self.width % WORD_BITS;
+		if used != 0 {
+			let mask = (1 << used) - 1;
+			if let Some(word) = self.aval.last_mut() {
+				*word &= mask;
+			}
+			if let Some(word) = self.bval.last_mut() {
+				*word &= mask;
+			}
+		}
+	}
+
+	/// Apply `op` to the known bits of two vectors of the same width, any `x` or `z` bit in
+	/// either operand makes the whole result `x`
+	fn arithmetic(&self, rhs: &Self, op: impl FnOnce(&[u64], &[u64]) -> Option<Vec<u64>>) -> Self {
+		if !self.is_known() || !rhs.is_known() {
+			return Self::filled(self.width, self.signed, Logic::X);
+		}
+
+		match op(&self.aval, &rhs.aval) {
+			Some(aval) => {
+				let mut vec = Self { aval, ..Self::new(self.width, self.signed) };
+				vec.aval.resize(self.bval.len(), 0);
+				vec.normalize();
+				vec
+			},
+			None => Self::filled(self.width, self.signed, Logic::X),
+		}
+	}
+
+	/// Apply a per-bit operation on the `(known zero, known one)` masks of each word
+	fn bitwise(&self, rhs: &Self, op: impl Fn((u64, u64), (u64, u64)) -> (u64, u64)) -> Self {
+		let mut vec = Self::new(self.width, self.signed);
+		for idx in 0..vec.aval.len() {
+			let lhs = planes_to_masks(self.aval[idx], self.bval[idx]);
+			let rhs = planes_to_masks(rhs.aval[idx], rhs.bval[idx]);
+			let (zero, one) = op(lhs, rhs);
+
+			let unknown = !(zero | one);
+			vec.aval[idx] = one | unknown;
+			vec.bval[idx] = unknown;
+		}
+		vec.normalize();
+
+		vec
+	}
+
+	fn compare(&self, rhs: &Self) -> Ordering {
+		match (self.is_negative(), rhs.is_negative()) {
+			(true, false) => Ordering::Less,
+			(false, true) => Ordering::Greater,
+			_ => compare_words(&self.aval, &rhs.aval),
+		}
+	}
+
+	fn negated(&self) -> Self {
+		self.arithmetic(self, |value, _| Some(negate(value)))
+	}
+
+	/// The absolute value of the known bits, as an unsigned vector of the same width
+	fn magnitude(&self) -> Vec<u64> {
+		if !self.is_negative() {
+			return self.aval.clone();
+		}
+
+		let mut vec = Self {
+			aval: negate(&self.aval),
+			..Self::new(self.width, false)
+		};
+		vec.normalize();
+		vec.aval
+	}
+
+	fn shift(&self, amount: Option<u64>, left: bool, arithmetic: bool) -> Self {
+		let Some(amount) = amount else {
+			return Self::filled(self.width, self.signed, Logic::X);
+		};
+
+		let fill = if arithmetic && !left && self.signed {
+			self.bit(self.width - 1)
+		} else {
+			Logic::Zero
+		};
+
+		let mut vec = Self::filled(self.width, self.signed, fill);
+		for idx in 0..self.width {
+			let source = if left {
+				u64::from(idx).checked_sub(amount)
+			} else {
+				u64::from(idx)
+					.checked_add(amount)
+					.filter(|bit| *bit < u64::from(self.width))
+			};
+			if let Some(source) = source {
+				vec.set_bit(idx, self.bit(source as u32));
+			}
+		}
+
+		vec
+	}
+
+	fn power(&self, exponent: &Self) -> Self {
+		if !self.is_known() || !exponent.is_known() {
+			return Self::filled(self.width, self.signed, Logic::X);
+		}
+
+		if exponent.is_negative() {
+			// NOTE(aki): Only `1` and `-1` have non-zero integral results for negative exponents
+			let one = Self::from_u64(1, self.width, self.signed);
+			return if *self == one {
+				one
+			} else if *self == one.negated() {
+				if exponent.bit(0) == Logic::One {
+					self.clone()
+				} else {
+					one
+				}
+			} else if self.truth() == Logic::Zero {
+				Self::filled(self.width, self.signed, Logic::X)
+			} else {
+				Self::new(self.width, self.signed)
+			};
+		}
+
+		let mut result = Self::from_u64(1, self.width, self.signed);
+		let mut base = self.clone();
+		for idx in 0..exponent.width {
+			if exponent.bit(idx) == Logic::One {
+				result = result.arithmetic(&base, |lhs, rhs| Some(multiply(lhs, rhs)));
+			}
+			base = base.arithmetic(&base, |lhs, rhs| Some(multiply(lhs, rhs)));
+		}
+
+		result
+	}
+}
+
+impl Value {
+	/// A 32-bit signed integer
+	pub fn integer(value: i64) -> Self {
+		Self::Vector(LogicVec::from_i64(value, 32, true))
+	}
+
+	pub fn from_bool(value: bool) -> Self {
+		Self::Vector(LogicVec::from_logic(value.into()))
+	}
+
+	pub fn is_real(&self) -> bool {
+		matches!(self, Self::Real(_))
+	}
+
+	/// The width of the value in bits, reals are 64 bits
+	pub fn width(&self) -> u32 {
+		match self {
+			Self::Vector(vec) => vec.width(),
+			Self::Real(_) => 64,
+		}
+	}
+
+	pub fn is_signed(&self) -> bool {
+		match self {
+			Self::Vector(vec) => vec.is_signed(),
+			Self::Real(_) => true,
+		}
+	}
+
+	pub fn to_f64(&self) -> f64 {
+		match self {
+			Self::Vector(vec) => vec.to_f64(),
+			Self::Real(value) => *value,
+		}
+	}
+
+	pub fn to_i64(&self) -> Option<i64> {
+		match self {
+			Self::Vector(vec) => vec.to_i64(),
+			Self::Real(value) => LogicVec::from_f64(*value, 64, true).to_i64(),
+		}
+	}
+
+	/// The value as a vector, rounding reals to 64-bit signed integers
+	pub fn to_vector(&self) -> LogicVec {
+		match self {
+			Self::Vector(vec) => vec.clone(),
+			Self::Real(value) => LogicVec::from_f64(*value, 64, true),
+		}
+	}
+
+	/// The truth value, see [`LogicVec::truth`]
+	pub fn truth(&self) -> Logic {
+		match self {
+			Self::Vector(vec) => vec.truth(),
+			Self::Real(value) => (*value != 0.0).into(),
+		}
+	}
+
+	/// Apply a unary operator, returning `None` if the operator is not valid for the operand
+	///
+	/// IEEE 1800-2017 § 11.4
+	pub fn unary(operator: UnaryOperator, operand: &Self) -> Option<Self> {
+		let vec = match operand {
+			Self::Real(value) => {
+				return match operator {
+					UnaryOperator::Plus => Some(Self::Real(*value)),
+					UnaryOperator::Minus => Some(Self::Real(-value)),
+					UnaryOperator::LogicalNot => Some(Self::from_bool(*value == 0.0)),
+					_ => None,
+				};
+			},
+			Self::Vector(vec) => vec,
+		};
+
+		let reduce = |op: fn(Logic, Logic) -> Logic, invert: bool| {
+			let bit = (1..vec.width).fold(vec.bit(0), |acc, idx| op(acc, vec.bit(idx)));
+			Self::Vector(LogicVec::from_logic(if invert {
+				not(bit)
+			} else {
+				not(not(bit))
+			}))
+		};
+
+		Some(match operator {
+			UnaryOperator::Plus => operand.clone(),
+			UnaryOperator::Minus => Self::Vector(vec.negated()),
+			UnaryOperator::LogicalNot => Self::Vector(LogicVec::from_logic(not(vec.truth()))),
+			UnaryOperator::BitwiseNot => {
+				Self::Vector(vec.bitwise(vec, |(zero, one), _| (one, zero)))
+			},
+			UnaryOperator::ReductionAnd => reduce(and, false),
+			UnaryOperator::ReductionNand => reduce(and, true),
+			UnaryOperator::ReductionOr => reduce(or, false),
+			UnaryOperator::ReductionNor => reduce(or, true),
+			UnaryOperator::ReductionXor => reduce(xor, false),
+			UnaryOperator::ReductionXnor => reduce(xor, true),
+		})
+	}
+
+	/// Apply a binary operator, returning `None` if the operator is not valid for the operands
+	///
+	/// The operands of arithmetic, bitwise, relational, and equality operators are extended to
+	/// the wider of the two, and are only treated as signed if both are signed. Shifts and
+	/// powers take the width and signedness of their left operand. Any real operand makes the
+	/// operation real.
+	///
+	/// IEEE 1800-2017 § 11.4, § 11.6, § 11.8
+	pub fn binary(operator: BinaryOperator, lhs: &Self, rhs: &Self) -> Option<Self> {
+		use BinaryOperator as Op;
+
+		match operator {
+			Op::LogicalAnd | Op::LogicalOr | Op::LogicalImplication | Op::LogicalEquivalence => {
+				let (lhs, rhs) = (lhs.truth(), rhs.truth());
+				let bit = match operator {
+					Op::LogicalAnd => and(lhs, rhs),
+					Op::LogicalOr => or(lhs, rhs),
+					Op::LogicalImplication => or(not(lhs), rhs),
+					_ => not(xor(lhs, rhs)),
+				};
+				return Some(Self::Vector(LogicVec::from_logic(bit)));
+			},
+			_ => (),
+		}
+
+		if lhs.is_real() || rhs.is_real() {
+			return real_binary(operator, lhs.to_f64(), rhs.to_f64());
+		}
+
+		let (Self::Vector(lhs), Self::Vector(rhs)) = (lhs, rhs) else {
+			return None;
+		};
+
+		match operator {
+			Op::ShiftLeft | Op::ArithmeticShiftLeft => {
+				return Some(Self::Vector(lhs.shift(rhs.to_u64_unsigned(), true, false)));
+			},
+			Op::ShiftRight => {
+				return Some(Self::Vector(lhs.shift(rhs.to_u64_unsigned(), false, false)));
+			},
+			Op::ArithmeticShiftRight => {
+				return Some(Self::Vector(lhs.shift(rhs.to_u64_unsigned(), false, true)));
+			},
+			Op::Power => return Some(Self::Vector(lhs.power(rhs))),
+			_ => (),
+		}
+
+		let signed = lhs.signed && rhs.signed;
+		let width = lhs.width.max(rhs.width);
+		let lhs = lhs.clone().with_signed(signed).resize(width);
+		let rhs = rhs.clone().with_signed(signed).resize(width);
+
+		let bit = |bit: Logic| Some(Self::Vector(LogicVec::from_logic(bit)));
+		let relational = |accept: fn(Ordering) -> bool| {
+			if lhs.is_known() && rhs.is_known() {
+				bit(accept(lhs.compare(&rhs)).into())
+			} else {
+				bit(Logic::X)
+			}
+		};
+
+		match operator {
+			Op::Add => Some(Self::Vector(
+				lhs.arithmetic(&rhs, |lhs, rhs| Some(add(lhs, rhs))),
+			)),
+			Op::Subtract => Some(Self::Vector(
+				lhs.arithmetic(&rhs, |lhs, rhs| Some(add(lhs, &negate(rhs)))),
+			)),
+			Op::Multiply => Some(Self::Vector(
+				lhs.arithmetic(&rhs, |lhs, rhs| Some(multiply(lhs, rhs))),
+			)),
+			Op::Divide | Op::Modulus => {
+				let negative = (lhs.is_negative() != rhs.is_negative(), lhs.is_negative());
+				let (lhs_mag, rhs_mag) = (lhs.magnitude(), rhs.magnitude());
+				Some(Self::Vector(lhs.arithmetic(&rhs, |_, _| {
+					let (quotient, remainder) = divide(&lhs_mag, &rhs_mag)?;
+					Some(match operator {
+						Op::Divide if negative.0 => negate(&quotient),
+						Op::Divide => quotient,
+						_ if negative.1 => negate(&remainder),
+						_ => remainder,
+					})
+				})))
+			},
+			Op::BitwiseAnd => Some(Self::Vector(
+				lhs.bitwise(&rhs, |lhs, rhs| (lhs.0 | rhs.0, lhs.1 & rhs.1)),
+			)),
+			Op::BitwiseOr => Some(Self::Vector(
+				lhs.bitwise(&rhs, |lhs, rhs| (lhs.0 & rhs.0, lhs.1 | rhs.1)),
+			)),
+			Op::BitwiseXor => Some(Self::Vector(lhs.bitwise(&rhs, |lhs, rhs| {
+				(
+					(lhs.0 & rhs.0) | (lhs.1 & rhs.1),
+					(lhs.0 & rhs.1) | (lhs.1 & rhs.0),
+				)
+			}))),
+			Op::BitwiseXnor => Some(Self::Vector(lhs.bitwise(&rhs, |lhs, rhs| {
+				(
+					(lhs.0 & rhs.1) | (lhs.1 & rhs.0),
+					(lhs.0 & rhs.0) | (lhs.1 & rhs.1),
+				)
+			}))),
+			Op::LessThan => relational(Ordering::is_lt),
+			Op::LessThanEqual => relational(Ordering::is_le),
+			Op::GreaterThan => relational(Ordering::is_gt),
+			Op::GreaterThanEqual => relational(Ordering::is_ge),
+			Op::LogicalEquality => bit(equality(&lhs, &rhs, false)),
+			Op::LogicalInequality => bit(not(equality(&lhs, &rhs, false))),
+			Op::WildcardEquality => bit(equality(&lhs, &rhs, true)),
+			Op::WildcardInequality => bit(not(equality(&lhs, &rhs, true))),
+			Op::CaseEquality => bit((lhs == rhs).into()),
+			Op::CaseInequality => bit((lhs != rhs).into()),
+			_ => None,
+		}
+	}
+
+	/// Select between two values with an unknown condition, bits that differ become `x`
+	///
+	/// IEEE 1800-2017 § 11.4.11
+	pub fn merge(lhs: &Self, rhs: &Self) -> Self {
+		match (lhs, rhs) {
+			(Self::Vector(lhs), Self::Vector(rhs)) => {
+				let signed = lhs.signed && rhs.signed;
+				let width = lhs.width.max(rhs.width);
+				let lhs = lhs.clone().with_signed(signed).resize(width);
+				let rhs = rhs.clone().with_signed(signed).resize(width);
+
+				let mut vec = LogicVec::new(width, signed);
+				for idx in 0..width {
+					let bit = lhs.bit(idx);
+					vec.set_bit(
+						idx,
+						if bit == rhs.bit(idx) && bit.is_known() {
+							bit
+						} else {
+							Logic::X
+						},
+					);
+				}
+				Self::Vector(vec)
+			},
+			_ if lhs.to_f64() == rhs.to_f64() => Self::Real(lhs.to_f64()),
+			_ => Self::Real(0.0),
+		}
+	}
+}
+
+impl LogicVec {
+	/// The value as an unsigned shift amount, regardless of the signedness of the vector
+	fn to_u64_unsigned(&self) -> Option<u64> {
+		self.clone().with_signed(false).to_u64().or_else(|| {
+			// NOTE(aki): Known amounts too wide for a u64 shift everything out
+			self.is_known().then_some(u64::MAX)
+		})
+	}
+}
+
+impl Display for Logic {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_char())
+	}
+}
+
+/// Known values are written in decimal, values with `x` or `z` bits as sized binary numbers
+impl Display for LogicVec {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if let Some(value) = self.to_i64() {
+			return write!(f, "{value}");
+		}
+
+		if self.is_known() && !self.is_negative() {
+			let mut digits = Vec::new();
+			let mut words = self.aval.clone();
+			while words.iter().any(|word| *word != 0) {
+				digits.push(div_small(&mut words, 10) as u8 + b'0');
+			}
+			digits.reverse();
+			return write!(f, "{}", String::from_utf8_lossy(&digits));
+		}
+
+		write!(
+			f,
+			"{}'{}b",
+			self.width,
+			if self.signed {
+				"s"
+			} else {
+				""
+			}
+		)?;
+		for idx in (0..self.width).rev() {
+			write!(f, "{}", self.bit(idx))?;
+		}
+
+		Ok(())
+	}
+}
+
+impl Display for Value {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Vector(vec) => vec.fmt(f),
+			Self::Real(value) => write!(f, "{value:?}"),
+		}
+	}
+}
+
+fn words(width: u32) -> usize {
+	width.div_ceil(WORD_BITS) as usize
+}
+
+/// Convert the `aval`/`bval` planes of a word into masks of the known `0` and `1` bits
+fn planes_to_masks(aval: u64, bval: u64) -> (u64, u64) {
+	(!aval & !bval, aval & !bval)
+}
+
+fn not(bit: Logic) -> Logic {
+	match bit {
+		Logic::Zero => Logic::One,
+		Logic::One => Logic::Zero,
+		_ => Logic::X,
+	}
+}
+
+fn and(lhs: Logic, rhs: Logic) -> Logic {
+	match (lhs, rhs) {
+		(Logic::Zero, _) | (_, Logic::Zero) => Logic::Zero,
+		(Logic::One, Logic::One) => Logic::One,
+		_ => Logic::X,
+	}
+}
+
+fn or(lhs: Logic, rhs: Logic) -> Logic {
+	match (lhs, rhs) {
+		(Logic::One, _) | (_, Logic::One) => Logic::One,
+		(Logic::Zero, Logic::Zero) => Logic::Zero,
+		_ => Logic::X,
+	}
+}
+
+fn xor(lhs: Logic, rhs: Logic) -> Logic {
+	if lhs.is_known() && rhs.is_known() {
+		(lhs != rhs).into()
+	} else {
+		Logic::X
+	}
+}
+
+/// `==` and `==?`, for wildcard equality `x` and `z` bits of the right operand match anything
+///
+/// IEEE 1800-2017 § 11.4.5, § 11.4.6
+fn equality(lhs: &LogicVec, rhs: &LogicVec, wildcard: bool) -> Logic {
+	let mut unknown = false;
+	for idx in 0..lhs.width {
+		let (lhs, rhs) = (lhs.bit(idx), rhs.bit(idx));
+		if wildcard && !rhs.is_known() {
+			continue;
+		}
+
+		if !lhs.is_known() || !rhs.is_known() {
+			unknown = true;
+		} else if lhs != rhs {
+			return Logic::Zero;
+		}
+	}
+
+	if unknown {
+		Logic::X
+	} else {
+		Logic::One
+	}
+}
+
+fn real_binary(operator: BinaryOperator, lhs: f64, rhs: f64) -> Option<Value> {
+	use BinaryOperator as Op;
+
+	let bool = |value: bool| Some(Value::from_bool(value));
+	match operator {
+		Op::Add => Some(Value::Real(lhs + rhs)),
+		Op::Subtract => Some(Value::Real(lhs - rhs)),
+		Op::Multiply => Some(Value::Real(lhs * rhs)),
+		Op::Divide => Some(Value::Real(lhs / rhs)),
+		Op::Power => Some(Value::Real(lhs.powf(rhs))),
+		Op::LessThan => bool(lhs < rhs),
+		Op::LessThanEqual => bool(lhs <= rhs),
+		Op::GreaterThan => bool(lhs > rhs),
+		Op::GreaterThanEqual => bool(lhs >= rhs),
+		Op::LogicalEquality => bool(lhs == rhs),
+		Op::LogicalInequality => bool(lhs != rhs),
+		_ => None,
+	}
+}
+
+/// The bits of the digits of a based decimal number, which is either all digits or a single `x`
+/// or `z` digit
+fn decimal_digits(digits: &[u8]) -> Vec<Logic> {
+	match digits.first().map(u8::to_ascii_lowercase) {
+		Some(b'x') => return vec![Logic::X],
+		Some(b'z' | b'?') => return vec![Logic::Z],
+		_ => (),
+	}
+
+	let mut words = vec![0];
+	for digit in digits.iter().filter(|digit| digit.is_ascii_digit()) {
+		mul_add(&mut words, 10, u64::from(digit - b'0'));
+	}
+
+	(0..significant_bits(&words))
+		.map(|idx| Logic::from((words[(idx / WORD_BITS) as usize] >> (idx % WORD_BITS)) & 1 != 0))
+		.collect()
+}
+
+/// The number of bits needed to hold the unsigned value, at least one
+fn significant_bits(words: &[u64]) -> u32 {
+	words.iter().rposition(|word| *word != 0).map_or(1, |idx| {
+		idx as u32 * WORD_BITS + (WORD_BITS - words[idx].leading_zeros())
+	})
+}
+
+/// `words = words * mul + add`, growing `words` as needed
+fn mul_add(words: &mut Vec<u64>, mul: u64, add: u64) {
+	let mut carry = u128::from(add);
+	for word in words.iter_mut() {
+		let value = u128::from(*word) * u128::from(mul) + carry;
+		*word = value as u64;
+		carry = value >> WORD_BITS;
+	}
+	if carry != 0 {
+		words.push(carry as u64);
+	}
+}
+
+/// `words = words / div`, returning the remainder
+fn div_small(words: &mut [u64], div: u64) -> u64 {
+	let mut remainder = 0u128;
+	for word in words.iter_mut().rev() {
+		let value = (remainder << WORD_BITS) | u128::from(*word);
+		*word = (value / u128::from(div)) as u64;
+		remainder = value % u128::from(div);
+	}
+
+	remainder as u64
+}
+
+fn add(lhs: &[u64], rhs: &[u64]) -> Vec<u64> {
+	let mut carry = false;
+	lhs.iter()
+		.zip(rhs)
+		.map(|(lhs, rhs)| {
+			let (sum, overflow_a) = lhs.overflowing_add(*rhs);
+			let (sum, overflow_b) = sum.overflowing_add(u64::from(carry));
+			carry = overflow_a || overflow_b;
+			sum
+		})
+		.collect()
+}
+
+/// The two's complement negation, the caller truncates the result to the vector width
+fn negate(value: &[u64]) -> Vec<u64> {
+	let mut one = vec![0; value.len()];
+	if let Some(word) = one.first_mut() {
+		*word = 1;
+	}
+
+	add(&value.iter().map(|word| !word).collect::<Vec<_>>(), &one)
+}
+
+fn multiply(lhs: &[u64], rhs: &[u64]) -> Vec<u64> {
+	let mut product = vec![0u64; lhs.len()];
+	for (idx, lhs) in lhs.iter().enumerate() {
+		let mut carry = 0u128;
+		for (jdx, rhs) in rhs.iter().enumerate().take(product.len() - idx) {
+			let value =
+				u128::from(product[idx + jdx]) + u128::from(*lhs) * u128::from(*rhs) + carry;
+			product[idx + jdx] = value as u64;
+			carry = value >> WORD_BITS;
+		}
+	}
+
+	product
+}
+
+fn compare_words(lhs: &[u64], rhs: &[u64]) -> Ordering {
+	lhs.iter().rev().cmp(rhs.iter().rev())
+}
+
+/// Unsigned long division, returning `None` when dividing by zero
+fn divide(lhs: &[u64], rhs: &[u64]) -> Option<(Vec<u64>, Vec<u64>)> {
+	if rhs.iter().all(|word| *word == 0) {
+		return None;
+	}
+
+	let mut quotient = vec![0u64; lhs.len()];
+	let mut remainder = vec![0u64; lhs.len()];
+	for idx in (0..lhs.len() as u32 * WORD_BITS).rev() {
+		// remainder = (remainder << 1) | lhs[idx]
+		let mut carry = (lhs[(idx / WORD_BITS) as usize] >> (idx % WORD_BITS)) & 1;
+		for word in &mut remainder {
+			let next = *word >> (WORD_BITS - 1);
+			*word = (*word << 1) | carry;
+			carry = next;
+		}
+
+		if carry != 0 || compare_words(&remainder, rhs).is_ge() {
+			remainder = add(&remainder, &negate(rhs));
+			quotient[(idx / WORD_BITS) as usize] |= 1 << (idx % WORD_BITS);
+		}
+	}
+
+	Some((quotient, remainder))
+}
@@ -0,0 +1,265 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Incremental re-parsing
+//!
+//! When a file is edited, only the top-level descriptions around the edit are re-lexed and
+//! re-parsed, the descriptions before them are kept untouched and the ones after them are only
+//! moved along by the size of the edit. The result is always the same as parsing the whole edited
+//! file from scratch, diagnostics included, if the edit could have changed how anything past the
+//! re-parsed region is parsed, such as by opening a block comment, the region is grown up to the
+//! end of the file.
+//!
+//! Files that use text macros or conditional compilation are always parsed in full, as an edit
+//! anywhere in them can change what the preprocessor makes of the rest of the file.
+
+use std::path::Path;
+
+use vermilion_diagnostics::{Diagnostic, StringDiagnostic};
+use vermilion_lang::AtomicByteTendril;
+use vermilion_loc::{Span, Spanned};
+
+pub(crate) use self::relocate::Relocate;
+use crate::lang::{
+	ast::{Ast, Description},
+	parser::VerilogParser,
+	preprocessor::{Preprocessor, SourceMap, changes_text},
+	tokenizer::token::Token,
+	types::Control,
+};
+
+mod relocate;
+
+/// A change to the text of a file, replacing the bytes from `begin` to `end` with `length` new ones
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TextEdit {
+	/// The offset of the first replaced byte
+	pub begin:  u32,
+	/// The offset just past the last replaced byte, before the edit
+	pub end:    u32,
+	/// The number of bytes that were inserted in place of the replaced ones
+	pub length: u32,
+}
+
+/// How far the syntax after an edit has moved
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Shift {
+	bytes: i64,
+	lines: i64,
+}
+
+impl Shift {
+	fn offset(&self, offset: u32) -> u32 {
+		u32::try_from(i64::from(offset) + self.bytes).unwrap_or_default()
+	}
+
+	fn line(&self, line: u32) -> u32 {
+		u32::try_from(i64::from(line) + self.lines).unwrap_or_default()
+	}
+}
+
+impl Ast {
+	/// Update the AST of a file after `edit` was made to it, `content` being the edited text
+	///
+	/// This gives the same [`Ast`] as parsing all of `content` with [`VerilogParser`], but only
+	/// re-parses the descriptions the edit could have changed. The file is preprocessed with
+	/// `preprocessor` if it has to be parsed in full, as files using text macros always are.
+	pub fn reparse(
+		self,
+		preprocessor: &mut Preprocessor,
+		content: AtomicByteTendril,
+		edit: TextEdit,
+	) -> eyre::Result<Self> {
+		let std = preprocessor.std();
+		let (mut descriptions, diagnostics, mut syntax, source_map) = self.into_parts();
+
+		if descriptions.is_empty() || syntax.tokens().any(|token| changes_text(token.token())) {
+			return parse_in_full(preprocessor, &source_map, content);
+		}
+
+		// NOTE(aki): The description before the edited one is re-parsed too, as how it ends can
+		// depend on the first token of the next one, e.g. the label after `endmodule`.
+		let mut first = descriptions
+			.iter()
+			.rposition(|description| *description.span().begin() <= edit.begin)
+			.map_or(0, |idx| idx.saturating_sub(1));
+
+		// A description that doesn't end properly reports it at the first token of the next one,
+		// so if anything was reported there the description before has to be re-parsed to report
+		// it again
+		while first > 0 && reported_at(&diagnostics, &descriptions[first]) {
+			first -= 1;
+		}
+		let region_begin = match first {
+			0 => Span::new(0, 0, 0, 0),
+			idx => *descriptions[idx].span(),
+		};
+
+		let bytes = i64::from(edit.length) - i64::from(edit.end) + i64::from(edit.begin);
+		let mut tail = descriptions
+			.iter()
+			.position(|description| *description.span().begin() > edit.end);
+
+		let (region, lines) = loop {
+			// Only a description at the start of a line can be kept, as the region has to end
+			// on a newline for us to know nothing in it carries on past its end. Nor can one with
+			// anything reported at its start, which could be about the description before it
+			while let Some(idx) = tail &&
+				(*descriptions[idx].span().character() != 0 ||
+					reported_at(&diagnostics, &descriptions[idx]))
+			{
+				tail = Some(idx + 1).filter(|idx| *idx < descriptions.len());
+			}
+
+			let end = tail.map_or(content.len32(), |idx| {
+				Shift { bytes, lines: 0 }.offset(*descriptions[idx].span().begin())
+			});
+			let span = Span::from_position(*region_begin.begin(), end, region_begin.get_position());
+			let region = VerilogParser::new_at(std, content.clone(), span)?.parse();
+			if region
+				.syntax()
+				.tokens()
+				.any(|token| changes_text(token.token()))
+			{
+				return parse_in_full(preprocessor, &source_map, content);
+			}
+
+			let Some(idx) = tail else {
+				break (region, 0);
+			};
+
+			match region_end(&region) {
+				Some(line) => {
+					break (
+						region,
+						i64::from(line) - i64::from(*descriptions[idx].span().line()),
+					);
+				},
+				None => tail = None,
+			}
+		};
+
+		let shift = Shift { bytes, lines };
+		let tail_begin = tail.map(|idx| *descriptions[idx].span().begin());
+		let (region_descriptions, region_diagnostics, region_syntax, _) = region.into_parts();
+
+		let mut rest = tail.map_or_else(Vec::new, |idx| descriptions.split_off(idx));
+		rest.relocate(&shift);
+		descriptions.truncate(first);
+		descriptions.extend(region_descriptions);
+		descriptions.extend(rest);
+
+		// Diagnostics without a span are sorted first by the parser, and as there is no telling
+		// where they came from the ones the region doesn't report again are kept
+		let (unspanned, spanned) = region_diagnostics
+			.into_iter()
+			.partition::<Vec<_>, _>(|diagnostic| diagnostic.span().is_none());
+
+		let mut unspanned_kept = Vec::new();
+		let mut kept = Vec::new();
+		let mut moved = Vec::new();
+		for diagnostic in diagnostics {
+			let Some(span) = diagnostic.span() else {
+				if !unspanned.contains(&diagnostic) {
+					unspanned_kept.push(diagnostic);
+				}
+				continue;
+			};
+
+			if *span.begin() < *region_begin.begin() {
+				kept.push(diagnostic);
+			} else if let Some(tail) = tail_begin &&
+				*span.begin() >= tail
+			{
+				let mut span = *span;
+				span.relocate(&shift);
+				moved.push(StringDiagnostic::new(
+					diagnostic.code(),
+					diagnostic.message(),
+					Some(span),
+				));
+			}
+		}
+
+		let diagnostics = unspanned_kept
+			.into_iter()
+			.chain(unspanned)
+			.chain(kept)
+			.chain(spanned)
+			.chain(moved)
+			.collect();
+
+		syntax.splice(region_syntax, tail_begin, &shift);
+
+		// NOTE(aki): Files using macros are always parsed in full, so the source map has no
+		// expansions that would need moving
+		Ok(Self::new(descriptions, diagnostics, syntax).with_source_map(source_map))
+	}
+}
+
+/// Whether any of `diagnostics` are reported at the start of `description`
+fn reported_at(diagnostics: &[StringDiagnostic], description: &Spanned<Description>) -> bool {
+	diagnostics.iter().any(|diagnostic| {
+		diagnostic
+			.span()
+			.is_some_and(|span| *span.begin() == *description.span().begin())
+	})
+}
+
+/// Parse all of `content`, preprocessing it as the file at the same path as before the edit
+fn parse_in_full(
+	preprocessor: &mut Preprocessor,
+	source_map: &SourceMap,
+	content: AtomicByteTendril,
+) -> eyre::Result<Ast> {
+	let path = source_map
+		.path(source_map.file())
+		.map(Path::to_path_buf)
+		.unwrap_or_default();
+
+	Ok(VerilogParser::with_preprocessor(preprocessor, path, content)?.parse())
+}
+
+/// The line just past the end of a re-parsed `region`, if nothing in it can carry on past its end
+///
+/// That is the case when the region ends on a newline that is not a line continuation, and the
+/// last description in it parsed without any problems. Otherwise the region could end in the
+/// middle of a comment, a compiler directive, or a description that really goes on further.
+fn region_end(region: &Ast) -> Option<u32> {
+	let last = region
+		.descriptions()
+		.last()
+		.map_or(*region.syntax().span().begin(), |description| {
+			*description.span().begin()
+		});
+
+	if region
+		.descriptions()
+		.last()
+		.is_some_and(|description| matches!(description.inner(), Description::Error)) ||
+		region
+			.diagnostics()
+			.iter()
+			.any(|diagnostic| diagnostic.span().is_none_or(|span| *span.begin() >= last))
+	{
+		return None;
+	}
+
+	let tokens = region.syntax().tokens().collect::<Vec<_>>();
+	let (newline, tokens) = tokens.split_last()?;
+
+	// Whitespace and comments don't break up a line continuation
+	let continued = tokens
+		.iter()
+		.rev()
+		.find(|token| !matches!(token.token(), Token::Whitespace(_) | Token::Comment(_)))
+		.is_some_and(|token| matches!(token.token(), Token::Control(Control::ReverseSolidus)));
+
+	if !matches!(newline.token(), Token::Newline(_)) || continued {
+		return None;
+	}
+
+	Some(newline.span().line() + 1)
+}
+
+#[cfg(test)]
+mod tests;
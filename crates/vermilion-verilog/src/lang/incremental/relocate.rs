@@ -0,0 +1,483 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Moving already parsed syntax along after an edit
+//!
+//! Everything after the re-parsed region of a file is kept as-is, only its byte offsets and lines
+//! are moved by the size of the edit. Columns never change, as the region always ends at the
+//! start of a line.
+
+use vermilion_lang::AtomicByteTendril;
+use vermilion_loc::{Span, Spanned};
+
+use crate::lang::{
+	ast::*,
+	incremental::Shift,
+	tokenizer::token::Token,
+	types::{Identifier, SystemFunc},
+};
+
+/// Something holding spans that can be moved along by a [`Shift`]
+pub(crate) trait Relocate {
+	fn relocate(&mut self, shift: &Shift);
+}
+
+impl Relocate for Span {
+	fn relocate(&mut self, shift: &Shift) {
+		*self = Self::new(
+			shift.offset(*self.begin()),
+			shift.offset(*self.end()),
+			shift.line(*self.line()),
+			*self.character(),
+		);
+	}
+}
+
+impl<T: Relocate> Relocate for Spanned<T> {
+	fn relocate(&mut self, shift: &Shift) {
+		self.span_mut().relocate(shift);
+		self.inner_mut().relocate(shift);
+	}
+}
+
+impl<T: Relocate> Relocate for Vec<T> {
+	fn relocate(&mut self, shift: &Shift) {
+		for value in self {
+			value.relocate(shift);
+		}
+	}
+}
+
+impl<T: Relocate> Relocate for Option<T> {
+	fn relocate(&mut self, shift: &Shift) {
+		if let Some(value) = self {
+			value.relocate(shift);
+		}
+	}
+}
+
+impl<T: Relocate> Relocate for Box<T> {
+	fn relocate(&mut self, shift: &Shift) {
+		(**self).relocate(shift);
+	}
+}
+
+impl<A: Relocate, B: Relocate> Relocate for (A, B) {
+	fn relocate(&mut self, shift: &Shift) {
+		self.0.relocate(shift);
+		self.1.relocate(shift);
+	}
+}
+
+/// Types without any spans inside of them
+macro_rules! relocate_leaves {
+	($($name:ty),* $(,)?) => {
+		$(
+			impl Relocate for $name {
+				fn relocate(&mut self, _: &Shift) {}
+			}
+		)*
+	};
+}
+
+/// Structs, relocating each of the given fields
+macro_rules! relocate_structs {
+	($($name:ident { $($field:ident),* $(,)? }),* $(,)?) => {
+		$(
+			impl Relocate for $name {
+				fn relocate(&mut self, shift: &Shift) {
+					$(self.$field.relocate(shift);)*
+				}
+			}
+		)*
+	};
+}
+
+/// Enums, relocating the fields of each of the given variants, any other variants have no fields
+macro_rules! relocate_enums {
+	($(
+		$name:ident {
+			$($variant:ident $(($($value:ident),*))? $({ $($field:ident),* })?),* $(,)?
+		}
+	),* $(,)?) => {
+		$(
+			impl Relocate for $name {
+				#[allow(unreachable_patterns, reason = "Not every enum has variants without fields")]
+				fn relocate(&mut self, shift: &Shift) {
+					match self {
+						$(
+							Self::$variant $(($($value),*))? $({ $($field,)* .. })? => {
+								$($($value.relocate(shift);)*)?
+								$($($field.relocate(shift);)*)?
+							},
+						)*
+						_ => (),
+					}
+				}
+			}
+		)*
+	};
+}
+
+relocate_leaves! {
+	AssignmentOperator,
+	AtomicByteTendril,
+	BinaryOperator,
+	Identifier,
+	IncDecOperator,
+	Polarity,
+	PropertyOperator,
+	PropertyUnaryOperator,
+	RepetitionKind,
+	SystemFunc,
+	Token,
+	UdpSymbol,
+	UnaryOperator,
+}
+
+relocate_structs! {
+	Nature { name, parent, attributes },
+	NatureAttribute { name, value },
+	Discipline { name, items },
+	DisciplineNet { discipline, data_type, declarators },
+	Branch { terminals, names },
+	ValueRange { bounds },
+	Assertion { label, property, pass, fail },
+	PropertySpec { clock, disable, property },
+	PropertyDeclaration { name, ports, variables, property },
+	SequenceDeclaration { name, ports, variables, sequence },
+	AssertionPort { port_type, name, default },
+	Class { name, parameters, extends, arguments, implements, items },
+	ClassType { path, parameters },
+	ClassItem { item },
+	Constraint { name, items },
+	DistItem { value, weight },
+	CoverGroup { name, ports, event, items },
+	CoverageOption { name, value },
+	Coverpoint { label, expression, iff, bins },
+	Cross { label, items, iff, bins },
+	Bins { name, size, values, with, iff },
+	TransitionItem { values, repetition },
+	Item { attributes, kind },
+	StructType { members, dimensions },
+	StructMember { attributes, data_type, declarators },
+	EnumType { base, members, dimensions },
+	EnumMember { name, range, value },
+	Declarator { name, dimensions, init, ranges },
+	PortDeclaration { attributes, discipline, data_type, declarators },
+	NetDeclaration { data_type, delay, declarators },
+	VariableDeclaration { data_type, declarators },
+	ParameterDeclaration { data_type, declarators },
+	ContinuousAssign { delay, assignments },
+	GateInstantiation { delay, instances },
+	GateInstance { name, range, terminals },
+	Instantiation { module, parameters, instances },
+	Instance { name, range, connections },
+	NamedConnection { attributes, name, value },
+	Task { scope, name, ports, items, body },
+	Function { return_type, scope, name, ports, items, body },
+	GenerateBlock { name, items },
+	GenerateFor { init, condition, step, block },
+	GenerateIf { condition, if_true, if_false },
+	GenerateCase { expression, items },
+	GenerateCaseItem { labels, block },
+	Typedef { data_type, name, dimensions },
+	PackageImport { package, item },
+	ModportDeclaration { name, ports },
+	ModportPort { name, expression },
+	SubroutinePrototype { return_type, name, ports },
+	LetDeclaration { name, ports, expression },
+	TimeUnits { unit, precision },
+	Bind { target, instances, instantiation },
+	ClockingBlock { name, event, items },
+	ClockingSkew { delay },
+	ClockingSignal { name, value },
+	DpiDeclaration { c_name, prototype },
+	Attribute { name, value },
+	Module { attributes, name, imports, parameters, ports, items },
+	Port { name, expression },
+	Package { attributes, name, items },
+	Config { name, design, rules },
+	CellReference { library, cell },
+	ConfigRule { target, action },
+	SpecparamDeclaration { range, assignments },
+	SpecparamAssignment { name, value },
+	PathDeclaration { condition, inputs, outputs, data_source, delays },
+	TimingCheck { name, arguments },
+	TimingCheckEvent { edge, expression, condition },
+	Statement { attributes, label, kind },
+	ForInit { data_type, assignment },
+	Assignment { lvalue, control, value },
+	Case { expression, items },
+	CaseItem { labels, statement },
+	Block { name, items, statements },
+	Delay { values },
+	EventExpression { expression, iff },
+	Udp { attributes, name, ports, declarations, initial, table },
+	UdpInitial { name, value },
+	UdpTableEntry { inputs, current, output },
+}
+
+relocate_enums! {
+	DisciplineItem {
+		Binding { nature },
+		Attribute { attribute },
+	},
+	BranchTerminal {
+		Net(v0),
+		Port(v0),
+	},
+	ValueRangeBounds {
+		Interval { low, high },
+		Value(v0),
+	},
+	PropertyExpression {
+		Expression(v0),
+		Delay { lhs, delay, rhs },
+		Repetition { operand, range },
+		Binary { operator, lhs, rhs },
+		Unary { operator, range, operand },
+		Clocked { clock, property },
+		If { condition, if_true, if_false },
+		Abort { condition, property },
+		Parenthesized(v0),
+	},
+	CycleRange {
+		Single(v0),
+		Range { min, max },
+	},
+	AssertionPortType {
+		Data(v0),
+	},
+	ConstraintItem {
+		Expression { expression },
+		Dist { expression, items },
+		Implication { condition, constraints },
+		If { condition, if_true, if_false },
+		Foreach { array, variables, constraints },
+		SolveBefore { solve, before },
+		Unique(v0),
+		DisableSoft(v0),
+	},
+	DistWeight {
+		PerValue(v0),
+		PerRange(v0),
+	},
+	CoverageEvent {
+		Clocking(v0),
+		Sample(v0),
+	},
+	CoverageItem {
+		Option(v0),
+		Coverpoint(v0),
+		Cross(v0),
+	},
+	BinsItem {
+		Option(v0),
+		Bins(v0),
+	},
+	BinsValues {
+		Set(v0),
+		Transitions(v0),
+		Select(v0),
+	},
+	BinsSelect {
+		BinsOf { target, intersect },
+		Not(v0),
+		And(v0, v1),
+		Or(v0, v1),
+		Parenthesized(v0),
+	},
+	Expression {
+		Member { target, member },
+		Select { target, select },
+		Concatenation(v0),
+		Replication { count, items },
+		Call { target, attributes, arguments },
+		SystemCall { name, arguments },
+		Unary { operator, attributes, operand },
+		Binary { operator, attributes, lhs, rhs },
+		Conditional { attributes, condition, if_true, if_false },
+		MinTypMax { min, typ, max },
+		Parenthesized(v0),
+		Assign { lvalue, operator, value },
+		IncDec { operator, operand },
+		Scope { scope, member },
+		Cast { target, value },
+		AssignmentPattern { data_type, items },
+		Inside { expression, set },
+		ValueRange { low, high },
+		ToleranceRange { center, tolerance },
+		Streaming { slice, items },
+		New { size, arguments },
+		DataType(v0),
+		With { target, expression },
+		RandomizeWith { call, constraints },
+	},
+	CastTarget {
+		Type(v0),
+		Expression(v0),
+	},
+	PatternItem {
+		Positional(v0),
+		Keyed { key, value },
+		Default(v0),
+	},
+	Select {
+		Bit(v0),
+		Range { msb, lsb },
+		IndexedUp { base, width },
+		IndexedDown { base, width },
+	},
+	ItemKind {
+		Port(v0),
+		Net(v0),
+		Variable(v0),
+		Parameter(v0),
+		Specparam(v0),
+		Genvar(v0),
+		Defparam(v0),
+		ContinuousAssign(v0),
+		Gate(v0),
+		Instantiation(v0),
+		Initial(v0),
+		Always(v0),
+		Task(v0),
+		Function(v0),
+		GenerateRegion(v0),
+		GenerateFor(v0),
+		GenerateIf(v0),
+		GenerateCase(v0),
+		GenerateBlock(v0),
+		Specify(v0),
+		AlwaysComb(v0),
+		AlwaysFF(v0),
+		AlwaysLatch(v0),
+		Final(v0),
+		Typedef(v0),
+		Import(v0),
+		Export(v0),
+		Modport(v0),
+		Class(v0),
+		Constraint(v0),
+		Prototype(v0),
+		Assertion(v0),
+		Property(v0),
+		Sequence(v0),
+		Let(v0),
+		CoverGroup(v0),
+		Clocking(v0),
+		DefaultClocking(v0),
+		DefaultDisable(v0),
+		TimeUnits(v0),
+		Bind(v0),
+		Dpi(v0),
+		DisciplineNet(v0),
+		Ground(v0),
+		Branch(v0),
+		Analog(v0),
+		AnalogInitial(v0),
+		AnalogFunction(v0),
+	},
+	DataType {
+		Implicit { packed },
+		IntegerVector { packed },
+		Struct(v0),
+		Enum(v0),
+		Named { path, parameters, packed },
+		VirtualInterface { name, parameters, modport },
+		Interface { name, modport },
+	},
+	Dimension {
+		Range { msb, lsb },
+		Size(v0),
+		Queue(v0),
+		Associative(v0),
+	},
+	Connections {
+		Ordered(v0),
+		Named(v0),
+	},
+	ClockingItem {
+		Default { input, output },
+		Signals { input_skew, output_skew, signals },
+		Item(v0),
+	},
+	Description {
+		Module(v0),
+		Primitive(v0),
+		Config(v0),
+		Package(v0),
+		Class(v0),
+		Item(v0),
+		Nature(v0),
+		Discipline(v0),
+	},
+	Ports {
+		NonAnsi(v0),
+		Ansi(v0),
+	},
+	ConfigRuleTarget {
+		Instance(v0),
+		Cell(v0),
+	},
+	ConfigRuleAction {
+		LibList(v0),
+		Use(v0),
+	},
+	SpecifyItem {
+		Specparam(v0),
+		PulseStyle { outputs },
+		ShowCancelled { outputs },
+		Path(v0),
+		TimingCheck(v0),
+	},
+	SpecparamValue {
+		Value(v0),
+		PulseControl { reject, error },
+	},
+	PathCondition {
+		If(v0),
+	},
+	TimingCheckEdge {
+		Descriptors(v0),
+	},
+	StatementKind {
+		Blocking(v0),
+		NonBlocking(v0),
+		ProceduralAssign(v0),
+		Deassign(v0),
+		Force(v0),
+		Release(v0),
+		Case(v0),
+		If { condition, if_true, if_false },
+		Disable(v0),
+		EventTrigger(v0),
+		Forever(v0),
+		Repeat { count, body },
+		While { condition, body },
+		For { init, condition, step, body },
+		Block(v0),
+		TimingControl { control, statement },
+		SystemTaskEnable { name, arguments },
+		TaskEnable { name, arguments },
+		Wait { condition, statement },
+		Expression(v0),
+		DoWhile { body, condition },
+		Foreach { array, variables, body },
+		Return(v0),
+		EventTriggerNb { control, name },
+		Assertion(v0),
+		Contribution { target, value },
+		IndirectContribution { target, equation },
+	},
+	TimingControl {
+		Delay(v0),
+		Event(v0),
+		Repeat { count, event },
+		Cycle(v0),
+	},
+	EventControl {
+		Identifier(v0),
+		Expression(v0),
+	},
+}
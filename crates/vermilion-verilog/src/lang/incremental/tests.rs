@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+use crate::LanguageStd;
+
+const SOURCE: &str = "// A few modules
+`timescale 1ns / 1ps
+
+module first(input a, output b);
+	assign b = a;
+endmodule
+
+module second #(parameter WIDTH = 8) (
+	input  [WIDTH - 1:0] data,
+	output               valid
+);
+	wire [WIDTH - 1:0] inverted = ~data;
+	assign valid = |inverted;
+endmodule : second
+
+module third;
+	first u_first(.a(1'b0), .b());
+	second #(.WIDTH(4)) u_second(.data(4'h0), .valid());
+endmodule
+
+package fourth;
+	typedef logic [3:0] nibble_t;
+endpackage
+";
+
+fn parse(source: &str) -> Ast {
+	VerilogParser::new(LanguageStd::Sv17, source.as_bytes().into())
+		.unwrap_or_else(|err| panic!("Failed to create parser: {err}"))
+		.parse()
+}
+
+/// Replace the first `find` in `source` with `replace`, returning the edited source and the edit
+fn edit(source: &str, find: &str, replace: &str) -> (String, TextEdit) {
+	let Some(begin) = source.find(find) else {
+		panic!("`{find}` not found in the source");
+	};
+
+	let edited = format!(
+		"{}{replace}{}",
+		&source[..begin],
+		&source[begin + find.len()..]
+	);
+	let edit = TextEdit {
+		begin:  begin as u32,
+		end:    (begin + find.len()) as u32,
+		length: replace.len() as u32,
+	};
+
+	(edited, edit)
+}
+
+/// Apply each of the `edits` to `source` one after another, checking the re-parsed AST matches
+/// a full parse of the edited source after each of them
+fn check(source: &str, edits: &[(&str, &str)]) {
+	let mut source = source.to_string();
+	let mut ast = parse(&source);
+
+	for (find, replace) in edits {
+		let (edited, edit) = edit(&source, find, replace);
+
+		ast = ast
+			.reparse(
+				&mut Preprocessor::new(LanguageStd::Sv17),
+				edited.as_bytes().into(),
+				edit,
+			)
+			.unwrap_or_else(|err| panic!("Failed to re-parse: {err}"));
+		assert_eq!(
+			ast,
+			parse(&edited),
+			"after replacing `{find}` with `{replace}`"
+		);
+		assert_eq!(ast.syntax().text(), edited.as_bytes());
+
+		source = edited;
+	}
+}
+
+#[test]
+fn test_edit_in_description() {
+	check(SOURCE, &[("~data", "data ^ 8'hff"), ("u_first", "u_one")]);
+}
+
+#[test]
+fn test_edit_lines() {
+	check(
+		SOURCE,
+		&[
+			("assign b = a;", "assign b = a;\n\twire c;\n\twire d;"),
+			("\twire [WIDTH - 1:0] inverted = ~data;\n", ""),
+		],
+	);
+}
+
+#[test]
+fn test_edit_first_and_last() {
+	check(
+		SOURCE,
+		&[
+			("// A few", "/* Some */ // A few"),
+			("nibble_t", "byte_t"),
+			("endpackage\n", "endpackage\n\nmodule fifth;\nendmodule\n"),
+		],
+	);
+}
+
+#[test]
+fn test_edit_descriptions() {
+	check(
+		SOURCE,
+		&[
+			("module third;", "module extra;\nendmodule\n\nmodule third;"),
+			("module extra;\nendmodule\n\n", ""),
+			("endmodule : second", "endmodule"),
+			(
+				"endmodule\n\nmodule third",
+				"endmodule : second\n\nmodule third",
+			),
+		],
+	);
+}
+
+#[test]
+fn test_edit_errors() {
+	check(
+		SOURCE,
+		&[
+			("endmodule : second", ""),
+			("assign valid", "assign valid = "),
+			("assign valid = ", "assign valid"),
+			("\n\nmodule third", "endmodule\n\nmodule third"),
+		],
+	);
+}
+
+#[test]
+fn test_edit_trivia() {
+	check(
+		SOURCE,
+		&[
+			("module third;", "/*\nmodule third;"),
+			("/*\n", ""),
+			("module third;", "`define THIRD \\\nmodule third;"),
+			("`define THIRD \\\n", "// \\\n"),
+		],
+	);
+}
+
+/// Apply `edits` to `source` as in [`check`], checking the diagnostics of the re-parsed AST
+/// match those of a full parse after each of them
+fn check_diagnostics(source: &str, edits: &[(&str, &str)]) {
+	let mut source = source.to_string();
+	let mut ast = parse(&source);
+
+	for (find, replace) in edits {
+		let (edited, edit) = edit(&source, find, replace);
+
+		ast = ast
+			.reparse(
+				&mut Preprocessor::new(LanguageStd::Sv17),
+				edited.as_bytes().into(),
+				edit,
+			)
+			.unwrap_or_else(|err| panic!("Failed to re-parse: {err}"));
+		assert_eq!(
+			ast.diagnostics(),
+			parse(&edited).diagnostics(),
+			"after replacing `{find}` with `{replace}`"
+		);
+
+		source = edited;
+	}
+}
+
+#[test]
+fn test_edit_diagnostics() {
+	let source = "module a;\n\nmodule b;\nendmodule\n\nmodule c;\n\twire x;\nendmodule\n";
+
+	// The missing `endmodule` of `a` is reported at the start of `b`, which is re-parsed along with
+	// `c` but can't report it itself
+	check_diagnostics(source, &[("wire x;", "wire x, y;"), ("wire x, y;", "wire x;")]);
+
+	// Fixing the description before the one after the edit drops what it reported there
+	check_diagnostics(
+		source,
+		&[("module a;\n", "module a;\nendmodule\n"), ("wire x;", "wire z;")],
+	);
+}
@@ -2403,6 +2403,7 @@ pub static VERILOG_AMS_09_KEYWORD_MAP: phf::Map<&'static str, Keyword> = phf_map
 	"min" => Keyword::Min,
 	"module" => Keyword::Module,
 	"nand" => Keyword::Nand,
+	"nature" => Keyword::Nature,
 	"negedge" => Keyword::NegEdge,
 	"net_resolution" => Keyword::NetResolution,
 	"nmos" => Keyword::Nmos,
@@ -2536,7 +2537,7 @@ pub static VERILOG_AMS_09_KEYWORD_SET: phf::Set<&'static str> = phf_set! {
 	"cosh",          "function",        "net_resolution", "specify",             "zi_np",
 	"cross",         "generate",        "nmos",           "specparam",           "zi_zd",
 	"ddt_nature",    "genvar",          "noise_table",    "split",               "zi_zp",
-	"ddt",           "ground",          "nor",            "sqrt",
+	"ddt",           "ground",          "nor",            "sqrt",                "nature",
 };
 
 /// Verilog-AMS 2.4.0 (Verilog-AMS 2014) Keyword to [`Keyword`] token map
@@ -2663,6 +2664,7 @@ pub static VERILOG_AMS_14_KEYWORD_MAP: phf::Map<&'static str, Keyword> = phf_map
 	"min" => Keyword::Min,
 	"module" => Keyword::Module,
 	"nand" => Keyword::Nand,
+	"nature" => Keyword::Nature,
 	"negedge" => Keyword::NegEdge,
 	"net_resolution" => Keyword::NetResolution,
 	"nmos" => Keyword::Nmos,
@@ -2798,7 +2800,7 @@ pub static VERILOG_AMS_14_KEYWORD_SET: phf::Set<&'static str> = phf_set! {
 	"cosh",          "generate",        "noise_table_log", "split",
 	"cross",         "genvar",          "noise_table",     "sqrt",
 	"ddt_nature",    "ground",          "nor",             "string",
-	"ddt",           "highz0",          "noshowcancelled", "strong0",
+	"ddt",           "highz0",          "noshowcancelled", "strong0",             "nature",
 };
 
 /// Verilog-AMS 2023 Keyword to [`Keyword`] token map
@@ -2928,6 +2930,7 @@ pub static VERILOG_AMS_23_KEYWORD_MAP: phf::Map<&'static str, Keyword> = phf_map
 	"min" => Keyword::Min,
 	"module" => Keyword::Module,
 	"nand" => Keyword::Nand,
+	"nature" => Keyword::Nature,
 	"negedge" => Keyword::NegEdge,
 	"nmos" => Keyword::Nmos,
 	"noise_table_log" => Keyword::NoiseTableLog,
@@ -3063,7 +3066,7 @@ pub static VERILOG_AMS_23_KEYWORD_SET: phf::Set<&'static str> = phf_set! {
 	"cos",           "from",            "negedge",         "small",               "zi_np",
 	"cosh",          "function",        "nmos",            "specify",             "zi_zd",
 	"cross",         "generate",        "noise_table_log", "specparam",           "zi_zp",
-	"ddt_nature",    "genvar",          "noise_table",     "split",
+	"ddt_nature",    "genvar",          "noise_table",     "split",               "nature",
 };
 
 /// Get the Verilog 1995 keyword for the given identifier if it exists
@@ -3371,350 +3374,357 @@ pub fn is_keyword(ident: &str) -> LanguageStd {
 	supported
 }
 
+impl Keyword {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Above => "above",           // Added: Verilog-AMS 2009
+			Self::Abs => "abs",               // Added: Verilog-AMS 2009
+			Self::AbsDelay => "absdelay",     // Added: Verilog-AMS 2009
+			Self::AbsDelta => "absdelta",     // Added: Verilog-AMS 2014
+			Self::AbsTol => "abstol",         // Added: Verilog-AMS 2009
+			Self::AcceptOn => "accept_on",    // Added: IEEE 1800-2009
+			Self::Access => "access",         // Added: Verilog-AMS 2009
+			Self::Acos => "acos",             // Added: Verilog-AMS 2009
+			Self::Acosh => "acosh",           // Added: Verilog-AMS 2009
+			Self::AcStim => "ac_stim",        // Added: Verilog-AMS 2009
+			Self::Alias => "alias",           // Added: IEEE 1800-2005
+			Self::Aliasparam => "aliasparam", // Added: Verilog-AMS 2009
+			Self::Always => "always",
+			Self::AlwaysComb => "always_comb",   // Added: IEEE 1800-2005
+			Self::AlwaysFF => "always_ff",       // Added: IEEE 1800-2005
+			Self::AlwaysLatch => "always_latch", // Added: IEEE 1800-2005
+			Self::Analog => "analog",            // Added: Verilog-AMS 2009
+			Self::Analysis => "analysis",        // Added: Verilog-AMS 2009
+			Self::And => "and",
+			Self::Asin => "asin",     // Added: Verilog-AMS 2009
+			Self::Asinh => "asinh",   // Added: Verilog-AMS 2009
+			Self::Assert => "assert", // Added: IEEE 1800-2005 & Verilog-AMS 2009-2014
+			Self::Assign => "assign",
+			Self::Assume => "assume",       // Added: IEEE 1800-2005
+			Self::Atan => "atan",           // Added: Verilog-AMS 2009
+			Self::Atan2 => "atan2",         // Added: Verilog-AMS 2009
+			Self::Atanh => "atanh",         // Added: Verilog-AMS 2009
+			Self::Automatic => "automatic", // Added: IEEE 1364-2001
+			Self::Before => "before",       // Added: IEEE 1800-2005
+			Self::Begin => "begin",
+			Self::Bind => "bind",     // Added: IEEE 1800-2005
+			Self::Bins => "bins",     // Added: IEEE 1800-2005
+			Self::BinsOf => "binsof", // Added: IEEE 1800-2005
+			Self::Bit => "bit",       // Added: IEEE 1800-2005
+			Self::Branch => "branch", // Added: Verilog-AMS 2009
+			Self::Break => "break",   // Added: IEEE 1800-2005 & Verilog-AMS 2023
+			Self::Buf => "buf",
+			Self::BufIf0 => "bufif0",
+			Self::BufIf1 => "bufif1",
+			Self::Byte => "byte", // Added: IEEE 1800-2005
+			Self::Case => "case",
+			Self::CaseX => "casex",
+			Self::CaseZ => "casez",
+			Self::Ceil => "ceil",         // Added: Verilog-AMS 2009
+			Self::Cell => "cell",         // Added: IEEE 1364-2001
+			Self::CHandle => "chandle",   // Added: IEEE 1800-2005
+			Self::Checker => "checker",   // Added: IEEE 1800-2009
+			Self::Class => "class",       // Added: IEEE 1800-2005
+			Self::Clocking => "clocking", // Added: IEEE 1800-2005
+			Self::Cmos => "cmos",
+			Self::Config => "config",               // Added: IEEE 1364-2001
+			Self::Connect => "connect",             // Added: Verilog-AMS 2009
+			Self::ConnectModule => "connectmodule", // Added: Verilog-AMS 2009
+			Self::ConnectRules => "connectrules",   // Added: Verilog-AMS 2009
+			Self::Const => "const",                 // Added: IEEE 1800-2005
+			Self::Constraint => "constraint",       // Added: IEEE 1800-2005
+			Self::Context => "context",             // Added: IEEE 1800-2005
+			Self::Continue => "continue",           // Added: IEEE 1800-2005 & Verilog-AMS 2023
+			Self::Continuous => "continuous",       // Added: Verilog-AMS 2009
+			Self::Cos => "cos",                     // Added: Verilog-AMS 2009
+			Self::Cosh => "cosh",                   // Added: Verilog-AMS 2009
+			Self::Cover => "cover",                 // Added: IEEE 1800-2005
+			Self::CoverGroup => "covergroup",       // Added: IEEE 1800-2005
+			Self::CoverPoint => "coverpoint",       // Added: IEEE 1800-2005
+			Self::Cross => "cross",                 // Added: IEEE 1800-2005 & Verilog-AMS 2009
+			Self::Ddt => "ddt",                     // Added: Verilog-AMS 2009
+			Self::DdtNature => "ddt_nature",        // Added: Verilog-AMS 2009
+			Self::Ddx => "ddx",                     // Added: Verilog-AMS 2009
+			Self::Deassign => "deassign",
+			Self::Default => "default",
+			Self::DefParam => "defparam",
+			Self::Design => "design", // Added: IEEE 1364-2001
+			Self::Disable => "disable",
+			Self::Discipline => "discipline",      // Added: Verilog-AMS 2009
+			Self::Discrete => "discrete",          // Added: Verilog-AMS 2009
+			Self::Dist => "dist",                  // Added: IEEE 1800-2005
+			Self::Do => "do",                      // Added: IEEE 1800-2005
+			Self::Domain => "domain",              // Added: Verilog-AMS 2009
+			Self::DriverUpdate => "driver_update", // Added: Verilog-AMS 2009
+			Self::Edge => "edge",
+			Self::Else => "else",
+			Self::End => "end",
+			Self::EndCase => "endcase",
+			Self::EndChecker => "endchecker",   // Added: IEEE 1800-2009
+			Self::EndClass => "endclass",       // Added: IEEE 1800-2005
+			Self::EndClocking => "endclocking", // Added: IEEE 1800-2005
+			Self::EndConfig => "endconfig",     // Added: IEEE 1364-2001
+			Self::EndConnectRules => "endconnectrules", // Added: Verilog-AMS 2009
+			Self::EndDiscipline => "enddiscipline", // Added: Verilog-AMS 2009
+			Self::EndFunction => "endfunction",
+			Self::EndGenerate => "endgenerate",   // Added: IEEE 1364-2001
+			Self::EndGroup => "endgroup",         // Added: IEEE 1800-2005
+			Self::EndInterface => "endinterface", // Added: IEEE 1800-2005
+			Self::EndModule => "endmodule",
+			Self::EndNature => "endnature",     // Added: Verilog-AMS 2009
+			Self::EndPackage => "endpackage",   // Added: IEEE 1800-2005
+			Self::EndParamSet => "endparamset", // Added: Verilog-AMS 2009
+			Self::EndPrimitive => "endprimitive",
+			Self::EndProgram => "endprogram",   // Added: IEEE 1800-2005
+			Self::EndProperty => "endproperty", // Added: IEEE 1800-2005
+			Self::EndSequence => "endsequence", // Added: IEEE 1800-2005
+			Self::EndSpecify => "endspecify",
+			Self::EndTable => "endtable",
+			Self::EndTask => "endtask",
+			Self::Enum => "enum", // Added: IEEE 1800-2005
+			Self::Event => "event",
+			Self::Eventually => "eventually",      // Added: IEEE 1800-2009
+			Self::Exclude => "exclude",            // Added: Verilog-AMS 2009
+			Self::Exp => "exp",                    // Added: Verilog-AMS 2009
+			Self::Expect => "expect",              // Added: IEEE 1800-2005
+			Self::Expm1 => "expm1",                // Added:Verilog-AMS 2023
+			Self::Export => "export",              // Added: IEEE 1800-2005
+			Self::Extends => "extends",            // Added: IEEE 1800-2005
+			Self::Extern => "extern",              // Added: IEEE 1800-2005
+			Self::Final => "final",                // Added: IEEE 1800-2005
+			Self::FinalStep => "final_step",       // Added: Verilog-AMS 2009
+			Self::FirstMatch => "first_match",     // Added: IEEE 1800-2005
+			Self::FlickerNoise => "flicker_noise", // Added: Verilog-AMS 2009
+			Self::Floor => "floor",                // Added: Verilog-AMS 2009
+			Self::Flow => "flow",                  // Added: Verilog-AMS 2009
+			Self::For => "for",
+			Self::Force => "force",
+			Self::ForEach => "foreach", // Added: IEEE 1800-2005
+			Self::Forever => "forever",
+			Self::Fork => "fork",
+			Self::ForkJoin => "forkjoin", // Added: IEEE 1800-2005
+			Self::From => "from",         // Added: Verilog-AMS 2009
+			Self::Function => "function",
+			Self::Generate => "generate", // Added: IEEE 1364-2001
+			Self::Genvar => "genvar",     // Added: IEEE 1364-2001
+			Self::Global => "global",     // Added: IEEE 1800-2009
+			Self::Ground => "ground",     // Added: Verilog-AMS 2009
+			Self::HighZ0 => "highz0",
+			Self::HighZ1 => "highz1",
+			Self::Hypot => "hypot",          // Added: Verilog-AMS 2009
+			Self::Idt => "idt",              // Added: Verilog-AMS 2009
+			Self::IdtMod => "idtmod",        // Added: Verilog-AMS 2009
+			Self::IdtNature => "idt_nature", // Added: Verilog-AMS 2009
+			Self::If => "if",
+			Self::Iff => "iff", // Added: IEEE 1800-2005
+			Self::IfNone => "ifnone",
+			Self::IgnoreBins => "ignore_bins",   // Added: IEEE 1800-2005
+			Self::IllegalBins => "illegal_bins", // Added: IEEE 1800-2005
+			Self::Implements => "implements",    // Added: IEEE 1800-2012
+			Self::Implies => "implies",          // Added: IEEE 1800-2009
+			Self::Import => "import",            // Added: IEEE 1800-2005
+			Self::IncDir => "incdir",            // Added: IEEE 1364-2001
+			Self::Include => "include",          // Added: IEEE 1364-2001
+			Self::Inf => "inf",                  // Added: Verilog-AMS 2009
+			Self::Initial => "initial",
+			Self::InitialStep => "initial_step", // Added: Verilog-AMS 2009
+			Self::InOut => "inout",
+			Self::Input => "input",
+			Self::Inside => "inside",     // Added: IEEE 1800-2005
+			Self::Instance => "instance", // Added: IEEE 1364-2001
+			Self::Int => "int",           // Added: IEEE 1800-2005
+			Self::Integer => "integer",
+			Self::Interconnect => "interconnect", // Added: IEEE 1800-2012
+			Self::Interface => "interface",       // Added: IEEE 1800-2005
+			Self::Intersect => "intersect",       // Added: IEEE 1800-2005
+			Self::Join => "join",
+			Self::JoinAny => "join_any",     // Added: IEEE 1800-2005
+			Self::JoinNone => "join_none",   // Added: IEEE 1800-2005
+			Self::LaplaceNd => "laplace_nd", // Added: Verilog-AMS 2009
+			Self::LaplaceNp => "laplace_np", // Added: Verilog-AMS 2009
+			Self::LaplaceZd => "laplace_zd", // Added: Verilog-AMS 2009
+			Self::LaplaceZp => "laplace_zp", // Added: Verilog-AMS 2009
+			Self::Large => "large",
+			Self::LastCrossing => "last_crossing", // Added: Verilog-AMS 2009
+			Self::Let => "let",                    // Added: IEEE 1800-2009
+			Self::LibList => "liblist",            // Added: IEEE 1364-2001
+			Self::Library => "library",            // Added: IEEE 1364-2001
+			Self::LimExp => "limexp",              // Added: Verilog-AMS 2009
+			Self::Ln => "ln",                      // Added: Verilog-AMS 2009
+			Self::Ln1p => "ln1p",                  // Added: Verilog-AMS 2023
+			Self::Local => "local",                // Added: IEEE 1800-2005
+			Self::LocalParam => "localparam",      // Added: IEEE 1364-2001
+			Self::Log => "log",                    // Added: Verilog-AMS 2009
+			Self::Logic => "logic",                // Added: IEEE 1800-2005
+			Self::LongInt => "longint",            // Added: IEEE 1800-2005
+			Self::MacroModule => "macromodule",
+			Self::Matches => "matches", // Added: IEEE 1800-2005
+			Self::Max => "max",         // Added: Verilog-AMS 2009
+			Self::Medium => "medium",
+			Self::Merged => "merged",   // Added: Verilog-AMS 2009
+			Self::Min => "min",         // Added: Verilog-AMS 2009
+			Self::ModPort => "modport", // Added: IEEE 1800-2005
+			Self::Module => "module",
+			Self::Nand => "nand",
+			Self::Nature => "nature", // Added: Verilog-AMS 2009
+			Self::NegEdge => "negedge",
+			Self::NetResolution => "net_resolution", // Added: Verilog-AMS 2009-2014
+			Self::NetType => "nettype",              // Added: IEEE 1800-2012
+			Self::New => "new",                      // Added: IEEE 1800-2005
+			Self::NextTime => "nexttime",            // Added: IEEE 1800-2009
+			Self::Nmos => "nmos",
+			Self::NoiseTable => "noise_table", // Added: Verilog-AMS 2009
+			Self::NoiseTableLog => "noise_table_log", // Added: Verilog-AMS 2014
+			Self::Nor => "nor",
+			Self::NoShowCancelled => "noshowcancelled", // Added: IEEE 1364-2001
+			Self::Not => "not",
+			Self::NotIf0 => "notif0",
+			Self::NotIf1 => "notif1",
+			Self::Null => "null", // Added: IEEE 1800-2005
+			Self::Or => "or",
+			Self::Output => "output",
+			Self::Package => "package", // Added: IEEE 1800-2005
+			Self::Packed => "packed",   // Added: IEEE 1800-2005
+			Self::Parameter => "parameter",
+			Self::ParamSet => "paramset", // Added: Verilog-AMS 2009
+			Self::Pmos => "pmos",
+			Self::PosEdge => "posedge",
+			Self::Potential => "potential", // Added: Verilog-AMS 2009
+			Self::Pow => "pow",             // Added: Verilog-AMS 2009
+			Self::Primitive => "primitive",
+			Self::Priority => "priority",   // Added: IEEE 1800-2005
+			Self::Program => "program",     // Added: IEEE 1800-2005
+			Self::Property => "property",   // Added: IEEE 1800-2005
+			Self::Protected => "protected", // Added: IEEE 1800-2005
+			Self::Pull0 => "pull0",
+			Self::Pull1 => "pull1",
+			Self::Pulldown => "pulldown",
+			Self::Pullup => "pullup",
+			Self::PulseStyleOnDetect => "pulsestyle_ondetect", // Added: IEEE 1364-2001
+			Self::PulseStyleOnEvent => "pulsestyle_onevent",   // Added: IEEE 1364-2001
+			Self::Pure => "pure",                              // Added: IEEE 1800-2005
+			Self::Rand => "rand",                              // Added: IEEE 1800-2005
+			Self::RandC => "randc",                            // Added: IEEE 1800-2005
+			Self::RandCase => "randcase",                      // Added: IEEE 1800-2005
+			Self::RandSequence => "randsequence",              // Added: IEEE 1800-2005
+			Self::Rcmos => "rcmos",
+			Self::Real => "real",
+			Self::Realtime => "realtime",
+			Self::Ref => "ref", // Added: IEEE 1800-2005
+			Self::Reg => "reg",
+			Self::RejectOn => "reject_on", // Added: IEEE 1800-2009
+			Self::Release => "release",
+			Self::Repeat => "repeat",
+			Self::ResolveTo => "resolveto", // Added: Verilog-AMS 2009
+			Self::Restrict => "restrict",   // Added: IEEE 1800-2009
+			Self::Return => "return",       // Added: IEEE 1800-2005 & Verilog-AMS 2023
+			Self::Rnmos => "rnmos",
+			Self::Rpmos => "rpmos",
+			Self::Rtran => "rtran",
+			Self::RtranIf0 => "rtranif0",
+			Self::RtranIf1 => "rtranif1",
+			Self::SAlways => "s_always", // Added: IEEE 1800-2009
+			Self::Scalared => "scalared",
+			Self::Sequence => "sequence",           // Added: IEEE 1800-2005
+			Self::SEventually => "s_eventually",    // Added: IEEE 1800-2009
+			Self::ShortInt => "shortint",           // Added: IEEE 1800-2005
+			Self::ShortReal => "shortreal",         // Added: IEEE 1800-2005
+			Self::ShowCancelled => "showcancelled", // Added: IEEE 1364-2001
+			Self::Signed => "signed",               // Added: IEEE 1364-2001
+			Self::Sin => "sin",                     // Added: Verilog-AMS 2009
+			Self::Sinh => "sinh",                   // Added: Verilog-AMS 2009
+			Self::Slew => "slew",                   // Added: Verilog-AMS 2009
+			Self::Small => "small",
+			Self::SNextTime => "s_nexttime", // Added: IEEE 1800-2009
+			Self::Soft => "soft",            // Added: IEEE 1800-2012
+			Self::Solve => "solve",          // Added: IEEE 1800-2005
+			Self::Specify => "specify",
+			Self::SpecParam => "specparam",
+			Self::Split => "split",   // Added: Verilog-AMS 2009
+			Self::Sqrt => "sqrt",     // Added: Verilog-AMS 2009
+			Self::Static => "static", // Added: IEEE 1800-2005
+			Self::String => "string", // Added: IEEE 1800-2005 & Verilog-AMS 2009
+			Self::Strong => "strong", // Added: IEEE 1800-2009
+			Self::Strong0 => "strong0",
+			Self::Strong1 => "strong1",
+			Self::Struct => "struct",           // Added: IEEE 1800-2005
+			Self::SUntil => "s_until",          // Added: IEEE 1800-2009
+			Self::SUntilWith => "s_until_with", // Added: IEEE 1800-2009
+			Self::Super => "super",             // Added: IEEE 1800-2005
+			Self::Supply0 => "supply0",
+			Self::Supply1 => "supply1",
+			Self::SyncAcceptOn => "sync_accept_on", // Added: IEEE 1800-2009
+			Self::SyncRejectOn => "sync_reject_on", // Added: IEEE 1800-2009
+			Self::Table => "table",
+			Self::Tagged => "tagged", // Added: IEEE 1800-2005
+			Self::Tan => "tan",       // Added: Verilog-AMS 2009
+			Self::Tanh => "tanh",     // Added: Verilog-AMS 2009
+			Self::Task => "task",
+			Self::This => "this",             // Added: IEEE 1800-2005
+			Self::Throughout => "throughout", // Added: IEEE 1800-2005
+			Self::Time => "time",
+			Self::TimePrecision => "timeprecision", // Added: IEEE 1800-2005
+			Self::Timer => "timer",                 // Added: Verilog-AMS 2009
+			Self::TimeUnit => "timeunit",           // Added: IEEE 1800-2005
+			Self::Tran => "tran",
+			Self::TranIf0 => "tranif0",
+			Self::TranIf1 => "tranif1",
+			Self::Transition => "transition", // Added: Verilog-AMS 2009
+			Self::Tri => "tri",
+			Self::Tri0 => "tri0",
+			Self::Tri1 => "tri1",
+			Self::Triand => "triand",
+			Self::Trior => "trior",
+			Self::Trireg => "trireg",
+			Self::Type => "type",            // Added: IEEE 1800-2005
+			Self::TypeDef => "typedef",      // Added: IEEE 1800-2005
+			Self::Union => "union",          // Added: IEEE 1800-2005
+			Self::Unique => "unique",        // Added: IEEE 1800-2005
+			Self::Unique0 => "unique0",      // Added: IEEE 1800-2009
+			Self::Units => "units",          // Added: Verilog-AMS 2009
+			Self::Unsigned => "unsigned",    // Added: IEEE 1364-2001
+			Self::Until => "until",          // Added: IEEE 1800-2009
+			Self::UntilWith => "until_with", // Added: IEEE 1800-2009
+			Self::Untyped => "untyped",      // Added: IEEE 1800-2009
+			Self::Use => "use",              // Added: IEEE 1364-2001
+			Self::Uwire => "uwire",          // Added: IEEE 1364-2005
+			Self::Var => "var",              // Added: IEEE 1800-2005
+			Self::Vectored => "vectored",
+			Self::Virtual => "virtual", // Added: IEEE 1800-2005
+			Self::Void => "void",       // Added: IEEE 1800-2005
+			Self::Wait => "wait",
+			Self::WaitOrder => "wait_order", // Added: IEEE 1800-2005
+			Self::Wand => "wand",
+			Self::Weak => "weak", // Added: IEEE 1800-2009
+			Self::Weak0 => "weak0",
+			Self::Weak1 => "weak1",
+			Self::While => "while",
+			Self::WhiteNoise => "white_noise", // Added: Verilog-AMS 2009
+			Self::Wildcard => "wildcard",      // Added: IEEE 1800-2005
+			Self::Wire => "wire",
+			Self::With => "with",     // Added: IEEE 1800-2005
+			Self::Within => "within", // Added: IEEE 1800-2005
+			Self::Wor => "wor",
+			Self::Wreal => "wreal", // Added: Verilog-AMS 2009
+			Self::Xnor => "xnor",
+			Self::Xor => "xor",
+			Self::ZiNd => "zi_nd", // Added: Verilog-AMS 2009
+			Self::ZiNp => "zi_np", // Added: Verilog-AMS 2009
+			Self::ZiZd => "zi_zd", // Added: Verilog-AMS 2009
+			Self::ZiZp => "zi_zp", // Added: Verilog-AMS 2009
+		}
+	}
+
+	/// The standard the keyword was first reserved in
+	pub fn added(&self) -> LanguageStd {
+		is_keyword(self.as_str()).earliest()
+	}
+}
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 impl Display for Keyword {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(
-			f,
-			"Keyword({})",
-			match self {
-				Self::Above => "above",           // Added: Verilog-AMS 2009
-				Self::Abs => "abs",               // Added: Verilog-AMS 2009
-				Self::AbsDelay => "absdelay",     // Added: Verilog-AMS 2009
-				Self::AbsDelta => "absdelta",     // Added: Verilog-AMS 2014
-				Self::AbsTol => "abstol",         // Added: Verilog-AMS 2009
-				Self::AcceptOn => "accept_on",    // Added: IEEE 1800-2009
-				Self::Access => "access",         // Added: Verilog-AMS 2009
-				Self::Acos => "acos",             // Added: Verilog-AMS 2009
-				Self::Acosh => "acosh",           // Added: Verilog-AMS 2009
-				Self::AcStim => "ac_stim",        // Added: Verilog-AMS 2009
-				Self::Alias => "alias",           // Added: IEEE 1800-2005
-				Self::Aliasparam => "aliasparam", // Added: Verilog-AMS 2009
-				Self::Always => "always",
-				Self::AlwaysComb => "always_comb",   // Added: IEEE 1800-2005
-				Self::AlwaysFF => "always_ff",       // Added: IEEE 1800-2005
-				Self::AlwaysLatch => "always_latch", // Added: IEEE 1800-2005
-				Self::Analog => "analog",            // Added: Verilog-AMS 2009
-				Self::Analysis => "analysis",        // Added: Verilog-AMS 2009
-				Self::And => "and",
-				Self::Asin => "asin",     // Added: Verilog-AMS 2009
-				Self::Asinh => "asinh",   // Added: Verilog-AMS 2009
-				Self::Assert => "assert", // Added: IEEE 1800-2005 & Verilog-AMS 2009-2014
-				Self::Assign => "assign",
-				Self::Assume => "assume",       // Added: IEEE 1800-2005
-				Self::Atan => "atan",           // Added: Verilog-AMS 2009
-				Self::Atan2 => "atan2",         // Added: Verilog-AMS 2009
-				Self::Atanh => "atanh",         // Added: Verilog-AMS 2009
-				Self::Automatic => "automatic", // Added: IEEE 1364-2001
-				Self::Before => "before",       // Added: IEEE 1800-2005
-				Self::Begin => "begin",
-				Self::Bind => "bind",     // Added: IEEE 1800-2005
-				Self::Bins => "bins",     // Added: IEEE 1800-2005
-				Self::BinsOf => "binsof", // Added: IEEE 1800-2005
-				Self::Bit => "bit",       // Added: IEEE 1800-2005
-				Self::Branch => "branch", // Added: Verilog-AMS 2009
-				Self::Break => "break",   // Added: IEEE 1800-2005 & Verilog-AMS 2023
-				Self::Buf => "buf",
-				Self::BufIf0 => "bufif0",
-				Self::BufIf1 => "bufif1",
-				Self::Byte => "byte", // Added: IEEE 1800-2005
-				Self::Case => "case",
-				Self::CaseX => "casex",
-				Self::CaseZ => "casez",
-				Self::Ceil => "ceil",         // Added: Verilog-AMS 2009
-				Self::Cell => "cell",         // Added: IEEE 1364-2001
-				Self::CHandle => "chandle",   // Added: IEEE 1800-2005
-				Self::Checker => "checker",   // Added: IEEE 1800-2009
-				Self::Class => "class",       // Added: IEEE 1800-2005
-				Self::Clocking => "clocking", // Added: IEEE 1800-2005
-				Self::Cmos => "cmos",
-				Self::Config => "config",               // Added: IEEE 1364-2001
-				Self::Connect => "connect",             // Added: Verilog-AMS 2009
-				Self::ConnectModule => "connectmodule", // Added: Verilog-AMS 2009
-				Self::ConnectRules => "connectrules",   // Added: Verilog-AMS 2009
-				Self::Const => "const",                 // Added: IEEE 1800-2005
-				Self::Constraint => "constraint",       // Added: IEEE 1800-2005
-				Self::Context => "context",             // Added: IEEE 1800-2005
-				Self::Continue => "continue",           // Added: IEEE 1800-2005 & Verilog-AMS 2023
-				Self::Continuous => "continuous",       // Added: Verilog-AMS 2009
-				Self::Cos => "cos",                     // Added: Verilog-AMS 2009
-				Self::Cosh => "cosh",                   // Added: Verilog-AMS 2009
-				Self::Cover => "cover",                 // Added: IEEE 1800-2005
-				Self::CoverGroup => "covergroup",       // Added: IEEE 1800-2005
-				Self::CoverPoint => "coverpoint",       // Added: IEEE 1800-2005
-				Self::Cross => "cross",                 // Added: IEEE 1800-2005 & Verilog-AMS 2009
-				Self::Ddt => "ddt",                     // Added: Verilog-AMS 2009
-				Self::DdtNature => "ddt_nature",        // Added: Verilog-AMS 2009
-				Self::Ddx => "ddx",                     // Added: Verilog-AMS 2009
-				Self::Deassign => "deassign",
-				Self::Default => "default",
-				Self::DefParam => "defparam",
-				Self::Design => "design", // Added: IEEE 1364-2001
-				Self::Disable => "disable",
-				Self::Discipline => "discipline", // Added: Verilog-AMS 2009
-				Self::Discrete => "discrete",     // Added: Verilog-AMS 2009
-				Self::Dist => "dist",             // Added: IEEE 1800-2005
-				Self::Do => "do",                 // Added: IEEE 1800-2005
-				Self::Domain => "domain",         // Added: Verilog-AMS 2009
-				Self::DriverUpdate => "driver_update", // Added: Verilog-AMS 2009
-				Self::Edge => "edge",
-				Self::Else => "else",
-				Self::End => "end",
-				Self::EndCase => "endcase",
-				Self::EndChecker => "endchecker",   // Added: IEEE 1800-2009
-				Self::EndClass => "endclass",       // Added: IEEE 1800-2005
-				Self::EndClocking => "endclocking", // Added: IEEE 1800-2005
-				Self::EndConfig => "endconfig",     // Added: IEEE 1364-2001
-				Self::EndConnectRules => "endconnectrules", // Added: Verilog-AMS 2009
-				Self::EndDiscipline => "enddiscipline", // Added: Verilog-AMS 2009
-				Self::EndFunction => "endfunction",
-				Self::EndGenerate => "endgenerate",   // Added: IEEE 1364-2001
-				Self::EndGroup => "endgroup",         // Added: IEEE 1800-2005
-				Self::EndInterface => "endinterface", // Added: IEEE 1800-2005
-				Self::EndModule => "endmodule",
-				Self::EndNature => "endnature",     // Added: Verilog-AMS 2009
-				Self::EndPackage => "endpackage",   // Added: IEEE 1800-2005
-				Self::EndParamSet => "endparamset", // Added: Verilog-AMS 2009
-				Self::EndPrimitive => "endprimitive",
-				Self::EndProgram => "endprogram",   // Added: IEEE 1800-2005
-				Self::EndProperty => "endproperty", // Added: IEEE 1800-2005
-				Self::EndSequence => "endsequence", // Added: IEEE 1800-2005
-				Self::EndSpecify => "endspecify",
-				Self::EndTable => "endtable",
-				Self::EndTask => "endtask",
-				Self::Enum => "enum", // Added: IEEE 1800-2005
-				Self::Event => "event",
-				Self::Eventually => "eventually",      // Added: IEEE 1800-2009
-				Self::Exclude => "exclude",            // Added: Verilog-AMS 2009
-				Self::Exp => "exp",                    // Added: Verilog-AMS 2009
-				Self::Expect => "expect",              // Added: IEEE 1800-2005
-				Self::Expm1 => "expm1",                // Added:Verilog-AMS 2023
-				Self::Export => "export",              // Added: IEEE 1800-2005
-				Self::Extends => "extends",            // Added: IEEE 1800-2005
-				Self::Extern => "extern",              // Added: IEEE 1800-2005
-				Self::Final => "final",                // Added: IEEE 1800-2005
-				Self::FinalStep => "final_step",       // Added: Verilog-AMS 2009
-				Self::FirstMatch => "first_match",     // Added: IEEE 1800-2005
-				Self::FlickerNoise => "flicker_noise", // Added: Verilog-AMS 2009
-				Self::Floor => "floor",                // Added: Verilog-AMS 2009
-				Self::Flow => "flow",                  // Added: Verilog-AMS 2009
-				Self::For => "for",
-				Self::Force => "force",
-				Self::ForEach => "foreach", // Added: IEEE 1800-2005
-				Self::Forever => "forever",
-				Self::Fork => "fork",
-				Self::ForkJoin => "forkjoin", // Added: IEEE 1800-2005
-				Self::From => "from",         // Added: Verilog-AMS 2009
-				Self::Function => "function",
-				Self::Generate => "generate", // Added: IEEE 1364-2001
-				Self::Genvar => "genvar",     // Added: IEEE 1364-2001
-				Self::Global => "global",     // Added: IEEE 1800-2009
-				Self::Ground => "ground",     // Added: Verilog-AMS 2009
-				Self::HighZ0 => "highz0",
-				Self::HighZ1 => "highz1",
-				Self::Hypot => "hypot",          // Added: Verilog-AMS 2009
-				Self::Idt => "idt",              // Added: Verilog-AMS 2009
-				Self::IdtMod => "idtmod",        // Added: Verilog-AMS 2009
-				Self::IdtNature => "idt_nature", // Added: Verilog-AMS 2009
-				Self::If => "if",
-				Self::Iff => "iff", // Added: IEEE 1800-2005
-				Self::IfNone => "ifnone",
-				Self::IgnoreBins => "ignore_bins",   // Added: IEEE 1800-2005
-				Self::IllegalBins => "illegal_bins", // Added: IEEE 1800-2005
-				Self::Implements => "implements",    // Added: IEEE 1800-2012
-				Self::Implies => "implies",          // Added: IEEE 1800-2009
-				Self::Import => "import",            // Added: IEEE 1800-2005
-				Self::IncDir => "incdir",            // Added: IEEE 1364-2001
-				Self::Include => "include",          // Added: IEEE 1364-2001
-				Self::Inf => "inf",                  // Added: Verilog-AMS 2009
-				Self::Initial => "initial",
-				Self::InitialStep => "initial_step", // Added: Verilog-AMS 2009
-				Self::InOut => "inout",
-				Self::Input => "input",
-				Self::Inside => "inside",     // Added: IEEE 1800-2005
-				Self::Instance => "instance", // Added: IEEE 1364-2001
-				Self::Int => "int",           // Added: IEEE 1800-2005
-				Self::Integer => "integer",
-				Self::Interconnect => "interconnect", // Added: IEEE 1800-2012
-				Self::Interface => "interface",       // Added: IEEE 1800-2005
-				Self::Intersect => "intersect",       // Added: IEEE 1800-2005
-				Self::Join => "join",
-				Self::JoinAny => "join_any",     // Added: IEEE 1800-2005
-				Self::JoinNone => "join_none",   // Added: IEEE 1800-2005
-				Self::LaplaceNd => "laplace_nd", // Added: Verilog-AMS 2009
-				Self::LaplaceNp => "laplace_np", // Added: Verilog-AMS 2009
-				Self::LaplaceZd => "laplace_zd", // Added: Verilog-AMS 2009
-				Self::LaplaceZp => "laplace_zp", // Added: Verilog-AMS 2009
-				Self::Large => "large",
-				Self::LastCrossing => "last_crossing", // Added: Verilog-AMS 2009
-				Self::Let => "let",                    // Added: IEEE 1800-2009
-				Self::LibList => "liblist",            // Added: IEEE 1364-2001
-				Self::Library => "library",            // Added: IEEE 1364-2001
-				Self::LimExp => "limexp",              // Added: Verilog-AMS 2009
-				Self::Ln => "ln",                      // Added: Verilog-AMS 2009
-				Self::Ln1p => "ln1p",                  // Added: Verilog-AMS 2023
-				Self::Local => "local",                // Added: IEEE 1800-2005
-				Self::LocalParam => "localparam",      // Added: IEEE 1364-2001
-				Self::Log => "log",                    // Added: Verilog-AMS 2009
-				Self::Logic => "logic",                // Added: IEEE 1800-2005
-				Self::LongInt => "longint",            // Added: IEEE 1800-2005
-				Self::MacroModule => "macromodule",
-				Self::Matches => "matches", // Added: IEEE 1800-2005
-				Self::Max => "max",         // Added: Verilog-AMS 2009
-				Self::Medium => "medium",
-				Self::Merged => "merged",   // Added: Verilog-AMS 2009
-				Self::Min => "min",         // Added: Verilog-AMS 2009
-				Self::ModPort => "modport", // Added: IEEE 1800-2005
-				Self::Module => "module",
-				Self::Nand => "nand",
-				Self::Nature => "nature", // Added: Verilog-AMS 2009
-				Self::NegEdge => "negedge",
-				Self::NetResolution => "net_resolution", // Added: Verilog-AMS 2009-2014
-				Self::NetType => "nettype",              // Added: IEEE 1800-2012
-				Self::New => "new",                      // Added: IEEE 1800-2005
-				Self::NextTime => "nexttime",            // Added: IEEE 1800-2009
-				Self::Nmos => "nmos",
-				Self::NoiseTable => "noise_table", // Added: Verilog-AMS 2009
-				Self::NoiseTableLog => "noise_table_log", // Added: Verilog-AMS 2014
-				Self::Nor => "nor",
-				Self::NoShowCancelled => "noshowcancelled", // Added: IEEE 1364-2001
-				Self::Not => "not",
-				Self::NotIf0 => "notif0",
-				Self::NotIf1 => "notif1",
-				Self::Null => "null", // Added: IEEE 1800-2005
-				Self::Or => "or",
-				Self::Output => "output",
-				Self::Package => "package", // Added: IEEE 1800-2005
-				Self::Packed => "packed",   // Added: IEEE 1800-2005
-				Self::Parameter => "parameter",
-				Self::ParamSet => "paramset", // Added: Verilog-AMS 2009
-				Self::Pmos => "pmos",
-				Self::PosEdge => "posedge",
-				Self::Potential => "potential", // Added: Verilog-AMS 2009
-				Self::Pow => "pow",             // Added: Verilog-AMS 2009
-				Self::Primitive => "primitive",
-				Self::Priority => "priority",   // Added: IEEE 1800-2005
-				Self::Program => "program",     // Added: IEEE 1800-2005
-				Self::Property => "property",   // Added: IEEE 1800-2005
-				Self::Protected => "protected", // Added: IEEE 1800-2005
-				Self::Pull0 => "pull0",
-				Self::Pull1 => "pull1",
-				Self::Pulldown => "pulldown",
-				Self::Pullup => "pullup",
-				Self::PulseStyleOnDetect => "pulsestyle_ondetect", // Added: IEEE 1364-2001
-				Self::PulseStyleOnEvent => "pulsestyle_onevent",   // Added: IEEE 1364-2001
-				Self::Pure => "pure",                              // Added: IEEE 1800-2005
-				Self::Rand => "rand",                              // Added: IEEE 1800-2005
-				Self::RandC => "randc",                            // Added: IEEE 1800-2005
-				Self::RandCase => "randcase",                      // Added: IEEE 1800-2005
-				Self::RandSequence => "randsequence",              // Added: IEEE 1800-2005
-				Self::Rcmos => "rcmos",
-				Self::Real => "real",
-				Self::Realtime => "realtime",
-				Self::Ref => "ref", // Added: IEEE 1800-2005
-				Self::Reg => "reg",
-				Self::RejectOn => "reject_on", // Added: IEEE 1800-2009
-				Self::Release => "release",
-				Self::Repeat => "repeat",
-				Self::ResolveTo => "resolveto", // Added: Verilog-AMS 2009
-				Self::Restrict => "restrict",   // Added: IEEE 1800-2009
-				Self::Return => "return",       // Added: IEEE 1800-2005 & Verilog-AMS 2023
-				Self::Rnmos => "rnmos",
-				Self::Rpmos => "rpmos",
-				Self::Rtran => "rtran",
-				Self::RtranIf0 => "rtranif0",
-				Self::RtranIf1 => "rtranif1",
-				Self::SAlways => "s_always", // Added: IEEE 1800-2009
-				Self::Scalared => "scalared",
-				Self::Sequence => "sequence",           // Added: IEEE 1800-2005
-				Self::SEventually => "s_eventually",    // Added: IEEE 1800-2009
-				Self::ShortInt => "shortint",           // Added: IEEE 1800-2005
-				Self::ShortReal => "shortreal",         // Added: IEEE 1800-2005
-				Self::ShowCancelled => "showcancelled", // Added: IEEE 1364-2001
-				Self::Signed => "signed",               // Added: IEEE 1364-2001
-				Self::Sin => "sin",                     // Added: Verilog-AMS 2009
-				Self::Sinh => "sinh",                   // Added: Verilog-AMS 2009
-				Self::Slew => "slew",                   // Added: Verilog-AMS 2009
-				Self::Small => "small",
-				Self::SNextTime => "s_nexttime", // Added: IEEE 1800-2009
-				Self::Soft => "soft",            // Added: IEEE 1800-2012
-				Self::Solve => "solve",          // Added: IEEE 1800-2005
-				Self::Specify => "specify",
-				Self::SpecParam => "specparam",
-				Self::Split => "split",   // Added: Verilog-AMS 2009
-				Self::Sqrt => "sqrt",     // Added: Verilog-AMS 2009
-				Self::Static => "static", // Added: IEEE 1800-2005
-				Self::String => "string", // Added: IEEE 1800-2005 & Verilog-AMS 2009
-				Self::Strong => "strong", // Added: IEEE 1800-2009
-				Self::Strong0 => "strong0",
-				Self::Strong1 => "strong1",
-				Self::Struct => "struct",           // Added: IEEE 1800-2005
-				Self::SUntil => "s_until",          // Added: IEEE 1800-2009
-				Self::SUntilWith => "s_until_with", // Added: IEEE 1800-2009
-				Self::Super => "super",             // Added: IEEE 1800-2005
-				Self::Supply0 => "supply0",
-				Self::Supply1 => "supply1",
-				Self::SyncAcceptOn => "sync_accept_on", // Added: IEEE 1800-2009
-				Self::SyncRejectOn => "sync_reject_on", // Added: IEEE 1800-2009
-				Self::Table => "table",
-				Self::Tagged => "tagged", // Added: IEEE 1800-2005
-				Self::Tan => "tan",       // Added: Verilog-AMS 2009
-				Self::Tanh => "tanh",     // Added: Verilog-AMS 2009
-				Self::Task => "task",
-				Self::This => "this",             // Added: IEEE 1800-2005
-				Self::Throughout => "throughout", // Added: IEEE 1800-2005
-				Self::Time => "time",
-				Self::TimePrecision => "timeprecision", // Added: IEEE 1800-2005
-				Self::Timer => "timer",                 // Added: Verilog-AMS 2009
-				Self::TimeUnit => "timeunit",           // Added: IEEE 1800-2005
-				Self::Tran => "tran",
-				Self::TranIf0 => "tranif0",
-				Self::TranIf1 => "tranif1",
-				Self::Transition => "transition", // Added: Verilog-AMS 2009
-				Self::Tri => "tri",
-				Self::Tri0 => "tri0",
-				Self::Tri1 => "tri1",
-				Self::Triand => "triand",
-				Self::Trior => "trior",
-				Self::Trireg => "trireg",
-				Self::Type => "type",            // Added: IEEE 1800-2005
-				Self::TypeDef => "typedef",      // Added: IEEE 1800-2005
-				Self::Union => "union",          // Added: IEEE 1800-2005
-				Self::Unique => "unique",        // Added: IEEE 1800-2005
-				Self::Unique0 => "unique0",      // Added: IEEE 1800-2009
-				Self::Units => "units",          // Added: Verilog-AMS 2009
-				Self::Unsigned => "unsigned",    // Added: IEEE 1364-2001
-				Self::Until => "until",          // Added: IEEE 1800-2009
-				Self::UntilWith => "until_with", // Added: IEEE 1800-2009
-				Self::Untyped => "untyped",      // Added: IEEE 1800-2009
-				Self::Use => "use",              // Added: IEEE 1364-2001
-				Self::Uwire => "uwire",          // Added: IEEE 1364-2005
-				Self::Var => "var",              // Added: IEEE 1800-2005
-				Self::Vectored => "vectored",
-				Self::Virtual => "virtual", // Added: IEEE 1800-2005
-				Self::Void => "void",       // Added: IEEE 1800-2005
-				Self::Wait => "wait",
-				Self::WaitOrder => "wait_order", // Added: IEEE 1800-2005
-				Self::Wand => "wand",
-				Self::Weak => "weak", // Added: IEEE 1800-2009
-				Self::Weak0 => "weak0",
-				Self::Weak1 => "weak1",
-				Self::While => "while",
-				Self::WhiteNoise => "white_noise", // Added: Verilog-AMS 2009
-				Self::Wildcard => "wildcard",      // Added: IEEE 1800-2005
-				Self::Wire => "wire",
-				Self::With => "with",     // Added: IEEE 1800-2005
-				Self::Within => "within", // Added: IEEE 1800-2005
-				Self::Wor => "wor",
-				Self::Wreal => "wreal", // Added: Verilog-AMS 2009
-				Self::Xnor => "xnor",
-				Self::Xor => "xor",
-				Self::ZiNd => "zi_nd", // Added: Verilog-AMS 2009
-				Self::ZiNp => "zi_np", // Added: Verilog-AMS 2009
-				Self::ZiZd => "zi_zd", // Added: Verilog-AMS 2009
-				Self::ZiZp => "zi_zp", // Added: Verilog-AMS 2009
-			}
-		)
+		write!(f, "Keyword({})", self.as_str())
 	}
 }
 
@@ -1,12 +1,18 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
 pub mod ast;
+pub mod cst;
 pub mod directives;
+pub mod elab;
+pub mod incremental;
 pub mod keywords;
 pub mod parser;
+pub mod preprocessor;
+pub mod semantic;
 pub mod sysfuncs;
 pub mod text_macros;
 pub mod tokenizer;
+pub mod typeck;
 pub mod types;
 
 #[cfg(test)]
@@ -0,0 +1,372 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{
+		Branch, BranchTerminal, Discipline, DisciplineDomain, DisciplineItem, DisciplineNet,
+		Expression, ItemKind, Nature, NatureAttribute, NatureBinding, ValueRange, ValueRangeBounds,
+	},
+	keywords::Keyword,
+	parser::{VerilogParser, error::ParseResult},
+	tokenizer::token::Token,
+	types::{Control, Identifier, Operator},
+};
+
+impl VerilogParser {
+	/// Parse a nature declaration
+	///
+	/// Verilog-AMS 2.4 § 3.6.2
+	pub(crate) fn parse_nature(&mut self) -> ParseResult<Nature> {
+		self.expect_keyword(Keyword::Nature)?;
+		let name = self.expect_identifier()?;
+
+		let parent = if self.eat_control(Control::Colon) {
+			Some(self.parse_nature_parent()?)
+		} else {
+			None
+		};
+		self.eat_control(Control::Semicolon);
+
+		let mut attributes = Vec::new();
+		while !self.eat_keyword(Keyword::EndNature) {
+			let start = self.span();
+			let attribute = self.parse_nature_attribute()?;
+			self.expect_control(Control::Semicolon)?;
+
+			attributes.push(Spanned::new(attribute, self.span_from(start)));
+		}
+
+		Ok(Nature { name, parent, attributes })
+	}
+
+	/// Parse the parent of a derived nature, either a nature or a discipline's potential or flow
+	fn parse_nature_parent(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+		let name = self.expect_identifier()?;
+		let mut parent = Spanned::new(Expression::Identifier(name.as_inner()), start);
+
+		if self.eat_control(Control::Dot) {
+			let member = self.expect_nature_binding_name()?;
+			parent = Spanned::new(
+				Expression::Member { target: Box::new(parent), member },
+				self.span_from(start),
+			);
+		}
+
+		Ok(parent)
+	}
+
+	/// Parse a `name = value` nature attribute
+	fn parse_nature_attribute(&mut self) -> ParseResult<NatureAttribute> {
+		// NOTE(aki): Most of the standard attribute names, e.g. `abstol`, are keywords
+		let name = match self.peek() {
+			Some(Token::Keyword(keyword)) => {
+				let name = Identifier::Simple(keyword.as_str().as_bytes().into());
+				let span = self.span();
+				self.bump();
+
+				Spanned::new(name, span)
+			},
+			_ => self.expect_identifier()?,
+		};
+
+		self.expect_operator(Operator::Equals)?;
+		let value = self.parse_expression()?;
+
+		Ok(NatureAttribute { name, value })
+	}
+
+	/// Parse `potential` or `flow` as a name
+	fn expect_nature_binding_name(&mut self) -> ParseResult<Spanned<Identifier>> {
+		let span = self.span();
+		let name = match self.parse_nature_binding() {
+			Some(NatureBinding::Potential) => "potential",
+			Some(NatureBinding::Flow) => "flow",
+			None => return self.unexpected("`potential` or `flow`"),
+		};
+
+		Ok(Spanned::new(
+			Identifier::Simple(name.as_bytes().into()),
+			span,
+		))
+	}
+
+	fn parse_nature_binding(&mut self) -> Option<NatureBinding> {
+		if self.eat_keyword(Keyword::Potential) {
+			Some(NatureBinding::Potential)
+		} else if self.eat_keyword(Keyword::Flow) {
+			Some(NatureBinding::Flow)
+		} else {
+			None
+		}
+	}
+
+	/// Parse a discipline declaration
+	///
+	/// Verilog-AMS 2.4 § 3.6.3
+	pub(crate) fn parse_discipline(&mut self) -> ParseResult<Discipline> {
+		self.expect_keyword(Keyword::Discipline)?;
+		let name = self.expect_identifier()?;
+		self.eat_control(Control::Semicolon);
+
+		let mut items = Vec::new();
+		while !self.eat_keyword(Keyword::EndDiscipline) {
+			let start = self.span();
+
+			let item = if let Some(binding) = self.parse_nature_binding() {
+				if self.eat_control(Control::Dot) {
+					DisciplineItem::Attribute { binding, attribute: self.parse_nature_attribute()? }
+				} else {
+					DisciplineItem::Binding { binding, nature: self.expect_identifier()? }
+				}
+			} else if self.eat_keyword(Keyword::Domain) {
+				if self.eat_keyword(Keyword::Continuous) {
+					DisciplineItem::Domain(DisciplineDomain::Continuous)
+				} else if self.eat_keyword(Keyword::Discrete) {
+					DisciplineItem::Domain(DisciplineDomain::Discrete)
+				} else {
+					return self.unexpected("`continuous` or `discrete`");
+				}
+			} else {
+				return self.unexpected("`potential`, `flow`, `domain`, or `enddiscipline`");
+			};
+			self.expect_control(Control::Semicolon)?;
+
+			items.push(Spanned::new(item, self.span_from(start)));
+		}
+
+		Ok(Discipline { name, items })
+	}
+
+	/// Returns true if the identifier at the current position names the discipline of a net
+	/// declaration rather than the module of an instantiation
+	pub(crate) fn is_discipline_net_declaration(&self) -> bool {
+		let mut offset = 1;
+		if self.is_control_at(offset, Control::BracketOpen) {
+			match self.skip_balanced(offset) {
+				Some(next) => offset = next,
+				None => return false,
+			}
+		}
+
+		if !self.is_identifier_at(offset) {
+			return false;
+		}
+
+		// NOTE(aki): `electrical a[3:0];` and `mod inst[3:0] (...);` only differ after the range
+		offset += 1;
+		while self.is_control_at(offset, Control::BracketOpen) {
+			match self.skip_balanced(offset) {
+				Some(next) => offset = next,
+				None => return false,
+			}
+		}
+
+		!self.is_control_at(offset, Control::ParenOpen)
+	}
+
+	/// Verilog-AMS 2.4 § 3.6.4
+	pub(crate) fn parse_discipline_net(&mut self) -> ParseResult<DisciplineNet> {
+		let discipline = self.expect_identifier()?;
+		let data_type = self.parse_implicit_type()?;
+		let declarators = self.parse_declarators()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(DisciplineNet { discipline, data_type, declarators })
+	}
+
+	/// Parse the analog items, `ground` declarations, named branches, analog blocks, and
+	/// analog functions
+	pub(crate) fn parse_analog_item(&mut self) -> ParseResult<ItemKind> {
+		match self.peek() {
+			Some(Token::Keyword(Keyword::Ground)) => {
+				self.bump();
+
+				let mut names = vec![self.expect_identifier()?];
+				while self.eat_control(Control::Comma) {
+					names.push(self.expect_identifier()?);
+				}
+				self.expect_control(Control::Semicolon)?;
+
+				Ok(ItemKind::Ground(names))
+			},
+			Some(Token::Keyword(Keyword::Branch)) => Ok(ItemKind::Branch(self.parse_branch()?)),
+			Some(Token::Keyword(Keyword::Analog)) => {
+				self.bump();
+
+				match self.peek() {
+					Some(Token::Keyword(Keyword::Function)) => {
+						Ok(ItemKind::AnalogFunction(self.parse_function()?))
+					},
+					Some(Token::Keyword(Keyword::Initial)) => {
+						self.bump();
+						Ok(ItemKind::AnalogInitial(self.parse_statement()?))
+					},
+					_ => Ok(ItemKind::Analog(self.parse_statement()?)),
+				}
+			},
+			_ => self.unexpected("an analog item"),
+		}
+	}
+
+	/// Verilog-AMS 2.4 § 3.12.2
+	fn parse_branch(&mut self) -> ParseResult<Branch> {
+		self.expect_keyword(Keyword::Branch)?;
+		self.expect_control(Control::ParenOpen)?;
+
+		let mut terminals = vec![self.parse_branch_terminal()?];
+		if self.eat_control(Control::Comma) {
+			terminals.push(self.parse_branch_terminal()?);
+		}
+		self.expect_control(Control::ParenClose)?;
+
+		let mut names = vec![self.expect_identifier()?];
+		while self.eat_control(Control::Comma) {
+			names.push(self.expect_identifier()?);
+		}
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(Branch { terminals, names })
+	}
+
+	fn parse_branch_terminal(&mut self) -> ParseResult<Spanned<BranchTerminal>> {
+		let start = self.span();
+
+		// NOTE(aki): The tokenizer calls `<` `GreaterThan` and `>` `LessThan`
+		let terminal = if self.eat_operator(Operator::GreaterThan) {
+			let port = self.parse_lvalue()?;
+			self.expect_operator(Operator::LessThan)?;
+
+			BranchTerminal::Port(port)
+		} else {
+			BranchTerminal::Net(self.parse_lvalue()?)
+		};
+
+		Ok(Spanned::new(terminal, self.span_from(start)))
+	}
+
+	/// Parse any `from` and `exclude` ranges following a parameter assignment
+	///
+	/// Verilog-AMS 2.4 § 3.4.2
+	pub(crate) fn parse_value_ranges(&mut self) -> ParseResult<Vec<Spanned<ValueRange>>> {
+		let mut ranges = Vec::new();
+
+		loop {
+			let start = self.span();
+
+			let exclude = if self.eat_keyword(Keyword::From) {
+				false
+			} else if self.eat_keyword(Keyword::Exclude) {
+				true
+			} else {
+				break;
+			};
+
+			let bounds =
+				if self.is_control(Control::BracketOpen) || self.is_control(Control::ParenOpen) {
+					// `exclude (value)` is a parenthesized value rather than an interval
+					let checkpoint = self.checkpoint();
+					match self.parse_value_range_interval() {
+						Ok(bounds) => bounds,
+						Err(_) if exclude => {
+							self.restore(checkpoint);
+							ValueRangeBounds::Value(self.parse_expression()?)
+						},
+						Err(err) => return Err(err),
+					}
+				} else {
+					ValueRangeBounds::Value(self.parse_expression()?)
+				};
+
+			ranges.push(Spanned::new(
+				ValueRange { exclude, bounds },
+				self.span_from(start),
+			));
+		}
+
+		Ok(ranges)
+	}
+
+	fn parse_value_range_interval(&mut self) -> ParseResult<ValueRangeBounds> {
+		let low_inclusive = self.eat_control(Control::BracketOpen);
+		if !low_inclusive {
+			self.expect_control(Control::ParenOpen)?;
+		}
+
+		let low = self.parse_expression()?;
+		self.expect_control(Control::Colon)?;
+		let high = self.parse_expression()?;
+
+		let high_inclusive = self.eat_control(Control::BracketClose);
+		if !high_inclusive {
+			self.expect_control(Control::ParenClose)?;
+		}
+
+		Ok(ValueRangeBounds::Interval { low, low_inclusive, high, high_inclusive })
+	}
+}
+
+/// Returns true if `keyword` names a builtin analog function, operator, or event, e.g. `ddt`,
+/// which are otherwise called like any other function
+///
+/// Verilog-AMS 2.4 § 4.5
+pub(crate) fn is_analog_builtin(keyword: Keyword) -> bool {
+	matches!(
+		keyword,
+		Keyword::Above |
+			Keyword::Abs |
+			Keyword::AbsDelay |
+			Keyword::AbsDelta |
+			Keyword::AcStim |
+			Keyword::Acos |
+			Keyword::Acosh |
+			Keyword::Analysis |
+			Keyword::Asin |
+			Keyword::Asinh |
+			Keyword::Atan |
+			Keyword::Atan2 |
+			Keyword::Atanh |
+			Keyword::Ceil |
+			Keyword::Cos |
+			Keyword::Cosh |
+			Keyword::Cross |
+			Keyword::Ddt |
+			Keyword::Ddx |
+			Keyword::Exp |
+			Keyword::FinalStep |
+			Keyword::FlickerNoise |
+			Keyword::Floor |
+			Keyword::Hypot |
+			Keyword::Idt |
+			Keyword::IdtMod |
+			Keyword::Inf |
+			Keyword::InitialStep |
+			Keyword::LaplaceNd |
+			Keyword::LaplaceNp |
+			Keyword::LaplaceZd |
+			Keyword::LaplaceZp |
+			Keyword::LastCrossing |
+			Keyword::LimExp |
+			Keyword::Ln |
+			Keyword::Log |
+			Keyword::Max |
+			Keyword::Min |
+			Keyword::NoiseTable |
+			Keyword::NoiseTableLog |
+			Keyword::Pow |
+			Keyword::Sin |
+			Keyword::Sinh |
+			Keyword::Slew |
+			Keyword::Sqrt |
+			Keyword::Tan |
+			Keyword::Tanh |
+			Keyword::Timer |
+			Keyword::Transition |
+			Keyword::WhiteNoise |
+			Keyword::ZiNd |
+			Keyword::ZiNp |
+			Keyword::ZiZd |
+			Keyword::ZiZp
+	)
+}
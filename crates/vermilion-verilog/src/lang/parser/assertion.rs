@@ -0,0 +1,773 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{
+		AbortKind, Assertion, AssertionKind, AssertionPort, AssertionPortType, AssertionTiming,
+		ClockingBlock, ClockingDirection, ClockingItem, ClockingSignal, ClockingSkew, CycleRange,
+		Item, ItemKind, LetDeclaration, PropertyDeclaration, PropertyExpression, PropertyOperator,
+		PropertySpec, PropertyUnaryOperator, RepetitionKind, SequenceDeclaration,
+	},
+	keywords::Keyword,
+	parser::{
+		SYSTEM_VERILOG_09_STDS, SYSTEM_VERILOG_12_STDS, VerilogParser,
+		error::{ParseError, ParseResult},
+	},
+	tokenizer::token::Token,
+	types::{Control, EdgeIdentifier, Operator},
+};
+
+/// The precedence of `not`, `nexttime`, and `s_nexttime`
+const NOT_PRECEDENCE: u8 = 6;
+/// The precedence of the `##` cycle delay operator
+const DELAY_PRECEDENCE: u8 = 10;
+
+impl VerilogParser {
+	/// Parse an immediate, deferred, or concurrent assertion
+	///
+	/// IEEE 1800-2017 § A.6.10, § A.2.10
+	pub(crate) fn parse_assertion(&mut self) -> ParseResult<Assertion> {
+		let mut kind = match self.peek() {
+			Some(Token::Keyword(Keyword::Assert)) => AssertionKind::Assert,
+			Some(Token::Keyword(Keyword::Assume)) => AssertionKind::Assume,
+			Some(Token::Keyword(Keyword::Cover)) => AssertionKind::Cover,
+			Some(Token::Keyword(Keyword::Restrict)) => AssertionKind::Restrict,
+			_ => return self.unexpected("`assert`, `assume`, `cover`, or `restrict`"),
+		};
+		self.bump();
+
+		let timing = if self.eat_keyword(Keyword::Property) {
+			AssertionTiming::Concurrent
+		} else if kind == AssertionKind::Cover && self.eat_keyword(Keyword::Sequence) {
+			kind = AssertionKind::CoverSequence;
+			AssertionTiming::Concurrent
+		} else if self.is_control(Control::Octothorp) {
+			self.require_std(SYSTEM_VERILOG_09_STDS, self.span(), "Deferred assertions");
+			self.bump();
+
+			match self.peek() {
+				Some(Token::UnsignedNumber(value)) if &value[..] == b"0" => {
+					self.bump();
+				},
+				_ => return self.unexpected("`0`"),
+			}
+
+			AssertionTiming::Deferred
+		} else if self.is_keyword(Keyword::Final) {
+			self.require_std(
+				SYSTEM_VERILOG_12_STDS,
+				self.span(),
+				"Final deferred assertions",
+			);
+			self.bump();
+
+			AssertionTiming::Final
+		} else {
+			AssertionTiming::Immediate
+		};
+
+		self.expect_control(Control::ParenOpen)?;
+		let property = if timing == AssertionTiming::Concurrent {
+			self.parse_property_spec()?
+		} else {
+			let expression = self.parse_expression()?;
+			let span = *expression.span();
+
+			Spanned::new(
+				PropertySpec {
+					clock:    None,
+					disable:  None,
+					property: Spanned::new(
+						PropertyExpression::Expression(expression.as_inner()),
+						span,
+					),
+				},
+				span,
+			)
+		};
+		self.expect_control(Control::ParenClose)?;
+
+		if kind == AssertionKind::Restrict {
+			self.expect_control(Control::Semicolon)?;
+
+			return Ok(Assertion {
+				label: None,
+				kind,
+				timing,
+				property,
+				pass: None,
+				fail: None,
+			});
+		}
+
+		// The action block, a lone `;` is a null pass statement
+		let pass = if self.eat_control(Control::Semicolon) || self.is_keyword(Keyword::Else) {
+			None
+		} else {
+			Some(Box::new(self.parse_statement()?))
+		};
+
+		let fail = if self.eat_keyword(Keyword::Else) {
+			Some(Box::new(self.parse_statement()?))
+		} else {
+			None
+		};
+
+		Ok(Assertion { label: None, kind, timing, property, pass, fail })
+	}
+
+	/// IEEE 1800-2017 § A.2.10
+	pub(crate) fn parse_property_spec(&mut self) -> ParseResult<Spanned<PropertySpec>> {
+		let start = self.span();
+
+		let clock = if self.is_control(Control::At) {
+			Some(self.parse_event_control()?)
+		} else {
+			None
+		};
+
+		let disable = if self.eat_keyword(Keyword::Disable) {
+			self.expect_keyword(Keyword::Iff)?;
+			self.expect_control(Control::ParenOpen)?;
+			let condition = self.parse_expression()?;
+			self.expect_control(Control::ParenClose)?;
+
+			Some(condition)
+		} else {
+			None
+		};
+
+		let property = self.parse_property_expression(0)?;
+
+		Ok(Spanned::new(
+			PropertySpec { clock, disable, property },
+			self.span_from(start),
+		))
+	}
+
+	/// Parse a property or sequence expression, binding operators of at least `min_precedence`
+	///
+	/// IEEE 1800-2017 § 16.12, Table 16-3
+	fn parse_property_expression(
+		&mut self,
+		min_precedence: u8,
+	) -> ParseResult<Spanned<PropertyExpression>> {
+		let start = self.span();
+		let mut lhs = self.parse_property_unary()?;
+
+		loop {
+			if self.is_operator(Operator::CycleDelay) && min_precedence <= DELAY_PRECEDENCE {
+				self.bump();
+				let delay = self.parse_cycle_delay_range()?;
+				let rhs = self.parse_property_expression(DELAY_PRECEDENCE + 1)?;
+
+				lhs = Spanned::new(
+					PropertyExpression::Delay {
+						lhs: Some(Box::new(lhs)),
+						delay,
+						rhs: Box::new(rhs),
+					},
+					self.span_from(start),
+				);
+				continue;
+			}
+
+			let Some(operator) = self.peek_property_operator() else {
+				break;
+			};
+
+			let precedence = property_precedence(operator);
+			if precedence < min_precedence {
+				break;
+			}
+
+			if property_requires_09(operator) {
+				self.require_std(
+					SYSTEM_VERILOG_09_STDS,
+					self.span(),
+					"Temporal property operators",
+				);
+			}
+
+			let operator = Spanned::new(operator, self.span());
+			self.bump();
+
+			let next = if is_right_associative(*operator.inner()) {
+				precedence
+			} else {
+				precedence + 1
+			};
+			let rhs = self.parse_property_expression(next)?;
+
+			lhs = Spanned::new(
+				PropertyExpression::Binary { operator, lhs: Box::new(lhs), rhs: Box::new(rhs) },
+				self.span_from(start),
+			);
+		}
+
+		Ok(lhs)
+	}
+
+	fn peek_property_operator(&self) -> Option<PropertyOperator> {
+		Some(match self.peek()? {
+			Token::Keyword(keyword) => match keyword {
+				Keyword::And => PropertyOperator::And,
+				Keyword::Or => PropertyOperator::Or,
+				Keyword::Intersect => PropertyOperator::Intersect,
+				Keyword::Within => PropertyOperator::Within,
+				Keyword::Throughout => PropertyOperator::Throughout,
+				Keyword::Iff => PropertyOperator::Iff,
+				Keyword::Implies => PropertyOperator::Implies,
+				Keyword::Until => PropertyOperator::Until,
+				Keyword::SUntil => PropertyOperator::SUntil,
+				Keyword::UntilWith => PropertyOperator::UntilWith,
+				Keyword::SUntilWith => PropertyOperator::SUntilWith,
+				_ => return None,
+			},
+			Token::Operator(operator) => match operator {
+				Operator::PropImplOverlap => PropertyOperator::OverlappingImplication,
+				Operator::PropImplNonOverlap => PropertyOperator::NonOverlappingImplication,
+				Operator::FollowedByOverlapped => PropertyOperator::OverlappingFollowedBy,
+				Operator::FollowedByNonOverlapped => PropertyOperator::NonOverlappingFollowedBy,
+				_ => return None,
+			},
+			_ => return None,
+		})
+	}
+
+	/// Parse a prefix property operator or a primary along with any repetitions
+	fn parse_property_unary(&mut self) -> ParseResult<Spanned<PropertyExpression>> {
+		let start = self.span();
+
+		let expression = match self.peek() {
+			Some(Token::Operator(Operator::CycleDelay)) => {
+				self.bump();
+				let delay = self.parse_cycle_delay_range()?;
+				let rhs = self.parse_property_expression(DELAY_PRECEDENCE + 1)?;
+
+				PropertyExpression::Delay { lhs: None, delay, rhs: Box::new(rhs) }
+			},
+			Some(Token::Control(Control::At)) => {
+				let clock = self.parse_event_control()?;
+				let property = self.parse_property_expression(0)?;
+
+				PropertyExpression::Clocked { clock, property: Box::new(property) }
+			},
+			Some(Token::Keyword(Keyword::If)) => {
+				self.bump();
+				self.expect_control(Control::ParenOpen)?;
+				let condition = self.parse_expression()?;
+				self.expect_control(Control::ParenClose)?;
+
+				let if_true = self.parse_property_expression(0)?;
+				let if_false = if self.eat_keyword(Keyword::Else) {
+					Some(Box::new(self.parse_property_expression(0)?))
+				} else {
+					None
+				};
+
+				PropertyExpression::If { condition, if_true: Box::new(if_true), if_false }
+			},
+			Some(Token::Keyword(
+				keyword @ (Keyword::AcceptOn |
+				Keyword::RejectOn |
+				Keyword::SyncAcceptOn |
+				Keyword::SyncRejectOn),
+			)) => {
+				let kind = match keyword {
+					Keyword::AcceptOn => AbortKind::AcceptOn,
+					Keyword::RejectOn => AbortKind::RejectOn,
+					Keyword::SyncAcceptOn => AbortKind::SyncAcceptOn,
+					_ => AbortKind::SyncRejectOn,
+				};
+				self.bump();
+
+				self.expect_control(Control::ParenOpen)?;
+				let condition = self.parse_expression()?;
+				self.expect_control(Control::ParenClose)?;
+				let property = self.parse_property_expression(0)?;
+
+				PropertyExpression::Abort { kind, condition, property: Box::new(property) }
+			},
+			Some(Token::Keyword(keyword)) if property_unary_operator(*keyword).is_some() => {
+				let Some(operator) = property_unary_operator(*keyword) else {
+					return self.unexpected("a property operator");
+				};
+				let operator = Spanned::new(operator, self.span());
+				self.bump();
+
+				match operator.inner() {
+					PropertyUnaryOperator::Not => {
+						let operand = self.parse_property_expression(NOT_PRECEDENCE)?;
+						PropertyExpression::Unary {
+							operator,
+							range: None,
+							operand: Box::new(operand),
+						}
+					},
+					PropertyUnaryOperator::FirstMatch |
+					PropertyUnaryOperator::Strong |
+					PropertyUnaryOperator::Weak => {
+						self.expect_control(Control::ParenOpen)?;
+						let operand = self.parse_property_expression(0)?;
+						self.expect_control(Control::ParenClose)?;
+
+						PropertyExpression::Unary {
+							operator,
+							range: None,
+							operand: Box::new(operand),
+						}
+					},
+					_ => {
+						self.require_std(
+							SYSTEM_VERILOG_09_STDS,
+							*operator.span(),
+							"Temporal property operators",
+						);
+
+						let range = self.parse_property_range()?;
+						let precedence = match operator.inner() {
+							PropertyUnaryOperator::NextTime | PropertyUnaryOperator::SNextTime => {
+								NOT_PRECEDENCE
+							},
+							_ => 0,
+						};
+						let operand = self.parse_property_expression(precedence)?;
+
+						PropertyExpression::Unary { operator, range, operand: Box::new(operand) }
+					},
+				}
+			},
+			Some(Token::Control(Control::ParenOpen)) => {
+				// NOTE(aki): A parenthesized boolean expression may continue past the closing
+				// paren, e.g. `(a + b) == c`, so try that first before falling back to a
+				// parenthesized sequence or property
+				let checkpoint = self.checkpoint();
+
+				match self.parse_expression() {
+					Ok(expression) => PropertyExpression::Expression(expression.as_inner()),
+					Err(_) => {
+						self.restore(checkpoint);
+						self.bump();
+						let inner = self.parse_property_expression(0)?;
+						self.expect_control(Control::ParenClose)?;
+
+						PropertyExpression::Parenthesized(Box::new(inner))
+					},
+				}
+			},
+			_ => PropertyExpression::Expression(self.parse_expression()?.as_inner()),
+		};
+
+		let mut expression = Spanned::new(expression, self.span_from(start));
+
+		while self.is_repetition() {
+			let (kind, range) = self.parse_repetition()?;
+
+			expression = Spanned::new(
+				PropertyExpression::Repetition { operand: Box::new(expression), kind, range },
+				self.span_from(start),
+			);
+		}
+
+		Ok(expression)
+	}
+
+	/// Parse the optional `[n]` or `[min:max]` range of a ranged property operator
+	fn parse_property_range(&mut self) -> ParseResult<Option<Spanned<CycleRange>>> {
+		if !self.is_control(Control::BracketOpen) {
+			return Ok(None);
+		}
+
+		let start = self.span();
+		self.bump();
+		let first = self.parse_expression()?;
+
+		let range = if self.eat_control(Control::Colon) {
+			CycleRange::Range { min: first, max: self.parse_expression()? }
+		} else {
+			CycleRange::Single(first)
+		};
+		self.expect_control(Control::BracketClose)?;
+
+		Ok(Some(Spanned::new(range, self.span_from(start))))
+	}
+
+	/// Parse the delay following a `##`, e.g. `1`, `(n)`, `[1:$]`, `[*]`, or `[+]`
+	///
+	/// IEEE 1800-2017 § A.2.10
+	fn parse_cycle_delay_range(&mut self) -> ParseResult<Spanned<CycleRange>> {
+		let start = self.span();
+
+		let range = if self.eat_control(Control::BracketOpen) {
+			let range = if self.eat_operator(Operator::Asterisk) {
+				CycleRange::ZeroOrMore
+			} else if self.eat_operator(Operator::Plus) {
+				CycleRange::OneOrMore
+			} else {
+				let min = self.parse_expression()?;
+				self.expect_control(Control::Colon)?;
+				let max = self.parse_expression()?;
+
+				CycleRange::Range { min, max }
+			};
+			self.expect_control(Control::BracketClose)?;
+
+			range
+		} else if self.eat_control(Control::ParenOpen) {
+			let delay = self.parse_expression()?;
+			self.expect_control(Control::ParenClose)?;
+
+			CycleRange::Single(delay)
+		} else {
+			CycleRange::Single(self.parse_primary()?)
+		};
+
+		Ok(Spanned::new(range, self.span_from(start)))
+	}
+
+	/// Parse a `[*n]`, `[=n]`, or `[->n]` repetition, along with the `[*]` and `[+]` shorthands
+	///
+	/// IEEE 1800-2017 § A.2.10
+	pub(crate) fn parse_repetition(
+		&mut self,
+	) -> ParseResult<(RepetitionKind, Spanned<CycleRange>)> {
+		let start = self.expect_control(Control::BracketOpen)?;
+
+		if self.eat_operator(Operator::Plus) {
+			self.expect_control(Control::BracketClose)?;
+
+			return Ok((
+				RepetitionKind::Consecutive,
+				Spanned::new(CycleRange::OneOrMore, self.span_from(start)),
+			));
+		}
+
+		let kind = if self.eat_operator(Operator::Asterisk) {
+			RepetitionKind::Consecutive
+		} else if self.eat_operator(Operator::Equals) {
+			RepetitionKind::NonConsecutive
+		} else {
+			self.expect_operator(Operator::EventTrigger)?;
+			RepetitionKind::GoTo
+		};
+
+		if kind == RepetitionKind::Consecutive && self.eat_control(Control::BracketClose) {
+			return Ok((
+				kind,
+				Spanned::new(CycleRange::ZeroOrMore, self.span_from(start)),
+			));
+		}
+
+		let first = self.parse_expression()?;
+		let range = if self.eat_control(Control::Colon) {
+			CycleRange::Range { min: first, max: self.parse_expression()? }
+		} else {
+			CycleRange::Single(first)
+		};
+		self.expect_control(Control::BracketClose)?;
+
+		Ok((kind, Spanned::new(range, self.span_from(start))))
+	}
+
+	/// IEEE 1800-2017 § A.2.10
+	pub(crate) fn parse_property_declaration(&mut self) -> ParseResult<PropertyDeclaration> {
+		self.expect_keyword(Keyword::Property)?;
+		let name = self.expect_identifier()?;
+		let ports = self.parse_assertion_ports()?;
+		self.expect_control(Control::Semicolon)?;
+
+		let mut variables = Vec::new();
+		while let Some(item) = self.parse_block_item_declaration(false)? {
+			variables.push(item);
+		}
+
+		let property = self.parse_property_spec()?;
+		self.eat_control(Control::Semicolon);
+
+		self.expect_keyword(Keyword::EndProperty)?;
+		self.parse_end_label(Some(&name))?;
+
+		Ok(PropertyDeclaration { name, ports, variables, property })
+	}
+
+	/// IEEE 1800-2017 § A.2.10
+	pub(crate) fn parse_sequence_declaration(&mut self) -> ParseResult<SequenceDeclaration> {
+		self.expect_keyword(Keyword::Sequence)?;
+		let name = self.expect_identifier()?;
+		let ports = self.parse_assertion_ports()?;
+		self.expect_control(Control::Semicolon)?;
+
+		let mut variables = Vec::new();
+		while let Some(item) = self.parse_block_item_declaration(false)? {
+			variables.push(item);
+		}
+
+		let sequence = self.parse_property_spec()?;
+		self.eat_control(Control::Semicolon);
+
+		self.expect_keyword(Keyword::EndSequence)?;
+		self.parse_end_label(Some(&name))?;
+
+		Ok(SequenceDeclaration { name, ports, variables, sequence })
+	}
+
+	/// IEEE 1800-2017 § A.2.12
+	pub(crate) fn parse_let_declaration(&mut self) -> ParseResult<LetDeclaration> {
+		self.expect_keyword(Keyword::Let)?;
+		let name = self.expect_identifier()?;
+		let ports = self.parse_assertion_ports()?;
+		self.expect_operator(Operator::Equals)?;
+		let expression = self.parse_expression()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(LetDeclaration { name, ports, expression })
+	}
+
+	/// Parse the formal arguments of a property, sequence, or let declaration, a port without a
+	/// type shares the type of the port before it
+	fn parse_assertion_ports(&mut self) -> ParseResult<Vec<Spanned<AssertionPort>>> {
+		let mut ports = Vec::new();
+
+		if !self.eat_control(Control::ParenOpen) {
+			return Ok(ports);
+		}
+
+		if self.eat_control(Control::ParenClose) {
+			return Ok(ports);
+		}
+
+		let mut port_type = AssertionPortType::Untyped;
+		loop {
+			let start = self.span();
+
+			if self.eat_keyword(Keyword::Untyped) {
+				port_type = AssertionPortType::Untyped;
+			} else if self.eat_keyword(Keyword::Sequence) {
+				port_type = AssertionPortType::Sequence;
+			} else if self.eat_keyword(Keyword::Property) {
+				port_type = AssertionPortType::Property;
+			} else if self.is_data_type_keyword(0) || self.user_type_lookahead(0).is_some() {
+				port_type = AssertionPortType::Data(self.parse_data_type()?);
+			}
+
+			let name = self.expect_identifier()?;
+			let default = if self.eat_operator(Operator::Equals) {
+				Some(self.parse_expression()?)
+			} else {
+				None
+			};
+
+			ports.push(Spanned::new(
+				AssertionPort { port_type: port_type.clone(), name, default },
+				self.span_from(start),
+			));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(ports)
+	}
+
+	/// Parse a clocking block, any `default` or `global` keyword has already been consumed
+	///
+	/// IEEE 1800-2017 § A.6.11
+	pub(crate) fn parse_clocking_block(
+		&mut self,
+		default: bool,
+		global: bool,
+	) -> ParseResult<ClockingBlock> {
+		self.expect_keyword(Keyword::Clocking)?;
+
+		let name = if self.is_identifier() {
+			Some(self.expect_identifier()?)
+		} else {
+			None
+		};
+
+		let event = self.parse_event_control()?;
+		self.expect_control(Control::Semicolon)?;
+
+		let mut items = Vec::new();
+		while !self.eat_keyword(Keyword::EndClocking) {
+			if self.at_eof() {
+				return self.unexpected("`endclocking`");
+			}
+
+			let start = self.span();
+			let item = self.parse_clocking_item()?;
+			items.push(Spanned::new(item, self.span_from(start)));
+		}
+		self.parse_end_label(name.as_ref())?;
+
+		Ok(ClockingBlock { default, global, name, event, items })
+	}
+
+	fn parse_clocking_item(&mut self) -> ParseResult<ClockingItem> {
+		if self.eat_keyword(Keyword::Default) {
+			let input = if self.eat_keyword(Keyword::Input) {
+				self.parse_clocking_skew()?
+			} else {
+				None
+			};
+
+			let output = if self.eat_keyword(Keyword::Output) {
+				self.parse_clocking_skew()?
+			} else {
+				None
+			};
+			self.expect_control(Control::Semicolon)?;
+
+			return Ok(ClockingItem::Default { input, output });
+		}
+
+		let (direction, input_skew, output_skew) = if self.eat_keyword(Keyword::InOut) {
+			(ClockingDirection::InOut, None, None)
+		} else if self.eat_keyword(Keyword::Input) {
+			let input_skew = self.parse_clocking_skew()?;
+
+			if self.eat_keyword(Keyword::Output) {
+				let output_skew = self.parse_clocking_skew()?;
+				(ClockingDirection::InputOutput, input_skew, output_skew)
+			} else {
+				(ClockingDirection::Input, input_skew, None)
+			}
+		} else if self.eat_keyword(Keyword::Output) {
+			(ClockingDirection::Output, None, self.parse_clocking_skew()?)
+		} else {
+			let start = self.span();
+			let attributes = self.parse_attributes()?;
+			let kind = self.parse_item_kind(false)?;
+
+			return match kind {
+				kind @ (ItemKind::Property(_) | ItemKind::Sequence(_) | ItemKind::Let(_)) => {
+					Ok(ClockingItem::Item(Box::new(Item { attributes, kind })))
+				},
+				_ => Err(ParseError::new(
+					"Only property, sequence, and let declarations may appear in a clocking block",
+					self.span_from(start),
+				)),
+			};
+		};
+
+		let mut signals = Vec::new();
+		loop {
+			let start = self.span();
+			let name = self.expect_identifier()?;
+			let value = if self.eat_operator(Operator::Equals) {
+				Some(self.parse_expression()?)
+			} else {
+				None
+			};
+
+			signals.push(Spanned::new(
+				ClockingSignal { name, value },
+				self.span_from(start),
+			));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(ClockingItem::Signals { direction, input_skew, output_skew, signals })
+	}
+
+	/// Parse an optional clocking skew, e.g. `#1`, `negedge`, or `posedge #1step`
+	fn parse_clocking_skew(&mut self) -> ParseResult<Option<Spanned<ClockingSkew>>> {
+		let start = self.span();
+
+		let edge = if self.eat_keyword(Keyword::PosEdge) {
+			Some(EdgeIdentifier::PosEdge)
+		} else if self.eat_keyword(Keyword::NegEdge) {
+			Some(EdgeIdentifier::NegEdge)
+		} else if self.eat_keyword(Keyword::Edge) {
+			Some(EdgeIdentifier::Edge)
+		} else {
+			None
+		};
+
+		let delay = if self.is_control(Control::Octothorp) {
+			Some(self.parse_delay()?)
+		} else {
+			None
+		};
+
+		if edge.is_none() && delay.is_none() {
+			return Ok(None);
+		}
+
+		Ok(Some(Spanned::new(
+			ClockingSkew { edge, delay },
+			self.span_from(start),
+		)))
+	}
+}
+
+fn property_unary_operator(keyword: Keyword) -> Option<PropertyUnaryOperator> {
+	Some(match keyword {
+		Keyword::Not => PropertyUnaryOperator::Not,
+		Keyword::FirstMatch => PropertyUnaryOperator::FirstMatch,
+		Keyword::Strong => PropertyUnaryOperator::Strong,
+		Keyword::Weak => PropertyUnaryOperator::Weak,
+		Keyword::NextTime => PropertyUnaryOperator::NextTime,
+		Keyword::SNextTime => PropertyUnaryOperator::SNextTime,
+		Keyword::Always => PropertyUnaryOperator::Always,
+		Keyword::SAlways => PropertyUnaryOperator::SAlways,
+		Keyword::Eventually => PropertyUnaryOperator::Eventually,
+		Keyword::SEventually => PropertyUnaryOperator::SEventually,
+		_ => return None,
+	})
+}
+
+/// IEEE 1800-2017 § 16.12, Table 16-3
+fn property_precedence(operator: PropertyOperator) -> u8 {
+	match operator {
+		PropertyOperator::OverlappingImplication |
+		PropertyOperator::NonOverlappingImplication |
+		PropertyOperator::OverlappingFollowedBy |
+		PropertyOperator::NonOverlappingFollowedBy => 1,
+		PropertyOperator::Implies |
+		PropertyOperator::Until |
+		PropertyOperator::SUntil |
+		PropertyOperator::UntilWith |
+		PropertyOperator::SUntilWith => 2,
+		PropertyOperator::Iff => 3,
+		PropertyOperator::Or => 4,
+		PropertyOperator::And => 5,
+		PropertyOperator::Intersect => 7,
+		PropertyOperator::Within => 8,
+		PropertyOperator::Throughout => 9,
+	}
+}
+
+fn is_right_associative(operator: PropertyOperator) -> bool {
+	!matches!(
+		operator,
+		PropertyOperator::Or |
+			PropertyOperator::And |
+			PropertyOperator::Intersect |
+			PropertyOperator::Within
+	)
+}
+
+/// Returns true if the operator was only added in IEEE 1800-2009
+fn property_requires_09(operator: PropertyOperator) -> bool {
+	matches!(
+		operator,
+		PropertyOperator::OverlappingFollowedBy |
+			PropertyOperator::NonOverlappingFollowedBy |
+			PropertyOperator::Iff |
+			PropertyOperator::Implies |
+			PropertyOperator::Until |
+			PropertyOperator::SUntil |
+			PropertyOperator::UntilWith |
+			PropertyOperator::SUntilWith
+	)
+}
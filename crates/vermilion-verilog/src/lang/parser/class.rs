@@ -0,0 +1,376 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{
+		Class, ClassItem, ClassQualifier, ClassType, Constraint, ConstraintItem, DistItem,
+		DistWeight, Item, ItemKind,
+	},
+	cst::NodeKind,
+	keywords::Keyword,
+	parser::{SYSTEM_VERILOG_12_STDS, VerilogParser, error::ParseResult},
+	tokenizer::token::Token,
+	types::{Control, Operator},
+};
+
+impl VerilogParser {
+	/// IEEE 1800-2017 § A.1.2
+	pub(crate) fn parse_class(&mut self) -> ParseResult<Class> {
+		let is_virtual = self.eat_keyword(Keyword::Virtual);
+
+		let interface = if self.is_keyword(Keyword::Interface) {
+			self.require_std(SYSTEM_VERILOG_12_STDS, self.span(), "Interface classes");
+			self.bump();
+			true
+		} else {
+			false
+		};
+
+		self.expect_keyword(Keyword::Class)?;
+		let lifetime = self.parse_lifetime();
+		let name = self.expect_identifier()?;
+
+		let parameters = if self.eat_control(Control::Octothorp) {
+			Some(self.parse_parameter_port_list()?)
+		} else {
+			None
+		};
+
+		let mut extends = Vec::new();
+		let mut arguments = None;
+		if self.eat_keyword(Keyword::Extends) {
+			extends.push(self.parse_class_type()?);
+
+			// NOTE(aki): Only interface classes may extend more than one class
+			if interface {
+				while self.eat_control(Control::Comma) {
+					extends.push(self.parse_class_type()?);
+				}
+			} else if self.is_control(Control::ParenOpen) {
+				arguments = Some(self.parse_call_arguments()?);
+			}
+		}
+
+		let mut implements = Vec::new();
+		if self.is_keyword(Keyword::Implements) {
+			self.require_std(
+				SYSTEM_VERILOG_12_STDS,
+				self.span(),
+				"Interface class implementations",
+			);
+			self.bump();
+
+			implements.push(self.parse_class_type()?);
+			while self.eat_control(Control::Comma) {
+				implements.push(self.parse_class_type()?);
+			}
+		}
+
+		self.expect_control(Control::Semicolon)?;
+
+		let (items, end) = self.parse_list(
+			&[Keyword::EndClass],
+			Self::parse_optional_class_item,
+			|| ClassItem {
+				qualifiers: Vec::new(),
+				item:       Item::new(ItemKind::Error),
+			},
+		);
+		if end.is_some() {
+			self.parse_end_label(Some(&name))?;
+		}
+
+		Ok(Class {
+			is_virtual,
+			interface,
+			lifetime,
+			name,
+			parameters,
+			extends,
+			arguments,
+			implements,
+			items,
+		})
+	}
+
+	/// Parse a reference to a class, e.g. `pkg::base #(8)`
+	pub(crate) fn parse_class_type(&mut self) -> ParseResult<Spanned<ClassType>> {
+		let start = self.span();
+		let mut path = vec![self.expect_identifier()?];
+		let mut parameters = self.parse_parameter_value_assignment()?;
+
+		while self.is_operator(Operator::ClassScopeResolution) && self.is_identifier_at(1) {
+			self.bump();
+			path.push(self.expect_identifier()?);
+
+			if parameters.is_none() {
+				parameters = self.parse_parameter_value_assignment()?;
+			}
+		}
+
+		Ok(Spanned::new(
+			ClassType { path, parameters },
+			self.span_from(start),
+		))
+	}
+
+	/// Parse a class item, skipping over empty `;` items
+	fn parse_optional_class_item(&mut self) -> ParseResult<Option<Spanned<ClassItem>>> {
+		if self.eat_control(Control::Semicolon) {
+			return Ok(None);
+		}
+
+		self.parse_class_item().map(Some)
+	}
+
+	/// IEEE 1800-2017 § A.1.9
+	fn parse_class_item(&mut self) -> ParseResult<Spanned<ClassItem>> {
+		let start = self.span();
+		let attributes = self.parse_attributes()?;
+		let qualifiers = self.parse_class_qualifiers();
+
+		let prototype = qualifiers.iter().any(|qualifier| {
+			matches!(
+				qualifier,
+				ClassQualifier::Extern | ClassQualifier::PureVirtual
+			)
+		});
+
+		let kind = if self.is_keyword(Keyword::Constraint) {
+			ItemKind::Constraint(self.parse_constraint()?)
+		} else if prototype &&
+			(self.is_keyword(Keyword::Task) || self.is_keyword(Keyword::Function))
+		{
+			let prototype = self.parse_subroutine_prototype()?;
+			self.expect_control(Control::Semicolon)?;
+
+			ItemKind::Prototype(prototype)
+		} else {
+			self.parse_item_kind(false)?
+		};
+
+		let item = Spanned::new(
+			ClassItem { qualifiers, item: Item { attributes, kind } },
+			self.span_from(start),
+		);
+		Ok(self.node(NodeKind::Item, item))
+	}
+
+	fn parse_class_qualifiers(&mut self) -> Vec<ClassQualifier> {
+		let mut qualifiers = Vec::new();
+
+		loop {
+			let qualifier = match self.peek() {
+				Some(Token::Keyword(Keyword::Static)) => ClassQualifier::Static,
+				Some(Token::Keyword(Keyword::Protected)) => ClassQualifier::Protected,
+				Some(Token::Keyword(Keyword::Local)) => ClassQualifier::Local,
+				Some(Token::Keyword(Keyword::Rand)) => ClassQualifier::Rand,
+				Some(Token::Keyword(Keyword::RandC)) => ClassQualifier::RandC,
+				Some(Token::Keyword(Keyword::Extern)) => ClassQualifier::Extern,
+				// `virtual` followed by an interface name is a virtual interface type instead
+				Some(Token::Keyword(Keyword::Virtual))
+					if !self.is_identifier_at(1) &&
+						!matches!(
+							self.peek_nth(1),
+							Some(Token::Keyword(Keyword::Interface))
+						) =>
+				{
+					ClassQualifier::Virtual
+				},
+				Some(Token::Keyword(Keyword::Pure))
+					if matches!(self.peek_nth(1), Some(Token::Keyword(Keyword::Virtual))) =>
+				{
+					self.bump();
+					ClassQualifier::PureVirtual
+				},
+				_ => break,
+			};
+
+			self.bump();
+			qualifiers.push(qualifier);
+		}
+
+		qualifiers
+	}
+
+	/// Parse a constraint declaration or prototype
+	///
+	/// IEEE 1800-2017 § A.1.10
+	fn parse_constraint(&mut self) -> ParseResult<Constraint> {
+		self.expect_keyword(Keyword::Constraint)?;
+		let name = self.expect_identifier()?;
+
+		let items = if self.eat_control(Control::Semicolon) {
+			None
+		} else {
+			Some(self.parse_constraint_block()?)
+		};
+
+		Ok(Constraint { name, items })
+	}
+
+	/// Parse a `{...}` constraint block
+	///
+	/// IEEE 1800-2017 § A.1.10
+	pub(crate) fn parse_constraint_block(&mut self) -> ParseResult<Vec<Spanned<ConstraintItem>>> {
+		self.expect_control(Control::BraceOpen)?;
+
+		let mut items = Vec::new();
+		while !self.eat_control(Control::BraceClose) {
+			if self.at_eof() {
+				return self.unexpected("`}`");
+			}
+
+			items.push(self.parse_constraint_item()?);
+		}
+
+		Ok(items)
+	}
+
+	/// Parse either a constraint block or a single constraint
+	fn parse_constraint_set(&mut self) -> ParseResult<Vec<Spanned<ConstraintItem>>> {
+		if self.is_control(Control::BraceOpen) {
+			self.parse_constraint_block()
+		} else {
+			Ok(vec![self.parse_constraint_item()?])
+		}
+	}
+
+	fn parse_constraint_item(&mut self) -> ParseResult<Spanned<ConstraintItem>> {
+		let start = self.span();
+
+		let item = match self.peek() {
+			Some(Token::Keyword(Keyword::If)) => {
+				self.bump();
+				self.expect_control(Control::ParenOpen)?;
+				let condition = self.parse_expression()?;
+				self.expect_control(Control::ParenClose)?;
+
+				let if_true = self.parse_constraint_set()?;
+				let if_false = if self.eat_keyword(Keyword::Else) {
+					Some(self.parse_constraint_set()?)
+				} else {
+					None
+				};
+
+				ConstraintItem::If { condition, if_true, if_false }
+			},
+			Some(Token::Keyword(Keyword::ForEach)) => {
+				self.bump();
+				self.expect_control(Control::ParenOpen)?;
+				let (array, variables) = self.parse_foreach_header()?;
+				self.expect_control(Control::ParenClose)?;
+				let constraints = self.parse_constraint_set()?;
+
+				ConstraintItem::Foreach { array, variables, constraints }
+			},
+			Some(Token::Keyword(Keyword::Solve)) => {
+				self.bump();
+
+				let mut solve = vec![self.parse_expression()?];
+				while self.eat_control(Control::Comma) {
+					solve.push(self.parse_expression()?);
+				}
+
+				self.expect_keyword(Keyword::Before)?;
+
+				let mut before = vec![self.parse_expression()?];
+				while self.eat_control(Control::Comma) {
+					before.push(self.parse_expression()?);
+				}
+				self.expect_control(Control::Semicolon)?;
+
+				ConstraintItem::SolveBefore { solve, before }
+			},
+			Some(Token::Keyword(Keyword::Unique)) => {
+				self.require_std(
+					SYSTEM_VERILOG_12_STDS,
+					self.span(),
+					"Uniqueness constraints",
+				);
+				self.bump();
+				let items = self.parse_range_list()?;
+				self.expect_control(Control::Semicolon)?;
+
+				ConstraintItem::Unique(items)
+			},
+			Some(Token::Keyword(Keyword::Disable)) => {
+				self.require_std(
+					SYSTEM_VERILOG_12_STDS,
+					self.span(),
+					"Soft constraint disables",
+				);
+				self.bump();
+				self.expect_keyword(Keyword::Soft)?;
+				let expression = self.parse_expression()?;
+				self.expect_control(Control::Semicolon)?;
+
+				ConstraintItem::DisableSoft(expression)
+			},
+			_ => {
+				let soft = if self.is_keyword(Keyword::Soft) {
+					self.require_std(SYSTEM_VERILOG_12_STDS, self.span(), "Soft constraints");
+					self.bump();
+					true
+				} else {
+					false
+				};
+
+				// NOTE(aki): The condition of an implication can't itself contain a bare `->`
+				let expression = self.parse_conditional_expression()?;
+
+				if !soft && self.eat_operator(Operator::EventTrigger) {
+					let constraints = self.parse_constraint_set()?;
+
+					ConstraintItem::Implication { condition: expression, constraints }
+				} else if self.eat_keyword(Keyword::Dist) {
+					let items = self.parse_dist_list()?;
+					self.expect_control(Control::Semicolon)?;
+
+					ConstraintItem::Dist { soft, expression, items }
+				} else {
+					self.expect_control(Control::Semicolon)?;
+
+					ConstraintItem::Expression { soft, expression }
+				}
+			},
+		};
+
+		Ok(Spanned::new(item, self.span_from(start)))
+	}
+
+	/// Parse the `{...}` of a `dist` constraint
+	///
+	/// IEEE 1800-2017 § A.2.10
+	fn parse_dist_list(&mut self) -> ParseResult<Vec<Spanned<DistItem>>> {
+		self.expect_control(Control::BraceOpen)?;
+
+		let mut items = Vec::new();
+		loop {
+			let start = self.span();
+			let value = self.parse_value_range()?;
+
+			let weight = if self.eat_operator(Operator::WeightAssignUnit) {
+				Some(DistWeight::PerValue(self.parse_expression()?))
+			} else if self.eat_operator(Operator::WeightAssignDist) {
+				Some(DistWeight::PerRange(self.parse_expression()?))
+			} else {
+				None
+			};
+
+			items.push(Spanned::new(
+				DistItem { value, weight },
+				self.span_from(start),
+			));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::BraceClose)?;
+
+		Ok(items)
+	}
+}
@@ -0,0 +1,357 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{
+		Bins, BinsItem, BinsKind, BinsSelect, BinsValues, CoverGroup, CoverageEvent, CoverageItem,
+		CoverageOption, Coverpoint, Cross, Expression, TransitionItem,
+	},
+	keywords::Keyword,
+	parser::{SYSTEM_VERILOG_09_STDS, VerilogParser, error::ParseResult},
+	tokenizer::token::Token,
+	types::{Control, Identifier, Operator},
+};
+
+impl VerilogParser {
+	/// IEEE 1800-2017 § A.2.11
+	pub(crate) fn parse_covergroup(&mut self) -> ParseResult<CoverGroup> {
+		self.expect_keyword(Keyword::CoverGroup)?;
+		let name = self.expect_identifier()?;
+		let ports = self.parse_subroutine_ports()?;
+
+		let start = self.span();
+		let event = if self.is_control(Control::At) {
+			let event = self.parse_event_control()?;
+			Some(Spanned::new(
+				CoverageEvent::Clocking(event.as_inner()),
+				self.span_from(start),
+			))
+		} else if self.is_keyword(Keyword::With) {
+			self.require_std(
+				SYSTEM_VERILOG_09_STDS,
+				self.span(),
+				"Covergroup sample functions",
+			);
+			self.bump();
+			self.expect_keyword(Keyword::Function)?;
+
+			if !self.is_simple_identifier(0, b"sample") {
+				return self.unexpected("`sample`");
+			}
+			self.bump();
+
+			let ports = self.parse_subroutine_ports()?.unwrap_or_default();
+			Some(Spanned::new(
+				CoverageEvent::Sample(ports),
+				self.span_from(start),
+			))
+		} else {
+			None
+		};
+		self.expect_control(Control::Semicolon)?;
+
+		let mut items = Vec::new();
+		while !self.eat_keyword(Keyword::EndGroup) {
+			if self.at_eof() {
+				return self.unexpected("`endgroup`");
+			}
+
+			let start = self.span();
+			let item = self.parse_coverage_item()?;
+			items.push(Spanned::new(item, self.span_from(start)));
+		}
+		self.parse_end_label(Some(&name))?;
+
+		Ok(CoverGroup { name, ports, event, items })
+	}
+
+	fn parse_coverage_item(&mut self) -> ParseResult<CoverageItem> {
+		if let Some(option) = self.parse_coverage_option()? {
+			return Ok(CoverageItem::Option(option));
+		}
+
+		let label = if self.is_identifier() && self.is_control_at(1, Control::Colon) {
+			let label = self.expect_identifier()?;
+			self.bump();
+			Some(label)
+		} else {
+			None
+		};
+
+		if self.eat_keyword(Keyword::CoverPoint) {
+			let expression = self.parse_expression()?;
+			let iff = self.parse_coverage_iff()?;
+			let bins = self.parse_bins_block(false)?;
+
+			Ok(CoverageItem::Coverpoint(Coverpoint {
+				label,
+				expression,
+				iff,
+				bins,
+			}))
+		} else if self.eat_keyword(Keyword::Cross) {
+			let mut items = vec![self.expect_identifier()?];
+			while self.eat_control(Control::Comma) {
+				items.push(self.expect_identifier()?);
+			}
+
+			let iff = self.parse_coverage_iff()?;
+			let bins = self.parse_bins_block(true)?;
+
+			Ok(CoverageItem::Cross(Cross { label, items, iff, bins }))
+		} else {
+			self.unexpected("`coverpoint`, `cross`, or a coverage option")
+		}
+	}
+
+	/// Parse an `option.name = value;` or `type_option.name = value;` if one is present
+	fn parse_coverage_option(&mut self) -> ParseResult<Option<CoverageOption>> {
+		let type_option = if self.is_simple_identifier(0, b"option") {
+			false
+		} else if self.is_simple_identifier(0, b"type_option") {
+			true
+		} else {
+			return Ok(None);
+		};
+
+		if !self.is_control_at(1, Control::Dot) {
+			return Ok(None);
+		}
+
+		self.bump();
+		self.bump();
+		let name = self.expect_identifier()?;
+		self.expect_operator(Operator::Equals)?;
+		let value = self.parse_expression()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(Some(CoverageOption { type_option, name, value }))
+	}
+
+	fn parse_coverage_iff(&mut self) -> ParseResult<Option<Spanned<Expression>>> {
+		if !self.eat_keyword(Keyword::Iff) {
+			return Ok(None);
+		}
+
+		self.expect_control(Control::ParenOpen)?;
+		let condition = self.parse_expression()?;
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(Some(condition))
+	}
+
+	/// Parse the `{...}` bins of a coverpoint or cross, `None` if it was terminated by a `;`
+	fn parse_bins_block(&mut self, cross: bool) -> ParseResult<Option<Vec<Spanned<BinsItem>>>> {
+		if self.eat_control(Control::Semicolon) {
+			return Ok(None);
+		}
+
+		self.expect_control(Control::BraceOpen)?;
+
+		let mut items = Vec::new();
+		while !self.eat_control(Control::BraceClose) {
+			if self.at_eof() {
+				return self.unexpected("`}`");
+			}
+
+			let start = self.span();
+			let item = if let Some(option) = self.parse_coverage_option()? {
+				BinsItem::Option(option)
+			} else {
+				let bins = self.parse_bins(cross)?;
+				self.expect_control(Control::Semicolon)?;
+				BinsItem::Bins(Box::new(bins))
+			};
+
+			items.push(Spanned::new(item, self.span_from(start)));
+		}
+
+		Ok(Some(items))
+	}
+
+	/// IEEE 1800-2017 § A.2.11
+	fn parse_bins(&mut self, cross: bool) -> ParseResult<Bins> {
+		let wildcard = self.eat_keyword(Keyword::Wildcard);
+
+		let kind = match self.peek() {
+			Some(Token::Keyword(Keyword::Bins)) => BinsKind::Bins,
+			Some(Token::Keyword(Keyword::IllegalBins)) => BinsKind::IllegalBins,
+			Some(Token::Keyword(Keyword::IgnoreBins)) => BinsKind::IgnoreBins,
+			_ => return self.unexpected("`bins`, `illegal_bins`, or `ignore_bins`"),
+		};
+		self.bump();
+
+		let name = self.expect_identifier()?;
+
+		let mut array = false;
+		let mut size = None;
+		if self.eat_control(Control::BracketOpen) {
+			array = true;
+
+			if !self.is_control(Control::BracketClose) {
+				size = Some(self.parse_expression()?);
+			}
+			self.expect_control(Control::BracketClose)?;
+		}
+
+		self.expect_operator(Operator::Equals)?;
+
+		let values = if self.eat_keyword(Keyword::Default) {
+			if self.eat_keyword(Keyword::Sequence) {
+				BinsValues::DefaultSequence
+			} else {
+				BinsValues::Default
+			}
+		} else if self.is_control(Control::BraceOpen) {
+			BinsValues::Set(self.parse_range_list()?)
+		} else if cross {
+			BinsValues::Select(self.parse_bins_select(0)?)
+		} else {
+			BinsValues::Transitions(self.parse_transitions()?)
+		};
+
+		let with = if self.is_keyword(Keyword::With) {
+			self.require_std(SYSTEM_VERILOG_09_STDS, self.span(), "Bins `with` filters");
+			self.bump();
+
+			self.expect_control(Control::ParenOpen)?;
+			let filter = self.parse_expression()?;
+			self.expect_control(Control::ParenClose)?;
+
+			Some(filter)
+		} else {
+			None
+		};
+
+		let iff = self.parse_coverage_iff()?;
+
+		Ok(Bins {
+			wildcard,
+			kind,
+			name,
+			array,
+			size,
+			values,
+			with,
+			iff,
+		})
+	}
+
+	/// Parse a list of `(a => b, c => d [*2])` transition sets
+	///
+	/// IEEE 1800-2017 § A.2.11
+	fn parse_transitions(&mut self) -> ParseResult<Vec<Vec<Spanned<TransitionItem>>>> {
+		let mut transitions = Vec::new();
+
+		loop {
+			self.expect_control(Control::ParenOpen)?;
+
+			let mut steps = Vec::new();
+			loop {
+				let start = self.span();
+
+				let mut values = vec![self.parse_value_range()?];
+				while self.eat_control(Control::Comma) {
+					values.push(self.parse_value_range()?);
+				}
+
+				let repetition = if self.is_repetition() {
+					Some(self.parse_repetition()?)
+				} else {
+					None
+				};
+
+				steps.push(Spanned::new(
+					TransitionItem { values, repetition },
+					self.span_from(start),
+				));
+
+				if !self.eat_operator(Operator::ParallelConnection) {
+					break;
+				}
+			}
+			self.expect_control(Control::ParenClose)?;
+
+			transitions.push(steps);
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		Ok(transitions)
+	}
+
+	/// Parse a cross bins select expression, `!` binds tighter than `&&` which binds tighter than
+	/// `||`
+	///
+	/// IEEE 1800-2017 § A.2.11
+	fn parse_bins_select(&mut self, min_precedence: u8) -> ParseResult<Spanned<BinsSelect>> {
+		let start = self.span();
+
+		let mut lhs = if self.eat_operator(Operator::Exclamation) {
+			let operand = self.parse_bins_select(2)?;
+			Spanned::new(BinsSelect::Not(Box::new(operand)), self.span_from(start))
+		} else if self.eat_control(Control::ParenOpen) {
+			let inner = self.parse_bins_select(0)?;
+			self.expect_control(Control::ParenClose)?;
+
+			Spanned::new(
+				BinsSelect::Parenthesized(Box::new(inner)),
+				self.span_from(start),
+			)
+		} else {
+			self.expect_keyword(Keyword::BinsOf)?;
+			self.expect_control(Control::ParenOpen)?;
+			let target = self.parse_expression()?;
+			self.expect_control(Control::ParenClose)?;
+
+			let intersect = if self.eat_keyword(Keyword::Intersect) {
+				Some(self.parse_range_list()?)
+			} else {
+				None
+			};
+
+			Spanned::new(
+				BinsSelect::BinsOf { target, intersect },
+				self.span_from(start),
+			)
+		};
+
+		loop {
+			let precedence = if self.is_operator(Operator::LogicalAnd) {
+				1
+			} else if self.is_operator(Operator::LogicalOr) {
+				0
+			} else {
+				break;
+			};
+
+			if precedence < min_precedence {
+				break;
+			}
+
+			let and = self.is_operator(Operator::LogicalAnd);
+			self.bump();
+			let rhs = self.parse_bins_select(precedence + 1)?;
+
+			let select = if and {
+				BinsSelect::And(Box::new(lhs), Box::new(rhs))
+			} else {
+				BinsSelect::Or(Box::new(lhs), Box::new(rhs))
+			};
+			lhs = Spanned::new(select, self.span_from(start));
+		}
+
+		Ok(lhs)
+	}
+
+	/// Returns true if the token at `offset` is the simple identifier `name`
+	fn is_simple_identifier(&self, offset: usize, name: &[u8]) -> bool {
+		matches!(
+			self.peek_nth(offset),
+			Some(Token::Identifier(Identifier::Simple(ident))) if &ident[..] == name
+		)
+	}
+}
@@ -0,0 +1,1322 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::{Span, Spanned};
+
+use crate::{
+	LanguageStd,
+	lang::{
+		ast::{
+			AssignmentOperator, BinaryOperator, CastTarget, Delay, EventControl, EventExpression,
+			Expression, IncDecOperator, Literal, PatternItem, Select, Signing, StreamDirection,
+			UnaryOperator,
+		},
+		keywords::Keyword,
+		parser::{
+			SYSTEM_VERILOG_09_STDS, VERILOG_01_STDS, VerilogParser, analog::is_analog_builtin,
+			error::ParseResult,
+		},
+		tokenizer::token::Token,
+		types::{CompilerDirective, Control, EdgeIdentifier, Identifier, Operator, TimeUnit},
+	},
+};
+
+impl VerilogParser {
+	/// IEEE 1364-2005 § A.8.3, IEEE 1800-2017 § A.8.3
+	pub(crate) fn parse_expression(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+		let lhs = self.parse_conditional_expression()?;
+
+		let operator = if self.is_operator(Operator::EventTrigger) {
+			BinaryOperator::LogicalImplication
+		} else if self.is_operator(Operator::Equivalence) {
+			BinaryOperator::LogicalEquivalence
+		} else {
+			return Ok(lhs);
+		};
+
+		let operator = Spanned::new(operator, self.span());
+		self.require_std(
+			SYSTEM_VERILOG_09_STDS,
+			*operator.span(),
+			"Logical implication and equivalence operators",
+		);
+		self.bump();
+
+		let attributes = self.parse_attributes()?;
+		let rhs = self.parse_expression()?;
+
+		Ok(Spanned::new(
+			Expression::Binary {
+				operator,
+				attributes,
+				lhs: Box::new(lhs),
+				rhs: Box::new(rhs),
+			},
+			self.span_from(start),
+		))
+	}
+
+	/// Parse an expression without a trailing `->` or `<->`, as used in constraints where `->`
+	/// introduces a constraint set
+	pub(crate) fn parse_conditional_expression(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+		let condition = self.parse_binary_expression(1)?;
+
+		if !self.eat_control(Control::Question) {
+			return Ok(condition);
+		}
+
+		let attributes = self.parse_attributes()?;
+		let if_true = self.parse_expression()?;
+		self.expect_control(Control::Colon)?;
+		let if_false = self.parse_conditional_expression()?;
+
+		Ok(Spanned::new(
+			Expression::Conditional {
+				attributes,
+				condition: Box::new(condition),
+				if_true: Box::new(if_true),
+				if_false: Box::new(if_false),
+			},
+			self.span_from(start),
+		))
+	}
+
+	/// Parse an expression that may be a `min:typ:max` triple
+	pub(crate) fn parse_mintypmax_expression(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+		let min = self.parse_expression()?;
+
+		if !self.eat_control(Control::Colon) {
+			return Ok(min);
+		}
+
+		let typ = self.parse_expression()?;
+		self.expect_control(Control::Colon)?;
+		let max = self.parse_expression()?;
+
+		Ok(Spanned::new(
+			Expression::MinTypMax {
+				min: Box::new(min),
+				typ: Box::new(typ),
+				max: Box::new(max),
+			},
+			self.span_from(start),
+		))
+	}
+
+	fn parse_binary_expression(&mut self, min_precedence: u8) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+		let mut lhs = self.parse_unary_expression()?;
+
+		loop {
+			// `inside` binds as tightly as the relational operators
+			if self.is_keyword(Keyword::Inside) && min_precedence <= 7 {
+				self.bump();
+				let set = self.parse_range_list()?;
+
+				lhs = Spanned::new(
+					Expression::Inside { expression: Box::new(lhs), set },
+					self.span_from(start),
+				);
+				continue;
+			}
+
+			let Some(operator) = self.peek_binary_operator() else {
+				break;
+			};
+
+			let precedence = operator.precedence();
+			if precedence < min_precedence {
+				break;
+			}
+
+			let operator = Spanned::new(operator, self.span());
+			self.bump();
+
+			let attributes = self.parse_attributes()?;
+			let rhs = self.parse_binary_expression(precedence + 1)?;
+
+			lhs = Spanned::new(
+				Expression::Binary {
+					operator,
+					attributes,
+					lhs: Box::new(lhs),
+					rhs: Box::new(rhs),
+				},
+				self.span_from(start),
+			);
+		}
+
+		Ok(lhs)
+	}
+
+	fn peek_binary_operator(&self) -> Option<BinaryOperator> {
+		// NOTE(aki): The tokenizer names the relational operators from the other direction
+		Some(match self.peek()? {
+			Token::Operator(operator) => match operator {
+				Operator::Plus => BinaryOperator::Add,
+				Operator::Minus => BinaryOperator::Subtract,
+				Operator::Asterisk => BinaryOperator::Multiply,
+				Operator::Solidus => BinaryOperator::Divide,
+				Operator::Percent => BinaryOperator::Modulus,
+				Operator::Pow => BinaryOperator::Power,
+				Operator::LogicalEquality => BinaryOperator::LogicalEquality,
+				Operator::LogicalInequality => BinaryOperator::LogicalInequality,
+				Operator::CaseEquality => BinaryOperator::CaseEquality,
+				Operator::CaseInequality => BinaryOperator::CaseInequality,
+				Operator::LogicalAnd => BinaryOperator::LogicalAnd,
+				Operator::LogicalOr => BinaryOperator::LogicalOr,
+				Operator::GreaterThan => BinaryOperator::LessThan,
+				Operator::GreaterThanEqual => BinaryOperator::LessThanEqual,
+				Operator::LessThan => BinaryOperator::GreaterThan,
+				Operator::LessThanEqual => BinaryOperator::GreaterThanEqual,
+				Operator::Ampersand => BinaryOperator::BitwiseAnd,
+				Operator::Pipe => BinaryOperator::BitwiseOr,
+				Operator::Circumflex => BinaryOperator::BitwiseXor,
+				Operator::TildeCircumflex(_) => BinaryOperator::BitwiseXnor,
+				Operator::ShiftLeft => BinaryOperator::ShiftLeft,
+				Operator::ShiftRight => BinaryOperator::ShiftRight,
+				Operator::ArithmeticShl => BinaryOperator::ArithmeticShiftLeft,
+				Operator::ArithmeticShr => BinaryOperator::ArithmeticShiftRight,
+				Operator::WildcardEqual => BinaryOperator::WildcardEquality,
+				Operator::WildcardNotEqual => BinaryOperator::WildcardInequality,
+				_ => return None,
+			},
+			_ => return None,
+		})
+	}
+
+	fn peek_unary_operator(&self) -> Option<UnaryOperator> {
+		Some(match self.peek()? {
+			Token::Operator(operator) => match operator {
+				Operator::Plus => UnaryOperator::Plus,
+				Operator::Minus => UnaryOperator::Minus,
+				Operator::Exclamation => UnaryOperator::LogicalNot,
+				Operator::Tilde => UnaryOperator::BitwiseNot,
+				Operator::Ampersand => UnaryOperator::ReductionAnd,
+				Operator::ReductionNand => UnaryOperator::ReductionNand,
+				Operator::Pipe => UnaryOperator::ReductionOr,
+				Operator::ReductionNor => UnaryOperator::ReductionNor,
+				Operator::Circumflex => UnaryOperator::ReductionXor,
+				Operator::TildeCircumflex(_) => UnaryOperator::ReductionXnor,
+				_ => return None,
+			},
+			_ => return None,
+		})
+	}
+
+	fn parse_unary_expression(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+
+		if let Some(operator) = self.peek_inc_dec_operator() {
+			let operator = Spanned::new(operator, start);
+			self.bump();
+			let operand = self.parse_unary_expression()?;
+
+			return Ok(Spanned::new(
+				Expression::IncDec { operator, prefix: true, operand: Box::new(operand) },
+				self.span_from(start),
+			));
+		}
+
+		let Some(operator) = self.peek_unary_operator() else {
+			let primary = self.parse_primary()?;
+			return self.parse_primary_suffix(primary);
+		};
+
+		let operator = Spanned::new(operator, start);
+		self.bump();
+
+		let attributes = self.parse_attributes()?;
+		let operand = self.parse_unary_expression()?;
+
+		Ok(Spanned::new(
+			Expression::Unary { operator, attributes, operand: Box::new(operand) },
+			self.span_from(start),
+		))
+	}
+
+	/// Parse any postfix increments or decrements, casts, and typed assignment patterns
+	/// following a primary
+	fn parse_primary_suffix(
+		&mut self,
+		mut expression: Spanned<Expression>,
+	) -> ParseResult<Spanned<Expression>> {
+		let start = *expression.span();
+
+		loop {
+			if let Some(operator) = self.peek_inc_dec_operator() {
+				let operator = Spanned::new(operator, self.span());
+				self.bump();
+
+				expression = Spanned::new(
+					Expression::IncDec {
+						operator,
+						prefix: false,
+						operand: Box::new(expression),
+					},
+					self.span_from(start),
+				);
+			} else if self.is_control(Control::Apostrophe) &&
+				self.is_control_at(1, Control::ParenOpen)
+			{
+				self.bump();
+				let (target, span) = expression.split();
+				let target = match target {
+					Expression::DataType(data_type) => CastTarget::Type(*data_type),
+					target => CastTarget::Expression(target),
+				};
+
+				let value = self.parse_cast_value()?;
+				expression = Spanned::new(
+					Expression::Cast {
+						target: Box::new(Spanned::new(target, span)),
+						value:  Box::new(value),
+					},
+					self.span_from(start),
+				);
+			} else if self.is_control(Control::Apostrophe) &&
+				self.is_control_at(1, Control::BraceOpen)
+			{
+				self.bump();
+				let items = self.parse_assignment_pattern()?;
+
+				expression = Spanned::new(
+					Expression::AssignmentPattern { data_type: Some(Box::new(expression)), items },
+					self.span_from(start),
+				);
+			} else {
+				break;
+			}
+		}
+
+		Ok(expression)
+	}
+
+	fn peek_inc_dec_operator(&self) -> Option<IncDecOperator> {
+		if self.is_operator(Operator::Increment) {
+			Some(IncDecOperator::Increment)
+		} else if self.is_operator(Operator::Decrement) {
+			Some(IncDecOperator::Decrement)
+		} else {
+			None
+		}
+	}
+
+	/// The assignment operator at the current position, if any
+	pub(crate) fn peek_assignment_operator(&self) -> Option<AssignmentOperator> {
+		Some(match self.peek()? {
+			Token::Operator(operator) => match operator {
+				Operator::Equals => AssignmentOperator::Assign,
+				Operator::AddEquals => AssignmentOperator::Add,
+				Operator::SubEquals => AssignmentOperator::Subtract,
+				Operator::MulEquals => AssignmentOperator::Multiply,
+				Operator::DivEquals => AssignmentOperator::Divide,
+				Operator::RemEquals => AssignmentOperator::Modulus,
+				Operator::AndEquals => AssignmentOperator::And,
+				Operator::OrEquals => AssignmentOperator::Or,
+				Operator::XorEquals => AssignmentOperator::Xor,
+				Operator::ShiftLeftEquals => AssignmentOperator::ShiftLeft,
+				Operator::ShiftRightEquals => AssignmentOperator::ShiftRight,
+				Operator::ArithmeticShlEquals => AssignmentOperator::ArithmeticShiftLeft,
+				Operator::ArithmeticShrEquals => AssignmentOperator::ArithmeticShiftRight,
+				_ => return None,
+			},
+			_ => return None,
+		})
+	}
+
+	/// Parse an increment, decrement, or operator assignment, e.g. the step of a `for` loop
+	///
+	/// IEEE 1800-2017 § A.6.2
+	pub(crate) fn parse_step_expression(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+
+		if let Some(operator) = self.peek_inc_dec_operator() {
+			let operator = Spanned::new(operator, start);
+			self.bump();
+			let operand = self.parse_lvalue()?;
+
+			return Ok(Spanned::new(
+				Expression::IncDec { operator, prefix: true, operand: Box::new(operand) },
+				self.span_from(start),
+			));
+		}
+
+		let lvalue = self.parse_lvalue()?;
+		self.parse_step_suffix(lvalue)
+	}
+
+	/// Parse the `++`, `--`, or assignment that follows `lvalue` in a step expression
+	pub(crate) fn parse_step_suffix(
+		&mut self,
+		lvalue: Spanned<Expression>,
+	) -> ParseResult<Spanned<Expression>> {
+		let start = *lvalue.span();
+
+		if let Some(operator) = self.peek_inc_dec_operator() {
+			let operator = Spanned::new(operator, self.span());
+			self.bump();
+
+			return Ok(Spanned::new(
+				Expression::IncDec { operator, prefix: false, operand: Box::new(lvalue) },
+				self.span_from(start),
+			));
+		}
+
+		let Some(operator) = self.peek_assignment_operator() else {
+			return self.unexpected("an assignment, `++`, or `--`");
+		};
+		let operator = Spanned::new(operator, self.span());
+		self.bump();
+
+		let value = self.parse_expression()?;
+
+		Ok(Spanned::new(
+			Expression::Assign {
+				lvalue: Box::new(lvalue),
+				operator,
+				value: Box::new(value),
+			},
+			self.span_from(start),
+		))
+	}
+
+	/// Parse the parenthesized value of a cast, the `'` has already been consumed
+	fn parse_cast_value(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.expect_control(Control::ParenOpen)?;
+		let value = self.parse_expression()?;
+		self.expect_control(Control::ParenClose)?;
+
+		let span = self.span_from(start);
+		self.require_std(LanguageStd::SYSTEM_VERILOG_STDS, span, "Casts");
+
+		Ok(Spanned::new(value.as_inner(), span))
+	}
+
+	/// Parse the `{...}` of an assignment pattern, the `'` has already been consumed
+	///
+	/// IEEE 1800-2017 § A.6.7.1
+	fn parse_assignment_pattern(&mut self) -> ParseResult<Vec<Spanned<PatternItem>>> {
+		let start = self.expect_control(Control::BraceOpen)?;
+
+		let mut items = Vec::new();
+		while !self.is_control(Control::BraceClose) {
+			let item_start = self.span();
+
+			let item = if self.eat_keyword(Keyword::Default) {
+				self.expect_control(Control::Colon)?;
+				PatternItem::Default(self.parse_expression()?)
+			} else {
+				let key = self.parse_expression()?;
+
+				if self.eat_control(Control::Colon) {
+					PatternItem::Keyed { key, value: self.parse_expression()? }
+				} else if self.is_control(Control::BraceOpen) {
+					// A replicated pattern, e.g. `'{4{0}}`
+					let items = match self.parse_concatenation()?.as_inner() {
+						Expression::Concatenation(items) => items,
+						other => vec![Spanned::new(other, item_start)],
+					};
+
+					PatternItem::Positional(Spanned::new(
+						Expression::Replication { count: Box::new(key), items },
+						self.span_from(item_start),
+					))
+				} else {
+					PatternItem::Positional(key)
+				}
+			};
+
+			items.push(Spanned::new(item, self.span_from(item_start)));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::BraceClose)?;
+		self.require_std(
+			LanguageStd::SYSTEM_VERILOG_STDS,
+			self.span_from(start),
+			"Assignment patterns",
+		);
+
+		Ok(items)
+	}
+
+	/// Parse a `{...}` list of values and `[low:high]` ranges, as used by `inside`
+	///
+	/// IEEE 1800-2017 § A.8.3
+	pub(crate) fn parse_range_list(&mut self) -> ParseResult<Vec<Spanned<Expression>>> {
+		self.expect_control(Control::BraceOpen)?;
+
+		let mut items = vec![self.parse_value_range()?];
+		while self.eat_control(Control::Comma) {
+			items.push(self.parse_value_range()?);
+		}
+
+		self.expect_control(Control::BraceClose)?;
+
+		Ok(items)
+	}
+
+	/// Parse either an expression or a `[low:high]` range
+	pub(crate) fn parse_value_range(&mut self) -> ParseResult<Spanned<Expression>> {
+		if !self.is_control(Control::BracketOpen) {
+			return self.parse_expression();
+		}
+
+		let start = self.span();
+		self.bump();
+		let low = self.parse_expression()?;
+
+		let expression = if self.is_operator(Operator::AbsTolerance) ||
+			self.is_operator(Operator::RelTolerance)
+		{
+			let relative = self.is_operator(Operator::RelTolerance);
+			self.bump();
+			let tolerance = self.parse_expression()?;
+
+			Expression::ToleranceRange {
+				center: Box::new(low),
+				relative,
+				tolerance: Box::new(tolerance),
+			}
+		} else {
+			self.expect_control(Control::Colon)?;
+			let high = self.parse_expression()?;
+
+			Expression::ValueRange { low: Box::new(low), high: Box::new(high) }
+		};
+
+		self.expect_control(Control::BracketClose)?;
+
+		Ok(Spanned::new(expression, self.span_from(start)))
+	}
+
+	/// IEEE 1364-2005 § A.8.4, IEEE 1800-2017 § A.8.4
+	pub(crate) fn parse_primary(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+
+		if let Some(literal) = self.parse_time_literal()? {
+			return Ok(literal);
+		}
+
+		match self.peek() {
+			Some(Token::UnsignedNumber(_) | Token::BasedLiteralSpecifier(_)) => self.parse_number(),
+			Some(Token::Real(value)) => {
+				let value = *value;
+				self.bump();
+				Ok(Spanned::new(
+					Expression::Literal(Literal::Real(value)),
+					start,
+				))
+			},
+			Some(Token::SingleQuotedString(string)) => {
+				let string = string.clone();
+				self.bump();
+				Ok(Spanned::new(
+					Expression::Literal(Literal::String(string)),
+					start,
+				))
+			},
+			Some(Token::TripleQuotedString(string)) => {
+				let string = string.clone();
+				self.bump();
+				Ok(Spanned::new(
+					Expression::Literal(Literal::TripleQuotedString(string)),
+					start,
+				))
+			},
+			Some(Token::SystemFunc(_)) => self.parse_system_call(),
+			Some(Token::Identifier(_)) => {
+				let ident = self.expect_identifier()?;
+				let expression = Spanned::new(Expression::Identifier(ident.as_inner()), start);
+				self.parse_postfix(expression, true)
+			},
+			Some(Token::Keyword(Keyword::This | Keyword::Super | Keyword::Null)) => {
+				let expression = match self.peek() {
+					Some(Token::Keyword(Keyword::This)) => Expression::This,
+					Some(Token::Keyword(Keyword::Super)) => Expression::Super,
+					_ => Expression::Null,
+				};
+				self.bump();
+
+				self.parse_postfix(Spanned::new(expression, start), true)
+			},
+			Some(Token::Keyword(Keyword::New)) => self.parse_new(),
+			Some(Token::Keyword(keyword)) if is_analog_builtin(*keyword) => {
+				let name = Identifier::Simple(keyword.as_str().as_bytes().into());
+				self.bump();
+
+				self.parse_postfix(Spanned::new(Expression::Identifier(name), start), true)
+			},
+			Some(Token::Keyword(Keyword::Signed | Keyword::Unsigned | Keyword::Const))
+				if self.is_control_at(1, Control::Apostrophe) =>
+			{
+				let target = match self.peek() {
+					Some(Token::Keyword(Keyword::Signed)) => CastTarget::Signing(Signing::Signed),
+					Some(Token::Keyword(Keyword::Unsigned)) => {
+						CastTarget::Signing(Signing::Unsigned)
+					},
+					_ => CastTarget::Const,
+				};
+				self.position += 2;
+				let value = self.parse_cast_value()?;
+
+				Ok(Spanned::new(
+					Expression::Cast {
+						target: Box::new(Spanned::new(target, start)),
+						value:  Box::new(value),
+					},
+					self.span_from(start),
+				))
+			},
+			Some(Token::Keyword(Keyword::Void | Keyword::Type)) => self.parse_type_expression(),
+			_ if self.is_data_type_keyword(0) => self.parse_type_expression(),
+			Some(Token::Control(Control::Dollar)) => {
+				self.bump();
+				Ok(Spanned::new(Expression::Unbounded, start))
+			},
+			Some(Token::Control(Control::Apostrophe)) => self.parse_apostrophe_primary(),
+			Some(Token::Control(Control::BraceOpen)) => self.parse_concatenation(),
+			Some(Token::Control(Control::ParenOpen)) => {
+				self.bump();
+				let mut inner = self.parse_mintypmax_expression()?;
+
+				// An assignment used as an expression, e.g. `if ((a = b))`
+				if let Some(operator) = self.peek_assignment_operator() {
+					let operator = Spanned::new(operator, self.span());
+					self.bump();
+					let value = self.parse_expression()?;
+
+					let span = self.span_from(*inner.span());
+					self.require_std(
+						LanguageStd::SYSTEM_VERILOG_STDS,
+						span,
+						"Assignments within expressions",
+					);
+
+					inner = Spanned::new(
+						Expression::Assign {
+							lvalue: Box::new(inner),
+							operator,
+							value: Box::new(value),
+						},
+						span,
+					);
+				}
+				self.expect_control(Control::ParenClose)?;
+
+				Ok(Spanned::new(
+					Expression::Parenthesized(Box::new(inner)),
+					self.span_from(start),
+				))
+			},
+			Some(Token::CompilerDirective(CompilerDirective::TextMacro(text_macro))) => {
+				let text_macro = text_macro.clone();
+				self.bump();
+				Ok(Spanned::new(Expression::MacroUsage(text_macro), start))
+			},
+			_ => self.unexpected("an expression"),
+		}
+	}
+
+	/// Parse a builtin data type used as an expression, e.g. the `int` in `int'(x)`
+	fn parse_type_expression(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+		let Some(data_type) = self.parse_variable_type()? else {
+			return self.unexpected("a data type");
+		};
+
+		Ok(Spanned::new(
+			Expression::DataType(Box::new(data_type)),
+			self.span_from(start),
+		))
+	}
+
+	/// Parse an unbased unsized literal like `'1` or an untyped assignment pattern
+	fn parse_apostrophe_primary(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.expect_control(Control::Apostrophe)?;
+
+		if self.is_control(Control::BraceOpen) {
+			let items = self.parse_assignment_pattern()?;
+			return Ok(Spanned::new(
+				Expression::AssignmentPattern { data_type: None, items },
+				self.span_from(start),
+			));
+		}
+
+		let digit =
+			match self.peek() {
+				Some(
+					Token::UnsignedNumber(value) | Token::Identifier(Identifier::Simple(value)),
+				) if self.is_adjacent() => match &value[..] {
+					b"0" => b'0',
+					b"1" => b'1',
+					b"x" | b"X" => b'x',
+					b"z" | b"Z" => b'z',
+					_ => return self.unexpected("`0`, `1`, `x`, or `z`"),
+				},
+				_ => return self.unexpected("`{`, `0`, `1`, `x`, or `z`"),
+			};
+		self.bump();
+
+		Ok(Spanned::new(
+			Expression::Literal(Literal::UnbasedUnsized(digit)),
+			self.span_from(start),
+		))
+	}
+
+	/// Returns true if the current token directly follows the previous one with no whitespace
+	pub(crate) fn is_adjacent(&self) -> bool {
+		let previous = self
+			.position
+			.checked_sub(1)
+			.and_then(|idx| self.tokens.get(idx));
+
+		match (previous, self.tokens.get(self.position)) {
+			(Some(previous), Some(current)) => previous.span().end() == current.span().begin(),
+			_ => false,
+		}
+	}
+
+	/// Parse a time literal, e.g. `1ns` or `2.5ps`, if one is next
+	///
+	/// IEEE 1800-2017 § A.8.4
+	fn parse_time_literal(&mut self) -> ParseResult<Option<Spanned<Expression>>> {
+		if !matches!(self.peek(), Some(Token::UnsignedNumber(_) | Token::Real(_))) {
+			return Ok(None);
+		}
+
+		let start = self.span();
+		let unit = match self.tokens.get(self.position + 1) {
+			Some(next) if next.span().begin() == start.end() => match next.inner() {
+				Token::Identifier(Identifier::Simple(unit)) => time_unit_for(unit),
+				_ => None,
+			},
+			_ => None,
+		};
+		let Some(unit) = unit else {
+			return Ok(None);
+		};
+
+		let value = self.source_text(&start);
+		self.position += 2;
+
+		let span = self.span_from(start);
+		self.require_std(LanguageStd::SYSTEM_VERILOG_STDS, span, "Time literals");
+
+		Ok(Some(Spanned::new(
+			Expression::Literal(Literal::Time { value, unit }),
+			span,
+		)))
+	}
+
+	/// IEEE 1800-2017 § A.8.2
+	fn parse_new(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.expect_keyword(Keyword::New)?;
+
+		let size = if self.eat_control(Control::BracketOpen) {
+			let size = self.parse_expression()?;
+			self.expect_control(Control::BracketClose)?;
+			Some(Box::new(size))
+		} else {
+			None
+		};
+
+		let arguments = if self.is_control(Control::ParenOpen) {
+			self.parse_call_arguments()?
+		} else {
+			Vec::new()
+		};
+
+		Ok(Spanned::new(
+			Expression::New { size, arguments },
+			self.span_from(start),
+		))
+	}
+
+	/// IEEE 1364-2005 § A.8.7
+	pub(crate) fn parse_number(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+
+		let size = match self.peek() {
+			Some(Token::UnsignedNumber(size)) => {
+				let size = size.clone();
+				self.bump();
+
+				if !matches!(self.peek(), Some(Token::BasedLiteralSpecifier(_))) {
+					return Ok(Spanned::new(
+						Expression::Literal(Literal::Unsigned(size)),
+						start,
+					));
+				}
+
+				Some(size)
+			},
+			_ => None,
+		};
+
+		let Some(Token::BasedLiteralSpecifier(specifier)) = self.peek() else {
+			return self.unexpected("a number");
+		};
+		let specifier = *specifier;
+		self.bump();
+
+		let value = match self.peek() {
+			Some(Token::Number(value) | Token::UnsignedNumber(value)) => value.clone(),
+			_ => return self.unexpected("a based number value"),
+		};
+		self.bump();
+
+		Ok(Spanned::new(
+			Expression::Literal(Literal::Based { size, specifier, value }),
+			self.span_from(start),
+		))
+	}
+
+	fn parse_system_call(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+
+		let Some(Token::SystemFunc(name)) = self.peek() else {
+			return self.unexpected("a system function");
+		};
+		let name = Spanned::new(name.clone(), start);
+		self.bump();
+
+		let arguments = self.parse_system_arguments()?;
+
+		Ok(Spanned::new(
+			Expression::SystemCall { name, arguments },
+			self.span_from(start),
+		))
+	}
+
+	/// Parse the optional argument list of a system task or function call
+	pub(crate) fn parse_system_arguments(
+		&mut self,
+	) -> ParseResult<Vec<Option<Spanned<Expression>>>> {
+		let mut arguments = Vec::new();
+
+		if !self.eat_control(Control::ParenOpen) {
+			return Ok(arguments);
+		}
+
+		if self.eat_control(Control::ParenClose) {
+			return Ok(arguments);
+		}
+
+		loop {
+			if self.is_control(Control::Comma) || self.is_control(Control::ParenClose) {
+				arguments.push(None);
+			} else {
+				arguments.push(Some(self.parse_expression()?));
+			}
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(arguments)
+	}
+
+	/// Parse a comma separated list of arguments enclosed in parentheses
+	pub(crate) fn parse_call_arguments(&mut self) -> ParseResult<Vec<Spanned<Expression>>> {
+		let mut arguments = Vec::new();
+
+		self.expect_control(Control::ParenOpen)?;
+		if self.eat_control(Control::ParenClose) {
+			return Ok(arguments);
+		}
+
+		loop {
+			arguments.push(self.parse_expression()?);
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(arguments)
+	}
+
+	/// Parse any hierarchical name components, selects, and calls following `expression`
+	pub(crate) fn parse_postfix(
+		&mut self,
+		mut expression: Spanned<Expression>,
+		allow_call: bool,
+	) -> ParseResult<Spanned<Expression>> {
+		let start = *expression.span();
+
+		loop {
+			if self.is_control(Control::Dot) && self.is_member_name(1) {
+				self.bump();
+				let member = self.expect_member_name()?;
+
+				expression = Spanned::new(
+					Expression::Member { target: Box::new(expression), member },
+					self.span_from(start),
+				);
+			} else if self.is_operator(Operator::ClassScopeResolution) && self.is_member_name(1) {
+				self.bump();
+				let member = self.expect_member_name()?;
+
+				expression = Spanned::new(
+					Expression::Scope { scope: Box::new(expression), member },
+					self.span_from(start),
+				);
+			} else if self.is_control(Control::BracketOpen) && !self.is_repetition() {
+				let select = self.parse_select()?;
+
+				expression = Spanned::new(
+					Expression::Select {
+						target: Box::new(expression),
+						select: Box::new(select),
+					},
+					self.span_from(start),
+				);
+			} else if allow_call &&
+				self.is_control(Control::ParenOpen) &&
+				matches!(
+					expression.inner(),
+					Expression::Identifier(_) |
+						Expression::Member { .. } |
+						Expression::Scope { .. }
+				) {
+				let arguments = self.parse_call_arguments()?;
+
+				expression = Spanned::new(
+					Expression::Call {
+						target: Box::new(expression),
+						attributes: Vec::new(),
+						arguments,
+					},
+					self.span_from(start),
+				);
+			} else if allow_call &&
+				self.is_keyword(Keyword::With) &&
+				matches!(
+					expression.inner(),
+					Expression::Call { .. } | Expression::Member { .. }
+				) {
+				expression = self.parse_with_clause(expression)?;
+			} else {
+				break;
+			}
+		}
+
+		Ok(expression)
+	}
+
+	/// Parse the `with (...)` of an array method or the `with { ... }` of a randomize call
+	///
+	/// IEEE 1800-2017 § A.8.2
+	pub(crate) fn parse_with_clause(
+		&mut self,
+		target: Spanned<Expression>,
+	) -> ParseResult<Spanned<Expression>> {
+		let start = *target.span();
+		self.expect_keyword(Keyword::With)?;
+
+		if self.is_control(Control::BraceOpen) {
+			let constraints = self.parse_constraint_block()?;
+
+			return Ok(Spanned::new(
+				Expression::RandomizeWith { call: Box::new(target), constraints },
+				self.span_from(start),
+			));
+		}
+
+		self.expect_control(Control::ParenOpen)?;
+		let expression = self.parse_expression()?;
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(Spanned::new(
+			Expression::With {
+				target:     Box::new(target),
+				expression: Box::new(expression),
+			},
+			self.span_from(start),
+		))
+	}
+
+	/// Returns true if the token at `offset` can name a member, some built-in methods share their
+	/// names with keywords
+	fn is_member_name(&self, offset: usize) -> bool {
+		matches!(
+			self.peek_nth(offset),
+			Some(
+				Token::Identifier(_) |
+					Token::Keyword(
+						Keyword::New | Keyword::And | Keyword::Or | Keyword::Xor | Keyword::Unique
+					)
+			)
+		)
+	}
+
+	fn expect_member_name(&mut self) -> ParseResult<Spanned<Identifier>> {
+		let Some(Token::Keyword(keyword)) = self.peek() else {
+			return self.expect_identifier();
+		};
+
+		let ident = Identifier::Simple(keyword.as_str().as_bytes().into());
+		let span = self.span();
+		self.bump();
+
+		Ok(Spanned::new(ident, span))
+	}
+
+	/// Returns true if the current `[` opens a sequence repetition like `[*2]` rather than a select
+	pub(crate) fn is_repetition(&self) -> bool {
+		if !self.is_control(Control::BracketOpen) {
+			return false;
+		}
+
+		match self.peek_nth(1) {
+			Some(Token::Operator(
+				Operator::Asterisk | Operator::Equals | Operator::EventTrigger,
+			)) => true,
+			Some(Token::Operator(Operator::Plus)) => self.is_control_at(2, Control::BracketClose),
+			_ => false,
+		}
+	}
+
+	/// Parse a hierarchical identifier without any selects, e.g. `a.b.c`
+	pub(crate) fn parse_hierarchical_identifier(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+		let ident = self.expect_identifier()?;
+		let mut expression = Spanned::new(Expression::Identifier(ident.as_inner()), start);
+
+		while self.is_control(Control::Dot) &&
+			matches!(self.peek_nth(1), Some(Token::Identifier(_)))
+		{
+			self.bump();
+			let member = self.expect_identifier()?;
+
+			expression = Spanned::new(
+				Expression::Member { target: Box::new(expression), member },
+				self.span_from(start),
+			);
+		}
+
+		Ok(expression)
+	}
+
+	fn parse_select(&mut self) -> ParseResult<Spanned<Select>> {
+		let start = self.expect_control(Control::BracketOpen)?;
+		let first = self.parse_expression()?;
+
+		let select = if self.eat_control(Control::Colon) {
+			Select::Range { msb: first, lsb: self.parse_expression()? }
+		} else if self.is_operator(Operator::IndexedPartPos) {
+			self.bump();
+			Select::IndexedUp { base: first, width: self.parse_expression()? }
+		} else if self.is_operator(Operator::IndexedPartNeg) {
+			self.bump();
+			Select::IndexedDown { base: first, width: self.parse_expression()? }
+		} else {
+			Select::Bit(first)
+		};
+
+		self.expect_control(Control::BracketClose)?;
+
+		Ok(Spanned::new(select, self.span_from(start)))
+	}
+
+	fn parse_concatenation(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.expect_control(Control::BraceOpen)?;
+
+		if self.is_operator(Operator::ShiftLeft) || self.is_operator(Operator::ShiftRight) {
+			return self.parse_streaming_concatenation(start);
+		}
+
+		if self.eat_control(Control::BraceClose) {
+			let span = self.span_from(start);
+			self.require_std(
+				LanguageStd::SYSTEM_VERILOG_STDS,
+				span,
+				"Empty concatenations",
+			);
+
+			return Ok(Spanned::new(Expression::Concatenation(Vec::new()), span));
+		}
+
+		let first = self.parse_expression()?;
+
+		if self.is_control(Control::BraceOpen) {
+			let inner = self.parse_concatenation()?;
+			self.expect_control(Control::BraceClose)?;
+
+			let items = match inner.as_inner() {
+				Expression::Concatenation(items) => items,
+				other => vec![Spanned::new(other, start)],
+			};
+
+			return Ok(Spanned::new(
+				Expression::Replication { count: Box::new(first), items },
+				self.span_from(start),
+			));
+		}
+
+		let mut items = vec![first];
+		while self.eat_control(Control::Comma) {
+			items.push(self.parse_expression()?);
+		}
+
+		self.expect_control(Control::BraceClose)?;
+
+		Ok(Spanned::new(
+			Expression::Concatenation(items),
+			self.span_from(start),
+		))
+	}
+
+	/// Parse the remainder of a streaming concatenation, the opening `{` has been consumed
+	///
+	/// IEEE 1800-2017 § A.8.1
+	fn parse_streaming_concatenation(&mut self, start: Span) -> ParseResult<Spanned<Expression>> {
+		let direction = if self.eat_operator(Operator::ShiftLeft) {
+			StreamDirection::Left
+		} else {
+			self.expect_operator(Operator::ShiftRight)?;
+			StreamDirection::Right
+		};
+
+		let slice = if self.is_control(Control::BraceOpen) {
+			None
+		} else {
+			Some(Box::new(self.parse_expression()?))
+		};
+
+		self.expect_control(Control::BraceOpen)?;
+		let mut items = vec![self.parse_expression()?];
+		while self.eat_control(Control::Comma) {
+			items.push(self.parse_expression()?);
+		}
+		self.expect_control(Control::BraceClose)?;
+		self.expect_control(Control::BraceClose)?;
+
+		let span = self.span_from(start);
+		self.require_std(
+			LanguageStd::SYSTEM_VERILOG_STDS,
+			span,
+			"Streaming concatenations",
+		);
+
+		Ok(Spanned::new(
+			Expression::Streaming { direction, slice, items },
+			span,
+		))
+	}
+
+	/// Parse the target of an assignment, a hierarchical name with selects or a concatenation
+	///
+	/// IEEE 1364-2005 § A.8.5
+	pub(crate) fn parse_lvalue(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+
+		match self.peek() {
+			Some(Token::Control(Control::BraceOpen)) => {
+				self.bump();
+
+				let mut items = vec![self.parse_lvalue()?];
+				while self.eat_control(Control::Comma) {
+					items.push(self.parse_lvalue()?);
+				}
+
+				self.expect_control(Control::BraceClose)?;
+
+				Ok(Spanned::new(
+					Expression::Concatenation(items),
+					self.span_from(start),
+				))
+			},
+			Some(Token::Identifier(_)) => {
+				let ident = self.expect_identifier()?;
+				let expression = Spanned::new(Expression::Identifier(ident.as_inner()), start);
+				self.parse_postfix(expression, false)
+			},
+			Some(Token::Keyword(Keyword::This | Keyword::Super)) => {
+				let expression = if self.eat_keyword(Keyword::This) {
+					Expression::This
+				} else {
+					self.bump();
+					Expression::Super
+				};
+
+				self.parse_postfix(Spanned::new(expression, start), false)
+			},
+			Some(Token::CompilerDirective(CompilerDirective::TextMacro(text_macro))) => {
+				let text_macro = text_macro.clone();
+				self.bump();
+				Ok(Spanned::new(Expression::MacroUsage(text_macro), start))
+			},
+			_ => self.unexpected("an identifier or concatenation"),
+		}
+	}
+
+	/// IEEE 1364-2005 § A.2.2.3
+	pub(crate) fn parse_delay(&mut self) -> ParseResult<Spanned<Delay>> {
+		let start = self.expect_control(Control::Octothorp)?;
+
+		let values = if self.eat_control(Control::ParenOpen) {
+			let mut values = vec![self.parse_mintypmax_expression()?];
+			while self.eat_control(Control::Comma) {
+				values.push(self.parse_mintypmax_expression()?);
+			}
+
+			self.expect_control(Control::ParenClose)?;
+			values
+		} else {
+			vec![self.parse_delay_value()?]
+		};
+
+		Ok(Spanned::new(Delay { values }, self.span_from(start)))
+	}
+
+	/// Parse a single delay value, a number or an identifier
+	pub(crate) fn parse_delay_value(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+
+		if let Some(literal) = self.parse_time_literal()? {
+			return Ok(literal);
+		}
+
+		match self.peek() {
+			Some(Token::UnsignedNumber(_)) => self.parse_number(),
+			Some(Token::Real(value)) => {
+				let value = *value;
+				self.bump();
+				Ok(Spanned::new(
+					Expression::Literal(Literal::Real(value)),
+					start,
+				))
+			},
+			Some(Token::Identifier(_)) => self.parse_hierarchical_identifier(),
+			Some(Token::CompilerDirective(CompilerDirective::TextMacro(text_macro))) => {
+				let text_macro = text_macro.clone();
+				self.bump();
+				Ok(Spanned::new(Expression::MacroUsage(text_macro), start))
+			},
+			_ => self.unexpected("a delay value"),
+		}
+	}
+
+	/// IEEE 1364-2005 § A.6.5
+	pub(crate) fn parse_event_control(&mut self) -> ParseResult<Spanned<EventControl>> {
+		let start = self.expect_control(Control::At)?;
+
+		let wildcard = match (self.peek(), self.peek_nth(1), self.peek_nth(2)) {
+			(Some(Token::Operator(Operator::Asterisk)), ..) => Some(1),
+			(
+				Some(Token::Control(Control::AttributeOpen)),
+				Some(Token::Control(Control::ParenClose)),
+				_,
+			) |
+			(
+				Some(Token::Control(Control::ParenOpen)),
+				Some(Token::Control(Control::AttributeClose)),
+				_,
+			) => Some(2),
+			(
+				Some(Token::Control(Control::ParenOpen)),
+				Some(Token::Operator(Operator::Asterisk)),
+				Some(Token::Control(Control::ParenClose)),
+			) => Some(3),
+			_ => None,
+		};
+
+		if let Some(count) = wildcard {
+			self.require_std(VERILOG_01_STDS, start, "Implicit event expression lists");
+			self.position += count;
+
+			return Ok(Spanned::new(EventControl::Wildcard, self.span_from(start)));
+		}
+
+		if !self.eat_control(Control::ParenOpen) {
+			let name = self.parse_hierarchical_identifier()?;
+			return Ok(Spanned::new(
+				EventControl::Identifier(name),
+				self.span_from(start),
+			));
+		}
+
+		let mut expressions = Vec::new();
+		loop {
+			let event_start = self.span();
+
+			let edge = if self.eat_keyword(Keyword::PosEdge) {
+				Some(EdgeIdentifier::PosEdge)
+			} else if self.eat_keyword(Keyword::NegEdge) {
+				Some(EdgeIdentifier::NegEdge)
+			} else if self.is_keyword(Keyword::Edge) {
+				self.require_std(
+					SYSTEM_VERILOG_09_STDS,
+					self.span(),
+					"Edge event expressions",
+				);
+				self.bump();
+				Some(EdgeIdentifier::Edge)
+			} else {
+				None
+			};
+
+			let expression = self.parse_expression()?;
+
+			let iff = if self.eat_keyword(Keyword::Iff) {
+				Some(self.parse_expression()?)
+			} else {
+				None
+			};
+
+			expressions.push(Spanned::new(
+				EventExpression { edge, expression, iff },
+				self.span_from(event_start),
+			));
+
+			if self.eat_keyword(Keyword::Or) {
+				continue;
+			}
+
+			if self.is_control(Control::Comma) {
+				self.require_std(
+					VERILOG_01_STDS,
+					self.span(),
+					"Comma separated event expressions",
+				);
+				self.bump();
+				continue;
+			}
+
+			break;
+		}
+
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(Spanned::new(
+			EventControl::Expression(expressions),
+			self.span_from(start),
+		))
+	}
+}
+
+fn time_unit_for(unit: &[u8]) -> Option<TimeUnit> {
+	Some(match unit {
+		b"s" => TimeUnit::Seconds,
+		b"ms" => TimeUnit::Milliseconds,
+		b"us" => TimeUnit::Microseconds,
+		b"ns" => TimeUnit::Nanoseconds,
+		b"ps" => TimeUnit::Picoseconds,
+		b"fs" => TimeUnit::Femtoseconds,
+		b"step" => TimeUnit::Step,
+		_ => return None,
+	})
+}
@@ -0,0 +1,1586 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::{
+	LanguageStd,
+	lang::{
+		ast::{
+			AssignmentOperator, Attribute, Bind, Connections, ContinuousAssign, DataType,
+			Declarator, Dimension, DpiDeclaration, DpiDirection, DpiProperty, Expression, Function,
+			GateInstance, GateInstantiation, GateType, GenerateBlock, GenerateCase,
+			GenerateCaseItem, GenerateFor, GenerateIf, Instance, Instantiation, Item, ItemKind,
+			Lifetime, ModportDeclaration, ModportPort, ModportPortKind, NamedConnection,
+			NetDeclaration, PackageImport, ParameterDeclaration, PortDeclaration, PortDirection,
+			Statement, StatementKind, Strength, SubroutineKind, SubroutinePrototype, Task,
+			TimeUnits, Typedef, VariableDeclaration, VectorKind,
+		},
+		cst::NodeKind,
+		keywords::Keyword,
+		parser::{
+			SYSTEM_VERILOG_09_STDS, VERILOG_01_STDS, VERILOG_05_STDS, VerilogParser,
+			error::{ParseError, ParseResult},
+		},
+		tokenizer::token::Token,
+		types::{
+			ChargeStrength, Control, DriveStrength, HighStrength, Identifier, LowStrength, NetType,
+			Operator,
+		},
+	},
+};
+
+/// The optional name and range of a gate or module instance
+type InstanceName = (Option<Spanned<Identifier>>, Option<Spanned<Dimension>>);
+
+impl VerilogParser {
+	/// IEEE 1364-2005 § A.1.4
+	pub(crate) fn parse_module_item(&mut self) -> ParseResult<Spanned<Item>> {
+		self.parse_item(false)
+	}
+
+	/// Parse a module item, skipping over the empty `;` items permitted by SystemVerilog
+	pub(crate) fn parse_optional_module_item(&mut self) -> ParseResult<Option<Spanned<Item>>> {
+		if self.is_system_verilog() && self.eat_control(Control::Semicolon) {
+			return Ok(None);
+		}
+
+		self.parse_module_item().map(Some)
+	}
+
+	/// Parse an item inside of a generate region or block
+	fn parse_generate_item(&mut self) -> ParseResult<Option<Spanned<Item>>> {
+		self.parse_item(true).map(Some)
+	}
+
+	/// Parse a module item, or an item inside of a generate region or block if `generate` is set
+	fn parse_item(&mut self, generate: bool) -> ParseResult<Spanned<Item>> {
+		let start = self.span();
+		let attributes = self.parse_attributes()?;
+		let kind = self.parse_item_kind(generate)?;
+
+		let item = Spanned::new(Item { attributes, kind }, self.span_from(start));
+		Ok(self.node(NodeKind::Item, item))
+	}
+
+	pub(crate) fn parse_item_kind(&mut self, generate: bool) -> ParseResult<ItemKind> {
+		let Some(Token::Keyword(keyword)) = self.peek() else {
+			if self.is_identifier() {
+				return self.parse_identifier_item();
+			}
+
+			return self.unexpected("a module item");
+		};
+		let keyword = *keyword;
+
+		if let Some(net_type) = net_type_for(keyword) {
+			return Ok(ItemKind::Net(self.parse_net_declaration(net_type)?));
+		}
+
+		if let Some(gate) = gate_type_for(keyword) {
+			return Ok(ItemKind::Gate(self.parse_gate_instantiation(gate)?));
+		}
+
+		match keyword {
+			Keyword::Input | Keyword::Output | Keyword::InOut | Keyword::Ref => {
+				let mut port = self.parse_port_declaration_head(Vec::new())?;
+				port.declarators = self.parse_declarators()?;
+				self.expect_control(Control::Semicolon)?;
+
+				Ok(ItemKind::Port(port))
+			},
+			Keyword::Parameter | Keyword::LocalParam => {
+				let parameter = self.parse_parameter_declaration()?;
+				self.expect_control(Control::Semicolon)?;
+
+				Ok(ItemKind::Parameter(parameter))
+			},
+			Keyword::SpecParam => Ok(ItemKind::Specparam(self.parse_specparam_declaration()?)),
+			Keyword::Genvar => {
+				self.require_std(VERILOG_01_STDS, self.span(), "Genvar declarations");
+				self.bump();
+
+				let mut names = vec![self.expect_identifier()?];
+				while self.eat_control(Control::Comma) {
+					names.push(self.expect_identifier()?);
+				}
+				self.expect_control(Control::Semicolon)?;
+
+				Ok(ItemKind::Genvar(names))
+			},
+			Keyword::DefParam => {
+				self.bump();
+
+				let mut assignments = vec![self.parse_assignment()?];
+				while self.eat_control(Control::Comma) {
+					assignments.push(self.parse_assignment()?);
+				}
+				self.expect_control(Control::Semicolon)?;
+
+				Ok(ItemKind::Defparam(assignments))
+			},
+			Keyword::Assign => Ok(ItemKind::ContinuousAssign(self.parse_continuous_assign()?)),
+			Keyword::Initial => {
+				self.bump();
+				Ok(ItemKind::Initial(self.parse_statement()?))
+			},
+			Keyword::Always => {
+				self.bump();
+				Ok(ItemKind::Always(self.parse_statement()?))
+			},
+			Keyword::AlwaysComb => {
+				self.bump();
+				Ok(ItemKind::AlwaysComb(self.parse_statement()?))
+			},
+			Keyword::AlwaysFF => {
+				self.bump();
+				Ok(ItemKind::AlwaysFF(self.parse_statement()?))
+			},
+			Keyword::AlwaysLatch => {
+				self.bump();
+				Ok(ItemKind::AlwaysLatch(self.parse_statement()?))
+			},
+			Keyword::Final => {
+				self.bump();
+				Ok(ItemKind::Final(self.parse_statement()?))
+			},
+			Keyword::Ground | Keyword::Branch | Keyword::Analog => self.parse_analog_item(),
+			Keyword::Task => Ok(ItemKind::Task(self.parse_task()?)),
+			Keyword::Function => Ok(ItemKind::Function(self.parse_function()?)),
+			Keyword::Specify => Ok(ItemKind::Specify(self.parse_specify_block()?)),
+			Keyword::Generate => {
+				self.require_std(VERILOG_01_STDS, self.span(), "Generate regions");
+				self.bump();
+
+				let (items, _) =
+					self.parse_list(&[Keyword::EndGenerate], Self::parse_generate_item, || {
+						Item::new(ItemKind::Error)
+					});
+
+				Ok(ItemKind::GenerateRegion(items))
+			},
+			Keyword::For | Keyword::If | Keyword::Case => {
+				if !generate {
+					self.require_std(
+						VERILOG_05_STDS,
+						self.span(),
+						"Generate constructs outside of generate regions",
+					);
+				}
+
+				match keyword {
+					Keyword::For => Ok(ItemKind::GenerateFor(Box::new(self.parse_generate_for()?))),
+					Keyword::If => Ok(ItemKind::GenerateIf(self.parse_generate_if()?)),
+					_ => Ok(ItemKind::GenerateCase(self.parse_generate_case()?)),
+				}
+			},
+			Keyword::Begin if generate => Ok(ItemKind::GenerateBlock(
+				self.parse_generate_block()?.as_inner(),
+			)),
+			Keyword::TypeDef => Ok(ItemKind::Typedef(self.parse_typedef()?)),
+			Keyword::Import | Keyword::Export
+				if matches!(self.peek_nth(1), Some(Token::SingleQuotedString(_))) =>
+			{
+				Ok(ItemKind::Dpi(self.parse_dpi_declaration()?))
+			},
+			Keyword::Import => Ok(ItemKind::Import(self.parse_package_imports()?)),
+			Keyword::Export => {
+				self.require_std(SYSTEM_VERILOG_09_STDS, self.span(), "Package exports");
+				self.bump();
+
+				let exports = if self.eat_operator(Operator::WildcardExport) {
+					Vec::new()
+				} else {
+					self.parse_package_import_items()?
+				};
+				self.expect_control(Control::Semicolon)?;
+
+				Ok(ItemKind::Export(exports))
+			},
+			Keyword::ModPort => Ok(ItemKind::Modport(self.parse_modport_declaration()?)),
+			Keyword::Class => Ok(ItemKind::Class(self.parse_class()?)),
+			Keyword::Virtual | Keyword::Interface
+				if matches!(self.peek_nth(1), Some(Token::Keyword(Keyword::Class))) =>
+			{
+				Ok(ItemKind::Class(self.parse_class()?))
+			},
+			Keyword::CoverGroup => Ok(ItemKind::CoverGroup(self.parse_covergroup()?)),
+			Keyword::Assert | Keyword::Assume | Keyword::Cover | Keyword::Restrict => {
+				Ok(ItemKind::Assertion(self.parse_assertion()?))
+			},
+			Keyword::Property => Ok(ItemKind::Property(self.parse_property_declaration()?)),
+			Keyword::Sequence => Ok(ItemKind::Sequence(self.parse_sequence_declaration()?)),
+			Keyword::Let => Ok(ItemKind::Let(self.parse_let_declaration()?)),
+			Keyword::Clocking => Ok(ItemKind::Clocking(self.parse_clocking_block(false, false)?)),
+			Keyword::Default => {
+				self.bump();
+
+				if self.eat_keyword(Keyword::Disable) {
+					self.expect_keyword(Keyword::Iff)?;
+					let condition = self.parse_expression()?;
+					self.expect_control(Control::Semicolon)?;
+
+					return Ok(ItemKind::DefaultDisable(condition));
+				}
+
+				// `default clocking name;` refers to a clocking block declared elsewhere
+				if self.is_keyword(Keyword::Clocking) &&
+					self.is_identifier_at(1) &&
+					self.is_control_at(2, Control::Semicolon)
+				{
+					self.bump();
+					let name = self.expect_identifier()?;
+					self.bump();
+
+					return Ok(ItemKind::DefaultClocking(name));
+				}
+
+				Ok(ItemKind::Clocking(self.parse_clocking_block(true, false)?))
+			},
+			Keyword::Global => {
+				self.require_std(
+					SYSTEM_VERILOG_09_STDS,
+					self.span(),
+					"Global clocking blocks",
+				);
+				self.bump();
+
+				Ok(ItemKind::Clocking(self.parse_clocking_block(false, true)?))
+			},
+			Keyword::TimeUnit | Keyword::TimePrecision => {
+				Ok(ItemKind::TimeUnits(self.parse_time_units()?))
+			},
+			Keyword::Bind => Ok(ItemKind::Bind(self.parse_bind()?)),
+			Keyword::Const | Keyword::Var | Keyword::Static | Keyword::Automatic => {
+				Ok(ItemKind::Variable(self.parse_variable_declaration()?))
+			},
+			_ if self.is_data_type_keyword(0) => {
+				Ok(ItemKind::Variable(self.parse_variable_declaration()?))
+			},
+			_ => self.unexpected("a module item"),
+		}
+	}
+
+	/// Parse an item starting with an identifier, which is either an instantiation or, in
+	/// SystemVerilog, a variable of a user defined type or a labeled assertion, or in
+	/// Verilog-AMS, a net of a discipline
+	fn parse_identifier_item(&mut self) -> ParseResult<ItemKind> {
+		if self.is_verilog_ams() && self.is_discipline_net_declaration() {
+			return Ok(ItemKind::DisciplineNet(self.parse_discipline_net()?));
+		}
+
+		if !self.is_system_verilog() {
+			return Ok(ItemKind::Instantiation(self.parse_instantiation()?));
+		}
+
+		if self.is_control_at(1, Control::Colon) &&
+			matches!(
+				self.peek_nth(2),
+				Some(Token::Keyword(
+					Keyword::Assert | Keyword::Assume | Keyword::Cover | Keyword::Restrict
+				))
+			) {
+			let label = self.expect_identifier()?;
+			self.bump();
+
+			let mut assertion = self.parse_assertion()?;
+			assertion.label = Some(label);
+
+			return Ok(ItemKind::Assertion(assertion));
+		}
+
+		// NOTE(aki): `name #(...) inst (...)` and `type_t #(...) name;` only differ in what follows
+		// the second name, so look past any instance range for the port connections
+		if let Some(mut offset) = self.user_type_lookahead(0) {
+			offset += 1;
+			while self.is_control_at(offset, Control::BracketOpen) {
+				match self.skip_balanced(offset) {
+					Some(next) => offset = next,
+					None => break,
+				}
+			}
+
+			if !self.is_control_at(offset, Control::ParenOpen) {
+				return Ok(ItemKind::Variable(self.parse_variable_declaration()?));
+			}
+		}
+
+		Ok(ItemKind::Instantiation(self.parse_instantiation()?))
+	}
+
+	/// Parse a block item declaration if one is next, tasks and functions may also have ports
+	///
+	/// IEEE 1364-2005 § A.2.8, IEEE 1800-2017 § A.2.8
+	pub(crate) fn parse_block_item_declaration(
+		&mut self,
+		allow_ports: bool,
+	) -> ParseResult<Option<Spanned<Item>>> {
+		let lookahead = self.attributes_lookahead();
+
+		let declaration = match self.peek_nth(lookahead) {
+			Some(Token::Keyword(Keyword::Parameter | Keyword::LocalParam)) => true,
+			Some(Token::Keyword(
+				Keyword::Input | Keyword::Output | Keyword::InOut | Keyword::Ref,
+			)) => allow_ports,
+			Some(Token::Keyword(
+				Keyword::Const |
+				Keyword::Var |
+				Keyword::Static |
+				Keyword::Automatic |
+				Keyword::TypeDef |
+				Keyword::Let |
+				Keyword::Import,
+			)) => true,
+			Some(Token::Keyword(_)) => self.is_data_type_keyword(lookahead),
+			Some(Token::Identifier(_)) => {
+				self.is_system_verilog() && self.user_type_lookahead(lookahead).is_some()
+			},
+			_ => false,
+		};
+
+		if !declaration {
+			return Ok(None);
+		}
+
+		self.parse_item(false).map(Some)
+	}
+
+	/// Returns true if the token at `offset` begins an ANSI style port declaration
+	pub(crate) fn is_port_declaration_start(&self, offset: usize) -> bool {
+		match self.peek_nth(offset) {
+			Some(Token::Keyword(
+				Keyword::Input | Keyword::Output | Keyword::InOut | Keyword::Ref,
+			)) => true,
+			_ if !self.is_system_verilog() => false,
+			Some(Token::Keyword(keyword)) => {
+				net_type_for(*keyword).is_some() ||
+					matches!(
+						keyword,
+						Keyword::Var |
+							Keyword::Const | Keyword::Interface |
+							Keyword::Signed | Keyword::Unsigned
+					) || self.is_data_type_keyword(offset)
+			},
+			Some(Token::Control(Control::BracketOpen)) => true,
+			_ => self.user_type_lookahead(offset).is_some(),
+		}
+	}
+
+	/// Parse the direction and type of a port declaration, leaving the declarators empty
+	fn parse_port_declaration_head(
+		&mut self,
+		attributes: Vec<Spanned<Attribute>>,
+	) -> ParseResult<PortDeclaration> {
+		// NOTE(aki): `const` is only valid as part of a `const ref` subroutine port
+		let mut var = self.eat_keyword(Keyword::Const);
+
+		let direction = match self.peek() {
+			Some(Token::Keyword(Keyword::Input)) => Some(PortDirection::Input),
+			Some(Token::Keyword(Keyword::Output)) => Some(PortDirection::Output),
+			Some(Token::Keyword(Keyword::InOut)) => Some(PortDirection::InOut),
+			Some(Token::Keyword(Keyword::Ref)) => Some(PortDirection::Ref),
+			_ => None,
+		};
+		if direction.is_some() {
+			self.bump();
+		} else if !self.is_system_verilog() {
+			return self.unexpected("`input`, `output`, or `inout`");
+		}
+
+		// NOTE(aki): A discipline is only followed by the range or name of the port
+		let discipline = if self.is_verilog_ams() &&
+			self.is_identifier() &&
+			(self.is_identifier_at(1) || self.is_control_at(1, Control::BracketOpen))
+		{
+			Some(self.expect_identifier()?)
+		} else {
+			None
+		};
+
+		let net_type = match self.peek() {
+			Some(Token::Keyword(keyword)) => net_type_for(*keyword),
+			_ => None,
+		};
+		if net_type.is_some() {
+			self.bump();
+		} else {
+			var |= self.eat_keyword(Keyword::Var);
+		}
+
+		let data_type = if self.is_keyword(Keyword::Interface) {
+			self.bump();
+
+			let modport = if self.eat_control(Control::Dot) {
+				Some(self.expect_identifier()?)
+			} else {
+				None
+			};
+
+			DataType::Interface { name: None, modport }
+		} else if self.is_system_verilog() {
+			self.parse_data_type_or_implicit()?
+		} else {
+			match self.parse_variable_type()? {
+				Some(data_type) => data_type,
+				None => self.parse_implicit_type()?,
+			}
+		};
+
+		Ok(PortDeclaration {
+			attributes,
+			direction,
+			discipline,
+			net_type,
+			var,
+			data_type,
+			declarators: Vec::new(),
+		})
+	}
+
+	/// Parse a comma separated list of ANSI style port declarations
+	///
+	/// IEEE 1364-2005 § A.1.3, IEEE 1800-2017 § A.1.3
+	pub(crate) fn parse_ansi_port_declarations(
+		&mut self,
+	) -> ParseResult<Vec<Spanned<PortDeclaration>>> {
+		let mut ports: Vec<Spanned<PortDeclaration>> = Vec::new();
+
+		loop {
+			let start = self.span();
+			let lookahead = self.attributes_lookahead();
+
+			if self.is_port_declaration_start(lookahead) ||
+				(ports.is_empty() && self.is_system_verilog())
+			{
+				let attributes = self.parse_attributes()?;
+				let mut port = self.parse_port_declaration_head(attributes)?;
+				port.declarators.push(self.parse_declarator()?);
+
+				ports.push(Spanned::new(port, self.span_from(start)));
+			} else {
+				// Identifiers without a direction share the declaration before them
+				let declarator = self.parse_declarator()?;
+				let Some(previous) = ports.last_mut() else {
+					return self.unexpected("a port declaration");
+				};
+
+				previous.declarators.push(declarator);
+				*previous.span_mut() = self.span_from(*previous.span());
+			}
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		Ok(ports)
+	}
+
+	/// Parse the `(...)` of a module parameter port list, the `#` has already been consumed
+	///
+	/// IEEE 1364-2005 § A.1.3, IEEE 1800-2017 § A.1.3
+	pub(crate) fn parse_parameter_port_list(
+		&mut self,
+	) -> ParseResult<Vec<Spanned<ParameterDeclaration>>> {
+		self.expect_control(Control::ParenOpen)?;
+
+		let mut parameters = Vec::new();
+		if self.eat_control(Control::ParenClose) {
+			return Ok(parameters);
+		}
+
+		loop {
+			let start = self.span();
+			let parameter =
+				if self.is_keyword(Keyword::Parameter) || self.is_keyword(Keyword::LocalParam) {
+					self.parse_parameter_declaration()?
+				} else {
+					self.require_std(
+						LanguageStd::SYSTEM_VERILOG_STDS,
+						self.span(),
+						"Parameter port declarations without `parameter`",
+					);
+					self.parse_parameter_body(false)?
+				};
+			parameters.push(Spanned::new(parameter, self.span_from(start)));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(parameters)
+	}
+
+	/// Parse a `parameter` or `localparam` declaration without the trailing `;`
+	///
+	/// IEEE 1364-2005 § A.2.1.1
+	fn parse_parameter_declaration(&mut self) -> ParseResult<ParameterDeclaration> {
+		let local = if self.is_keyword(Keyword::LocalParam) {
+			self.require_std(VERILOG_01_STDS, self.span(), "Local parameters");
+			self.bump();
+			true
+		} else {
+			self.expect_keyword(Keyword::Parameter)?;
+			false
+		};
+
+		self.parse_parameter_body(local)
+	}
+
+	/// Parse the type and assignments of a parameter declaration following the keyword, if any
+	fn parse_parameter_body(&mut self, local: bool) -> ParseResult<ParameterDeclaration> {
+		let data_type = if self.is_system_verilog() {
+			self.parse_data_type_or_implicit()?
+		} else {
+			match self.peek() {
+				Some(Token::Keyword(
+					Keyword::Integer | Keyword::Real | Keyword::Realtime | Keyword::Time,
+				)) => self.parse_variable_type()?.unwrap_or_default(),
+				_ => self.parse_implicit_type()?,
+			}
+		};
+		let type_parameter = data_type == DataType::Type;
+
+		let mut declarators = vec![self.parse_parameter_declarator(type_parameter)?];
+		// NOTE(aki): In a parameter port list a comma may also be followed by the next declaration
+		while self.is_control(Control::Comma) &&
+			self.is_identifier_at(1) &&
+			self.user_type_lookahead(1).is_none()
+		{
+			self.bump();
+			declarators.push(self.parse_parameter_declarator(type_parameter)?);
+		}
+
+		Ok(ParameterDeclaration { local, data_type, declarators })
+	}
+
+	/// Parse a single parameter assignment, the value of a type parameter is a data type
+	fn parse_parameter_declarator(
+		&mut self,
+		type_parameter: bool,
+	) -> ParseResult<Spanned<Declarator>> {
+		let start = self.span();
+
+		if !type_parameter {
+			let mut declarator = self.parse_declarator()?.as_inner();
+			declarator.ranges = self.parse_value_ranges()?;
+
+			return Ok(Spanned::new(declarator, self.span_from(start)));
+		}
+
+		let name = self.expect_identifier()?;
+
+		let init = if self.eat_operator(Operator::Equals) {
+			let type_start = self.span();
+			let data_type = self.parse_data_type()?;
+
+			Some(Spanned::new(
+				Expression::DataType(Box::new(data_type)),
+				self.span_from(type_start),
+			))
+		} else {
+			None
+		};
+
+		Ok(Spanned::new(
+			Declarator {
+				name,
+				dimensions: Vec::new(),
+				init,
+				ranges: Vec::new(),
+			},
+			self.span_from(start),
+		))
+	}
+
+	/// Parse a declared name along with any unpacked dimensions and initializer
+	pub(crate) fn parse_declarator(&mut self) -> ParseResult<Spanned<Declarator>> {
+		let start = self.span();
+		let name = self.expect_identifier()?;
+		let dimensions = self.parse_unpacked_dimensions()?;
+
+		let init = if self.eat_operator(Operator::Equals) {
+			Some(self.parse_mintypmax_expression()?)
+		} else {
+			None
+		};
+
+		Ok(Spanned::new(
+			Declarator { name, dimensions, init, ranges: Vec::new() },
+			self.span_from(start),
+		))
+	}
+
+	pub(crate) fn parse_declarators(&mut self) -> ParseResult<Vec<Spanned<Declarator>>> {
+		let mut declarators = vec![self.parse_declarator()?];
+		while self.eat_control(Control::Comma) {
+			declarators.push(self.parse_declarator()?);
+		}
+
+		Ok(declarators)
+	}
+
+	/// Parse an optional `static` or `automatic` lifetime
+	pub(crate) fn parse_lifetime(&mut self) -> Option<Lifetime> {
+		if self.eat_keyword(Keyword::Static) {
+			Some(Lifetime::Static)
+		} else if self.eat_keyword(Keyword::Automatic) {
+			Some(Lifetime::Automatic)
+		} else {
+			None
+		}
+	}
+
+	/// IEEE 1364-2005 § A.2.1.3, IEEE 1800-2017 § A.2.1.3
+	fn parse_variable_declaration(&mut self) -> ParseResult<VariableDeclaration> {
+		let constant = self.eat_keyword(Keyword::Const);
+		let var = self.eat_keyword(Keyword::Var);
+		let lifetime = self.parse_lifetime();
+
+		// NOTE(aki): The data type may only be omitted if `var` is present, e.g. `var [3:0] a;`
+		let data_type = if var {
+			self.parse_data_type_or_implicit()?
+		} else {
+			self.parse_data_type()?
+		};
+
+		let declarators = self.parse_declarators()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(VariableDeclaration { constant, lifetime, data_type, declarators })
+	}
+
+	/// IEEE 1364-2005 § A.2.1.3
+	fn parse_net_declaration(&mut self, net_type: NetType) -> ParseResult<NetDeclaration> {
+		self.bump();
+
+		let strength = self.parse_strength()?;
+
+		let vector = if self.eat_keyword(Keyword::Vectored) {
+			Some(VectorKind::Vectored)
+		} else if self.eat_keyword(Keyword::Scalared) {
+			Some(VectorKind::Scalared)
+		} else {
+			None
+		};
+
+		let data_type = if self.is_system_verilog() {
+			self.parse_data_type_or_implicit()?
+		} else {
+			self.parse_implicit_type()?
+		};
+
+		let delay = if self.is_control(Control::Octothorp) {
+			Some(self.parse_delay()?)
+		} else {
+			None
+		};
+
+		let declarators = self.parse_declarators()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(NetDeclaration {
+			net_type,
+			strength,
+			vector,
+			data_type,
+			delay,
+			declarators,
+		})
+	}
+
+	/// IEEE 1800-2017 § A.2.1.3
+	fn parse_typedef(&mut self) -> ParseResult<Typedef> {
+		self.expect_keyword(Keyword::TypeDef)?;
+
+		// Forward declarations, e.g. `typedef class c;` or `typedef interface class c;`
+		let forward = match self.peek() {
+			Some(Token::Keyword(
+				Keyword::Class | Keyword::Enum | Keyword::Struct | Keyword::Union,
+			)) => Some(1),
+			Some(Token::Keyword(Keyword::Interface))
+				if matches!(self.peek_nth(1), Some(Token::Keyword(Keyword::Class))) =>
+			{
+				Some(2)
+			},
+			Some(Token::Identifier(_)) => Some(0),
+			_ => None,
+		}
+		.filter(|&offset| {
+			self.is_identifier_at(offset) && self.is_control_at(offset + 1, Control::Semicolon)
+		});
+
+		if let Some(offset) = forward {
+			self.position += offset;
+			let name = self.expect_identifier()?;
+			self.expect_control(Control::Semicolon)?;
+
+			return Ok(Typedef { data_type: None, name, dimensions: Vec::new() });
+		}
+
+		let data_type = self.parse_data_type()?;
+		let name = self.expect_identifier()?;
+		let dimensions = self.parse_unpacked_dimensions()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(Typedef { data_type: Some(data_type), name, dimensions })
+	}
+
+	/// Parse an `import pkg::item, pkg::*;` declaration
+	///
+	/// IEEE 1800-2017 § A.2.1.3
+	pub(crate) fn parse_package_imports(&mut self) -> ParseResult<Vec<Spanned<PackageImport>>> {
+		self.expect_keyword(Keyword::Import)?;
+		let imports = self.parse_package_import_items()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(imports)
+	}
+
+	fn parse_package_import_items(&mut self) -> ParseResult<Vec<Spanned<PackageImport>>> {
+		let mut imports = Vec::new();
+
+		loop {
+			let start = self.span();
+			let package = self.expect_identifier()?;
+			self.expect_operator(Operator::ClassScopeResolution)?;
+
+			let item = if self.eat_operator(Operator::Asterisk) {
+				None
+			} else {
+				Some(self.expect_identifier()?)
+			};
+
+			imports.push(Spanned::new(
+				PackageImport { package, item },
+				self.span_from(start),
+			));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		Ok(imports)
+	}
+
+	/// IEEE 1800-2017 § A.2.6
+	fn parse_dpi_declaration(&mut self) -> ParseResult<DpiDeclaration> {
+		let direction = if self.eat_keyword(Keyword::Import) {
+			DpiDirection::Import
+		} else {
+			self.expect_keyword(Keyword::Export)?;
+			DpiDirection::Export
+		};
+
+		let Some(Token::SingleQuotedString(spec)) = self.peek() else {
+			return self.unexpected("a DPI spec string");
+		};
+		let spec = spec.clone();
+		self.bump();
+
+		let property = if direction != DpiDirection::Import {
+			None
+		} else if self.eat_keyword(Keyword::Context) {
+			Some(DpiProperty::Context)
+		} else if self.eat_keyword(Keyword::Pure) {
+			Some(DpiProperty::Pure)
+		} else {
+			None
+		};
+
+		let c_name = if self.is_identifier() && self.is_operator_at(1, Operator::Equals) {
+			let name = self.expect_identifier()?;
+			self.bump();
+			Some(name)
+		} else {
+			None
+		};
+
+		let prototype = if direction == DpiDirection::Import {
+			self.parse_subroutine_prototype()?
+		} else {
+			let (kind, return_type) = if self.eat_keyword(Keyword::Task) {
+				(SubroutineKind::Task, DataType::Void)
+			} else {
+				self.expect_keyword(Keyword::Function)?;
+				(SubroutineKind::Function, DataType::default())
+			};
+
+			SubroutinePrototype {
+				kind,
+				return_type,
+				name: self.expect_identifier()?,
+				ports: None,
+			}
+		};
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(DpiDeclaration { direction, spec, property, c_name, prototype })
+	}
+
+	/// IEEE 1800-2017 § A.2.9
+	fn parse_modport_declaration(&mut self) -> ParseResult<Vec<Spanned<ModportDeclaration>>> {
+		self.expect_keyword(Keyword::ModPort)?;
+
+		let mut modports = Vec::new();
+		loop {
+			let start = self.span();
+			let name = self.expect_identifier()?;
+			self.expect_control(Control::ParenOpen)?;
+
+			let mut ports = Vec::new();
+			let mut kind = None;
+			loop {
+				let port_start = self.span();
+
+				if let Some(next) = self.parse_modport_port_kind() {
+					kind = Some(next);
+				}
+				let Some(kind) = kind else {
+					return self.unexpected("a port direction, `import`, or `export`");
+				};
+
+				let subroutine =
+					self.is_keyword(Keyword::Task) || self.is_keyword(Keyword::Function);
+				let (name, expression) = match kind {
+					ModportPortKind::Import | ModportPortKind::Export if subroutine => {
+						(self.parse_subroutine_prototype()?.name, None)
+					},
+					ModportPortKind::Direction(_) if self.eat_control(Control::Dot) => {
+						let name = self.expect_identifier()?;
+						self.expect_control(Control::ParenOpen)?;
+						let expression = if self.is_control(Control::ParenClose) {
+							None
+						} else {
+							Some(self.parse_expression()?)
+						};
+						self.expect_control(Control::ParenClose)?;
+
+						(name, expression)
+					},
+					_ => (self.expect_identifier()?, None),
+				};
+
+				ports.push(Spanned::new(
+					ModportPort { kind, name, expression },
+					self.span_from(port_start),
+				));
+
+				if !self.eat_control(Control::Comma) {
+					break;
+				}
+			}
+			self.expect_control(Control::ParenClose)?;
+
+			modports.push(Spanned::new(
+				ModportDeclaration { name, ports },
+				self.span_from(start),
+			));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(modports)
+	}
+
+	fn parse_modport_port_kind(&mut self) -> Option<ModportPortKind> {
+		let kind = match self.peek()? {
+			Token::Keyword(Keyword::Input) => ModportPortKind::Direction(PortDirection::Input),
+			Token::Keyword(Keyword::Output) => ModportPortKind::Direction(PortDirection::Output),
+			Token::Keyword(Keyword::InOut) => ModportPortKind::Direction(PortDirection::InOut),
+			Token::Keyword(Keyword::Ref) => ModportPortKind::Direction(PortDirection::Ref),
+			Token::Keyword(Keyword::Import) => ModportPortKind::Import,
+			Token::Keyword(Keyword::Export) => ModportPortKind::Export,
+			Token::Keyword(Keyword::Clocking) => ModportPortKind::Clocking,
+			_ => return None,
+		};
+		self.bump();
+
+		Some(kind)
+	}
+
+	/// IEEE 1800-2017 § A.1.2
+	fn parse_time_units(&mut self) -> ParseResult<TimeUnits> {
+		let mut units = TimeUnits { unit: None, precision: None };
+
+		if self.eat_keyword(Keyword::TimeUnit) {
+			units.unit = Some(self.parse_primary()?);
+
+			if self.eat_operator(Operator::Solidus) {
+				self.require_std(
+					SYSTEM_VERILOG_09_STDS,
+					self.span(),
+					"Time precisions in `timeunit` declarations",
+				);
+				units.precision = Some(self.parse_primary()?);
+			}
+		} else {
+			self.expect_keyword(Keyword::TimePrecision)?;
+			units.precision = Some(self.parse_primary()?);
+		}
+
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(units)
+	}
+
+	/// IEEE 1800-2017 § A.1.4
+	fn parse_bind(&mut self) -> ParseResult<Bind> {
+		self.expect_keyword(Keyword::Bind)?;
+		let target = self.parse_hierarchical_identifier()?;
+
+		let mut instances = Vec::new();
+		if self.eat_control(Control::Colon) {
+			instances.push(self.parse_hierarchical_identifier()?);
+			while self.eat_control(Control::Comma) {
+				instances.push(self.parse_hierarchical_identifier()?);
+			}
+		}
+
+		let instantiation = self.parse_instantiation()?;
+
+		Ok(Bind { target, instances, instantiation })
+	}
+
+	/// Parse a drive or charge strength, if one is next
+	///
+	/// IEEE 1364-2005 § A.2.2.2
+	fn parse_strength(&mut self) -> ParseResult<Option<Strength>> {
+		if !self.is_control(Control::ParenOpen) ||
+			!matches!(self.peek_nth(1), Some(Token::Keyword(keyword)) if is_strength(*keyword))
+		{
+			return Ok(None);
+		}
+
+		let start = self.span();
+		self.bump();
+
+		let charge = match self.peek() {
+			Some(Token::Keyword(Keyword::Small)) => Some(ChargeStrength::Small),
+			Some(Token::Keyword(Keyword::Medium)) => Some(ChargeStrength::Medium),
+			Some(Token::Keyword(Keyword::Large)) => Some(ChargeStrength::Large),
+			_ => None,
+		};
+
+		if let Some(charge) = charge {
+			self.bump();
+			self.expect_control(Control::ParenClose)?;
+			return Ok(Some(Strength::Charge(charge)));
+		}
+
+		let first = self.parse_strength_value()?;
+		let strength = if self.eat_control(Control::Comma) {
+			let second = self.parse_strength_value()?;
+
+			match (first, second) {
+				(Strength::High(high), Strength::Low(low)) => {
+					Strength::Drive(DriveStrength::HighLow(high, low))
+				},
+				(Strength::Low(low), Strength::High(high)) => {
+					Strength::Drive(DriveStrength::LowHigh(low, high))
+				},
+				_ => {
+					return Err(ParseError::new(
+						"Drive strengths must specify both a strength0 and strength1",
+						self.span_from(start),
+					));
+				},
+			}
+		} else {
+			first
+		};
+
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(Some(strength))
+	}
+
+	fn parse_strength_value(&mut self) -> ParseResult<Strength> {
+		let strength = match self.peek() {
+			Some(Token::Keyword(keyword)) => match keyword {
+				Keyword::Supply0 => Strength::Low(LowStrength::Supply),
+				Keyword::Strong0 => Strength::Low(LowStrength::Strong),
+				Keyword::Pull0 => Strength::Low(LowStrength::Pull),
+				Keyword::Weak0 => Strength::Low(LowStrength::Weak),
+				Keyword::HighZ0 => Strength::Low(LowStrength::HighZ),
+				Keyword::Supply1 => Strength::High(HighStrength::Supply),
+				Keyword::Strong1 => Strength::High(HighStrength::Strong),
+				Keyword::Pull1 => Strength::High(HighStrength::Pull),
+				Keyword::Weak1 => Strength::High(HighStrength::Weak),
+				Keyword::HighZ1 => Strength::High(HighStrength::HighZ),
+				_ => return self.unexpected("a strength"),
+			},
+			_ => return self.unexpected("a strength"),
+		};
+
+		self.bump();
+
+		Ok(strength)
+	}
+
+	/// IEEE 1364-2005 § A.6.1
+	fn parse_continuous_assign(&mut self) -> ParseResult<ContinuousAssign> {
+		self.expect_keyword(Keyword::Assign)?;
+
+		let strength = self.parse_strength()?;
+		let delay = if self.is_control(Control::Octothorp) {
+			Some(self.parse_delay()?)
+		} else {
+			None
+		};
+
+		let mut assignments = vec![self.parse_assignment()?];
+		while self.eat_control(Control::Comma) {
+			assignments.push(self.parse_assignment()?);
+		}
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(ContinuousAssign { strength, delay, assignments })
+	}
+
+	/// IEEE 1364-2005 § A.3.1
+	fn parse_gate_instantiation(&mut self, gate: GateType) -> ParseResult<GateInstantiation> {
+		self.bump();
+
+		let strength = self.parse_strength()?;
+		let delay = if self.is_control(Control::Octothorp) {
+			Some(self.parse_delay()?)
+		} else {
+			None
+		};
+
+		let mut instances = Vec::new();
+		loop {
+			let start = self.span();
+			let (name, range) = self.parse_instance_name()?;
+			let terminals = self.parse_call_arguments()?;
+
+			instances.push(Spanned::new(
+				GateInstance { name, range, terminals },
+				self.span_from(start),
+			));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(GateInstantiation { gate, strength, delay, instances })
+	}
+
+	/// Parse the optional name and range of a gate or module instance
+	fn parse_instance_name(&mut self) -> ParseResult<InstanceName> {
+		if !self.is_identifier() {
+			return Ok((None, None));
+		}
+
+		let name = self.expect_identifier()?;
+		let range = if self.is_control(Control::BracketOpen) {
+			Some(self.parse_unpacked_dimension()?)
+		} else {
+			None
+		};
+
+		Ok((Some(name), range))
+	}
+
+	/// IEEE 1364-2005 § A.4.1
+	fn parse_instantiation(&mut self) -> ParseResult<Instantiation> {
+		let module = self.expect_identifier()?;
+		let strength = self.parse_strength()?;
+		let parameters = self.parse_parameter_value_assignment()?;
+
+		let mut instances = Vec::new();
+		loop {
+			let start = self.span();
+			let (name, range) = self.parse_instance_name()?;
+			let connections = self.parse_connections()?;
+
+			instances.push(Spanned::new(
+				Instance { name, range, connections },
+				self.span_from(start),
+			));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(Instantiation { module, strength, parameters, instances })
+	}
+
+	/// Parse the `#(...)` parameter value assignment of an instantiation or type, if present
+	///
+	/// IEEE 1364-2005 § A.4.1.1
+	pub(crate) fn parse_parameter_value_assignment(
+		&mut self,
+	) -> ParseResult<Option<Spanned<Connections>>> {
+		if !self.is_control(Control::Octothorp) {
+			return Ok(None);
+		}
+
+		let start = self.span();
+		self.bump();
+
+		let connections = if self.is_control(Control::ParenOpen) {
+			self.parse_connections()?
+		} else {
+			Connections::Ordered(vec![Some(self.parse_delay_value()?)])
+		};
+
+		Ok(Some(Spanned::new(connections, self.span_from(start))))
+	}
+
+	/// Parse a parenthesized list of ordered or named connections
+	fn parse_connections(&mut self) -> ParseResult<Connections> {
+		self.expect_control(Control::ParenOpen)?;
+
+		let lookahead = self.attributes_lookahead();
+		if matches!(
+			self.peek_nth(lookahead),
+			Some(Token::Control(Control::Dot) | Token::Operator(Operator::Wildcard))
+		) {
+			let mut connections = Vec::new();
+			loop {
+				let start = self.span();
+				let attributes = self.parse_attributes()?;
+
+				if self.is_operator(Operator::Wildcard) {
+					self.require_std(
+						LanguageStd::SYSTEM_VERILOG_STDS,
+						self.span(),
+						"Wildcard port connections",
+					);
+					self.bump();
+
+					connections.push(Spanned::new(
+						NamedConnection {
+							attributes,
+							name: None,
+							value: None,
+							implicit: false,
+						},
+						self.span_from(start),
+					));
+				} else {
+					self.expect_control(Control::Dot)?;
+					let name = self.expect_identifier()?;
+
+					let (value, implicit) = if self.eat_control(Control::ParenOpen) {
+						let value = if self.is_control(Control::ParenClose) {
+							None
+						} else {
+							Some(self.parse_expression()?)
+						};
+						self.expect_control(Control::ParenClose)?;
+
+						(value, false)
+					} else {
+						self.require_std(
+							LanguageStd::SYSTEM_VERILOG_STDS,
+							self.span_from(start),
+							"Implicit named port connections",
+						);
+
+						(None, true)
+					};
+
+					connections.push(Spanned::new(
+						NamedConnection { attributes, name: Some(name), value, implicit },
+						self.span_from(start),
+					));
+				}
+
+				if !self.eat_control(Control::Comma) {
+					break;
+				}
+			}
+
+			self.expect_control(Control::ParenClose)?;
+			return Ok(Connections::Named(connections));
+		}
+
+		let mut connections = Vec::new();
+		if self.eat_control(Control::ParenClose) {
+			return Ok(Connections::Ordered(connections));
+		}
+
+		loop {
+			// TODO(aki): Attributes on ordered connections are currently dropped
+			self.parse_attributes()?;
+
+			if self.is_control(Control::Comma) || self.is_control(Control::ParenClose) {
+				connections.push(None);
+			} else {
+				connections.push(Some(self.parse_expression()?));
+			}
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(Connections::Ordered(connections))
+	}
+
+	/// IEEE 1364-2005 § A.2.7
+	fn parse_task(&mut self) -> ParseResult<Task> {
+		self.expect_keyword(Keyword::Task)?;
+		let automatic = self.parse_automatic();
+		let scope = self.parse_subroutine_scope()?;
+		let name = self.expect_subroutine_name()?;
+
+		let ports = self.parse_subroutine_ports()?;
+		self.expect_control(Control::Semicolon)?;
+
+		let mut items = Vec::new();
+		while let Some(item) = self.parse_block_item_declaration(ports.is_none())? {
+			items.push(item);
+		}
+
+		let (body, end) = self.parse_list(&[Keyword::EndTask], Self::parse_list_statement, || {
+			Statement::new(StatementKind::Error)
+		});
+		if end.is_some() {
+			self.parse_end_label(Some(&name))?;
+		}
+
+		Ok(Task { automatic, scope, name, ports, items, body })
+	}
+
+	/// IEEE 1364-2005 § A.2.6
+	pub(crate) fn parse_function(&mut self) -> ParseResult<Function> {
+		self.expect_keyword(Keyword::Function)?;
+		let automatic = self.parse_automatic();
+		let return_type = self.parse_return_type()?;
+		let scope = self.parse_subroutine_scope()?;
+		let name = self.expect_subroutine_name()?;
+
+		let ports = self.parse_subroutine_ports()?;
+		self.expect_control(Control::Semicolon)?;
+
+		let mut items = Vec::new();
+		while let Some(item) = self.parse_block_item_declaration(ports.is_none())? {
+			items.push(item);
+		}
+
+		let (body, end) =
+			self.parse_list(&[Keyword::EndFunction], Self::parse_list_statement, || {
+				Statement::new(StatementKind::Error)
+			});
+		if end.is_some() {
+			self.parse_end_label(Some(&name))?;
+		}
+
+		Ok(Function {
+			automatic,
+			return_type,
+			scope,
+			name,
+			ports,
+			items,
+			body,
+		})
+	}
+
+	/// Parse a task or function header without a body, e.g. for `extern` methods or DPI imports
+	///
+	/// IEEE 1800-2017 § A.2.7
+	pub(crate) fn parse_subroutine_prototype(&mut self) -> ParseResult<SubroutinePrototype> {
+		let (kind, return_type) = if self.eat_keyword(Keyword::Task) {
+			(SubroutineKind::Task, DataType::Void)
+		} else {
+			self.expect_keyword(Keyword::Function)?;
+			(SubroutineKind::Function, self.parse_return_type()?)
+		};
+
+		let name = self.expect_subroutine_name()?;
+		let ports = self.parse_subroutine_ports()?;
+
+		Ok(SubroutinePrototype { kind, return_type, name, ports })
+	}
+
+	/// Parse the return type of a function, which is implicit if the name follows directly
+	fn parse_return_type(&mut self) -> ParseResult<DataType> {
+		if self.is_keyword(Keyword::New) {
+			return Ok(DataType::default());
+		}
+
+		if self.is_system_verilog() {
+			return self.parse_data_type_or_implicit();
+		}
+
+		match self.peek() {
+			Some(Token::Keyword(
+				Keyword::Integer | Keyword::Real | Keyword::Realtime | Keyword::Time,
+			)) => Ok(self.parse_variable_type()?.unwrap_or_default()),
+			_ => self.parse_implicit_type(),
+		}
+	}
+
+	/// Parse the `cls::` scope of an out-of-block method definition, if present
+	fn parse_subroutine_scope(&mut self) -> ParseResult<Option<Spanned<Identifier>>> {
+		if !self.is_identifier() || !self.is_operator_at(1, Operator::ClassScopeResolution) {
+			return Ok(None);
+		}
+
+		let scope = self.expect_identifier()?;
+		self.bump();
+
+		Ok(Some(scope))
+	}
+
+	/// Parse the name of a task or function, class constructors are named `new`
+	fn expect_subroutine_name(&mut self) -> ParseResult<Spanned<Identifier>> {
+		if !self.is_keyword(Keyword::New) {
+			return self.expect_identifier();
+		}
+
+		let span = self.span();
+		self.bump();
+
+		Ok(Spanned::new(
+			Identifier::Simple("new".as_bytes().into()),
+			span,
+		))
+	}
+
+	fn parse_automatic(&mut self) -> bool {
+		if self.is_keyword(Keyword::Automatic) {
+			self.require_std(
+				VERILOG_01_STDS,
+				self.span(),
+				"Automatic tasks and functions",
+			);
+			self.bump();
+			true
+		} else {
+			self.eat_keyword(Keyword::Static);
+			false
+		}
+	}
+
+	/// Parse the ANSI style port list of a task or function, if present
+	pub(crate) fn parse_subroutine_ports(
+		&mut self,
+	) -> ParseResult<Option<Vec<Spanned<PortDeclaration>>>> {
+		if !self.is_control(Control::ParenOpen) {
+			return Ok(None);
+		}
+
+		self.require_std(
+			VERILOG_01_STDS,
+			self.span(),
+			"ANSI style task and function ports",
+		);
+		self.bump();
+
+		let ports = if self.is_control(Control::ParenClose) {
+			Vec::new()
+		} else {
+			self.parse_ansi_port_declarations()?
+		};
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(Some(ports))
+	}
+
+	/// Parse a generate block, which is either a `begin`/`end` block or a single item
+	///
+	/// IEEE 1364-2005 § A.4.2
+	fn parse_generate_block(&mut self) -> ParseResult<Spanned<GenerateBlock>> {
+		let start = self.span();
+
+		if !self.eat_keyword(Keyword::Begin) {
+			let item = self.parse_item(true)?;
+			return Ok(Spanned::new(
+				GenerateBlock { name: None, items: vec![item] },
+				self.span_from(start),
+			));
+		}
+
+		let name = if self.eat_control(Control::Colon) {
+			Some(self.expect_identifier()?)
+		} else {
+			None
+		};
+
+		let (items, end) = self.parse_list(&[Keyword::End], Self::parse_generate_item, || {
+			Item::new(ItemKind::Error)
+		});
+		if end.is_some() {
+			self.parse_end_label(name.as_ref())?;
+		}
+
+		Ok(Spanned::new(
+			GenerateBlock { name, items },
+			self.span_from(start),
+		))
+	}
+
+	fn parse_generate_block_or_null(&mut self) -> ParseResult<Option<Spanned<GenerateBlock>>> {
+		if self.eat_control(Control::Semicolon) {
+			Ok(None)
+		} else {
+			self.parse_generate_block().map(Some)
+		}
+	}
+
+	fn parse_generate_for(&mut self) -> ParseResult<GenerateFor> {
+		self.expect_keyword(Keyword::For)?;
+		self.expect_control(Control::ParenOpen)?;
+
+		let genvar = if self.is_keyword(Keyword::Genvar) {
+			self.require_std(
+				LanguageStd::SYSTEM_VERILOG_STDS,
+				self.span(),
+				"Inline genvar declarations",
+			);
+			self.bump();
+			true
+		} else {
+			false
+		};
+
+		let init = self.parse_assignment()?;
+		self.expect_control(Control::Semicolon)?;
+		let condition = self.parse_expression()?;
+		self.expect_control(Control::Semicolon)?;
+		let step = self.parse_step_expression()?;
+		self.expect_control(Control::ParenClose)?;
+
+		if !matches!(
+			step.inner(),
+			Expression::Assign { operator, .. } if *operator.inner() == AssignmentOperator::Assign
+		) {
+			self.require_std(
+				LanguageStd::SYSTEM_VERILOG_STDS,
+				*step.span(),
+				"Increment and operator assignment generate loop steps",
+			);
+		}
+
+		let block = self.parse_generate_block()?;
+
+		Ok(GenerateFor { genvar, init, condition, step, block })
+	}
+
+	fn parse_generate_if(&mut self) -> ParseResult<GenerateIf> {
+		self.expect_keyword(Keyword::If)?;
+		self.expect_control(Control::ParenOpen)?;
+		let condition = self.parse_expression()?;
+		self.expect_control(Control::ParenClose)?;
+
+		let if_true = self.parse_generate_block_or_null()?;
+		let if_false = if self.eat_keyword(Keyword::Else) {
+			self.parse_generate_block_or_null()?
+		} else {
+			None
+		};
+
+		Ok(GenerateIf { condition, if_true, if_false })
+	}
+
+	fn parse_generate_case(&mut self) -> ParseResult<GenerateCase> {
+		self.expect_keyword(Keyword::Case)?;
+		self.expect_control(Control::ParenOpen)?;
+		let expression = self.parse_expression()?;
+		self.expect_control(Control::ParenClose)?;
+
+		let mut items = Vec::new();
+		while !self.eat_keyword(Keyword::EndCase) {
+			let start = self.span();
+			let labels = self.parse_case_labels()?;
+			let block = self.parse_generate_block_or_null()?;
+
+			items.push(Spanned::new(
+				GenerateCaseItem { labels, block },
+				self.span_from(start),
+			));
+		}
+
+		Ok(GenerateCase { expression, items })
+	}
+}
+
+fn net_type_for(keyword: Keyword) -> Option<NetType> {
+	Some(match keyword {
+		Keyword::Supply0 => NetType::Supply0,
+		Keyword::Supply1 => NetType::Supply1,
+		Keyword::Tri => NetType::Tri,
+		Keyword::Tri0 => NetType::Tri0,
+		Keyword::Tri1 => NetType::Tri1,
+		Keyword::Triand => NetType::TriAnd,
+		Keyword::Trior => NetType::TriOr,
+		Keyword::Trireg => NetType::Trireg,
+		Keyword::Uwire => NetType::Uwire,
+		Keyword::Wire => NetType::Wire,
+		Keyword::Wand => NetType::WireAnd,
+		Keyword::Wor => NetType::WireOr,
+		Keyword::Wreal => NetType::Wreal,
+		_ => return None,
+	})
+}
+
+fn gate_type_for(keyword: Keyword) -> Option<GateType> {
+	Some(match keyword {
+		Keyword::Cmos => GateType::Cmos,
+		Keyword::Rcmos => GateType::Rcmos,
+		Keyword::BufIf0 => GateType::BufIf0,
+		Keyword::BufIf1 => GateType::BufIf1,
+		Keyword::NotIf0 => GateType::NotIf0,
+		Keyword::NotIf1 => GateType::NotIf1,
+		Keyword::Nmos => GateType::Nmos,
+		Keyword::Pmos => GateType::Pmos,
+		Keyword::Rnmos => GateType::Rnmos,
+		Keyword::Rpmos => GateType::Rpmos,
+		Keyword::And => GateType::And,
+		Keyword::Nand => GateType::Nand,
+		Keyword::Or => GateType::Or,
+		Keyword::Nor => GateType::Nor,
+		Keyword::Xor => GateType::Xor,
+		Keyword::Xnor => GateType::Xnor,
+		Keyword::Buf => GateType::Buf,
+		Keyword::Not => GateType::Not,
+		Keyword::TranIf0 => GateType::TranIf0,
+		Keyword::TranIf1 => GateType::TranIf1,
+		Keyword::RtranIf0 => GateType::RtranIf0,
+		Keyword::RtranIf1 => GateType::RtranIf1,
+		Keyword::Tran => GateType::Tran,
+		Keyword::Rtran => GateType::Rtran,
+		Keyword::Pulldown => GateType::Pulldown,
+		Keyword::Pullup => GateType::Pullup,
+		_ => return None,
+	})
+}
+
+fn is_strength(keyword: Keyword) -> bool {
+	matches!(
+		keyword,
+		Keyword::Supply0 |
+			Keyword::Supply1 |
+			Keyword::Strong0 |
+			Keyword::Strong1 |
+			Keyword::Pull0 |
+			Keyword::Pull1 |
+			Keyword::Weak0 |
+			Keyword::Weak1 |
+			Keyword::HighZ0 |
+			Keyword::HighZ1 |
+			Keyword::Small |
+			Keyword::Medium |
+			Keyword::Large
+	)
+}
@@ -1,38 +1,1049 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
+use std::{collections::BTreeMap, path::PathBuf};
+
+use vermilion_diagnostics::{Code, Diagnostic, StringDiagnostic};
 use vermilion_lang::{AtomicByteTendril, tokenizer::TokenizerIter};
+use vermilion_loc::{FileId, Span, Spanned};
 
 use crate::{
 	LanguageStd,
+	diagnostics::{system_verilog, verilog, verilog_ams},
 	lang::{
-		ast::Ast,
+		ast::{
+			Ast, Attribute, CellReference, Config, ConfigRule, ConfigRuleAction, ConfigRuleTarget,
+			Description, Item, ItemKind, Module, ModuleKeyword, Package, Port, Ports,
+		},
+		cst::{NodeKind, SyntaxNode},
+		keywords::Keyword,
+		parser::error::{ParseError, ParseResult},
+		preprocessor::{Preprocessor, SourceMap},
+		sysfuncs,
 		tokenizer::{VerilogTokenizer, token::Token},
+		types::{CompilerDirective, Control, Identifier, Operator, SystemFunc},
 	},
 };
 
+mod analog;
+mod assertion;
+mod class;
+mod covergroup;
+pub mod error;
+mod expr;
+mod item;
+mod recovery;
+mod specify;
+mod stmt;
+mod types;
+mod udp;
+
+/// All of the standards that include the IEEE 1364-2001 additions
+pub(crate) const VERILOG_01_STDS: LanguageStd = LanguageStd::Vl01
+	.or(LanguageStd::Vl05)
+	.or(LanguageStd::SYSTEM_VERILOG_STDS)
+	.or(LanguageStd::VERILOG_AMS_STDS);
+
+/// All of the standards that include the IEEE 1364-2005 additions
+pub(crate) const VERILOG_05_STDS: LanguageStd = LanguageStd::Vl05
+	.or(LanguageStd::SYSTEM_VERILOG_STDS)
+	.or(LanguageStd::VERILOG_AMS_STDS);
+
+/// All of the standards that include the IEEE 1800-2009 additions
+pub(crate) const SYSTEM_VERILOG_09_STDS: LanguageStd = LanguageStd::Sv09
+	.or(LanguageStd::Sv12)
+	.or(LanguageStd::Sv17)
+	.or(LanguageStd::Sv23);
+
+/// All of the standards that include the IEEE 1800-2012 additions
+pub(crate) const SYSTEM_VERILOG_12_STDS: LanguageStd = LanguageStd::Sv12
+	.or(LanguageStd::Sv17)
+	.or(LanguageStd::Sv23);
+
 pub struct VerilogParser {
-	_std:      LanguageStd,
-	tokenizer: VerilogTokenizer,
+	std:           LanguageStd,
+	source:        AtomicByteTendril,
+	tokens:        Vec<Spanned<Token>>,
+	position:      usize,
+	diagnostics:   Vec<StringDiagnostic>,
+	/// Diagnostics for tokens that were only valid in a later standard, keyed by token index
+	gated:         BTreeMap<usize, StringDiagnostic>,
+	/// The keywords that close the constructs currently being parsed, used for error recovery
+	closers:       Vec<Keyword>,
+	/// Every token of the source, including the whitespace and comments left out of `tokens`
+	source_tokens: Vec<Spanned<Token>>,
+	/// The spans of the concrete syntax tree nodes found so far
+	nodes:         Vec<Spanned<NodeKind>>,
+	/// The part of the source being parsed, the whole of it unless re-parsing an edited region
+	region:        Span,
+	source_map:    SourceMap,
 }
 
+/// A saved parser position to backtrack to
+pub(crate) type Checkpoint = (usize, usize, usize);
+
 impl VerilogParser {
+	/// Create a parser for `content`, which is preprocessed first with no macros predefined and
+	/// no include paths
 	pub fn new(std: LanguageStd, content: AtomicByteTendril) -> eyre::Result<Self> {
+		Self::with_preprocessor(&mut Preprocessor::new(std), PathBuf::new(), content)
+	}
+
+	/// Create a parser for `content` read from `path`, which is preprocessed by `preprocessor`
+	///
+	/// Tokens that came from an `` `include `` file are located at the `` `include `` directive,
+	/// and those from a macro expansion at the macro usage, problems found while preprocessing
+	/// are reported along with the syntax errors.
+	pub fn with_preprocessor(
+		preprocessor: &mut Preprocessor,
+		path: impl Into<PathBuf>,
+		content: AtomicByteTendril,
+	) -> eyre::Result<Self> {
+		let std = preprocessor.std();
+		let region = Span::new(0, content.len32(), 0, 0);
+
+		let mut tokenizer = VerilogTokenizer::new(std, content.clone())?;
+		let source_tokens = TokenizerIter::new(&mut tokenizer).collect::<Vec<_>>();
+
+		let file = FileId::new(preprocessor.files().len());
+		let diagnostics = preprocessor.diagnostics().len();
+		let preprocessed = preprocessor.preprocess(path, content.clone())?;
+
+		let mut tokens = Self::collect_tokens(
+			std,
+			preprocessed.iter().map(|token| {
+				Spanned::new(
+					token.token().clone(),
+					preprocessor.span_in(file, token.location()),
+				)
+			}),
+		);
+		let gated = Self::reinterpret_tokens(std, &mut tokens);
+
+		let diagnostics = preprocessor.diagnostics()[diagnostics..]
+			.iter()
+			.map(|diagnostic| {
+				StringDiagnostic::new(
+					diagnostic.code(),
+					diagnostic.message(),
+					Some(preprocessor.span_in(file, diagnostic)),
+				)
+			})
+			.collect();
+		let source_map = preprocessor.source_map(file);
+
+		Ok(Self {
+			std,
+			source: content,
+			tokens,
+			position: 0,
+			diagnostics,
+			gated,
+			closers: Vec::new(),
+			source_tokens,
+			nodes: Vec::new(),
+			region,
+			source_map,
+		})
+	}
+
+	/// Create a parser for only the given `region` of `content`
+	///
+	/// Everything in `content` past the end of the region is ignored, the resulting [`Ast`] has
+	/// the descriptions in the region and a concrete syntax tree covering only the region. The
+	/// region is not preprocessed, so it must not depend on any text macros.
+	pub fn new_at(
+		std: LanguageStd,
+		content: AtomicByteTendril,
+		region: Span,
+	) -> eyre::Result<Self> {
+		let end = (*region.end()).min(content.len32());
+		let content = content.subtendril(0, end);
+
+		let mut tokenizer =
+			VerilogTokenizer::new_at(std, content.clone(), *region.begin(), region.get_position())?;
+		let source_tokens = TokenizerIter::new(&mut tokenizer).collect::<Vec<_>>();
+		let mut tokens = Self::collect_tokens(std, source_tokens.iter().cloned());
+		let gated = Self::reinterpret_tokens(std, &mut tokens);
+
 		Ok(Self {
-			_std:      std,
-			tokenizer: VerilogTokenizer::new(std, content)?,
+			std,
+			source: content,
+			tokens,
+			position: 0,
+			diagnostics: Vec::new(),
+			gated,
+			closers: Vec::new(),
+			source_tokens,
+			nodes: Vec::new(),
+			region,
+			source_map: SourceMap::default(),
+		})
+	}
+
+	// NOTE(aki):
+	// The parser itself only ever sees the significant tokens, whitespace and comments are kept
+	// in `source_tokens` and only come back in when building the concrete syntax tree.
+	fn collect_tokens<I>(std: LanguageStd, iter: I) -> Vec<Spanned<Token>>
+	where
+		I: Iterator<Item = Spanned<Token>>,
+	{
+		let mut tokens: Vec<Spanned<Token>> = Vec::new();
+		let mut iter = iter.peekable();
+
+		while let Some(token) = iter.next() {
+			match token.inner() {
+				Token::Whitespace(_) | Token::Comment(_) | Token::Newline(_) => {},
+				// The directives left after preprocessing, such as `` `timescale ``, don't change
+				// the syntax of the source, so they are skipped along with the rest of the line
+				// they are on, honoring line continuations.
+				Token::CompilerDirective(CompilerDirective::Builtin(_)) => {
+					let mut continued = false;
+					for token in iter.by_ref() {
+						match token.inner() {
+							Token::Newline(_) if !continued => break,
+							Token::Control(Control::ReverseSolidus) => continued = true,
+							Token::Whitespace(_) | Token::Comment(_) => {},
+							_ => continued = false,
+						}
+					}
+				},
+				// The tokenizer emits `$` and the name following it separately, so join them
+				// back up into a single system function token if nothing is between them.
+				Token::Control(Control::Dollar) => {
+					let name = iter.peek().and_then(|next| match next.inner() {
+						Token::Identifier(Identifier::Simple(name)) => Some((
+							unsafe { str::from_utf8_unchecked(name) }.to_string(),
+							*next.span(),
+						)),
+						Token::Keyword(keyword) => {
+							Some((keyword.as_str().to_string(), *next.span()))
+						},
+						_ => None,
+					});
+
+					if let Some((name, name_span)) = name {
+						iter.next();
+
+						let sysfunc = match sysfuncs::get_builtin_sysfunc(&name, std) {
+							Some(builtin) => SystemFunc::Builtin(builtin),
+							None => SystemFunc::Other(name.as_bytes().into()),
+						};
+
+						tokens.push(Spanned::new(
+							Token::SystemFunc(sysfunc),
+							Span::from_position(
+								*token.span().begin(),
+								*name_span.end(),
+								token.span().get_position(),
+							),
+						));
+					} else {
+						tokens.push(token);
+					}
+				},
+				_ => tokens.push(token),
+			}
+		}
+
+		tokens
+	}
+
+	// NOTE(aki):
+	// Rather than failing outright on tokens that are only valid in a later standard, we
+	// re-tokenize them as that standard and parse on as normal, reporting a diagnostic for them.
+	/// Replace any tokens that are only valid in a later standard with their valid equivalent
+	fn reinterpret_tokens(
+		std: LanguageStd,
+		tokens: &mut [Spanned<Token>],
+	) -> BTreeMap<usize, StringDiagnostic> {
+		let mut gated = BTreeMap::new();
+
+		for idx in 0..tokens.len() {
+			let span = *tokens[idx].span();
+
+			let (text, stds, reinterpreted) = match tokens[idx].inner() {
+				Token::ContextuallyInvalid(text, stds) => {
+					// `+=>` and `-=>` in specify blocks are glued back together by the parser
+					let path_polarity = matches!(&text[..], b"+=" | b"-=") &&
+						tokens.get(idx + 1).is_some_and(|next| {
+							matches!(next.inner(), Token::Operator(Operator::LessThan)) &&
+								next.span().begin() == span.end()
+						});
+					if path_polarity {
+						continue;
+					}
+
+					let Some(token) = reinterpret_token(text, earliest_std(*stds)) else {
+						continue;
+					};
+
+					(text.clone(), *stds, token)
+				},
+				// The tokenizer only knows about `'` for casts after a size, e.g. `8'(x)`
+				Token::Invalid(Some(text)) if &text[..] == b"'" => {
+					let token = Token::Control(Control::Apostrophe);
+					if LanguageStd::SYSTEM_VERILOG_STDS.contains(std) {
+						tokens[idx] = Spanned::new(token, span);
+						continue;
+					}
+
+					(text.clone(), LanguageStd::SYSTEM_VERILOG_STDS, token)
+				},
+				_ => continue,
+			};
+
+			gated.insert(
+				idx,
+				StringDiagnostic::new(
+					unsupported_code(std),
+					format!(
+						"`{}` is not valid in {std}, requires {}",
+						unsafe { str::from_utf8_unchecked(&text) },
+						earliest_std(stds)
+					),
+					Some(span),
+				),
+			);
+			tokens[idx] = Spanned::new(reinterpreted, span);
+		}
+
+		gated
+	}
+
+	/// Parse the whole file, or the region of it given to [`Self::new_at`]
+	///
+	/// Syntax errors do not stop the parse, each one is reported as a diagnostic and the construct
+	/// containing it is replaced with an error node, so the resulting AST is always as complete as
+	/// possible. The lossless concrete syntax tree of the file is built alongside the AST.
+	pub fn parse(mut self) -> Ast {
+		let mut descriptions = Vec::new();
+
+		while !self.at_eof() {
+			let start = self.span();
+			let position = self.position;
+
+			match self.parse_description() {
+				Ok(description) => {
+					let description = self.node(NodeKind::Description, description);
+					descriptions.push(description);
+				},
+				Err(err) => {
+					self.report(err);
+					self.synchronize_description(position);
+
+					let error = Spanned::new(Description::Error, self.span_from(start));
+					descriptions.push(self.node(NodeKind::Error, error));
+				},
+			}
+		}
+
+		let mut diagnostics = self.diagnostics;
+		diagnostics.extend(self.gated.into_values());
+		diagnostics.sort_by_key(|diagnostic| diagnostic.span().map(|span| *span.begin()));
+
+		let syntax = SyntaxNode::build(&self.source, &self.source_tokens, self.nodes, self.region);
+
+		Ast::new(descriptions, diagnostics, syntax).with_source_map(self.source_map)
+	}
+
+	pub fn std(&self) -> LanguageStd {
+		self.std
+	}
+
+	fn parse_description(&mut self) -> ParseResult<Spanned<Description>> {
+		let start = self.span();
+		let attributes = self.parse_attributes()?;
+
+		match self.peek() {
+			Some(Token::Keyword(Keyword::Interface))
+				if matches!(self.peek_nth(1), Some(Token::Keyword(Keyword::Class))) =>
+			{
+				let class = self.parse_class()?;
+				Ok(Spanned::new(
+					Description::Class(class),
+					self.span_from(start),
+				))
+			},
+			Some(Token::Keyword(
+				Keyword::Module | Keyword::MacroModule | Keyword::Interface | Keyword::Program,
+			)) => {
+				let module = self.parse_module(attributes)?;
+				Ok(Spanned::new(
+					Description::Module(module),
+					self.span_from(start),
+				))
+			},
+			Some(Token::Keyword(Keyword::Package)) => {
+				let package = self.parse_package(attributes)?;
+				Ok(Spanned::new(
+					Description::Package(package),
+					self.span_from(start),
+				))
+			},
+			Some(Token::Keyword(Keyword::Class)) | Some(Token::Keyword(Keyword::Virtual))
+				if matches!(
+					self.peek_nth(usize::from(self.is_keyword(Keyword::Virtual))),
+					Some(Token::Keyword(Keyword::Class))
+				) =>
+			{
+				let class = self.parse_class()?;
+				Ok(Spanned::new(
+					Description::Class(class),
+					self.span_from(start),
+				))
+			},
+			Some(Token::Keyword(Keyword::Primitive)) => {
+				let udp = self.parse_udp(attributes)?;
+				Ok(Spanned::new(
+					Description::Primitive(udp),
+					self.span_from(start),
+				))
+			},
+			Some(Token::Keyword(Keyword::Config)) => {
+				let config = self.parse_config()?;
+				Ok(Spanned::new(
+					Description::Config(config),
+					self.span_from(start),
+				))
+			},
+			Some(Token::Keyword(Keyword::Nature)) => {
+				let nature = self.parse_nature()?;
+				Ok(Spanned::new(
+					Description::Nature(nature),
+					self.span_from(start),
+				))
+			},
+			Some(Token::Keyword(Keyword::Discipline)) => {
+				let discipline = self.parse_discipline()?;
+				Ok(Spanned::new(
+					Description::Discipline(discipline),
+					self.span_from(start),
+				))
+			},
+			_ if self.is_system_verilog() => {
+				let kind = self.parse_item_kind(false)?;
+				Ok(Spanned::new(
+					Description::Item(Box::new(Item { attributes, kind })),
+					self.span_from(start),
+				))
+			},
+			_ if self.is_verilog_ams() => {
+				self.unexpected("a module, primitive, config, nature, or discipline declaration")
+			},
+			_ => self.unexpected("a module, primitive, or config declaration"),
+		}
+	}
+
+	/// Parse a module, interface, or program declaration
+	///
+	/// IEEE 1364-2005 § A.1.2, IEEE 1800-2017 § A.1.2
+	fn parse_module(&mut self, attributes: Vec<Spanned<Attribute>>) -> ParseResult<Module> {
+		let (keyword, end) = match self.peek() {
+			Some(Token::Keyword(Keyword::MacroModule)) => {
+				(ModuleKeyword::MacroModule, Keyword::EndModule)
+			},
+			Some(Token::Keyword(Keyword::Interface)) => {
+				(ModuleKeyword::Interface, Keyword::EndInterface)
+			},
+			Some(Token::Keyword(Keyword::Program)) => (ModuleKeyword::Program, Keyword::EndProgram),
+			Some(Token::Keyword(Keyword::Module)) => (ModuleKeyword::Module, Keyword::EndModule),
+			_ => return self.unexpected("`module`"),
+		};
+		self.bump();
+
+		let lifetime = self.parse_lifetime();
+		let name = self.expect_identifier()?;
+
+		let mut imports = Vec::new();
+		while self.is_keyword(Keyword::Import) {
+			self.require_std(
+				SYSTEM_VERILOG_09_STDS,
+				self.span(),
+				"Package imports in module headers",
+			);
+			imports.extend(self.parse_package_imports()?);
+		}
+
+		let parameters = if self.is_control(Control::Octothorp) {
+			let span = self.span();
+			self.bump();
+			self.require_std(VERILOG_01_STDS, span, "Module parameter port lists");
+			Some(self.parse_parameter_port_list()?)
+		} else {
+			None
+		};
+
+		let ports = if self.is_control(Control::ParenOpen) {
+			self.parse_module_ports()?
+		} else {
+			Ports::None
+		};
+
+		self.expect_control(Control::Semicolon)?;
+
+		let (items, end) = self.parse_list(&[end], Self::parse_optional_module_item, || {
+			Item::new(ItemKind::Error)
+		});
+		if end.is_some() {
+			self.parse_end_label(Some(&name))?;
+		}
+
+		Ok(Module {
+			attributes,
+			keyword,
+			lifetime,
+			name,
+			imports,
+			parameters,
+			ports,
+			items,
 		})
 	}
 
-	pub fn parse(mut self) -> eyre::Result<Ast> {
-		// TODO(aki):
-		// Skip over whitespace for now, just so we can deal with getting the parser working,
-		// once it is, figure out how to merge in whitespace to the AST.
-		let mut iter = TokenizerIter::new(&mut self.tokenizer)
-			.filter(|token| !matches!(token.inner(), Token::Whitespace(_) | Token::Newline(_)));
+	/// IEEE 1800-2017 § A.1.2
+	fn parse_package(&mut self, attributes: Vec<Spanned<Attribute>>) -> ParseResult<Package> {
+		self.expect_keyword(Keyword::Package)?;
+		let lifetime = self.parse_lifetime();
+		let name = self.expect_identifier()?;
+		self.expect_control(Control::Semicolon)?;
+
+		let (items, end) = self.parse_list(
+			&[Keyword::EndPackage],
+			Self::parse_optional_module_item,
+			|| Item::new(ItemKind::Error),
+		);
+		if end.is_some() {
+			self.parse_end_label(Some(&name))?;
+		}
+
+		Ok(Package { attributes, lifetime, name, items })
+	}
+
+	/// Parse the optional `: name` label following an end keyword, ensuring it matches `name`
+	pub(crate) fn parse_end_label(
+		&mut self,
+		name: Option<&Spanned<Identifier>>,
+	) -> ParseResult<()> {
+		if !self.is_control(Control::Colon) {
+			return Ok(());
+		}
+
+		let start = self.span();
+		self.bump();
+
+		let label = if self.is_keyword(Keyword::New) {
+			let span = self.span();
+			self.bump();
+			Spanned::new(Identifier::Simple("new".as_bytes().into()), span)
+		} else {
+			self.expect_identifier()?
+		};
+
+		self.require_std(
+			LanguageStd::SYSTEM_VERILOG_STDS,
+			self.span_from(start),
+			"End labels",
+		);
+
+		if let Some(name) = name &&
+			name.inner() != label.inner()
+		{
+			self.diagnostics.push(StringDiagnostic::new(
+				system_verilog::E0002,
+				format!(
+					"End label `{}` does not match `{}`",
+					label.inner().as_str(),
+					name.inner().as_str()
+				),
+				Some(*label.span()),
+			));
+		}
+
+		Ok(())
+	}
+
+	fn parse_module_ports(&mut self) -> ParseResult<Ports> {
+		self.expect_control(Control::ParenOpen)?;
+
+		if self.eat_control(Control::ParenClose) {
+			return Ok(Ports::NonAnsi(Vec::new()));
+		}
+
+		let lookahead = self.attributes_lookahead();
+		if self.is_port_declaration_start(lookahead) {
+			self.require_std(VERILOG_01_STDS, self.span(), "ANSI style port declarations");
+
+			let ports = self.parse_ansi_port_declarations()?;
+			self.expect_control(Control::ParenClose)?;
+
+			return Ok(Ports::Ansi(ports));
+		}
+
+		let mut ports = Vec::new();
+		loop {
+			let start = self.span();
+
+			let port = if self.eat_control(Control::Dot) {
+				let name = self.expect_identifier()?;
+				self.expect_control(Control::ParenOpen)?;
+
+				let expression = if self.is_control(Control::ParenClose) {
+					None
+				} else {
+					Some(self.parse_lvalue()?)
+				};
+
+				self.expect_control(Control::ParenClose)?;
+				Port { name: Some(name), expression }
+			} else if self.is_control(Control::Comma) || self.is_control(Control::ParenClose) {
+				Port { name: None, expression: None }
+			} else {
+				Port {
+					name:       None,
+					expression: Some(self.parse_lvalue()?),
+				}
+			};
+
+			ports.push(Spanned::new(port, self.span_from(start)));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(Ports::NonAnsi(ports))
+	}
+
+	fn parse_config(&mut self) -> ParseResult<Config> {
+		self.expect_keyword(Keyword::Config)?;
+		let name = self.expect_identifier()?;
+		self.expect_control(Control::Semicolon)?;
+
+		self.expect_keyword(Keyword::Design)?;
+		let mut design = Vec::new();
+		while !self.eat_control(Control::Semicolon) {
+			let start = self.span();
+			let cell = self.parse_cell_reference()?;
+			design.push(Spanned::new(cell, self.span_from(start)));
+		}
+
+		let mut rules = Vec::new();
+		while !self.eat_keyword(Keyword::EndConfig) {
+			let start = self.span();
+
+			let target = if self.eat_keyword(Keyword::Default) {
+				ConfigRuleTarget::Default
+			} else if self.eat_keyword(Keyword::Instance) {
+				let mut path = vec![self.expect_identifier()?];
+				while self.eat_control(Control::Dot) {
+					path.push(self.expect_identifier()?);
+				}
+
+				ConfigRuleTarget::Instance(path)
+			} else if self.eat_keyword(Keyword::Cell) {
+				ConfigRuleTarget::Cell(self.parse_cell_reference()?)
+			} else {
+				return self.unexpected("`default`, `instance`, `cell`, or `endconfig`");
+			};
+
+			let action = if self.eat_keyword(Keyword::LibList) {
+				let mut libraries = Vec::new();
+				while !self.is_control(Control::Semicolon) {
+					libraries.push(self.expect_identifier()?);
+				}
+
+				ConfigRuleAction::LibList(libraries)
+			} else if self.eat_keyword(Keyword::Use) {
+				ConfigRuleAction::Use(self.parse_cell_reference()?)
+			} else {
+				return self.unexpected("`liblist` or `use`");
+			};
+
+			self.expect_control(Control::Semicolon)?;
+			rules.push(Spanned::new(
+				ConfigRule { target, action },
+				self.span_from(start),
+			));
+		}
+
+		Ok(Config { name, design, rules })
+	}
+
+	fn parse_cell_reference(&mut self) -> ParseResult<CellReference> {
+		let first = self.expect_identifier()?;
+
+		let (library, cell) = if self.eat_control(Control::Dot) {
+			(Some(first), self.expect_identifier()?)
+		} else {
+			(None, first)
+		};
+
+		let config = if self.eat_control(Control::Colon) {
+			self.expect_keyword(Keyword::Config)?;
+			true
+		} else {
+			false
+		};
+
+		Ok(CellReference { library, cell, config })
+	}
+
+	/// Parse zero or more attribute instances, flattening all of the attribute specs
+	pub(crate) fn parse_attributes(&mut self) -> ParseResult<Vec<Spanned<Attribute>>> {
+		let mut attributes = Vec::new();
+
+		while self.eat_control(Control::AttributeOpen) {
+			loop {
+				let start = self.span();
+				let name = self.expect_identifier()?;
+
+				let value = if self.eat_operator(Operator::Equals) {
+					Some(self.parse_expression()?)
+				} else {
+					None
+				};
+
+				attributes.push(Spanned::new(
+					Attribute { name, value },
+					self.span_from(start),
+				));
+
+				if !self.eat_control(Control::Comma) {
+					break;
+				}
+			}
+
+			self.expect_control(Control::AttributeClose)?;
+		}
+
+		Ok(attributes)
+	}
+
+	/// Returns the number of tokens taken up by any attribute instances at the current position
+	pub(crate) fn attributes_lookahead(&self) -> usize {
+		let mut offset = 0;
+
+		while matches!(
+			self.peek_nth(offset),
+			Some(Token::Control(Control::AttributeOpen))
+		) {
+			while !matches!(
+				self.peek_nth(offset),
+				Some(Token::Control(Control::AttributeClose)) | None
+			) {
+				offset += 1;
+			}
+
+			offset += 1;
+		}
+
+		offset
+	}
+
+	/// Ensure the current standard is one of the given `stds`, reporting `feature` if not
+	pub(crate) fn require_std(&mut self, stds: LanguageStd, span: Span, feature: &str) {
+		if stds.contains(self.std) {
+			return;
+		}
+
+		self.diagnostics.push(StringDiagnostic::new(
+			unsupported_code(self.std),
+			format!(
+				"{feature} are not supported in {}, requires {}",
+				self.std,
+				earliest_std(stds)
+			),
+			Some(span),
+		));
+	}
+
+	/// Drop the diagnostic for a token at the current position that is only valid in a later
+	/// standard, for places where the original token is valid
+	pub(crate) fn ungate(&mut self) {
+		self.gated.remove(&self.position);
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_system_verilog(&self) -> bool {
+		LanguageStd::SYSTEM_VERILOG_STDS.contains(self.std)
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_verilog_ams(&self) -> bool {
+		LanguageStd::VERILOG_AMS_STDS.contains(self.std)
+	}
+
+	/// Save the current position so the parser can backtrack to it
+	pub(crate) fn checkpoint(&self) -> Checkpoint {
+		(self.position, self.diagnostics.len(), self.nodes.len())
+	}
+
+	/// Backtrack to a previously saved position, discarding any diagnostics since then
+	pub(crate) fn restore(&mut self, (position, diagnostics, nodes): Checkpoint) {
+		self.position = position;
+		self.diagnostics.truncate(diagnostics);
+		self.nodes.truncate(nodes);
+	}
+
+	/// Record the span of a parsed construct as a node of the concrete syntax tree
+	pub(crate) fn node<T>(&mut self, kind: NodeKind, value: Spanned<T>) -> Spanned<T> {
+		self.nodes.push(Spanned::new(kind, *value.span()));
+		value
+	}
+
+	/// Returns the offset just past the bracketed group that opens at `offset`
+	pub(crate) fn skip_balanced(&self, offset: usize) -> Option<usize> {
+		let mut depth = 0usize;
+		let mut offset = offset;
+
+		loop {
+			match self.peek_nth(offset)? {
+				Token::Control(Control::ParenOpen | Control::BracketOpen | Control::BraceOpen) => {
+					depth += 1;
+				},
+				Token::Control(
+					Control::ParenClose | Control::BracketClose | Control::BraceClose,
+				) => {
+					depth = depth.checked_sub(1)?;
+					if depth == 0 {
+						return Some(offset + 1);
+					}
+				},
+				_ => {},
+			}
+
+			offset += 1;
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn at_eof(&self) -> bool {
+		self.position >= self.tokens.len()
+	}
+
+	#[inline(always)]
+	pub(crate) fn peek(&self) -> Option<&Token> {
+		self.peek_nth(0)
+	}
+
+	#[inline(always)]
+	pub(crate) fn peek_nth(&self, offset: usize) -> Option<&Token> {
+		self.tokens.get(self.position + offset).map(Spanned::inner)
+	}
+
+	/// The span of the current token, or an empty span at the end of the file
+	pub(crate) fn span(&self) -> Span {
+		match self.tokens.get(self.position) {
+			Some(token) => *token.span(),
+			None => match self.tokens.last() {
+				Some(token) => Span::from_position(
+					*token.span().end(),
+					*token.span().end(),
+					token.span().get_position(),
+				),
+				None => Span::empty(),
+			},
+		}
+	}
+
+	/// Create a span starting at `start` and ending at the end of the previously consumed token
+	pub(crate) fn span_from(&self, start: Span) -> Span {
+		let end = match self
+			.position
+			.checked_sub(1)
+			.and_then(|idx| self.tokens.get(idx))
+		{
+			Some(token) => (*token.span().end()).max(*start.begin()),
+			None => *start.begin(),
+		};
+
+		Span::from_position(*start.begin(), end, start.get_position())
+	}
+
+	pub(crate) fn bump(&mut self) -> Option<Spanned<Token>> {
+		let token = self.tokens.get(self.position).cloned();
+		if token.is_some() {
+			self.position += 1;
+		}
+		token
+	}
+
+	/// Get the source text for the given span
+	pub(crate) fn source_text(&self, span: &Span) -> AtomicByteTendril {
+		let begin = (*span.begin()).min(self.source.len32());
+		let end = (*span.end()).min(self.source.len32());
+
+		self.source.subtendril(begin, end.saturating_sub(begin))
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_keyword(&self, keyword: Keyword) -> bool {
+		matches!(self.peek(), Some(Token::Keyword(kw)) if *kw == keyword)
+	}
+
+	pub(crate) fn eat_keyword(&mut self, keyword: Keyword) -> bool {
+		if self.is_keyword(keyword) {
+			self.position += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	pub(crate) fn expect_keyword(&mut self, keyword: Keyword) -> ParseResult<Span> {
+		let span = self.span();
+		if self.eat_keyword(keyword) {
+			Ok(span)
+		} else {
+			self.unexpected(&format!("`{}`", keyword.as_str()))
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_control(&self, control: Control) -> bool {
+		matches!(self.peek(), Some(Token::Control(ctrl)) if *ctrl == control)
+	}
+
+	pub(crate) fn eat_control(&mut self, control: Control) -> bool {
+		if self.is_control(control) {
+			self.position += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	pub(crate) fn expect_control(&mut self, control: Control) -> ParseResult<Span> {
+		let span = self.span();
+		if self.eat_control(control) {
+			Ok(span)
+		} else {
+			self.unexpected(&format!("`{}`", control.as_str()))
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_operator(&self, operator: Operator) -> bool {
+		matches!(self.peek(), Some(Token::Operator(op)) if *op == operator)
+	}
+
+	pub(crate) fn eat_operator(&mut self, operator: Operator) -> bool {
+		if self.is_operator(operator) {
+			self.position += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	pub(crate) fn expect_operator(&mut self, operator: Operator) -> ParseResult<Span> {
+		let span = self.span();
+		if self.eat_operator(operator) {
+			Ok(span)
+		} else {
+			self.unexpected(&format!("`{}`", operator.as_str()))
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_identifier(&self) -> bool {
+		matches!(self.peek(), Some(Token::Identifier(_)))
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_control_at(&self, offset: usize, control: Control) -> bool {
+		matches!(self.peek_nth(offset), Some(Token::Control(ctrl)) if *ctrl == control)
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_operator_at(&self, offset: usize, operator: Operator) -> bool {
+		matches!(self.peek_nth(offset), Some(Token::Operator(op)) if *op == operator)
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_identifier_at(&self, offset: usize) -> bool {
+		matches!(self.peek_nth(offset), Some(Token::Identifier(_)))
+	}
+
+	pub(crate) fn expect_identifier(&mut self) -> ParseResult<Spanned<Identifier>> {
+		match self.peek() {
+			Some(Token::Identifier(ident)) => {
+				let ident = Spanned::new(ident.clone(), self.span());
+				self.position += 1;
+				Ok(ident)
+			},
+			_ => self.unexpected("an identifier"),
+		}
+	}
+
+	/// Produce an error describing the current token and what was expected instead
+	pub(crate) fn unexpected<T>(&self, expected: &str) -> ParseResult<T> {
+		let span = self.span();
+
+		let message = match self.tokens.get(self.position) {
+			None => format!("Expected {expected}, found end of file"),
+			Some(token) => match token.inner() {
+				Token::ContextuallyInvalid(text, stds) => {
+					format!(
+						"`{}` is not valid in {}, requires {}",
+						unsafe { str::from_utf8_unchecked(text) },
+						self.std,
+						earliest_std(*stds)
+					)
+				},
+				_ => {
+					let text = self.source_text(token.span());
+					format!("Expected {expected}, found `{}`", unsafe {
+						str::from_utf8_unchecked(&text)
+					})
+				},
+			},
+		};
+
+		Err(ParseError::new(message, span))
+	}
+}
+
+/// The earliest standard out of `stds`
+fn earliest_std(stds: LanguageStd) -> LanguageStd {
+	LanguageStd::flags()
+		.find(|(_, std)| stds.contains(*std))
+		.map(|(_, std)| *std)
+		.unwrap_or(stds)
+}
+
+/// The diagnostic code for constructs that are unsupported in `std`
+fn unsupported_code(std: LanguageStd) -> Code {
+	if LanguageStd::SYSTEM_VERILOG_STDS.contains(std) {
+		system_verilog::E0001
+	} else if LanguageStd::VERILOG_AMS_STDS.contains(std) {
+		verilog_ams::E0001
+	} else {
+		verilog::E0001
+	}
+}
+
+/// The diagnostic code for syntax errors in `std`
+fn syntax_error_code(std: LanguageStd) -> Code {
+	if LanguageStd::SYSTEM_VERILOG_STDS.contains(std) {
+		system_verilog::E0003
+	} else if LanguageStd::VERILOG_AMS_STDS.contains(std) {
+		verilog_ams::E0002
+	} else {
+		verilog::E0002
+	}
+}
 
-		for token in &mut iter {}
+/// Tokenize `text` as `std`, returning the token if it is a single token
+fn reinterpret_token(text: &AtomicByteTendril, std: LanguageStd) -> Option<Token> {
+	let mut tokenizer = VerilogTokenizer::new(std, text.clone()).ok()?;
+	let mut tokens = TokenizerIter::new(&mut tokenizer).map(Spanned::as_inner);
 
-		Ok(Ast::default())
+	match (tokens.next(), tokens.next()) {
+		(Some(token), None) if !matches!(token, Token::ContextuallyInvalid(..)) => Some(token),
+		_ => None,
 	}
 }
 
@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_diagnostics::StringDiagnostic;
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	cst::NodeKind,
+	keywords::Keyword,
+	parser::{
+		VerilogParser,
+		error::{ParseError, ParseResult},
+		syntax_error_code,
+	},
+	tokenizer::token::Token,
+	types::Control,
+};
+
+impl VerilogParser {
+	/// Parse the elements of a construct up to one of its closing `ends` keywords, returning them
+	/// along with the closing keyword, or `None` if it was missing
+	///
+	/// An element that fails to parse is reported and replaced with the node built by `error`,
+	/// parsing then resumes after the next `;` or at the next closing keyword of this or an
+	/// enclosing construct. When `parse` returns `None` nothing is added to the list, which is
+	/// used for skipping empty items.
+	pub(crate) fn parse_list<T, P, E>(
+		&mut self,
+		ends: &[Keyword],
+		mut parse: P,
+		error: E,
+	) -> (Vec<Spanned<T>>, Option<Keyword>)
+	where
+		P: FnMut(&mut Self) -> ParseResult<Option<Spanned<T>>>,
+		E: Fn() -> T,
+	{
+		let depth = self.closers.len();
+		self.closers.extend_from_slice(ends);
+
+		let mut elements = Vec::new();
+		let end = loop {
+			if let Some(Token::Keyword(keyword)) = self.peek() &&
+				ends.contains(keyword)
+			{
+				let keyword = *keyword;
+				self.bump();
+				break Some(keyword);
+			}
+
+			if self.at_eof() || self.at_boundary(depth) {
+				let expected = ends
+					.iter()
+					.map(|keyword| format!("`{}`", keyword.as_str()))
+					.collect::<Vec<_>>()
+					.join(" or ");
+
+				if let Err(err) = self.unexpected::<()>(&expected) {
+					self.report(err);
+				}
+				break None;
+			}
+
+			let start = self.span();
+			match parse(self) {
+				Ok(Some(element)) => elements.push(element),
+				Ok(None) => {},
+				Err(err) => {
+					self.report(err);
+					self.synchronize();
+					let element = Spanned::new(error(), self.span_from(start));
+					elements.push(self.node(NodeKind::Error, element));
+				},
+			}
+		};
+
+		self.closers.truncate(depth);
+
+		(elements, end)
+	}
+
+	/// Record a syntax error as a diagnostic so parsing can continue
+	pub(crate) fn report(&mut self, err: ParseError) {
+		self.diagnostics.push(StringDiagnostic::new(
+			syntax_error_code(self.std),
+			err.message(),
+			Some(*err.span()),
+		));
+	}
+
+	/// Skip tokens up to and including the next `;`, or up to the next closing keyword of an
+	/// enclosing construct, whichever comes first
+	///
+	/// Any `begin`/`end`, `fork`/`join`, or `case`/`endcase` pairs and bracketed groups that are
+	/// opened while skipping are skipped over entirely.
+	pub(crate) fn synchronize(&mut self) {
+		let mut brackets = 0usize;
+		let mut blocks = 0usize;
+
+		while let Some(token) = self.peek() {
+			match token {
+				Token::Control(Control::ParenOpen | Control::BracketOpen | Control::BraceOpen) => {
+					brackets += 1;
+				},
+				Token::Control(
+					Control::ParenClose | Control::BracketClose | Control::BraceClose,
+				) => {
+					brackets = brackets.saturating_sub(1);
+				},
+				Token::Control(Control::Semicolon) if brackets == 0 && blocks == 0 => {
+					self.bump();
+					return;
+				},
+				Token::Keyword(
+					Keyword::Begin |
+					Keyword::Fork |
+					Keyword::Case |
+					Keyword::CaseX |
+					Keyword::CaseZ,
+				) => {
+					blocks += 1;
+				},
+				Token::Keyword(
+					Keyword::End |
+					Keyword::Join |
+					Keyword::JoinAny |
+					Keyword::JoinNone |
+					Keyword::EndCase,
+				) if blocks > 0 => {
+					blocks -= 1;
+				},
+				Token::Keyword(keyword) if self.closers.contains(keyword) => return,
+				Token::Keyword(keyword) if is_description_start(*keyword) => return,
+				_ => {},
+			}
+
+			self.bump();
+		}
+	}
+
+	/// Skip the remainder of a top-level construct that failed to parse, stopping after its end
+	/// keyword or at the start of the next one
+	pub(crate) fn synchronize_description(&mut self, start: usize) {
+		// Always make progress, the error may have been at the very first token
+		if self.position == start {
+			self.bump();
+		}
+
+		while let Some(token) = self.peek() {
+			match token {
+				Token::Keyword(keyword) if is_description_start(*keyword) => return,
+				Token::Keyword(keyword) if is_description_end(*keyword) => {
+					self.bump();
+					return;
+				},
+				_ => {
+					self.bump();
+				},
+			}
+		}
+	}
+
+	/// Returns true if the current token closes one of the enclosing constructs, whose closing
+	/// keywords are the first `depth` entries of `closers`, or starts a new top-level construct
+	fn at_boundary(&self, depth: usize) -> bool {
+		match self.peek() {
+			Some(Token::Keyword(keyword)) => {
+				self.closers[..depth].contains(keyword) || is_description_start(*keyword)
+			},
+			_ => false,
+		}
+	}
+}
+
+/// Keywords that can only start a top-level construct
+fn is_description_start(keyword: Keyword) -> bool {
+	matches!(
+		keyword,
+		Keyword::Module |
+			Keyword::MacroModule |
+			Keyword::Primitive |
+			Keyword::Config |
+			Keyword::Package
+	)
+}
+
+/// Keywords that end a top-level construct
+fn is_description_end(keyword: Keyword) -> bool {
+	matches!(
+		keyword,
+		Keyword::EndModule |
+			Keyword::EndInterface |
+			Keyword::EndProgram |
+			Keyword::EndPrimitive |
+			Keyword::EndConfig |
+			Keyword::EndPackage |
+			Keyword::EndClass
+	)
+}
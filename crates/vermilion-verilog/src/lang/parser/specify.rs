@@ -0,0 +1,374 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_lang::AtomicByteTendril;
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{
+		Expression, PathCondition, PathConnection, PathDeclaration, Polarity, PulseStyle,
+		SpecifyItem, SpecparamAssignment, SpecparamDeclaration, SpecparamValue, TimingCheck,
+		TimingCheckEdge, TimingCheckEvent,
+	},
+	keywords::Keyword,
+	parser::{VERILOG_01_STDS, VerilogParser, error::ParseResult},
+	tokenizer::token::Token,
+	types::{Control, EdgeIdentifier, Identifier, Operator},
+};
+
+impl VerilogParser {
+	/// Parse the contents of a `specify` block up to and including the `endspecify`
+	///
+	/// IEEE 1364-2005 § A.7.1
+	pub(crate) fn parse_specify_block(&mut self) -> ParseResult<Vec<Spanned<SpecifyItem>>> {
+		self.expect_keyword(Keyword::Specify)?;
+
+		let mut items = Vec::new();
+		while !self.eat_keyword(Keyword::EndSpecify) {
+			let start = self.span();
+			let item = self.parse_specify_item()?;
+
+			items.push(Spanned::new(item, self.span_from(start)));
+		}
+
+		Ok(items)
+	}
+
+	fn parse_specify_item(&mut self) -> ParseResult<SpecifyItem> {
+		match self.peek() {
+			Some(Token::Keyword(Keyword::SpecParam)) => {
+				Ok(SpecifyItem::Specparam(self.parse_specparam_declaration()?))
+			},
+			Some(Token::Keyword(
+				keyword @ (Keyword::PulseStyleOnEvent | Keyword::PulseStyleOnDetect),
+			)) => {
+				let kind = if *keyword == Keyword::PulseStyleOnEvent {
+					PulseStyle::OnEvent
+				} else {
+					PulseStyle::OnDetect
+				};
+
+				self.require_std(VERILOG_01_STDS, self.span(), "Pulse style declarations");
+				self.bump();
+				let outputs = self.parse_specify_terminals()?;
+				self.expect_control(Control::Semicolon)?;
+
+				Ok(SpecifyItem::PulseStyle { kind, outputs })
+			},
+			Some(Token::Keyword(keyword @ (Keyword::ShowCancelled | Keyword::NoShowCancelled))) => {
+				let show = *keyword == Keyword::ShowCancelled;
+
+				self.require_std(VERILOG_01_STDS, self.span(), "Show cancelled declarations");
+				self.bump();
+				let outputs = self.parse_specify_terminals()?;
+				self.expect_control(Control::Semicolon)?;
+
+				Ok(SpecifyItem::ShowCancelled { show, outputs })
+			},
+			Some(Token::Keyword(Keyword::If)) => {
+				self.bump();
+				self.expect_control(Control::ParenOpen)?;
+				let condition = self.parse_expression()?;
+				self.expect_control(Control::ParenClose)?;
+
+				Ok(SpecifyItem::Path(self.parse_path_declaration(Some(
+					PathCondition::If(condition),
+				))?))
+			},
+			Some(Token::Keyword(Keyword::IfNone)) => {
+				self.bump();
+				Ok(SpecifyItem::Path(
+					self.parse_path_declaration(Some(PathCondition::IfNone))?,
+				))
+			},
+			Some(Token::Control(Control::ParenOpen)) => {
+				Ok(SpecifyItem::Path(self.parse_path_declaration(None)?))
+			},
+			Some(Token::SystemFunc(_)) => Ok(SpecifyItem::TimingCheck(self.parse_timing_check()?)),
+			_ => self.unexpected("a specify item"),
+		}
+	}
+
+	/// IEEE 1364-2005 § A.2.2.1
+	pub(crate) fn parse_specparam_declaration(&mut self) -> ParseResult<SpecparamDeclaration> {
+		self.expect_keyword(Keyword::SpecParam)?;
+
+		let range = if self.is_control(Control::BracketOpen) {
+			Some(self.parse_dimension()?)
+		} else {
+			None
+		};
+
+		let mut assignments = Vec::new();
+		loop {
+			let start = self.span();
+			let name = self.expect_identifier()?;
+			self.expect_operator(Operator::Equals)?;
+
+			let value = if is_pathpulse(name.inner()) {
+				self.expect_control(Control::ParenOpen)?;
+				let reject = self.parse_mintypmax_expression()?;
+				let error = if self.eat_control(Control::Comma) {
+					Some(self.parse_mintypmax_expression()?)
+				} else {
+					None
+				};
+				self.expect_control(Control::ParenClose)?;
+
+				SpecparamValue::PulseControl { reject, error }
+			} else {
+				SpecparamValue::Value(self.parse_mintypmax_expression()?)
+			};
+
+			assignments.push(Spanned::new(
+				SpecparamAssignment { name, value },
+				self.span_from(start),
+			));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(SpecparamDeclaration { range, assignments })
+	}
+
+	fn parse_specify_terminals(&mut self) -> ParseResult<Vec<Spanned<Expression>>> {
+		let mut terminals = vec![self.parse_lvalue()?];
+		while self.eat_control(Control::Comma) {
+			terminals.push(self.parse_lvalue()?);
+		}
+
+		Ok(terminals)
+	}
+
+	/// IEEE 1364-2005 § A.7.2
+	fn parse_path_declaration(
+		&mut self,
+		condition: Option<PathCondition>,
+	) -> ParseResult<PathDeclaration> {
+		self.expect_control(Control::ParenOpen)?;
+
+		let edge = self.parse_edge_identifier();
+		let inputs = self.parse_specify_terminals()?;
+		let (polarity, connection) = self.parse_path_connection()?;
+
+		let (outputs, data_source) = if self.eat_control(Control::ParenOpen) {
+			let outputs = self.parse_specify_terminals()?;
+			let polarity = self.parse_data_source_polarity()?;
+			let data = self.parse_expression()?;
+			self.expect_control(Control::ParenClose)?;
+
+			(outputs, Some((polarity, data)))
+		} else {
+			(self.parse_specify_terminals()?, None)
+		};
+
+		self.expect_control(Control::ParenClose)?;
+		self.expect_operator(Operator::Equals)?;
+
+		let delays = if self.eat_control(Control::ParenOpen) {
+			let mut delays = vec![self.parse_mintypmax_expression()?];
+			while self.eat_control(Control::Comma) {
+				delays.push(self.parse_mintypmax_expression()?);
+			}
+			self.expect_control(Control::ParenClose)?;
+
+			delays
+		} else {
+			vec![self.parse_mintypmax_expression()?]
+		};
+
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(PathDeclaration {
+			condition,
+			edge,
+			inputs,
+			polarity,
+			connection,
+			outputs,
+			data_source,
+			delays,
+		})
+	}
+
+	fn parse_edge_identifier(&mut self) -> Option<EdgeIdentifier> {
+		if self.eat_keyword(Keyword::PosEdge) {
+			Some(EdgeIdentifier::PosEdge)
+		} else if self.eat_keyword(Keyword::NegEdge) {
+			Some(EdgeIdentifier::NegEdge)
+		} else {
+			None
+		}
+	}
+
+	/// Parse the `=>`/`*>` of a path, along with any polarity operator before it
+	fn parse_path_connection(&mut self) -> ParseResult<(Option<Polarity>, PathConnection)> {
+		// NOTE(aki):
+		// `+=>` and `-=>` get split up into `+=`/`-=` and a textual `>` by the tokenizer, which
+		// is an invalid token in the non-SystemVerilog standards, so we need to glue it back up.
+		let split_polarity = match self.peek() {
+			Some(Token::Operator(Operator::AddEquals)) => Some(Polarity::Positive),
+			Some(Token::Operator(Operator::SubEquals)) => Some(Polarity::Negative),
+			Some(Token::ContextuallyInvalid(text, _)) if &text[..] == b"+=" => {
+				Some(Polarity::Positive)
+			},
+			Some(Token::ContextuallyInvalid(text, _)) if &text[..] == b"-=" => {
+				Some(Polarity::Negative)
+			},
+			_ => None,
+		};
+
+		if let Some(polarity) = split_polarity {
+			self.bump();
+			self.expect_operator(Operator::LessThan)?;
+			return Ok((Some(polarity), PathConnection::Parallel));
+		}
+
+		let polarity = if self.eat_operator(Operator::Plus) {
+			Some(Polarity::Positive)
+		} else if self.eat_operator(Operator::Minus) {
+			Some(Polarity::Negative)
+		} else {
+			None
+		};
+
+		let connection = if self.eat_operator(Operator::ParallelConnection) {
+			PathConnection::Parallel
+		} else if self.eat_operator(Operator::FullConnection) {
+			PathConnection::Full
+		} else {
+			return self.unexpected("`=>` or `*>`");
+		};
+
+		Ok((polarity, connection))
+	}
+
+	/// Parse the `+:`, `-:`, or `:` between the outputs and data source of an edge sensitive path
+	fn parse_data_source_polarity(&mut self) -> ParseResult<Option<Polarity>> {
+		let polarity = match self.peek() {
+			// NOTE(aki): `+:` and `-:` are valid here in all standards, not only as part selects
+			Some(Token::Operator(Operator::IndexedPartPos)) => {
+				self.ungate();
+				Some(Polarity::Positive)
+			},
+			Some(Token::Operator(Operator::IndexedPartNeg)) => {
+				self.ungate();
+				Some(Polarity::Negative)
+			},
+			Some(Token::Operator(Operator::Plus)) => {
+				self.bump();
+				self.expect_control(Control::Colon)?;
+				return Ok(Some(Polarity::Positive));
+			},
+			Some(Token::Operator(Operator::Minus)) => {
+				self.bump();
+				self.expect_control(Control::Colon)?;
+				return Ok(Some(Polarity::Negative));
+			},
+			_ => {
+				self.expect_control(Control::Colon)?;
+				return Ok(None);
+			},
+		};
+
+		self.bump();
+
+		Ok(polarity)
+	}
+
+	/// IEEE 1364-2005 § A.7.5
+	fn parse_timing_check(&mut self) -> ParseResult<TimingCheck> {
+		let Some(Token::SystemFunc(name)) = self.peek() else {
+			return self.unexpected("a timing check");
+		};
+		let name = Spanned::new(name.clone(), self.span());
+		self.bump();
+
+		self.expect_control(Control::ParenOpen)?;
+
+		let mut arguments = Vec::new();
+		loop {
+			if self.is_control(Control::Comma) || self.is_control(Control::ParenClose) {
+				arguments.push(None);
+			} else {
+				arguments.push(Some(self.parse_timing_check_event()?));
+			}
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::ParenClose)?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(TimingCheck { name, arguments })
+	}
+
+	fn parse_timing_check_event(&mut self) -> ParseResult<Spanned<TimingCheckEvent>> {
+		let start = self.span();
+
+		let edge = if let Some(edge) = self.parse_edge_identifier() {
+			Some(TimingCheckEdge::Edge(edge))
+		} else if self.eat_keyword(Keyword::Edge) {
+			Some(TimingCheckEdge::Descriptors(self.parse_edge_descriptors()?))
+		} else {
+			None
+		};
+
+		let expression = self.parse_mintypmax_expression()?;
+
+		let condition = if self.eat_operator(Operator::TripleAnd) {
+			Some(self.parse_expression()?)
+		} else {
+			None
+		};
+
+		Ok(Spanned::new(
+			TimingCheckEvent { edge, expression, condition },
+			self.span_from(start),
+		))
+	}
+
+	/// Parse the `[01, 10, ...]` edge control specifier of a timing check event
+	///
+	/// The edge descriptors are taken from the source text directly, as the tokenizer splits them
+	/// up into a mix of numbers and identifiers.
+	fn parse_edge_descriptors(&mut self) -> ParseResult<Vec<Spanned<AtomicByteTendril>>> {
+		self.expect_control(Control::BracketOpen)?;
+
+		let mut descriptors = Vec::new();
+		loop {
+			let start = self.span();
+			while !self.is_control(Control::Comma) && !self.is_control(Control::BracketClose) {
+				if self.at_eof() {
+					return self.unexpected("`]`");
+				}
+
+				self.bump();
+			}
+
+			let span = self.span_from(start);
+			descriptors.push(Spanned::new(self.source_text(&span), span));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::BracketClose)?;
+
+		Ok(descriptors)
+	}
+}
+
+/// Check if the given specparam name is one of the `PATHPULSE$` pulse control specparams
+fn is_pathpulse(name: &Identifier) -> bool {
+	match name {
+		Identifier::Simple(name) => name.starts_with(b"PATHPULSE$"),
+		Identifier::Escaped(_) => false,
+	}
+}
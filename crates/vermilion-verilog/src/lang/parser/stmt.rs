@@ -0,0 +1,664 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::{
+	LanguageStd,
+	lang::{
+		ast::{
+			Assignment, AssignmentOperator, Block, BlockKind, Case, CaseItem, CaseKind,
+			CaseQualifier, Expression, ForInit, Statement, StatementKind, TimingControl,
+		},
+		cst::NodeKind,
+		keywords::Keyword,
+		parser::{VerilogParser, error::ParseResult},
+		tokenizer::token::Token,
+		types::{CompilerDirective, Control, Identifier, Operator},
+	},
+};
+
+/// The array being iterated over by a `foreach` and its loop variables
+pub(crate) type ForeachHeader = (Spanned<Expression>, Vec<Option<Spanned<Identifier>>>);
+
+impl VerilogParser {
+	/// Parse a statement in a block or subroutine body
+	pub(crate) fn parse_list_statement(&mut self) -> ParseResult<Option<Spanned<Statement>>> {
+		self.parse_statement().map(Some)
+	}
+
+	/// IEEE 1364-2005 § A.6.4, IEEE 1800-2017 § A.6.4
+	pub(crate) fn parse_statement(&mut self) -> ParseResult<Spanned<Statement>> {
+		let start = self.span();
+		let attributes = self.parse_attributes()?;
+
+		let label = if self.is_identifier() && self.is_control_at(1, Control::Colon) {
+			let label = self.expect_identifier()?;
+			self.bump();
+			self.require_std(
+				LanguageStd::SYSTEM_VERILOG_STDS,
+				self.span_from(*label.span()),
+				"Statement labels",
+			);
+
+			Some(label)
+		} else {
+			None
+		};
+
+		let kind = self.parse_statement_kind()?;
+
+		let statement = Spanned::new(Statement { attributes, label, kind }, self.span_from(start));
+		Ok(self.node(NodeKind::Statement, statement))
+	}
+
+	fn parse_statement_kind(&mut self) -> ParseResult<StatementKind> {
+		match self.peek() {
+			Some(Token::Control(Control::Semicolon)) => {
+				self.bump();
+				Ok(StatementKind::Null)
+			},
+			Some(Token::Control(Control::Octothorp | Control::At)) |
+			Some(Token::Operator(Operator::CycleDelay)) => {
+				let control = self.parse_timing_control()?;
+				let statement = self.parse_statement()?;
+
+				Ok(StatementKind::TimingControl { control, statement: Box::new(statement) })
+			},
+			Some(Token::Operator(Operator::EventTrigger)) => {
+				self.bump();
+				let name = self.parse_hierarchical_identifier()?;
+				self.expect_control(Control::Semicolon)?;
+
+				Ok(StatementKind::EventTrigger(name))
+			},
+			Some(Token::Operator(Operator::EventTriggerNb)) => {
+				self.bump();
+				let control = self.parse_intra_assignment_control()?;
+				let name = self.parse_hierarchical_identifier()?;
+				self.expect_control(Control::Semicolon)?;
+
+				Ok(StatementKind::EventTriggerNb { control, name })
+			},
+			Some(Token::Operator(Operator::Increment | Operator::Decrement)) => {
+				let expression = self.parse_step_expression()?;
+				self.expect_control(Control::Semicolon)?;
+
+				Ok(StatementKind::Expression(expression))
+			},
+			Some(Token::Keyword(keyword)) => match keyword {
+				Keyword::Begin => Ok(StatementKind::Block(self.parse_block()?)),
+				Keyword::Fork => Ok(StatementKind::Block(self.parse_block()?)),
+				Keyword::If => self.parse_if_statement(None),
+				Keyword::Case | Keyword::CaseX | Keyword::CaseZ => {
+					Ok(StatementKind::Case(self.parse_case_statement(None)?))
+				},
+				Keyword::Unique | Keyword::Unique0 | Keyword::Priority => {
+					let qualifier = match keyword {
+						Keyword::Unique => CaseQualifier::Unique,
+						Keyword::Unique0 => CaseQualifier::Unique0,
+						_ => CaseQualifier::Priority,
+					};
+					self.bump();
+
+					if self.is_keyword(Keyword::If) {
+						self.parse_if_statement(Some(qualifier))
+					} else {
+						Ok(StatementKind::Case(
+							self.parse_case_statement(Some(qualifier))?,
+						))
+					}
+				},
+				Keyword::Forever => {
+					self.bump();
+					let body = self.parse_statement()?;
+
+					Ok(StatementKind::Forever(Box::new(body)))
+				},
+				Keyword::Repeat => {
+					self.bump();
+					self.expect_control(Control::ParenOpen)?;
+					let count = self.parse_expression()?;
+					self.expect_control(Control::ParenClose)?;
+					let body = self.parse_statement()?;
+
+					Ok(StatementKind::Repeat { count, body: Box::new(body) })
+				},
+				Keyword::While => {
+					self.bump();
+					self.expect_control(Control::ParenOpen)?;
+					let condition = self.parse_expression()?;
+					self.expect_control(Control::ParenClose)?;
+					let body = self.parse_statement()?;
+
+					Ok(StatementKind::While { condition, body: Box::new(body) })
+				},
+				Keyword::Do => {
+					self.bump();
+					let body = self.parse_statement()?;
+					self.expect_keyword(Keyword::While)?;
+					self.expect_control(Control::ParenOpen)?;
+					let condition = self.parse_expression()?;
+					self.expect_control(Control::ParenClose)?;
+					self.expect_control(Control::Semicolon)?;
+
+					Ok(StatementKind::DoWhile { body: Box::new(body), condition })
+				},
+				Keyword::For => self.parse_for_statement(),
+				Keyword::ForEach => {
+					self.bump();
+					self.expect_control(Control::ParenOpen)?;
+					let (array, variables) = self.parse_foreach_header()?;
+					self.expect_control(Control::ParenClose)?;
+					let body = self.parse_statement()?;
+
+					Ok(StatementKind::Foreach { array, variables, body: Box::new(body) })
+				},
+				Keyword::Return => {
+					self.bump();
+
+					let value = if self.is_control(Control::Semicolon) {
+						None
+					} else {
+						Some(self.parse_expression()?)
+					};
+					self.expect_control(Control::Semicolon)?;
+
+					Ok(StatementKind::Return(value))
+				},
+				Keyword::Break | Keyword::Continue => {
+					let kind = if self.eat_keyword(Keyword::Break) {
+						StatementKind::Break
+					} else {
+						self.bump();
+						StatementKind::Continue
+					};
+					self.expect_control(Control::Semicolon)?;
+
+					Ok(kind)
+				},
+				Keyword::Wait => {
+					self.bump();
+
+					if self.eat_keyword(Keyword::Fork) {
+						self.expect_control(Control::Semicolon)?;
+						return Ok(StatementKind::WaitFork);
+					}
+
+					self.expect_control(Control::ParenOpen)?;
+					let condition = self.parse_expression()?;
+					self.expect_control(Control::ParenClose)?;
+					let statement = self.parse_statement()?;
+
+					Ok(StatementKind::Wait { condition, statement: Box::new(statement) })
+				},
+				Keyword::Disable => {
+					self.bump();
+
+					if self.eat_keyword(Keyword::Fork) {
+						self.expect_control(Control::Semicolon)?;
+						return Ok(StatementKind::DisableFork);
+					}
+
+					let name = self.parse_hierarchical_identifier()?;
+					self.expect_control(Control::Semicolon)?;
+
+					Ok(StatementKind::Disable(name))
+				},
+				Keyword::Assign => {
+					self.bump();
+					let assignment = self.parse_assignment()?;
+					self.expect_control(Control::Semicolon)?;
+
+					Ok(StatementKind::ProceduralAssign(assignment.as_inner()))
+				},
+				Keyword::Deassign => {
+					self.bump();
+					let lvalue = self.parse_lvalue()?;
+					self.expect_control(Control::Semicolon)?;
+
+					Ok(StatementKind::Deassign(lvalue))
+				},
+				Keyword::Force => {
+					self.bump();
+					let assignment = self.parse_assignment()?;
+					self.expect_control(Control::Semicolon)?;
+
+					Ok(StatementKind::Force(assignment.as_inner()))
+				},
+				Keyword::Release => {
+					self.bump();
+					let lvalue = self.parse_lvalue()?;
+					self.expect_control(Control::Semicolon)?;
+
+					Ok(StatementKind::Release(lvalue))
+				},
+				Keyword::Assert | Keyword::Assume | Keyword::Cover | Keyword::Restrict => {
+					Ok(StatementKind::Assertion(Box::new(self.parse_assertion()?)))
+				},
+				Keyword::Void => {
+					let expression = self.parse_expression()?;
+					self.expect_control(Control::Semicolon)?;
+
+					Ok(StatementKind::Expression(expression))
+				},
+				Keyword::This | Keyword::Super => self.parse_assignment_or_task_enable(),
+				_ => self.unexpected("a statement"),
+			},
+			Some(Token::SystemFunc(name)) => {
+				let name = Spanned::new(name.clone(), self.span());
+				self.bump();
+				let arguments = self.parse_system_arguments()?;
+				self.expect_control(Control::Semicolon)?;
+
+				Ok(StatementKind::SystemTaskEnable { name, arguments })
+			},
+			Some(
+				Token::Identifier(_) |
+				Token::Control(Control::BraceOpen) |
+				Token::CompilerDirective(CompilerDirective::TextMacro(_)),
+			) => self.parse_assignment_or_task_enable(),
+			_ => self.unexpected("a statement"),
+		}
+	}
+
+	fn parse_assignment_or_task_enable(&mut self) -> ParseResult<StatementKind> {
+		let lvalue = self.parse_lvalue()?;
+
+		if self.is_control(Control::ParenOpen) || self.is_control(Control::Semicolon) {
+			let start = *lvalue.span();
+			let arguments = if self.is_control(Control::ParenOpen) {
+				self.parse_call_arguments()?
+			} else {
+				Vec::new()
+			};
+
+			// `obj.randomize() with { ... };`
+			if self.is_keyword(Keyword::With) {
+				let call = Spanned::new(
+					Expression::Call {
+						target: Box::new(lvalue),
+						attributes: Vec::new(),
+						arguments,
+					},
+					self.span_from(start),
+				);
+				let expression = self.parse_with_clause(call)?;
+				self.expect_control(Control::Semicolon)?;
+
+				return Ok(StatementKind::Expression(expression));
+			}
+
+			// `V(p, n) <+ value;` and `V(out) : V(in) == 0;`
+			if self.is_operator(Operator::BranchContribution) || self.is_control(Control::Colon) {
+				let target = Spanned::new(
+					Expression::Call {
+						target: Box::new(lvalue),
+						attributes: Vec::new(),
+						arguments,
+					},
+					self.span_from(start),
+				);
+
+				let kind = if self.eat_operator(Operator::BranchContribution) {
+					let value = self.parse_expression()?;
+					StatementKind::Contribution { target, value }
+				} else {
+					self.bump();
+					let equation = self.parse_expression()?;
+					StatementKind::IndirectContribution { target, equation }
+				};
+				self.expect_control(Control::Semicolon)?;
+
+				return Ok(kind);
+			}
+
+			self.expect_control(Control::Semicolon)?;
+
+			return Ok(StatementKind::TaskEnable { name: lvalue, arguments });
+		}
+
+		// NOTE(aki): The tokenizer calls the textual `<=` `GreaterThanEqual`
+		let blocking = if self.eat_operator(Operator::Equals) {
+			true
+		} else if self.eat_operator(Operator::GreaterThanEqual) {
+			false
+		} else if self.peek_assignment_operator().is_some() ||
+			self.is_operator(Operator::Increment) ||
+			self.is_operator(Operator::Decrement)
+		{
+			let expression = self.parse_step_suffix(lvalue)?;
+			self.expect_control(Control::Semicolon)?;
+
+			return Ok(StatementKind::Expression(expression));
+		} else {
+			return self.unexpected("`=`, `<=`, `(`, or `;`");
+		};
+
+		let control = self.parse_intra_assignment_control()?;
+		let value = self.parse_expression()?;
+		self.expect_control(Control::Semicolon)?;
+
+		let assignment = Assignment { lvalue, control, value };
+
+		Ok(if blocking {
+			StatementKind::Blocking(assignment)
+		} else {
+			StatementKind::NonBlocking(assignment)
+		})
+	}
+
+	/// Parse a plain `lvalue = expression` assignment
+	pub(crate) fn parse_assignment(&mut self) -> ParseResult<Spanned<Assignment>> {
+		let start = self.span();
+		let lvalue = self.parse_lvalue()?;
+		self.expect_operator(Operator::Equals)?;
+		let value = self.parse_expression()?;
+
+		Ok(Spanned::new(
+			Assignment { lvalue, control: None, value },
+			self.span_from(start),
+		))
+	}
+
+	fn parse_intra_assignment_control(&mut self) -> ParseResult<Option<Spanned<TimingControl>>> {
+		if self.is_control(Control::Octothorp) ||
+			self.is_control(Control::At) ||
+			self.is_operator(Operator::CycleDelay) ||
+			self.is_keyword(Keyword::Repeat)
+		{
+			Ok(Some(self.parse_timing_control()?))
+		} else {
+			Ok(None)
+		}
+	}
+
+	/// IEEE 1364-2005 § A.6.5
+	pub(crate) fn parse_timing_control(&mut self) -> ParseResult<Spanned<TimingControl>> {
+		let start = self.span();
+
+		if self.is_control(Control::Octothorp) {
+			let delay = self.parse_delay()?;
+			return Ok(Spanned::new(
+				TimingControl::Delay(delay.as_inner()),
+				self.span_from(start),
+			));
+		}
+
+		if self.eat_operator(Operator::CycleDelay) {
+			let count = if self.is_control(Control::ParenOpen) {
+				self.bump();
+				let count = self.parse_expression()?;
+				self.expect_control(Control::ParenClose)?;
+				count
+			} else {
+				self.parse_primary()?
+			};
+
+			return Ok(Spanned::new(
+				TimingControl::Cycle(count),
+				self.span_from(start),
+			));
+		}
+
+		if self.eat_keyword(Keyword::Repeat) {
+			self.expect_control(Control::ParenOpen)?;
+			let count = self.parse_expression()?;
+			self.expect_control(Control::ParenClose)?;
+			let event = self.parse_event_control()?;
+
+			return Ok(Spanned::new(
+				TimingControl::Repeat { count, event },
+				self.span_from(start),
+			));
+		}
+
+		let event = self.parse_event_control()?;
+		Ok(Spanned::new(
+			TimingControl::Event(event.as_inner()),
+			self.span_from(start),
+		))
+	}
+
+	fn parse_if_statement(
+		&mut self,
+		qualifier: Option<CaseQualifier>,
+	) -> ParseResult<StatementKind> {
+		self.expect_keyword(Keyword::If)?;
+		self.expect_control(Control::ParenOpen)?;
+		let condition = self.parse_expression()?;
+		self.expect_control(Control::ParenClose)?;
+
+		let if_true = self.parse_statement()?;
+		let if_false = if self.eat_keyword(Keyword::Else) {
+			Some(Box::new(self.parse_statement()?))
+		} else {
+			None
+		};
+
+		Ok(StatementKind::If {
+			qualifier,
+			condition,
+			if_true: Box::new(if_true),
+			if_false,
+		})
+	}
+
+	/// Parse a `for` loop, SystemVerilog permits declaring the loop variables and having multiple
+	/// or no initializers and steps
+	///
+	/// IEEE 1800-2017 § A.6.8
+	fn parse_for_statement(&mut self) -> ParseResult<StatementKind> {
+		let start = self.expect_keyword(Keyword::For)?;
+		self.expect_control(Control::ParenOpen)?;
+
+		let mut init = Vec::new();
+		if !self.is_control(Control::Semicolon) {
+			loop {
+				let init_start = self.span();
+
+				let data_type = if self.is_keyword(Keyword::Var) ||
+					self.is_data_type_keyword(0) ||
+					self.user_type_lookahead(0).is_some()
+				{
+					self.eat_keyword(Keyword::Var);
+					Some(self.parse_data_type()?)
+				} else {
+					None
+				};
+				let assignment = self.parse_assignment()?;
+
+				init.push(Spanned::new(
+					ForInit { data_type, assignment },
+					self.span_from(init_start),
+				));
+
+				if !self.eat_control(Control::Comma) {
+					break;
+				}
+			}
+		}
+		self.expect_control(Control::Semicolon)?;
+
+		let condition = if self.is_control(Control::Semicolon) {
+			None
+		} else {
+			Some(self.parse_expression()?)
+		};
+		self.expect_control(Control::Semicolon)?;
+
+		let mut step = Vec::new();
+		if !self.is_control(Control::ParenClose) {
+			step.push(self.parse_step_expression()?);
+			while self.eat_control(Control::Comma) {
+				step.push(self.parse_step_expression()?);
+			}
+		}
+		self.expect_control(Control::ParenClose)?;
+
+		let extended = init.len() != 1 ||
+			init.iter().any(|init| init.data_type.is_some()) ||
+			condition.is_none() ||
+			step.len() != 1 ||
+			step.iter().any(|step| {
+				!matches!(
+					step.inner(),
+					Expression::Assign { operator, .. }
+						if *operator.inner() == AssignmentOperator::Assign
+				)
+			});
+		if extended {
+			self.require_std(
+				LanguageStd::SYSTEM_VERILOG_STDS,
+				self.span_from(start),
+				"Loop variable declarations, multiple or omitted for loop clauses, and operator \
+				 assignment steps",
+			);
+		}
+
+		let body = self.parse_statement()?;
+
+		Ok(StatementKind::For { init, condition, step, body: Box::new(body) })
+	}
+
+	/// Parse the `array[i, j]` of a `foreach` loop, skipped loop variables are `None`
+	///
+	/// IEEE 1800-2017 § A.6.8
+	pub(crate) fn parse_foreach_header(&mut self) -> ParseResult<ForeachHeader> {
+		let array = self.parse_hierarchical_identifier()?;
+
+		self.expect_control(Control::BracketOpen)?;
+		let mut variables = Vec::new();
+		loop {
+			if self.is_identifier() {
+				variables.push(Some(self.expect_identifier()?));
+			} else {
+				variables.push(None);
+			}
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+		self.expect_control(Control::BracketClose)?;
+
+		Ok((array, variables))
+	}
+
+	fn parse_case_statement(&mut self, qualifier: Option<CaseQualifier>) -> ParseResult<Case> {
+		let kind = if self.eat_keyword(Keyword::CaseX) {
+			CaseKind::CaseX
+		} else if self.eat_keyword(Keyword::CaseZ) {
+			CaseKind::CaseZ
+		} else {
+			self.expect_keyword(Keyword::Case)?;
+			CaseKind::Case
+		};
+
+		self.expect_control(Control::ParenOpen)?;
+		let expression = self.parse_expression()?;
+		self.expect_control(Control::ParenClose)?;
+
+		let inside = self.eat_keyword(Keyword::Inside);
+
+		let mut items = Vec::new();
+		while !self.eat_keyword(Keyword::EndCase) {
+			if self.at_eof() {
+				return self.unexpected("`endcase`");
+			}
+
+			let start = self.span();
+			let labels = if inside {
+				self.parse_case_inside_labels()?
+			} else {
+				self.parse_case_labels()?
+			};
+			let statement = self.parse_statement()?;
+
+			items.push(Spanned::new(
+				CaseItem { labels, statement },
+				self.span_from(start),
+			));
+		}
+
+		Ok(Case { qualifier, kind, inside, expression, items })
+	}
+
+	/// Parse the labels of a case item up to and including the `:`, `default` has no labels
+	pub(crate) fn parse_case_labels(&mut self) -> ParseResult<Vec<Spanned<Expression>>> {
+		if self.eat_keyword(Keyword::Default) {
+			self.eat_control(Control::Colon);
+			return Ok(Vec::new());
+		}
+
+		let mut labels = vec![self.parse_expression()?];
+		while self.eat_control(Control::Comma) {
+			labels.push(self.parse_expression()?);
+		}
+
+		self.expect_control(Control::Colon)?;
+
+		Ok(labels)
+	}
+
+	/// Parse the labels of a `case inside` item, which may also be `[low:high]` ranges
+	fn parse_case_inside_labels(&mut self) -> ParseResult<Vec<Spanned<Expression>>> {
+		if self.eat_keyword(Keyword::Default) {
+			self.eat_control(Control::Colon);
+			return Ok(Vec::new());
+		}
+
+		let mut labels = vec![self.parse_value_range()?];
+		while self.eat_control(Control::Comma) {
+			labels.push(self.parse_value_range()?);
+		}
+
+		self.expect_control(Control::Colon)?;
+
+		Ok(labels)
+	}
+
+	fn parse_block(&mut self) -> ParseResult<Block> {
+		let parallel = if self.eat_keyword(Keyword::Fork) {
+			true
+		} else {
+			self.expect_keyword(Keyword::Begin)?;
+			false
+		};
+
+		let name = if self.eat_control(Control::Colon) {
+			Some(self.expect_identifier()?)
+		} else {
+			None
+		};
+
+		let mut items = Vec::new();
+		if name.is_some() || self.is_system_verilog() {
+			while let Some(item) = self.parse_block_item_declaration(false)? {
+				items.push(item);
+			}
+		}
+
+		let ends: &[Keyword] = if parallel {
+			&[Keyword::Join, Keyword::JoinAny, Keyword::JoinNone]
+		} else {
+			&[Keyword::End]
+		};
+
+		let (statements, end) = self.parse_list(ends, Self::parse_list_statement, || {
+			Statement::new(StatementKind::Error)
+		});
+
+		let kind = match end {
+			Some(Keyword::JoinAny) => BlockKind::ParallelAny,
+			Some(Keyword::JoinNone) => BlockKind::ParallelNone,
+			_ if parallel => BlockKind::Parallel,
+			_ => BlockKind::Sequential,
+		};
+
+		if end.is_some() {
+			self.parse_end_label(name.as_ref())?;
+		}
+
+		Ok(Block { kind, name, items, statements })
+	}
+}
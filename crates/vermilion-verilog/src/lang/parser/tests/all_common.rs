@@ -1 +1,910 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+use crate::lang::types::{Identifier, NetType, SystemFunc};
+
+fn ident(name: &str) -> Identifier {
+	Identifier::Simple(name.as_bytes().into())
+}
+
+/// Get the items of the first module in the file
+fn module_items(ast: &Ast) -> &[vermilion_loc::Spanned<Item>] {
+	match ast.modules().next() {
+		Some(module) => &module.items,
+		None => panic!("No module in {ast:#?}"),
+	}
+}
+
+/// Get the statement of the first `initial` or `always` in the first module in the file
+fn first_process(ast: &Ast) -> &Statement {
+	for item in module_items(ast) {
+		if let ItemKind::Initial(statement) | ItemKind::Always(statement) = &item.kind {
+			return statement.inner();
+		}
+	}
+
+	panic!("No process in {ast:#?}")
+}
+
+parser_test!(all, empty_file, "", |parsed| {
+	assert!(expect_ast(parsed).descriptions().is_empty());
+});
+
+parser_test!(all, empty_module, "module foo; endmodule", |parsed| {
+	let ast = expect_ast(parsed);
+	let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+	assert_eq!(module.name.inner(), &ident("foo"));
+	assert_eq!(module.keyword, ModuleKeyword::Module);
+	assert_eq!(module.ports, Ports::None);
+	assert!(module.items.is_empty());
+	assert_eq!(*ast.descriptions()[0].span().begin(), 0);
+	assert_eq!(*ast.descriptions()[0].span().end(), 21);
+});
+
+parser_test!(all, macromodule, "macromodule foo(); endmodule", |parsed| {
+	let ast = expect_ast(parsed);
+	let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+	assert_eq!(module.keyword, ModuleKeyword::MacroModule);
+	assert_eq!(module.ports, Ports::NonAnsi(Vec::new()));
+});
+
+parser_test!(
+	all,
+	multiple_modules,
+	"module a; endmodule\nmodule b; endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let names = ast
+			.modules()
+			.map(|module| module.name.inner().clone())
+			.collect::<Vec<_>>();
+
+		assert_eq!(names, vec![ident("a"), ident("b")]);
+	}
+);
+
+parser_test!(
+	all,
+	non_ansi_ports,
+	"module m(a, b[3:0], .c(d), , {e, f});\n\tinput a;\n\toutput [3:0] b;\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let Ports::NonAnsi(ports) = &module.ports else {
+			panic!("Expected non-ANSI ports, got {:#?}", module.ports);
+		};
+
+		assert_eq!(ports.len(), 5);
+		assert!(ports[0].name.is_none());
+		assert!(matches!(
+			ports[1].expression.as_ref().map(|expr| expr.inner()),
+			Some(Expression::Select { .. })
+		));
+		assert_eq!(
+			ports[2].name.as_ref().map(|name| name.inner()),
+			Some(&ident("c"))
+		);
+		assert!(ports[3].expression.is_none());
+		assert!(matches!(
+			ports[4].expression.as_ref().map(|expr| expr.inner()),
+			Some(Expression::Concatenation(items)) if items.len() == 2
+		));
+
+		let items = module_items(&ast);
+		assert!(matches!(
+			&items[0].kind,
+			ItemKind::Port(PortDeclaration { direction: Some(PortDirection::Input), .. })
+		));
+		let ItemKind::Port(port) = &items[1].kind else {
+			panic!("Expected a port declaration, got {:#?}", items[1]);
+		};
+		assert_eq!(port.direction, Some(PortDirection::Output));
+		assert_eq!(port.data_type.packed().len(), 1);
+	}
+);
+
+parser_test!(
+	all,
+	net_declarations,
+	"module m;\n\twire a, b;\n\ttri1 [7:0] c = 8'hFF;\n\twand (strong0, weak1) #(1, 2) \
+	 d;\n\ttrireg (small) e;\n\twire vectored [1:0] f;\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let nets = module_items(&ast)
+			.iter()
+			.map(|item| match &item.kind {
+				ItemKind::Net(net) => net,
+				other => panic!("Expected a net declaration, got {other:#?}"),
+			})
+			.collect::<Vec<_>>();
+
+		assert_eq!(nets[0].net_type, NetType::Wire);
+		assert_eq!(nets[0].declarators.len(), 2);
+
+		assert_eq!(nets[1].net_type, NetType::Tri1);
+		assert!(matches!(
+			nets[1].declarators[0]
+				.init
+				.as_ref()
+				.map(|init| init.inner()),
+			Some(Expression::Literal(Literal::Based { .. }))
+		));
+
+		assert_eq!(nets[2].net_type, NetType::WireAnd);
+		assert_eq!(
+			nets[2].strength,
+			Some(Strength::Drive(crate::lang::types::DriveStrength::LowHigh(
+				crate::lang::types::LowStrength::Strong,
+				crate::lang::types::HighStrength::Weak
+			)))
+		);
+		assert_eq!(
+			nets[2].delay.as_ref().map(|delay| delay.values.len()),
+			Some(2)
+		);
+
+		assert_eq!(
+			nets[3].strength,
+			Some(Strength::Charge(crate::lang::types::ChargeStrength::Small))
+		);
+		assert_eq!(nets[4].vector, Some(VectorKind::Vectored));
+	}
+);
+
+parser_test!(
+	all,
+	variable_declarations,
+	"module m;\n\treg a, b[0:3];\n\treg [7:0] c = 0;\n\tinteger i;\n\ttime t;\n\treal \
+	 r;\n\trealtime rt;\n\tevent e;\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let types = module_items(&ast)
+			.iter()
+			.map(|item| match &item.kind {
+				ItemKind::Variable(variable) => variable.data_type.clone(),
+				other => panic!("Expected a variable declaration, got {other:#?}"),
+			})
+			.collect::<Vec<_>>();
+
+		assert!(matches!(
+			&types[0],
+			DataType::IntegerVector { kind: IntegerVectorType::Reg, packed, .. } if packed.is_empty()
+		));
+		assert_eq!(types[1].packed().len(), 1);
+		assert!(matches!(
+			types[2],
+			DataType::IntegerAtom { kind: IntegerAtomType::Integer, .. }
+		));
+		assert!(matches!(
+			types[3],
+			DataType::IntegerAtom { kind: IntegerAtomType::Time, .. }
+		));
+		assert_eq!(types[4], DataType::NonInteger(NonIntegerType::Real));
+		assert_eq!(types[5], DataType::NonInteger(NonIntegerType::Realtime));
+		assert_eq!(types[6], DataType::Event);
+
+		let ItemKind::Variable(variable) = &module_items(&ast)[0].kind else {
+			unreachable!();
+		};
+		assert_eq!(variable.declarators[1].dimensions.len(), 1);
+	}
+);
+
+parser_test!(
+	all,
+	parameters,
+	"module m;\n\tparameter A = 1, B = A * 2;\n\tparameter [3:0] C = 4'b1010;\n\tparameter \
+	 integer D = 3;\n\tdefparam u.A = 2, u.v.B = 3;\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let items = module_items(&ast);
+
+		let ItemKind::Parameter(parameter) = &items[0].kind else {
+			panic!("Expected a parameter, got {:#?}", items[0]);
+		};
+		assert!(!parameter.local);
+		assert_eq!(parameter.declarators.len(), 2);
+
+		let ItemKind::Parameter(parameter) = &items[1].kind else {
+			panic!("Expected a parameter, got {:#?}", items[1]);
+		};
+		assert_eq!(parameter.data_type.packed().len(), 1);
+
+		let ItemKind::Parameter(parameter) = &items[2].kind else {
+			panic!("Expected a parameter, got {:#?}", items[2]);
+		};
+		assert!(matches!(
+			parameter.data_type,
+			DataType::IntegerAtom { kind: IntegerAtomType::Integer, .. }
+		));
+
+		let ItemKind::Defparam(assignments) = &items[3].kind else {
+			panic!("Expected a defparam, got {:#?}", items[3]);
+		};
+		assert_eq!(assignments.len(), 2);
+		assert!(matches!(
+			assignments[1].lvalue.inner(),
+			Expression::Member { .. }
+		));
+	}
+);
+
+parser_test!(
+	all,
+	expression_precedence,
+	"module m; assign a = b + c * d == e || f && g; endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let ItemKind::ContinuousAssign(assign) = &module_items(&ast)[0].kind else {
+			panic!("Expected a continuous assignment");
+		};
+
+		// ((b + (c * d)) == e) || (f && g)
+		let Expression::Binary { operator, lhs, rhs, .. } = assign.assignments[0].value.inner()
+		else {
+			panic!("Expected a binary expression");
+		};
+		assert_eq!(*operator.inner(), BinaryOperator::LogicalOr);
+		assert!(matches!(
+			rhs.inner(),
+			Expression::Binary { operator, .. } if *operator.inner() == BinaryOperator::LogicalAnd
+		));
+
+		let Expression::Binary { operator, lhs, .. } = lhs.inner() else {
+			panic!("Expected a binary expression");
+		};
+		assert_eq!(*operator.inner(), BinaryOperator::LogicalEquality);
+
+		let Expression::Binary { operator, rhs, .. } = lhs.inner() else {
+			panic!("Expected a binary expression");
+		};
+		assert_eq!(*operator.inner(), BinaryOperator::Add);
+		assert!(matches!(
+			rhs.inner(),
+			Expression::Binary { operator, .. } if *operator.inner() == BinaryOperator::Multiply
+		));
+	}
+);
+
+parser_test!(
+	all,
+	expression_relational,
+	"module m; assign a = (b < c) & (d >= e) & (f << 1); endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let ItemKind::ContinuousAssign(assign) = &module_items(&ast)[0].kind else {
+			panic!("Expected a continuous assignment");
+		};
+
+		let mut operators = Vec::new();
+		let mut stack = vec![assign.assignments[0].value.inner()];
+		while let Some(expression) = stack.pop() {
+			match expression {
+				Expression::Binary { operator, lhs, rhs, .. } => {
+					operators.push(*operator.inner());
+					stack.push(lhs.inner());
+					stack.push(rhs.inner());
+				},
+				Expression::Parenthesized(inner) => stack.push(inner.inner()),
+				_ => {},
+			}
+		}
+
+		assert!(operators.contains(&BinaryOperator::LessThan));
+		assert!(operators.contains(&BinaryOperator::GreaterThanEqual));
+		assert!(operators.contains(&BinaryOperator::ShiftLeft));
+	}
+);
+
+parser_test!(
+	all,
+	expression_primaries,
+	"module m; assign a = {2{b[3:0], c}} ? -d : $random(e) + f(g, 1.5) + \"str\" + 'hx; endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let ItemKind::ContinuousAssign(assign) = &module_items(&ast)[0].kind else {
+			panic!("Expected a continuous assignment");
+		};
+
+		let Expression::Conditional { condition, if_true, if_false, .. } =
+			assign.assignments[0].value.inner()
+		else {
+			panic!("Expected a conditional expression");
+		};
+
+		assert!(matches!(
+			condition.inner(),
+			Expression::Replication { items, .. } if items.len() == 2
+		));
+		assert!(matches!(
+			if_true.inner(),
+			Expression::Unary { operator, .. } if *operator.inner() == UnaryOperator::Minus
+		));
+
+		let mut primaries = Vec::new();
+		let mut stack = vec![if_false.inner()];
+		while let Some(expression) = stack.pop() {
+			match expression {
+				Expression::Binary { lhs, rhs, .. } => {
+					stack.push(rhs.inner());
+					stack.push(lhs.inner());
+				},
+				other => primaries.push(other),
+			}
+		}
+
+		assert!(matches!(
+			primaries[0],
+			Expression::SystemCall { name, arguments }
+				if matches!(name.inner(), SystemFunc::Builtin(_)) && arguments.len() == 1
+		));
+		assert!(matches!(
+			primaries[1],
+			Expression::Call { arguments, .. } if arguments.len() == 2
+		));
+		assert!(matches!(
+			primaries[2],
+			Expression::Literal(Literal::String(_))
+		));
+		assert!(matches!(
+			primaries[3],
+			Expression::Literal(Literal::Based { size: None, .. })
+		));
+	}
+);
+
+parser_test!(
+	all,
+	mintypmax_delay,
+	"module m; assign #(1:2:3) a = b; endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let ItemKind::ContinuousAssign(assign) = &module_items(&ast)[0].kind else {
+			panic!("Expected a continuous assignment");
+		};
+
+		let delay = assign
+			.delay
+			.as_ref()
+			.unwrap_or_else(|| panic!("Expected a delay"));
+		assert!(matches!(
+			delay.values[0].inner(),
+			Expression::MinTypMax { .. }
+		));
+	}
+);
+
+parser_test!(
+	all,
+	procedural_statements,
+	"module m;\n\tinitial begin : blk\n\t\treg r;\n\t\ta = 1;\n\t\tb <= #1 2;\n\t\tif (a) c = 0; \
+	 else c = 1;\n\t\tcasez (d)\n\t\t\t2'b1?: e = 1;\n\t\t\t2'b00, 2'b01: ;\n\t\t\tdefault: e = \
+	 0;\n\t\tendcase\n\t\tfor (i = 0; i < 4; i = i + 1) f[i] = 0;\n\t\twhile (g) g = g - \
+	 1;\n\t\trepeat (3) @(posedge clk);\n\t\tforever #5 clk = ~clk;\n\t\twait (h) -> \
+	 ev;\n\t\tdisable blk;\n\t\t$display(\"%d\", a, , b);\n\t\tt(1, 2);\n\t\tassign x = \
+	 1;\n\t\tdeassign x;\n\t\tforce y = 0;\n\t\trelease y;\n\t\tfork a = 1; b = 2; \
+	 join\n\tend\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let StatementKind::Block(block) = &first_process(&ast).kind else {
+			panic!("Expected a block");
+		};
+
+		assert_eq!(block.kind, BlockKind::Sequential);
+		assert_eq!(
+			block.name.as_ref().map(|name| name.inner()),
+			Some(&ident("blk"))
+		);
+		assert_eq!(block.items.len(), 1);
+
+		let kinds = block
+			.statements
+			.iter()
+			.map(|statement| &statement.kind)
+			.collect::<Vec<_>>();
+
+		assert!(matches!(kinds[0], StatementKind::Blocking(_)));
+		assert!(matches!(
+			kinds[1],
+			StatementKind::NonBlocking(Assignment { control: Some(_), .. })
+		));
+		assert!(matches!(
+			kinds[2],
+			StatementKind::If { if_false: Some(_), .. }
+		));
+
+		let StatementKind::Case(case) = kinds[3] else {
+			panic!("Expected a case statement, got {:#?}", kinds[3]);
+		};
+		assert_eq!(case.kind, CaseKind::CaseZ);
+		assert_eq!(case.items.len(), 3);
+		assert_eq!(case.items[1].labels.len(), 2);
+		assert_eq!(case.items[1].statement.kind, StatementKind::Null);
+		assert!(case.items[2].labels.is_empty());
+
+		assert!(matches!(kinds[4], StatementKind::For { .. }));
+		assert!(matches!(kinds[5], StatementKind::While { .. }));
+		assert!(matches!(kinds[6], StatementKind::Repeat { .. }));
+		assert!(matches!(kinds[7], StatementKind::Forever(_)));
+		assert!(matches!(kinds[8], StatementKind::Wait { .. }));
+		assert!(matches!(kinds[9], StatementKind::Disable(_)));
+		assert!(matches!(
+			kinds[10],
+			StatementKind::SystemTaskEnable { arguments, .. }
+				if arguments.len() == 4 && arguments[2].is_none()
+		));
+		assert!(matches!(
+			kinds[11],
+			StatementKind::TaskEnable { arguments, .. } if arguments.len() == 2
+		));
+		assert!(matches!(kinds[12], StatementKind::ProceduralAssign(_)));
+		assert!(matches!(kinds[13], StatementKind::Deassign(_)));
+		assert!(matches!(kinds[14], StatementKind::Force(_)));
+		assert!(matches!(kinds[15], StatementKind::Release(_)));
+		assert!(matches!(
+			kinds[16],
+			StatementKind::Block(Block { kind: BlockKind::Parallel, .. })
+		));
+	}
+);
+
+parser_test!(
+	all,
+	event_controls,
+	"module m; always @(posedge clk or negedge rst) q <= d; always @e x = 1; endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let StatementKind::TimingControl { control, .. } = &first_process(&ast).kind else {
+			panic!("Expected a timing control");
+		};
+
+		let TimingControl::Event(EventControl::Expression(events)) = control.inner() else {
+			panic!("Expected an event expression, got {control:#?}");
+		};
+		assert_eq!(events.len(), 2);
+		assert_eq!(
+			events[0].edge,
+			Some(crate::lang::types::EdgeIdentifier::PosEdge)
+		);
+		assert_eq!(
+			events[1].edge,
+			Some(crate::lang::types::EdgeIdentifier::NegEdge)
+		);
+
+		let ItemKind::Always(statement) = &module_items(&ast)[1].kind else {
+			panic!("Expected an always block");
+		};
+		assert!(matches!(
+			&statement.kind,
+			StatementKind::TimingControl { control, .. }
+				if matches!(control.inner(), TimingControl::Event(EventControl::Identifier(_)))
+		));
+	}
+);
+
+parser_test!(
+	all,
+	instantiations,
+	"module m;\n\tfoo #(8, 4) u0 (a, , b), u1 (.x(c), .y());\n\tbar #(.W(2)) u2 [3:0] \
+	 (.x(d));\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let instantiations = module_items(&ast)
+			.iter()
+			.filter_map(|item| match &item.kind {
+				ItemKind::Instantiation(instantiation) => Some(instantiation),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		assert_eq!(instantiations.len(), 2);
+		assert_eq!(instantiations[0].module.inner(), &ident("foo"));
+		assert!(matches!(
+			instantiations[0].parameters.as_ref().map(|params| params.inner()),
+			Some(Connections::Ordered(params)) if params.len() == 2
+		));
+		assert_eq!(instantiations[0].instances.len(), 2);
+		assert!(matches!(
+			&instantiations[0].instances[0].connections,
+			Connections::Ordered(ports) if ports.len() == 3 && ports[1].is_none()
+		));
+		assert!(matches!(
+			&instantiations[0].instances[1].connections,
+			Connections::Named(ports) if ports.len() == 2 && ports[1].value.is_none()
+		));
+		assert!(matches!(
+			instantiations[1].parameters.as_ref().map(|params| params.inner()),
+			Some(Connections::Named(params)) if params.len() == 1
+		));
+		assert!(instantiations[1].instances[0].range.is_some());
+	}
+);
+
+parser_test!(
+	all,
+	udp_instance_delay,
+	"module m; udp #5 (e, f); endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let ItemKind::Instantiation(instantiation) = &module_items(&ast)[0].kind else {
+			panic!("Expected an instantiation");
+		};
+
+		assert!(matches!(
+			instantiation.parameters.as_ref().map(|params| params.inner()),
+			Some(Connections::Ordered(params)) if params.len() == 1
+		));
+		assert!(instantiation.instances[0].name.is_none());
+	}
+);
+
+parser_test!(
+	all,
+	gate_instantiations,
+	"module m;\n\tand #1 g0 (y, a, b), (z, c, d);\n\tbufif0 (strong0, pull1) b0 [1:0] (o, i, \
+	 e);\n\tpullup (strong1) (w);\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let gates = module_items(&ast)
+			.iter()
+			.map(|item| match &item.kind {
+				ItemKind::Gate(gate) => gate,
+				other => panic!("Expected a gate instantiation, got {other:#?}"),
+			})
+			.collect::<Vec<_>>();
+
+		assert_eq!(gates[0].gate, GateType::And);
+		assert_eq!(gates[0].instances.len(), 2);
+		assert!(gates[0].instances[1].name.is_none());
+		assert_eq!(gates[0].instances[0].terminals.len(), 3);
+
+		assert_eq!(gates[1].gate, GateType::BufIf0);
+		assert!(gates[1].instances[0].range.is_some());
+
+		assert_eq!(gates[2].gate, GateType::Pullup);
+		assert_eq!(
+			gates[2].strength,
+			Some(Strength::High(crate::lang::types::HighStrength::Strong))
+		);
+	}
+);
+
+parser_test!(
+	all,
+	tasks_and_functions,
+	"module m;\n\ttask t;\n\t\tinput [3:0] a;\n\t\toutput b;\n\t\treg c;\n\t\tbegin b = a[0]; \
+	 end\n\tendtask\n\tfunction [7:0] f;\n\t\tinput x;\n\t\tf = x;\n\tendfunction\n\tfunction \
+	 integer g;\n\t\tinput y;\n\t\tg = y;\n\tendfunction\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let items = module_items(&ast);
+
+		let ItemKind::Task(task) = &items[0].kind else {
+			panic!("Expected a task, got {:#?}", items[0]);
+		};
+		assert!(!task.automatic);
+		assert!(task.ports.is_none());
+		assert_eq!(task.items.len(), 3);
+		assert_eq!(task.body.len(), 1);
+
+		let ItemKind::Function(function) = &items[1].kind else {
+			panic!("Expected a function, got {:#?}", items[1]);
+		};
+		assert_eq!(function.return_type.packed().len(), 1);
+		assert_eq!(function.items.len(), 1);
+
+		let ItemKind::Function(function) = &items[2].kind else {
+			panic!("Expected a function, got {:#?}", items[2]);
+		};
+		assert!(matches!(
+			function.return_type,
+			DataType::IntegerAtom { kind: IntegerAtomType::Integer, .. }
+		));
+	}
+);
+
+parser_test!(
+	all,
+	specify_block,
+	"module m;\n\tspecify\n\t\tspecparam tRise = 1:2:3, PATHPULSE$a$y = (1, 2);\n\t\t(a => y) = \
+	 1;\n\t\t(a, b *> y, z) = (1, 2);\n\t\tif (en) (a +=> y) = tRise;\n\t\tifnone (b -*> y) = \
+	 2;\n\t\t(posedge clk => (q +: d)) = (1, 2);\n\t\t$setup(d, posedge clk, \
+	 1);\n\t\t$width(negedge clk &&& en, 5, , n);\n\tendspecify\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let ItemKind::Specify(items) = &module_items(&ast)[0].kind else {
+			panic!("Expected a specify block");
+		};
+		assert_eq!(items.len(), 8);
+
+		let SpecifyItem::Specparam(specparam) = items[0].inner() else {
+			panic!("Expected a specparam, got {:#?}", items[0]);
+		};
+		assert!(matches!(
+			specparam.assignments[0].value,
+			SpecparamValue::Value(_)
+		));
+		assert!(matches!(
+			specparam.assignments[1].value,
+			SpecparamValue::PulseControl { error: Some(_), .. }
+		));
+
+		let paths = items[1..6]
+			.iter()
+			.map(|item| match item.inner() {
+				SpecifyItem::Path(path) => path,
+				other => panic!("Expected a path, got {other:#?}"),
+			})
+			.collect::<Vec<_>>();
+
+		assert_eq!(paths[0].connection, PathConnection::Parallel);
+		assert_eq!(paths[0].polarity, None);
+		assert_eq!(paths[1].connection, PathConnection::Full);
+		assert_eq!(paths[1].inputs.len(), 2);
+		assert_eq!(paths[1].delays.len(), 2);
+		assert!(matches!(paths[2].condition, Some(PathCondition::If(_))));
+		assert_eq!(paths[2].polarity, Some(Polarity::Positive));
+		assert_eq!(paths[3].condition, Some(PathCondition::IfNone));
+		assert_eq!(paths[3].polarity, Some(Polarity::Negative));
+		assert_eq!(paths[3].connection, PathConnection::Full);
+		assert!(paths[4].edge.is_some());
+		assert!(matches!(
+			paths[4].data_source,
+			Some((Some(Polarity::Positive), _))
+		));
+
+		let SpecifyItem::TimingCheck(check) = items[7].inner() else {
+			panic!("Expected a timing check, got {:#?}", items[7]);
+		};
+		assert_eq!(check.arguments.len(), 4);
+		assert!(check.arguments[2].is_none());
+		assert!(matches!(
+			&check.arguments[0],
+			Some(event) if event.condition.is_some() && event.edge.is_some()
+		));
+	}
+);
+
+parser_test!(
+	all,
+	udp_combinational,
+	"primitive mux(y, s, a, b);\n\toutput y;\n\tinput s, a, b;\n\ttable\n\t\t0 0 ? : 0;\n\t\t0 1 \
+	 ? : 1;\n\t\t1 ? 0 : 0;\n\t\t1 ? 1 : 1;\n\t\tx 00 : 0;\n\tendtable\nendprimitive",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let Description::Primitive(udp) = ast.descriptions()[0].inner() else {
+			panic!("Expected a UDP");
+		};
+
+		assert_eq!(udp.ports.len(), 4);
+		assert_eq!(udp.declarations.len(), 2);
+		assert!(!udp.is_sequential());
+		assert_eq!(udp.table.len(), 5);
+		assert_eq!(
+			udp.table[0]
+				.inputs
+				.iter()
+				.map(|symbol| *symbol.inner())
+				.collect::<Vec<_>>(),
+			vec![UdpSymbol::Level(b'0'), UdpSymbol::Level(b'0'), UdpSymbol::Level(b'?')]
+		);
+		assert_eq!(*udp.table[0].output.inner(), UdpSymbol::Level(b'0'));
+		assert_eq!(udp.table[4].inputs.len(), 3);
+	}
+);
+
+parser_test!(
+	all,
+	udp_sequential,
+	"primitive dff(q, clk, d);\n\toutput q;\n\treg q;\n\tinput clk, d;\n\tinitial q = \
+	 1'b0;\n\ttable\n\t\t(01) 0 : ? : 0;\n\t\t(01) 1 : ? : 1;\n\t\t(1?) ? : ? : -;\n\t\tn ? : ? : \
+	 -;\n\t\t? * : ? : -;\n\tendtable\nendprimitive",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let Description::Primitive(udp) = ast.descriptions()[0].inner() else {
+			panic!("Expected a UDP");
+		};
+
+		assert!(udp.is_sequential());
+		assert!(udp.initial.is_some());
+		assert_eq!(udp.declarations.len(), 3);
+
+		let first = &udp.table[0].inputs[0];
+		assert_eq!(*first.inner(), UdpSymbol::Transition(b'0', b'1'));
+		assert_eq!(first.span().end() - first.span().begin(), 4);
+
+		assert_eq!(*udp.table[2].output.inner(), UdpSymbol::NoChange);
+		assert_eq!(*udp.table[3].inputs[0].inner(), UdpSymbol::Edge(b'n'));
+		assert_eq!(*udp.table[4].inputs[1].inner(), UdpSymbol::Edge(b'*'));
+	}
+);
+
+parser_test!(
+	all,
+	builtin_directives_skipped,
+	"`timescale 1ns / 1ps\n`define FOO \\\n\t1\nmodule m; endmodule",
+	|parsed| {
+		assert_eq!(expect_ast(parsed).modules().count(), 1);
+	}
+);
+
+parser_test!(
+	all,
+	preprocessed_conditionals,
+	"`define WIDTH 8\n`ifdef FAST\nmodule fast; endmodule\n`else\nmodule slow;\n\twire \
+	 [`WIDTH-1:0] a;\n\tinitial $display(a);\nendmodule\n`endif\n",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let names = ast
+			.modules()
+			.map(|module| module.name.inner().clone())
+			.collect::<Vec<_>>();
+		assert_eq!(names, [ident("slow")]);
+		assert!(ast.diagnostics().is_empty());
+	}
+);
+
+parser_test!(
+	all,
+	preprocessor_errors,
+	"module m;\n\twire a = `NOPE;\nendmodule\n`endif\n",
+	|parsed| {
+		let messages = parsed
+			.diagnostics()
+			.iter()
+			.map(|diagnostic| {
+				(
+					diagnostic.message().to_string(),
+					diagnostic.span().map(|span| span.get_range::<u32>()),
+				)
+			})
+			.collect::<Vec<_>>();
+
+		assert!(messages.contains(&("Undefined macro `NOPE`".to_string(), Some(20..25))));
+		assert!(messages.contains(&(
+			"`` `endif `` without a matching `` `ifdef ``".to_string(),
+			Some(37..43)
+		)));
+	}
+);
+
+parser_test!(all, missing_endmodule, "module m; wire a;", |parsed| {
+	assert!(expect_error(parsed).starts_with("Expected `endmodule`, found end of file"));
+});
+
+parser_test!(
+	all,
+	unexpected_token,
+	"module m; wire ; endmodule",
+	|parsed| {
+		assert!(expect_error(parsed).starts_with("Expected an identifier, found `;`"));
+	}
+);
+
+parser_test!(verilog_all, unexpected_description, "wire a;", |parsed| {
+	assert!(
+		expect_error(parsed)
+			.starts_with("Expected a module, primitive, or config declaration, found `wire`")
+	);
+});
+
+/// Get the syntax errors of a parse, along with the range of source they point at
+fn syntax_errors(ast: &Ast) -> Vec<(String, std::ops::Range<u32>)> {
+	ast.diagnostics()
+		.iter()
+		.filter(|diagnostic| is_syntax_error(diagnostic))
+		.map(|diagnostic| {
+			let span = diagnostic
+				.span()
+				.unwrap_or_else(|| panic!("Syntax error without a span"));
+			(diagnostic.message().to_string(), span.get_range())
+		})
+		.collect()
+}
+
+parser_test!(
+	all,
+	recover_item,
+	"module m; wire ; wire b; endmodule",
+	|parsed| {
+		let errors = syntax_errors(&parsed);
+		assert_eq!(errors.len(), 1);
+		assert!(errors[0].0.starts_with("Expected an identifier, found `;`"));
+		assert_eq!(errors[0].1, 15..16);
+
+		let items = module_items(&parsed);
+		assert_eq!(items.len(), 2);
+		assert_eq!(items[0].kind, ItemKind::Error);
+		assert!(matches!(items[1].kind, ItemKind::Net(_)));
+	}
+);
+
+parser_test!(
+	all,
+	recover_multiple_items,
+	"module m; wire ; reg ; wire c; endmodule",
+	|parsed| {
+		assert_eq!(syntax_errors(&parsed).len(), 2);
+
+		let kinds = module_items(&parsed)
+			.iter()
+			.map(|item| &item.kind)
+			.collect::<Vec<_>>();
+		assert!(matches!(
+			kinds[..],
+			[ItemKind::Error, ItemKind::Error, ItemKind::Net(_)]
+		));
+	}
+);
+
+parser_test!(
+	all,
+	recover_statement,
+	"module m; initial begin a = ; b = 1; end wire c; endmodule",
+	|parsed| {
+		assert_eq!(syntax_errors(&parsed).len(), 1);
+
+		let StatementKind::Block(block) = &first_process(&parsed).kind else {
+			panic!("Expected a block");
+		};
+		assert_eq!(block.statements.len(), 2);
+		assert_eq!(block.statements[0].kind, StatementKind::Error);
+		assert!(matches!(
+			block.statements[1].kind,
+			StatementKind::Blocking(_)
+		));
+
+		assert_eq!(module_items(&parsed).len(), 2);
+	}
+);
+
+parser_test!(
+	all,
+	recover_missing_end,
+	"module m; initial begin a = 1; endmodule",
+	|parsed| {
+		let errors = syntax_errors(&parsed);
+		assert_eq!(errors.len(), 1);
+		assert!(errors[0].0.starts_with("Expected `end`, found `endmodule`"));
+
+		let StatementKind::Block(block) = &first_process(&parsed).kind else {
+			panic!("Expected a block");
+		};
+		assert_eq!(block.statements.len(), 1);
+	}
+);
+
+parser_test!(
+	all,
+	recover_missing_endmodule,
+	"module a; wire x;\nmodule b; endmodule",
+	|parsed| {
+		let errors = syntax_errors(&parsed);
+		assert_eq!(errors.len(), 1);
+		assert!(
+			errors[0]
+				.0
+				.starts_with("Expected `endmodule`, found `module`")
+		);
+
+		let names = parsed
+			.modules()
+			.map(|module| module.name.inner().clone())
+			.collect::<Vec<_>>();
+		assert_eq!(names, [ident("a"), ident("b")]);
+	}
+);
+
+parser_test!(
+	verilog_all,
+	recover_description,
+	"wire a; module m; endmodule",
+	|parsed| {
+		assert_eq!(syntax_errors(&parsed).len(), 1);
+		assert_eq!(*parsed.descriptions()[0].inner(), Description::Error);
+		assert_eq!(parsed.modules().count(), 1);
+	}
+);
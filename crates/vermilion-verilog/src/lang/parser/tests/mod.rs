@@ -1,163 +1,208 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
-// TODO(aki): Write tests
-#[allow(unused, reason = "Currently unused")]
 use paste::paste;
+use vermilion_diagnostics::{Diagnostic, StringDiagnostic};
 
-// TODO(aki): Write tests
-#[allow(unused, reason = "Currently unused")]
-use crate::{LanguageStd, lang::parser::VerilogParser};
+use crate::{
+	LanguageStd,
+	diagnostics::{system_verilog, verilog, verilog_ams},
+	lang::{ast::*, parser::VerilogParser},
+};
+
+/// Returns true if `diagnostic` is a syntax error rather than a warning about the standard
+fn is_syntax_error(diagnostic: &StringDiagnostic) -> bool {
+	[system_verilog::E0003, verilog::E0002, verilog_ams::E0002].contains(&diagnostic.code())
+}
+
+/// Check that a parse had no syntax errors, failing the test with the first one if there was one
+fn expect_ast(parsed: Ast) -> Ast {
+	if let Some(err) = parsed
+		.diagnostics()
+		.iter()
+		.find(|diagnostic| is_syntax_error(diagnostic))
+	{
+		panic!("Failed to parse: {}", err.message());
+	}
+
+	parsed
+}
+
+/// Check that a parse had no syntax errors, returning the messages of the diagnostics it produced
+fn expect_diagnostics(parsed: Ast) -> Vec<String> {
+	expect_ast(parsed)
+		.diagnostics()
+		.iter()
+		.map(|diagnostic| diagnostic.message().to_string())
+		.collect()
+}
+
+/// Returns true if any of the diagnostics produced by a successful parse start with `message`
+fn has_diagnostic(parsed: Ast, message: &str) -> bool {
+	expect_diagnostics(parsed)
+		.iter()
+		.any(|diagnostic| diagnostic.starts_with(message))
+}
+
+/// Return the message of the first syntax error of a parse, failing the test if there were none
+fn expect_error(parsed: Ast) -> String {
+	match parsed
+		.diagnostics()
+		.iter()
+		.find(|diagnostic| is_syntax_error(diagnostic))
+	{
+		Some(err) => err.message().to_string(),
+		None => panic!("Expected a parse error, got: {parsed:#?}"),
+	}
+}
 
-// TODO(aki): Write tests
-#[allow(unused, reason = "Currently unused")]
 macro_rules! parser_test {
-	(verilog95, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(verilog95, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<verilog_95_ $test_name>],
 			$input,
 			LanguageStd::Vl95,
-			$($tokens),+
+			$check
 		); }
 	};
-	(verilog01, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(verilog01, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<verilog_01_ $test_name>],
 			$input,
 			LanguageStd::Vl01,
-			$($tokens),+
+			$check
 		); }
 	};
-	(verilog01+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(verilog01, $test_name, $input, $($tokens),+);
-		parser_test!(verilog05, $test_name, $input, $($tokens),+);
-		parser_test!(system_verilog_all, $test_name, $input, $($tokens),+);
+	(verilog01+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(verilog01, $test_name, $input, $check);
+		parser_test!(verilog05, $test_name, $input, $check);
+		parser_test!(system_verilog_all, $test_name, $input, $check);
 	};
-	(verilog05, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(verilog05, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<verilog_05_ $test_name>],
 			$input,
 			LanguageStd::Vl05,
-			$($tokens),+
+			$check
 		); }
 	};
-	(verilog05+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(verilog05, $test_name, $input, $($tokens),+);
-		parser_test!(system_verilog_all, $test_name, $input, $($tokens),+);
+	(verilog05+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(verilog05, $test_name, $input, $check);
+		parser_test!(system_verilog_all, $test_name, $input, $check);
 	};
-	(verilog_all, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(verilog95, $test_name, $input, $($tokens),+);
-		parser_test!(verilog01, $test_name, $input, $($tokens),+);
-		parser_test!(verilog05, $test_name, $input, $($tokens),+);
+	(verilog_all, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(verilog95, $test_name, $input, $check);
+		parser_test!(verilog01, $test_name, $input, $check);
+		parser_test!(verilog05, $test_name, $input, $check);
 	};
-	(system_verilog05, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(system_verilog05, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<system_verilog_05_ $test_name>],
 			$input,
 			LanguageStd::Sv05,
-			$($tokens),+
+			$check
 		); }
 	};
-	(system_verilog09, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(system_verilog09, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<system_verilog_09_ $test_name>],
 			$input,
 			LanguageStd::Sv09,
-			$($tokens),+
+			$check
 		); }
 	};
-	(system_verilog09+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(system_verilog09, $test_name, $input, $($tokens),+);
-		parser_test!(system_verilog12, $test_name, $input, $($tokens),+);
-		parser_test!(system_verilog17, $test_name, $input, $($tokens),+);
-		parser_test!(system_verilog23, $test_name, $input, $($tokens),+);
+	(system_verilog09+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(system_verilog09, $test_name, $input, $check);
+		parser_test!(system_verilog12, $test_name, $input, $check);
+		parser_test!(system_verilog17, $test_name, $input, $check);
+		parser_test!(system_verilog23, $test_name, $input, $check);
 	};
-	(system_verilog12, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(system_verilog12, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<system_verilog_12_ $test_name>],
 			$input,
 			LanguageStd::Sv12,
-			$($tokens),+
+			$check
 		); }
 	};
-	(system_verilog12+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(system_verilog12, $test_name, $input, $($tokens),+);
-		parser_test!(system_verilog17, $test_name, $input, $($tokens),+);
-		parser_test!(system_verilog23, $test_name, $input, $($tokens),+);
+	(system_verilog12+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(system_verilog12, $test_name, $input, $check);
+		parser_test!(system_verilog17, $test_name, $input, $check);
+		parser_test!(system_verilog23, $test_name, $input, $check);
 	};
-	(system_verilog17, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(system_verilog17, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<system_verilog_17_ $test_name>],
 			$input,
 			LanguageStd::Sv17,
-			$($tokens),+
+			$check
 		); }
 	};
-	(system_verilog17+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(system_verilog17, $test_name, $input, $($tokens),+);
-		parser_test!(system_verilog23, $test_name, $input, $($tokens),+);
+	(system_verilog17+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(system_verilog17, $test_name, $input, $check);
+		parser_test!(system_verilog23, $test_name, $input, $check);
 	};
-	(system_verilog23, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(system_verilog23, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<system_verilog_23_ $test_name>],
 			$input,
 			LanguageStd::Sv23,
-			$($tokens),+
+			$check
 		); }
 	};
-	(system_verilog23+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(system_verilog23, $test_name, $input, $($tokens),+);
+	(system_verilog23+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(system_verilog23, $test_name, $input, $check);
 	};
-	(system_verilog_all, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(system_verilog05, $test_name, $input, $($tokens),+);
-		parser_test!(system_verilog09, $test_name, $input, $($tokens),+);
-		parser_test!(system_verilog12, $test_name, $input, $($tokens),+);
-		parser_test!(system_verilog17, $test_name, $input, $($tokens),+);
-		parser_test!(system_verilog23, $test_name, $input, $($tokens),+);
+	(system_verilog_all, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(system_verilog05, $test_name, $input, $check);
+		parser_test!(system_verilog09, $test_name, $input, $check);
+		parser_test!(system_verilog12, $test_name, $input, $check);
+		parser_test!(system_verilog17, $test_name, $input, $check);
+		parser_test!(system_verilog23, $test_name, $input, $check);
 	};
-	(verilog_ams09, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(verilog_ams09, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<verilog_ams_09_ $test_name>],
 			$input,
 			LanguageStd::Vams09,
-			$($tokens),+
+			$check
 		); }
 	};
-	(verilog_ams14, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(verilog_ams14, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<verilog_ams_14_ $test_name>],
 			$input,
 			LanguageStd::Vams14,
-			$($tokens),+
+			$check
 		); }
 	};
-	(verilog_ams14+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(verilog_ams14, $test_name, $input, $($tokens),+);
-		parser_test!(verilog_ams23, $test_name, $input, $($tokens),+);
+	(verilog_ams14+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(verilog_ams14, $test_name, $input, $check);
+		parser_test!(verilog_ams23, $test_name, $input, $check);
 	};
-	(verilog_ams23, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(verilog_ams23, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<verilog_ams_23_ $test_name>],
 			$input,
 			LanguageStd::Vams23,
-			$($tokens),+
+			$check
 		); }
 	};
-	(verilog_ams23+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(verilog_ams23, $test_name, $input, $($tokens),+);
+	(verilog_ams23+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(verilog_ams23, $test_name, $input, $check);
 	};
-	(verilog_ams_all, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(verilog_ams09, $test_name, $input, $($tokens),+);
-		parser_test!(verilog_ams14, $test_name, $input, $($tokens),+);
-		parser_test!(verilog_ams23, $test_name, $input, $($tokens),+);
+	(verilog_ams_all, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(verilog_ams09, $test_name, $input, $check);
+		parser_test!(verilog_ams14, $test_name, $input, $check);
+		parser_test!(verilog_ams23, $test_name, $input, $check);
 	};
-	(all, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(all, $test_name:ident, $input:literal, $check:expr) => {
 		paste! {
-			parser_test!(verilog_all, [<$test_name _all>], $input, $($tokens),+);
-			parser_test!(system_verilog_all, [<$test_name _all>], $input, $($tokens),+);
-			parser_test!(verilog_ams_all, [<$test_name _all>], $input, $($tokens),+);
+			parser_test!(verilog_all, [<$test_name _all>], $input, $check);
+			parser_test!(system_verilog_all, [<$test_name _all>], $input, $check);
+			parser_test!(verilog_ams_all, [<$test_name _all>], $input, $check);
 		}
 	};
-	($test_name:ident, $input:literal, $std:expr, $($tokens:expr),+) => {
+	($test_name:ident, $input:literal, $std:expr, $check:expr) => {
 		paste! {
 			#[test]
 			fn [<test_parse_ $test_name>] () {
@@ -167,13 +212,8 @@ macro_rules! parser_test {
 				)]
 				let parser = VerilogParser::new($std, $input.as_bytes().into()).unwrap();
 
-				let parsed = parser.collect::<Vec<_>>();
-				let expected = vec![$($tokens),+];
-
-				assert_eq!(
-					parsed, expected,
-					"Parsed: {parsed:#?}\nExpected: {expected:#?}"
-				);
+				let check: fn(Ast) = $check;
+				check(parser.parse());
 			}
 		}
 	};
@@ -1 +1,40 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+parser_test!(
+	system_verilog05,
+	header_import_unsupported,
+	"module m import pkg::*; (input a); endmodule",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"Package imports in module headers are not supported in System Verilog 2005 (IEEE \
+			 1800-2005), requires System Verilog 2009 (IEEE 1800-2009)"
+		));
+	}
+);
+
+parser_test!(
+	system_verilog05,
+	deferred_assertion_unsupported,
+	"module m; always_comb assert #0 (a); endmodule",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"Deferred assertions are not supported"
+		));
+	}
+);
+
+parser_test!(
+	system_verilog05,
+	temporal_operator_unsupported,
+	"module m; assert property (a #-# b); endmodule",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"Temporal property operators are not supported"
+		));
+	}
+);
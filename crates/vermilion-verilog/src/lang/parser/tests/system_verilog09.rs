@@ -1 +1,97 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+parser_test!(
+	system_verilog09+,
+	header_import,
+	"module m import pkg::*; (input a); endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		assert_eq!(module.imports.len(), 1);
+	}
+);
+
+parser_test!(
+	system_verilog09+,
+	deferred_assertion,
+	"module m; always_comb assert #0 (a) else $error(\"a\"); endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::AlwaysComb(statement) = &module.items[0].kind else {
+			panic!("Expected an always_comb");
+		};
+		assert!(matches!(
+			&statement.kind,
+			StatementKind::Assertion(assertion) if assertion.timing == AssertionTiming::Deferred
+		));
+	}
+);
+
+parser_test!(
+	system_verilog09+,
+	temporal_operators,
+	"module m; assert property (s_eventually [1:2] a until_with b); endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::Assertion(assertion) = &module.items[0].kind else {
+			panic!("Expected an assertion");
+		};
+		let PropertyExpression::Unary { operator, range, operand } =
+			assertion.property.property.inner()
+		else {
+			panic!("Expected a unary property");
+		};
+		assert_eq!(*operator.inner(), PropertyUnaryOperator::SEventually);
+		assert!(range.is_some());
+		assert!(matches!(
+			operand.inner(),
+			PropertyExpression::Binary { operator, .. }
+				if *operator.inner() == PropertyOperator::UntilWith
+		));
+	}
+);
+
+parser_test!(
+	system_verilog09+,
+	let_declaration,
+	"module m; let max(a, b) = a > b ? a : b; endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		assert!(matches!(
+			&module.items[0].kind,
+			ItemKind::Let(declaration) if declaration.ports.len() == 2
+		));
+	}
+);
+
+parser_test!(
+	system_verilog09+,
+	covergroup_sample_function,
+	"module m;\n\tcovergroup cg with function sample(int v);\n\t\tcoverpoint \
+	 v;\n\tendgroup\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::CoverGroup(covergroup) = &module.items[0].kind else {
+			panic!("Expected a covergroup");
+		};
+		assert!(matches!(
+			covergroup.event.as_ref().map(|event| event.inner()),
+			Some(CoverageEvent::Sample(ports)) if ports.len() == 1
+		));
+	}
+);
@@ -1 +1,74 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+parser_test!(
+	system_verilog12+,
+	interface_class,
+	"interface class shape extends base, other;\n\tpure virtual function int \
+	 area();\nendclass\nclass square implements shape; endclass",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+
+		let Description::Class(shape) = ast.descriptions()[0].inner() else {
+			panic!("Expected a class");
+		};
+		assert!(shape.interface);
+		assert_eq!(shape.extends.len(), 2);
+		assert_eq!(shape.items[0].qualifiers, vec![ClassQualifier::PureVirtual]);
+		assert!(matches!(shape.items[0].item.kind, ItemKind::Prototype(_)));
+
+		let Description::Class(square) = ast.descriptions()[1].inner() else {
+			panic!("Expected a class");
+		};
+		assert_eq!(square.implements.len(), 1);
+	}
+);
+
+parser_test!(
+	system_verilog12+,
+	soft_constraints,
+	"class c;\n\tconstraint k { soft a < 4; disable soft b; unique { a, b }; }\nendclass",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+
+		let Description::Class(class) = ast.descriptions()[0].inner() else {
+			panic!("Expected a class");
+		};
+		let ItemKind::Constraint(constraint) = &class.items[0].item.kind else {
+			panic!("Expected a constraint");
+		};
+		let items = constraint
+			.items
+			.as_ref()
+			.unwrap_or_else(|| panic!("Expected items"));
+
+		assert!(matches!(
+			items[0].inner(),
+			ConstraintItem::Expression { soft: true, .. }
+		));
+		assert!(matches!(items[1].inner(), ConstraintItem::DisableSoft(_)));
+		assert!(matches!(items[2].inner(), ConstraintItem::Unique(values) if values.len() == 2));
+	}
+);
+
+parser_test!(
+	system_verilog12+,
+	final_assertion,
+	"module m; always_comb assert final (a); endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::AlwaysComb(statement) = &module.items[0].kind else {
+			panic!("Expected an always_comb");
+		};
+		assert!(matches!(
+			&statement.kind,
+			StatementKind::Assertion(assertion) if assertion.timing == AssertionTiming::Final
+		));
+	}
+);
@@ -1 +1,469 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+parser_test!(
+	system_verilog_all,
+	package,
+	"package pkg;\n\ttypedef logic [7:0] byte_t;\n\tparameter int W = 8;\n\tfunction automatic \
+	 int inc(int a); return a + 1; endfunction\nendpackage : pkg",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+
+		let Description::Package(package) = ast.descriptions()[0].inner() else {
+			panic!("Expected a package");
+		};
+		assert_eq!(package.items.len(), 3);
+		assert!(matches!(package.items[0].kind, ItemKind::Typedef(_)));
+		assert!(matches!(package.items[1].kind, ItemKind::Parameter(_)));
+		assert!(matches!(package.items[2].kind, ItemKind::Function(_)));
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	mismatched_end_label,
+	"package pkg; endpackage : other",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"End label `other` does not match `pkg`"
+		));
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	compilation_unit_item,
+	"wire a;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+
+		assert!(matches!(
+			ast.descriptions()[0].inner(),
+			Description::Item(item) if matches!(item.kind, ItemKind::Net(_))
+		));
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	interface_modport,
+	"interface bus (input logic clk);\n\tlogic [7:0] data;\n\tmodport master (output data, input \
+	 clk), slave (input data, clk);\nendinterface",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast
+			.modules()
+			.next()
+			.unwrap_or_else(|| panic!("No interface"));
+		assert_eq!(module.keyword, ModuleKeyword::Interface);
+
+		let ItemKind::Modport(modports) = &module.items[1].kind else {
+			panic!("Expected a modport declaration");
+		};
+		assert_eq!(modports.len(), 2);
+		assert_eq!(modports[0].ports.len(), 2);
+		assert_eq!(
+			modports[1].ports[1].kind,
+			ModportPortKind::Direction(PortDirection::Input)
+		);
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	interface_port,
+	"module m (bus.master b, input logic clk); endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let Ports::Ansi(ports) = &module.ports else {
+			panic!("Expected ANSI ports, got {:#?}", module.ports);
+		};
+		assert_eq!(ports.len(), 2);
+		assert_eq!(ports[0].direction, None);
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	struct_and_enum_typedefs,
+	"typedef enum logic [1:0] { IDLE, RUN = 2 } state_t;\ntypedef struct packed { logic [3:0] a; \
+	 bit b; } pair_t;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+
+		let Description::Item(item) = ast.descriptions()[0].inner() else {
+			panic!("Expected a compilation unit item");
+		};
+		let ItemKind::Typedef(typedef) = &item.kind else {
+			panic!("Expected a typedef");
+		};
+		let Some(DataType::Enum(enum_type)) = &typedef.data_type else {
+			panic!("Expected an enum type");
+		};
+		assert_eq!(enum_type.members.len(), 2);
+		assert!(enum_type.members[1].value.is_some());
+
+		let Description::Item(item) = ast.descriptions()[1].inner() else {
+			panic!("Expected a compilation unit item");
+		};
+		let ItemKind::Typedef(typedef) = &item.kind else {
+			panic!("Expected a typedef");
+		};
+		let Some(DataType::Struct(struct_type)) = &typedef.data_type else {
+			panic!("Expected a struct type");
+		};
+		assert!(struct_type.packed);
+		assert_eq!(struct_type.members.len(), 2);
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	always_procedures,
+	"module m;\n\talways_ff @(posedge clk) q <= d;\n\talways_comb y = a & b;\n\talways_latch if \
+	 (en) l = d;\n\tfinal $display(\"done\");\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		assert!(matches!(module.items[0].kind, ItemKind::AlwaysFF(_)));
+		assert!(matches!(module.items[1].kind, ItemKind::AlwaysComb(_)));
+		assert!(matches!(module.items[2].kind, ItemKind::AlwaysLatch(_)));
+		assert!(matches!(module.items[3].kind, ItemKind::Final(_)));
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	procedural_statements,
+	"module m;\n\talways_comb begin : blk\n\t\tint i;\n\t\tunique case (s) inside\n\t\t\t[0:3]: y \
+	 = 0;\n\t\t\tdefault: y = 1;\n\t\tendcase\n\t\tfor (int j = 0; j < 4; j++) y += \
+	 j;\n\t\tforeach (a[k]) a[k] = k;\n\tend : blk\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::AlwaysComb(statement) = &module.items[0].kind else {
+			panic!("Expected an always_comb");
+		};
+		let StatementKind::Block(block) = &statement.kind else {
+			panic!("Expected a block");
+		};
+		assert_eq!(block.items.len(), 1);
+		assert_eq!(block.statements.len(), 3);
+
+		let StatementKind::Case(case) = &block.statements[0].kind else {
+			panic!("Expected a case statement");
+		};
+		assert_eq!(case.qualifier, Some(CaseQualifier::Unique));
+		assert!(case.inside);
+
+		assert!(matches!(
+			&block.statements[1].kind,
+			StatementKind::For { init, step, .. } if init.len() == 1 && step.len() == 1
+		));
+		assert!(matches!(
+			&block.statements[2].kind,
+			StatementKind::Foreach { variables, .. } if variables.len() == 1
+		));
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	class_declaration,
+	"class derived #(type T = int) extends base;\n\trand T value;\n\tconstraint c { value < 10; \
+	 }\n\tfunction new(); super.new(); endfunction\n\textern virtual function void \
+	 show();\nendclass : derived",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+
+		let Description::Class(class) = ast.descriptions()[0].inner() else {
+			panic!("Expected a class");
+		};
+		assert!(class.parameters.is_some());
+		assert_eq!(class.extends.len(), 1);
+		assert_eq!(class.items.len(), 4);
+
+		assert_eq!(class.items[0].qualifiers, vec![ClassQualifier::Rand]);
+		assert!(matches!(class.items[1].item.kind, ItemKind::Constraint(_)));
+		assert!(matches!(class.items[2].item.kind, ItemKind::Function(_)));
+		assert!(matches!(class.items[3].item.kind, ItemKind::Prototype(_)));
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	constraint_items,
+	"class c;\n\tconstraint k {\n\t\tif (a) b == 1; else b == 2;\n\t\ta -> { c < 4; }\n\t\td dist \
+	 { 0 := 1, [1:3] :/ 2 };\n\t\tsolve a before b;\n\t}\nendclass",
+	|parsed| {
+		let ast = expect_ast(parsed);
+
+		let Description::Class(class) = ast.descriptions()[0].inner() else {
+			panic!("Expected a class");
+		};
+		let ItemKind::Constraint(constraint) = &class.items[0].item.kind else {
+			panic!("Expected a constraint");
+		};
+		let items = constraint
+			.items
+			.as_ref()
+			.unwrap_or_else(|| panic!("Expected items"));
+
+		assert!(matches!(
+			items[0].inner(),
+			ConstraintItem::If { if_false: Some(_), .. }
+		));
+		assert!(matches!(
+			items[1].inner(),
+			ConstraintItem::Implication { .. }
+		));
+		assert!(matches!(
+			items[2].inner(),
+			ConstraintItem::Dist { items, .. } if items.len() == 2
+		));
+		assert!(matches!(
+			items[3].inner(),
+			ConstraintItem::SolveBefore { .. }
+		));
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	immediate_assertion,
+	"module m; initial check: assert (a == b) else $error(\"mismatch\"); endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::Initial(statement) = &module.items[0].kind else {
+			panic!("Expected an initial block");
+		};
+		assert!(statement.label.is_some());
+
+		let StatementKind::Assertion(assertion) = &statement.kind else {
+			panic!("Expected an assertion");
+		};
+		assert_eq!(assertion.kind, AssertionKind::Assert);
+		assert_eq!(assertion.timing, AssertionTiming::Immediate);
+		assert!(assertion.pass.is_none());
+		assert!(assertion.fail.is_some());
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	concurrent_assertion,
+	"module m;\n\ta_req: assert property (@(posedge clk) disable iff (rst) req |-> ##[1:3] ack \
+	 [*2]);\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::Assertion(assertion) = &module.items[0].kind else {
+			panic!("Expected an assertion");
+		};
+		assert!(assertion.label.is_some());
+		assert_eq!(assertion.timing, AssertionTiming::Concurrent);
+
+		let spec = assertion.property.inner();
+		assert!(spec.clock.is_some());
+		assert!(spec.disable.is_some());
+
+		let PropertyExpression::Binary { operator, rhs, .. } = spec.property.inner() else {
+			panic!("Expected an implication, got {:#?}", spec.property);
+		};
+		assert_eq!(*operator.inner(), PropertyOperator::OverlappingImplication);
+
+		let PropertyExpression::Delay { lhs: None, delay, rhs } = rhs.inner() else {
+			panic!("Expected a cycle delay, got {rhs:#?}");
+		};
+		assert!(matches!(delay.inner(), CycleRange::Range { .. }));
+		assert!(matches!(
+			rhs.inner(),
+			PropertyExpression::Repetition { kind: RepetitionKind::Consecutive, .. }
+		));
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	property_precedence,
+	"module m; assert property (a and b or c |=> not d); endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::Assertion(assertion) = &module.items[0].kind else {
+			panic!("Expected an assertion");
+		};
+		let PropertyExpression::Binary { operator, lhs, rhs } = assertion.property.property.inner()
+		else {
+			panic!("Expected a binary property");
+		};
+		assert_eq!(
+			*operator.inner(),
+			PropertyOperator::NonOverlappingImplication
+		);
+		assert!(matches!(
+			lhs.inner(),
+			PropertyExpression::Binary { operator, .. }
+				if *operator.inner() == PropertyOperator::Or
+		));
+		assert!(matches!(rhs.inner(), PropertyExpression::Unary { .. }));
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	property_and_sequence_declarations,
+	"module m;\n\tsequence s(x, int n = 2); x ##n x; endsequence : s\n\tproperty p; @(posedge \
+	 clk) s(a) |-> b; endproperty\n\tcover property (p);\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::Sequence(sequence) = &module.items[0].kind else {
+			panic!("Expected a sequence declaration");
+		};
+		assert_eq!(sequence.ports.len(), 2);
+		assert_eq!(sequence.ports[0].port_type, AssertionPortType::Untyped);
+		assert!(matches!(
+			sequence.ports[1].port_type,
+			AssertionPortType::Data(_)
+		));
+		assert!(sequence.ports[1].default.is_some());
+
+		assert!(matches!(module.items[1].kind, ItemKind::Property(_)));
+		assert!(matches!(
+			&module.items[2].kind,
+			ItemKind::Assertion(assertion) if assertion.kind == AssertionKind::Cover
+		));
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	clocking_block,
+	"module m;\n\tdefault clocking cb @(posedge clk);\n\t\tdefault input #1step output \
+	 #2;\n\t\tinput a, b;\n\t\toutput negedge c;\n\tendclocking : cb\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::Clocking(clocking) = &module.items[0].kind else {
+			panic!("Expected a clocking block");
+		};
+		assert!(clocking.default);
+		assert_eq!(clocking.items.len(), 3);
+		assert!(matches!(
+			clocking.items[0].inner(),
+			ClockingItem::Default { input: Some(_), output: Some(_) }
+		));
+		assert!(matches!(
+			clocking.items[1].inner(),
+			ClockingItem::Signals { direction: ClockingDirection::Input, signals, .. }
+				if signals.len() == 2
+		));
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	covergroup,
+	"module m;\n\tcovergroup cg @(posedge clk);\n\t\toption.per_instance = 1;\n\t\tcp: coverpoint \
+	 s {\n\t\t\tbins low[] = {[0:3]};\n\t\t\tbins seq = (0 => 1 => 2), (3 => 4 \
+	 [*2]);\n\t\t\tillegal_bins bad = default;\n\t\t}\n\t\tcoverpoint t;\n\t\tcx: cross cp, t \
+	 {\n\t\t\tignore_bins i = binsof(cp) intersect {0} && !binsof(t);\n\t\t}\n\tendgroup : \
+	 cg\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::CoverGroup(covergroup) = &module.items[0].kind else {
+			panic!("Expected a covergroup");
+		};
+		assert!(covergroup.event.is_some());
+		assert_eq!(covergroup.items.len(), 4);
+		assert!(matches!(
+			covergroup.items[0].inner(),
+			CoverageItem::Option(_)
+		));
+
+		let CoverageItem::Coverpoint(coverpoint) = covergroup.items[1].inner() else {
+			panic!("Expected a coverpoint");
+		};
+		assert!(coverpoint.label.is_some());
+		let bins = coverpoint
+			.bins
+			.as_ref()
+			.unwrap_or_else(|| panic!("Expected bins"));
+		assert_eq!(bins.len(), 3);
+
+		let BinsItem::Bins(low) = bins[0].inner() else {
+			panic!("Expected bins");
+		};
+		assert!(low.array);
+		assert!(matches!(low.values, BinsValues::Set(_)));
+
+		let BinsItem::Bins(sequence) = bins[1].inner() else {
+			panic!("Expected bins");
+		};
+		let BinsValues::Transitions(transitions) = &sequence.values else {
+			panic!("Expected transitions");
+		};
+		assert_eq!(transitions.len(), 2);
+		assert_eq!(transitions[0].len(), 3);
+		assert!(transitions[1][1].repetition.is_some());
+
+		assert!(matches!(
+			covergroup.items[2].inner(),
+			CoverageItem::Coverpoint(Coverpoint { bins: None, .. })
+		));
+
+		let CoverageItem::Cross(cross) = covergroup.items[3].inner() else {
+			panic!("Expected a cross");
+		};
+		assert_eq!(cross.items.len(), 2);
+		let bins = cross
+			.bins
+			.as_ref()
+			.unwrap_or_else(|| panic!("Expected bins"));
+		let BinsItem::Bins(ignore) = bins[0].inner() else {
+			panic!("Expected bins");
+		};
+		assert_eq!(ignore.kind, BinsKind::IgnoreBins);
+		assert!(matches!(
+			&ignore.values,
+			BinsValues::Select(select) if matches!(select.inner(), BinsSelect::And(..))
+		));
+	}
+);
+
+parser_test!(
+	system_verilog_all,
+	package_import,
+	"module m;\n\timport pkg::*, other::item;\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::Import(imports) = &module.items[0].kind else {
+			panic!("Expected a package import");
+		};
+		assert_eq!(imports.len(), 2);
+		assert!(imports[0].item.is_none());
+		assert!(imports[1].item.is_some());
+	}
+);
@@ -1 +1,254 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+parser_test!(
+	verilog01+,
+	ansi_ports,
+	"module m #(parameter W = 8, D = 2, parameter integer N = 1) (\n\t(* keep *) input wire \
+	 [W-1:0] a, b,\n\toutput reg signed [W-1:0] y = 0,\n\tinout c\n);\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let parameters = module
+			.parameters
+			.as_ref()
+			.unwrap_or_else(|| panic!("Expected a parameter port list"));
+		assert_eq!(parameters.len(), 2);
+		assert_eq!(parameters[0].declarators.len(), 2);
+
+		let Ports::Ansi(ports) = &module.ports else {
+			panic!("Expected ANSI ports, got {:#?}", module.ports);
+		};
+		assert_eq!(ports.len(), 3);
+
+		assert_eq!(ports[0].attributes.len(), 1);
+		assert_eq!(ports[0].direction, Some(PortDirection::Input));
+		assert_eq!(ports[0].net_type, Some(crate::lang::types::NetType::Wire));
+		assert_eq!(ports[0].declarators.len(), 2);
+
+		assert_eq!(ports[1].direction, Some(PortDirection::Output));
+		assert_eq!(ports[1].data_type.signing(), Some(Signing::Signed));
+		assert!(ports[1].declarators[0].init.is_some());
+
+		assert_eq!(ports[2].direction, Some(PortDirection::InOut));
+	}
+);
+
+parser_test!(
+	verilog01+,
+	attributes,
+	"(* top, mode = \"fast\" *)\nmodule m;\n\t(* full_case *) always @* case (a) default: b = 0; \
+	 endcase\n\tassign c = d + (* carry *) e;\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		assert_eq!(module.attributes.len(), 2);
+		assert!(module.attributes[0].value.is_none());
+		assert!(module.attributes[1].value.is_some());
+		assert_eq!(module.items[0].attributes.len(), 1);
+
+		let ItemKind::ContinuousAssign(assign) = &module.items[1].kind else {
+			panic!("Expected a continuous assignment");
+		};
+		assert!(matches!(
+			assign.assignments[0].value.inner(),
+			Expression::Binary { attributes, .. } if attributes.len() == 1
+		));
+	}
+);
+
+parser_test!(
+	verilog01+,
+	implicit_event_lists,
+	"module m;\n\talways @* a = b;\n\talways @(*) a = b;\n\talways @( * ) a = b;\n\talways @(c, \
+	 posedge d) a = b;\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let controls = module
+			.items
+			.iter()
+			.map(|item| match &item.kind {
+				ItemKind::Always(statement) => match &statement.kind {
+					StatementKind::TimingControl { control, .. } => control.inner().clone(),
+					other => panic!("Expected a timing control, got {other:#?}"),
+				},
+				other => panic!("Expected an always block, got {other:#?}"),
+			})
+			.collect::<Vec<_>>();
+
+		assert_eq!(controls[0], TimingControl::Event(EventControl::Wildcard));
+		assert_eq!(controls[1], TimingControl::Event(EventControl::Wildcard));
+		assert_eq!(controls[2], TimingControl::Event(EventControl::Wildcard));
+		assert!(matches!(
+			&controls[3],
+			TimingControl::Event(EventControl::Expression(events)) if events.len() == 2
+		));
+	}
+);
+
+parser_test!(
+	verilog01+,
+	generate_region,
+	"module m;\n\tgenvar i;\n\tlocalparam N = 4;\n\tgenerate\n\t\tfor (i = 0; i < N; i = i + 1) \
+	 begin : g\n\t\t\twire w;\n\t\t\tassign w = i;\n\t\tend\n\t\tif (N > 2) begin : \
+	 big\n\t\t\tassign a = 1;\n\t\tend else ;\n\t\tcase (N)\n\t\t\t1, 2: assign b = \
+	 0;\n\t\t\tdefault: ;\n\t\tendcase\n\tendgenerate\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		assert!(matches!(&module.items[0].kind, ItemKind::Genvar(names) if names.len() == 1));
+		assert!(matches!(
+			&module.items[1].kind,
+			ItemKind::Parameter(ParameterDeclaration { local: true, .. })
+		));
+
+		let ItemKind::GenerateRegion(items) = &module.items[2].kind else {
+			panic!("Expected a generate region, got {:#?}", module.items[2]);
+		};
+
+		let ItemKind::GenerateFor(generate_for) = &items[0].kind else {
+			panic!("Expected a generate for, got {:#?}", items[0]);
+		};
+		assert!(generate_for.block.name.is_some());
+		assert_eq!(generate_for.block.items.len(), 2);
+
+		let ItemKind::GenerateIf(generate_if) = &items[1].kind else {
+			panic!("Expected a generate if, got {:#?}", items[1]);
+		};
+		assert!(generate_if.if_true.is_some());
+		assert!(generate_if.if_false.is_none());
+
+		let ItemKind::GenerateCase(generate_case) = &items[2].kind else {
+			panic!("Expected a generate case, got {:#?}", items[2]);
+		};
+		assert_eq!(generate_case.items[0].labels.len(), 2);
+		assert!(generate_case.items[1].block.is_none());
+	}
+);
+
+parser_test!(
+	verilog01+,
+	automatic_ansi_subroutines,
+	"module m;\n\ttask automatic t(input [3:0] a, output b);\n\t\tb = \
+	 a[0];\n\tendtask\n\tfunction automatic signed [7:0] f(input x, y);\n\t\tf = x + \
+	 y;\n\tendfunction\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::Task(task) = &module.items[0].kind else {
+			panic!("Expected a task, got {:#?}", module.items[0]);
+		};
+		assert!(task.automatic);
+		assert_eq!(task.ports.as_ref().map(Vec::len), Some(2));
+
+		let ItemKind::Function(function) = &module.items[1].kind else {
+			panic!("Expected a function, got {:#?}", module.items[1]);
+		};
+		assert!(function.automatic);
+		assert_eq!(function.return_type.signing(), Some(Signing::Signed));
+		assert_eq!(
+			function
+				.ports
+				.as_ref()
+				.map(|ports| ports[0].declarators.len()),
+			Some(2)
+		);
+	}
+);
+
+parser_test!(
+	verilog01+,
+	indexed_part_selects,
+	"module m; assign a = b[c +: 4] ^ b[d -: 2] ^ (e <<< 1) ^ (f >>> 2); endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		let ItemKind::ContinuousAssign(assign) = &module.items[0].kind else {
+			panic!("Expected a continuous assignment");
+		};
+
+		let mut selects = Vec::new();
+		let mut stack = vec![assign.assignments[0].value.inner()];
+		while let Some(expression) = stack.pop() {
+			match expression {
+				Expression::Binary { lhs, rhs, .. } => {
+					stack.push(rhs.inner());
+					stack.push(lhs.inner());
+				},
+				Expression::Select { select, .. } => selects.push(select.inner().clone()),
+				_ => {},
+			}
+		}
+
+		assert!(matches!(selects[0], Select::IndexedUp { .. }));
+		assert!(matches!(selects[1], Select::IndexedDown { .. }));
+	}
+);
+
+parser_test!(
+	verilog01+,
+	config,
+	"config cfg;\n\tdesign lib.top;\n\tdefault liblist lib other;\n\tinstance top.u0 use \
+	 lib.alt:config;\n\tcell foo liblist gates;\nendconfig",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let Description::Config(config) = ast.descriptions()[0].inner() else {
+			panic!("Expected a config, got {:#?}", ast.descriptions()[0]);
+		};
+
+		assert_eq!(config.design.len(), 1);
+		assert!(config.design[0].library.is_some());
+		assert_eq!(config.rules.len(), 3);
+		assert!(matches!(
+			&config.rules[0].action,
+			ConfigRuleAction::LibList(libraries) if libraries.len() == 2
+		));
+		assert!(matches!(
+			&config.rules[1].target,
+			ConfigRuleTarget::Instance(path) if path.len() == 2
+		));
+		assert!(matches!(
+			&config.rules[1].action,
+			ConfigRuleAction::Use(CellReference { config: true, .. })
+		));
+		assert!(matches!(config.rules[2].target, ConfigRuleTarget::Cell(_)));
+	}
+);
+
+parser_test!(
+	verilog01+,
+	ansi_udp,
+	"primitive inv(output reg q = 1'b1, input a);\n\ttable\n\t\t0 : ? : 1;\n\t\t1 : ? : \
+	 0;\n\tendtable\nendprimitive",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let Description::Primitive(udp) = ast.descriptions()[0].inner() else {
+			panic!("Expected a UDP, got {:#?}", ast.descriptions()[0]);
+		};
+
+		assert!(udp.ports.is_empty());
+		assert_eq!(udp.declarations.len(), 2);
+		assert!(udp.is_sequential());
+	}
+);
+
+parser_test!(
+	verilog01,
+	generate_construct_outside_region,
+	"module m; if (1) assign a = b; endmodule",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"Generate constructs outside of generate regions are not supported in Verilog 2001 \
+			 (IEEE 1364-2001), requires Verilog 2005 (IEEE 1364-2005)"
+		));
+	}
+);
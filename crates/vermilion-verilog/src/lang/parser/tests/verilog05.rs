@@ -1 +1,36 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+parser_test!(
+	verilog05+,
+	generate_constructs_without_region,
+	"module m;\n\tgenvar i;\n\tfor (i = 0; i < 2; i = i + 1) begin : g\n\t\tassign a[i] = \
+	 b[i];\n\tend\n\tif (W > 1) assign c = d;\n\tcase (W)\n\t\t1: ;\n\tendcase\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		assert!(matches!(module.items[1].kind, ItemKind::GenerateFor(_)));
+		assert!(matches!(
+			&module.items[2].kind,
+			ItemKind::GenerateIf(GenerateIf { if_true: Some(block), .. }) if block.name.is_none()
+		));
+		assert!(matches!(module.items[3].kind, ItemKind::GenerateCase(_)));
+	}
+);
+
+parser_test!(
+	verilog05+,
+	uwire,
+	"module m; uwire a; endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		assert!(matches!(
+			&module.items[0].kind,
+			ItemKind::Net(NetDeclaration { net_type: crate::lang::types::NetType::Uwire, .. })
+		));
+	}
+);
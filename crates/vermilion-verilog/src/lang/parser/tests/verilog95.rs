@@ -1 +1,76 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+parser_test!(
+	verilog95,
+	ansi_ports_unsupported,
+	"module m(input a, output b); endmodule",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"ANSI style port declarations are not supported in Verilog 1995 (IEEE 1364-1995), \
+			 requires Verilog 2001 (IEEE 1364-2001)"
+		));
+	}
+);
+
+parser_test!(
+	verilog95,
+	parameter_port_list_unsupported,
+	"module m #(parameter W = 8) (a); endmodule",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"Module parameter port lists are not supported"
+		));
+	}
+);
+
+parser_test!(
+	verilog95,
+	implicit_event_unsupported,
+	"module m; always @* a = b; endmodule",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"Implicit event expression lists are not supported"
+		));
+	}
+);
+
+parser_test!(
+	verilog95,
+	comma_event_unsupported,
+	"module m; always @(a, b) c = a; endmodule",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"Comma separated event expressions are not supported"
+		));
+	}
+);
+
+parser_test!(
+	verilog95,
+	power_operator_unsupported,
+	"module m; assign a = b ** 2; endmodule",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"`**` is not valid in Verilog 1995 (IEEE 1364-1995)"
+		));
+	}
+);
+
+parser_test!(
+	verilog95,
+	generate_is_identifier,
+	"module m; generate g(); endmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		assert!(matches!(module.items[0].kind, ItemKind::Instantiation(_)));
+	}
+);
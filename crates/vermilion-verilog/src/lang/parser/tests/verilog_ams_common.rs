@@ -1 +1,151 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+use crate::lang::types::NetType;
+
+parser_test!(
+	verilog_ams_all,
+	natures_and_disciplines,
+	"nature Voltage;\n\tunits = \"V\";\n\taccess = V;\n\tabstol = 1e-6;\nendnature\n\nnature \
+	 HighVoltage : Voltage\n\tabstol = 1e-3;\nendnature\n\ndiscipline electrical;\n\tpotential \
+	 Voltage;\n\tflow Current;\n\tpotential.abstol = 1e-9;\n\tdomain continuous;\nenddiscipline",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let descriptions = ast.descriptions();
+
+		let Description::Nature(nature) = descriptions[0].inner() else {
+			panic!("Expected a nature, got {:#?}", descriptions[0]);
+		};
+		assert!(nature.parent.is_none());
+		assert_eq!(nature.attributes.len(), 3);
+		assert_eq!(nature.attributes[2].inner().name.inner().as_str(), "abstol");
+
+		assert!(matches!(
+			descriptions[1].inner(),
+			Description::Nature(Nature { parent: Some(_), .. })
+		));
+
+		let Description::Discipline(discipline) = descriptions[2].inner() else {
+			panic!("Expected a discipline, got {:#?}", descriptions[2]);
+		};
+		assert_eq!(discipline.name.inner().as_str(), "electrical");
+		assert!(matches!(
+			discipline.items[0].inner(),
+			DisciplineItem::Binding { binding: NatureBinding::Potential, .. }
+		));
+		assert!(matches!(
+			discipline.items[2].inner(),
+			DisciplineItem::Attribute { binding: NatureBinding::Potential, .. }
+		));
+		assert!(matches!(
+			discipline.items[3].inner(),
+			DisciplineItem::Domain(DisciplineDomain::Continuous)
+		));
+	}
+);
+
+parser_test!(
+	verilog_ams_all,
+	analog_module,
+	"module res(p, n);\n\tinout electrical p, n;\n\tparameter real r = 1e3 from (0:inf) exclude \
+	 5;\n\telectrical [1:0] bus;\n\telectrical mid;\n\tground gnd;\n\tbranch (p, n) rb;\n\tbranch \
+	 (<p>) pb;\n\twreal level;\n\tsub u1 (p, mid);\n\n\tanalog begin\n\t\tV(rb) <+ r * \
+	 I(rb);\n\t\tI(p, gnd) <+ ddt(V(p)) * 1e-12 + white_noise(4 * 1.38e-23 * 300 / r, \
+	 \"thermal\");\n\t\tV(mid) : V(p) == 0;\n\t\t@(cross(V(p) - 0.5, 1)) ;\n\tend\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+		let items = &module.items;
+
+		assert!(matches!(
+			&items[0].kind,
+			ItemKind::Port(PortDeclaration { discipline: Some(discipline), declarators, .. })
+				if discipline.inner().as_str() == "electrical" && declarators.len() == 2
+		));
+
+		let ItemKind::Parameter(parameter) = &items[1].kind else {
+			panic!("Expected a parameter, got {:#?}", items[1]);
+		};
+		let ranges = &parameter.declarators[0].inner().ranges;
+		assert_eq!(ranges.len(), 2);
+		assert!(matches!(
+			ranges[0].inner(),
+			ValueRange {
+				exclude: false,
+				bounds:  ValueRangeBounds::Interval {
+					low_inclusive: false,
+					high_inclusive: false,
+					..
+				},
+			}
+		));
+		assert!(matches!(
+			ranges[1].inner(),
+			ValueRange { exclude: true, bounds: ValueRangeBounds::Value(_) }
+		));
+
+		assert!(matches!(
+			&items[2].kind,
+			ItemKind::DisciplineNet(DisciplineNet {
+				data_type: DataType::Implicit { packed, .. },
+				..
+			}) if packed.len() == 1
+		));
+		assert!(matches!(&items[3].kind, ItemKind::DisciplineNet(_)));
+		assert!(matches!(&items[4].kind, ItemKind::Ground(names) if names.len() == 1));
+		assert!(matches!(
+			&items[5].kind,
+			ItemKind::Branch(Branch { terminals, .. }) if terminals.len() == 2
+		));
+		assert!(matches!(
+			&items[6].kind,
+			ItemKind::Branch(Branch { terminals, .. })
+				if matches!(terminals[0].inner(), BranchTerminal::Port(_))
+		));
+		assert!(matches!(
+			&items[7].kind,
+			ItemKind::Net(NetDeclaration { net_type: NetType::Wreal, .. })
+		));
+		assert!(matches!(&items[8].kind, ItemKind::Instantiation(_)));
+
+		let ItemKind::Analog(statement) = &items[9].kind else {
+			panic!("Expected an analog block, got {:#?}", items[9]);
+		};
+		let StatementKind::Block(block) = &statement.inner().kind else {
+			panic!("Expected a block, got {statement:#?}");
+		};
+		assert!(matches!(
+			block.statements[0].inner().kind,
+			StatementKind::Contribution { .. }
+		));
+		assert!(matches!(
+			block.statements[1].inner().kind,
+			StatementKind::Contribution { .. }
+		));
+		assert!(matches!(
+			block.statements[2].inner().kind,
+			StatementKind::IndirectContribution { .. }
+		));
+		assert!(matches!(
+			block.statements[3].inner().kind,
+			StatementKind::TimingControl { .. }
+		));
+	}
+);
+
+parser_test!(
+	verilog_ams_all,
+	analog_initial_and_functions,
+	"module m;\n\treal x;\n\tanalog initial x = 0;\n\n\tanalog function real sq;\n\t\tinput \
+	 v;\n\t\treal v;\n\t\tsq = v * v;\n\tendfunction\nendmodule",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let module = ast.modules().next().unwrap_or_else(|| panic!("No module"));
+
+		assert!(matches!(module.items[1].kind, ItemKind::AnalogInitial(_)));
+		assert!(matches!(
+			&module.items[2].kind,
+			ItemKind::AnalogFunction(function) if function.name.inner().as_str() == "sq"
+		));
+	}
+);
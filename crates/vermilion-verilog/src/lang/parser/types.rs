@@ -0,0 +1,455 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::{
+	LanguageStd,
+	lang::{
+		ast::{
+			DataType, Dimension, EnumMember, EnumType, IntegerAtomType, IntegerVectorType,
+			NonIntegerType, RandomQualifier, Signing, StructKind, StructMember, StructType,
+		},
+		keywords::Keyword,
+		parser::{VERILOG_01_STDS, VerilogParser, error::ParseResult},
+		tokenizer::token::Token,
+		types::{Control, Operator},
+	},
+};
+
+impl VerilogParser {
+	/// Parse one of the builtin data type keywords along with its signing and range, if present
+	///
+	/// IEEE 1364-2005 § A.2.2.1, IEEE 1800-2017 § A.2.2.1
+	pub(crate) fn parse_variable_type(&mut self) -> ParseResult<Option<DataType>> {
+		let Some(Token::Keyword(keyword)) = self.peek() else {
+			return Ok(None);
+		};
+
+		let data_type = match keyword {
+			Keyword::Reg | Keyword::Bit | Keyword::Logic => {
+				let kind = match keyword {
+					Keyword::Bit => IntegerVectorType::Bit,
+					Keyword::Logic => IntegerVectorType::Logic,
+					_ => IntegerVectorType::Reg,
+				};
+				self.bump();
+				let signing = self.parse_signing()?;
+
+				DataType::IntegerVector { kind, signing, packed: self.parse_dimensions()? }
+			},
+			Keyword::Integer |
+			Keyword::Time |
+			Keyword::Byte |
+			Keyword::ShortInt |
+			Keyword::Int |
+			Keyword::LongInt => {
+				let kind = match keyword {
+					Keyword::Time => IntegerAtomType::Time,
+					Keyword::Byte => IntegerAtomType::Byte,
+					Keyword::ShortInt => IntegerAtomType::ShortInt,
+					Keyword::Int => IntegerAtomType::Int,
+					Keyword::LongInt => IntegerAtomType::LongInt,
+					_ => IntegerAtomType::Integer,
+				};
+				self.bump();
+
+				// NOTE(aki): Only SystemVerilog allows integer atom types to be given a signing
+				let signing = if self.is_system_verilog() {
+					self.parse_signing()?
+				} else {
+					None
+				};
+
+				DataType::IntegerAtom { kind, signing }
+			},
+			Keyword::Real => {
+				self.bump();
+				DataType::NonInteger(NonIntegerType::Real)
+			},
+			Keyword::Realtime => {
+				self.bump();
+				DataType::NonInteger(NonIntegerType::Realtime)
+			},
+			Keyword::ShortReal => {
+				self.bump();
+				DataType::NonInteger(NonIntegerType::ShortReal)
+			},
+			Keyword::Event => {
+				self.bump();
+				DataType::Event
+			},
+			Keyword::String => {
+				self.bump();
+				DataType::String
+			},
+			Keyword::CHandle => {
+				self.bump();
+				DataType::CHandle
+			},
+			Keyword::Void => {
+				self.bump();
+				DataType::Void
+			},
+			Keyword::Type => {
+				self.bump();
+				DataType::Type
+			},
+			Keyword::Struct | Keyword::Union => {
+				DataType::Struct(Box::new(self.parse_struct_type()?))
+			},
+			Keyword::Enum => DataType::Enum(Box::new(self.parse_enum_type()?)),
+			Keyword::Virtual => {
+				self.bump();
+				self.eat_keyword(Keyword::Interface);
+				let name = self.expect_identifier()?;
+				let parameters = self.parse_parameter_value_assignment()?;
+
+				let modport = if self.eat_control(Control::Dot) {
+					Some(self.expect_identifier()?)
+				} else {
+					None
+				};
+
+				DataType::VirtualInterface { name, parameters, modport }
+			},
+			_ => return Ok(None),
+		};
+
+		Ok(Some(data_type))
+	}
+
+	/// Parse an explicit data type, either a builtin type or a user defined type
+	pub(crate) fn parse_data_type(&mut self) -> ParseResult<DataType> {
+		if let Some(data_type) = self.parse_variable_type()? {
+			return Ok(data_type);
+		}
+
+		if self.is_identifier() {
+			return self.parse_named_type();
+		}
+
+		self.unexpected("a data type")
+	}
+
+	/// Parse an explicit data type if there is one, otherwise an implicit type
+	pub(crate) fn parse_data_type_or_implicit(&mut self) -> ParseResult<DataType> {
+		if let Some(data_type) = self.parse_variable_type()? {
+			return Ok(data_type);
+		}
+
+		if self.user_type_lookahead(0).is_some() {
+			return self.parse_named_type();
+		}
+
+		self.parse_implicit_type()
+	}
+
+	/// Parse a reference to a user defined type, e.g. `pkg::word_t [1:0]` or `fifo #(8)`
+	///
+	/// IEEE 1800-2017 § A.2.2.1
+	pub(crate) fn parse_named_type(&mut self) -> ParseResult<DataType> {
+		let start = self.span();
+		let mut path = vec![self.expect_identifier()?];
+		let mut parameters = self.parse_parameter_value_assignment()?;
+
+		while self.is_operator(Operator::ClassScopeResolution) && self.is_identifier_at(1) {
+			self.bump();
+			path.push(self.expect_identifier()?);
+
+			if parameters.is_none() {
+				parameters = self.parse_parameter_value_assignment()?;
+			}
+		}
+
+		self.require_std(
+			LanguageStd::SYSTEM_VERILOG_STDS,
+			self.span_from(start),
+			"User defined types",
+		);
+
+		// An interface port with a modport, e.g. `bus.master`
+		if path.len() == 1 && parameters.is_none() && self.is_control(Control::Dot) {
+			self.bump();
+			let modport = self.expect_identifier()?;
+
+			return Ok(DataType::Interface { name: path.pop(), modport: Some(modport) });
+		}
+
+		Ok(DataType::Named { path, parameters, packed: self.parse_dimensions()? })
+	}
+
+	/// Returns the offset just past a user defined type starting at `offset` if it is followed by
+	/// the name of a declaration
+	pub(crate) fn user_type_lookahead(&self, offset: usize) -> Option<usize> {
+		if !self.is_identifier_at(offset) {
+			return None;
+		}
+
+		let mut offset = offset + 1;
+		loop {
+			if self.is_operator_at(offset, Operator::ClassScopeResolution) &&
+				self.is_identifier_at(offset + 1)
+			{
+				offset += 2;
+			} else if self.is_control_at(offset, Control::Octothorp) &&
+				self.is_control_at(offset + 1, Control::ParenOpen)
+			{
+				offset = self.skip_balanced(offset + 1)?;
+			} else {
+				break;
+			}
+		}
+
+		if self.is_control_at(offset, Control::Dot) && self.is_identifier_at(offset + 1) {
+			offset += 2;
+		}
+
+		while self.is_control_at(offset, Control::BracketOpen) {
+			offset = self.skip_balanced(offset)?;
+		}
+
+		self.is_identifier_at(offset).then_some(offset)
+	}
+
+	/// Returns true if the current token begins a builtin data type
+	pub(crate) fn is_data_type_keyword(&self, offset: usize) -> bool {
+		matches!(
+			self.peek_nth(offset),
+			Some(Token::Keyword(
+				Keyword::Reg |
+					Keyword::Bit | Keyword::Logic |
+					Keyword::Integer |
+					Keyword::Time | Keyword::Byte |
+					Keyword::ShortInt |
+					Keyword::Int | Keyword::LongInt |
+					Keyword::Real | Keyword::Realtime |
+					Keyword::ShortReal |
+					Keyword::Event | Keyword::String |
+					Keyword::CHandle |
+					Keyword::Struct | Keyword::Union |
+					Keyword::Enum | Keyword::Virtual
+			))
+		)
+	}
+
+	/// IEEE 1800-2017 § A.2.2.1
+	fn parse_struct_type(&mut self) -> ParseResult<StructType> {
+		let kind = if self.eat_keyword(Keyword::Union) {
+			StructKind::Union
+		} else {
+			self.expect_keyword(Keyword::Struct)?;
+			StructKind::Struct
+		};
+
+		let tagged = kind == StructKind::Union && self.eat_keyword(Keyword::Tagged);
+		let packed = self.eat_keyword(Keyword::Packed);
+		let signing = if packed {
+			self.parse_signing()?
+		} else {
+			None
+		};
+
+		self.expect_control(Control::BraceOpen)?;
+
+		let mut members = Vec::new();
+		while !self.eat_control(Control::BraceClose) {
+			if self.at_eof() {
+				return self.unexpected("`}`");
+			}
+
+			let start = self.span();
+			let attributes = self.parse_attributes()?;
+
+			let random = if self.eat_keyword(Keyword::Rand) {
+				Some(RandomQualifier::Rand)
+			} else if self.eat_keyword(Keyword::RandC) {
+				Some(RandomQualifier::RandC)
+			} else {
+				None
+			};
+
+			let data_type = self.parse_data_type()?;
+			let declarators = self.parse_declarators()?;
+			self.expect_control(Control::Semicolon)?;
+
+			members.push(Spanned::new(
+				StructMember { attributes, random, data_type, declarators },
+				self.span_from(start),
+			));
+		}
+
+		Ok(StructType {
+			kind,
+			tagged,
+			packed,
+			signing,
+			members,
+			dimensions: self.parse_dimensions()?,
+		})
+	}
+
+	/// IEEE 1800-2017 § A.2.2.1
+	fn parse_enum_type(&mut self) -> ParseResult<EnumType> {
+		self.expect_keyword(Keyword::Enum)?;
+
+		let base = if self.is_control(Control::BraceOpen) {
+			None
+		} else {
+			Some(Box::new(self.parse_data_type_or_implicit()?))
+		};
+
+		self.expect_control(Control::BraceOpen)?;
+
+		let mut members = Vec::new();
+		loop {
+			let start = self.span();
+			let name = self.expect_identifier()?;
+
+			let range = if self.is_control(Control::BracketOpen) {
+				Some(self.parse_unpacked_dimension()?)
+			} else {
+				None
+			};
+
+			let value = if self.eat_operator(Operator::Equals) {
+				Some(self.parse_expression()?)
+			} else {
+				None
+			};
+
+			members.push(Spanned::new(
+				EnumMember { name, range, value },
+				self.span_from(start),
+			));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::BraceClose)?;
+
+		Ok(EnumType {
+			base,
+			members,
+			dimensions: self.parse_dimensions()?,
+		})
+	}
+
+	/// Parse an optional `signed` followed by an optional packed range
+	pub(crate) fn parse_implicit_type(&mut self) -> ParseResult<DataType> {
+		let signing = self.parse_signing()?;
+		let packed = self.parse_dimensions()?;
+
+		Ok(DataType::Implicit { signing, packed })
+	}
+
+	pub(crate) fn parse_signing(&mut self) -> ParseResult<Option<Signing>> {
+		if self.is_keyword(Keyword::Signed) {
+			self.require_std(VERILOG_01_STDS, self.span(), "Signed declarations");
+			self.bump();
+			Ok(Some(Signing::Signed))
+		} else if self.eat_keyword(Keyword::Unsigned) {
+			Ok(Some(Signing::Unsigned))
+		} else {
+			Ok(None)
+		}
+	}
+
+	/// Parse a `[msb:lsb]` range
+	pub(crate) fn parse_dimension(&mut self) -> ParseResult<Spanned<Dimension>> {
+		let start = self.expect_control(Control::BracketOpen)?;
+		let msb = self.parse_expression()?;
+		self.expect_control(Control::Colon)?;
+		let lsb = self.parse_expression()?;
+		self.expect_control(Control::BracketClose)?;
+
+		Ok(Spanned::new(
+			Dimension::Range { msb, lsb },
+			self.span_from(start),
+		))
+	}
+
+	pub(crate) fn parse_dimensions(&mut self) -> ParseResult<Vec<Spanned<Dimension>>> {
+		let mut dimensions = Vec::new();
+		while self.is_control(Control::BracketOpen) {
+			dimensions.push(self.parse_dimension()?);
+		}
+
+		Ok(dimensions)
+	}
+
+	/// Parse an unpacked dimension, which in SystemVerilog may also be a size, a dynamic array,
+	/// an associative array, or a queue
+	///
+	/// IEEE 1800-2017 § A.2.5
+	pub(crate) fn parse_unpacked_dimension(&mut self) -> ParseResult<Spanned<Dimension>> {
+		let start = self.span();
+
+		let dimension = match self.peek_nth(1) {
+			Some(Token::Control(Control::BracketClose)) => {
+				self.position += 2;
+				Dimension::Unsized
+			},
+			Some(Token::Control(Control::Dollar)) => {
+				self.position += 2;
+
+				let bound = if self.eat_control(Control::Colon) {
+					Some(self.parse_expression()?)
+				} else {
+					None
+				};
+				self.expect_control(Control::BracketClose)?;
+
+				Dimension::Queue(bound)
+			},
+			Some(Token::Operator(Operator::Asterisk))
+				if self.is_control_at(2, Control::BracketClose) =>
+			{
+				self.position += 3;
+				Dimension::Associative(None)
+			},
+			_ if self.is_data_type_keyword(1) => {
+				self.bump();
+				let index = self.parse_data_type()?;
+				self.expect_control(Control::BracketClose)?;
+
+				Dimension::Associative(Some(Box::new(index)))
+			},
+			_ => {
+				self.bump();
+				let first = self.parse_expression()?;
+
+				if self.eat_control(Control::Colon) {
+					let lsb = self.parse_expression()?;
+					self.expect_control(Control::BracketClose)?;
+
+					Dimension::Range { msb: first, lsb }
+				} else {
+					self.expect_control(Control::BracketClose)?;
+					Dimension::Size(first)
+				}
+			},
+		};
+
+		let span = self.span_from(start);
+		if !matches!(dimension, Dimension::Range { .. }) {
+			self.require_std(
+				LanguageStd::SYSTEM_VERILOG_STDS,
+				span,
+				"Unpacked dimensions other than ranges",
+			);
+		}
+
+		Ok(Spanned::new(dimension, span))
+	}
+
+	pub(crate) fn parse_unpacked_dimensions(&mut self) -> ParseResult<Vec<Spanned<Dimension>>> {
+		let mut dimensions = Vec::new();
+		while self.is_control(Control::BracketOpen) {
+			dimensions.push(self.parse_unpacked_dimension()?);
+		}
+
+		Ok(dimensions)
+	}
+}
@@ -0,0 +1,238 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::{Span, Spanned};
+
+use crate::lang::{
+	ast::{Attribute, Item, ItemKind, Udp, UdpInitial, UdpSymbol, UdpTableEntry},
+	keywords::Keyword,
+	parser::{
+		VERILOG_01_STDS, VerilogParser,
+		error::{ParseError, ParseResult},
+	},
+	tokenizer::token::Token,
+	types::{Control, Operator},
+};
+
+impl VerilogParser {
+	/// IEEE 1364-2005 § A.5.1
+	pub(crate) fn parse_udp(&mut self, attributes: Vec<Spanned<Attribute>>) -> ParseResult<Udp> {
+		self.expect_keyword(Keyword::Primitive)?;
+		let name = self.expect_identifier()?;
+
+		self.expect_control(Control::ParenOpen)?;
+
+		let mut ports = Vec::new();
+		let mut declarations = Vec::new();
+
+		let lookahead = self.attributes_lookahead();
+		if matches!(
+			self.peek_nth(lookahead),
+			Some(Token::Keyword(Keyword::Input | Keyword::Output))
+		) {
+			self.require_std(
+				VERILOG_01_STDS,
+				self.span(),
+				"ANSI style UDP port declarations",
+			);
+
+			for port in self.parse_ansi_port_declarations()? {
+				let (port, span) = port.split();
+				declarations.push(Spanned::new(Item::new(ItemKind::Port(port)), span));
+			}
+		} else {
+			ports.push(self.expect_identifier()?);
+			while self.eat_control(Control::Comma) {
+				ports.push(self.expect_identifier()?);
+			}
+		}
+
+		self.expect_control(Control::ParenClose)?;
+		self.expect_control(Control::Semicolon)?;
+
+		while let Some(item) = self.parse_block_item_declaration(true)? {
+			declarations.push(item);
+		}
+
+		let initial = if self.is_keyword(Keyword::Initial) {
+			let start = self.span();
+			self.bump();
+
+			let name = self.expect_identifier()?;
+			self.expect_operator(Operator::Equals)?;
+			let value = self.parse_number()?;
+			self.expect_control(Control::Semicolon)?;
+
+			Some(Spanned::new(
+				UdpInitial { name, value },
+				self.span_from(start),
+			))
+		} else {
+			None
+		};
+
+		self.expect_keyword(Keyword::Table)?;
+
+		let mut table = Vec::new();
+		while !self.eat_keyword(Keyword::EndTable) {
+			if self.at_eof() {
+				return self.unexpected("`endtable`");
+			}
+
+			table.push(self.parse_udp_table_entry()?);
+		}
+
+		self.expect_keyword(Keyword::EndPrimitive)?;
+
+		Ok(Udp {
+			attributes,
+			name,
+			ports,
+			declarations,
+			initial,
+			table,
+		})
+	}
+
+	// NOTE(aki):
+	// The tokenizer has no idea about UDP tables, so things like `0x`, `(01)`, or `?r` end up as a
+	// mix of numbers, identifiers, and operators, so we re-scan the source text of each token.
+	/// IEEE 1364-2005 § A.5.3
+	fn parse_udp_table_entry(&mut self) -> ParseResult<Spanned<UdpTableEntry>> {
+		let start = self.span();
+
+		let mut fields: Vec<Vec<Spanned<UdpSymbol>>> = vec![Vec::new()];
+		let mut transition: Option<(Span, Vec<u8>)> = None;
+
+		loop {
+			let Some(token) = self.bump() else {
+				return self.unexpected("`;`");
+			};
+
+			match token.inner() {
+				Token::Control(Control::Semicolon) if transition.is_none() => break,
+				Token::Control(Control::Colon) if transition.is_none() => {
+					fields.push(Vec::new());
+					continue;
+				},
+				_ => {},
+			}
+
+			let span = *token.span();
+			let text = self.source_text(&span);
+
+			for (idx, &chr) in text.iter().enumerate() {
+				let offset = *span.begin() + idx as u32;
+				let chr_span = Span::new(
+					offset,
+					offset + 1,
+					*span.line(),
+					*span.character() + idx as u32,
+				);
+
+				if let Some((transition_span, levels)) = &mut transition {
+					match chr {
+						b')' => {
+							let symbol = match levels[..] {
+								[from, to] => UdpSymbol::Transition(from, to),
+								_ => {
+									return Err(ParseError::new(
+										"UDP edge transitions must have exactly two levels",
+										join_spans(*transition_span, chr_span),
+									));
+								},
+							};
+
+							let symbol_span = join_spans(*transition_span, chr_span);
+							transition = None;
+
+							if let Some(field) = fields.last_mut() {
+								field.push(Spanned::new(symbol, symbol_span));
+							}
+						},
+						_ => match udp_symbol(chr) {
+							Some(UdpSymbol::Level(level)) => levels.push(level),
+							_ => {
+								return Err(ParseError::new(
+									format!("Invalid UDP level `{}`", chr as char),
+									chr_span,
+								));
+							},
+						},
+					}
+
+					continue;
+				}
+
+				if chr == b'(' {
+					transition = Some((chr_span, Vec::new()));
+					continue;
+				}
+
+				let Some(symbol) = udp_symbol(chr) else {
+					return Err(ParseError::new(
+						format!("Invalid UDP table symbol `{}`", chr as char),
+						chr_span,
+					));
+				};
+
+				if let Some(field) = fields.last_mut() {
+					field.push(Spanned::new(symbol, chr_span));
+				}
+			}
+		}
+
+		let span = self.span_from(start);
+		let mut fields = fields.into_iter();
+
+		let (inputs, current, output) = match (fields.next(), fields.next(), fields.next()) {
+			(Some(inputs), Some(output), None) if fields.len() == 0 => (inputs, None, output),
+			(Some(inputs), Some(current), Some(output)) if fields.len() == 0 => {
+				(inputs, single_symbol(current, span)?, output)
+			},
+			_ => {
+				return Err(ParseError::new(
+					"UDP table entries must be `inputs : output` or `inputs : state : next state`",
+					span,
+				));
+			},
+		};
+
+		let Some(output) = single_symbol(output, span)? else {
+			return Err(ParseError::new(
+				"UDP table entry is missing an output",
+				span,
+			));
+		};
+
+		Ok(Spanned::new(
+			UdpTableEntry { inputs, current, output },
+			span,
+		))
+	}
+}
+
+fn udp_symbol(chr: u8) -> Option<UdpSymbol> {
+	match chr.to_ascii_lowercase() {
+		chr @ (b'0' | b'1' | b'x' | b'?' | b'b') => Some(UdpSymbol::Level(chr)),
+		chr @ (b'r' | b'f' | b'p' | b'n' | b'*') => Some(UdpSymbol::Edge(chr)),
+		b'-' => Some(UdpSymbol::NoChange),
+		_ => None,
+	}
+}
+
+fn single_symbol(
+	mut symbols: Vec<Spanned<UdpSymbol>>,
+	span: Span,
+) -> ParseResult<Option<Spanned<UdpSymbol>>> {
+	match symbols.len() {
+		0 | 1 => Ok(symbols.pop()),
+		_ => Err(ParseError::new(
+			"UDP table state columns must be a single symbol",
+			span,
+		)),
+	}
+}
+
+fn join_spans(begin: Span, end: Span) -> Span {
+	Span::from_position(*begin.begin(), *end.end(), begin.get_position())
+}
@@ -0,0 +1,1330 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! The Verilog preprocessor
+//!
+//! Evaluates the compiler directives that change the text being compiled, that is the text macro
+//! directives (`` `define ``, `` `undef ``, `` `undefineall ``), the conditional compilation
+//! directives (`` `ifdef ``, `` `ifndef ``, `` `elsif ``, `` `else ``, `` `endif ``),
+//! `` `include ``, and `` `line ``, along with any text macro usages. All other directives, such as
+//! `` `timescale `` and `` `resetall ``, affect the meaning of the source rather than its text, so
+//! they are passed through untouched along with the rest of their line for later stages to handle.
+//!
+//! Every token produced remembers the file and span it came from, tokens produced by a macro
+//! expansion are located at the macro usage and carry the expansion they came from, which can be
+//! looked up in [`Preprocessor::expansions`] to find the macro definition.
+//!
+//! IEEE 1364-2005 § 19, IEEE 1800-2017 § 22
+
+use std::{
+	collections::HashMap,
+	fs,
+	path::{Path, PathBuf},
+};
+
+use vermilion_diagnostics::{Code, StringDiagnostic};
+use vermilion_lang::{AtomicByteTendril, tokenizer::TokenizerIter};
+use vermilion_loc::{Expansion, Expansions, FileId, Location, Span};
+
+use crate::{
+	LanguageStd,
+	diagnostics::{system_verilog, verilog, verilog_ams},
+	lang::{
+		directives::BuiltinDirective,
+		text_macros::BuiltinTextMacro,
+		tokenizer::{VerilogTokenizer, token::Token},
+		types::{CompilerDirective, Control, Operator, SingleQuotedString, TextMacro},
+	},
+};
+
+/// The deepest that `` `include `` directives may be nested, this stops a file that includes
+/// itself from looping forever
+const MAX_INCLUDE_DEPTH: usize = 64;
+
+/// A token produced by the preprocessor, along with where it came from
+#[derive(Clone, Debug, PartialEq)]
+pub struct PreprocessedToken {
+	location: Location<Token>,
+	text:     AtomicByteTendril,
+}
+
+/// A source file read by the preprocessor
+#[derive(Clone, Debug)]
+pub struct SourceFile {
+	path:          PathBuf,
+	content:       AtomicByteTendril,
+	/// The `` `include `` directive that pulled in this file, `None` if it was preprocessed
+	/// directly
+	included_from: Option<Location<()>>,
+}
+
+/// Where a text macro was defined
+#[derive(Clone, Debug, PartialEq)]
+pub enum MacroOrigin {
+	/// Predefined by the language standard
+	Builtin,
+	/// Defined outside of the source, such as on the command line
+	External,
+	/// Defined by a `` `define `` directive, located at the name of the macro
+	Source(Location<()>),
+}
+
+/// A text macro
+///
+/// IEEE 1800-2017 § 22.5.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct Macro {
+	/// The formal arguments of the macro, `None` if it doesn't take an argument list at all
+	pub arguments: Option<Vec<MacroArgument>>,
+	/// The macro text, with line continuations and comments removed
+	pub body:      Vec<u8>,
+	pub origin:    MacroOrigin,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MacroArgument {
+	pub name:    String,
+	pub default: Option<Vec<u8>>, // Added: IEEE 1800-2009
+}
+
+/// The macro expansions of a preprocessed file, for going from a macro usage in it to where the
+/// macro was defined
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceMap {
+	/// The preprocessed file, as numbered by the preprocessor
+	file:       FileId,
+	/// The paths of the files read by the preprocessor, indexed by [`FileId`]
+	paths:      Vec<PathBuf>,
+	expansions: Expansions,
+}
+
+pub struct Preprocessor {
+	std:           LanguageStd,
+	include_paths: Vec<PathBuf>,
+	/// The text to use for `` `include `` files rather than what is on disk
+	overrides:     HashMap<PathBuf, AtomicByteTendril>,
+	macros:        HashMap<String, Macro>,
+	files:         Vec<SourceFile>,
+	diagnostics:   Vec<Location<StringDiagnostic>>,
+	/// The names of the macros currently being expanded, used to catch recursive macros
+	expanding:     Vec<String>,
+	expansions:    Expansions,
+}
+
+/// The state of a conditional compilation directive
+struct Conditional {
+	/// Whether the text around the conditional is being compiled
+	parent:    bool,
+	/// Whether any of the branches so far have been taken
+	taken:     bool,
+	/// Whether the current branch is being compiled
+	active:    bool,
+	seen_else: bool,
+	/// The `` `ifdef `` or `` `ifndef `` that opened the conditional
+	location:  Location<()>,
+}
+
+/// The state of the file currently being preprocessed
+struct FileState {
+	id:          FileId,
+	/// How many `` `include `` directives deep this file is
+	depth:       usize,
+	/// The adjustment to line numbers made by a `` `line `` directive
+	line_offset: i64,
+	/// The file name set by a `` `line `` directive
+	name:        Option<String>,
+}
+
+/// A stream of tokens being preprocessed
+struct Input {
+	tokens:   Vec<PreprocessedToken>,
+	position: usize,
+}
+
+impl PreprocessedToken {
+	pub fn token(&self) -> &Token {
+		self.location.inner()
+	}
+
+	pub fn location(&self) -> &Location<Token> {
+		&self.location
+	}
+
+	pub fn file_id(&self) -> FileId {
+		*self.location.file_id()
+	}
+
+	pub fn span(&self) -> &Span {
+		self.location.span()
+	}
+
+	/// The text of the token, for tokens produced by a macro expansion this is the expanded text
+	pub fn text(&self) -> &AtomicByteTendril {
+		&self.text
+	}
+
+	fn is_whitespace(&self) -> bool {
+		matches!(self.token(), Token::Whitespace(_))
+	}
+
+	fn is_newline(&self) -> bool {
+		matches!(self.token(), Token::Newline(_))
+	}
+
+	fn is_control(&self, control: Control) -> bool {
+		matches!(self.token(), Token::Control(token) if *token == control)
+	}
+
+	/// The location of the token, without the token itself
+	fn position(&self) -> Location<()> {
+		self.location.with_inner(())
+	}
+}
+
+impl SourceFile {
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	pub fn content(&self) -> &AtomicByteTendril {
+		&self.content
+	}
+
+	pub fn included_from(&self) -> Option<&Location<()>> {
+		self.included_from.as_ref()
+	}
+}
+
+impl SourceMap {
+	/// The preprocessed file, the macro usages in it are located in this file
+	pub const fn file(&self) -> FileId {
+		self.file
+	}
+
+	/// The path of a file read while preprocessing, such as the one a macro was defined in
+	pub fn path(&self, id: FileId) -> Option<&Path> {
+		self.paths.get(*id.raw()).map(PathBuf::as_path)
+	}
+
+	/// The paths of the files pulled in by the `` `include `` directives of the file, directly or
+	/// through other included files
+	pub fn includes(&self) -> impl Iterator<Item = &Path> {
+		self.paths
+			.iter()
+			.skip(*self.file.raw() + 1)
+			.map(PathBuf::as_path)
+	}
+
+	pub const fn expansions(&self) -> &Expansions {
+		&self.expansions
+	}
+
+	/// The macro usage covering the byte `offset` of the file
+	pub fn usage_at(&self, offset: u32) -> Option<&Expansion> {
+		self.expansions.usage_at(self.file, offset)
+	}
+
+	/// The macro usages written in the file itself, rather than in a macro body or an
+	/// `` `include `` file
+	pub fn usages(&self) -> impl Iterator<Item = &Expansion> {
+		self.expansions.iter().filter_map(|(_, expansion)| {
+			let call_site = expansion.call_site();
+			(!call_site.is_expanded() && *call_site.file_id() == self.file).then_some(expansion)
+		})
+	}
+}
+
+impl Default for SourceMap {
+	fn default() -> Self {
+		Self {
+			file:       FileId::new(0usize),
+			paths:      Vec::new(),
+			expansions: Expansions::new(),
+		}
+	}
+}
+
+impl Macro {
+	/// The names of the formal arguments of the macro
+	fn argument_names(&self) -> Vec<&str> {
+		self.arguments
+			.iter()
+			.flatten()
+			.map(|argument| argument.name.as_str())
+			.collect()
+	}
+}
+
+impl Preprocessor {
+	pub fn new(std: LanguageStd) -> Self {
+		let mut macros = HashMap::new();
+
+		if LanguageStd::VERILOG_AMS_STDS.contains(std) {
+			for name in [BuiltinTextMacro::VamsEnable, BuiltinTextMacro::VamsCompactModeling] {
+				macros.insert(
+					name.as_str().to_string(),
+					Macro {
+						arguments: None,
+						body:      Vec::new(),
+						origin:    MacroOrigin::Builtin,
+					},
+				);
+			}
+		}
+
+		Self {
+			std,
+			include_paths: Vec::new(),
+			overrides: HashMap::new(),
+			macros,
+			files: Vec::new(),
+			diagnostics: Vec::new(),
+			expanding: Vec::new(),
+			expansions: Expansions::new(),
+		}
+	}
+
+	pub fn std(&self) -> LanguageStd {
+		self.std
+	}
+
+	/// Add a directory to search for `` `include `` files in, directories are searched in the
+	/// order they are added
+	pub fn add_include_path(&mut self, path: impl Into<PathBuf>) {
+		self.include_paths.push(path.into());
+	}
+
+	/// Use `content` as the text of the file at `path` when it is `` `include ``d, rather than
+	/// reading it from disk, such as for a file open in an editor with unsaved changes
+	pub fn override_file(&mut self, path: impl Into<PathBuf>, content: AtomicByteTendril) {
+		self.overrides.insert(path.into(), content);
+	}
+
+	/// Define a text macro without any arguments, as if by `` `define name body ``
+	pub fn define(&mut self, name: impl Into<String>, body: impl AsRef<[u8]>) {
+		self.macros.insert(
+			name.into(),
+			Macro {
+				arguments: None,
+				body:      body.as_ref().trim_ascii().to_vec(),
+				origin:    MacroOrigin::External,
+			},
+		);
+	}
+
+	/// Undefine a text macro, returning its definition if it was defined
+	pub fn undefine(&mut self, name: &str) -> Option<Macro> {
+		self.macros.remove(name)
+	}
+
+	pub fn is_defined(&self, name: &str) -> bool {
+		self.macros.contains_key(name)
+	}
+
+	pub fn get_macro(&self, name: &str) -> Option<&Macro> {
+		self.macros.get(name)
+	}
+
+	pub fn macros(&self) -> impl Iterator<Item = (&str, &Macro)> {
+		self.macros
+			.iter()
+			.map(|(name, definition)| (name.as_str(), definition))
+	}
+
+	/// All of the files read so far, indexed by their [`FileId`]
+	pub fn files(&self) -> &[SourceFile] {
+		&self.files
+	}
+
+	pub fn file(&self, id: FileId) -> Option<&SourceFile> {
+		self.files.get(*id.raw())
+	}
+
+	/// The `` `include `` directives that led to the given file, innermost first
+	pub fn include_chain(&self, id: FileId) -> Vec<Location<()>> {
+		let mut chain = Vec::new();
+		let mut current = self.file(id);
+
+		while let Some(location) = current.and_then(SourceFile::included_from) {
+			chain.push(*location);
+			current = self.file(*location.file_id());
+		}
+
+		chain
+	}
+
+	/// The span in `file` that `location` was read from, anything from a file `file` included is
+	/// located at the `` `include `` directive that pulled it in
+	pub fn span_in<T>(&self, file: FileId, location: &Location<T>) -> Span {
+		if *location.file_id() == file {
+			return *location.span();
+		}
+
+		self.include_chain(*location.file_id())
+			.into_iter()
+			.find(|directive| *directive.file_id() == file)
+			.map_or(*location.span(), |directive| *directive.span())
+	}
+
+	/// Problems found while preprocessing, located in the file they were found in
+	pub fn diagnostics(&self) -> &[Location<StringDiagnostic>] {
+		&self.diagnostics
+	}
+
+	/// Every macro expansion performed so far, used to map the locations of expanded tokens back
+	/// to the macro definitions
+	pub fn expansions(&self) -> &Expansions {
+		&self.expansions
+	}
+
+	/// The [`SourceMap`] of the given file, mapping its macro usages to the macro definitions
+	pub fn source_map(&self, file: FileId) -> SourceMap {
+		SourceMap {
+			file,
+			paths: self
+				.files
+				.iter()
+				.map(|source| source.path.clone())
+				.collect(),
+			expansions: self.expansions.clone(),
+		}
+	}
+
+	/// Describe the macro expansions that `location` came from, innermost first, such as
+	/// "in expansion of macro `FOO` defined at foo.svh:12"
+	pub fn expansion_notes<T>(&self, location: &Location<T>) -> Vec<String> {
+		self.expansions
+			.stack(location)
+			.map(|expansion| match expansion.definition() {
+				Some(definition) => format!(
+					"in expansion of macro `{}` defined at {}:{}",
+					expansion.name(),
+					self.file(*definition.file_id())
+						.map(|file| file.path.display().to_string())
+						.unwrap_or_default(),
+					definition.line() + 1
+				),
+				None => format!("in expansion of macro `{}`", expansion.name()),
+			})
+			.collect()
+	}
+
+	/// Find the definition of the macro used at the byte `offset` of the given file, `None` if
+	/// there is no macro usage there or the macro was not defined in a source file
+	pub fn definition_at(&self, id: FileId, offset: u32) -> Option<Location<()>> {
+		self.expansions
+			.usage_at(id, offset)
+			.and_then(Expansion::definition)
+			.copied()
+	}
+
+	/// Read and preprocess the file at `path`
+	///
+	/// Macros defined in the file stay defined for any files preprocessed after it, as they would
+	/// be for multiple files given to a single compilation.
+	pub fn preprocess_file(
+		&mut self,
+		path: impl Into<PathBuf>,
+	) -> eyre::Result<Vec<PreprocessedToken>> {
+		let path = path.into();
+		let content = fs::read(&path)?;
+
+		self.preprocess(path, content.as_slice().into())
+	}
+
+	/// Preprocess `content`, using `path` as the name of the file it was read from
+	pub fn preprocess(
+		&mut self,
+		path: impl Into<PathBuf>,
+		content: AtomicByteTendril,
+	) -> eyre::Result<Vec<PreprocessedToken>> {
+		let id = self.add_file(path.into(), content, None);
+		let tokens = self.tokenize_file(id)?;
+
+		let mut output = Vec::new();
+		let mut file = FileState { id, depth: 0, line_offset: 0, name: None };
+		self.process(tokens, &mut file, &mut output);
+
+		Ok(output)
+	}
+
+	fn add_file(
+		&mut self,
+		path: PathBuf,
+		content: AtomicByteTendril,
+		included_from: Option<Location<()>>,
+	) -> FileId {
+		self.files.push(SourceFile { path, content, included_from });
+		FileId::new(self.files.len() - 1)
+	}
+
+	fn tokenize_file(&self, id: FileId) -> eyre::Result<Vec<PreprocessedToken>> {
+		let Some(file) = self.file(id) else {
+			return Ok(Vec::new());
+		};
+
+		let mut tokenizer = VerilogTokenizer::new(self.std, file.content.clone())?;
+		Ok(TokenizerIter::new(&mut tokenizer)
+			.map(|token| {
+				let span = *token.span();
+				let begin = (*span.begin()).min(file.content.len32());
+				let end = (*span.end()).clamp(begin, file.content.len32());
+
+				PreprocessedToken {
+					location: Location::new(id, token.inner().clone(), span),
+					text:     file.content.subtendril(begin, end - begin),
+				}
+			})
+			.collect())
+	}
+
+	/// Tokenize the text produced by a macro expansion, locating all of the tokens at `location`,
+	/// which should carry the expansion
+	fn tokenize_expansion(
+		&mut self,
+		text: Vec<u8>,
+		location: Location<()>,
+	) -> Vec<PreprocessedToken> {
+		let text = AtomicByteTendril::from_slice(&text);
+		let mut tokenizer = match VerilogTokenizer::new(self.std, text.clone()) {
+			Ok(tokenizer) => tokenizer,
+			Err(err) => {
+				self.error(location, format!("Unable to expand macro: {err}"));
+				return Vec::new();
+			},
+		};
+
+		TokenizerIter::new(&mut tokenizer)
+			.map(|token| {
+				let begin = (*token.span().begin()).min(text.len32());
+				let end = (*token.span().end()).clamp(begin, text.len32());
+
+				PreprocessedToken {
+					location: location.with_inner(token.inner().clone()),
+					text:     text.subtendril(begin, end - begin),
+				}
+			})
+			.collect()
+	}
+
+	fn process(
+		&mut self,
+		tokens: Vec<PreprocessedToken>,
+		file: &mut FileState,
+		output: &mut Vec<PreprocessedToken>,
+	) {
+		let mut input = Input { tokens, position: 0 };
+		let mut conditionals: Vec<Conditional> = Vec::new();
+
+		while let Some(token) = input.bump() {
+			let active = conditionals
+				.last()
+				.is_none_or(|conditional| conditional.active);
+
+			match token.token() {
+				Token::CompilerDirective(CompilerDirective::Builtin(directive)) => {
+					match directive {
+						BuiltinDirective::IfDef | BuiltinDirective::IfNotDef => {
+							let defined = self.condition(&mut input, &token);
+							let condition = defined == (*directive == BuiltinDirective::IfDef);
+
+							conditionals.push(Conditional {
+								parent:    active,
+								taken:     active && condition,
+								active:    active && condition,
+								seen_else: false,
+								location:  token.position(),
+							});
+						},
+						BuiltinDirective::ElsIf => {
+							let defined = self.condition(&mut input, &token);
+
+							match conditionals.last_mut() {
+								Some(conditional) if !conditional.seen_else => {
+									conditional.active =
+										conditional.parent && !conditional.taken && defined;
+									conditional.taken |= conditional.active;
+								},
+								Some(_) => self.error(
+									token.position(),
+									"`` `elsif `` after `` `else ``".to_string(),
+								),
+								None => self.error(
+									token.position(),
+									"`` `elsif `` without a matching `` `ifdef ``".to_string(),
+								),
+							}
+						},
+						BuiltinDirective::Else => match conditionals.last_mut() {
+							Some(conditional) if !conditional.seen_else => {
+								conditional.active = conditional.parent && !conditional.taken;
+								conditional.taken = true;
+								conditional.seen_else = true;
+							},
+							Some(conditional) => {
+								conditional.active = false;
+								self.error(token.position(), "Duplicate `` `else ``".to_string());
+							},
+							None => self.error(
+								token.position(),
+								"`` `else `` without a matching `` `ifdef ``".to_string(),
+							),
+						},
+						BuiltinDirective::EndIf => {
+							if conditionals.pop().is_none() {
+								self.error(
+									token.position(),
+									"`` `endif `` without a matching `` `ifdef ``".to_string(),
+								);
+							}
+						},
+						_ if !active => {},
+						BuiltinDirective::Define => self.define_directive(&mut input, &token),
+						BuiltinDirective::Undef => {
+							if let Some(name) = self.directive_name(&mut input, &token) {
+								self.macros.remove(&name);
+							}
+						},
+						BuiltinDirective::UndefineAll => {
+							self.macros
+								.retain(|_, definition| definition.origin == MacroOrigin::Builtin);
+						},
+						BuiltinDirective::Include => {
+							self.include_directive(&mut input, &token, file, output);
+						},
+						BuiltinDirective::Line => self.line_directive(&mut input, &token, file),
+						_ => {
+							output.push(token);
+							output.extend(input.rest_of_line());
+						},
+					}
+				},
+				_ if !active => {},
+				Token::CompilerDirective(CompilerDirective::TextMacro(text_macro)) => {
+					let text_macro = text_macro.clone();
+					self.expand(&mut input, &token, &text_macro, file, output);
+				},
+				_ => output.push(token),
+			}
+		}
+
+		for conditional in conditionals {
+			self.error(
+				conditional.location,
+				"Missing `` `endif `` for conditional".to_string(),
+			);
+		}
+	}
+
+	/// Read the macro name of a conditional directive, returning true if it is defined
+	fn condition(&mut self, input: &mut Input, directive: &PreprocessedToken) -> bool {
+		self.directive_name(input, directive)
+			.is_some_and(|name| self.macros.contains_key(&name))
+	}
+
+	/// Read the macro name following a directive
+	fn directive_name(
+		&mut self,
+		input: &mut Input,
+		directive: &PreprocessedToken,
+	) -> Option<String> {
+		input.skip_whitespace();
+
+		match input.peek().map(PreprocessedToken::token) {
+			Some(Token::Identifier(_) | Token::Keyword(_)) => input
+				.bump()
+				.map(|name| String::from_utf8_lossy(name.text()).into_owned()),
+			_ => {
+				self.error(
+					directive.position(),
+					format!(
+						"Expected a macro name after `{}`",
+						directive_text(directive)
+					),
+				);
+				None
+			},
+		}
+	}
+
+	/// IEEE 1800-2017 § 22.5.1
+	fn define_directive(&mut self, input: &mut Input, directive: &PreprocessedToken) {
+		input.skip_whitespace();
+
+		let name = match input.peek() {
+			Some(name) if matches!(name.token(), Token::Identifier(_) | Token::Keyword(_)) => {
+				name.clone()
+			},
+			_ => {
+				self.error(
+					directive.position(),
+					"Expected a macro name after `` `define ``".to_string(),
+				);
+				input.rest_of_line();
+				return;
+			},
+		};
+		input.bump();
+
+		// NOTE(aki): The argument list must immediately follow the name, otherwise it's the body
+		let arguments = match input.peek() {
+			Some(paren)
+				if paren.is_control(Control::ParenOpen) &&
+					paren.span().begin() == name.span().end() =>
+			{
+				input.bump();
+				match self.formal_arguments(input, directive) {
+					Some(arguments) => Some(arguments),
+					None => {
+						input.rest_of_line();
+						return;
+					},
+				}
+			},
+			_ => None,
+		};
+
+		let body = macro_text(&input.rest_of_line());
+
+		self.macros.insert(
+			String::from_utf8_lossy(name.text()).into_owned(),
+			Macro {
+				arguments,
+				body,
+				origin: MacroOrigin::Source(name.position()),
+			},
+		);
+	}
+
+	/// Parse the formal arguments of a macro definition, after the opening `(`
+	fn formal_arguments(
+		&mut self,
+		input: &mut Input,
+		directive: &PreprocessedToken,
+	) -> Option<Vec<MacroArgument>> {
+		let mut arguments = Vec::new();
+
+		loop {
+			input.skip_whitespace();
+
+			let name = match input.bump() {
+				Some(name) if matches!(name.token(), Token::Identifier(_)) => {
+					String::from_utf8_lossy(name.text()).into_owned()
+				},
+				// An empty argument list
+				Some(paren) if paren.is_control(Control::ParenClose) && arguments.is_empty() => {
+					return Some(arguments);
+				},
+				_ => {
+					self.error(
+						directive.position(),
+						"Expected a macro argument name".to_string(),
+					);
+					return None;
+				},
+			};
+
+			input.skip_whitespace();
+			let (default, end) = if input
+				.peek()
+				.is_some_and(|token| matches!(token.token(), Token::Operator(Operator::Equals)))
+			{
+				input.bump();
+				let (tokens, end) = input.balanced_until_comma();
+				(Some(macro_text(&tokens)), end)
+			} else {
+				(None, input.bump())
+			};
+
+			arguments.push(MacroArgument { name, default });
+
+			match end {
+				Some(comma) if comma.is_control(Control::Comma) => {},
+				Some(paren) if paren.is_control(Control::ParenClose) => return Some(arguments),
+				_ => {
+					self.error(
+						directive.position(),
+						"Expected `,` or `)` in macro argument list".to_string(),
+					);
+					return None;
+				},
+			}
+		}
+	}
+
+	/// Expand a text macro usage
+	///
+	/// IEEE 1800-2017 § 22.5.1
+	fn expand(
+		&mut self,
+		input: &mut Input,
+		usage: &PreprocessedToken,
+		text_macro: &TextMacro,
+		file: &mut FileState,
+		output: &mut Vec<PreprocessedToken>,
+	) {
+		match text_macro {
+			TextMacro::Builtin(BuiltinTextMacro::DunderFile) => {
+				let name = match &file.name {
+					Some(name) => name.clone(),
+					None => self
+						.file(file.id)
+						.map(|source| source.path.display().to_string())
+						.unwrap_or_default(),
+				};
+				let text = format!("\"{name}\"");
+				let location = self.builtin_expansion(usage, text_macro);
+
+				output.push(PreprocessedToken {
+					location: location.with_inner(Token::SingleQuotedString(
+						SingleQuotedString::new(AtomicByteTendril::from_slice(name.as_bytes())),
+					)),
+					text:     AtomicByteTendril::from_slice(text.as_bytes()),
+				});
+			},
+			TextMacro::Builtin(BuiltinTextMacro::DunderLine) => {
+				let line = (i64::from(*usage.span().line()) + 1 + file.line_offset).to_string();
+				let location = self.builtin_expansion(usage, text_macro);
+
+				output.push(PreprocessedToken {
+					location: location.with_inner(Token::UnsignedNumber(
+						AtomicByteTendril::from_slice(line.as_bytes()),
+					)),
+					text:     AtomicByteTendril::from_slice(line.as_bytes()),
+				});
+			},
+			_ => {
+				let name = text_macro.as_str().to_string();
+				let Some(definition) = self.macros.get(&name).cloned() else {
+					self.error(usage.position(), format!("Undefined macro `{name}`"));
+					return;
+				};
+
+				if self.expanding.contains(&name) {
+					self.error(
+						usage.position(),
+						format!("Recursive expansion of macro `{name}`"),
+					);
+					return;
+				}
+
+				let mut span = *usage.span();
+				let mut actuals = Vec::new();
+
+				if let Some(formals) = &definition.arguments {
+					let Some((arguments, end)) = self.actual_arguments(input, usage, &name) else {
+						return;
+					};
+					span = Span::from_position(
+						*span.begin(),
+						(*end.end()).max(*span.end()),
+						span.get_position(),
+					);
+
+					// NOTE(aki): `FOO()` passes a single empty argument, which is fine for a
+					// macro that takes none
+					let count = if formals.is_empty() && arguments.iter().all(Vec::is_empty) {
+						0
+					} else {
+						arguments.len()
+					};
+
+					if count > formals.len() {
+						self.error(
+							usage.position(),
+							format!(
+								"Too many arguments for macro `{name}`, expected {} but found {}",
+								formals.len(),
+								count
+							),
+						);
+						return;
+					}
+
+					for (idx, formal) in formals.iter().enumerate() {
+						match (arguments.get(idx), &formal.default) {
+							(Some(actual), _) if !actual.is_empty() => actuals.push(actual.clone()),
+							(_, Some(default)) => actuals.push(default.clone()),
+							(Some(actual), None) => actuals.push(actual.clone()),
+							(None, None) => {
+								self.error(
+									usage.position(),
+									format!(
+										"Missing argument `{}` for macro `{name}`",
+										formal.name
+									),
+								);
+								return;
+							},
+						}
+					}
+				}
+
+				let call_site = Location::new(usage.file_id(), (), span)
+					.with_expansion(usage.location.expansion());
+				let definition_site = match definition.origin {
+					MacroOrigin::Source(location) => Some(location),
+					MacroOrigin::Builtin | MacroOrigin::External => None,
+				};
+				let expansion =
+					self.expansions
+						.push(Expansion::new(name.as_str(), call_site, definition_site));
+
+				let text = substitute(&definition.body, &definition.argument_names(), &actuals);
+				let tokens =
+					self.tokenize_expansion(text, call_site.with_expansion(Some(expansion)));
+
+				self.expanding.push(name);
+				self.process(tokens, file, output);
+				self.expanding.pop();
+			},
+		}
+	}
+
+	/// Record the expansion of `` `__FILE__ `` or `` `__LINE__ ``, returning the location for the
+	/// token it produces
+	fn builtin_expansion(
+		&mut self,
+		usage: &PreprocessedToken,
+		text_macro: &TextMacro,
+	) -> Location<()> {
+		let expansion =
+			self.expansions
+				.push(Expansion::new(text_macro.as_str(), usage.position(), None));
+
+		usage.position().with_expansion(Some(expansion))
+	}
+
+	/// Read the actual arguments of a macro usage, returning them along with the closing `)`
+	fn actual_arguments(
+		&mut self,
+		input: &mut Input,
+		usage: &PreprocessedToken,
+		name: &str,
+	) -> Option<(Vec<Vec<u8>>, Span)> {
+		let start = input.position;
+		input.skip_trivia();
+
+		if !input
+			.peek()
+			.is_some_and(|token| token.is_control(Control::ParenOpen))
+		{
+			input.position = start;
+			self.error(
+				usage.position(),
+				format!("Expected arguments for macro `{name}`"),
+			);
+			return None;
+		}
+		input.bump();
+
+		let mut arguments = Vec::new();
+		loop {
+			let (tokens, end) = input.balanced_until_comma();
+			arguments.push(macro_text(&tokens));
+
+			match end {
+				Some(comma) if comma.is_control(Control::Comma) => {},
+				Some(paren) => return Some((arguments, *paren.span())),
+				None => {
+					self.error(
+						usage.position(),
+						format!("Unterminated argument list for macro `{name}`"),
+					);
+					return None;
+				},
+			}
+		}
+	}
+
+	/// IEEE 1800-2017 § 22.4
+	fn include_directive(
+		&mut self,
+		input: &mut Input,
+		directive: &PreprocessedToken,
+		file: &FileState,
+		output: &mut Vec<PreprocessedToken>,
+	) {
+		input.skip_whitespace();
+
+		let (name, system) = match input.peek() {
+			Some(token) if matches!(token.token(), Token::SingleQuotedString(_)) => {
+				let text = token.text();
+				let name = text
+					.get(1..text.len().saturating_sub(1))
+					.unwrap_or_default();
+
+				(String::from_utf8_lossy(name).into_owned(), false)
+			},
+			Some(token) if &token.text()[..] == b"<" => {
+				let mut name = Vec::new();
+				let mut closed = false;
+
+				input.bump();
+				while let Some(token) = input.peek() {
+					if token.is_newline() {
+						break;
+					}
+					if &token.text()[..] == b">" {
+						closed = true;
+						break;
+					}
+
+					name.extend_from_slice(token.text());
+					input.bump();
+				}
+
+				if !closed {
+					self.error(
+						directive.position(),
+						"Expected a `>` to close the file name".to_string(),
+					);
+					return;
+				}
+
+				(String::from_utf8_lossy(&name).into_owned(), true)
+			},
+			_ => {
+				self.error(
+					directive.position(),
+					"Expected a file name after `` `include ``".to_string(),
+				);
+				input.rest_of_line();
+				return;
+			},
+		};
+		input.bump();
+
+		if file.depth >= MAX_INCLUDE_DEPTH {
+			self.error(
+				directive.position(),
+				format!("`` `include `` nested more than {MAX_INCLUDE_DEPTH} deep"),
+			);
+			return;
+		}
+
+		let Some(path) = self.find_include(&name, system, file.id) else {
+			self.error(
+				directive.position(),
+				format!("Unable to find include file `{name}`"),
+			);
+			return;
+		};
+
+		let content = match self.overrides.get(&path) {
+			Some(content) => content.clone(),
+			None => match fs::read(&path) {
+				Ok(content) => content.as_slice().into(),
+				Err(err) => {
+					self.error(
+						directive.position(),
+						format!("Unable to read include file `{}`: {err}", path.display()),
+					);
+					return;
+				},
+			},
+		};
+
+		let id = self.add_file(path, content, Some(directive.position()));
+		let tokens = match self.tokenize_file(id) {
+			Ok(tokens) => tokens,
+			Err(err) => {
+				self.error(
+					directive.position(),
+					format!("Unable to read include file `{name}`: {err}"),
+				);
+				return;
+			},
+		};
+
+		let mut included = FileState {
+			id,
+			depth: file.depth + 1,
+			line_offset: 0,
+			name: None,
+		};
+		self.process(tokens, &mut included, output);
+	}
+
+	/// Find an `` `include `` file, files in `"quotes"` are looked for relative to the including
+	/// file first, then in the include paths, and files in `<brackets>` only in the include paths
+	fn find_include(&self, name: &str, system: bool, from: FileId) -> Option<PathBuf> {
+		let relative = if system {
+			None
+		} else {
+			self.file(from)
+				.and_then(|file| file.path.parent())
+				.map(|dir| dir.join(name))
+		};
+
+		relative
+			.into_iter()
+			.chain(self.include_paths.iter().map(|dir| dir.join(name)))
+			.find(|path| self.overrides.contains_key(path) || path.is_file())
+	}
+
+	/// IEEE 1800-2017 § 22.12
+	fn line_directive(
+		&mut self,
+		input: &mut Input,
+		directive: &PreprocessedToken,
+		file: &mut FileState,
+	) {
+		let tokens = input.rest_of_line();
+		let mut tokens = tokens
+			.iter()
+			.filter(|token| !token.is_whitespace() && !matches!(token.token(), Token::Comment(_)));
+
+		let line = tokens.next().and_then(|token| match token.token() {
+			Token::UnsignedNumber(number) => str::from_utf8(number).ok()?.parse::<i64>().ok(),
+			_ => None,
+		});
+		let name = tokens.next().and_then(|token| match token.token() {
+			Token::SingleQuotedString(name) => Some(name.as_str().to_string()),
+			_ => None,
+		});
+
+		match (line, name) {
+			(Some(line), Some(name)) => {
+				// The line number given is that of the line following the directive
+				file.line_offset = line - (i64::from(*directive.span().line()) + 2);
+				file.name = Some(name);
+			},
+			_ => self.error(
+				directive.position(),
+				"Expected a line number and file name after `` `line ``".to_string(),
+			),
+		}
+	}
+
+	fn error(&mut self, location: Location<()>, message: String) {
+		self.diagnostics
+			.push(location.with_inner(StringDiagnostic::new(
+				preprocessor_error_code(self.std),
+				message,
+				Some(*location.span()),
+			)));
+	}
+}
+
+impl Input {
+	fn peek(&self) -> Option<&PreprocessedToken> {
+		self.tokens.get(self.position)
+	}
+
+	fn bump(&mut self) -> Option<PreprocessedToken> {
+		let token = self.tokens.get(self.position).cloned();
+		if token.is_some() {
+			self.position += 1;
+		}
+		token
+	}
+
+	fn skip_whitespace(&mut self) {
+		while self.peek().is_some_and(PreprocessedToken::is_whitespace) {
+			self.position += 1;
+		}
+	}
+
+	/// Skip whitespace, newlines, and comments
+	fn skip_trivia(&mut self) {
+		while self.peek().is_some_and(|token| {
+			matches!(
+				token.token(),
+				Token::Whitespace(_) | Token::Newline(_) | Token::Comment(_)
+			)
+		}) {
+			self.position += 1;
+		}
+	}
+
+	/// Take the tokens up to the end of the line, honoring `\` line continuations, the newline
+	/// itself is left in the input
+	fn rest_of_line(&mut self) -> Vec<PreprocessedToken> {
+		let mut tokens = Vec::new();
+
+		while let Some(token) = self.peek() {
+			if token.is_newline() &&
+				!tokens.last().is_some_and(|last: &PreprocessedToken| {
+					last.is_control(Control::ReverseSolidus)
+				}) {
+				break;
+			}
+
+			tokens.extend(self.bump());
+		}
+
+		tokens
+	}
+
+	/// Take the tokens up to the next `,` or `)` that is not nested in brackets, returning them
+	/// along with the `,` or `)`, which is consumed
+	fn balanced_until_comma(&mut self) -> (Vec<PreprocessedToken>, Option<PreprocessedToken>) {
+		let mut tokens = Vec::new();
+		let mut depth = 0usize;
+
+		while let Some(token) = self.bump() {
+			match token.token() {
+				Token::Control(Control::ParenOpen | Control::BracketOpen | Control::BraceOpen) => {
+					depth += 1;
+				},
+				Token::Control(Control::Comma | Control::ParenClose) if depth == 0 => {
+					return (tokens, Some(token));
+				},
+				Token::Control(
+					Control::ParenClose | Control::BracketClose | Control::BraceClose,
+				) => {
+					depth = depth.saturating_sub(1);
+				},
+				_ => {},
+			}
+
+			tokens.push(token);
+		}
+
+		(tokens, None)
+	}
+}
+
+/// Join the text of a sequence of tokens into macro text, line continuations become plain newlines
+/// and comments are dropped
+fn macro_text(tokens: &[PreprocessedToken]) -> Vec<u8> {
+	let mut text = Vec::new();
+	let mut tokens = tokens.iter().peekable();
+
+	while let Some(token) = tokens.next() {
+		match token.token() {
+			Token::Control(Control::ReverseSolidus)
+				if tokens.peek().is_some_and(|next| next.is_newline()) =>
+			{
+				text.push(b'\n');
+				tokens.next();
+			},
+			Token::Comment(_) => text.push(b' '),
+			_ => text.extend_from_slice(token.text()),
+		}
+	}
+
+	text.trim_ascii().to_vec()
+}
+
+/// Substitute the actual arguments of a macro usage into the macro text
+///
+/// ``` `` ``` joins the text either side of it, ``` `" ``` is replaced by a `"` with arguments
+/// still
+/// being substituted inside of it, and ``` `\`" ``` is replaced by `\"`. Arguments are not
+/// substituted inside of normal string literals.
+///
+/// IEEE 1800-2017 § 22.5.1
+fn substitute(body: &[u8], formals: &[&str], actuals: &[Vec<u8>]) -> Vec<u8> {
+	let mut text = Vec::with_capacity(body.len());
+	let mut idx = 0;
+
+	while let Some(&chr) = body.get(idx) {
+		let rest = &body[idx..];
+
+		if rest.starts_with(b"``") {
+			idx += 2;
+		} else if rest.starts_with(b"`\\`\"") {
+			text.extend_from_slice(b"\\\"");
+			idx += 4;
+		} else if rest.starts_with(b"`\"") {
+			text.push(b'"');
+			idx += 2;
+		} else if chr == b'"' {
+			// Copy string literals verbatim, up to the closing quote
+			let len = rest
+				.iter()
+				.enumerate()
+				.skip(1)
+				.scan(false, |escaped, (offset, &chr)| {
+					let end = !*escaped && chr == b'"';
+					*escaped = !*escaped && chr == b'\\';
+					Some((offset, end))
+				})
+				.find(|(_, end)| *end)
+				.map_or(rest.len(), |(offset, _)| offset + 1);
+
+			text.extend_from_slice(&rest[..len]);
+			idx += len;
+		} else if chr == b'\\' {
+			// Escaped identifiers run up to the next whitespace
+			let len = rest
+				.iter()
+				.position(u8::is_ascii_whitespace)
+				.unwrap_or(rest.len());
+
+			text.extend_from_slice(&rest[..len]);
+			idx += len;
+		} else if chr.is_ascii_alphabetic() || chr == b'_' {
+			let len = rest
+				.iter()
+				.position(|chr| !(chr.is_ascii_alphanumeric() || *chr == b'_' || *chr == b'$'))
+				.unwrap_or(rest.len());
+			let word = &rest[..len];
+
+			// Macro names and based number digits are never arguments
+			let literal = matches!(text.last(), Some(b'`' | b'\''));
+			match formals.iter().position(|formal| formal.as_bytes() == word) {
+				Some(formal) if !literal => {
+					text.extend_from_slice(actuals.get(formal).map_or(&[][..], Vec::as_slice));
+				},
+				_ => text.extend_from_slice(word),
+			}
+			idx += len;
+		} else if chr.is_ascii_digit() {
+			let len = rest
+				.iter()
+				.position(|chr| !(chr.is_ascii_alphanumeric() || *chr == b'_' || *chr == b'.'))
+				.unwrap_or(rest.len());
+
+			text.extend_from_slice(&rest[..len]);
+			idx += len;
+		} else {
+			text.push(chr);
+			idx += 1;
+		}
+	}
+
+	text
+}
+
+/// Whether `token` is a text macro usage or a directive that changes the text being compiled,
+/// that is anything the preprocessor doesn't pass through untouched
+pub(crate) fn changes_text(token: &Token) -> bool {
+	match token {
+		Token::CompilerDirective(CompilerDirective::TextMacro(_)) => true,
+		Token::CompilerDirective(CompilerDirective::Builtin(directive)) => matches!(
+			directive,
+			BuiltinDirective::Define |
+				BuiltinDirective::Undef |
+				BuiltinDirective::UndefineAll |
+				BuiltinDirective::IfDef |
+				BuiltinDirective::IfNotDef |
+				BuiltinDirective::ElsIf |
+				BuiltinDirective::Else |
+				BuiltinDirective::EndIf |
+				BuiltinDirective::Include |
+				BuiltinDirective::Line
+		),
+		_ => false,
+	}
+}
+
+/// The text of a directive token for use in messages
+fn directive_text(directive: &PreprocessedToken) -> String {
+	String::from_utf8_lossy(directive.text()).into_owned()
+}
+
+/// The diagnostic code for preprocessor errors in `std`
+fn preprocessor_error_code(std: LanguageStd) -> Code {
+	if LanguageStd::SYSTEM_VERILOG_STDS.contains(std) {
+		system_verilog::E0004
+	} else if LanguageStd::VERILOG_AMS_STDS.contains(std) {
+		verilog_ams::E0003
+	} else {
+		verilog::E0003
+	}
+}
+
+#[cfg(test)]
+mod tests;
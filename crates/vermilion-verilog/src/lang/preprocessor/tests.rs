@@ -0,0 +1,428 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use tempfile::TempDir;
+use vermilion_diagnostics::Diagnostic;
+
+use super::*;
+
+/// Preprocess `input`, returning the text of the significant tokens produced and the messages of
+/// any diagnostics
+fn preprocess(preprocessor: &mut Preprocessor, input: &str) -> (Vec<String>, Vec<String>) {
+	let tokens = match preprocessor.preprocess("test.sv", input.as_bytes().into()) {
+		Ok(tokens) => tokens,
+		Err(err) => panic!("Failed to preprocess: {err}"),
+	};
+
+	let text = tokens
+		.iter()
+		.filter(|token| {
+			!matches!(
+				token.token(),
+				Token::Whitespace(_) | Token::Newline(_) | Token::Comment(_)
+			)
+		})
+		.map(|token| String::from_utf8_lossy(token.text()).into_owned())
+		.collect();
+	let diagnostics = preprocessor
+		.diagnostics()
+		.iter()
+		.map(|diagnostic| diagnostic.message().to_string())
+		.collect();
+
+	(text, diagnostics)
+}
+
+/// Create an empty scratch directory for tests that need real files, which is removed along with
+/// everything in it once dropped
+fn scratch_dir(name: &str) -> TempDir {
+	match tempfile::Builder::new()
+		.prefix(&format!("vermilion-preprocessor-{name}-"))
+		.tempdir()
+	{
+		Ok(dir) => dir,
+		Err(err) => panic!("Unable to create a scratch directory: {err}"),
+	}
+}
+
+fn write(path: &Path, content: &str) {
+	if let Err(err) = fs::write(path, content) {
+		panic!("Unable to write {}: {err}", path.display());
+	}
+}
+
+#[test]
+fn test_object_macro() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Vl05);
+	let (text, diagnostics) = preprocess(
+		&mut preprocessor,
+		"`define WIDTH 8 // not part of the macro\nwire [`WIDTH-1:0] a;",
+	);
+
+	assert_eq!(text, ["wire", "[", "8", "-", "1", ":", "0", "]", "a", ";"]);
+	assert!(diagnostics.is_empty());
+	assert!(matches!(
+		preprocessor
+			.get_macro("WIDTH")
+			.map(|definition| &definition.origin),
+		Some(MacroOrigin::Source(_))
+	));
+}
+
+#[test]
+fn test_multiline_macro() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Vl05);
+	let (text, _) = preprocess(
+		&mut preprocessor,
+		"`define DECL(name) \\\n\twire name; \\\n\treg name``_q;\n`DECL(a)",
+	);
+
+	assert_eq!(text, ["wire", "a", ";", "reg", "a_q", ";"]);
+}
+
+#[test]
+fn test_macro_arguments() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Sv17);
+	let (text, diagnostics) = preprocess(
+		&mut preprocessor,
+		concat!(
+			"`define ADD(a, b = 1) (a + b)\n",
+			"`ADD(x, y) `ADD(f(x, y), ) `ADD(z)\n",
+		),
+	);
+
+	assert_eq!(
+		text,
+		[
+			"(", "x", "+", "y", ")", "(", "f", "(", "x", ",", "y", ")", "+", "1", ")", "(", "z",
+			"+", "1", ")"
+		]
+	);
+	assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn test_macro_stringify() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Sv17);
+	let (text, _) = preprocess(
+		&mut preprocessor,
+		"`define MSG(x) `\"x is `\\`\"x`\\`\"`\" \"x\"\n`MSG(foo)",
+	);
+
+	assert_eq!(text, ["\"foo is \\\"foo\\\"\"", "\"x\""]);
+}
+
+#[test]
+fn test_nested_macros() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Vl05);
+	let (text, _) = preprocess(
+		&mut preprocessor,
+		"`define A 1\n`define B(x) (x + `A)\n`define A 2\n`B(`A)",
+	);
+
+	assert_eq!(text, ["(", "2", "+", "2", ")"]);
+}
+
+#[test]
+fn test_macro_errors() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Sv17);
+	let (text, diagnostics) = preprocess(
+		&mut preprocessor,
+		concat!(
+			"`define LOOP `LOOP\n",
+			"`define F(a, b) a\n",
+			"`UNDEFINED `LOOP `F(1) `F(1, 2, 3) `F\n",
+			"ok\n",
+		),
+	);
+
+	assert_eq!(text, ["ok"]);
+	assert_eq!(
+		diagnostics,
+		[
+			"Undefined macro `UNDEFINED`",
+			"Recursive expansion of macro `LOOP`",
+			"Missing argument `b` for macro `F`",
+			"Too many arguments for macro `F`, expected 2 but found 3",
+			"Expected arguments for macro `F`",
+		]
+	);
+}
+
+#[test]
+fn test_expansion_locations() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Sv17);
+	preprocessor.define("EXTERNAL", "x");
+
+	let tokens = match preprocessor.preprocess(
+		"test.sv",
+		"`define INNER `EXTERNAL\n`define OUTER(a) a + `INNER\nassign y = `OUTER(b);\n"
+			.as_bytes()
+			.into(),
+	) {
+		Ok(tokens) => tokens,
+		Err(err) => panic!("Failed to preprocess: {err}"),
+	};
+
+	let find = |text: &[u8]| {
+		tokens
+			.iter()
+			.find(|token| &token.text()[..] == text)
+			.unwrap_or_else(|| panic!("No `{}` token", String::from_utf8_lossy(text)))
+	};
+
+	// Tokens written in the source have no expansion
+	assert!(!find(b"assign").location().is_expanded());
+
+	// Tokens from a macro body are located at the usage, along with the full expansion stack
+	let usage = Span::new(63, 72, 2, 11);
+	let x = find(b"x");
+	assert_eq!(*x.span(), usage);
+	assert_eq!(
+		preprocessor.expansion_notes(x.location()),
+		[
+			"in expansion of macro `EXTERNAL`",
+			"in expansion of macro `INNER` defined at test.sv:1",
+			"in expansion of macro `OUTER` defined at test.sv:2",
+		]
+	);
+	assert_eq!(
+		*preprocessor
+			.expansions()
+			.source_location(x.location())
+			.span(),
+		usage
+	);
+
+	// The usage of a macro leads back to its definition
+	let definition = preprocessor
+		.definition_at(x.file_id(), 66)
+		.unwrap_or_else(|| panic!("No macro usage found"));
+	assert_eq!(*definition.span(), Span::new(32, 37, 1, 8));
+	assert!(preprocessor.definition_at(x.file_id(), 60).is_none());
+}
+
+#[test]
+fn test_conditionals() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Vl05);
+	preprocessor.define("B", "");
+
+	let (text, diagnostics) = preprocess(
+		&mut preprocessor,
+		concat!(
+			"`ifdef A a `elsif B b `else c `endif\n",
+			"`ifndef A\n",
+			"  `ifdef B d `else e `endif\n",
+			"`else\n",
+			"  `ifdef B f `endif\n",
+			"`endif\n",
+			"`ifdef A `define X `endif\n",
+			"`ifdef X g `else h `endif\n",
+		),
+	);
+
+	assert_eq!(text, ["b", "d", "h"]);
+	assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn test_conditional_errors() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Vl05);
+	let (text, diagnostics) = preprocess(&mut preprocessor, "`endif\n`ifdef A a `else b `else c\n");
+
+	assert_eq!(text, ["b"]);
+	assert_eq!(
+		diagnostics,
+		[
+			"`` `endif `` without a matching `` `ifdef ``",
+			"Duplicate `` `else ``",
+			"Missing `` `endif `` for conditional",
+		]
+	);
+}
+
+#[test]
+fn test_undef() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Sv17);
+	preprocessor.define("EXTERNAL", "1");
+
+	let (text, _) = preprocess(
+		&mut preprocessor,
+		concat!(
+			"`define A\n`define B\n`undef A\n",
+			"`ifdef A a `endif `ifdef B b `endif\n",
+			"`undefineall\n",
+			"`ifdef B b `endif `ifdef EXTERNAL e `endif\n",
+		),
+	);
+
+	assert_eq!(text, ["b"]);
+	assert!(!preprocessor.is_defined("EXTERNAL"));
+}
+
+#[test]
+fn test_builtin_macros() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Sv17);
+	let (text, _) = preprocess(
+		&mut preprocessor,
+		"`__FILE__\n`__LINE__\n`line 100 \"other.sv\" 0\n`__LINE__ `__FILE__",
+	);
+
+	assert_eq!(text, ["\"test.sv\"", "2", "100", "\"other.sv\""]);
+}
+
+#[test]
+fn test_verilog_ams_predefined() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Vams14);
+	let (text, _) = preprocess(&mut preprocessor, "`ifdef __VAMS_ENABLE__ ams `endif");
+
+	assert_eq!(text, ["ams"]);
+}
+
+#[test]
+fn test_passthrough_directives() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Vl05);
+	let (text, _) = preprocess(
+		&mut preprocessor,
+		"`timescale 1ns / 1ps\n`resetall\nmodule m; endmodule",
+	);
+
+	assert_eq!(
+		text,
+		[
+			"`timescale",
+			"1",
+			"ns",
+			"/",
+			"1",
+			"ps",
+			"`resetall",
+			"module",
+			"m",
+			";",
+			"endmodule"
+		]
+	);
+}
+
+#[test]
+fn test_include() {
+	let scratch = scratch_dir("include");
+	let dir = scratch.path();
+	let incdir = dir.join("inc");
+	if let Err(err) = fs::create_dir_all(&incdir) {
+		panic!("Unable to create {}: {err}", incdir.display());
+	}
+
+	write(&dir.join("local.vh"), "`define LOCAL 1\nlocal\n");
+	write(&incdir.join("defs.vh"), "`include \"nested.vh\"\ndefs\n");
+	write(&incdir.join("nested.vh"), "nested `LOCAL\n");
+	write(
+		&dir.join("top.v"),
+		"`include \"local.vh\"\n`include \"defs.vh\"\n`include \"missing.vh\"\ntop\n",
+	);
+
+	let mut preprocessor = Preprocessor::new(LanguageStd::Vl05);
+	preprocessor.add_include_path(&incdir);
+
+	let tokens = match preprocessor.preprocess_file(dir.join("top.v")) {
+		Ok(tokens) => tokens,
+		Err(err) => panic!("Failed to preprocess: {err}"),
+	};
+	let text = tokens
+		.iter()
+		.filter(|token| {
+			matches!(
+				token.token(),
+				Token::Identifier(_) | Token::UnsignedNumber(_)
+			)
+		})
+		.map(|token| String::from_utf8_lossy(token.text()).into_owned())
+		.collect::<Vec<_>>();
+
+	assert_eq!(text, ["local", "nested", "1", "defs", "top"]);
+	assert_eq!(preprocessor.files().len(), 4);
+	assert_eq!(
+		preprocessor
+			.diagnostics()
+			.iter()
+			.map(|diagnostic| diagnostic.message())
+			.collect::<Vec<_>>(),
+		["Unable to find include file `missing.vh`"]
+	);
+
+	// Tokens from included files are located in them, with the chain of includes leading there
+	let nested = tokens
+		.iter()
+		.find(|token| &token.text()[..] == b"nested")
+		.map(PreprocessedToken::file_id)
+		.unwrap_or_else(|| panic!("No `nested` token"));
+	assert_eq!(
+		preprocessor.file(nested).map(SourceFile::path),
+		Some(incdir.join("nested.vh").as_path())
+	);
+
+	let chain = preprocessor.include_chain(nested);
+	assert_eq!(chain.len(), 2);
+	assert_eq!(*chain[0].line(), 0);
+	assert_eq!(
+		preprocessor.file(*chain[1].file_id()).map(SourceFile::path),
+		Some(dir.join("top.v").as_path())
+	);
+	assert_eq!(*chain[1].line(), 1);
+
+	// The source map of a file only has the usages written in it, with the paths of the files
+	// the macros were defined in
+	let top = preprocessor.source_map(FileId::new(0usize));
+	assert_eq!(top.usages().count(), 0);
+	assert_eq!(
+		top.includes().collect::<Vec<_>>(),
+		[
+			dir.join("local.vh"),
+			incdir.join("defs.vh"),
+			incdir.join("nested.vh"),
+		]
+	);
+
+	let source_map = preprocessor.source_map(nested);
+	let usage = source_map
+		.usage_at(8)
+		.unwrap_or_else(|| panic!("No macro usage at `LOCAL"));
+	assert_eq!(usage.name(), "LOCAL");
+	assert_eq!(
+		usage
+			.definition()
+			.and_then(|definition| source_map.path(*definition.file_id())),
+		Some(dir.join("local.vh").as_path())
+	);
+	assert_eq!(source_map.usages().count(), 1);
+}
+
+#[test]
+fn test_include_override() {
+	let mut preprocessor = Preprocessor::new(LanguageStd::Vl05);
+	preprocessor.add_include_path("/unsaved");
+	preprocessor.override_file("/unsaved/defs.vh", "`define WIDTH 8\n".as_bytes().into());
+
+	let (text, diagnostics) = preprocess(&mut preprocessor, "`include \"defs.vh\"\n`WIDTH\n");
+	assert_eq!(text, ["8"]);
+	assert!(diagnostics.is_empty(), "{diagnostics:#?}");
+}
+
+#[test]
+fn test_recursive_include() {
+	let scratch = scratch_dir("recursive");
+	let dir = scratch.path();
+	write(&dir.join("self.vh"), "`include \"self.vh\"\n");
+
+	let mut preprocessor = Preprocessor::new(LanguageStd::Vl05);
+	if let Err(err) = preprocessor.preprocess_file(dir.join("self.vh")) {
+		panic!("Failed to preprocess: {err}");
+	}
+
+	assert_eq!(preprocessor.files().len(), MAX_INCLUDE_DEPTH + 1);
+	assert!(
+		preprocessor.diagnostics()[0]
+			.message()
+			.contains("nested more than")
+	);
+}
@@ -0,0 +1,1712 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::{FileId, Location, Span, Spanned};
+
+use crate::lang::{
+	ast::{
+		Assertion, AssertionPort, AssertionPortType, Assignment, BinsItem, BinsSelect, BinsValues,
+		Block, BranchTerminal, CaseItem, CastTarget, Class, ClassType, ClockingBlock, ClockingItem,
+		ClockingSkew, Connections, ConstraintItem, CoverGroup, CoverageEvent, CoverageItem,
+		CycleRange, DataType, Declarator, Delay, Description, Dimension, DisciplineItem,
+		DistWeight, DpiDirection, EventControl, Expression, Function, GenerateBlock, Instantiation,
+		Item, ItemKind, Module, ModuleKeyword, PackageImport, ParameterDeclaration, PathCondition,
+		PatternItem, PortDeclaration, Ports, PropertyExpression, PropertySpec, Select, SpecifyItem,
+		SpecparamValue, Statement, StatementKind, SubroutineKind, SubroutinePrototype, Task,
+		TimingControl, ValueRangeBounds,
+	},
+	semantic::{
+		Import, Prefix, Reference, ReferenceId, ReferenceKind, ScopeId, ScopeKind, Symbol,
+		SymbolId, SymbolKind, SymbolTable,
+	},
+	types::Identifier,
+};
+
+/// Declares the symbols of a single source file and collects its references
+pub(super) struct Collector<'a> {
+	table: &'a mut SymbolTable,
+	file:  FileId,
+	scope: ScopeId,
+}
+
+impl<'a> Collector<'a> {
+	pub(super) fn new(table: &'a mut SymbolTable, file: FileId, scope: ScopeId) -> Self {
+		Self { table, file, scope }
+	}
+
+	fn location(&self, span: Span) -> Location<()> {
+		Location::new(self.file, (), span)
+	}
+
+	/// Declare `name` in the current scope
+	fn declare(&mut self, name: &Spanned<Identifier>, kind: SymbolKind) -> SymbolId {
+		self.declare_typed(name, kind, None)
+	}
+
+	fn declare_typed(
+		&mut self,
+		name: &Spanned<Identifier>,
+		kind: SymbolKind,
+		type_name: Option<ReferenceId>,
+	) -> SymbolId {
+		let symbol = Symbol {
+			name: name.inner().as_str().to_string(),
+			kind,
+			location: self.location(*name.span()),
+			scope: self.scope,
+			body: None,
+			type_name,
+			forward: false,
+		};
+
+		self.table.declare(symbol)
+	}
+
+	/// Run `f` in a new scope nested in the current one
+	fn scoped<F>(&mut self, kind: ScopeKind, name: Option<&Identifier>, span: Span, f: F) -> ScopeId
+	where
+		F: FnOnce(&mut Self),
+	{
+		let scope = self.table.push_scope(
+			kind,
+			name.map(|name| name.as_str().to_string()),
+			Some(self.scope),
+			self.location(span),
+		);
+
+		let parent = std::mem::replace(&mut self.scope, scope);
+		f(self);
+		self.scope = parent;
+
+		scope
+	}
+
+	/// Declare `name` in the current scope along with the new scope it introduces, running `f`
+	/// in that scope
+	fn declare_scoped<F>(
+		&mut self,
+		name: &Spanned<Identifier>,
+		kind: SymbolKind,
+		scope_kind: ScopeKind,
+		span: Span,
+		f: F,
+	) -> SymbolId
+	where
+		F: FnOnce(&mut Self),
+	{
+		let symbol = self.declare(name, kind);
+		let body = self.scoped(scope_kind, Some(name.inner()), span, f);
+		self.table.symbols[symbol.0].body = Some(body);
+
+		symbol
+	}
+
+	fn reference(
+		&mut self,
+		name: &Spanned<Identifier>,
+		kind: ReferenceKind,
+		prefix: Option<Prefix>,
+	) -> ReferenceId {
+		self.push_reference(name.inner(), *name.span(), kind, prefix, false)
+	}
+
+	fn push_reference(
+		&mut self,
+		name: &Identifier,
+		span: Span,
+		kind: ReferenceKind,
+		prefix: Option<Prefix>,
+		upward: bool,
+	) -> ReferenceId {
+		self.table.push_reference(Reference {
+			name: name.as_str().to_string(),
+			kind,
+			location: self.location(span),
+			scope: self.scope,
+			target: None,
+			prefix,
+			upward,
+		})
+	}
+
+	pub(super) fn descriptions(&mut self, descriptions: &[Spanned<Description>]) {
+		for description in descriptions {
+			let span = *description.span();
+
+			match description.inner() {
+				Description::Module(module) => self.module(module, span),
+				Description::Primitive(udp) => {
+					self.declare_scoped(
+						&udp.name,
+						SymbolKind::Primitive,
+						ScopeKind::Primitive,
+						span,
+						|this| {
+							this.items(&udp.declarations);
+							if let Some(initial) = &udp.initial {
+								let initial = initial.inner();
+								this.reference(&initial.name, ReferenceKind::Name, None);
+								this.expression(&initial.value);
+							}
+						},
+					);
+				},
+				Description::Config(config) => {
+					self.declare(&config.name, SymbolKind::Config);
+					for cell in &config.design {
+						self.reference(&cell.inner().cell, ReferenceKind::Definition, None);
+					}
+				},
+				Description::Package(package) => {
+					self.declare_scoped(
+						&package.name,
+						SymbolKind::Package,
+						ScopeKind::Package,
+						span,
+						|this| this.items(&package.items),
+					);
+				},
+				Description::Class(class) => self.class(class, span),
+				Description::Item(item) => self.item(item, span),
+				Description::Nature(nature) => {
+					self.declare(&nature.name, SymbolKind::Nature);
+					if let Some(parent) = &nature.parent {
+						self.nature_reference(parent);
+					}
+
+					for attribute in &nature.attributes {
+						let attribute = attribute.inner();
+						match attribute.value.inner() {
+							Expression::Identifier(name)
+								if attribute.name.inner().as_str() == "access" =>
+							{
+								let name = Spanned::new(name.clone(), *attribute.value.span());
+								self.declare(&name, SymbolKind::AccessFunction);
+							},
+							_ => self.nature_reference(&attribute.value),
+						}
+					}
+				},
+				Description::Discipline(discipline) => {
+					self.declare(&discipline.name, SymbolKind::Discipline);
+					for item in &discipline.items {
+						match item.inner() {
+							DisciplineItem::Binding { nature, .. } => {
+								self.reference(nature, ReferenceKind::Nature, None);
+							},
+							DisciplineItem::Attribute { attribute, .. } => {
+								self.nature_reference(&attribute.value);
+							},
+							DisciplineItem::Domain(_) => {},
+						}
+					}
+				},
+				Description::Error => {},
+			}
+		}
+	}
+
+	/// A reference to a nature or discipline, such as the parent of a nature or the value of an
+	/// `idt_nature` attribute
+	fn nature_reference(&mut self, expression: &Spanned<Expression>) {
+		match expression.inner() {
+			Expression::Identifier(name) => {
+				self.push_reference(name, *expression.span(), ReferenceKind::Nature, None, false);
+			},
+			// `electrical.potential`, the discipline is all that can be resolved
+			Expression::Member { target, .. } => self.nature_reference(target),
+			_ => self.expression(expression),
+		}
+	}
+
+	fn module(&mut self, module: &Module, span: Span) {
+		let (kind, scope_kind) = match module.keyword {
+			ModuleKeyword::Module | ModuleKeyword::MacroModule => {
+				(SymbolKind::Module, ScopeKind::Module)
+			},
+			ModuleKeyword::Interface => (SymbolKind::Interface, ScopeKind::Interface),
+			ModuleKeyword::Program => (SymbolKind::Program, ScopeKind::Program),
+		};
+
+		self.declare_scoped(&module.name, kind, scope_kind, span, |this| {
+			this.imports(&module.imports);
+
+			if let Some(parameters) = &module.parameters {
+				for parameter in parameters {
+					this.parameter(parameter.inner());
+				}
+			}
+
+			match &module.ports {
+				Ports::None => {},
+				Ports::NonAnsi(ports) => {
+					for port in ports {
+						let port = port.inner();
+
+						// `.name(expression)` names the port differently to the nets it
+						// connects to
+						if let Some(name) = &port.name {
+							let internal = port
+								.expression
+								.as_ref()
+								.and_then(|expression| expression.inner().as_identifier());
+							if internal != Some(name.inner()) {
+								this.declare(name, SymbolKind::Port);
+							}
+						}
+
+						if let Some(expression) = &port.expression {
+							this.expression(expression);
+						}
+					}
+				},
+				Ports::Ansi(ports) => {
+					for port in ports {
+						this.port(port.inner());
+					}
+				},
+			}
+
+			this.items(&module.items);
+		});
+	}
+
+	fn class(&mut self, class: &Class, span: Span) {
+		// The base classes are named in the scope enclosing the class
+		let bases = class
+			.extends
+			.iter()
+			.chain(&class.implements)
+			.filter_map(|base| self.class_type(base.inner()))
+			.collect::<Vec<_>>();
+
+		if let Some(arguments) = &class.arguments {
+			self.expressions(arguments);
+		}
+
+		self.declare_scoped(
+			&class.name,
+			SymbolKind::Class,
+			ScopeKind::Class,
+			span,
+			|this| {
+				this.table.scopes[this.scope.0].bases = bases;
+
+				if let Some(parameters) = &class.parameters {
+					for parameter in parameters {
+						this.parameter(parameter.inner());
+					}
+				}
+
+				for item in &class.items {
+					this.item(&item.inner().item, *item.span());
+				}
+			},
+		);
+	}
+
+	fn class_type(&mut self, class_type: &ClassType) -> Option<ReferenceId> {
+		if let Some(parameters) = &class_type.parameters {
+			self.connections(parameters.inner(), None, false);
+		}
+
+		self.type_path(&class_type.path)
+	}
+
+	/// A reference to a type named by `path`, e.g. `pkg::cls::inner_t`
+	fn type_path(&mut self, path: &[Spanned<Identifier>]) -> Option<ReferenceId> {
+		let (last, prefix) = path.split_last()?;
+		if prefix.is_empty() {
+			return Some(self.reference(last, ReferenceKind::Type, None));
+		}
+
+		let mut reference = self.reference(&prefix[0], ReferenceKind::ScopePrefix, None);
+		for name in prefix[1..].iter().chain(std::iter::once(last)) {
+			reference = self.reference(
+				name,
+				ReferenceKind::ScopeMember,
+				Some(Prefix::Reference(reference)),
+			);
+		}
+
+		Some(reference)
+	}
+
+	fn imports(&mut self, imports: &[Spanned<PackageImport>]) {
+		for import in imports {
+			let import = import.inner();
+			let package = import.package.inner().as_str();
+
+			// `export *::*;`
+			if package == "*" {
+				continue;
+			}
+
+			let reference = self.reference(&import.package, ReferenceKind::Package, None);
+			let item = import
+				.item
+				.as_ref()
+				.filter(|item| item.inner().as_str() != "*");
+
+			if let Some(item) = item {
+				self.reference(
+					item,
+					ReferenceKind::ScopeMember,
+					Some(Prefix::Reference(reference)),
+				);
+			}
+
+			self.table.scopes[self.scope.0].imports.push(Import {
+				package: package.to_string(),
+				item:    item.map(|item| item.inner().as_str().to_string()),
+			});
+		}
+	}
+
+	fn items(&mut self, items: &[Spanned<Item>]) {
+		for item in items {
+			self.item(item.inner(), *item.span());
+		}
+	}
+
+	fn item(&mut self, item: &Item, span: Span) {
+		match &item.kind {
+			ItemKind::Port(port) => self.port(port),
+			ItemKind::Net(net) => {
+				let type_name = self.data_type(&net.data_type);
+				self.delay(net.delay.as_ref());
+				self.declarators(&net.declarators, SymbolKind::Net, type_name);
+			},
+			ItemKind::Variable(variable) => {
+				let type_name = self.data_type(&variable.data_type);
+				self.declarators(&variable.declarators, SymbolKind::Variable, type_name);
+			},
+			ItemKind::Parameter(parameter) => self.parameter(parameter),
+			ItemKind::Specparam(specparam) => {
+				self.dimension(specparam.range.as_ref());
+				for assignment in &specparam.assignments {
+					let assignment = assignment.inner();
+					self.specparam_value(&assignment.value);
+					self.declare(&assignment.name, SymbolKind::Specparam);
+				}
+			},
+			ItemKind::Genvar(names) => {
+				for name in names {
+					self.declare(name, SymbolKind::Genvar);
+				}
+			},
+			ItemKind::Defparam(assignments) => {
+				for assignment in assignments {
+					self.assignment(assignment.inner());
+				}
+			},
+			ItemKind::ContinuousAssign(assign) => {
+				self.delay(assign.delay.as_ref());
+				for assignment in &assign.assignments {
+					let assignment = assignment.inner();
+					self.expression_as(&assignment.lvalue, ReferenceKind::ImplicitNet);
+					self.expression(&assignment.value);
+				}
+			},
+			ItemKind::Gate(gate) => {
+				self.delay(gate.delay.as_ref());
+				for instance in &gate.instances {
+					let instance = instance.inner();
+					if let Some(name) = &instance.name {
+						self.declare(name, SymbolKind::Instance);
+					}
+					self.dimension(instance.range.as_ref());
+
+					for terminal in &instance.terminals {
+						self.expression_as(terminal, ReferenceKind::ImplicitNet);
+					}
+				}
+			},
+			ItemKind::Instantiation(instantiation) => self.instantiation(instantiation, true),
+			ItemKind::Initial(statement) |
+			ItemKind::Always(statement) |
+			ItemKind::AlwaysComb(statement) |
+			ItemKind::AlwaysFF(statement) |
+			ItemKind::AlwaysLatch(statement) |
+			ItemKind::Final(statement) |
+			ItemKind::Analog(statement) |
+			ItemKind::AnalogInitial(statement) => self.statement(statement),
+			ItemKind::Task(task) => self.task(task, span),
+			ItemKind::Function(function) | ItemKind::AnalogFunction(function) => {
+				self.function(function, span);
+			},
+			ItemKind::GenerateRegion(items) => self.items(items),
+			ItemKind::GenerateFor(generate) => {
+				let init = generate.init.inner();
+
+				let block = generate.block.inner();
+				let body = |this: &mut Self| {
+					if generate.genvar {
+						if let Some(genvar) = init.lvalue.inner().as_identifier() {
+							let genvar = Spanned::new(genvar.clone(), *init.lvalue.span());
+							this.declare(&genvar, SymbolKind::Genvar);
+						}
+					} else {
+						this.expression(&init.lvalue);
+					}
+					this.expression(&init.value);
+					this.expression(&generate.condition);
+					this.expression(&generate.step);
+					this.items(&block.items);
+				};
+
+				// NOTE(aki): The genvar of `for (genvar i = ...)` is local to the loop, which is
+				// the same as declaring it in the generated block
+				match &block.name {
+					Some(name) => {
+						self.declare_scoped(
+							name,
+							SymbolKind::GenerateBlock,
+							ScopeKind::Generate,
+							*generate.block.span(),
+							body,
+						);
+					},
+					None => {
+						self.scoped(ScopeKind::Generate, None, *generate.block.span(), body);
+					},
+				}
+			},
+			ItemKind::GenerateIf(generate) => {
+				self.expression(&generate.condition);
+				for block in generate.if_true.iter().chain(&generate.if_false) {
+					self.generate_block(block.inner(), *block.span());
+				}
+			},
+			ItemKind::GenerateCase(generate) => {
+				self.expression(&generate.expression);
+				for item in &generate.items {
+					let item = item.inner();
+					self.expressions(&item.labels);
+					if let Some(block) = &item.block {
+						self.generate_block(block.inner(), *block.span());
+					}
+				}
+			},
+			ItemKind::GenerateBlock(block) => self.generate_block(block, span),
+			ItemKind::Specify(items) => {
+				for item in items {
+					self.specify_item(item.inner());
+				}
+			},
+			ItemKind::Typedef(typedef) => {
+				let type_name = typedef
+					.data_type
+					.as_ref()
+					.and_then(|data_type| self.data_type(data_type));
+				self.dimensions(&typedef.dimensions);
+
+				let symbol = self.declare_typed(&typedef.name, SymbolKind::Typedef, type_name);
+				self.table.symbols[symbol.0].forward = typedef.data_type.is_none();
+			},
+			ItemKind::Import(imports) | ItemKind::Export(imports) => self.imports(imports),
+			ItemKind::Modport(modports) => {
+				for modport in modports {
+					let modport = modport.inner();
+					self.declare(&modport.name, SymbolKind::Modport);
+
+					for port in &modport.ports {
+						let port = port.inner();
+						match &port.expression {
+							Some(expression) => self.expression(expression),
+							None => {
+								self.reference(&port.name, ReferenceKind::Name, None);
+							},
+						}
+					}
+				}
+			},
+			ItemKind::Class(class) => self.class(class, span),
+			ItemKind::Constraint(constraint) => {
+				self.declare(&constraint.name, SymbolKind::Constraint);
+				if let Some(items) = &constraint.items {
+					self.constraints(items);
+				}
+			},
+			ItemKind::Prototype(prototype) => self.prototype(prototype, span),
+			ItemKind::Assertion(assertion) => self.assertion(assertion),
+			ItemKind::Property(property) => {
+				self.declare_scoped(
+					&property.name,
+					SymbolKind::Property,
+					ScopeKind::Property,
+					span,
+					|this| {
+						this.assertion_ports(&property.ports);
+						this.items(&property.variables);
+						this.property_spec(property.property.inner());
+					},
+				);
+			},
+			ItemKind::Sequence(sequence) => {
+				self.declare_scoped(
+					&sequence.name,
+					SymbolKind::Sequence,
+					ScopeKind::Sequence,
+					span,
+					|this| {
+						this.assertion_ports(&sequence.ports);
+						this.items(&sequence.variables);
+						this.property_spec(sequence.sequence.inner());
+					},
+				);
+			},
+			ItemKind::Let(declaration) => {
+				self.declare_scoped(
+					&declaration.name,
+					SymbolKind::Let,
+					ScopeKind::Let,
+					span,
+					|this| {
+						this.assertion_ports(&declaration.ports);
+						this.expression(&declaration.expression);
+					},
+				);
+			},
+			ItemKind::CoverGroup(covergroup) => self.covergroup(covergroup, span),
+			ItemKind::Clocking(clocking) => self.clocking(clocking, span),
+			ItemKind::DefaultClocking(name) => {
+				self.reference(name, ReferenceKind::Name, None);
+			},
+			ItemKind::DefaultDisable(expression) => self.expression(expression),
+			ItemKind::TimeUnits(_) => {},
+			ItemKind::Bind(bind) => {
+				match bind.target.inner() {
+					Expression::Identifier(name) => {
+						self.push_reference(
+							name,
+							*bind.target.span(),
+							ReferenceKind::Definition,
+							None,
+							false,
+						);
+					},
+					_ => self.expression(&bind.target),
+				}
+				self.expressions(&bind.instances);
+
+				// NOTE(aki): The port connections of a bound instance are in the scope of the
+				// target, which is not known until elaboration
+				self.instantiation(&bind.instantiation, false);
+			},
+			ItemKind::Dpi(dpi) => match dpi.direction {
+				DpiDirection::Import => self.prototype(&dpi.prototype, span),
+				DpiDirection::Export => {
+					self.reference(&dpi.prototype.name, ReferenceKind::Call, None);
+				},
+			},
+			ItemKind::DisciplineNet(net) => {
+				self.reference(&net.discipline, ReferenceKind::Nature, None);
+				self.data_type(&net.data_type);
+				self.declarators(&net.declarators, SymbolKind::Net, None);
+			},
+			ItemKind::Ground(names) => {
+				for name in names {
+					self.reference(name, ReferenceKind::ImplicitNet, None);
+				}
+			},
+			ItemKind::Branch(branch) => {
+				for terminal in &branch.terminals {
+					match terminal.inner() {
+						BranchTerminal::Net(expression) | BranchTerminal::Port(expression) => {
+							self.expression(expression);
+						},
+					}
+				}
+				for name in &branch.names {
+					self.declare(name, SymbolKind::Branch);
+				}
+			},
+			ItemKind::Error => {},
+		}
+	}
+
+	fn port(&mut self, port: &PortDeclaration) {
+		if let Some(discipline) = &port.discipline {
+			self.reference(discipline, ReferenceKind::Nature, None);
+		}
+
+		let type_name = self.data_type(&port.data_type);
+		self.declarators(&port.declarators, SymbolKind::Port, type_name);
+	}
+
+	fn parameter(&mut self, parameter: &ParameterDeclaration) {
+		let kind = if matches!(parameter.data_type, DataType::Type) {
+			SymbolKind::TypeParameter
+		} else if parameter.local {
+			SymbolKind::LocalParameter
+		} else {
+			SymbolKind::Parameter
+		};
+
+		let type_name = self.data_type(&parameter.data_type);
+		self.declarators(&parameter.declarators, kind, type_name);
+	}
+
+	fn declarators(
+		&mut self,
+		declarators: &[Spanned<Declarator>],
+		kind: SymbolKind,
+		type_name: Option<ReferenceId>,
+	) {
+		for declarator in declarators {
+			let declarator = declarator.inner();
+			self.dimensions(&declarator.dimensions);
+			if let Some(init) = &declarator.init {
+				self.expression(init);
+			}
+			for range in &declarator.ranges {
+				match &range.inner().bounds {
+					ValueRangeBounds::Interval { low, high, .. } => {
+						self.expression(low);
+						self.expression(high);
+					},
+					ValueRangeBounds::Value(value) => self.expression(value),
+				}
+			}
+
+			self.declare_typed(&declarator.name, kind, type_name);
+		}
+	}
+
+	/// Collect the references of `data_type`, returning the reference to the type itself for
+	/// user defined types
+	fn data_type(&mut self, data_type: &DataType) -> Option<ReferenceId> {
+		match data_type {
+			DataType::Implicit { packed, .. } | DataType::IntegerVector { packed, .. } => {
+				self.dimensions(packed);
+				None
+			},
+			DataType::Struct(struct_type) => {
+				for member in &struct_type.members {
+					let member = member.inner();
+					self.data_type(&member.data_type);
+					for declarator in &member.declarators {
+						let declarator = declarator.inner();
+						self.dimensions(&declarator.dimensions);
+						if let Some(init) = &declarator.init {
+							self.expression(init);
+						}
+					}
+				}
+				self.dimensions(&struct_type.dimensions);
+				None
+			},
+			// Enum members are declared in the scope enclosing the enum
+			// IEEE 1800-2017 § 6.19
+			DataType::Enum(enum_type) => {
+				if let Some(base) = &enum_type.base {
+					self.data_type(base);
+				}
+				for member in &enum_type.members {
+					let member = member.inner();
+					self.dimension(member.range.as_ref());
+					if let Some(value) = &member.value {
+						self.expression(value);
+					}
+					self.declare(&member.name, SymbolKind::EnumMember);
+				}
+				self.dimensions(&enum_type.dimensions);
+				None
+			},
+			DataType::Named { path, parameters, packed } => {
+				let reference = self.type_path(path);
+				if let Some(parameters) = parameters {
+					self.connections(parameters.inner(), None, false);
+				}
+				self.dimensions(packed);
+				reference
+			},
+			DataType::VirtualInterface { name, parameters, modport } => {
+				let reference = self.reference(name, ReferenceKind::Type, None);
+				if let Some(parameters) = parameters {
+					self.connections(parameters.inner(), None, false);
+				}
+				if let Some(modport) = modport {
+					self.reference(
+						modport,
+						ReferenceKind::Member,
+						Some(Prefix::Reference(reference)),
+					);
+				}
+				Some(reference)
+			},
+			DataType::Interface { name, modport } => {
+				let reference = name
+					.as_ref()
+					.map(|name| self.reference(name, ReferenceKind::Definition, None));
+				if let (Some(reference), Some(modport)) = (reference, modport) {
+					self.reference(
+						modport,
+						ReferenceKind::Member,
+						Some(Prefix::Reference(reference)),
+					);
+				}
+				reference
+			},
+			DataType::IntegerAtom { .. } |
+			DataType::NonInteger(_) |
+			DataType::Event |
+			DataType::String |
+			DataType::CHandle |
+			DataType::Void |
+			DataType::Type => None,
+		}
+	}
+
+	fn dimensions(&mut self, dimensions: &[Spanned<Dimension>]) {
+		for dimension in dimensions {
+			self.dimension(Some(dimension));
+		}
+	}
+
+	fn dimension(&mut self, dimension: Option<&Spanned<Dimension>>) {
+		let Some(dimension) = dimension else {
+			return;
+		};
+
+		match dimension.inner() {
+			Dimension::Range { msb, lsb } => {
+				self.expression(msb);
+				self.expression(lsb);
+			},
+			Dimension::Size(size) | Dimension::Queue(Some(size)) => self.expression(size),
+			Dimension::Associative(Some(data_type)) => {
+				self.data_type(data_type);
+			},
+			Dimension::Unsized | Dimension::Queue(None) | Dimension::Associative(None) => {},
+		}
+	}
+
+	fn instantiation(&mut self, instantiation: &Instantiation, connections: bool) {
+		let module = self.reference(&instantiation.module, ReferenceKind::Definition, None);
+
+		if let Some(parameters) = &instantiation.parameters {
+			self.connections(parameters.inner(), Some(module), false);
+		}
+
+		for instance in &instantiation.instances {
+			let instance = instance.inner();
+			if !connections {
+				continue;
+			}
+
+			if let Some(name) = &instance.name {
+				self.declare_typed(name, SymbolKind::Instance, Some(module));
+			}
+			self.dimension(instance.range.as_ref());
+			self.connections(&instance.connections, Some(module), true);
+		}
+	}
+
+	/// Port connections or parameter value assignments, `ports` is set for port connections
+	fn connections(&mut self, connections: &Connections, module: Option<ReferenceId>, ports: bool) {
+		let kind = if ports {
+			ReferenceKind::ImplicitNet
+		} else {
+			ReferenceKind::Name
+		};
+
+		match connections {
+			Connections::Ordered(values) => {
+				for value in values.iter().flatten() {
+					self.expression_as(value, kind);
+				}
+			},
+			Connections::Named(connections) => {
+				for connection in connections {
+					let connection = connection.inner();
+
+					if let (Some(name), Some(module)) = (&connection.name, module) {
+						let name_kind = if ports {
+							ReferenceKind::Port
+						} else {
+							ReferenceKind::Parameter
+						};
+						self.reference(name, name_kind, Some(Prefix::Reference(module)));
+					}
+
+					match (&connection.value, &connection.name) {
+						(Some(value), _) => self.expression_as(value, kind),
+						// `.name` connects to the net or variable of the same name
+						(None, Some(name)) if connection.implicit => {
+							self.reference(name, ReferenceKind::Name, None);
+						},
+						(None, _) => {},
+					}
+				}
+			},
+		}
+	}
+
+	fn generate_block(&mut self, block: &GenerateBlock, span: Span) {
+		match &block.name {
+			Some(name) => {
+				self.declare_scoped(
+					name,
+					SymbolKind::GenerateBlock,
+					ScopeKind::Generate,
+					span,
+					|this| this.items(&block.items),
+				);
+			},
+			None => {
+				self.scoped(ScopeKind::Generate, None, span, |this| {
+					this.items(&block.items)
+				});
+			},
+		}
+	}
+
+	fn task(&mut self, task: &Task, span: Span) {
+		self.subroutine(
+			&task.name,
+			task.scope.as_ref(),
+			SymbolKind::Task,
+			ScopeKind::Task,
+			span,
+			|this| {
+				if let Some(ports) = &task.ports {
+					for port in ports {
+						this.port(port.inner());
+					}
+				}
+				this.items(&task.items);
+				this.statements(&task.body);
+			},
+		);
+	}
+
+	fn function(&mut self, function: &Function, span: Span) {
+		self.data_type(&function.return_type);
+
+		self.subroutine(
+			&function.name,
+			function.scope.as_ref(),
+			SymbolKind::Function,
+			ScopeKind::Function,
+			span,
+			|this| {
+				if let Some(ports) = &function.ports {
+					for port in ports {
+						this.port(port.inner());
+					}
+				}
+				this.items(&function.items);
+				this.statements(&function.body);
+			},
+		);
+	}
+
+	/// Declare a task or function, an out-of-block method of a class is declared by its
+	/// prototype so only its body is added, in the scope of its class
+	///
+	/// IEEE 1800-2017 § 8.24
+	fn subroutine<F>(
+		&mut self,
+		name: &Spanned<Identifier>,
+		class: Option<&Spanned<Identifier>>,
+		kind: SymbolKind,
+		scope_kind: ScopeKind,
+		span: Span,
+		f: F,
+	) where
+		F: FnOnce(&mut Self),
+	{
+		let Some(class) = class else {
+			self.declare_scoped(name, kind, scope_kind, span, f);
+			return;
+		};
+
+		let prefix = self.reference(class, ReferenceKind::ScopePrefix, None);
+		self.reference(
+			name,
+			ReferenceKind::ScopeMember,
+			Some(Prefix::Reference(prefix)),
+		);
+
+		let body = self
+			.table
+			.lookup(self.scope, class.inner().as_str())
+			.and_then(|class| self.table.symbols[class.0].body);
+
+		let current = self.scope;
+		let parent = std::mem::replace(&mut self.scope, body.unwrap_or(current));
+		let scope = self.scoped(scope_kind, Some(name.inner()), span, f);
+		self.scope = parent;
+
+		self.table.scopes[scope.0].open = body.is_none();
+	}
+
+	fn prototype(&mut self, prototype: &SubroutinePrototype, span: Span) {
+		self.data_type(&prototype.return_type);
+
+		let (kind, scope_kind) = match prototype.kind {
+			SubroutineKind::Task => (SymbolKind::Task, ScopeKind::Task),
+			SubroutineKind::Function => (SymbolKind::Function, ScopeKind::Function),
+		};
+
+		self.declare_scoped(&prototype.name, kind, scope_kind, span, |this| {
+			for port in prototype.ports.iter().flatten() {
+				this.port(port.inner());
+			}
+		});
+	}
+
+	fn specify_item(&mut self, item: &SpecifyItem) {
+		match item {
+			SpecifyItem::Specparam(specparam) => {
+				self.dimension(specparam.range.as_ref());
+				for assignment in &specparam.assignments {
+					let assignment = assignment.inner();
+					self.specparam_value(&assignment.value);
+					self.declare(&assignment.name, SymbolKind::Specparam);
+				}
+			},
+			SpecifyItem::PulseStyle { outputs, .. } |
+			SpecifyItem::ShowCancelled { outputs, .. } => self.expressions(outputs),
+			SpecifyItem::Path(path) => {
+				if let Some(PathCondition::If(condition)) = &path.condition {
+					self.expression(condition);
+				}
+				self.expressions(&path.inputs);
+				self.expressions(&path.outputs);
+				if let Some((_, source)) = &path.data_source {
+					self.expression(source);
+				}
+				self.expressions(&path.delays);
+			},
+			SpecifyItem::TimingCheck(check) => {
+				for event in check.arguments.iter().flatten() {
+					let event = event.inner();
+					self.expression(&event.expression);
+					if let Some(condition) = &event.condition {
+						self.expression(condition);
+					}
+				}
+			},
+		}
+	}
+
+	fn specparam_value(&mut self, value: &SpecparamValue) {
+		match value {
+			SpecparamValue::Value(value) => self.expression(value),
+			SpecparamValue::PulseControl { reject, error } => {
+				self.expression(reject);
+				if let Some(error) = error {
+					self.expression(error);
+				}
+			},
+		}
+	}
+
+	fn covergroup(&mut self, covergroup: &CoverGroup, span: Span) {
+		self.declare_scoped(
+			&covergroup.name,
+			SymbolKind::CoverGroup,
+			ScopeKind::CoverGroup,
+			span,
+			|this| {
+				for port in covergroup.ports.iter().flatten() {
+					this.port(port.inner());
+				}
+
+				match covergroup.event.as_ref().map(Spanned::inner) {
+					Some(CoverageEvent::Clocking(event)) => this.event_control(event),
+					Some(CoverageEvent::Sample(ports)) => {
+						for port in ports {
+							this.port(port.inner());
+						}
+					},
+					None => {},
+				}
+
+				for item in &covergroup.items {
+					match item.inner() {
+						CoverageItem::Option(option) => this.expression(&option.value),
+						CoverageItem::Coverpoint(coverpoint) => {
+							this.expression(&coverpoint.expression);
+							if let Some(iff) = &coverpoint.iff {
+								this.expression(iff);
+							}
+							this.bins(coverpoint.bins.as_deref());
+							if let Some(label) = &coverpoint.label {
+								this.declare(label, SymbolKind::Coverpoint);
+							}
+						},
+						CoverageItem::Cross(cross) => {
+							for item in &cross.items {
+								this.reference(item, ReferenceKind::Name, None);
+							}
+							if let Some(iff) = &cross.iff {
+								this.expression(iff);
+							}
+							this.bins(cross.bins.as_deref());
+							if let Some(label) = &cross.label {
+								this.declare(label, SymbolKind::Cross);
+							}
+						},
+					}
+				}
+			},
+		);
+	}
+
+	fn bins(&mut self, bins: Option<&[Spanned<BinsItem>]>) {
+		for item in bins.into_iter().flatten() {
+			let bins = match item.inner() {
+				BinsItem::Option(option) => {
+					self.expression(&option.value);
+					continue;
+				},
+				BinsItem::Bins(bins) => bins,
+			};
+
+			if let Some(size) = &bins.size {
+				self.expression(size);
+			}
+
+			match &bins.values {
+				BinsValues::Set(values) => self.expressions(values),
+				BinsValues::Transitions(transitions) => {
+					for item in transitions.iter().flatten() {
+						let item = item.inner();
+						self.expressions(&item.values);
+						if let Some((_, range)) = &item.repetition {
+							self.cycle_range(range.inner());
+						}
+					}
+				},
+				BinsValues::Select(select) => self.bins_select(select.inner()),
+				BinsValues::Default | BinsValues::DefaultSequence => {},
+			}
+
+			if let Some(with) = &bins.with {
+				self.with_item(with);
+			}
+			if let Some(iff) = &bins.iff {
+				self.expression(iff);
+			}
+		}
+	}
+
+	fn bins_select(&mut self, select: &BinsSelect) {
+		match select {
+			BinsSelect::BinsOf { target, intersect } => {
+				self.expression(target);
+				if let Some(intersect) = intersect {
+					self.expressions(intersect);
+				}
+			},
+			BinsSelect::Not(select) | BinsSelect::Parenthesized(select) => {
+				self.bins_select(select.inner());
+			},
+			BinsSelect::And(lhs, rhs) | BinsSelect::Or(lhs, rhs) => {
+				self.bins_select(lhs.inner());
+				self.bins_select(rhs.inner());
+			},
+		}
+	}
+
+	fn clocking(&mut self, clocking: &ClockingBlock, span: Span) {
+		self.event_control(clocking.event.inner());
+
+		let body = |this: &mut Self| {
+			for item in &clocking.items {
+				match item.inner() {
+					ClockingItem::Default { input, output } => {
+						this.clocking_skew(input.as_ref());
+						this.clocking_skew(output.as_ref());
+					},
+					ClockingItem::Signals { input_skew, output_skew, signals, .. } => {
+						this.clocking_skew(input_skew.as_ref());
+						this.clocking_skew(output_skew.as_ref());
+
+						for signal in signals {
+							let signal = signal.inner();
+
+							// The signal is sampled from the scope enclosing the clocking block
+							let parent = this.table.scopes[this.scope.0].parent;
+							let inner = this.scope;
+							this.scope = parent.unwrap_or(inner);
+							match &signal.value {
+								Some(value) => this.expression(value),
+								None => {
+									this.reference(&signal.name, ReferenceKind::Name, None);
+								},
+							}
+							this.scope = inner;
+
+							this.declare(&signal.name, SymbolKind::ClockingSignal);
+						}
+					},
+					ClockingItem::Item(item) => this.item(item, span),
+				}
+			}
+		};
+
+		match &clocking.name {
+			Some(name) => {
+				self.declare_scoped(name, SymbolKind::Clocking, ScopeKind::Clocking, span, body);
+			},
+			None => {
+				self.scoped(ScopeKind::Clocking, None, span, body);
+			},
+		}
+	}
+
+	fn clocking_skew(&mut self, skew: Option<&Spanned<ClockingSkew>>) {
+		if let Some(skew) = skew {
+			self.delay(skew.inner().delay.as_ref());
+		}
+	}
+
+	fn assertion_ports(&mut self, ports: &[Spanned<AssertionPort>]) {
+		for port in ports {
+			let port = port.inner();
+			if let AssertionPortType::Data(data_type) = &port.port_type {
+				self.data_type(data_type);
+			}
+			if let Some(default) = &port.default {
+				self.expression(default);
+			}
+			self.declare(&port.name, SymbolKind::Port);
+		}
+	}
+
+	fn assertion(&mut self, assertion: &Assertion) {
+		if let Some(label) = &assertion.label {
+			self.declare(label, SymbolKind::Assertion);
+		}
+
+		self.property_spec(assertion.property.inner());
+		for statement in assertion.pass.iter().chain(&assertion.fail) {
+			self.statement(statement);
+		}
+	}
+
+	fn property_spec(&mut self, spec: &PropertySpec) {
+		if let Some(clock) = &spec.clock {
+			self.event_control(clock.inner());
+		}
+		if let Some(disable) = &spec.disable {
+			self.expression(disable);
+		}
+		self.property_expression(&spec.property);
+	}
+
+	fn property_expression(&mut self, property: &Spanned<PropertyExpression>) {
+		match property.inner() {
+			PropertyExpression::Expression(expression) => {
+				self.expression_inner(expression, *property.span(), ReferenceKind::Name);
+			},
+			PropertyExpression::Delay { lhs, delay, rhs } => {
+				if let Some(lhs) = lhs {
+					self.property_expression(lhs);
+				}
+				self.cycle_range(delay.inner());
+				self.property_expression(rhs);
+			},
+			PropertyExpression::Repetition { operand, range, .. } => {
+				self.property_expression(operand);
+				self.cycle_range(range.inner());
+			},
+			PropertyExpression::Binary { lhs, rhs, .. } => {
+				self.property_expression(lhs);
+				self.property_expression(rhs);
+			},
+			PropertyExpression::Unary { range, operand, .. } => {
+				if let Some(range) = range {
+					self.cycle_range(range.inner());
+				}
+				self.property_expression(operand);
+			},
+			PropertyExpression::Clocked { clock, property } => {
+				self.event_control(clock.inner());
+				self.property_expression(property);
+			},
+			PropertyExpression::If { condition, if_true, if_false } => {
+				self.expression(condition);
+				self.property_expression(if_true);
+				if let Some(if_false) = if_false {
+					self.property_expression(if_false);
+				}
+			},
+			PropertyExpression::Abort { condition, property, .. } => {
+				self.expression(condition);
+				self.property_expression(property);
+			},
+			PropertyExpression::Parenthesized(property) => {
+				self.property_expression(property);
+			},
+		}
+	}
+
+	fn cycle_range(&mut self, range: &CycleRange) {
+		match range {
+			CycleRange::Single(value) => self.expression(value),
+			CycleRange::Range { min, max } => {
+				self.expression(min);
+				self.expression(max);
+			},
+			CycleRange::ZeroOrMore | CycleRange::OneOrMore => {},
+		}
+	}
+
+	fn constraints(&mut self, items: &[Spanned<ConstraintItem>]) {
+		for item in items {
+			match item.inner() {
+				ConstraintItem::Expression { expression, .. } => self.expression(expression),
+				ConstraintItem::Dist { expression, items, .. } => {
+					self.expression(expression);
+					for item in items {
+						let item = item.inner();
+						self.expression(&item.value);
+						match &item.weight {
+							Some(DistWeight::PerValue(weight) | DistWeight::PerRange(weight)) => {
+								self.expression(weight);
+							},
+							None => {},
+						}
+					}
+				},
+				ConstraintItem::Implication { condition, constraints } => {
+					self.expression(condition);
+					self.constraints(constraints);
+				},
+				ConstraintItem::If { condition, if_true, if_false } => {
+					self.expression(condition);
+					self.constraints(if_true);
+					if let Some(if_false) = if_false {
+						self.constraints(if_false);
+					}
+				},
+				ConstraintItem::Foreach { array, variables, constraints } => {
+					self.expression(array);
+					self.scoped(ScopeKind::Loop, None, *item.span(), |this| {
+						for variable in variables.iter().flatten() {
+							this.declare(variable, SymbolKind::Variable);
+						}
+						this.constraints(constraints);
+					});
+				},
+				ConstraintItem::SolveBefore { solve, before } => {
+					self.expressions(solve);
+					self.expressions(before);
+				},
+				ConstraintItem::Unique(expressions) => self.expressions(expressions),
+				ConstraintItem::DisableSoft(expression) => self.expression(expression),
+			}
+		}
+	}
+
+	fn statements(&mut self, statements: &[Spanned<Statement>]) {
+		for statement in statements {
+			self.statement(statement);
+		}
+	}
+
+	fn statement(&mut self, statement: &Spanned<Statement>) {
+		let span = *statement.span();
+		let statement = statement.inner();
+
+		match &statement.kind {
+			StatementKind::Null |
+			StatementKind::Break |
+			StatementKind::Continue |
+			StatementKind::WaitFork |
+			StatementKind::DisableFork |
+			StatementKind::Error => {},
+			StatementKind::Blocking(assignment) |
+			StatementKind::NonBlocking(assignment) |
+			StatementKind::ProceduralAssign(assignment) |
+			StatementKind::Force(assignment) => self.assignment(assignment),
+			StatementKind::Deassign(expression) |
+			StatementKind::Release(expression) |
+			StatementKind::Disable(expression) |
+			StatementKind::EventTrigger(expression) |
+			StatementKind::Expression(expression) |
+			StatementKind::Return(Some(expression)) => self.expression(expression),
+			StatementKind::Return(None) => {},
+			StatementKind::Case(case) => {
+				self.expression(&case.expression);
+				for item in &case.items {
+					let CaseItem { labels, statement } = item.inner();
+					self.expressions(labels);
+					self.statement(statement);
+				}
+			},
+			StatementKind::If { condition, if_true, if_false, .. } => {
+				self.expression(condition);
+				self.statement(if_true);
+				if let Some(if_false) = if_false {
+					self.statement(if_false);
+				}
+			},
+			StatementKind::Forever(body) => self.statement(body),
+			StatementKind::Repeat { count: condition, body } |
+			StatementKind::While { condition, body } |
+			StatementKind::DoWhile { body, condition } => {
+				self.expression(condition);
+				self.statement(body);
+			},
+			StatementKind::For { init, condition, step, body } => {
+				self.scoped(ScopeKind::Loop, None, span, |this| {
+					for init in init {
+						let init = init.inner();
+						let assignment = init.assignment.inner();
+
+						match &init.data_type {
+							Some(data_type) => {
+								let type_name = this.data_type(data_type);
+								this.expression(&assignment.value);
+								if let Some(name) = assignment.lvalue.inner().as_identifier() {
+									let name =
+										Spanned::new(name.clone(), *assignment.lvalue.span());
+									this.declare_typed(&name, SymbolKind::Variable, type_name);
+								}
+							},
+							None => this.assignment(assignment),
+						}
+					}
+
+					if let Some(condition) = condition {
+						this.expression(condition);
+					}
+					this.expressions(step);
+					this.statement(body);
+				});
+			},
+			StatementKind::Block(block) => {
+				self.block(block, statement.label.as_ref(), span);
+			},
+			StatementKind::TimingControl { control, statement } => {
+				self.timing_control(control.inner());
+				self.statement(statement);
+			},
+			StatementKind::SystemTaskEnable { arguments, .. } => {
+				for argument in arguments.iter().flatten() {
+					self.expression(argument);
+				}
+			},
+			StatementKind::TaskEnable { name, arguments } => {
+				self.expression_as(name, ReferenceKind::Call);
+				self.expressions(arguments);
+			},
+			StatementKind::Wait { condition, statement } => {
+				self.expression(condition);
+				self.statement(statement);
+			},
+			StatementKind::Foreach { array, variables, body } => {
+				self.expression(array);
+				self.scoped(ScopeKind::Loop, None, span, |this| {
+					for variable in variables.iter().flatten() {
+						this.declare(variable, SymbolKind::Variable);
+					}
+					this.statement(body);
+				});
+			},
+			StatementKind::EventTriggerNb { control, name } => {
+				if let Some(control) = control {
+					self.timing_control(control.inner());
+				}
+				self.expression(name);
+			},
+			StatementKind::Assertion(assertion) => self.assertion(assertion),
+			StatementKind::Contribution { target, value } => {
+				self.expression(target);
+				self.expression(value);
+			},
+			StatementKind::IndirectContribution { target, equation } => {
+				self.expression(target);
+				self.expression(equation);
+			},
+		}
+	}
+
+	fn block(&mut self, block: &Block, label: Option<&Spanned<Identifier>>, span: Span) {
+		let body = |this: &mut Self| {
+			this.items(&block.items);
+			this.statements(&block.statements);
+		};
+
+		match block.name.as_ref().or(label) {
+			Some(name) => {
+				self.declare_scoped(name, SymbolKind::Block, ScopeKind::Block, span, body);
+			},
+			None => {
+				self.scoped(ScopeKind::Block, None, span, body);
+			},
+		}
+	}
+
+	fn assignment(&mut self, assignment: &Assignment) {
+		self.expression(&assignment.lvalue);
+		if let Some(control) = &assignment.control {
+			self.timing_control(control.inner());
+		}
+		self.expression(&assignment.value);
+	}
+
+	fn timing_control(&mut self, control: &TimingControl) {
+		match control {
+			TimingControl::Delay(delay) => self.delay_values(delay),
+			TimingControl::Event(event) => self.event_control(event),
+			TimingControl::Repeat { count, event } => {
+				self.expression(count);
+				self.event_control(event.inner());
+			},
+			TimingControl::Cycle(cycles) => self.expression(cycles),
+		}
+	}
+
+	fn delay(&mut self, delay: Option<&Spanned<Delay>>) {
+		if let Some(delay) = delay {
+			self.delay_values(delay.inner());
+		}
+	}
+
+	fn delay_values(&mut self, delay: &Delay) {
+		self.expressions(&delay.values);
+	}
+
+	fn event_control(&mut self, event: &EventControl) {
+		match event {
+			EventControl::Identifier(expression) => self.expression(expression),
+			EventControl::Expression(events) => {
+				for event in events {
+					let event = event.inner();
+					self.expression(&event.expression);
+					if let Some(iff) = &event.iff {
+						self.expression(iff);
+					}
+				}
+			},
+			EventControl::Wildcard => {},
+		}
+	}
+
+	fn expressions(&mut self, expressions: &[Spanned<Expression>]) {
+		for expression in expressions {
+			self.expression(expression);
+		}
+	}
+
+	fn expression(&mut self, expression: &Spanned<Expression>) {
+		self.expression_as(expression, ReferenceKind::Name);
+	}
+
+	/// Collect the references in `expression`, with a simple identifier referenced as `kind`
+	fn expression_as(&mut self, expression: &Spanned<Expression>, kind: ReferenceKind) {
+		self.expression_inner(expression.inner(), *expression.span(), kind);
+	}
+
+	fn expression_inner(&mut self, expression: &Expression, span: Span, kind: ReferenceKind) {
+		match expression {
+			Expression::Identifier(name) => {
+				self.push_reference(name, span, kind, None, false);
+			},
+			Expression::Member { .. } | Expression::Scope { .. } => {
+				self.path(expression, span);
+			},
+			Expression::Select { target, select } => {
+				self.expression(target);
+				self.select(select.inner());
+			},
+			// Implicit nets may be declared by any of the identifiers in a concatenation
+			Expression::Concatenation(items) => {
+				for item in items {
+					self.expression_as(item, kind);
+				}
+			},
+			Expression::Replication { count, items } => {
+				self.expression(count);
+				self.expressions(items);
+			},
+			Expression::Call { target, arguments, .. } => {
+				self.expression_as(target, ReferenceKind::Call);
+				self.expressions(arguments);
+			},
+			Expression::SystemCall { arguments, .. } => {
+				for argument in arguments.iter().flatten() {
+					self.expression(argument);
+				}
+			},
+			Expression::Unary { operand, .. } | Expression::Parenthesized(operand) => {
+				self.expression(operand);
+			},
+			Expression::IncDec { operand, .. } => self.expression(operand),
+			Expression::Binary { lhs, rhs, .. } |
+			Expression::Assign { lvalue: lhs, value: rhs, .. } |
+			Expression::ValueRange { low: lhs, high: rhs } => {
+				self.expression(lhs);
+				self.expression(rhs);
+			},
+			Expression::ToleranceRange { center, tolerance, .. } => {
+				self.expression(center);
+				self.expression(tolerance);
+			},
+			Expression::Conditional { condition, if_true, if_false, .. } => {
+				self.expression(condition);
+				self.expression(if_true);
+				self.expression(if_false);
+			},
+			Expression::MinTypMax { min, typ, max } => {
+				self.expression(min);
+				self.expression(typ);
+				self.expression(max);
+			},
+			Expression::Cast { target, value } => {
+				match target.inner() {
+					CastTarget::Type(data_type) => {
+						self.data_type(data_type);
+					},
+					CastTarget::Expression(expression) => {
+						self.expression_inner(expression, *target.span(), ReferenceKind::Name);
+					},
+					CastTarget::Signing(_) | CastTarget::Const => {},
+				}
+				self.expression(value);
+			},
+			Expression::AssignmentPattern { data_type, items } => {
+				if let Some(data_type) = data_type {
+					self.expression(data_type);
+				}
+
+				for item in items {
+					match item.inner() {
+						PatternItem::Positional(value) | PatternItem::Default(value) => {
+							self.expression(value);
+						},
+						PatternItem::Keyed { key, value } => {
+							// A simple identifier key names a member of a structure
+							if key.inner().as_identifier().is_none() {
+								self.expression(key);
+							}
+							self.expression(value);
+						},
+					}
+				}
+			},
+			Expression::Inside { expression, set } => {
+				self.expression(expression);
+				self.expressions(set);
+			},
+			Expression::Streaming { slice, items, .. } => {
+				if let Some(slice) = slice {
+					self.expression(slice);
+				}
+				self.expressions(items);
+			},
+			Expression::New { size, arguments } => {
+				if let Some(size) = size {
+					self.expression(size);
+				}
+				self.expressions(arguments);
+			},
+			Expression::DataType(data_type) => {
+				self.data_type(data_type);
+			},
+			Expression::With { target, expression } => {
+				self.expression(target);
+				self.with_item(expression);
+			},
+			// NOTE(aki): The identifiers of inline constraints are looked up in the object being
+			// randomized first, which is not known until the types are checked
+			Expression::RandomizeWith { call, .. } => self.expression(call),
+			Expression::Literal(_) |
+			Expression::MacroUsage(_) |
+			Expression::This |
+			Expression::Super |
+			Expression::Null |
+			Expression::Unbounded => {},
+		}
+	}
+
+	/// The `with` clause of an array manipulation method or bins, with `item` declared
+	///
+	/// IEEE 1800-2017 § 7.12
+	fn with_item(&mut self, expression: &Spanned<Expression>) {
+		let span = *expression.span();
+		self.scoped(ScopeKind::With, None, span, |this| {
+			let item = Spanned::new(Identifier::Simple("item".as_bytes().into()), span);
+			this.declare(&item, SymbolKind::Variable);
+			this.expression(expression);
+		});
+	}
+
+	fn select(&mut self, select: &Select) {
+		match select {
+			Select::Bit(index) => self.expression(index),
+			Select::Range { msb: lhs, lsb: rhs } |
+			Select::IndexedUp { base: lhs, width: rhs } |
+			Select::IndexedDown { base: lhs, width: rhs } => {
+				self.expression(lhs);
+				self.expression(rhs);
+			},
+		}
+	}
+
+	/// Collect the references of a hierarchical or scoped name, returning the reference to its
+	/// last component
+	fn path(&mut self, expression: &Expression, span: Span) -> Option<ReferenceId> {
+		match expression {
+			Expression::Identifier(name) => {
+				Some(self.push_reference(name, span, ReferenceKind::Name, None, true))
+			},
+			Expression::Member { target, member } => {
+				let prefix = self.prefix(target)?;
+				Some(self.reference(member, ReferenceKind::Member, Some(prefix)))
+			},
+			Expression::Scope { scope, member } => {
+				let prefix = match scope.inner() {
+					Expression::Identifier(name) => Prefix::Reference(self.push_reference(
+						name,
+						*scope.span(),
+						ReferenceKind::ScopePrefix,
+						None,
+						false,
+					)),
+					Expression::Scope { .. } => {
+						Prefix::Reference(self.path(scope.inner(), *scope.span())?)
+					},
+					_ => {
+						self.expression(scope);
+						return None;
+					},
+				};
+				Some(self.reference(member, ReferenceKind::ScopeMember, Some(prefix)))
+			},
+			_ => {
+				self.expression_inner(expression, span, ReferenceKind::Name);
+				None
+			},
+		}
+	}
+
+	/// Collect the references of the target of a member reference, returning what the member
+	/// is looked up in
+	fn prefix(&mut self, target: &Spanned<Expression>) -> Option<Prefix> {
+		match target.inner() {
+			Expression::Identifier(_) | Expression::Member { .. } | Expression::Scope { .. } => {
+				self.path(target.inner(), *target.span())
+					.map(Prefix::Reference)
+			},
+			// The members of an element of an array of instances or generate blocks
+			Expression::Select { target, select } => {
+				self.select(select.inner());
+				self.prefix(target)
+			},
+			Expression::This => Some(Prefix::This),
+			Expression::Super => Some(Prefix::Super),
+			_ => {
+				self.expression(target);
+				None
+			},
+		}
+	}
+}
@@ -0,0 +1,1173 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use vermilion_diagnostics::{Code, StringDiagnostic};
+use vermilion_loc::{FileId, Location, Span};
+
+use crate::{
+	LanguageStd,
+	diagnostics::{system_verilog, verilog, verilog_ams},
+	lang::ast::Ast,
+};
+
+mod collect;
+
+use self::collect::Collector;
+
+/// A scope in a [`SymbolTable`]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ScopeId(usize);
+
+/// A symbol in a [`SymbolTable`]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SymbolId(usize);
+
+/// A reference in a [`SymbolTable`]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ReferenceId(usize);
+
+/// The construct that introduces a [`Scope`]
+///
+/// IEEE 1800-2017 § 3.13
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ScopeKind {
+	/// The items of a single source file outside of any other declaration, `$unit`
+	CompilationUnit,
+	Package, // Added: IEEE 1800-2005
+	Module,
+	Interface, // Added: IEEE 1800-2005
+	Program,   // Added: IEEE 1800-2005
+	Primitive,
+	Class, // Added: IEEE 1800-2005
+	Function,
+	Task,
+	/// A `begin`/`end` or `fork`/`join` block, named or not
+	Block,
+	/// A generate block, either named or not
+	Generate, // Added: IEEE 1364-2001
+	/// The loop variables of a `for` or `foreach` loop
+	Loop,
+	Property,   // Added: IEEE 1800-2005
+	Sequence,   // Added: IEEE 1800-2005
+	Let,        // Added: IEEE 1800-2009
+	CoverGroup, // Added: IEEE 1800-2005
+	Clocking,   // Added: IEEE 1800-2005
+	/// The `item` iterator of an array manipulation method's `with` clause
+	With, // Added: IEEE 1800-2005
+}
+
+/// What a [`Symbol`] declares
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SymbolKind {
+	Module,
+	Interface, // Added: IEEE 1800-2005
+	Program,   // Added: IEEE 1800-2005
+	Primitive,
+	Config,  // Added: IEEE 1364-2001
+	Package, // Added: IEEE 1800-2005
+	Class,   // Added: IEEE 1800-2005
+	Port,
+	Net,
+	/// A net implicitly declared by its use in a port connection or continuous assignment
+	ImplicitNet,
+	Variable,
+	Parameter,
+	LocalParameter,
+	TypeParameter, // Added: IEEE 1800-2005
+	Specparam,
+	Genvar,     // Added: IEEE 1364-2001
+	Typedef,    // Added: IEEE 1800-2005
+	EnumMember, // Added: IEEE 1800-2005
+	Function,
+	Task,
+	/// A module, interface, program, primitive, or gate instance
+	Instance,
+	/// A named `begin`/`end` or `fork`/`join` block
+	Block,
+	GenerateBlock,  // Added: IEEE 1364-2001
+	Modport,        // Added: IEEE 1800-2005
+	Clocking,       // Added: IEEE 1800-2005
+	ClockingSignal, // Added: IEEE 1800-2005
+	Property,       // Added: IEEE 1800-2005
+	Sequence,       // Added: IEEE 1800-2005
+	Let,            // Added: IEEE 1800-2009
+	/// The label of an assertion statement
+	Assertion, // Added: IEEE 1800-2005
+	CoverGroup,     // Added: IEEE 1800-2005
+	Coverpoint,     // Added: IEEE 1800-2005
+	Cross,          // Added: IEEE 1800-2005
+	Constraint,     // Added: IEEE 1800-2005
+	Nature,         // Added: Verilog-AMS 2009
+	Discipline,     // Added: Verilog-AMS 2009
+	/// The access function of a nature, e.g. the `V` in `access = V;`
+	AccessFunction, // Added: Verilog-AMS 2009
+	Branch,         // Added: Verilog-AMS 2009
+}
+
+/// How a [`Reference`] is resolved
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ReferenceKind {
+	/// A simple identifier, looked up through the enclosing scopes and their imports
+	Name,
+	/// A simple identifier that implicitly declares a scalar net if it is not declared, such as a
+	/// port connection or the target of a continuous assignment
+	///
+	/// IEEE 1364-2005 § 4.5
+	ImplicitNet,
+	/// The name of a called function or task
+	Call,
+	/// The name of a user defined type, class, or interface
+	Type,
+	/// The name of the module, interface, program, or primitive of an instantiation
+	Definition,
+	/// The package of an `import` or `export`
+	Package,
+	/// The package or class before a `::`
+	ScopePrefix,
+	/// The `b` in `a.b`, either a hierarchical name or a member of a class object
+	Member,
+	/// The `b` in `a::b`
+	ScopeMember,
+	/// The port name of a named port connection
+	Port,
+	/// The parameter name of a named parameter value assignment
+	Parameter,
+	/// A nature or discipline
+	Nature, // Added: Verilog-AMS 2009
+}
+
+/// A scope that symbols are declared in
+#[derive(Clone, Debug)]
+pub struct Scope {
+	pub kind:     ScopeKind,
+	/// The name of the declaration or block that introduces the scope, if it has one
+	pub name:     Option<String>,
+	pub parent:   Option<ScopeId>,
+	/// The file the scope is in and the span of the construct that introduces it
+	pub location: Location<()>,
+	symbols:      BTreeMap<String, SymbolId>,
+	imports:      Vec<Import>,
+	/// The references to the base classes and interface classes of a class scope
+	bases:        Vec<ReferenceId>,
+	/// Whether the scope may contain declarations that could not be found, such as the body of
+	/// an out-of-block method of an unknown class, undeclared identifiers are not reported in it
+	open:         bool,
+}
+
+/// A package import in a [`Scope`]
+#[derive(Clone, Debug)]
+struct Import {
+	package: String,
+	/// The imported item, or `None` for a wildcard import
+	item:    Option<String>,
+}
+
+/// Something declared in a [`Scope`]
+#[derive(Clone, Debug)]
+pub struct Symbol {
+	pub name:     String,
+	pub kind:     SymbolKind,
+	/// The file the symbol is declared in and the span of its name
+	pub location: Location<()>,
+	/// The scope the symbol is declared in
+	pub scope:    ScopeId,
+	/// The scope the symbol introduces, such as the body of a module or a named block
+	pub body:     Option<ScopeId>,
+	/// The reference to the type of a symbol with a user defined type, or the module of an
+	/// instance
+	type_name:    Option<ReferenceId>,
+	/// Whether the symbol is a forward `typedef` that the real declaration replaces
+	forward:      bool,
+}
+
+/// A use of an identifier
+#[derive(Clone, Debug)]
+pub struct Reference {
+	pub name:     String,
+	pub kind:     ReferenceKind,
+	/// The file the reference is in and the span of the identifier
+	pub location: Location<()>,
+	/// The scope the reference is in
+	pub scope:    ScopeId,
+	/// The symbol the reference resolves to, set by [`SymbolTable::resolve`]
+	pub target:   Option<SymbolId>,
+	prefix:       Option<Prefix>,
+	/// Whether the reference is the first component of a hierarchical name, which may be an
+	/// upwards reference to an instance that can only be found once the design is elaborated
+	upward:       bool,
+}
+
+/// What a member or scope member reference is a member of
+#[derive(Clone, Copy, Debug)]
+enum Prefix {
+	Reference(ReferenceId),
+	/// `this.member`
+	This,
+	/// `super.member`
+	Super,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ResolveState {
+	Unresolved,
+	Resolving,
+	Resolved,
+}
+
+/// The builtin methods of classes and the items of the `std` package, which are never reported
+/// as undeclared
+///
+/// IEEE 1800-2017 § 18.6, § 18.8, § G
+const BUILTIN_NAMES: &[&str] = &[
+	"constraint_mode",
+	"get_randstate",
+	"mailbox",
+	"new",
+	"post_randomize",
+	"pre_randomize",
+	"process",
+	"rand_mode",
+	"randomize",
+	"semaphore",
+	"set_randstate",
+	"srandom",
+	"std",
+];
+
+/// The scopes, declarations, and references of a set of Verilog, SystemVerilog, or Verilog-AMS
+/// source files
+///
+/// Each file is its own compilation unit scope, with the modules, interfaces, programs,
+/// primitives, and configurations of every file sharing the definitions name space and the
+/// packages sharing the package name space.
+///
+/// Symbols are declared as files are added, and references are resolved by
+/// [`SymbolTable::resolve`] once all of the files of a design have been added. Identifiers are
+/// looked up through the enclosing scopes, along with the base classes of class scopes and the
+/// packages imported into each scope, and hierarchical names are followed through instances,
+/// generate blocks, named blocks, and class objects. The first component of a hierarchical name
+/// that can not be found is not reported, as it may be an upwards reference that can only be
+/// resolved once the design is elaborated.
+///
+/// IEEE 1800-2017 § 3.13, § 23.6, § 26.3
+#[derive(Clone, Debug)]
+pub struct SymbolTable {
+	std:         LanguageStd,
+	scopes:      Vec<Scope>,
+	symbols:     Vec<Symbol>,
+	references:  Vec<Reference>,
+	state:       Vec<ResolveState>,
+	/// The compilation unit scope of each file
+	units:       BTreeMap<FileId, ScopeId>,
+	/// The modules, interfaces, programs, primitives, and configurations by name
+	definitions: BTreeMap<String, SymbolId>,
+	packages:    BTreeMap<String, SymbolId>,
+	diagnostics: Vec<Location<StringDiagnostic>>,
+}
+
+impl SymbolTable {
+	pub fn new(std: LanguageStd) -> Self {
+		Self {
+			std,
+			scopes: Vec::new(),
+			symbols: Vec::new(),
+			references: Vec::new(),
+			state: Vec::new(),
+			units: BTreeMap::new(),
+			definitions: BTreeMap::new(),
+			packages: BTreeMap::new(),
+			diagnostics: Vec::new(),
+		}
+	}
+
+	/// Declare the symbols of the source file `ast` and collect its references, returning its
+	/// compilation unit scope
+	///
+	/// Duplicate declarations are reported as they are found, the later declaration is kept in
+	/// the table but can not be found by name.
+	pub fn add_file(&mut self, file: FileId, ast: &Ast) -> ScopeId {
+		let unit = self.push_scope(
+			ScopeKind::CompilationUnit,
+			None,
+			None,
+			Location::new(file, (), Span::empty()),
+		);
+		self.units.insert(file, unit);
+
+		let mut collector = Collector::new(self, file, unit);
+		collector.descriptions(ast.descriptions());
+
+		unit
+	}
+
+	/// Resolve every reference that has not been resolved yet, reporting undeclared identifiers
+	///
+	/// Class bases are resolved first so members can be inherited regardless of the order the
+	/// classes were declared in, then identifiers that may implicitly declare nets so any other
+	/// use of those nets resolves to them.
+	pub fn resolve(&mut self) {
+		let bases = self
+			.scopes
+			.iter()
+			.flat_map(|scope| scope.bases.iter().copied())
+			.collect::<Vec<_>>();
+		for reference in bases {
+			self.resolve_reference(reference);
+		}
+
+		for idx in 0..self.references.len() {
+			if self.references[idx].kind == ReferenceKind::ImplicitNet {
+				self.resolve_reference(ReferenceId(idx));
+			}
+		}
+
+		for idx in 0..self.references.len() {
+			self.resolve_reference(ReferenceId(idx));
+		}
+	}
+
+	/// Duplicate declarations found while adding files, and undeclared identifiers found while
+	/// resolving
+	pub fn diagnostics(&self) -> &[Location<StringDiagnostic>] {
+		&self.diagnostics
+	}
+
+	pub fn scope(&self, id: ScopeId) -> &Scope {
+		&self.scopes[id.0]
+	}
+
+	pub fn symbol(&self, id: SymbolId) -> &Symbol {
+		&self.symbols[id.0]
+	}
+
+	pub fn reference(&self, id: ReferenceId) -> &Reference {
+		&self.references[id.0]
+	}
+
+	pub fn symbols(&self) -> impl Iterator<Item = (SymbolId, &Symbol)> {
+		self.symbols
+			.iter()
+			.enumerate()
+			.map(|(idx, symbol)| (SymbolId(idx), symbol))
+	}
+
+	pub fn references(&self) -> impl Iterator<Item = (ReferenceId, &Reference)> {
+		self.references
+			.iter()
+			.enumerate()
+			.map(|(idx, reference)| (ReferenceId(idx), reference))
+	}
+
+	/// The compilation unit scope of `file`, if it has been added
+	pub fn unit(&self, file: FileId) -> Option<ScopeId> {
+		self.units.get(&file).copied()
+	}
+
+	/// The module, interface, program, primitive, or configuration named `name`
+	pub fn definition(&self, name: &str) -> Option<SymbolId> {
+		self.definitions.get(name).copied()
+	}
+
+	pub fn package(&self, name: &str) -> Option<SymbolId> {
+		self.packages.get(name).copied()
+	}
+
+	/// The symbols declared directly in `scope`, by name
+	pub fn members(&self, scope: ScopeId) -> impl Iterator<Item = SymbolId> {
+		self.scopes[scope.0].symbols.values().copied()
+	}
+
+	/// Look up `name` from `scope` as a simple identifier would be
+	pub fn lookup(&self, scope: ScopeId, name: &str) -> Option<SymbolId> {
+		self.find(scope, name).ok()
+	}
+
+	/// The modules, interfaces, programs, primitives, and configurations of every file, by name
+	pub fn definitions(&self) -> impl Iterator<Item = SymbolId> {
+		self.definitions.values().copied()
+	}
+
+	/// Every symbol a simple identifier in `scope` can refer to, by name
+	///
+	/// The symbols of inner scopes hide those of the same name in the scopes enclosing them, and
+	/// the symbols declared in a scope hide those it imports from packages.
+	pub fn visible(&self, scope: ScopeId) -> Vec<SymbolId> {
+		let mut visible = BTreeMap::<&str, SymbolId>::new();
+		let mut current = Some(scope);
+
+		while let Some(id) = current {
+			let scope = &self.scopes[id.0];
+			for (name, symbol) in &scope.symbols {
+				visible.entry(name).or_insert(*symbol);
+			}
+
+			for import in &scope.imports {
+				let Some(package) = self
+					.package(&import.package)
+					.and_then(|package| self.symbols[package.0].body)
+				else {
+					continue;
+				};
+
+				for (name, symbol) in &self.scopes[package.0].symbols {
+					if import.item.as_deref().is_none_or(|item| item == name) {
+						visible.entry(name).or_insert(*symbol);
+					}
+				}
+			}
+
+			current = scope.parent;
+		}
+
+		visible.into_values().collect()
+	}
+
+	/// The symbol the reference resolves to, or the symbol declared with the name at `offset`
+	/// in `file`
+	pub fn symbol_at(&self, file: FileId, offset: u32) -> Option<SymbolId> {
+		let contains = |location: &Location<()>| {
+			*location.file_id() == file && location.begin() <= &offset && &offset <= location.end()
+		};
+
+		self.references
+			.iter()
+			.find(|reference| contains(&reference.location))
+			.and_then(|reference| reference.target)
+			.or_else(|| {
+				self.symbols
+					.iter()
+					.position(|symbol| contains(&symbol.location))
+					.map(SymbolId)
+			})
+	}
+
+	/// The innermost scope containing `offset` in `file`
+	pub fn scope_at(&self, file: FileId, offset: u32) -> Option<ScopeId> {
+		let unit = self.unit(file)?;
+
+		self.scopes
+			.iter()
+			.enumerate()
+			.filter(|(_, scope)| {
+				*scope.location.file_id() == file &&
+					scope.location.begin() <= &offset &&
+					&offset <= scope.location.end()
+			})
+			.min_by_key(|(_, scope)| scope.location.end() - scope.location.begin())
+			.map(|(idx, _)| ScopeId(idx))
+			.or(Some(unit))
+	}
+
+	/// The references that resolve to `symbol`
+	pub fn references_to(&self, symbol: SymbolId) -> impl Iterator<Item = &Reference> {
+		self.references
+			.iter()
+			.filter(move |reference| reference.target == Some(symbol))
+	}
+
+	/// The symbol the type of `symbol` resolves to, such as the module of an instance or the
+	/// class of an object
+	pub fn type_of(&self, symbol: SymbolId) -> Option<SymbolId> {
+		self.symbols[symbol.0]
+			.type_name
+			.and_then(|reference| self.references[reference.0].target)
+	}
+
+	fn push_scope(
+		&mut self,
+		kind: ScopeKind,
+		name: Option<String>,
+		parent: Option<ScopeId>,
+		location: Location<()>,
+	) -> ScopeId {
+		self.scopes.push(Scope {
+			kind,
+			name,
+			parent,
+			location,
+			symbols: BTreeMap::new(),
+			imports: Vec::new(),
+			bases: Vec::new(),
+			open: false,
+		});
+
+		ScopeId(self.scopes.len() - 1)
+	}
+
+	/// Declare `symbol`, reporting it if it is a duplicate of a symbol already in its scope
+	fn declare(&mut self, symbol: Symbol) -> SymbolId {
+		let id = SymbolId(self.symbols.len());
+
+		let existing = match symbol.kind {
+			SymbolKind::Module |
+			SymbolKind::Interface |
+			SymbolKind::Program |
+			SymbolKind::Primitive |
+			SymbolKind::Config => self.definitions.get(&symbol.name),
+			SymbolKind::Package => self.packages.get(&symbol.name),
+			_ => self.scopes[symbol.scope.0].symbols.get(&symbol.name),
+		}
+		.copied();
+
+		let replace = match existing {
+			None => true,
+			Some(existing) => {
+				let existing = &self.symbols[existing.0];
+
+				if existing.forward {
+					true
+				} else if !is_redeclaration(existing.kind, symbol.kind) {
+					self.diagnostics.push(Location::new(
+						*symbol.location.file_id(),
+						StringDiagnostic::new(
+							duplicate_code(self.std),
+							format!("Duplicate declaration of `{}`", symbol.name),
+							Some(*symbol.location.span()),
+						),
+						*symbol.location.span(),
+					));
+					false
+				} else {
+					false
+				}
+			},
+		};
+
+		if replace {
+			let names = match symbol.kind {
+				SymbolKind::Module |
+				SymbolKind::Interface |
+				SymbolKind::Program |
+				SymbolKind::Primitive |
+				SymbolKind::Config => &mut self.definitions,
+				SymbolKind::Package => &mut self.packages,
+				_ => &mut self.scopes[symbol.scope.0].symbols,
+			};
+			names.insert(symbol.name.clone(), id);
+		}
+
+		self.symbols.push(symbol);
+		id
+	}
+
+	fn push_reference(&mut self, reference: Reference) -> ReferenceId {
+		self.references.push(reference);
+		self.state.push(ResolveState::Unresolved);
+
+		ReferenceId(self.references.len() - 1)
+	}
+
+	fn resolve_reference(&mut self, id: ReferenceId) {
+		if self.state[id.0] != ResolveState::Unresolved {
+			return;
+		}
+		self.state[id.0] = ResolveState::Resolving;
+
+		if let Some(Prefix::Reference(prefix)) = self.references[id.0].prefix {
+			self.resolve_reference(prefix);
+		}
+
+		let reference = &self.references[id.0];
+		let (target, message) = match reference.kind {
+			ReferenceKind::Name |
+			ReferenceKind::ImplicitNet |
+			ReferenceKind::Call |
+			ReferenceKind::Type |
+			ReferenceKind::Nature => self.resolve_name(id),
+			ReferenceKind::Definition => (self.definition(&reference.name), None),
+			ReferenceKind::Package => match self.package(&reference.name) {
+				Some(package) => (Some(package), None),
+				None if is_builtin(&reference.name) => (None, None),
+				None => (
+					None,
+					Some(format!("Undeclared package `{}`", reference.name)),
+				),
+			},
+			ReferenceKind::ScopePrefix => {
+				match self
+					.package(&reference.name)
+					.or_else(|| self.lookup(reference.scope, &reference.name))
+				{
+					Some(target) => (Some(target), None),
+					None => self.undeclared(id, false),
+				}
+			},
+			ReferenceKind::Member |
+			ReferenceKind::ScopeMember |
+			ReferenceKind::Port |
+			ReferenceKind::Parameter => self.resolve_member(id),
+		};
+
+		if let Some(message) = message {
+			let location = self.references[id.0].location;
+			self.diagnostics
+				.push(location.with_inner(StringDiagnostic::new(
+					undeclared_code(self.std),
+					message,
+					Some(*location.span()),
+				)));
+		}
+
+		self.references[id.0].target = target;
+		self.state[id.0] = ResolveState::Resolved;
+	}
+
+	/// Resolve a simple identifier through the enclosing scopes
+	fn resolve_name(&mut self, id: ReferenceId) -> (Option<SymbolId>, Option<String>) {
+		let reference = &self.references[id.0];
+
+		let open = match self.find(reference.scope, &reference.name) {
+			Ok(target) => return (Some(target), None),
+			Err(open) => open,
+		};
+
+		// Interfaces are used as the types of interface ports, and modules may be the first
+		// component of a hierarchical name
+		if (reference.kind == ReferenceKind::Type || reference.upward) &&
+			let Some(definition) = self.definition(&reference.name)
+		{
+			return (Some(definition), None);
+		}
+
+		if reference.kind == ReferenceKind::ImplicitNet {
+			let symbol = Symbol {
+				name:      reference.name.clone(),
+				kind:      SymbolKind::ImplicitNet,
+				location:  reference.location,
+				scope:     reference.scope,
+				body:      None,
+				type_name: None,
+				forward:   false,
+			};
+			return (Some(self.declare(symbol)), None);
+		}
+
+		let reference = &self.references[id.0];
+		// NOTE(aki): Access functions and disciplines usually come from `disciplines.vams`,
+		// which may not have been included
+		let analog = LanguageStd::VERILOG_AMS_STDS.contains(self.std) &&
+			matches!(reference.kind, ReferenceKind::Call | ReferenceKind::Nature);
+
+		if reference.upward || analog {
+			return (None, None);
+		}
+
+		self.undeclared(id, open)
+	}
+
+	/// Resolve a reference to something declared in the scope of its prefix
+	fn resolve_member(&mut self, id: ReferenceId) -> (Option<SymbolId>, Option<String>) {
+		let Reference { prefix, kind, scope, .. } = self.references[id.0];
+
+		let (scope, owner) = match prefix {
+			Some(Prefix::Reference(prefix)) => {
+				let Some(target) = self.references[prefix.0].target else {
+					return (None, None);
+				};
+				match self.member_scope(target, kind) {
+					Some(scope) => (scope, self.symbols[target.0].name.clone()),
+					None => return (None, None),
+				}
+			},
+			Some(Prefix::This) => match self.enclosing_class(scope) {
+				Some(scope) => (scope, "this".to_string()),
+				None => return (None, None),
+			},
+			Some(Prefix::Super) => {
+				let Some(class) = self.enclosing_class(scope) else {
+					return (None, None);
+				};
+				let base = self.scopes[class.0]
+					.bases
+					.first()
+					.and_then(|base| self.references[base.0].target)
+					.and_then(|base| self.symbols[base.0].body);
+
+				match base {
+					Some(scope) => (scope, "super".to_string()),
+					None => return (None, None),
+				}
+			},
+			None => return (None, None),
+		};
+
+		let reference = &self.references[id.0];
+		let mut open = false;
+		if let Some(target) =
+			self.find_member(scope, &reference.name, &mut open, &mut BTreeSet::new())
+		{
+			return (Some(target), None);
+		}
+
+		if open || is_builtin(&reference.name) {
+			return (None, None);
+		}
+
+		let message = match reference.kind {
+			ReferenceKind::Port => format!("`{owner}` has no port `{}`", reference.name),
+			ReferenceKind::Parameter => format!("`{owner}` has no parameter `{}`", reference.name),
+			_ => format!("`{}` is not declared in `{owner}`", reference.name),
+		};
+
+		(None, Some(message))
+	}
+
+	/// The message for an undeclared identifier, unless it may be declared somewhere that
+	/// could not be searched
+	fn undeclared(&self, id: ReferenceId, open: bool) -> (Option<SymbolId>, Option<String>) {
+		let reference = &self.references[id.0];
+
+		if open || is_builtin(&reference.name) {
+			(None, None)
+		} else {
+			(
+				None,
+				Some(format!("Undeclared identifier `{}`", reference.name)),
+			)
+		}
+	}
+
+	/// The scope the members of `symbol` are declared in, if they can be named with a
+	/// reference of `kind`
+	fn member_scope(&mut self, symbol: SymbolId, kind: ReferenceKind) -> Option<ScopeId> {
+		let mut symbol = symbol;
+
+		// Follow instances to their modules and objects to their classes
+		for _ in 0..8 {
+			let current = &self.symbols[symbol.0];
+
+			let hierarchical = match kind {
+				ReferenceKind::ScopeMember => {
+					matches!(current.kind, SymbolKind::Package | SymbolKind::Class)
+				},
+				ReferenceKind::Port | ReferenceKind::Parameter => {
+					matches!(
+						current.kind,
+						SymbolKind::Module |
+							SymbolKind::Interface | SymbolKind::Program |
+							SymbolKind::Primitive
+					)
+				},
+				_ => {
+					matches!(
+						current.kind,
+						SymbolKind::Module |
+							SymbolKind::Interface | SymbolKind::Program |
+							SymbolKind::Class | SymbolKind::Function |
+							SymbolKind::Task | SymbolKind::Block |
+							SymbolKind::GenerateBlock |
+							SymbolKind::Clocking
+					)
+				},
+			};
+
+			if hierarchical {
+				return current.body;
+			}
+
+			let type_name = current.type_name?;
+			self.resolve_reference(type_name);
+			symbol = self.references[type_name.0].target?;
+		}
+
+		None
+	}
+
+	/// The innermost class scope enclosing `scope`
+	fn enclosing_class(&self, scope: ScopeId) -> Option<ScopeId> {
+		let mut current = Some(scope);
+		while let Some(scope) = current {
+			if self.scopes[scope.0].kind == ScopeKind::Class {
+				return Some(scope);
+			}
+			current = self.scopes[scope.0].parent;
+		}
+
+		None
+	}
+
+	/// Look up `name` through `scope` and its enclosing scopes, returning whether any of the
+	/// scopes searched are open if it is not found
+	fn find(&self, scope: ScopeId, name: &str) -> Result<SymbolId, bool> {
+		let mut open = false;
+		let mut current = Some(scope);
+
+		while let Some(id) = current {
+			if let Some(symbol) = self.find_member(id, name, &mut open, &mut BTreeSet::new()) {
+				return Ok(symbol);
+			}
+
+			let scope = &self.scopes[id.0];
+			open |= scope.open;
+
+			// Explicit imports take precedence over wildcard imports
+			// IEEE 1800-2017 § 26.3
+			let explicit = scope
+				.imports
+				.iter()
+				.filter(|import| import.item.as_deref() == Some(name));
+			let wildcard = scope.imports.iter().filter(|import| import.item.is_none());
+
+			for import in explicit.chain(wildcard) {
+				match self
+					.package(&import.package)
+					.and_then(|package| self.symbols[package.0].body)
+				{
+					Some(package) => {
+						if let Some(symbol) = self.scopes[package.0].symbols.get(name) {
+							return Ok(*symbol);
+						}
+					},
+					None => open = true,
+				}
+			}
+
+			current = scope.parent;
+		}
+
+		Err(open)
+	}
+
+	/// Look up `name` in `scope` and the scopes of its base classes
+	fn find_member(
+		&self,
+		scope: ScopeId,
+		name: &str,
+		open: &mut bool,
+		visited: &mut BTreeSet<ScopeId>,
+	) -> Option<SymbolId> {
+		if !visited.insert(scope) {
+			return None;
+		}
+
+		let current = &self.scopes[scope.0];
+		if let Some(symbol) = current.symbols.get(name) {
+			return Some(*symbol);
+		}
+
+		for base in &current.bases {
+			match self.references[base.0]
+				.target
+				.and_then(|base| self.symbols[base.0].body)
+			{
+				Some(base) => {
+					if let Some(symbol) = self.find_member(base, name, open, visited) {
+						return Some(symbol);
+					}
+				},
+				None => *open = true,
+			}
+		}
+
+		None
+	}
+}
+
+/// Whether declaring `kind` after `existing` in the same scope is allowed
+fn is_redeclaration(existing: SymbolKind, kind: SymbolKind) -> bool {
+	match (existing, kind) {
+		// Non-ANSI ports are declared again with their net or variable type
+		// IEEE 1364-2005 § 12.3.3
+		(SymbolKind::Port, SymbolKind::Net | SymbolKind::Variable) => true,
+		// Only one of the alternatives of a conditional generate construct is elaborated, so
+		// they may share a name
+		// IEEE 1800-2017 § 27.5
+		(SymbolKind::GenerateBlock, SymbolKind::GenerateBlock) => true,
+		_ => false,
+	}
+}
+
+fn is_builtin(name: &str) -> bool {
+	BUILTIN_NAMES.binary_search(&name).is_ok()
+}
+
+/// The diagnostic code for undeclared identifiers in `std`
+fn undeclared_code(std: LanguageStd) -> Code {
+	if LanguageStd::SYSTEM_VERILOG_STDS.contains(std) {
+		system_verilog::E0005
+	} else if LanguageStd::VERILOG_AMS_STDS.contains(std) {
+		verilog_ams::E0004
+	} else {
+		verilog::E0004
+	}
+}
+
+/// The diagnostic code for duplicate declarations in `std`
+fn duplicate_code(std: LanguageStd) -> Code {
+	if LanguageStd::SYSTEM_VERILOG_STDS.contains(std) {
+		system_verilog::E0006
+	} else if LanguageStd::VERILOG_AMS_STDS.contains(std) {
+		verilog_ams::E0005
+	} else {
+		verilog::E0005
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use vermilion_diagnostics::Diagnostic;
+
+	use super::*;
+	use crate::lang::parser::VerilogParser;
+
+	fn table(std: LanguageStd, sources: &[&str]) -> SymbolTable {
+		let mut table = SymbolTable::new(std);
+
+		for (idx, source) in sources.iter().enumerate() {
+			let ast = VerilogParser::new(std, source.as_bytes().into())
+				.unwrap_or_else(|err| panic!("Failed to create parser: {err}"))
+				.parse();
+			assert!(
+				ast.diagnostics().is_empty(),
+				"Unexpected parse errors: {:#?}",
+				ast.diagnostics()
+			);
+
+			table.add_file(FileId::new(idx), &ast);
+		}
+
+		table.resolve();
+		table
+	}
+
+	fn messages(table: &SymbolTable) -> Vec<String> {
+		table
+			.diagnostics()
+			.iter()
+			.map(|diagnostic| diagnostic.inner().message().to_string())
+			.collect()
+	}
+
+	#[test]
+	fn test_undeclared_identifier() {
+		let source =
+			"module m(input a, output y);\n\twire b;\n\tassign y = a & b & c;\nendmodule\n";
+		let table = table(LanguageStd::Vl05, &[source]);
+
+		assert_eq!(messages(&table), ["Undeclared identifier `c`"]);
+		assert_eq!(table.diagnostics()[0].inner().code(), verilog::E0004);
+		assert_eq!(
+			*table.diagnostics()[0].begin() as usize,
+			source.find("c;").unwrap_or_default()
+		);
+	}
+
+	#[test]
+	fn test_duplicate_declaration() {
+		let table = table(
+			LanguageStd::Vl05,
+			&["module m(a, y);\n\tinput a;\n\toutput y;\n\twire a;\n\treg y;\n\tinteger \
+			   i;\n\treal i;\nendmodule\nmodule m;\nendmodule\n"],
+		);
+
+		assert_eq!(
+			messages(&table),
+			["Duplicate declaration of `i`", "Duplicate declaration of `m`"]
+		);
+		assert_eq!(table.diagnostics()[0].inner().code(), verilog::E0005);
+	}
+
+	#[test]
+	fn test_implicit_nets() {
+		let table =
+			table(
+				LanguageStd::Vl05,
+				&["module top;\n\tsub u1 (.a(n1), .y(n2));\n\tand g1 (n3, n1, n2);\n\tassign n4 \
+				   = n3;\n\twire w = n4;\nendmodule\nmodule sub(input a, output y);\nendmodule\n"],
+			);
+		assert!(messages(&table).is_empty(), "{:#?}", messages(&table));
+
+		let top = table
+			.definition("top")
+			.and_then(|top| table.symbol(top).body)
+			.unwrap_or_else(|| panic!("No module `top`"));
+		let implicit = table
+			.members(top)
+			.filter(|symbol| table.symbol(*symbol).kind == SymbolKind::ImplicitNet)
+			.map(|symbol| table.symbol(symbol).name.as_str())
+			.collect::<Vec<_>>();
+		assert_eq!(implicit, ["n1", "n2", "n3", "n4"]);
+	}
+
+	#[test]
+	fn test_package_imports() {
+		let table = table(
+			LanguageStd::Sv17,
+			&[
+				"package a;\n\ttypedef logic [7:0] byte_t;\n\tparameter int W = 8;\nendpackage\n",
+				"package b;\n\tparameter int D = 4;\n\tparameter int E = 5;\nendpackage\nmodule \
+				 m;\n\timport a::*;\n\timport b::D;\n\tbyte_t x = W + D + b::E + a::Z;\n\tlogic y \
+				 = E;\n\timport c::*;\nendmodule\n",
+			],
+		);
+
+		assert_eq!(
+			messages(&table),
+			["`Z` is not declared in `a`", "Undeclared package `c`"]
+		);
+		assert_eq!(table.diagnostics()[1].inner().code(), system_verilog::E0005);
+
+		// `E` is not imported, but the wildcard import of the missing `c` may provide it
+		let unit = table
+			.unit(FileId::new(1usize))
+			.unwrap_or_else(|| panic!("No compilation unit"));
+		assert!(table.lookup(unit, "E").is_none());
+	}
+
+	#[test]
+	fn test_hierarchical_references() {
+		let table = table(
+			LanguageStd::Vl05,
+			&["module top;\n\tsub u1 ();\n\tgenerate\n\t\tif (1) begin : g\n\t\t\treg \
+			   r;\n\t\tend else begin : g\n\t\t\treg q;\n\t\tend\n\tendgenerate\n\tinitial \
+			   begin\n\t\tu1.s = g.r;\n\t\tu1.t = 0;\n\t\ttb.dut.x = \
+			   0;\n\tend\nendmodule\nmodule sub;\n\treg s;\nendmodule\n"],
+		);
+
+		assert_eq!(messages(&table), ["`t` is not declared in `u1`"]);
+
+		let s = table
+			.references()
+			.find(|(_, reference)| reference.name == "s")
+			.and_then(|(_, reference)| reference.target)
+			.unwrap_or_else(|| panic!("`u1.s` was not resolved"));
+		assert_eq!(table.symbol(s).kind, SymbolKind::Variable);
+		assert_eq!(
+			table.scope(table.symbol(s).scope).name.as_deref(),
+			Some("sub")
+		);
+	}
+
+	#[test]
+	fn test_instance_ports_and_parameters() {
+		let table = table(
+			LanguageStd::Vl05,
+			&[
+				"module top;\n\twire a, b;\n\tsub #(.W(2), .X(3)) u1 (.a(a), .b(b), \
+				 .c());\nendmodule\n",
+				"module sub #(parameter W = 1) (input a, output b);\nendmodule\n",
+			],
+		);
+
+		assert_eq!(
+			messages(&table),
+			["`sub` has no parameter `X`", "`sub` has no port `c`"]
+		);
+
+		let u1 = table
+			.symbols()
+			.find(|(_, symbol)| symbol.name == "u1")
+			.map(|(id, _)| id)
+			.unwrap_or_else(|| panic!("No instance `u1`"));
+		assert_eq!(table.type_of(u1), table.definition("sub"));
+	}
+
+	#[test]
+	fn test_classes() {
+		let table = table(
+			LanguageStd::Sv17,
+			&[
+				"class base;\n\tint x;\n\tfunction new();\n\tendfunction\nendclass\nclass derived \
+				 extends base;\n\tint y;\n\textern function void f();\nendclass\nfunction void \
+				 derived::f();\n\ty = x + this.x + z;\nendfunction\nclass other extends \
+				 unknown;\n\tfunction void g();\n\t\tw = 1;\n\tendfunction\nendclass\n",
+			],
+		);
+
+		assert_eq!(
+			messages(&table),
+			["Undeclared identifier `unknown`", "Undeclared identifier `z`"]
+		);
+	}
+
+	#[test]
+	fn test_scoped_declarations() {
+		let table =
+			table(
+				LanguageStd::Sv17,
+				&["typedef class c;\nclass c;\nendclass\nmodule m;\n\ttypedef enum { IDLE, BUSY \
+				   } state_t;\n\tstate_t state = IDLE;\n\tint q[$];\n\tinitial begin\n\t\tfor \
+				   (int i = 0; i < 4; i++) state = BUSY;\n\t\tforeach (q[j]) q[j] = j;\n\t\tq = \
+				   q.find with (item > 1);\n\t\ti = 0;\n\tend\nendmodule\n"],
+			);
+
+		assert_eq!(messages(&table), ["Undeclared identifier `i`"]);
+	}
+
+	#[test]
+	fn test_symbol_at() {
+		let source = "module m;\n\twire a;\n\tassign a = 1'b0;\nendmodule\n";
+		let table = table(LanguageStd::Vl05, &[source]);
+		let file = FileId::new(0usize);
+
+		let declaration = source.find("a;").unwrap_or_default() as u32;
+		let usage = source.find("a =").unwrap_or_default() as u32;
+
+		let symbol = table
+			.symbol_at(file, declaration)
+			.unwrap_or_else(|| panic!("No symbol at the declaration"));
+		assert_eq!(table.symbol_at(file, usage), Some(symbol));
+		assert_eq!(table.references_to(symbol).count(), 1);
+
+		let scope = table
+			.scope_at(file, usage)
+			.unwrap_or_else(|| panic!("No scope at the usage"));
+		assert_eq!(table.scope(scope).kind, ScopeKind::Module);
+		assert_eq!(table.lookup(scope, "a"), Some(symbol));
+	}
+
+	#[test]
+	fn test_visible() {
+		let source = "package p;\n\tparameter int W = 8;\n\tparameter int D = \
+		              4;\nendpackage\nmodule m;\n\timport p::W;\n\twire a, D;\n\tinitial begin : \
+		              b\n\t\treg a;\n\t\ta = 0;\n\tend\nendmodule\n";
+		let table = table(LanguageStd::Sv17, &[source]);
+		let file = FileId::new(0usize);
+
+		let scope = table
+			.scope_at(file, source.find("a = 0").unwrap_or_default() as u32)
+			.unwrap_or_else(|| panic!("No scope in the block"));
+		let visible = table
+			.visible(scope)
+			.into_iter()
+			.map(|symbol| {
+				let symbol = table.symbol(symbol);
+				(symbol.name.as_str(), table.scope(symbol.scope).kind)
+			})
+			.collect::<Vec<_>>();
+
+		// The `a` of the block hides the one of the module, and `D` is not imported
+		assert_eq!(
+			visible,
+			[
+				("D", ScopeKind::Module),
+				("W", ScopeKind::Package),
+				("a", ScopeKind::Block),
+				("b", ScopeKind::Module),
+			]
+		);
+		assert_eq!(table.definitions().count(), 1);
+	}
+
+	#[test]
+	fn test_analog() {
+		let table = table(
+			LanguageStd::Vams23,
+			&[
+				"nature Voltage;\n\taccess = V;\nendnature\ndiscipline electrical;\n\tpotential \
+				 Voltage;\nenddiscipline\nmodule res(p, n);\n\tinout p, n;\n\telectrical p, \
+				 n;\n\tparameter real r = 1e3;\n\tanalog V(p, n) <+ r * I(p, n);\nendmodule\n",
+			],
+		);
+
+		assert!(messages(&table).is_empty(), "{:#?}", messages(&table));
+
+		let access = table
+			.references()
+			.find(|(_, reference)| reference.name == "V")
+			.and_then(|(_, reference)| reference.target)
+			.unwrap_or_else(|| panic!("`V` was not resolved"));
+		assert_eq!(table.symbol(access).kind, SymbolKind::AccessFunction);
+	}
+}
@@ -3068,6 +3068,26 @@ pub fn get_builtin_sysfunc(ident: &str, std: LanguageStd) -> Option<BuiltinSysFu
 	}
 }
 
+/// Get every builtin system function in the given standard, in no particular order
+pub fn builtin_sysfuncs(std: LanguageStd) -> impl Iterator<Item = BuiltinSysFunc> {
+	let map = match std {
+		LanguageStd::Vl95 => Some(&VERILOG_95_BUILTIN_SYSFUNC_MAP),
+		LanguageStd::Vl01 => Some(&VERILOG_01_BUILTIN_SYSFUNC_MAP),
+		LanguageStd::Vl05 => Some(&VERILOG_05_BUILTIN_SYSFUNC_MAP),
+		LanguageStd::Sv05 => Some(&SYSTEM_VERILOG_05_BUILTIN_SYSFUNC_MAP),
+		LanguageStd::Sv09 => Some(&SYSTEM_VERILOG_09_BUILTIN_SYSFUNC_MAP),
+		LanguageStd::Sv12 => Some(&SYSTEM_VERILOG_12_BUILTIN_SYSFUNC_MAP),
+		LanguageStd::Sv17 => Some(&SYSTEM_VERILOG_17_BUILTIN_SYSFUNC_MAP),
+		LanguageStd::Sv23 => Some(&SYSTEM_VERILOG_23_BUILTIN_SYSFUNC_MAP),
+		LanguageStd::Vams09 => Some(&VERILOG_AMS_09_BUILTIN_SYSFUNC_MAP),
+		LanguageStd::Vams14 => Some(&VERILOG_AMS_14_BUILTIN_SYSFUNC_MAP),
+		LanguageStd::Vams23 => Some(&VERILOG_AMS_23_BUILTIN_SYSFUNC_MAP),
+		_ => None,
+	};
+
+	map.into_iter().flat_map(|map| map.values().copied())
+}
+
 /// Check to see if the given task name is a timing task or not.
 ///
 /// In IEEE 1364-1995 the timing tasks were classified as system tasks, but the were
@@ -3150,259 +3170,266 @@ pub fn is_builtin_sysfunc(ident: &str) -> LanguageStd {
 	supported
 }
 
+impl BuiltinSysFunc {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Acos => "acos",                             // Added: IEEE 1364-2005
+			Self::Acosh => "acosh",                           // Added: IEEE 1364-2005
+			Self::AnalogNodeAlias => "analog_node_alias",     // Added: Verilog-AMS 2.4.0
+			Self::AnalogPortAlias => "analog_port_alias",     // Added: Verilog-AMS 2.4.0
+			Self::Asin => "asin",                             // Added: IEEE 1364-2005
+			Self::Asinh => "asinh",                           // Added: IEEE 1364-2005
+			Self::AssertControl => "assertcontrol",           // Added: IEEE 1800-2012
+			Self::AssertFailOff => "assertfailoff",           // Added: IEEE 1800-2009
+			Self::AssertFailOn => "assertfailon",             // Added: IEEE 1800-2009
+			Self::AssertKill => "assertkill",                 // Added: IEEE 1800-2005
+			Self::AssertNonVacuousOn => "assertnonvacuouson", // Added: IEEE 1800-2009
+			Self::AssertOff => "assertoff",                   // Added: IEEE 1800-2005
+			Self::AssertOn => "asserton",                     // Added: IEEE 1800-2005
+			Self::AssertPassOff => "assertpassoff",           // Added: IEEE 1800-2009
+			Self::AssertPassOn => "assertpasson",             // Added: IEEE 1800-2009
+			Self::AssertVacuousOff => "assertvacuousoff",     // Added: IEEE 1800-2009
+			Self::AsyncAndArray => "async$and$array",
+			Self::AsyncAndPlane => "async$and$plane",
+			Self::AsyncNandArray => "async$nand$array",
+			Self::AsyncNandPlane => "async$nand$plane",
+			Self::AsyncNorArray => "async$nor$array",
+			Self::AsyncNorPlane => "async$nor$plane",
+			Self::AsyncOrArray => "async$or$array",
+			Self::AsyncOrPlane => "async$or$plane",
+			Self::Atan => "atan",   // Added: IEEE 1364-2005
+			Self::Atan2 => "atan2", // Added: IEEE 1364-2005
+			Self::Atanh => "atanh", // Added: IEEE 1364-2005
+			Self::Bits => "bits",   // Added: IEEE 1800-2005
+			Self::BitsToReal => "bitstoreal",
+			Self::BitsToShortReal => "bitstoshortreal", // Added: IEEE 1800-2005
+			Self::Cast => "cast",                       // Added: IEEE 1800-2005
+			Self::Ceil => "ceil",                       // Added: IEEE 1364-2005
+			Self::Changed => "changed",                 // Added: IEEE 1800-2009
+			Self::ChangedGclk => "changed_gclk",        // Added: IEEE 1800-2009
+			Self::ChangingGclk => "changing_gclk",      // Added: IEEE 1800-2009
+			Self::Clog2 => "clog2",                     // Added: IEEE 1364-2005
+			Self::Cos => "cos",                         // Added: IEEE 1364-2005
+			Self::Cosh => "cosh",                       // Added: IEEE 1364-2005
+			Self::CountBits => "countbits",             // Added: IEEE 1800-2012
+			Self::CountDrivers => "countdrivers",
+			Self::CountOnes => "countones", // Added: IEEE 1800-2005
+			Self::CoverageControl => "coverage_control", // Added: IEEE 1800-2005
+			Self::CoverageGet => "coverage_get", // Added: IEEE 1800-2005
+			Self::CoverageGetMax => "coverage_get_max", // Added: IEEE 1800-2005
+			Self::CoverageMerge => "coverage_merge", // Added: IEEE 1800-2005
+			Self::CoverageSave => "coverage_save", // Added: IEEE 1800-2005
+			Self::Dimensions => "dimensions", // Added: IEEE 1800-2005
+			Self::Display => "display",
+			Self::DisplayB => "displayb",
+			Self::DisplayH => "displayh",
+			Self::DisplayO => "displayo",
+			Self::DistChiSquare => "dist_chi_square",
+			Self::DistErlang => "dist_erlang",
+			Self::DistExponential => "dist_exponential",
+			Self::DistNormal => "dist_normal",
+			Self::DistPoisson => "dist_poisson",
+			Self::DistT => "dist_t",
+			Self::DistUniform => "dist_uniform",
+			Self::DumpAll => "dumpall",
+			Self::DumpFile => "dumpfile",
+			Self::DumpFlush => "dumpflush",
+			Self::DumpLimit => "dumplimit",
+			Self::DumpOff => "dumpoff",
+			Self::DumpOn => "dumpon",
+			Self::DumpPorts => "dumpports",           // Added: IEEE 1364-2001
+			Self::DumpPortsAll => "dumpportsall",     // Added: IEEE 1364-2001
+			Self::DumpPortsFlush => "dumpportsflush", // Added: IEEE 1364-2001
+			Self::DumpPortsLimit => "dumpportslimit", // Added: IEEE 1364-2001
+			Self::DumpPortsOff => "dumpportsoff",     // Added: IEEE 1364-2001
+			Self::DumpPortsOn => "dumpportson",       // Added: IEEE 1364-2001
+			Self::DumpVars => "dumpvars",
+			Self::Error => "error",              // Added: IEEE 1800-2005
+			Self::Exit => "exit",                // Added: IEEE 1800-2005
+			Self::Exp => "exp",                  // Added: IEEE 1364-2005
+			Self::FallingGclk => "falling_gclk", // Added: IEEE 1800-2009
+			Self::Fatal => "fatal",              // Added: IEEE 1800-2005
+			Self::Fclose => "fclose",
+			Self::Fdisplay => "fdisplay",
+			Self::FdisplayB => "fdisplayb",
+			Self::FdisplayH => "fdisplayh",
+			Self::FdisplayO => "fdisplayo",
+			Self::Fell => "fell",          // Added: IEEE 1800-2005
+			Self::FellGclk => "fell_gclk", // Added: IEEE 1800-2009
+			Self::Feof => "feof",          // Added: IEEE 1364-2005
+			Self::Ferror => "ferror",      // Added: IEEE 1364-2001
+			Self::Fflush => "fflush",      // Added: IEEE 1364-2001
+			Self::Fgetc => "fgetc",        // Added: IEEE 1364-2001
+			Self::Fgets => "fgets",        // Added: IEEE 1364-2001
+			Self::Finish => "finish",
+			Self::Floor => "floor", // Added: IEEE 1364-2005
+			Self::Fmonitor => "fmonitor",
+			Self::FmonitorB => "fmonitorb",
+			Self::FmonitorH => "fmonitorh",
+			Self::FmonitorO => "fmonitoro",
+			Self::Fopen => "fopen",
+			Self::Fread => "fread",   // Added: IEEE 1364-2001
+			Self::Fscanf => "fscanf", // Added: IEEE 1364-2001
+			Self::Fseek => "fseek",   // Added: IEEE 1364-2001
+			Self::Fstrobe => "fstrobe",
+			Self::FstrobeB => "fstrobeb",
+			Self::FstrobeH => "fstrobeh",
+			Self::FstrobeO => "fstrobeo",
+			Self::Ftell => "ftell",            // Added: IEEE 1364-2001
+			Self::FutureGclk => "future_gclk", // Added: IEEE 1800-2009
+			Self::Fwrite => "fwrite",
+			Self::FwriteB => "fwriteb",
+			Self::FwriteH => "fwriteh",
+			Self::FwriteO => "fwriteo",
+			Self::GetCoverage => "get_coverage", // Added: IEEE 1800-2005
+			Self::GetPattern => "getpattern",
+			Self::High => "high",           // Added: IEEE 1800-2005
+			Self::Hold => "hold",           // Removed: IEEE 1364-2001
+			Self::Hypot => "hypot",         // Added: IEEE 1364-2005
+			Self::Increment => "increment", // Added: IEEE 1800-2005
+			Self::IncSave => "incsave",
+			Self::Info => "info", // Added: IEEE 1800-2005
+			Self::Input => "input",
+			Self::IsUnbounded => "isunbounded", // Added: IEEE 1800-2005
+			Self::IsUnknown => "isunknown",     // Added: IEEE 1800-2005
+			Self::Itor => "itor",
+			Self::Key => "key",
+			Self::Left => "left", // Added: IEEE 1800-2005
+			Self::List => "list",
+			Self::Ln => "ln",                           // Added: IEEE 1364-2005
+			Self::LoadCoverageDB => "load_coverage_db", // Added: IEEE 1800-2005
+			Self::Log => "log",
+			Self::Log10 => "log10", // Added: IEEE 1364-2005
+			Self::Low => "low",     // Added: IEEE 1800-2005
+			Self::Monitor => "monitor",
+			Self::MonitorB => "monitorb",
+			Self::MonitorH => "monitorh",
+			Self::MonitorO => "monitoro",
+			Self::MonitorOff => "monitoroff",
+			Self::MonitorOn => "monitoron",
+			Self::NoChange => "nochange", // Removed: IEEE 1364-2001
+			Self::NoKey => "nokey",
+			Self::NoLog => "nolog",
+			Self::OneHot => "onehot",      // Added: IEEE 1800-2005
+			Self::OneHot0 => "onehot0",    // Added: IEEE 1800-2005
+			Self::Past => "past",          // Added: IEEE 1800-2005
+			Self::PastGclk => "past_gclk", // Added: IEEE 1800-2009
+			Self::Period => "period",      // Removed: IEEE 1364-2001
+			Self::Pow => "pow",            // Added: IEEE 1800-2009
+			Self::PrintTimescale => "printtimescale",
+			Self::QAdd => "q_add",
+			Self::QExam => "q_exam",
+			Self::QFull => "q_full",
+			Self::QInitialize => "q_initialize",
+			Self::QRemove => "q_remove",
+			Self::Random => "random",
+			Self::ReadmemB => "readmemb",
+			Self::ReadmemH => "readmemh",
+			Self::RealTime => "realtime",
+			Self::RealToBits => "realtobits",
+			Self::Recovery => "recovery",            // Removed: IEEE 1364-2001
+			Self::ReceiverCount => "receiver_count", // Added: Verilog-AMS 23
+			Self::Reset => "reset",
+			Self::ResetCount => "reset_count",
+			Self::ResetValue => "reset_value",
+			Self::Restart => "restart",
+			Self::Rewind => "rewind",          // Added: IEEE 1364-2001
+			Self::Right => "right",            // Added: IEEE 1800-2005
+			Self::RisingGclk => "rising_gclk", // Added: IEEE 1800-2009
+			Self::Rose => "rose",              // Added: IEEE 1800-2005
+			Self::RoseGclk => "rose_gclk",     // Added: IEEE 1800-2009
+			Self::Rtoi => "rtoi",
+			Self::Sampled => "sampled", // Added: IEEE 1800-2005
+			Self::Save => "save",
+			Self::Scale => "scale",
+			Self::Scope => "scope",
+			Self::SdfAnnotate => "sdf_annotate", // Added: IEEE 1364-2001
+			Self::SetCoverageDb => "set_coverage_db", // Added: IEEE 1800-2005-IEEE 1800-2009
+			Self::SetCoverageDbName => "set_coverage_db_name", // Added: IEEE 1800-2009
+			Self::Setup => "setup",              // Removed: IEEE 1364-2001
+			Self::Setuphold => "setuphold",      // Removed: IEEE 1364-2001
+			Self::Sformat => "sformat",          // Added: IEEE 1364-2001
+			Self::Sformatf => "sformatf",        // Added: IEEE 1800-2009
+			Self::ShortRealToBits => "shortrealtobits", // Added: IEEE 1800-2005
+			Self::Showscopes => "showscopes",
+			Self::Showvars => "showvars",
+			Self::Signed => "signed",           // Added: IEEE 1364-2001
+			Self::SimParam => "simparam",       // Added: Verilog-AMS 2.3.1
+			Self::SimParamStr => "simparamstr", // Added: Verilog-AMS 2.3.1
+			Self::SimProbe => "simprobe",       // Added: Verilog-AMS 2.3.1
+			Self::Sin => "sin",                 // Added: IEEE 1364-2005
+			Self::Sinh => "sinh",               // Added: IEEE 1800-2009
+			Self::Size => "size",               // Added: IEEE 1800-2005
+			Self::Skew => "skew",               // Removed: IEEE 1364-2001
+			Self::Sqrt => "sqrt",               // Added: IEEE 1364-2005
+			Self::SreadmemB => "sreadmemb",
+			Self::SreadmemH => "sreadmemh",
+			Self::Sscanf => "sscanf",          // Added: IEEE 1364-2001
+			Self::Stable => "stable",          // Added: IEEE 1800-2005
+			Self::StableGclk => "stable_gclk", // Added: IEEE 1800-2009
+			Self::Stacktrace => "stacktrace",  // Added: IEEE 1800-2023
+			Self::SteadyGclk => "steady_gclk", // Added: IEEE 1800-2009
+			Self::Stime => "stime",
+			Self::Stop => "stop",
+			Self::Strobe => "strobe",
+			Self::StrobeB => "strobeb",
+			Self::StrobeH => "strobeh",
+			Self::StrobeO => "strobeo",
+			Self::Swrite => "swrite",   // Added: IEEE 1364-2001
+			Self::SwriteB => "swriteb", // Added: IEEE 1364-2001
+			Self::SwriteH => "swriteh", // Added: IEEE 1364-2001
+			Self::SwriteO => "swriteo", // Added: IEEE 1364-2001
+			Self::SyncAndArray => "sync$and$array",
+			Self::SyncAndPlane => "sync$and$plane",
+			Self::SyncNandArray => "sync$nand$array",
+			Self::SyncNandPlane => "sync$nand$plane",
+			Self::SyncNorArray => "sync$nor$array",
+			Self::SyncNorPlane => "sync$nor$plane",
+			Self::SyncOrArray => "sync$or$array",
+			Self::SyncOrPlane => "sync$or$plane",
+			Self::System => "system",              // Added: IEEE 1800-2009
+			Self::TableModel => "table_model",     // Added: Verilog-AMS 2.3.1
+			Self::Tan => "tan",                    // Added: IEEE 1364-2005
+			Self::Tanh => "tanh",                  // Added: IEEE 1364-2005
+			Self::Temperature => "temperature",    // Added: Verilog-AMS 2.3.1
+			Self::TestPlusArgs => "test$plusargs", // Added: IEEE 1364-2001
+			Self::Time => "time",
+			Self::TimeFormat => "timeformat",
+			Self::TimePrecision => "timeprecision", // Added: IEEE 1800-2023
+			Self::TimeUnit => "timeunit",           // Added: IEEE 1800-2023
+			Self::TypeName => "typename",           // Added: IEEE 1800-2005
+			Self::Ungetc => "ungetc",               // Added: IEEE 1364-2001
+			Self::UnpackedDimensions => "unpacked_dimensions", // Added: IEEE 1800-2005
+			Self::Unsigned => "unsigned",           // Added: IEEE 1364-2001
+			Self::Urandom => "urandom",             // Added: IEEE 1800-2005
+			Self::UrandomRange => "urandom_range",  // Added: IEEE 1800-2005
+			Self::ValuePlusArgs => "value$plusargs", // Added: IEEE 1364-2001
+			Self::Vflip => "vflip",                 // Added: Verilog-AMS 2.3.1
+			Self::Vt => "vt",                       // Added: Verilog-AMS 2.3.1
+			Self::Warning => "warning",             // Added: IEEE 1800-2005 & Verilog-AMS
+			// 2.3.1
+			Self::Width => "width", // Removed: IEEE 1364-2001
+			Self::Write => "write",
+			Self::WriteB => "writeb",
+			Self::WriteH => "writeh",
+			Self::WritememB => "writememb", // Added: IEEE 1800-2009
+			Self::WritememH => "writememh", // Added: IEEE 1800-2009
+			Self::WriteO => "writeo",
+			Self::XPosition => "xposition", // Added: Verilog-AMS 2.3.1
+			Self::YPosition => "yposition", // Added: Verilog-AMS 2.3.1
+		}
+	}
+
+	/// The oldest standard the system function or task is in, the one it was added in
+	pub fn added(&self) -> LanguageStd {
+		is_builtin_sysfunc(self.as_str()).earliest()
+	}
+}
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 impl Display for BuiltinSysFunc {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(
-			f,
-			"BuiltinSysFunc(${})",
-			match self {
-				Self::Acos => "acos",                             // Added: IEEE 1364-2005
-				Self::Acosh => "acosh",                           // Added: IEEE 1364-2005
-				Self::AnalogNodeAlias => "analog_node_alias",     // Added: Verilog-AMS 2.4.0
-				Self::AnalogPortAlias => "analog_port_alias",     // Added: Verilog-AMS 2.4.0
-				Self::Asin => "asin",                             // Added: IEEE 1364-2005
-				Self::Asinh => "asinh",                           // Added: IEEE 1364-2005
-				Self::AssertControl => "assertcontrol",           // Added: IEEE 1800-2012
-				Self::AssertFailOff => "assertfailoff",           // Added: IEEE 1800-2009
-				Self::AssertFailOn => "assertfailon",             // Added: IEEE 1800-2009
-				Self::AssertKill => "assertkill",                 // Added: IEEE 1800-2005
-				Self::AssertNonVacuousOn => "assertnonvacuouson", // Added: IEEE 1800-2009
-				Self::AssertOff => "assertoff",                   // Added: IEEE 1800-2005
-				Self::AssertOn => "asserton",                     // Added: IEEE 1800-2005
-				Self::AssertPassOff => "assertpassoff",           // Added: IEEE 1800-2009
-				Self::AssertPassOn => "assertpasson",             // Added: IEEE 1800-2009
-				Self::AssertVacuousOff => "assertvacuousoff",     // Added: IEEE 1800-2009
-				Self::AsyncAndArray => "async$and$array",
-				Self::AsyncAndPlane => "async$and$plane",
-				Self::AsyncNandArray => "async$nand$array",
-				Self::AsyncNandPlane => "async$nand$plane",
-				Self::AsyncNorArray => "async$nor$array",
-				Self::AsyncNorPlane => "async$nor$plane",
-				Self::AsyncOrArray => "async$or$array",
-				Self::AsyncOrPlane => "async$or$plane",
-				Self::Atan => "atan",   // Added: IEEE 1364-2005
-				Self::Atan2 => "atan2", // Added: IEEE 1364-2005
-				Self::Atanh => "atanh", // Added: IEEE 1364-2005
-				Self::Bits => "bits",   // Added: IEEE 1800-2005
-				Self::BitsToReal => "bitstoreal",
-				Self::BitsToShortReal => "bitstoshortreal", // Added: IEEE 1800-2005
-				Self::Cast => "cast",                       // Added: IEEE 1800-2005
-				Self::Ceil => "ceil",                       // Added: IEEE 1364-2005
-				Self::Changed => "changed",                 // Added: IEEE 1800-2009
-				Self::ChangedGclk => "changed_gclk",        // Added: IEEE 1800-2009
-				Self::ChangingGclk => "changing_gclk",      // Added: IEEE 1800-2009
-				Self::Clog2 => "clog2",                     // Added: IEEE 1364-2005
-				Self::Cos => "cos",                         // Added: IEEE 1364-2005
-				Self::Cosh => "cosh",                       // Added: IEEE 1364-2005
-				Self::CountBits => "countbits",             // Added: IEEE 1800-2012
-				Self::CountDrivers => "countdrivers",
-				Self::CountOnes => "countones", // Added: IEEE 1800-2005
-				Self::CoverageControl => "coverage_control", // Added: IEEE 1800-2005
-				Self::CoverageGet => "coverage_get", // Added: IEEE 1800-2005
-				Self::CoverageGetMax => "coverage_get_max", // Added: IEEE 1800-2005
-				Self::CoverageMerge => "coverage_merge", // Added: IEEE 1800-2005
-				Self::CoverageSave => "coverage_save", // Added: IEEE 1800-2005
-				Self::Dimensions => "dimensions", // Added: IEEE 1800-2005
-				Self::Display => "display",
-				Self::DisplayB => "displayb",
-				Self::DisplayH => "displayh",
-				Self::DisplayO => "displayo",
-				Self::DistChiSquare => "dist_chi_square",
-				Self::DistErlang => "dist_erlang",
-				Self::DistExponential => "dist_exponential",
-				Self::DistNormal => "dist_normal",
-				Self::DistPoisson => "dist_poisson",
-				Self::DistT => "dist_t",
-				Self::DistUniform => "dist_uniform",
-				Self::DumpAll => "dumpall",
-				Self::DumpFile => "dumpfile",
-				Self::DumpFlush => "dumpflush",
-				Self::DumpLimit => "dumplimit",
-				Self::DumpOff => "dumpoff",
-				Self::DumpOn => "dumpon",
-				Self::DumpPorts => "dumpports",           // Added: IEEE 1364-2001
-				Self::DumpPortsAll => "dumpportsall",     // Added: IEEE 1364-2001
-				Self::DumpPortsFlush => "dumpportsflush", // Added: IEEE 1364-2001
-				Self::DumpPortsLimit => "dumpportslimit", // Added: IEEE 1364-2001
-				Self::DumpPortsOff => "dumpportsoff",     // Added: IEEE 1364-2001
-				Self::DumpPortsOn => "dumpportson",       // Added: IEEE 1364-2001
-				Self::DumpVars => "dumpvars",
-				Self::Error => "error",              // Added: IEEE 1800-2005
-				Self::Exit => "exit",                // Added: IEEE 1800-2005
-				Self::Exp => "exp",                  // Added: IEEE 1364-2005
-				Self::FallingGclk => "falling_gclk", // Added: IEEE 1800-2009
-				Self::Fatal => "fatal",              // Added: IEEE 1800-2005
-				Self::Fclose => "fclose",
-				Self::Fdisplay => "fdisplay",
-				Self::FdisplayB => "fdisplayb",
-				Self::FdisplayH => "fdisplayh",
-				Self::FdisplayO => "fdisplayo",
-				Self::Fell => "fell",          // Added: IEEE 1800-2005
-				Self::FellGclk => "fell_gclk", // Added: IEEE 1800-2009
-				Self::Feof => "feof",          // Added: IEEE 1364-2005
-				Self::Ferror => "ferror",      // Added: IEEE 1364-2001
-				Self::Fflush => "fflush",      // Added: IEEE 1364-2001
-				Self::Fgetc => "fgetc",        // Added: IEEE 1364-2001
-				Self::Fgets => "fgets",        // Added: IEEE 1364-2001
-				Self::Finish => "finish",
-				Self::Floor => "floor", // Added: IEEE 1364-2005
-				Self::Fmonitor => "fmonitor",
-				Self::FmonitorB => "fmonitorb",
-				Self::FmonitorH => "fmonitorh",
-				Self::FmonitorO => "fmonitoro",
-				Self::Fopen => "fopen",
-				Self::Fread => "fread",   // Added: IEEE 1364-2001
-				Self::Fscanf => "fscanf", // Added: IEEE 1364-2001
-				Self::Fseek => "fseek",   // Added: IEEE 1364-2001
-				Self::Fstrobe => "fstrobe",
-				Self::FstrobeB => "fstrobeb",
-				Self::FstrobeH => "fstrobeh",
-				Self::FstrobeO => "fstrobeo",
-				Self::Ftell => "ftell",            // Added: IEEE 1364-2001
-				Self::FutureGclk => "future_gclk", // Added: IEEE 1800-2009
-				Self::Fwrite => "fwrite",
-				Self::FwriteB => "fwriteb",
-				Self::FwriteH => "fwriteh",
-				Self::FwriteO => "fwriteo",
-				Self::GetCoverage => "get_coverage", // Added: IEEE 1800-2005
-				Self::GetPattern => "getpattern",
-				Self::High => "high",           // Added: IEEE 1800-2005
-				Self::Hold => "hold",           // Removed: IEEE 1364-2001
-				Self::Hypot => "hypot",         // Added: IEEE 1364-2005
-				Self::Increment => "increment", // Added: IEEE 1800-2005
-				Self::IncSave => "incsave",
-				Self::Info => "info", // Added: IEEE 1800-2005
-				Self::Input => "input",
-				Self::IsUnbounded => "isunbounded", // Added: IEEE 1800-2005
-				Self::IsUnknown => "isunknown",     // Added: IEEE 1800-2005
-				Self::Itor => "itor",
-				Self::Key => "key",
-				Self::Left => "left", // Added: IEEE 1800-2005
-				Self::List => "list",
-				Self::Ln => "ln",                           // Added: IEEE 1364-2005
-				Self::LoadCoverageDB => "load_coverage_db", // Added: IEEE 1800-2005
-				Self::Log => "log",
-				Self::Log10 => "log10", // Added: IEEE 1364-2005
-				Self::Low => "low",     // Added: IEEE 1800-2005
-				Self::Monitor => "monitor",
-				Self::MonitorB => "monitorb",
-				Self::MonitorH => "monitorh",
-				Self::MonitorO => "monitoro",
-				Self::MonitorOff => "monitoroff",
-				Self::MonitorOn => "monitoron",
-				Self::NoChange => "nochange", // Removed: IEEE 1364-2001
-				Self::NoKey => "nokey",
-				Self::NoLog => "nolog",
-				Self::OneHot => "onehot",      // Added: IEEE 1800-2005
-				Self::OneHot0 => "onehot0",    // Added: IEEE 1800-2005
-				Self::Past => "past",          // Added: IEEE 1800-2005
-				Self::PastGclk => "past_gclk", // Added: IEEE 1800-2009
-				Self::Period => "period",      // Removed: IEEE 1364-2001
-				Self::Pow => "pow",            // Added: IEEE 1800-2009
-				Self::PrintTimescale => "printtimescale",
-				Self::QAdd => "q_add",
-				Self::QExam => "q_exam",
-				Self::QFull => "q_full",
-				Self::QInitialize => "q_initialize",
-				Self::QRemove => "q_remove",
-				Self::Random => "random",
-				Self::ReadmemB => "readmemb",
-				Self::ReadmemH => "readmemh",
-				Self::RealTime => "realtime",
-				Self::RealToBits => "realtobits",
-				Self::Recovery => "recovery",            // Removed: IEEE 1364-2001
-				Self::ReceiverCount => "receiver_count", // Added: Verilog-AMS 23
-				Self::Reset => "reset",
-				Self::ResetCount => "reset_count",
-				Self::ResetValue => "reset_value",
-				Self::Restart => "restart",
-				Self::Rewind => "rewind",          // Added: IEEE 1364-2001
-				Self::Right => "right",            // Added: IEEE 1800-2005
-				Self::RisingGclk => "rising_gclk", // Added: IEEE 1800-2009
-				Self::Rose => "rose",              // Added: IEEE 1800-2005
-				Self::RoseGclk => "rose_gclk",     // Added: IEEE 1800-2009
-				Self::Rtoi => "rtoi",
-				Self::Sampled => "sampled", // Added: IEEE 1800-2005
-				Self::Save => "save",
-				Self::Scale => "scale",
-				Self::Scope => "scope",
-				Self::SdfAnnotate => "sdf_annotate", // Added: IEEE 1364-2001
-				Self::SetCoverageDb => "set_coverage_db", // Added: IEEE 1800-2005-IEEE 1800-2009
-				Self::SetCoverageDbName => "set_coverage_db_name", // Added: IEEE 1800-2009
-				Self::Setup => "setup",              // Removed: IEEE 1364-2001
-				Self::Setuphold => "setuphold",      // Removed: IEEE 1364-2001
-				Self::Sformat => "sformat",          // Added: IEEE 1364-2001
-				Self::Sformatf => "sformatf",        // Added: IEEE 1800-2009
-				Self::ShortRealToBits => "shortrealtobits", // Added: IEEE 1800-2005
-				Self::Showscopes => "showscopes",
-				Self::Showvars => "showvars",
-				Self::Signed => "signed",           // Added: IEEE 1364-2001
-				Self::SimParam => "simparam",       // Added: Verilog-AMS 2.3.1
-				Self::SimParamStr => "simparamstr", // Added: Verilog-AMS 2.3.1
-				Self::SimProbe => "simprobe",       // Added: Verilog-AMS 2.3.1
-				Self::Sin => "sin",                 // Added: IEEE 1364-2005
-				Self::Sinh => "sinh",               // Added: IEEE 1800-2009
-				Self::Size => "size",               // Added: IEEE 1800-2005
-				Self::Skew => "skew",               // Removed: IEEE 1364-2001
-				Self::Sqrt => "sqrt",               // Added: IEEE 1364-2005
-				Self::SreadmemB => "sreadmemb",
-				Self::SreadmemH => "sreadmemh",
-				Self::Sscanf => "sscanf",          // Added: IEEE 1364-2001
-				Self::Stable => "stable",          // Added: IEEE 1800-2005
-				Self::StableGclk => "stable_gclk", // Added: IEEE 1800-2009
-				Self::Stacktrace => "stacktrace",  // Added: IEEE 1800-2023
-				Self::SteadyGclk => "steady_gclk", // Added: IEEE 1800-2009
-				Self::Stime => "stime",
-				Self::Stop => "stop",
-				Self::Strobe => "strobe",
-				Self::StrobeB => "strobeb",
-				Self::StrobeH => "strobeh",
-				Self::StrobeO => "strobeo",
-				Self::Swrite => "swrite",   // Added: IEEE 1364-2001
-				Self::SwriteB => "swriteb", // Added: IEEE 1364-2001
-				Self::SwriteH => "swriteh", // Added: IEEE 1364-2001
-				Self::SwriteO => "swriteo", // Added: IEEE 1364-2001
-				Self::SyncAndArray => "sync$and$array",
-				Self::SyncAndPlane => "sync$and$plane",
-				Self::SyncNandArray => "sync$nand$array",
-				Self::SyncNandPlane => "sync$nand$plane",
-				Self::SyncNorArray => "sync$nor$array",
-				Self::SyncNorPlane => "sync$nor$plane",
-				Self::SyncOrArray => "sync$or$array",
-				Self::SyncOrPlane => "sync$or$plane",
-				Self::System => "system",              // Added: IEEE 1800-2009
-				Self::TableModel => "table_model",     // Added: Verilog-AMS 2.3.1
-				Self::Tan => "tan",                    // Added: IEEE 1364-2005
-				Self::Tanh => "tanh",                  // Added: IEEE 1364-2005
-				Self::Temperature => "temperature",    // Added: Verilog-AMS 2.3.1
-				Self::TestPlusArgs => "test$plusargs", // Added: IEEE 1364-2001
-				Self::Time => "time",
-				Self::TimeFormat => "timeformat",
-				Self::TimePrecision => "timeprecision", // Added: IEEE 1800-2023
-				Self::TimeUnit => "timeunit",           // Added: IEEE 1800-2023
-				Self::TypeName => "typename",           // Added: IEEE 1800-2005
-				Self::Ungetc => "ungetc",               // Added: IEEE 1364-2001
-				Self::UnpackedDimensions => "unpacked_dimensions", // Added: IEEE 1800-2005
-				Self::Unsigned => "unsigned",           // Added: IEEE 1364-2001
-				Self::Urandom => "urandom",             // Added: IEEE 1800-2005
-				Self::UrandomRange => "urandom_range",  // Added: IEEE 1800-2005
-				Self::ValuePlusArgs => "value$plusargs", // Added: IEEE 1364-2001
-				Self::Vflip => "vflip",                 // Added: Verilog-AMS 2.3.1
-				Self::Vt => "vt",                       // Added: Verilog-AMS 2.3.1
-				Self::Warning => "warning",             // Added: IEEE 1800-2005 & Verilog-AMS
-				// 2.3.1
-				Self::Width => "width", // Removed: IEEE 1364-2001
-				Self::Write => "write",
-				Self::WriteB => "writeb",
-				Self::WriteH => "writeh",
-				Self::WritememB => "writememb", // Added: IEEE 1800-2009
-				Self::WritememH => "writememh", // Added: IEEE 1800-2009
-				Self::WriteO => "writeo",
-				Self::XPosition => "xposition", // Added: Verilog-AMS 2.3.1
-				Self::YPosition => "yposition", // Added: Verilog-AMS 2.3.1
-			}
-		)
+		write!(f, "BuiltinSysFunc(${})", self.as_str())
 	}
 }
 
@@ -3618,4 +3645,27 @@ mod test {
 		assert!(is_verilog_ams_23_builtin_sysfunc("receiver_count"));
 		assert!(is_builtin_sysfunc("receiver_count").contains(LanguageStd::Vams23));
 	}
+
+	#[test]
+	fn test_sysfunc_added() {
+		assert_eq!(BuiltinSysFunc::Display.added(), LanguageStd::Vl95);
+		assert_eq!(BuiltinSysFunc::Fgets.added(), LanguageStd::Vl01);
+		assert_eq!(BuiltinSysFunc::Clog2.added(), LanguageStd::Vl05);
+		assert_eq!(BuiltinSysFunc::Bits.added(), LanguageStd::Sv05);
+		assert_eq!(BuiltinSysFunc::AssertControl.added(), LanguageStd::Sv12);
+		assert_eq!(BuiltinSysFunc::AnalogNodeAlias.added(), LanguageStd::Vams14);
+	}
+
+	#[test]
+	fn test_builtin_sysfuncs() {
+		let verilog = builtin_sysfuncs(LanguageStd::Vl95).collect::<Vec<_>>();
+		assert!(verilog.contains(&BuiltinSysFunc::Display));
+		assert!(!verilog.contains(&BuiltinSysFunc::Clog2));
+
+		assert_eq!(
+			builtin_sysfuncs(LanguageStd::Sv17).count(),
+			SYSTEM_VERILOG_17_BUILTIN_SYSFUNC_MAP.len()
+		);
+		assert_eq!(builtin_sysfuncs(LanguageStd::none()).count(), 0);
+	}
 }
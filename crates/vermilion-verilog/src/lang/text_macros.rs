@@ -444,19 +444,21 @@ pub fn is_builtin_text_macro(ident: &str) -> LanguageStd {
 	supported
 }
 
+impl BuiltinTextMacro {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::DunderFile => "__FILE__", // Added: Verilog-AMS 2023 & IEEE 1800-2009
+			Self::DunderLine => "__LINE__", // Added: Verilog-AMS 2023 & IEEE 1800-2009
+			Self::VamsCompactModeling => "__VAMS_COMPACT_MODELING__", // Added: Verilog-AMS 2.3.1
+			Self::VamsEnable => "__VAMS_ENABLE__", // Added: Verilog-AMS 2.3.1
+		}
+	}
+}
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 impl Display for BuiltinTextMacro {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(
-			f,
-			"BuiltinTextMacro({})",
-			match self {
-				Self::DunderFile => "__FILE__", // Added: Verilog-AMS 2023 & IEEE 1800-2009
-				Self::DunderLine => "__LINE__", // Added: Verilog-AMS 2023 & IEEE 1800-2009
-				Self::VamsCompactModeling => "__VAMS_COMPACT_MODELING__", // Added: Verilog-AMS 2.3.1
-				Self::VamsEnable => "__VAMS_ENABLE__", // Added: Verilog-AMS 2.3.1
-			}
-		)
+		write!(f, "BuiltinTextMacro({})", self.as_str())
 	}
 }
 
@@ -33,6 +33,16 @@ pub struct VerilogTokenizer {
 
 impl VerilogTokenizer {
 	pub fn new(standard: LanguageStd, file: AtomicByteTendril) -> eyre::Result<Self> {
+		Self::new_at(standard, file, 0, Position::sof())
+	}
+
+	/// Create a new [`VerilogTokenizer`] that starts at `offset` in `file`, which is at `position`
+	pub fn new_at(
+		standard: LanguageStd,
+		file: AtomicByteTendril,
+		offset: u32,
+		position: Position,
+	) -> eyre::Result<Self> {
 		// Check to see if more than one language standard is set
 		if !standard.has_single_std() {
 			return Err(eyre!("More than one language standard set: {:?}", standard));
@@ -40,7 +50,7 @@ impl VerilogTokenizer {
 
 		Ok(Self {
 			standard,
-			tokenizer: CoreTokenizer::new(file),
+			tokenizer: CoreTokenizer::new_at(file, offset, position),
 			token: spanned_token!(Token::default()),
 			token_stream: VecDeque::new(),
 		})
@@ -0,0 +1,1620 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::BTreeMap;
+
+use vermilion_loc::{FileId, Location, Span, Spanned};
+
+use crate::lang::{
+	ast::{
+		Assignment, BinaryOperator, Case, CastTarget, Connections, DataType, Declarator,
+		Description, Dimension, EventControl, Expression, Function, GenerateBlock, Item, ItemKind,
+		Literal, Module, Package, ParameterDeclaration, PatternItem, PortDeclaration, Ports,
+		Select, Signing, Statement, StatementKind, Task, TimingControl, UnaryOperator,
+	},
+	elab::{Constant, Evaluator, Logic, Value},
+	sysfuncs::BuiltinSysFunc,
+	typeck::{ExprType, TypeTable, TypedDeclaration, TypedExpression},
+	types::{Identifier, SystemFunc},
+};
+
+/// A declared net, variable, port, or parameter
+#[derive(Clone, Debug)]
+struct Declared {
+	data_type: ExprType,
+	/// The sizes of the packed dimensions, outermost first
+	packed:    Vec<u32>,
+	/// The number of unpacked dimensions
+	unpacked:  usize,
+	/// The number of bits needed to hold the value of a parameter without an explicit type,
+	/// otherwise the width of the type
+	fit:       u32,
+}
+
+/// The names declared by a single scope
+#[derive(Clone, Debug, Default)]
+struct Scope {
+	values:    BTreeMap<String, Declared>,
+	/// The return types of the functions
+	functions: BTreeMap<String, ExprType>,
+	/// Imported packages, with the imported name or `None` for a wildcard import
+	imports:   Vec<(String, Option<String>)>,
+}
+
+/// The self-determined type of an expression, along with what is needed to type selects of it
+#[derive(Clone, Debug)]
+struct Inferred {
+	/// The type of the expression, or of its elements if it is an unpacked array
+	data_type: ExprType,
+	packed:    Vec<u32>,
+	unpacked:  usize,
+	/// The number of bits needed to hold the value of the expression without losing
+	/// information, unsized constants only need as many bits as their value
+	fit:       u32,
+}
+
+/// Infers the types of the expressions of a set of source files
+pub(super) struct Checker<'t, 'a> {
+	table:     &'t mut TypeTable,
+	file:      FileId,
+	evaluator: Evaluator<'a>,
+	scopes:    Vec<Scope>,
+	packages:  BTreeMap<String, Scope>,
+	/// The return type of the function being checked
+	returns:   Option<ExprType>,
+	/// The self-determined types of the expression being checked and its operands
+	// NOTE(aki): The AST has no node identities and is not modified while an expression is
+	// checked, so the operands are keyed by their address
+	inferred: BTreeMap<*const Expression, Inferred>,
+}
+
+impl Declared {
+	fn new(data_type: ExprType) -> Self {
+		Self {
+			data_type,
+			packed: Vec::new(),
+			unpacked: 0,
+			fit: data_type.width().unwrap_or_default(),
+		}
+	}
+}
+
+impl Inferred {
+	fn new(data_type: ExprType) -> Self {
+		Self {
+			data_type,
+			packed: Vec::new(),
+			unpacked: 0,
+			fit: data_type.width().unwrap_or_default(),
+		}
+	}
+
+	fn with_fit(mut self, fit: u32) -> Self {
+		self.fit = fit;
+		self
+	}
+
+	/// The type of the expression itself, unpacked arrays do not have one
+	fn value_type(&self) -> ExprType {
+		if self.unpacked > 0 {
+			ExprType::Unknown
+		} else {
+			self.data_type
+		}
+	}
+}
+
+impl From<&Declared> for Inferred {
+	fn from(declared: &Declared) -> Self {
+		Self {
+			data_type: declared.data_type,
+			packed:    declared.packed.clone(),
+			unpacked:  declared.unpacked,
+			fit:       declared.fit,
+		}
+	}
+}
+
+impl<'t, 'a> Checker<'t, 'a> {
+	pub(super) fn new(table: &'t mut TypeTable, file: FileId) -> Self {
+		let evaluator = Evaluator::new(table.std, file);
+		Self {
+			table,
+			file,
+			evaluator,
+			scopes: vec![Scope::default()],
+			packages: BTreeMap::new(),
+			returns: None,
+			inferred: BTreeMap::new(),
+		}
+	}
+
+	pub(super) fn set_file(&mut self, file: FileId) {
+		self.file = file;
+		self.evaluator.set_file(file);
+	}
+
+	/// Make the parameters and types of the packages available for constant evaluation
+	pub(super) fn add_packages(&mut self, file: FileId, descriptions: &'a [Spanned<Description>]) {
+		for description in descriptions {
+			if let Description::Package(package) = description.inner() {
+				self.evaluator.add_package(file, package);
+			}
+		}
+	}
+
+	pub(super) fn package(&mut self, package: &'a Package) {
+		let scope = self.scoped(|this| this.items(&package.items));
+		self.packages
+			.insert(package.name.inner().as_str().to_string(), scope);
+	}
+
+	/// Check everything but the packages of a source file, the items outside of any other
+	/// declaration share the compilation unit scope of the file
+	pub(super) fn descriptions(&mut self, descriptions: &'a [Spanned<Description>]) {
+		self.scoped(|this| {
+			for description in descriptions {
+				match description.inner() {
+					Description::Module(module) => this.module(module),
+					Description::Item(item) => this.item(item),
+					_ => (),
+				}
+			}
+		});
+	}
+
+	/// Run `f` in a new scope nested in the current one, returning the scope
+	fn scoped<F>(&mut self, f: F) -> Scope
+	where
+		F: FnOnce(&mut Self),
+	{
+		self.scopes.push(Scope::default());
+		self.evaluator.push_scope();
+
+		f(self);
+
+		self.evaluator.pop_scope();
+		self.scopes.pop().unwrap_or_default()
+	}
+
+	fn declare(&mut self, name: &Identifier, declared: Declared) {
+		if let Some(scope) = self.scopes.last_mut() {
+			scope.values.insert(name.as_str().to_string(), declared);
+		}
+	}
+
+	/// Look `name` up through the scopes and their imports
+	fn lookup<'s>(&'s self, name: &'s str) -> Option<&'s Declared> {
+		self.scopes.iter().rev().find_map(|scope| {
+			scope.values.get(name).or_else(|| {
+				self.imported(scope, name)
+					.find_map(|package| package.values.get(name))
+			})
+		})
+	}
+
+	/// Look the function `name` up through the scopes and their imports
+	fn lookup_function<'s>(&'s self, name: &'s str) -> Option<ExprType> {
+		self.scopes.iter().rev().find_map(|scope| {
+			scope.functions.get(name).copied().or_else(|| {
+				self.imported(scope, name)
+					.find_map(|package| package.functions.get(name).copied())
+			})
+		})
+	}
+
+	/// The packages imported into `scope` that may provide `name`
+	fn imported<'s>(&'s self, scope: &'s Scope, name: &'s str) -> impl Iterator<Item = &'s Scope> {
+		scope
+			.imports
+			.iter()
+			.filter(move |(_, item)| item.as_deref().is_none_or(|item| item == name))
+			.filter_map(|(package, _)| self.packages.get(package))
+	}
+
+	fn module(&mut self, module: &'a Module) {
+		self.scoped(|this| {
+			for import in &module.imports {
+				let import = import.inner();
+				this.import(
+					import.package.inner().as_str(),
+					import.item.as_ref().map(|item| item.inner().as_str()),
+				);
+			}
+
+			for parameter in module.parameters.iter().flatten() {
+				this.parameter(parameter.inner());
+			}
+
+			if let Ports::Ansi(ports) = &module.ports {
+				for port in ports {
+					this.port(port.inner());
+				}
+			}
+
+			this.items(&module.items);
+		});
+	}
+
+	fn import(&mut self, package: &str, item: Option<&str>) {
+		self.evaluator.import(package, item);
+		if let Some(scope) = self.scopes.last_mut() {
+			scope
+				.imports
+				.push((package.to_string(), item.map(str::to_string)));
+		}
+	}
+
+	fn items(&mut self, items: &'a [Spanned<Item>]) {
+		for item in items {
+			self.item(item.inner());
+		}
+	}
+
+	fn item(&mut self, item: &'a Item) {
+		match &item.kind {
+			ItemKind::Port(port) => self.port(port),
+			ItemKind::Net(net) => {
+				self.evaluator.declare_item(item);
+				self.declarations(&net.data_type, &net.declarators);
+			},
+			ItemKind::Variable(variable) => {
+				self.evaluator.declare_item(item);
+				self.declarations(&variable.data_type, &variable.declarators);
+			},
+			ItemKind::Parameter(parameter) => self.parameter(parameter),
+			ItemKind::Genvar(names) => {
+				for name in names {
+					self.declare(name.inner(), Declared::new(ExprType::INTEGER));
+				}
+			},
+			ItemKind::Defparam(assignments) => {
+				for assignment in assignments {
+					self.expression(&assignment.inner().value, None);
+				}
+			},
+			ItemKind::ContinuousAssign(assign) => {
+				for assignment in &assign.assignments {
+					self.assignment(assignment.inner());
+				}
+			},
+			ItemKind::Gate(gate) => {
+				for instance in &gate.instances {
+					for terminal in &instance.inner().terminals {
+						self.expression(terminal, None);
+					}
+				}
+			},
+			ItemKind::Instantiation(instantiation) => {
+				let connections = instantiation.parameters.iter().map(Spanned::inner).chain(
+					instantiation
+						.instances
+						.iter()
+						.map(|instance| &instance.inner().connections),
+				);
+				for connections in connections {
+					for expr in connection_expressions(connections) {
+						self.expression(expr, None);
+					}
+				}
+			},
+			ItemKind::Initial(statement) |
+			ItemKind::Always(statement) |
+			ItemKind::AlwaysComb(statement) |
+			ItemKind::AlwaysFF(statement) |
+			ItemKind::AlwaysLatch(statement) |
+			ItemKind::Final(statement) |
+			ItemKind::Analog(statement) |
+			ItemKind::AnalogInitial(statement) => self.statement(statement),
+			ItemKind::Task(task) => self.task(task),
+			ItemKind::Function(function) | ItemKind::AnalogFunction(function) => {
+				self.evaluator.declare_item(item);
+				self.function(function);
+			},
+			ItemKind::GenerateRegion(items) => self.items(items),
+			ItemKind::GenerateFor(generate) => {
+				self.scoped(|this| {
+					if generate.genvar {
+						let name = generate.init.inner().lvalue.inner().as_identifier();
+						if let Some(name) = name {
+							this.declare(name, Declared::new(ExprType::INTEGER));
+						}
+					}
+					this.assignment(generate.init.inner());
+					this.expression(&generate.condition, None);
+					this.expression(&generate.step, None);
+					this.generate_block(generate.block.inner());
+				});
+			},
+			ItemKind::GenerateIf(generate) => {
+				self.expression(&generate.condition, None);
+				for block in generate.if_true.iter().chain(&generate.if_false) {
+					self.generate_block(block.inner());
+				}
+			},
+			ItemKind::GenerateCase(generate) => {
+				let labels = generate.items.iter().flat_map(|item| &item.inner().labels);
+				self.case_expressions(&generate.expression, labels);
+				for item in &generate.items {
+					if let Some(block) = &item.inner().block {
+						self.generate_block(block.inner());
+					}
+				}
+			},
+			ItemKind::GenerateBlock(block) => self.generate_block(block),
+			ItemKind::Typedef(_) => self.evaluator.declare_item(item),
+			ItemKind::Import(imports) => {
+				for import in imports {
+					let import = import.inner();
+					self.import(
+						import.package.inner().as_str(),
+						import.item.as_ref().map(|item| item.inner().as_str()),
+					);
+				}
+			},
+			_ => (),
+		}
+	}
+
+	fn generate_block(&mut self, block: &'a GenerateBlock) {
+		self.scoped(|this| this.items(&block.items));
+	}
+
+	/// Declare a parameter, those without an explicit type take the type of their value
+	fn parameter(&mut self, parameter: &'a ParameterDeclaration) {
+		if parameter.data_type == DataType::Type {
+			for declarator in &parameter.declarators {
+				let declarator = declarator.inner();
+				let data_type = declarator
+					.init
+					.as_ref()
+					.and_then(|init| match init.inner() {
+						Expression::DataType(data_type) => self.evaluator.value_type(data_type),
+						Expression::Identifier(name) => {
+							self.evaluator.lookup_type(name.as_str()).flatten()
+						},
+						_ => None,
+					});
+				self.evaluator
+					.define_type(declarator.name.inner().as_str(), data_type);
+			}
+			return;
+		}
+
+		let explicit = self.evaluator.value_type(&parameter.data_type);
+		for declarator in &parameter.declarators {
+			let declarator = declarator.inner();
+			let name = declarator.name.inner();
+
+			if let Some(init) = &declarator.init {
+				match explicit {
+					Some(data_type) => self.assigned(data_type.into(), init),
+					None => {
+						self.expression(init, None);
+					},
+				}
+
+				if let Some(value) = self.evaluator.evaluate_as(&parameter.data_type, init) {
+					self.evaluator
+						.define(name.as_str(), Constant { value, data_type: explicit });
+				}
+			}
+
+			let constant = declarator
+				.init
+				.as_ref()
+				.and_then(|_| self.evaluator.lookup(name.as_str()));
+			let declared = match (explicit, constant) {
+				(Some(data_type), _) => Declared::new(data_type.into()),
+				(None, Some(constant)) => constant_type(constant),
+				(None, None) => Declared::new(ExprType::Unknown),
+			};
+
+			self.table.push_declaration(TypedDeclaration {
+				location:  Location::new(self.file, (), *declarator.name.span()),
+				data_type: declared.data_type,
+				packed:    declared.packed.clone(),
+				unpacked:  Vec::new(),
+				value:     constant.map(|constant| constant.value.clone()),
+			});
+			self.declare(name, declared);
+		}
+	}
+
+	fn port(&mut self, port: &'a PortDeclaration) {
+		self.declarations(&port.data_type, &port.declarators);
+	}
+
+	/// Declare nets, variables, or ports, checking their initial values
+	fn declarations(&mut self, data_type: &DataType, declarators: &'a [Spanned<Declarator>]) {
+		let bare = matches!(
+			data_type,
+			DataType::Implicit { signing: None, packed } if packed.is_empty()
+		);
+
+		for declarator in declarators {
+			let declarator = declarator.inner();
+			let name = declarator.name.inner();
+
+			// Non-ANSI ports may be declared again as a net or variable without a range
+			// IEEE 1364-2005 § 12.3.3
+			let existing = self
+				.scopes
+				.last()
+				.and_then(|scope| scope.values.get(name.as_str()));
+			let declared = match existing {
+				Some(existing) if bare && declarator.dimensions.is_empty() => existing.clone(),
+				_ => {
+					let mut declared = self.declared(data_type);
+					declared.unpacked = declarator.dimensions.len();
+					declared
+				},
+			};
+
+			if let Some(init) = &declarator.init {
+				if declared.unpacked == 0 {
+					self.assigned(declared.data_type, init);
+				} else {
+					self.expression(init, None);
+				}
+			}
+
+			let unpacked = declarator
+				.dimensions
+				.iter()
+				.map(|dimension| self.dimension_size(dimension.inner()))
+				.collect();
+			self.table.push_declaration(TypedDeclaration {
+				location: Location::new(self.file, (), *declarator.name.span()),
+				data_type: declared.data_type,
+				packed: declared.packed.clone(),
+				unpacked,
+				value: None,
+			});
+			self.declare(name, declared);
+		}
+	}
+
+	/// The type of a declaration of `data_type`
+	fn declared(&mut self, data_type: &DataType) -> Declared {
+		let packed = data_type
+			.packed()
+			.iter()
+			.map(|dimension| self.dimension_size(dimension.inner()))
+			.collect::<Option<Vec<_>>>()
+			.unwrap_or_default();
+
+		Declared { packed, ..Declared::new(self.data_type(data_type)) }
+	}
+
+	fn data_type(&mut self, data_type: &DataType) -> ExprType {
+		match data_type {
+			DataType::Implicit { signing, packed } if packed.is_empty() => ExprType::Integral {
+				width:     1,
+				signed:    signing.is_some_and(|signing| signing == Signing::Signed),
+				two_state: false,
+			},
+			DataType::Void => ExprType::Void,
+			_ => self
+				.evaluator
+				.value_type(data_type)
+				.map_or(ExprType::Unknown, ExprType::from),
+		}
+	}
+
+	/// The number of elements of a packed or fixed size unpacked dimension
+	fn dimension_size(&mut self, dimension: &Dimension) -> Option<u32> {
+		match dimension {
+			Dimension::Range { msb, lsb } => {
+				let msb = self.evaluator.evaluate(msb)?.to_i64()?;
+				let lsb = self.evaluator.evaluate(lsb)?.to_i64()?;
+				(msb.abs_diff(lsb) + 1).try_into().ok()
+			},
+			Dimension::Size(size) => self.evaluator.evaluate(size)?.to_i64()?.try_into().ok(),
+			_ => None,
+		}
+	}
+
+	fn function(&mut self, function: &'a Function) {
+		let return_type = self.data_type(&function.return_type);
+		if function.scope.is_none() &&
+			let Some(scope) = self.scopes.last_mut()
+		{
+			scope
+				.functions
+				.insert(function.name.inner().as_str().to_string(), return_type);
+		}
+
+		self.scoped(|this| {
+			// The function name is an implicit variable holding the return value
+			// IEEE 1364-2005 § 10.4.1
+			if return_type != ExprType::Void {
+				this.declare(function.name.inner(), Declared::new(return_type));
+			}
+
+			for port in function.ports.iter().flatten() {
+				this.port(port.inner());
+			}
+			this.items(&function.items);
+
+			let returns = this.returns.replace(return_type);
+			for statement in &function.body {
+				this.statement(statement);
+			}
+			this.returns = returns;
+		});
+	}
+
+	fn task(&mut self, task: &'a Task) {
+		self.scoped(|this| {
+			for port in task.ports.iter().flatten() {
+				this.port(port.inner());
+			}
+			this.items(&task.items);
+
+			let returns = this.returns.take();
+			for statement in &task.body {
+				this.statement(statement);
+			}
+			this.returns = returns;
+		});
+	}
+
+	fn statement(&mut self, statement: &'a Spanned<Statement>) {
+		match &statement.inner().kind {
+			StatementKind::Blocking(assignment) |
+			StatementKind::NonBlocking(assignment) |
+			StatementKind::ProceduralAssign(assignment) |
+			StatementKind::Force(assignment) => {
+				if let Some(control) = &assignment.control {
+					self.timing_control(control.inner());
+				}
+				self.assignment(assignment);
+			},
+			StatementKind::Deassign(expr) | StatementKind::Release(expr) => {
+				self.expression(expr, None);
+			},
+			StatementKind::Case(case) => self.case(case),
+			StatementKind::If { condition, if_true, if_false, .. } => {
+				self.expression(condition, None);
+				self.statement(if_true);
+				if let Some(if_false) = if_false {
+					self.statement(if_false);
+				}
+			},
+			StatementKind::Forever(body) => self.statement(body),
+			StatementKind::Repeat { count: condition, body } |
+			StatementKind::While { condition, body } |
+			StatementKind::DoWhile { body, condition } => {
+				self.expression(condition, None);
+				self.statement(body);
+			},
+			StatementKind::For { init, condition, step, body } => {
+				self.scoped(|this| {
+					for init in init {
+						let init = init.inner();
+						if let Some(data_type) = &init.data_type &&
+							let Some(name) =
+								init.assignment.inner().lvalue.inner().as_identifier()
+						{
+							let declared = this.declared(data_type);
+							this.declare(name, declared);
+						}
+						this.assignment(init.assignment.inner());
+					}
+					if let Some(condition) = condition {
+						this.expression(condition, None);
+					}
+					for step in step {
+						this.expression(step, None);
+					}
+					this.statement(body);
+				});
+			},
+			StatementKind::Block(block) => {
+				self.scoped(|this| {
+					this.items(&block.items);
+					for statement in &block.statements {
+						this.statement(statement);
+					}
+				});
+			},
+			StatementKind::TimingControl { control, statement } => {
+				self.timing_control(control.inner());
+				self.statement(statement);
+			},
+			StatementKind::SystemTaskEnable { arguments, .. } => {
+				for argument in arguments.iter().flatten() {
+					self.expression(argument, None);
+				}
+			},
+			StatementKind::TaskEnable { arguments, .. } => {
+				for argument in arguments {
+					self.expression(argument, None);
+				}
+			},
+			StatementKind::Wait { condition, statement } => {
+				self.expression(condition, None);
+				self.statement(statement);
+			},
+			StatementKind::Expression(expr) => {
+				self.expression(expr, None);
+			},
+			StatementKind::Foreach { array, variables, body } => {
+				self.expression(array, None);
+				self.scoped(|this| {
+					for variable in variables.iter().flatten() {
+						this.declare(
+							variable.inner(),
+							Declared::new(ExprType::Integral {
+								width:     32,
+								signed:    true,
+								two_state: true,
+							}),
+						);
+					}
+					this.statement(body);
+				});
+			},
+			StatementKind::Return(Some(value)) => match self.returns {
+				Some(return_type) => self.assigned(return_type, value),
+				None => {
+					self.expression(value, None);
+				},
+			},
+			StatementKind::Contribution { target, value } |
+			StatementKind::IndirectContribution { target, equation: value } => {
+				self.expression(target, None);
+				self.expression(value, None);
+			},
+			_ => (),
+		}
+	}
+
+	fn timing_control(&mut self, control: &'a TimingControl) {
+		match control {
+			TimingControl::Delay(delay) => {
+				for value in &delay.values {
+					self.expression(value, None);
+				}
+			},
+			TimingControl::Event(event) => self.event_control(event),
+			TimingControl::Repeat { count, event } => {
+				self.expression(count, None);
+				self.event_control(event.inner());
+			},
+			TimingControl::Cycle(count) => {
+				self.expression(count, None);
+			},
+		}
+	}
+
+	fn event_control(&mut self, event: &'a EventControl) {
+		match event {
+			EventControl::Identifier(expr) => {
+				self.expression(expr, None);
+			},
+			EventControl::Expression(events) => {
+				for event in events {
+					let event = event.inner();
+					self.expression(&event.expression, None);
+					if let Some(iff) = &event.iff {
+						self.expression(iff, None);
+					}
+				}
+			},
+			EventControl::Wildcard => (),
+		}
+	}
+
+	/// The case expression and the labels are all extended to the widest of them
+	///
+	/// IEEE 1800-2017 § 12.5.1
+	fn case(&mut self, case: &'a Case) {
+		let labels = case.items.iter().flat_map(|item| &item.inner().labels);
+		self.case_expressions(&case.expression, labels);
+
+		for item in &case.items {
+			self.statement(&item.inner().statement);
+		}
+	}
+
+	fn case_expressions<I>(&mut self, expression: &'a Spanned<Expression>, labels: I)
+	where
+		I: Iterator<Item = &'a Spanned<Expression>> + Clone,
+	{
+		let mut context = self.infer(expression).value_type();
+		for label in labels.clone() {
+			let label = self.infer(label).value_type();
+			context = combine(context, label);
+		}
+
+		self.propagate(expression, Some(context));
+		for label in labels {
+			self.propagate(label, Some(context));
+		}
+		self.inferred.clear();
+	}
+
+	fn assignment(&mut self, assignment: &'a Assignment) {
+		let target = self.expression(&assignment.lvalue, None);
+		self.assigned(target, &assignment.value);
+	}
+
+	/// Type the value assigned to a target of type `target`, which extends the value to the
+	/// width of the target
+	fn assigned(&mut self, target: ExprType, value: &'a Spanned<Expression>) {
+		let inferred = self.infer(value);
+		self.assigned_inferred(target, value, &inferred);
+		self.inferred.clear();
+	}
+
+	fn assigned_inferred(
+		&mut self,
+		target: ExprType,
+		value: &'a Spanned<Expression>,
+		inferred: &Inferred,
+	) {
+		let value_type = inferred.value_type();
+		self.propagate(value, Some(assignment_context(target, value_type)));
+
+		if let (ExprType::Integral { width: target, .. }, ExprType::Integral { width, .. }) =
+			(target, value_type) &&
+			inferred.fit > target
+		{
+			let location = Location::new(self.file, (), *value.span());
+			self.table.truncation(location, width, target);
+		}
+	}
+
+	/// Type an expression, returning its final type
+	fn expression(&mut self, expr: &'a Spanned<Expression>, context: Option<ExprType>) -> ExprType {
+		self.infer(expr);
+		let final_type = self.propagate(expr, context);
+		self.inferred.clear();
+
+		final_type
+	}
+
+	/// Find the self-determined type of an expression and its operands
+	///
+	/// IEEE 1800-2017 § 11.6.1, Table 11-21, § 11.8.1
+	fn infer(&mut self, expr: &'a Spanned<Expression>) -> Inferred {
+		let inferred = match expr.inner() {
+			Expression::Literal(literal) => self.literal(expr, literal),
+			Expression::Identifier(name) => self.name(name.as_str()),
+			Expression::Scope { scope, member } => {
+				let declared = scope
+					.inner()
+					.as_identifier()
+					.and_then(|package| self.packages.get(package.as_str()))
+					.and_then(|package| package.values.get(member.inner().as_str()));
+
+				match declared {
+					Some(declared) => declared.into(),
+					None => match self.evaluator.evaluate(expr) {
+						Some(value) => Inferred::new(ExprType::of_value(&value)),
+						None => Inferred::new(ExprType::Unknown),
+					},
+				}
+			},
+			Expression::Member { target, .. } => {
+				self.infer(target);
+				Inferred::new(ExprType::Unknown)
+			},
+			Expression::Select { target, select } => {
+				let target = self.infer(target);
+				self.select(&target, select.inner())
+			},
+			Expression::Concatenation(items) => {
+				let width = self.concatenation(items);
+				Inferred::new(width)
+			},
+			Expression::Replication { count, items } => {
+				self.infer(count);
+				let data_type = match (self.concatenation(items), self.constant(count)) {
+					(ExprType::Integral { width, two_state, .. }, Some(count)) => {
+						u32::try_from(count)
+							.ok()
+							.and_then(|count| count.checked_mul(width))
+							.map_or(ExprType::Unknown, |width| ExprType::Integral {
+								width,
+								signed: false,
+								two_state,
+							})
+					},
+					(ExprType::String, _) => ExprType::String,
+					_ => ExprType::Unknown,
+				};
+				Inferred::new(data_type)
+			},
+			Expression::Call { target, arguments, .. } => {
+				for argument in arguments {
+					self.infer(argument);
+				}
+
+				let return_type = match target.inner() {
+					Expression::Identifier(name) => self.lookup_function(name.as_str()),
+					Expression::Scope { scope, member } => scope
+						.inner()
+						.as_identifier()
+						.and_then(|package| self.packages.get(package.as_str()))
+						.and_then(|package| package.functions.get(member.inner().as_str()))
+						.copied(),
+					_ => None,
+				};
+				Inferred::new(return_type.unwrap_or(ExprType::Unknown))
+			},
+			Expression::SystemCall { name, arguments } => {
+				let arguments = arguments
+					.iter()
+					.flatten()
+					.map(|argument| self.infer(argument).value_type())
+					.collect::<Vec<_>>();
+				Inferred::new(system_call_type(name.inner(), &arguments))
+			},
+			Expression::Unary { operator, operand, .. } => {
+				let operand = self.infer(operand);
+				match operator.inner() {
+					UnaryOperator::Plus | UnaryOperator::Minus | UnaryOperator::BitwiseNot => {
+						Inferred::new(operand.value_type()).with_fit(operand.fit)
+					},
+					_ => Inferred::new(single_bit(operand.value_type())),
+				}
+			},
+			Expression::Binary { operator, lhs, rhs, .. } => {
+				let lhs = self.infer(lhs);
+				let rhs = self.infer(rhs);
+				let (lhs_type, rhs_type) = (lhs.value_type(), rhs.value_type());
+
+				match operator_kind(*operator.inner()) {
+					OperatorKind::Context => {
+						Inferred::new(combine(lhs_type, rhs_type)).with_fit(lhs.fit.max(rhs.fit))
+					},
+					OperatorKind::Comparison | OperatorKind::Logical => {
+						Inferred::new(single_bit(combine(lhs_type, rhs_type)))
+					},
+					OperatorKind::Shift if *operator.inner() == BinaryOperator::Power => {
+						if lhs_type.is_real() || rhs_type.is_real() {
+							Inferred::new(ExprType::Real)
+						} else {
+							Inferred::new(lhs_type).with_fit(lhs.fit)
+						}
+					},
+					OperatorKind::Shift => Inferred::new(lhs_type).with_fit(lhs.fit),
+				}
+			},
+			Expression::Conditional { condition, if_true, if_false, .. } => {
+				self.infer(condition);
+				let if_true = self.infer(if_true);
+				let if_false = self.infer(if_false);
+
+				Inferred::new(combine(if_true.value_type(), if_false.value_type()))
+					.with_fit(if_true.fit.max(if_false.fit))
+			},
+			Expression::MinTypMax { min, typ, max } => {
+				self.infer(min);
+				let typ = self.infer(typ);
+				self.infer(max);
+				typ
+			},
+			Expression::Parenthesized(inner) => self.infer(inner),
+			Expression::Assign { lvalue, value, .. } => {
+				let lvalue = self.infer(lvalue);
+				self.infer(value);
+				Inferred::new(lvalue.value_type())
+			},
+			Expression::IncDec { operand, .. } => {
+				let operand = self.infer(operand);
+				Inferred::new(operand.value_type())
+			},
+			Expression::Cast { target, value } => {
+				let value = self.infer(value).value_type();
+				Inferred::new(self.cast(target.inner(), value))
+			},
+			Expression::AssignmentPattern { data_type, items } => {
+				for item in items {
+					match item.inner() {
+						PatternItem::Positional(value) |
+						PatternItem::Keyed { value, .. } |
+						PatternItem::Default(value) => {
+							self.infer(value);
+						},
+					}
+				}
+
+				let data_type = data_type
+					.as_ref()
+					.and_then(|data_type| data_type.inner().as_identifier())
+					.and_then(|name| self.evaluator.lookup_type(name.as_str()).flatten());
+				Inferred::new(data_type.map_or(ExprType::Unknown, ExprType::from))
+			},
+			Expression::Inside { expression, set } => {
+				self.infer(expression);
+				for item in set {
+					self.infer(item);
+				}
+				Inferred::new(ExprType::LOGIC)
+			},
+			Expression::ValueRange { low: lhs, high: rhs } |
+			Expression::ToleranceRange { center: lhs, tolerance: rhs, .. } => {
+				self.infer(lhs);
+				self.infer(rhs);
+				Inferred::new(ExprType::Unknown)
+			},
+			Expression::Streaming { slice, items, .. } => {
+				if let Some(slice) = slice {
+					self.infer(slice);
+				}
+				let data_type = self.concatenation(items);
+				Inferred::new(data_type)
+			},
+			Expression::New { size, arguments } => {
+				for argument in size.iter().map(AsRef::as_ref).chain(arguments) {
+					self.infer(argument);
+				}
+				Inferred::new(ExprType::Unknown)
+			},
+			Expression::With { target, expression } => {
+				self.infer(target);
+				self.infer(expression);
+				Inferred::new(ExprType::Unknown)
+			},
+			Expression::RandomizeWith { call, .. } => {
+				self.infer(call);
+				Inferred::new(ExprType::Unknown)
+			},
+			Expression::MacroUsage(_) |
+			Expression::This |
+			Expression::Super |
+			Expression::Null |
+			Expression::Unbounded |
+			Expression::DataType(_) => Inferred::new(ExprType::Unknown),
+		};
+
+		self.inferred
+			.insert(std::ptr::from_ref(expr.inner()), inferred.clone());
+		inferred
+	}
+
+	/// Apply the context to an expression and its operands, recording their types
+	///
+	/// The final type of a context-determined operand has the width of the widest operand of
+	/// the expression it is in, or the target it is assigned to, and the signedness of that
+	/// expression, while self-determined operands keep their own type.
+	///
+	/// IEEE 1800-2017 § 11.8.2
+	fn propagate(&mut self, expr: &'a Spanned<Expression>, context: Option<ExprType>) -> ExprType {
+		let Some(inferred) = self.inferred.get(&std::ptr::from_ref(expr.inner())) else {
+			return ExprType::Unknown;
+		};
+		let self_type = inferred.value_type();
+		let final_type = context.map_or(self_type, |context| extend(context, self_type));
+
+		if !matches!(expr.inner(), Expression::DataType(_)) {
+			self.table.push(TypedExpression {
+				location: Location::new(self.file, (), *expr.span()),
+				self_type,
+				final_type,
+				context_determined: context.is_some(),
+			});
+		}
+
+		match expr.inner() {
+			Expression::Member { target, .. } => {
+				self.propagate(target, None);
+			},
+			Expression::Select { target, select } => {
+				self.propagate(target, None);
+				match select.inner() {
+					Select::Bit(index) => {
+						self.propagate(index, None);
+					},
+					Select::Range { msb: lhs, lsb: rhs } |
+					Select::IndexedUp { base: lhs, width: rhs } |
+					Select::IndexedDown { base: lhs, width: rhs } => {
+						self.propagate(lhs, None);
+						self.propagate(rhs, None);
+					},
+				}
+			},
+			Expression::Concatenation(items) | Expression::Streaming { items, .. } => {
+				if let Expression::Streaming { slice: Some(slice), .. } = expr.inner() {
+					self.propagate(slice, None);
+				}
+				for item in items {
+					self.propagate(item, None);
+				}
+			},
+			Expression::Replication { count, items } => {
+				self.propagate(count, None);
+				for item in items {
+					self.propagate(item, None);
+				}
+			},
+			Expression::Call { arguments, .. } => {
+				for argument in arguments {
+					self.propagate(argument, None);
+				}
+			},
+			Expression::SystemCall { arguments, .. } => {
+				for argument in arguments.iter().flatten() {
+					self.propagate(argument, None);
+				}
+			},
+			Expression::Unary { operator, operand, .. } => {
+				let context = matches!(
+					operator.inner(),
+					UnaryOperator::Plus | UnaryOperator::Minus | UnaryOperator::BitwiseNot
+				)
+				.then_some(final_type);
+				self.propagate(operand, context);
+			},
+			Expression::Binary { operator, lhs, rhs, .. } => match operator_kind(*operator.inner())
+			{
+				OperatorKind::Context => {
+					self.propagate(lhs, Some(final_type));
+					self.propagate(rhs, Some(final_type));
+				},
+				OperatorKind::Comparison => {
+					// The operands are extended to the wider of the two, but not past it
+					let operands = combine(self.self_type(lhs), self.self_type(rhs));
+					self.propagate(lhs, Some(operands));
+					self.propagate(rhs, Some(operands));
+				},
+				OperatorKind::Logical => {
+					self.propagate(lhs, None);
+					self.propagate(rhs, None);
+				},
+				OperatorKind::Shift => {
+					self.propagate(lhs, Some(final_type));
+					self.propagate(rhs, None);
+				},
+			},
+			Expression::Conditional { condition, if_true, if_false, .. } => {
+				self.propagate(condition, None);
+				self.propagate(if_true, Some(final_type));
+				self.propagate(if_false, Some(final_type));
+			},
+			Expression::MinTypMax { min, typ, max } => {
+				for expr in [min, typ, max] {
+					self.propagate(expr, Some(final_type));
+				}
+			},
+			Expression::Parenthesized(inner) => {
+				self.propagate(inner, context.map(|_| final_type));
+			},
+			Expression::Assign { lvalue, value, .. } => {
+				let target = self.propagate(lvalue, None);
+				if let Some(inferred) = self
+					.inferred
+					.get(&std::ptr::from_ref(value.inner()))
+					.cloned()
+				{
+					self.assigned_inferred(target, value, &inferred);
+				}
+			},
+			Expression::IncDec { operand, .. } => {
+				self.propagate(operand, None);
+			},
+			Expression::Cast { target, value } => {
+				// Casting to a type is done as if by assigning to a variable of the type
+				// IEEE 1800-2017 § 6.24.1
+				let context = match target.inner() {
+					CastTarget::Type(_) | CastTarget::Expression(_) if self_type.is_integral() => {
+						Some(assignment_context(self_type, self.self_type(value)))
+					},
+					_ => None,
+				};
+				self.propagate(value, context);
+			},
+			Expression::AssignmentPattern { items, .. } => {
+				for item in items {
+					match item.inner() {
+						PatternItem::Positional(value) |
+						PatternItem::Keyed { value, .. } |
+						PatternItem::Default(value) => {
+							self.propagate(value, None);
+						},
+					}
+				}
+			},
+			Expression::Inside { expression, set } => {
+				self.propagate(expression, None);
+				for item in set {
+					self.propagate(item, None);
+				}
+			},
+			Expression::ValueRange { low: lhs, high: rhs } |
+			Expression::ToleranceRange { center: lhs, tolerance: rhs, .. } |
+			Expression::With { target: lhs, expression: rhs } => {
+				self.propagate(lhs, None);
+				self.propagate(rhs, None);
+			},
+			Expression::New { size, arguments } => {
+				for argument in size.iter().map(AsRef::as_ref).chain(arguments) {
+					self.propagate(argument, None);
+				}
+			},
+			Expression::RandomizeWith { call, .. } => {
+				self.propagate(call, None);
+			},
+			Expression::Literal(_) |
+			Expression::Identifier(_) |
+			Expression::Scope { .. } |
+			Expression::MacroUsage(_) |
+			Expression::This |
+			Expression::Super |
+			Expression::Null |
+			Expression::Unbounded |
+			Expression::DataType(_) => (),
+		}
+
+		final_type
+	}
+
+	/// The self-determined type of an operand that has already been inferred
+	fn self_type(&self, expr: &Spanned<Expression>) -> ExprType {
+		self.inferred
+			.get(&std::ptr::from_ref(expr.inner()))
+			.map_or(ExprType::Unknown, Inferred::value_type)
+	}
+
+	/// Literals have the type of their value, unsized literals only need as many bits as their
+	/// value to fit
+	///
+	/// IEEE 1800-2017 § 5.7.1
+	fn literal(&mut self, expr: &Spanned<Expression>, literal: &Literal) -> Inferred {
+		let sized = match literal {
+			Literal::Real(_) | Literal::Time { .. } => return Inferred::new(ExprType::Real),
+			Literal::UnbasedUnsized(_) => return Inferred::new(ExprType::LOGIC),
+			Literal::Unsigned(_) => false,
+			Literal::Based { size, .. } => size.is_some(),
+			Literal::String(_) | Literal::TripleQuotedString(_) => true,
+		};
+
+		let Some(value) = self.evaluator.evaluate(expr) else {
+			return Inferred::new(ExprType::Unknown);
+		};
+
+		let inferred = Inferred::new(ExprType::of_value(&value));
+		if sized {
+			inferred
+		} else {
+			let fit = value_fit(&value).unwrap_or(inferred.fit);
+			inferred.with_fit(fit)
+		}
+	}
+
+	fn name(&self, name: &str) -> Inferred {
+		if let Some(declared) = self.lookup(name) {
+			return declared.into();
+		}
+
+		// Enum members and parameters of imported packages are only known to the evaluator
+		match self.evaluator.lookup(name) {
+			Some(constant) => (&constant_type(constant)).into(),
+			None => Inferred::new(ExprType::Unknown),
+		}
+	}
+
+	/// Selects consume the unpacked dimensions first, then the packed ones, and are unsigned
+	///
+	/// IEEE 1800-2017 § 7.4.6, § 11.5.1
+	fn select(&mut self, target: &Inferred, select: &'a Select) -> Inferred {
+		let count = match select {
+			Select::Bit(index) => {
+				self.infer(index);
+				Some(1)
+			},
+			Select::Range { msb, lsb } => {
+				self.infer(msb);
+				self.infer(lsb);
+				self.constant(msb)
+					.zip(self.constant(lsb))
+					.and_then(|(msb, lsb)| u32::try_from(msb.abs_diff(lsb) + 1).ok())
+			},
+			Select::IndexedUp { base, width } | Select::IndexedDown { base, width } => {
+				self.infer(base);
+				self.infer(width);
+				self.constant(width)
+					.and_then(|width| u32::try_from(width).ok())
+			},
+		};
+
+		if target.unpacked > 0 {
+			let mut inferred = target.clone();
+			if matches!(select, Select::Bit(_)) {
+				inferred.unpacked -= 1;
+			}
+			return inferred;
+		}
+
+		match (target.data_type, count) {
+			(ExprType::Integral { width, two_state, .. }, Some(count)) => {
+				let (element, packed) = match target.packed.split_first() {
+					Some((first, rest)) if *first > 0 => (width / first, rest),
+					_ => (1, &[][..]),
+				};
+
+				let packed = if matches!(select, Select::Bit(_)) {
+					packed.to_vec()
+				} else {
+					std::iter::once(count)
+						.chain(packed.iter().copied())
+						.collect()
+				};
+
+				Inferred {
+					packed,
+					..Inferred::new(ExprType::Integral {
+						width: element.saturating_mul(count),
+						signed: false,
+						two_state,
+					})
+				}
+			},
+			(ExprType::String, _) if matches!(select, Select::Bit(_)) => {
+				Inferred::new(ExprType::Integral {
+					width:     8,
+					signed:    false,
+					two_state: true,
+				})
+			},
+			_ => Inferred::new(ExprType::Unknown),
+		}
+	}
+
+	/// The items of a concatenation are self-determined, and the result is unsigned
+	///
+	/// IEEE 1800-2017 § 11.4.12
+	fn concatenation(&mut self, items: &'a [Spanned<Expression>]) -> ExprType {
+		let mut width = 0_u32;
+		let mut two_state = true;
+		let mut string = false;
+		let mut unknown = false;
+
+		for item in items {
+			match self.infer(item).value_type() {
+				ExprType::Integral { width: item, two_state: item_two_state, .. } => {
+					width = width.saturating_add(item);
+					two_state &= item_two_state;
+				},
+				ExprType::String => string = true,
+				_ => unknown = true,
+			}
+		}
+
+		if string {
+			ExprType::String
+		} else if unknown {
+			ExprType::Unknown
+		} else {
+			ExprType::Integral { width, signed: false, two_state }
+		}
+	}
+
+	fn cast(&mut self, target: &CastTarget, value: ExprType) -> ExprType {
+		match target {
+			CastTarget::Type(data_type) => self.data_type(data_type),
+			CastTarget::Expression(Expression::Identifier(name))
+				if let Some(data_type) = self.evaluator.lookup_type(name.as_str()) =>
+			{
+				data_type.map_or(ExprType::Unknown, ExprType::from)
+			},
+			CastTarget::Expression(size) => {
+				let size = Spanned::new(size.clone(), Span::empty());
+				match self
+					.evaluator
+					.evaluate(&size)
+					.and_then(|size| size.to_i64())
+					.and_then(|size| u32::try_from(size).ok())
+				{
+					Some(width) => ExprType::Integral {
+						width,
+						signed: value.is_signed(),
+						two_state: matches!(value, ExprType::Integral { two_state: true, .. }),
+					},
+					None => ExprType::Unknown,
+				}
+			},
+			CastTarget::Signing(signing) => value.with_signed(*signing == Signing::Signed),
+			CastTarget::Const => value,
+		}
+	}
+
+	/// The value of a constant expression as an integer
+	fn constant(&mut self, expr: &Spanned<Expression>) -> Option<i64> {
+		self.evaluator.evaluate(expr)?.to_i64()
+	}
+}
+
+/// How the operands of a binary operator are sized
+///
+/// IEEE 1800-2017 § 11.6.1, Table 11-21
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum OperatorKind {
+	/// Both operands are context-determined, e.g. `+` and `&`
+	Context,
+	/// The operands are sized to each other and the result is a single bit, e.g. `==`
+	Comparison,
+	/// Both operands are self-determined and the result is a single bit, e.g. `&&`
+	Logical,
+	/// The left operand is context-determined and the right self-determined, e.g. `<<` and `**`
+	Shift,
+}
+
+fn operator_kind(operator: BinaryOperator) -> OperatorKind {
+	match operator {
+		BinaryOperator::Add |
+		BinaryOperator::Subtract |
+		BinaryOperator::Multiply |
+		BinaryOperator::Divide |
+		BinaryOperator::Modulus |
+		BinaryOperator::BitwiseAnd |
+		BinaryOperator::BitwiseOr |
+		BinaryOperator::BitwiseXor |
+		BinaryOperator::BitwiseXnor => OperatorKind::Context,
+		BinaryOperator::LogicalEquality |
+		BinaryOperator::LogicalInequality |
+		BinaryOperator::CaseEquality |
+		BinaryOperator::CaseInequality |
+		BinaryOperator::WildcardEquality |
+		BinaryOperator::WildcardInequality |
+		BinaryOperator::LessThan |
+		BinaryOperator::LessThanEqual |
+		BinaryOperator::GreaterThan |
+		BinaryOperator::GreaterThanEqual => OperatorKind::Comparison,
+		BinaryOperator::LogicalAnd |
+		BinaryOperator::LogicalOr |
+		BinaryOperator::LogicalImplication |
+		BinaryOperator::LogicalEquivalence => OperatorKind::Logical,
+		BinaryOperator::Power |
+		BinaryOperator::ShiftLeft |
+		BinaryOperator::ShiftRight |
+		BinaryOperator::ArithmeticShiftLeft |
+		BinaryOperator::ArithmeticShiftRight => OperatorKind::Shift,
+	}
+}
+
+/// The type of an expression with operands of type `lhs` and `rhs` that are sized to each other
+///
+/// Integral operands are extended to the wider of the two and are only signed if both are, and
+/// if either is real the expression is real.
+///
+/// IEEE 1800-2017 § 11.8.1
+fn combine(lhs: ExprType, rhs: ExprType) -> ExprType {
+	match (lhs, rhs) {
+		(
+			ExprType::Integral { width, signed, two_state },
+			ExprType::Integral {
+				width: rhs_width,
+				signed: rhs_signed,
+				two_state: rhs_two_state,
+			},
+		) => ExprType::Integral {
+			width:     width.max(rhs_width),
+			signed:    signed && rhs_signed,
+			two_state: two_state && rhs_two_state,
+		},
+		(ExprType::ShortReal, ExprType::ShortReal | ExprType::Integral { .. }) |
+		(ExprType::Integral { .. }, ExprType::ShortReal) => ExprType::ShortReal,
+		(ExprType::Real | ExprType::ShortReal, ExprType::Real | ExprType::ShortReal) |
+		(ExprType::Real, ExprType::Integral { .. }) |
+		(ExprType::Integral { .. }, ExprType::Real) => ExprType::Real,
+		(ExprType::String, ExprType::String) => ExprType::String,
+		_ => ExprType::Unknown,
+	}
+}
+
+/// Apply the type of the enclosing expression to an operand of type `data_type`
+fn extend(context: ExprType, data_type: ExprType) -> ExprType {
+	match (context, data_type) {
+		(
+			ExprType::Integral { width: context_width, signed, .. },
+			ExprType::Integral { width, two_state, .. },
+		) => ExprType::Integral { width: context_width.max(width), signed, two_state },
+		(ExprType::Real, ExprType::Integral { .. } | ExprType::ShortReal) => ExprType::Real,
+		(ExprType::ShortReal, ExprType::Integral { .. }) => ExprType::ShortReal,
+		_ => data_type,
+	}
+}
+
+/// The context of a value assigned to a target of type `target`, the value is extended to the
+/// width of the target but keeps its own signedness
+///
+/// IEEE 1800-2017 § 11.8.2
+fn assignment_context(target: ExprType, value: ExprType) -> ExprType {
+	match (target, value) {
+		(
+			ExprType::Integral { width: target, .. },
+			ExprType::Integral { width, signed, two_state },
+		) => ExprType::Integral { width: target.max(width), signed, two_state },
+		_ => value,
+	}
+}
+
+/// The single bit result of a reduction, logical, or comparison operator on operands of type
+/// `operand`
+fn single_bit(operand: ExprType) -> ExprType {
+	ExprType::Integral {
+		width:     1,
+		signed:    false,
+		two_state: matches!(operand, ExprType::Integral { two_state: true, .. }),
+	}
+}
+
+/// The type of a constant, parameters without an explicit type only need as many bits as their
+/// value to fit
+fn constant_type(constant: &Constant) -> Declared {
+	match constant.data_type {
+		Some(data_type) => Declared::new(data_type.into()),
+		None => {
+			let declared = Declared::new(ExprType::of_value(&constant.value));
+			Declared {
+				fit: value_fit(&constant.value).unwrap_or(declared.fit),
+				..declared
+			}
+		},
+	}
+}
+
+/// The number of bits needed to hold an integral value, including the sign bit of negative
+/// values
+fn value_fit(value: &Value) -> Option<u32> {
+	let Value::Vector(vec) = value else {
+		return None;
+	};
+	if !vec.is_known() {
+		return Some(vec.width());
+	}
+
+	let negative = vec.is_negative();
+	let sign = if negative {
+		Logic::One
+	} else {
+		Logic::Zero
+	};
+	let top = (0..vec.width()).rev().find(|idx| vec.bit(*idx) != sign);
+
+	Some(top.map_or(1, |idx| idx + 1 + u32::from(negative)))
+}
+
+/// The expressions of parameter value assignments or port connections
+fn connection_expressions(connections: &Connections) -> impl Iterator<Item = &Spanned<Expression>> {
+	let (ordered, named) = match connections {
+		Connections::Ordered(ordered) => (Some(ordered), None),
+		Connections::Named(named) => (None, Some(named)),
+	};
+
+	ordered
+		.into_iter()
+		.flatten()
+		.flatten()
+		.chain(
+			named
+				.into_iter()
+				.flatten()
+				.filter_map(|connection| connection.inner().value.as_ref()),
+		)
+		// Type parameter values are not expressions
+		.filter(|expr| !matches!(expr.inner(), Expression::DataType(_)))
+}
+
+/// The type of a call to a system function with arguments of the types `arguments`
+///
+/// IEEE 1800-2017 § 20
+fn system_call_type(name: &SystemFunc, arguments: &[ExprType]) -> ExprType {
+	const INT: ExprType = ExprType::Integral { width: 32, signed: true, two_state: true };
+	const BIT: ExprType = ExprType::Integral { width: 1, signed: false, two_state: true };
+
+	let SystemFunc::Builtin(function) = name else {
+		return ExprType::Unknown;
+	};
+	let first = arguments.first().copied().unwrap_or(ExprType::Unknown);
+
+	match function {
+		BuiltinSysFunc::Signed => first.with_signed(true),
+		BuiltinSysFunc::Unsigned => first.with_signed(false),
+		BuiltinSysFunc::Past | BuiltinSysFunc::PastGclk | BuiltinSysFunc::Sampled => first,
+		BuiltinSysFunc::Bits |
+		BuiltinSysFunc::Size |
+		BuiltinSysFunc::Left |
+		BuiltinSysFunc::Right |
+		BuiltinSysFunc::Low |
+		BuiltinSysFunc::High |
+		BuiltinSysFunc::Increment |
+		BuiltinSysFunc::Dimensions |
+		BuiltinSysFunc::UnpackedDimensions |
+		BuiltinSysFunc::CountOnes |
+		BuiltinSysFunc::CountBits |
+		BuiltinSysFunc::Cast => INT,
+		BuiltinSysFunc::Clog2 |
+		BuiltinSysFunc::Rtoi |
+		BuiltinSysFunc::Random |
+		BuiltinSysFunc::DistChiSquare |
+		BuiltinSysFunc::DistErlang |
+		BuiltinSysFunc::DistExponential |
+		BuiltinSysFunc::DistNormal |
+		BuiltinSysFunc::DistPoisson |
+		BuiltinSysFunc::DistT |
+		BuiltinSysFunc::DistUniform |
+		BuiltinSysFunc::Fopen |
+		BuiltinSysFunc::Fgetc |
+		BuiltinSysFunc::Ungetc |
+		BuiltinSysFunc::Fgets |
+		BuiltinSysFunc::Fscanf |
+		BuiltinSysFunc::Sscanf |
+		BuiltinSysFunc::Fread |
+		BuiltinSysFunc::Ftell |
+		BuiltinSysFunc::Fseek |
+		BuiltinSysFunc::Rewind |
+		BuiltinSysFunc::Feof |
+		BuiltinSysFunc::Ferror |
+		BuiltinSysFunc::TestPlusArgs |
+		BuiltinSysFunc::ValuePlusArgs |
+		BuiltinSysFunc::System => ExprType::INTEGER,
+		BuiltinSysFunc::Urandom | BuiltinSysFunc::UrandomRange => {
+			ExprType::Integral { width: 32, signed: false, two_state: true }
+		},
+		BuiltinSysFunc::OneHot |
+		BuiltinSysFunc::OneHot0 |
+		BuiltinSysFunc::IsUnknown |
+		BuiltinSysFunc::IsUnbounded |
+		BuiltinSysFunc::Rose |
+		BuiltinSysFunc::Fell |
+		BuiltinSysFunc::Stable |
+		BuiltinSysFunc::Changed |
+		BuiltinSysFunc::RoseGclk |
+		BuiltinSysFunc::FellGclk |
+		BuiltinSysFunc::StableGclk |
+		BuiltinSysFunc::ChangedGclk |
+		BuiltinSysFunc::RisingGclk |
+		BuiltinSysFunc::FallingGclk |
+		BuiltinSysFunc::SteadyGclk |
+		BuiltinSysFunc::ChangingGclk => BIT,
+		BuiltinSysFunc::Time => {
+			ExprType::Integral { width: 64, signed: false, two_state: false }
+		},
+		BuiltinSysFunc::Stime => {
+			ExprType::Integral { width: 32, signed: false, two_state: false }
+		},
+		BuiltinSysFunc::RealToBits => {
+			ExprType::Integral { width: 64, signed: false, two_state: true }
+		},
+		BuiltinSysFunc::ShortRealToBits => {
+			ExprType::Integral { width: 32, signed: false, two_state: true }
+		},
+		BuiltinSysFunc::BitsToShortReal => ExprType::ShortReal,
+		BuiltinSysFunc::RealTime |
+		BuiltinSysFunc::Itor |
+		BuiltinSysFunc::BitsToReal |
+		BuiltinSysFunc::Ln |
+		BuiltinSysFunc::Log10 |
+		BuiltinSysFunc::Exp |
+		BuiltinSysFunc::Sqrt |
+		BuiltinSysFunc::Pow |
+		BuiltinSysFunc::Floor |
+		BuiltinSysFunc::Ceil |
+		BuiltinSysFunc::Sin |
+		BuiltinSysFunc::Cos |
+		BuiltinSysFunc::Tan |
+		BuiltinSysFunc::Asin |
+		BuiltinSysFunc::Acos |
+		BuiltinSysFunc::Atan |
+		BuiltinSysFunc::Atan2 |
+		BuiltinSysFunc::Hypot |
+		BuiltinSysFunc::Sinh |
+		BuiltinSysFunc::Cosh |
+		BuiltinSysFunc::Tanh |
+		BuiltinSysFunc::Asinh |
+		BuiltinSysFunc::Acosh |
+		BuiltinSysFunc::Atanh |
+		BuiltinSysFunc::Temperature |
+		BuiltinSysFunc::Vt |
+		BuiltinSysFunc::SimParam => ExprType::Real,
+		BuiltinSysFunc::Sformatf | BuiltinSysFunc::TypeName | BuiltinSysFunc::SimParamStr => {
+			ExprType::String
+		},
+		_ => ExprType::Unknown,
+	}
+}
@@ -0,0 +1,810 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::fmt::Display;
+
+use vermilion_diagnostics::{Code, StringDiagnostic};
+use vermilion_loc::{FileId, Location, Span};
+
+use crate::{
+	LanguageStd,
+	diagnostics::{system_verilog, verilog, verilog_ams},
+	lang::{
+		ast::{Ast, Description},
+		elab::{Value, ValueType},
+	},
+};
+
+mod infer;
+
+use self::infer::Checker;
+
+/// The type of an expression as far as sizing and signedness are concerned
+///
+/// IEEE 1800-2017 § 11.6, § 11.8
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ExprType {
+	/// An integral value, packed arrays and structures are flattened to a single vector
+	Integral {
+		width:     u32,
+		signed:    bool,
+		two_state: bool,
+	},
+	Real,
+	ShortReal, // Added: IEEE 1800-2005
+	String,    // Added: IEEE 1800-2005
+	/// The result of a call to a `void` function
+	Void, // Added: IEEE 1800-2005
+	/// The type could not be inferred, or is one that expression sizing does not apply to, such
+	/// as an unpacked array, a class handle, or an event
+	Unknown,
+}
+
+/// The inferred type of a single expression
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypedExpression {
+	/// The file the expression is in and its span
+	pub location:           Location<()>,
+	/// The type of the expression from its operands alone
+	pub self_type:          ExprType,
+	/// The type the expression is evaluated at, after extending it to the size and signedness
+	/// of the expression it is an operand of
+	pub final_type:         ExprType,
+	/// Whether the expression is a context-determined operand, one whose size and signedness
+	/// are affected by the expression it is in, rather than self-determined
+	pub context_determined: bool,
+}
+
+/// The type of a declared net, variable, port, or parameter
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypedDeclaration {
+	/// The file the declaration is in and the span of the declared name
+	pub location:  Location<()>,
+	/// The type of the declaration, or of its elements if it is an unpacked array
+	pub data_type: ExprType,
+	/// The sizes of the packed dimensions, outermost first
+	pub packed:    Vec<u32>,
+	/// The sizes of the unpacked dimensions, outermost first, `None` for those that are not of a
+	/// fixed size, such as dynamic arrays and queues, or whose size could not be evaluated
+	pub unpacked:  Vec<Option<u32>>,
+	/// The value of a parameter, from its default value
+	pub value:     Option<Value>,
+}
+
+/// The inferred types of the expressions in a set of Verilog, SystemVerilog, or Verilog-AMS
+/// source files
+///
+/// Expressions are typed in two passes, first their self-determined types are found from their
+/// operands, then the size and signedness of the outermost expression, along with that of the
+/// target of an assignment, are propagated down to its context-determined operands. The types
+/// of names come from their declarations, with parameters taking their default values, and
+/// expressions whose type can not be found, such as hierarchical references or members of
+/// structures, are [`ExprType::Unknown`].
+///
+/// The expressions are keyed by their location, as the AST nodes do not carry an identity of
+/// their own, and assignments that implicitly truncate their value are reported. The types of
+/// the nets, variables, ports, and parameters are kept along with them, keyed by the location of
+/// the declared name.
+///
+/// IEEE 1800-2017 § 11.6, § 11.8, § 12.5.1
+#[derive(Clone, Debug)]
+pub struct TypeTable {
+	std:                      LanguageStd,
+	expressions:              Vec<TypedExpression>,
+	declarations:             Vec<TypedDeclaration>,
+	diagnostics:              Vec<Location<StringDiagnostic>>,
+	expressions_by_location:  LocationIndex,
+	declarations_by_location: LocationIndex,
+}
+
+/// The items of a list sorted by their location, so they can be found by where they are without
+/// going through the whole list
+#[derive(Clone, Debug, Default)]
+struct LocationIndex {
+	/// The file, begin, and end of each item along with its index in the list
+	entries: Vec<(FileId, u32, u32, usize)>,
+	/// The furthest end of any entry up to and including each one in the same file
+	reach:   Vec<u32>,
+}
+
+impl ExprType {
+	/// `integer`
+	pub const INTEGER: Self = Self::Integral { width: 32, signed: true, two_state: false };
+	/// A single bit result, such as that of a comparison
+	pub const LOGIC: Self = Self::Integral { width: 1, signed: false, two_state: false };
+
+	/// The width of the type in bits, if it has one
+	pub fn width(&self) -> Option<u32> {
+		match self {
+			Self::Integral { width, .. } => Some(*width),
+			Self::Real => Some(64),
+			Self::ShortReal => Some(32),
+			Self::String | Self::Void | Self::Unknown => None,
+		}
+	}
+
+	pub fn is_signed(&self) -> bool {
+		match self {
+			Self::Integral { signed, .. } => *signed,
+			Self::Real | Self::ShortReal => true,
+			Self::String | Self::Void | Self::Unknown => false,
+		}
+	}
+
+	pub fn is_integral(&self) -> bool {
+		matches!(self, Self::Integral { .. })
+	}
+
+	pub fn is_real(&self) -> bool {
+		matches!(self, Self::Real | Self::ShortReal)
+	}
+
+	/// The type of a constant value
+	pub fn of_value(value: &Value) -> Self {
+		match value {
+			Value::Vector(vec) => Self::Integral {
+				width:     vec.width(),
+				signed:    vec.is_signed(),
+				two_state: false,
+			},
+			Value::Real(_) => Self::Real,
+		}
+	}
+
+	/// This type with the signedness replaced, types that are not integral are unchanged
+	pub fn with_signed(self, signed: bool) -> Self {
+		match self {
+			Self::Integral { width, two_state, .. } => Self::Integral { width, signed, two_state },
+			_ => self,
+		}
+	}
+}
+
+impl From<ValueType> for ExprType {
+	fn from(value_type: ValueType) -> Self {
+		match value_type {
+			ValueType::Vector { signed, two_state, .. } => {
+				Self::Integral { width: value_type.width(), signed, two_state }
+			},
+			ValueType::Real => Self::Real,
+			ValueType::ShortReal => Self::ShortReal,
+			ValueType::String => Self::String,
+		}
+	}
+}
+
+impl Display for ExprType {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Integral { width, signed, two_state } => {
+				f.write_str(if *two_state {
+					"bit"
+				} else {
+					"logic"
+				})?;
+				if *signed {
+					f.write_str(" signed")?;
+				}
+				if *width > 1 {
+					write!(f, " [{}:0]", width - 1)?;
+				}
+				Ok(())
+			},
+			Self::Real => f.write_str("real"),
+			Self::ShortReal => f.write_str("shortreal"),
+			Self::String => f.write_str("string"),
+			Self::Void => f.write_str("void"),
+			Self::Unknown => f.write_str("<unknown>"),
+		}
+	}
+}
+
+impl TypedExpression {
+	/// Whether the expression is extended past its self-determined width
+	pub fn is_extended(&self) -> bool {
+		matches!(
+			(self.self_type.width(), self.final_type.width()),
+			(Some(width), Some(final_width)) if final_width > width
+		)
+	}
+}
+
+impl TypedDeclaration {
+	/// The total width of the declaration in bits, including all of its unpacked elements
+	pub fn width(&self) -> Option<u32> {
+		self.unpacked
+			.iter()
+			.try_fold(self.data_type.width()?, |width, size| {
+				width.checked_mul((*size)?)
+			})
+	}
+}
+
+impl TypeTable {
+	/// Infer the types of the expressions of the source files, the packages of every file are
+	/// visible to all of them
+	pub fn new(std: LanguageStd, files: &[(FileId, &Ast)]) -> Self {
+		let mut table = Self {
+			std,
+			expressions: Vec::new(),
+			declarations: Vec::new(),
+			diagnostics: Vec::new(),
+			expressions_by_location: LocationIndex::default(),
+			declarations_by_location: LocationIndex::default(),
+		};
+
+		let Some((first, _)) = files.first() else {
+			return table;
+		};
+
+		let mut checker = Checker::new(&mut table, *first);
+		for (file, ast) in files {
+			checker.add_packages(*file, ast.descriptions());
+		}
+
+		// Packages are checked first so their declarations can be imported by the other files
+		for (file, ast) in files {
+			checker.set_file(*file);
+			for description in ast.descriptions() {
+				if let Description::Package(package) = description.inner() {
+					checker.package(package);
+				}
+			}
+		}
+
+		for (file, ast) in files {
+			checker.set_file(*file);
+			checker.descriptions(ast.descriptions());
+		}
+
+		table.expressions_by_location = LocationIndex::new(
+			table
+				.expressions
+				.iter()
+				.map(|expression| &expression.location),
+		);
+		table.declarations_by_location = LocationIndex::new(
+			table
+				.declarations
+				.iter()
+				.map(|declaration| &declaration.location),
+		);
+
+		table
+	}
+
+	/// Every typed expression, outermost expressions before their operands
+	pub fn expressions(&self) -> &[TypedExpression] {
+		&self.expressions
+	}
+
+	/// The expression covering exactly the offsets of `span` in `file`
+	pub fn expression(&self, file: FileId, span: Span) -> Option<&TypedExpression> {
+		self.expressions
+			.get(self.expressions_by_location.exact(file, &span)?)
+	}
+
+	/// The innermost expression containing `offset` in `file`
+	pub fn expression_at(&self, file: FileId, offset: u32) -> Option<&TypedExpression> {
+		self.expressions
+			.get(self.expressions_by_location.innermost(file, offset)?)
+	}
+
+	/// Every typed net, variable, port, and parameter declaration
+	pub fn declarations(&self) -> &[TypedDeclaration] {
+		&self.declarations
+	}
+
+	/// The declaration of the name at exactly the offsets of `span` in `file`
+	pub fn declaration(&self, file: FileId, span: Span) -> Option<&TypedDeclaration> {
+		self.declarations
+			.get(self.declarations_by_location.exact(file, &span)?)
+	}
+
+	/// Implicit truncations found while typing the expressions
+	pub fn diagnostics(&self) -> &[Location<StringDiagnostic>] {
+		&self.diagnostics
+	}
+
+	fn push(&mut self, expression: TypedExpression) {
+		self.expressions.push(expression);
+	}
+
+	fn push_declaration(&mut self, declaration: TypedDeclaration) {
+		self.declarations.push(declaration);
+	}
+
+	fn truncation(&mut self, location: Location<()>, width: u32, target: u32) {
+		self.diagnostics
+			.push(location.with_inner(StringDiagnostic::new(
+				truncation_code(self.std),
+				format!("{width} bit expression is implicitly truncated to {target} bits"),
+				Some(*location.span()),
+			)));
+	}
+}
+
+impl LocationIndex {
+	fn new<'a>(locations: impl Iterator<Item = &'a Location<()>>) -> Self {
+		let mut entries = locations
+			.enumerate()
+			.map(|(idx, location)| (*location.file_id(), *location.begin(), *location.end(), idx))
+			.collect::<Vec<_>>();
+		entries.sort_unstable();
+
+		let mut reach = Vec::<u32>::with_capacity(entries.len());
+		for (idx, (file, _, end, _)) in entries.iter().enumerate() {
+			let before = idx
+				.checked_sub(1)
+				.filter(|before| entries[*before].0 == *file)
+				.map_or(0, |before| reach[before]);
+			reach.push(before.max(*end));
+		}
+
+		Self { entries, reach }
+	}
+
+	/// The index of the first item at exactly `span` in `file`
+	fn exact(&self, file: FileId, span: &Span) -> Option<usize> {
+		let key = (file, *span.begin(), *span.end());
+		let idx = self
+			.entries
+			.partition_point(|(file, begin, end, _)| (*file, *begin, *end) < key);
+
+		self.entries
+			.get(idx)
+			.filter(|(file, begin, end, _)| (*file, *begin, *end) == key)
+			.map(|(.., item)| *item)
+	}
+
+	/// The index of the shortest item containing `offset` in `file`, including its end, and the
+	/// first of them if there are several
+	fn innermost(&self, file: FileId, offset: u32) -> Option<usize> {
+		let after = self
+			.entries
+			.partition_point(|(other, begin, ..)| (*other, *begin) <= (file, offset));
+
+		let mut innermost: Option<(u32, usize)> = None;
+		for idx in (0..after).rev() {
+			let (other, begin, end, item) = self.entries[idx];
+
+			// NOTE(aki): Nothing before here reaches `offset`, or is shorter than what was found
+			if other != file ||
+				self.reach[idx] < offset ||
+				innermost.is_some_and(|(length, _)| offset - begin > length)
+			{
+				break;
+			}
+
+			if offset <= end && innermost.is_none_or(|found| (end - begin, item) < found) {
+				innermost = Some((end - begin, item));
+			}
+		}
+
+		innermost.map(|(_, item)| item)
+	}
+}
+
+/// The diagnostic code for implicit truncations in `std`
+fn truncation_code(std: LanguageStd) -> Code {
+	if LanguageStd::SYSTEM_VERILOG_STDS.contains(std) {
+		system_verilog::L0001
+	} else if LanguageStd::VERILOG_AMS_STDS.contains(std) {
+		verilog_ams::L0001
+	} else {
+		verilog::L0001
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use vermilion_diagnostics::Diagnostic;
+
+	use super::*;
+	use crate::lang::parser::VerilogParser;
+
+	fn check(std: LanguageStd, source: &str) -> TypeTable {
+		let ast = VerilogParser::new(std, source.as_bytes().into())
+			.unwrap_or_else(|err| panic!("Failed to create parser: {err}"))
+			.parse();
+		assert!(
+			ast.diagnostics().is_empty(),
+			"Unexpected parse errors: {:#?}",
+			ast.diagnostics()
+		);
+
+		TypeTable::new(std, &[(FileId::new(0_usize), &ast)])
+	}
+
+	/// The expression with the text `expr`, the last occurrence of it in `source`
+	fn typed<'t>(table: &'t TypeTable, source: &str, expr: &str) -> &'t TypedExpression {
+		typed_in(table, source, expr, expr)
+	}
+
+	/// The expression with the text `expr` in the last occurrence of `context` in `source`
+	fn typed_in<'t>(
+		table: &'t TypeTable,
+		source: &str,
+		context: &str,
+		expr: &str,
+	) -> &'t TypedExpression {
+		let begin = source
+			.rfind(context)
+			.and_then(|begin| Some(begin + context.find(expr)?))
+			.unwrap_or_else(|| panic!("`{expr}` is not in the source"));
+		let span = Span::from((offset(begin), offset(begin + expr.len())));
+
+		table
+			.expression(FileId::new(0_usize), span)
+			.unwrap_or_else(|| panic!("`{expr}` in `{context}` was not typed"))
+	}
+
+	fn offset(offset: usize) -> u32 {
+		u32::try_from(offset).unwrap_or_else(|err| panic!("{err}"))
+	}
+
+	fn types(table: &TypeTable, source: &str, expr: &str) -> (String, String) {
+		types_in(table, source, expr, expr)
+	}
+
+	fn types_in(table: &TypeTable, source: &str, context: &str, expr: &str) -> (String, String) {
+		let typed = typed_in(table, source, context, expr);
+		(typed.self_type.to_string(), typed.final_type.to_string())
+	}
+
+	fn messages(table: &TypeTable) -> Vec<&str> {
+		table
+			.diagnostics()
+			.iter()
+			.map(|diagnostic| diagnostic.inner().message())
+			.collect()
+	}
+
+	#[test]
+	fn test_self_determined() {
+		let source = "module m;
+	wire [7:0] a;
+	wire [3:0] b;
+	wire signed [15:0] c;
+	reg [31:0] x;
+	initial begin
+		x = a + b;
+		x = {a, b};
+		x = {2{a}};
+		x = a[3:0];
+		x = a[2 +: 3];
+		x = a[1];
+		x = &a;
+		x = a == b;
+		x = a && b;
+		x = b << a;
+		x = 4'd3;
+		x = 12;
+		x = c;
+	end
+endmodule
+";
+		let table = check(LanguageStd::Vl05, source);
+
+		let self_type = |expr| types(&table, source, expr).0;
+		assert_eq!(self_type("a + b"), "logic [7:0]");
+		assert_eq!(self_type("{a, b}"), "logic [11:0]");
+		assert_eq!(self_type("{2{a}}"), "logic [15:0]");
+		assert_eq!(self_type("a[3:0]"), "logic [3:0]");
+		assert_eq!(self_type("a[2 +: 3]"), "logic [2:0]");
+		assert_eq!(self_type("a[1]"), "logic");
+		assert_eq!(self_type("&a"), "logic");
+		assert_eq!(self_type("a == b"), "logic");
+		assert_eq!(self_type("a && b"), "logic");
+		assert_eq!(self_type("b << a"), "logic [3:0]");
+		assert_eq!(self_type("4'd3"), "logic [3:0]");
+		assert_eq!(self_type("12"), "logic signed [31:0]");
+		assert_eq!(self_type("c"), "logic signed [15:0]");
+	}
+
+	#[test]
+	fn test_context_determined() {
+		let source = "module m;
+	wire [7:0] a;
+	wire [3:0] b, c;
+	reg [15:0] x;
+	reg y;
+	initial begin
+		x = a + b;
+		y = (c == a);
+		x = {a - b};
+		x = a ? b : 4'd1;
+		x = c << a;
+	end
+endmodule
+";
+		let table = check(LanguageStd::Vl05, source);
+
+		// The operands of the addition are extended to the width of the assignment target
+		let sum = typed(&table, source, "a + b");
+		assert_eq!(sum.final_type.to_string(), "logic [15:0]");
+		assert!(sum.is_extended());
+		assert!(sum.context_determined);
+		assert_eq!(
+			types_in(&table, source, "b :", "b"),
+			("logic [3:0]".into(), "logic [15:0]".into())
+		);
+		assert_eq!(
+			types(&table, source, "4'd1"),
+			("logic [3:0]".into(), "logic [15:0]".into())
+		);
+
+		// The operands of a comparison are only extended to each other
+		assert_eq!(types(&table, source, "c == a").1, "logic");
+		assert_eq!(
+			types_in(&table, source, "c ==", "c"),
+			("logic [3:0]".into(), "logic [7:0]".into())
+		);
+
+		// Concatenation operands are self-determined
+		let difference = typed(&table, source, "a - b");
+		assert_eq!(difference.final_type.to_string(), "logic [7:0]");
+		assert!(!difference.context_determined);
+
+		// The shift amount is self-determined
+		assert_eq!(types_in(&table, source, "c <<", "c").1, "logic [15:0]");
+		assert_eq!(types_in(&table, source, "<< a", "a").1, "logic [7:0]");
+	}
+
+	#[test]
+	fn test_signedness() {
+		let source = "module m;
+	wire signed [7:0] s;
+	wire signed [3:0] t;
+	wire [7:0] u;
+	reg signed [15:0] x;
+	initial begin
+		x = s + t;
+		x = s + u;
+		x = s + 4'sd1;
+		x = $unsigned(s);
+		x = $signed(u);
+		x = s[3:0];
+		x = {s};
+	end
+endmodule
+";
+		let table = check(LanguageStd::Vl05, source);
+
+		let self_type = |expr| types(&table, source, expr).0;
+		assert_eq!(self_type("s + t"), "logic signed [7:0]");
+		// Mixing signed and unsigned operands makes the expression unsigned
+		assert_eq!(self_type("s + u"), "logic [7:0]");
+		assert_eq!(self_type("s + 4'sd1"), "logic signed [7:0]");
+		assert_eq!(self_type("$unsigned(s)"), "logic [7:0]");
+		assert_eq!(self_type("$signed(u)"), "logic signed [7:0]");
+		assert_eq!(self_type("s[3:0]"), "logic [3:0]");
+		assert_eq!(self_type("{s}"), "logic [7:0]");
+
+		// A signed operand of an unsigned expression is extended as unsigned
+		let lhs = typed_in(&table, source, "s + u", "s");
+		assert_eq!(lhs.final_type.to_string(), "logic [15:0]");
+	}
+
+	#[test]
+	fn test_declarations() {
+		let source = "package p;
+	typedef logic [5:0] word_t;
+	parameter int W = 12;
+endpackage
+
+module m #(parameter N = 4) (input [N-1:0] d);
+	import p::*;
+	word_t w;
+	logic [W-1:0] v;
+	logic [3:0][7:0] packed_array;
+	logic [7:0] memory [16];
+	int i;
+	real r;
+	logic [31:0] x;
+
+	function logic [2:0] f(input int a);
+		return a;
+	endfunction
+
+	initial begin
+		x = d;
+		x = w;
+		x = v;
+		x = packed_array[1];
+		x = memory[2];
+		x = memory[2][3];
+		x = i;
+		x = f(1);
+		r = r * 2;
+		x = $clog2(N);
+	end
+endmodule
+";
+		let table = check(LanguageStd::Sv17, source);
+
+		let self_type = |expr| types(&table, source, expr).0;
+		assert_eq!(types_in(&table, source, "= d;", "d").0, "logic [3:0]");
+		assert_eq!(types_in(&table, source, "= w;", "w").0, "logic [5:0]");
+		assert_eq!(types_in(&table, source, "= v;", "v").0, "logic [11:0]");
+		assert_eq!(self_type("packed_array[1]"), "logic [7:0]");
+		assert_eq!(self_type("memory[2]"), "logic [7:0]");
+		assert_eq!(self_type("memory[2][3]"), "logic");
+		assert_eq!(types_in(&table, source, "= i;", "i").0, "bit signed [31:0]");
+		assert_eq!(self_type("f(1)"), "logic [2:0]");
+		assert_eq!(
+			types_in(&table, source, "* 2", "2"),
+			("logic signed [31:0]".into(), "real".into())
+		);
+		assert_eq!(self_type("$clog2(N)"), "logic signed [31:0]");
+	}
+
+	#[test]
+	fn test_expression_at() {
+		let source = "module m;
+	wire [7:0] a;
+	wire [3:0] b;
+	wire [15:0] x = a + b;
+endmodule
+";
+		let table = check(LanguageStd::Vl05, source);
+
+		let offset = offset(source.rfind("b;").unwrap_or_default());
+		let typed = table
+			.expression_at(FileId::new(0_usize), offset)
+			.unwrap_or_else(|| panic!("No expression at {offset}"));
+		assert_eq!(typed.self_type.to_string(), "logic [3:0]");
+		assert_eq!(typed.final_type.to_string(), "logic [15:0]");
+
+		assert!(table.expression_at(FileId::new(0_usize), 0).is_none());
+	}
+
+	#[test]
+	fn test_location_index() {
+		let location = |file: usize, begin: u32, end: u32| {
+			Location::new(FileId::new(file), (), Span::from((begin, end)))
+		};
+		let locations = [
+			location(1, 0, 20),
+			location(0, 4, 12),
+			location(0, 4, 8),
+			location(0, 10, 12),
+			location(0, 0, 30),
+			location(0, 4, 8),
+			location(0, 14, 16),
+		];
+		let index = LocationIndex::new(locations.iter());
+
+		let file = FileId::new(0_usize);
+		assert_eq!(index.exact(file, &Span::from((4_u32, 8_u32))), Some(2));
+		assert_eq!(index.exact(file, &Span::from((4_u32, 9_u32))), None);
+		assert_eq!(index.exact(FileId::new(1_usize), &Span::from((0_u32, 20_u32))), Some(0));
+
+		// The shortest one wins, with the first of them winning a tie
+		assert_eq!(index.innermost(file, 6), Some(2));
+		assert_eq!(index.innermost(file, 8), Some(2));
+		assert_eq!(index.innermost(file, 11), Some(3));
+		assert_eq!(index.innermost(file, 13), Some(4));
+		assert_eq!(index.innermost(file, 31), None);
+		assert_eq!(index.innermost(FileId::new(1_usize), 25), None);
+		assert_eq!(index.innermost(FileId::new(2_usize), 0), None);
+	}
+
+	#[test]
+	fn test_truncation() {
+		let source = "module m #(parameter P = 3, parameter [7:0] Q = 3);
+	wire [7:0] a;
+	wire [3:0] b;
+	reg [3:0] x;
+	reg [1:0] y;
+	assign b = a;
+	initial begin
+		x = a + b;
+		x = a[3:0];
+		x = 4'd15;
+		x = 12;
+		x = 'hf;
+		x = P;
+		x = 20;
+		x = Q;
+		y <= -1;
+		x = x + 1;
+	end
+endmodule
+";
+		let table = check(LanguageStd::Vl05, source);
+
+		assert_eq!(
+			messages(&table),
+			[
+				"8 bit expression is implicitly truncated to 4 bits",
+				"8 bit expression is implicitly truncated to 4 bits",
+				"32 bit expression is implicitly truncated to 4 bits",
+				"8 bit expression is implicitly truncated to 4 bits",
+			]
+		);
+		assert!(
+			table
+				.diagnostics()
+				.iter()
+				.all(|diagnostic| diagnostic.inner().code() == verilog::L0001)
+		);
+	}
+
+	#[test]
+	fn test_truncation_code() {
+		let source = "module m;
+	logic [7:0] a;
+	logic [3:0] b;
+	always_comb b = a;
+endmodule
+";
+		let table = check(LanguageStd::Sv17, source);
+
+		let codes = table
+			.diagnostics()
+			.iter()
+			.map(|diagnostic| diagnostic.inner().code())
+			.collect::<Vec<_>>();
+		assert_eq!(codes, [system_verilog::L0001]);
+	}
+
+	#[test]
+	fn test_declared_types() {
+		let source = "module m #(parameter WIDTH = 4, parameter [7:0] MASK = 8'hf0);
+	localparam DEPTH = WIDTH * 2;
+	wire [WIDTH - 1:0] a;
+	logic [3:0][7:0] b;
+	reg [7:0] mem [0:DEPTH - 1];
+	int queue [$];
+endmodule
+";
+		let table = check(LanguageStd::Sv17, source);
+
+		let declaration = |name: &str| {
+			let begin = source
+				.find(&format!(" {name}"))
+				.unwrap_or_else(|| panic!("`{name}` is not in the source")) +
+				1;
+			let span = Span::from((offset(begin), offset(begin + name.len())));
+			table
+				.declaration(FileId::new(0_usize), span)
+				.unwrap_or_else(|| panic!("`{name}` was not declared"))
+		};
+
+		let width = declaration("WIDTH");
+		assert_eq!(
+			width.value.as_ref().map(Value::to_string).as_deref(),
+			Some("4")
+		);
+		let mask = declaration("MASK");
+		assert_eq!(mask.data_type.to_string(), "logic [7:0]");
+		assert_eq!(
+			mask.value.as_ref().map(Value::to_string).as_deref(),
+			Some("240")
+		);
+		let depth = declaration("DEPTH");
+		assert_eq!(
+			depth.value.as_ref().map(Value::to_string).as_deref(),
+			Some("8")
+		);
+
+		let a = declaration("a");
+		assert_eq!(
+			(a.data_type.to_string(), a.width()),
+			("logic [3:0]".into(), Some(4))
+		);
+		assert!(a.value.is_none());
+
+		let b = declaration("b");
+		assert_eq!(b.packed, [4, 8]);
+		assert_eq!(b.width(), Some(32));
+
+		let mem = declaration("mem");
+		assert_eq!(mem.unpacked, [Some(8)]);
+		assert_eq!(mem.width(), Some(64));
+
+		let queue = declaration("queue");
+		assert_eq!(queue.unpacked, [None]);
+		assert_eq!(queue.width(), None);
+	}
+}
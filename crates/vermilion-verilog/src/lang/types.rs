@@ -17,9 +17,12 @@ pub enum NetType {
 	Tri1,
 	TriAnd,
 	TriOr,
+	Trireg,
+	Uwire, // Added: IEEE 1364-2005
 	Wire,
 	WireAnd,
 	WireOr,
+	Wreal, // Added: Verilog-AMS 2009
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -64,6 +67,7 @@ pub enum EdgeDescriptor {
 pub enum EdgeIdentifier {
 	PosEdge,
 	NegEdge,
+	Edge, // Added: IEEE 1800-2009
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -74,6 +78,7 @@ pub enum TimeUnit {
 	Nanoseconds,
 	Picoseconds,
 	Femtoseconds,
+	Step, // Added: IEEE 1800-2005
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -225,18 +230,68 @@ impl BasedLiteralSpecifier {
 	pub fn new(specifier: BaseSpecifier, uppercase: bool, signed: bool) -> Self {
 		Self { specifier, uppercase, signed }
 	}
+
+	pub fn specifier(&self) -> BaseSpecifier {
+		self.specifier
+	}
+
+	pub fn is_uppercase(&self) -> bool {
+		self.uppercase
+	}
+
+	pub fn is_signed(&self) -> bool {
+		self.signed
+	}
+}
+
+impl Identifier {
+	pub fn as_str(&self) -> &str {
+		match self {
+			Self::Simple(tendril) | Self::Escaped(tendril) => unsafe {
+				str::from_utf8_unchecked(tendril)
+			},
+		}
+	}
 }
 
 impl SingleQuotedString {
 	pub fn new(tendril: AtomicByteTendril) -> Self {
 		Self(tendril)
 	}
+
+	pub fn as_str(&self) -> &str {
+		unsafe { str::from_utf8_unchecked(&self.0) }
+	}
+}
+
+impl SystemFunc {
+	/// The name of the system function without the leading `$`
+	pub fn as_str(&self) -> &str {
+		match self {
+			Self::Builtin(builtin) => builtin.as_str(),
+			Self::Other(tendril) => unsafe { str::from_utf8_unchecked(tendril) },
+		}
+	}
+}
+
+impl TextMacro {
+	/// The name of the text macro without the leading `` ` ``
+	pub fn as_str(&self) -> &str {
+		match self {
+			Self::Builtin(builtin) => builtin.as_str(),
+			Self::Other(tendril) => unsafe { str::from_utf8_unchecked(tendril) },
+		}
+	}
 }
 
 impl TripleQuotedString {
 	pub fn new(tendril: AtomicByteTendril) -> Self {
 		Self(tendril)
 	}
+
+	pub fn as_str(&self) -> &str {
+		unsafe { str::from_utf8_unchecked(&self.0) }
+	}
 }
 
 #[cfg_attr(coverage_nightly, coverage(off))]
@@ -293,34 +348,36 @@ impl Display for CompilerDirective {
 	}
 }
 
+impl Control {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Apostrophe => "'", // Added: IEEE 1800-2005
+			Self::At => "@",
+			Self::AttributeClose => "*)", // Added: IEEE 1364-2001
+			Self::AttributeOpen => "(*",  // Added: IEEE 1364-2001
+			Self::BraceClose => "}",
+			Self::BraceOpen => "{",
+			Self::BracketClose => "]",
+			Self::BracketOpen => "[",
+			Self::Colon => ":",
+			Self::Comma => ",",
+			Self::Dollar => "$",
+			Self::Dot => ".",
+			Self::Grave => "`",
+			Self::Octothorp => "#",
+			Self::ParenClose => ")",
+			Self::ParenOpen => "(",
+			Self::Question => "?",
+			Self::ReverseSolidus => "\\",
+			Self::Semicolon => ";",
+		}
+	}
+}
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 impl Display for Control {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(
-			f,
-			"Control({})",
-			match self {
-				Self::Apostrophe => "'", // Added: IEEE 1800-2005
-				Self::At => "@",
-				Self::AttributeClose => "*)", // Added: IEEE 1364-2001
-				Self::AttributeOpen => "(*",  // Added: IEEE 1364-2001
-				Self::BraceClose => "}",
-				Self::BraceOpen => "{",
-				Self::BracketClose => "]",
-				Self::BracketOpen => "[",
-				Self::Colon => ":",
-				Self::Comma => ",",
-				Self::Dollar => "$",
-				Self::Dot => ".",
-				Self::Grave => "`",
-				Self::Octothorp => "#",
-				Self::ParenClose => ")",
-				Self::ParenOpen => "(",
-				Self::Question => "?",
-				Self::ReverseSolidus => "\\",
-				Self::Semicolon => ";",
-			}
-		)
+		write!(f, "Control({})", self.as_str())
 	}
 }
 
@@ -338,86 +395,89 @@ impl Display for Identifier {
 	}
 }
 
+impl Operator {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::AddEquals => "+=",     // Added: IEEE 1800-2005
+			Self::AbsTolerance => "+/-", // Added: IEEE 1800-2023
+			Self::Ampersand => "&",
+			Self::AndEquals => "&=",             // Added: IEEE 1800-2005
+			Self::ArithmeticShl => "<<<",        // Added: IEEE 1364-2001
+			Self::ArithmeticShlEquals => "<<<=", // Added: IEEE 1800-2005
+			Self::ArithmeticShr => ">>>",        // Added: IEEE 1364-2001
+			Self::ArithmeticShrEquals => ">>>=", // Added: IEEE 1800-2005
+			Self::Asterisk => "*",
+			Self::BranchContribution => "<+", // Added: Verilog-AMS 2009
+			Self::CaseEquality => "===",
+			Self::CaseInequality => "!==",
+			Self::Circumflex => "^",
+			Self::ClassScopeResolution => "::", // Added: IEEE 1800-2005
+			Self::CycleDelay => "##",           // Added: IEEE 1800-2005
+			Self::Decrement => "--",            // Added: IEEE 1800-2005
+			Self::DivEquals => "/=",            // Added: IEEE 1800-2005
+			Self::Equals => "=",
+			Self::Equivalence => "<->", // Added: IEEE 1800-2009
+			Self::EventTrigger => "->",
+			Self::EventTriggerNb => "->>", // Added: IEEE 1800-2005
+			Self::Exclamation => "!",
+			Self::FollowedByNonOverlapped => "#=#", // Added: IEEE 1800-2009
+			Self::FollowedByOverlapped => "#-#",    // Added: IEEE 1800-2009
+			Self::FullConnection => "*>",
+			Self::GreaterThan => "<",
+			Self::GreaterThanEqual => "<=",
+			Self::Increment => "++",      // Added: IEEE 1800-2005
+			Self::IndexedPartNeg => "-:", // Added: IEEE 1364-2001
+			Self::IndexedPartPos => "+:", // Added: IEEE 1364-2001
+			Self::LessThan => ">",
+			Self::LessThanEqual => ">=",
+			Self::LogicalAnd => "&&",
+			Self::LogicalEquality => "==",
+			Self::LogicalInequality => "!=",
+			Self::LogicalOr => "||",
+			Self::Minus => "-",
+			Self::MulEquals => "*=", // Added: IEEE 1800-2005
+			Self::OrEquals => "|=",  // Added: IEEE 1800-2005
+			Self::ParallelConnection => "=>",
+			Self::Percent => "%",
+			Self::Pipe => "|",
+			Self::Plus => "+",
+			Self::Pow => "**",                 // Added: IEEE 1364-2001
+			Self::PropImplNonOverlap => "|=>", // Added: IEEE 1800-2005
+			Self::PropImplOverlap => "|->",    // Added: IEEE 1800-2005
+			Self::ReductionNand => "~&",
+			Self::ReductionNor => "~|",
+			Self::RelTolerance => "+%-", // Added: IEEE 1800-2023
+			Self::RemEquals => "%=",     // Added: IEEE 1800-2005
+			Self::ShiftLeft => "<<",
+			Self::ShiftLeftEquals => "<<=", // Added: IEEE 1800-2005
+			Self::ShiftRight => ">>",
+			Self::ShiftRightEquals => ">>=", // Added: IEEE 1800-2005
+			Self::Solidus => "/",
+			Self::SubEquals => "-=", // Added: IEEE 1800-2005
+			Self::Tilde => "~",
+			Self::TildeCircumflex(chirality) => {
+				if *chirality {
+					"^~"
+				} else {
+					"~^"
+				}
+			},
+			Self::TripleAnd => "&&&",
+			Self::WeightAssignDist => ":/",  // Added: IEEE 1800-2005
+			Self::WeightAssignUnit => ":=",  // Added: IEEE 1800-2005
+			Self::Wildcard => ".*",          // Added: IEEE 1800-2005
+			Self::WildcardEqual => "==?",    // Added: IEEE 1800-2005
+			Self::WildcardExport => "*::*",  // Added: IEEE 1800-2009
+			Self::WildcardNotEqual => "!=?", // Added: IEEE 1800-2005
+			Self::XorEquals => "^=",         // Added: IEEE 1800-2005
+		}
+	}
+}
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 impl Display for Operator {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(
-			f,
-			"Operator({})",
-			match self {
-				Self::AddEquals => "+=",     // Added: IEEE 1800-2005
-				Self::AbsTolerance => "+/-", // Added: IEEE 1800-2023
-				Self::Ampersand => "&",
-				Self::AndEquals => "&=",             // Added: IEEE 1800-2005
-				Self::ArithmeticShl => "<<<",        // Added: IEEE 1364-2001
-				Self::ArithmeticShlEquals => "<<<=", // Added: IEEE 1800-2005
-				Self::ArithmeticShr => ">>>",        // Added: IEEE 1364-2001
-				Self::ArithmeticShrEquals => ">>>=", // Added: IEEE 1800-2005
-				Self::Asterisk => "*",
-				Self::BranchContribution => "<+", // Added: Verilog-AMS 2009
-				Self::CaseEquality => "===",
-				Self::CaseInequality => "!==",
-				Self::Circumflex => "^",
-				Self::ClassScopeResolution => "::", // Added: IEEE 1800-2005
-				Self::CycleDelay => "##",           // Added: IEEE 1800-2005
-				Self::Decrement => "--",            // Added: IEEE 1800-2005
-				Self::DivEquals => "/=",            // Added: IEEE 1800-2005
-				Self::Equals => "=",
-				Self::Equivalence => "<->", // Added: IEEE 1800-2009
-				Self::EventTrigger => "->",
-				Self::EventTriggerNb => "->>", // Added: IEEE 1800-2005
-				Self::Exclamation => "!",
-				Self::FollowedByNonOverlapped => "#=#", // Added: IEEE 1800-2009
-				Self::FollowedByOverlapped => "#-#",    // Added: IEEE 1800-2009
-				Self::FullConnection => "*>",
-				Self::GreaterThan => "<",
-				Self::GreaterThanEqual => "<=",
-				Self::Increment => "++",      // Added: IEEE 1800-2005
-				Self::IndexedPartNeg => "-:", // Added: IEEE 1364-2001
-				Self::IndexedPartPos => "+:", // Added: IEEE 1364-2001
-				Self::LessThan => ">",
-				Self::LessThanEqual => ">=",
-				Self::LogicalAnd => "&&",
-				Self::LogicalEquality => "==",
-				Self::LogicalInequality => "!=",
-				Self::LogicalOr => "||",
-				Self::Minus => "-",
-				Self::MulEquals => "*=", // Added: IEEE 1800-2005
-				Self::OrEquals => "|=",  // Added: IEEE 1800-2005
-				Self::ParallelConnection => "=>",
-				Self::Percent => "%",
-				Self::Pipe => "|",
-				Self::Plus => "+",
-				Self::Pow => "**",                 // Added: IEEE 1364-2001
-				Self::PropImplNonOverlap => "|=>", // Added: IEEE 1800-2005
-				Self::PropImplOverlap => "|->",    // Added: IEEE 1800-2005
-				Self::ReductionNand => "~&",
-				Self::ReductionNor => "~|",
-				Self::RelTolerance => "+%-", // Added: IEEE 1800-2023
-				Self::RemEquals => "%=",     // Added: IEEE 1800-2005
-				Self::ShiftLeft => "<<",
-				Self::ShiftLeftEquals => "<<=", // Added: IEEE 1800-2005
-				Self::ShiftRight => ">>",
-				Self::ShiftRightEquals => ">>=", // Added: IEEE 1800-2005
-				Self::Solidus => "/",
-				Self::SubEquals => "-=", // Added: IEEE 1800-2005
-				Self::Tilde => "~",
-				Self::TildeCircumflex(chirality) =>
-					if *chirality {
-						"^~"
-					} else {
-						"~^"
-					},
-				Self::TripleAnd => "&&&",
-				Self::WeightAssignDist => ":/",  // Added: IEEE 1800-2005
-				Self::WeightAssignUnit => ":=",  // Added: IEEE 1800-2005
-				Self::Wildcard => ".*",          // Added: IEEE 1800-2005
-				Self::WildcardEqual => "==?",    // Added: IEEE 1800-2005
-				Self::WildcardExport => "*::*",  // Added: IEEE 1800-2009
-				Self::WildcardNotEqual => "!=?", // Added: IEEE 1800-2005
-				Self::XorEquals => "^=",         // Added: IEEE 1800-2005
-			}
-		)
+		write!(f, "Operator({})", self.as_str())
 	}
 }
 
@@ -54,6 +54,15 @@ impl LanguageStd {
 		self.bits.count_ones()
 	}
 
+	/// The oldest of the standards in the set
+	///
+	/// The standards are ordered by revision within Verilog, SystemVerilog, and Verilog-AMS, so
+	/// this is the standard that something supported by all of the standards in the set was
+	/// added in.
+	pub fn earliest(&self) -> Self {
+		Self { bits: self.bits & self.bits.wrapping_neg() }
+	}
+
 	pub fn is_verilog(&self) -> bool {
 		self.contains(Self::VERILOG_STDS)
 	}
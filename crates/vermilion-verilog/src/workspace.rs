@@ -1,10 +1,17 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
-use vermilion_macros::cfg_schema;
+use std::{
+	collections::BTreeMap,
+	path::{Path, PathBuf},
+};
+
+use globset::{GlobBuilder, GlobMatcher};
+use vermilion_macros::{cfg_schema, cfg_serde};
 
 use crate::{
 	LanguageStd,
 	fmt::workspace::{SystemVerilogFormat, VerilogAmsFormat, VerilogFormat},
+	lang::preprocessor::Preprocessor,
 	lint::workspace::{SystemVerilogLint, VerilogAmsLint, VerilogLint},
 };
 
@@ -17,11 +24,14 @@ use crate::{
 )]
 pub struct VerilogWorkspace {
 	/// Specific Verilog standard to use
-	pub std:  LanguageStd,
+	pub std:    LanguageStd,
 	/// Verilog formatting options
-	pub fmt:  Option<VerilogFormat>,
+	pub fmt:    Option<VerilogFormat>,
 	/// Verilog lint options
-	pub lint: Option<VerilogLint>,
+	pub lint:   Option<VerilogLint>,
+	/// Where Verilog sources are found and how they are preprocessed
+	#[cfg_attr(feature = "serde", serde(flatten))]
+	pub search: VerilogSearchSettings,
 }
 
 /// Workspace specific SystemVerilog options
@@ -33,11 +43,14 @@ pub struct VerilogWorkspace {
 )]
 pub struct SystemVerilogWorkspace {
 	/// Specific SystemVerilog standard to use
-	pub std:  LanguageStd,
+	pub std:    LanguageStd,
 	/// SystemVerilog formatting options
-	pub fmt:  Option<SystemVerilogFormat>,
+	pub fmt:    Option<SystemVerilogFormat>,
 	/// SystemVerilog lint options
-	pub lint: Option<SystemVerilogLint>,
+	pub lint:   Option<SystemVerilogLint>,
+	/// Where SystemVerilog sources are found and how they are preprocessed
+	#[cfg_attr(feature = "serde", serde(flatten))]
+	pub search: VerilogSearchSettings,
 }
 
 /// Workspace specific Verilog-AMS options
@@ -49,19 +62,151 @@ pub struct SystemVerilogWorkspace {
 )]
 pub struct VerilogAmsWorkspace {
 	/// Specific Verilog-AMS standard to use
-	pub std:  LanguageStd,
+	pub std:    LanguageStd,
 	/// Verilog-AMS formatting options
-	pub fmt:  Option<VerilogAmsFormat>,
+	pub fmt:    Option<VerilogAmsFormat>,
 	/// Verilog-AMS lint options
-	pub lint: Option<VerilogAmsLint>,
+	pub lint:   Option<VerilogAmsLint>,
+	/// Where Verilog-AMS sources are found and how they are preprocessed
+	#[cfg_attr(feature = "serde", serde(flatten))]
+	pub search: VerilogSearchSettings,
+}
+
+/// Workspace specific options for finding and preprocessing the sources of the Verilog family
+/// languages
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(
+	feature = "serde",
+	derive(::serde::Serialize, ::serde::Deserialize),
+	serde(deny_unknown_fields)
+)]
+pub struct VerilogSearchSettings {
+	/// Directories to search for `` `include `` files in, relative to the workspace root
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+	pub include_dirs:       Vec<PathBuf>,
+	/// Directories to search for library modules in, relative to the workspace root
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+	pub library_dirs:       Vec<PathBuf>,
+	/// File extensions of the library module files in the library directories
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+	pub library_extensions: Vec<String>,
+	/// Macros defined before any file is read, as if by `` `define ``
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "BTreeMap::is_empty"))]
+	pub defines:            BTreeMap<String, String>,
+	/// Language standard overrides for specific files
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+	pub overrides:          Vec<StdOverride>,
+}
+
+/// A language standard to use for all files matching a glob, rather than the workspace default
+///
+/// The glob is matched against the path of the file relative to the workspace root, `*` and `?`
+/// match within a single path component and `**` matches any number of components. The glob is
+/// compiled once when the override is created, so an invalid one is an error in the settings.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+	feature = "serde",
+	derive(::serde::Serialize, ::serde::Deserialize),
+	serde(try_from = "StdOverrideSettings", into = "StdOverrideSettings")
+)]
+pub struct StdOverride {
+	/// Glob of the files to override the standard for, e.g. `rtl/legacy/**/*.v`
+	files:   String,
+	/// Specific standard to use for the matching files
+	std:     LanguageStd,
+	matcher: GlobMatcher,
+}
+
+cfg_serde! {
+	/// A [`StdOverride`] as it is written in the workspace settings
+	#[derive(::serde::Serialize, ::serde::Deserialize)]
+	#[serde(deny_unknown_fields)]
+	struct StdOverrideSettings {
+		files: String,
+		std:   LanguageStd,
+	}
+
+	impl TryFrom<StdOverrideSettings> for StdOverride {
+		type Error = globset::Error;
+
+		fn try_from(settings: StdOverrideSettings) -> Result<Self, Self::Error> {
+			Self::new(settings.files, settings.std)
+		}
+	}
+
+	impl From<StdOverride> for StdOverrideSettings {
+		fn from(std_override: StdOverride) -> Self {
+			Self { files: std_override.files, std: std_override.std }
+		}
+	}
+}
+
+impl StdOverride {
+	/// Create an override to `std` for the files matching the glob `files`
+	pub fn new(files: impl Into<String>, std: LanguageStd) -> Result<Self, globset::Error> {
+		let files = files.into();
+		let matcher = GlobBuilder::new(&files)
+			.literal_separator(true)
+			.build()?
+			.compile_matcher();
+
+		Ok(Self { files, std, matcher })
+	}
+
+	/// The glob of the files to override the standard for
+	pub fn files(&self) -> &str {
+		&self.files
+	}
+
+	/// The standard to use for the matching files
+	pub fn std(&self) -> LanguageStd {
+		self.std
+	}
+
+	/// Returns true if this override applies to `path`, which is relative to the workspace root
+	pub fn matches(&self, path: &Path) -> bool {
+		self.matcher.is_match(path)
+	}
+}
+
+impl VerilogSearchSettings {
+	/// The standard to use for the file at `path`, relative to the workspace root, in a workspace
+	/// using `std`
+	///
+	/// The first matching override wins.
+	pub fn std_for(&self, std: LanguageStd, path: &Path) -> LanguageStd {
+		self.overrides
+			.iter()
+			.find(|std_override| std_override.matches(path))
+			.map_or(std, StdOverride::std)
+	}
+
+	/// Add the include directories and macros of this workspace, which is rooted at `root`, to
+	/// `preprocessor`
+	pub fn configure_preprocessor(&self, root: &Path, preprocessor: &mut Preprocessor) {
+		for dir in &self.include_dirs {
+			preprocessor.add_include_path(root.join(dir));
+		}
+
+		for (name, value) in &self.defines {
+			preprocessor.define(name.as_str(), value);
+		}
+	}
+
+	/// Find the file holding the library module `name` in the library directories of this
+	/// workspace, which is rooted at `root`
+	pub fn find_library_module(&self, root: &Path, name: &str) -> Option<PathBuf> {
+		find_library_module(root, &self.library_dirs, &self.library_extensions, name)
+	}
 }
 
 impl Default for VerilogWorkspace {
 	fn default() -> Self {
 		Self {
-			std:  LanguageStd::Vl95,
-			fmt:  Default::default(),
-			lint: Default::default(),
+			std:    LanguageStd::Vl95,
+			fmt:    Default::default(),
+			lint:   Default::default(),
+			search: Default::default(),
 		}
 	}
 }
@@ -69,9 +214,10 @@ impl Default for VerilogWorkspace {
 impl Default for SystemVerilogWorkspace {
 	fn default() -> Self {
 		Self {
-			std:  LanguageStd::Sv05,
-			fmt:  Default::default(),
-			lint: Default::default(),
+			std:    LanguageStd::Sv05,
+			fmt:    Default::default(),
+			lint:   Default::default(),
+			search: Default::default(),
 		}
 	}
 }
@@ -79,14 +225,130 @@ impl Default for SystemVerilogWorkspace {
 impl Default for VerilogAmsWorkspace {
 	fn default() -> Self {
 		Self {
-			std:  LanguageStd::Vams09,
-			fmt:  Default::default(),
-			lint: Default::default(),
+			std:    LanguageStd::Vams09,
+			fmt:    Default::default(),
+			lint:   Default::default(),
+			search: Default::default(),
 		}
 	}
 }
 
+/// Library modules are looked for as a file named after the module with each of the extensions in
+/// turn, or with no extension at all if none are given
+pub fn find_library_module(
+	root: &Path,
+	library_dirs: &[PathBuf],
+	extensions: &[String],
+	name: &str,
+) -> Option<PathBuf> {
+	let no_extension = [String::new()];
+	let extensions = if extensions.is_empty() {
+		&no_extension[..]
+	} else {
+		extensions
+	};
+
+	library_dirs.iter().find_map(|dir| {
+		extensions
+			.iter()
+			.map(|extension| root.join(dir).join(format!("{name}{extension}")))
+			.find(|path| path.is_file())
+	})
+}
+
 cfg_schema! {
+	/// Add the properties of the flattened [`VerilogSearchSettings`] to the `schema` of a workspace
+	fn with_search_settings(
+		generator: &mut schemars::SchemaGenerator,
+		mut schema: schemars::Schema,
+	) -> schemars::Schema {
+		use schemars::JsonSchema;
+
+		let search = VerilogSearchSettings::json_schema(generator);
+		if let Some(properties) = schema
+			.get_mut("properties")
+			.and_then(|properties| properties.as_object_mut()) &&
+			let Some(search) = search
+				.get("properties")
+				.and_then(|properties| properties.as_object())
+		{
+			properties.extend(search.clone());
+		}
+
+		schema
+	}
+
+	#[cfg_attr(coverage_nightly, coverage(off))]
+	impl schemars::JsonSchema for VerilogSearchSettings {
+		fn schema_name() -> std::borrow::Cow<'static, str> {
+			"VerilogSearchSettings".into()
+		}
+
+		fn schema_id() -> std::borrow::Cow<'static, str> {
+			concat!(module_path!(), "::VerilogSearchSettings").into()
+		}
+
+		fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+			schemars::json_schema!({
+				"description": "Verilog Family Source Search Configuration",
+				"type": "object",
+				"properties": {
+					"include_dirs": {
+						"description": "Directories to search for `include files in",
+						"type": "array",
+						"items": { "type": "string" }
+					},
+					"library_dirs": {
+						"description": "Directories to search for library modules in",
+						"type": "array",
+						"items": { "type": "string" }
+					},
+					"library_extensions": {
+						"description": "File extensions of library module files",
+						"type": "array",
+						"items": { "type": "string" }
+					},
+					"defines": {
+						"description": "Macros defined before any file is read",
+						"type": "object",
+						"additionalProperties": { "type": "string" }
+					},
+					"overrides": {
+						"description": "Language standard overrides for specific files",
+						"type": "array",
+						"items": {
+							"type": "object",
+							"properties": {
+								"files": {
+									"description": "Glob of the files to override the standard for",
+									"type": "string"
+								},
+								"std": {
+									"description": "Verilog Family Standard",
+									"type": "string",
+									"enum": [
+										"Vl95",
+										"Vl01",
+										"Vl05",
+										"Sv05",
+										"Sv09",
+										"Sv12",
+										"Sv17",
+										"Sv23",
+										"Vams09",
+										"Vams14",
+										"Vams23"
+									]
+								}
+							},
+							"required": [ "files", "std" ]
+						}
+					}
+				}
+			})
+		}
+	}
+
 	#[cfg_attr(coverage_nightly, coverage(off))]
 	impl schemars::JsonSchema for VerilogWorkspace {
 		fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -110,7 +372,7 @@ cfg_schema! {
 				]
 			});
 
-			schemars::json_schema!({
+			let schema = schemars::json_schema!({
 				"description": "Verilog Workspace Configuration",
 				"type": "object",
 				"properties": {
@@ -118,7 +380,9 @@ cfg_schema! {
 					"fmt": fmt_schema,
 					"lint": lint_schema,
 				}
-			})
+			});
+
+			with_search_settings(generator, schema)
 		}
 	}
 
@@ -147,7 +411,7 @@ cfg_schema! {
 				]
 			});
 
-			schemars::json_schema!({
+			let schema = schemars::json_schema!({
 				"description": "SystemVerilog Workspace Configuration",
 				"type": "object",
 				"properties": {
@@ -155,7 +419,9 @@ cfg_schema! {
 					"fmt": fmt_schema,
 					"lint": lint_schema,
 				}
-			})
+			});
+
+			with_search_settings(generator, schema)
 		}
 	}
 
@@ -182,7 +448,7 @@ cfg_schema! {
 				]
 			});
 
-			schemars::json_schema!({
+			let schema = schemars::json_schema!({
 				"description": "Verilog-AMS Workspace Configuration",
 				"type": "object",
 				"properties": {
@@ -190,7 +456,75 @@ cfg_schema! {
 					"fmt": fmt_schema,
 					"lint": lint_schema,
 				}
-			})
+			});
+
+			with_search_settings(generator, schema)
 		}
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// Create an override with a glob that is known to be valid
+	fn std_override(files: &str, std: LanguageStd) -> StdOverride {
+		StdOverride::new(files, std).unwrap_or_else(|err| panic!("{err}"))
+	}
+
+	#[test]
+	fn test_override_globs() {
+		let matches = |files: &str, path: &str| {
+			std_override(files, LanguageStd::Vl05).matches(Path::new(path))
+		};
+
+		assert!(matches("*.v", "top.v"));
+		assert!(!matches("*.v", "rtl/top.v"));
+		assert!(!matches("*.v", "top.sv"));
+		assert!(matches("rtl/*.v", "rtl/top.v"));
+		assert!(matches("**/*.v", "top.v"));
+		assert!(matches("**/*.v", "rtl/legacy/top.v"));
+		assert!(matches("rtl/**", "rtl/legacy/top.v"));
+		assert!(!matches("rtl/**", "tb/top.v"));
+		assert!(matches("top?.v", "top1.v"));
+		assert!(!matches("top?.v", "top.v"));
+		assert!(matches("{rtl,tb}/*.v", "tb/top.v"));
+		assert!(StdOverride::new("rtl/[", LanguageStd::Vl05).is_err());
+	}
+
+	#[test]
+	fn test_std_overrides() {
+		let search = VerilogSearchSettings {
+			overrides: vec![
+				std_override("legacy/**/*.v", LanguageStd::Sv05),
+				std_override("**/*_23.sv", LanguageStd::Sv23),
+			],
+			..Default::default()
+		};
+
+		assert_eq!(
+			search.std_for(LanguageStd::Sv17, Path::new("rtl/top.sv")),
+			LanguageStd::Sv17
+		);
+		assert_eq!(
+			search.std_for(LanguageStd::Sv17, Path::new("legacy/cpu/alu.v")),
+			LanguageStd::Sv05
+		);
+		assert_eq!(
+			search.std_for(LanguageStd::Sv17, Path::new("rtl/fifo_23.sv")),
+			LanguageStd::Sv23
+		);
+	}
+
+	#[test]
+	fn test_workspace_preprocessor() {
+		let search = VerilogSearchSettings {
+			defines: BTreeMap::from([("SYNTHESIS".to_string(), String::new())]),
+			..Default::default()
+		};
+
+		let mut preprocessor = Preprocessor::new(LanguageStd::Vl05);
+		search.configure_preprocessor(Path::new("."), &mut preprocessor);
+		assert!(preprocessor.is_defined("SYNTHESIS"));
+	}
+}
@@ -18,6 +18,12 @@ vermilion_diagnostics::define_diagnostics_table! {
 vermilion_diagnostics::define_diagnostics_table! {
 	error, "./messages/error", vhdl,
 	0000 => "Placeholder",
+	0001 => "Unsupported in the selected language standard",
+	0002 => "Syntax error",
+	0003 => "Missing design unit",
+	0004 => "Circular dependency between design units",
+	0005 => "Malformed BSDL attribute value",
+	0006 => "BSDL description does not conform to IEEE 1149.1",
 }
 
 vermilion_diagnostics::define_diagnostics_table! {
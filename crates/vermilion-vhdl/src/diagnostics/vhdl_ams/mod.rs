@@ -18,6 +18,10 @@ vermilion_diagnostics::define_diagnostics_table! {
 vermilion_diagnostics::define_diagnostics_table! {
 	error, "./messages/error", vhdl_ams,
 	0000 => "Placeholder",
+	0001 => "Unsupported in the selected language standard",
+	0002 => "Syntax error",
+	0003 => "Missing design unit",
+	0004 => "Circular dependency between design units",
 }
 
 vermilion_diagnostics::define_diagnostics_table! {
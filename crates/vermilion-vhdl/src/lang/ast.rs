@@ -1,11 +0,0 @@
-// SPDX-License-Identifier: BSD-3-Clause
-
-#[derive(Debug, Default)]
-pub struct Ast {}
-
-#[cfg(test)]
-mod tests {
-	// TODO(aki): Write tests
-	#[allow(unused, reason = "Currently unused")]
-	use super::*;
-}
@@ -0,0 +1,483 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_lang::AtomicByteTendril;
+use vermilion_loc::Spanned;
+
+use crate::lang::ast::{
+	Association, BindingIndication, ComponentSpecification, DiscreteRange, Expression, Identifier,
+	Name, Package, PackageBody, PackageInstance, Range, SequentialStatement, Signature,
+	SubtypeIndication,
+};
+
+/// A declaration in a declarative part
+///
+/// IEEE 1076-2008 § 3.2.3, § 3.3.2, § 4.3, § 4.7, § 4.8, § 11.3, § 11.2
+#[derive(Clone, Debug, PartialEq)]
+pub enum Declaration {
+	Type(TypeDeclaration),
+	Subtype {
+		name:    Spanned<Identifier>,
+		subtype: Spanned<SubtypeIndication>,
+	},
+	Object(ObjectDeclaration),
+	Alias(AliasDeclaration),
+	Component(ComponentDeclaration),
+	/// `attribute name : type_mark;`
+	Attribute {
+		name:      Spanned<Identifier>,
+		type_mark: Spanned<Name>,
+	},
+	AttributeSpecification(AttributeSpecification),
+	/// A subprogram declaration without a body
+	Subprogram(SubprogramSpecification),
+	SubprogramBody(SubprogramBody),
+	SubprogramInstance(SubprogramInstance), // Added: IEEE 1076-2008
+	Use(Vec<Spanned<Name>>),
+	ConfigurationSpecification(ConfigurationSpecification),
+	/// `disconnect signals : type_mark after time;`
+	Disconnect {
+		signals:   InstantiationTargets,
+		type_mark: Spanned<Name>,
+		after:     Spanned<Expression>,
+	},
+	Package(Package),                 // Added: IEEE 1076-2008
+	PackageBody(PackageBody),         // Added: IEEE 1076-2008
+	PackageInstance(PackageInstance), // Added: IEEE 1076-2008
+	Psl(PslDeclaration),              // Added: IEEE 1076-2008
+	Nature(NatureDeclaration),        // Added: IEEE 1076.1-1999
+	/// `subnature name is subnature_indication;`
+	Subnature {
+		name:      Spanned<Identifier>,
+		subnature: Spanned<SubnatureIndication>,
+	}, // Added: IEEE 1076.1-1999
+	/// `terminal names : subnature_indication;`
+	Terminal {
+		names:  Vec<Spanned<Identifier>>,
+		nature: Spanned<SubnatureIndication>,
+	}, // Added: IEEE 1076.1-1999
+	Quantity(QuantityDeclaration),    // Added: IEEE 1076.1-1999
+	/// `limit quantities : type_mark with value;`
+	StepLimit {
+		quantities: InstantiationTargets,
+		type_mark:  Spanned<Name>,
+		limit:      Spanned<Expression>,
+	}, // Added: IEEE 1076.1-1999
+	/// A declaration that failed to parse, the error is reported in the diagnostics
+	Error,
+}
+
+/// The signals a disconnection specification, or the quantities a step limit specification,
+/// applies to
+///
+/// IEEE 1076-2008 § 7.4, IEEE 1076.1-2007 § 5.4
+#[derive(Clone, Debug, PartialEq)]
+pub enum InstantiationTargets {
+	Names(Vec<Spanned<Name>>),
+	Others,
+	All,
+}
+
+/// IEEE 1076-2008 § 6.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeDeclaration {
+	pub name:       Spanned<Identifier>,
+	/// The type definition, which is absent for incomplete type declarations
+	pub definition: Option<Spanned<TypeDefinition>>,
+}
+
+/// IEEE 1076-2008 § 5
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypeDefinition {
+	Enumeration(Vec<Spanned<EnumerationLiteral>>),
+	/// An integer, floating point, or physical type definition
+	Range {
+		range: Spanned<Range>,
+		units: Option<PhysicalUnits>,
+	},
+	Array {
+		indices: Vec<Spanned<IndexSubtype>>,
+		element: Spanned<SubtypeIndication>,
+	},
+	Record(Vec<Spanned<ElementDeclaration>>),
+	Access(Spanned<SubtypeIndication>),
+	File(Spanned<Name>),
+	Protected(Vec<Spanned<Declaration>>), // Added: IEEE 1076-2000
+	ProtectedBody(Vec<Spanned<Declaration>>), // Added: IEEE 1076-2000
+}
+
+/// IEEE 1076-2008 § 5.2.2.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum EnumerationLiteral {
+	Identifier(Identifier),
+	Character(AtomicByteTendril),
+}
+
+/// The `units ... end units` of a physical type definition
+///
+/// IEEE 1076-2008 § 5.2.4.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhysicalUnits {
+	pub primary:   Spanned<Identifier>,
+	pub secondary: Vec<Spanned<SecondaryUnit>>,
+}
+
+/// IEEE 1076-2008 § 5.2.4.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct SecondaryUnit {
+	pub name:  Spanned<Identifier>,
+	pub value: Spanned<Expression>,
+}
+
+/// IEEE 1076-2008 § 5.3.2.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum IndexSubtype {
+	/// `type_mark range <>`
+	Unconstrained(Spanned<Name>),
+	Constrained(DiscreteRange),
+}
+
+/// IEEE 1076-2008 § 5.3.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElementDeclaration {
+	pub names:   Vec<Spanned<Identifier>>,
+	pub subtype: Spanned<SubtypeIndication>,
+}
+
+/// IEEE 1076-2008 § 6.4.2.1
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ObjectClass {
+	Constant,
+	Signal,
+	Variable,
+	File,
+	Quantity, // Added: IEEE 1076.1-1999
+}
+
+/// IEEE 1076-2008 § 6.4.2.3
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SignalKind {
+	Register,
+	Bus,
+}
+
+/// IEEE 1076-2008 § 6.4.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObjectDeclaration {
+	pub class:       ObjectClass,
+	/// If this is a `shared variable`
+	pub shared:      bool, // Added: IEEE 1076-1993
+	pub names:       Vec<Spanned<Identifier>>,
+	pub subtype:     Spanned<SubtypeIndication>,
+	pub signal_kind: Option<SignalKind>,
+	pub default:     Option<Spanned<Expression>>,
+	pub file_open:   Option<FileOpenInformation>,
+}
+
+/// The `open kind is name` of a file declaration
+///
+/// IEEE 1076-2008 § 6.4.2.5
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileOpenInformation {
+	/// The file open kind, or the `in`/`out` mode of an IEEE 1076-1987 file declaration
+	pub kind: Option<Spanned<Expression>>,
+	pub name: Spanned<Expression>,
+}
+
+/// IEEE 1076-2008 § 4.2.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum Designator {
+	Identifier(Identifier),
+	OperatorSymbol(AtomicByteTendril),
+	Character(AtomicByteTendril),
+}
+
+/// IEEE 1076-2008 § 6.6.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct AliasDeclaration {
+	pub designator: Spanned<Designator>,
+	pub subtype:    Option<Spanned<SubtypeIndication>>,
+	pub name:       Spanned<Name>,
+	pub signature:  Option<Spanned<Signature>>,
+}
+
+/// IEEE 1076-2008 § 6.8
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComponentDeclaration {
+	pub name:     Spanned<Identifier>,
+	pub generics: Option<Vec<Spanned<InterfaceDeclaration>>>,
+	pub ports:    Option<Vec<Spanned<InterfaceDeclaration>>>,
+}
+
+/// IEEE 1076-2008 § 7.2
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum EntityClass {
+	Entity,
+	Architecture,
+	Configuration,
+	Procedure,
+	Function,
+	Package,
+	Type,
+	Subtype,
+	Constant,
+	Signal,
+	Variable,
+	Component,
+	Label,
+	Literal,   // Added: IEEE 1076-1993
+	Units,     // Added: IEEE 1076-1993
+	Group,     // Added: IEEE 1076-1993
+	File,      // Added: IEEE 1076-1993
+	Property,  // Added: IEEE 1076-2008
+	Sequence,  // Added: IEEE 1076-2008
+	View,      // Added: IEEE 1076-2019
+	Nature,    // Added: IEEE 1076.1-1999
+	Subnature, // Added: IEEE 1076.1-1999
+	Quantity,  // Added: IEEE 1076.1-1999
+	Terminal,  // Added: IEEE 1076.1-1999
+}
+
+/// IEEE 1076-2008 § 7.2
+#[derive(Clone, Debug, PartialEq)]
+pub enum EntityNameList {
+	/// The entity designators, each with an optional signature
+	Names(Vec<Spanned<(Designator, Option<Spanned<Signature>>)>>),
+	Others,
+	All,
+}
+
+/// `attribute name of entities : class is value;`
+///
+/// IEEE 1076-2008 § 7.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttributeSpecification {
+	pub attribute: Spanned<Identifier>,
+	pub entities:  EntityNameList,
+	pub class:     EntityClass,
+	pub value:     Spanned<Expression>,
+}
+
+/// IEEE 1076-2008 § 4.2.1
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SubprogramKind {
+	Procedure,
+	Function,
+	PureFunction,   // Added: IEEE 1076-1993
+	ImpureFunction, // Added: IEEE 1076-1993
+}
+
+/// IEEE 1076-2008 § 4.2.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubprogramSpecification {
+	pub kind:        SubprogramKind,
+	pub designator:  Spanned<Designator>,
+	pub generics:    Option<Vec<Spanned<InterfaceDeclaration>>>, // Added: IEEE 1076-2008
+	pub generic_map: Option<Vec<Spanned<Association>>>,          // Added: IEEE 1076-2008
+	pub parameters:  Option<Vec<Spanned<InterfaceDeclaration>>>,
+	pub return_type: Option<Spanned<Name>>,
+}
+
+/// IEEE 1076-2008 § 4.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubprogramBody {
+	pub specification: SubprogramSpecification,
+	pub declarations:  Vec<Spanned<Declaration>>,
+	pub statements:    Vec<Spanned<SequentialStatement>>,
+}
+
+/// `function a is new b generic map (...);`
+///
+/// IEEE 1076-2008 § 4.4
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubprogramInstance {
+	pub kind:        SubprogramKind,
+	pub designator:  Spanned<Designator>,
+	pub subprogram:  Spanned<Name>,
+	pub signature:   Option<Spanned<Signature>>,
+	pub generic_map: Option<Vec<Spanned<Association>>>,
+}
+
+/// `for instances : component use binding;`
+///
+/// IEEE 1076-2008 § 7.3.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigurationSpecification {
+	pub specification: ComponentSpecification,
+	pub binding:       BindingIndication,
+}
+
+/// IEEE 1076-2008 § 6.5.2
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Mode {
+	In,
+	Out,
+	InOut,
+	Buffer,
+	Linkage,
+}
+
+/// IEEE 1076-2008 § 6.5.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum InterfaceDeclaration {
+	Object(InterfaceObject),
+	/// `type name`
+	Type(Spanned<Identifier>), // Added: IEEE 1076-2008
+	/// `function name ... [is default]`
+	Subprogram {
+		specification: SubprogramSpecification,
+		default:       Option<Spanned<SubprogramDefault>>,
+	}, // Added: IEEE 1076-2008
+	/// `package name is new package generic map (...)`
+	Package {
+		name:        Spanned<Identifier>,
+		package:     Spanned<Name>,
+		generic_map: InterfacePackageGenerics,
+	}, // Added: IEEE 1076-2008
+	/// `terminal names : subnature_indication`
+	Terminal {
+		names:  Vec<Spanned<Identifier>>,
+		nature: Spanned<SubnatureIndication>,
+	}, // Added: IEEE 1076.1-1999
+}
+
+/// IEEE 1076-2008 § 6.5.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct InterfaceObject {
+	/// The explicit object class, if any
+	pub class:   Option<ObjectClass>,
+	pub names:   Vec<Spanned<Identifier>>,
+	pub mode:    Option<Mode>,
+	pub subtype: Spanned<SubtypeIndication>,
+	pub bus:     bool,
+	pub default: Option<Spanned<Expression>>,
+}
+
+/// IEEE 1076-2008 § 6.5.4
+#[derive(Clone, Debug, PartialEq)]
+pub enum SubprogramDefault {
+	Name(Name),
+	/// `is <>`
+	Box,
+}
+
+/// IEEE 1076-2008 § 6.5.5
+#[derive(Clone, Debug, PartialEq)]
+pub enum InterfacePackageGenerics {
+	Associations(Vec<Spanned<Association>>),
+	/// `generic map (<>)`
+	Box,
+	/// `generic map (default)`
+	Default,
+}
+
+/// IEEE 1076-2008 § 12.3 (PSL)
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PslDeclarationKind {
+	Property,
+	Sequence,
+	/// `default clock is ...`
+	DefaultClock,
+	/// An IEEE 1850-2005 endpoint declaration
+	Endpoint,
+}
+
+/// A PSL declaration, kept as the source text of the whole declaration
+///
+/// IEEE 1076-2008 § 12.3 (PSL), IEEE 1850-2010 § 6.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct PslDeclaration {
+	pub kind: PslDeclarationKind,
+	/// The name being declared, default clock declarations have no name
+	pub name: Option<Spanned<Identifier>>,
+	pub text: AtomicByteTendril,
+}
+
+/// `nature name is definition;`
+///
+/// IEEE 1076.1-2007 § 4.8
+#[derive(Clone, Debug, PartialEq)]
+pub struct NatureDeclaration {
+	pub name:       Spanned<Identifier>,
+	pub definition: Spanned<NatureDefinition>,
+}
+
+/// IEEE 1076.1-2007 § 3.5
+#[derive(Clone, Debug, PartialEq)]
+pub enum NatureDefinition {
+	/// `across_type across through_type through reference_terminal reference`
+	Scalar {
+		across:    Spanned<Name>,
+		through:   Spanned<Name>,
+		reference: Spanned<Identifier>,
+	},
+	Array {
+		indices: Vec<Spanned<IndexSubtype>>,
+		element: Spanned<SubnatureIndication>,
+	},
+	Record(Vec<Spanned<NatureElementDeclaration>>),
+}
+
+/// IEEE 1076.1-2007 § 3.5.2.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct NatureElementDeclaration {
+	pub names:  Vec<Spanned<Identifier>>,
+	pub nature: Spanned<SubnatureIndication>,
+}
+
+/// IEEE 1076.1-2007 § 4.9
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubnatureIndication {
+	pub nature:     Spanned<Name>,
+	pub constraint: Option<Vec<Spanned<DiscreteRange>>>,
+	/// The `tolerance across_tolerance across through_tolerance through` of the subnature
+	pub tolerance:  Option<(Spanned<Expression>, Spanned<Expression>)>,
+}
+
+/// IEEE 1076.1-2007 § 4.3.1.6
+#[derive(Clone, Debug, PartialEq)]
+pub enum QuantityDeclaration {
+	/// `quantity names : subtype [:= default];`
+	Free {
+		names:   Vec<Spanned<Identifier>>,
+		subtype: Spanned<SubtypeIndication>,
+		default: Option<Spanned<Expression>>,
+	},
+	Branch(BranchQuantity),
+	/// `quantity names : subtype spectrum magnitude, phase;` or `... noise power;`
+	Source {
+		names:   Vec<Spanned<Identifier>>,
+		subtype: Spanned<SubtypeIndication>,
+		source:  SourceAspect,
+	},
+}
+
+/// `quantity [across_aspect across] [through_aspect through] plus [to minus];`
+///
+/// IEEE 1076.1-2007 § 4.3.1.6
+#[derive(Clone, Debug, PartialEq)]
+pub struct BranchQuantity {
+	pub across:  Option<QuantityAspect>,
+	pub through: Option<QuantityAspect>,
+	pub plus:    Spanned<Name>,
+	pub minus:   Option<Spanned<Name>>,
+}
+
+/// The names, tolerance, and default value of the across or through quantities of a branch
+///
+/// IEEE 1076.1-2007 § 4.3.1.6
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantityAspect {
+	pub names:     Vec<Spanned<Identifier>>,
+	pub tolerance: Option<Spanned<Expression>>,
+	pub default:   Option<Spanned<Expression>>,
+}
+
+/// IEEE 1076.1-2007 § 4.3.1.6
+#[derive(Clone, Debug, PartialEq)]
+pub enum SourceAspect {
+	Spectrum {
+		magnitude: Spanned<Expression>,
+		phase:     Spanned<Expression>,
+	},
+	Noise(Spanned<Expression>),
+}
@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_lang::AtomicByteTendril;
+use vermilion_loc::Spanned;
+
+use crate::lang::ast::Identifier;
+
+/// IEEE 1076-2008 § 8.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum Name {
+	Simple(Identifier),
+	/// A string literal naming an operator, e.g. the `"+"` in `"+"(a, b)`
+	OperatorSymbol(AtomicByteTendril),
+	/// A character literal used as a name, e.g. the `'0'` in `bit'('0')`
+	Character(AtomicByteTendril),
+	/// `prefix.suffix`
+	Selected {
+		prefix: Box<Spanned<Self>>,
+		suffix: Spanned<Suffix>,
+	},
+	/// A function call, indexed name, slice name, or type conversion, which can not be told apart
+	/// without knowing what the prefix refers to
+	Call {
+		prefix:    Box<Spanned<Self>>,
+		arguments: Vec<Spanned<Association>>,
+	},
+	/// `prefix[signature]'attribute(argument)`
+	Attribute {
+		prefix:    Box<Spanned<Self>>,
+		signature: Option<Box<Spanned<Signature>>>,
+		attribute: Spanned<Identifier>,
+		argument:  Option<Box<Spanned<Expression>>>,
+	},
+	/// `<<signal .top.dut.sig : std_logic>>`
+	External {
+		class:   ExternalClass,
+		path:    Spanned<ExternalPath>,
+		subtype: Box<Spanned<SubtypeIndication>>,
+	}, // Added: IEEE 1076-2008
+}
+
+/// The suffix of a selected name
+///
+/// IEEE 1076-2008 § 8.3
+#[derive(Clone, Debug, PartialEq)]
+pub enum Suffix {
+	Identifier(Identifier),
+	Character(AtomicByteTendril),
+	OperatorSymbol(AtomicByteTendril),
+	All,
+}
+
+/// IEEE 1076-2008 § 8.7
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ExternalClass {
+	Constant,
+	Signal,
+	Variable,
+}
+
+/// IEEE 1076-2008 § 8.7
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExternalPathKind {
+	/// `@library.package.object`
+	Package,
+	/// `.top.path.object`
+	Absolute,
+	/// `^.^.path.object`, with the number of `^.` going up the hierarchy
+	Relative(usize),
+}
+
+/// IEEE 1076-2008 § 8.7
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExternalPath {
+	pub kind:     ExternalPathKind,
+	/// The path elements, the last of which is the object name
+	pub elements: Vec<Spanned<Name>>,
+}
+
+/// A subprogram or enumeration literal signature, e.g. `[integer, bit return bit]`
+///
+/// IEEE 1076-2008 § 4.5.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct Signature {
+	pub parameters:  Vec<Spanned<Name>>,
+	pub return_type: Option<Spanned<Name>>,
+}
+
+/// An element of an association list, e.g. the `a => b` in a port map
+///
+/// IEEE 1076-2008 § 6.5.7.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct Association {
+	pub formal: Option<Spanned<Name>>,
+	pub actual: Spanned<Actual>,
+}
+
+/// IEEE 1076-2008 § 6.5.7.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum Actual {
+	Expression(Expression),
+	/// A discrete range, which is how slice names end up being represented
+	Range(Range),
+	Open,
+	/// `inertial expression` in a port map
+	Inertial(Expression), // Added: IEEE 1076-2008
+}
+
+/// IEEE 1076-2008 § 9.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expression {
+	Name(Name),
+	Literal(Literal),
+	/// `(choice => value, others => value)`
+	Aggregate(Vec<Spanned<ElementAssociation>>),
+	/// `type_mark'(value)` or `type_mark'aggregate`
+	Qualified {
+		type_mark: Box<Spanned<Name>>,
+		value:     Box<Spanned<Self>>,
+	},
+	/// `new subtype` or `new qualified_expression`
+	Allocator(Box<Spanned<Self>>),
+	Unary {
+		operator: Spanned<UnaryOperator>,
+		operand:  Box<Spanned<Self>>,
+	},
+	Binary {
+		operator: Spanned<BinaryOperator>,
+		lhs:      Box<Spanned<Self>>,
+		rhs:      Box<Spanned<Self>>,
+	},
+	Parenthesized(Box<Spanned<Self>>),
+}
+
+/// IEEE 1076-2008 § 9.3.2
+#[derive(Clone, Debug, PartialEq)]
+pub enum Literal {
+	/// A decimal or based abstract literal
+	Number(AtomicByteTendril),
+	/// An abstract literal followed by a unit name, e.g. `10 ns`
+	Physical {
+		value: Option<AtomicByteTendril>,
+		unit:  Spanned<Identifier>,
+	},
+	BitString(AtomicByteTendril),
+	String(AtomicByteTendril),
+	Character(AtomicByteTendril),
+	Null,
+}
+
+/// IEEE 1076-2008 § 9.2
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UnaryOperator {
+	/// `??`
+	Condition, // Added: IEEE 1076-2008
+	Plus,
+	Minus,
+	Abs,
+	Not,
+	/// The reduction forms of the logical operators
+	And, // Added: IEEE 1076-2008
+	Or,   // Added: IEEE 1076-2008
+	Nand, // Added: IEEE 1076-2008
+	Nor,  // Added: IEEE 1076-2008
+	Xor,  // Added: IEEE 1076-2008
+	Xnor, // Added: IEEE 1076-2008
+}
+
+/// IEEE 1076-2008 § 9.2
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum BinaryOperator {
+	And,
+	Or,
+	Nand,
+	Nor,
+	Xor,
+	Xnor, // Added: IEEE 1076-1993
+	Equals,
+	Inequality,
+	LessThan,
+	LessThanEqual,
+	GreaterThan,
+	GreaterThanEqual,
+	MatchingEquals,           // Added: IEEE 1076-2008
+	MatchingInequality,       // Added: IEEE 1076-2008
+	MatchingLessThan,         // Added: IEEE 1076-2008
+	MatchingLessThanEqual,    // Added: IEEE 1076-2008
+	MatchingGreaterThan,      // Added: IEEE 1076-2008
+	MatchingGreaterThanEqual, // Added: IEEE 1076-2008
+	Sll,                      // Added: IEEE 1076-1993
+	Srl,                      // Added: IEEE 1076-1993
+	Sla,                      // Added: IEEE 1076-1993
+	Sra,                      // Added: IEEE 1076-1993
+	Rol,                      // Added: IEEE 1076-1993
+	Ror,                      // Added: IEEE 1076-1993
+	Plus,
+	Minus,
+	Concatenate,
+	Multiply,
+	Divide,
+	Mod,
+	Rem,
+	Power,
+}
+
+/// An element of an aggregate
+///
+/// IEEE 1076-2008 § 9.3.3.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElementAssociation {
+	/// The choices before the `=>`, empty for positional associations
+	pub choices: Vec<Spanned<Choice>>,
+	pub value:   Spanned<Expression>,
+}
+
+/// IEEE 1076-2008 § 9.3.3.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum Choice {
+	Expression(Expression),
+	Range(Range),
+	Others,
+}
+
+/// IEEE 1076-2008 § 5.2.1
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+	To,
+	DownTo,
+}
+
+/// IEEE 1076-2008 § 5.2.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum Range {
+	Explicit {
+		left:      Box<Spanned<Expression>>,
+		direction: Direction,
+		right:     Box<Spanned<Expression>>,
+	},
+	/// A `'range` or `'reverse_range` attribute name
+	Attribute(Name),
+}
+
+/// IEEE 1076-2008 § 5.3.2.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum DiscreteRange {
+	Range(Range),
+	Subtype(SubtypeIndication),
+}
+
+/// IEEE 1076-2008 § 6.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubtypeIndication {
+	/// The resolution function name, or the element resolution in parentheses
+	pub resolution: Option<Spanned<Name>>,
+	pub type_mark:  Spanned<Name>,
+	pub constraint: Option<Spanned<Constraint>>,
+	/// The `tolerance` aspect of the subtype of a quantity
+	pub tolerance:  Option<Box<Spanned<Expression>>>, // Added: IEEE 1076.1-1999
+}
+
+/// IEEE 1076-2008 § 6.3
+#[derive(Clone, Debug, PartialEq)]
+pub enum Constraint {
+	Range(Range),
+	/// An index constraint, optionally followed by an element constraint
+	Index {
+		/// The index ranges, an empty list is the `(open)` constraint
+		ranges:  Vec<Spanned<DiscreteRange>>,
+		element: Option<Box<Spanned<Self>>>, // Added: IEEE 1076-2008
+	},
+}
@@ -0,0 +1,383 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_diagnostics::StringDiagnostic;
+use vermilion_lang::AtomicByteTendril;
+use vermilion_loc::Spanned;
+
+pub mod decl;
+pub mod expr;
+pub mod stmt;
+
+pub use self::{decl::*, expr::*, stmt::*};
+
+/// The root of a parsed VHDL source file, the design file
+///
+/// IEEE 1076-2008 § 13.1
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ast {
+	units:       Vec<Spanned<DesignUnit>>,
+	/// Problems found while parsing, such as syntax errors that were recovered from or constructs
+	/// from a later standard
+	diagnostics: Vec<StringDiagnostic>,
+}
+
+/// An identifier, VHDL identifiers are case insensitive unless they are extended identifiers
+///
+/// IEEE 1076-2008 § 15.4
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Identifier {
+	Basic(AtomicByteTendril),
+	/// The contents between the reverse solidi of an extended identifier
+	Extended(AtomicByteTendril), // Added: IEEE 1076-1993
+}
+
+/// A library unit along with the context clause that precedes it
+///
+/// IEEE 1076-2008 § 13.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct DesignUnit {
+	pub context: Vec<Spanned<ContextItem>>,
+	pub unit:    LibraryUnit,
+}
+
+/// IEEE 1076-2008 § 13.4
+#[derive(Clone, Debug, PartialEq)]
+pub enum ContextItem {
+	/// A `library a, b;` clause
+	Library(Vec<Spanned<Identifier>>),
+	/// A `use a.b.all;` clause
+	Use(Vec<Spanned<Name>>),
+	/// A `context a.b;` reference
+	Context(Vec<Spanned<Name>>), // Added: IEEE 1076-2008
+}
+
+/// IEEE 1076-2008 § 13.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum LibraryUnit {
+	Entity(Entity),
+	Architecture(Architecture),
+	Package(Package),
+	PackageBody(PackageBody),
+	PackageInstance(PackageInstance), // Added: IEEE 1076-2008
+	Configuration(Configuration),
+	Context(ContextDeclaration), // Added: IEEE 1076-2008
+	/// A PSL verification unit, either native or from within a `-- psl` comment
+	VerificationUnit(VerificationUnit), // Added: IEEE 1076-2008
+	/// A library unit that failed to parse, the error is reported in the diagnostics
+	Error,
+}
+
+/// IEEE 1076-2008 § 3.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entity {
+	pub name:         Spanned<Identifier>,
+	pub generics:     Option<Vec<Spanned<InterfaceDeclaration>>>,
+	pub ports:        Option<Vec<Spanned<InterfaceDeclaration>>>,
+	pub declarations: Vec<Spanned<Declaration>>,
+	/// The passive concurrent statements after the `begin`, if any
+	pub statements:   Vec<Spanned<ConcurrentStatement>>,
+}
+
+/// IEEE 1076-2008 § 3.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct Architecture {
+	pub name:         Spanned<Identifier>,
+	pub entity:       Spanned<Identifier>,
+	pub declarations: Vec<Spanned<Declaration>>,
+	pub statements:   Vec<Spanned<ConcurrentStatement>>,
+}
+
+/// IEEE 1076-2008 § 4.7
+#[derive(Clone, Debug, PartialEq)]
+pub struct Package {
+	pub name:         Spanned<Identifier>,
+	pub generics:     Option<Vec<Spanned<InterfaceDeclaration>>>, // Added: IEEE 1076-2008
+	pub generic_map:  Option<Vec<Spanned<Association>>>,          // Added: IEEE 1076-2008
+	pub declarations: Vec<Spanned<Declaration>>,
+}
+
+/// IEEE 1076-2008 § 4.8
+#[derive(Clone, Debug, PartialEq)]
+pub struct PackageBody {
+	pub name:         Spanned<Identifier>,
+	pub declarations: Vec<Spanned<Declaration>>,
+}
+
+/// A `package a is new b generic map (...);` declaration
+///
+/// IEEE 1076-2008 § 4.9
+#[derive(Clone, Debug, PartialEq)]
+pub struct PackageInstance {
+	pub name:        Spanned<Identifier>,
+	pub package:     Spanned<Name>,
+	pub generic_map: Option<Vec<Spanned<Association>>>,
+}
+
+/// IEEE 1076-2008 § 3.4
+#[derive(Clone, Debug, PartialEq)]
+pub struct Configuration {
+	pub name:         Spanned<Identifier>,
+	pub entity:       Spanned<Name>,
+	/// The use clauses and attribute specifications in the configuration declarative part
+	pub declarations: Vec<Spanned<Declaration>>,
+	pub block:        Spanned<BlockConfiguration>,
+}
+
+/// A `for name ... end for;` block configuration
+///
+/// IEEE 1076-2008 § 3.4.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockConfiguration {
+	/// The architecture, block, or generate statement being configured
+	pub specification: Spanned<Name>,
+	pub uses:          Vec<Spanned<Name>>,
+	pub items:         Vec<Spanned<ConfigurationItem>>,
+}
+
+/// IEEE 1076-2008 § 3.4.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigurationItem {
+	Block(BlockConfiguration),
+	Component(Box<ComponentConfiguration>),
+}
+
+/// IEEE 1076-2008 § 3.4.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComponentConfiguration {
+	pub specification: ComponentSpecification,
+	pub binding:       Option<BindingIndication>,
+	pub block:         Option<Spanned<BlockConfiguration>>,
+}
+
+/// The `instances : component` part of a component configuration or configuration
+/// specification
+///
+/// IEEE 1076-2008 § 7.3.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComponentSpecification {
+	pub instances: InstantiationList,
+	pub component: Spanned<Name>,
+}
+
+/// IEEE 1076-2008 § 7.3.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum InstantiationList {
+	Labels(Vec<Spanned<Identifier>>),
+	Others,
+	All,
+}
+
+/// IEEE 1076-2008 § 7.3.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct BindingIndication {
+	pub entity:      Option<Spanned<EntityAspect>>,
+	pub generic_map: Option<Vec<Spanned<Association>>>,
+	pub port_map:    Option<Vec<Spanned<Association>>>,
+}
+
+/// IEEE 1076-2008 § 7.3.2.2
+#[derive(Clone, Debug, PartialEq)]
+pub enum EntityAspect {
+	Entity {
+		name:         Spanned<Name>,
+		architecture: Option<Spanned<Identifier>>,
+	},
+	Configuration(Spanned<Name>),
+	Open,
+}
+
+/// IEEE 1076-2008 § 13.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContextDeclaration {
+	pub name:  Spanned<Identifier>,
+	pub items: Vec<Spanned<ContextItem>>,
+}
+
+/// IEEE 1076-2008 § 12.3 (PSL)
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum VerificationUnitKind {
+	Vunit,
+	Vprop,
+	Vmode,
+}
+
+/// A PSL verification unit, the body is kept as the source text between the braces
+///
+/// IEEE 1076-2008 § 12.3 (PSL), IEEE 1850-2010 § 7.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct VerificationUnit {
+	pub kind:   VerificationUnitKind,
+	pub name:   Spanned<Identifier>,
+	/// The `(entity(architecture))` the unit is bound to, if any
+	pub target: Option<Spanned<Name>>,
+	pub body:   Spanned<AtomicByteTendril>,
+}
+
+impl Ast {
+	pub fn new(units: Vec<Spanned<DesignUnit>>, diagnostics: Vec<StringDiagnostic>) -> Self {
+		Self { units, diagnostics }
+	}
+
+	pub fn units(&self) -> &[Spanned<DesignUnit>] {
+		&self.units
+	}
+
+	pub fn diagnostics(&self) -> &[StringDiagnostic] {
+		&self.diagnostics
+	}
+
+	/// Iterate over all of the entities declared in this file
+	pub fn entities(&self) -> impl Iterator<Item = &Entity> {
+		self.units.iter().filter_map(|unit| match &unit.unit {
+			LibraryUnit::Entity(entity) => Some(entity),
+			_ => None,
+		})
+	}
+
+	/// Iterate over all of the architectures declared in this file
+	pub fn architectures(&self) -> impl Iterator<Item = &Architecture> {
+		self.units.iter().filter_map(|unit| match &unit.unit {
+			LibraryUnit::Architecture(architecture) => Some(architecture),
+			_ => None,
+		})
+	}
+
+	/// Iterate over all of the packages declared in this file
+	pub fn packages(&self) -> impl Iterator<Item = &Package> {
+		self.units.iter().filter_map(|unit| match &unit.unit {
+			LibraryUnit::Package(package) => Some(package),
+			_ => None,
+		})
+	}
+}
+
+impl Identifier {
+	/// The identifier as written, without the reverse solidi of an extended identifier
+	pub fn as_str(&self) -> &str {
+		match self {
+			// SAFETY: The tokenizer only produces identifiers from valid UTF-8
+			Self::Basic(ident) | Self::Extended(ident) => unsafe {
+				str::from_utf8_unchecked(ident)
+			},
+		}
+	}
+
+	/// The identifier in a form that can be compared directly, basic identifiers are case
+	/// insensitive so they are lowercased, and extended identifiers keep their reverse solidi so
+	/// that `\a\` is distinct from `a`
+	pub fn normalized(&self) -> String {
+		match self {
+			Self::Basic(_) => self.as_str().to_ascii_lowercase(),
+			Self::Extended(_) => format!("\\{}\\", self.as_str()),
+		}
+	}
+
+	/// Returns true if both identifiers refer to the same thing
+	pub fn matches(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Self::Basic(lhs), Self::Basic(rhs)) => lhs.eq_ignore_ascii_case(rhs),
+			(Self::Extended(lhs), Self::Extended(rhs)) => lhs == rhs,
+			_ => false,
+		}
+	}
+}
+
+impl LibraryUnit {
+	/// The name of the library unit, errors are not named
+	pub fn name(&self) -> Option<&Spanned<Identifier>> {
+		match self {
+			Self::Entity(entity) => Some(&entity.name),
+			Self::Architecture(architecture) => Some(&architecture.name),
+			Self::Package(package) => Some(&package.name),
+			Self::PackageBody(body) => Some(&body.name),
+			Self::PackageInstance(instance) => Some(&instance.name),
+			Self::Configuration(configuration) => Some(&configuration.name),
+			Self::Context(context) => Some(&context.name),
+			Self::VerificationUnit(unit) => Some(&unit.name),
+			Self::Error => None,
+		}
+	}
+
+	/// Returns true if this is a primary unit, which is placed directly into a library, rather
+	/// than a secondary unit, which belongs to a primary unit
+	///
+	/// IEEE 1076-2008 § 13.1
+	pub fn is_primary(&self) -> bool {
+		!matches!(
+			self,
+			Self::Architecture(_) | Self::PackageBody(_) | Self::Error
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use vermilion_loc::Span;
+
+	use super::*;
+
+	fn ident(name: &str) -> Spanned<Identifier> {
+		Spanned::new(Identifier::Basic(name.as_bytes().into()), Span::empty())
+	}
+
+	#[test]
+	fn test_identifier_normalized() {
+		assert_eq!(ident("FooBar").inner().normalized(), "foobar");
+		assert_eq!(
+			Identifier::Extended("FooBar".as_bytes().into()).normalized(),
+			"\\FooBar\\"
+		);
+	}
+
+	#[test]
+	fn test_identifier_matches() {
+		assert!(ident("Foo").inner().matches(ident("fOO").inner()));
+		assert!(
+			!Identifier::Extended("Foo".as_bytes().into())
+				.matches(&Identifier::Extended("foo".as_bytes().into()))
+		);
+		assert!(!Identifier::Extended("foo".as_bytes().into()).matches(ident("foo").inner()));
+	}
+
+	#[test]
+	fn test_ast_units() {
+		let ast = Ast::new(
+			vec![
+				Spanned::new(
+					DesignUnit {
+						context: Vec::new(),
+						unit:    LibraryUnit::Entity(Entity {
+							name:         ident("a"),
+							generics:     None,
+							ports:        None,
+							declarations: Vec::new(),
+							statements:   Vec::new(),
+						}),
+					},
+					Span::empty(),
+				),
+				Spanned::new(
+					DesignUnit {
+						context: Vec::new(),
+						unit:    LibraryUnit::Architecture(Architecture {
+							name:         ident("rtl"),
+							entity:       ident("a"),
+							declarations: Vec::new(),
+							statements:   Vec::new(),
+						}),
+					},
+					Span::empty(),
+				),
+			],
+			Vec::new(),
+		);
+
+		assert_eq!(ast.units().len(), 2);
+		assert_eq!(ast.entities().count(), 1);
+		assert_eq!(ast.architectures().count(), 1);
+		assert_eq!(ast.packages().count(), 0);
+		assert!(ast.units()[0].unit.is_primary());
+		assert!(!ast.units()[1].unit.is_primary());
+		assert_eq!(ast.units()[1].unit.name(), Some(&ident("rtl")));
+	}
+}
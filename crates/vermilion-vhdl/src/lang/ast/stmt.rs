@@ -0,0 +1,358 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_lang::AtomicByteTendril;
+use vermilion_loc::Spanned;
+
+use crate::lang::ast::{
+	Association, Choice, Declaration, DiscreteRange, Expression, Identifier, InterfaceDeclaration,
+	Name,
+};
+
+/// IEEE 1076-2008 § 11.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConcurrentStatement {
+	pub label:     Option<Spanned<Identifier>>,
+	pub postponed: bool,
+	pub kind:      ConcurrentStatementKind,
+}
+
+/// IEEE 1076-2008 § 11.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConcurrentStatementKind {
+	Block(Block),
+	Process(Process),
+	ProcedureCall(Spanned<Name>),
+	Assertion(Assertion),
+	SignalAssignment(ConcurrentSignalAssignment),
+	Instantiation(Instantiation),
+	/// `for parameter in range generate`
+	ForGenerate {
+		parameter: Spanned<Identifier>,
+		range:     Spanned<DiscreteRange>,
+		body:      GenerateBody,
+	},
+	/// An `if condition generate`, with the `elsif` and `else` branches from IEEE 1076-2008
+	IfGenerate(Vec<Spanned<GenerateBranch>>),
+	CaseGenerate {
+		expression:   Spanned<Expression>,
+		alternatives: Vec<Spanned<CaseGenerateAlternative>>,
+	}, // Added: IEEE 1076-2008
+	Psl(PslDirective), // Added: IEEE 1076-2008
+	/// `lhs == rhs [tolerance expression];`
+	SimpleSimultaneous {
+		lhs:       Spanned<Expression>,
+		rhs:       Spanned<Expression>,
+		tolerance: Option<Spanned<Expression>>,
+	}, // Added: IEEE 1076.1-1999
+	/// An `if condition use ... end use`, the final branch has no condition if there is an `else`
+	SimultaneousIf(Vec<Spanned<Conditional<Vec<Spanned<ConcurrentStatement>>>>>), /* Added: IEEE 1076.1-1999 */
+	/// `case expression use ... end case`
+	SimultaneousCase {
+		expression:   Spanned<Expression>,
+		alternatives: Vec<Spanned<Selected<Vec<Spanned<ConcurrentStatement>>>>>,
+	}, // Added: IEEE 1076.1-1999
+	SimultaneousProcedural(Procedural), // Added: IEEE 1076.1-1999
+	/// `null;`
+	SimultaneousNull,       // Added: IEEE 1076.1-1999
+	/// `break [elements] [on sensitivity] [when condition];`
+	Break {
+		elements:    Vec<Spanned<BreakElement>>,
+		sensitivity: Vec<Spanned<Name>>,
+		condition:   Option<Spanned<Expression>>,
+	}, // Added: IEEE 1076.1-1999
+	/// A statement that failed to parse, the error is reported in the diagnostics
+	Error,
+}
+
+/// IEEE 1076-2008 § 11.2
+#[derive(Clone, Debug, PartialEq)]
+pub struct Block {
+	pub guard:        Option<Spanned<Expression>>,
+	pub generics:     Option<Vec<Spanned<InterfaceDeclaration>>>,
+	pub generic_map:  Option<Vec<Spanned<Association>>>,
+	pub ports:        Option<Vec<Spanned<InterfaceDeclaration>>>,
+	pub port_map:     Option<Vec<Spanned<Association>>>,
+	pub declarations: Vec<Spanned<Declaration>>,
+	pub statements:   Vec<Spanned<ConcurrentStatement>>,
+}
+
+/// `procedural is ... begin ... end procedural;`
+///
+/// IEEE 1076.1-2007 § 15.4
+#[derive(Clone, Debug, PartialEq)]
+pub struct Procedural {
+	pub declarations: Vec<Spanned<Declaration>>,
+	pub statements:   Vec<Spanned<SequentialStatement>>,
+}
+
+/// `[for selector use] quantity => value`
+///
+/// IEEE 1076.1-2007 § 8.14
+#[derive(Clone, Debug, PartialEq)]
+pub struct BreakElement {
+	/// The quantity whose derivative is used as the break selector, if it differs from `quantity`
+	pub selector: Option<Spanned<Name>>,
+	pub quantity: Spanned<Name>,
+	pub value:    Spanned<Expression>,
+}
+
+/// IEEE 1076-2008 § 11.3
+#[derive(Clone, Debug, PartialEq)]
+pub enum SensitivityList {
+	Names(Vec<Spanned<Name>>),
+	/// `process (all)`
+	All, // Added: IEEE 1076-2008
+}
+
+/// IEEE 1076-2008 § 11.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct Process {
+	pub sensitivity:  Option<SensitivityList>,
+	pub declarations: Vec<Spanned<Declaration>>,
+	pub statements:   Vec<Spanned<SequentialStatement>>,
+}
+
+/// An assertion, either sequential or concurrent
+///
+/// IEEE 1076-2008 § 10.3
+#[derive(Clone, Debug, PartialEq)]
+pub struct Assertion {
+	pub condition: Spanned<Expression>,
+	pub report:    Option<Spanned<Expression>>,
+	pub severity:  Option<Spanned<Expression>>,
+}
+
+/// IEEE 1076-2008 § 10.5.2.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum DelayMechanism {
+	Transport,
+	/// `[reject time] inertial`
+	Inertial(Option<Spanned<Expression>>),
+}
+
+/// IEEE 1076-2008 § 10.5.2.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct WaveformElement {
+	/// The value, a `null` literal for disconnecting a guarded signal
+	pub value: Spanned<Expression>,
+	pub after: Option<Spanned<Expression>>,
+}
+
+/// IEEE 1076-2008 § 10.5.2.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum Waveform {
+	Elements(Vec<Spanned<WaveformElement>>),
+	Unaffected, // Added: IEEE 1076-1993
+}
+
+/// A value guarded by an optional condition, e.g. `a when b`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conditional<T> {
+	pub value:     T,
+	/// The condition, which is absent for the final `else` value
+	pub condition: Option<Spanned<Expression>>,
+}
+
+/// A value selected by a set of choices, e.g. `a when b | c`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Selected<T> {
+	pub value:   T,
+	pub choices: Vec<Spanned<Choice>>,
+}
+
+/// The right hand side of a simple, conditional, or selected assignment
+///
+/// IEEE 1076-2008 § 10.5, § 10.6
+#[derive(Clone, Debug, PartialEq)]
+pub enum AssignmentKind<T> {
+	Simple(T),
+	/// `target <= a when c else b;`
+	Conditional(Vec<Spanned<Conditional<T>>>),
+	/// `with expression select target <= a when c, b when others;`
+	Selected {
+		expression:   Spanned<Expression>,
+		/// If this is a `select?` matching selected assignment
+		matching:     bool, // Added: IEEE 1076-2008
+		alternatives: Vec<Spanned<Selected<T>>>,
+	},
+}
+
+/// IEEE 1076-2008 § 11.6
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConcurrentSignalAssignment {
+	/// The target name or aggregate
+	pub target:  Spanned<Expression>,
+	pub guarded: bool,
+	pub delay:   Option<DelayMechanism>,
+	pub value:   AssignmentKind<Waveform>,
+}
+
+/// IEEE 1076-2008 § 11.7
+#[derive(Clone, Debug, PartialEq)]
+pub enum InstantiatedUnit {
+	/// `[component] name`
+	Component(Spanned<Name>),
+	Entity {
+		name:         Spanned<Name>,
+		architecture: Option<Spanned<Identifier>>,
+	}, // Added: IEEE 1076-1993
+	Configuration(Spanned<Name>), // Added: IEEE 1076-1993
+}
+
+/// IEEE 1076-2008 § 11.7
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instantiation {
+	pub unit:        InstantiatedUnit,
+	pub generic_map: Option<Vec<Spanned<Association>>>,
+	pub port_map:    Option<Vec<Spanned<Association>>>,
+}
+
+/// The contents of a generate statement
+///
+/// IEEE 1076-2008 § 11.8
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenerateBody {
+	/// The alternative label of an IEEE 1076-2008 `if` or `case` generate branch
+	pub label:        Option<Spanned<Identifier>>,
+	pub declarations: Vec<Spanned<Declaration>>,
+	pub statements:   Vec<Spanned<ConcurrentStatement>>,
+}
+
+/// IEEE 1076-2008 § 11.8
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenerateBranch {
+	/// The condition, which is absent for the final `else` branch
+	pub condition: Option<Spanned<Expression>>,
+	pub body:      GenerateBody,
+}
+
+/// IEEE 1076-2008 § 11.8
+#[derive(Clone, Debug, PartialEq)]
+pub struct CaseGenerateAlternative {
+	pub choices: Vec<Spanned<Choice>>,
+	pub body:    GenerateBody,
+}
+
+/// IEEE 1076-2008 § 11.1 (PSL)
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PslDirectiveKind {
+	Assert,
+	Assume,
+	AssumeGuarantee,
+	Restrict,
+	RestrictGuarantee,
+	Cover,
+	Fairness,
+}
+
+/// A PSL verification directive, kept as the source text of the whole directive
+///
+/// IEEE 1076-2008 § 11.1 (PSL), IEEE 1850-2010 § 7.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct PslDirective {
+	pub kind: PslDirectiveKind,
+	pub text: AtomicByteTendril,
+}
+
+/// IEEE 1076-2008 § 10.1
+#[derive(Clone, Debug, PartialEq)]
+pub struct SequentialStatement {
+	pub label: Option<Spanned<Identifier>>,
+	pub kind:  SequentialStatementKind,
+}
+
+/// IEEE 1076-2008 § 10.1
+#[derive(Clone, Debug, PartialEq)]
+pub enum SequentialStatementKind {
+	/// `wait [on names] [until condition] [for timeout];`
+	Wait {
+		sensitivity: Vec<Spanned<Name>>,
+		condition:   Option<Spanned<Expression>>,
+		timeout:     Option<Spanned<Expression>>,
+	},
+	Assertion(Assertion),
+	/// `report message [severity level];`
+	Report {
+		message:  Spanned<Expression>,
+		severity: Option<Spanned<Expression>>,
+	}, // Added: IEEE 1076-1993
+	SignalAssignment {
+		target: Spanned<Expression>,
+		delay:  Option<DelayMechanism>,
+		value:  AssignmentKind<Waveform>,
+	},
+	VariableAssignment {
+		target: Spanned<Expression>,
+		value:  AssignmentKind<Spanned<Expression>>,
+	},
+	/// `target <= force [mode] value;`
+	Force {
+		target: Spanned<Expression>,
+		mode:   Option<ForceMode>,
+		value:  AssignmentKind<Spanned<Expression>>,
+	}, // Added: IEEE 1076-2008
+	/// `target <= release [mode];`
+	Release {
+		target: Spanned<Expression>,
+		mode:   Option<ForceMode>,
+	}, // Added: IEEE 1076-2008
+	ProcedureCall(Spanned<Name>),
+	/// An `if` statement, the final branch has no condition if there is an `else`
+	If(Vec<Spanned<Conditional<Vec<Spanned<SequentialStatement>>>>>),
+	Case {
+		/// If this is a `case?` matching case statement
+		matching:     bool, // Added: IEEE 1076-2008
+		expression:   Spanned<Expression>,
+		alternatives: Vec<Spanned<Selected<Vec<Spanned<SequentialStatement>>>>>,
+	},
+	Loop {
+		scheme:     Option<IterationScheme>,
+		statements: Vec<Spanned<SequentialStatement>>,
+	},
+	Next {
+		target:    Option<Spanned<Identifier>>,
+		condition: Option<Spanned<Expression>>,
+	},
+	Exit {
+		target:    Option<Spanned<Identifier>>,
+		condition: Option<Spanned<Expression>>,
+	},
+	Return(Option<Spanned<Expression>>),
+	Null,
+	/// `break [elements] [when condition];`
+	Break {
+		elements:  Vec<Spanned<BreakElement>>,
+		condition: Option<Spanned<Expression>>,
+	}, // Added: IEEE 1076.1-1999
+	/// A statement that failed to parse, the error is reported in the diagnostics
+	Error,
+}
+
+/// IEEE 1076-2008 § 10.5.2.1
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ForceMode {
+	In,
+	Out,
+}
+
+/// IEEE 1076-2008 § 10.10
+#[derive(Clone, Debug, PartialEq)]
+pub enum IterationScheme {
+	While(Spanned<Expression>),
+	For {
+		parameter: Spanned<Identifier>,
+		range:     Box<Spanned<DiscreteRange>>,
+	},
+}
+
+impl ConcurrentStatement {
+	pub fn new(kind: ConcurrentStatementKind) -> Self {
+		Self { label: None, postponed: false, kind }
+	}
+}
+
+impl SequentialStatement {
+	pub fn new(kind: SequentialStatementKind) -> Self {
+		Self { label: None, kind }
+	}
+}
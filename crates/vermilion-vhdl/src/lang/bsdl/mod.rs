@@ -0,0 +1,435 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_diagnostics::StringDiagnostic;
+use vermilion_loc::{Span, Spanned};
+
+mod validate;
+
+/// A Boundary Scan Description Language description of a device
+///
+/// Names are kept as written, BSDL is case insensitive so they should be compared with
+/// [`str::eq_ignore_ascii_case`].
+///
+/// IEEE 1149.1-2013 § B.8
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BsdlDescription {
+	/// The name of the entity describing the device
+	pub entity:                Option<Spanned<String>>,
+	/// The default value of the `PHYSICAL_PIN_MAP` generic, the name of the pin map in use
+	pub physical_pin_map:      Option<Spanned<String>>,
+	pub ports:                 Vec<Spanned<BsdlPort>>,
+	/// The packages made visible by `use` clauses, such as `STD_1149_1_2013`
+	pub packages:              Vec<Spanned<String>>,
+	/// The `COMPONENT_CONFORMANCE` attribute, the version of the standard the device follows
+	pub component_conformance: Option<Spanned<String>>,
+	/// The `PIN_MAP_STRING` constants, one for each package the device is available in
+	pub pin_maps:              Vec<Spanned<PinMap>>,
+	/// The ports making up the test access port
+	pub tap_ports:             Vec<Spanned<TapPort>>,
+	pub instruction_length:    Option<Spanned<u32>>,
+	pub instructions:          Vec<Spanned<Instruction>>,
+	/// The `INSTRUCTION_CAPTURE` pattern loaded into the instruction register
+	pub instruction_capture:   Option<Spanned<String>>,
+	/// The names of the instructions listed in `INSTRUCTION_PRIVATE`
+	pub instruction_private:   Vec<Spanned<String>>,
+	/// The `IDCODE_REGISTER` pattern, with the most significant bit first
+	pub idcode:                Option<Spanned<String>>,
+	/// The `USERCODE_REGISTER` pattern, with the most significant bit first
+	pub usercode:              Option<Spanned<String>>,
+	pub boundary_length:       Option<Spanned<u32>>,
+	/// The cells of the `BOUNDARY_REGISTER` attribute in the order they are listed
+	pub boundary_cells:        Vec<Spanned<BoundaryCell>>,
+	/// Every attribute specification, including those that are not modelled above
+	pub attributes:            Vec<Spanned<BsdlAttribute>>,
+	/// Problems found while parsing and checking the description
+	diagnostics:               Vec<StringDiagnostic>,
+}
+
+/// IEEE 1149.1-2013 § B.8.3
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PortMode {
+	In,
+	Out,
+	InOut,
+	Buffer,
+	Linkage,
+}
+
+/// A port of the device, each name in a port declaration is its own port
+///
+/// IEEE 1149.1-2013 § B.8.3
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BsdlPort {
+	pub name:   Spanned<String>,
+	pub mode:   PortMode,
+	/// The left and right bounds of a `bit_vector` port, or `None` for a `bit` port
+	pub bounds: Option<(i64, i64)>,
+}
+
+/// A `constant name : PIN_MAP_STRING := "...";` mapping ports to package pins
+///
+/// IEEE 1149.1-2013 § B.8.6
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PinMap {
+	pub name:    Spanned<String>,
+	pub entries: Vec<Spanned<PinMapEntry>>,
+}
+
+/// IEEE 1149.1-2013 § B.8.6
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PinMapEntry {
+	pub port: Spanned<String>,
+	/// The pins the port is connected to, one for each element of a `bit_vector` port
+	pub pins: Vec<Spanned<String>>,
+}
+
+/// IEEE 1149.1-2013 § B.8.8
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TapSignal {
+	/// `TAP_SCAN_IN`, the TDI port
+	ScanIn,
+	/// `TAP_SCAN_OUT`, the TDO port
+	ScanOut,
+	/// `TAP_SCAN_MODE`, the TMS port
+	ScanMode,
+	/// `TAP_SCAN_RESET`, the optional TRST* port
+	ScanReset,
+	/// `TAP_SCAN_CLOCK`, the TCK port
+	ScanClock,
+}
+
+/// IEEE 1149.1-2013 § B.8.8
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TapPort {
+	pub port:   Spanned<String>,
+	pub signal: TapSignal,
+}
+
+/// An instruction from the `INSTRUCTION_OPCODE` attribute
+///
+/// IEEE 1149.1-2013 § B.8.11.3
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Instruction {
+	pub name:    Spanned<String>,
+	/// The opcodes that select the instruction, with the most significant bit first
+	pub opcodes: Vec<Spanned<String>>,
+}
+
+/// IEEE 1149.1-2013 § B.10.2
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CellFunction {
+	Input,
+	Clock,
+	Output2,
+	Output3,
+	Control,
+	ControlR,
+	Internal,
+	Bidir,
+	ObserveOnly,
+}
+
+/// The value a cell should be loaded with to avoid damaging the device or board
+///
+/// IEEE 1149.1-2013 § B.10.2
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SafeValue {
+	Zero,
+	One,
+	/// `X`, the cell has no safe value
+	DontCare,
+}
+
+/// The state of a driver disabled by its control cell
+///
+/// IEEE 1149.1-2013 § B.10.2
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DisableResult {
+	Z,
+	Weak0,
+	Weak1,
+	Pull0,
+	Pull1,
+	Keeper,
+}
+
+/// The control cell of an output or bidirectional cell
+///
+/// IEEE 1149.1-2013 § B.10.2
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CellControl {
+	/// The number of the control cell
+	pub cell:    Spanned<u32>,
+	/// The value of the control cell that disables the driver
+	pub disable: bool,
+	pub result:  DisableResult,
+}
+
+/// A cell of the boundary scan register
+///
+/// IEEE 1149.1-2013 § B.10.2
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BoundaryCell {
+	pub number:   Spanned<u32>,
+	/// The cell type, such as `BC_1`
+	pub cell:     Spanned<String>,
+	/// The port the cell is connected to, such as `D(3)`, or `None` for `*`
+	pub port:     Option<Spanned<String>>,
+	pub function: CellFunction,
+	pub safe:     SafeValue,
+	pub control:  Option<CellControl>,
+}
+
+/// `attribute name of targets : class is value;`
+///
+/// IEEE 1149.1-2013 § B.8.1
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BsdlAttribute {
+	pub name:    Spanned<String>,
+	/// The entity or ports the attribute is specified for
+	pub targets: Vec<Spanned<String>>,
+	/// The class of the targets, `entity` or `signal`
+	pub class:   String,
+	pub value:   AttributeValue,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum AttributeValue {
+	/// A string, with any concatenated strings joined together
+	String(String),
+	Integer(u64),
+	/// A name such as `true` or a pin map constant
+	Name(String),
+	/// Any other value, as it was written
+	Other(String),
+}
+
+/// Something in a BSDL description that can be found by its location
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BsdlItem<'a> {
+	Port(&'a BsdlPort),
+	PinMapEntry(&'a PinMapEntry),
+	TapPort(&'a TapPort),
+	Instruction(&'a Instruction),
+	BoundaryCell(&'a BoundaryCell),
+	Attribute(&'a BsdlAttribute),
+}
+
+impl BsdlDescription {
+	pub(crate) fn set_diagnostics(&mut self, diagnostics: Vec<StringDiagnostic>) {
+		self.diagnostics = diagnostics;
+	}
+
+	pub fn diagnostics(&self) -> &[StringDiagnostic] {
+		&self.diagnostics
+	}
+
+	/// Find the port named `name`, which may be an element of a `bit_vector` port such as `D(3)`
+	pub fn port(&self, name: &str) -> Option<&BsdlPort> {
+		let base = name.split('(').next().unwrap_or(name).trim();
+
+		self.ports
+			.iter()
+			.map(Spanned::inner)
+			.find(|port| port.name.inner().eq_ignore_ascii_case(base))
+	}
+
+	/// Find the instruction named `name`
+	pub fn instruction(&self, name: &str) -> Option<&Instruction> {
+		self.instructions
+			.iter()
+			.map(Spanned::inner)
+			.find(|instruction| instruction.name.inner().eq_ignore_ascii_case(name))
+	}
+
+	/// Find the boundary register cell numbered `number`
+	pub fn boundary_cell(&self, number: u32) -> Option<&BoundaryCell> {
+		self.boundary_cells
+			.iter()
+			.map(Spanned::inner)
+			.find(|cell| *cell.number.inner() == number)
+	}
+
+	/// The boundary register cells connected to the port `name` or one of its elements
+	pub fn cells_for_port<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a BoundaryCell> {
+		self.boundary_cells
+			.iter()
+			.map(Spanned::inner)
+			.filter(move |cell| {
+				cell.port.as_ref().is_some_and(|port| {
+					port.inner()
+						.split('(')
+						.next()
+						.is_some_and(|base| base.trim().eq_ignore_ascii_case(name))
+				})
+			})
+	}
+
+	/// The pin map selected by the `PHYSICAL_PIN_MAP` generic, or the only pin map if there is
+	/// just one
+	pub fn active_pin_map(&self) -> Option<&PinMap> {
+		let pin_maps = self.pin_maps.iter().map(Spanned::inner);
+
+		match &self.physical_pin_map {
+			Some(name) => pin_maps
+				.clone()
+				.find(|pin_map| pin_map.name.inner().eq_ignore_ascii_case(name.inner())),
+			None if self.pin_maps.len() == 1 => pin_maps.clone().next(),
+			None => None,
+		}
+	}
+
+	/// Find the most specific item whose name or entry covers `offset`
+	pub fn item_at(&self, offset: u32) -> Option<(Span, BsdlItem<'_>)> {
+		let covers = |span: &Span| (*span.begin()..*span.end()).contains(&offset);
+
+		self.ports
+			.iter()
+			.filter(|port| covers(port.inner().name.span()))
+			.map(|port| (*port.inner().name.span(), BsdlItem::Port(port.inner())))
+			.chain(
+				self.pin_maps
+					.iter()
+					.flat_map(|pin_map| pin_map.inner().entries.iter())
+					.filter(|entry| covers(entry.span()))
+					.map(|entry| (*entry.span(), BsdlItem::PinMapEntry(entry.inner()))),
+			)
+			.chain(
+				self.tap_ports
+					.iter()
+					.filter(|tap| covers(tap.inner().port.span()))
+					.map(|tap| (*tap.inner().port.span(), BsdlItem::TapPort(tap.inner()))),
+			)
+			.chain(
+				self.instructions
+					.iter()
+					.filter(|instruction| covers(instruction.span()))
+					.map(|instruction| {
+						(
+							*instruction.span(),
+							BsdlItem::Instruction(instruction.inner()),
+						)
+					}),
+			)
+			.chain(
+				self.boundary_cells
+					.iter()
+					.filter(|cell| covers(cell.span()))
+					.map(|cell| (*cell.span(), BsdlItem::BoundaryCell(cell.inner()))),
+			)
+			.chain(
+				self.attributes
+					.iter()
+					.filter(|attribute| covers(attribute.inner().name.span()))
+					.map(|attribute| {
+						(
+							*attribute.inner().name.span(),
+							BsdlItem::Attribute(attribute.inner()),
+						)
+					}),
+			)
+			.next()
+	}
+}
+
+impl BsdlPort {
+	/// The number of elements of the port, which is 1 for a `bit` port
+	pub fn width(&self) -> u64 {
+		self.bounds
+			.map_or(1, |(left, right)| left.abs_diff(right) + 1)
+	}
+}
+
+impl PortMode {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::In => "in",
+			Self::Out => "out",
+			Self::InOut => "inout",
+			Self::Buffer => "buffer",
+			Self::Linkage => "linkage",
+		}
+	}
+}
+
+impl TapSignal {
+	/// The name of the attribute identifying the port
+	pub fn attribute(&self) -> &'static str {
+		match self {
+			Self::ScanIn => "TAP_SCAN_IN",
+			Self::ScanOut => "TAP_SCAN_OUT",
+			Self::ScanMode => "TAP_SCAN_MODE",
+			Self::ScanReset => "TAP_SCAN_RESET",
+			Self::ScanClock => "TAP_SCAN_CLOCK",
+		}
+	}
+
+	/// The conventional name of the test access port signal
+	pub fn signal(&self) -> &'static str {
+		match self {
+			Self::ScanIn => "TDI",
+			Self::ScanOut => "TDO",
+			Self::ScanMode => "TMS",
+			Self::ScanReset => "TRST*",
+			Self::ScanClock => "TCK",
+		}
+	}
+}
+
+impl CellFunction {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Input => "INPUT",
+			Self::Clock => "CLOCK",
+			Self::Output2 => "OUTPUT2",
+			Self::Output3 => "OUTPUT3",
+			Self::Control => "CONTROL",
+			Self::ControlR => "CONTROLR",
+			Self::Internal => "INTERNAL",
+			Self::Bidir => "BIDIR",
+			Self::ObserveOnly => "OBSERVE_ONLY",
+		}
+	}
+
+	/// Parse a cell function, which is case insensitive
+	pub fn from_name(name: &str) -> Option<Self> {
+		[
+			Self::Input,
+			Self::Clock,
+			Self::Output2,
+			Self::Output3,
+			Self::Control,
+			Self::ControlR,
+			Self::Internal,
+			Self::Bidir,
+			Self::ObserveOnly,
+		]
+		.into_iter()
+		.find(|function| function.as_str().eq_ignore_ascii_case(name))
+	}
+
+	/// Returns true if the cell drives its port and may need a control cell to disable it
+	pub fn drives(&self) -> bool {
+		matches!(self, Self::Output3 | Self::Bidir)
+	}
+}
+
+impl DisableResult {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Z => "Z",
+			Self::Weak0 => "WEAK0",
+			Self::Weak1 => "WEAK1",
+			Self::Pull0 => "PULL0",
+			Self::Pull1 => "PULL1",
+			Self::Keeper => "KEEPER",
+		}
+	}
+
+	/// Parse a disable result, which is case insensitive
+	pub fn from_name(name: &str) -> Option<Self> {
+		[Self::Z, Self::Weak0, Self::Weak1, Self::Pull0, Self::Pull1, Self::Keeper]
+			.into_iter()
+			.find(|result| result.as_str().eq_ignore_ascii_case(name))
+	}
+}
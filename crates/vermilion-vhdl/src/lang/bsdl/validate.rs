@@ -0,0 +1,522 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use vermilion_diagnostics::StringDiagnostic;
+use vermilion_loc::{Span, Spanned};
+
+use crate::{
+	diagnostics::vhdl,
+	lang::bsdl::{BsdlDescription, CellFunction, PortMode, TapSignal},
+};
+
+/// The instructions every device must implement
+///
+/// IEEE 1149.1-2013 § 8.1.1
+const REQUIRED_INSTRUCTIONS: [&str; 3] = ["BYPASS", "SAMPLE", "EXTEST"];
+
+impl BsdlDescription {
+	// NOTE(aki):
+	// Only the rules that can be checked from the description itself are checked here, whether the
+	// description matches the silicon is left to the board test tools.
+	/// Check the description against the rules of IEEE 1149.1-2013, reporting any violations
+	pub(crate) fn validate(&self, diagnostics: &mut Vec<StringDiagnostic>) {
+		let Some(entity) = &self.entity else {
+			return;
+		};
+
+		let mut validator = Validator {
+			description: self,
+			entity: *entity.span(),
+			diagnostics,
+		};
+
+		validator.tap_ports();
+		validator.instructions();
+		validator.registers();
+		validator.boundary_register();
+		validator.pin_maps();
+	}
+}
+
+struct Validator<'a> {
+	description: &'a BsdlDescription,
+	/// Where to report anything that is missing from the description
+	entity:      Span,
+	diagnostics: &'a mut Vec<StringDiagnostic>,
+}
+
+impl Validator<'_> {
+	fn report(&mut self, span: Span, message: String) {
+		self.diagnostics
+			.push(StringDiagnostic::new(vhdl::E0006, message, Some(span)));
+	}
+
+	/// Report that the attribute `name` is required but missing
+	fn missing(&mut self, name: &str) {
+		self.report(
+			self.entity,
+			format!("The required `{name}` attribute is missing"),
+		);
+	}
+
+	/// IEEE 1149.1-2013 § B.8.8
+	fn tap_ports(&mut self) {
+		let description = self.description;
+
+		for signal in
+			[TapSignal::ScanIn, TapSignal::ScanOut, TapSignal::ScanMode, TapSignal::ScanClock]
+		{
+			if !description
+				.tap_ports
+				.iter()
+				.any(|tap| tap.inner().signal == signal)
+			{
+				self.missing(signal.attribute());
+			}
+		}
+
+		let mut seen = BTreeMap::new();
+		for tap in &description.tap_ports {
+			let tap = tap.inner();
+
+			let Some(port) = description.port(tap.port.inner()) else {
+				self.report(
+					*tap.port.span(),
+					format!(
+						"`{}` names `{}`, which is not a port",
+						tap.signal.attribute(),
+						tap.port.inner()
+					),
+				);
+				continue;
+			};
+
+			let expected = match tap.signal {
+				TapSignal::ScanOut => PortMode::Out,
+				_ => PortMode::In,
+			};
+
+			if port.mode != expected && port.mode != PortMode::InOut {
+				self.report(
+					*tap.port.span(),
+					format!(
+						"The {} port `{}` must have mode `{}`, not `{}`",
+						tap.signal.signal(),
+						tap.port.inner(),
+						expected.as_str(),
+						port.mode.as_str()
+					),
+				);
+			}
+
+			if port.bounds.is_some() {
+				self.report(
+					*tap.port.span(),
+					format!(
+						"The {} port `{}` must be a `bit` port",
+						tap.signal.signal(),
+						tap.port.inner()
+					),
+				);
+			}
+
+			if let Some(previous) = seen.insert(tap.signal, tap.port.inner().as_str()) {
+				self.report(
+					*tap.port.span(),
+					format!(
+						"`{}` is already given for `{previous}`",
+						tap.signal.attribute()
+					),
+				);
+			}
+		}
+	}
+
+	/// IEEE 1149.1-2013 § 7.1.1, § 8.1.1, § B.8.11
+	fn instructions(&mut self) {
+		let description = self.description;
+
+		let Some(length) = &description.instruction_length else {
+			self.missing("INSTRUCTION_LENGTH");
+			return;
+		};
+
+		let length_span = *length.span();
+		let length = *length.inner() as usize;
+		if length < 2 {
+			self.report(
+				length_span,
+				format!("The instruction register must be at least 2 bits long, not {length}"),
+			);
+		}
+
+		if description.instructions.is_empty() {
+			self.missing("INSTRUCTION_OPCODE");
+		}
+
+		let mut opcodes = BTreeMap::<String, &str>::new();
+		for instruction in &description.instructions {
+			let instruction = instruction.inner();
+
+			for opcode in &instruction.opcodes {
+				if !is_pattern(opcode.inner(), "01X") {
+					self.report(
+						*opcode.span(),
+						format!(
+							"The opcode `{}` of `{}` may only contain 0, 1, or X",
+							opcode.inner(),
+							instruction.name.inner()
+						),
+					);
+				} else if opcode.inner().len() != length {
+					self.report(
+						*opcode.span(),
+						format!(
+							"The opcode `{}` of `{}` is {} bits long, the instruction register is \
+							 {length}",
+							opcode.inner(),
+							instruction.name.inner(),
+							opcode.inner().len()
+						),
+					);
+				}
+
+				let key = opcode.inner().to_ascii_uppercase();
+				if let Some(previous) = opcodes.get(&key) &&
+					!previous.eq_ignore_ascii_case(instruction.name.inner())
+				{
+					self.report(
+						*opcode.span(),
+						format!(
+							"The opcode `{}` of `{}` is also used by `{previous}`",
+							opcode.inner(),
+							instruction.name.inner()
+						),
+					);
+				} else {
+					opcodes.insert(key, instruction.name.inner());
+				}
+			}
+		}
+
+		if !description.instructions.is_empty() {
+			for name in REQUIRED_INSTRUCTIONS {
+				if description.instruction(name).is_none() {
+					self.report(
+						self.entity,
+						format!("The required `{name}` instruction is missing"),
+					);
+				}
+			}
+
+			// The all ones opcode must select `BYPASS`
+			let ones = "1".repeat(length);
+			match opcodes.get(&ones) {
+				Some(name) if !name.eq_ignore_ascii_case("BYPASS") => {
+					let span = self.opcode_span(name, &ones);
+					self.report(
+						span,
+						format!("The all ones opcode must be `BYPASS`, not `{name}`"),
+					);
+				},
+				None => {
+					let span = description
+						.instruction("BYPASS")
+						.map_or(self.entity, |bypass| *bypass.name.span());
+					self.report(
+						span,
+						format!("The all ones opcode `{ones}` must select `BYPASS`"),
+					);
+				},
+				Some(_) => {},
+			}
+		}
+
+		for private in &description.instruction_private {
+			if description.instruction(private.inner()).is_none() {
+				self.report(
+					*private.span(),
+					format!(
+						"The private instruction `{}` has no opcode",
+						private.inner()
+					),
+				);
+			}
+		}
+
+		match &description.instruction_capture {
+			Some(capture) if capture.inner().len() != length => {
+				self.report(
+					*capture.span(),
+					format!(
+						"`INSTRUCTION_CAPTURE` is {} bits long, the instruction register is \
+						 {length}",
+						capture.inner().len()
+					),
+				);
+			},
+			Some(capture) if !is_pattern(capture.inner(), "01X") => {
+				self.report(
+					*capture.span(),
+					"`INSTRUCTION_CAPTURE` may only contain 0, 1, or X".to_string(),
+				);
+			},
+			// The two least significant bits must capture `01`
+			Some(capture) if !capture.inner().ends_with("01") => {
+				self.report(
+					*capture.span(),
+					format!(
+						"`INSTRUCTION_CAPTURE` must end in `01`, not `{}`",
+						capture.inner()
+					),
+				);
+			},
+			Some(_) => {},
+			None => self.missing("INSTRUCTION_CAPTURE"),
+		}
+	}
+
+	/// The span of the opcode `opcode` of the instruction `name`
+	fn opcode_span(&self, name: &str, opcode: &str) -> Span {
+		self.description
+			.instruction(name)
+			.and_then(|instruction| {
+				instruction
+					.opcodes
+					.iter()
+					.find(|candidate| candidate.inner().eq_ignore_ascii_case(opcode))
+			})
+			.map_or(self.entity, |opcode| *opcode.span())
+	}
+
+	/// IEEE 1149.1-2013 § 12.1.1, § B.8.13
+	fn registers(&mut self) {
+		let description = self.description;
+
+		for (name, register, instruction) in [
+			("IDCODE_REGISTER", &description.idcode, "IDCODE"),
+			("USERCODE_REGISTER", &description.usercode, "USERCODE"),
+		] {
+			let Some(register) = register else {
+				continue;
+			};
+
+			if register.inner().len() != 32 || !is_pattern(register.inner(), "01X") {
+				self.report(
+					*register.span(),
+					format!("`{name}` must be 32 bits of 0, 1, or X"),
+				);
+			} else if name == "IDCODE_REGISTER" && !register.inner().ends_with('1') {
+				self.report(
+					*register.span(),
+					"The least significant bit of `IDCODE_REGISTER` must be 1".to_string(),
+				);
+			}
+
+			if description.instruction(instruction).is_none() {
+				self.report(
+					*register.span(),
+					format!("`{name}` is given, but there is no `{instruction}` instruction"),
+				);
+			}
+		}
+	}
+
+	/// IEEE 1149.1-2013 § B.10
+	fn boundary_register(&mut self) {
+		let description = self.description;
+
+		let Some(length) = &description.boundary_length else {
+			self.missing("BOUNDARY_LENGTH");
+			return;
+		};
+
+		if description.boundary_cells.is_empty() {
+			self.missing("BOUNDARY_REGISTER");
+			return;
+		}
+
+		let mut numbers = BTreeSet::new();
+		for cell in &description.boundary_cells {
+			let number = &cell.inner().number;
+
+			if *number.inner() >= *length.inner() {
+				self.report(
+					*number.span(),
+					format!(
+						"Cell {} is outside of the boundary register, which is {} cells long",
+						number.inner(),
+						length.inner()
+					),
+				);
+			}
+
+			if !numbers.insert(*number.inner()) {
+				self.report(
+					*number.span(),
+					format!("Cell {} is described more than once", number.inner()),
+				);
+			}
+		}
+
+		if numbers.len() != *length.inner() as usize {
+			self.report(
+				*length.span(),
+				format!(
+					"`BOUNDARY_LENGTH` is {}, but {} cells are described",
+					length.inner(),
+					numbers.len()
+				),
+			);
+		}
+
+		for cell in description.boundary_cells.iter().map(Spanned::inner) {
+			if let Some(port) = &cell.port &&
+				description.port(port.inner()).is_none()
+			{
+				self.report(
+					*port.span(),
+					format!(
+						"Cell {} is connected to `{}`, which is not a port",
+						cell.number.inner(),
+						port.inner()
+					),
+				);
+			}
+
+			match &cell.control {
+				Some(control) => match description.boundary_cell(*control.cell.inner()) {
+					Some(target)
+						if matches!(
+							target.function,
+							CellFunction::Control | CellFunction::ControlR
+						) => {},
+					Some(_) => {
+						self.report(
+							*control.cell.span(),
+							format!(
+								"Cell {} is controlled by cell {}, which is not a control cell",
+								cell.number.inner(),
+								control.cell.inner()
+							),
+						);
+					},
+					None => {
+						self.report(
+							*control.cell.span(),
+							format!(
+								"Cell {} is controlled by cell {}, which is not described",
+								cell.number.inner(),
+								control.cell.inner()
+							),
+						);
+					},
+				},
+				None if cell.function.drives() => {
+					self.report(
+						*cell.number.span(),
+						format!(
+							"Cell {} is an `{}` cell, which needs a control cell",
+							cell.number.inner(),
+							cell.function.as_str()
+						),
+					);
+				},
+				None => {},
+			}
+		}
+	}
+
+	/// IEEE 1149.1-2013 § B.8.6
+	fn pin_maps(&mut self) {
+		let description = self.description;
+
+		if let Some(name) = &description.physical_pin_map &&
+			!description.pin_maps.is_empty() &&
+			description.active_pin_map().is_none()
+		{
+			self.report(
+				*name.span(),
+				format!("There is no pin map named `{}`", name.inner()),
+			);
+		}
+
+		for pin_map in description.pin_maps.iter().map(Spanned::inner) {
+			let mut pins = BTreeMap::<String, &str>::new();
+
+			for entry in pin_map.entries.iter().map(Spanned::inner) {
+				let Some(port) = description.port(entry.port.inner()) else {
+					self.report(
+						*entry.port.span(),
+						format!(
+							"`{}` maps `{}`, which is not a port",
+							pin_map.name.inner(),
+							entry.port.inner()
+						),
+					);
+					continue;
+				};
+
+				if entry.pins.len() as u64 != port.width() {
+					self.report(
+						*entry.port.span(),
+						format!(
+							"`{}` is {} bits wide, but is mapped to {} pins",
+							entry.port.inner(),
+							port.width(),
+							entry.pins.len()
+						),
+					);
+				}
+
+				for pin in &entry.pins {
+					let key = pin.inner().to_ascii_uppercase();
+
+					if let Some(previous) = pins.get(&key) &&
+						!previous.eq_ignore_ascii_case(entry.port.inner())
+					{
+						self.report(
+							*pin.span(),
+							format!(
+								"Pin `{}` is mapped to both `{previous}` and `{}`",
+								pin.inner(),
+								entry.port.inner()
+							),
+						);
+					} else {
+						pins.insert(key, entry.port.inner());
+					}
+				}
+			}
+
+			for port in description.ports.iter().map(Spanned::inner) {
+				if !pin_map.entries.iter().any(|entry| {
+					entry
+						.inner()
+						.port
+						.inner()
+						.eq_ignore_ascii_case(port.name.inner())
+				}) {
+					self.report(
+						*port.name.span(),
+						format!(
+							"`{}` is not mapped to a pin by `{}`",
+							port.name.inner(),
+							pin_map.name.inner()
+						),
+					);
+				}
+			}
+		}
+	}
+}
+
+/// Returns true if `pattern` is non-empty and only made up of the characters in `allowed`, which
+/// is case insensitive
+fn is_pattern(pattern: &str, allowed: &str) -> bool {
+	!pattern.is_empty() &&
+		pattern
+			.chars()
+			.all(|chr| allowed.contains(chr.to_ascii_uppercase()))
+}
@@ -0,0 +1,704 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use vermilion_diagnostics::{Code, StringDiagnostic};
+use vermilion_loc::{FileId, Location, Span, Spanned};
+
+use crate::{
+	LanguageStd,
+	diagnostics::{vhdl, vhdl_ams},
+	lang::ast::{
+		Ast, BindingIndication, BlockConfiguration, ConcurrentStatement, ConcurrentStatementKind,
+		ConfigurationItem, ContextItem, Declaration, EntityAspect, GenerateBody, Identifier,
+		InstantiatedUnit, LibraryUnit, Name, Suffix,
+	},
+};
+
+/// The kind of a design unit in a [`DependencyGraph`]
+///
+/// IEEE 1076-2008 § 13.1
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UnitKind {
+	Entity,
+	Architecture,
+	Package,
+	PackageBody,
+	PackageInstance, // Added: IEEE 1076-2008
+	Configuration,
+	Context,          // Added: IEEE 1076-2008
+	VerificationUnit, // Added: IEEE 1076-2008
+}
+
+/// A design unit that has been added to a [`DependencyGraph`]
+#[derive(Clone, Debug)]
+pub struct GraphUnit {
+	/// The normalized logical name of the library the unit is analyzed into
+	pub library:  String,
+	/// The normalized name of the unit
+	pub name:     String,
+	/// The normalized name of the primary unit a secondary unit belongs to
+	pub primary:  Option<String>,
+	pub kind:     UnitKind,
+	/// The file the unit is in and the span of its name
+	pub location: Location<()>,
+	dependencies: Vec<Dependency>,
+}
+
+/// A reference from a design unit to a primary unit
+#[derive(Clone, Debug)]
+struct Dependency {
+	library:  String,
+	name:     String,
+	span:     Span,
+	/// Whether the referenced unit must exist, references by a simple name may be to something
+	/// other than a design unit, such as a component with no matching entity
+	required: bool,
+}
+
+/// The result of ordering the units of a [`DependencyGraph`]
+#[derive(Clone, Debug)]
+pub struct AnalysisOrder<'a> {
+	/// Every unit in the graph, each one after all of the units it depends on
+	pub units:       Vec<&'a GraphUnit>,
+	/// Circular dependencies and references to missing design units
+	pub diagnostics: Vec<Location<StringDiagnostic>>,
+}
+
+/// The design units of a set of design files and the references between them, used to find the
+/// order the units must be analyzed in
+///
+/// Design units depend on the units they name in `use` clauses, `context` references, entity
+/// and configuration instantiations, binding indications, and package instantiations, along with
+/// the entity or package a secondary unit belongs to. Component instantiations depend on the
+/// entity of the same name in the library of the instantiating unit if there is one, as that is
+/// what the default binding uses.
+///
+/// Only references into libraries that have had files added to the graph are followed, anything
+/// else, such as `ieee` and `std`, is assumed to already be analyzed.
+///
+/// IEEE 1076-2008 § 13.5
+#[derive(Clone, Debug)]
+pub struct DependencyGraph {
+	std:       LanguageStd,
+	units:     Vec<GraphUnit>,
+	/// The primary units by library and name
+	primaries: BTreeMap<(String, String), usize>,
+}
+
+impl DependencyGraph {
+	pub fn new(std: LanguageStd) -> Self {
+		Self { std, units: Vec::new(), primaries: BTreeMap::new() }
+	}
+
+	/// All of the units added so far, in the order they were added
+	pub fn units(&self) -> &[GraphUnit] {
+		&self.units
+	}
+
+	/// Add the design units of the design file `ast` to the graph, analyzing them into `library`
+	///
+	/// A primary unit replaces any earlier primary unit of the same name in the same library, as
+	/// reanalyzing it would.
+	pub fn add_file(&mut self, library: &str, file: FileId, ast: &Ast) {
+		let library = library.to_ascii_lowercase();
+
+		for unit in ast.units() {
+			let Some(name) = unit.unit.name() else {
+				continue;
+			};
+
+			let mut collector = Collector { library: &library, dependencies: Vec::new() };
+			collector.context(&unit.context);
+
+			let (kind, primary) = match &unit.unit {
+				LibraryUnit::Entity(entity) => {
+					collector.declarations(&entity.declarations);
+					collector.statements(&entity.statements);
+					(UnitKind::Entity, None)
+				},
+				LibraryUnit::Architecture(architecture) => {
+					collector.require(&architecture.entity);
+					collector.declarations(&architecture.declarations);
+					collector.statements(&architecture.statements);
+					(
+						UnitKind::Architecture,
+						Some(architecture.entity.inner().normalized()),
+					)
+				},
+				LibraryUnit::Package(package) => {
+					collector.declarations(&package.declarations);
+					(UnitKind::Package, None)
+				},
+				LibraryUnit::PackageBody(body) => {
+					collector.require(&body.name);
+					collector.declarations(&body.declarations);
+					(UnitKind::PackageBody, Some(body.name.inner().normalized()))
+				},
+				LibraryUnit::PackageInstance(instance) => {
+					collector.selected_name(&instance.package);
+					(UnitKind::PackageInstance, None)
+				},
+				LibraryUnit::Configuration(configuration) => {
+					collector.name(&configuration.entity, true);
+					collector.declarations(&configuration.declarations);
+					collector.block_configuration(configuration.block.inner());
+					(UnitKind::Configuration, None)
+				},
+				LibraryUnit::Context(context) => {
+					collector.context(&context.items);
+					(UnitKind::Context, None)
+				},
+				LibraryUnit::VerificationUnit(unit) => {
+					// The target is `entity(architecture)`, only the entity needs to be analyzed
+					if let Some(target) = &unit.target {
+						match target.inner() {
+							Name::Call { prefix, .. } => collector.name(prefix, false),
+							_ => collector.name(target, false),
+						}
+					}
+					(UnitKind::VerificationUnit, None)
+				},
+				LibraryUnit::Error => continue,
+			};
+
+			let idx = self.units.len();
+			let location = Location::new(file, (), *name.span());
+			let name = name.inner().normalized();
+
+			if primary.is_none() {
+				self.primaries.insert((library.clone(), name.clone()), idx);
+			}
+
+			self.units.push(GraphUnit {
+				library: library.clone(),
+				name,
+				primary,
+				kind,
+				location,
+				dependencies: collector.dependencies,
+			});
+		}
+	}
+
+	/// Order the units so that each one comes after all of the units it depends on, reporting
+	/// circular dependencies and references to missing units
+	///
+	/// Units on a cycle are still ordered, with the reference that closes the cycle ignored, so
+	/// that as much of the design as possible can be analyzed.
+	pub fn analysis_order(&self) -> AnalysisOrder<'_> {
+		let libraries = self
+			.units
+			.iter()
+			.map(|unit| unit.library.as_str())
+			.collect::<BTreeSet<_>>();
+
+		let mut diagnostics = Vec::new();
+
+		// Resolve the references of each unit to the indices of the units they depend on
+		let edges = self
+			.units
+			.iter()
+			.enumerate()
+			.map(|(idx, unit)| {
+				let mut edges = Vec::new();
+
+				for dependency in &unit.dependencies {
+					if !libraries.contains(dependency.library.as_str()) {
+						continue;
+					}
+
+					match self
+						.primaries
+						.get(&(dependency.library.clone(), dependency.name.clone()))
+					{
+						Some(&target) if target != idx => edges.push((target, dependency.span)),
+						Some(_) => {},
+						None if dependency.required => {
+							diagnostics.push(Location::new(
+								*unit.location.file_id(),
+								StringDiagnostic::new(
+									missing_unit_code(self.std),
+									format!(
+										"No design unit `{}` in library `{}`",
+										dependency.name, dependency.library
+									),
+									Some(dependency.span),
+								),
+								dependency.span,
+							));
+						},
+						None => {},
+					}
+				}
+
+				edges
+			})
+			.collect::<Vec<_>>();
+
+		let mut state = vec![VisitState::Unvisited; self.units.len()];
+		let mut order = Vec::with_capacity(self.units.len());
+		let mut stack = Vec::new();
+
+		for idx in 0..self.units.len() {
+			self.visit(
+				idx,
+				&edges,
+				&mut state,
+				&mut stack,
+				&mut order,
+				&mut diagnostics,
+			);
+		}
+
+		AnalysisOrder {
+			units: order.into_iter().map(|idx| &self.units[idx]).collect(),
+			diagnostics,
+		}
+	}
+
+	/// Depth first visit of the unit `idx`, adding it to `order` after its dependencies
+	fn visit(
+		&self,
+		idx: usize,
+		edges: &[Vec<(usize, Span)>],
+		state: &mut [VisitState],
+		stack: &mut Vec<usize>,
+		order: &mut Vec<usize>,
+		diagnostics: &mut Vec<Location<StringDiagnostic>>,
+	) {
+		if state[idx] != VisitState::Unvisited {
+			return;
+		}
+
+		state[idx] = VisitState::Visiting;
+		stack.push(idx);
+
+		for &(target, span) in &edges[idx] {
+			if state[target] == VisitState::Visiting {
+				let start = stack
+					.iter()
+					.position(|&unit| unit == target)
+					.unwrap_or_default();
+				let cycle = stack[start..]
+					.iter()
+					.chain(std::iter::once(&target))
+					.map(|&unit| self.units[unit].qualified_name())
+					.collect::<Vec<_>>()
+					.join("` -> `");
+
+				diagnostics.push(Location::new(
+					*self.units[idx].location.file_id(),
+					StringDiagnostic::new(
+						circular_dependency_code(self.std),
+						format!("Circular dependency between design units `{cycle}`"),
+						Some(span),
+					),
+					span,
+				));
+				continue;
+			}
+
+			self.visit(target, edges, state, stack, order, diagnostics);
+		}
+
+		stack.pop();
+		state[idx] = VisitState::Done;
+		order.push(idx);
+	}
+}
+
+impl GraphUnit {
+	/// The name of the unit as `library.name`, or `library.primary(name)` for an architecture
+	pub fn qualified_name(&self) -> String {
+		match (&self.primary, self.kind) {
+			(Some(primary), UnitKind::Architecture) => {
+				format!("{}.{primary}({})", self.library, self.name)
+			},
+			_ => format!("{}.{}", self.library, self.name),
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum VisitState {
+	Unvisited,
+	Visiting,
+	Done,
+}
+
+/// Collects the references to other design units from a single design unit
+struct Collector<'a> {
+	/// The library the design unit is analyzed into, which `work` refers to
+	library:      &'a str,
+	dependencies: Vec<Dependency>,
+}
+
+impl Collector<'_> {
+	/// Add a reference to the primary unit `name` in the same library, which must exist
+	fn require(&mut self, name: &Spanned<Identifier>) {
+		self.dependencies.push(Dependency {
+			library:  self.library.to_string(),
+			name:     name.inner().normalized(),
+			span:     *name.span(),
+			required: true,
+		});
+	}
+
+	/// Add a reference to the design unit `name`, which must exist if it is a selected name
+	fn selected_name(&mut self, name: &Spanned<Name>) {
+		let mut path = Vec::new();
+		if name_path(name.inner(), &mut path) {
+			self.name(name, path.len() > 1);
+		}
+	}
+
+	/// Add a reference to the design unit `name`
+	///
+	/// A selected name is taken to be `library.unit`, and a simple name is taken to be a unit in
+	/// the same library.
+	fn name(&mut self, name: &Spanned<Name>, required: bool) {
+		let mut path = Vec::new();
+		if !name_path(name.inner(), &mut path) {
+			return;
+		}
+
+		let (library, unit) = match path.as_slice() {
+			[unit] => (self.library.to_string(), unit.clone()),
+			[library, unit, ..] if library == "work" => (self.library.to_string(), unit.clone()),
+			[library, unit, ..] => (library.clone(), unit.clone()),
+			[] => return,
+		};
+
+		self.dependencies
+			.push(Dependency { library, name: unit, span: *name.span(), required });
+	}
+
+	/// `use` clauses only reference a design unit when selecting from a library, `use a.b` where
+	/// `a` is a package makes `b` directly visible rather than naming a design unit
+	fn use_clause(&mut self, names: &[Spanned<Name>]) {
+		for name in names {
+			let mut path = Vec::new();
+			if name_path(name.inner(), &mut path) && path.len() > 1 {
+				self.name(name, true);
+			}
+		}
+	}
+
+	/// IEEE 1076-2008 § 13.4
+	fn context(&mut self, items: &[Spanned<ContextItem>]) {
+		for item in items {
+			match item.inner() {
+				ContextItem::Library(_) => {},
+				ContextItem::Use(names) => self.use_clause(names),
+				ContextItem::Context(names) => {
+					for name in names {
+						self.selected_name(name);
+					}
+				},
+			}
+		}
+	}
+
+	fn declarations(&mut self, declarations: &[Spanned<Declaration>]) {
+		for declaration in declarations {
+			match declaration.inner() {
+				Declaration::Use(names) => self.use_clause(names),
+				Declaration::ConfigurationSpecification(specification) => {
+					self.binding(&specification.binding);
+				},
+				Declaration::SubprogramBody(body) => self.declarations(&body.declarations),
+				Declaration::Package(package) => self.declarations(&package.declarations),
+				Declaration::PackageBody(body) => self.declarations(&body.declarations),
+				Declaration::PackageInstance(instance) => self.selected_name(&instance.package),
+				_ => {},
+			}
+		}
+	}
+
+	fn statements(&mut self, statements: &[Spanned<ConcurrentStatement>]) {
+		for statement in statements {
+			match &statement.inner().kind {
+				ConcurrentStatementKind::Block(block) => {
+					self.declarations(&block.declarations);
+					self.statements(&block.statements);
+				},
+				ConcurrentStatementKind::Process(process) => {
+					self.declarations(&process.declarations);
+				},
+				ConcurrentStatementKind::Instantiation(instantiation) => {
+					match &instantiation.unit {
+						InstantiatedUnit::Component(name) => self.name(name, false),
+						InstantiatedUnit::Entity { name, .. } |
+						InstantiatedUnit::Configuration(name) => self.selected_name(name),
+					}
+				},
+				ConcurrentStatementKind::ForGenerate { body, .. } => self.generate(body),
+				ConcurrentStatementKind::IfGenerate(branches) => {
+					for branch in branches {
+						self.generate(&branch.inner().body);
+					}
+				},
+				ConcurrentStatementKind::CaseGenerate { alternatives, .. } => {
+					for alternative in alternatives {
+						self.generate(&alternative.inner().body);
+					}
+				},
+				ConcurrentStatementKind::SimultaneousIf(branches) => {
+					for branch in branches {
+						self.statements(&branch.inner().value);
+					}
+				},
+				ConcurrentStatementKind::SimultaneousCase { alternatives, .. } => {
+					for alternative in alternatives {
+						self.statements(&alternative.inner().value);
+					}
+				},
+				ConcurrentStatementKind::SimultaneousProcedural(procedural) => {
+					self.declarations(&procedural.declarations);
+				},
+				_ => {},
+			}
+		}
+	}
+
+	fn generate(&mut self, body: &GenerateBody) {
+		self.declarations(&body.declarations);
+		self.statements(&body.statements);
+	}
+
+	/// IEEE 1076-2008 § 3.4.2
+	fn block_configuration(&mut self, block: &BlockConfiguration) {
+		self.use_clause(&block.uses);
+
+		for item in &block.items {
+			match item.inner() {
+				ConfigurationItem::Block(block) => self.block_configuration(block),
+				ConfigurationItem::Component(component) => {
+					if let Some(binding) = &component.binding {
+						self.binding(binding);
+					}
+
+					if let Some(block) = &component.block {
+						self.block_configuration(block.inner());
+					}
+				},
+			}
+		}
+	}
+
+	/// IEEE 1076-2008 § 7.3.2
+	fn binding(&mut self, binding: &BindingIndication) {
+		match binding.entity.as_ref().map(Spanned::inner) {
+			Some(EntityAspect::Entity { name, .. } | EntityAspect::Configuration(name)) => {
+				self.selected_name(name);
+			},
+			Some(EntityAspect::Open) | None => {},
+		}
+	}
+}
+
+/// Flatten a simple or selected name into its normalized identifiers, stopping at a `.all` or
+/// non-identifier suffix, returns false if the name is not made up of identifiers
+fn name_path(name: &Name, path: &mut Vec<String>) -> bool {
+	match name {
+		Name::Simple(ident) => {
+			path.push(ident.normalized());
+			true
+		},
+		Name::Selected { prefix, suffix } => {
+			if !name_path(prefix.inner(), path) {
+				return false;
+			}
+
+			if let Suffix::Identifier(ident) = suffix.inner() {
+				path.push(ident.normalized());
+			}
+			true
+		},
+		_ => false,
+	}
+}
+
+/// The diagnostic code for references to missing design units in `std`
+fn missing_unit_code(std: LanguageStd) -> Code {
+	if LanguageStd::VHDL_AMS_STDS.contains(std) {
+		vhdl_ams::E0003
+	} else {
+		vhdl::E0003
+	}
+}
+
+/// The diagnostic code for circular dependencies between design units in `std`
+fn circular_dependency_code(std: LanguageStd) -> Code {
+	if LanguageStd::VHDL_AMS_STDS.contains(std) {
+		vhdl_ams::E0004
+	} else {
+		vhdl::E0004
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use vermilion_diagnostics::Diagnostic;
+
+	use super::*;
+	use crate::lang::parser::VhdlParser;
+
+	fn parse(source: &str) -> Ast {
+		VhdlParser::new(LanguageStd::Vh08, source.as_bytes().into())
+			.unwrap_or_else(|err| panic!("Failed to create parser: {err}"))
+			.parse()
+	}
+
+	fn order(graph: &DependencyGraph) -> Vec<String> {
+		graph
+			.analysis_order()
+			.units
+			.iter()
+			.map(|unit| unit.qualified_name())
+			.collect()
+	}
+
+	#[test]
+	fn test_secondary_after_primary() {
+		let mut graph = DependencyGraph::new(LanguageStd::Vh08);
+		graph.add_file(
+			"work",
+			FileId::new(0usize),
+			&parse(
+				"architecture rtl of top is begin end architecture;\npackage body pkg is end \
+				 package body;\n",
+			),
+		);
+		graph.add_file(
+			"WORK",
+			FileId::new(1usize),
+			&parse("entity top is end entity;\npackage pkg is end package;\n"),
+		);
+
+		assert_eq!(
+			order(&graph),
+			["work.top", "work.top(rtl)", "work.pkg", "work.pkg"]
+		);
+		assert!(graph.analysis_order().diagnostics.is_empty());
+	}
+
+	#[test]
+	fn test_use_and_instantiation() {
+		let mut graph = DependencyGraph::new(LanguageStd::Vh08);
+		graph.add_file(
+			"work",
+			FileId::new(0usize),
+			&parse(
+				"library ieee, ip;\nuse ieee.std_logic_1164.all;\nuse work.types.all;\nentity top \
+				 is end entity;\narchitecture rtl of top is\nbegin\n\tu0: entity ip.core;\n\tg: \
+				 for i in 0 to 1 generate\n\t\tu1: leaf;\n\tend generate;\nend architecture;\n",
+			),
+		);
+		graph.add_file(
+			"work",
+			FileId::new(1usize),
+			&parse("entity leaf is end entity;\npackage types is end package;\n"),
+		);
+		graph.add_file(
+			"ip",
+			FileId::new(2usize),
+			&parse("use work.ip_pkg.all;\nentity core is end entity;\n"),
+		);
+		graph.add_file(
+			"ip",
+			FileId::new(3usize),
+			&parse("package ip_pkg is end package;\n"),
+		);
+
+		let order = order(&graph);
+		let position = |name: &str| {
+			order
+				.iter()
+				.position(|unit| unit == name)
+				.unwrap_or_else(|| panic!("{name} is not in the analysis order"))
+		};
+
+		assert_eq!(order.len(), 6);
+		assert!(position("work.types") < position("work.top"));
+		assert!(position("ip.core") < position("work.top(rtl)"));
+		assert!(position("ip.ip_pkg") < position("ip.core"));
+		assert!(position("work.leaf") < position("work.top(rtl)"));
+		assert!(graph.analysis_order().diagnostics.is_empty());
+	}
+
+	#[test]
+	fn test_configuration() {
+		let mut graph = DependencyGraph::new(LanguageStd::Vh08);
+		graph.add_file(
+			"work",
+			FileId::new(0usize),
+			&parse(
+				"configuration cfg of top is\n\tfor rtl\n\t\tfor u0: comp use entity \
+				 work.impl(rtl); end for;\n\tend for;\nend configuration;\nentity top is end \
+				 entity;\nentity impl is end entity;\n",
+			),
+		);
+
+		assert_eq!(order(&graph), ["work.top", "work.impl", "work.cfg"]);
+	}
+
+	#[test]
+	fn test_missing_units() {
+		let mut graph = DependencyGraph::new(LanguageStd::Vh08);
+		graph.add_file(
+			"work",
+			FileId::new(0usize),
+			&parse(
+				"library vendor;\nuse vendor.pkg.all;\nuse work.missing_pkg.all;\nentity top is \
+				 end entity;\narchitecture rtl of other is\nbegin\n\tu0: black_box;\n\tu1: entity \
+				 work.gone;\nend architecture;\n",
+			),
+		);
+
+		let analysis = graph.analysis_order();
+		assert_eq!(analysis.units.len(), 2);
+
+		let messages = analysis
+			.diagnostics
+			.iter()
+			.map(|diagnostic| {
+				assert_eq!(diagnostic.inner().code(), vhdl::E0003);
+				diagnostic.inner().message().to_string()
+			})
+			.collect::<Vec<_>>();
+
+		assert_eq!(
+			messages,
+			[
+				"No design unit `missing_pkg` in library `work`",
+				"No design unit `other` in library `work`",
+				"No design unit `gone` in library `work`",
+			]
+		);
+	}
+
+	#[test]
+	fn test_circular_dependency() {
+		let mut graph = DependencyGraph::new(LanguageStd::Vhams17);
+		graph.add_file(
+			"work",
+			FileId::new(0usize),
+			&parse(
+				"use work.b.all;\npackage a is end package;\nuse work.a.all;\npackage b is end \
+				 package;\n",
+			),
+		);
+
+		let analysis = graph.analysis_order();
+		assert_eq!(analysis.units.len(), 2);
+		assert_eq!(analysis.diagnostics.len(), 1);
+		assert_eq!(analysis.diagnostics[0].inner().code(), vhdl_ams::E0004);
+		assert_eq!(
+			analysis.diagnostics[0].inner().message(),
+			"Circular dependency between design units `work.a` -> `work.b` -> `work.a`"
+		);
+		assert_eq!(*analysis.diagnostics[0].file_id(), FileId::new(0usize));
+	}
+}
@@ -2430,7 +2430,6 @@ pub static VHDL_AMS_21_KEYWORD_SET: phf::Set<&'static str> = phf_set! {
 };
 
 /// Get the IEEE 1149.1-2013 (BSDL) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_bsdl_keyword(ident: &str) -> Option<Keyword> {
 	BSDL_KEYWORD_MAP.get(ident).copied()
@@ -2439,14 +2438,12 @@ pub fn get_bsdl_keyword(ident: &str) -> Option<Keyword> {
 /// Check to see if the given identifier is a IEEE 1149.1-2013 (BSDL) keyword
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_bsdl_keyword(ident: &str) -> bool {
 	BSDL_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEEE 1076-1987 (VHDL 1987) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_87_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_87_KEYWORD_MAP.get(ident).copied()
@@ -2459,14 +2456,12 @@ pub fn get_vhdl_87_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_87_keyword(ident: &str) -> bool {
 	VHDL_87_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEEE 1076-1993 (VHDL 1993) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_93_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_93_KEYWORD_MAP.get(ident).copied()
@@ -2479,14 +2474,12 @@ pub fn get_vhdl_93_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_93_keyword(ident: &str) -> bool {
 	VHDL_93_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEEE 1076-2000 (VHDL 2000) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_2k_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_2K_KEYWORD_MAP.get(ident).copied()
@@ -2499,14 +2492,12 @@ pub fn get_vhdl_2k_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_2k_keyword(ident: &str) -> bool {
 	VHDL_2K_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEEE 1076-2002 (VHDL 2002) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_02_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_02_KEYWORD_MAP.get(ident).copied()
@@ -2519,14 +2510,12 @@ pub fn get_vhdl_02_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_02_keyword(ident: &str) -> bool {
 	VHDL_02_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEC 61691-1-1:2004 (VHDL 2004) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_04_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_04_KEYWORD_MAP.get(ident).copied()
@@ -2539,14 +2528,12 @@ pub fn get_vhdl_04_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_04_keyword(ident: &str) -> bool {
 	VHDL_04_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEEE 1076-2007 (VHDL 2007) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_07_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_07_KEYWORD_MAP.get(ident).copied()
@@ -2559,14 +2546,12 @@ pub fn get_vhdl_07_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_07_keyword(ident: &str) -> bool {
 	VHDL_07_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEEE 1076-2008 (VHDL 2008) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_08_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_08_KEYWORD_MAP.get(ident).copied()
@@ -2579,14 +2564,12 @@ pub fn get_vhdl_08_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_08_keyword(ident: &str) -> bool {
 	VHDL_08_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEC 61691-1-1:2011 (VHDL 2011) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_11_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_11_KEYWORD_MAP.get(ident).copied()
@@ -2599,14 +2582,12 @@ pub fn get_vhdl_11_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_11_keyword(ident: &str) -> bool {
 	VHDL_11_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEEE 1076-2019 (VHDL 2019) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_19_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_19_KEYWORD_MAP.get(ident).copied()
@@ -2619,14 +2600,12 @@ pub fn get_vhdl_19_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_19_keyword(ident: &str) -> bool {
 	VHDL_19_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEC 61691-1-1:2023 (VHDL 2023) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_23_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_23_KEYWORD_MAP.get(ident).copied()
@@ -2639,14 +2618,12 @@ pub fn get_vhdl_23_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_23_keyword(ident: &str) -> bool {
 	VHDL_23_KEYWORD_SET.contains(ident)
 }
 
 /// Get the VHDL keyword for the given standard if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_keyword(ident: &str, std: LanguageStd) -> Option<Keyword> {
 	match std {
@@ -2665,7 +2642,6 @@ pub fn get_vhdl_keyword(ident: &str, std: LanguageStd) -> Option<Keyword> {
 }
 
 /// Get the IEEE 1076.1-1999 (VHDL-AMS 1999) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_ams_99_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_AMS_99_KEYWORD_MAP.get(ident).copied()
@@ -2678,14 +2654,12 @@ pub fn get_vhdl_ams_99_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_ams_99_keyword(ident: &str) -> bool {
 	VHDL_AMS_99_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEEE 1076.1-2007 (VHDL-AMS 2007) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_ams_07_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_AMS_07_KEYWORD_MAP.get(ident).copied()
@@ -2698,14 +2672,12 @@ pub fn get_vhdl_ams_07_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_ams_07_keyword(ident: &str) -> bool {
 	VHDL_AMS_07_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEC 61691-6:2009 (VHDL-AMS 2009) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_ams_09_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_AMS_09_KEYWORD_MAP.get(ident).copied()
@@ -2718,14 +2690,12 @@ pub fn get_vhdl_ams_09_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_ams_09_keyword(ident: &str) -> bool {
 	VHDL_AMS_09_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEEE 1076.1-2017 (VHDL-AMS 2017) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_ams_17_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_AMS_17_KEYWORD_MAP.get(ident).copied()
@@ -2738,14 +2708,12 @@ pub fn get_vhdl_ams_17_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_ams_17_keyword(ident: &str) -> bool {
 	VHDL_AMS_17_KEYWORD_SET.contains(ident)
 }
 
 /// Get the IEC 61691-6:2021 (VHDL-AMS 2021) keyword for the given identifier if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_ams_21_keyword(ident: &str) -> Option<Keyword> {
 	VHDL_AMS_21_KEYWORD_MAP.get(ident).copied()
@@ -2758,14 +2726,12 @@ pub fn get_vhdl_ams_21_keyword(ident: &str) -> Option<Keyword> {
 /// standard.
 ///
 /// It prevents the copy into an [`Option`] if it is a keyword.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn is_vhdl_ams_21_keyword(ident: &str) -> bool {
 	VHDL_AMS_21_KEYWORD_SET.contains(ident)
 }
 
 /// Get the VHDL-AMS keyword for the given standard if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_vhdl_ams_keyword(ident: &str, std: LanguageStd) -> Option<Keyword> {
 	match std {
@@ -2779,7 +2745,6 @@ pub fn get_vhdl_ams_keyword(ident: &str, std: LanguageStd) -> Option<Keyword> {
 }
 
 /// Get the given VHDL/VHDL-AMS keyword for the given standard if it exists
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn get_keyword(ident: &str, std: LanguageStd) -> Option<Keyword> {
 	match std {
@@ -2805,7 +2770,6 @@ pub fn get_keyword(ident: &str, std: LanguageStd) -> Option<Keyword> {
 
 /// Check to see if the given identifier is a keyword in a future VHDL standard, returning
 /// the standard version if so.
-#[allow(unused, reason = "VHDL Tokenizer not implemented yet")]
 #[inline(always)]
 pub fn keyword_in(ident: &str) -> LanguageStd {
 	let mut supported = LanguageStd::none();
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
 pub mod ast;
+pub mod bsdl;
+pub mod depgraph;
 pub mod keywords;
 pub mod parser;
 pub mod tokenizer;
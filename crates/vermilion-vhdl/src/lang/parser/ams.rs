@@ -0,0 +1,501 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::{
+	LanguageStd,
+	lang::{
+		ast::{
+			BranchQuantity, BreakElement, ConcurrentStatementKind, Conditional, Declaration,
+			Expression, Identifier, InstantiationTargets, NatureDeclaration, NatureDefinition,
+			NatureElementDeclaration, Procedural, QuantityAspect, QuantityDeclaration, Selected,
+			SequentialStatementKind, SourceAspect, SubnatureIndication,
+		},
+		parser::{VhdlParser, error::ParseResult},
+		tokenizer::token::{Control, Keyword, Operator, Token},
+	},
+};
+
+impl VhdlParser {
+	/// Returns true if the analog and mixed-signal extensions are available
+	pub(crate) fn is_ams(&self) -> bool {
+		LanguageStd::VHDL_AMS_STDS.contains(self.std)
+	}
+
+	/// IEEE 1076.1-2007 § 4.8
+	pub(crate) fn parse_nature_declaration(&mut self) -> ParseResult<NatureDeclaration> {
+		self.expect_keyword(Keyword::Nature)?;
+		let name = self.expect_identifier()?;
+		self.expect_keyword(Keyword::Is)?;
+
+		let start = self.span();
+		let definition = match self.peek() {
+			Some(Token::Keyword(Keyword::Array)) => {
+				self.bump();
+				self.expect_control(Control::ParenOpen)?;
+
+				let mut indices = vec![self.parse_index_subtype()?];
+				while self.eat_control(Control::Comma) {
+					indices.push(self.parse_index_subtype()?);
+				}
+
+				self.expect_control(Control::ParenClose)?;
+				self.expect_keyword(Keyword::Of)?;
+				let element = self.parse_subnature_indication()?;
+
+				NatureDefinition::Array { indices, element }
+			},
+			Some(Token::Keyword(Keyword::Record)) => {
+				self.bump();
+
+				let mut elements = Vec::new();
+				while !self.is_keyword(Keyword::End) {
+					let start = self.span();
+					let names = self.parse_identifier_list()?;
+					self.expect_control(Control::Colon)?;
+					let nature = self.parse_subnature_indication()?;
+					self.expect_control(Control::Semicolon)?;
+
+					elements.push(Spanned::new(
+						NatureElementDeclaration { names, nature },
+						self.span_from(start),
+					));
+				}
+
+				self.expect_keyword(Keyword::End)?;
+				self.expect_keyword(Keyword::Record)?;
+				self.parse_closing_name(&name)?;
+
+				NatureDefinition::Record(elements)
+			},
+			_ => {
+				let across = self.parse_type_mark()?;
+				self.expect_keyword(Keyword::Across)?;
+				let through = self.parse_type_mark()?;
+				self.expect_keyword(Keyword::Through)?;
+				let reference = self.expect_identifier()?;
+				self.expect_keyword(Keyword::Reference)?;
+
+				NatureDefinition::Scalar { across, through, reference }
+			},
+		};
+		let definition = Spanned::new(definition, self.span_from(start));
+
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(NatureDeclaration { name, definition })
+	}
+
+	/// IEEE 1076.1-2007 § 4.9
+	pub(crate) fn parse_subnature_indication(
+		&mut self,
+	) -> ParseResult<Spanned<SubnatureIndication>> {
+		let start = self.span();
+		let nature = self.parse_type_mark()?;
+
+		let constraint = if self.eat_control(Control::ParenOpen) {
+			let mut ranges = vec![self.parse_discrete_range()?];
+			while self.eat_control(Control::Comma) {
+				ranges.push(self.parse_discrete_range()?);
+			}
+			self.expect_control(Control::ParenClose)?;
+
+			Some(ranges)
+		} else {
+			None
+		};
+
+		let tolerance = if self.eat_keyword(Keyword::Tolerance) {
+			let across = self.parse_expression()?;
+			self.expect_keyword(Keyword::Across)?;
+			let through = self.parse_expression()?;
+			self.expect_keyword(Keyword::Through)?;
+
+			Some((across, through))
+		} else {
+			None
+		};
+
+		Ok(Spanned::new(
+			SubnatureIndication { nature, constraint, tolerance },
+			self.span_from(start),
+		))
+	}
+
+	/// Parse a `tolerance expression` aspect, if there is one
+	///
+	/// IEEE 1076.1-2007 § 4.2
+	pub(crate) fn parse_tolerance_aspect(&mut self) -> ParseResult<Option<Spanned<Expression>>> {
+		if self.eat_keyword(Keyword::Tolerance) {
+			Ok(Some(self.parse_expression()?))
+		} else {
+			Ok(None)
+		}
+	}
+
+	/// IEEE 1076.1-2007 § 4.3.1.5
+	pub(crate) fn parse_terminal_declaration(&mut self) -> ParseResult<Declaration> {
+		self.expect_keyword(Keyword::Terminal)?;
+		let names = self.parse_identifier_list()?;
+		self.expect_control(Control::Colon)?;
+		let nature = self.parse_subnature_indication()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(Declaration::Terminal { names, nature })
+	}
+
+	/// IEEE 1076.1-2007 § 4.3.1.6
+	pub(crate) fn parse_quantity_declaration(&mut self) -> ParseResult<QuantityDeclaration> {
+		self.expect_keyword(Keyword::Quantity)?;
+
+		// Free and source quantities are an identifier list followed by a `:`, branch quantities
+		// have their across and through aspects and then the terminals
+		let checkpoint = self.checkpoint();
+		if let Ok(names) = self.parse_identifier_list() &&
+			self.eat_control(Control::Colon)
+		{
+			let subtype = self.parse_subtype_indication()?;
+
+			let declaration = match self.peek() {
+				Some(Token::Keyword(Keyword::Spectrum)) => {
+					self.bump();
+					let magnitude = self.parse_expression()?;
+					self.expect_control(Control::Comma)?;
+					let phase = self.parse_expression()?;
+
+					QuantityDeclaration::Source {
+						names,
+						subtype,
+						source: SourceAspect::Spectrum { magnitude, phase },
+					}
+				},
+				Some(Token::Keyword(Keyword::Noise)) => {
+					self.bump();
+
+					QuantityDeclaration::Source {
+						names,
+						subtype,
+						source: SourceAspect::Noise(self.parse_expression()?),
+					}
+				},
+				_ => {
+					let default = if self.eat_control(Control::VariableAssignment) {
+						Some(self.parse_expression()?)
+					} else {
+						None
+					};
+
+					QuantityDeclaration::Free { names, subtype, default }
+				},
+			};
+
+			self.expect_control(Control::Semicolon)?;
+			return Ok(declaration);
+		}
+		self.restore(checkpoint);
+
+		let across = self.parse_quantity_aspect(Keyword::Across)?;
+		let through = self.parse_quantity_aspect(Keyword::Through)?;
+
+		let plus = self.parse_name()?;
+		let minus = if self.eat_keyword(Keyword::To) {
+			Some(self.parse_name()?)
+		} else {
+			None
+		};
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(QuantityDeclaration::Branch(BranchQuantity {
+			across,
+			through,
+			plus,
+			minus,
+		}))
+	}
+
+	/// Parse the `names [tolerance] [:= default] across` or `... through` aspect of a branch
+	/// quantity declaration, if there is one
+	///
+	/// IEEE 1076.1-2007 § 4.3.1.6
+	fn parse_quantity_aspect(&mut self, keyword: Keyword) -> ParseResult<Option<QuantityAspect>> {
+		if !self.is_identifier() {
+			return Ok(None);
+		}
+
+		let checkpoint = self.checkpoint();
+		let names = self.parse_identifier_list()?;
+		let tolerance = self.parse_tolerance_aspect()?;
+		let default = if self.eat_control(Control::VariableAssignment) {
+			Some(self.parse_expression()?)
+		} else {
+			None
+		};
+
+		// Without the keyword this is the terminal aspect rather than a quantity aspect
+		if !self.eat_keyword(keyword) {
+			self.restore(checkpoint);
+			return Ok(None);
+		}
+
+		Ok(Some(QuantityAspect { names, tolerance, default }))
+	}
+
+	/// IEEE 1076.1-2007 § 5.4
+	pub(crate) fn parse_step_limit(&mut self) -> ParseResult<Declaration> {
+		self.expect_keyword(Keyword::Limit)?;
+
+		let quantities = if self.eat_keyword(Keyword::Others) {
+			InstantiationTargets::Others
+		} else if self.eat_keyword(Keyword::All) {
+			InstantiationTargets::All
+		} else {
+			InstantiationTargets::Names(self.parse_selected_names()?)
+		};
+
+		self.expect_control(Control::Colon)?;
+		let type_mark = self.parse_type_mark()?;
+		self.expect_keyword(Keyword::With)?;
+		let limit = self.parse_expression()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(Declaration::StepLimit { quantities, type_mark, limit })
+	}
+
+	/// Returns true if the `if` or `case` at the current position is a simultaneous statement,
+	/// that is it reaches a `use` before a `generate`
+	pub(crate) fn is_simultaneous_use(&self) -> bool {
+		if !self.is_ams() {
+			return false;
+		}
+
+		let mut depth = 0usize;
+		let mut offset = 1;
+		while let Some(token) = self.peek_nth(offset) {
+			match token {
+				Token::Control(Control::ParenOpen) => depth += 1,
+				Token::Control(Control::ParenClose) => depth = depth.saturating_sub(1),
+				Token::Keyword(Keyword::Use) if depth == 0 => return true,
+				Token::Keyword(Keyword::Generate | Keyword::Is) if depth == 0 => return false,
+				Token::Control(Control::Semicolon) => return false,
+				_ => {},
+			}
+			offset += 1;
+		}
+
+		false
+	}
+
+	/// Returns true if the statement at the current position is a simple simultaneous statement,
+	/// that is it has a `==` before its `;`
+	pub(crate) fn is_simple_simultaneous(&self) -> bool {
+		if !self.is_ams() {
+			return false;
+		}
+
+		let mut depth = 0usize;
+		let mut offset = 0;
+		while let Some(token) = self.peek_nth(offset) {
+			match token {
+				Token::Control(Control::ParenOpen) => depth += 1,
+				Token::Control(Control::ParenClose) => depth = depth.saturating_sub(1),
+				Token::Operator(Operator::DoubleEquals) if depth == 0 => return true,
+				Token::Control(Control::Semicolon) => return false,
+				_ => {},
+			}
+			offset += 1;
+		}
+
+		false
+	}
+
+	/// IEEE 1076.1-2007 § 15.1
+	pub(crate) fn parse_simple_simultaneous(&mut self) -> ParseResult<ConcurrentStatementKind> {
+		let lhs = self.parse_expression()?;
+		self.expect_operator(Operator::DoubleEquals)?;
+		let rhs = self.parse_expression()?;
+		let tolerance = self.parse_tolerance_aspect()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(ConcurrentStatementKind::SimpleSimultaneous { lhs, rhs, tolerance })
+	}
+
+	/// IEEE 1076.1-2007 § 15.2
+	pub(crate) fn parse_simultaneous_if(
+		&mut self,
+		label: Option<&Spanned<Identifier>>,
+	) -> ParseResult<ConcurrentStatementKind> {
+		let ends = [Keyword::Elsif, Keyword::Else, Keyword::End];
+		let mut branches = Vec::new();
+
+		let mut start = self.expect_keyword(Keyword::If)?;
+		let mut condition = Some(self.parse_expression()?);
+		self.expect_keyword(Keyword::Use)?;
+
+		loop {
+			let (statements, closed) = self.parse_concurrent_statements(&ends);
+			let last = condition.is_none();
+
+			branches.push(Spanned::new(
+				Conditional { value: statements, condition },
+				self.span_from(start),
+			));
+
+			if !closed {
+				return Ok(ConcurrentStatementKind::SimultaneousIf(branches));
+			}
+
+			start = self.span();
+			match self.peek() {
+				Some(Token::Keyword(Keyword::Elsif)) if !last => {
+					self.bump();
+					condition = Some(self.parse_expression()?);
+					self.expect_keyword(Keyword::Use)?;
+				},
+				Some(Token::Keyword(Keyword::Else)) if !last => {
+					self.bump();
+					condition = None;
+				},
+				_ => break,
+			}
+		}
+
+		self.parse_end(&[Keyword::Use], true, label)?;
+
+		Ok(ConcurrentStatementKind::SimultaneousIf(branches))
+	}
+
+	/// IEEE 1076.1-2007 § 15.3
+	pub(crate) fn parse_simultaneous_case(
+		&mut self,
+		label: Option<&Spanned<Identifier>>,
+	) -> ParseResult<ConcurrentStatementKind> {
+		self.expect_keyword(Keyword::Case)?;
+		let expression = self.parse_expression()?;
+		self.expect_keyword(Keyword::Use)?;
+
+		let mut alternatives = Vec::new();
+		while self.is_keyword(Keyword::When) {
+			let start = self.span();
+			self.bump();
+
+			let choices = self.parse_choices()?;
+			self.expect_control(Control::Arrow)?;
+
+			let (statements, closed) =
+				self.parse_concurrent_statements(&[Keyword::When, Keyword::End]);
+			alternatives.push(Spanned::new(
+				Selected { value: statements, choices },
+				self.span_from(start),
+			));
+
+			if !closed {
+				return Ok(ConcurrentStatementKind::SimultaneousCase { expression, alternatives });
+			}
+		}
+
+		self.parse_end(&[Keyword::Case], true, label)?;
+
+		Ok(ConcurrentStatementKind::SimultaneousCase { expression, alternatives })
+	}
+
+	/// IEEE 1076.1-2007 § 15.4
+	pub(crate) fn parse_simultaneous_procedural(
+		&mut self,
+		label: Option<&Spanned<Identifier>>,
+	) -> ParseResult<Procedural> {
+		let start = self.expect_keyword(Keyword::Procedural)?;
+		self.require_std(
+			LanguageStd::VHDL_AMS_STDS,
+			start,
+			"Simultaneous procedural statements",
+		);
+		self.eat_keyword(Keyword::Is);
+
+		let (declarations, closed) = self.parse_declarations(&[Keyword::Begin]);
+		if !closed {
+			return Ok(Procedural { declarations, statements: Vec::new() });
+		}
+
+		self.expect_keyword(Keyword::Begin)?;
+		let (statements, closed) = self.parse_sequential_statements(&[Keyword::End]);
+		if closed {
+			self.parse_end(&[Keyword::Procedural], true, label)?;
+		}
+
+		Ok(Procedural { declarations, statements })
+	}
+
+	/// IEEE 1076.1-2007 § 9.7
+	pub(crate) fn parse_concurrent_break(&mut self) -> ParseResult<ConcurrentStatementKind> {
+		self.expect_keyword(Keyword::Break)?;
+		let elements = self.parse_break_elements()?;
+
+		let sensitivity = if self.eat_keyword(Keyword::On) {
+			self.parse_name_list()?
+		} else {
+			Vec::new()
+		};
+
+		let condition = if self.eat_keyword(Keyword::When) {
+			Some(self.parse_expression()?)
+		} else {
+			None
+		};
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(ConcurrentStatementKind::Break { elements, sensitivity, condition })
+	}
+
+	/// IEEE 1076.1-2007 § 8.14
+	pub(crate) fn parse_sequential_break(&mut self) -> ParseResult<SequentialStatementKind> {
+		self.expect_keyword(Keyword::Break)?;
+		let elements = self.parse_break_elements()?;
+
+		let condition = if self.eat_keyword(Keyword::When) {
+			Some(self.parse_expression()?)
+		} else {
+			None
+		};
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(SequentialStatementKind::Break { elements, condition })
+	}
+
+	/// Parse the comma separated `[for selector use] quantity => value` elements of a break
+	/// statement, if there are any
+	///
+	/// IEEE 1076.1-2007 § 8.14
+	fn parse_break_elements(&mut self) -> ParseResult<Vec<Spanned<BreakElement>>> {
+		let mut elements = Vec::new();
+		if !self.is_keyword(Keyword::For) && !self.is_identifier() {
+			return Ok(elements);
+		}
+
+		loop {
+			let start = self.span();
+
+			let selector = if self.eat_keyword(Keyword::For) {
+				let selector = self.parse_name()?;
+				self.expect_keyword(Keyword::Use)?;
+				Some(selector)
+			} else {
+				None
+			};
+
+			let quantity = self.parse_name()?;
+			self.expect_control(Control::Arrow)?;
+			let value = self.parse_expression()?;
+
+			elements.push(Spanned::new(
+				BreakElement { selector, quantity, value },
+				self.span_from(start),
+			));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		Ok(elements)
+	}
+}
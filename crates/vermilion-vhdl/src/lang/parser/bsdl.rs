@@ -0,0 +1,911 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::ops::Range;
+
+use vermilion_diagnostics::{Diagnostic, StringDiagnostic};
+use vermilion_loc::{Span, Spanned};
+
+use crate::{
+	diagnostics::vhdl,
+	lang::{
+		bsdl::{
+			AttributeValue, BoundaryCell, BsdlAttribute, BsdlDescription, BsdlPort, CellControl,
+			CellFunction, DisableResult, Instruction, PinMap, PinMapEntry, PortMode, SafeValue,
+			TapPort, TapSignal,
+		},
+		parser::{VhdlParser, error::ParseResult},
+		tokenizer::token::{Control, Operator, Token},
+	},
+};
+
+// NOTE(aki):
+// BSDL is a subset of VHDL, but it has its own set of reserved words which do not include most of
+// the VHDL ones, so the structure is parsed here by matching words rather than keywords. Most of
+// the description is in attribute strings, those are parsed by a separate small grammar with the
+// spans of their contents mapped back to the string literals they came from.
+impl VhdlParser {
+	/// Parse a BSDL file into a description of the device, checking it against the rules of
+	/// IEEE 1149.1-2013
+	///
+	/// IEEE 1149.1-2013 § B.8
+	pub fn parse_bsdl(mut self) -> BsdlDescription {
+		let mut description = BsdlDescription::default();
+
+		while !self.at_eof() {
+			let result = if self.is_word("entity") && description.entity.is_none() {
+				self.parse_bsdl_entity(&mut description)
+			} else {
+				self.unexpected("`entity`")
+			};
+
+			if let Err(err) = result {
+				self.report(err);
+				self.skip_bsdl_statement();
+			}
+		}
+
+		let mut diagnostics = self.diagnostics;
+		if !diagnostics
+			.iter()
+			.any(|diagnostic| diagnostic.code() == vhdl::E0002)
+		{
+			description.validate(&mut diagnostics);
+		}
+		diagnostics.sort_by_key(|diagnostic| diagnostic.span().map(|span| *span.begin()));
+
+		description.set_diagnostics(diagnostics);
+		description
+	}
+
+	/// IEEE 1149.1-2013 § B.8.1
+	fn parse_bsdl_entity(&mut self, description: &mut BsdlDescription) -> ParseResult<()> {
+		self.bump();
+		let name = self.expect_word()?;
+		self.expect_word_of("is")?;
+		description.entity = Some(name.clone());
+
+		while !self.is_word("end") {
+			if self.at_eof() {
+				return self.unexpected("`end`");
+			}
+
+			let result = if self.is_word("generic") {
+				self.parse_bsdl_generic(description)
+			} else if self.is_word("port") {
+				self.parse_bsdl_ports(description)
+			} else if self.is_word("use") {
+				self.parse_bsdl_use(description)
+			} else if self.is_word("attribute") {
+				self.parse_bsdl_attribute(description)
+			} else if self.is_word("constant") {
+				self.parse_bsdl_constant(description)
+			} else {
+				self.unexpected("a generic, port, use clause, attribute, or constant")
+			};
+
+			if let Err(err) = result {
+				self.report(err);
+				self.skip_bsdl_statement();
+			}
+		}
+
+		self.bump();
+		if self.is_word("entity") {
+			self.bump();
+		}
+
+		if !self.is_control(Control::Semicolon) {
+			let label = self.expect_word()?;
+			if !label.inner().eq_ignore_ascii_case(name.inner()) {
+				self.diagnostics.push(StringDiagnostic::new(
+					vhdl::E0002,
+					format!(
+						"End label `{}` does not match `{}`",
+						label.inner(),
+						name.inner()
+					),
+					Some(*label.span()),
+				));
+			}
+		}
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(())
+	}
+
+	/// `generic (PHYSICAL_PIN_MAP : string := "name");`
+	///
+	/// IEEE 1149.1-2013 § B.8.2
+	fn parse_bsdl_generic(&mut self, description: &mut BsdlDescription) -> ParseResult<()> {
+		self.bump();
+		self.expect_control(Control::ParenOpen)?;
+
+		if !self.is_word("physical_pin_map") {
+			return self.unexpected("`PHYSICAL_PIN_MAP`");
+		}
+		self.bump();
+		self.expect_control(Control::Colon)?;
+		self.expect_word_of("string")?;
+
+		if self.eat_control(Control::VariableAssignment) {
+			let (value, span) = self.parse_bsdl_string()?;
+			description.physical_pin_map = Some(Spanned::new(value.text, span));
+		}
+
+		self.expect_control(Control::ParenClose)?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(())
+	}
+
+	/// IEEE 1149.1-2013 § B.8.3
+	fn parse_bsdl_ports(&mut self, description: &mut BsdlDescription) -> ParseResult<()> {
+		self.bump();
+		self.expect_control(Control::ParenOpen)?;
+
+		loop {
+			let start = self.span();
+			let mut names = vec![self.expect_word()?];
+			while self.eat_control(Control::Comma) {
+				names.push(self.expect_word()?);
+			}
+
+			self.expect_control(Control::Colon)?;
+
+			let mode = if self.is_word("in") {
+				PortMode::In
+			} else if self.is_word("out") {
+				PortMode::Out
+			} else if self.is_word("inout") {
+				PortMode::InOut
+			} else if self.is_word("buffer") {
+				PortMode::Buffer
+			} else if self.is_word("linkage") {
+				PortMode::Linkage
+			} else {
+				return self.unexpected("a port mode");
+			};
+			self.bump();
+
+			let bounds = if self.is_word("bit") {
+				self.bump();
+				None
+			} else if self.is_word("bit_vector") {
+				self.bump();
+				self.expect_control(Control::ParenOpen)?;
+				let left = self.expect_bsdl_integer()?;
+				if !self.is_word("to") && !self.is_word("downto") {
+					return self.unexpected("`to` or `downto`");
+				}
+				self.bump();
+				let right = self.expect_bsdl_integer()?;
+				self.expect_control(Control::ParenClose)?;
+
+				Some((*left.inner() as i64, *right.inner() as i64))
+			} else {
+				return self.unexpected("`bit` or `bit_vector`");
+			};
+
+			let span = self.span_from(start);
+			description.ports.extend(
+				names
+					.into_iter()
+					.map(|name| Spanned::new(BsdlPort { name, mode, bounds }, span)),
+			);
+
+			if !self.eat_control(Control::Semicolon) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::ParenClose)?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(())
+	}
+
+	/// `use STD_1149_1_2013.all;`
+	///
+	/// IEEE 1149.1-2013 § B.8.4
+	fn parse_bsdl_use(&mut self, description: &mut BsdlDescription) -> ParseResult<()> {
+		self.bump();
+		let package = self.expect_word()?;
+		self.expect_control(Control::Dot)?;
+		self.expect_word_of("all")?;
+		self.expect_control(Control::Semicolon)?;
+
+		description.packages.push(package);
+
+		Ok(())
+	}
+
+	/// `constant name : PIN_MAP_STRING := "...";`
+	///
+	/// IEEE 1149.1-2013 § B.8.6
+	fn parse_bsdl_constant(&mut self, description: &mut BsdlDescription) -> ParseResult<()> {
+		let start = self.span();
+		self.bump();
+		let name = self.expect_word()?;
+		self.expect_control(Control::Colon)?;
+		self.expect_word_of("pin_map_string")?;
+		self.expect_control(Control::VariableAssignment)?;
+		let (value, _) = self.parse_bsdl_string()?;
+		self.expect_control(Control::Semicolon)?;
+
+		let entries = self.parse_bsdl_value(&value, parse_pin_map);
+		description.pin_maps.push(Spanned::new(
+			PinMap { name, entries },
+			self.span_from(start),
+		));
+
+		Ok(())
+	}
+
+	/// `attribute name of targets : class is value;`
+	///
+	/// IEEE 1149.1-2013 § B.8.1
+	fn parse_bsdl_attribute(&mut self, description: &mut BsdlDescription) -> ParseResult<()> {
+		let start = self.span();
+		self.bump();
+		let name = self.expect_word()?;
+		self.expect_word_of("of")?;
+
+		let mut targets = vec![self.expect_word()?];
+		while self.eat_control(Control::Comma) {
+			targets.push(self.expect_word()?);
+		}
+
+		self.expect_control(Control::Colon)?;
+		let class = self.expect_word()?.inner().to_ascii_lowercase();
+		self.expect_word_of("is")?;
+
+		let (value, value_span, string) = if matches!(self.peek(), Some(Token::String(_))) {
+			let (string, span) = self.parse_bsdl_string()?;
+			(
+				AttributeValue::String(string.text.clone()),
+				span,
+				Some(string),
+			)
+		} else {
+			let value_start = self.span();
+			let mut depth = 0usize;
+			while !self.at_eof() && (depth > 0 || !self.is_control(Control::Semicolon)) {
+				match self.peek() {
+					Some(Token::Control(Control::ParenOpen)) => depth += 1,
+					Some(Token::Control(Control::ParenClose)) => depth = depth.saturating_sub(1),
+					_ => {},
+				}
+				self.bump();
+			}
+
+			let span = self.span_from(value_start);
+			let text = String::from_utf8_lossy(&self.source_text(&span)).into_owned();
+			let value = match text.parse::<u64>() {
+				Ok(value) => AttributeValue::Integer(value),
+				Err(_) if !text.is_empty() && text.chars().all(is_word_char) => {
+					AttributeValue::Name(text)
+				},
+				Err(_) => AttributeValue::Other(text),
+			};
+
+			(value, span, None)
+		};
+		self.expect_control(Control::Semicolon)?;
+
+		self.model_bsdl_attribute(
+			description,
+			&name,
+			&targets,
+			&value,
+			value_span,
+			string.as_ref(),
+		);
+
+		description.attributes.push(Spanned::new(
+			BsdlAttribute { name, targets, class, value },
+			self.span_from(start),
+		));
+
+		Ok(())
+	}
+
+	/// Fill in the parts of the description given by the attribute `name`
+	///
+	/// IEEE 1149.1-2013 § B.8.5 - § B.8.13, § B.10
+	fn model_bsdl_attribute(
+		&mut self,
+		description: &mut BsdlDescription,
+		name: &Spanned<String>,
+		targets: &[Spanned<String>],
+		value: &AttributeValue,
+		span: Span,
+		string: Option<&BsdlString>,
+	) {
+		let name = name.inner().to_ascii_uppercase();
+
+		let tap = match name.as_str() {
+			"TAP_SCAN_IN" => Some(TapSignal::ScanIn),
+			"TAP_SCAN_OUT" => Some(TapSignal::ScanOut),
+			"TAP_SCAN_MODE" => Some(TapSignal::ScanMode),
+			"TAP_SCAN_RESET" => Some(TapSignal::ScanReset),
+			"TAP_SCAN_CLOCK" => Some(TapSignal::ScanClock),
+			_ => None,
+		};
+
+		if let Some(signal) = tap {
+			description.tap_ports.extend(
+				targets
+					.iter()
+					.map(|port| Spanned::new(TapPort { port: port.clone(), signal }, *port.span())),
+			);
+			return;
+		}
+
+		match (name.as_str(), value, string) {
+			("COMPONENT_CONFORMANCE", _, Some(string)) => {
+				description.component_conformance = Some(Spanned::new(string.text.clone(), span));
+			},
+			("INSTRUCTION_LENGTH", AttributeValue::Integer(length), _) => {
+				description.instruction_length = Some(Spanned::new(*length as u32, span));
+			},
+			("BOUNDARY_LENGTH", AttributeValue::Integer(length), _) => {
+				description.boundary_length = Some(Spanned::new(*length as u32, span));
+			},
+			("INSTRUCTION_OPCODE", _, Some(string)) => {
+				description.instructions = self.parse_bsdl_value(string, parse_opcodes);
+			},
+			("INSTRUCTION_CAPTURE", _, Some(string)) => {
+				description.instruction_capture = Some(string.pattern());
+			},
+			("INSTRUCTION_PRIVATE", _, Some(string)) => {
+				description.instruction_private = self.parse_bsdl_value(string, parse_names);
+			},
+			("IDCODE_REGISTER", _, Some(string)) => description.idcode = Some(string.pattern()),
+			("USERCODE_REGISTER", _, Some(string)) => {
+				description.usercode = Some(string.pattern());
+			},
+			("BOUNDARY_REGISTER", _, Some(string)) => {
+				description.boundary_cells = self.parse_bsdl_value(string, parse_boundary_register);
+			},
+			(
+				"INSTRUCTION_LENGTH" |
+				"BOUNDARY_LENGTH" |
+				"COMPONENT_CONFORMANCE" |
+				"INSTRUCTION_OPCODE" |
+				"INSTRUCTION_CAPTURE" |
+				"INSTRUCTION_PRIVATE" |
+				"IDCODE_REGISTER" |
+				"USERCODE_REGISTER" |
+				"BOUNDARY_REGISTER",
+				..,
+			) => {
+				self.diagnostics.push(StringDiagnostic::new(
+					vhdl::E0005,
+					format!("`{name}` has the wrong kind of value"),
+					Some(span),
+				));
+			},
+			_ => {},
+		}
+	}
+
+	/// Parse the contents of an attribute string with `parse`, reporting any error in it
+	fn parse_bsdl_value<T, P>(&mut self, string: &BsdlString, parse: P) -> Vec<Spanned<T>>
+	where
+		P: FnOnce(&mut ValueParser<'_>) -> Result<(), (Range<usize>, String)>,
+		T: ValueItem,
+	{
+		let mut parser = ValueParser {
+			string,
+			words: lex(&string.text),
+			position: 0,
+			items: Vec::new(),
+		};
+
+		if let Err((range, message)) = parse(&mut parser) {
+			self.diagnostics.push(StringDiagnostic::new(
+				vhdl::E0005,
+				message,
+				Some(string.span(range)),
+			));
+		}
+
+		T::take(parser.items)
+	}
+
+	/// Parse a string literal, or several joined by `&`
+	fn parse_bsdl_string(&mut self) -> ParseResult<(BsdlString, Span)> {
+		let start = self.span();
+		let mut string = BsdlString { text: String::new(), pieces: Vec::new() };
+
+		loop {
+			let Some(Token::String(text)) = self.peek() else {
+				return self.unexpected("a string");
+			};
+
+			string.pieces.push((string.text.len(), self.span()));
+			string.text.push_str(&String::from_utf8_lossy(text));
+			self.bump();
+
+			if !self.eat_operator(Operator::Ampersand) {
+				break;
+			}
+		}
+
+		Ok((string, self.span_from(start)))
+	}
+
+	/// A name, which may be a BSDL reserved word such as `BC_1`
+	fn expect_word(&mut self) -> ParseResult<Spanned<String>> {
+		match self.peek() {
+			Some(Token::Identifier(_) | Token::Keyword(_)) => {
+				let span = self.span();
+				let text = String::from_utf8_lossy(&self.source_text(&span)).into_owned();
+				self.bump();
+
+				Ok(Spanned::new(text, span))
+			},
+			_ => self.unexpected("a name"),
+		}
+	}
+
+	fn expect_word_of(&mut self, word: &str) -> ParseResult<()> {
+		if self.is_word(word) {
+			self.bump();
+			Ok(())
+		} else {
+			self.unexpected(&format!("`{word}`"))
+		}
+	}
+
+	fn expect_bsdl_integer(&mut self) -> ParseResult<Spanned<u64>> {
+		let span = self.span();
+		match self.peek() {
+			Some(Token::Number(text)) => match String::from_utf8_lossy(text).parse() {
+				Ok(value) => {
+					self.bump();
+					Ok(Spanned::new(value, span))
+				},
+				Err(_) => self.unexpected("an integer"),
+			},
+			_ => self.unexpected("an integer"),
+		}
+	}
+
+	/// Skip tokens up to and including the next `;` outside of any parentheses
+	fn skip_bsdl_statement(&mut self) {
+		let mut depth = 0usize;
+
+		while let Some(token) = self.peek() {
+			match token {
+				Token::Control(Control::ParenOpen) => depth += 1,
+				Token::Control(Control::ParenClose) => depth = depth.saturating_sub(1),
+				Token::Control(Control::Semicolon) if depth == 0 => {
+					self.bump();
+					return;
+				},
+				_ => {},
+			}
+
+			self.bump();
+		}
+	}
+}
+
+/// The contents of one or more concatenated string literals
+struct BsdlString {
+	text:   String,
+	/// The offset into `text` where each string literal starts, and the span of the literal
+	pieces: Vec<(usize, Span)>,
+}
+
+impl BsdlString {
+	/// The span in the source of the given range of the text
+	fn span(&self, range: Range<usize>) -> Span {
+		let locate = |offset: usize| {
+			self.pieces
+				.iter()
+				.rev()
+				.find(|(start, _)| *start <= offset)
+				.map(|(start, span)| (offset - start, *span))
+		};
+
+		let Some((begin, span)) = locate(range.start) else {
+			return Span::empty();
+		};
+		let end = locate(range.end.saturating_sub(1).max(range.start))
+			.map_or(*span.end(), |(end, span)| {
+				*span.begin() + 1 + end as u32 + 1
+			});
+
+		// The string contents start after the opening quotation mark
+		let begin = *span.begin() + 1 + begin as u32;
+		let position = span.get_position();
+
+		Span::new(
+			begin,
+			end.max(begin),
+			*position.line(),
+			*position.character() + (begin - *span.begin()),
+		)
+	}
+
+	/// The text as a pattern of bits, ignoring any whitespace
+	fn pattern(&self) -> Spanned<String> {
+		let text = self
+			.text
+			.chars()
+			.filter(|chr| !chr.is_whitespace())
+			.collect();
+
+		Spanned::new(text, self.span(0..self.text.len()))
+	}
+}
+
+/// A word or punctuation character in an attribute string
+#[derive(Clone, Debug, PartialEq)]
+enum Word {
+	Name(String),
+	Punctuation(char),
+}
+
+/// Split an attribute string into words, along with their ranges in the string
+fn lex(text: &str) -> Vec<(Range<usize>, Word)> {
+	let mut words = Vec::new();
+	let mut chars = text.char_indices().peekable();
+
+	while let Some((start, chr)) = chars.next() {
+		if chr.is_whitespace() {
+			continue;
+		}
+
+		if !is_word_char(chr) {
+			words.push((start..start + chr.len_utf8(), Word::Punctuation(chr)));
+			continue;
+		}
+
+		let mut end = start + chr.len_utf8();
+		while let Some((idx, chr)) = chars.peek().copied() &&
+			is_word_char(chr)
+		{
+			end = idx + chr.len_utf8();
+			chars.next();
+		}
+
+		words.push((start..end, Word::Name(text[start..end].to_string())));
+	}
+
+	words
+}
+
+fn is_word_char(chr: char) -> bool {
+	chr.is_ascii_alphanumeric() || chr == '_' || chr == '.'
+}
+
+/// The items parsed out of an attribute string
+enum ValueItems {
+	PinMap(Vec<Spanned<PinMapEntry>>),
+	Instructions(Vec<Spanned<Instruction>>),
+	Names(Vec<Spanned<String>>),
+	Cells(Vec<Spanned<BoundaryCell>>),
+}
+
+/// Something that can be parsed out of an attribute string
+trait ValueItem: Sized {
+	fn take(items: Vec<ValueItems>) -> Vec<Spanned<Self>>;
+}
+
+macro_rules! value_item {
+	($type:ty, $variant:ident) => {
+		impl ValueItem for $type {
+			fn take(items: Vec<ValueItems>) -> Vec<Spanned<Self>> {
+				items
+					.into_iter()
+					.flat_map(|items| match items {
+						ValueItems::$variant(items) => items,
+						_ => Vec::new(),
+					})
+					.collect()
+			}
+		}
+	};
+}
+
+value_item!(PinMapEntry, PinMap);
+value_item!(Instruction, Instructions);
+value_item!(String, Names);
+value_item!(BoundaryCell, Cells);
+
+/// Parses the contents of an attribute string
+struct ValueParser<'a> {
+	string:   &'a BsdlString,
+	words:    Vec<(Range<usize>, Word)>,
+	position: usize,
+	items:    Vec<ValueItems>,
+}
+
+type ValueResult<T> = Result<T, (Range<usize>, String)>;
+
+impl ValueParser<'_> {
+	fn at_end(&self) -> bool {
+		self.position >= self.words.len()
+	}
+
+	/// The range of the current word, or an empty range at the end of the string
+	fn range(&self) -> Range<usize> {
+		match self.words.get(self.position) {
+			Some((range, _)) => range.clone(),
+			None => self.string.text.len()..self.string.text.len(),
+		}
+	}
+
+	fn spanned<T>(&self, inner: T, range: Range<usize>) -> Spanned<T> {
+		Spanned::new(inner, self.string.span(range))
+	}
+
+	/// The range from the start of `start` to the end of the previous word
+	fn range_from(&self, start: Range<usize>) -> Range<usize> {
+		let end = self
+			.position
+			.checked_sub(1)
+			.and_then(|idx| self.words.get(idx))
+			.map_or(start.end, |(range, _)| range.end);
+
+		start.start..end.max(start.start)
+	}
+
+	fn is_punctuation(&self, chr: char) -> bool {
+		matches!(self.words.get(self.position), Some((_, Word::Punctuation(punct))) if *punct == chr)
+	}
+
+	fn eat_punctuation(&mut self, chr: char) -> bool {
+		if self.is_punctuation(chr) {
+			self.position += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	fn expect_punctuation(&mut self, chr: char) -> ValueResult<()> {
+		if self.eat_punctuation(chr) {
+			Ok(())
+		} else {
+			self.unexpected(&format!("`{chr}`"))
+		}
+	}
+
+	fn expect_name(&mut self, expected: &str) -> ValueResult<Spanned<String>> {
+		match self.words.get(self.position) {
+			Some((range, Word::Name(name))) => {
+				let name = self.spanned(name.clone(), range.clone());
+				self.position += 1;
+				Ok(name)
+			},
+			_ => self.unexpected(expected),
+		}
+	}
+
+	fn expect_number(&mut self) -> ValueResult<Spanned<u32>> {
+		let range = self.range();
+		let name = self.expect_name("a number")?;
+
+		match name.inner().parse() {
+			Ok(number) => Ok(self.spanned(number, range)),
+			Err(_) => Err((
+				range,
+				format!("Expected a number, found `{}`", name.inner()),
+			)),
+		}
+	}
+
+	fn unexpected<T>(&self, expected: &str) -> ValueResult<T> {
+		let message = match self.words.get(self.position) {
+			Some((_, Word::Name(name))) => format!("Expected {expected}, found `{name}`"),
+			Some((_, Word::Punctuation(chr))) => format!("Expected {expected}, found `{chr}`"),
+			None => format!("Expected {expected}, found the end of the string"),
+		};
+
+		Err((self.range(), message))
+	}
+
+	/// Parse a `,` separated list of `parse`
+	fn list<F>(&mut self, mut parse: F) -> ValueResult<()>
+	where
+		F: FnMut(&mut Self) -> ValueResult<()>,
+	{
+		while !self.at_end() {
+			parse(self)?;
+
+			if !self.eat_punctuation(',') {
+				break;
+			}
+		}
+
+		if self.at_end() {
+			Ok(())
+		} else {
+			self.unexpected("`,`")
+		}
+	}
+}
+
+/// `port : pin, bus : (pin, pin), ...`
+///
+/// IEEE 1149.1-2013 § B.8.6
+fn parse_pin_map(parser: &mut ValueParser<'_>) -> ValueResult<()> {
+	let mut entries = Vec::new();
+
+	let result = parser.list(|parser| {
+		let start = parser.range();
+		let port = parser.expect_name("a port")?;
+		parser.expect_punctuation(':')?;
+
+		let mut pins = Vec::new();
+		if parser.eat_punctuation('(') {
+			pins.push(parser.expect_name("a pin")?);
+			while parser.eat_punctuation(',') {
+				pins.push(parser.expect_name("a pin")?);
+			}
+			parser.expect_punctuation(')')?;
+		} else {
+			pins.push(parser.expect_name("a pin")?);
+		}
+
+		entries.push(parser.spanned(PinMapEntry { port, pins }, parser.range_from(start)));
+		Ok(())
+	});
+
+	parser.items.push(ValueItems::PinMap(entries));
+	result
+}
+
+/// `name (opcode, opcode), ...`
+///
+/// IEEE 1149.1-2013 § B.8.11.3
+fn parse_opcodes(parser: &mut ValueParser<'_>) -> ValueResult<()> {
+	let mut instructions = Vec::new();
+
+	let result = parser.list(|parser| {
+		let start = parser.range();
+		let name = parser.expect_name("an instruction")?;
+		parser.expect_punctuation('(')?;
+
+		let mut opcodes = vec![parser.expect_name("an opcode")?];
+		while parser.eat_punctuation(',') {
+			opcodes.push(parser.expect_name("an opcode")?);
+		}
+		parser.expect_punctuation(')')?;
+
+		instructions.push(parser.spanned(Instruction { name, opcodes }, parser.range_from(start)));
+		Ok(())
+	});
+
+	parser.items.push(ValueItems::Instructions(instructions));
+	result
+}
+
+/// `name, name, ...`
+///
+/// IEEE 1149.1-2013 § B.8.11.4
+fn parse_names(parser: &mut ValueParser<'_>) -> ValueResult<()> {
+	let mut names = Vec::new();
+
+	let result = parser.list(|parser| {
+		names.push(parser.expect_name("an instruction")?);
+		Ok(())
+	});
+
+	parser.items.push(ValueItems::Names(names));
+	result
+}
+
+/// `number (cell, port, function, safe [, control, disable, result]), ...`
+///
+/// IEEE 1149.1-2013 § B.10.2
+fn parse_boundary_register(parser: &mut ValueParser<'_>) -> ValueResult<()> {
+	let mut cells = Vec::new();
+
+	let result = parser.list(|parser| {
+		let start = parser.range();
+		let number = parser.expect_number()?;
+		parser.expect_punctuation('(')?;
+
+		// The cell may be qualified with the package it is from
+		let cell = parser.expect_name("a cell")?;
+		let (cell, cell_span) = cell.split();
+		let cell = Spanned::new(
+			cell.rsplit('.').next().unwrap_or(&cell).to_string(),
+			cell_span,
+		);
+		parser.expect_punctuation(',')?;
+
+		let port = if parser.eat_punctuation('*') {
+			None
+		} else {
+			let port_start = parser.range();
+			let port = parser.expect_name("a port")?;
+
+			// An element of a `bit_vector` port
+			if parser.eat_punctuation('(') {
+				let index = parser.expect_number()?;
+				parser.expect_punctuation(')')?;
+
+				Some(parser.spanned(
+					format!("{}({})", port.inner(), index.inner()),
+					parser.range_from(port_start),
+				))
+			} else {
+				Some(port)
+			}
+		};
+		parser.expect_punctuation(',')?;
+
+		let function_range = parser.range();
+		let function = parser.expect_name("a cell function")?;
+		let function = CellFunction::from_name(function.inner()).ok_or_else(|| {
+			(
+				function_range,
+				format!("`{}` is not a cell function", function.inner()),
+			)
+		})?;
+		parser.expect_punctuation(',')?;
+
+		let safe = parse_safe(parser)?;
+
+		let control = if parser.eat_punctuation(',') {
+			let cell = parser.expect_number()?;
+			parser.expect_punctuation(',')?;
+
+			let disable_range = parser.range();
+			let disable = match parser.expect_name("0 or 1")?.inner().as_str() {
+				"0" => false,
+				"1" => true,
+				value => {
+					return Err((disable_range, format!("Expected 0 or 1, found `{value}`")));
+				},
+			};
+			parser.expect_punctuation(',')?;
+
+			let result_range = parser.range();
+			let result = parser.expect_name("a disable result")?;
+			let result = DisableResult::from_name(result.inner()).ok_or_else(|| {
+				(
+					result_range,
+					format!("`{}` is not a disable result", result.inner()),
+				)
+			})?;
+
+			Some(CellControl { cell, disable, result })
+		} else {
+			None
+		};
+		parser.expect_punctuation(')')?;
+
+		cells.push(parser.spanned(
+			BoundaryCell { number, cell, port, function, safe, control },
+			parser.range_from(start),
+		));
+		Ok(())
+	});
+
+	parser.items.push(ValueItems::Cells(cells));
+	result
+}
+
+/// IEEE 1149.1-2013 § B.10.2
+fn parse_safe(parser: &mut ValueParser<'_>) -> ValueResult<SafeValue> {
+	let range = parser.range();
+
+	match parser
+		.expect_name("0, 1, or X")?
+		.inner()
+		.to_ascii_uppercase()
+		.as_str()
+	{
+		"0" => Ok(SafeValue::Zero),
+		"1" => Ok(SafeValue::One),
+		"X" => Ok(SafeValue::DontCare),
+		value => Err((range, format!("Expected 0, 1, or X, found `{value}`"))),
+	}
+}
@@ -0,0 +1,1032 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{
+		AliasDeclaration, Association, AttributeSpecification, BindingIndication,
+		ComponentDeclaration, ComponentSpecification, ConfigurationSpecification, Declaration,
+		Designator, ElementDeclaration, EntityAspect, EntityClass, EntityNameList,
+		EnumerationLiteral, Expression, FileOpenInformation, Identifier, IndexSubtype,
+		InstantiationList, InstantiationTargets, InterfaceDeclaration, InterfaceObject,
+		InterfacePackageGenerics, Mode, Name, ObjectClass, ObjectDeclaration, PhysicalUnits,
+		SecondaryUnit, SignalKind, SubprogramBody, SubprogramDefault, SubprogramInstance,
+		SubprogramKind, SubprogramSpecification, TypeDeclaration, TypeDefinition,
+	},
+	parser::{
+		VHDL_2K_STDS, VHDL_08_STDS, VHDL_19_STDS, VHDL_93_STDS, VhdlParser, error::ParseResult,
+	},
+	tokenizer::token::{Control, Keyword, Operator, Token},
+};
+
+impl VhdlParser {
+	/// Parse the declarations of a declarative part up to one of the `ends` keywords
+	pub(crate) fn parse_declarations(
+		&mut self,
+		ends: &[Keyword],
+	) -> (Vec<Spanned<Declaration>>, bool) {
+		self.parse_list(ends, Self::parse_declaration, || Declaration::Error)
+	}
+
+	/// IEEE 1076-2008 § 3.2.3, § 3.3.2, § 4.3, § 4.7, § 4.8, § 11.2, § 11.3, IEEE 1076.1-2007 § 4
+	pub(crate) fn parse_declaration(&mut self) -> ParseResult<Spanned<Declaration>> {
+		let start = self.span();
+
+		let declaration = match self.peek() {
+			Some(Token::Keyword(Keyword::Type)) => {
+				Declaration::Type(self.parse_type_declaration()?)
+			},
+			Some(Token::Keyword(Keyword::Subtype)) => {
+				self.bump();
+				let name = self.expect_identifier()?;
+				self.expect_keyword(Keyword::Is)?;
+				let subtype = self.parse_subtype_indication()?;
+				self.expect_control(Control::Semicolon)?;
+
+				Declaration::Subtype { name, subtype }
+			},
+			Some(Token::Keyword(
+				Keyword::Constant |
+				Keyword::Signal |
+				Keyword::Variable |
+				Keyword::Shared |
+				Keyword::File,
+			)) => Declaration::Object(self.parse_object_declaration()?),
+			Some(Token::Keyword(Keyword::Alias)) => {
+				Declaration::Alias(self.parse_alias_declaration()?)
+			},
+			Some(Token::Keyword(Keyword::Component)) => {
+				Declaration::Component(self.parse_component_declaration()?)
+			},
+			Some(Token::Keyword(Keyword::Attribute)) if self.is_control_at(2, Control::Colon) => {
+				self.bump();
+				let name = self.expect_identifier()?;
+				self.expect_control(Control::Colon)?;
+				let type_mark = self.parse_type_mark()?;
+				self.expect_control(Control::Semicolon)?;
+
+				Declaration::Attribute { name, type_mark }
+			},
+			Some(Token::Keyword(Keyword::Attribute)) => {
+				Declaration::AttributeSpecification(self.parse_attribute_specification()?)
+			},
+			Some(Token::Keyword(
+				Keyword::Function | Keyword::Procedure | Keyword::Pure | Keyword::Impure,
+			)) => self.parse_subprogram()?,
+			Some(Token::Keyword(Keyword::Use)) => {
+				self.bump();
+				let names = self.parse_selected_names()?;
+				self.expect_control(Control::Semicolon)?;
+
+				Declaration::Use(names)
+			},
+			Some(Token::Keyword(Keyword::For)) => {
+				Declaration::ConfigurationSpecification(self.parse_configuration_specification()?)
+			},
+			Some(Token::Keyword(Keyword::Disconnect)) => self.parse_disconnect()?,
+			Some(Token::Keyword(Keyword::Package)) => {
+				self.require_std(VHDL_08_STDS, start, "Nested packages");
+
+				if self.is_keyword_at(1, Keyword::Body) {
+					Declaration::PackageBody(self.parse_package_body()?)
+				} else {
+					self.parse_package()?
+				}
+			},
+			Some(Token::Keyword(Keyword::Nature)) => {
+				Declaration::Nature(self.parse_nature_declaration()?)
+			},
+			Some(Token::Keyword(Keyword::SubNature)) => {
+				self.bump();
+				let name = self.expect_identifier()?;
+				self.expect_keyword(Keyword::Is)?;
+				let subnature = self.parse_subnature_indication()?;
+				self.expect_control(Control::Semicolon)?;
+
+				Declaration::Subnature { name, subnature }
+			},
+			Some(Token::Keyword(Keyword::Terminal)) => self.parse_terminal_declaration()?,
+			Some(Token::Keyword(Keyword::Quantity)) => {
+				Declaration::Quantity(self.parse_quantity_declaration()?)
+			},
+			Some(Token::Keyword(Keyword::Limit)) => self.parse_step_limit()?,
+			_ if self.is_psl_declaration_start() => Declaration::Psl(self.parse_psl_declaration()?),
+			_ => return self.unexpected("a declaration"),
+		};
+
+		Ok(Spanned::new(declaration, self.span_from(start)))
+	}
+
+	/// Parse a `generic (...);` clause, if there is one
+	///
+	/// IEEE 1076-2008 § 6.5.6.2
+	pub(crate) fn parse_generic_clause(
+		&mut self,
+	) -> ParseResult<Option<Vec<Spanned<InterfaceDeclaration>>>> {
+		if !self.is_keyword(Keyword::Generic) || !self.is_control_at(1, Control::ParenOpen) {
+			return Ok(None);
+		}
+
+		self.bump();
+		let generics = self.parse_interface_list()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(Some(generics))
+	}
+
+	/// Parse a `port (...);` clause, if there is one
+	///
+	/// IEEE 1076-2008 § 6.5.6.3
+	pub(crate) fn parse_port_clause(
+		&mut self,
+	) -> ParseResult<Option<Vec<Spanned<InterfaceDeclaration>>>> {
+		if !self.is_keyword(Keyword::Port) || !self.is_control_at(1, Control::ParenOpen) {
+			return Ok(None);
+		}
+
+		self.bump();
+		let ports = self.parse_interface_list()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(Some(ports))
+	}
+
+	/// Parse a `generic map (...)`, if there is one
+	///
+	/// IEEE 1076-2008 § 6.5.7.2
+	pub(crate) fn parse_generic_map(&mut self) -> ParseResult<Option<Vec<Spanned<Association>>>> {
+		if !self.is_keyword(Keyword::Generic) || !self.is_keyword_at(1, Keyword::Map) {
+			return Ok(None);
+		}
+
+		self.bump();
+		self.bump();
+
+		Ok(Some(self.parse_association_list()?))
+	}
+
+	/// Parse a `port map (...)`, if there is one
+	///
+	/// IEEE 1076-2008 § 6.5.7.3
+	pub(crate) fn parse_port_map(&mut self) -> ParseResult<Option<Vec<Spanned<Association>>>> {
+		if !self.is_keyword(Keyword::Port) || !self.is_keyword_at(1, Keyword::Map) {
+			return Ok(None);
+		}
+
+		self.bump();
+		self.bump();
+
+		Ok(Some(self.parse_association_list()?))
+	}
+
+	/// IEEE 1076-2008 § 6.5.6.1
+	pub(crate) fn parse_interface_list(
+		&mut self,
+	) -> ParseResult<Vec<Spanned<InterfaceDeclaration>>> {
+		self.expect_control(Control::ParenOpen)?;
+
+		let mut declarations = Vec::new();
+		loop {
+			declarations.push(self.parse_interface_declaration()?);
+
+			let semicolon = self.span();
+			if !self.eat_control(Control::Semicolon) {
+				break;
+			}
+
+			if self.is_control(Control::ParenClose) {
+				self.require_std(
+					VHDL_19_STDS,
+					semicolon,
+					"Trailing semicolons in interface lists",
+				);
+				break;
+			}
+		}
+
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(declarations)
+	}
+
+	/// IEEE 1076-2008 § 6.5.1
+	fn parse_interface_declaration(&mut self) -> ParseResult<Spanned<InterfaceDeclaration>> {
+		let start = self.span();
+
+		let declaration = match self.peek() {
+			Some(Token::Keyword(Keyword::Type)) => {
+				self.require_std(VHDL_08_STDS, start, "Generic types");
+				self.bump();
+
+				InterfaceDeclaration::Type(self.expect_identifier()?)
+			},
+			Some(Token::Keyword(
+				Keyword::Function | Keyword::Procedure | Keyword::Pure | Keyword::Impure,
+			)) => {
+				self.require_std(VHDL_08_STDS, start, "Generic subprograms");
+				let specification = self.parse_subprogram_specification()?;
+
+				let default = if self.eat_keyword(Keyword::Is) {
+					let start = self.span();
+					let default = if self.eat_control(Control::Box) {
+						SubprogramDefault::Box
+					} else {
+						SubprogramDefault::Name(self.parse_name()?.as_inner())
+					};
+
+					Some(Spanned::new(default, self.span_from(start)))
+				} else {
+					None
+				};
+
+				InterfaceDeclaration::Subprogram { specification, default }
+			},
+			Some(Token::Keyword(Keyword::Package)) => {
+				self.require_std(VHDL_08_STDS, start, "Generic packages");
+				self.bump();
+
+				let name = self.expect_identifier()?;
+				self.expect_keyword(Keyword::Is)?;
+				self.expect_keyword(Keyword::New)?;
+				let package = self.parse_type_mark()?;
+				self.expect_keyword(Keyword::Generic)?;
+				self.expect_keyword(Keyword::Map)?;
+
+				let generic_map = if self.is_control_at(1, Control::Box) {
+					self.bump();
+					self.bump();
+					self.expect_control(Control::ParenClose)?;
+					InterfacePackageGenerics::Box
+				} else if self.is_keyword_at(1, Keyword::Default) {
+					self.bump();
+					self.bump();
+					self.expect_control(Control::ParenClose)?;
+					InterfacePackageGenerics::Default
+				} else {
+					InterfacePackageGenerics::Associations(self.parse_association_list()?)
+				};
+
+				InterfaceDeclaration::Package { name, package, generic_map }
+			},
+			Some(Token::Keyword(Keyword::Terminal)) => {
+				self.bump();
+				let names = self.parse_identifier_list()?;
+				self.expect_control(Control::Colon)?;
+				let nature = self.parse_subnature_indication()?;
+
+				InterfaceDeclaration::Terminal { names, nature }
+			},
+			_ => InterfaceDeclaration::Object(self.parse_interface_object()?),
+		};
+
+		Ok(Spanned::new(declaration, self.span_from(start)))
+	}
+
+	/// IEEE 1076-2008 § 6.5.2
+	fn parse_interface_object(&mut self) -> ParseResult<InterfaceObject> {
+		let class = self.parse_object_class();
+		let names = self.parse_identifier_list()?;
+		self.expect_control(Control::Colon)?;
+
+		let mode = match self.peek() {
+			Some(Token::Keyword(Keyword::In)) => Some(Mode::In),
+			Some(Token::Keyword(Keyword::Out)) => Some(Mode::Out),
+			Some(Token::Keyword(Keyword::InOut)) => Some(Mode::InOut),
+			Some(Token::Keyword(Keyword::Buffer)) => Some(Mode::Buffer),
+			Some(Token::Keyword(Keyword::Linkage)) => Some(Mode::Linkage),
+			_ => None,
+		};
+		if mode.is_some() {
+			self.bump();
+		}
+
+		let subtype = self.parse_subtype_indication()?;
+		let bus = self.eat_keyword(Keyword::Bus);
+		let default = self.parse_default_value()?;
+
+		Ok(InterfaceObject { class, names, mode, subtype, bus, default })
+	}
+
+	/// Parse an explicit object class keyword, if there is one
+	fn parse_object_class(&mut self) -> Option<ObjectClass> {
+		let class = match self.peek() {
+			Some(Token::Keyword(Keyword::Constant)) => ObjectClass::Constant,
+			Some(Token::Keyword(Keyword::Signal)) => ObjectClass::Signal,
+			Some(Token::Keyword(Keyword::Variable)) => ObjectClass::Variable,
+			Some(Token::Keyword(Keyword::File)) => ObjectClass::File,
+			Some(Token::Keyword(Keyword::Quantity)) => ObjectClass::Quantity,
+			_ => return None,
+		};
+
+		self.bump();
+		Some(class)
+	}
+
+	/// Parse a `:= expression` default value, if there is one
+	fn parse_default_value(&mut self) -> ParseResult<Option<Spanned<Expression>>> {
+		if self.eat_control(Control::VariableAssignment) {
+			Ok(Some(self.parse_expression()?))
+		} else {
+			Ok(None)
+		}
+	}
+
+	/// Parse a comma separated list of identifiers
+	pub(crate) fn parse_identifier_list(&mut self) -> ParseResult<Vec<Spanned<Identifier>>> {
+		let mut names = vec![self.expect_identifier()?];
+		while self.eat_control(Control::Comma) {
+			names.push(self.expect_identifier()?);
+		}
+
+		Ok(names)
+	}
+
+	/// IEEE 1076-2008 § 6.2
+	fn parse_type_declaration(&mut self) -> ParseResult<TypeDeclaration> {
+		self.expect_keyword(Keyword::Type)?;
+		let name = self.expect_identifier()?;
+
+		// Incomplete type declaration
+		if self.eat_control(Control::Semicolon) {
+			return Ok(TypeDeclaration { name, definition: None });
+		}
+
+		self.expect_keyword(Keyword::Is)?;
+
+		let start = self.span();
+		let definition = match self.peek() {
+			Some(Token::Control(Control::ParenOpen)) => {
+				self.bump();
+
+				let mut literals = Vec::new();
+				loop {
+					let start = self.span();
+					let literal = match self.peek() {
+						Some(Token::Character(character)) => {
+							let literal = EnumerationLiteral::Character(character.clone());
+							self.bump();
+							literal
+						},
+						_ => EnumerationLiteral::Identifier(self.expect_identifier()?.as_inner()),
+					};
+					literals.push(Spanned::new(literal, self.span_from(start)));
+
+					if !self.eat_control(Control::Comma) {
+						break;
+					}
+				}
+
+				self.expect_control(Control::ParenClose)?;
+				TypeDefinition::Enumeration(literals)
+			},
+			Some(Token::Keyword(Keyword::Range)) => {
+				self.bump();
+
+				let range_start = self.span();
+				let range = self.parse_range()?;
+				let range = Spanned::new(range, self.span_from(range_start));
+
+				let units = if self.is_keyword(Keyword::Units) {
+					Some(self.parse_physical_units(&name)?)
+				} else {
+					None
+				};
+
+				TypeDefinition::Range { range, units }
+			},
+			Some(Token::Keyword(Keyword::Array)) => {
+				self.bump();
+				self.expect_control(Control::ParenOpen)?;
+
+				let mut indices = vec![self.parse_index_subtype()?];
+				while self.eat_control(Control::Comma) {
+					indices.push(self.parse_index_subtype()?);
+				}
+
+				self.expect_control(Control::ParenClose)?;
+				self.expect_keyword(Keyword::Of)?;
+				let element = self.parse_subtype_indication()?;
+
+				TypeDefinition::Array { indices, element }
+			},
+			Some(Token::Keyword(Keyword::Record)) => {
+				self.bump();
+
+				let mut elements = Vec::new();
+				while !self.is_keyword(Keyword::End) {
+					let start = self.span();
+					let names = self.parse_identifier_list()?;
+					self.expect_control(Control::Colon)?;
+					let subtype = self.parse_subtype_indication()?;
+					self.expect_control(Control::Semicolon)?;
+
+					elements.push(Spanned::new(
+						ElementDeclaration { names, subtype },
+						self.span_from(start),
+					));
+				}
+
+				self.expect_keyword(Keyword::End)?;
+				self.expect_keyword(Keyword::Record)?;
+				self.parse_closing_name(&name)?;
+
+				TypeDefinition::Record(elements)
+			},
+			Some(Token::Keyword(Keyword::Access)) => {
+				self.bump();
+				TypeDefinition::Access(self.parse_subtype_indication()?)
+			},
+			Some(Token::Keyword(Keyword::File)) => {
+				self.bump();
+				self.expect_keyword(Keyword::Of)?;
+				TypeDefinition::File(self.parse_type_mark()?)
+			},
+			Some(Token::Keyword(Keyword::Protected)) => {
+				self.require_std(VHDL_2K_STDS, start, "Protected types");
+				self.bump();
+
+				let body = self.eat_keyword(Keyword::Body);
+				let (declarations, closed) = self.parse_declarations(&[Keyword::End]);
+				if closed {
+					self.expect_keyword(Keyword::End)?;
+					self.expect_keyword(Keyword::Protected)?;
+					if body {
+						self.expect_keyword(Keyword::Body)?;
+					}
+					self.parse_closing_name(&name)?;
+				}
+
+				if body {
+					TypeDefinition::ProtectedBody(declarations)
+				} else {
+					TypeDefinition::Protected(declarations)
+				}
+			},
+			_ => return self.unexpected("a type definition"),
+		};
+		let definition = Spanned::new(definition, self.span_from(start));
+
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(TypeDeclaration { name, definition: Some(definition) })
+	}
+
+	/// Parse the optional name that follows the closing keywords of a type definition, which was
+	/// added in IEEE 1076-1993
+	pub(crate) fn parse_closing_name(&mut self, name: &Spanned<Identifier>) -> ParseResult<()> {
+		if !self.is_identifier() {
+			return Ok(());
+		}
+
+		let start = self.span();
+		self.parse_end_label(Some(name))?;
+		self.require_std(VHDL_93_STDS, start, "Names after `end` in type definitions");
+
+		Ok(())
+	}
+
+	/// IEEE 1076-2008 § 5.2.4.1
+	fn parse_physical_units(&mut self, name: &Spanned<Identifier>) -> ParseResult<PhysicalUnits> {
+		self.expect_keyword(Keyword::Units)?;
+
+		let primary = self.expect_identifier()?;
+		self.expect_control(Control::Semicolon)?;
+
+		let mut secondary = Vec::new();
+		while !self.is_keyword(Keyword::End) {
+			let start = self.span();
+			let name = self.expect_identifier()?;
+			self.expect_operator(Operator::Equals)?;
+			let value = self.parse_expression()?;
+			self.expect_control(Control::Semicolon)?;
+
+			secondary.push(Spanned::new(
+				SecondaryUnit { name, value },
+				self.span_from(start),
+			));
+		}
+
+		self.expect_keyword(Keyword::End)?;
+		self.expect_keyword(Keyword::Units)?;
+		self.parse_closing_name(name)?;
+
+		Ok(PhysicalUnits { primary, secondary })
+	}
+
+	/// Parse either an unconstrained `type_mark range <>` index, or a constrained index
+	///
+	/// IEEE 1076-2008 § 5.3.2.1
+	pub(crate) fn parse_index_subtype(&mut self) -> ParseResult<Spanned<IndexSubtype>> {
+		let start = self.span();
+		let checkpoint = self.checkpoint();
+
+		if let Ok(type_mark) = self.parse_type_mark() &&
+			self.eat_keyword(Keyword::Range) &&
+			self.eat_control(Control::Box)
+		{
+			return Ok(Spanned::new(
+				IndexSubtype::Unconstrained(type_mark),
+				self.span_from(start),
+			));
+		}
+
+		self.restore(checkpoint);
+		let range = self.parse_discrete_range()?;
+
+		Ok(Spanned::new(
+			IndexSubtype::Constrained(range.as_inner()),
+			self.span_from(start),
+		))
+	}
+
+	/// IEEE 1076-2008 § 6.4.2
+	fn parse_object_declaration(&mut self) -> ParseResult<ObjectDeclaration> {
+		let shared_span = self.span();
+		let shared = self.eat_keyword(Keyword::Shared);
+		if shared {
+			self.require_std(VHDL_93_STDS, shared_span, "Shared variables");
+		}
+
+		let class = match self.parse_object_class() {
+			Some(class) if !shared || class == ObjectClass::Variable => class,
+			_ => return self.unexpected("`variable`"),
+		};
+
+		let names = self.parse_identifier_list()?;
+		self.expect_control(Control::Colon)?;
+		let subtype = self.parse_subtype_indication()?;
+
+		let signal_kind = match self.peek() {
+			Some(Token::Keyword(Keyword::Register)) if class == ObjectClass::Signal => {
+				Some(SignalKind::Register)
+			},
+			Some(Token::Keyword(Keyword::Bus)) if class == ObjectClass::Signal => {
+				Some(SignalKind::Bus)
+			},
+			_ => None,
+		};
+		if signal_kind.is_some() {
+			self.bump();
+		}
+
+		let default = self.parse_default_value()?;
+
+		let file_open = if class == ObjectClass::File {
+			self.parse_file_open_information()?
+		} else {
+			None
+		};
+
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(ObjectDeclaration {
+			class,
+			shared,
+			names,
+			subtype,
+			signal_kind,
+			default,
+			file_open,
+		})
+	}
+
+	/// IEEE 1076-2008 § 6.4.2.5, IEEE 1076-1987 § 4.3.2
+	fn parse_file_open_information(&mut self) -> ParseResult<Option<FileOpenInformation>> {
+		let kind = if self.eat_keyword(Keyword::Open) {
+			Some(self.parse_expression()?)
+		} else {
+			None
+		};
+
+		if !self.eat_keyword(Keyword::Is) {
+			return match kind {
+				Some(_) => self.unexpected("`is`"),
+				None => Ok(None),
+			};
+		}
+
+		// IEEE 1076-1987 style `is in "name"` mode
+		let kind = match self.peek() {
+			Some(Token::Keyword(Keyword::In | Keyword::Out)) if kind.is_none() => {
+				let span = self.span();
+				self.bump();
+
+				let text = self.source_text(&span);
+				Some(Spanned::new(
+					Expression::Name(Name::Simple(Identifier::Basic(text))),
+					span,
+				))
+			},
+			_ => kind,
+		};
+
+		let name = self.parse_expression()?;
+
+		Ok(Some(FileOpenInformation { kind, name }))
+	}
+
+	/// IEEE 1076-2008 § 6.6.1
+	fn parse_alias_declaration(&mut self) -> ParseResult<AliasDeclaration> {
+		self.expect_keyword(Keyword::Alias)?;
+		let designator = self.parse_designator()?;
+
+		let subtype = if self.eat_control(Control::Colon) {
+			Some(self.parse_subtype_indication()?)
+		} else {
+			None
+		};
+
+		self.expect_keyword(Keyword::Is)?;
+		let name = self.parse_name()?;
+		let signature = if self.is_control(Control::BracketOpen) {
+			Some(self.parse_signature()?)
+		} else {
+			None
+		};
+
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(AliasDeclaration { designator, subtype, name, signature })
+	}
+
+	/// Parse an identifier, operator symbol, or character literal designator
+	///
+	/// IEEE 1076-2008 § 4.2.1
+	fn parse_designator(&mut self) -> ParseResult<Spanned<Designator>> {
+		let span = self.span();
+
+		let designator = match self.peek() {
+			Some(Token::String(text)) => Designator::OperatorSymbol(text.clone()),
+			Some(Token::Character(text)) => Designator::Character(text.clone()),
+			_ => {
+				let (ident, span) = self.expect_identifier()?.split();
+				return Ok(Spanned::new(Designator::Identifier(ident), span));
+			},
+		};
+
+		self.bump();
+
+		Ok(Spanned::new(designator, span))
+	}
+
+	/// IEEE 1076-2008 § 6.8
+	fn parse_component_declaration(&mut self) -> ParseResult<ComponentDeclaration> {
+		self.expect_keyword(Keyword::Component)?;
+		let name = self.expect_identifier()?;
+
+		let is = self.span();
+		if self.eat_keyword(Keyword::Is) {
+			self.require_std(VHDL_93_STDS, is, "`is` in component declarations");
+		}
+
+		let generics = self.parse_generic_clause()?;
+		let ports = self.parse_port_clause()?;
+		self.parse_end(&[Keyword::Component], true, Some(&name))?;
+
+		Ok(ComponentDeclaration { name, generics, ports })
+	}
+
+	/// IEEE 1076-2008 § 7.2
+	pub(crate) fn parse_attribute_specification(&mut self) -> ParseResult<AttributeSpecification> {
+		self.expect_keyword(Keyword::Attribute)?;
+		let attribute = self.expect_identifier()?;
+		self.expect_keyword(Keyword::Of)?;
+
+		let entities = if self.eat_keyword(Keyword::Others) {
+			EntityNameList::Others
+		} else if self.eat_keyword(Keyword::All) {
+			EntityNameList::All
+		} else {
+			let mut names = Vec::new();
+			loop {
+				let start = self.span();
+				let designator = self.parse_designator()?.as_inner();
+				let signature = if self.is_control(Control::BracketOpen) {
+					Some(self.parse_signature()?)
+				} else {
+					None
+				};
+				names.push(Spanned::new((designator, signature), self.span_from(start)));
+
+				if !self.eat_control(Control::Comma) {
+					break;
+				}
+			}
+
+			EntityNameList::Names(names)
+		};
+
+		self.expect_control(Control::Colon)?;
+		let class = self.parse_entity_class()?;
+		self.expect_keyword(Keyword::Is)?;
+		let value = self.parse_expression()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(AttributeSpecification { attribute, entities, class, value })
+	}
+
+	/// IEEE 1076-2008 § 7.2
+	fn parse_entity_class(&mut self) -> ParseResult<EntityClass> {
+		let class = match self.peek() {
+			Some(Token::Keyword(Keyword::Entity)) => EntityClass::Entity,
+			Some(Token::Keyword(Keyword::Architecture)) => EntityClass::Architecture,
+			Some(Token::Keyword(Keyword::Configuration)) => EntityClass::Configuration,
+			Some(Token::Keyword(Keyword::Procedure)) => EntityClass::Procedure,
+			Some(Token::Keyword(Keyword::Function)) => EntityClass::Function,
+			Some(Token::Keyword(Keyword::Package)) => EntityClass::Package,
+			Some(Token::Keyword(Keyword::Type)) => EntityClass::Type,
+			Some(Token::Keyword(Keyword::Subtype)) => EntityClass::Subtype,
+			Some(Token::Keyword(Keyword::Constant)) => EntityClass::Constant,
+			Some(Token::Keyword(Keyword::Signal)) => EntityClass::Signal,
+			Some(Token::Keyword(Keyword::Variable)) => EntityClass::Variable,
+			Some(Token::Keyword(Keyword::Component)) => EntityClass::Component,
+			Some(Token::Keyword(Keyword::Label)) => EntityClass::Label,
+			Some(Token::Keyword(Keyword::Literal)) => EntityClass::Literal,
+			Some(Token::Keyword(Keyword::Units)) => EntityClass::Units,
+			Some(Token::Keyword(Keyword::Group)) => EntityClass::Group,
+			Some(Token::Keyword(Keyword::File)) => EntityClass::File,
+			Some(Token::Keyword(Keyword::Property)) => EntityClass::Property,
+			Some(Token::Keyword(Keyword::Sequence)) => EntityClass::Sequence,
+			Some(Token::Keyword(Keyword::View)) => EntityClass::View,
+			Some(Token::Keyword(Keyword::Nature)) => EntityClass::Nature,
+			Some(Token::Keyword(Keyword::SubNature)) => EntityClass::Subnature,
+			Some(Token::Keyword(Keyword::Quantity)) => EntityClass::Quantity,
+			Some(Token::Keyword(Keyword::Terminal)) => EntityClass::Terminal,
+			_ => return self.unexpected("an entity class"),
+		};
+
+		self.bump();
+		Ok(class)
+	}
+
+	/// Parse a subprogram declaration, body, or instantiation
+	///
+	/// IEEE 1076-2008 § 4.2, § 4.3, § 4.4
+	fn parse_subprogram(&mut self) -> ParseResult<Declaration> {
+		let start = self.span();
+		let specification = self.parse_subprogram_specification()?;
+
+		if self.eat_control(Control::Semicolon) {
+			return Ok(Declaration::Subprogram(specification));
+		}
+
+		self.expect_keyword(Keyword::Is)?;
+
+		if self.is_keyword(Keyword::New) {
+			self.require_std(
+				VHDL_08_STDS,
+				self.span_from(start),
+				"Subprogram instantiations",
+			);
+			self.bump();
+
+			let subprogram = self.parse_type_mark()?;
+			let signature = if self.is_control(Control::BracketOpen) {
+				Some(self.parse_signature()?)
+			} else {
+				None
+			};
+			let generic_map = self.parse_generic_map()?;
+			self.expect_control(Control::Semicolon)?;
+
+			return Ok(Declaration::SubprogramInstance(SubprogramInstance {
+				kind: specification.kind,
+				designator: specification.designator,
+				subprogram,
+				signature,
+				generic_map,
+			}));
+		}
+
+		let (declarations, closed) = self.parse_declarations(&[Keyword::Begin]);
+		if !closed {
+			return Ok(Declaration::SubprogramBody(SubprogramBody {
+				specification,
+				declarations,
+				statements: Vec::new(),
+			}));
+		}
+
+		self.expect_keyword(Keyword::Begin)?;
+		let (statements, closed) = self.parse_sequential_statements(&[Keyword::End]);
+
+		if closed {
+			self.expect_keyword(Keyword::End)?;
+
+			let keyword = match specification.kind {
+				SubprogramKind::Procedure => Keyword::Procedure,
+				_ => Keyword::Function,
+			};
+
+			let start = self.span();
+			if self.eat_keyword(keyword) {
+				self.require_std(VHDL_93_STDS, start, "Keywords after `end` in declarations");
+			}
+
+			match specification.designator.inner() {
+				Designator::Identifier(ident) => {
+					let name = Spanned::new(ident.clone(), *specification.designator.span());
+					self.parse_end_label(Some(&name))?;
+				},
+				_ => self.parse_end_label(None)?,
+			}
+
+			self.expect_control(Control::Semicolon)?;
+		}
+
+		Ok(Declaration::SubprogramBody(SubprogramBody {
+			specification,
+			declarations,
+			statements,
+		}))
+	}
+
+	/// IEEE 1076-2008 § 4.2.1
+	pub(crate) fn parse_subprogram_specification(
+		&mut self,
+	) -> ParseResult<SubprogramSpecification> {
+		let start = self.span();
+
+		let kind = match self.peek() {
+			Some(Token::Keyword(Keyword::Procedure)) => {
+				self.bump();
+				SubprogramKind::Procedure
+			},
+			Some(Token::Keyword(Keyword::Pure | Keyword::Impure)) => {
+				let kind = if self.is_keyword(Keyword::Pure) {
+					SubprogramKind::PureFunction
+				} else {
+					SubprogramKind::ImpureFunction
+				};
+
+				self.require_std(VHDL_93_STDS, start, "Function purity");
+				self.bump();
+				self.expect_keyword(Keyword::Function)?;
+				kind
+			},
+			_ => {
+				self.expect_keyword(Keyword::Function)?;
+				SubprogramKind::Function
+			},
+		};
+
+		let designator = self.parse_designator()?;
+
+		let generic_start = self.span();
+		let (generics, generic_map) =
+			if self.is_keyword(Keyword::Generic) && self.is_control_at(1, Control::ParenOpen) {
+				self.bump();
+				let generics = self.parse_interface_list()?;
+				let generic_map = self.parse_generic_map()?;
+				self.require_std(
+					VHDL_08_STDS,
+					self.span_from(generic_start),
+					"Subprogram generics",
+				);
+
+				(Some(generics), generic_map)
+			} else {
+				(None, None)
+			};
+
+		let parameter_span = self.span();
+		if self.eat_keyword(Keyword::Parameter) {
+			self.require_std(VHDL_08_STDS, parameter_span, "`parameter` keywords");
+		}
+
+		let parameters = if self.is_control(Control::ParenOpen) {
+			Some(self.parse_interface_list()?)
+		} else {
+			None
+		};
+
+		// Subprogram instantiations take the return type from the uninstantiated subprogram
+		let return_type = if kind == SubprogramKind::Procedure ||
+			(self.is_keyword(Keyword::Is) && self.is_keyword_at(1, Keyword::New))
+		{
+			None
+		} else {
+			self.expect_keyword(Keyword::Return)?;
+			Some(self.parse_type_mark()?)
+		};
+
+		Ok(SubprogramSpecification {
+			kind,
+			designator,
+			generics,
+			generic_map,
+			parameters,
+			return_type,
+		})
+	}
+
+	/// IEEE 1076-2008 § 7.3.1
+	fn parse_configuration_specification(&mut self) -> ParseResult<ConfigurationSpecification> {
+		self.expect_keyword(Keyword::For)?;
+		let specification = self.parse_component_specification()?;
+		let binding = self.parse_binding_indication()?;
+		self.expect_control(Control::Semicolon)?;
+
+		// IEEE 1076-2008 allows closing configuration specifications with `end for;`
+		if self.is_keyword(Keyword::End) && self.is_keyword_at(1, Keyword::For) {
+			let start = self.span();
+			self.bump();
+			self.bump();
+			self.expect_control(Control::Semicolon)?;
+			self.require_std(
+				VHDL_08_STDS,
+				self.span_from(start),
+				"Closed configuration specifications",
+			);
+		}
+
+		Ok(ConfigurationSpecification { specification, binding })
+	}
+
+	/// IEEE 1076-2008 § 7.3.1
+	pub(crate) fn parse_component_specification(&mut self) -> ParseResult<ComponentSpecification> {
+		let instances = if self.eat_keyword(Keyword::Others) {
+			InstantiationList::Others
+		} else if self.eat_keyword(Keyword::All) {
+			InstantiationList::All
+		} else {
+			InstantiationList::Labels(self.parse_identifier_list()?)
+		};
+
+		self.expect_control(Control::Colon)?;
+		let component = self.parse_type_mark()?;
+
+		Ok(ComponentSpecification { instances, component })
+	}
+
+	/// IEEE 1076-2008 § 7.3.2
+	pub(crate) fn parse_binding_indication(&mut self) -> ParseResult<BindingIndication> {
+		let entity = if self.is_keyword(Keyword::Use) {
+			self.bump();
+			let start = self.span();
+
+			let aspect = match self.peek() {
+				Some(Token::Keyword(Keyword::Entity)) => {
+					self.bump();
+					let name = self.parse_type_mark()?;
+					let architecture = self.parse_architecture_identifier()?;
+
+					EntityAspect::Entity { name, architecture }
+				},
+				Some(Token::Keyword(Keyword::Configuration)) => {
+					self.bump();
+					EntityAspect::Configuration(self.parse_type_mark()?)
+				},
+				Some(Token::Keyword(Keyword::Open)) => {
+					self.bump();
+					EntityAspect::Open
+				},
+				_ => return self.unexpected("`entity`, `configuration`, or `open`"),
+			};
+
+			Some(Spanned::new(aspect, self.span_from(start)))
+		} else {
+			None
+		};
+
+		let generic_map = self.parse_generic_map()?;
+		let port_map = self.parse_port_map()?;
+
+		Ok(BindingIndication { entity, generic_map, port_map })
+	}
+
+	/// Parse the `(architecture)` following an entity name, if there is one
+	pub(crate) fn parse_architecture_identifier(
+		&mut self,
+	) -> ParseResult<Option<Spanned<Identifier>>> {
+		if !self.eat_control(Control::ParenOpen) {
+			return Ok(None);
+		}
+
+		let architecture = self.expect_identifier()?;
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(Some(architecture))
+	}
+
+	/// IEEE 1076-2008 § 7.4
+	fn parse_disconnect(&mut self) -> ParseResult<Declaration> {
+		self.expect_keyword(Keyword::Disconnect)?;
+
+		let signals = if self.eat_keyword(Keyword::Others) {
+			InstantiationTargets::Others
+		} else if self.eat_keyword(Keyword::All) {
+			InstantiationTargets::All
+		} else {
+			InstantiationTargets::Names(self.parse_selected_names()?)
+		};
+
+		self.expect_control(Control::Colon)?;
+		let type_mark = self.parse_type_mark()?;
+		self.expect_keyword(Keyword::After)?;
+		let after = self.parse_expression()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(Declaration::Disconnect { signals, type_mark, after })
+	}
+}
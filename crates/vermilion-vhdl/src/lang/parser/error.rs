@@ -1 +1,40 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use std::fmt::Display;
+
+use vermilion_loc::Span;
+
+/// An error encountered while parsing VHDL
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+	message: String,
+	span:    Span,
+}
+
+impl ParseError {
+	pub fn new<T>(message: T, span: Span) -> Self
+	where
+		T: Into<String>,
+	{
+		Self { message: message.into(), span }
+	}
+
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+
+	pub fn span(&self) -> &Span {
+		&self.span
+	}
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl Display for ParseError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{} at {}", self.message, self.span)
+	}
+}
+
+impl std::error::Error for ParseError {}
+
+pub(crate) type ParseResult<T> = Result<T, ParseError>;
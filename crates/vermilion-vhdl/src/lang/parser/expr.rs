@@ -0,0 +1,869 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::{Span, Spanned};
+
+use crate::lang::{
+	ast::{
+		Actual, Association, BinaryOperator, Choice, Constraint, Direction, DiscreteRange,
+		ElementAssociation, Expression, ExternalClass, ExternalPath, ExternalPathKind, Identifier,
+		Literal, Name, Range, Signature, SubtypeIndication, Suffix, UnaryOperator,
+	},
+	parser::{
+		VHDL_08_STDS, VHDL_93_STDS, VhdlParser,
+		error::{ParseError, ParseResult},
+	},
+	tokenizer::token::{Control, Keyword, Operator, Token},
+};
+
+/// The precedence of the logical operators, the lowest binding binary operators
+const LOGICAL_PRECEDENCE: u8 = 1;
+/// The precedence of the adding operators, which a leading sign applies to the first term of
+const ADDING_PRECEDENCE: u8 = 4;
+/// The precedence of the multiplying operators
+const MULTIPLYING_PRECEDENCE: u8 = 5;
+
+impl VhdlParser {
+	/// IEEE 1076-2008 § 9.1
+	pub(crate) fn parse_expression(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+
+		// The condition operator applies to a single primary, and can only start an expression
+		if self.is_operator(Operator::Condition) {
+			let operator = Spanned::new(UnaryOperator::Condition, self.span());
+			self.require_std(VHDL_08_STDS, start, "Condition operators");
+			self.bump();
+
+			let operand = self.parse_primary()?;
+			return Ok(Spanned::new(
+				Expression::Unary { operator, operand: Box::new(operand) },
+				self.span_from(start),
+			));
+		}
+
+		self.parse_binary(LOGICAL_PRECEDENCE)
+	}
+
+	/// Parse a binary expression where all of the operators bind at least as tightly as
+	/// `precedence`
+	fn parse_binary(&mut self, precedence: u8) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+		let mut lhs = self.parse_unary()?;
+
+		while let Some((operator, operator_precedence)) = self.peek_binary_operator() &&
+			operator_precedence >= precedence
+		{
+			let operator = Spanned::new(operator, self.span());
+			self.bump();
+
+			let rhs = self.parse_binary(operator_precedence + 1)?;
+			lhs = Spanned::new(
+				Expression::Binary { operator, lhs: Box::new(lhs), rhs: Box::new(rhs) },
+				self.span_from(start),
+			);
+		}
+
+		Ok(lhs)
+	}
+
+	/// The binary operator at the current position along with its precedence, if there is one
+	///
+	/// IEEE 1076-2008 § 9.2.1
+	fn peek_binary_operator(&self) -> Option<(BinaryOperator, u8)> {
+		let operator = match self.peek()? {
+			Token::Keyword(Keyword::And) => (BinaryOperator::And, 1),
+			Token::Keyword(Keyword::Or) => (BinaryOperator::Or, 1),
+			Token::Keyword(Keyword::Nand) => (BinaryOperator::Nand, 1),
+			Token::Keyword(Keyword::Nor) => (BinaryOperator::Nor, 1),
+			Token::Keyword(Keyword::Xor) => (BinaryOperator::Xor, 1),
+			Token::Keyword(Keyword::Xnor) => (BinaryOperator::Xnor, 1),
+			Token::Operator(Operator::Equals) => (BinaryOperator::Equals, 2),
+			Token::Operator(Operator::Inequality) => (BinaryOperator::Inequality, 2),
+			Token::Operator(Operator::LessThan) => (BinaryOperator::LessThan, 2),
+			Token::Operator(Operator::LessThanEqual) => (BinaryOperator::LessThanEqual, 2),
+			Token::Operator(Operator::GreaterThan) => (BinaryOperator::GreaterThan, 2),
+			Token::Operator(Operator::GreaterThanEqual) => (BinaryOperator::GreaterThanEqual, 2),
+			Token::Operator(Operator::MatchingEquals) => (BinaryOperator::MatchingEquals, 2),
+			Token::Operator(Operator::MatchingInequality) => {
+				(BinaryOperator::MatchingInequality, 2)
+			},
+			Token::Operator(Operator::MatchingLessThan) => (BinaryOperator::MatchingLessThan, 2),
+			Token::Operator(Operator::MatchingLessThanEqual) => {
+				(BinaryOperator::MatchingLessThanEqual, 2)
+			},
+			Token::Operator(Operator::MatchingGreaterThan) => {
+				(BinaryOperator::MatchingGreaterThan, 2)
+			},
+			Token::Operator(Operator::MatchingGreaterThanEqual) => {
+				(BinaryOperator::MatchingGreaterThanEqual, 2)
+			},
+			Token::Keyword(Keyword::Sll) => (BinaryOperator::Sll, 3),
+			Token::Keyword(Keyword::Srl) => (BinaryOperator::Srl, 3),
+			Token::Keyword(Keyword::Sla) => (BinaryOperator::Sla, 3),
+			Token::Keyword(Keyword::Sra) => (BinaryOperator::Sra, 3),
+			Token::Keyword(Keyword::Rol) => (BinaryOperator::Rol, 3),
+			Token::Keyword(Keyword::Ror) => (BinaryOperator::Ror, 3),
+			Token::Operator(Operator::Plus) => (BinaryOperator::Plus, ADDING_PRECEDENCE),
+			Token::Operator(Operator::Minus) => (BinaryOperator::Minus, ADDING_PRECEDENCE),
+			Token::Operator(Operator::Ampersand) => {
+				(BinaryOperator::Concatenate, ADDING_PRECEDENCE)
+			},
+			Token::Operator(Operator::Asterisk) => {
+				(BinaryOperator::Multiply, MULTIPLYING_PRECEDENCE)
+			},
+			Token::Operator(Operator::Solidus) => (BinaryOperator::Divide, MULTIPLYING_PRECEDENCE),
+			Token::Keyword(Keyword::Mod) => (BinaryOperator::Mod, MULTIPLYING_PRECEDENCE),
+			Token::Keyword(Keyword::Rem) => (BinaryOperator::Rem, MULTIPLYING_PRECEDENCE),
+			Token::Operator(Operator::Power) => (BinaryOperator::Power, 6),
+			_ => return None,
+		};
+
+		Some(operator)
+	}
+
+	/// Parse a unary expression, or a primary if there is no unary operator
+	///
+	/// IEEE 1076-2008 § 9.1
+	fn parse_unary(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+
+		let (operator, operand) = match self.peek() {
+			// A sign applies to the whole of the first term of a simple expression
+			Some(Token::Operator(Operator::Plus | Operator::Minus)) => {
+				let operator = if self.is_operator(Operator::Plus) {
+					UnaryOperator::Plus
+				} else {
+					UnaryOperator::Minus
+				};
+				self.bump();
+
+				(operator, self.parse_binary(MULTIPLYING_PRECEDENCE)?)
+			},
+			Some(Token::Keyword(Keyword::Abs)) => {
+				self.bump();
+				(UnaryOperator::Abs, self.parse_primary()?)
+			},
+			Some(Token::Keyword(Keyword::Not)) => {
+				self.bump();
+				(UnaryOperator::Not, self.parse_primary()?)
+			},
+			Some(Token::Keyword(
+				Keyword::And |
+				Keyword::Or |
+				Keyword::Nand |
+				Keyword::Nor |
+				Keyword::Xor |
+				Keyword::Xnor,
+			)) => {
+				let operator = match self.peek() {
+					Some(Token::Keyword(Keyword::And)) => UnaryOperator::And,
+					Some(Token::Keyword(Keyword::Or)) => UnaryOperator::Or,
+					Some(Token::Keyword(Keyword::Nand)) => UnaryOperator::Nand,
+					Some(Token::Keyword(Keyword::Nor)) => UnaryOperator::Nor,
+					Some(Token::Keyword(Keyword::Xor)) => UnaryOperator::Xor,
+					_ => UnaryOperator::Xnor,
+				};
+				self.require_std(VHDL_08_STDS, start, "Unary logical operators");
+				self.bump();
+
+				(operator, self.parse_primary()?)
+			},
+			_ => return self.parse_primary(),
+		};
+
+		Ok(Spanned::new(
+			Expression::Unary {
+				operator: Spanned::new(operator, start),
+				operand:  Box::new(operand),
+			},
+			self.span_from(start),
+		))
+	}
+
+	/// IEEE 1076-2008 § 9.1
+	pub(crate) fn parse_primary(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+
+		let expression = match self.peek() {
+			Some(Token::Number(value)) => {
+				let value = value.clone();
+				self.bump();
+
+				// An abstract literal followed by a unit name is a physical literal
+				if self.is_identifier() {
+					let unit = self.expect_identifier()?;
+					Expression::Literal(Literal::Physical { value: Some(value), unit })
+				} else {
+					Expression::Literal(Literal::Number(value))
+				}
+			},
+			Some(Token::BitString(value)) => {
+				let literal = Literal::BitString(value.clone());
+				self.bump();
+				Expression::Literal(literal)
+			},
+			// A string followed by a parenthesis is an operator symbol being called
+			Some(Token::String(_)) if self.is_control_at(1, Control::ParenOpen) => {
+				return self.parse_name_or_qualified();
+			},
+			Some(Token::String(value)) => {
+				let literal = Literal::String(value.clone());
+				self.bump();
+				Expression::Literal(literal)
+			},
+			Some(Token::Character(value)) => {
+				let literal = Literal::Character(value.clone());
+				self.bump();
+				Expression::Literal(literal)
+			},
+			Some(Token::Keyword(Keyword::Null)) => {
+				self.bump();
+				Expression::Literal(Literal::Null)
+			},
+			Some(Token::Control(Control::ParenOpen)) => return self.parse_parenthesized(),
+			Some(Token::Keyword(Keyword::New)) => {
+				self.bump();
+				let value = self.parse_name_or_qualified()?;
+				Expression::Allocator(Box::new(value))
+			},
+			Some(
+				Token::Identifier(_) |
+				Token::ExtendedIdentifier(_) |
+				Token::Control(Control::DoubleLessThan),
+			) => return self.parse_name_or_qualified(),
+			_ => return self.unexpected("an expression"),
+		};
+
+		Ok(Spanned::new(expression, self.span_from(start)))
+	}
+
+	/// Parse a name, or a qualified expression if the name is followed by `'(`
+	fn parse_name_or_qualified(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.span();
+		let name = self.parse_name()?;
+
+		if self.is_control(Control::Apostrophe) && self.is_control_at(1, Control::ParenOpen) {
+			self.bump();
+			let value = self.parse_parenthesized()?;
+
+			return Ok(Spanned::new(
+				Expression::Qualified {
+					type_mark: Box::new(name),
+					value:     Box::new(value),
+				},
+				self.span_from(start),
+			));
+		}
+
+		let (name, span) = name.split();
+		Ok(Spanned::new(Expression::Name(name), span))
+	}
+
+	/// Parse a parenthesized expression or an aggregate
+	///
+	/// IEEE 1076-2008 § 9.3.3.1
+	fn parse_parenthesized(&mut self) -> ParseResult<Spanned<Expression>> {
+		let start = self.expect_control(Control::ParenOpen)?;
+
+		let mut elements = Vec::new();
+		loop {
+			let element_start = self.span();
+
+			let first = self.parse_choice()?;
+			let named = self.is_control(Control::Pipe) ||
+				self.is_control(Control::Arrow) ||
+				!matches!(first.inner(), Choice::Expression(_));
+
+			let element = if named {
+				let mut choices = vec![first];
+				while self.eat_control(Control::Pipe) {
+					choices.push(self.parse_choice()?);
+				}
+				self.expect_control(Control::Arrow)?;
+
+				ElementAssociation { choices, value: self.parse_expression()? }
+			} else {
+				let (choice, span) = first.split();
+				let Choice::Expression(value) = choice else {
+					return self.unexpected("`=>`");
+				};
+
+				ElementAssociation {
+					choices: Vec::new(),
+					value:   Spanned::new(value, span),
+				}
+			};
+			elements.push(Spanned::new(element, self.span_from(element_start)));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::ParenClose)?;
+
+		// A single positional element is just a parenthesized expression
+		if elements.len() == 1 && elements[0].choices.is_empty() {
+			let element = elements.remove(0).as_inner();
+			return Ok(Spanned::new(
+				Expression::Parenthesized(Box::new(element.value)),
+				self.span_from(start),
+			));
+		}
+
+		Ok(Spanned::new(
+			Expression::Aggregate(elements),
+			self.span_from(start),
+		))
+	}
+
+	/// Parse a `|` separated list of choices
+	///
+	/// IEEE 1076-2008 § 9.3.3.1
+	pub(crate) fn parse_choices(&mut self) -> ParseResult<Vec<Spanned<Choice>>> {
+		let mut choices = vec![self.parse_choice()?];
+		while self.eat_control(Control::Pipe) {
+			choices.push(self.parse_choice()?);
+		}
+
+		Ok(choices)
+	}
+
+	/// IEEE 1076-2008 § 9.3.3.1
+	fn parse_choice(&mut self) -> ParseResult<Spanned<Choice>> {
+		let start = self.span();
+
+		if self.eat_keyword(Keyword::Others) {
+			return Ok(Spanned::new(Choice::Others, start));
+		}
+
+		let expression = self.parse_expression()?;
+		let choice = match self.parse_direction() {
+			Some(direction) => Choice::Range(self.finish_range(expression, direction)?),
+			None => Choice::Expression(expression.as_inner()),
+		};
+
+		Ok(Spanned::new(choice, self.span_from(start)))
+	}
+
+	/// Parse a `to` or `downto` range direction, if there is one
+	fn parse_direction(&mut self) -> Option<Direction> {
+		let direction = match self.peek() {
+			Some(Token::Keyword(Keyword::To)) => Direction::To,
+			Some(Token::Keyword(Keyword::DownTo)) => Direction::DownTo,
+			_ => return None,
+		};
+
+		self.bump();
+		Some(direction)
+	}
+
+	/// Parse the right hand side of an explicit range whose left hand side and direction have
+	/// already been parsed
+	fn finish_range(
+		&mut self,
+		left: Spanned<Expression>,
+		direction: Direction,
+	) -> ParseResult<Range> {
+		let right = self.parse_expression()?;
+
+		Ok(Range::Explicit {
+			left: Box::new(left),
+			direction,
+			right: Box::new(right),
+		})
+	}
+
+	/// IEEE 1076-2008 § 5.2.1
+	pub(crate) fn parse_range(&mut self) -> ParseResult<Range> {
+		let expression = self.parse_expression()?;
+
+		if let Some(direction) = self.parse_direction() {
+			return self.finish_range(expression, direction);
+		}
+
+		match expression.as_inner() {
+			Expression::Name(name @ Name::Attribute { .. }) => Ok(Range::Attribute(name)),
+			_ => self.unexpected("`to` or `downto`"),
+		}
+	}
+
+	/// IEEE 1076-2008 § 5.3.2.1
+	pub(crate) fn parse_discrete_range(&mut self) -> ParseResult<Spanned<DiscreteRange>> {
+		let start = self.span();
+		let checkpoint = self.checkpoint();
+		let expression = self.parse_expression()?;
+
+		let range = if let Some(direction) = self.parse_direction() {
+			DiscreteRange::Range(self.finish_range(expression, direction)?)
+		} else {
+			match expression.as_inner() {
+				Expression::Name(Name::Attribute { attribute, .. })
+					if is_range_attribute(attribute.inner()) =>
+				{
+					self.restore(checkpoint);
+					DiscreteRange::Range(self.parse_range()?)
+				},
+				Expression::Name(_) => {
+					// A subtype indication, which needs parsing again as the constraint of a
+					// type mark looks like a call
+					self.restore(checkpoint);
+					DiscreteRange::Subtype(self.parse_subtype_indication()?.as_inner())
+				},
+				_ => return self.unexpected("`to` or `downto`"),
+			}
+		};
+
+		Ok(Spanned::new(range, self.span_from(start)))
+	}
+
+	/// IEEE 1076-2008 § 6.3
+	pub(crate) fn parse_subtype_indication(&mut self) -> ParseResult<Spanned<SubtypeIndication>> {
+		let start = self.span();
+
+		let (resolution, type_mark) = if self.is_control(Control::ParenOpen) {
+			// An IEEE 1076-2008 element resolution, e.g. `(resolved) std_ulogic_vector`
+			let resolution_start = self.span();
+			self.bump();
+			let resolution = self.parse_type_mark()?;
+			self.expect_control(Control::ParenClose)?;
+			self.require_std(
+				VHDL_08_STDS,
+				self.span_from(resolution_start),
+				"Element resolution functions",
+			);
+
+			let (resolution, _) = resolution.split();
+			let resolution = Spanned::new(resolution, self.span_from(resolution_start));
+
+			(Some(resolution), self.parse_type_mark()?)
+		} else {
+			let first = self.parse_type_mark()?;
+			if self.is_identifier() {
+				(Some(first), self.parse_type_mark()?)
+			} else {
+				(None, first)
+			}
+		};
+
+		let constraint = self.parse_constraint()?;
+		let tolerance = self.parse_tolerance_aspect()?.map(Box::new);
+
+		Ok(Spanned::new(
+			SubtypeIndication { resolution, type_mark, constraint, tolerance },
+			self.span_from(start),
+		))
+	}
+
+	/// Parse a range or index constraint, if there is one
+	///
+	/// IEEE 1076-2008 § 6.3
+	fn parse_constraint(&mut self) -> ParseResult<Option<Spanned<Constraint>>> {
+		let start = self.span();
+
+		let constraint = match self.peek() {
+			Some(Token::Keyword(Keyword::Range)) => {
+				self.bump();
+				Constraint::Range(self.parse_range()?)
+			},
+			Some(Token::Control(Control::ParenOpen)) => {
+				self.bump();
+
+				let ranges = if self.is_keyword(Keyword::Open) {
+					self.require_std(VHDL_08_STDS, self.span(), "`open` index constraints");
+					self.bump();
+					Vec::new()
+				} else {
+					let mut ranges = vec![self.parse_discrete_range()?];
+					while self.eat_control(Control::Comma) {
+						ranges.push(self.parse_discrete_range()?);
+					}
+					ranges
+				};
+
+				self.expect_control(Control::ParenClose)?;
+
+				let element = if self.is_control(Control::ParenOpen) {
+					let element_start = self.span();
+					let element = self.parse_constraint()?;
+					self.require_std(
+						VHDL_08_STDS,
+						self.span_from(element_start),
+						"Element constraints",
+					);
+					element.map(Box::new)
+				} else {
+					None
+				};
+
+				Constraint::Index { ranges, element }
+			},
+			_ => return Ok(None),
+		};
+
+		Ok(Some(Spanned::new(constraint, self.span_from(start))))
+	}
+
+	/// Parse a name, including any calls, indexes, and slices
+	///
+	/// IEEE 1076-2008 § 8.1
+	pub(crate) fn parse_name(&mut self) -> ParseResult<Spanned<Name>> {
+		self.parse_name_with(true)
+	}
+
+	/// Parse a type mark, which is a name without any calls, indexes, or slices, as anything in
+	/// parentheses after it is a constraint
+	///
+	/// IEEE 1076-2008 § 6.3
+	pub(crate) fn parse_type_mark(&mut self) -> ParseResult<Spanned<Name>> {
+		self.parse_name_with(false)
+	}
+
+	fn parse_name_with(&mut self, calls: bool) -> ParseResult<Spanned<Name>> {
+		let start = self.span();
+
+		let mut name = match self.peek() {
+			Some(Token::String(text)) => {
+				let name = Name::OperatorSymbol(text.clone());
+				self.bump();
+				Spanned::new(name, start)
+			},
+			Some(Token::Character(text)) => {
+				let name = Name::Character(text.clone());
+				self.bump();
+				Spanned::new(name, start)
+			},
+			Some(Token::Control(Control::DoubleLessThan)) => self.parse_external_name()?,
+			_ => {
+				let (ident, span) = self.expect_identifier()?.split();
+				Spanned::new(Name::Simple(ident), span)
+			},
+		};
+
+		loop {
+			match self.peek() {
+				Some(Token::Control(Control::Dot)) => {
+					self.bump();
+					let suffix = self.parse_suffix()?;
+
+					name = Spanned::new(
+						Name::Selected { prefix: Box::new(name), suffix },
+						self.span_from(start),
+					);
+				},
+				Some(Token::Control(Control::ParenOpen)) if calls => {
+					let arguments = self.parse_association_list()?;
+
+					name = Spanned::new(
+						Name::Call { prefix: Box::new(name), arguments },
+						self.span_from(start),
+					);
+				},
+				// `'(` starts a qualified expression rather than an attribute
+				Some(Token::Control(Control::Apostrophe))
+					if !self.is_control_at(1, Control::ParenOpen) =>
+				{
+					self.bump();
+					name = self.finish_attribute_name(start, name, None, calls)?;
+				},
+				// A signature can only be followed by an attribute in a name
+				Some(Token::Control(Control::BracketOpen)) if self.is_signature_attribute() => {
+					let signature = self.parse_signature()?;
+					self.expect_control(Control::Apostrophe)?;
+					name = self.finish_attribute_name(start, name, Some(signature), calls)?;
+				},
+				_ => break,
+			}
+		}
+
+		Ok(name)
+	}
+
+	/// Returns true if the `[` at the current position is a signature followed by an attribute
+	fn is_signature_attribute(&self) -> bool {
+		let mut offset = 1;
+		while let Some(token) = self.peek_nth(offset) {
+			match token {
+				Token::Control(Control::BracketClose) => {
+					return self.is_control_at(offset + 1, Control::Apostrophe);
+				},
+				Token::Control(Control::Semicolon) => return false,
+				_ => offset += 1,
+			}
+		}
+
+		false
+	}
+
+	/// Parse the attribute designator and optional argument of an attribute name
+	fn finish_attribute_name(
+		&mut self,
+		start: Span,
+		prefix: Spanned<Name>,
+		signature: Option<Spanned<Signature>>,
+		calls: bool,
+	) -> ParseResult<Spanned<Name>> {
+		// `range` and `subtype`, along with the VHDL-AMS `across`, `through`, `reference`, and
+		// `tolerance`, are reserved words that are also predefined attribute names
+		let attribute = match self.peek() {
+			Some(Token::Keyword(
+				keyword @ (Keyword::Range |
+				Keyword::Subtype |
+				Keyword::Across |
+				Keyword::Through |
+				Keyword::Reference |
+				Keyword::Tolerance),
+			)) => {
+				let text = keyword.as_str().as_bytes().into();
+				let attribute = Spanned::new(Identifier::Basic(text), self.span());
+				self.bump();
+				attribute
+			},
+			_ => self.expect_identifier()?,
+		};
+
+		let argument = if calls && self.is_control(Control::ParenOpen) {
+			let arguments_start = self.span();
+			self.bump();
+			let mut argument = self.parse_expression()?;
+
+			// Attributes such as the VHDL-AMS `'ltf(num, den)` take more than one argument, these
+			// are kept as a positional aggregate
+			if self.is_control(Control::Comma) {
+				let mut arguments = vec![argument];
+				while self.eat_control(Control::Comma) {
+					arguments.push(self.parse_expression()?);
+				}
+				self.expect_control(Control::ParenClose)?;
+
+				let arguments = arguments
+					.into_iter()
+					.map(|value| {
+						let span = *value.span();
+						Spanned::new(ElementAssociation { choices: Vec::new(), value }, span)
+					})
+					.collect();
+				argument = Spanned::new(
+					Expression::Aggregate(arguments),
+					self.span_from(arguments_start),
+				);
+			} else {
+				self.expect_control(Control::ParenClose)?;
+			}
+
+			Some(Box::new(argument))
+		} else {
+			None
+		};
+
+		Ok(Spanned::new(
+			Name::Attribute {
+				prefix: Box::new(prefix),
+				signature: signature.map(Box::new),
+				attribute,
+				argument,
+			},
+			self.span_from(start),
+		))
+	}
+
+	/// IEEE 1076-2008 § 8.3
+	fn parse_suffix(&mut self) -> ParseResult<Spanned<Suffix>> {
+		let span = self.span();
+
+		let suffix = match self.peek() {
+			Some(Token::Keyword(Keyword::All)) => Suffix::All,
+			Some(Token::Character(text)) => Suffix::Character(text.clone()),
+			Some(Token::String(text)) => Suffix::OperatorSymbol(text.clone()),
+			_ => {
+				let (ident, span) = self.expect_identifier()?.split();
+				return Ok(Spanned::new(Suffix::Identifier(ident), span));
+			},
+		};
+
+		self.bump();
+		Ok(Spanned::new(suffix, span))
+	}
+
+	/// IEEE 1076-2008 § 8.7
+	fn parse_external_name(&mut self) -> ParseResult<Spanned<Name>> {
+		let start = self.expect_control(Control::DoubleLessThan)?;
+		self.require_std(VHDL_08_STDS, start, "External names");
+
+		let class = match self.peek() {
+			Some(Token::Keyword(Keyword::Constant)) => ExternalClass::Constant,
+			Some(Token::Keyword(Keyword::Signal)) => ExternalClass::Signal,
+			Some(Token::Keyword(Keyword::Variable)) => ExternalClass::Variable,
+			_ => return self.unexpected("`constant`, `signal`, or `variable`"),
+		};
+		self.bump();
+
+		let path_start = self.span();
+		let kind = if self.eat_control(Control::At) {
+			ExternalPathKind::Package
+		} else if self.eat_control(Control::Dot) {
+			ExternalPathKind::Absolute
+		} else {
+			let mut up = 0;
+			while self.eat_control(Control::Circumflex) {
+				self.expect_control(Control::Dot)?;
+				up += 1;
+			}
+
+			ExternalPathKind::Relative(up)
+		};
+
+		let mut elements = Vec::new();
+		loop {
+			let element_start = self.span();
+			let (ident, span) = self.expect_identifier()?.split();
+			let mut element = Spanned::new(Name::Simple(ident), span);
+
+			// Generate statement labels can be followed by an index
+			if self.is_control(Control::ParenOpen) {
+				let arguments = self.parse_association_list()?;
+				element = Spanned::new(
+					Name::Call { prefix: Box::new(element), arguments },
+					self.span_from(element_start),
+				);
+			}
+
+			elements.push(element);
+
+			if !self.eat_control(Control::Dot) {
+				break;
+			}
+		}
+
+		let path = Spanned::new(ExternalPath { kind, elements }, self.span_from(path_start));
+
+		self.expect_control(Control::Colon)?;
+		let subtype = self.parse_subtype_indication()?;
+		self.expect_control(Control::DoubleGreaterThan)?;
+
+		Ok(Spanned::new(
+			Name::External { class, path, subtype: Box::new(subtype) },
+			self.span_from(start),
+		))
+	}
+
+	/// IEEE 1076-2008 § 4.5.3
+	pub(crate) fn parse_signature(&mut self) -> ParseResult<Spanned<Signature>> {
+		let start = self.expect_control(Control::BracketOpen)?;
+		self.require_std(VHDL_93_STDS, start, "Signatures");
+
+		let mut parameters = Vec::new();
+		if !self.is_control(Control::BracketClose) && !self.is_keyword(Keyword::Return) {
+			parameters.push(self.parse_type_mark()?);
+			while self.eat_control(Control::Comma) {
+				parameters.push(self.parse_type_mark()?);
+			}
+		}
+
+		let return_type = if self.eat_keyword(Keyword::Return) {
+			Some(self.parse_type_mark()?)
+		} else {
+			None
+		};
+
+		self.expect_control(Control::BracketClose)?;
+
+		Ok(Spanned::new(
+			Signature { parameters, return_type },
+			self.span_from(start),
+		))
+	}
+
+	/// Parse a parenthesized association list
+	///
+	/// IEEE 1076-2008 § 6.5.7.1
+	pub(crate) fn parse_association_list(&mut self) -> ParseResult<Vec<Spanned<Association>>> {
+		self.expect_control(Control::ParenOpen)?;
+
+		let mut associations = Vec::new();
+		loop {
+			associations.push(self.parse_association()?);
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		self.expect_control(Control::ParenClose)?;
+
+		Ok(associations)
+	}
+
+	/// IEEE 1076-2008 § 6.5.7.1
+	fn parse_association(&mut self) -> ParseResult<Spanned<Association>> {
+		let start = self.span();
+
+		let mut actual = self.parse_actual()?;
+		let formal = if self.eat_control(Control::Arrow) {
+			let (formal, span) = actual.split();
+			let formal = match formal {
+				Actual::Expression(expression) => {
+					expression_to_name(Spanned::new(expression, span))?
+				},
+				_ => return Err(ParseError::new("Expected a formal name", span)),
+			};
+
+			actual = self.parse_actual()?;
+			Some(formal)
+		} else {
+			None
+		};
+
+		Ok(Spanned::new(
+			Association { formal, actual },
+			self.span_from(start),
+		))
+	}
+
+	/// IEEE 1076-2008 § 6.5.7.1
+	fn parse_actual(&mut self) -> ParseResult<Spanned<Actual>> {
+		let start = self.span();
+
+		let actual = match self.peek() {
+			Some(Token::Keyword(Keyword::Open)) => {
+				self.bump();
+				Actual::Open
+			},
+			Some(Token::Keyword(Keyword::Inertial)) => {
+				self.require_std(VHDL_08_STDS, start, "`inertial` port actuals");
+				self.bump();
+				Actual::Inertial(self.parse_expression()?.as_inner())
+			},
+			_ => {
+				let expression = self.parse_expression()?;
+				match self.parse_direction() {
+					Some(direction) => Actual::Range(self.finish_range(expression, direction)?),
+					None => Actual::Expression(expression.as_inner()),
+				}
+			},
+		};
+
+		Ok(Spanned::new(actual, self.span_from(start)))
+	}
+}
+
+/// Convert an expression that is just a name into that name
+pub(crate) fn expression_to_name(expression: Spanned<Expression>) -> ParseResult<Spanned<Name>> {
+	let (expression, span) = expression.split();
+
+	match expression {
+		Expression::Name(name) => Ok(Spanned::new(name, span)),
+		Expression::Literal(Literal::String(text)) => {
+			Ok(Spanned::new(Name::OperatorSymbol(text), span))
+		},
+		_ => Err(ParseError::new("Expected a name", span)),
+	}
+}
+
+/// Returns true if `attribute` is `range` or `reverse_range`, which denote a range
+fn is_range_attribute(attribute: &Identifier) -> bool {
+	matches!(attribute, Identifier::Basic(_)) &&
+		matches!(
+			attribute.as_str().to_ascii_lowercase().as_str(),
+			"range" | "reverse_range"
+		)
+}
@@ -1,29 +1,961 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
-use vermilion_lang::AtomicByteTendril;
-use vermilion_loc::Spanned;
+use std::{collections::BTreeMap, ops::Range};
+
+use vermilion_diagnostics::{Code, Diagnostic, StringDiagnostic};
+use vermilion_lang::{AtomicByteTendril, tokenizer::TokenizerIter};
+use vermilion_loc::{Position, Span, Spanned};
 
 use crate::{
 	LanguageStd,
-	lang::tokenizer::{VhdlTokenizer, token::Token},
+	diagnostics::{vhdl, vhdl_ams},
+	lang::{
+		ast::{
+			Architecture, Ast, BlockConfiguration, ComponentConfiguration, Configuration,
+			ConfigurationItem, ContextDeclaration, ContextItem, Declaration, DesignUnit, Entity,
+			Identifier, LibraryUnit, Name, Package, PackageBody, PackageInstance,
+		},
+		parser::error::{ParseError, ParseResult},
+		tokenizer::{
+			VhdlTokenizer,
+			token::{Comment, Control, Keyword, Operator, Token},
+		},
+	},
 };
 
+mod ams;
+mod bsdl;
+mod decl;
 pub mod error;
+mod expr;
+mod psl;
+mod recovery;
+mod stmt;
+
+/// All of the standards that include the IEEE 1076-1993 additions
+pub(crate) const VHDL_93_STDS: LanguageStd = LanguageStd::Vh93
+	.or(LanguageStd::Vh2k)
+	.or(LanguageStd::Vh02)
+	.or(LanguageStd::Vh04)
+	.or(LanguageStd::Vh07)
+	.or(LanguageStd::Vh08)
+	.or(LanguageStd::Vh11)
+	.or(LanguageStd::Vh19)
+	.or(LanguageStd::Vh23)
+	.or(LanguageStd::VHDL_AMS_STDS)
+	.or(LanguageStd::Bsdl);
+
+/// All of the standards that include the IEEE 1076-2000 additions
+pub(crate) const VHDL_2K_STDS: LanguageStd = LanguageStd::Vh2k
+	.or(LanguageStd::Vh02)
+	.or(LanguageStd::Vh04)
+	.or(LanguageStd::Vh07)
+	.or(VHDL_08_STDS)
+	.or(LanguageStd::Vhams07)
+	.or(LanguageStd::Vhams09);
+
+/// All of the standards that include the IEEE 1076-2008 additions
+pub(crate) const VHDL_08_STDS: LanguageStd = LanguageStd::Vh08
+	.or(LanguageStd::Vh11)
+	.or(LanguageStd::Vh19)
+	.or(LanguageStd::Vh23)
+	.or(LanguageStd::Vhams17)
+	.or(LanguageStd::Vhams21);
+
+/// All of the standards that include the IEEE 1076-2019 additions
+pub(crate) const VHDL_19_STDS: LanguageStd = LanguageStd::Vh19.or(LanguageStd::Vh23);
 
 pub struct VhdlParser {
-	_std:           LanguageStd,
-	_tokenizer:     VhdlTokenizer,
-	_current_token: Option<Spanned<Token>>,
+	std:         LanguageStd,
+	source:      AtomicByteTendril,
+	tokens:      Vec<Spanned<Token>>,
+	position:    usize,
+	diagnostics: Vec<StringDiagnostic>,
+	/// Diagnostics for tokens that were only valid in a later standard, keyed by token index
+	gated:       BTreeMap<usize, StringDiagnostic>,
+	/// The keywords that close the constructs currently being parsed, used for error recovery
+	closers:     Vec<Keyword>,
+	/// The ranges of `tokens` that came from `-- psl` comments
+	psl:         Vec<Range<usize>>,
 }
 
+/// A saved parser position to backtrack to
+pub(crate) type Checkpoint = (usize, usize);
+
 impl VhdlParser {
 	pub fn new(std: LanguageStd, content: AtomicByteTendril) -> eyre::Result<Self> {
+		let mut tokenizer = VhdlTokenizer::new(std, content.clone())?;
+		let (mut tokens, psl) = Self::collect_tokens(std, TokenizerIter::new(&mut tokenizer));
+		let gated = Self::reinterpret_tokens(std, &mut tokens);
+
 		Ok(Self {
-			_std:           std,
-			_tokenizer:     VhdlTokenizer::new(std, content)?,
-			_current_token: None,
+			std,
+			source: content,
+			tokens,
+			position: 0,
+			diagnostics: Vec::new(),
+			gated,
+			closers: Vec::new(),
+			psl,
 		})
 	}
+
+	// NOTE(aki):
+	// PSL can be embedded in VHDL within comments that start with `psl`, the contents of these are
+	// tokenized as VHDL and spliced into the token stream in place of the comment, with the ranges
+	// they occupy remembered so the parser knows to treat them as PSL.
+	/// Collect the significant tokens, along with the ranges of any tokens from `-- psl` comments
+	fn collect_tokens<I>(std: LanguageStd, iter: I) -> (Vec<Spanned<Token>>, Vec<Range<usize>>)
+	where
+		I: Iterator<Item = Spanned<Token>>,
+	{
+		let mut tokens = Vec::new();
+		let mut psl = Vec::new();
+		let mut iter = iter.peekable();
+
+		while let Some(token) = iter.next() {
+			match token.inner() {
+				Token::Whitespace(_) | Token::Newline(_) => {},
+				Token::Comment(Comment::SingleLine(text)) => {
+					let Some(embedded) = psl_comment_tokens(std, text, token.span()) else {
+						continue;
+					};
+
+					let start = tokens.len();
+					tokens.extend(embedded);
+					psl.push(start..tokens.len());
+				},
+				Token::Comment(_) => {},
+				// XXX(aki): Tool directives are not acted upon yet, so they are skipped along with
+				// the rest of the line they are on
+				Token::ToolDirective(_) => {
+					for token in iter.by_ref() {
+						if matches!(token.inner(), Token::Newline(_)) {
+							break;
+						}
+					}
+				},
+				_ => tokens.push(token),
+			}
+		}
+
+		(tokens, psl)
+	}
+
+	// NOTE(aki):
+	// Rather than failing outright on tokens that are only valid in a later standard, we
+	// re-tokenize them as that standard and parse on as normal, reporting a diagnostic for them.
+	/// Replace any tokens that are only valid in a later standard with their valid equivalent
+	fn reinterpret_tokens(
+		std: LanguageStd,
+		tokens: &mut [Spanned<Token>],
+	) -> BTreeMap<usize, StringDiagnostic> {
+		let mut gated = BTreeMap::new();
+
+		for (idx, token) in tokens.iter_mut().enumerate() {
+			let span = *token.span();
+
+			let Token::ContextuallyInvalid(text, stds) = token.inner() else {
+				continue;
+			};
+
+			let Some(reinterpreted) = reinterpret_token(text, earliest_std(*stds)) else {
+				continue;
+			};
+
+			gated.insert(
+				idx,
+				StringDiagnostic::new(
+					unsupported_code(std),
+					format!(
+						"`{}` is not valid in {std}, requires {}",
+						unsafe { str::from_utf8_unchecked(text) },
+						earliest_std(*stds)
+					),
+					Some(span),
+				),
+			);
+			*token = Spanned::new(reinterpreted, span);
+		}
+
+		gated
+	}
+
+	/// Parse the whole design file
+	///
+	/// Syntax errors do not stop the parse, each one is reported as a diagnostic and the construct
+	/// containing it is replaced with an error node, so the resulting AST is always as complete as
+	/// possible.
+	pub fn parse(mut self) -> Ast {
+		let mut units = Vec::new();
+
+		while !self.at_eof() {
+			let start = self.span();
+			let position = self.position;
+
+			match self.parse_design_unit() {
+				Ok(unit) => units.push(unit),
+				Err(err) => {
+					self.report(err);
+					self.synchronize_unit(position);
+
+					units.push(Spanned::new(
+						DesignUnit { context: Vec::new(), unit: LibraryUnit::Error },
+						self.span_from(start),
+					));
+				},
+			}
+		}
+
+		let mut diagnostics = self.diagnostics;
+		diagnostics.extend(self.gated.into_values());
+		diagnostics.sort_by_key(|diagnostic| diagnostic.span().map(|span| *span.begin()));
+
+		Ast::new(units, diagnostics)
+	}
+
+	pub fn std(&self) -> LanguageStd {
+		self.std
+	}
+
+	/// IEEE 1076-2008 § 13.1
+	fn parse_design_unit(&mut self) -> ParseResult<Spanned<DesignUnit>> {
+		let start = self.span();
+
+		let mut context = Vec::new();
+		while let Some(item) = self.parse_context_item()? {
+			context.push(item);
+		}
+
+		let unit = match self.peek() {
+			Some(Token::Keyword(Keyword::Entity)) => LibraryUnit::Entity(self.parse_entity()?),
+			Some(Token::Keyword(Keyword::Architecture)) => {
+				LibraryUnit::Architecture(self.parse_architecture()?)
+			},
+			Some(Token::Keyword(Keyword::Package))
+				if matches!(self.peek_nth(1), Some(Token::Keyword(Keyword::Body))) =>
+			{
+				LibraryUnit::PackageBody(self.parse_package_body()?)
+			},
+			Some(Token::Keyword(Keyword::Package)) => match self.parse_package()? {
+				Declaration::PackageInstance(instance) => LibraryUnit::PackageInstance(instance),
+				Declaration::Package(package) => LibraryUnit::Package(package),
+				_ => LibraryUnit::Error,
+			},
+			Some(Token::Keyword(Keyword::Configuration)) => {
+				LibraryUnit::Configuration(self.parse_configuration()?)
+			},
+			Some(Token::Keyword(Keyword::Context)) => {
+				LibraryUnit::Context(self.parse_context_declaration()?)
+			},
+			_ if self.is_verification_unit_start() => {
+				LibraryUnit::VerificationUnit(self.parse_verification_unit()?)
+			},
+			_ => return self.unexpected("a library unit"),
+		};
+
+		Ok(Spanned::new(
+			DesignUnit { context, unit },
+			self.span_from(start),
+		))
+	}
+
+	/// Parse a library clause, use clause, or context reference, if there is one
+	///
+	/// IEEE 1076-2008 § 13.4
+	fn parse_context_item(&mut self) -> ParseResult<Option<Spanned<ContextItem>>> {
+		let start = self.span();
+
+		let item = match self.peek() {
+			Some(Token::Keyword(Keyword::Library)) => {
+				self.bump();
+				let mut names = vec![self.expect_identifier()?];
+				while self.eat_control(Control::Comma) {
+					names.push(self.expect_identifier()?);
+				}
+
+				ContextItem::Library(names)
+			},
+			Some(Token::Keyword(Keyword::Use)) => {
+				self.bump();
+				ContextItem::Use(self.parse_selected_names()?)
+			},
+			// `context a is` starts a context declaration rather than a reference
+			Some(Token::Keyword(Keyword::Context))
+				if !matches!(self.peek_nth(2), Some(Token::Keyword(Keyword::Is))) =>
+			{
+				self.bump();
+				self.require_std(VHDL_08_STDS, start, "Context references");
+				ContextItem::Context(self.parse_selected_names()?)
+			},
+			_ => return Ok(None),
+		};
+
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(Some(Spanned::new(item, self.span_from(start))))
+	}
+
+	/// Parse a comma separated list of selected names
+	pub(crate) fn parse_selected_names(&mut self) -> ParseResult<Vec<Spanned<Name>>> {
+		let mut names = vec![self.parse_type_mark()?];
+		while self.eat_control(Control::Comma) {
+			names.push(self.parse_type_mark()?);
+		}
+
+		Ok(names)
+	}
+
+	/// IEEE 1076-2008 § 3.2
+	fn parse_entity(&mut self) -> ParseResult<Entity> {
+		self.expect_keyword(Keyword::Entity)?;
+		let name = self.expect_identifier()?;
+		self.expect_keyword(Keyword::Is)?;
+
+		let generics = self.parse_generic_clause()?;
+		let ports = self.parse_port_clause()?;
+
+		let (declarations, mut closed) = self.parse_declarations(&[Keyword::Begin, Keyword::End]);
+
+		let statements = if closed && self.eat_keyword(Keyword::Begin) {
+			let (statements, end) = self.parse_concurrent_statements(&[Keyword::End]);
+			closed = end;
+			statements
+		} else {
+			Vec::new()
+		};
+
+		if closed {
+			self.parse_end(&[Keyword::Entity], false, Some(&name))?;
+		}
+
+		Ok(Entity { name, generics, ports, declarations, statements })
+	}
+
+	/// IEEE 1076-2008 § 3.3
+	fn parse_architecture(&mut self) -> ParseResult<Architecture> {
+		self.expect_keyword(Keyword::Architecture)?;
+		let name = self.expect_identifier()?;
+		self.expect_keyword(Keyword::Of)?;
+		let entity = self.expect_identifier()?;
+		self.expect_keyword(Keyword::Is)?;
+
+		let (declarations, closed) = self.parse_declarations(&[Keyword::Begin]);
+
+		let statements = if closed {
+			self.expect_keyword(Keyword::Begin)?;
+
+			let (statements, closed) = self.parse_concurrent_statements(&[Keyword::End]);
+			if closed {
+				self.parse_end(&[Keyword::Architecture], false, Some(&name))?;
+			}
+
+			statements
+		} else {
+			Vec::new()
+		};
+
+		Ok(Architecture { name, entity, declarations, statements })
+	}
+
+	/// Parse a package declaration or package instantiation
+	///
+	/// IEEE 1076-2008 § 4.7, § 4.9
+	pub(crate) fn parse_package(&mut self) -> ParseResult<Declaration> {
+		self.expect_keyword(Keyword::Package)?;
+		let name = self.expect_identifier()?;
+		self.expect_keyword(Keyword::Is)?;
+
+		if self.is_keyword(Keyword::New) {
+			self.require_std(VHDL_08_STDS, self.span(), "Package instantiations");
+			self.bump();
+
+			let package = self.parse_type_mark()?;
+			let generic_map = self.parse_generic_map()?;
+			self.expect_control(Control::Semicolon)?;
+
+			return Ok(Declaration::PackageInstance(PackageInstance {
+				name,
+				package,
+				generic_map,
+			}));
+		}
+
+		let start = self.span();
+		let generics = self.parse_generic_clause()?;
+		let generic_map = if generics.is_some() {
+			let generic_map = self.parse_generic_map()?;
+			if generic_map.is_some() {
+				self.expect_control(Control::Semicolon)?;
+			}
+			generic_map
+		} else {
+			None
+		};
+
+		if generics.is_some() {
+			self.require_std(VHDL_08_STDS, self.span_from(start), "Package generics");
+		}
+
+		let (declarations, closed) = self.parse_declarations(&[Keyword::End]);
+		if closed {
+			self.parse_end(&[Keyword::Package], false, Some(&name))?;
+		}
+
+		Ok(Declaration::Package(Package {
+			name,
+			generics,
+			generic_map,
+			declarations,
+		}))
+	}
+
+	/// IEEE 1076-2008 § 4.8
+	pub(crate) fn parse_package_body(&mut self) -> ParseResult<PackageBody> {
+		self.expect_keyword(Keyword::Package)?;
+		self.expect_keyword(Keyword::Body)?;
+		let name = self.expect_identifier()?;
+		self.expect_keyword(Keyword::Is)?;
+
+		let (declarations, closed) = self.parse_declarations(&[Keyword::End]);
+		if closed {
+			self.parse_end(&[Keyword::Package, Keyword::Body], false, Some(&name))?;
+		}
+
+		Ok(PackageBody { name, declarations })
+	}
+
+	/// IEEE 1076-2008 § 3.4
+	fn parse_configuration(&mut self) -> ParseResult<Configuration> {
+		self.expect_keyword(Keyword::Configuration)?;
+		let name = self.expect_identifier()?;
+		self.expect_keyword(Keyword::Of)?;
+		let entity = self.parse_type_mark()?;
+		self.expect_keyword(Keyword::Is)?;
+
+		let mut declarations = Vec::new();
+		while !self.is_keyword(Keyword::For) && !self.at_eof() {
+			let start = self.span();
+
+			let declaration = match self.peek() {
+				Some(Token::Keyword(Keyword::Use)) => {
+					self.bump();
+					let names = self.parse_selected_names()?;
+					self.expect_control(Control::Semicolon)?;
+					Declaration::Use(names)
+				},
+				Some(Token::Keyword(Keyword::Attribute)) => {
+					Declaration::AttributeSpecification(self.parse_attribute_specification()?)
+				},
+				_ => return self.unexpected("`for`"),
+			};
+
+			declarations.push(Spanned::new(declaration, self.span_from(start)));
+		}
+
+		let block = self.parse_block_configuration()?;
+		self.parse_end(&[Keyword::Configuration], false, Some(&name))?;
+
+		Ok(Configuration { name, entity, declarations, block })
+	}
+
+	/// IEEE 1076-2008 § 3.4.2
+	fn parse_block_configuration(&mut self) -> ParseResult<Spanned<BlockConfiguration>> {
+		let start = self.expect_keyword(Keyword::For)?;
+		let specification = self.parse_name()?;
+
+		let mut uses = Vec::new();
+		while self.eat_keyword(Keyword::Use) {
+			uses.extend(self.parse_selected_names()?);
+			self.expect_control(Control::Semicolon)?;
+		}
+
+		let mut items = Vec::new();
+		while self.is_keyword(Keyword::For) {
+			items.push(self.parse_configuration_item()?);
+		}
+
+		self.expect_keyword(Keyword::End)?;
+		self.expect_keyword(Keyword::For)?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(Spanned::new(
+			BlockConfiguration { specification, uses, items },
+			self.span_from(start),
+		))
+	}
+
+	/// Parse either a nested block configuration or a component configuration, which both start
+	/// with `for`, but a component configuration has a `:` after the instantiation list
+	///
+	/// IEEE 1076-2008 § 3.4.1
+	fn parse_configuration_item(&mut self) -> ParseResult<Spanned<ConfigurationItem>> {
+		let mut offset = 1;
+		while !matches!(
+			self.peek_nth(offset),
+			Some(Token::Control(Control::Colon | Control::Semicolon)) |
+				Some(Token::Keyword(Keyword::Use | Keyword::For | Keyword::End)) |
+				None
+		) {
+			offset += 1;
+		}
+
+		if !self.is_control_at(offset, Control::Colon) {
+			let block = self.parse_block_configuration()?;
+			let span = *block.span();
+			return Ok(Spanned::new(
+				ConfigurationItem::Block(block.as_inner()),
+				span,
+			));
+		}
+
+		let start = self.expect_keyword(Keyword::For)?;
+		let specification = self.parse_component_specification()?;
+
+		let binding = if self.is_keyword(Keyword::Use) ||
+			self.is_keyword(Keyword::Generic) ||
+			self.is_keyword(Keyword::Port)
+		{
+			let binding = self.parse_binding_indication()?;
+			self.expect_control(Control::Semicolon)?;
+			Some(binding)
+		} else {
+			None
+		};
+
+		let block = if self.is_keyword(Keyword::For) {
+			Some(self.parse_block_configuration()?)
+		} else {
+			None
+		};
+
+		self.expect_keyword(Keyword::End)?;
+		self.expect_keyword(Keyword::For)?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(Spanned::new(
+			ConfigurationItem::Component(Box::new(ComponentConfiguration {
+				specification,
+				binding,
+				block,
+			})),
+			self.span_from(start),
+		))
+	}
+
+	/// IEEE 1076-2008 § 13.3
+	fn parse_context_declaration(&mut self) -> ParseResult<ContextDeclaration> {
+		let start = self.expect_keyword(Keyword::Context)?;
+		self.require_std(VHDL_08_STDS, start, "Context declarations");
+
+		let name = self.expect_identifier()?;
+		self.expect_keyword(Keyword::Is)?;
+
+		let mut items = Vec::new();
+		while let Some(item) = self.parse_context_item()? {
+			items.push(item);
+		}
+
+		self.parse_end(&[Keyword::Context], false, Some(&name))?;
+
+		Ok(ContextDeclaration { name, items })
+	}
+
+	/// Parse the `end [keywords] [label];` that closes a construct, `required` is if the
+	/// keywords must be present, and `name` is the name or label of the construct, if any
+	pub(crate) fn parse_end(
+		&mut self,
+		keywords: &[Keyword],
+		required: bool,
+		name: Option<&Spanned<Identifier>>,
+	) -> ParseResult<()> {
+		self.expect_keyword(Keyword::End)?;
+
+		if let Some(first) = keywords.first() &&
+			(required || self.is_keyword(*first))
+		{
+			let start = self.span();
+			for keyword in keywords {
+				self.expect_keyword(*keyword)?;
+			}
+
+			if !required {
+				self.require_std(
+					VHDL_93_STDS,
+					self.span_from(start),
+					"Keywords after `end` in declarations",
+				);
+			}
+		}
+
+		self.parse_end_label(name)?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(())
+	}
+
+	/// Parse the optional label following `end`, ensuring it matches `name`
+	fn parse_end_label(&mut self, name: Option<&Spanned<Identifier>>) -> ParseResult<()> {
+		let label = match self.peek() {
+			Some(Token::Identifier(_) | Token::ExtendedIdentifier(_)) => {
+				self.expect_identifier()?
+			},
+			// Subprograms named by an operator symbol or character literal
+			Some(Token::String(_) | Token::Character(_)) => {
+				self.bump();
+				return Ok(());
+			},
+			_ => return Ok(()),
+		};
+
+		if let Some(name) = name &&
+			!name.inner().matches(label.inner())
+		{
+			self.diagnostics.push(StringDiagnostic::new(
+				syntax_error_code(self.std),
+				format!(
+					"End label `{}` does not match `{}`",
+					label.inner().as_str(),
+					name.inner().as_str()
+				),
+				Some(*label.span()),
+			));
+		}
+
+		Ok(())
+	}
+
+	/// Ensure the current standard is one of the given `stds`, reporting `feature` if not
+	pub(crate) fn require_std(&mut self, stds: LanguageStd, span: Span, feature: &str) {
+		if stds.contains(self.std) {
+			return;
+		}
+
+		self.diagnostics.push(StringDiagnostic::new(
+			unsupported_code(self.std),
+			format!(
+				"{feature} are not supported in {}, requires {}",
+				self.std,
+				earliest_std(stds)
+			),
+			Some(span),
+		));
+	}
+
+	/// Save the current position so the parser can backtrack to it
+	pub(crate) fn checkpoint(&self) -> Checkpoint {
+		(self.position, self.diagnostics.len())
+	}
+
+	/// Backtrack to a previously saved position, discarding any diagnostics since then
+	pub(crate) fn restore(&mut self, (position, diagnostics): Checkpoint) {
+		self.position = position;
+		self.diagnostics.truncate(diagnostics);
+	}
+
+	/// Returns true if the current token came from a `-- psl` comment
+	pub(crate) fn in_psl(&self) -> bool {
+		self.psl.iter().any(|range| range.contains(&self.position))
+	}
+
+	#[inline(always)]
+	pub(crate) fn at_eof(&self) -> bool {
+		self.position >= self.tokens.len()
+	}
+
+	#[inline(always)]
+	pub(crate) fn peek(&self) -> Option<&Token> {
+		self.peek_nth(0)
+	}
+
+	#[inline(always)]
+	pub(crate) fn peek_nth(&self, offset: usize) -> Option<&Token> {
+		self.tokens.get(self.position + offset).map(Spanned::inner)
+	}
+
+	/// The span of the current token, or an empty span at the end of the file
+	pub(crate) fn span(&self) -> Span {
+		match self.tokens.get(self.position) {
+			Some(token) => *token.span(),
+			None => match self.tokens.last() {
+				Some(token) => Span::from_position(
+					*token.span().end(),
+					*token.span().end(),
+					token.span().get_position(),
+				),
+				None => Span::empty(),
+			},
+		}
+	}
+
+	/// Create a span starting at `start` and ending at the end of the previously consumed token
+	pub(crate) fn span_from(&self, start: Span) -> Span {
+		let end = match self
+			.position
+			.checked_sub(1)
+			.and_then(|idx| self.tokens.get(idx))
+		{
+			Some(token) => (*token.span().end()).max(*start.begin()),
+			None => *start.begin(),
+		};
+
+		Span::from_position(*start.begin(), end, start.get_position())
+	}
+
+	pub(crate) fn bump(&mut self) -> Option<Spanned<Token>> {
+		let token = self.tokens.get(self.position).cloned();
+		if token.is_some() {
+			self.position += 1;
+		}
+		token
+	}
+
+	/// Get the source text for the given span
+	pub(crate) fn source_text(&self, span: &Span) -> AtomicByteTendril {
+		let begin = (*span.begin()).min(self.source.len32());
+		let end = (*span.end()).min(self.source.len32());
+
+		self.source.subtendril(begin, end.saturating_sub(begin))
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_keyword(&self, keyword: Keyword) -> bool {
+		self.is_keyword_at(0, keyword)
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_keyword_at(&self, offset: usize, keyword: Keyword) -> bool {
+		matches!(self.peek_nth(offset), Some(Token::Keyword(kw)) if *kw == keyword)
+	}
+
+	pub(crate) fn eat_keyword(&mut self, keyword: Keyword) -> bool {
+		if self.is_keyword(keyword) {
+			self.position += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	pub(crate) fn expect_keyword(&mut self, keyword: Keyword) -> ParseResult<Span> {
+		let span = self.span();
+		if self.eat_keyword(keyword) {
+			Ok(span)
+		} else {
+			self.unexpected(&format!("`{}`", keyword.as_str()))
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_control(&self, control: Control) -> bool {
+		self.is_control_at(0, control)
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_control_at(&self, offset: usize, control: Control) -> bool {
+		matches!(self.peek_nth(offset), Some(Token::Control(ctrl)) if *ctrl == control)
+	}
+
+	pub(crate) fn eat_control(&mut self, control: Control) -> bool {
+		if self.is_control(control) {
+			self.position += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	pub(crate) fn expect_control(&mut self, control: Control) -> ParseResult<Span> {
+		let span = self.span();
+		if self.eat_control(control) {
+			Ok(span)
+		} else {
+			self.unexpected(&format!("`{}`", control.as_str()))
+		}
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_operator(&self, operator: Operator) -> bool {
+		matches!(self.peek(), Some(Token::Operator(op)) if *op == operator)
+	}
+
+	pub(crate) fn eat_operator(&mut self, operator: Operator) -> bool {
+		if self.is_operator(operator) {
+			self.position += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	pub(crate) fn expect_operator(&mut self, operator: Operator) -> ParseResult<Span> {
+		let span = self.span();
+		if self.eat_operator(operator) {
+			Ok(span)
+		} else {
+			self.unexpected(&format!("`{}`", operator.as_str()))
+		}
+	}
+
+	/// Returns true if the current token is a basic or extended identifier
+	#[inline(always)]
+	pub(crate) fn is_identifier(&self) -> bool {
+		self.is_identifier_at(0)
+	}
+
+	#[inline(always)]
+	pub(crate) fn is_identifier_at(&self, offset: usize) -> bool {
+		matches!(
+			self.peek_nth(offset),
+			Some(Token::Identifier(_) | Token::ExtendedIdentifier(_))
+		)
+	}
+
+	pub(crate) fn expect_identifier(&mut self) -> ParseResult<Spanned<Identifier>> {
+		let ident = match self.peek() {
+			Some(Token::Identifier(ident)) => Identifier::Basic(ident.clone()),
+			Some(Token::ExtendedIdentifier(ident)) => Identifier::Extended(ident.clone()),
+			_ => return self.unexpected("an identifier"),
+		};
+
+		let ident = Spanned::new(ident, self.span());
+		self.position += 1;
+
+		Ok(ident)
+	}
+
+	/// Returns true if the current token is the word `word`, either as a keyword or identifier,
+	/// this is used for PSL, which has its own set of keywords
+	pub(crate) fn is_word(&self, word: &str) -> bool {
+		self.is_word_at(0, word)
+	}
+
+	pub(crate) fn is_word_at(&self, offset: usize, word: &str) -> bool {
+		match self.peek_nth(offset) {
+			Some(Token::Identifier(ident)) => ident.eq_ignore_ascii_case(word.as_bytes()),
+			Some(Token::Keyword(keyword)) => keyword.as_str() == word,
+			_ => false,
+		}
+	}
+
+	/// Produce an error describing the current token and what was expected instead
+	pub(crate) fn unexpected<T>(&self, expected: &str) -> ParseResult<T> {
+		let span = self.span();
+
+		let message = match self.tokens.get(self.position) {
+			None => format!("Expected {expected}, found end of file"),
+			Some(token) => match token.inner() {
+				Token::ContextuallyInvalid(text, stds) => {
+					format!(
+						"`{}` is not valid in {}, requires {}",
+						unsafe { str::from_utf8_unchecked(text) },
+						self.std,
+						earliest_std(*stds)
+					)
+				},
+				_ => {
+					let text = self.source_text(token.span());
+					format!("Expected {expected}, found `{}`", unsafe {
+						str::from_utf8_unchecked(&text)
+					})
+				},
+			},
+		};
+
+		Err(ParseError::new(message, span))
+	}
+}
+
+/// If `text`, the contents of a single line comment, is a `-- psl` comment then tokenize the rest
+/// of it, with the spans of the tokens being relative to the file
+fn psl_comment_tokens(
+	std: LanguageStd,
+	text: &AtomicByteTendril,
+	span: &Span,
+) -> Option<Vec<Spanned<Token>>> {
+	let indent = text
+		.iter()
+		.take_while(|byte| matches!(byte, b' ' | b'\t'))
+		.count();
+	let rest = &text[indent..];
+
+	if rest.len() < 3 ||
+		!rest[..3].eq_ignore_ascii_case(b"psl") ||
+		rest.get(3)
+			.is_some_and(|byte| !matches!(byte, b' ' | b'\t'))
+	{
+		return None;
+	}
+
+	// The offset of the embedded PSL from the start of the comment, including the `--`
+	let offset = (indent + 5) as u32;
+	let contents = text.subtendril(offset - 2, text.len32() - (offset - 2));
+
+	let mut tokenizer = VhdlTokenizer::new(std, contents).ok()?;
+	let begin = *span.begin() + offset;
+	let position = span.get_position();
+
+	Some(
+		TokenizerIter::new(&mut tokenizer)
+			.filter(|token| {
+				!matches!(
+					token.inner(),
+					Token::Whitespace(_) | Token::Newline(_) | Token::Comment(_)
+				)
+			})
+			.map(|token| {
+				let (inner, token_span) = (token.inner().clone(), *token.span());
+				Spanned::new(
+					inner,
+					Span::from_position(
+						begin + *token_span.begin(),
+						begin + *token_span.end(),
+						Position::new(
+							*position.line(),
+							*position.character() + offset + *token_span.character(),
+						),
+					),
+				)
+			})
+			.collect(),
+	)
+}
+
+/// The earliest standard out of `stds`
+fn earliest_std(stds: LanguageStd) -> LanguageStd {
+	LanguageStd::flags()
+		.find(|(_, std)| stds.contains(*std))
+		.map(|(_, std)| *std)
+		.unwrap_or(stds)
+}
+
+/// The diagnostic code for constructs that are unsupported in `std`
+fn unsupported_code(std: LanguageStd) -> Code {
+	if LanguageStd::VHDL_AMS_STDS.contains(std) {
+		vhdl_ams::E0001
+	} else {
+		vhdl::E0001
+	}
+}
+
+/// The diagnostic code for syntax errors in `std`
+fn syntax_error_code(std: LanguageStd) -> Code {
+	if LanguageStd::VHDL_AMS_STDS.contains(std) {
+		vhdl_ams::E0002
+	} else {
+		vhdl::E0002
+	}
+}
+
+/// Tokenize `text` as `std`, returning the token if it is a single token
+fn reinterpret_token(text: &AtomicByteTendril, std: LanguageStd) -> Option<Token> {
+	let mut tokenizer = VhdlTokenizer::new(std, text.clone()).ok()?;
+	let mut tokens = TokenizerIter::new(&mut tokenizer).map(Spanned::as_inner);
+
+	match (tokens.next(), tokens.next()) {
+		(Some(token), None) if !matches!(token, Token::ContextuallyInvalid(..)) => Some(token),
+		_ => None,
+	}
 }
 
 #[cfg(test)]
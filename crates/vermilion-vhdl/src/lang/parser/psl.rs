@@ -0,0 +1,207 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	ast::{
+		PslDeclaration, PslDeclarationKind, PslDirective, PslDirectiveKind, VerificationUnit,
+		VerificationUnitKind,
+	},
+	parser::{VHDL_08_STDS, VhdlParser, error::ParseResult},
+	tokenizer::token::{Control, Token},
+};
+
+// NOTE(aki):
+// PSL has its own grammar which is not parsed here, only the extent of each PSL construct is
+// found so that the surrounding VHDL can be parsed, the constructs themselves are kept as their
+// source text for later handling.
+impl VhdlParser {
+	/// Returns true if PSL constructs can appear at the current position, which is either within
+	/// a `-- psl` comment or anywhere in IEEE 1076-2008 and later
+	fn psl_allowed(&self) -> bool {
+		self.in_psl() || VHDL_08_STDS.contains(self.std)
+	}
+
+	/// Returns true if the current position is the start of a PSL declaration
+	///
+	/// IEEE 1076-2008 § 12.3 (PSL)
+	pub(crate) fn is_psl_declaration_start(&self) -> bool {
+		if !self.psl_allowed() {
+			return false;
+		}
+
+		((self.is_word("property") || self.is_word("sequence") || self.is_word("endpoint")) &&
+			self.is_identifier_at(1)) ||
+			(self.is_word("default") && self.is_word_at(1, "clock"))
+	}
+
+	/// IEEE 1076-2008 § 12.3 (PSL), IEEE 1850-2010 § 6.1
+	pub(crate) fn parse_psl_declaration(&mut self) -> ParseResult<PslDeclaration> {
+		let start = self.span();
+
+		let (kind, name) = if self.is_word("default") {
+			self.bump();
+			self.bump();
+			(PslDeclarationKind::DefaultClock, None)
+		} else {
+			let kind = if self.is_word("property") {
+				PslDeclarationKind::Property
+			} else if self.is_word("sequence") {
+				PslDeclarationKind::Sequence
+			} else {
+				PslDeclarationKind::Endpoint
+			};
+			self.bump();
+
+			(kind, Some(self.expect_identifier()?))
+		};
+
+		self.skip_psl()?;
+		let text = self.source_text(&self.span_from(start));
+
+		Ok(PslDeclaration { kind, name, text })
+	}
+
+	/// Returns true if the current position is the start of a PSL verification directive
+	///
+	/// Within a `-- psl` comment an `assert` is a PSL directive, elsewhere it is taken to be a
+	/// VHDL assertion.
+	///
+	/// IEEE 1076-2008 § 11.1 (PSL)
+	pub(crate) fn is_psl_directive_start(&self) -> bool {
+		if !self.psl_allowed() {
+			return false;
+		}
+
+		(self.in_psl() && self.is_word("assert")) ||
+			self.is_word("assume") ||
+			self.is_word("assume_guarantee") ||
+			self.is_word("restrict") ||
+			self.is_word("restrict_guarantee") ||
+			self.is_word("cover") ||
+			self.is_word("fairness") ||
+			(self.is_word("strong") && self.is_word_at(1, "fairness"))
+	}
+
+	/// IEEE 1076-2008 § 11.1 (PSL), IEEE 1850-2010 § 7.1
+	pub(crate) fn parse_psl_directive(&mut self) -> ParseResult<PslDirective> {
+		let start = self.span();
+
+		let kind = if self.is_word("assert") {
+			PslDirectiveKind::Assert
+		} else if self.is_word("assume") {
+			PslDirectiveKind::Assume
+		} else if self.is_word("assume_guarantee") {
+			PslDirectiveKind::AssumeGuarantee
+		} else if self.is_word("restrict") {
+			PslDirectiveKind::Restrict
+		} else if self.is_word("restrict_guarantee") {
+			PslDirectiveKind::RestrictGuarantee
+		} else if self.is_word("cover") {
+			PslDirectiveKind::Cover
+		} else {
+			PslDirectiveKind::Fairness
+		};
+
+		self.skip_psl()?;
+		let text = self.source_text(&self.span_from(start));
+
+		Ok(PslDirective { kind, text })
+	}
+
+	/// Skip to and over the `;` that ends the current PSL construct
+	fn skip_psl(&mut self) -> ParseResult<()> {
+		let mut depth = 0usize;
+
+		while let Some(token) = self.peek() {
+			match token {
+				Token::Control(Control::ParenOpen | Control::BracketOpen) => depth += 1,
+				Token::Invalid(Some(text)) if &**text == b"{" => depth += 1,
+				Token::Control(Control::ParenClose | Control::BracketClose) => {
+					depth = depth.saturating_sub(1);
+				},
+				Token::Invalid(Some(text)) if &**text == b"}" => depth = depth.saturating_sub(1),
+				Token::Control(Control::Semicolon) if depth == 0 => {
+					self.bump();
+					return Ok(());
+				},
+				_ => {},
+			}
+
+			self.bump();
+		}
+
+		self.unexpected("`;`")
+	}
+
+	/// Returns true if the current position is the start of a PSL verification unit
+	///
+	/// IEEE 1076-2008 § 12.3 (PSL)
+	pub(crate) fn is_verification_unit_start(&self) -> bool {
+		self.psl_allowed() &&
+			(self.is_word("vunit") || self.is_word("vprop") || self.is_word("vmode")) &&
+			self.is_identifier_at(1)
+	}
+
+	/// IEEE 1076-2008 § 12.3 (PSL), IEEE 1850-2010 § 7.1
+	pub(crate) fn parse_verification_unit(&mut self) -> ParseResult<VerificationUnit> {
+		let kind = if self.is_word("vunit") {
+			VerificationUnitKind::Vunit
+		} else if self.is_word("vprop") {
+			VerificationUnitKind::Vprop
+		} else {
+			VerificationUnitKind::Vmode
+		};
+		self.bump();
+
+		let name = self.expect_identifier()?;
+
+		let target = if self.eat_control(Control::ParenOpen) {
+			let target = self.parse_name()?;
+			self.expect_control(Control::ParenClose)?;
+			Some(target)
+		} else {
+			None
+		};
+
+		if !self.is_brace(b"{") {
+			return self.unexpected("`{`");
+		}
+		self.bump();
+
+		let start = self.span();
+		let mut depth = 0usize;
+		let body = loop {
+			if self.at_eof() {
+				return self.unexpected("`}`");
+			}
+
+			if self.is_brace(b"{") {
+				depth += 1;
+			} else if self.is_brace(b"}") {
+				if depth == 0 {
+					let body = self.span_from(start);
+					self.bump();
+					break body;
+				}
+				depth -= 1;
+			}
+
+			self.bump();
+		};
+
+		// An empty body ends before it starts
+		let text = if body.begin() < start.begin() {
+			self.source.subtendril(0, 0)
+		} else {
+			self.source_text(&body)
+		};
+
+		Ok(VerificationUnit { kind, name, target, body: Spanned::new(text, body) })
+	}
+
+	/// Returns true if the current token is the given brace, which are not VHDL tokens
+	fn is_brace(&self, brace: &[u8]) -> bool {
+		matches!(self.peek(), Some(Token::Invalid(Some(text))) if &**text == brace)
+	}
+}
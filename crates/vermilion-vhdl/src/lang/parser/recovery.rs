@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_diagnostics::StringDiagnostic;
+use vermilion_loc::Spanned;
+
+use crate::lang::{
+	parser::{
+		VhdlParser,
+		error::{ParseError, ParseResult},
+		syntax_error_code,
+	},
+	tokenizer::token::{Control, Keyword, Token},
+};
+
+impl VhdlParser {
+	/// Parse the elements of a construct up to one of its closing `ends` keywords, which is left
+	/// for the caller to consume, returning them along with whether the closing keyword was found
+	///
+	/// An element that fails to parse is reported and replaced with the node built by `error`,
+	/// parsing then resumes after the next `;` or at the next closing keyword of this or an
+	/// enclosing construct.
+	pub(crate) fn parse_list<T, P, E>(
+		&mut self,
+		ends: &[Keyword],
+		mut parse: P,
+		error: E,
+	) -> (Vec<Spanned<T>>, bool)
+	where
+		P: FnMut(&mut Self) -> ParseResult<Spanned<T>>,
+		E: Fn() -> T,
+	{
+		let depth = self.closers.len();
+		self.closers.extend_from_slice(ends);
+
+		let mut elements = Vec::new();
+		let closed = loop {
+			if let Some(Token::Keyword(keyword)) = self.peek() &&
+				ends.contains(keyword)
+			{
+				break true;
+			}
+
+			if self.at_eof() || self.at_boundary(depth) {
+				let expected = ends
+					.iter()
+					.map(|keyword| format!("`{}`", keyword.as_str()))
+					.collect::<Vec<_>>()
+					.join(" or ");
+
+				if let Err(err) = self.unexpected::<()>(&expected) {
+					self.report(err);
+				}
+				break false;
+			}
+
+			let start = self.span();
+			let position = self.position;
+			match parse(self) {
+				Ok(element) => elements.push(element),
+				Err(err) => {
+					self.report(err);
+					self.synchronize();
+
+					// Always make progress, the error may have been at a closing keyword of an
+					// enclosing construct that is not expected here
+					if self.position == position {
+						self.bump();
+					}
+
+					elements.push(Spanned::new(error(), self.span_from(start)));
+				},
+			}
+		};
+
+		self.closers.truncate(depth);
+
+		(elements, closed)
+	}
+
+	/// Record a syntax error as a diagnostic so parsing can continue
+	pub(crate) fn report(&mut self, err: ParseError) {
+		self.diagnostics.push(StringDiagnostic::new(
+			syntax_error_code(self.std),
+			err.message(),
+			Some(*err.span()),
+		));
+	}
+
+	/// Skip tokens up to and including the next `;`, or up to the next closing keyword of an
+	/// enclosing construct, whichever comes first
+	///
+	/// Any bracketed groups, and any constructs such as `if`/`end if` and `process`/`end process`
+	/// that are opened while skipping are skipped over entirely.
+	pub(crate) fn synchronize(&mut self) {
+		let mut brackets = 0usize;
+		let mut blocks = 0usize;
+
+		while let Some(token) = self.peek() {
+			match token {
+				Token::Control(Control::ParenOpen | Control::BracketOpen) => {
+					brackets += 1;
+				},
+				Token::Control(Control::ParenClose | Control::BracketClose) => {
+					brackets = brackets.saturating_sub(1);
+				},
+				Token::Control(Control::Semicolon) if brackets == 0 && blocks == 0 => {
+					self.bump();
+					return;
+				},
+				Token::Keyword(keyword) if is_block_start(*keyword) => {
+					blocks += 1;
+				},
+				// The keyword after the `end` is skipped with it so it is not counted as opening
+				// another block
+				Token::Keyword(Keyword::End) if blocks > 0 => {
+					blocks -= 1;
+					self.bump();
+					if let Some(Token::Keyword(keyword)) = self.peek() &&
+						is_block_start(*keyword)
+					{
+						self.bump();
+					}
+					continue;
+				},
+				Token::Keyword(keyword) if self.closers.contains(keyword) => return,
+				_ if self.is_unit_start() => return,
+				_ => {},
+			}
+
+			self.bump();
+		}
+	}
+
+	/// Skip the remainder of a library unit that failed to parse, stopping at the start of the
+	/// next one
+	pub(crate) fn synchronize_unit(&mut self, start: usize) {
+		// Always make progress, the error may have been at the very first token
+		if self.position == start {
+			self.bump();
+		}
+
+		while !self.at_eof() && !self.is_unit_start() {
+			self.bump();
+		}
+	}
+
+	/// Returns true if the current token closes one of the enclosing constructs, whose closing
+	/// keywords are the first `depth` entries of `closers`, or starts a new library unit
+	fn at_boundary(&self, depth: usize) -> bool {
+		match self.peek() {
+			Some(Token::Keyword(keyword)) if self.closers[..depth].contains(keyword) => true,
+			_ => self.is_unit_start(),
+		}
+	}
+
+	/// Returns true if the current token can only be the start of a new design unit, which is
+	/// a keyword that can only start one at the start of a statement
+	fn is_unit_start(&self) -> bool {
+		let after_statement = self
+			.position
+			.checked_sub(1)
+			.and_then(|idx| self.tokens.get(idx))
+			.is_none_or(|token| matches!(token.inner(), Token::Control(Control::Semicolon)));
+
+		after_statement &&
+			matches!(
+				self.peek(),
+				Some(Token::Keyword(
+					Keyword::Entity |
+						Keyword::Architecture |
+						Keyword::Configuration |
+						Keyword::Library
+				))
+			)
+	}
+}
+
+/// Keywords that open a construct which is closed by `end keyword`
+fn is_block_start(keyword: Keyword) -> bool {
+	matches!(
+		keyword,
+		Keyword::If |
+			Keyword::Case |
+			Keyword::Loop |
+			Keyword::Process |
+			Keyword::Block |
+			Keyword::Record |
+			Keyword::Units |
+			Keyword::Protected |
+			Keyword::Procedural
+	)
+}
@@ -0,0 +1,1051 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::{Span, Spanned};
+
+use crate::lang::{
+	ast::{
+		Assertion, AssignmentKind, Block, CaseGenerateAlternative, ConcurrentSignalAssignment,
+		ConcurrentStatement, ConcurrentStatementKind, Conditional, DelayMechanism, Expression,
+		ForceMode, GenerateBody, GenerateBranch, Identifier, InstantiatedUnit, Instantiation,
+		IterationScheme, Name, Process, Selected, SensitivityList, SequentialStatement,
+		SequentialStatementKind, Waveform, WaveformElement,
+	},
+	parser::{
+		VHDL_08_STDS, VHDL_93_STDS, VhdlParser,
+		error::{ParseError, ParseResult},
+		expr::expression_to_name,
+	},
+	tokenizer::token::{Control, Keyword, Operator, Token},
+};
+
+impl VhdlParser {
+	/// Parse the concurrent statements of a statement part up to one of the `ends` keywords
+	pub(crate) fn parse_concurrent_statements(
+		&mut self,
+		ends: &[Keyword],
+	) -> (Vec<Spanned<ConcurrentStatement>>, bool) {
+		self.parse_list(ends, Self::parse_concurrent_statement, || {
+			ConcurrentStatement::new(ConcurrentStatementKind::Error)
+		})
+	}
+
+	/// Parse the sequential statements of a statement part up to one of the `ends` keywords
+	pub(crate) fn parse_sequential_statements(
+		&mut self,
+		ends: &[Keyword],
+	) -> (Vec<Spanned<SequentialStatement>>, bool) {
+		self.parse_list(ends, Self::parse_sequential_statement, || {
+			SequentialStatement::new(SequentialStatementKind::Error)
+		})
+	}
+
+	/// Parse a `label :` if there is one
+	fn parse_label(&mut self) -> ParseResult<Option<Spanned<Identifier>>> {
+		if !self.is_identifier() || !self.is_control_at(1, Control::Colon) {
+			return Ok(None);
+		}
+
+		let label = self.expect_identifier()?;
+		self.expect_control(Control::Colon)?;
+
+		Ok(Some(label))
+	}
+
+	/// IEEE 1076-2008 § 11.1
+	fn parse_concurrent_statement(&mut self) -> ParseResult<Spanned<ConcurrentStatement>> {
+		let start = self.span();
+		let label = self.parse_label()?;
+
+		let postponed_span = self.span();
+		let postponed = self.eat_keyword(Keyword::Postponed);
+		if postponed {
+			self.require_std(VHDL_93_STDS, postponed_span, "Postponed statements");
+		}
+
+		// A labelled name can be either an instantiation or the start of an assignment or call
+		let instantiation =
+			label.is_some() && self.is_identifier() && self.is_component_instantiation();
+
+		let kind = match self.peek() {
+			Some(Token::Keyword(Keyword::Process)) => {
+				ConcurrentStatementKind::Process(self.parse_process(label.as_ref())?)
+			},
+			Some(Token::Keyword(Keyword::Block)) => {
+				ConcurrentStatementKind::Block(self.parse_block(label.as_ref())?)
+			},
+			_ if self.is_psl_directive_start() => {
+				ConcurrentStatementKind::Psl(self.parse_psl_directive()?)
+			},
+			Some(Token::Keyword(Keyword::Assert)) => {
+				let assertion = self.parse_assertion()?;
+				self.expect_control(Control::Semicolon)?;
+
+				ConcurrentStatementKind::Assertion(assertion)
+			},
+			Some(Token::Keyword(Keyword::For)) => self.parse_for_generate(label.as_ref())?,
+			Some(Token::Keyword(Keyword::If)) if self.is_simultaneous_use() => {
+				self.parse_simultaneous_if(label.as_ref())?
+			},
+			Some(Token::Keyword(Keyword::If)) => self.parse_if_generate(label.as_ref())?,
+			Some(Token::Keyword(Keyword::Case)) if self.is_simultaneous_use() => {
+				self.parse_simultaneous_case(label.as_ref())?
+			},
+			Some(Token::Keyword(Keyword::Case)) => self.parse_case_generate(label.as_ref())?,
+			Some(Token::Keyword(Keyword::Procedural)) => {
+				ConcurrentStatementKind::SimultaneousProcedural(
+					self.parse_simultaneous_procedural(label.as_ref())?,
+				)
+			},
+			Some(Token::Keyword(Keyword::Null))
+				if self.is_ams() && self.is_control_at(1, Control::Semicolon) =>
+			{
+				self.bump();
+				self.bump();
+				ConcurrentStatementKind::SimultaneousNull
+			},
+			Some(Token::Keyword(Keyword::Break)) => self.parse_concurrent_break()?,
+			Some(Token::Keyword(Keyword::With)) => self.parse_concurrent_selected_assignment()?,
+			Some(Token::Keyword(Keyword::Component | Keyword::Entity | Keyword::Configuration)) => {
+				ConcurrentStatementKind::Instantiation(self.parse_instantiation()?)
+			},
+			_ if instantiation => {
+				ConcurrentStatementKind::Instantiation(self.parse_instantiation()?)
+			},
+			_ if self.is_simple_simultaneous() => self.parse_simple_simultaneous()?,
+			_ => self.parse_concurrent_assignment_or_call()?,
+		};
+
+		Ok(Spanned::new(
+			ConcurrentStatement { label, postponed, kind },
+			self.span_from(start),
+		))
+	}
+
+	/// IEEE 1076-2008 § 11.3
+	fn parse_process(&mut self, label: Option<&Spanned<Identifier>>) -> ParseResult<Process> {
+		self.expect_keyword(Keyword::Process)?;
+
+		let sensitivity = if self.eat_control(Control::ParenOpen) {
+			let sensitivity = if self.is_keyword(Keyword::All) {
+				self.require_std(VHDL_08_STDS, self.span(), "`all` sensitivity lists");
+				self.bump();
+				SensitivityList::All
+			} else {
+				SensitivityList::Names(self.parse_name_list()?)
+			};
+			self.expect_control(Control::ParenClose)?;
+
+			Some(sensitivity)
+		} else {
+			None
+		};
+
+		self.eat_optional_is();
+
+		let (declarations, closed) = self.parse_declarations(&[Keyword::Begin]);
+		if !closed {
+			return Ok(Process { sensitivity, declarations, statements: Vec::new() });
+		}
+
+		self.expect_keyword(Keyword::Begin)?;
+		let (statements, closed) = self.parse_sequential_statements(&[Keyword::End]);
+
+		if closed {
+			self.expect_keyword(Keyword::End)?;
+			self.eat_keyword(Keyword::Postponed);
+			self.expect_keyword(Keyword::Process)?;
+			self.parse_end_label(label)?;
+			self.expect_control(Control::Semicolon)?;
+		}
+
+		Ok(Process { sensitivity, declarations, statements })
+	}
+
+	/// IEEE 1076-2008 § 11.2
+	fn parse_block(&mut self, label: Option<&Spanned<Identifier>>) -> ParseResult<Block> {
+		self.expect_keyword(Keyword::Block)?;
+
+		let guard = if self.eat_control(Control::ParenOpen) {
+			let guard = self.parse_expression()?;
+			self.expect_control(Control::ParenClose)?;
+			Some(guard)
+		} else {
+			None
+		};
+
+		self.eat_optional_is();
+
+		let generics = self.parse_generic_clause()?;
+		let generic_map = if generics.is_some() {
+			let generic_map = self.parse_generic_map()?;
+			if generic_map.is_some() {
+				self.expect_control(Control::Semicolon)?;
+			}
+			generic_map
+		} else {
+			None
+		};
+
+		let ports = self.parse_port_clause()?;
+		let port_map = if ports.is_some() {
+			let port_map = self.parse_port_map()?;
+			if port_map.is_some() {
+				self.expect_control(Control::Semicolon)?;
+			}
+			port_map
+		} else {
+			None
+		};
+
+		let (declarations, closed) = self.parse_declarations(&[Keyword::Begin]);
+
+		let statements = if closed {
+			self.expect_keyword(Keyword::Begin)?;
+
+			let (statements, closed) = self.parse_concurrent_statements(&[Keyword::End]);
+			if closed {
+				self.parse_end(&[Keyword::Block], true, label)?;
+			}
+
+			statements
+		} else {
+			Vec::new()
+		};
+
+		Ok(Block {
+			guard,
+			generics,
+			generic_map,
+			ports,
+			port_map,
+			declarations,
+			statements,
+		})
+	}
+
+	/// Consume the `is` that can optionally follow the header of a process or block
+	fn eat_optional_is(&mut self) {
+		let span = self.span();
+		if self.eat_keyword(Keyword::Is) {
+			self.require_std(VHDL_93_STDS, span, "`is` after process and block headers");
+		}
+	}
+
+	/// Parse a comma separated list of names
+	pub(crate) fn parse_name_list(&mut self) -> ParseResult<Vec<Spanned<Name>>> {
+		let mut names = vec![self.parse_name()?];
+		while self.eat_control(Control::Comma) {
+			names.push(self.parse_name()?);
+		}
+
+		Ok(names)
+	}
+
+	/// Parse an assertion without the trailing `;`
+	///
+	/// IEEE 1076-2008 § 10.3
+	fn parse_assertion(&mut self) -> ParseResult<Assertion> {
+		self.expect_keyword(Keyword::Assert)?;
+		let condition = self.parse_expression()?;
+
+		let report = if self.eat_keyword(Keyword::Report) {
+			Some(self.parse_expression()?)
+		} else {
+			None
+		};
+
+		let severity = if self.eat_keyword(Keyword::Severity) {
+			Some(self.parse_expression()?)
+		} else {
+			None
+		};
+
+		Ok(Assertion { condition, report, severity })
+	}
+
+	/// Returns true if the current position is a component instantiation using only the name of
+	/// the component, that is a name followed by a generic map, port map, or `;`
+	fn is_component_instantiation(&mut self) -> bool {
+		let checkpoint = self.checkpoint();
+
+		let result = self.parse_type_mark().is_ok() &&
+			(self.is_control(Control::Semicolon) ||
+				((self.is_keyword(Keyword::Generic) || self.is_keyword(Keyword::Port)) &&
+					self.is_keyword_at(1, Keyword::Map)));
+
+		self.restore(checkpoint);
+		result
+	}
+
+	/// IEEE 1076-2008 § 11.7
+	fn parse_instantiation(&mut self) -> ParseResult<Instantiation> {
+		let start = self.span();
+
+		let unit = match self.peek() {
+			Some(Token::Keyword(Keyword::Entity)) => {
+				self.require_std(VHDL_93_STDS, start, "Direct entity instantiations");
+				self.bump();
+
+				let name = self.parse_type_mark()?;
+				let architecture = self.parse_architecture_identifier()?;
+
+				InstantiatedUnit::Entity { name, architecture }
+			},
+			Some(Token::Keyword(Keyword::Configuration)) => {
+				self.require_std(VHDL_93_STDS, start, "Direct configuration instantiations");
+				self.bump();
+
+				InstantiatedUnit::Configuration(self.parse_type_mark()?)
+			},
+			_ => {
+				if self.is_keyword(Keyword::Component) {
+					self.require_std(VHDL_93_STDS, start, "`component` in instantiations");
+					self.bump();
+				}
+
+				InstantiatedUnit::Component(self.parse_type_mark()?)
+			},
+		};
+
+		let generic_map = self.parse_generic_map()?;
+		let port_map = self.parse_port_map()?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(Instantiation { unit, generic_map, port_map })
+	}
+
+	/// IEEE 1076-2008 § 11.8
+	fn parse_for_generate(
+		&mut self,
+		label: Option<&Spanned<Identifier>>,
+	) -> ParseResult<ConcurrentStatementKind> {
+		self.expect_keyword(Keyword::For)?;
+		let parameter = self.expect_identifier()?;
+		self.expect_keyword(Keyword::In)?;
+		let range = self.parse_discrete_range()?;
+		self.expect_keyword(Keyword::Generate)?;
+
+		let (body, closed) = self.parse_generate_body(None, &[Keyword::End])?;
+		if closed {
+			self.parse_end(&[Keyword::Generate], true, label)?;
+		}
+
+		Ok(ConcurrentStatementKind::ForGenerate { parameter, range, body })
+	}
+
+	/// IEEE 1076-2008 § 11.8
+	fn parse_if_generate(
+		&mut self,
+		label: Option<&Spanned<Identifier>>,
+	) -> ParseResult<ConcurrentStatementKind> {
+		let ends = [Keyword::Elsif, Keyword::Else, Keyword::End];
+		let mut branches = Vec::new();
+
+		let mut start = self.expect_keyword(Keyword::If)?;
+		let mut conditional = true;
+		loop {
+			let alternative = self.parse_alternative_label()?;
+			let condition = if conditional {
+				Some(self.parse_expression()?)
+			} else {
+				None
+			};
+			self.expect_keyword(Keyword::Generate)?;
+
+			let (body, closed) = self.parse_generate_body(alternative, &ends)?;
+			branches.push(Spanned::new(
+				GenerateBranch { condition, body },
+				self.span_from(start),
+			));
+
+			if !closed {
+				return Ok(ConcurrentStatementKind::IfGenerate(branches));
+			}
+
+			start = self.span();
+			match self.peek() {
+				Some(Token::Keyword(Keyword::Elsif)) if conditional => conditional = true,
+				Some(Token::Keyword(Keyword::Else)) if conditional => conditional = false,
+				_ => break,
+			}
+
+			self.require_std(VHDL_08_STDS, start, "`elsif` and `else` generate branches");
+			self.bump();
+		}
+
+		self.parse_end(&[Keyword::Generate], true, label)?;
+
+		Ok(ConcurrentStatementKind::IfGenerate(branches))
+	}
+
+	/// IEEE 1076-2008 § 11.8
+	fn parse_case_generate(
+		&mut self,
+		label: Option<&Spanned<Identifier>>,
+	) -> ParseResult<ConcurrentStatementKind> {
+		let start = self.expect_keyword(Keyword::Case)?;
+		self.require_std(VHDL_08_STDS, start, "Case generate statements");
+
+		let expression = self.parse_expression()?;
+		self.expect_keyword(Keyword::Generate)?;
+
+		let mut alternatives = Vec::new();
+		while self.is_keyword(Keyword::When) {
+			let start = self.span();
+			self.bump();
+
+			let alternative = self.parse_alternative_label()?;
+			let choices = self.parse_choices()?;
+			self.expect_control(Control::Arrow)?;
+
+			let (body, closed) =
+				self.parse_generate_body(alternative, &[Keyword::When, Keyword::End])?;
+			alternatives.push(Spanned::new(
+				CaseGenerateAlternative { choices, body },
+				self.span_from(start),
+			));
+
+			if !closed {
+				return Ok(ConcurrentStatementKind::CaseGenerate { expression, alternatives });
+			}
+		}
+
+		self.parse_end(&[Keyword::Generate], true, label)?;
+
+		Ok(ConcurrentStatementKind::CaseGenerate { expression, alternatives })
+	}
+
+	/// Parse the IEEE 1076-2008 `label :` of a generate alternative, if there is one
+	fn parse_alternative_label(&mut self) -> ParseResult<Option<Spanned<Identifier>>> {
+		let start = self.span();
+		let label = self.parse_label()?;
+
+		if label.is_some() {
+			self.require_std(VHDL_08_STDS, self.span_from(start), "Alternative labels");
+		}
+
+		Ok(label)
+	}
+
+	/// Parse the declarations and statements of a generate statement, along with the IEEE
+	/// 1076-2008 `end [alternative];` that can close it
+	///
+	/// IEEE 1076-2008 § 11.8
+	fn parse_generate_body(
+		&mut self,
+		label: Option<Spanned<Identifier>>,
+		ends: &[Keyword],
+	) -> ParseResult<(GenerateBody, bool)> {
+		let declarations = if self.is_keyword(Keyword::Begin) || self.is_declaration_start() {
+			let (declarations, closed) = self.parse_declarations(&[Keyword::Begin]);
+			if !closed {
+				let body = GenerateBody { label, declarations, statements: Vec::new() };
+				return Ok((body, false));
+			}
+
+			self.expect_keyword(Keyword::Begin)?;
+			declarations
+		} else {
+			Vec::new()
+		};
+
+		let (statements, closed) = self.parse_concurrent_statements(ends);
+
+		if closed && self.is_keyword(Keyword::End) && !self.is_keyword_at(1, Keyword::Generate) {
+			let start = self.span();
+			self.bump();
+			self.parse_end_label(label.as_ref())?;
+			self.expect_control(Control::Semicolon)?;
+			self.require_std(
+				VHDL_08_STDS,
+				self.span_from(start),
+				"`end` in generate statement bodies",
+			);
+		}
+
+		Ok((GenerateBody { label, declarations, statements }, closed))
+	}
+
+	/// Returns true if the current token can only start a declaration and not a concurrent
+	/// statement, statements that start with `for` must have a label so an unlabelled `for` is
+	/// a configuration specification
+	fn is_declaration_start(&self) -> bool {
+		matches!(
+			self.peek(),
+			Some(Token::Keyword(
+				Keyword::Type |
+					Keyword::Subtype |
+					Keyword::Constant |
+					Keyword::Signal | Keyword::Variable |
+					Keyword::Shared | Keyword::File |
+					Keyword::Alias | Keyword::Component |
+					Keyword::Attribute |
+					Keyword::Function |
+					Keyword::Procedure |
+					Keyword::Pure | Keyword::Impure |
+					Keyword::Use | Keyword::For |
+					Keyword::Disconnect |
+					Keyword::Package |
+					Keyword::Nature | Keyword::SubNature |
+					Keyword::Terminal |
+					Keyword::Quantity |
+					Keyword::Limit
+			))
+		) || self.is_psl_declaration_start()
+	}
+
+	/// IEEE 1076-2008 § 11.6
+	fn parse_concurrent_selected_assignment(&mut self) -> ParseResult<ConcurrentStatementKind> {
+		let (expression, matching) = self.parse_selector()?;
+		let target = self.parse_primary()?;
+		self.expect_operator(Operator::LessThanEqual)?;
+
+		let guarded = self.eat_keyword(Keyword::Guarded);
+		let delay = self.parse_delay_mechanism()?;
+		let alternatives = self.parse_selected(Self::parse_waveform)?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(ConcurrentStatementKind::SignalAssignment(
+			ConcurrentSignalAssignment {
+				target,
+				guarded,
+				delay,
+				value: AssignmentKind::Selected { expression, matching, alternatives },
+			},
+		))
+	}
+
+	/// Parse the `with expression select[?]` that starts a selected assignment, returning the
+	/// expression and if it is a matching select
+	fn parse_selector(&mut self) -> ParseResult<(Spanned<Expression>, bool)> {
+		self.expect_keyword(Keyword::With)?;
+		let expression = self.parse_expression()?;
+		self.expect_keyword(Keyword::Select)?;
+		let matching = self.eat_control(Control::Question);
+
+		Ok((expression, matching))
+	}
+
+	/// Parse a concurrent signal assignment or concurrent procedure call
+	///
+	/// IEEE 1076-2008 § 11.4, § 11.6
+	fn parse_concurrent_assignment_or_call(&mut self) -> ParseResult<ConcurrentStatementKind> {
+		let target = self.parse_primary()?;
+
+		if self.eat_control(Control::Semicolon) {
+			return Ok(ConcurrentStatementKind::ProcedureCall(expression_to_name(
+				target,
+			)?));
+		}
+
+		self.expect_operator(Operator::LessThanEqual)?;
+
+		let guarded = self.eat_keyword(Keyword::Guarded);
+		let delay = self.parse_delay_mechanism()?;
+		let value = self.parse_conditional(Self::parse_waveform)?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(ConcurrentStatementKind::SignalAssignment(
+			ConcurrentSignalAssignment { target, guarded, delay, value },
+		))
+	}
+
+	/// Parse a delay mechanism, if there is one
+	///
+	/// IEEE 1076-2008 § 10.5.2.1
+	fn parse_delay_mechanism(&mut self) -> ParseResult<Option<DelayMechanism>> {
+		let start = self.span();
+
+		let delay = match self.peek() {
+			Some(Token::Keyword(Keyword::Transport)) => {
+				self.bump();
+				DelayMechanism::Transport
+			},
+			Some(Token::Keyword(Keyword::Reject)) => {
+				self.bump();
+				let reject = self.parse_expression()?;
+				self.expect_keyword(Keyword::Inertial)?;
+				self.require_std(VHDL_93_STDS, self.span_from(start), "Inertial delays");
+
+				DelayMechanism::Inertial(Some(reject))
+			},
+			Some(Token::Keyword(Keyword::Inertial)) => {
+				self.require_std(VHDL_93_STDS, start, "Inertial delays");
+				self.bump();
+				DelayMechanism::Inertial(None)
+			},
+			_ => return Ok(None),
+		};
+
+		Ok(Some(delay))
+	}
+
+	/// IEEE 1076-2008 § 10.5.2.1
+	fn parse_waveform(&mut self) -> ParseResult<Waveform> {
+		if self.is_keyword(Keyword::Unaffected) {
+			self.require_std(VHDL_93_STDS, self.span(), "`unaffected` waveforms");
+			self.bump();
+			return Ok(Waveform::Unaffected);
+		}
+
+		let mut elements = Vec::new();
+		loop {
+			let start = self.span();
+			let value = self.parse_expression()?;
+			let after = if self.eat_keyword(Keyword::After) {
+				Some(self.parse_expression()?)
+			} else {
+				None
+			};
+
+			elements.push(Spanned::new(
+				WaveformElement { value, after },
+				self.span_from(start),
+			));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		Ok(Waveform::Elements(elements))
+	}
+
+	/// Parse a value with `parse`, followed by any `when condition else value` alternatives
+	fn parse_conditional<T, P>(&mut self, mut parse: P) -> ParseResult<AssignmentKind<T>>
+	where
+		P: FnMut(&mut Self) -> ParseResult<T>,
+	{
+		let mut start = self.span();
+		let mut value = parse(self)?;
+
+		if !self.is_keyword(Keyword::When) {
+			return Ok(AssignmentKind::Simple(value));
+		}
+
+		let mut alternatives = Vec::new();
+		loop {
+			let condition = if self.eat_keyword(Keyword::When) {
+				Some(self.parse_expression()?)
+			} else {
+				None
+			};
+			let last = condition.is_none();
+
+			alternatives.push(Spanned::new(
+				Conditional { value, condition },
+				self.span_from(start),
+			));
+
+			if last || !self.is_keyword(Keyword::Else) {
+				break;
+			}
+
+			self.bump();
+			start = self.span();
+			value = parse(self)?;
+		}
+
+		Ok(AssignmentKind::Conditional(alternatives))
+	}
+
+	/// Parse the `value when choices` alternatives of a selected assignment
+	fn parse_selected<T, P>(&mut self, mut parse: P) -> ParseResult<Vec<Spanned<Selected<T>>>>
+	where
+		P: FnMut(&mut Self) -> ParseResult<T>,
+	{
+		let mut alternatives = Vec::new();
+		loop {
+			let start = self.span();
+			let value = parse(self)?;
+			self.expect_keyword(Keyword::When)?;
+			let choices = self.parse_choices()?;
+
+			alternatives.push(Spanned::new(
+				Selected { value, choices },
+				self.span_from(start),
+			));
+
+			if !self.eat_control(Control::Comma) {
+				break;
+			}
+		}
+
+		Ok(alternatives)
+	}
+
+	/// IEEE 1076-2008 § 10.1
+	fn parse_sequential_statement(&mut self) -> ParseResult<Spanned<SequentialStatement>> {
+		let start = self.span();
+		let label = self.parse_label()?;
+
+		let is_loop = matches!(
+			self.peek(),
+			Some(Token::Keyword(
+				Keyword::Loop | Keyword::While | Keyword::For
+			))
+		);
+		if label.is_some() && !is_loop {
+			self.require_std(
+				VHDL_93_STDS,
+				self.span_from(start),
+				"Labels on sequential statements other than loops",
+			);
+		}
+
+		let kind = match self.peek() {
+			Some(Token::Keyword(Keyword::Wait)) => self.parse_wait()?,
+			Some(Token::Keyword(Keyword::Assert)) => {
+				let assertion = self.parse_assertion()?;
+				self.expect_control(Control::Semicolon)?;
+
+				SequentialStatementKind::Assertion(assertion)
+			},
+			Some(Token::Keyword(Keyword::Report)) => {
+				self.require_std(VHDL_93_STDS, self.span(), "Report statements");
+				self.bump();
+
+				let message = self.parse_expression()?;
+				let severity = if self.eat_keyword(Keyword::Severity) {
+					Some(self.parse_expression()?)
+				} else {
+					None
+				};
+				self.expect_control(Control::Semicolon)?;
+
+				SequentialStatementKind::Report { message, severity }
+			},
+			Some(Token::Keyword(Keyword::If)) => self.parse_if(label.as_ref())?,
+			Some(Token::Keyword(Keyword::Case)) => self.parse_case(label.as_ref())?,
+			Some(Token::Keyword(Keyword::Loop | Keyword::While | Keyword::For)) => {
+				self.parse_loop(label.as_ref())?
+			},
+			Some(Token::Keyword(Keyword::Next | Keyword::Exit)) => {
+				let next = self.is_keyword(Keyword::Next);
+				self.bump();
+
+				let target = if self.is_identifier() {
+					Some(self.expect_identifier()?)
+				} else {
+					None
+				};
+				let condition = if self.eat_keyword(Keyword::When) {
+					Some(self.parse_expression()?)
+				} else {
+					None
+				};
+				self.expect_control(Control::Semicolon)?;
+
+				if next {
+					SequentialStatementKind::Next { target, condition }
+				} else {
+					SequentialStatementKind::Exit { target, condition }
+				}
+			},
+			Some(Token::Keyword(Keyword::Return)) => {
+				self.bump();
+				let value = if self.is_control(Control::Semicolon) {
+					None
+				} else {
+					Some(self.parse_expression()?)
+				};
+				self.expect_control(Control::Semicolon)?;
+
+				SequentialStatementKind::Return(value)
+			},
+			Some(Token::Keyword(Keyword::Null)) if self.is_control_at(1, Control::Semicolon) => {
+				self.bump();
+				self.bump();
+				SequentialStatementKind::Null
+			},
+			Some(Token::Keyword(Keyword::With)) => self.parse_sequential_selected_assignment()?,
+			Some(Token::Keyword(Keyword::Break)) => self.parse_sequential_break()?,
+			_ => self.parse_sequential_assignment_or_call()?,
+		};
+
+		Ok(Spanned::new(
+			SequentialStatement { label, kind },
+			self.span_from(start),
+		))
+	}
+
+	/// IEEE 1076-2008 § 10.2
+	fn parse_wait(&mut self) -> ParseResult<SequentialStatementKind> {
+		self.expect_keyword(Keyword::Wait)?;
+
+		let sensitivity = if self.eat_keyword(Keyword::On) {
+			self.parse_name_list()?
+		} else {
+			Vec::new()
+		};
+
+		let condition = if self.eat_keyword(Keyword::Until) {
+			Some(self.parse_expression()?)
+		} else {
+			None
+		};
+
+		let timeout = if self.eat_keyword(Keyword::For) {
+			Some(self.parse_expression()?)
+		} else {
+			None
+		};
+
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(SequentialStatementKind::Wait { sensitivity, condition, timeout })
+	}
+
+	/// IEEE 1076-2008 § 10.8
+	fn parse_if(
+		&mut self,
+		label: Option<&Spanned<Identifier>>,
+	) -> ParseResult<SequentialStatementKind> {
+		let ends = [Keyword::Elsif, Keyword::Else, Keyword::End];
+		let mut branches = Vec::new();
+
+		let mut start = self.expect_keyword(Keyword::If)?;
+		let mut condition = Some(self.parse_expression()?);
+		self.expect_keyword(Keyword::Then)?;
+
+		loop {
+			let (statements, closed) = self.parse_sequential_statements(&ends);
+			let last = condition.is_none();
+
+			branches.push(Spanned::new(
+				Conditional { value: statements, condition },
+				self.span_from(start),
+			));
+
+			if !closed {
+				return Ok(SequentialStatementKind::If(branches));
+			}
+
+			start = self.span();
+			match self.peek() {
+				Some(Token::Keyword(Keyword::Elsif)) if !last => {
+					self.bump();
+					condition = Some(self.parse_expression()?);
+					self.expect_keyword(Keyword::Then)?;
+				},
+				Some(Token::Keyword(Keyword::Else)) if !last => {
+					self.bump();
+					condition = None;
+				},
+				_ => break,
+			}
+		}
+
+		self.parse_end(&[Keyword::If], true, label)?;
+
+		Ok(SequentialStatementKind::If(branches))
+	}
+
+	/// IEEE 1076-2008 § 10.9
+	fn parse_case(
+		&mut self,
+		label: Option<&Spanned<Identifier>>,
+	) -> ParseResult<SequentialStatementKind> {
+		self.expect_keyword(Keyword::Case)?;
+		let matching = self.eat_control(Control::Question);
+
+		let expression = self.parse_expression()?;
+		self.expect_keyword(Keyword::Is)?;
+
+		let mut alternatives = Vec::new();
+		while self.is_keyword(Keyword::When) {
+			let start = self.span();
+			self.bump();
+
+			let choices = self.parse_choices()?;
+			self.expect_control(Control::Arrow)?;
+
+			let (statements, closed) =
+				self.parse_sequential_statements(&[Keyword::When, Keyword::End]);
+			alternatives.push(Spanned::new(
+				Selected { value: statements, choices },
+				self.span_from(start),
+			));
+
+			if !closed {
+				return Ok(SequentialStatementKind::Case { matching, expression, alternatives });
+			}
+		}
+
+		self.expect_keyword(Keyword::End)?;
+		self.expect_keyword(Keyword::Case)?;
+		if matching {
+			self.expect_control(Control::Question)?;
+		}
+		self.parse_end_label(label)?;
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(SequentialStatementKind::Case { matching, expression, alternatives })
+	}
+
+	/// IEEE 1076-2008 § 10.10
+	fn parse_loop(
+		&mut self,
+		label: Option<&Spanned<Identifier>>,
+	) -> ParseResult<SequentialStatementKind> {
+		let scheme = match self.peek() {
+			Some(Token::Keyword(Keyword::While)) => {
+				self.bump();
+				Some(IterationScheme::While(self.parse_expression()?))
+			},
+			Some(Token::Keyword(Keyword::For)) => {
+				self.bump();
+				let parameter = self.expect_identifier()?;
+				self.expect_keyword(Keyword::In)?;
+				let range = Box::new(self.parse_discrete_range()?);
+
+				Some(IterationScheme::For { parameter, range })
+			},
+			_ => None,
+		};
+
+		self.expect_keyword(Keyword::Loop)?;
+
+		let (statements, closed) = self.parse_sequential_statements(&[Keyword::End]);
+		if closed {
+			self.parse_end(&[Keyword::Loop], true, label)?;
+		}
+
+		Ok(SequentialStatementKind::Loop { scheme, statements })
+	}
+
+	/// IEEE 1076-2008 § 10.5.4, § 10.6.4
+	fn parse_sequential_selected_assignment(&mut self) -> ParseResult<SequentialStatementKind> {
+		let start = self.span();
+		let (expression, matching) = self.parse_selector()?;
+		self.require_std(
+			VHDL_08_STDS,
+			self.span_from(start),
+			"Sequential selected assignments",
+		);
+
+		let target = self.parse_primary()?;
+
+		let kind = if self.eat_control(Control::VariableAssignment) {
+			let alternatives = self.parse_selected(Self::parse_expression)?;
+
+			SequentialStatementKind::VariableAssignment {
+				target,
+				value: AssignmentKind::Selected { expression, matching, alternatives },
+			}
+		} else {
+			self.expect_operator(Operator::LessThanEqual)?;
+
+			if self.eat_keyword(Keyword::Force) {
+				let mode = self.parse_force_mode();
+				let alternatives = self.parse_selected(Self::parse_expression)?;
+
+				SequentialStatementKind::Force {
+					target,
+					mode,
+					value: AssignmentKind::Selected { expression, matching, alternatives },
+				}
+			} else {
+				let delay = self.parse_delay_mechanism()?;
+				let alternatives = self.parse_selected(Self::parse_waveform)?;
+
+				SequentialStatementKind::SignalAssignment {
+					target,
+					delay,
+					value: AssignmentKind::Selected { expression, matching, alternatives },
+				}
+			}
+		};
+
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(kind)
+	}
+
+	/// Parse a sequential signal or variable assignment, or a procedure call
+	///
+	/// IEEE 1076-2008 § 10.5, § 10.6, § 10.7
+	fn parse_sequential_assignment_or_call(&mut self) -> ParseResult<SequentialStatementKind> {
+		let target = self.parse_primary()?;
+
+		if self.eat_control(Control::Semicolon) {
+			return Ok(SequentialStatementKind::ProcedureCall(expression_to_name(
+				target,
+			)?));
+		}
+
+		let kind = match self.peek() {
+			Some(Token::Control(Control::VariableAssignment)) => {
+				self.bump();
+
+				let start = self.span();
+				let value = self.parse_conditional(Self::parse_expression)?;
+				self.require_conditional_std(&value, start);
+
+				SequentialStatementKind::VariableAssignment { target, value }
+			},
+			Some(Token::Operator(Operator::LessThanEqual)) => {
+				self.bump();
+				let start = self.span();
+
+				match self.peek() {
+					Some(Token::Keyword(Keyword::Force)) => {
+						self.bump();
+						let mode = self.parse_force_mode();
+						let value = self.parse_conditional(Self::parse_expression)?;
+
+						SequentialStatementKind::Force { target, mode, value }
+					},
+					Some(Token::Keyword(Keyword::Release)) => {
+						self.bump();
+						let mode = self.parse_force_mode();
+
+						SequentialStatementKind::Release { target, mode }
+					},
+					_ => {
+						let delay = self.parse_delay_mechanism()?;
+						let value = self.parse_conditional(Self::parse_waveform)?;
+						self.require_conditional_std(&value, start);
+
+						SequentialStatementKind::SignalAssignment { target, delay, value }
+					},
+				}
+			},
+			_ => {
+				let span = *target.span();
+				return Err(ParseError::new(
+					"Expected `<=`, `:=`, or `;` after name",
+					span,
+				));
+			},
+		};
+
+		self.expect_control(Control::Semicolon)?;
+
+		Ok(kind)
+	}
+
+	/// Ensure conditional sequential assignments are only used where they are supported
+	fn require_conditional_std<T>(&mut self, value: &AssignmentKind<T>, start: Span) {
+		if matches!(value, AssignmentKind::Conditional(_)) {
+			self.require_std(
+				VHDL_08_STDS,
+				self.span_from(start),
+				"Sequential conditional assignments",
+			);
+		}
+	}
+
+	/// Parse the `in` or `out` mode of a force or release, if there is one
+	///
+	/// IEEE 1076-2008 § 10.5.2.1
+	fn parse_force_mode(&mut self) -> Option<ForceMode> {
+		if self.eat_keyword(Keyword::In) {
+			Some(ForceMode::In)
+		} else if self.eat_keyword(Keyword::Out) {
+			Some(ForceMode::Out)
+		} else {
+			None
+		}
+	}
+}
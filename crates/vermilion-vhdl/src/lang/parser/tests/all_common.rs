@@ -0,0 +1,475 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+fn ident(name: &str) -> Identifier {
+	Identifier::Basic(name.as_bytes().into())
+}
+
+/// Get the first architecture in the file
+fn first_architecture(ast: &Ast) -> &Architecture {
+	match ast.architectures().next() {
+		Some(architecture) => architecture,
+		None => panic!("No architecture in {ast:#?}"),
+	}
+}
+
+/// Get the sequential statements of the first process in the first architecture in the file
+fn first_process(ast: &Ast) -> &[vermilion_loc::Spanned<SequentialStatement>] {
+	for statement in &first_architecture(ast).statements {
+		if let ConcurrentStatementKind::Process(process) = &statement.kind {
+			return &process.statements;
+		}
+	}
+
+	panic!("No process in {ast:#?}")
+}
+
+parser_test!(all, empty_file, "", |parsed| {
+	assert!(expect_ast(parsed).units().is_empty());
+});
+
+parser_test!(all, empty_entity, "entity foo is end;", |parsed| {
+	let ast = expect_ast(parsed);
+	let entity = ast.entities().next().unwrap_or_else(|| panic!("No entity"));
+
+	assert_eq!(entity.name.inner(), &ident("foo"));
+	assert!(entity.generics.is_none());
+	assert!(entity.ports.is_none());
+	assert_eq!(*ast.units()[0].span().begin(), 0);
+	assert_eq!(*ast.units()[0].span().end(), 18);
+});
+
+parser_test!(
+	all,
+	entity_ports,
+	"entity foo is
+		generic (WIDTH : integer := 8);
+		port (
+			clk, rst : in bit;
+			data     : out bit_vector(WIDTH - 1 downto 0)
+		);
+	end foo;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let entity = ast.entities().next().unwrap_or_else(|| panic!("No entity"));
+
+		let generics = entity
+			.generics
+			.as_ref()
+			.unwrap_or_else(|| panic!("No generics"));
+		assert_eq!(generics.len(), 1);
+
+		let ports = entity.ports.as_ref().unwrap_or_else(|| panic!("No ports"));
+		assert_eq!(ports.len(), 2);
+
+		let InterfaceDeclaration::Object(clk) = ports[0].inner() else {
+			panic!("Expected an object, got {:#?}", ports[0]);
+		};
+		assert_eq!(clk.names.len(), 2);
+		assert_eq!(clk.mode, Some(Mode::In));
+
+		let InterfaceDeclaration::Object(data) = ports[1].inner() else {
+			panic!("Expected an object, got {:#?}", ports[1]);
+		};
+		assert_eq!(data.mode, Some(Mode::Out));
+		assert!(matches!(
+			data.subtype
+				.constraint
+				.as_ref()
+				.map(|constraint| constraint.inner()),
+			Some(Constraint::Index { .. })
+		));
+	}
+);
+
+parser_test!(
+	all,
+	context_clause,
+	"library ieee, work;
+	use ieee.std_logic_1164.all;
+	entity foo is end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let unit = ast.units()[0].inner();
+
+		assert_eq!(unit.context.len(), 2);
+		let ContextItem::Library(libraries) = unit.context[0].inner() else {
+			panic!("Expected a library clause, got {:#?}", unit.context[0]);
+		};
+		assert_eq!(libraries.len(), 2);
+
+		let ContextItem::Use(names) = unit.context[1].inner() else {
+			panic!("Expected a use clause, got {:#?}", unit.context[1]);
+		};
+		let Name::Selected { suffix, .. } = names[0].inner() else {
+			panic!("Expected a selected name, got {:#?}", names[0]);
+		};
+		assert_eq!(suffix.inner(), &Suffix::All);
+	}
+);
+
+parser_test!(
+	all,
+	architecture,
+	"architecture rtl of foo is
+		signal a, b : bit;
+		constant C : integer := 2 ** 4;
+	begin
+		a <= b after 1 ns;
+		b <= '1' when a = '0' else '0';
+	end rtl;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let architecture = first_architecture(&ast);
+
+		assert_eq!(architecture.name.inner(), &ident("rtl"));
+		assert_eq!(architecture.entity.inner(), &ident("foo"));
+		assert_eq!(architecture.declarations.len(), 2);
+		assert_eq!(architecture.statements.len(), 2);
+
+		let ConcurrentStatementKind::SignalAssignment(assignment) =
+			&architecture.statements[1].kind
+		else {
+			panic!(
+				"Expected an assignment, got {:#?}",
+				architecture.statements[1]
+			);
+		};
+		let AssignmentKind::Conditional(alternatives) = &assignment.value else {
+			panic!("Expected a conditional assignment, got {assignment:#?}");
+		};
+		assert_eq!(alternatives.len(), 2);
+		assert!(alternatives[1].condition.is_none());
+	}
+);
+
+parser_test!(
+	all,
+	end_label_mismatch,
+	"entity foo is end bar;",
+	|parsed| {
+		assert_eq!(expect_error(parsed), "End label `bar` does not match `foo`");
+	}
+);
+
+parser_test!(
+	all,
+	expression_precedence,
+	"architecture a of e is begin x <= a + b * c = d and e; end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let ConcurrentStatementKind::SignalAssignment(assignment) =
+			&first_architecture(&ast).statements[0].kind
+		else {
+			panic!("Expected an assignment");
+		};
+		let AssignmentKind::Simple(Waveform::Elements(elements)) = &assignment.value else {
+			panic!("Expected a waveform, got {assignment:#?}");
+		};
+
+		// ((a + (b * c)) = d) and e
+		let Expression::Binary { operator, lhs, .. } = elements[0].value.inner() else {
+			panic!("Expected a binary expression");
+		};
+		assert_eq!(operator.inner(), &BinaryOperator::And);
+
+		let Expression::Binary { operator, lhs, .. } = lhs.inner() else {
+			panic!("Expected a binary expression");
+		};
+		assert_eq!(operator.inner(), &BinaryOperator::Equals);
+
+		let Expression::Binary { operator, rhs, .. } = lhs.inner() else {
+			panic!("Expected a binary expression");
+		};
+		assert_eq!(operator.inner(), &BinaryOperator::Plus);
+		assert!(matches!(
+			rhs.inner(),
+			Expression::Binary { operator, .. } if operator.inner() == &BinaryOperator::Multiply
+		));
+	}
+);
+
+parser_test!(
+	all,
+	aggregate,
+	"architecture a of e is begin x <= (0 => '1', others => '0'); end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let ConcurrentStatementKind::SignalAssignment(assignment) =
+			&first_architecture(&ast).statements[0].kind
+		else {
+			panic!("Expected an assignment");
+		};
+		let AssignmentKind::Simple(Waveform::Elements(elements)) = &assignment.value else {
+			panic!("Expected a waveform, got {assignment:#?}");
+		};
+		let Expression::Aggregate(associations) = elements[0].value.inner() else {
+			panic!("Expected an aggregate, got {:#?}", elements[0]);
+		};
+
+		assert_eq!(associations.len(), 2);
+		assert_eq!(associations[1].choices[0].inner(), &Choice::Others);
+	}
+);
+
+parser_test!(
+	all,
+	process,
+	"architecture a of e is begin
+		process (clk)
+			variable count : integer := 0;
+		begin
+			if clk = '1' then
+				count := count + 1;
+			elsif rst = '1' then
+				count := 0;
+			else
+				null;
+			end if;
+
+			case count is
+				when 0 | 1 => q <= '0';
+				when 2 to 4 => q <= '1';
+				when others => null;
+			end case;
+
+			for i in 0 to 7 loop
+				next when i = 3;
+				exit;
+			end loop;
+
+			wait on clk until clk = '1' for 10 ns;
+		end process;
+	end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let statements = first_process(&ast);
+
+		assert_eq!(statements.len(), 4);
+
+		let SequentialStatementKind::If(branches) = &statements[0].kind else {
+			panic!("Expected an if, got {:#?}", statements[0]);
+		};
+		assert_eq!(branches.len(), 3);
+
+		let SequentialStatementKind::Case { alternatives, matching, .. } = &statements[1].kind
+		else {
+			panic!("Expected a case, got {:#?}", statements[1]);
+		};
+		assert!(!matching);
+		assert_eq!(alternatives.len(), 3);
+		assert_eq!(alternatives[0].choices.len(), 2);
+		assert!(matches!(
+			alternatives[1].choices[0].inner(),
+			Choice::Range(_)
+		));
+
+		let SequentialStatementKind::Loop { scheme, statements: body } = &statements[2].kind else {
+			panic!("Expected a loop, got {:#?}", statements[2]);
+		};
+		assert!(matches!(scheme, Some(IterationScheme::For { .. })));
+		assert_eq!(body.len(), 2);
+
+		let SequentialStatementKind::Wait { sensitivity, condition, timeout } = &statements[3].kind
+		else {
+			panic!("Expected a wait, got {:#?}", statements[3]);
+		};
+		assert_eq!(sensitivity.len(), 1);
+		assert!(condition.is_some());
+		assert!(timeout.is_some());
+	}
+);
+
+parser_test!(
+	all,
+	component_instantiation,
+	"architecture a of e is
+		component c
+			port (a : in bit);
+		end component;
+	begin
+		u0 : c port map (a => x);
+		u1 : c port map (x);
+	end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let architecture = first_architecture(&ast);
+
+		assert!(matches!(
+			architecture.declarations[0].inner(),
+			Declaration::Component(_)
+		));
+
+		for statement in &architecture.statements {
+			let ConcurrentStatementKind::Instantiation(instantiation) = &statement.kind else {
+				panic!("Expected an instantiation, got {statement:#?}");
+			};
+			assert!(matches!(instantiation.unit, InstantiatedUnit::Component(_)));
+			assert_eq!(instantiation.port_map.as_ref().map(Vec::len), Some(1));
+		}
+	}
+);
+
+parser_test!(
+	all,
+	for_generate,
+	"architecture a of e is begin
+		gen : for i in 0 to 3 generate
+			x(i) <= y(i);
+		end generate gen;
+	end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let statement = &first_architecture(&ast).statements[0];
+
+		assert_eq!(
+			statement.label.as_ref().map(|label| label.inner()),
+			Some(&ident("gen"))
+		);
+		let ConcurrentStatementKind::ForGenerate { parameter, body, .. } = &statement.kind else {
+			panic!("Expected a for generate, got {statement:#?}");
+		};
+		assert_eq!(parameter.inner(), &ident("i"));
+		assert_eq!(body.statements.len(), 1);
+	}
+);
+
+parser_test!(
+	all,
+	package_and_body,
+	"package p is
+		type state is (idle, busy);
+		type word is array (natural range <>) of bit;
+		type rec is record
+			a : integer;
+		end record;
+		subtype byte is word(7 downto 0);
+		function f(x : integer) return integer;
+	end package;
+
+	package body p is
+		function f(x : integer) return integer is
+		begin
+			return x + 1;
+		end;
+	end package body;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+
+		let package = ast
+			.packages()
+			.next()
+			.unwrap_or_else(|| panic!("No package"));
+		assert_eq!(package.declarations.len(), 5);
+		assert!(matches!(
+			package.declarations[4].inner(),
+			Declaration::Subprogram(_)
+		));
+
+		let LibraryUnit::PackageBody(body) = &ast.units()[1].unit else {
+			panic!("Expected a package body, got {:#?}", ast.units()[1]);
+		};
+		let Declaration::SubprogramBody(function) = body.declarations[0].inner() else {
+			panic!(
+				"Expected a subprogram body, got {:#?}",
+				body.declarations[0]
+			);
+		};
+		assert_eq!(function.statements.len(), 1);
+	}
+);
+
+parser_test!(
+	all,
+	configuration,
+	"configuration cfg of e is
+		for rtl
+			for u0 : c use entity work.c(rtl);
+			end for;
+		end for;
+	end cfg;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let LibraryUnit::Configuration(configuration) = &ast.units()[0].unit else {
+			panic!("Expected a configuration, got {:#?}", ast.units()[0]);
+		};
+
+		assert_eq!(configuration.block.items.len(), 1);
+		let ConfigurationItem::Component(component) = configuration.block.items[0].inner() else {
+			panic!("Expected a component configuration");
+		};
+		assert!(matches!(
+			component
+				.binding
+				.as_ref()
+				.and_then(|binding| binding.entity.as_ref())
+				.map(|entity| entity.inner()),
+			Some(EntityAspect::Entity { architecture: Some(_), .. })
+		));
+	}
+);
+
+parser_test!(
+	all,
+	psl_comment,
+	"architecture a of e is
+		-- psl default clock is rising_edge(clk);
+	begin
+		-- psl assert always req -> next ack;
+	end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let architecture = first_architecture(&ast);
+
+		let Declaration::Psl(declaration) = architecture.declarations[0].inner() else {
+			panic!(
+				"Expected a PSL declaration, got {:#?}",
+				architecture.declarations[0]
+			);
+		};
+		assert_eq!(declaration.kind, PslDeclarationKind::DefaultClock);
+
+		let ConcurrentStatementKind::Psl(directive) = &architecture.statements[0].kind else {
+			panic!(
+				"Expected a PSL directive, got {:#?}",
+				architecture.statements[0]
+			);
+		};
+		assert_eq!(directive.kind, PslDirectiveKind::Assert);
+		assert_eq!(
+			&*directive.text,
+			b"assert always req -> next ack;".as_slice()
+		);
+	}
+);
+
+parser_test!(
+	all,
+	recovers_after_error,
+	"architecture a of e is begin
+		x <= ;
+		y <= z;
+	end;
+	entity e is end;",
+	|parsed| {
+		assert!(parsed.diagnostics().iter().any(is_syntax_error));
+		assert_eq!(parsed.units().len(), 2);
+
+		let architecture = first_architecture(&parsed);
+		assert_eq!(architecture.statements.len(), 2);
+		assert_eq!(
+			architecture.statements[0].kind,
+			ConcurrentStatementKind::Error
+		);
+		assert!(matches!(
+			architecture.statements[1].kind,
+			ConcurrentStatementKind::SignalAssignment(_)
+		));
+		assert!(parsed.entities().next().is_some());
+	}
+);
+
+parser_test!(all, missing_semicolon, "entity e is end", |parsed| {
+	assert_eq!(expect_error(parsed), "Expected `;`, found end of file");
+});
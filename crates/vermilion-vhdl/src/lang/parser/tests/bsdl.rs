@@ -0,0 +1,339 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+use crate::lang::bsdl::{
+	BsdlDescription, BsdlItem, CellFunction, DisableResult, PortMode, SafeValue,
+};
+
+/// A small but conforming description, tests replace parts of it to check the validation
+const DEVICE: &str = r#"
+entity TINY_DEV is
+	generic (PHYSICAL_PIN_MAP : string := "QFN8");
+
+	port (
+		TCK  : in bit;
+		TDI  : in bit;
+		TDO  : out bit;
+		TMS  : in bit;
+		DATA : inout bit_vector(0 to 1);
+		CLK  : in bit
+	);
+
+	use STD_1149_1_2013.all;
+
+	attribute COMPONENT_CONFORMANCE of TINY_DEV : entity is "STD_1149_1_2013";
+	attribute PIN_MAP of TINY_DEV : entity is PHYSICAL_PIN_MAP;
+
+	constant QFN8 : PIN_MAP_STRING :=
+		"TCK : 1, TDI : 2, TDO : 3, TMS : 4, " &
+		"DATA : (5, 6), CLK : 7";
+
+	attribute TAP_SCAN_IN    of TDI : signal is true;
+	attribute TAP_SCAN_OUT   of TDO : signal is true;
+	attribute TAP_SCAN_MODE  of TMS : signal is true;
+	attribute TAP_SCAN_CLOCK of TCK : signal is (20.0e6, BOTH);
+
+	attribute INSTRUCTION_LENGTH of TINY_DEV : entity is 2;
+	attribute INSTRUCTION_OPCODE of TINY_DEV : entity is
+		"EXTEST (00), " &
+		"SAMPLE (01), " &
+		"IDCODE (10), " &
+		"BYPASS (11)";
+	attribute INSTRUCTION_CAPTURE of TINY_DEV : entity is "01";
+
+	attribute IDCODE_REGISTER of TINY_DEV : entity is
+		"0001" & "0000000000000001" & "00000001111" & "1";
+
+	attribute BOUNDARY_LENGTH of TINY_DEV : entity is 6;
+	attribute BOUNDARY_REGISTER of TINY_DEV : entity is
+		"0 (BC_1, CLK, input, X), " &
+		"1 (BC_1, *, control, 0), " &
+		"2 (BC_7, DATA(0), bidir, X, 1, 0, Z), " &
+		"3 (BC_1, *, control, 0), " &
+		"4 (BC_7, DATA(1), bidir, X, 3, 0, Z), " &
+		"5 (BC_4, *, internal, X)";
+end TINY_DEV;
+"#;
+
+fn parse(input: &str) -> BsdlDescription {
+	#[allow(
+		clippy::unwrap_used,
+		reason = "We are within tests, and while this shouldn't explode, if it does here that's \
+		          fine"
+	)]
+	let parser = VhdlParser::new(LanguageStd::Bsdl, input.as_bytes().into()).unwrap();
+
+	parser.parse_bsdl()
+}
+
+/// Parse `DEVICE` with `from` replaced by `to`, returning the messages of the diagnostics
+fn diagnostics_with(from: &str, to: &str) -> Vec<String> {
+	assert!(DEVICE.contains(from), "`{from}` is not in the device");
+
+	let description = parse(&DEVICE.replacen(from, to, 1));
+	description
+		.diagnostics()
+		.iter()
+		.map(|diagnostic| diagnostic.message().to_string())
+		.collect()
+}
+
+/// Returns true if parsing `DEVICE` with `from` replaced by `to` produces a diagnostic starting
+/// with `message`
+fn has_bsdl_diagnostic(from: &str, to: &str, message: &str) -> bool {
+	diagnostics_with(from, to)
+		.iter()
+		.any(|diagnostic| diagnostic.starts_with(message))
+}
+
+#[test]
+fn test_parse_bsdl_device() {
+	let description = parse(DEVICE);
+	assert!(
+		description.diagnostics().is_empty(),
+		"{:#?}",
+		description.diagnostics()
+	);
+
+	assert_eq!(
+		description
+			.entity
+			.as_ref()
+			.map(|entity| entity.inner().as_str()),
+		Some("TINY_DEV")
+	);
+	assert_eq!(
+		description
+			.physical_pin_map
+			.as_ref()
+			.map(|map| map.inner().as_str()),
+		Some("QFN8")
+	);
+	assert_eq!(description.packages.len(), 1);
+	assert_eq!(description.packages[0].inner(), "STD_1149_1_2013");
+
+	assert_eq!(description.ports.len(), 6);
+	let data = description
+		.port("DATA(1)")
+		.unwrap_or_else(|| panic!("Expected the `DATA` port"));
+	assert_eq!(data.mode, PortMode::InOut);
+	assert_eq!(data.width(), 2);
+
+	let pin_map = description
+		.active_pin_map()
+		.unwrap_or_else(|| panic!("Expected the `QFN8` pin map"));
+	assert_eq!(pin_map.entries.len(), 6);
+	let pins = pin_map
+		.entries
+		.iter()
+		.find(|entry| entry.inner().port.inner() == "DATA")
+		.map(|entry| {
+			entry
+				.inner()
+				.pins
+				.iter()
+				.map(|pin| pin.inner().as_str())
+				.collect::<Vec<_>>()
+		});
+	assert_eq!(pins, Some(vec!["5", "6"]));
+
+	assert_eq!(description.tap_ports.len(), 4);
+	assert_eq!(
+		description
+			.instruction_length
+			.as_ref()
+			.map(|length| *length.inner()),
+		Some(2)
+	);
+	assert_eq!(description.instructions.len(), 4);
+	assert_eq!(
+		description
+			.instruction("bypass")
+			.map(|bypass| bypass.opcodes[0].inner().as_str()),
+		Some("11")
+	);
+	assert_eq!(
+		description
+			.idcode
+			.as_ref()
+			.map(|idcode| idcode.inner().len()),
+		Some(32)
+	);
+}
+
+#[test]
+fn test_parse_bsdl_boundary_register() {
+	let description = parse(DEVICE);
+
+	assert_eq!(description.boundary_cells.len(), 6);
+
+	let cell = description
+		.boundary_cell(2)
+		.unwrap_or_else(|| panic!("Expected boundary cell 2"));
+	assert_eq!(cell.cell.inner(), "BC_7");
+	assert_eq!(
+		cell.port.as_ref().map(|port| port.inner().as_str()),
+		Some("DATA(0)")
+	);
+	assert_eq!(cell.function, CellFunction::Bidir);
+	assert_eq!(cell.safe, SafeValue::DontCare);
+
+	let control = cell
+		.control
+		.as_ref()
+		.unwrap_or_else(|| panic!("Expected cell 2 to have a control cell"));
+	assert_eq!(*control.cell.inner(), 1);
+	assert!(!control.disable);
+	assert_eq!(control.result, DisableResult::Z);
+
+	assert_eq!(description.cells_for_port("DATA").count(), 2);
+	assert_eq!(
+		description.boundary_cell(5).map(|cell| cell.function),
+		Some(CellFunction::Internal)
+	);
+}
+
+#[test]
+fn test_parse_bsdl_item_at() {
+	let description = parse(DEVICE);
+
+	let offset = |needle: &str| {
+		#[allow(clippy::cast_possible_truncation, reason = "The test device is tiny")]
+		DEVICE
+			.find(needle)
+			.map(|offset| offset as u32 + 1)
+			.unwrap_or_else(|| panic!("`{needle}` is not in the device"))
+	};
+
+	assert!(matches!(
+		description.item_at(offset("BC_7, DATA(1)")),
+		Some((_, BsdlItem::BoundaryCell(cell))) if *cell.number.inner() == 4
+	));
+	assert!(matches!(
+		description.item_at(offset("IDCODE (10)")),
+		Some((_, BsdlItem::Instruction(instruction))) if instruction.name.inner() == "IDCODE"
+	));
+	assert!(matches!(
+		description.item_at(offset("DATA : (5")),
+		Some((_, BsdlItem::PinMapEntry(entry))) if entry.port.inner() == "DATA"
+	));
+}
+
+#[test]
+fn test_parse_bsdl_syntax_error() {
+	let description = parse(&DEVICE.replacen("port (", "port", 1));
+	assert!(description.diagnostics().iter().any(is_syntax_error));
+}
+
+#[test]
+fn test_parse_bsdl_malformed_value() {
+	let description =
+		parse(&DEVICE.replacen("\"0 (BC_1, CLK, input, X), \"", "\"0 (BC_1 CLK), \"", 1));
+
+	assert!(
+		description
+			.diagnostics()
+			.iter()
+			.any(|diagnostic| diagnostic.code() == vhdl::E0005)
+	);
+}
+
+#[test]
+fn test_validate_bsdl_tap_ports() {
+	assert!(has_bsdl_diagnostic(
+		"attribute TAP_SCAN_MODE  of TMS : signal is true;",
+		"",
+		"The required `TAP_SCAN_MODE` attribute is missing"
+	));
+	assert!(has_bsdl_diagnostic(
+		"TDO  : out bit;",
+		"TDO  : in bit;",
+		"The TDO port `TDO` must have mode `out`"
+	));
+	assert!(has_bsdl_diagnostic(
+		"TAP_SCAN_IN    of TDI",
+		"TAP_SCAN_IN    of TDX",
+		"`TAP_SCAN_IN` names `TDX`, which is not a port"
+	));
+}
+
+#[test]
+fn test_validate_bsdl_instructions() {
+	assert!(has_bsdl_diagnostic(
+		"\"SAMPLE (01), \"",
+		"\"SAMPLE (011), \"",
+		"The opcode `011` of `SAMPLE` is 3 bits long"
+	));
+	assert!(has_bsdl_diagnostic(
+		"\"SAMPLE (01), \"",
+		"\"SAMPLE (00), \"",
+		"The opcode `00` of `SAMPLE` is also used by `EXTEST`"
+	));
+	assert!(has_bsdl_diagnostic(
+		"\"EXTEST (00), \"",
+		"\"PRELOAD (00), \"",
+		"The required `EXTEST` instruction is missing"
+	));
+	assert!(has_bsdl_diagnostic(
+		"\"IDCODE (10), \" &\n\t\t\"BYPASS (11)\"",
+		"\"IDCODE (11), \" &\n\t\t\"BYPASS (10)\"",
+		"The all ones opcode must be `BYPASS`, not `IDCODE`"
+	));
+	assert!(has_bsdl_diagnostic(
+		"INSTRUCTION_CAPTURE of TINY_DEV : entity is \"01\"",
+		"INSTRUCTION_CAPTURE of TINY_DEV : entity is \"10\"",
+		"`INSTRUCTION_CAPTURE` must end in `01`"
+	));
+}
+
+#[test]
+fn test_validate_bsdl_idcode() {
+	assert!(has_bsdl_diagnostic(
+		"\"00000001111\" & \"1\"",
+		"\"00000001111\" & \"0\"",
+		"The least significant bit of `IDCODE_REGISTER` must be 1"
+	));
+}
+
+#[test]
+fn test_validate_bsdl_boundary_register() {
+	assert!(has_bsdl_diagnostic(
+		"entity is 6;",
+		"entity is 7;",
+		"`BOUNDARY_LENGTH` is 7, but 6 cells are described"
+	));
+	assert!(has_bsdl_diagnostic(
+		"\"5 (BC_4, *, internal, X)\"",
+		"\"4 (BC_4, *, internal, X)\"",
+		"Cell 4 is described more than once"
+	));
+	assert!(has_bsdl_diagnostic(
+		"\"0 (BC_1, CLK, input, X), \"",
+		"\"0 (BC_1, CLOCK, input, X), \"",
+		"Cell 0 is connected to `CLOCK`, which is not a port"
+	));
+	assert!(has_bsdl_diagnostic(
+		"bidir, X, 3, 0, Z",
+		"bidir, X, 2, 0, Z",
+		"Cell 4 is controlled by cell 2, which is not a control cell"
+	));
+}
+
+#[test]
+fn test_validate_bsdl_pin_map() {
+	assert!(has_bsdl_diagnostic(
+		"\"DATA : (5, 6), CLK : 7\"",
+		"\"DATA : (5, 6), CLK : 6\"",
+		"Pin `6` is mapped to both `DATA` and `CLK`"
+	));
+	assert!(has_bsdl_diagnostic(
+		"\"DATA : (5, 6), CLK : 7\"",
+		"\"DATA : 5, CLK : 7\"",
+		"`DATA` is 2 bits wide, but is mapped to 1 pins"
+	));
+	assert!(has_bsdl_diagnostic(
+		"PHYSICAL_PIN_MAP : string := \"QFN8\"",
+		"PHYSICAL_PIN_MAP : string := \"BGA4\"",
+		"There is no pin map named `BGA4`"
+	));
+}
@@ -1,243 +1,288 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
-// TODO(aki): Write tests
-#[allow(unused, reason = "Currently unused")]
 use paste::paste;
+use vermilion_diagnostics::{Diagnostic, StringDiagnostic};
 
-// TODO(aki): Write tests
-#[allow(unused, reason = "Currently unused")]
-use crate::{LanguageStd, lang::parser::VhdlParser};
+use crate::{
+	LanguageStd,
+	diagnostics::{vhdl, vhdl_ams},
+	lang::{ast::*, parser::VhdlParser},
+};
+
+/// Returns true if `diagnostic` is a syntax error rather than a warning about the standard
+fn is_syntax_error(diagnostic: &StringDiagnostic) -> bool {
+	[vhdl::E0002, vhdl_ams::E0002].contains(&diagnostic.code())
+}
+
+/// Check that a parse had no syntax errors, failing the test with the first one if there was one
+fn expect_ast(parsed: Ast) -> Ast {
+	if let Some(err) = parsed
+		.diagnostics()
+		.iter()
+		.find(|diagnostic| is_syntax_error(diagnostic))
+	{
+		panic!("Failed to parse: {}", err.message());
+	}
+
+	parsed
+}
+
+/// Check that a parse had no syntax errors, returning the messages of the diagnostics it produced
+fn expect_diagnostics(parsed: Ast) -> Vec<String> {
+	expect_ast(parsed)
+		.diagnostics()
+		.iter()
+		.map(|diagnostic| diagnostic.message().to_string())
+		.collect()
+}
+
+/// Returns true if any of the diagnostics produced by a successful parse start with `message`
+fn has_diagnostic(parsed: Ast, message: &str) -> bool {
+	expect_diagnostics(parsed)
+		.iter()
+		.any(|diagnostic| diagnostic.starts_with(message))
+}
+
+/// Return the message of the first syntax error of a parse, failing the test if there were none
+fn expect_error(parsed: Ast) -> String {
+	match parsed
+		.diagnostics()
+		.iter()
+		.find(|diagnostic| is_syntax_error(diagnostic))
+	{
+		Some(err) => err.message().to_string(),
+		None => panic!("Expected a parse error, got: {parsed:#?}"),
+	}
+}
 
-// TODO(aki): Write tests
-#[allow(unused, reason = "Currently unused")]
 macro_rules! parser_test {
-	(vhdl87, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl87, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_87_ $test_name>],
 			$input,
 			LanguageStd::Vh87,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl93, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl93, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_93_ $test_name>],
 			$input,
 			LanguageStd::Vh93,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl93+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl93, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl2k, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl02, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl04, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl07, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl08, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl11, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl19, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl23, $test_name, $input, $($tokens),+);
-	};
-	(vhdl2k, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl93+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl93, $test_name, $input, $check);
+		parser_test!(vhdl2k, $test_name, $input, $check);
+		parser_test!(vhdl02, $test_name, $input, $check);
+		parser_test!(vhdl04, $test_name, $input, $check);
+		parser_test!(vhdl07, $test_name, $input, $check);
+		parser_test!(vhdl08, $test_name, $input, $check);
+		parser_test!(vhdl11, $test_name, $input, $check);
+		parser_test!(vhdl19, $test_name, $input, $check);
+		parser_test!(vhdl23, $test_name, $input, $check);
+	};
+	(vhdl2k, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_2k_ $test_name>],
 			$input,
 			LanguageStd::Vh2k,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl2k+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl2k, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl02, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl04, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl07, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl08, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl11, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl19, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl23, $test_name, $input, $($tokens),+);
-	};
-	(vhdl02, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl2k+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl2k, $test_name, $input, $check);
+		parser_test!(vhdl02, $test_name, $input, $check);
+		parser_test!(vhdl04, $test_name, $input, $check);
+		parser_test!(vhdl07, $test_name, $input, $check);
+		parser_test!(vhdl08, $test_name, $input, $check);
+		parser_test!(vhdl11, $test_name, $input, $check);
+		parser_test!(vhdl19, $test_name, $input, $check);
+		parser_test!(vhdl23, $test_name, $input, $check);
+	};
+	(vhdl02, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_02_ $test_name>],
 			$input,
 			LanguageStd::Vh02,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl02+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl02, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl04, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl07, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl08, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl11, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl19, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl23, $test_name, $input, $($tokens),+);
+	(vhdl02+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl02, $test_name, $input, $check);
+		parser_test!(vhdl04, $test_name, $input, $check);
+		parser_test!(vhdl07, $test_name, $input, $check);
+		parser_test!(vhdl08, $test_name, $input, $check);
+		parser_test!(vhdl11, $test_name, $input, $check);
+		parser_test!(vhdl19, $test_name, $input, $check);
+		parser_test!(vhdl23, $test_name, $input, $check);
 	};
-	(vhdl04, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl04, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_04_ $test_name>],
 			$input,
 			LanguageStd::Vh04,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl04+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl04, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl07, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl08, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl11, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl19, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl23, $test_name, $input, $($tokens),+);
+	(vhdl04+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl04, $test_name, $input, $check);
+		parser_test!(vhdl07, $test_name, $input, $check);
+		parser_test!(vhdl08, $test_name, $input, $check);
+		parser_test!(vhdl11, $test_name, $input, $check);
+		parser_test!(vhdl19, $test_name, $input, $check);
+		parser_test!(vhdl23, $test_name, $input, $check);
 	};
-	(vhdl07, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl07, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_07_ $test_name>],
 			$input,
 			LanguageStd::Vh07,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl07+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl07, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl08, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl11, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl19, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl23, $test_name, $input, $($tokens),+);
+	(vhdl07+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl07, $test_name, $input, $check);
+		parser_test!(vhdl08, $test_name, $input, $check);
+		parser_test!(vhdl11, $test_name, $input, $check);
+		parser_test!(vhdl19, $test_name, $input, $check);
+		parser_test!(vhdl23, $test_name, $input, $check);
 	};
-	(vhdl08, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl08, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_08_ $test_name>],
 			$input,
 			LanguageStd::Vh08,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl08+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl08, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl11, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl19, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl23, $test_name, $input, $($tokens),+);
+	(vhdl08+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl08, $test_name, $input, $check);
+		parser_test!(vhdl11, $test_name, $input, $check);
+		parser_test!(vhdl19, $test_name, $input, $check);
+		parser_test!(vhdl23, $test_name, $input, $check);
 	};
-	(vhdl11, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl11, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_11_ $test_name>],
 			$input,
 			LanguageStd::Vh11,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl11+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl11, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl19, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl23, $test_name, $input, $($tokens),+);
+	(vhdl11+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl11, $test_name, $input, $check);
+		parser_test!(vhdl19, $test_name, $input, $check);
+		parser_test!(vhdl23, $test_name, $input, $check);
 	};
-	(vhdl19, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl19, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_19_ $test_name>],
 			$input,
 			LanguageStd::Vh19,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl19+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl19, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl23, $test_name, $input, $($tokens),+);
+	(vhdl19+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl19, $test_name, $input, $check);
+		parser_test!(vhdl23, $test_name, $input, $check);
 	};
-	(vhdl23, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl23, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_23_ $test_name>],
 			$input,
 			LanguageStd::Vh23,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl23+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl23, $test_name, $input, $($tokens),+);
-	};
-	(vhdl_all, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl87, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl93, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl2k, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl02, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl04, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl07, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl08, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl11, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl19, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl23, $test_name, $input, $($tokens),+);
-	};
-	(vhdl_ams99, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl23+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl23, $test_name, $input, $check);
+	};
+	(vhdl_all, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl87, $test_name, $input, $check);
+		parser_test!(vhdl93, $test_name, $input, $check);
+		parser_test!(vhdl2k, $test_name, $input, $check);
+		parser_test!(vhdl02, $test_name, $input, $check);
+		parser_test!(vhdl04, $test_name, $input, $check);
+		parser_test!(vhdl07, $test_name, $input, $check);
+		parser_test!(vhdl08, $test_name, $input, $check);
+		parser_test!(vhdl11, $test_name, $input, $check);
+		parser_test!(vhdl19, $test_name, $input, $check);
+		parser_test!(vhdl23, $test_name, $input, $check);
+	};
+	(vhdl_ams99, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_ams_99_ $test_name>],
 			$input,
 			LanguageStd::Vhams99,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl_ams07, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl_ams07, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_ams_07_ $test_name>],
 			$input,
 			LanguageStd::Vhams07,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl_ams07+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl_ams07, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl_ams09, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl_ams17, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl_ams21, $test_name, $input, $($tokens),+);
+	(vhdl_ams07+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl_ams07, $test_name, $input, $check);
+		parser_test!(vhdl_ams09, $test_name, $input, $check);
+		parser_test!(vhdl_ams17, $test_name, $input, $check);
+		parser_test!(vhdl_ams21, $test_name, $input, $check);
 	};
-	(vhdl_ams09, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl_ams09, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_ams_09_ $test_name>],
 			$input,
 			LanguageStd::Vhams09,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl_ams09+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl_ams09, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl_ams17, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl_ams21, $test_name, $input, $($tokens),+);
+	(vhdl_ams09+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl_ams09, $test_name, $input, $check);
+		parser_test!(vhdl_ams17, $test_name, $input, $check);
+		parser_test!(vhdl_ams21, $test_name, $input, $check);
 	};
-	(vhdl_ams17, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl_ams17, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_ams_17_ $test_name>],
 			$input,
 			LanguageStd::Vhams17,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl_ams17+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl_ams17, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl_ams21, $test_name, $input, $($tokens),+);
+	(vhdl_ams17+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl_ams17, $test_name, $input, $check);
+		parser_test!(vhdl_ams21, $test_name, $input, $check);
 	};
-	(vhdl_ams21, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(vhdl_ams21, $test_name:ident, $input:literal, $check:expr) => {
 		paste! { parser_test!(
 			[<vhdl_ams_21_ $test_name>],
 			$input,
 			LanguageStd::Vhams21,
-			$($tokens),+
+			$check
 		); }
 	};
-	(vhdl_ams21+, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl_ams21, $test_name, $input, $($tokens),+);
+	(vhdl_ams21+, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl_ams21, $test_name, $input, $check);
 	};
-	(vhdl_ams_all, $test_name:ident, $input:literal, $($tokens:expr),+) => {
-		parser_test!(vhdl_ams99, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl_ams07, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl_ams09, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl_ams17, $test_name, $input, $($tokens),+);
-		parser_test!(vhdl_ams21, $test_name, $input, $($tokens),+);
+	(vhdl_ams_all, $test_name:ident, $input:literal, $check:expr) => {
+		parser_test!(vhdl_ams99, $test_name, $input, $check);
+		parser_test!(vhdl_ams07, $test_name, $input, $check);
+		parser_test!(vhdl_ams09, $test_name, $input, $check);
+		parser_test!(vhdl_ams17, $test_name, $input, $check);
+		parser_test!(vhdl_ams21, $test_name, $input, $check);
 	};
-	(all, $test_name:ident, $input:literal, $($tokens:expr),+) => {
+	(all, $test_name:ident, $input:literal, $check:expr) => {
 		paste! {
-			parser_test!(vhdl_all, [<$test_name _all>], $input, $($tokens),+);
-			parser_test!(vhdl_ams_all, [<$test_name _all>], $input, $($tokens),+);
+			parser_test!(vhdl_all, [<$test_name _all>], $input, $check);
+			parser_test!(vhdl_ams_all, [<$test_name _all>], $input, $check);
 		}
 	};
-	($test_name:ident, $input:literal, $std:expr, $($tokens:expr),+) => {
+	($test_name:ident, $input:literal, $std:expr, $check:expr) => {
 		paste! {
 			#[test]
 			fn [<test_parse_ $test_name>] () {
@@ -245,20 +290,19 @@ macro_rules! parser_test {
 					clippy::unwrap_used,
 					reason = "We are within tests, and while this shouldn't explode, if it does here that's fine"
 				)]
-				let parser = VhdlParser::new($std, $input.as_bytes().into());
-
-				let parsed = parser.collect::<Vec<_>>();
-				let expected = vec![$($tokens),+];
+				let parser = VhdlParser::new($std, $input.as_bytes().into()).unwrap();
 
-				assert_eq!(
-					parsed, expected,
-					"Parsed: {parsed:#?}\nExpected: {expected:#?}"
-				);
+				let check: fn(Ast) = $check;
+				check(parser.parse());
 			}
 		}
 	};
 }
 
+// BSDL Parser Tests
+mod bsdl;
+// Common Parser Tests
+mod all_common;
 // VHDL Parser Tests
 mod vhdl02;
 mod vhdl04;
@@ -1 +1,253 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+parser_test!(
+	vhdl08,
+	context_declaration,
+	"context ctx is
+		library ieee;
+		use ieee.std_logic_1164.all;
+	end context ctx;
+
+	context work.ctx;
+	entity e is end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+
+		let LibraryUnit::Context(context) = &ast.units()[0].unit else {
+			panic!("Expected a context, got {:#?}", ast.units()[0]);
+		};
+		assert_eq!(context.items.len(), 2);
+		assert!(matches!(
+			ast.units()[1].context[0].inner(),
+			ContextItem::Context(_)
+		));
+	}
+);
+
+parser_test!(
+	vhdl08,
+	package_generics,
+	"package p is
+		generic (
+			type t;
+			function f(a : t) return boolean is <>;
+			package q is new work.r generic map (<>)
+		);
+	end package;
+
+	package i is new work.p generic map (t => integer);",
+	|parsed| {
+		let ast = expect_ast(parsed);
+
+		let package = ast
+			.packages()
+			.next()
+			.unwrap_or_else(|| panic!("No package"));
+		let generics = package
+			.generics
+			.as_ref()
+			.unwrap_or_else(|| panic!("No generics"));
+		assert!(matches!(generics[0].inner(), InterfaceDeclaration::Type(_)));
+		assert!(matches!(
+			generics[1].inner(),
+			InterfaceDeclaration::Subprogram { default: Some(_), .. }
+		));
+		assert!(matches!(
+			generics[2].inner(),
+			InterfaceDeclaration::Package { generic_map: InterfacePackageGenerics::Box, .. }
+		));
+
+		assert!(matches!(
+			ast.units()[1].unit,
+			LibraryUnit::PackageInstance(_)
+		));
+	}
+);
+
+parser_test!(
+	vhdl08,
+	subprogram_generics,
+	"package p is
+		function f generic (type t) parameter (a : t) return t;
+		function g is new f generic map (t => bit);
+	end package;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let package = ast
+			.packages()
+			.next()
+			.unwrap_or_else(|| panic!("No package"));
+
+		let Declaration::Subprogram(specification) = package.declarations[0].inner() else {
+			panic!("Expected a subprogram, got {:#?}", package.declarations[0]);
+		};
+		assert!(specification.generics.is_some());
+		assert!(matches!(
+			package.declarations[1].inner(),
+			Declaration::SubprogramInstance(_)
+		));
+	}
+);
+
+parser_test!(
+	vhdl08,
+	generate_branches,
+	"architecture a of e is begin
+		g0 : if first : c = 0 generate
+			x <= '0';
+		end first;
+		elsif c = 1 generate
+			x <= '1';
+		else generate
+			x <= 'Z';
+		end generate g0;
+
+		g1 : case c generate
+			when zero : 0 =>
+				y <= '0';
+			when others =>
+				y <= '1';
+		end generate;
+	end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let architecture = ast
+			.architectures()
+			.next()
+			.unwrap_or_else(|| panic!("No architecture"));
+
+		let ConcurrentStatementKind::IfGenerate(branches) = &architecture.statements[0].kind else {
+			panic!(
+				"Expected an if generate, got {:#?}",
+				architecture.statements[0]
+			);
+		};
+		assert_eq!(branches.len(), 3);
+		assert!(branches[0].body.label.is_some());
+		assert!(branches[2].condition.is_none());
+
+		let ConcurrentStatementKind::CaseGenerate { alternatives, .. } =
+			&architecture.statements[1].kind
+		else {
+			panic!(
+				"Expected a case generate, got {:#?}",
+				architecture.statements[1]
+			);
+		};
+		assert_eq!(alternatives.len(), 2);
+	}
+);
+
+parser_test!(
+	vhdl08,
+	sequential_2008,
+	"architecture a of e is begin
+		process (all)
+		begin
+			case? s is
+				when \"1-\" => x := '1';
+				when others => x := '0';
+			end case?;
+
+			y <= a when c else b;
+			z <= force in '1';
+			z <= release;
+			w := << signal .top.dut.sig : bit >>;
+		end process;
+	end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let architecture = ast
+			.architectures()
+			.next()
+			.unwrap_or_else(|| panic!("No architecture"));
+		let ConcurrentStatementKind::Process(process) = &architecture.statements[0].kind else {
+			panic!("Expected a process");
+		};
+
+		assert_eq!(process.sensitivity, Some(SensitivityList::All));
+		assert!(matches!(
+			process.statements[0].kind,
+			SequentialStatementKind::Case { matching: true, .. }
+		));
+		assert!(matches!(
+			process.statements[1].kind,
+			SequentialStatementKind::SignalAssignment { value: AssignmentKind::Conditional(_), .. }
+		));
+		assert!(matches!(
+			process.statements[2].kind,
+			SequentialStatementKind::Force { mode: Some(ForceMode::In), .. }
+		));
+		assert!(matches!(
+			process.statements[3].kind,
+			SequentialStatementKind::Release { mode: None, .. }
+		));
+
+		let SequentialStatementKind::VariableAssignment {
+			value: AssignmentKind::Simple(value),
+			..
+		} = &process.statements[4].kind
+		else {
+			panic!(
+				"Expected a variable assignment, got {:#?}",
+				process.statements[4]
+			);
+		};
+		assert!(matches!(
+			value.inner(),
+			Expression::Name(Name::External { class: ExternalClass::Signal, .. })
+		));
+	}
+);
+
+parser_test!(
+	vhdl08,
+	native_psl,
+	"vunit checks (e(rtl)) {
+		default clock is rising_edge(clk);
+		assert always { req } |=> ack;
+	}
+
+	architecture a of e is
+		sequence s is { req; ack };
+	begin
+		cover s;
+	end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+
+		let LibraryUnit::VerificationUnit(unit) = &ast.units()[0].unit else {
+			panic!("Expected a verification unit, got {:#?}", ast.units()[0]);
+		};
+		assert_eq!(unit.kind, VerificationUnitKind::Vunit);
+		assert!(unit.target.is_some());
+		assert!(unit.body.inner().starts_with(b"default clock"));
+
+		let architecture = ast
+			.architectures()
+			.next()
+			.unwrap_or_else(|| panic!("No architecture"));
+		assert!(matches!(
+			architecture.declarations[0].inner(),
+			Declaration::Psl(PslDeclaration { kind: PslDeclarationKind::Sequence, .. })
+		));
+		assert!(matches!(
+			architecture.statements[0].kind,
+			ConcurrentStatementKind::Psl(PslDirective { kind: PslDirectiveKind::Cover, .. })
+		));
+	}
+);
+
+parser_test!(
+	vhdl08,
+	trailing_interface_semicolon_unsupported,
+	"entity e is port (a : in bit;); end;",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"Trailing semicolons in interface lists are not supported"
+		));
+	}
+);
@@ -1 +1,16 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+parser_test!(
+	vhdl19,
+	trailing_interface_semicolon,
+	"entity e is port (a : in bit; b : out bit;); end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let entity = ast.entities().next().unwrap_or_else(|| panic!("No entity"));
+
+		assert!(ast.diagnostics().is_empty());
+		assert_eq!(entity.ports.as_ref().map(Vec::len), Some(2));
+	}
+);
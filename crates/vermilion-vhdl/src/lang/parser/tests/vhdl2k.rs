@@ -1 +1,17 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+parser_test!(
+	vhdl2k,
+	simultaneous_procedural_unsupported,
+	"architecture a of e is begin
+		p : procedural is begin end procedural p;
+	end architecture a;",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"Simultaneous procedural statements are not supported"
+		));
+	}
+);
@@ -1 +1,50 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+parser_test!(
+	vhdl87,
+	end_keyword_unsupported,
+	"entity e is end entity;",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"Keywords after `end` in declarations are not supported in VHDL 1987 (IEEE \
+			 1076-1987), requires VHDL 1993 (IEEE 1076-1993)"
+		));
+	}
+);
+
+parser_test!(
+	vhdl87,
+	report_statement_unsupported,
+	"architecture a of e is begin process begin report \"hi\"; wait; end process; end;",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"Report statements are not supported"
+		));
+	}
+);
+
+parser_test!(
+	vhdl87,
+	entity_instantiation_unsupported,
+	"architecture a of e is begin u0 : entity work.c; end;",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"Direct entity instantiations are not supported"
+		));
+	}
+);
+
+parser_test!(
+	vhdl87,
+	later_keywords_are_identifiers,
+	"entity e is port (sll, inertial : in bit); end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+	}
+);
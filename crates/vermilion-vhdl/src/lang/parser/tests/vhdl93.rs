@@ -1 +1,59 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+parser_test!(
+	vhdl93,
+	postponed_process,
+	"architecture a of e is begin
+		p : postponed process is
+		begin
+			wait;
+		end postponed process p;
+	end architecture a;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let architecture = ast
+			.architectures()
+			.next()
+			.unwrap_or_else(|| panic!("No architecture"));
+
+		assert!(ast.diagnostics().is_empty());
+		assert!(architecture.statements[0].postponed);
+	}
+);
+
+parser_test!(
+	vhdl93,
+	package_generics_unsupported,
+	"package p is generic (W : integer); end package;",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"Package generics are not supported in VHDL 1993 (IEEE 1076-1993), requires VHDL 2008 \
+			 (IEEE 1076-2008)"
+		));
+	}
+);
+
+parser_test!(
+	vhdl93,
+	process_all_unsupported,
+	"architecture a of e is begin process (all) begin end process; end;",
+	|parsed| {
+		assert!(has_diagnostic(
+			parsed,
+			"`all` sensitivity lists are not supported"
+		));
+	}
+);
+
+parser_test!(
+	vhdl93,
+	psl_keywords_are_identifiers,
+	"architecture a of e is signal property, cover : bit; begin end;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		assert!(ast.diagnostics().is_empty());
+	}
+);
@@ -1 +1,338 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+parser_test!(
+	vhdl_ams_all,
+	nature_declarations,
+	"package natures is
+		subtype voltage is real tolerance \"default_voltage\";
+		subtype current is real tolerance \"default_current\";
+
+		nature electrical is voltage across current through ground reference;
+		nature electrical_vector is array (natural range <>) of electrical;
+		nature pair is record
+			a, b : electrical;
+		end record pair;
+		subnature quiet is electrical tolerance \"low_voltage\" across \"low_current\" through;
+	end package natures;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let package = ast
+			.packages()
+			.next()
+			.unwrap_or_else(|| panic!("No package"));
+
+		let Declaration::Subtype { subtype, .. } = package.declarations[0].inner() else {
+			panic!("Expected a subtype, got {:#?}", package.declarations[0]);
+		};
+		assert!(subtype.inner().tolerance.is_some());
+
+		let Declaration::Nature(nature) = package.declarations[2].inner() else {
+			panic!("Expected a nature, got {:#?}", package.declarations[2]);
+		};
+		assert_eq!(nature.name.inner().as_str(), "electrical");
+		assert!(matches!(
+			nature.definition.inner(),
+			NatureDefinition::Scalar { reference, .. } if reference.inner().as_str() == "ground"
+		));
+
+		let Declaration::Nature(array) = package.declarations[3].inner() else {
+			panic!("Expected a nature, got {:#?}", package.declarations[3]);
+		};
+		assert!(matches!(
+			array.definition.inner(),
+			NatureDefinition::Array { indices, .. } if indices.len() == 1
+		));
+
+		let Declaration::Nature(record) = package.declarations[4].inner() else {
+			panic!("Expected a nature, got {:#?}", package.declarations[4]);
+		};
+		assert!(matches!(
+			record.definition.inner(),
+			NatureDefinition::Record(elements) if elements[0].inner().names.len() == 2
+		));
+
+		let Declaration::Subnature { subnature, .. } = package.declarations[5].inner() else {
+			panic!("Expected a subnature, got {:#?}", package.declarations[5]);
+		};
+		assert!(subnature.inner().tolerance.is_some());
+	}
+);
+
+parser_test!(
+	vhdl_ams_all,
+	terminals_and_quantities,
+	"entity resistor is
+		generic (r : real := 1.0e3);
+		port (terminal p, n : electrical; quantity gain : in real);
+	end entity resistor;
+
+	architecture behaviour of resistor is
+		terminal internal : electrical;
+		quantity v across i through p to n;
+		quantity vr tolerance \"tight\" := 0.0 across p;
+		quantity level : real := 0.0;
+		quantity ac : real spectrum 1.0, 0.0;
+		quantity thermal : real noise 4.0 * 1.38e-23 * 300.0 * r;
+		limit v : real with 1.0e-6;
+	begin
+	end architecture behaviour;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+
+		let entity = ast.entities().next().unwrap_or_else(|| panic!("No entity"));
+		let ports = entity.ports.as_ref().unwrap_or_else(|| panic!("No ports"));
+		assert!(matches!(
+			ports[0].inner(),
+			InterfaceDeclaration::Terminal { names, .. } if names.len() == 2
+		));
+		assert!(matches!(
+			ports[1].inner(),
+			InterfaceDeclaration::Object(InterfaceObject {
+				class: Some(ObjectClass::Quantity),
+				mode: Some(Mode::In),
+				..
+			})
+		));
+
+		let architecture = ast
+			.architectures()
+			.next()
+			.unwrap_or_else(|| panic!("No architecture"));
+		let declarations = &architecture.declarations;
+
+		assert!(matches!(
+			declarations[0].inner(),
+			Declaration::Terminal { .. }
+		));
+
+		let Declaration::Quantity(QuantityDeclaration::Branch(branch)) = declarations[1].inner()
+		else {
+			panic!("Expected a branch quantity, got {:#?}", declarations[1]);
+		};
+		assert!(branch.across.is_some());
+		assert!(branch.through.is_some());
+		assert!(branch.minus.is_some());
+
+		let Declaration::Quantity(QuantityDeclaration::Branch(branch)) = declarations[2].inner()
+		else {
+			panic!("Expected a branch quantity, got {:#?}", declarations[2]);
+		};
+		let across = branch
+			.across
+			.as_ref()
+			.unwrap_or_else(|| panic!("No across aspect"));
+		assert!(across.tolerance.is_some());
+		assert!(across.default.is_some());
+		assert!(branch.through.is_none());
+		assert!(branch.minus.is_none());
+
+		assert!(matches!(
+			declarations[3].inner(),
+			Declaration::Quantity(QuantityDeclaration::Free { default: Some(_), .. })
+		));
+		assert!(matches!(
+			declarations[4].inner(),
+			Declaration::Quantity(QuantityDeclaration::Source {
+				source: SourceAspect::Spectrum { .. },
+				..
+			})
+		));
+		assert!(matches!(
+			declarations[5].inner(),
+			Declaration::Quantity(QuantityDeclaration::Source {
+				source: SourceAspect::Noise(_),
+				..
+			})
+		));
+		assert!(matches!(
+			declarations[6].inner(),
+			Declaration::StepLimit { quantities: InstantiationTargets::Names(names), .. }
+				if names.len() == 1
+		));
+	}
+);
+
+parser_test!(
+	vhdl_ams_all,
+	simultaneous_statements,
+	"architecture behaviour of diode is
+		quantity v across i through p to n;
+	begin
+		i == isat * (exp(v / vt) - 1.0);
+		limit_v : v == 0.0 tolerance \"loose\";
+
+		region : if v'above(0.7) use
+			i == v / r_on;
+		elsif v'above(-5.0) use
+			i == 0.0;
+		else
+			null;
+		end use region;
+
+		case mode use
+			when active => i == v / r_on;
+			when others => i == 0.0;
+		end case;
+
+		p : procedural is
+			variable x : real;
+		begin
+			x := v * 2.0;
+		end procedural p;
+
+		break v => 0.0 on clk when clk = '1';
+		break for v use i => 0.0;
+	end architecture behaviour;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let architecture = ast
+			.architectures()
+			.next()
+			.unwrap_or_else(|| panic!("No architecture"));
+		let statements = &architecture.statements;
+
+		assert!(matches!(
+			statements[0].inner().kind,
+			ConcurrentStatementKind::SimpleSimultaneous { tolerance: None, .. }
+		));
+		assert!(matches!(
+			statements[1].inner().kind,
+			ConcurrentStatementKind::SimpleSimultaneous { tolerance: Some(_), .. }
+		));
+
+		let ConcurrentStatementKind::SimultaneousIf(branches) = &statements[2].inner().kind else {
+			panic!("Expected a simultaneous if, got {:#?}", statements[2]);
+		};
+		assert_eq!(branches.len(), 3);
+		assert!(branches[2].inner().condition.is_none());
+		assert!(matches!(
+			branches[2].inner().value[0].inner().kind,
+			ConcurrentStatementKind::SimultaneousNull
+		));
+
+		assert!(matches!(
+			&statements[3].inner().kind,
+			ConcurrentStatementKind::SimultaneousCase { alternatives, .. }
+				if alternatives.len() == 2
+		));
+		assert!(matches!(
+			&statements[4].inner().kind,
+			ConcurrentStatementKind::SimultaneousProcedural(procedural)
+				if procedural.declarations.len() == 1 && procedural.statements.len() == 1
+		));
+
+		let ConcurrentStatementKind::Break { elements, sensitivity, condition } =
+			&statements[5].inner().kind
+		else {
+			panic!("Expected a break, got {:#?}", statements[5]);
+		};
+		assert_eq!(elements.len(), 1);
+		assert_eq!(sensitivity.len(), 1);
+		assert!(condition.is_some());
+
+		assert!(matches!(
+			&statements[6].inner().kind,
+			ConcurrentStatementKind::Break { elements, .. }
+				if elements[0].inner().selector.is_some()
+		));
+	}
+);
+
+parser_test!(
+	vhdl_ams_all,
+	sequential_break,
+	"architecture a of e is begin
+		process begin
+			break v => 0.0 when reset;
+			break;
+			wait on clk;
+		end process;
+	end architecture a;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let architecture = ast
+			.architectures()
+			.next()
+			.unwrap_or_else(|| panic!("No architecture"));
+
+		let ConcurrentStatementKind::Process(process) = &architecture.statements[0].inner().kind
+		else {
+			panic!("Expected a process, got {:#?}", architecture.statements[0]);
+		};
+		assert!(matches!(
+			&process.statements[0].inner().kind,
+			SequentialStatementKind::Break { elements, condition: Some(_) } if elements.len() == 1
+		));
+		assert!(matches!(
+			&process.statements[1].inner().kind,
+			SequentialStatementKind::Break { elements, condition: None } if elements.is_empty()
+		));
+	}
+);
+
+parser_test!(
+	vhdl_ams_all,
+	analog_attributes,
+	"architecture a of e is
+		quantity filtered : real;
+	begin
+		filtered == v'ltf(num, den) + p'reference + v'dot;
+	end architecture a;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let architecture = ast
+			.architectures()
+			.next()
+			.unwrap_or_else(|| panic!("No architecture"));
+
+		let ConcurrentStatementKind::SimpleSimultaneous { rhs, .. } =
+			&architecture.statements[0].inner().kind
+		else {
+			panic!(
+				"Expected a simultaneous statement, got {:#?}",
+				architecture.statements[0]
+			);
+		};
+
+		fn find_ltf(expression: &Expression) -> bool {
+			match expression {
+				Expression::Binary { lhs, rhs, .. } => {
+					find_ltf(lhs.inner()) || find_ltf(rhs.inner())
+				},
+				Expression::Name(Name::Attribute { attribute, argument, .. }) => {
+					attribute.inner().as_str() == "ltf" &&
+						matches!(
+							argument.as_deref().map(|argument| argument.inner()),
+							Some(Expression::Aggregate(arguments)) if arguments.len() == 2
+						)
+				},
+				_ => false,
+			}
+		}
+		assert!(find_ltf(rhs.inner()));
+	}
+);
+
+parser_test!(
+	vhdl_ams_all,
+	generate_is_not_simultaneous,
+	"architecture a of e is begin
+		g : if width > 1 generate
+			v == 0.0;
+		end generate g;
+	end architecture a;",
+	|parsed| {
+		let ast = expect_ast(parsed);
+		let architecture = ast
+			.architectures()
+			.next()
+			.unwrap_or_else(|| panic!("No architecture"));
+
+		assert!(matches!(
+			architecture.statements[0].inner().kind,
+			ConcurrentStatementKind::IfGenerate(_)
+		));
+	}
+);
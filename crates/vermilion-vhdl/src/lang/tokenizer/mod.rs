@@ -3,20 +3,55 @@ use std::collections::VecDeque;
 
 use eyre::eyre;
 use vermilion_lang::{
-	AtomicByteTendril, spanned_token,
+	AtomicByteTendril, simple_token, spanned_token,
 	tokenizer::{CoreTokenizer, Tokenizer},
+	versioned_token,
 };
-use vermilion_loc::Spanned;
+use vermilion_loc::{Position, Span, Spanned, span::ThinSpan};
 
-use crate::{LanguageStd, lang::tokenizer::token::Token};
+use crate::{
+	LanguageStd,
+	lang::{
+		keywords,
+		tokenizer::token::{Comment, Control, Keyword, Operator, Token},
+	},
+};
 
 pub mod token;
 
+/// Standards with the IEEE 1076-1993 lexical additions, extended identifiers and brackets
+const VHDL_93_STDS: LanguageStd = LanguageStd::Vh93
+	.or(LanguageStd::Vh2k)
+	.or(LanguageStd::Vh02)
+	.or(LanguageStd::Vh04)
+	.or(LanguageStd::Vh07)
+	.or(LanguageStd::Vh08)
+	.or(LanguageStd::Vh11)
+	.or(LanguageStd::Vh19)
+	.or(LanguageStd::Vh23)
+	.or(LanguageStd::VHDL_AMS_STDS)
+	.or(LanguageStd::Bsdl);
+
+/// Standards with the IEEE 1076-2008 lexical additions, such as block comments, sized bit string
+/// literals, and the matching operators
+const VHDL_08_STDS: LanguageStd = LanguageStd::Vh08
+	.or(LanguageStd::Vh11)
+	.or(LanguageStd::Vh19)
+	.or(LanguageStd::Vh23)
+	.or(LanguageStd::Vhams17)
+	.or(LanguageStd::Vhams21);
+
+/// Standards with IEEE 1076-2019 tool directives
+const VHDL_19_STDS: LanguageStd = LanguageStd::Vh19.or(LanguageStd::Vh23);
+
 pub struct VhdlTokenizer {
-	_standard:    LanguageStd,
+	standard:     LanguageStd,
 	tokenizer:    CoreTokenizer,
 	token:        Spanned<Token>,
 	token_stream: VecDeque<Spanned<Token>>,
+	/// If an apostrophe at this point would be an attribute tick rather than the start of a
+	/// character literal, this depends on the last non-trivia token
+	tick_allowed: bool,
 }
 
 impl VhdlTokenizer {
@@ -27,10 +62,11 @@ impl VhdlTokenizer {
 		}
 
 		Ok(Self {
-			_standard:    standard,
-			tokenizer:    CoreTokenizer::new(file),
-			token:        spanned_token!(Token::default()),
+			standard,
+			tokenizer: CoreTokenizer::new(file),
+			token: spanned_token!(Token::default()),
 			token_stream: VecDeque::new(),
+			tick_allowed: false,
 		})
 	}
 
@@ -44,6 +80,26 @@ impl VhdlTokenizer {
 		self.tokenizer.is_empty()
 	}
 
+	#[inline(always)]
+	fn current_is_whitespace(&self) -> bool {
+		matches!(self.tokenizer.current_byte(), b' ' | b'\t' | 0x0B | 0x0C)
+	}
+
+	#[inline(always)]
+	fn current_is_newline(&self) -> bool {
+		matches!(self.tokenizer.current_byte(), b'\r' | b'\n')
+	}
+
+	/// Look at the byte `distance` bytes ahead of the current one without consuming anything,
+	/// returning `0` if that would be past the end of the input
+	#[inline(always)]
+	fn peek(&self, distance: u32) -> u8 {
+		self.tokenizer[..]
+			.get((self.tokenizer.offset() + distance) as usize)
+			.copied()
+			.unwrap_or(0)
+	}
+
 	fn read_token(&mut self) {
 		if !self.token_stream.is_empty() {
 			#[allow(
@@ -59,17 +115,651 @@ impl VhdlTokenizer {
 			return;
 		}
 
-		#[allow(
-			clippy::match_single_binding,
-			reason = "Temporary until the tokenizer is fleshed out"
-		)]
 		match self.tokenizer.current_byte() {
+			b' ' | b'\t' | 0x0B | 0x0C => self.read_whitespace(),
+			b'\r' | b'\n' => self.read_newline(),
+			b'&' => simple_token!(self, Token::Operator(Operator::Ampersand)),
+			b'\'' => self.read_apostrophe_token(),
+			b'(' => simple_token!(self, Token::Control(Control::ParenOpen)),
+			b')' => simple_token!(self, Token::Control(Control::ParenClose)),
+			b'*' => self.read_asterisk_token(),
+			b'+' => simple_token!(self, Token::Operator(Operator::Plus)),
+			b',' => simple_token!(self, Token::Control(Control::Comma)),
+			b'-' => self.read_minus_token(),
+			b'.' => simple_token!(self, Token::Control(Control::Dot)),
+			b'/' => self.read_solidus_token(),
+			b':' => self.read_colon_token(),
+			b';' => simple_token!(self, Token::Control(Control::Semicolon)),
+			b'<' => self.read_less_than_token(),
+			b'=' => self.read_equals_token(),
+			b'>' => self.read_greater_than_token(),
+			b'?' => self.read_question_token(),
+			b'|' => simple_token!(self, Token::Control(Control::Pipe)),
+			b'[' => self.read_versioned_control(Control::BracketOpen, VHDL_93_STDS),
+			b']' => self.read_versioned_control(Control::BracketClose, VHDL_93_STDS),
+			b'@' => self.read_versioned_control(Control::At, VHDL_08_STDS),
+			b'^' => self.read_versioned_control(Control::Circumflex, VHDL_08_STDS),
+			b'\\' => self.read_extended_identifier(),
+			b'`' => self.read_grave_token(),
+			b'"' => self.read_quote_token(),
 			_ => self.read_extended_token(),
 		}
 	}
 
 	fn read_extended_token(&mut self) {
-		let _context = self.tokenizer.position();
+		if self.tokenizer.current_byte().is_ascii_alphabetic() {
+			self.read_identifier();
+		} else if self.tokenizer.current_byte().is_ascii_digit() {
+			self.read_number_token();
+		} else {
+			// If we hit this then we've encountered some invalid input, likely an invalid
+			// character, so we consume it and emit an invalid token so we can advance the
+			// tokenizer state and not get stuck in a loop forever.
+			self.read_invalid();
+		}
+	}
+
+	fn read_whitespace(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+
+		while self.current_is_whitespace() {
+			self.tokenizer.next_char();
+		}
+
+		let token_span = ThinSpan::new(begin, self.tokenizer.offset());
+		self.token = spanned_token!(
+			Token::Whitespace(self.tokenizer.subtendril(token_span)),
+			token_span.as_span(Some(context))
+		)
+	}
+
+	fn read_invalid(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+
+		// Always consume at least one byte, then the rest of any run of non-ASCII bytes so a
+		// multi-byte character ends up in a single token
+		self.tokenizer.next_char();
+		while !self.tokenizer.current_byte().is_ascii() && !self.tokenizer.is_eof() {
+			self.tokenizer.next_char();
+		}
+
+		let token_span = ThinSpan::new(begin, self.tokenizer.offset());
+		self.token = spanned_token!(
+			Token::Invalid(Some(self.tokenizer.subtendril(token_span))),
+			token_span.as_span(Some(context))
+		)
+	}
+
+	fn read_newline(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+
+		// Handle \r\n sequences
+		if self.tokenizer.next_char() == b'\r' && self.tokenizer.current_byte() == b'\n' {
+			self.tokenizer.next_char();
+		}
+
+		// Newlines reset the position context for the next token
+		self.tokenizer.advance_line();
+		let token_span = ThinSpan::new(begin, self.tokenizer.offset());
+		self.token = spanned_token!(
+			Token::Newline(self.tokenizer.subtendril(token_span)),
+			token_span.as_span(Some(context))
+		)
+	}
+
+	/// Read a single character delimiter that only exists in some standards
+	fn read_versioned_control(&mut self, control: Control, stds: LanguageStd) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+		self.tokenizer.next_char();
+
+		let token_span = ThinSpan::new(begin, self.tokenizer.offset());
+		self.token = spanned_token!(
+			versioned_token!(self, token_span, Token::Control(control), stds),
+			token_span.as_span(Some(context))
+		);
+	}
+
+	fn read_apostrophe_token(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+
+		// An apostrophe right after a name is always an attribute tick, otherwise check for a
+		// character literal, which is exactly one graphic character between two apostrophes
+		if !self.tick_allowed && self.peek(2) == b'\'' && matches!(self.peek(1), 0x20..=0x7E) {
+			self.tokenizer.next_char();
+			self.tokenizer.next_char();
+			self.tokenizer.next_char();
+
+			self.token = spanned_token!(
+				Token::Character(
+					self.tokenizer
+						.subtendril(ThinSpan::new(begin + 1, begin + 2))
+				),
+				Span::from_position(begin, self.tokenizer.offset(), context)
+			);
+		} else {
+			simple_token!(self, Token::Control(Control::Apostrophe));
+		}
+	}
+
+	fn read_asterisk_token(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+		self.tokenizer.next_char();
+
+		self.token = spanned_token!(
+			match self.tokenizer.current_byte() {
+				b'*' => {
+					self.tokenizer.next_char();
+					Token::Operator(Operator::Power)
+				},
+				_ => Token::Operator(Operator::Asterisk),
+			},
+			Span::from_position(begin, self.tokenizer.offset(), context)
+		);
+	}
+
+	fn read_minus_token(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+		self.tokenizer.next_char(); // Consume the first `-`
+
+		if self.tokenizer.current_byte() == b'-' {
+			self.tokenizer.next_char();
+			self.read_singleline_comment(context, begin);
+		} else {
+			self.token = spanned_token!(
+				Token::Operator(Operator::Minus),
+				Span::from_position(begin, self.tokenizer.offset(), context)
+			);
+		}
+	}
+
+	fn read_solidus_token(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+		self.tokenizer.next_char(); // Consume the opening `/`
+
+		match self.tokenizer.current_byte() {
+			b'*' => {
+				self.tokenizer.next_char();
+				self.read_multiline_comment(context, begin);
+			},
+			b'=' => {
+				self.tokenizer.next_char();
+				self.token = spanned_token!(
+					Token::Operator(Operator::Inequality),
+					Span::from_position(begin, self.tokenizer.offset(), context)
+				);
+			},
+			_ => {
+				self.token = spanned_token!(
+					Token::Operator(Operator::Solidus),
+					Span::from_position(begin, self.tokenizer.offset(), context)
+				);
+			},
+		}
+	}
+
+	fn read_colon_token(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+		self.tokenizer.next_char();
+
+		self.token = spanned_token!(
+			match self.tokenizer.current_byte() {
+				b'=' => {
+					self.tokenizer.next_char();
+					Token::Control(Control::VariableAssignment)
+				},
+				_ => Token::Control(Control::Colon),
+			},
+			Span::from_position(begin, self.tokenizer.offset(), context)
+		);
+	}
+
+	fn read_less_than_token(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+		self.tokenizer.next_char();
+
+		self.token = spanned_token!(
+			match self.tokenizer.current_byte() {
+				b'=' => {
+					self.tokenizer.next_char();
+					Token::Operator(Operator::LessThanEqual)
+				},
+				b'>' => {
+					self.tokenizer.next_char();
+					Token::Control(Control::Box)
+				},
+				b'<' => {
+					self.tokenizer.next_char();
+					versioned_token!(
+						self,
+						ThinSpan::new(begin, self.tokenizer.offset()),
+						Token::Control(Control::DoubleLessThan),
+						VHDL_08_STDS
+					)
+				},
+				_ => Token::Operator(Operator::LessThan),
+			},
+			Span::from_position(begin, self.tokenizer.offset(), context)
+		);
+	}
+
+	fn read_equals_token(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+		self.tokenizer.next_char();
+
+		self.token = spanned_token!(
+			match self.tokenizer.current_byte() {
+				b'>' => {
+					self.tokenizer.next_char();
+					Token::Control(Control::Arrow)
+				},
+				b'=' => {
+					self.tokenizer.next_char();
+					versioned_token!(
+						self,
+						ThinSpan::new(begin, self.tokenizer.offset()),
+						Token::Operator(Operator::DoubleEquals),
+						LanguageStd::VHDL_AMS_STDS
+					)
+				},
+				_ => Token::Operator(Operator::Equals),
+			},
+			Span::from_position(begin, self.tokenizer.offset(), context)
+		);
+	}
+
+	fn read_greater_than_token(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+		self.tokenizer.next_char();
+
+		self.token = spanned_token!(
+			match self.tokenizer.current_byte() {
+				b'=' => {
+					self.tokenizer.next_char();
+					Token::Operator(Operator::GreaterThanEqual)
+				},
+				b'>' => {
+					self.tokenizer.next_char();
+					versioned_token!(
+						self,
+						ThinSpan::new(begin, self.tokenizer.offset()),
+						Token::Control(Control::DoubleGreaterThan),
+						VHDL_08_STDS
+					)
+				},
+				_ => Token::Operator(Operator::GreaterThan),
+			},
+			Span::from_position(begin, self.tokenizer.offset(), context)
+		);
+	}
+
+	fn read_question_token(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+		self.tokenizer.next_char();
+
+		let token = match self.tokenizer.current_byte() {
+			b'?' => {
+				self.tokenizer.next_char();
+				Token::Operator(Operator::Condition)
+			},
+			b'=' => {
+				self.tokenizer.next_char();
+				Token::Operator(Operator::MatchingEquals)
+			},
+			b'/' if self.peek(1) == b'=' => {
+				self.tokenizer.next_char();
+				self.tokenizer.next_char();
+				Token::Operator(Operator::MatchingInequality)
+			},
+			b'<' => {
+				self.tokenizer.next_char();
+				if self.tokenizer.current_byte() == b'=' {
+					self.tokenizer.next_char();
+					Token::Operator(Operator::MatchingLessThanEqual)
+				} else {
+					Token::Operator(Operator::MatchingLessThan)
+				}
+			},
+			b'>' => {
+				self.tokenizer.next_char();
+				if self.tokenizer.current_byte() == b'=' {
+					self.tokenizer.next_char();
+					Token::Operator(Operator::MatchingGreaterThanEqual)
+				} else {
+					Token::Operator(Operator::MatchingGreaterThan)
+				}
+			},
+			_ => Token::Control(Control::Question),
+		};
+
+		let token_span = ThinSpan::new(begin, self.tokenizer.offset());
+		self.token = spanned_token!(
+			versioned_token!(self, token_span, token, VHDL_08_STDS),
+			token_span.as_span(Some(context))
+		);
+	}
+
+	fn read_multiline_comment(&mut self, context: Position, begin: u32) {
+		let mut invalid_comment = false;
+
+		// Consume the block comment, they do not nest so the first `*/` always ends it
+		loop {
+			if self.tokenizer.is_eof() {
+				invalid_comment = true;
+				break;
+			}
+
+			match self.tokenizer.next_char() {
+				b'*' if self.tokenizer.current_byte() == b'/' => {
+					self.tokenizer.next_char();
+					break;
+				},
+				b'\n' => self.tokenizer.advance_line(),
+				b'\r' if self.tokenizer.current_byte() != b'\n' => self.tokenizer.advance_line(),
+				_ => {},
+			}
+		}
+
+		let token_span = ThinSpan::new(begin, self.tokenizer.offset());
+		let comment = if invalid_comment {
+			Comment::Invalid(self.tokenizer.subtendril(token_span))
+		} else {
+			Comment::MultiLine(self.tokenizer.subtendril(token_span))
+		};
+
+		self.token = spanned_token!(
+			versioned_token!(self, token_span, Token::Comment(comment), VHDL_08_STDS),
+			token_span.as_span(Some(context))
+		);
+	}
+
+	fn read_singleline_comment(&mut self, context: Position, begin: u32) {
+		while !self.current_is_newline() && !self.tokenizer.is_eof() {
+			self.tokenizer.next_char();
+		}
+
+		self.token = spanned_token!(
+			Token::Comment(Comment::SingleLine(
+				self.tokenizer
+					.subtendril(ThinSpan::new(begin + 2, self.tokenizer.offset())),
+			)),
+			Span::from_position(begin, self.tokenizer.offset(), context)
+		);
+	}
+
+	fn read_grave_token(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+		self.tokenizer.next_char(); // Skip the '`'
+
+		// Tool directives must be directly followed by their name
+		if !self.tokenizer.current_byte().is_ascii_alphabetic() {
+			let token_span = ThinSpan::new(begin, self.tokenizer.offset());
+			self.token = spanned_token!(
+				Token::Invalid(Some(self.tokenizer.subtendril(token_span))),
+				token_span.as_span(Some(context))
+			);
+
+			return;
+		}
+
+		let name_span = self.read_basic_ident();
+		let token_span = ThinSpan::new(begin, self.tokenizer.offset());
+		self.token = spanned_token!(
+			versioned_token!(
+				self,
+				token_span,
+				Token::ToolDirective(self.tokenizer.subtendril(name_span)),
+				VHDL_19_STDS
+			),
+			token_span.as_span(Some(context))
+		);
+	}
+
+	fn read_quote_token(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+
+		self.token = match self.read_delimited(b'"') {
+			Some(str_span) => spanned_token!(
+				Token::String(self.tokenizer.subtendril(str_span)),
+				Span::from_position(begin, self.tokenizer.offset(), context)
+			),
+			None => {
+				let token_span = ThinSpan::new(begin, self.tokenizer.offset());
+				spanned_token!(
+					Token::Invalid(Some(self.tokenizer.subtendril(token_span))),
+					token_span.as_span(Some(context))
+				)
+			},
+		};
+	}
+
+	fn read_extended_identifier(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+
+		let token_span;
+		self.token = match self.read_delimited(b'\\') {
+			Some(ident_span) => {
+				token_span = ThinSpan::new(begin, self.tokenizer.offset());
+				spanned_token!(
+					versioned_token!(
+						self,
+						token_span,
+						Token::ExtendedIdentifier(self.tokenizer.subtendril(ident_span)),
+						VHDL_93_STDS
+					),
+					token_span.as_span(Some(context))
+				)
+			},
+			None => {
+				token_span = ThinSpan::new(begin, self.tokenizer.offset());
+				spanned_token!(
+					Token::Invalid(Some(self.tokenizer.subtendril(token_span))),
+					token_span.as_span(Some(context))
+				)
+			},
+		};
+	}
+
+	/// Read a string-like literal delimited by `delimiter`, where a doubled delimiter stands for
+	/// a single one inside of the literal
+	///
+	/// Returns the span of the contents between the delimiters, or `None` if the literal was not
+	/// closed before the end of the line.
+	fn read_delimited(&mut self, delimiter: u8) -> Option<ThinSpan> {
+		self.tokenizer.next_char(); // Consume the opening delimiter
+		let contents_begin = self.tokenizer.offset();
+
+		loop {
+			if self.current_is_newline() || self.tokenizer.is_eof() {
+				return None;
+			}
+
+			if self.tokenizer.next_char() == delimiter {
+				if self.tokenizer.current_byte() == delimiter {
+					self.tokenizer.next_char();
+				} else {
+					return Some(ThinSpan::new(contents_begin, self.tokenizer.offset() - 1));
+				}
+			}
+		}
+	}
+
+	fn read_identifier(&mut self) {
+		let context = self.tokenizer.position();
+		let span = self.read_basic_ident();
+
+		// An identifier that is a base specifier directly followed by a string is actually a
+		// bit string literal, such as `X"FF"`
+		if self.tokenizer.current_byte() == b'"' &&
+			let Some(stds) = base_specifier_stds(&self.tokenizer[span])
+		{
+			self.read_bit_string(context, *span.begin(), stds);
+			return;
+		}
+
+		// We've already validated via the above read, that the entire token is valid UTF-8.
+		// Just make it a string.
+		let ident = unsafe { str::from_utf8_unchecked(&self.tokenizer[span]) };
+
+		// VHDL is case insensitive, and the keyword maps are all lowercase
+		let token = match keywords::get_keyword(&ident.to_ascii_lowercase(), self.standard) {
+			Some(keyword) => Token::Keyword(keyword),
+			None => Token::Identifier(self.tokenizer.subtendril(span)),
+		};
+
+		self.token = spanned_token!(token, span.as_span(Some(context)));
+	}
+
+	fn read_number_token(&mut self) {
+		let context = self.tokenizer.position();
+		let begin = self.tokenizer.offset();
+
+		self.read_digits(|byte| byte.is_ascii_digit());
+
+		// An integer directly followed by a base specifier and a string is a sized bit string
+		let mut specifier_len = 0;
+		while self.peek(specifier_len).is_ascii_alphabetic() {
+			specifier_len += 1;
+		}
+
+		let offset = self.tokenizer.offset();
+		if specifier_len > 0 &&
+			self.peek(specifier_len) == b'"' &&
+			base_specifier_stds(&self.tokenizer[offset..offset + specifier_len]).is_some()
+		{
+			for _ in 0..specifier_len {
+				self.tokenizer.next_char();
+			}
+
+			self.read_bit_string(context, begin, VHDL_08_STDS);
+			return;
+		}
+
+		if self.tokenizer.current_byte() == b'#' {
+			// Based literal, such as `16#FF#` or `2#1.1#E4`
+			self.tokenizer.next_char();
+			self.read_digits(|byte| byte.is_ascii_hexdigit());
+			if self.tokenizer.current_byte() == b'.' {
+				self.tokenizer.next_char();
+				self.read_digits(|byte| byte.is_ascii_hexdigit());
+			}
+
+			if self.tokenizer.current_byte() != b'#' {
+				let token_span = ThinSpan::new(begin, self.tokenizer.offset());
+				self.token = spanned_token!(
+					Token::Invalid(Some(self.tokenizer.subtendril(token_span))),
+					token_span.as_span(Some(context))
+				);
+
+				return;
+			}
+			self.tokenizer.next_char();
+		} else if self.tokenizer.current_byte() == b'.' && self.peek(1).is_ascii_digit() {
+			// Real decimal literal
+			self.tokenizer.next_char();
+			self.read_digits(|byte| byte.is_ascii_digit());
+		}
+
+		self.read_exponent();
+
+		let token_span = ThinSpan::new(begin, self.tokenizer.offset());
+		self.token = spanned_token!(
+			Token::Number(self.tokenizer.subtendril(token_span)),
+			token_span.as_span(Some(context))
+		);
+	}
+
+	/// Consume an optional exponent, such as `E6` or `e-3`
+	fn read_exponent(&mut self) {
+		if !matches!(self.tokenizer.current_byte(), b'e' | b'E') {
+			return;
+		}
+
+		let digits_at = if matches!(self.peek(1), b'+' | b'-') {
+			2
+		} else {
+			1
+		};
+		if !self.peek(digits_at).is_ascii_digit() {
+			return;
+		}
+
+		for _ in 0..digits_at {
+			self.tokenizer.next_char();
+		}
+		self.read_digits(|byte| byte.is_ascii_digit());
+	}
+
+	/// Consume a sequence of digits that may be separated by underscores
+	#[inline(always)]
+	fn read_digits(&mut self, is_digit: impl Fn(u8) -> bool) {
+		while is_digit(self.tokenizer.current_byte()) || self.tokenizer.current_byte() == b'_' {
+			self.tokenizer.next_char();
+		}
+	}
+
+	/// Read the string part of a bit string literal whose length and base specifier have already
+	/// been consumed, and are valid in `stds`
+	fn read_bit_string(&mut self, context: Position, begin: u32, stds: LanguageStd) {
+		let closed = self.read_delimited(b'"').is_some();
+		let token_span = ThinSpan::new(begin, self.tokenizer.offset());
+
+		self.token = spanned_token!(
+			if closed {
+				versioned_token!(
+					self,
+					token_span,
+					Token::BitString(self.tokenizer.subtendril(token_span)),
+					stds
+				)
+			} else {
+				Token::Invalid(Some(self.tokenizer.subtendril(token_span)))
+			},
+			token_span.as_span(Some(context))
+		);
+	}
+
+	#[inline(always)]
+	fn read_basic_ident(&mut self) -> ThinSpan {
+		let begin = self.tokenizer.offset();
+		// Scan through till we get something that's not a-zA-Z0-9_
+		while self.tokenizer.current_byte().is_ascii_alphanumeric() ||
+			self.tokenizer.current_byte() == b'_'
+		{
+			self.tokenizer.next_char();
+		}
+		// Return the range consumed
+		ThinSpan::new(begin, self.tokenizer.offset())
+	}
+}
+
+/// Get the standards the given bit string base specifier is valid in, if it is one
+fn base_specifier_stds(specifier: &[u8]) -> Option<LanguageStd> {
+	match specifier.to_ascii_lowercase().as_slice() {
+		b"b" | b"o" | b"x" => Some(LanguageStd::all_bits()),
+		b"ub" | b"uo" | b"ux" | b"sb" | b"so" | b"sx" | b"d" => Some(VHDL_08_STDS),
+		_ => None,
+	}
+}
+
+impl From<VhdlTokenizer> for Vec<Spanned<Token>> {
+	fn from(value: VhdlTokenizer) -> Self {
+		value.collect::<Self>()
 	}
 }
 
@@ -84,6 +774,21 @@ impl Iterator for VhdlTokenizer {
 			return None;
 		}
 		self.read_token();
+
+		// Trivia doesn't change what an apostrophe means, so only track significant tokens
+		match self.token.inner() {
+			Token::Whitespace(_) | Token::Newline(_) | Token::Comment(_) => {},
+			token => {
+				self.tick_allowed = matches!(
+					token,
+					Token::Identifier(_) |
+						Token::ExtendedIdentifier(_) |
+						Token::Keyword(Keyword::All) |
+						Token::Control(Control::ParenClose | Control::BracketClose)
+				)
+			},
+		}
+
 		Some(self.token.clone())
 	}
 }
@@ -1 +1,469 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+tokenizer_test!(
+	all,
+	whitespace_mixed,
+	"\t \x0C",
+	spanned_token!(
+		Token::Whitespace("\t \x0C".as_bytes().into()),
+		Span::from_position(0, 3, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	newline_mixed,
+	"\r\n\n\r",
+	spanned_token!(
+		Token::Newline("\r\n".as_bytes().into()),
+		Span::from_position(0, 2, Position::new(0, 0))
+	),
+	spanned_token!(
+		Token::Newline("\n".as_bytes().into()),
+		Span::from_position(2, 3, Position::new(1, 0))
+	),
+	spanned_token!(
+		Token::Newline("\r".as_bytes().into()),
+		Span::from_position(3, 4, Position::new(2, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	identifier,
+	"data_in",
+	spanned_token!(
+		Token::Identifier("data_in".as_bytes().into()),
+		Span::from_position(0, 7, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	keyword_case_insensitive,
+	"EnTiTy",
+	spanned_token!(
+		Token::Keyword(Keyword::Entity),
+		Span::from_position(0, 6, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	comment_singleline,
+	"-- comment\nx",
+	spanned_token!(
+		Token::Comment(Comment::SingleLine(" comment".as_bytes().into())),
+		Span::from_position(0, 10, Position::new(0, 0))
+	),
+	spanned_token!(
+		Token::Newline("\n".as_bytes().into()),
+		Span::from_position(10, 11, Position::new(0, 10))
+	),
+	spanned_token!(
+		Token::Identifier("x".as_bytes().into()),
+		Span::from_position(11, 12, Position::new(1, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	string,
+	"\"a \"\"b\"\" c\"",
+	spanned_token!(
+		Token::String("a \"\"b\"\" c".as_bytes().into()),
+		Span::from_position(0, 11, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	string_unterminated,
+	"\"abc\n",
+	spanned_token!(
+		Token::Invalid(Some("\"abc".as_bytes().into())),
+		Span::from_position(0, 4, Position::new(0, 0))
+	),
+	spanned_token!(
+		Token::Newline("\n".as_bytes().into()),
+		Span::from_position(4, 5, Position::new(0, 4))
+	)
+);
+
+tokenizer_test!(
+	all,
+	character,
+	"'1'",
+	spanned_token!(
+		Token::Character("1".as_bytes().into()),
+		Span::from_position(0, 3, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	attribute_tick,
+	"clk'event",
+	spanned_token!(
+		Token::Identifier("clk".as_bytes().into()),
+		Span::from_position(0, 3, Position::new(0, 0))
+	),
+	spanned_token!(
+		Token::Control(Control::Apostrophe),
+		Span::from_position(3, 4, Position::new(0, 3))
+	),
+	spanned_token!(
+		Token::Identifier("event".as_bytes().into()),
+		Span::from_position(4, 9, Position::new(0, 4))
+	)
+);
+
+tokenizer_test!(
+	all,
+	qualified_character,
+	"t'('a')",
+	spanned_token!(
+		Token::Identifier("t".as_bytes().into()),
+		Span::from_position(0, 1, Position::new(0, 0))
+	),
+	spanned_token!(
+		Token::Control(Control::Apostrophe),
+		Span::from_position(1, 2, Position::new(0, 1))
+	),
+	spanned_token!(
+		Token::Control(Control::ParenOpen),
+		Span::from_position(2, 3, Position::new(0, 2))
+	),
+	spanned_token!(
+		Token::Character("a".as_bytes().into()),
+		Span::from_position(3, 6, Position::new(0, 3))
+	),
+	spanned_token!(
+		Token::Control(Control::ParenClose),
+		Span::from_position(6, 7, Position::new(0, 6))
+	)
+);
+
+tokenizer_test!(
+	all,
+	number_integer,
+	"1_000",
+	spanned_token!(
+		Token::Number("1_000".as_bytes().into()),
+		Span::from_position(0, 5, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	number_real,
+	"1.5E-3",
+	spanned_token!(
+		Token::Number("1.5E-3".as_bytes().into()),
+		Span::from_position(0, 6, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	number_based,
+	"16#FF_0#",
+	spanned_token!(
+		Token::Number("16#FF_0#".as_bytes().into()),
+		Span::from_position(0, 8, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	number_based_real,
+	"2#1.1#e4",
+	spanned_token!(
+		Token::Number("2#1.1#e4".as_bytes().into()),
+		Span::from_position(0, 8, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	number_based_unterminated,
+	"16#FF ",
+	spanned_token!(
+		Token::Invalid(Some("16#FF".as_bytes().into())),
+		Span::from_position(0, 5, Position::new(0, 0))
+	),
+	spanned_token!(
+		Token::Whitespace(" ".as_bytes().into()),
+		Span::from_position(5, 6, Position::new(0, 5))
+	)
+);
+
+tokenizer_test!(
+	all,
+	number_physical,
+	"10 ns",
+	spanned_token!(
+		Token::Number("10".as_bytes().into()),
+		Span::from_position(0, 2, Position::new(0, 0))
+	),
+	spanned_token!(
+		Token::Whitespace(" ".as_bytes().into()),
+		Span::from_position(2, 3, Position::new(0, 2))
+	),
+	spanned_token!(
+		Token::Identifier("ns".as_bytes().into()),
+		Span::from_position(3, 5, Position::new(0, 3))
+	)
+);
+
+tokenizer_test!(
+	all,
+	bit_string,
+	"X\"F_F\"",
+	spanned_token!(
+		Token::BitString("X\"F_F\"".as_bytes().into()),
+		Span::from_position(0, 6, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	bit_string_binary,
+	"b\"1010\"",
+	spanned_token!(
+		Token::BitString("b\"1010\"".as_bytes().into()),
+		Span::from_position(0, 7, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	operator_ampersand,
+	"&",
+	spanned_token!(
+		Token::Operator(Operator::Ampersand),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	operator_asterisk,
+	"*",
+	spanned_token!(
+		Token::Operator(Operator::Asterisk),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	operator_power,
+	"**",
+	spanned_token!(
+		Token::Operator(Operator::Power),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	operator_plus,
+	"+",
+	spanned_token!(
+		Token::Operator(Operator::Plus),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	operator_minus,
+	"-",
+	spanned_token!(
+		Token::Operator(Operator::Minus),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	operator_solidus,
+	"/",
+	spanned_token!(
+		Token::Operator(Operator::Solidus),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	operator_inequality,
+	"/=",
+	spanned_token!(
+		Token::Operator(Operator::Inequality),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	operator_less_than,
+	"<",
+	spanned_token!(
+		Token::Operator(Operator::LessThan),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	operator_less_than_equal,
+	"<=",
+	spanned_token!(
+		Token::Operator(Operator::LessThanEqual),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	operator_greater_than,
+	">",
+	spanned_token!(
+		Token::Operator(Operator::GreaterThan),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	operator_greater_than_equal,
+	">=",
+	spanned_token!(
+		Token::Operator(Operator::GreaterThanEqual),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	operator_equals,
+	"=",
+	spanned_token!(
+		Token::Operator(Operator::Equals),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	control_arrow,
+	"=>",
+	spanned_token!(
+		Token::Control(Control::Arrow),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	control_box,
+	"<>",
+	spanned_token!(
+		Token::Control(Control::Box),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	control_variable_assignment,
+	":=",
+	spanned_token!(
+		Token::Control(Control::VariableAssignment),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	control_colon,
+	":",
+	spanned_token!(
+		Token::Control(Control::Colon),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	control_semicolon,
+	";",
+	spanned_token!(
+		Token::Control(Control::Semicolon),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	control_comma,
+	",",
+	spanned_token!(
+		Token::Control(Control::Comma),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	control_dot,
+	".",
+	spanned_token!(
+		Token::Control(Control::Dot),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	control_pipe,
+	"|",
+	spanned_token!(
+		Token::Control(Control::Pipe),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	control_paren_open,
+	"(",
+	spanned_token!(
+		Token::Control(Control::ParenOpen),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	control_paren_close,
+	")",
+	spanned_token!(
+		Token::Control(Control::ParenClose),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	all,
+	invalid_character,
+	"!",
+	spanned_token!(
+		Token::Invalid(Some("!".as_bytes().into())),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
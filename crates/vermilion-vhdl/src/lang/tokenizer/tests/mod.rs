@@ -1,18 +1,9 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
-// TODO(aki): Write tests
-#[allow(unused, reason = "Currently unused")]
 use paste::paste;
 
-// TODO(aki): Write tests
-#[allow(unused, reason = "Currently unused")]
-use crate::{
-	LanguageStd,
-	lang::tokenizer::{token::Keyword, *},
-};
+use crate::{LanguageStd, lang::tokenizer::*};
 
-// TODO(aki): Write tests
-#[allow(unused, reason = "Currently unused")]
 macro_rules! tokenizer_test {
 	(vhdl87, $test_name:ident, $input:literal, $($tokens:expr),+) => {
 		paste! { tokenizer_test!(
@@ -248,7 +239,7 @@ macro_rules! tokenizer_test {
 					clippy::unwrap_used,
 					reason = "We are within tests, and while this shouldn't explode, if it does here that's fine"
 				)]
-				let tokenizer = VhdlTokenizer::new($std, $input.as_bytes().into());
+				let tokenizer = VhdlTokenizer::new($std, $input.as_bytes().into()).unwrap();
 
 				let parsed = tokenizer.collect::<Vec<_>>();
 				let expected = vec![$($tokens),+];
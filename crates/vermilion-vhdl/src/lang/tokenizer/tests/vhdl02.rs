@@ -1 +1,33 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+tokenizer_test!(
+	vhdl02,
+	comment_block,
+	"/* a */",
+	spanned_token!(
+		Token::ContextuallyInvalid("/* a */".as_bytes().into(), VHDL_08_STDS),
+		Span::from_position(0, 7, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl02,
+	bit_string_sized,
+	"8UX\"F\"",
+	spanned_token!(
+		Token::ContextuallyInvalid("8UX\"F\"".as_bytes().into(), VHDL_08_STDS),
+		Span::from_position(0, 6, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl02,
+	operator_condition,
+	"??",
+	spanned_token!(
+		Token::ContextuallyInvalid("??".as_bytes().into(), VHDL_08_STDS),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
@@ -1 +1,177 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+tokenizer_test!(
+	vhdl08+,
+	comment_block,
+	"/* a\n b */x",
+	spanned_token!(
+		Token::Comment(Comment::MultiLine("/* a\n b */".as_bytes().into())),
+		Span::from_position(0, 10, Position::new(0, 0))
+	),
+	spanned_token!(
+		Token::Identifier("x".as_bytes().into()),
+		Span::from_position(10, 11, Position::new(1, 5))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	comment_block_unterminated,
+	"/* a",
+	spanned_token!(
+		Token::Comment(Comment::Invalid("/* a".as_bytes().into())),
+		Span::from_position(0, 4, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	bit_string_sized,
+	"12UX\"F0\"",
+	spanned_token!(
+		Token::BitString("12UX\"F0\"".as_bytes().into()),
+		Span::from_position(0, 8, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	bit_string_decimal,
+	"d\"15\"",
+	spanned_token!(
+		Token::BitString("d\"15\"".as_bytes().into()),
+		Span::from_position(0, 5, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	operator_condition,
+	"??",
+	spanned_token!(
+		Token::Operator(Operator::Condition),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	operator_matching_equals,
+	"?=",
+	spanned_token!(
+		Token::Operator(Operator::MatchingEquals),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	operator_matching_inequality,
+	"?/=",
+	spanned_token!(
+		Token::Operator(Operator::MatchingInequality),
+		Span::from_position(0, 3, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	operator_matching_less_than,
+	"?<",
+	spanned_token!(
+		Token::Operator(Operator::MatchingLessThan),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	operator_matching_less_than_equal,
+	"?<=",
+	spanned_token!(
+		Token::Operator(Operator::MatchingLessThanEqual),
+		Span::from_position(0, 3, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	operator_matching_greater_than,
+	"?>",
+	spanned_token!(
+		Token::Operator(Operator::MatchingGreaterThan),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	operator_matching_greater_than_equal,
+	"?>=",
+	spanned_token!(
+		Token::Operator(Operator::MatchingGreaterThanEqual),
+		Span::from_position(0, 3, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	control_question,
+	"?",
+	spanned_token!(
+		Token::Control(Control::Question),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	control_double_less_than,
+	"<<",
+	spanned_token!(
+		Token::Control(Control::DoubleLessThan),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	control_double_greater_than,
+	">>",
+	spanned_token!(
+		Token::Control(Control::DoubleGreaterThan),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	control_at,
+	"@",
+	spanned_token!(
+		Token::Control(Control::At),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08+,
+	control_circumflex,
+	"^",
+	spanned_token!(
+		Token::Control(Control::Circumflex),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl08,
+	tool_directive,
+	"`if",
+	spanned_token!(
+		Token::ContextuallyInvalid("`if".as_bytes().into(), VHDL_19_STDS),
+		Span::from_position(0, 3, Position::new(0, 0))
+	)
+);
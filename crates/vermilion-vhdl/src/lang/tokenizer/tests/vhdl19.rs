@@ -1 +1,35 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+tokenizer_test!(
+	vhdl19+,
+	tool_directive,
+	"`warning \"x\"",
+	spanned_token!(
+		Token::ToolDirective("warning".as_bytes().into()),
+		Span::from_position(0, 8, Position::new(0, 0))
+	),
+	spanned_token!(
+		Token::Whitespace(" ".as_bytes().into()),
+		Span::from_position(8, 9, Position::new(0, 8))
+	),
+	spanned_token!(
+		Token::String("x".as_bytes().into()),
+		Span::from_position(9, 12, Position::new(0, 9))
+	)
+);
+
+tokenizer_test!(
+	vhdl19+,
+	tool_directive_invalid,
+	"` ",
+	spanned_token!(
+		Token::Invalid(Some("`".as_bytes().into())),
+		Span::from_position(0, 1, Position::new(0, 0))
+	),
+	spanned_token!(
+		Token::Whitespace(" ".as_bytes().into()),
+		Span::from_position(1, 2, Position::new(0, 1))
+	)
+);
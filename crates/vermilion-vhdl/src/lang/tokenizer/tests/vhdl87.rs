@@ -1 +1,23 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+tokenizer_test!(
+	vhdl87,
+	extended_identifier,
+	"\\foo\\",
+	spanned_token!(
+		Token::ContextuallyInvalid("\\foo\\".as_bytes().into(), VHDL_93_STDS),
+		Span::from_position(0, 5, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl87,
+	control_bracket_open,
+	"[",
+	spanned_token!(
+		Token::ContextuallyInvalid("[".as_bytes().into(), VHDL_93_STDS),
+		Span::from_position(0, 1, Position::new(0, 0))
+	)
+);
@@ -1 +1,47 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+tokenizer_test!(
+	vhdl93+,
+	extended_identifier,
+	"\\a\\\\b c\\",
+	spanned_token!(
+		Token::ExtendedIdentifier("a\\\\b c".as_bytes().into()),
+		Span::from_position(0, 8, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl93+,
+	extended_identifier_unterminated,
+	"\\abc",
+	spanned_token!(
+		Token::Invalid(Some("\\abc".as_bytes().into())),
+		Span::from_position(0, 4, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl93+,
+	control_brackets,
+	"[]",
+	spanned_token!(
+		Token::Control(Control::BracketOpen),
+		Span::from_position(0, 1, Position::new(0, 0))
+	),
+	spanned_token!(
+		Token::Control(Control::BracketClose),
+		Span::from_position(1, 2, Position::new(0, 1))
+	)
+);
+
+tokenizer_test!(
+	vhdl93+,
+	keyword_xnor,
+	"XNOR",
+	spanned_token!(
+		Token::Keyword(Keyword::Xnor),
+		Span::from_position(0, 4, Position::new(0, 0))
+	)
+);
@@ -1 +1,13 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+tokenizer_test!(
+	vhdl_ams17+,
+	comment_block,
+	"/**/",
+	spanned_token!(
+		Token::Comment(Comment::MultiLine("/**/".as_bytes().into())),
+		Span::from_position(0, 4, Position::new(0, 0))
+	)
+);
@@ -1 +1,23 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+tokenizer_test!(
+	vhdl_ams_all,
+	operator_double_equals,
+	"==",
+	spanned_token!(
+		Token::Operator(Operator::DoubleEquals),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
+
+tokenizer_test!(
+	vhdl_ams_all,
+	keyword_quantity,
+	"quantity",
+	spanned_token!(
+		Token::Keyword(Keyword::Quantity),
+		Span::from_position(0, 8, Position::new(0, 0))
+	)
+);
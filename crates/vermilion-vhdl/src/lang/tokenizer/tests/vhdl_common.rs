@@ -1 +1,13 @@
 // SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+
+tokenizer_test!(
+	vhdl_all,
+	operator_double_equals,
+	"==",
+	spanned_token!(
+		Token::ContextuallyInvalid("==".as_bytes().into(), LanguageStd::VHDL_AMS_STDS),
+		Span::from_position(0, 2, Position::new(0, 0))
+	)
+);
@@ -8,17 +8,28 @@ use crate::LanguageStd;
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Token {
+	/// A bit string literal, including the optional length and base specifier
+	BitString(AtomicByteTendril),
+	/// The single graphic character between the apostrophes of a character literal
+	Character(AtomicByteTendril),
 	Comment(Comment),
 	/// Hold the VHDL variant for when this token would become valid
 	ContextuallyInvalid(AtomicByteTendril, LanguageStd),
 	Control(Control),
+	/// The contents between the reverse solidi of an extended identifier, with any doubled
+	/// reverse solidi left as-is
+	ExtendedIdentifier(AtomicByteTendril), // Added: IEEE 1076-1993
 	Identifier(AtomicByteTendril),
 	Invalid(Option<AtomicByteTendril>),
 	Keyword(Keyword),
 	Newline(AtomicByteTendril),
+	/// A decimal or based abstract literal
 	Number(AtomicByteTendril),
 	Operator(Operator),
+	/// The contents of a string literal, with any doubled quotation marks left as-is
 	String(AtomicByteTendril),
+	/// The name of a tool directive without the leading `` ` ``
+	ToolDirective(AtomicByteTendril), // Added: IEEE 1076-2019
 	Whitespace(AtomicByteTendril),
 }
 
@@ -29,8 +40,27 @@ pub enum Comment {
 	SingleLine(AtomicByteTendril),
 }
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub enum Control {}
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Control {
+	Apostrophe,
+	Arrow,
+	At, // Added: IEEE 1076-2008
+	Box,
+	BracketClose, // Added: IEEE 1076-1993
+	BracketOpen,  // Added: IEEE 1076-1993
+	Circumflex,   // Added: IEEE 1076-2008
+	Colon,
+	Comma,
+	Dot,
+	DoubleGreaterThan, // Added: IEEE 1076-2008
+	DoubleLessThan,    // Added: IEEE 1076-2008
+	ParenClose,
+	ParenOpen,
+	Pipe,
+	Question, // Added: IEEE 1076-2008
+	Semicolon,
+	VariableAssignment,
+}
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Keyword {
@@ -409,12 +439,91 @@ pub enum Keyword {
 	Zero, // Added: IEEE 1149.1-2013 (BSDL)
 }
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub enum Operator {}
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Operator {
+	Ampersand,
+	Asterisk,
+	Condition,    // Added: IEEE 1076-2008
+	DoubleEquals, // Added: IEEE 1076.1-1999
+	Equals,
+	GreaterThan,
+	GreaterThanEqual,
+	Inequality,
+	LessThan,
+	LessThanEqual,
+	MatchingEquals,           // Added: IEEE 1076-2008
+	MatchingGreaterThan,      // Added: IEEE 1076-2008
+	MatchingGreaterThanEqual, // Added: IEEE 1076-2008
+	MatchingInequality,       // Added: IEEE 1076-2008
+	MatchingLessThan,         // Added: IEEE 1076-2008
+	MatchingLessThanEqual,    // Added: IEEE 1076-2008
+	Minus,
+	Plus,
+	Power,
+	Solidus,
+}
+
+impl Control {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Apostrophe => "'",
+			Self::Arrow => "=>",
+			Self::At => "@", // Added: IEEE 1076-2008
+			Self::Box => "<>",
+			Self::BracketClose => "]", // Added: IEEE 1076-1993
+			Self::BracketOpen => "[",  // Added: IEEE 1076-1993
+			Self::Circumflex => "^",   // Added: IEEE 1076-2008
+			Self::Colon => ":",
+			Self::Comma => ",",
+			Self::Dot => ".",
+			Self::DoubleGreaterThan => ">>", // Added: IEEE 1076-2008
+			Self::DoubleLessThan => "<<",    // Added: IEEE 1076-2008
+			Self::ParenClose => ")",
+			Self::ParenOpen => "(",
+			Self::Pipe => "|",
+			Self::Question => "?", // Added: IEEE 1076-2008
+			Self::Semicolon => ";",
+			Self::VariableAssignment => ":=",
+		}
+	}
+}
+
+impl Operator {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Ampersand => "&",
+			Self::Asterisk => "*",
+			Self::Condition => "??",    // Added: IEEE 1076-2008
+			Self::DoubleEquals => "==", // Added: IEEE 1076.1-1999
+			Self::Equals => "=",
+			Self::GreaterThan => ">",
+			Self::GreaterThanEqual => ">=",
+			Self::Inequality => "/=",
+			Self::LessThan => "<",
+			Self::LessThanEqual => "<=",
+			Self::MatchingEquals => "?=",            // Added: IEEE 1076-2008
+			Self::MatchingGreaterThan => "?>",       // Added: IEEE 1076-2008
+			Self::MatchingGreaterThanEqual => "?>=", // Added: IEEE 1076-2008
+			Self::MatchingInequality => "?/=",       // Added: IEEE 1076-2008
+			Self::MatchingLessThan => "?<",          // Added: IEEE 1076-2008
+			Self::MatchingLessThanEqual => "?<=",    // Added: IEEE 1076-2008
+			Self::Minus => "-",
+			Self::Plus => "+",
+			Self::Power => "**",
+			Self::Solidus => "/",
+		}
+	}
+}
 
 impl Display for Token {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
+			Self::BitString(tendril) => write!(f, "BitString({})", unsafe {
+				str::from_utf8_unchecked(tendril)
+			}),
+			Self::Character(tendril) => write!(f, "Character('{}')", unsafe {
+				str::from_utf8_unchecked(tendril)
+			}),
 			Self::Comment(comment) => comment.fmt(f),
 			Self::ContextuallyInvalid(tendril, std) => {
 				write!(
@@ -425,10 +534,18 @@ impl Display for Token {
 				)
 			},
 			Self::Control(control) => control.fmt(f),
+			Self::ExtendedIdentifier(tendril) => write!(f, "ExtendedIdentifier(\\{}\\)", unsafe {
+				str::from_utf8_unchecked(tendril)
+			}),
 			Self::Identifier(tendril) => write!(f, "Identifier({})", unsafe {
 				str::from_utf8_unchecked(tendril)
 			}),
-			Self::Invalid(_tendril) => todo!(),
+			Self::Invalid(Some(tendril)) => write!(f, "Invalid(\"{}\")", unsafe {
+				str::from_utf8_unchecked(tendril)
+					.escape_default()
+					.collect::<String>()
+			}),
+			Self::Invalid(None) => write!(f, "Invalid"),
 			Self::Keyword(keyword) => keyword.fmt(f),
 			Self::Newline(tendril) => write!(f, "Newline({})", tendril.len()),
 			Self::Number(tendril) => write!(f, "Number({})", unsafe {
@@ -438,6 +555,9 @@ impl Display for Token {
 			Self::String(tendril) => write!(f, "String(\"{}\")", unsafe {
 				str::from_utf8_unchecked(tendril)
 			}),
+			Self::ToolDirective(tendril) => write!(f, "ToolDirective(`{}`)", unsafe {
+				str::from_utf8_unchecked(tendril)
+			}),
 			Self::Whitespace(tendril) => write!(f, "Whitespace({})", unsafe {
 				str::from_utf8_unchecked(tendril)
 			}),
@@ -447,405 +567,417 @@ impl Display for Token {
 
 impl Display for Comment {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(f, "Comment()")
+		match self {
+			Self::Invalid(tendril) => write!(f, "InvalidComment(\"{}\")", unsafe {
+				str::from_utf8_unchecked(tendril)
+			}),
+			Self::MultiLine(tendril) => write!(f, "MultiLineComment(\"{}\")", unsafe {
+				str::from_utf8_unchecked(tendril)
+			}),
+			Self::SingleLine(tendril) => write!(f, "SingleLineComment(\"-- {}\")", unsafe {
+				str::from_utf8_unchecked(tendril)
+			}),
+		}
 	}
 }
 
 impl Display for Control {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(f, "Control()")
+		write!(f, "Control({})", self.as_str())
+	}
+}
+
+impl Keyword {
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Abs => "abs",
+			Self::Access => "access",
+			Self::Across => "across", // Added: IEEE 1076.1-1999
+			Self::After => "after",
+			Self::Alias => "alias",
+			Self::All => "all",
+			Self::And => "and",
+			Self::Architecture => "architecture",
+			Self::Array => "array",
+			Self::Assert => "assert",
+			Self::Assume => "assume",                    // Added: IEEE 1076-2008
+			Self::AssumeGuarantee => "assume_guarantee", // Added: IEEE 1076-2008
+			Self::AtPins => "at_pins",                   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Attribute => "attribute",
+			Self::Bc0 => "bc_0",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc1 => "bc_1",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc2 => "bc_2",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc3 => "bc_3",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc4 => "bc_4",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc5 => "bc_5",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc6 => "bc_6",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc7 => "bc_7",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc8 => "bc_8",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc9 => "bc_9",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc10 => "bc_10", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc11 => "bc_11", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc12 => "bc_12", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc13 => "bc_13", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc14 => "bc_14", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc15 => "bc_15", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc16 => "bc_16", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc17 => "bc_17", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc18 => "bc_18", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc19 => "bc_19", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc20 => "bc_20", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc21 => "bc_21", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc22 => "bc_22", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc23 => "bc_23", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc24 => "bc_24", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc25 => "bc_25", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc26 => "bc_26", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc27 => "bc_27", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc28 => "bc_28", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc29 => "bc_29", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc30 => "bc_30", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc31 => "bc_31", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc32 => "bc_32", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc33 => "bc_33", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc34 => "bc_34", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc35 => "bc_35", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc36 => "bc_36", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc37 => "bc_37", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc38 => "bc_38", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc39 => "bc_39", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc40 => "bc_40", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc41 => "bc_41", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc42 => "bc_42", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc43 => "bc_43", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc44 => "bc_44", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc45 => "bc_45", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc46 => "bc_46", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc47 => "bc_47", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc48 => "bc_48", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc49 => "bc_49", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc50 => "bc_50", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc51 => "bc_51", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc52 => "bc_52", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc53 => "bc_53", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc54 => "bc_54", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc55 => "bc_55", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc56 => "bc_56", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc57 => "bc_57", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc58 => "bc_58", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc59 => "bc_59", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc60 => "bc_60", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc61 => "bc_61", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc62 => "bc_62", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc63 => "bc_63", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc64 => "bc_64", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc65 => "bc_65", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc66 => "bc_66", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc67 => "bc_67", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc68 => "bc_68", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc69 => "bc_69", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc70 => "bc_70", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc71 => "bc_71", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc72 => "bc_72", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc73 => "bc_73", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc74 => "bc_74", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc75 => "bc_75", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc76 => "bc_76", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc77 => "bc_77", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc78 => "bc_78", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc79 => "bc_79", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc80 => "bc_80", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc81 => "bc_81", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc82 => "bc_82", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc83 => "bc_83", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc84 => "bc_84", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc85 => "bc_85", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc86 => "bc_86", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc87 => "bc_87", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc88 => "bc_88", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc89 => "bc_89", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc90 => "bc_90", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc91 => "bc_91", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc92 => "bc_92", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc93 => "bc_93", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc94 => "bc_94", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc95 => "bc_95", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc96 => "bc_96", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc97 => "bc_97", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc98 => "bc_98", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Bc99 => "bc_99", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Begin => "begin",
+			Self::BiDir => "bidir",        // Added: IEEE 1149.1-2013 (BSDL)
+			Self::BiDirIn => "bidir_in",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::BiDirOut => "bidir_out", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Block => "block",
+			Self::Body => "body",
+			Self::Both => "both",                      // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Boundary => "boundary",              // Added: IEEE 1149.1-2013 (BSDL)
+			Self::BoundaryLength => "boundary_length", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::BoundaryRegister => "boundary_register", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::BoundarySegment => "boundary_segment", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Break => "break",                    // Added: IEEE 1076.1-1999
+			Self::BroadcastField => "broadcastfield",  // Added: IEEE 1149.1-2013 (BSDL)
+			Self::BroadcastValues => "broadcastvalues", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::BscanInst => "bscan_inst",           // Added: IEEE 1149.1-2013 (BSDL)
+			Self::BsdlExtension => "bsdl_extension",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Buffer => "buffer",
+			Self::Bus => "bus",
+			Self::Bypass => "bypass",     // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Cap => "cap",           // Added: IEEE 1149.1-2013 (BSDL)
+			Self::CapData => "cap_data",  // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Captures => "captures", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Case => "case",
+			Self::CelLData => "celL_data",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::CellInfo => "cell_info",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::ChReset => "ch_reset",     // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Clamp => "clamp",          // Added: IEEE 1149.1-2013 (BSDL)
+			Self::ClampHold => "clamp_hold", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::ClampRelease => "clamp_release", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Clock => "clock",          // Added: IEEE 1149.1-2013 (BSDL)
+			Self::ClockInfo => "clock_info", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::ClockLevel => "clock_level", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::CompliancePatterns => "compliance_patterns", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Component => "component",
+			Self::ComponentConformance => "component_conformance", // Added: IEEE
+			// 1149.1-2013 (BSDL)
+			Self::Configuration => "configuration",
+			Self::Constant => "constant",
+			Self::Context => "context",   // Added: IEEE 1076-2008
+			Self::Control => "control",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::ControlR => "controlr", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Cover => "cover",       // Added: IEEE 1076-2008
+			Self::Default => "default",   // Added: IEEE 1076-2008 & IEEE
+			// 1149.1-2013 (BSDL)
+			Self::DelayPo => "delaypo", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::DesignWarning => "design_warning", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::DeviceID => "device_id", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::DifferentialCurrent => "differential_current", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::DifferentialVoltage => "differential_voltage", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Disconnect => "disconnect",
+			Self::Domain => "domain", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::DomainExternal => "domain_external", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::DomCtrl => "domctrl", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::DomPor => "dompor", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::DownTo => "downto",
+			Self::Ecid => "ecid",         // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Ecidcode => "ecidcode", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Else => "else",
+			Self::Elsif => "elsif",
+			Self::End => "end",
+			Self::Entity => "entity",
+			Self::Exit => "exit",
+			Self::Expect0 => "expect0",        // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Expect1 => "expect1",        // Added: IEEE 1149.1-2013 (BSDL)
+			Self::ExpectData => "expect_data", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::ExTest => "ex_test",         // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Fairness => "fairness",      // Added: IEEE 1076-2008
+			Self::File => "file",
+			Self::For => "for",
+			Self::Force => "force", // Added: IEEE 1076-2008
+			Self::Function => "function",
+			Self::Generate => "generate",
+			Self::Generic => "generic",
+			Self::Group => "group", // Added: IEEE 1076-1993
+			Self::Guarded => "guarded",
+			Self::HierReset => "hierreset", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::HighZ => "highz",         // Added: IEEE 1149.1-2013 (BSDL)
+			Self::IcReset => "ic_reset",    // Added: IEEE 1149.1-2013 (BSDL)
+			Self::IdBits => "id_bits",      // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Idcode => "idcode",       // Added: IEEE 1149.1-2013 (BSDL)
+			Self::IdcodeRegister => "idcode_register", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::IdString => "id_string",  // Added: IEEE 1149.1-2013 (BSDL)
+			Self::If => "if",
+			Self::Impure => "impure", // Added: IEEE 1076-1993
+			Self::In => "in",
+			Self::Inertial => "inertial",               // Added: IEEE 1076-1993
+			Self::InitData => "init_data",              // Added: IEEE 1149.1-2013 (BSDL)
+			Self::InitRun => "init_run",                // Added: IEEE 1149.1-2013 (BSDL)
+			Self::InitSetup => "init_setup",            // Added: IEEE 1149.1-2013 (BSDL)
+			Self::InitSetupClamp => "Init_setup_clamp", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::InitStatus => "init_status",          // Added: IEEE 1149.1-2013 (BSDL)
+			Self::InOut => "inout",
+			Self::Input => "input", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::InstructionCapture => "instruction_capture", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::InstructionLength => "instruction_length", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::InstructionOpcode => "instruction_opcode", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::InstructionPrivate => "instruction_private", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Internal => "internal", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::InTest => "intest", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::InTestExecution => "intest_execution", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Is => "is",
+			Self::Keeper => "keeper", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Label => "label",
+			Self::Library => "library",
+			Self::Limit => "limit", // Added: IEEE 1076.1-1999
+			Self::Linkage => "linkage",
+			Self::LinkageBuffer => "linkage_buffer", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::LinkageIn => "linkage_in",         // Added: IEEE 1149.1-2013 (BSDL)
+			Self::LinkageInOut => "linkage_inout",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::LinkageMechanical => "linkage_mechanical", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::LinkageOut => "linkage_out",       // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Literal => "literal",              // Added: IEEE 1076-1993
+			Self::Loop => "loop",
+			Self::Low => "low", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Map => "map",
+			Self::Mod => "mod",
+			Self::Mon => "mon", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Nand => "nand",
+			Self::Nature => "nature", // Added: IEEE 1076.1-1999
+			Self::New => "new",
+			Self::Next => "next",
+			Self::Noise => "noise", // Added: IEEE 1076.1-1999
+			Self::Nopi => "nopi",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Nopo => "nopo",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Nor => "nor",
+			Self::NoRetain => "noretain", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Not => "not",
+			Self::NoUpd => "noupd", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Null => "null",
+			Self::ObserveOnly => "observe_only", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Observing => "observing",      // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Of => "of",
+			Self::On => "on",
+			Self::One => "one",        // Added: IEEE 1149.1-2013 (BSDL)
+			Self::OneHot => "one_hot", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Open => "open",      // Added: IEEE 1076-1987 & IEEE 1149.1-2013 (BSDL)
+			Self::Open0 => "open0",    // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Open1 => "open1",    // Added: IEEE 1149.1-2013 (BSDL)
+			Self::OpenX => "openX",    // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Or => "or",
+			Self::Others => "others",
+			Self::Out => "out",
+			Self::Output2 => "output2", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Output3 => "output3", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Package => "package",
+			Self::Parameter => "parameter", // Added: IEEE 1076-2008
+			Self::PhysicalPinMap => "physical_pin_map", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Pi => "pi",               // Added: IEEE 1149.1-2013 (BSDL)
+			Self::PinMap => "pin_map",      // Added: IEEE 1149.1-2013 (BSDL)
+			Self::PinMapString => "pin_map_string", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Po => "po",               // Added: IEEE 1149.1-2013 (BSDL)
+			Self::PorReset => "por_reset",  // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Port => "port",
+			Self::PortGrouping => "port_grouping", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Postponed => "postponed",        // Added: IEEE 1076-1993
+			Self::Power0 => "power0",              // Added: IEEE 1149.1-2013 (BSDL)
+			Self::PowerNeg => "power_neg",         // Added: IEEE 1149.1-2013 (BSDL)
+			Self::PowerPortAssociation => "power_port_association", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::PowerPos => "power_pos",         // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Preload => "preload",            // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Private => "private",            // Added: IEEE 1076-2019
+			Self::Procedural => "procedural",      // Added: IEEE 1076-2000 & IEEE 1076.1-1999
+			Self::Procedure => "procedure",
+			Self::Process => "process",
+			Self::Property => "property",   // Added: IEEE 1076-2008
+			Self::Protected => "protected", // Added: IEEE 1076-2000 & IEEE 1076.1-2007
+			Self::Pull0 => "pull0",         // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Pull1 => "pull1",         // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Pulse0 => "pulse0",       // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Pulse1 => "pulse1",       // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Pure => "pure",           // Added: IEEE 1076-1993
+			Self::Quantity => "quantity",   // Added: IEEE 1076.1-1999
+			Self::Range => "range",
+			Self::Record => "record",
+			Self::Reference => "reference", // Added: IEEE 1076-2000 & IEEE 1076.1-1999
+			Self::Register => "register",
+			Self::RegisterAccess => "register_access", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::RegisterAssembly => "register_assembly", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::RegisterAssociation => "register_association", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::RegisterConstraints => "register_constraints", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::RegisterFields => "register_fields", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::RegisterMnemonics => "register_mnemonics", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Reject => "reject",                  // Added: IEEE 1076-1993
+			Self::Release => "release",                // Added: IEEE 1076-2008
+			Self::Rem => "rem",
+			Self::Report => "report",
+			Self::ResetSelect => "reset_select", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::ResetVal => "resetval",        // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Restrict => "restrict",        // Added: IEEE 1076-2008
+			Self::RestrictGuarantee => "restrict_guarantee", // Added: IEEE 1076-2008
+			Self::Return => "return",
+			Self::Rol => "rol",                            // Added: IEEE 1076-1993
+			Self::Ror => "ror",                            // Added: IEEE 1076-1993
+			Self::RunBist => "runbist",                    // Added: IEEE 1149.1-2013 (BSDL)
+			Self::RunBistExecution => "runbist_execution", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Safe => "safe",                          // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Sample => "sample",                      // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Segment => "segment",                    // Added: IEEE 1149.1-2013 (BSDL)
+			Self::SegMux => "segmux",                      // Added: IEEE 1149.1-2013 (BSDL)
+			Self::SegStart => "segstart",                  // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Select => "select",
+			Self::SelectField => "selectfield", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::SelectMux => "selectmux",     // Added: IEEE 1149.1-2013 (BSDL)
+			Self::SelectValues => "selectvalues", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Sequence => "sequence",       // Added: IEEE 1076-2008
+			Self::Severity => "severity",
+			Self::Shared => "shared", // Added: IEEE 1076-1993 & IEEE 1149.1-2013 (BSDL)
+			Self::Signal => "signal",
+			Self::Sla => "sla",             // Added: IEEE 1076-1993
+			Self::Sll => "sll",             // Added: IEEE 1076-1993
+			Self::Spectrum => "spectrum",   // Added: IEEE 1076.1-1999
+			Self::Sra => "sra",             // Added: IEEE 1076-1993
+			Self::Srl => "srl",             // Added: IEEE 1076-1993
+			Self::Strong => "strong",       // Added: IEEE 1076-2008
+			Self::SubNature => "subnature", // Added: IEEE 1076.1-1999
+			Self::Subtype => "subtype",
+			Self::TapReset => "tapreset", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::TapScanClock => "tap_scan_clock", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::TapScanIn => "tap_scan_in", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::TapScanMode => "tap_scan_mode", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::TapScanOut => "tap_scan_out", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::TapScanReset => "tap_scan_reset", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Terminal => "terminal", // Added: IEEE 1076.1-1999
+			Self::Then => "then",
+			Self::Through => "through",      // Added: IEEE 1076.1-1999
+			Self::Tie0 => "tie0",            // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Tie1 => "tie1",            // Added: IEEE 1149.1-2013 (BSDL)
+			Self::TmpStatus => "tmp_status", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::To => "to",
+			Self::Tolerance => "tolerance", // Added: IEEE 1076.1-1999
+			Self::Transport => "transport",
+			Self::TrstReset => "trstreset", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Type => "type",
+			Self::Unaffected => "unaffected", // Added: IEEE 1076-1993
+			Self::Units => "units",
+			Self::Until => "until",
+			Self::Upd => "upd", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Use => "use",
+			Self::User => "user",         // Added: IEEE 1149.1-2013 (BSDL)
+			Self::UserCode => "usercode", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::UserCodeRegister => "usercode_register", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Variable => "variable",
+			Self::View => "view",        // Added: IEEE 1076-2019
+			Self::Vmode => "vmode",      // Added: IEEE 1076-2008
+			Self::Vpkg => "vpkg",        // Added: IEEE 1076-2019
+			Self::Vprop => "vprop",      // Added: IEEE 1076-2008
+			Self::VrefIn => "vref_in",   // Added: IEEE 1149.1-2013 (BSDL)
+			Self::VrefOut => "vref_out", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Vunit => "vunit",      // Added: IEEE 1076-2008
+			Self::Wait => "wait",
+			Self::WaitDuration => "wait_duration", // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Weak0 => "weak0",                // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Weak1 => "weak1",                // Added: IEEE 1149.1-2013 (BSDL)
+			Self::When => "when",
+			Self::While => "while",
+			Self::With => "with",
+			Self::X => "x",       // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Xnor => "xnor", // Added: IEEE 1076-1993
+			Self::Xor => "xor",
+			Self::Z => "z",       // Added: IEEE 1149.1-2013 (BSDL)
+			Self::Zero => "zero", // Added: IEEE 1149.1-2013 (BSDL)
+		}
 	}
 }
 
 impl Display for Keyword {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(
-			f,
-			"Keyword({})",
-			match self {
-				Self::Abs => "abs",
-				Self::Access => "access",
-				Self::Across => "across", // Added: IEEE 1076.1-1999
-				Self::After => "after",
-				Self::Alias => "alias",
-				Self::All => "all",
-				Self::And => "and",
-				Self::Architecture => "architecture",
-				Self::Array => "array",
-				Self::Assert => "assert",
-				Self::Assume => "assume",                    // Added: IEEE 1076-2008
-				Self::AssumeGuarantee => "assume_guarantee", // Added: IEEE 1076-2008
-				Self::AtPins => "at_pins",                   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Attribute => "attribute",
-				Self::Bc0 => "bc_0",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc1 => "bc_1",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc2 => "bc_2",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc3 => "bc_3",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc4 => "bc_4",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc5 => "bc_5",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc6 => "bc_6",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc7 => "bc_7",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc8 => "bc_8",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc9 => "bc_9",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc10 => "bc_10", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc11 => "bc_11", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc12 => "bc_12", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc13 => "bc_13", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc14 => "bc_14", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc15 => "bc_15", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc16 => "bc_16", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc17 => "bc_17", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc18 => "bc_18", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc19 => "bc_19", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc20 => "bc_20", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc21 => "bc_21", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc22 => "bc_22", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc23 => "bc_23", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc24 => "bc_24", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc25 => "bc_25", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc26 => "bc_26", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc27 => "bc_27", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc28 => "bc_28", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc29 => "bc_29", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc30 => "bc_30", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc31 => "bc_31", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc32 => "bc_32", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc33 => "bc_33", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc34 => "bc_34", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc35 => "bc_35", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc36 => "bc_36", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc37 => "bc_37", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc38 => "bc_38", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc39 => "bc_39", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc40 => "bc_40", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc41 => "bc_41", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc42 => "bc_42", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc43 => "bc_43", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc44 => "bc_44", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc45 => "bc_45", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc46 => "bc_46", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc47 => "bc_47", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc48 => "bc_48", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc49 => "bc_49", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc50 => "bc_50", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc51 => "bc_51", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc52 => "bc_52", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc53 => "bc_53", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc54 => "bc_54", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc55 => "bc_55", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc56 => "bc_56", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc57 => "bc_57", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc58 => "bc_58", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc59 => "bc_59", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc60 => "bc_60", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc61 => "bc_61", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc62 => "bc_62", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc63 => "bc_63", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc64 => "bc_64", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc65 => "bc_65", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc66 => "bc_66", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc67 => "bc_67", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc68 => "bc_68", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc69 => "bc_69", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc70 => "bc_70", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc71 => "bc_71", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc72 => "bc_72", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc73 => "bc_73", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc74 => "bc_74", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc75 => "bc_75", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc76 => "bc_76", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc77 => "bc_77", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc78 => "bc_78", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc79 => "bc_79", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc80 => "bc_80", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc81 => "bc_81", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc82 => "bc_82", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc83 => "bc_83", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc84 => "bc_84", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc85 => "bc_85", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc86 => "bc_86", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc87 => "bc_87", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc88 => "bc_88", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc89 => "bc_89", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc90 => "bc_90", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc91 => "bc_91", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc92 => "bc_92", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc93 => "bc_93", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc94 => "bc_94", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc95 => "bc_95", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc96 => "bc_96", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc97 => "bc_97", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc98 => "bc_98", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Bc99 => "bc_99", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Begin => "begin",
-				Self::BiDir => "bidir",        // Added: IEEE 1149.1-2013 (BSDL)
-				Self::BiDirIn => "bidir_in",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::BiDirOut => "bidir_out", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Block => "block",
-				Self::Body => "body",
-				Self::Both => "both",                      // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Boundary => "boundary",              // Added: IEEE 1149.1-2013 (BSDL)
-				Self::BoundaryLength => "boundary_length", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::BoundaryRegister => "boundary_register", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::BoundarySegment => "boundary_segment", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Break => "break",                    // Added: IEEE 1076.1-1999
-				Self::BroadcastField => "broadcastfield",  // Added: IEEE 1149.1-2013 (BSDL)
-				Self::BroadcastValues => "broadcastvalues", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::BscanInst => "bscan_inst",           // Added: IEEE 1149.1-2013 (BSDL)
-				Self::BsdlExtension => "bsdl_extension",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Buffer => "buffer",
-				Self::Bus => "bus",
-				Self::Bypass => "bypass",     // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Cap => "cap",           // Added: IEEE 1149.1-2013 (BSDL)
-				Self::CapData => "cap_data",  // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Captures => "captures", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Case => "case",
-				Self::CelLData => "celL_data",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::CellInfo => "cell_info",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::ChReset => "ch_reset",     // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Clamp => "clamp",          // Added: IEEE 1149.1-2013 (BSDL)
-				Self::ClampHold => "clamp_hold", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::ClampRelease => "clamp_release", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Clock => "clock",          // Added: IEEE 1149.1-2013 (BSDL)
-				Self::ClockInfo => "clock_info", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::ClockLevel => "clock_level", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::CompliancePatterns => "compliance_patterns", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Component => "component",
-				Self::ComponentConformance => "component_conformance", // Added: IEEE
-				// 1149.1-2013 (BSDL)
-				Self::Configuration => "configuration",
-				Self::Constant => "constant",
-				Self::Context => "context",   // Added: IEEE 1076-2008
-				Self::Control => "control",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::ControlR => "controlr", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Cover => "cover",       // Added: IEEE 1076-2008
-				Self::Default => "default",   // Added: IEEE 1076-2008 & IEEE
-				// 1149.1-2013 (BSDL)
-				Self::DelayPo => "delaypo", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::DesignWarning => "design_warning", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::DeviceID => "device_id", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::DifferentialCurrent => "differential_current", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::DifferentialVoltage => "differential_voltage", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Disconnect => "disconnect",
-				Self::Domain => "domain", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::DomainExternal => "domain_external", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::DomCtrl => "domctrl", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::DomPor => "dompor", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::DownTo => "downto",
-				Self::Ecid => "ecid",         // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Ecidcode => "ecidcode", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Else => "else",
-				Self::Elsif => "elsif",
-				Self::End => "end",
-				Self::Entity => "entity",
-				Self::Exit => "exit",
-				Self::Expect0 => "expect0",        // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Expect1 => "expect1",        // Added: IEEE 1149.1-2013 (BSDL)
-				Self::ExpectData => "expect_data", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::ExTest => "ex_test",         // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Fairness => "fairness",      // Added: IEEE 1076-2008
-				Self::File => "file",
-				Self::For => "for",
-				Self::Force => "force", // Added: IEEE 1076-2008
-				Self::Function => "function",
-				Self::Generate => "generate",
-				Self::Generic => "generic",
-				Self::Group => "group", // Added: IEEE 1076-1993
-				Self::Guarded => "guarded",
-				Self::HierReset => "hierreset", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::HighZ => "highz",         // Added: IEEE 1149.1-2013 (BSDL)
-				Self::IcReset => "ic_reset",    // Added: IEEE 1149.1-2013 (BSDL)
-				Self::IdBits => "id_bits",      // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Idcode => "idcode",       // Added: IEEE 1149.1-2013 (BSDL)
-				Self::IdcodeRegister => "idcode_register", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::IdString => "id_string",  // Added: IEEE 1149.1-2013 (BSDL)
-				Self::If => "if",
-				Self::Impure => "impure", // Added: IEEE 1076-1993
-				Self::In => "in",
-				Self::Inertial => "inertial",               // Added: IEEE 1076-1993
-				Self::InitData => "init_data",              // Added: IEEE 1149.1-2013 (BSDL)
-				Self::InitRun => "init_run",                // Added: IEEE 1149.1-2013 (BSDL)
-				Self::InitSetup => "init_setup",            // Added: IEEE 1149.1-2013 (BSDL)
-				Self::InitSetupClamp => "Init_setup_clamp", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::InitStatus => "init_status",          // Added: IEEE 1149.1-2013 (BSDL)
-				Self::InOut => "inout",
-				Self::Input => "input", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::InstructionCapture => "instruction_capture", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::InstructionLength => "instruction_length", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::InstructionOpcode => "instruction_opcode", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::InstructionPrivate => "instruction_private", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Internal => "internal", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::InTest => "intest", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::InTestExecution => "intest_execution", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Is => "is",
-				Self::Keeper => "keeper", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Label => "label",
-				Self::Library => "library",
-				Self::Limit => "limit", // Added: IEEE 1076.1-1999
-				Self::Linkage => "linkage",
-				Self::LinkageBuffer => "linkage_buffer", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::LinkageIn => "linkage_in",         // Added: IEEE 1149.1-2013 (BSDL)
-				Self::LinkageInOut => "linkage_inout",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::LinkageMechanical => "linkage_mechanical", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::LinkageOut => "linkage_out",       // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Literal => "literal",              // Added: IEEE 1076-1993
-				Self::Loop => "loop",
-				Self::Low => "low", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Map => "map",
-				Self::Mod => "mod",
-				Self::Mon => "mon", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Nand => "nand",
-				Self::Nature => "nature", // Added: IEEE 1076.1-1999
-				Self::New => "new",
-				Self::Next => "next",
-				Self::Noise => "noise", // Added: IEEE 1076.1-1999
-				Self::Nopi => "nopi",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Nopo => "nopo",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Nor => "nor",
-				Self::NoRetain => "noretain", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Not => "not",
-				Self::NoUpd => "noupd", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Null => "null",
-				Self::ObserveOnly => "observe_only", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Observing => "observing",      // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Of => "of",
-				Self::On => "on",
-				Self::One => "one",        // Added: IEEE 1149.1-2013 (BSDL)
-				Self::OneHot => "one_hot", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Open => "open",      // Added: IEEE 1076-1987 & IEEE 1149.1-2013 (BSDL)
-				Self::Open0 => "open0",    // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Open1 => "open1",    // Added: IEEE 1149.1-2013 (BSDL)
-				Self::OpenX => "openX",    // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Or => "or",
-				Self::Others => "others",
-				Self::Out => "out",
-				Self::Output2 => "output2", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Output3 => "output3", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Package => "package",
-				Self::Parameter => "parameter", // Added: IEEE 1076-2008
-				Self::PhysicalPinMap => "physical_pin_map", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Pi => "pi",               // Added: IEEE 1149.1-2013 (BSDL)
-				Self::PinMap => "pin_map",      // Added: IEEE 1149.1-2013 (BSDL)
-				Self::PinMapString => "pin_map_string", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Po => "po",               // Added: IEEE 1149.1-2013 (BSDL)
-				Self::PorReset => "por_reset",  // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Port => "port",
-				Self::PortGrouping => "port_grouping", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Postponed => "postponed",        // Added: IEEE 1076-1993
-				Self::Power0 => "power0",              // Added: IEEE 1149.1-2013 (BSDL)
-				Self::PowerNeg => "power_neg",         // Added: IEEE 1149.1-2013 (BSDL)
-				Self::PowerPortAssociation => "power_port_association", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::PowerPos => "power_pos",         // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Preload => "preload",            // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Private => "private",            // Added: IEEE 1076-2019
-				Self::Procedural => "procedural",      // Added: IEEE 1076-2000 & IEEE 1076.1-1999
-				Self::Procedure => "procedure",
-				Self::Process => "process",
-				Self::Property => "property",   // Added: IEEE 1076-2008
-				Self::Protected => "protected", // Added: IEEE 1076-2000 & IEEE 1076.1-2007
-				Self::Pull0 => "pull0",         // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Pull1 => "pull1",         // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Pulse0 => "pulse0",       // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Pulse1 => "pulse1",       // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Pure => "pure",           // Added: IEEE 1076-1993
-				Self::Quantity => "quantity",   // Added: IEEE 1076.1-1999
-				Self::Range => "range",
-				Self::Record => "record",
-				Self::Reference => "reference", // Added: IEEE 1076-2000 & IEEE 1076.1-1999
-				Self::Register => "register",
-				Self::RegisterAccess => "register_access", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::RegisterAssembly => "register_assembly", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::RegisterAssociation => "register_association", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::RegisterConstraints => "register_constraints", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::RegisterFields => "register_fields", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::RegisterMnemonics => "register_mnemonics", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Reject => "reject",                  // Added: IEEE 1076-1993
-				Self::Release => "release",                // Added: IEEE 1076-2008
-				Self::Rem => "rem",
-				Self::Report => "report",
-				Self::ResetSelect => "reset_select", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::ResetVal => "resetval",        // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Restrict => "restrict",        // Added: IEEE 1076-2008
-				Self::RestrictGuarantee => "restrict_guarantee", // Added: IEEE 1076-2008
-				Self::Return => "return",
-				Self::Rol => "rol",                            // Added: IEEE 1076-1993
-				Self::Ror => "ror",                            // Added: IEEE 1076-1993
-				Self::RunBist => "runbist",                    // Added: IEEE 1149.1-2013 (BSDL)
-				Self::RunBistExecution => "runbist_execution", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Safe => "safe",                          // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Sample => "sample",                      // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Segment => "segment",                    // Added: IEEE 1149.1-2013 (BSDL)
-				Self::SegMux => "segmux",                      // Added: IEEE 1149.1-2013 (BSDL)
-				Self::SegStart => "segstart",                  // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Select => "select",
-				Self::SelectField => "selectfield", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::SelectMux => "selectmux",     // Added: IEEE 1149.1-2013 (BSDL)
-				Self::SelectValues => "selectvalues", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Sequence => "sequence",       // Added: IEEE 1076-2008
-				Self::Severity => "severity",
-				Self::Shared => "shared", // Added: IEEE 1076-1993 & IEEE 1149.1-2013 (BSDL)
-				Self::Signal => "signal",
-				Self::Sla => "sla",             // Added: IEEE 1076-1993
-				Self::Sll => "sll",             // Added: IEEE 1076-1993
-				Self::Spectrum => "spectrum",   // Added: IEEE 1076.1-1999
-				Self::Sra => "sra",             // Added: IEEE 1076-1993
-				Self::Srl => "srl",             // Added: IEEE 1076-1993
-				Self::Strong => "strong",       // Added: IEEE 1076-2008
-				Self::SubNature => "subnature", // Added: IEEE 1076.1-1999
-				Self::Subtype => "subtype",
-				Self::TapReset => "tapreset", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::TapScanClock => "tap_scan_clock", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::TapScanIn => "tap_scan_in", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::TapScanMode => "tap_scan_mode", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::TapScanOut => "tap_scan_out", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::TapScanReset => "tap_scan_reset", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Terminal => "terminal", // Added: IEEE 1076.1-1999
-				Self::Then => "then",
-				Self::Through => "through",      // Added: IEEE 1076.1-1999
-				Self::Tie0 => "tie0",            // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Tie1 => "tie1",            // Added: IEEE 1149.1-2013 (BSDL)
-				Self::TmpStatus => "tmp_status", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::To => "to",
-				Self::Tolerance => "tolerance", // Added: IEEE 1076.1-1999
-				Self::Transport => "transport",
-				Self::TrstReset => "trstreset", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Type => "type",
-				Self::Unaffected => "unaffected", // Added: IEEE 1076-1993
-				Self::Units => "units",
-				Self::Until => "until",
-				Self::Upd => "upd", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Use => "use",
-				Self::User => "user",         // Added: IEEE 1149.1-2013 (BSDL)
-				Self::UserCode => "usercode", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::UserCodeRegister => "usercode_register", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Variable => "variable",
-				Self::View => "view",        // Added: IEEE 1076-2019
-				Self::Vmode => "vmode",      // Added: IEEE 1076-2008
-				Self::Vpkg => "vpkg",        // Added: IEEE 1076-2019
-				Self::Vprop => "vprop",      // Added: IEEE 1076-2008
-				Self::VrefIn => "vref_in",   // Added: IEEE 1149.1-2013 (BSDL)
-				Self::VrefOut => "vref_out", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Vunit => "vunit",      // Added: IEEE 1076-2008
-				Self::Wait => "wait",
-				Self::WaitDuration => "wait_duration", // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Weak0 => "weak0",                // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Weak1 => "weak1",                // Added: IEEE 1149.1-2013 (BSDL)
-				Self::When => "when",
-				Self::While => "while",
-				Self::With => "with",
-				Self::X => "x",       // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Xnor => "xnor", // Added: IEEE 1076-1993
-				Self::Xor => "xor",
-				Self::Z => "z",       // Added: IEEE 1149.1-2013 (BSDL)
-				Self::Zero => "zero", // Added: IEEE 1149.1-2013 (BSDL)
-			}
-		)
+		write!(f, "Keyword({})", self.as_str())
 	}
 }
 
 impl Display for Operator {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(f, "Operator()")
+		write!(f, "Operator({})", self.as_str())
 	}
 }
 
@@ -1,5 +1,10 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
+use std::{
+	collections::BTreeMap,
+	path::{Path, PathBuf},
+};
+
 use vermilion_macros::cfg_schema;
 
 use crate::{
@@ -17,11 +22,14 @@ use crate::{
 )]
 pub struct VhdlWorkspace {
 	/// Specific VHDL standard to use
-	pub std:  LanguageStd,
+	pub std:       LanguageStd,
 	/// VHDL formatting options
-	pub fmt:  Option<VhdlFormat>,
+	pub fmt:       Option<VhdlFormat>,
 	/// VHDL lint options
-	pub lint: Option<VhdlLint>,
+	pub lint:      Option<VhdlLint>,
+	/// Design libraries and the sources analyzed into them, keyed by the library logical name
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "BTreeMap::is_empty"))]
+	pub libraries: BTreeMap<String, VhdlLibrary>,
 }
 
 /// Workspace specific VHDL-AMS options
@@ -33,19 +41,83 @@ pub struct VhdlWorkspace {
 )]
 pub struct VhdlAmsWorkspace {
 	/// Specific VHDL-AMS standard to use
-	pub std:  LanguageStd,
+	pub std:       LanguageStd,
 	/// VHDL-AMS formatting options
-	pub fmt:  Option<VhdlAmsFormat>,
+	pub fmt:       Option<VhdlAmsFormat>,
 	/// VHDL-AMS lint options
-	pub lint: Option<VhdlAmsLint>,
+	pub lint:      Option<VhdlAmsLint>,
+	/// Design libraries and the sources analyzed into them, keyed by the library logical name
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "BTreeMap::is_empty"))]
+	pub libraries: BTreeMap<String, VhdlLibrary>,
+}
+
+/// The sources of a design library
+///
+/// Any file that is not part of a configured library is analyzed into `work`.
+///
+/// IEEE 1076-2008 § 13.2
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(
+	feature = "serde",
+	derive(::serde::Serialize, ::serde::Deserialize),
+	serde(deny_unknown_fields)
+)]
+pub struct VhdlLibrary {
+	/// Directories whose VHDL files, including those in subdirectories, are analyzed into the
+	/// library, relative to the workspace root
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+	pub dirs:  Vec<PathBuf>,
+	/// Individual files analyzed into the library, relative to the workspace root
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+	pub files: Vec<PathBuf>,
+}
+
+impl VhdlLibrary {
+	/// Returns true if the file at `path`, relative to the workspace root, is part of this library
+	pub fn contains(&self, path: &Path) -> bool {
+		self.files.iter().any(|file| file == path) ||
+			self.dirs.iter().any(|dir| path.starts_with(dir))
+	}
+}
+
+impl VhdlWorkspace {
+	/// The logical name of the library the file at `path`, relative to the workspace root, is
+	/// analyzed into
+	pub fn library_for(&self, path: &Path) -> &str {
+		library_for(&self.libraries, path)
+	}
+
+	/// All of the source files of the library `name`, in the order they are listed
+	pub fn library_files(&self, root: &Path, name: &str) -> Vec<PathBuf> {
+		library_files(&self.libraries, root, name, &LanguageStd::VHDL_KNOWN_EXTS)
+	}
+}
+
+impl VhdlAmsWorkspace {
+	/// The logical name of the library the file at `path`, relative to the workspace root, is
+	/// analyzed into
+	pub fn library_for(&self, path: &Path) -> &str {
+		library_for(&self.libraries, path)
+	}
+
+	/// All of the source files of the library `name`, in the order they are listed
+	pub fn library_files(&self, root: &Path, name: &str) -> Vec<PathBuf> {
+		library_files(
+			&self.libraries,
+			root,
+			name,
+			&LanguageStd::VHDL_AMS_KNOWN_EXTS,
+		)
+	}
 }
 
 impl Default for VhdlWorkspace {
 	fn default() -> Self {
 		Self {
-			std:  LanguageStd::Vh87,
-			fmt:  Default::default(),
-			lint: Default::default(),
+			std:       LanguageStd::Vh87,
+			fmt:       Default::default(),
+			lint:      Default::default(),
+			libraries: Default::default(),
 		}
 	}
 }
@@ -53,14 +125,106 @@ impl Default for VhdlWorkspace {
 impl Default for VhdlAmsWorkspace {
 	fn default() -> Self {
 		Self {
-			std:  LanguageStd::Vhams99,
-			fmt:  Default::default(),
-			lint: Default::default(),
+			std:       LanguageStd::Vhams99,
+			fmt:       Default::default(),
+			lint:      Default::default(),
+			libraries: Default::default(),
+		}
+	}
+}
+
+/// Explicitly listed files take precedence over directories, and the first matching library wins
+fn library_for<'a>(libraries: &'a BTreeMap<String, VhdlLibrary>, path: &Path) -> &'a str {
+	libraries
+		.iter()
+		.find(|(_, library)| library.files.iter().any(|file| file == path))
+		.or_else(|| libraries.iter().find(|(_, library)| library.contains(path)))
+		.map_or("work", |(name, _)| name.as_str())
+}
+
+/// Library names are case insensitive, the listed files come first, followed by the files found in
+/// each directory, which are sorted so the order does not depend on the filesystem
+fn library_files(
+	libraries: &BTreeMap<String, VhdlLibrary>,
+	root: &Path,
+	name: &str,
+	extensions: &[&str],
+) -> Vec<PathBuf> {
+	let Some(library) = libraries
+		.iter()
+		.find(|(library, _)| library.eq_ignore_ascii_case(name))
+		.map(|(_, library)| library)
+	else {
+		return Vec::new();
+	};
+
+	let mut files = library
+		.files
+		.iter()
+		.map(|file| root.join(file))
+		.collect::<Vec<_>>();
+
+	for dir in &library.dirs {
+		let mut found = Vec::new();
+		collect_sources(&root.join(dir), extensions, &mut found);
+		found.sort();
+
+		for file in found {
+			if !files.contains(&file) {
+				files.push(file);
+			}
+		}
+	}
+
+	files
+}
+
+/// Recursively collect the files in `dir` with one of the given extensions
+fn collect_sources(dir: &Path, extensions: &[&str], files: &mut Vec<PathBuf>) {
+	let Ok(entries) = std::fs::read_dir(dir) else {
+		return;
+	};
+
+	for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+		if path.is_dir() {
+			collect_sources(&path, extensions, files);
+		} else if path
+			.extension()
+			.and_then(|extension| extension.to_str())
+			.is_some_and(|extension| {
+				extensions
+					.iter()
+					.any(|known| known.eq_ignore_ascii_case(extension))
+			}) {
+			files.push(path);
 		}
 	}
 }
 
 cfg_schema! {
+	/// The schema for the design library mapping
+	fn libraries_schema() -> schemars::Schema {
+		schemars::json_schema!({
+			"description": "Design libraries and the sources analyzed into them",
+			"type": "object",
+			"additionalProperties": {
+				"type": "object",
+				"properties": {
+					"dirs": {
+						"description": "Directories whose VHDL files are analyzed into the library",
+						"type": "array",
+						"items": { "type": "string" }
+					},
+					"files": {
+						"description": "Individual files analyzed into the library",
+						"type": "array",
+						"items": { "type": "string" }
+					},
+				}
+			}
+		})
+	}
+
 	#[cfg_attr(coverage_nightly, coverage(off))]
 	impl schemars::JsonSchema for VhdlWorkspace {
 		fn schema_name() -> std::borrow::Cow<'static, str> {
@@ -97,6 +261,7 @@ cfg_schema! {
 					"std": std_schema,
 					"fmt": fmt_schema,
 					"lint": lint_schema,
+					"libraries": libraries_schema(),
 				}
 			})
 		}
@@ -134,8 +299,67 @@ cfg_schema! {
 					"std": std_schema,
 					"fmt": fmt_schema,
 					"lint": lint_schema,
+					"libraries": libraries_schema(),
 				}
 			})
 		}
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn workspace() -> VhdlWorkspace {
+		VhdlWorkspace {
+			std: LanguageStd::Vh08,
+			libraries: BTreeMap::from([
+				(
+					"lib_a".to_string(),
+					VhdlLibrary {
+						dirs:  vec![PathBuf::from("ip/a")],
+						files: vec![PathBuf::from("shared/b_pkg.vhd")],
+					},
+				),
+				(
+					"lib_b".to_string(),
+					VhdlLibrary {
+						dirs:  vec![PathBuf::from("ip")],
+						files: vec![PathBuf::from("ip/a/b_only.vhd")],
+					},
+				),
+			]),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn test_library_for() {
+		let workspace = workspace();
+
+		assert_eq!(workspace.library_for(Path::new("rtl/top.vhd")), "work");
+		assert_eq!(workspace.library_for(Path::new("ip/a/core.vhd")), "lib_a");
+		assert_eq!(
+			workspace.library_for(Path::new("ip/a/nested/core.vhd")),
+			"lib_a"
+		);
+		assert_eq!(workspace.library_for(Path::new("ip/c/core.vhd")), "lib_b");
+		assert_eq!(
+			workspace.library_for(Path::new("shared/b_pkg.vhd")),
+			"lib_a"
+		);
+		assert_eq!(workspace.library_for(Path::new("ip/a/b_only.vhd")), "lib_b");
+	}
+
+	#[test]
+	fn test_library_files() {
+		let workspace = workspace();
+		let root = Path::new("/nonexistent");
+
+		assert_eq!(
+			workspace.library_files(root, "LIB_A"),
+			vec![root.join("shared/b_pkg.vhd")]
+		);
+		assert!(workspace.library_files(root, "work").is_empty());
+	}
+}
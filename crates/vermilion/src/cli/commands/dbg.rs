@@ -2,8 +2,10 @@
 
 use std::{fs::File, io::Read};
 
-use clap::{Arg, ArgAction, ArgMatches, Command};
+use clap::{Arg, ArgAction, ArgMatches, Command, ValueHint};
 use eyre::eyre;
+use vermilion_diagnostics::Diagnostic;
+use vermilion_vhdl::LanguageStd as VhdlStd;
 
 use crate::lang::Language;
 
@@ -31,6 +33,25 @@ fn subcommands() -> Vec<Command> {
 					.value_delimiter(',')
 					.required(true),
 			),
+		Command::new("dump-preprocessed")
+			.about("Dump the preprocessed source of the given file to stdout")
+			.arg(
+				Arg::new("include-dir")
+					.short('I')
+					.long("include-dir")
+					.help("Add a directory to the `include search path")
+					.action(ArgAction::Append)
+					.value_hint(ValueHint::DirPath)
+					.value_name("DIR"),
+			)
+			.arg(
+				Arg::new("files")
+					.help("HDL files")
+					.action(ArgAction::Append)
+					.value_name("FILE")
+					.value_delimiter(',')
+					.required(true),
+			),
 	]
 }
 
@@ -46,6 +67,7 @@ pub(crate) fn exec(cmd: &mut Command, args: &ArgMatches) -> eyre::Result<()> {
 			Some((cmd, cmd_args)) => match cmd {
 				"dump-ast" => dump_ast(cmd_args, lang),
 				"dump-tokens" => dump_tokens(cmd_args, lang),
+				"dump-preprocessed" => dump_preprocessed(args, cmd_args, lang),
 				_ => unreachable!(),
 			},
 			_ => Err(eyre!("No subcommand")),
@@ -60,8 +82,6 @@ pub(crate) fn exec(cmd: &mut Command, args: &ArgMatches) -> eyre::Result<()> {
 }
 
 fn dump_ast(args: &ArgMatches, language: Language) -> eyre::Result<()> {
-	fn dump_parser<A>(_res: eyre::Result<A>) {}
-
 	if let Some(files) = args.try_get_many::<String>("files")? {
 		for file in files {
 			let mut data = Vec::new();
@@ -69,8 +89,13 @@ fn dump_ast(args: &ArgMatches, language: Language) -> eyre::Result<()> {
 			hdl_file.read_to_end(&mut data)?;
 
 			match language.parser(data.as_slice().into()) {
-				Ok(crate::lang::Parser::Verilog(parser)) => dump_parser(parser.parse()),
-				Ok(crate::lang::Parser::Vhdl(_parser)) => unimplemented!(),
+				Ok(crate::lang::Parser::Verilog(parser)) => println!("{:#?}", parser.parse()),
+				Ok(crate::lang::Parser::Vhdl(parser))
+					if language == Language::Vhdl(VhdlStd::Bsdl) =>
+				{
+					println!("{:#?}", parser.parse_bsdl());
+				},
+				Ok(crate::lang::Parser::Vhdl(parser)) => println!("{:#?}", parser.parse()),
 				Err(err) => {
 					return Err(err);
 				},
@@ -109,3 +134,48 @@ fn dump_tokens(args: &ArgMatches, language: Language) -> eyre::Result<()> {
 
 	Ok(())
 }
+
+fn dump_preprocessed(
+	common_args: &ArgMatches,
+	args: &ArgMatches,
+	language: Language,
+) -> eyre::Result<()> {
+	let Language::Verilog(std) = language else {
+		return Err(eyre!("Only Verilog has a preprocessor"));
+	};
+
+	let mut sources = crate::cli::sources(common_args, None)?;
+	if let Some(dirs) = args.try_get_many::<String>("include-dir")? {
+		sources
+			.command_file
+			.include_dirs
+			.extend(dirs.map(Into::into));
+	}
+
+	if let Some(files) = args.try_get_many::<String>("files")? {
+		for file in files {
+			let mut preprocessor = sources.verilog_preprocessor(std);
+
+			for token in preprocessor.preprocess_file(file)? {
+				print!("{}", String::from_utf8_lossy(token.text()));
+			}
+
+			for diagnostic in preprocessor.diagnostics() {
+				eprintln!(
+					"{}:{}: {}",
+					preprocessor
+						.file(*diagnostic.file_id())
+						.map_or_else(|| file.into(), |source| source.path().display().to_string()),
+					diagnostic.line() + 1,
+					diagnostic.message()
+				);
+
+				for note in preprocessor.expansion_notes(diagnostic) {
+					eprintln!("  note: {note}");
+				}
+			}
+		}
+	}
+
+	Ok(())
+}
@@ -12,7 +12,8 @@ pub(crate) fn init() -> eyre::Result<Command> {
 }
 
 pub(crate) fn exec(_: &mut Command, args: &ArgMatches) -> eyre::Result<()> {
-	let _workspace_config = crate::workspace::Workspace::load(args)?;
+	let workspace_config = crate::workspace::Workspace::load(args)?;
+	let _sources = crate::cli::sources(args, workspace_config)?;
 	let _lang = args
 		.try_get_one::<Language>("lang-std")?
 		.cloned()
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::{fs, io::Write};
+
+use clap::{Arg, ArgAction, ArgMatches, Command, ValueHint, builder::PossibleValuesParser};
+use eyre::{OptionExt, eyre};
+
+use crate::{hierarchy::Design, lang::Language};
+
+pub(crate) const COMMAND_NAME: &str = "hierarchy";
+
+pub(crate) fn init() -> eyre::Result<Command> {
+	Ok(Command::new(COMMAND_NAME)
+		.about("Print the elaborated instance hierarchy of a design")
+		.long_about(
+			"Print the elaborated instance hierarchy of a design\n\nThe design is elaborated from \
+			 each of the given top modules, or from every module that is not instantiated \
+			 anywhere if none are given.",
+		)
+		.arg(
+			Arg::new("top")
+				.short('t')
+				.long("top")
+				.help("The top module to elaborate the design from")
+				.action(ArgAction::Append)
+				.value_name("MODULE"),
+		)
+		.arg(
+			Arg::new("format")
+				.long("format")
+				.help("The format to print the hierarchy in")
+				.value_parser(PossibleValuesParser::new(["tree", "json", "dot"]))
+				.default_value("tree")
+				.value_name("FORMAT"),
+		)
+		.arg(
+			Arg::new("output")
+				.long("output")
+				.short('o')
+				.help("The file to write the hierarchy to")
+				.action(ArgAction::Set)
+				.value_hint(ValueHint::FilePath),
+		))
+}
+
+pub(crate) fn exec(_: &mut Command, args: &ArgMatches) -> eyre::Result<()> {
+	let workspace_config = crate::workspace::Workspace::load(args)?;
+	let sources = crate::cli::sources(args, workspace_config)?;
+	let lang = args
+		.try_get_one::<Language>("lang-std")?
+		.cloned()
+		.ok_or_eyre("Language standard not specified")?;
+
+	let Language::Verilog(std) = lang else {
+		return Err(eyre!("Only Verilog based languages can be elaborated"));
+	};
+
+	let tops = args
+		.try_get_many::<String>("top")?
+		.map(|tops| tops.cloned().collect::<Vec<_>>())
+		.unwrap_or_default();
+
+	let mut design = Design::load(&sources, std)?;
+	let hierarchy = design.hierarchy(&tops)?;
+
+	for diagnostic in &hierarchy.diagnostics {
+		eprintln!("{diagnostic}");
+	}
+
+	let output = match args.try_get_one::<String>("format")?.map(String::as_str) {
+		Some("json") => hierarchy.to_json()?,
+		Some("dot") => hierarchy.to_dot(),
+		_ => hierarchy.to_tree(),
+	};
+
+	if let Some(file_path) = args.try_get_one::<String>("output")? {
+		let mut file = fs::File::create(file_path)?;
+		file.write_all(output.as_bytes())?;
+	} else {
+		print!("{output}");
+	}
+
+	Ok(())
+}
@@ -5,6 +5,7 @@ pub(crate) mod dbg;
 pub(crate) mod dump;
 pub(crate) mod explain;
 pub(crate) mod fmt;
+pub(crate) mod hierarchy;
 pub(crate) mod init;
 pub(crate) mod lint;
 pub(crate) mod lsp;
@@ -5,7 +5,13 @@ use clap::{
 };
 use color_print::{cformat, cstr};
 
-use crate::{env::VERMILION_LOG_LEVEL, lang::Language, paths};
+use crate::{
+	command_file::CommandFile,
+	env::VERMILION_LOG_LEVEL,
+	lang::Language,
+	paths,
+	workspace::{Sources, Workspace},
+};
 
 mod commands;
 
@@ -129,6 +135,7 @@ fn init_commands() -> eyre::Result<Vec<Command>> {
 		commands::explain::init()?,
 		commands::init::init()?,
 		lang_common(commands::fmt::init()?, true),
+		lang_common(commands::hierarchy::init()?, true),
 		lang_common(commands::lint::init()?, true),
 		commands::lsp::init()?,
 	])
@@ -141,6 +148,7 @@ pub(crate) fn exec_command(command: &str) -> Option<CmdExec> {
 		commands::dump::COMMAND_NAME => Some(commands::dump::exec),
 		commands::explain::COMMAND_NAME => Some(commands::explain::exec),
 		commands::fmt::COMMAND_NAME => Some(commands::fmt::exec),
+		commands::hierarchy::COMMAND_NAME => Some(commands::hierarchy::exec),
 		commands::init::COMMAND_NAME => Some(commands::init::exec),
 		commands::lint::COMMAND_NAME => Some(commands::lint::exec),
 		commands::lsp::COMMAND_NAME => Some(commands::lsp::exec),
@@ -172,6 +180,21 @@ fn lang_common(command: Command, with_files: bool) -> Command {
 				.help("Undefine the provided compiler macro")
 				.action(ArgAction::Append)
 				.value_name("NAME"),
+		)
+		.arg(
+			Arg::new("command-file")
+				.short('f')
+				.long("command-file")
+				.help("Read sources, include directories, and macros from a command file")
+				.long_help(cstr!(
+					"Read sources, include directories, and macros from a command file\n\nCommand \
+					 files are the <cyan>*.f</> files used by simulators, holding source files \
+					 along with <blue>+incdir+</>, <blue>+define+</>, <blue>+libext+</>, \
+					 <blue>-v</>, <blue>-y</>, and nested <blue>-f</> options."
+				))
+				.action(ArgAction::Append)
+				.value_hint(ValueHint::FilePath)
+				.value_name("FILE"),
 		);
 
 	if with_files {
@@ -182,13 +205,53 @@ fn lang_common(command: Command, with_files: bool) -> Command {
 				.value_name("FILE")
 				.value_delimiter(',')
 				.value_hint(ValueHint::FilePath)
-				.required(true),
+				.required_unless_present("command-file"),
 		)
 	} else {
 		cmd
 	}
 }
 
+/// Collect the sources to work on from the workspace settings and command files, any command files
+/// given with `-f`, and the files given on the command line, along with the macros given with `-D`
+/// and `-U`
+pub(crate) fn sources(args: &ArgMatches, workspace: Option<Workspace>) -> eyre::Result<Sources> {
+	let mut sources = Sources::load(workspace)?;
+	let command_file = &mut sources.command_file;
+
+	// NOTE(aki): Command files given on the command line are relative to where we were run from
+	if let Some(command_files) = args.try_get_many::<String>("command-file")? {
+		let current_dir = std::env::current_dir()?;
+		for path in command_files {
+			command_file.extend(CommandFile::load(path.as_ref(), &current_dir)?);
+		}
+	}
+
+	// NOTE(aki): Not every command takes files directly, such as `dbg`
+	if let Ok(Some(files)) = args.try_get_many::<String>("files") {
+		command_file.files.extend(files.map(Into::into));
+	}
+
+	if let Some(defines) = args.try_get_many::<String>("define")? {
+		command_file
+			.defines
+			.extend(defines.map(|define| match define.split_once('=') {
+				Some((name, value)) => (name.to_string(), value.to_string()),
+				None => (define.clone(), String::new()),
+			}));
+	}
+
+	// Macros undefined on the command line win over those defined anywhere else
+	if let Some(undefines) = args.try_get_many::<String>("undefine")? {
+		for undefine in undefines {
+			command_file.defines.retain(|(name, _)| name != undefine);
+			command_file.undefines.push(undefine.clone());
+		}
+	}
+
+	Ok(sources)
+}
+
 /// Generate a shell completions file for the given shell
 pub(crate) fn dump_completions(cmd: &Command, shell: clap_complete::Shell) -> eyre::Result<String> {
 	let mut cli = cmd.clone();
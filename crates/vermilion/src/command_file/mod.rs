@@ -0,0 +1,369 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Simulator style command files
+//!
+//! Most EDA flows describe a compilation with a command file, usually called `*.f`, which holds
+//! the command line arguments to give the simulator or synthesis tool. We understand the subset of
+//! those arguments that describe the sources of the design:
+//!
+//! * Source files, given as plain paths
+//! * `+incdir+<dir>[+<dir>...]` - Directories to search for `` `include `` files in
+//! * `+define+<name>[=<value>][+<name>[=<value>]...]` - Macros to predefine
+//! * `+libext+<ext>[+<ext>...]` - Extensions of the files in library directories
+//! * `-v <file>` - A library file
+//! * `-y <dir>` - A library directory
+//! * `-f <file>` - Another command file, with paths relative to the base directory
+//! * `-F <file>` - Another command file, with paths relative to that file
+//!
+//! Arguments are separated by whitespace and may be quoted, `//`, `#`, and `/* */` comments are
+//! skipped, and environment variables in the form of `$VAR`, `${VAR}`, or `$(VAR)` are expanded.
+//! Any other options are ignored, along with the value following the ones known to take one, such
+//! as `-timescale 1ns/1ps` or `-top tb`.
+
+use std::{
+	fs,
+	path::{Path, PathBuf},
+};
+
+use eyre::eyre;
+use tracing::warn;
+use vermilion_verilog::lang::preprocessor::Preprocessor;
+
+/// Options of the common simulators and synthesis tools that take a separate value, which is
+/// skipped along with the option rather than being taken as a source file
+const VALUE_OPTIONS: &[&str] = &[
+	"-timescale",
+	"-top",
+	"-l",
+	"-L",
+	"-log",
+	"-o",
+	"-s",
+	"-work",
+	"-lib",
+	"-reflib",
+	"-makelib",
+	"-outdir",
+	"-Mdir",
+	"--Mdir",
+	"--top-module",
+	"--prefix",
+	"-sv_lib",
+	"-sv_liblist",
+	"-sv_root",
+	"-load",
+	"-pli",
+	"-default_nettype",
+	"-suppress",
+	"-error",
+	"-warning",
+	"-note",
+];
+
+/// The sources of a design as described by one or more command files
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct CommandFile {
+	/// Source files, in the order they were given
+	pub files:              Vec<PathBuf>,
+	/// Library files, given with `-v`
+	pub library_files:      Vec<PathBuf>,
+	/// Library directories, given with `-y`
+	pub library_dirs:       Vec<PathBuf>,
+	/// Library file extensions, given with `+libext+`
+	pub library_extensions: Vec<String>,
+	/// Include directories, given with `+incdir+`
+	pub include_dirs:       Vec<PathBuf>,
+	/// Predefined macros along with their values, given with `+define+`
+	pub defines:            Vec<(String, String)>,
+	/// Macros to leave undefined even if they are predefined elsewhere, such as in the workspace
+	/// settings
+	pub undefines:          Vec<String>,
+}
+
+impl CommandFile {
+	/// Read the command file at `path` along with any command files it references, `path` and the
+	/// relative paths in it are relative to `base`, such as the workspace root
+	pub fn load(path: &Path, base: &Path) -> eyre::Result<Self> {
+		let mut command_file = Self::default();
+		command_file.read(path, base, &mut Vec::new())?;
+
+		Ok(command_file)
+	}
+
+	/// Add everything from `other` after the contents of this command file
+	pub fn extend(&mut self, other: Self) {
+		self.files.extend(other.files);
+		self.library_files.extend(other.library_files);
+		self.library_dirs.extend(other.library_dirs);
+		self.library_extensions.extend(other.library_extensions);
+		self.include_dirs.extend(other.include_dirs);
+		self.defines.extend(other.defines);
+		self.undefines.extend(other.undefines);
+	}
+
+	/// Add the include directories and macros from this command file to `preprocessor`
+	pub fn configure_preprocessor(&self, preprocessor: &mut Preprocessor) {
+		for dir in &self.include_dirs {
+			preprocessor.add_include_path(dir);
+		}
+
+		for (name, value) in &self.defines {
+			preprocessor.define(name.as_str(), value);
+		}
+
+		for name in &self.undefines {
+			preprocessor.undefine(name);
+		}
+	}
+
+	/// Every file of the design, which is the source files and library files followed by the files
+	/// in the library directories that have one of the library extensions, or all of them when
+	/// there are no library extensions
+	pub fn source_files(&self) -> Vec<PathBuf> {
+		let mut files = self
+			.files
+			.iter()
+			.chain(&self.library_files)
+			.cloned()
+			.collect::<Vec<_>>();
+
+		for dir in &self.library_dirs {
+			let entries = match fs::read_dir(dir) {
+				Ok(entries) => entries,
+				Err(err) => {
+					warn!("Unable to read library directory {}: {err}", dir.display());
+					continue;
+				},
+			};
+
+			let mut library = entries
+				.flatten()
+				.map(|entry| entry.path())
+				.filter(|path| {
+					let name = path
+						.file_name()
+						.and_then(|name| name.to_str())
+						.unwrap_or_default();
+
+					path.is_file() &&
+						(self.library_extensions.is_empty() ||
+							self.library_extensions
+								.iter()
+								.any(|extension| name.ends_with(extension.as_str())))
+				})
+				.collect::<Vec<_>>();
+			library.sort();
+			files.extend(library);
+		}
+
+		files
+	}
+
+	/// Read the command file at `path`, `stack` holds the command files currently being read and is
+	/// used to catch command files that reference themselves
+	fn read(&mut self, path: &Path, base: &Path, stack: &mut Vec<PathBuf>) -> eyre::Result<()> {
+		let path = base.join(path);
+		let canonical = path
+			.canonicalize()
+			.map_err(|err| eyre!("Unable to read command file {}: {err}", path.display()))?;
+
+		if stack.contains(&canonical) {
+			return Err(eyre!("Command file {} references itself", path.display()));
+		}
+
+		let content = fs::read_to_string(&canonical)
+			.map_err(|err| eyre!("Unable to read command file {}: {err}", path.display()))?;
+
+		stack.push(canonical);
+		let result = self.parse_into(&content, &path, base, stack);
+		stack.pop();
+
+		result
+	}
+
+	fn parse_into(
+		&mut self,
+		content: &str,
+		path: &Path,
+		base: &Path,
+		stack: &mut Vec<PathBuf>,
+	) -> eyre::Result<()> {
+		let mut arguments = arguments(content).into_iter();
+
+		// Get the value of an option that takes a separate argument
+		let value = |option: &str, arguments: &mut std::vec::IntoIter<String>| {
+			arguments
+				.next()
+				.ok_or_else(|| eyre!("{}: Expected an argument after `{option}`", path.display()))
+		};
+
+		while let Some(argument) = arguments.next() {
+			if let Some(dirs) = argument.strip_prefix("+incdir+") {
+				self.include_dirs
+					.extend(plus_separated(dirs).map(|dir| base.join(dir)));
+			} else if let Some(defines) = argument.strip_prefix("+define+") {
+				self.defines.extend(plus_separated(defines).map(|define| {
+					match define.split_once('=') {
+						Some((name, value)) => (name.to_string(), value.to_string()),
+						None => (define.to_string(), String::new()),
+					}
+				}));
+			} else if let Some(extensions) = argument.strip_prefix("+libext+") {
+				self.library_extensions
+					.extend(plus_separated(extensions).map(str::to_string));
+			} else {
+				match argument.as_str() {
+					"-v" => {
+						let file = value("-v", &mut arguments)?;
+						self.library_files.push(base.join(file));
+					},
+					"-y" => {
+						let dir = value("-y", &mut arguments)?;
+						self.library_dirs.push(base.join(dir));
+					},
+					"-f" => {
+						let file = value("-f", &mut arguments)?;
+						self.read(Path::new(&file), base, stack)?;
+					},
+					"-F" => {
+						let file = base.join(value("-F", &mut arguments)?);
+						let dir = file.parent().unwrap_or(base).to_path_buf();
+						let name = file.file_name().map(PathBuf::from).unwrap_or_default();
+
+						self.read(&name, &dir, stack)?;
+					},
+					option if VALUE_OPTIONS.contains(&option) => {
+						let value = value(option, &mut arguments)?;
+						warn!(
+							"{}: Ignoring unsupported option `{option} {value}`",
+							path.display()
+						);
+					},
+					option if option.starts_with(['-', '+']) => {
+						warn!("{}: Ignoring unsupported option `{option}`", path.display());
+					},
+					file => self.files.push(base.join(file)),
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Split `+` separated option values, ignoring empty values
+fn plus_separated(values: &str) -> impl Iterator<Item = &str> {
+	values.split('+').filter(|value| !value.is_empty())
+}
+
+/// Split the contents of a command file into its arguments, skipping comments and expanding
+/// environment variables
+fn arguments(content: &str) -> Vec<String> {
+	let mut arguments = Vec::new();
+	let mut chars = content.chars().peekable();
+
+	while let Some(&chr) = chars.peek() {
+		if chr.is_whitespace() {
+			chars.next();
+			continue;
+		}
+
+		let mut argument = String::new();
+		let mut quote = None;
+
+		while let Some(&chr) = chars.peek() {
+			match (chr, quote) {
+				(chr, None) if chr.is_whitespace() => break,
+				('"' | '\'', None) => {
+					chars.next();
+					quote = Some(chr);
+				},
+				(chr, Some(open)) if chr == open => {
+					chars.next();
+					quote = None;
+				},
+				('$', Some('"') | None) => {
+					chars.next();
+					argument.push_str(&environment_variable(&mut chars));
+				},
+				('/' | '#', None) if argument.is_empty() && is_comment(&mut chars) => break,
+				_ => {
+					chars.next();
+					argument.push(chr);
+				},
+			}
+		}
+
+		if !argument.is_empty() || quote.is_some() {
+			arguments.push(argument);
+		}
+	}
+
+	arguments
+}
+
+/// Skip a comment if one starts at the current character, returning true if one was skipped
+fn is_comment(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> bool {
+	let mut lookahead = chars.clone();
+
+	match (lookahead.next(), lookahead.next()) {
+		(Some('#'), _) | (Some('/'), Some('/')) => {
+			chars.find(|chr| *chr == '\n');
+			true
+		},
+		(Some('/'), Some('*')) => {
+			chars.next();
+			chars.next();
+
+			let mut previous = '\0';
+			for chr in chars.by_ref() {
+				if previous == '*' && chr == '/' {
+					break;
+				}
+				previous = chr;
+			}
+			true
+		},
+		_ => false,
+	}
+}
+
+/// Expand the environment variable following a `$`, which has already been consumed
+fn environment_variable(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+	let close = match chars.peek() {
+		Some('{') => Some('}'),
+		Some('(') => Some(')'),
+		_ => None,
+	};
+
+	let mut name = String::new();
+	if let Some(close) = close {
+		chars.next();
+		for chr in chars.by_ref() {
+			if chr == close {
+				break;
+			}
+			name.push(chr);
+		}
+	} else {
+		while let Some(&chr) = chars.peek() {
+			if !(chr.is_ascii_alphanumeric() || chr == '_') {
+				break;
+			}
+			name.push(chr);
+			chars.next();
+		}
+	}
+
+	if name.is_empty() {
+		return "$".to_string();
+	}
+
+	std::env::var(&name).unwrap_or_else(|_| {
+		warn!("Environment variable `{name}` is not set");
+		String::new()
+	})
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use super::*;
+use crate::scratch::ScratchDir;
+
+fn parse(content: &str) -> eyre::Result<CommandFile> {
+	let mut command_file = CommandFile::default();
+	command_file.parse_into(
+		content,
+		Path::new("test.f"),
+		Path::new("/work"),
+		&mut Vec::new(),
+	)?;
+
+	Ok(command_file)
+}
+
+#[test]
+fn test_options() {
+	let command_file = parse(concat!(
+		"// Sources\n",
+		"rtl/top.sv rtl/pkg.sv # trailing comment\n",
+		"+incdir+inc+/abs/inc\n",
+		"+define+SYNTHESIS+WIDTH=8\n",
+		"+libext+.v+.sv\n",
+		"/* library\n   setup */ -v cells.v -y lib\n",
+		"+notiming -timescale=1ns/1ps\n",
+	))
+	.unwrap_or_else(|err| panic!("{err}"));
+
+	assert_eq!(
+		command_file,
+		CommandFile {
+			files:              vec![
+				PathBuf::from("/work/rtl/top.sv"),
+				PathBuf::from("/work/rtl/pkg.sv"),
+			],
+			library_files:      vec![PathBuf::from("/work/cells.v")],
+			library_dirs:       vec![PathBuf::from("/work/lib")],
+			library_extensions: vec![".v".to_string(), ".sv".to_string()],
+			include_dirs:       vec![PathBuf::from("/work/inc"), PathBuf::from("/abs/inc")],
+			defines:            vec![
+				("SYNTHESIS".to_string(), String::new()),
+				("WIDTH".to_string(), "8".to_string()),
+			],
+			undefines:          Vec::new(),
+		}
+	);
+}
+
+#[test]
+fn test_quoting_and_variables() {
+	let home = std::env::var("HOME").unwrap_or_default();
+	let command_file = parse("\"my dir/a.sv\" '$HOME/b.sv' $HOME/c.sv ${HOME}/d.sv $(HOME)/e.sv")
+		.unwrap_or_else(|err| panic!("{err}"));
+
+	assert_eq!(
+		command_file.files,
+		[
+			PathBuf::from("/work/my dir/a.sv"),
+			PathBuf::from("/work/$HOME/b.sv"),
+			Path::new("/work").join(format!("{home}/c.sv")),
+			Path::new("/work").join(format!("{home}/d.sv")),
+			Path::new("/work").join(format!("{home}/e.sv")),
+		]
+	);
+}
+
+#[test]
+fn test_missing_argument() {
+	assert!(parse("top.v -y").is_err());
+}
+
+#[test]
+fn test_nested_command_files() {
+	let dir = ScratchDir::new("nested");
+	let sub = dir.join("sub");
+
+	dir.write("sub/relative.f", "b.v\n");
+	dir.write("sub/cwd.f", "c.v\n");
+	dir.write("top.f", "a.v\n-F sub/relative.f\n-f sub/cwd.f\n");
+
+	let mut command_file = CommandFile::default();
+	if let Err(err) = command_file.read(Path::new("top.f"), &dir, &mut Vec::new()) {
+		panic!("{err}");
+	}
+
+	assert_eq!(
+		command_file.files,
+		[dir.join("a.v"), sub.join("b.v"), dir.join("c.v")]
+	);
+}
+
+#[test]
+fn test_recursive_command_file() {
+	let dir = ScratchDir::new("recursive");
+	dir.write("self.f", "-f self.f\n");
+
+	let mut command_file = CommandFile::default();
+	let result = command_file.read(Path::new("self.f"), &dir, &mut Vec::new());
+
+	assert!(result.is_err_and(|err| err.to_string().contains("references itself")));
+}
+
+#[test]
+fn test_option_values() {
+	let command_file = parse("-timescale 1ns/1ps -top tb -l sim.log -work lib top.v -y cells")
+		.unwrap_or_else(|err| panic!("{err}"));
+
+	assert_eq!(command_file.files, [PathBuf::from("/work/top.v")]);
+	assert_eq!(command_file.library_dirs, [PathBuf::from("/work/cells")]);
+	assert!(parse("top.v -top").is_err());
+}
+
+#[test]
+fn test_load_base_dir() {
+	let dir = ScratchDir::new("base-dir");
+	dir.write("sub/sources.f", "a.v\n+incdir+inc\n-f sub/nested.f\n");
+	dir.write("sub/nested.f", "b.v\n");
+
+	// Everything is relative to the base directory, not to where we happen to be running from
+	let command_file =
+		CommandFile::load(Path::new("sub/sources.f"), &dir).unwrap_or_else(|err| panic!("{err}"));
+
+	assert_eq!(command_file.files, [dir.join("a.v"), dir.join("b.v")]);
+	assert_eq!(command_file.include_dirs, [dir.join("inc")]);
+}
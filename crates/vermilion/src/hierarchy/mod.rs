@@ -0,0 +1,375 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Design hierarchies
+//!
+//! Loads the Verilog sources of a design and elaborates it from one or more top modules into a
+//! tree of [`Node`]s, one for each module instance and generate block, which can be rendered as
+//! an indented tree, JSON, or a Graphviz DOT graph.
+//!
+//! When no top module is given, every module that is not instantiated anywhere in the design is
+//! used. Definitions that are not found in the source files are searched for in the library
+//! directories of the workspace settings and then of the [`CommandFile`], as `<dir>/<module><ext>`
+//! for each library extension, or as `<dir>/<module>` when there are none.
+//!
+//! Every file is preprocessed with the include directories and macros of the workspace settings
+//! and command files, so `` `ifdef ``ed instances and `` `include ``d declarations are found, and
+//! parsed in the standard the workspace settings override it to, if they do.
+//!
+//! [`CommandFile`]: crate::command_file::CommandFile
+
+use std::{
+	fmt::Write,
+	fs,
+	path::{Path, PathBuf},
+};
+
+use eyre::eyre;
+use serde::Serialize;
+use vermilion_diagnostics::{Diagnostic, StringDiagnostic};
+use vermilion_lang::AtomicByteTendril;
+use vermilion_loc::{FileId, Location};
+use vermilion_verilog::{
+	LanguageStd as VerilogStd,
+	lang::{
+		ast::Ast,
+		elab::{Elaborator, Instance, InstanceKind},
+		parser::VerilogParser,
+	},
+};
+
+use crate::workspace::Sources;
+
+/// A parsed source file of a design
+struct SourceFile {
+	path: PathBuf,
+	ast:  Ast,
+}
+
+/// The parsed sources of a Verilog, SystemVerilog, or Verilog-AMS design
+pub(crate) struct Design<'a> {
+	std:     VerilogStd,
+	sources: &'a Sources,
+	files:   Vec<SourceFile>,
+}
+
+/// What a [`Node`] in a design hierarchy is
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum NodeKind {
+	Module,
+	Primitive,
+	/// An instance of a definition that could not be found
+	Missing,
+	Generate,
+}
+
+/// A parameter of a [`Node`] along with its elaborated value
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub(crate) struct Parameter {
+	pub name:  String,
+	pub value: String,
+}
+
+/// A module instance or generate block in an elaborated design
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub(crate) struct Node {
+	/// The instance or generate block name
+	pub name:       String,
+	pub kind:       NodeKind,
+	/// The name of the instantiated module or primitive, if this is an instance
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub module:     Option<String>,
+	/// The hierarchical path of the instance, e.g. `top.u_core.genblk1`
+	pub path:       String,
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub parameters: Vec<Parameter>,
+	/// The file with the declaration of the instance
+	pub file:       PathBuf,
+	/// The line of the declaration of the instance, starting from 1
+	pub line:       u32,
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub children:   Vec<Self>,
+}
+
+/// The elaborated hierarchies below the chosen top modules
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Hierarchy {
+	pub tops:        Vec<Node>,
+	/// Problems found while elaborating, formatted as `<file>:<line>: <message>`
+	pub diagnostics: Vec<String>,
+}
+
+impl<'a> Design<'a> {
+	/// Parse the source and library files of `sources`
+	pub fn load(sources: &'a Sources, std: VerilogStd) -> eyre::Result<Self> {
+		let mut design = Self { std, sources, files: Vec::new() };
+
+		let command_file = &sources.command_file;
+		for path in command_file.files.iter().chain(&command_file.library_files) {
+			design.add_file(path)?;
+		}
+
+		Ok(design)
+	}
+
+	fn add_file(&mut self, path: &Path) -> eyre::Result<()> {
+		let content =
+			fs::read(path).map_err(|err| eyre!("Unable to read {}: {err}", path.display()))?;
+		let std = self.sources.verilog_std_for(path, self.std);
+		let ast = VerilogParser::with_preprocessor(
+			&mut self.sources.verilog_preprocessor(std),
+			path,
+			AtomicByteTendril::from_slice(&content),
+		)?
+		.parse();
+
+		self.files
+			.push(SourceFile { path: path.to_path_buf(), ast });
+		Ok(())
+	}
+
+	fn elaborator(&self) -> Elaborator<'_> {
+		let mut elaborator = Elaborator::new(self.std);
+		for (idx, file) in self.files.iter().enumerate() {
+			elaborator.add_file(FileId::new(idx), &file.ast);
+		}
+
+		elaborator
+	}
+
+	/// The modules that are not instantiated anywhere in the design, in name order
+	pub fn top_modules(&self) -> Vec<String> {
+		self.elaborator()
+			.top_modules()
+			.into_iter()
+			.map(str::to_string)
+			.collect()
+	}
+
+	/// Elaborate the design from each of `tops`, or from every top module if there are none
+	pub fn hierarchy(&mut self, tops: &[String]) -> eyre::Result<Hierarchy> {
+		let tops = if tops.is_empty() {
+			self.top_modules()
+		} else {
+			tops.to_vec()
+		};
+
+		if tops.is_empty() {
+			return Err(eyre!("No top modules found in the design"));
+		}
+
+		// Pull in library files for missing definitions until no more are found
+		while self.resolve_missing(&tops)? {}
+
+		let mut elaborator = self.elaborator();
+		let mut hierarchy = Hierarchy::default();
+		for top in &tops {
+			let root = elaborator
+				.elaborate(top)
+				.ok_or_else(|| eyre!("No module named `{top}` found in the design"))?;
+
+			hierarchy.tops.push(self.node(&root, ""));
+			hierarchy.diagnostics.extend(
+				elaborator
+					.diagnostics()
+					.iter()
+					.map(|diagnostic| self.describe(diagnostic)),
+			);
+		}
+
+		Ok(hierarchy)
+	}
+
+	/// Add the library files for the definitions missing from the hierarchies below `tops`,
+	/// returning whether any were added
+	fn resolve_missing(&mut self, tops: &[String]) -> eyre::Result<bool> {
+		let mut missing = Vec::new();
+		{
+			let mut elaborator = self.elaborator();
+			for top in tops {
+				if let Some(root) = elaborator.elaborate(top) {
+					collect_missing(&root, &mut missing);
+				}
+			}
+		}
+
+		let mut found = false;
+		for name in missing {
+			if let Some(path) = self.sources.find_library_module(self.std, &name) &&
+				!self.files.iter().any(|file| file.path == path)
+			{
+				self.add_file(&path)?;
+				found = true;
+			}
+		}
+
+		Ok(found)
+	}
+
+	fn path_of(&self, file: FileId) -> PathBuf {
+		self.files
+			.get(*file.raw())
+			.map(|file| file.path.clone())
+			.unwrap_or_default()
+	}
+
+	fn node(&self, instance: &Instance, parent: &str) -> Node {
+		let path = if parent.is_empty() {
+			instance.name.clone()
+		} else {
+			format!("{parent}.{}", instance.name)
+		};
+
+		let (kind, module) = match &instance.kind {
+			InstanceKind::Module(name) => (NodeKind::Module, Some(name.clone())),
+			InstanceKind::Primitive(name) => (NodeKind::Primitive, Some(name.clone())),
+			InstanceKind::Missing(name) => (NodeKind::Missing, Some(name.clone())),
+			InstanceKind::Generate => (NodeKind::Generate, None),
+		};
+
+		Node {
+			name: instance.name.clone(),
+			kind,
+			module,
+			parameters: instance
+				.parameters
+				.iter()
+				.map(|(name, value)| Parameter { name: name.clone(), value: value.to_string() })
+				.collect(),
+			file: self.path_of(*instance.location.file_id()),
+			line: instance.location.line() + 1,
+			children: instance
+				.children
+				.iter()
+				.map(|child| self.node(child, &path))
+				.collect(),
+			path,
+		}
+	}
+
+	fn describe(&self, diagnostic: &Location<StringDiagnostic>) -> String {
+		format!(
+			"{}:{}: {}",
+			self.path_of(*diagnostic.file_id()).display(),
+			diagnostic.line() + 1,
+			diagnostic.inner().message()
+		)
+	}
+}
+
+impl Node {
+	/// The label of the node in the rendered hierarchy, e.g. `u_core (core) [WIDTH=8]`
+	fn label(&self) -> String {
+		let mut label = self.name.clone();
+
+		match (self.kind, &self.module) {
+			(NodeKind::Missing, Some(module)) => {
+				let _ = write!(label, " ({module}, missing)");
+			},
+			(_, Some(module)) if *module != self.name => {
+				let _ = write!(label, " ({module})");
+			},
+			_ => (),
+		}
+
+		if !self.parameters.is_empty() {
+			let parameters = self
+				.parameters
+				.iter()
+				.map(|parameter| format!("{}={}", parameter.name, parameter.value))
+				.collect::<Vec<_>>();
+			let _ = write!(label, " [{}]", parameters.join(", "));
+		}
+
+		label
+	}
+}
+
+impl Hierarchy {
+	/// Render the hierarchies as indented trees
+	pub fn to_tree(&self) -> String {
+		fn render(node: &Node, prefix: &str, last: bool, root: bool, tree: &mut String) {
+			let (branch, indent) = match (root, last) {
+				(true, _) => ("", ""),
+				(false, true) => ("└── ", "    "),
+				(false, false) => ("├── ", "│   "),
+			};
+			let _ = writeln!(tree, "{prefix}{branch}{}", node.label());
+
+			let prefix = format!("{prefix}{indent}");
+			for (idx, child) in node.children.iter().enumerate() {
+				render(child, &prefix, idx + 1 == node.children.len(), false, tree);
+			}
+		}
+
+		let mut tree = String::new();
+		for top in &self.tops {
+			render(top, "", true, true, &mut tree);
+		}
+
+		tree
+	}
+
+	/// Render the hierarchies as JSON
+	pub fn to_json(&self) -> eyre::Result<String> {
+		Ok(serde_json::to_string_pretty(&self.tops)?)
+	}
+
+	/// Render the hierarchies as a Graphviz DOT graph, with an edge from each instance to the
+	/// instances and generate blocks within it
+	pub fn to_dot(&self) -> String {
+		fn render(node: &Node, dot: &mut String) {
+			let shape = match node.kind {
+				NodeKind::Module => "box",
+				NodeKind::Primitive => "ellipse",
+				NodeKind::Missing => "octagon",
+				NodeKind::Generate => "folder",
+			};
+			let _ = writeln!(
+				dot,
+				"\t\"{}\" [label=\"{}\", shape={shape}];",
+				escape(&node.path),
+				escape(&node.label())
+			);
+
+			for child in &node.children {
+				let _ = writeln!(
+					dot,
+					"\t\"{}\" -> \"{}\";",
+					escape(&node.path),
+					escape(&child.path)
+				);
+				render(child, dot);
+			}
+		}
+
+		let mut dot = String::from("digraph hierarchy {\n");
+		for top in &self.tops {
+			render(top, &mut dot);
+		}
+		dot.push_str("}\n");
+
+		dot
+	}
+}
+
+/// Collect the names of the missing definitions in the hierarchy below `instance`
+fn collect_missing(instance: &Instance, missing: &mut Vec<String>) {
+	if let InstanceKind::Missing(name) = &instance.kind &&
+		!missing.contains(name)
+	{
+		missing.push(name.clone());
+	}
+
+	for child in &instance.children {
+		collect_missing(child, missing);
+	}
+}
+
+/// Escape a string for use in a quoted DOT identifier
+fn escape(value: &str) -> String {
+	value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests;
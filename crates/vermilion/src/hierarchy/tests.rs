@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_verilog::workspace::{StdOverride, SystemVerilogWorkspace, VerilogSearchSettings};
+
+use super::*;
+use crate::{command_file::CommandFile, scratch::ScratchDir, workspace::Workspace};
+
+const TOP: &str = "module top;
+	core #(.WIDTH(16)) u_core();
+	core u_small();
+endmodule
+";
+
+const CORE: &str = "module core #(parameter WIDTH = 8);
+	generate
+		if (WIDTH > 8) begin : g_wide
+			leaf u_leaf();
+		end
+	endgenerate
+endmodule
+
+module leaf;
+endmodule
+";
+
+/// Write `files` to a scratch directory called `name`, returning it along with sources listing
+/// the files
+fn sources(name: &str, files: &[(&str, &str)]) -> (ScratchDir, Sources) {
+	let dir = ScratchDir::new(name);
+
+	let mut sources = Sources::default();
+	for (file, content) in files {
+		sources.command_file.files.push(dir.write(file, content));
+	}
+
+	(dir, sources)
+}
+
+/// Elaborate the hierarchy of `sources` from every top module
+fn hierarchy(sources: &Sources, std: VerilogStd) -> Hierarchy {
+	let mut design = Design::load(sources, std).unwrap_or_else(|err| panic!("{err}"));
+	design.hierarchy(&[]).unwrap_or_else(|err| panic!("{err}"))
+}
+
+#[test]
+fn test_top_modules() {
+	let (dir, sources) = sources("top-modules", &[("top.v", TOP), ("core.v", CORE)]);
+	let design = Design::load(&sources, VerilogStd::Vl05).unwrap_or_else(|err| panic!("{err}"));
+	assert_eq!(design.top_modules(), ["top"]);
+
+	let hierarchy = hierarchy(&sources, VerilogStd::Vl05);
+	assert!(
+		hierarchy.diagnostics.is_empty(),
+		"{:#?}",
+		hierarchy.diagnostics
+	);
+	assert_eq!(hierarchy.tops.len(), 1);
+
+	let top = &hierarchy.tops[0];
+	assert_eq!(top.kind, NodeKind::Module);
+	assert_eq!(top.file, dir.join("top.v"));
+	assert_eq!(top.line, 1);
+
+	let u_core = &top.children[0];
+	assert_eq!(u_core.path, "top.u_core");
+	assert_eq!(u_core.module.as_deref(), Some("core"));
+	assert_eq!(u_core.line, 2);
+	assert_eq!(u_core.children[0].kind, NodeKind::Generate);
+	assert_eq!(
+		u_core.children[0].children[0].path,
+		"top.u_core.g_wide.u_leaf"
+	);
+	assert_eq!(
+		u_core.parameters,
+		[Parameter { name: "WIDTH".into(), value: "16".into() }]
+	);
+
+	// The generate block is only there for wide cores
+	assert!(top.children[1].children.is_empty());
+}
+
+#[test]
+fn test_explicit_top() {
+	let (_dir, sources) = sources("explicit-top", &[("top.v", TOP), ("core.v", CORE)]);
+	let mut design = Design::load(&sources, VerilogStd::Vl05).unwrap_or_else(|err| panic!("{err}"));
+
+	let hierarchy = design
+		.hierarchy(&["core".to_string()])
+		.unwrap_or_else(|err| panic!("{err}"));
+	assert_eq!(hierarchy.tops[0].path, "core");
+	assert!(hierarchy.tops[0].children.is_empty());
+
+	assert!(design.hierarchy(&["missing".to_string()]).is_err());
+}
+
+#[test]
+fn test_library_dirs() {
+	let dir = ScratchDir::new("library-dirs");
+	let lib = dir.join("lib");
+
+	let top = dir.write(
+		"top.v",
+		"module top;\n\tmid u_mid();\n\tnowhere u_gone();\nendmodule\n",
+	);
+	dir.write(
+		"lib/mid.sv",
+		"module mid;\n\tbottom u_bottom();\nendmodule\n",
+	);
+	dir.write("lib/bottom.sv", "module bottom;\nendmodule\n");
+
+	let sources = Sources {
+		command_file: CommandFile {
+			files: vec![top],
+			library_dirs: vec![lib.clone()],
+			library_extensions: vec![".sv".to_string()],
+			..Default::default()
+		},
+		..Default::default()
+	};
+
+	let hierarchy = hierarchy(&sources, VerilogStd::Sv17);
+	let top = &hierarchy.tops[0];
+	let u_bottom = &top.children[0].children[0];
+	assert_eq!(u_bottom.path, "top.u_mid.u_bottom");
+	assert_eq!(u_bottom.file, lib.join("mid.sv"));
+	assert_eq!(top.children[1].kind, NodeKind::Missing);
+}
+
+#[test]
+fn test_formats() {
+	let (_dir, sources) = sources("formats", &[("top.v", TOP), ("core.v", CORE)]);
+	let hierarchy = hierarchy(&sources, VerilogStd::Vl05);
+
+	assert_eq!(
+		hierarchy.to_tree(),
+		concat!(
+			"top\n",
+			"├── u_core (core) [WIDTH=16]\n",
+			"│   └── g_wide\n",
+			"│       └── u_leaf (leaf)\n",
+			"└── u_small (core) [WIDTH=8]\n",
+		)
+	);
+
+	let dot = hierarchy.to_dot();
+	assert!(dot.starts_with("digraph hierarchy {\n"));
+	assert!(dot.contains("\t\"top.u_core\" [label=\"u_core (core) [WIDTH=16]\", shape=box];\n"));
+	assert!(dot.contains("\t\"top.u_core\" -> \"top.u_core.g_wide\";\n"));
+	assert!(dot.contains("\t\"top.u_core.g_wide\" [label=\"g_wide\", shape=folder];\n"));
+
+	let json = hierarchy.to_json().unwrap_or_else(|err| panic!("{err}"));
+	let json: serde_json::Value = serde_json::from_str(&json).unwrap_or_else(|err| panic!("{err}"));
+	assert_eq!(json[0]["name"], "top");
+	assert_eq!(json[0]["children"][0]["module"], "core");
+	assert_eq!(json[0]["children"][0]["parameters"][0]["value"], "16");
+	assert_eq!(json[0]["children"][1]["kind"], "module");
+}
+
+#[test]
+fn test_preprocessed() {
+	let (dir, mut sources) = sources(
+		"preprocessed",
+		&[
+			(
+				"top.v",
+				"`include \"defs.vh\"\nmodule top;\n`ifdef WITH_CORE\n\tcore u_core();\n`endif\n\t\
+				 `LEAF u_leaf();\nendmodule\n",
+			),
+			("core.v", CORE),
+		],
+	);
+	dir.write("defs.vh", "`define LEAF leaf\n");
+
+	// Without the macro from the command file the core isn't instantiated
+	let top = &hierarchy(&sources, VerilogStd::Vl05).tops;
+	assert_eq!(top.len(), 2);
+	assert_eq!(top[0].name, "core");
+	assert_eq!(top[1].children.len(), 1);
+	assert_eq!(top[1].children[0].module.as_deref(), Some("leaf"));
+
+	sources
+		.command_file
+		.defines
+		.push(("WITH_CORE".to_string(), String::new()));
+
+	let top = &hierarchy(&sources, VerilogStd::Vl05).tops;
+	assert_eq!(top.len(), 1);
+	assert_eq!(top[0].children[0].path, "top.u_core");
+	assert_eq!(top[0].children[1].path, "top.u_leaf");
+}
+
+#[test]
+fn test_workspace_settings() {
+	let dir = ScratchDir::new("workspace-settings");
+	let top = dir.write("top.sv", "module top;\n\tmid u_mid();\nendmodule\n");
+	// NOTE(aki): `bit` is only a keyword from SystemVerilog on, before that it is a module name
+	let mid = dir.write("lib/mid.v", "module mid;\n\tbit u_bit();\nendmodule\n");
+	dir.write("lib/bit.v", "module bit;\nendmodule\n");
+
+	let mut sources = Sources {
+		workspace:    Workspace {
+			system_verilog: Some(SystemVerilogWorkspace {
+				search: VerilogSearchSettings {
+					library_dirs: vec!["lib".into()],
+					library_extensions: vec![".v".to_string()],
+					..Default::default()
+				},
+				..Default::default()
+			}),
+			root: dir.to_path_buf(),
+			..Default::default()
+		},
+		command_file: CommandFile { files: vec![top], ..Default::default() },
+	};
+
+	let system_verilog = hierarchy(&sources, VerilogStd::Sv17);
+	let u_mid = &system_verilog.tops[0].children[0];
+	assert_eq!(u_mid.module.as_deref(), Some("mid"));
+	assert!(u_mid.children.is_empty(), "{:#?}", u_mid.children);
+
+	// The library file is parsed as Verilog once the settings say so
+	if let Some(settings) = &mut sources.workspace.system_verilog {
+		settings.search.overrides.push(
+			StdOverride::new("lib/*.v", VerilogStd::Vl05).unwrap_or_else(|err| panic!("{err}")),
+		);
+	}
+
+	let verilog = hierarchy(&sources, VerilogStd::Sv17);
+	let u_bit = &verilog.tops[0].children[0].children[0];
+	assert_eq!(u_bit.path, "top.u_mid.u_bit");
+	assert_eq!(u_bit.kind, NodeKind::Module);
+	assert_eq!(u_bit.file, mid);
+}
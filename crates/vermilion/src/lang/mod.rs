@@ -1,15 +1,26 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
-use std::fmt::Display;
+use std::{
+	fmt::Display,
+	path::{Path, PathBuf},
+};
 
 use clap::{ValueEnum, builder::PossibleValue};
 use color_print::cformat;
+use tracing::warn;
 use vermilion_lang::AtomicByteTendril;
 pub(crate) use vermilion_verilog::lang::{ast::Ast as VerilogAst, tokenizer::VerilogTokenizer};
-use vermilion_verilog::{LanguageStd as VerilogStd, lang::parser::VerilogParser};
-pub(crate) use vermilion_vhdl::lang::{ast::Ast as VhdlAst, tokenizer::VhdlTokenizer};
+use vermilion_verilog::{
+	LanguageStd as VerilogStd,
+	lang::{incremental::TextEdit, parser::VerilogParser, preprocessor::Preprocessor},
+};
+pub(crate) use vermilion_vhdl::lang::{
+	ast::Ast as VhdlAst, bsdl::BsdlDescription, tokenizer::VhdlTokenizer,
+};
 use vermilion_vhdl::{LanguageStd as VhdlStd, lang::parser::VhdlParser};
 
+use crate::workspace::Sources;
+
 pub(crate) mod verilog;
 pub(crate) mod vhdl;
 
@@ -34,6 +45,7 @@ pub(crate) enum Parser {
 pub(crate) enum Ast {
 	Verilog(VerilogAst),
 	Vhdl(VhdlAst),
+	Bsdl(Box<BsdlDescription>),
 }
 
 impl Display for Language {
@@ -48,7 +60,7 @@ impl Display for Language {
 }
 
 impl Language {
-	pub const STD_VALUES: [Self; 25] = [
+	pub const STD_VALUES: [Self; 26] = [
 		Self::Verilog(VerilogStd::Vl95),
 		Self::Verilog(VerilogStd::Vl01),
 		Self::Verilog(VerilogStd::Vl05),
@@ -74,6 +86,7 @@ impl Language {
 		Self::Vhdl(VhdlStd::Vhams09),
 		Self::Vhdl(VhdlStd::Vhams17),
 		Self::Vhdl(VhdlStd::Vhams21),
+		Self::Vhdl(VhdlStd::Bsdl),
 	];
 
 	pub fn tokenizer(self, content: AtomicByteTendril) -> eyre::Result<Tokenizer> {
@@ -92,9 +105,96 @@ impl Language {
 		})
 	}
 
-	pub fn parse_file(self, _content: AtomicByteTendril) -> Ast {
-		todo!()
+	/// Parse `content` read from `path` into a possibly partial AST, syntax errors are reported as
+	/// diagnostics on the AST rather than failing the parse. Verilog files are preprocessed with
+	/// the include directories and macros of `sources`, and `` `include ``s are also found
+	/// relative to `path`
+	///
+	/// The files named in `overrides` are `` `include ``d with the text given there rather than what
+	/// is on disk.
+	pub fn parse_file_at(
+		self,
+		path: &Path,
+		content: AtomicByteTendril,
+		sources: &Sources,
+		overrides: &[(PathBuf, AtomicByteTendril)],
+	) -> Ast {
+		match self {
+			Self::Verilog(std) => Ast::Verilog(
+				match VerilogParser::with_preprocessor(
+					&mut verilog_preprocessor(std, sources, overrides),
+					path,
+					content,
+				) {
+					Ok(parser) => parser.parse(),
+					Err(err) => {
+						warn!("Unable to set up the parser: {err}");
+						VerilogAst::default()
+					},
+				},
+			),
+			Self::Vhdl(VhdlStd::Bsdl) => {
+				Ast::Bsdl(Box::new(match VhdlParser::new(VhdlStd::Bsdl, content) {
+					Ok(parser) => parser.parse_bsdl(),
+					Err(err) => {
+						warn!("Unable to set up the parser: {err}");
+						BsdlDescription::default()
+					},
+				}))
+			},
+			Self::Vhdl(std) => Ast::Vhdl(match VhdlParser::new(std, content) {
+				Ok(parser) => parser.parse(),
+				Err(err) => {
+					warn!("Unable to set up the parser: {err}");
+					VhdlAst::default()
+				},
+			}),
+		}
+	}
+
+	/// Update `ast` after `edit` was made to the file, `content` being the edited text
+	///
+	/// Only the part of a Verilog file around the edit is re-parsed, VHDL and BSDL files are
+	/// always parsed in full.
+	pub fn reparse_file(
+		self,
+		ast: &mut Ast,
+		content: AtomicByteTendril,
+		edit: TextEdit,
+		sources: &Sources,
+		overrides: &[(PathBuf, AtomicByteTendril)],
+	) {
+		match (self, ast) {
+			(Self::Verilog(std), Ast::Verilog(ast)) => {
+				let mut preprocessor = verilog_preprocessor(std, sources, overrides);
+				*ast = match std::mem::take(ast).reparse(&mut preprocessor, content, edit) {
+					Ok(ast) => ast,
+					Err(err) => {
+						warn!("Unable to re-parse: {err}");
+						VerilogAst::default()
+					},
+				};
+			},
+			(language, ast) => {
+				*ast = language.parse_file_at(Path::new(""), content, sources, overrides);
+			},
+		}
+	}
+}
+
+/// The preprocessor for `sources`, reading `` `include ``d files from `overrides` where they are
+/// there
+fn verilog_preprocessor(
+	std: VerilogStd,
+	sources: &Sources,
+	overrides: &[(PathBuf, AtomicByteTendril)],
+) -> Preprocessor {
+	let mut preprocessor = sources.verilog_preprocessor(std);
+	for (path, content) in overrides {
+		preprocessor.override_file(path.clone(), content.clone());
 	}
+
+	preprocessor
 }
 
 impl ValueEnum for Language {
@@ -193,6 +293,10 @@ impl ValueEnum for Language {
 				VhdlStd::Vhams21 => Some(PossibleValue::new("vhdams21").help(cformat!(
 					"<red>VHDL-AMS</> 2021 (<cyan>IEC</> 61691-6:2021)"
 				))),
+				VhdlStd::Bsdl => Some(
+					PossibleValue::new("bsdl")
+						.help(cformat!("<green>BSDL</> (<blue>IEEE</> 1149.1-2013)")),
+				),
 				_ => None,
 			},
 		}
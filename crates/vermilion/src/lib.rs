@@ -40,12 +40,16 @@ use vermilion_macros::cfg_build_tooling;
 use crate::env::VERMILION_LOG_LEVEL;
 
 mod cli;
+mod command_file;
 mod config;
 mod env;
+mod hierarchy;
 mod hooks;
 mod lang;
 mod lsp;
 mod paths;
+#[cfg(test)]
+mod scratch;
 mod workspace;
 
 fn fmt_color() -> bool {
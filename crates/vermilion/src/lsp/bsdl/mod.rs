@@ -0,0 +1,392 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::fmt::Write;
+
+use vermilion_lsp::types::{
+	DocumentSymbol, Hover, HoverContents, MarkupContent, MarkupKind, SymbolKind,
+};
+use vermilion_vhdl::lang::bsdl::{
+	AttributeValue, BoundaryCell, BsdlAttribute, BsdlDescription, BsdlItem, BsdlPort, Instruction,
+	PinMapEntry, SafeValue, TapPort,
+};
+
+use super::workspace::Document;
+
+/// Describe the item of the BSDL description under `offset`
+pub(super) fn hover(
+	document: &Document,
+	description: &BsdlDescription,
+	offset: u32,
+) -> Option<Hover> {
+	let (span, item) = description.item_at(offset)?;
+
+	let value = match item {
+		BsdlItem::Port(port) => describe_port(description, port),
+		BsdlItem::PinMapEntry(entry) => describe_pin_map_entry(description, entry),
+		BsdlItem::TapPort(tap) => describe_tap_port(description, tap),
+		BsdlItem::Instruction(instruction) => describe_instruction(description, instruction),
+		BsdlItem::BoundaryCell(cell) => describe_cell(description, cell),
+		BsdlItem::Attribute(attribute) => describe_attribute(attribute),
+	};
+
+	Some(
+		Hover::new(HoverContents::MarkupContent(MarkupContent::new(
+			MarkupKind::Markdown,
+			value,
+		)))
+		.with_range(document.range_of(&span)),
+	)
+}
+
+/// Build the outline of the BSDL description, with the ports, pin maps, and attributes nested
+/// under the entity
+pub(super) fn document_symbols(
+	document: &Document,
+	description: &BsdlDescription,
+) -> Vec<DocumentSymbol> {
+	let Some(entity) = &description.entity else {
+		return Vec::new();
+	};
+
+	let mut children = Vec::new();
+
+	children.extend(description.ports.iter().map(|port| {
+		let range = document.range_of(port.span());
+		let port = port.inner();
+
+		DocumentSymbol::new(
+			port.name.inner().clone(),
+			SymbolKind::Field,
+			range,
+			document.range_of(port.name.span()),
+		)
+		.with_detail(port_type(port))
+	}));
+
+	children.extend(description.pin_maps.iter().map(|pin_map| {
+		let range = document.range_of(pin_map.span());
+		let pin_map = pin_map.inner();
+
+		let entries = pin_map
+			.entries
+			.iter()
+			.map(|entry| {
+				let range = document.range_of(entry.span());
+				DocumentSymbol::new(
+					entry.inner().port.inner().clone(),
+					SymbolKind::Key,
+					range.clone(),
+					range,
+				)
+				.with_detail(pins(entry.inner()))
+			})
+			.collect();
+
+		DocumentSymbol::new(
+			pin_map.name.inner().clone(),
+			SymbolKind::Constant,
+			range,
+			document.range_of(pin_map.name.span()),
+		)
+		.with_detail("PIN_MAP_STRING".to_string())
+		.with_children(entries)
+	}));
+
+	children.extend(description.attributes.iter().map(|attribute| {
+		let range = document.range_of(attribute.span());
+		let attribute = attribute.inner();
+
+		let symbol = DocumentSymbol::new(
+			attribute.name.inner().clone(),
+			SymbolKind::Property,
+			range,
+			document.range_of(attribute.name.span()),
+		);
+
+		// The instructions and boundary register cells are nested under the attributes that
+		// describe them, everything else just shows its value
+		if attribute
+			.name
+			.inner()
+			.eq_ignore_ascii_case("INSTRUCTION_OPCODE")
+		{
+			symbol.with_children(
+				description
+					.instructions
+					.iter()
+					.map(|instruction| {
+						let range = document.range_of(instruction.span());
+						DocumentSymbol::new(
+							instruction.inner().name.inner().clone(),
+							SymbolKind::EnumMember,
+							range,
+							document.range_of(instruction.inner().name.span()),
+						)
+						.with_detail(opcodes(instruction.inner()))
+					})
+					.collect(),
+			)
+		} else if attribute
+			.name
+			.inner()
+			.eq_ignore_ascii_case("BOUNDARY_REGISTER")
+		{
+			symbol.with_children(
+				description
+					.boundary_cells
+					.iter()
+					.map(|cell| {
+						let range = document.range_of(cell.span());
+						let cell = cell.inner();
+						DocumentSymbol::new(
+							cell.number.inner().to_string(),
+							SymbolKind::Object,
+							range,
+							document.range_of(cell.number.span()),
+						)
+						.with_detail(format!(
+							"{} {} {}",
+							cell.cell.inner(),
+							cell.port.as_ref().map_or("*", |port| port.inner().as_str()),
+							cell.function.as_str()
+						))
+					})
+					.collect(),
+			)
+		} else {
+			symbol.with_detail(attribute_value(&attribute.value))
+		}
+	}));
+
+	let entity_range = document.range_of(entity.span());
+	vec![
+		DocumentSymbol::new(
+			entity.inner().clone(),
+			SymbolKind::Module,
+			document.full_range(),
+			entity_range,
+		)
+		.with_children(children),
+	]
+}
+
+/// The type of `port` as it is declared, such as `inout bit_vector(0 to 7)`
+fn port_type(port: &BsdlPort) -> String {
+	match port.bounds {
+		Some((left, right)) => {
+			let direction = if left <= right {
+				"to"
+			} else {
+				"downto"
+			};
+			format!(
+				"{} bit_vector({left} {direction} {right})",
+				port.mode.as_str()
+			)
+		},
+		None => format!("{} bit", port.mode.as_str()),
+	}
+}
+
+/// The pins of a pin map entry, such as `(5, 6)`
+fn pins(entry: &PinMapEntry) -> String {
+	let pins = entry
+		.pins
+		.iter()
+		.map(|pin| pin.inner().as_str())
+		.collect::<Vec<_>>();
+
+	match pins.as_slice() {
+		[pin] => (*pin).to_string(),
+		pins => format!("({})", pins.join(", ")),
+	}
+}
+
+fn opcodes(instruction: &Instruction) -> String {
+	instruction
+		.opcodes
+		.iter()
+		.map(|opcode| opcode.inner().as_str())
+		.collect::<Vec<_>>()
+		.join(", ")
+}
+
+fn attribute_value(value: &AttributeValue) -> String {
+	match value {
+		AttributeValue::String(value) => format!("\"{value}\""),
+		AttributeValue::Integer(value) => value.to_string(),
+		AttributeValue::Name(value) | AttributeValue::Other(value) => value.clone(),
+	}
+}
+
+fn describe_port(description: &BsdlDescription, port: &BsdlPort) -> String {
+	let mut value = format!(
+		"```vhdl\n{} : {}\n```\n",
+		port.name.inner(),
+		port_type(port)
+	);
+
+	if let Some(tap) = description
+		.tap_ports
+		.iter()
+		.map(|tap| tap.inner())
+		.find(|tap| tap.port.inner().eq_ignore_ascii_case(port.name.inner()))
+	{
+		let _ = writeln!(
+			value,
+			"\nTest access port {} (`{}`)",
+			tap.signal.signal(),
+			tap.signal.attribute()
+		);
+	}
+
+	if let Some(entry) = description.active_pin_map().and_then(|pin_map| {
+		pin_map
+			.entries
+			.iter()
+			.map(|entry| entry.inner())
+			.find(|entry| entry.port.inner().eq_ignore_ascii_case(port.name.inner()))
+	}) {
+		let _ = writeln!(value, "\nPins: {}", pins(entry));
+	}
+
+	describe_port_cells(description, port.name.inner(), &mut value);
+	value
+}
+
+fn describe_pin_map_entry(description: &BsdlDescription, entry: &PinMapEntry) -> String {
+	let mut value = match description.port(entry.port.inner()) {
+		Some(port) => format!(
+			"```vhdl\n{} : {}\n```\n",
+			port.name.inner(),
+			port_type(port)
+		),
+		None => format!("`{}`\n", entry.port.inner()),
+	};
+
+	let _ = writeln!(value, "\nPins: {}", pins(entry));
+	value
+}
+
+fn describe_tap_port(description: &BsdlDescription, tap: &TapPort) -> String {
+	let mut value = format!(
+		"Test access port {} (`{}`)\n",
+		tap.signal.signal(),
+		tap.signal.attribute()
+	);
+
+	if let Some(port) = description.port(tap.port.inner()) {
+		let _ = writeln!(
+			value,
+			"\n```vhdl\n{} : {}\n```",
+			port.name.inner(),
+			port_type(port)
+		);
+	}
+
+	value
+}
+
+fn describe_instruction(description: &BsdlDescription, instruction: &Instruction) -> String {
+	let mut value = format!(
+		"Instruction `{}`\n\nOpcode: `{}`\n",
+		instruction.name.inner(),
+		opcodes(instruction)
+	);
+
+	if description.instruction_private.iter().any(|private| {
+		private
+			.inner()
+			.eq_ignore_ascii_case(instruction.name.inner())
+	}) {
+		value.push_str("\nPrivate, for use by the manufacturer only\n");
+	}
+
+	value
+}
+
+fn describe_cell(description: &BsdlDescription, cell: &BoundaryCell) -> String {
+	let mut value = format!(
+		"Boundary register cell {}\n\n| | |\n|-|-|\n| Cell | `{}` |\n| Port | `{}` |\n| Function \
+		 | `{}` |\n| Safe | `{}` |\n",
+		cell.number.inner(),
+		cell.cell.inner(),
+		cell.port.as_ref().map_or("*", |port| port.inner().as_str()),
+		cell.function.as_str(),
+		safe_value(cell.safe),
+	);
+
+	if let Some(control) = &cell.control {
+		let _ = writeln!(
+			value,
+			"\nDisabled by cell {} at `{}`, which leaves the pin `{}`",
+			control.cell.inner(),
+			u8::from(control.disable),
+			control.result.as_str()
+		);
+	}
+
+	// Show what a control cell is controlling
+	let controlled = description
+		.boundary_cells
+		.iter()
+		.map(|other| other.inner())
+		.filter(|other| {
+			other
+				.control
+				.as_ref()
+				.is_some_and(|control| control.cell.inner() == cell.number.inner())
+		})
+		.map(|other| other.number.inner().to_string())
+		.collect::<Vec<_>>();
+
+	if !controlled.is_empty() {
+		let _ = writeln!(value, "\nControls cells {}", controlled.join(", "));
+	}
+
+	value
+}
+
+fn describe_attribute(attribute: &BsdlAttribute) -> String {
+	format!(
+		"```vhdl\nattribute {} of {} : {}\n```\n",
+		attribute.name.inner(),
+		attribute
+			.targets
+			.iter()
+			.map(|target| target.inner().as_str())
+			.collect::<Vec<_>>()
+			.join(", "),
+		attribute.class
+	)
+}
+
+/// List the boundary register cells connected to the port `name`
+fn describe_port_cells(description: &BsdlDescription, name: &str, value: &mut String) {
+	let cells = description
+		.cells_for_port(name)
+		.map(|cell| {
+			format!(
+				"{} (`{}`, {})",
+				cell.number.inner(),
+				cell.cell.inner(),
+				cell.function.as_str()
+			)
+		})
+		.collect::<Vec<_>>();
+
+	if !cells.is_empty() {
+		let _ = writeln!(value, "\nBoundary register cells: {}", cells.join(", "));
+	}
+}
+
+fn safe_value(safe: SafeValue) -> &'static str {
+	match safe {
+		SafeValue::Zero => "0",
+		SafeValue::One => "1",
+		SafeValue::DontCare => "X",
+	}
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_vhdl::LanguageStd as VhdlStd;
+
+use super::*;
+use crate::{
+	lang::Language,
+	lsp::{db::Database, fixture::offset},
+};
+
+const DEVICE: &str = r#"entity TINY_DEV is
+	generic (PHYSICAL_PIN_MAP : string := "QFN8");
+
+	port (
+		TCK  : in bit;
+		TDI  : in bit;
+		TDO  : out bit;
+		TMS  : in bit;
+		DATA : inout bit_vector(0 to 1)
+	);
+
+	use STD_1149_1_2013.all;
+
+	attribute COMPONENT_CONFORMANCE of TINY_DEV : entity is "STD_1149_1_2013";
+	attribute PIN_MAP of TINY_DEV : entity is PHYSICAL_PIN_MAP;
+
+	constant QFN8 : PIN_MAP_STRING :=
+		"TCK : 1, TDI : 2, TDO : 3, TMS : 4, " &
+		"DATA : (5, 6)";
+
+	attribute TAP_SCAN_IN    of TDI : signal is true;
+	attribute TAP_SCAN_OUT   of TDO : signal is true;
+	attribute TAP_SCAN_MODE  of TMS : signal is true;
+	attribute TAP_SCAN_CLOCK of TCK : signal is (20.0e6, BOTH);
+
+	attribute INSTRUCTION_LENGTH of TINY_DEV : entity is 2;
+	attribute INSTRUCTION_OPCODE of TINY_DEV : entity is
+		"EXTEST (00), " &
+		"SAMPLE (01), " &
+		"BYPASS (11)";
+	attribute INSTRUCTION_CAPTURE of TINY_DEV : entity is "01";
+
+	attribute BOUNDARY_LENGTH of TINY_DEV : entity is 3;
+	attribute BOUNDARY_REGISTER of TINY_DEV : entity is
+		"0 (BC_1, *, control, 0), " &
+		"1 (BC_7, DATA(0), bidir, X, 0, 0, Z), " &
+		"2 (BC_7, DATA(1), bidir, X, 0, 0, Z)";
+end TINY_DEV;
+"#;
+
+/// Run `check` on the document for `DEVICE`
+fn with_document(check: impl FnOnce(&Document)) {
+	let mut db = Database::new();
+	let file = db.add_file(Language::Vhdl(VhdlStd::Bsdl), DEVICE.as_bytes().into());
+	let Some(content) = db.text(file).cloned() else {
+		panic!("No text for the file");
+	};
+	let Some(ast) = db.parse(file) else {
+		panic!("Failed to parse the file");
+	};
+
+	check(&Document::new(content, ast));
+}
+
+/// The hover text for what is at `offset` in `DEVICE`
+fn describe(offset: u32) -> String {
+	let mut value = String::new();
+	with_document(|document| {
+		let Some(hover) = document.hover(&document.position_at(offset)) else {
+			panic!("Nothing to describe at {offset}");
+		};
+		let HoverContents::MarkupContent(content) = hover.contents() else {
+			panic!("Expected markup, got {:?}", hover.contents());
+		};
+
+		value.clone_from(content.value());
+	});
+
+	value
+}
+
+#[test]
+fn test_hover() {
+	let port = describe(offset(DEVICE, "DATA", 0));
+	assert!(port.contains("```vhdl\nDATA : inout bit_vector(0 to 1)\n```"));
+	assert!(port.contains("Pins: (5, 6)"));
+	assert!(port.contains("Boundary register cells: 1 (`BC_7`, BIDIR), 2 (`BC_7`, BIDIR)"));
+
+	let tap = describe(offset(DEVICE, "TCK", 0));
+	assert!(tap.contains("Test access port"));
+	assert!(tap.contains("`TAP_SCAN_CLOCK`"));
+
+	let instruction = describe(offset(DEVICE, "SAMPLE", 0));
+	assert!(instruction.contains("Instruction `SAMPLE`"));
+	assert!(instruction.contains("Opcode: `01`"));
+
+	let control = describe(offset(DEVICE, "0 (BC_1", 0));
+	assert!(control.contains("Boundary register cell 0"));
+	assert!(control.contains("Controls cells 1, 2"));
+}
+
+#[test]
+fn test_document_symbols() {
+	with_document(|document| {
+		let outline = document.document_symbols();
+		let [entity] = outline.as_slice() else {
+			panic!("Expected just the entity, got {outline:?}");
+		};
+		assert_eq!(entity.name(), "TINY_DEV");
+		assert_eq!(entity.kind(), &SymbolKind::Module);
+
+		let children = entity.children().map_or(&[][..], Vec::as_slice);
+		let child = |name: &str| {
+			children
+				.iter()
+				.find(|symbol| symbol.name() == name)
+				.unwrap_or_else(|| panic!("No `{name}` in the outline"))
+		};
+
+		assert_eq!(
+			child("DATA").detail().map(String::as_str),
+			Some("inout bit_vector(0 to 1)")
+		);
+		assert_eq!(child("QFN8").children().map(Vec::len), Some(5));
+		assert_eq!(
+			child("INSTRUCTION_LENGTH").detail().map(String::as_str),
+			Some("2")
+		);
+
+		let instructions = child("INSTRUCTION_OPCODE")
+			.children()
+			.map_or(&[][..], Vec::as_slice)
+			.iter()
+			.map(|symbol| symbol.name().as_str())
+			.collect::<Vec<_>>();
+		assert_eq!(instructions, ["EXTEST", "SAMPLE", "BYPASS"]);
+
+		let cells = child("BOUNDARY_REGISTER")
+			.children()
+			.map_or(&[][..], Vec::as_slice)
+			.iter()
+			.filter_map(|symbol| symbol.detail().cloned())
+			.collect::<Vec<_>>();
+		assert_eq!(
+			cells,
+			["BC_1 * CONTROL", "BC_7 DATA(0) BIDIR", "BC_7 DATA(1) BIDIR"]
+		);
+	});
+}
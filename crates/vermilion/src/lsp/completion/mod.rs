@@ -0,0 +1,814 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Completing the name being typed at the cursor
+//!
+//! What a Verilog name is completed with depends on what comes right before it. A grave accent
+//! completes the text macros of the workspace and the compiler directives, a `$` completes the
+//! system tasks and functions of the standard the file is in, `name::` the members of a package
+//! or class, and `name.` the members of an instance, interface, block, or structure. A `.` that
+//! starts a named port connection completes the ports of the module being instantiated. Anything
+//! else is completed with the names visible from the cursor and the modules of the workspace,
+//! each of which also comes as a snippet instantiating it with all of its ports connected.
+//!
+//! VHDL files only have the design units of the workspace completed.
+//!
+//! The items carry just enough in their `data` to be described once the client resolves them,
+//! which is done the same way hovering over the name describes it.
+
+use std::fmt::Write;
+
+use serde_json::{Value, json};
+use vermilion_loc::{FileId, Location, Span, Spanned};
+use vermilion_lsp::types::{
+	CompletionItem, CompletionItemKind, InsertTextFormat, MarkupContent, MarkupKind, MarkupOrString,
+};
+use vermilion_verilog::{
+	LanguageStd,
+	lang::{
+		ast::{DataType, Declarator, Description, Item, ItemKind, Ports, StructType},
+		directives::{builtin_directives, get_builtin_directive, is_builtin_directive},
+		semantic::{ScopeId, Symbol, SymbolId, SymbolKind, SymbolTable},
+		sysfuncs::{builtin_sysfuncs, get_builtin_sysfunc, is_builtin_sysfunc},
+	},
+};
+
+use super::{
+	db::{Database, queries::DefinitionKind},
+	hover::{describe_directive, describe_symbol, describe_sysfunc, kind_name},
+};
+use crate::lang::{Ast, Language};
+
+/// How many `typedef`s are followed to find the structure a name is declared with
+const MAX_TYPEDEFS: usize = 16;
+
+/// What the name being completed is, going by the text right before it
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Context {
+	/// A text macro or compiler directive, after a grave accent
+	Macro,
+	/// A system task or function, after a `$`
+	SystemFunction,
+	/// A member of the package or class named before the `::`
+	ScopeMember(String),
+	/// A member of what the hierarchical name before the `.` names
+	Member(Vec<String>),
+	/// A port of `module` in a named port connection, or one of its parameters in a named
+	/// parameter value assignment
+	Connection { module: String, parameters: bool },
+	/// Any name visible from the cursor
+	Name,
+}
+
+/// The completions of the name being typed at `offset` in `file`, and the span of what has
+/// been typed of it so far
+pub(crate) fn completions(
+	db: &mut Database,
+	file: FileId,
+	offset: u32,
+) -> Option<(Span, Vec<CompletionItem>)> {
+	let language = db.language(file)?;
+	let text = db.text(file)?.clone();
+	let offset = offset.min(u32::try_from(text.len()).unwrap_or(u32::MAX));
+	let begin = word_begin(&text, offset as usize);
+	let prefix = String::from_utf8_lossy(&text[begin..offset as usize]).into_owned();
+	let span = Span::from((begin as u32, offset));
+
+	let std = match language {
+		Language::Verilog(std) => std,
+		Language::Vhdl(_) => return Some((span, vhdl_units(db))),
+	};
+
+	let context = context(&text, begin)?;
+	// NOTE(aki): A single `:` is most likely a range or a label, so nothing is offered for it
+	if context == Context::Name && prefix.is_empty() && text[..begin].ends_with(b":") {
+		return None;
+	}
+
+	let items = match context {
+		Context::Macro => macros(db, std),
+		Context::SystemFunction => system_functions(std),
+		Context::ScopeMember(scope) => scope_members(db, file, offset, &scope),
+		Context::Member(path) => members(db, file, offset, &path),
+		Context::Connection { module, parameters } => connections(db, file, &module, parameters),
+		Context::Name => names(db, file, offset, &prefix),
+	};
+
+	Some((span, items))
+}
+
+/// Fill in the documentation of a completion item from what its `data` says it is
+pub(crate) fn resolve(db: &mut Database, item: CompletionItem) -> CompletionItem {
+	let Some(value) = item.data().cloned().and_then(|data| describe(db, &data)) else {
+		return item;
+	};
+
+	item.with_documentation(MarkupOrString::Markup(MarkupContent::new(
+		MarkupKind::Markdown,
+		value,
+	)))
+}
+
+/// Describe what the `data` of a completion item refers to
+fn describe(db: &mut Database, data: &Value) -> Option<String> {
+	if let Some(name) = data.get("sysfunc").and_then(Value::as_str) {
+		return get_builtin_sysfunc(name, is_builtin_sysfunc(name).earliest())
+			.map(describe_sysfunc);
+	}
+
+	if let Some(name) = data.get("directive").and_then(Value::as_str) {
+		return get_builtin_directive(name, is_builtin_directive(name).earliest())
+			.map(describe_directive);
+	}
+
+	if let Some(name) = data.get("definition").and_then(Value::as_str) {
+		let file = *db.definitions().files(name).first()?;
+		let location = {
+			let table = db.scopes(file)?;
+			let symbol = table.definition(name).or_else(|| table.package(name))?;
+			table.symbol(symbol).location
+		};
+
+		return describe_symbol(db, *location.file_id(), *location.begin());
+	}
+
+	let file = usize::try_from(data.get("file")?.as_u64()?).ok()?;
+	let offset = u32::try_from(data.get("offset")?.as_u64()?).ok()?;
+	describe_symbol(db, FileId::new(file), offset)
+}
+
+pub(super) fn is_word(chr: u8) -> bool {
+	chr.is_ascii_alphanumeric() || chr == b'_'
+}
+
+/// The offset of the start of the word that ends at `offset`
+fn word_begin(text: &[u8], offset: usize) -> usize {
+	text[..offset]
+		.iter()
+		.rposition(|chr| !is_word(*chr))
+		.map_or(0, |idx| idx + 1)
+}
+
+/// The offset just past the last character before `end` that isn't whitespace
+pub(super) fn trim_end(text: &[u8], end: usize) -> usize {
+	text[..end]
+		.iter()
+		.rposition(|chr| !chr.is_ascii_whitespace())
+		.map_or(0, |idx| idx + 1)
+}
+
+/// The word right before `end`, ignoring whitespace, and the offset it starts at
+pub(super) fn word_before(text: &[u8], end: usize) -> Option<(usize, String)> {
+	let end = trim_end(text, end);
+	let begin = word_begin(text, end);
+
+	(begin < end).then(|| {
+		(
+			begin,
+			String::from_utf8_lossy(&text[begin..end]).into_owned(),
+		)
+	})
+}
+
+/// The offset of the innermost `open` before `end` that isn't closed before it
+pub(super) fn unclosed(text: &[u8], end: usize, open: u8, close: u8) -> Option<usize> {
+	let mut depth = 0usize;
+
+	for (idx, chr) in text[..end].iter().enumerate().rev() {
+		if *chr == close {
+			depth += 1;
+		} else if *chr == open {
+			if depth == 0 {
+				return Some(idx);
+			}
+			depth -= 1;
+		}
+	}
+
+	None
+}
+
+/// What the name starting at `begin` is going by the text before it, if there is anything to
+/// complete it with
+fn context(text: &[u8], begin: usize) -> Option<Context> {
+	let before = &text[..begin];
+
+	Some(match before.last() {
+		Some(b'`') => Context::Macro,
+		// NOTE(aki): A `$` after a word is part of that identifier, not a system function
+		Some(b'$') if !before[..begin - 1].last().is_some_and(|chr| is_word(*chr)) => {
+			Context::SystemFunction
+		},
+		Some(b':') if before.ends_with(b"::") => {
+			Context::ScopeMember(word_before(text, begin - 2)?.1)
+		},
+		Some(b'.') => {
+			let mut path = Vec::new();
+			let mut end = begin - 1;
+			while let Some((start, name)) = word_before(text, end) {
+				path.push(name);
+				match text[..start].last() {
+					Some(b'.') => end = start - 1,
+					_ => break,
+				}
+			}
+
+			if path.is_empty() {
+				connection(text, begin - 1)?
+			} else {
+				path.reverse();
+				Context::Member(path)
+			}
+		},
+		_ => Context::Name,
+	})
+}
+
+/// The instantiation whose port connections or parameter value assignments the `.` at `dot`
+/// starts a named connection in
+fn connection(text: &[u8], dot: usize) -> Option<Context> {
+	let (module, parameters) = instantiated(text, unclosed(text, dot, b'(', b')')?)?;
+	Some(Context::Connection { module, parameters })
+}
+
+/// The module instantiated by the instantiation whose port connections, or parameter value
+/// assignments if the flag is set, are opened by the `(` at `open`
+pub(super) fn instantiated(text: &[u8], open: usize) -> Option<(String, bool)> {
+	let mut end = trim_end(text, open);
+
+	if text[..end].ends_with(b"#") {
+		let (_, module) = word_before(text, end - 1)?;
+		return Some((module, true));
+	}
+
+	// Skip over the range of an array of instances
+	if text[..end].ends_with(b"]") {
+		end = unclosed(text, end - 1, b'[', b']')?;
+	}
+	let (instance, _) = word_before(text, end)?;
+
+	// And the parameter value assignments before the name of the instance
+	end = trim_end(text, instance);
+	if text[..end].ends_with(b")") {
+		end = trim_end(text, unclosed(text, end - 1, b'(', b')')?);
+		if !text[..end].ends_with(b"#") {
+			return None;
+		}
+		end -= 1;
+	}
+
+	let (_, module) = word_before(text, end)?;
+	Some((module, false))
+}
+
+/// Escape the characters that are special in snippets
+fn escape(name: &str) -> String {
+	name.replace('\\', "\\\\")
+		.replace('$', "\\$")
+		.replace('}', "\\}")
+}
+
+fn item_kind(kind: SymbolKind) -> CompletionItemKind {
+	match kind {
+		SymbolKind::Module |
+		SymbolKind::Program |
+		SymbolKind::Primitive |
+		SymbolKind::Config |
+		SymbolKind::Package => CompletionItemKind::Module,
+		SymbolKind::Interface | SymbolKind::Modport => CompletionItemKind::Interface,
+		SymbolKind::Class | SymbolKind::CoverGroup => CompletionItemKind::Class,
+		SymbolKind::Port |
+		SymbolKind::Net |
+		SymbolKind::ImplicitNet |
+		SymbolKind::Variable |
+		SymbolKind::Genvar |
+		SymbolKind::ClockingSignal |
+		SymbolKind::Branch => CompletionItemKind::Variable,
+		SymbolKind::Parameter | SymbolKind::LocalParameter | SymbolKind::Specparam => {
+			CompletionItemKind::Constant
+		},
+		SymbolKind::TypeParameter => CompletionItemKind::TypeParameter,
+		SymbolKind::Typedef | SymbolKind::Nature | SymbolKind::Discipline => {
+			CompletionItemKind::Struct
+		},
+		SymbolKind::EnumMember => CompletionItemKind::EnumMember,
+		SymbolKind::Function |
+		SymbolKind::Task |
+		SymbolKind::AccessFunction |
+		SymbolKind::Let |
+		SymbolKind::Property |
+		SymbolKind::Sequence => CompletionItemKind::Function,
+		SymbolKind::Instance => CompletionItemKind::Field,
+		SymbolKind::Block |
+		SymbolKind::GenerateBlock |
+		SymbolKind::Clocking |
+		SymbolKind::Assertion |
+		SymbolKind::Coverpoint |
+		SymbolKind::Cross |
+		SymbolKind::Constraint => CompletionItemKind::Reference,
+	}
+}
+
+/// The completion of a declared name, resolved by describing its declaration
+fn symbol_item(table: &SymbolTable, symbol: SymbolId) -> CompletionItem {
+	let symbol = table.symbol(symbol);
+
+	CompletionItem::new(symbol.name.clone())
+		.with_kind(item_kind(symbol.kind))
+		.with_detail(kind_name(symbol.kind).to_string())
+		.with_data(json!({
+			"file": symbol.location.file_id().raw(),
+			"offset": symbol.location.begin(),
+		}))
+}
+
+/// The text macros defined in the workspace and the compiler directives of `std`
+fn macros(db: &mut Database, std: LanguageStd) -> Vec<CompletionItem> {
+	let defined = db
+		.definitions()
+		.macros()
+		.map(|name| {
+			CompletionItem::new(format!("`{name}"))
+				.with_kind(CompletionItemKind::Constant)
+				.with_detail("text macro".to_string())
+				.with_filter_text(name.to_string())
+				.with_insert_text(name.to_string())
+		})
+		.collect::<Vec<_>>();
+
+	let mut directives = builtin_directives(std).collect::<Vec<_>>();
+	directives.sort();
+	directives.dedup();
+
+	defined
+		.into_iter()
+		.chain(directives.into_iter().map(|directive| {
+			let name = directive.as_str();
+			CompletionItem::new(format!("`{name}"))
+				.with_kind(CompletionItemKind::Keyword)
+				.with_detail("compiler directive".to_string())
+				.with_filter_text(name.to_string())
+				.with_insert_text(name.to_string())
+				.with_data(json!({ "directive": name }))
+		}))
+		.collect()
+}
+
+/// The system tasks and functions of `std`
+fn system_functions(std: LanguageStd) -> Vec<CompletionItem> {
+	let mut sysfuncs = builtin_sysfuncs(std).collect::<Vec<_>>();
+	sysfuncs.sort();
+	sysfuncs.dedup();
+
+	sysfuncs
+		.into_iter()
+		.map(|sysfunc| {
+			let name = sysfunc.as_str();
+			CompletionItem::new(format!("${name}"))
+				.with_kind(CompletionItemKind::Function)
+				.with_detail("system function".to_string())
+				.with_filter_text(name.to_string())
+				.with_insert_text(name.to_string())
+				.with_data(json!({ "sysfunc": name }))
+		})
+		.collect()
+}
+
+/// The file whose symbol table has the definition or package `name`, preferring `file` itself
+pub(super) fn defining_file(db: &mut Database, file: FileId, name: &str) -> Option<FileId> {
+	let known = db.scopes(file).is_some_and(|table| {
+		table
+			.definition(name)
+			.or_else(|| table.package(name))
+			.is_some()
+	});
+
+	if known {
+		Some(file)
+	} else {
+		db.definitions().files(name).first().copied()
+	}
+}
+
+/// The members of the package or class `name` as seen from `offset` in `file`
+fn scope_members(db: &mut Database, file: FileId, offset: u32, name: &str) -> Vec<CompletionItem> {
+	let source = defining_file(db, file, name).unwrap_or(file);
+	let Some(table) = db.scopes(source) else {
+		return Vec::new();
+	};
+
+	let body = table
+		.package(name)
+		.or_else(|| {
+			table
+				.scope_at(file, offset)
+				.and_then(|scope| table.lookup(scope, name))
+		})
+		.and_then(|symbol| table.symbol(symbol).body);
+
+	body.map(|body| {
+		table
+			.members(body)
+			.map(|symbol| symbol_item(table, symbol))
+			.collect()
+	})
+	.unwrap_or_default()
+}
+
+/// The scope the members of `symbol` are declared in, such as the module of an instance or
+/// the class of an object
+fn member_scope(table: &SymbolTable, symbol: SymbolId) -> Option<ScopeId> {
+	table.symbol(symbol).body.or_else(|| {
+		table
+			.type_of(symbol)
+			.and_then(|target| table.symbol(target).body)
+	})
+}
+
+/// The members of what the hierarchical name `path` names from `offset` in `file`
+fn members(db: &mut Database, file: FileId, offset: u32, path: &[String]) -> Vec<CompletionItem> {
+	let Some(table) = db.scopes(file) else {
+		return Vec::new();
+	};
+	let Some(mut symbol) = table
+		.scope_at(file, offset)
+		.zip(path.first())
+		.and_then(|(scope, name)| table.lookup(scope, name))
+	else {
+		return Vec::new();
+	};
+
+	// Follow the path through the scopes for as long as there are any, the rest of it are the
+	// fields of a structure
+	let mut rest = &path[1..];
+	while let Some((name, remaining)) = rest.split_first() &&
+		let Some(member) = member_scope(table, symbol).and_then(|scope| {
+			table
+				.members(scope)
+				.find(|member| table.symbol(*member).name == *name)
+		}) {
+		symbol = member;
+		rest = remaining;
+	}
+
+	if rest.is_empty() &&
+		let Some(scope) = member_scope(table, symbol)
+	{
+		return table
+			.members(scope)
+			.map(|member| symbol_item(table, member))
+			.collect();
+	}
+
+	let symbol = table.symbol(symbol).clone();
+	struct_fields(db, file, &symbol, rest)
+}
+
+/// The fields of the structure `symbol` is declared with, after following the fields in `path`
+fn struct_fields(
+	db: &mut Database,
+	file: FileId,
+	symbol: &Symbol,
+	path: &[String],
+) -> Vec<CompletionItem> {
+	let Some(mut data_type) = declared_type(db, &symbol.location) else {
+		return Vec::new();
+	};
+
+	let (mut file, mut scope) = (file, symbol.scope);
+	for name in path {
+		let Some((struct_type, struct_file, struct_scope)) =
+			struct_type(db, file, scope, data_type)
+		else {
+			return Vec::new();
+		};
+		let Some(member) = struct_type.members.iter().find(|member| {
+			member
+				.inner()
+				.declarators
+				.iter()
+				.any(|declarator| declarator.inner().name.inner().as_str() == name)
+		}) else {
+			return Vec::new();
+		};
+
+		data_type = member.inner().data_type.clone();
+		(file, scope) = (struct_file, struct_scope);
+	}
+
+	let Some((struct_type, ..)) = struct_type(db, file, scope, data_type) else {
+		return Vec::new();
+	};
+
+	struct_type
+		.members
+		.iter()
+		.flat_map(|member| &member.inner().declarators)
+		.map(|declarator| {
+			CompletionItem::new(declarator.inner().name.inner().as_str().to_string())
+				.with_kind(CompletionItemKind::Field)
+				.with_detail("field".to_string())
+		})
+		.collect()
+}
+
+/// The structure `data_type` is, following the `typedef`s it names from `scope` of the symbol
+/// table of `file`, along with the file and scope it is declared in
+fn struct_type(
+	db: &mut Database,
+	mut file: FileId,
+	mut scope: ScopeId,
+	mut data_type: DataType,
+) -> Option<(Box<StructType>, FileId, ScopeId)> {
+	for _ in 0..MAX_TYPEDEFS {
+		let path = match data_type {
+			DataType::Struct(struct_type) => return Some((struct_type, file, scope)),
+			DataType::Named { path, .. } => path,
+			_ => return None,
+		};
+
+		// NOTE(aki): A package named without being imported is only in the table of its own file
+		if let [package, _] = &path[..] {
+			file = defining_file(db, file, package.inner().as_str())?;
+		}
+
+		let typedef = {
+			let table = db.scopes(file)?;
+			let symbol = match &path[..] {
+				[name] => table.lookup(scope, name.inner().as_str())?,
+				[package, name] => {
+					let body = table
+						.symbol(table.package(package.inner().as_str())?)
+						.body?;
+					table
+						.members(body)
+						.find(|symbol| table.symbol(*symbol).name == name.inner().as_str())?
+				},
+				_ => return None,
+			};
+			table.symbol(symbol).clone()
+		};
+
+		data_type = declared_type(db, &typedef.location)?;
+		scope = typedef.scope;
+	}
+
+	None
+}
+
+/// The data type of the net, variable, port, or `typedef` whose name is at `location`
+fn declared_type(db: &mut Database, location: &Location<()>) -> Option<DataType> {
+	let Some(Ast::Verilog(ast)) = db.parse(*location.file_id()) else {
+		return None;
+	};
+	let span = *location.span();
+
+	ast.descriptions()
+		.iter()
+		.find_map(|description| match description.inner() {
+			Description::Module(module) => {
+				let ports = match &module.ports {
+					Ports::Ansi(ports) => ports.iter().find_map(|port| {
+						let port = port.inner();
+						declarator_type(&port.data_type, &port.declarators, span)
+					}),
+					_ => None,
+				};
+				ports.or_else(|| items_type(&module.items, span))
+			},
+			Description::Package(package) => items_type(&package.items, span),
+			Description::Item(item) => item_type(item, span),
+			_ => None,
+		})
+		.cloned()
+}
+
+fn items_type(items: &[Spanned<Item>], span: Span) -> Option<&DataType> {
+	items.iter().find_map(|item| item_type(item.inner(), span))
+}
+
+fn item_type(item: &Item, span: Span) -> Option<&DataType> {
+	match &item.kind {
+		ItemKind::Port(port) => declarator_type(&port.data_type, &port.declarators, span),
+		ItemKind::Net(net) => declarator_type(&net.data_type, &net.declarators, span),
+		ItemKind::Variable(variable) => {
+			declarator_type(&variable.data_type, &variable.declarators, span)
+		},
+		ItemKind::Typedef(typedef) if *typedef.name.span() == span => typedef.data_type.as_ref(),
+		ItemKind::GenerateRegion(items) => items_type(items, span),
+		ItemKind::GenerateBlock(block) => items_type(&block.items, span),
+		_ => None,
+	}
+}
+
+/// `data_type` if one of `declarators` declares the name at `span`
+fn declarator_type<'a>(
+	data_type: &'a DataType,
+	declarators: &[Spanned<Declarator>],
+	span: Span,
+) -> Option<&'a DataType> {
+	declarators
+		.iter()
+		.any(|declarator| *declarator.inner().name.span() == span)
+		.then_some(data_type)
+}
+
+/// The ports, or parameters, of `module` in the order they are declared
+fn connections(
+	db: &mut Database,
+	file: FileId,
+	module: &str,
+	parameters: bool,
+) -> Vec<CompletionItem> {
+	let Some(source) = defining_file(db, file, module) else {
+		return Vec::new();
+	};
+	let Some(table) = db.scopes(source) else {
+		return Vec::new();
+	};
+	let kind = if parameters {
+		SymbolKind::Parameter
+	} else {
+		SymbolKind::Port
+	};
+
+	declared(table, module, kind)
+		.into_iter()
+		.enumerate()
+		.map(|(idx, symbol)| {
+			let name = escape(&table.symbol(symbol).name);
+			symbol_item(table, symbol)
+				.with_sort_text(format!("{idx:04}"))
+				.with_insert_text(format!("{name}($0)"))
+				.with_insert_text_format(InsertTextFormat::Snippet)
+		})
+		.collect()
+}
+
+/// The symbols of `kind` declared in the body of the definition `name`, in declaration order
+fn declared(table: &SymbolTable, name: &str, kind: SymbolKind) -> Vec<SymbolId> {
+	let Some(body) = table
+		.definition(name)
+		.and_then(|definition| table.symbol(definition).body)
+	else {
+		return Vec::new();
+	};
+
+	let mut symbols = table
+		.members(body)
+		.filter(|symbol| table.symbol(*symbol).kind == kind)
+		.collect::<Vec<_>>();
+	symbols.sort_by_key(|symbol| *table.symbol(*symbol).location.begin());
+
+	symbols
+}
+
+/// The names visible from `offset` in `file`, and the definitions of the workspace
+fn names(db: &mut Database, file: FileId, offset: u32, prefix: &str) -> Vec<CompletionItem> {
+	let mut items = Vec::new();
+
+	if let Some(table) = db.scopes(file) &&
+		let Some(scope) = table.scope_at(file, offset)
+	{
+		// NOTE(aki): The name being typed may itself have been taken for an implicit net
+		items.extend(
+			table
+				.visible(scope)
+				.into_iter()
+				.filter(|symbol| {
+					let location = &table.symbol(*symbol).location;
+					*location.file_id() != file ||
+						!(*location.begin() <= offset && offset <= *location.end())
+				})
+				.map(|symbol| symbol_item(table, symbol)),
+		);
+	}
+
+	items.extend(definitions(db, prefix));
+	items
+}
+
+/// The modules, interfaces, programs, primitives, and packages of the workspace, along with a
+/// snippet instantiating each of the ones starting with `prefix`
+fn definitions(db: &mut Database, prefix: &str) -> Vec<CompletionItem> {
+	let mut items = Vec::new();
+
+	for file in db.files() {
+		let Some(Language::Verilog(_)) = db.language(file) else {
+			continue;
+		};
+		let Some(definitions) = db.item_tree(file).map(|tree| tree.definitions.clone()) else {
+			continue;
+		};
+
+		for definition in definitions {
+			let (kind, detail) = match definition.kind {
+				DefinitionKind::Module => (CompletionItemKind::Module, "module"),
+				DefinitionKind::Interface => (CompletionItemKind::Interface, "interface"),
+				DefinitionKind::Program => (CompletionItemKind::Module, "program"),
+				DefinitionKind::Primitive => (CompletionItemKind::Module, "primitive"),
+				DefinitionKind::Package => (CompletionItemKind::Module, "package"),
+				_ => continue,
+			};
+
+			items.push(
+				CompletionItem::new(definition.name.clone())
+					.with_kind(kind)
+					.with_detail(detail.to_string())
+					.with_data(json!({ "definition": definition.name })),
+			);
+
+			if definition.kind != DefinitionKind::Package &&
+				!prefix.is_empty() &&
+				definition.name.starts_with(prefix) &&
+				let Some(snippet) = instantiation(db, file, &definition.name)
+			{
+				items.push(snippet);
+			}
+		}
+	}
+
+	items
+}
+
+/// The named connections of `symbols` for a snippet, numbering their tab stops from `stop`
+fn snippet_connections(table: &SymbolTable, symbols: &[SymbolId], stop: &mut usize) -> String {
+	symbols
+		.iter()
+		.map(|symbol| {
+			*stop += 1;
+			let name = escape(&table.symbol(*symbol).name);
+			format!("\t.{name}(${{{stop}:{name}}})")
+		})
+		.collect::<Vec<_>>()
+		.join(",\n")
+}
+
+/// A snippet instantiating the definition `name` of `file` with all of its parameters and
+/// ports connected by name
+fn instantiation(db: &mut Database, file: FileId, name: &str) -> Option<CompletionItem> {
+	let table = db.scopes(file)?;
+	table.definition(name)?;
+
+	let parameters = declared(table, name, SymbolKind::Parameter);
+	let ports = declared(table, name, SymbolKind::Port);
+	let escaped = escape(name);
+	let mut stop = 0;
+
+	let mut snippet = escaped.clone();
+	if !parameters.is_empty() {
+		let connections = snippet_connections(table, &parameters, &mut stop);
+		let _ = write!(snippet, " #(\n{connections}\n)");
+	}
+
+	stop += 1;
+	let _ = write!(snippet, " ${{{stop}:u_{escaped}}} (");
+	if !ports.is_empty() {
+		let connections = snippet_connections(table, &ports, &mut stop);
+		let _ = write!(snippet, "\n{connections}\n");
+	}
+	snippet.push_str(");$0");
+
+	Some(
+		CompletionItem::new(name.to_string())
+			.with_kind(CompletionItemKind::Snippet)
+			.with_detail(format!("Instantiate `{name}`"))
+			.with_insert_text(snippet)
+			.with_insert_text_format(InsertTextFormat::Snippet)
+			.with_data(json!({ "definition": name })),
+	)
+}
+
+/// The design units of the VHDL files of the workspace
+fn vhdl_units(db: &mut Database) -> Vec<CompletionItem> {
+	let mut items = Vec::new();
+
+	for file in db.files() {
+		let Some(Language::Vhdl(_)) = db.language(file) else {
+			continue;
+		};
+		let Some(tree) = db.item_tree(file) else {
+			continue;
+		};
+
+		items.extend(tree.definitions.iter().filter_map(|definition| {
+			let detail = match definition.kind {
+				DefinitionKind::Entity => "entity",
+				DefinitionKind::Package => "package",
+				DefinitionKind::Config => "configuration",
+				DefinitionKind::Context => "context",
+				_ => return None,
+			};
+
+			Some(
+				CompletionItem::new(definition.name.clone())
+					.with_kind(CompletionItemKind::Module)
+					.with_detail(detail.to_string()),
+			)
+		}));
+	}
+
+	items
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_verilog::LanguageStd as VerilogStd;
+use vermilion_vhdl::LanguageStd as VhdlStd;
+
+use super::*;
+use crate::lsp::fixture::cursor;
+
+const VERILOG: Language = Language::Verilog(VerilogStd::Sv17);
+
+const CORE: &str = "`define WIDTH 8
+module core #(parameter DEPTH = 4, localparam LAST = DEPTH - 1) (input clk, output [7:0] q);
+	reg [7:0] count;
+endmodule
+";
+
+const PKG: &str = "package pkg;
+	typedef struct packed {
+		logic       valid;
+		logic [7:0] data;
+	} beat_t;
+	typedef struct packed {
+		beat_t head;
+		logic  last;
+	} packet_t;
+	parameter int LANES = 2;
+endpackage
+";
+
+/// Complete at the `|` in `text`, along with the other files of the workspace
+fn complete(text: &str, files: &[(Language, &str)]) -> Option<(String, Vec<CompletionItem>)> {
+	let (text, offset) = cursor(text);
+
+	let mut db = Database::new();
+	let file = db.add_file(files[0].0, text.as_bytes().into());
+	for (language, other) in &files[1..] {
+		db.add_file(*language, other.as_bytes().into());
+	}
+
+	let (span, items) = completions(&mut db, file, offset)?;
+	Some((
+		text[*span.begin() as usize..*span.end() as usize].to_string(),
+		items,
+	))
+}
+
+/// The labels of the completions at the `|` in `text`, with `CORE` and `PKG` in the workspace
+fn labels_at(text: &str) -> Vec<String> {
+	let Some((_, items)) = complete(text, &[(VERILOG, ""), (VERILOG, CORE), (VERILOG, PKG)]) else {
+		panic!("Nothing to complete in {text:?}");
+	};
+
+	items.iter().map(|item| item.label().clone()).collect()
+}
+
+fn has(labels: &[String], label: &str) -> bool {
+	labels.iter().any(|known| known == label)
+}
+
+#[test]
+fn test_context() {
+	let context = |text: &str| {
+		let begin = word_begin(text.as_bytes(), text.len());
+		super::context(text.as_bytes(), begin)
+	};
+
+	assert_eq!(context("assign a = `WID"), Some(Context::Macro));
+	assert_eq!(context("initial $dis"), Some(Context::SystemFunction));
+	assert_eq!(context("a$b"), Some(Context::Name));
+	assert_eq!(
+		context("x = pkg::LA"),
+		Some(Context::ScopeMember("pkg".to_string()))
+	);
+	assert_eq!(
+		context("x = u_core.g.c"),
+		Some(Context::Member(vec!["u_core".to_string(), "g".to_string()]))
+	);
+	assert_eq!(
+		context("core #(.DEPTH(2)) u_core [3:0] (\n\t.clk(clk),\n\t.q"),
+		Some(Context::Connection { module: "core".to_string(), parameters: false })
+	);
+	assert_eq!(
+		context("core #(."),
+		Some(Context::Connection { module: "core".to_string(), parameters: true })
+	);
+	assert_eq!(context("x = (."), None);
+}
+
+#[test]
+fn test_names() {
+	let labels = labels_at(
+		"module top;\n\twire clk;\n\tlogic [7:0] data;\n\tassign data = d|;\nendmodule\n",
+	);
+	assert!(has(&labels, "clk"));
+	assert!(has(&labels, "data"));
+	assert!(has(&labels, "core"));
+	assert!(has(&labels, "pkg"));
+	// The name being typed is not offered as an implicit net
+	assert!(!has(&labels, "d"));
+
+	let Some((typed, items)) = complete(
+		"module top;\n\tco|\nendmodule\n",
+		&[(VERILOG, ""), (VERILOG, CORE)],
+	) else {
+		panic!("Nothing to complete");
+	};
+	assert_eq!(typed, "co");
+
+	let Some(snippet) = items
+		.iter()
+		.find(|item| item.kind() == Some(&CompletionItemKind::Snippet))
+	else {
+		panic!("No instantiation snippet for `core`");
+	};
+	assert_eq!(
+		snippet.insert_text().map(String::as_str),
+		Some(
+			"core #(\n\t.DEPTH(${1:DEPTH})\n) ${2:u_core} (\n\t.clk(${3:clk}),\n\t.q(${4:q})\n);$0"
+		)
+	);
+}
+
+#[test]
+fn test_connections() {
+	let labels =
+		labels_at("module top;\n\tcore u_core (\n\t\t.clk(clk),\n\t\t.|\n\t);\nendmodule\n");
+	assert_eq!(labels, ["clk", "q"]);
+
+	let labels = labels_at("module top;\n\tcore #(.|) u_core ();\nendmodule\n");
+	assert_eq!(labels, ["DEPTH"]);
+}
+
+#[test]
+fn test_members() {
+	let labels = labels_at("module top;\n\tcore u_core ();\n\tinitial u_core.|\nendmodule\n");
+	assert!(has(&labels, "count"));
+	assert!(has(&labels, "clk"));
+
+	let labels = labels_at("module top;\n\tinitial $display(pkg::|);\nendmodule\n");
+	assert!(has(&labels, "LANES"));
+	assert!(has(&labels, "beat_t"));
+
+	let labels = labels_at(
+		"module top;\n\timport pkg::*;\n\tpacket_t packet;\n\tassign packet.|\nendmodule\n",
+	);
+	assert_eq!(labels, ["head", "last"]);
+
+	let labels =
+		labels_at("module top;\n\tpkg::packet_t packet;\n\tassign packet.head.|\nendmodule\n");
+	assert_eq!(labels, ["valid", "data"]);
+}
+
+#[test]
+fn test_builtins() {
+	let labels = labels_at("module top;\n\tinitial $cl|\nendmodule\n");
+	assert!(has(&labels, "$clog2"));
+	assert!(has(&labels, "$display"));
+
+	// `$clog2` was added in Verilog 2005
+	let Some((typed, items)) = complete(
+		"module top;\n\tinitial $cl|\nendmodule\n",
+		&[(Language::Verilog(VerilogStd::Vl95), "")],
+	) else {
+		panic!("Nothing to complete");
+	};
+	assert_eq!(typed, "cl");
+	assert!(!items.iter().any(|item| item.label() == "$clog2"));
+
+	let labels = labels_at("`|");
+	assert!(has(&labels, "`WIDTH"));
+	assert!(has(&labels, "`define"));
+
+	// A single `:` is left alone
+	assert!(complete("wire [7:|", &[(VERILOG, "")]).is_none());
+}
+
+#[test]
+fn test_resolve() {
+	let mut db = Database::new();
+	let file = db.add_file(VERILOG, CORE.as_bytes().into());
+	let offset = CORE.find("count;").unwrap_or_default() + 6;
+
+	let Some((_, items)) = completions(&mut db, file, offset as u32) else {
+		panic!("Nothing to complete");
+	};
+	let resolved = |db: &mut Database, label: &str| {
+		let Some(item) = items.iter().find(|item| item.label() == label) else {
+			panic!("No completion for `{label}`");
+		};
+
+		match resolve(db, item.clone()).documentation() {
+			Some(MarkupOrString::Markup(markup)) => markup.value().clone(),
+			_ => panic!("`{label}` has no documentation"),
+		}
+	};
+
+	assert!(resolved(&mut db, "count").contains("variable `count`"));
+	assert!(resolved(&mut db, "core").contains("module `core`"));
+
+	let Some((_, items)) = completions(
+		&mut db,
+		file,
+		CORE.find("define").unwrap_or_default() as u32,
+	) else {
+		panic!("Nothing to complete");
+	};
+	let Some(define) = items.into_iter().find(|item| item.label() == "`define") else {
+		panic!("No completion for `define");
+	};
+	let Some(MarkupOrString::Markup(markup)) = resolve(&mut db, define).documentation().cloned()
+	else {
+		panic!("`define has no documentation");
+	};
+	assert!(markup.value().contains("See IEEE 1800-2017 § 22.5.1"));
+}
+
+#[test]
+fn test_vhdl() {
+	let Some((_, items)) = complete(
+		"architecture rtl of top is\nbegin\n\tu : entity work.c|\nend architecture;\n",
+		&[
+			(Language::Vhdl(VhdlStd::Vh08), ""),
+			(
+				Language::Vhdl(VhdlStd::Vh08),
+				"entity counter is\nend entity;\n",
+			),
+		],
+	) else {
+		panic!("Nothing to complete");
+	};
+
+	assert!(items.iter().any(|item| item.label() == "counter"));
+}
@@ -0,0 +1,501 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! The analysis database of the language server
+//!
+//! Everything the language server knows about the workspace is derived from the text of its files
+//! through a chain of memoized queries, each only re-run when something it read has changed:
+//!
+//! ```text
+//! text ─► parse ─► item tree ─► definitions ─► related files ─► scopes
+//!  │                                                       └──► types
+//!  └──► lines
+//! ```
+//!
+//! The text of each file is an input, set by the workspace as documents are opened and edited, as
+//! are the [`Sources`] saying how Verilog files are preprocessed.
+//! Every query records the inputs and queries it read while it ran, and every change to an input
+//! bumps the current [`Revision`]. When a query is asked for again its dependencies are checked
+//! first, and if none of them changed since it was last verified the memoized result is reused.
+//!
+//! When a query is re-run and gives the same result as before, it keeps its old change revision,
+//! so the queries depending on it don't need to re-run either. That is what lets an edit inside
+//! of a module body reuse the definitions index of the whole workspace, the item tree of the file
+//! doesn't change.
+
+use std::{
+	collections::{BTreeMap, HashMap},
+	path::{Path, PathBuf},
+};
+
+use vermilion_lang::AtomicByteTendril;
+use vermilion_loc::FileId;
+use vermilion_verilog::lang::{incremental::TextEdit, semantic::SymbolTable, typeck::TypeTable};
+
+use self::queries::{Definitions, ItemTree};
+use super::lines::LineIndex;
+use crate::{
+	lang::{Ast, Language},
+	workspace::Sources,
+};
+
+pub(super) mod queries;
+
+/// How many edits to a file are kept around for incrementally re-parsing it, any more and the
+/// file is just parsed again from scratch
+const MAX_PENDING_EDITS: usize = 64;
+
+/// A point in the history of the database, bumped every time an input changes
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) struct Revision(u64);
+
+/// An input or query of the database
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) enum Key {
+	/// The set of files in the workspace, along with their paths
+	Files,
+	/// The language, text, and path of a file
+	Text(FileId),
+	/// The workspace settings and command files
+	Sources,
+	Lines(FileId),
+	Parse(FileId),
+	ItemTree(FileId),
+	Definitions,
+	RelatedFiles(FileId),
+	Scopes(FileId),
+	Types(FileId),
+}
+
+/// What is known about the last run of a query
+#[derive(Clone, Debug)]
+struct Memo {
+	/// The last revision the result was known to be up to date in
+	verified_at:  Revision,
+	/// The revision the result last changed in
+	changed_at:   Revision,
+	/// The inputs and queries read while running, in the order they were read
+	dependencies: Vec<Key>,
+}
+
+/// The text of a file along with the edits made to it since it was last parsed
+struct SourceFile {
+	language:   Language,
+	content:    AtomicByteTendril,
+	/// Where the file is on disk, if it is anywhere at all
+	path:       Option<PathBuf>,
+	changed_at: Revision,
+	/// The revision the whole text was last replaced in, the edits are all after it
+	base:       Revision,
+	/// The edits made to the text since `base`, with the text after each of them
+	edits:      Vec<(Revision, TextEdit, AtomicByteTendril)>,
+}
+
+/// The results of the queries, kept separately from their [`Memo`]s
+#[derive(Default)]
+struct Values {
+	lines:       HashMap<FileId, LineIndex>,
+	parses:      HashMap<FileId, Ast>,
+	item_trees:  HashMap<FileId, ItemTree>,
+	definitions: Definitions,
+	related:     HashMap<FileId, Vec<FileId>>,
+	scopes:      HashMap<FileId, Option<SymbolTable>>,
+	types:       HashMap<FileId, Option<TypeTable>>,
+}
+
+#[derive(Default)]
+pub(crate) struct Database {
+	revision:           Revision,
+	next_file:          usize,
+	files:              BTreeMap<FileId, SourceFile>,
+	files_changed_at:   Revision,
+	sources:            Sources,
+	sources_changed_at: Revision,
+	memos:              HashMap<Key, Memo>,
+	values:             Values,
+	/// The dependencies of each query currently running, innermost last
+	active:             Vec<Vec<Key>>,
+	/// How many times each query has been run, so tests can tell what was reused
+	#[cfg(test)]
+	executions:         HashMap<Key, usize>,
+}
+
+impl Database {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	fn bump(&mut self) -> Revision {
+		self.revision = Revision(self.revision.0 + 1);
+		self.revision
+	}
+
+	/// Add a new file with the given language and text to the database
+	pub fn add_file(&mut self, language: Language, content: AtomicByteTendril) -> FileId {
+		let revision = self.bump();
+		let file = FileId::new(self.next_file);
+		self.next_file += 1;
+
+		self.files.insert(
+			file,
+			SourceFile {
+				language,
+				content,
+				path: None,
+				changed_at: revision,
+				base: revision,
+				edits: Vec::new(),
+			},
+		);
+		self.files_changed_at = revision;
+
+		file
+	}
+
+	/// Add a new file at `path` along with its already parsed syntax tree, such as one parsed in
+	/// the background while indexing the workspace
+	pub fn add_parsed_file(
+		&mut self,
+		path: PathBuf,
+		language: Language,
+		content: AtomicByteTendril,
+		ast: Ast,
+	) -> FileId {
+		let file = self.add_file(language, content);
+		if let Some(source) = self.files.get_mut(&file) {
+			source.path = Some(path);
+		}
+
+		// NOTE(aki): Files with `` `include ``s are parsed again when first needed, so the files
+		// they include are recorded as dependencies of the parse
+		if let Ast::Verilog(ast) = &ast &&
+			ast.source_map().includes().next().is_some()
+		{
+			return file;
+		}
+
+		self.memos.insert(
+			Key::Parse(file),
+			Memo {
+				verified_at:  self.revision,
+				changed_at:   self.revision,
+				dependencies: vec![Key::Text(file)],
+			},
+		);
+		self.values.parses.insert(file, ast);
+
+		file
+	}
+
+	/// Remove a file, along with everything that was derived from it alone
+	pub fn remove_file(&mut self, file: FileId) {
+		if self.files.remove(&file).is_none() {
+			return;
+		}
+
+		self.files_changed_at = self.bump();
+		self.memos.retain(|key, _| key.file() != Some(file));
+		self.values.lines.remove(&file);
+		self.values.parses.remove(&file);
+		self.values.item_trees.remove(&file);
+		self.values.related.remove(&file);
+		self.values.scopes.remove(&file);
+		self.values.types.remove(&file);
+	}
+
+	/// Replace the whole text of a file, and possibly its language
+	pub fn set_text(&mut self, file: FileId, language: Language, content: AtomicByteTendril) {
+		let revision = self.bump();
+		if let Some(source) = self.files.get_mut(&file) {
+			*source = SourceFile {
+				language,
+				content,
+				path: source.path.take(),
+				changed_at: revision,
+				base: revision,
+				edits: Vec::new(),
+			};
+		}
+	}
+
+	/// Set the workspace settings and command files, which every Verilog file is preprocessed
+	/// with
+	pub fn set_sources(&mut self, sources: Sources) {
+		self.sources = sources;
+		self.sources_changed_at = self.bump();
+	}
+
+	/// Set where a file is on disk, which is where the files it includes are found relative to
+	pub fn set_path(&mut self, file: FileId, path: PathBuf) {
+		if !self
+			.files
+			.get(&file)
+			.is_some_and(|source| source.path.as_ref() != Some(&path))
+		{
+			return;
+		}
+
+		let revision = self.bump();
+		if let Some(source) = self.files.get_mut(&file) {
+			source.path = Some(path);
+			source.changed_at = revision;
+			source.base = revision;
+			source.edits.clear();
+		}
+		self.files_changed_at = revision;
+	}
+
+	/// Set the text of a file after `edit` was made to it
+	///
+	/// Unlike [`Self::set_text`], the next parse of the file only re-parses around the edit.
+	pub fn edit_text(&mut self, file: FileId, content: AtomicByteTendril, edit: TextEdit) {
+		let revision = self.bump();
+		let parsed_at = self
+			.memos
+			.get(&Key::Parse(file))
+			.map(|memo| memo.verified_at);
+
+		let Some(source) = self.files.get_mut(&file) else {
+			return;
+		};
+
+		// The edits the last parse already has don't need to be kept
+		match parsed_at {
+			Some(parsed_at) => source.edits.retain(|(at, ..)| *at > parsed_at),
+			None => source.edits.clear(),
+		}
+
+		if parsed_at.is_none() || source.edits.len() >= MAX_PENDING_EDITS {
+			source.edits.clear();
+			source.base = revision;
+		} else {
+			source.edits.push((revision, edit, content.clone()));
+		}
+
+		source.content = content;
+		source.changed_at = revision;
+	}
+
+	/// Every file in the database, in the order they were added
+	pub fn files(&mut self) -> Vec<FileId> {
+		self.ensure(Key::Files);
+		self.files.keys().copied().collect()
+	}
+
+	pub fn sources(&mut self) -> &Sources {
+		self.ensure(Key::Sources);
+		&self.sources
+	}
+
+	pub fn language(&mut self, file: FileId) -> Option<Language> {
+		self.ensure(Key::Text(file));
+		self.files.get(&file).map(|source| source.language)
+	}
+
+	pub fn text(&mut self, file: FileId) -> Option<&AtomicByteTendril> {
+		self.ensure(Key::Text(file));
+		self.files.get(&file).map(|source| &source.content)
+	}
+
+	/// The text of a file along with where each of its lines start
+	pub fn lines(&mut self, file: FileId) -> Option<(&AtomicByteTendril, &LineIndex)> {
+		self.ensure(Key::Text(file));
+		self.ensure(Key::Lines(file));
+
+		let content = &self.files.get(&file)?.content;
+		Some((content, self.values.lines.get(&file)?))
+	}
+
+	/// The file at `path` on disk, if it is in the database
+	pub fn file_at(&mut self, path: &Path) -> Option<FileId> {
+		self.ensure(Key::Files);
+		self.file_with_path(path)
+	}
+
+	/// The file at `path` on disk, without recording a dependency on the set of files
+	fn file_with_path(&self, path: &Path) -> Option<FileId> {
+		self.files
+			.iter()
+			.find_map(|(file, source)| (source.path.as_deref() == Some(path)).then_some(*file))
+	}
+
+	/// The syntax tree of a file
+	pub fn parse(&mut self, file: FileId) -> Option<&Ast> {
+		self.ensure(Key::Parse(file));
+		self.values.parses.get(&file)
+	}
+
+	/// The definitions in a file, without anything that depends on where exactly they are
+	pub fn item_tree(&mut self, file: FileId) -> Option<&ItemTree> {
+		self.ensure(Key::ItemTree(file));
+		self.values.item_trees.get(&file)
+	}
+
+	/// The files defining each module, package, and other top-level construct of the workspace
+	pub fn definitions(&mut self) -> &Definitions {
+		self.ensure(Key::Definitions);
+		&self.values.definitions
+	}
+
+	/// The file itself along with the files defining what it uses, in file order
+	pub fn related_files(&mut self, file: FileId) -> &[FileId] {
+		self.ensure(Key::RelatedFiles(file));
+		self.values.related.get(&file).map_or(&[], Vec::as_slice)
+	}
+
+	/// The resolved symbols of a Verilog file and the files it uses
+	pub fn scopes(&mut self, file: FileId) -> Option<&SymbolTable> {
+		self.ensure(Key::Scopes(file));
+		self.values.scopes.get(&file)?.as_ref()
+	}
+
+	/// The types of the expressions of a Verilog file and the files it uses
+	pub fn types(&mut self, file: FileId) -> Option<&TypeTable> {
+		self.ensure(Key::Types(file));
+		self.values.types.get(&file)?.as_ref()
+	}
+
+	/// Bring `key` up to date, recording it as a dependency of the query currently running
+	fn ensure(&mut self, key: Key) {
+		self.refresh(key);
+		if let Some(dependencies) = self.active.last_mut() {
+			dependencies.push(key);
+		}
+	}
+
+	/// Bring `key` up to date, returning the revision it last changed in
+	fn refresh(&mut self, key: Key) -> Revision {
+		match key {
+			Key::Files => return self.files_changed_at,
+			Key::Sources => return self.sources_changed_at,
+			Key::Text(file) => {
+				// NOTE(aki): A file that is gone counts as having just changed
+				return self
+					.files
+					.get(&file)
+					.map_or(self.revision, |source| source.changed_at);
+			},
+			_ => (),
+		}
+
+		if let Some(memo) = self.memos.get(&key) {
+			if memo.verified_at == self.revision {
+				return memo.changed_at;
+			}
+
+			let (verified_at, changed_at) = (memo.verified_at, memo.changed_at);
+			let dependencies = memo.dependencies.clone();
+			if dependencies
+				.into_iter()
+				.all(|dependency| self.refresh(dependency) <= verified_at)
+			{
+				if let Some(memo) = self.memos.get_mut(&key) {
+					memo.verified_at = self.revision;
+				}
+				return changed_at;
+			}
+		}
+
+		self.execute(key)
+	}
+
+	/// Run the query `key`, returning the revision its result last changed in
+	fn execute(&mut self, key: Key) -> Revision {
+		let previous = self.memos.remove(&key);
+
+		#[cfg(test)]
+		{
+			*self.executions.entry(key).or_default() += 1;
+		}
+
+		self.active.push(Vec::new());
+		let unchanged = match key {
+			Key::Files | Key::Text(_) | Key::Sources => false,
+			Key::Lines(file) => {
+				let lines = queries::lines(self, file);
+				store(&mut self.values.lines, file, lines)
+			},
+			Key::Parse(file) => {
+				let last = previous
+					.as_ref()
+					.zip(self.values.parses.remove(&file))
+					.map(|(memo, ast)| (ast, memo.verified_at));
+
+				let ast = queries::parse(self, file, last);
+				self.values.parses.insert(file, ast);
+				false
+			},
+			Key::ItemTree(file) => {
+				let tree = queries::item_tree(self, file);
+				store(&mut self.values.item_trees, file, tree)
+			},
+			Key::Definitions => {
+				let definitions = queries::definitions(self);
+				let unchanged = self.values.definitions == definitions;
+				self.values.definitions = definitions;
+				unchanged
+			},
+			Key::RelatedFiles(file) => {
+				let related = queries::related_files(self, file);
+				store(&mut self.values.related, file, related)
+			},
+			Key::Scopes(file) => {
+				let scopes = queries::scopes(self, file);
+				self.values.scopes.insert(file, scopes);
+				false
+			},
+			Key::Types(file) => {
+				let types = queries::types(self, file);
+				self.values.types.insert(file, types);
+				false
+			},
+		};
+		let dependencies = self.active.pop().unwrap_or_default();
+
+		let changed_at = match previous {
+			Some(previous) if unchanged => previous.changed_at,
+			_ => self.revision,
+		};
+		self.memos.insert(
+			key,
+			Memo {
+				verified_at: self.revision,
+				changed_at,
+				dependencies,
+			},
+		);
+
+		changed_at
+	}
+
+	/// The number of times the query `key` has been run
+	#[cfg(test)]
+	fn executions(&self, key: Key) -> usize {
+		self.executions.get(&key).copied().unwrap_or_default()
+	}
+}
+
+impl Key {
+	/// The file this key is about, if it is only about a single file
+	fn file(&self) -> Option<FileId> {
+		match self {
+			Self::Files | Self::Sources | Self::Definitions => None,
+			Self::Text(file) |
+			Self::Lines(file) |
+			Self::Parse(file) |
+			Self::ItemTree(file) |
+			Self::RelatedFiles(file) |
+			Self::Scopes(file) |
+			Self::Types(file) => Some(*file),
+		}
+	}
+}
+
+/// Store the result of a query for `file`, returning if it is the same as the previous one
+fn store<T: PartialEq>(values: &mut HashMap<FileId, T>, file: FileId, value: T) -> bool {
+	let unchanged = values.get(&file) == Some(&value);
+	values.insert(file, value);
+	unchanged
+}
+
+#[cfg(test)]
+mod tests;
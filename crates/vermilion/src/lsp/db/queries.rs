@@ -0,0 +1,437 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! The queries of the [`Database`]
+//!
+//! Queries read other queries and inputs through the methods of the [`Database`], which records
+//! them as dependencies of the query being run.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use vermilion_loc::{FileId, Span, Spanned};
+use vermilion_verilog::lang::{
+	ast::{Description, Item, ItemKind, ModuleKeyword, PackageImport},
+	directives::BuiltinDirective,
+	semantic::SymbolTable,
+	tokenizer::token::Token,
+	typeck::TypeTable,
+	types::{CompilerDirective, TextMacro},
+};
+use vermilion_vhdl::lang::ast::LibraryUnit;
+
+use super::{Database, Key, Revision};
+use crate::{
+	lang::{Ast, Language, VerilogAst, VhdlAst},
+	lsp::lines::LineIndex,
+};
+
+/// What kind of top-level construct a [`Definition`] is
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) enum DefinitionKind {
+	Module,
+	Interface,
+	Program,
+	Primitive,
+	Config,
+	Package,
+	Class,
+	Nature,
+	Discipline,
+	Entity,
+	Context,
+}
+
+/// A named top-level construct of a file
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct Definition {
+	pub name: String,
+	pub kind: DefinitionKind,
+}
+
+/// The top-level constructs of a file and the names of the ones it uses from elsewhere
+///
+/// Nothing in here depends on where in the file things are, so edits that don't add, remove, or
+/// rename anything leave the item tree of the file as it was.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct ItemTree {
+	pub definitions: Vec<Definition>,
+	/// The modules, interfaces, and primitives instantiated, and the packages imported
+	pub uses:        BTreeSet<String>,
+	/// The text macros defined with `` `define ``
+	pub macros:      BTreeSet<String>,
+}
+
+/// A use or definition of a text macro
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct MacroName {
+	pub name:    String,
+	/// The span of the name, including the grave accent of a use
+	pub span:    Span,
+	/// Whether this is the name of a `` `define `` rather than a use of the macro
+	pub defined: bool,
+}
+
+/// The files defining each named top-level construct of the workspace
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Definitions {
+	files:  BTreeMap<String, Vec<FileId>>,
+	macros: BTreeMap<String, Vec<FileId>>,
+}
+
+impl Definitions {
+	/// The files with a definition named `name`, usually just the one
+	pub fn files(&self, name: &str) -> &[FileId] {
+		self.files.get(name).map_or(&[], Vec::as_slice)
+	}
+
+	/// The files defining the text macro `name`
+	pub fn macro_files(&self, name: &str) -> &[FileId] {
+		self.macros.get(name).map_or(&[], Vec::as_slice)
+	}
+
+	/// The names of the text macros defined anywhere in the workspace
+	pub fn macros(&self) -> impl Iterator<Item = &str> {
+		self.macros.keys().map(String::as_str)
+	}
+}
+
+/// Where each line of a file starts
+pub(super) fn lines(db: &mut Database, file: FileId) -> LineIndex {
+	db.text(file)
+		.map(|content| LineIndex::new(content))
+		.unwrap_or_default()
+}
+
+pub(super) fn parse(db: &mut Database, file: FileId, last: Option<(Ast, Revision)>) -> Ast {
+	let Some(language) = db.language(file) else {
+		return Ast::Verilog(VerilogAst::default());
+	};
+	db.sources();
+	let Some(source) = db.files.get(&file) else {
+		return Ast::Verilog(VerilogAst::default());
+	};
+
+	// `` `include ``d files are read from the database where they are in it, so their unsaved
+	// changes are seen
+	let overrides = db
+		.files
+		.iter()
+		.filter(|(other, _)| **other != file)
+		.filter_map(|(_, other)| Some((other.path.clone()?, other.content.clone())))
+		.collect::<Vec<_>>();
+
+	// Catch the last parse up with the edits made since, if it hasn't missed any and the file is
+	// still preprocessed the same way
+	let ast = match last {
+		Some((mut ast, parsed_at))
+			if source.base <= parsed_at &&
+				db.sources_changed_at <= parsed_at &&
+				source.language == language &&
+				included_files(db, &ast).all(|included| {
+					included.is_some_and(|included| {
+						db.files
+							.get(&included)
+							.is_some_and(|included| included.changed_at <= parsed_at)
+					})
+				}) =>
+		{
+			for (_, edit, content) in source.edits.iter().filter(|(at, ..)| *at > parsed_at) {
+				language.reparse_file(&mut ast, content.clone(), *edit, &db.sources, &overrides);
+			}
+
+			ast
+		},
+		_ => {
+			let path = source.path.clone().unwrap_or_default();
+			language.parse_file_at(&path, source.content.clone(), &db.sources, &overrides)
+		},
+	};
+
+	let included = included_files(db, &ast).flatten().collect::<Vec<_>>();
+	for included in included {
+		db.ensure(Key::Text(included));
+	}
+
+	ast
+}
+
+/// The files in the database `` `include ``d by `ast`, [`None`] for the ones that were read from
+/// disk
+fn included_files<'a>(
+	db: &'a Database,
+	ast: &'a Ast,
+) -> impl Iterator<Item = Option<FileId>> + 'a {
+	let includes = match ast {
+		Ast::Verilog(ast) => Some(ast.source_map().includes()),
+		Ast::Vhdl(_) | Ast::Bsdl(_) => None,
+	};
+
+	includes
+		.into_iter()
+		.flatten()
+		.map(|path| db.file_with_path(path))
+}
+
+pub(super) fn item_tree(db: &mut Database, file: FileId) -> ItemTree {
+	match db.parse(file) {
+		Some(Ast::Verilog(ast)) => verilog_item_tree(ast),
+		Some(Ast::Vhdl(ast)) => vhdl_item_tree(ast),
+		_ => ItemTree::default(),
+	}
+}
+
+fn verilog_item_tree(ast: &VerilogAst) -> ItemTree {
+	let mut tree = ItemTree {
+		macros: macros(ast)
+			.filter(|text_macro| text_macro.defined)
+			.map(|text_macro| text_macro.name)
+			.collect(),
+		..Default::default()
+	};
+
+	for description in ast.descriptions() {
+		let kind = match description.inner() {
+			Description::Module(module) => {
+				imports(&module.imports, &mut tree.uses);
+				uses(&module.items, &mut tree.uses);
+
+				match module.keyword {
+					ModuleKeyword::Module | ModuleKeyword::MacroModule => DefinitionKind::Module,
+					ModuleKeyword::Interface => DefinitionKind::Interface,
+					ModuleKeyword::Program => DefinitionKind::Program,
+				}
+			},
+			Description::Primitive(_) => DefinitionKind::Primitive,
+			Description::Config(_) => DefinitionKind::Config,
+			Description::Package(package) => {
+				uses(&package.items, &mut tree.uses);
+				DefinitionKind::Package
+			},
+			Description::Class(class) => {
+				for item in &class.items {
+					item_uses(&item.inner().item, &mut tree.uses);
+				}
+				DefinitionKind::Class
+			},
+			Description::Nature(_) => DefinitionKind::Nature,
+			Description::Discipline(_) => DefinitionKind::Discipline,
+			Description::Item(item) => {
+				item_uses(item, &mut tree.uses);
+				continue;
+			},
+			Description::Error => continue,
+		};
+
+		if let Some(name) = description.inner().name() {
+			tree.definitions
+				.push(Definition { name: name.inner().as_str().to_string(), kind });
+		}
+	}
+
+	tree
+}
+
+/// The primary units of a VHDL file, named as they are normalized so lookups are case insensitive
+///
+/// Secondary units aren't definitions of their own, they belong to the primary unit they name.
+fn vhdl_item_tree(ast: &VhdlAst) -> ItemTree {
+	let mut tree = ItemTree::default();
+
+	for unit in ast.units() {
+		let kind = match &unit.inner().unit {
+			LibraryUnit::Entity(_) => DefinitionKind::Entity,
+			LibraryUnit::Package(_) | LibraryUnit::PackageInstance(_) => DefinitionKind::Package,
+			LibraryUnit::Configuration(_) => DefinitionKind::Config,
+			LibraryUnit::Context(_) => DefinitionKind::Context,
+			_ => continue,
+		};
+
+		if let Some(name) = unit.inner().unit.name() {
+			tree.definitions
+				.push(Definition { name: name.inner().normalized(), kind });
+		}
+	}
+
+	tree
+}
+
+pub(super) fn definitions(db: &mut Database) -> Definitions {
+	let mut definitions = Definitions::default();
+
+	for file in db.files() {
+		let Some(tree) = db.item_tree(file) else {
+			continue;
+		};
+
+		for definition in &tree.definitions {
+			definitions
+				.files
+				.entry(definition.name.clone())
+				.or_default()
+				.push(file);
+		}
+
+		for name in &tree.macros {
+			definitions
+				.macros
+				.entry(name.clone())
+				.or_default()
+				.push(file);
+		}
+	}
+
+	definitions
+}
+
+pub(super) fn related_files(db: &mut Database, file: FileId) -> Vec<FileId> {
+	let Some(Language::Verilog(_)) = db.language(file) else {
+		return Vec::new();
+	};
+	let Some(uses) = db.item_tree(file).map(|tree| tree.uses.clone()) else {
+		return Vec::new();
+	};
+
+	let definitions = db.definitions();
+	let mut related = BTreeSet::from([file]);
+	for name in &uses {
+		related.extend(definitions.files(name));
+	}
+
+	related.into_iter().collect()
+}
+
+pub(super) fn scopes(db: &mut Database, file: FileId) -> Option<SymbolTable> {
+	let Some(Language::Verilog(std)) = db.language(file) else {
+		return None;
+	};
+
+	let files = verilog_files(db, file);
+	let mut table = SymbolTable::new(std);
+	for related in &files {
+		if let Some(Ast::Verilog(ast)) = db.values.parses.get(related) {
+			table.add_file(*related, ast);
+		}
+	}
+	table.resolve();
+
+	Some(table)
+}
+
+pub(super) fn types(db: &mut Database, file: FileId) -> Option<TypeTable> {
+	let Some(Language::Verilog(std)) = db.language(file) else {
+		return None;
+	};
+
+	let files = verilog_files(db, file);
+	let asts = files
+		.iter()
+		.filter_map(|related| match db.values.parses.get(related) {
+			Some(Ast::Verilog(ast)) => Some((*related, ast)),
+			_ => None,
+		})
+		.collect::<Vec<_>>();
+
+	Some(TypeTable::new(std, &asts))
+}
+
+/// Parse the files related to `file`, returning them
+///
+/// The syntax trees are read straight out of the database afterwards, as they can't be borrowed
+/// while other queries are still being run.
+fn verilog_files(db: &mut Database, file: FileId) -> Vec<FileId> {
+	let files = db.related_files(file).to_vec();
+	for related in &files {
+		db.parse(*related);
+	}
+
+	files
+}
+
+/// Collect the packages imported by `imports`
+fn imports(imports: &[Spanned<PackageImport>], names: &mut BTreeSet<String>) {
+	for import in imports {
+		names.insert(import.inner().package.inner().as_str().to_string());
+	}
+}
+
+/// Collect the definitions instantiated and packages imported by the items
+fn uses(items: &[Spanned<Item>], names: &mut BTreeSet<String>) {
+	for item in items {
+		item_uses(item.inner(), names);
+	}
+}
+
+/// Collect the definitions instantiated and packages imported by a single item
+fn item_uses(item: &Item, names: &mut BTreeSet<String>) {
+	match &item.kind {
+		ItemKind::Instantiation(instantiation) => {
+			names.insert(instantiation.module.inner().as_str().to_string());
+		},
+		ItemKind::Bind(bind) => {
+			names.insert(bind.instantiation.module.inner().as_str().to_string());
+		},
+		ItemKind::Import(items) => imports(items, names),
+		ItemKind::GenerateRegion(items) => uses(items, names),
+		ItemKind::GenerateBlock(block) => uses(&block.items, names),
+		ItemKind::GenerateFor(generate) => uses(&generate.block.inner().items, names),
+		ItemKind::GenerateIf(generate) => {
+			for block in [&generate.if_true, &generate.if_false]
+				.into_iter()
+				.flatten()
+			{
+				uses(&block.inner().items, names);
+			}
+		},
+		ItemKind::GenerateCase(generate) => {
+			for item in &generate.items {
+				if let Some(block) = &item.inner().block {
+					uses(&block.inner().items, names);
+				}
+			}
+		},
+		_ => (),
+	}
+}
+
+/// The text macros defined, used, and tested by the compiler directives of a file
+///
+/// The name after a `` `define ``, `` `undef ``, `` `ifdef ``, `` `ifndef ``, or `` `elsif `` is
+/// taken to be a macro name, along with any other `` `name `` that isn't a builtin directive.
+pub(crate) fn macros(ast: &VerilogAst) -> impl Iterator<Item = MacroName> + '_ {
+	let mut directive = None;
+
+	ast.syntax().tokens().filter_map(move |token| {
+		match token.token() {
+			Token::Whitespace(_) => return None,
+			Token::CompilerDirective(CompilerDirective::TextMacro(TextMacro::Other(name))) => {
+				directive = None;
+				return Some(MacroName {
+					name:    String::from_utf8_lossy(name).into_owned(),
+					span:    *token.span(),
+					defined: false,
+				});
+			},
+			Token::Identifier(ident) if let Some(defined) = directive.take() => {
+				return Some(MacroName {
+					name: ident.as_str().to_string(),
+					span: *token.span(),
+					defined,
+				});
+			},
+			_ => (),
+		}
+
+		directive = match token.token() {
+			Token::CompilerDirective(CompilerDirective::Builtin(BuiltinDirective::Define)) => {
+				Some(true)
+			},
+			Token::CompilerDirective(CompilerDirective::Builtin(
+				BuiltinDirective::Undef |
+				BuiltinDirective::IfDef |
+				BuiltinDirective::IfNotDef |
+				BuiltinDirective::ElsIf,
+			)) => Some(false),
+			_ => None,
+		};
+		None
+	})
+}
@@ -0,0 +1,212 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::path::Path;
+
+use vermilion_verilog::LanguageStd as VerilogStd;
+use vermilion_vhdl::LanguageStd as VhdlStd;
+
+use super::*;
+
+const LANGUAGE: Language = Language::Verilog(VerilogStd::Sv17);
+
+const TOP: &str = "module top;
+	core u_core();
+endmodule
+";
+
+const CORE: &str = "module core;
+	wire a;
+	assign a = 1'b0;
+endmodule
+";
+
+const OTHER: &str = "package other;
+	typedef logic [7:0] byte_t;
+endpackage
+";
+
+/// Replace the first `find` in the text of `file` with `replace`
+fn edit(db: &mut Database, file: FileId, find: &str, replace: &str) {
+	let text = String::from_utf8_lossy(db.text(file).map_or(&[][..], |text| &text[..])).to_string();
+	let Some(begin) = text.find(find) else {
+		panic!("`{find}` not found in the text");
+	};
+
+	let edited = format!("{}{replace}{}", &text[..begin], &text[begin + find.len()..]);
+	let edit = TextEdit {
+		begin:  begin as u32,
+		end:    (begin + find.len()) as u32,
+		length: replace.len() as u32,
+	};
+
+	db.edit_text(file, edited.as_bytes().into(), edit);
+}
+
+fn workspace() -> (Database, [FileId; 3]) {
+	let mut db = Database::new();
+	let files = [TOP, CORE, OTHER].map(|text| db.add_file(LANGUAGE, text.as_bytes().into()));
+
+	(db, files)
+}
+
+#[test]
+fn test_memoized() {
+	let (mut db, [top, ..]) = workspace();
+
+	assert!(db.parse(top).is_some());
+	assert!(db.scopes(top).is_some());
+	assert!(db.scopes(top).is_some());
+
+	assert_eq!(db.executions(Key::Parse(top)), 1);
+	assert_eq!(db.executions(Key::Scopes(top)), 1);
+}
+
+#[test]
+fn test_related_files() {
+	let (mut db, [top, core, other]) = workspace();
+
+	assert_eq!(db.related_files(top), [top, core]);
+	assert_eq!(db.related_files(core), [core]);
+	assert_eq!(db.definitions().files("other"), [other]);
+
+	edit(&mut db, top, "endmodule", "\timport other::*;\nendmodule");
+	assert_eq!(db.related_files(top), [top, core, other]);
+
+	db.remove_file(core);
+	assert_eq!(db.related_files(top), [top, other]);
+	assert!(db.definitions().files("core").is_empty());
+}
+
+#[test]
+fn test_invalidation() {
+	let (mut db, [top, core, other]) = workspace();
+	assert!(db.scopes(top).is_some());
+
+	// Editing a file nothing uses doesn't touch anything derived from the others
+	edit(&mut db, other, "byte_t", "octet_t");
+	assert!(db.scopes(top).is_some());
+	assert_eq!(db.executions(Key::ItemTree(other)), 2);
+	assert_eq!(db.executions(Key::Definitions), 1);
+	assert_eq!(db.executions(Key::Scopes(top)), 1);
+
+	// Editing the body of a module re-runs what uses its syntax, but not the definitions index
+	edit(&mut db, core, "wire a;", "wire a, b;");
+	assert!(db.scopes(top).is_some());
+	assert_eq!(db.executions(Key::ItemTree(core)), 2);
+	assert_eq!(db.executions(Key::Definitions), 1);
+	assert_eq!(db.executions(Key::RelatedFiles(top)), 1);
+	assert_eq!(db.executions(Key::Scopes(top)), 2);
+
+	// Renaming a definition does change the index
+	edit(&mut db, other, "package other", "package another");
+	assert!(db.scopes(top).is_some());
+	assert_eq!(db.executions(Key::Definitions), 2);
+	assert_eq!(db.executions(Key::RelatedFiles(top)), 2);
+	assert_eq!(db.executions(Key::Scopes(top)), 2);
+}
+
+#[test]
+fn test_incremental_parse() {
+	let (mut db, [_, core, _]) = workspace();
+	assert!(db.parse(core).is_some());
+
+	// Several edits between parses are all caught up on at once
+	edit(&mut db, core, "wire a;", "wire a;\n\twire b;");
+	edit(&mut db, core, "1'b0", "b");
+	assert!(db.parse(core).is_some());
+	edit(
+		&mut db,
+		core,
+		"endmodule\n",
+		"endmodule\n\nmodule extra;\nendmodule\n",
+	);
+
+	let text = db.text(core).cloned().unwrap_or_default();
+	let expected = LANGUAGE.parse_file_at(Path::new(""), text, &Sources::default(), &[]);
+	match (db.parse(core), expected) {
+		(Some(Ast::Verilog(ast)), Ast::Verilog(expected)) => assert_eq!(*ast, expected),
+		_ => panic!("Expected a Verilog syntax tree"),
+	}
+	assert_eq!(db.executions(Key::Parse(core)), 3);
+}
+
+#[test]
+fn test_parsed_file() {
+	let (mut db, [top, ..]) = workspace();
+	let ast = LANGUAGE.parse_file_at(
+		Path::new(""),
+		OTHER.as_bytes().into(),
+		&Sources::default(),
+		&[],
+	);
+	let file = db.add_parsed_file("other.sv".into(), LANGUAGE, OTHER.as_bytes().into(), ast);
+
+	// The syntax tree it was added with is used as-is until the text changes
+	assert!(db.parse(file).is_some());
+	assert_eq!(db.executions(Key::Parse(file)), 0);
+	assert_eq!(db.definitions().files("other").len(), 2);
+	assert!(db.scopes(top).is_some());
+
+	edit(&mut db, file, "package other", "package another");
+	assert_eq!(db.definitions().files("another"), [file]);
+	assert_eq!(db.executions(Key::Parse(file)), 1);
+}
+
+#[test]
+fn test_vhdl_definitions() {
+	let mut db = Database::new();
+	let file = db.add_file(
+		Language::Vhdl(VhdlStd::Vh08),
+		"entity Counter is\nend entity;\n\narchitecture rtl of counter is\nbegin\nend \
+		 architecture;\n\npackage Util is\nend package;\n"
+			.as_bytes()
+			.into(),
+	);
+
+	assert_eq!(db.definitions().files("counter"), [file]);
+	assert_eq!(db.definitions().files("util"), [file]);
+	assert!(db.definitions().files("rtl").is_empty());
+}
+
+#[test]
+fn test_sources() {
+	let mut db = Database::new();
+	let file = db.add_file(
+		LANGUAGE,
+		"`ifdef FAST\nmodule fast;\n`else\nmodule slow;\n`endif\nendmodule\n"
+			.as_bytes()
+			.into(),
+	);
+	assert_eq!(db.definitions().files("slow"), [file]);
+
+	// The file is parsed again in full with the macros from the command files, even with an edit
+	// waiting to be caught up on
+	edit(&mut db, file, "endmodule", "endmodule // done");
+	let mut sources = Sources::default();
+	sources
+		.command_file
+		.defines
+		.push(("FAST".to_string(), String::new()));
+	db.set_sources(sources);
+
+	assert_eq!(db.definitions().files("fast"), [file]);
+	assert!(db.definitions().files("slow").is_empty());
+}
+
+#[test]
+fn test_include() {
+	let mut db = Database::new();
+	let header = db.add_file(LANGUAGE, "module from_header;\nendmodule\n".as_bytes().into());
+	db.set_path(header, "/work/defs.vh".into());
+	let top = db.add_file(LANGUAGE, "`include \"defs.vh\"\n".as_bytes().into());
+	db.set_path(top, "/work/top.sv".into());
+
+	// The header isn't on disk, so it can only have come from the database
+	assert_eq!(db.definitions().files("from_header"), [header, top]);
+
+	// Unsaved changes to the header are seen by the file including it
+	edit(&mut db, header, "from_header", "renamed");
+	assert_eq!(db.definitions().files("renamed"), [header, top]);
+	assert!(db.definitions().files("from_header").is_empty());
+	assert_eq!(db.executions(Key::Parse(top)), 2);
+}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Helpers for writing the texts the tests of the language features run on
+
+/// The offset of the `nth` occurrence of `find` in `text`
+pub(super) fn offset(text: &str, find: &str, nth: usize) -> u32 {
+	let Some((offset, _)) = text.match_indices(find).nth(nth) else {
+		panic!("`{find}` not found {} times in the text", nth + 1);
+	};
+
+	offset as u32
+}
+
+/// The text without the `|` marking where the cursor is, along with the offset of the cursor
+pub(super) fn cursor(text: &str) -> (String, u32) {
+	let Some(offset) = text.find('|') else {
+		panic!("No cursor in the text");
+	};
+
+	(text.replacen('|', "", 1), offset as u32)
+}
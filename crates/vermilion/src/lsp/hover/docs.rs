@@ -0,0 +1,775 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Short descriptions of the builtin system functions, keywords, and compiler directives
+//!
+//! These are paraphrased from the LRMs, and are only meant to jog the memory of what something
+//! does, the clause they are from is given so the full description can be found.
+
+use vermilion_verilog::lang::{
+	directives::BuiltinDirective, keywords::Keyword, sysfuncs::BuiltinSysFunc,
+};
+
+/// A description of a builtin and where it is described in full
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) struct Doc {
+	pub summary:   &'static str,
+	pub reference: &'static str,
+}
+
+const fn doc(summary: &'static str, reference: &'static str) -> Doc {
+	Doc { summary, reference }
+}
+
+pub(super) fn sysfunc(sysfunc: BuiltinSysFunc) -> Doc {
+	match sysfunc {
+		BuiltinSysFunc::Display |
+		BuiltinSysFunc::DisplayB |
+		BuiltinSysFunc::DisplayH |
+		BuiltinSysFunc::DisplayO => doc(
+			"Writes its arguments to the standard output followed by a newline, numbers default \
+			 to decimal, or the binary, hexadecimal, or octal of the suffix.",
+			"IEEE 1800-2017 § 21.2.1",
+		),
+		BuiltinSysFunc::Write |
+		BuiltinSysFunc::WriteB |
+		BuiltinSysFunc::WriteH |
+		BuiltinSysFunc::WriteO => doc(
+			"Writes its arguments to the standard output without a trailing newline.",
+			"IEEE 1800-2017 § 21.2.1",
+		),
+		BuiltinSysFunc::Strobe |
+		BuiltinSysFunc::StrobeB |
+		BuiltinSysFunc::StrobeH |
+		BuiltinSysFunc::StrobeO => doc(
+			"Displays its arguments at the end of the current time step, once every other event \
+			 in it has been processed.",
+			"IEEE 1800-2017 § 21.2.2",
+		),
+		BuiltinSysFunc::Monitor |
+		BuiltinSysFunc::MonitorB |
+		BuiltinSysFunc::MonitorH |
+		BuiltinSysFunc::MonitorO => doc(
+			"Displays its arguments whenever any of them change value, only one monitor is active \
+			 at a time.",
+			"IEEE 1800-2017 § 21.2.3",
+		),
+		BuiltinSysFunc::MonitorOff | BuiltinSysFunc::MonitorOn => doc(
+			"Disables or re-enables the active `$monitor`.",
+			"IEEE 1800-2017 § 21.2.3",
+		),
+		BuiltinSysFunc::Fopen => doc(
+			"Opens a file, returning a file descriptor, or `0` if it could not be opened.",
+			"IEEE 1800-2017 § 21.3.1",
+		),
+		BuiltinSysFunc::Fclose => doc(
+			"Closes a file or the channels of a multichannel descriptor.",
+			"IEEE 1800-2017 § 21.3.1",
+		),
+		BuiltinSysFunc::Fdisplay |
+		BuiltinSysFunc::FdisplayB |
+		BuiltinSysFunc::FdisplayH |
+		BuiltinSysFunc::FdisplayO |
+		BuiltinSysFunc::Fwrite |
+		BuiltinSysFunc::FwriteB |
+		BuiltinSysFunc::FwriteH |
+		BuiltinSysFunc::FwriteO |
+		BuiltinSysFunc::Fstrobe |
+		BuiltinSysFunc::FstrobeB |
+		BuiltinSysFunc::FstrobeH |
+		BuiltinSysFunc::FstrobeO |
+		BuiltinSysFunc::Fmonitor |
+		BuiltinSysFunc::FmonitorB |
+		BuiltinSysFunc::FmonitorH |
+		BuiltinSysFunc::FmonitorO => doc(
+			"The same as the matching display task, writing to the file descriptor given as the \
+			 first argument.",
+			"IEEE 1800-2017 § 21.3.2",
+		),
+		BuiltinSysFunc::Swrite |
+		BuiltinSysFunc::SwriteB |
+		BuiltinSysFunc::SwriteH |
+		BuiltinSysFunc::SwriteO => doc(
+			"Formats its arguments as `$write` does, into the string variable given as the first \
+			 argument.",
+			"IEEE 1800-2017 § 21.3.3",
+		),
+		BuiltinSysFunc::Sformat => doc(
+			"Formats its arguments according to the format string, into the string variable given \
+			 as the first argument.",
+			"IEEE 1800-2017 § 21.3.3",
+		),
+		BuiltinSysFunc::Sformatf => doc(
+			"Returns its arguments formatted according to the format string.",
+			"IEEE 1800-2017 § 21.3.3",
+		),
+		BuiltinSysFunc::Fgetc => doc(
+			"Reads a single character from a file, returning `EOF` on an error.",
+			"IEEE 1800-2017 § 21.3.4",
+		),
+		BuiltinSysFunc::Ungetc => doc(
+			"Pushes a character back onto a file to be read again.",
+			"IEEE 1800-2017 § 21.3.4",
+		),
+		BuiltinSysFunc::Fgets => doc(
+			"Reads a line from a file into a variable, returning the number of characters read.",
+			"IEEE 1800-2017 § 21.3.4",
+		),
+		BuiltinSysFunc::Fscanf | BuiltinSysFunc::Sscanf => doc(
+			"Reads formatted data from a file or string into its arguments, returning the number \
+			 of items read.",
+			"IEEE 1800-2017 § 21.3.4",
+		),
+		BuiltinSysFunc::Fread => doc(
+			"Reads binary data from a file into a variable or memory.",
+			"IEEE 1800-2017 § 21.3.4",
+		),
+		BuiltinSysFunc::Ftell | BuiltinSysFunc::Fseek | BuiltinSysFunc::Rewind => doc(
+			"Gets or sets the position in a file that the next read or write happens at.",
+			"IEEE 1800-2017 § 21.3.5",
+		),
+		BuiltinSysFunc::Fflush => doc(
+			"Writes out any buffered output of a file, or of every open file.",
+			"IEEE 1800-2017 § 21.3.6",
+		),
+		BuiltinSysFunc::Ferror => doc(
+			"Returns the error code of the most recent file I/O operation, and a description of \
+			 it.",
+			"IEEE 1800-2017 § 21.3.7",
+		),
+		BuiltinSysFunc::Feof => doc(
+			"Returns non-zero once the end of a file has been reached.",
+			"IEEE 1800-2017 § 21.3.8",
+		),
+		BuiltinSysFunc::ReadmemB | BuiltinSysFunc::ReadmemH => doc(
+			"Loads a memory from a text file of binary or hexadecimal values.",
+			"IEEE 1800-2017 § 21.4",
+		),
+		BuiltinSysFunc::WritememB | BuiltinSysFunc::WritememH => doc(
+			"Dumps the contents of a memory to a text file as binary or hexadecimal values.",
+			"IEEE 1800-2017 § 21.5",
+		),
+		BuiltinSysFunc::TestPlusArgs => doc(
+			"Returns non-zero if a `+` argument starting with the given string was passed to the \
+			 simulator.",
+			"IEEE 1800-2017 § 21.6",
+		),
+		BuiltinSysFunc::ValuePlusArgs => doc(
+			"Finds a `+` argument matching the format string and converts the rest of it into a \
+			 variable.",
+			"IEEE 1800-2017 § 21.6",
+		),
+		BuiltinSysFunc::DumpFile |
+		BuiltinSysFunc::DumpVars |
+		BuiltinSysFunc::DumpOff |
+		BuiltinSysFunc::DumpOn |
+		BuiltinSysFunc::DumpAll |
+		BuiltinSysFunc::DumpLimit |
+		BuiltinSysFunc::DumpFlush => doc(
+			"Controls dumping value changes to a four-state VCD file.",
+			"IEEE 1800-2017 § 21.7.1",
+		),
+		BuiltinSysFunc::DumpPorts |
+		BuiltinSysFunc::DumpPortsOff |
+		BuiltinSysFunc::DumpPortsOn |
+		BuiltinSysFunc::DumpPortsAll |
+		BuiltinSysFunc::DumpPortsLimit |
+		BuiltinSysFunc::DumpPortsFlush => doc(
+			"Controls dumping the values and strengths of ports to an extended VCD file.",
+			"IEEE 1800-2017 § 21.7.3",
+		),
+		BuiltinSysFunc::Finish => doc(
+			"Ends the simulation, the argument selects how much diagnostic information is printed.",
+			"IEEE 1800-2017 § 20.2",
+		),
+		BuiltinSysFunc::Stop => doc(
+			"Suspends the simulation, handing control to the interactive prompt of the simulator.",
+			"IEEE 1800-2017 § 20.2",
+		),
+		BuiltinSysFunc::Exit => doc(
+			"Ends the program it is called from, ending the simulation once every program has \
+			 ended.",
+			"IEEE 1800-2017 § 24.7",
+		),
+		BuiltinSysFunc::Time => doc(
+			"Returns the current simulation time as a 64-bit integer, rounded to the time unit of \
+			 the module it is called from.",
+			"IEEE 1800-2017 § 20.3",
+		),
+		BuiltinSysFunc::Stime => doc(
+			"Returns the current simulation time as a 32-bit unsigned integer.",
+			"IEEE 1800-2017 § 20.3",
+		),
+		BuiltinSysFunc::RealTime => doc(
+			"Returns the current simulation time as a real number in the time unit of the module \
+			 it is called from.",
+			"IEEE 1800-2017 § 20.3",
+		),
+		BuiltinSysFunc::PrintTimescale => doc(
+			"Displays the time unit and precision of a module.",
+			"IEEE 1800-2017 § 20.4",
+		),
+		BuiltinSysFunc::TimeFormat => doc(
+			"Sets how the `%t` format specifier displays times.",
+			"IEEE 1800-2017 § 20.4",
+		),
+		BuiltinSysFunc::TimeUnit | BuiltinSysFunc::TimePrecision => doc(
+			"Returns the time unit or precision of a module as a power of ten.",
+			"IEEE 1800-2023 § 20.4",
+		),
+		BuiltinSysFunc::Rtoi => doc(
+			"Converts a real value to an integer, truncating it.",
+			"IEEE 1800-2017 § 20.5",
+		),
+		BuiltinSysFunc::Itor => doc(
+			"Converts an integer value to a real.",
+			"IEEE 1800-2017 § 20.5",
+		),
+		BuiltinSysFunc::RealToBits |
+		BuiltinSysFunc::BitsToReal |
+		BuiltinSysFunc::ShortRealToBits |
+		BuiltinSysFunc::BitsToShortReal => doc(
+			"Converts between a real value and the bits of its IEEE 754 representation.",
+			"IEEE 1800-2017 § 20.5",
+		),
+		BuiltinSysFunc::Signed | BuiltinSysFunc::Unsigned => doc(
+			"Returns the value of the argument as signed or unsigned, keeping its width.",
+			"IEEE 1800-2017 § 11.7",
+		),
+		BuiltinSysFunc::Cast => doc(
+			"Assigns a value to a variable of a different type, checking the value is valid for \
+			 it.",
+			"IEEE 1800-2017 § 6.24.2",
+		),
+		BuiltinSysFunc::TypeName => doc(
+			"Returns a string describing the resolved type of its argument.",
+			"IEEE 1800-2017 § 20.6.1",
+		),
+		BuiltinSysFunc::Bits => doc(
+			"Returns the number of bits needed to hold the value of an expression or data type.",
+			"IEEE 1800-2017 § 20.6.2",
+		),
+		BuiltinSysFunc::IsUnbounded => doc(
+			"Returns whether a constant is `$`, the unbounded value of a parameter.",
+			"IEEE 1800-2017 § 20.6.3",
+		),
+		BuiltinSysFunc::Left |
+		BuiltinSysFunc::Right |
+		BuiltinSysFunc::Low |
+		BuiltinSysFunc::High |
+		BuiltinSysFunc::Increment |
+		BuiltinSysFunc::Size => doc(
+			"Returns the bounds, direction, or number of elements of a dimension of an array.",
+			"IEEE 1800-2017 § 20.7",
+		),
+		BuiltinSysFunc::Dimensions | BuiltinSysFunc::UnpackedDimensions => doc(
+			"Returns the number of dimensions, or unpacked dimensions, of an array.",
+			"IEEE 1800-2017 § 20.7",
+		),
+		BuiltinSysFunc::Clog2 => doc(
+			"Returns the ceiling of the log base 2 of its argument, the number of address bits \
+			 needed for that many elements.",
+			"IEEE 1800-2017 § 20.8.1",
+		),
+		BuiltinSysFunc::Ln |
+		BuiltinSysFunc::Log10 |
+		BuiltinSysFunc::Exp |
+		BuiltinSysFunc::Sqrt |
+		BuiltinSysFunc::Pow |
+		BuiltinSysFunc::Floor |
+		BuiltinSysFunc::Ceil |
+		BuiltinSysFunc::Sin |
+		BuiltinSysFunc::Cos |
+		BuiltinSysFunc::Tan |
+		BuiltinSysFunc::Asin |
+		BuiltinSysFunc::Acos |
+		BuiltinSysFunc::Atan |
+		BuiltinSysFunc::Atan2 |
+		BuiltinSysFunc::Hypot |
+		BuiltinSysFunc::Sinh |
+		BuiltinSysFunc::Cosh |
+		BuiltinSysFunc::Tanh |
+		BuiltinSysFunc::Asinh |
+		BuiltinSysFunc::Acosh |
+		BuiltinSysFunc::Atanh => doc(
+			"The real math function of the same name from the C standard library.",
+			"IEEE 1800-2017 § 20.8.2",
+		),
+		BuiltinSysFunc::CountBits => doc(
+			"Returns the number of bits of an expression that have any of the given values.",
+			"IEEE 1800-2017 § 20.9",
+		),
+		BuiltinSysFunc::CountOnes => doc(
+			"Returns the number of bits of an expression that are `1`.",
+			"IEEE 1800-2017 § 20.9",
+		),
+		BuiltinSysFunc::OneHot => doc(
+			"Returns whether exactly one bit of an expression is `1`.",
+			"IEEE 1800-2017 § 20.9",
+		),
+		BuiltinSysFunc::OneHot0 => doc(
+			"Returns whether at most one bit of an expression is `1`.",
+			"IEEE 1800-2017 § 20.9",
+		),
+		BuiltinSysFunc::IsUnknown => doc(
+			"Returns whether any bit of an expression is `x` or `z`.",
+			"IEEE 1800-2017 § 20.9",
+		),
+		BuiltinSysFunc::Fatal |
+		BuiltinSysFunc::Error |
+		BuiltinSysFunc::Warning |
+		BuiltinSysFunc::Info => doc(
+			"Reports a message with the severity of its name, `$fatal` also ends the simulation.",
+			"IEEE 1800-2017 § 20.10",
+		),
+		BuiltinSysFunc::AssertControl |
+		BuiltinSysFunc::AssertOn |
+		BuiltinSysFunc::AssertOff |
+		BuiltinSysFunc::AssertKill |
+		BuiltinSysFunc::AssertPassOn |
+		BuiltinSysFunc::AssertPassOff |
+		BuiltinSysFunc::AssertFailOn |
+		BuiltinSysFunc::AssertFailOff |
+		BuiltinSysFunc::AssertNonVacuousOn |
+		BuiltinSysFunc::AssertVacuousOff => doc(
+			"Controls whether assertions are checked and which of their action blocks run.",
+			"IEEE 1800-2017 § 20.12",
+		),
+		BuiltinSysFunc::Sampled => doc(
+			"Returns the value of an expression sampled in the preponed region of the time step.",
+			"IEEE 1800-2017 § 16.9.3",
+		),
+		BuiltinSysFunc::Rose |
+		BuiltinSysFunc::Fell |
+		BuiltinSysFunc::Stable |
+		BuiltinSysFunc::Changed => doc(
+			"Returns whether the sampled value of an expression rose, fell, was stable, or \
+			 changed since the previous clock tick.",
+			"IEEE 1800-2017 § 16.9.3",
+		),
+		BuiltinSysFunc::Past => doc(
+			"Returns the sampled value of an expression a number of clock ticks in the past.",
+			"IEEE 1800-2017 § 16.9.3",
+		),
+		BuiltinSysFunc::PastGclk |
+		BuiltinSysFunc::RoseGclk |
+		BuiltinSysFunc::FellGclk |
+		BuiltinSysFunc::StableGclk |
+		BuiltinSysFunc::ChangedGclk |
+		BuiltinSysFunc::FutureGclk |
+		BuiltinSysFunc::RisingGclk |
+		BuiltinSysFunc::FallingGclk |
+		BuiltinSysFunc::SteadyGclk |
+		BuiltinSysFunc::ChangingGclk => doc(
+			"A sampled value function of the global clock.",
+			"IEEE 1800-2017 § 16.9.4",
+		),
+		BuiltinSysFunc::CoverageControl |
+		BuiltinSysFunc::CoverageGetMax |
+		BuiltinSysFunc::CoverageGet |
+		BuiltinSysFunc::CoverageMerge |
+		BuiltinSysFunc::CoverageSave => doc(
+			"Controls and queries the coverage of the design hierarchy.",
+			"IEEE 1800-2017 § 20.14",
+		),
+		BuiltinSysFunc::GetCoverage |
+		BuiltinSysFunc::SetCoverageDbName |
+		BuiltinSysFunc::LoadCoverageDB |
+		BuiltinSysFunc::SetCoverageDb => doc(
+			"Queries the overall coverage, or names the coverage database.",
+			"IEEE 1800-2017 § 19.9",
+		),
+		BuiltinSysFunc::Random => doc(
+			"Returns a new signed 32-bit random number each time it is called.",
+			"IEEE 1800-2017 § 20.15.1",
+		),
+		BuiltinSysFunc::DistUniform |
+		BuiltinSysFunc::DistNormal |
+		BuiltinSysFunc::DistExponential |
+		BuiltinSysFunc::DistPoisson |
+		BuiltinSysFunc::DistChiSquare |
+		BuiltinSysFunc::DistT |
+		BuiltinSysFunc::DistErlang => doc(
+			"Returns a random number from the probability distribution of its name.",
+			"IEEE 1800-2017 § 20.15.2",
+		),
+		BuiltinSysFunc::Urandom | BuiltinSysFunc::UrandomRange => doc(
+			"Returns a new unsigned 32-bit random number, within the given range for \
+			 `$urandom_range`.",
+			"IEEE 1800-2017 § 18.13",
+		),
+		BuiltinSysFunc::QInitialize |
+		BuiltinSysFunc::QAdd |
+		BuiltinSysFunc::QRemove |
+		BuiltinSysFunc::QFull |
+		BuiltinSysFunc::QExam => doc(
+			"Creates and manages a queue for stochastic queueing models.",
+			"IEEE 1800-2017 § 20.16",
+		),
+		BuiltinSysFunc::AsyncAndArray |
+		BuiltinSysFunc::AsyncAndPlane |
+		BuiltinSysFunc::AsyncNandArray |
+		BuiltinSysFunc::AsyncNandPlane |
+		BuiltinSysFunc::AsyncOrArray |
+		BuiltinSysFunc::AsyncOrPlane |
+		BuiltinSysFunc::AsyncNorArray |
+		BuiltinSysFunc::AsyncNorPlane |
+		BuiltinSysFunc::SyncAndArray |
+		BuiltinSysFunc::SyncAndPlane |
+		BuiltinSysFunc::SyncNandArray |
+		BuiltinSysFunc::SyncNandPlane |
+		BuiltinSysFunc::SyncOrArray |
+		BuiltinSysFunc::SyncOrPlane |
+		BuiltinSysFunc::SyncNorArray |
+		BuiltinSysFunc::SyncNorPlane => doc(
+			"Models a programmable logic array from a memory of its personality.",
+			"IEEE 1800-2017 § 20.17",
+		),
+		BuiltinSysFunc::System => doc(
+			"Runs a shell command, returning its exit status.",
+			"IEEE 1800-2017 § 20.18",
+		),
+		BuiltinSysFunc::Stacktrace => doc(
+			"Prints, or returns as a string, the call stack of where it is called from.",
+			"IEEE 1800-2023 § 20.18",
+		),
+		BuiltinSysFunc::Setup |
+		BuiltinSysFunc::Hold |
+		BuiltinSysFunc::Setuphold |
+		BuiltinSysFunc::Recovery => doc(
+			"A timing check of a data event against a reference event over a stability window.",
+			"IEEE 1800-2017 § 31.3",
+		),
+		BuiltinSysFunc::Skew |
+		BuiltinSysFunc::Period |
+		BuiltinSysFunc::Width |
+		BuiltinSysFunc::NoChange => doc(
+			"A timing check of a clock or control signal.",
+			"IEEE 1800-2017 § 31.4",
+		),
+		BuiltinSysFunc::SdfAnnotate => doc(
+			"Back-annotates the delays and timing checks of the design from an SDF file.",
+			"IEEE 1800-2017 § 32.7",
+		),
+		BuiltinSysFunc::CountDrivers |
+		BuiltinSysFunc::GetPattern |
+		BuiltinSysFunc::IncSave |
+		BuiltinSysFunc::Input |
+		BuiltinSysFunc::Key |
+		BuiltinSysFunc::List |
+		BuiltinSysFunc::Log |
+		BuiltinSysFunc::NoKey |
+		BuiltinSysFunc::NoLog |
+		BuiltinSysFunc::Reset |
+		BuiltinSysFunc::ResetCount |
+		BuiltinSysFunc::ResetValue |
+		BuiltinSysFunc::Restart |
+		BuiltinSysFunc::Save |
+		BuiltinSysFunc::Scale |
+		BuiltinSysFunc::Scope |
+		BuiltinSysFunc::Showscopes |
+		BuiltinSysFunc::Showvars |
+		BuiltinSysFunc::SreadmemB |
+		BuiltinSysFunc::SreadmemH => doc(
+			"An optional system task, that tools are not required to support.",
+			"IEEE 1800-2017 Annex D",
+		),
+		BuiltinSysFunc::Temperature |
+		BuiltinSysFunc::Vt |
+		BuiltinSysFunc::SimParam |
+		BuiltinSysFunc::SimParamStr |
+		BuiltinSysFunc::SimProbe => doc(
+			"Returns a value of the circuit simulation environment.",
+			"Verilog-AMS 2.4 § 9.15",
+		),
+		BuiltinSysFunc::TableModel => doc(
+			"Interpolates a value from a table of samples given inline or in a file.",
+			"Verilog-AMS 2.4 § 9.21",
+		),
+		BuiltinSysFunc::AnalogNodeAlias | BuiltinSysFunc::AnalogPortAlias => doc(
+			"Aliases a local node to a node or port given by its hierarchical name.",
+			"Verilog-AMS 2.4 § 9.20",
+		),
+		BuiltinSysFunc::ReceiverCount => doc(
+			"Returns the number of receivers of a digital signal.",
+			"Verilog-AMS 2.4 § 7.4",
+		),
+		BuiltinSysFunc::XPosition | BuiltinSysFunc::YPosition | BuiltinSysFunc::Vflip => doc(
+			"Returns the placement of an instance on the layout.",
+			"Verilog-AMS 2.4 § 9.18",
+		),
+	}
+}
+
+pub(super) fn directive(directive: BuiltinDirective) -> Doc {
+	match directive {
+		BuiltinDirective::ResetAll => doc(
+			"Resets every compiler directive to its default.",
+			"IEEE 1800-2017 § 22.3",
+		),
+		BuiltinDirective::Include => doc(
+			"Inserts the contents of a file in place of the directive.",
+			"IEEE 1800-2017 § 22.4",
+		),
+		BuiltinDirective::Define => doc(
+			"Defines a text macro, optionally with arguments, that is substituted wherever it is \
+			 used.",
+			"IEEE 1800-2017 § 22.5.1",
+		),
+		BuiltinDirective::Undef => doc("Undefines a text macro.", "IEEE 1800-2017 § 22.5.2"),
+		BuiltinDirective::UndefineAll => doc(
+			"Undefines every text macro defined so far.",
+			"IEEE 1800-2017 § 22.5.3",
+		),
+		BuiltinDirective::IfDef |
+		BuiltinDirective::IfNotDef |
+		BuiltinDirective::ElsIf |
+		BuiltinDirective::Else |
+		BuiltinDirective::EndIf => doc(
+			"Conditionally compiles the source text depending on whether a text macro is defined.",
+			"IEEE 1800-2017 § 22.6",
+		),
+		BuiltinDirective::TimeScale => doc(
+			"Sets the time unit and precision of the modules that follow.",
+			"IEEE 1800-2017 § 22.7",
+		),
+		BuiltinDirective::DefaultNetType => doc(
+			"Sets the type of implicitly declared nets, or forbids them with `none`.",
+			"IEEE 1800-2017 § 22.8",
+		),
+		BuiltinDirective::UnconnectedDrive | BuiltinDirective::NoUnconnectedDrive => doc(
+			"Pulls the unconnected input ports of the modules that follow up or down.",
+			"IEEE 1800-2017 § 22.9",
+		),
+		BuiltinDirective::CellDefine | BuiltinDirective::EndCellDefine => doc(
+			"Marks the modules between them as cells, for the PLI and delay calculation.",
+			"IEEE 1800-2017 § 22.10",
+		),
+		BuiltinDirective::Pragma => doc(
+			"Passes information to the tools, its meaning depends on the name following it.",
+			"IEEE 1800-2017 § 22.11",
+		),
+		BuiltinDirective::Line => doc(
+			"Sets the file name and line number reported for the source text that follows.",
+			"IEEE 1800-2017 § 22.12",
+		),
+		BuiltinDirective::BeginKeywords | BuiltinDirective::EndKeywords => doc(
+			"Sets which standard's reserved keywords the source text between them uses.",
+			"IEEE 1800-2017 § 22.14",
+		),
+		BuiltinDirective::DefaultDecayTime |
+		BuiltinDirective::DefaultTriRegStrength |
+		BuiltinDirective::DelayModeDistributed |
+		BuiltinDirective::DelayModePath |
+		BuiltinDirective::DelayModeUnit |
+		BuiltinDirective::DelayModeZero => doc(
+			"An optional compiler directive, that tools are not required to support.",
+			"IEEE 1800-2017 Annex E",
+		),
+		BuiltinDirective::DefaultDiscipline => doc(
+			"Sets the discipline of nets declared without one.",
+			"Verilog-AMS 2.4 § 10.2",
+		),
+		BuiltinDirective::DefaultTransition => doc(
+			"Sets the default rise and fall time of the `transition` analog operator.",
+			"Verilog-AMS 2.4 § 10.3",
+		),
+	}
+}
+
+/// The keywords that are worth describing, most need no more than the standard they were added
+/// in
+pub(super) fn keyword(keyword: Keyword) -> Option<Doc> {
+	Some(match keyword {
+		Keyword::Module | Keyword::EndModule => doc(
+			"Declares a module, the basic building block of a design.",
+			"IEEE 1800-2017 § 23.2",
+		),
+		Keyword::Interface | Keyword::EndInterface => doc(
+			"Declares an interface, a bundle of signals and the functionality to use them.",
+			"IEEE 1800-2017 § 25",
+		),
+		Keyword::Package | Keyword::EndPackage => doc(
+			"Declares a package of declarations to share between modules.",
+			"IEEE 1800-2017 § 26",
+		),
+		Keyword::Program | Keyword::EndProgram => doc(
+			"Declares a program, a testbench that runs in the reactive region.",
+			"IEEE 1800-2017 § 24",
+		),
+		Keyword::Class | Keyword::EndClass => doc("Declares a class.", "IEEE 1800-2017 § 8"),
+		Keyword::Wire | Keyword::Tri => doc(
+			"A net driven by continuous assignments, whose value is resolved from its drivers.",
+			"IEEE 1800-2017 § 6.6.1",
+		),
+		Keyword::Uwire => doc(
+			"A net that may only have a single driver.",
+			"IEEE 1800-2017 § 6.6.2",
+		),
+		Keyword::Wand | Keyword::Triand | Keyword::Wor | Keyword::Trior => doc(
+			"A net whose drivers are resolved with a wired and, or a wired or.",
+			"IEEE 1800-2017 § 6.6.3",
+		),
+		Keyword::Trireg => doc(
+			"A net that stores a value when none of its drivers are driving it.",
+			"IEEE 1800-2017 § 6.6.4",
+		),
+		Keyword::Tri0 | Keyword::Tri1 => doc(
+			"A net pulled down or up when none of its drivers are driving it.",
+			"IEEE 1800-2017 § 6.6.5",
+		),
+		Keyword::Supply0 | Keyword::Supply1 => doc(
+			"A net tied to ground or the power supply.",
+			"IEEE 1800-2017 § 6.6.6",
+		),
+		Keyword::Reg | Keyword::Logic => doc(
+			"A four-state variable, or the four-state data type of a net.",
+			"IEEE 1800-2017 § 6.11",
+		),
+		Keyword::Bit => doc("A two-state integral type.", "IEEE 1800-2017 § 6.11"),
+		Keyword::Byte | Keyword::ShortInt | Keyword::Int | Keyword::LongInt => doc(
+			"A two-state signed integer of 8, 16, 32, or 64 bits.",
+			"IEEE 1800-2017 § 6.11",
+		),
+		Keyword::Integer => doc(
+			"A four-state signed 32-bit integer.",
+			"IEEE 1800-2017 § 6.11",
+		),
+		Keyword::Time => doc(
+			"A four-state unsigned 64-bit integer holding a simulation time.",
+			"IEEE 1800-2017 § 6.11",
+		),
+		Keyword::Real | Keyword::ShortReal | Keyword::Realtime => doc(
+			"A double, or single for `shortreal`, precision floating point value.",
+			"IEEE 1800-2017 § 6.12",
+		),
+		Keyword::String => doc(
+			"A variable length ordered collection of characters.",
+			"IEEE 1800-2017 § 6.16",
+		),
+		Keyword::Parameter => doc(
+			"A constant that may be overridden for each instance of a module.",
+			"IEEE 1800-2017 § 6.20.2",
+		),
+		Keyword::LocalParam => doc(
+			"A constant that can not be overridden.",
+			"IEEE 1800-2017 § 6.20.4",
+		),
+		Keyword::SpecParam => doc(
+			"A constant for timing and delay values in a specify block.",
+			"IEEE 1800-2017 § 6.20.5",
+		),
+		Keyword::Genvar => doc(
+			"The index variable of a loop generate construct.",
+			"IEEE 1800-2017 § 27.4",
+		),
+		Keyword::TypeDef => doc("Names a data type.", "IEEE 1800-2017 § 6.18"),
+		Keyword::Enum => doc(
+			"A set of integral named constants.",
+			"IEEE 1800-2017 § 6.19",
+		),
+		Keyword::Struct | Keyword::Union => doc(
+			"A collection of members, which share their storage in a union.",
+			"IEEE 1800-2017 § 7.2",
+		),
+		Keyword::Packed => doc(
+			"Packs the members of a structure or union into a vector.",
+			"IEEE 1800-2017 § 7.2.1",
+		),
+		Keyword::Input | Keyword::Output | Keyword::InOut | Keyword::Ref => {
+			doc("The direction of a port.", "IEEE 1800-2017 § 23.2.2")
+		},
+		Keyword::Assign => doc(
+			"A continuous assignment, driving a net with the value of an expression.",
+			"IEEE 1800-2017 § 10.3",
+		),
+		Keyword::Always => doc(
+			"A procedure that runs repeatedly for the whole simulation.",
+			"IEEE 1800-2017 § 9.2.2",
+		),
+		Keyword::AlwaysComb => doc(
+			"A procedure modelling combinational logic, sensitive to everything it reads.",
+			"IEEE 1800-2017 § 9.2.2.2",
+		),
+		Keyword::AlwaysLatch => doc(
+			"A procedure modelling latched logic, sensitive to everything it reads.",
+			"IEEE 1800-2017 § 9.2.2.3",
+		),
+		Keyword::AlwaysFF => doc(
+			"A procedure modelling synthesizable sequential logic.",
+			"IEEE 1800-2017 § 9.2.2.4",
+		),
+		Keyword::Initial => doc(
+			"A procedure that runs once at the start of the simulation.",
+			"IEEE 1800-2017 § 9.2.1",
+		),
+		Keyword::Final => doc(
+			"A procedure that runs once at the end of the simulation.",
+			"IEEE 1800-2017 § 9.2.3",
+		),
+		Keyword::Function | Keyword::EndFunction => doc(
+			"Declares a function, a subroutine that runs in zero time and may return a value.",
+			"IEEE 1800-2017 § 13.4",
+		),
+		Keyword::Task | Keyword::EndTask => doc(
+			"Declares a task, a subroutine that may consume time.",
+			"IEEE 1800-2017 § 13.3",
+		),
+		Keyword::Generate | Keyword::EndGenerate => doc(
+			"Marks a generate region, the generate constructs in it are elaborated conditionally \
+			 or repeatedly.",
+			"IEEE 1800-2017 § 27.3",
+		),
+		Keyword::PosEdge | Keyword::NegEdge | Keyword::Edge => doc(
+			"An event on a rising, falling, or any edge of an expression.",
+			"IEEE 1800-2017 § 9.4.2",
+		),
+		Keyword::Unique | Keyword::Unique0 | Keyword::Priority => doc(
+			"Requires the conditions of an `if` or `case` to be checked for overlap or \
+			 completeness.",
+			"IEEE 1800-2017 § 12.4.2",
+		),
+		Keyword::Case | Keyword::CaseX | Keyword::CaseZ | Keyword::EndCase => doc(
+			"Selects a statement by the first item matching an expression, `casez` and `casex` \
+			 treat `z` and `x` bits as wildcards.",
+			"IEEE 1800-2017 § 12.5",
+		),
+		Keyword::Automatic => doc(
+			"Gives a subroutine or block storage that is allocated for each call.",
+			"IEEE 1800-2017 § 6.21",
+		),
+		Keyword::Static => doc(
+			"Gives a subroutine or block storage that is shared by every call.",
+			"IEEE 1800-2017 § 6.21",
+		),
+		Keyword::Import => doc(
+			"Makes the declarations of a package visible in the current scope.",
+			"IEEE 1800-2017 § 26.3",
+		),
+		Keyword::Assert | Keyword::Assume | Keyword::Cover | Keyword::Restrict => doc(
+			"An assertion checking, assuming, covering, or restricting a property.",
+			"IEEE 1800-2017 § 16",
+		),
+		Keyword::Property | Keyword::EndProperty => {
+			doc("Declares a property.", "IEEE 1800-2017 § 16.12")
+		},
+		Keyword::Sequence | Keyword::EndSequence => {
+			doc("Declares a sequence.", "IEEE 1800-2017 § 16.8")
+		},
+		Keyword::CoverGroup | Keyword::EndGroup => {
+			doc("Declares a covergroup.", "IEEE 1800-2017 § 19.3")
+		},
+		Keyword::Analog => doc(
+			"A procedure describing the continuous time behaviour of an analog module.",
+			"Verilog-AMS 2.4 § 5.2",
+		),
+		Keyword::Discipline | Keyword::EndDiscipline => doc(
+			"Declares a discipline, the natures of the potential and flow of a net.",
+			"Verilog-AMS 2.4 § 3.6",
+		),
+		Keyword::Nature | Keyword::EndNature => doc(
+			"Declares a nature, the units and tolerances of a quantity.",
+			"Verilog-AMS 2.4 § 3.6",
+		),
+		_ => return None,
+	})
+}
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Describing the Verilog name, system function, keyword, or compiler directive under the cursor
+//!
+//! Names are resolved through the [`SymbolTable`] as they are when going to their definition, and
+//! the nets, variables, ports, and parameters are described with the type, dimensions, and value
+//! the [`TypeTable`] found for their declaration. The builtins are described from the LRM along
+//! with the standard they were added in.
+//!
+//! [`SymbolTable`]: vermilion_verilog::lang::semantic::SymbolTable
+//! [`TypeTable`]: vermilion_verilog::lang::typeck::TypeTable
+
+use std::fmt::Write;
+
+use vermilion_loc::{FileId, Span};
+use vermilion_verilog::{
+	LanguageStd,
+	lang::{
+		cst::SyntaxToken,
+		directives::BuiltinDirective,
+		semantic::SymbolKind,
+		sysfuncs::{BuiltinSysFunc, get_builtin_sysfunc, is_builtin_sysfunc},
+		tokenizer::token::Token,
+		typeck::TypedDeclaration,
+		types::{CompilerDirective, Control, SystemFunc},
+	},
+};
+
+use self::docs::Doc;
+use super::db::Database;
+use crate::lang::Ast;
+
+mod docs;
+
+/// The markdown describing what is at `offset` in `file`, and the span of what it describes
+pub(crate) fn hover(db: &mut Database, file: FileId, offset: u32) -> Option<(Span, String)> {
+	let Some(Ast::Verilog(ast)) = db.parse(file) else {
+		return None;
+	};
+
+	let tokens = ast.syntax().tokens().collect::<Vec<_>>();
+	let idx = tokens
+		.iter()
+		.position(|token| *token.span().begin() <= offset && offset < *token.span().end())?;
+
+	if let Some((span, sysfunc)) = system_function(&tokens, idx) {
+		return Some((span, describe_sysfunc(sysfunc)));
+	}
+
+	let span = *tokens[idx].span();
+	let value = match tokens[idx].token().clone() {
+		Token::Identifier(_) => describe_symbol(db, file, offset)?,
+		Token::SystemFunc(SystemFunc::Builtin(sysfunc)) => describe_sysfunc(sysfunc),
+		Token::Keyword(keyword) => {
+			describe_builtin(keyword.as_str(), docs::keyword(keyword), keyword.added())
+		},
+		Token::CompilerDirective(CompilerDirective::Builtin(directive)) => {
+			describe_directive(directive)
+		},
+		_ => return None,
+	};
+
+	Some((span, value))
+}
+
+/// The builtin system function the token at `idx` is part of, and the span of its whole name
+///
+/// The names of system functions are kept as a `$` followed by an identifier in the syntax tree,
+/// they are looked up in every standard so those from a later one than the file is in are still
+/// described.
+fn system_function(tokens: &[&SyntaxToken], idx: usize) -> Option<(Span, BuiltinSysFunc)> {
+	let dollar = match tokens[idx].token() {
+		Token::Control(Control::Dollar) => idx,
+		_ => idx.checked_sub(1)?,
+	};
+	let (Token::Control(Control::Dollar), Token::Identifier(name)) =
+		(tokens[dollar].token(), tokens.get(dollar + 1)?.token())
+	else {
+		return None;
+	};
+
+	let (begin, end) = (tokens[dollar].span(), tokens[dollar + 1].span());
+	if begin.end() != end.begin() {
+		return None;
+	}
+
+	let sysfunc = get_builtin_sysfunc(name.as_str(), is_builtin_sysfunc(name.as_str()).earliest())?;
+	Some((Span::from((*begin.begin(), *end.end())), sysfunc))
+}
+
+/// Describe the symbol the name at `offset` in `file` declares or refers to
+pub(crate) fn describe_symbol(db: &mut Database, file: FileId, offset: u32) -> Option<String> {
+	let symbol = {
+		let table = db.scopes(file)?;
+		table.symbol(table.symbol_at(file, offset)?).clone()
+	};
+	let declaration = db
+		.types(file)
+		.and_then(|types| types.declaration(*symbol.location.file_id(), *symbol.location.span()))
+		.cloned();
+	let line = db
+		.text(*symbol.location.file_id())
+		.map(|text| declaration_line(text, *symbol.location.begin()))
+		.unwrap_or_default();
+
+	let mut value = format!("```systemverilog\n{line}\n```\n");
+	let _ = writeln!(value, "\n{} `{}`", kind_name(symbol.kind), symbol.name);
+
+	if let Some(declaration) = declaration {
+		describe_declaration(&declaration, &mut value);
+	}
+
+	Some(value)
+}
+
+/// Add the type, dimensions, width, and value of a declaration to `value`
+fn describe_declaration(declaration: &TypedDeclaration, value: &mut String) {
+	let _ = writeln!(value, "\nType: `{}`", declaration.data_type);
+
+	if !declaration.packed.is_empty() {
+		let dimensions = declaration
+			.packed
+			.iter()
+			.map(|size| format!("[{size}]"))
+			.collect::<String>();
+		let _ = writeln!(value, "\nPacked dimensions: `{dimensions}`");
+	}
+
+	if !declaration.unpacked.is_empty() {
+		let dimensions = declaration
+			.unpacked
+			.iter()
+			.map(|size| match size {
+				Some(size) => format!("[{size}]"),
+				None => "[]".to_string(),
+			})
+			.collect::<String>();
+		let _ = writeln!(value, "\nUnpacked dimensions: `{dimensions}`");
+	}
+
+	if let Some(width) = declaration.width() {
+		let _ = writeln!(
+			value,
+			"\nWidth: {width} bit{}",
+			if width == 1 {
+				""
+			} else {
+				"s"
+			}
+		);
+	}
+
+	if let Some(constant) = &declaration.value {
+		let _ = writeln!(value, "\nValue: `{constant}`");
+	}
+}
+
+/// Describe a builtin system task or function
+pub(crate) fn describe_sysfunc(sysfunc: BuiltinSysFunc) -> String {
+	describe_builtin(
+		&format!("${}", sysfunc.as_str()),
+		Some(docs::sysfunc(sysfunc)),
+		sysfunc.added(),
+	)
+}
+
+/// Describe a builtin compiler directive
+pub(crate) fn describe_directive(directive: BuiltinDirective) -> String {
+	describe_builtin(
+		&format!("`{}", directive.as_str()),
+		Some(docs::directive(directive)),
+		directive.added(),
+	)
+}
+
+/// Describe a system function, keyword, or compiler directive
+fn describe_builtin(name: &str, doc: Option<Doc>, added: LanguageStd) -> String {
+	let mut value = format!("```systemverilog\n{name}\n```\n");
+
+	if let Some(doc) = doc {
+		let _ = writeln!(value, "\n{}\n\nSee {}", doc.summary, doc.reference);
+	}
+
+	if added != LanguageStd::none() {
+		let _ = writeln!(value, "\nAdded in {added}");
+	}
+
+	value
+}
+
+/// The trimmed line of `text` that `offset` is on
+fn declaration_line(text: &[u8], offset: u32) -> String {
+	let offset = (offset as usize).min(text.len());
+	let begin = text[..offset]
+		.iter()
+		.rposition(|chr| *chr == b'\n')
+		.map_or(0, |idx| idx + 1);
+	let end = text[offset..]
+		.iter()
+		.position(|chr| *chr == b'\n')
+		.map_or(text.len(), |idx| offset + idx);
+
+	String::from_utf8_lossy(&text[begin..end])
+		.trim()
+		.to_string()
+}
+
+pub(crate) fn kind_name(kind: SymbolKind) -> &'static str {
+	match kind {
+		SymbolKind::Module => "module",
+		SymbolKind::Interface => "interface",
+		SymbolKind::Program => "program",
+		SymbolKind::Primitive => "primitive",
+		SymbolKind::Config => "config",
+		SymbolKind::Package => "package",
+		SymbolKind::Class => "class",
+		SymbolKind::Port => "port",
+		SymbolKind::Net => "net",
+		SymbolKind::ImplicitNet => "implicit net",
+		SymbolKind::Variable => "variable",
+		SymbolKind::Parameter => "parameter",
+		SymbolKind::LocalParameter => "localparam",
+		SymbolKind::TypeParameter => "type parameter",
+		SymbolKind::Specparam => "specparam",
+		SymbolKind::Genvar => "genvar",
+		SymbolKind::Typedef => "typedef",
+		SymbolKind::EnumMember => "enum member",
+		SymbolKind::Function => "function",
+		SymbolKind::Task => "task",
+		SymbolKind::Instance => "instance",
+		SymbolKind::Block => "block",
+		SymbolKind::GenerateBlock => "generate block",
+		SymbolKind::Modport => "modport",
+		SymbolKind::Clocking => "clocking block",
+		SymbolKind::ClockingSignal => "clocking signal",
+		SymbolKind::Property => "property",
+		SymbolKind::Sequence => "sequence",
+		SymbolKind::Let => "let",
+		SymbolKind::Assertion => "assertion",
+		SymbolKind::CoverGroup => "covergroup",
+		SymbolKind::Coverpoint => "coverpoint",
+		SymbolKind::Cross => "cross",
+		SymbolKind::Constraint => "constraint",
+		SymbolKind::Nature => "nature",
+		SymbolKind::Discipline => "discipline",
+		SymbolKind::AccessFunction => "access function",
+		SymbolKind::Branch => "branch",
+	}
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_verilog::LanguageStd as VerilogStd;
+
+use super::*;
+use crate::{lang::Language, lsp::fixture::offset};
+
+const VERILOG: Language = Language::Verilog(VerilogStd::Sv17);
+
+const TOP: &str = "`define DEPTH 4
+module top #(parameter WIDTH = 8, localparam LAST = WIDTH - 1) (input clk);
+	logic [LAST:0] data;
+	logic [3:0][7:0] words;
+	reg [WIDTH - 1:0] mem [0:LAST];
+	always_ff @(posedge clk) data <= $clog2(WIDTH);
+endmodule
+";
+
+/// The description of what is at `offset` in `TOP`, and the text of the span it describes
+fn describe(offset: u32) -> (String, String) {
+	let mut db = Database::new();
+	let file = db.add_file(VERILOG, TOP.as_bytes().into());
+
+	let Some((span, value)) = hover(&mut db, file, offset) else {
+		panic!("Nothing to describe at {offset}");
+	};
+
+	(
+		TOP[*span.begin() as usize..*span.end() as usize].to_string(),
+		value,
+	)
+}
+
+#[test]
+fn test_signals() {
+	let (name, value) = describe(offset(TOP, "data", 1));
+	assert_eq!(name, "data");
+	assert!(value.contains("```systemverilog\nlogic [LAST:0] data;\n```"));
+	assert!(value.contains("variable `data`"));
+	assert!(value.contains("Type: `logic [7:0]`"));
+	assert!(value.contains("Packed dimensions: `[8]`"));
+	assert!(value.contains("Width: 8 bits"));
+
+	let (_, value) = describe(offset(TOP, "words", 0));
+	assert!(value.contains("Packed dimensions: `[4][8]`"));
+	assert!(value.contains("Width: 32 bits"));
+
+	let (_, value) = describe(offset(TOP, "mem", 0));
+	assert!(value.contains("Unpacked dimensions: `[8]`"));
+	assert!(value.contains("Width: 64 bits"));
+
+	let (_, value) = describe(offset(TOP, "clk", 1));
+	assert!(value.contains("port `clk`"));
+	assert!(value.contains("Width: 1 bit\n"));
+}
+
+#[test]
+fn test_parameters() {
+	let (_, value) = describe(offset(TOP, "WIDTH", 2));
+	assert!(value.contains("parameter `WIDTH`"));
+	assert!(value.contains("Value: `8`"));
+
+	let (_, value) = describe(offset(TOP, "LAST", 1));
+	assert!(value.contains("localparam `LAST`"));
+	assert!(value.contains("Value: `7`"));
+}
+
+#[test]
+fn test_builtins() {
+	let (name, value) = describe(offset(TOP, "$clog2", 0) + 1);
+	assert_eq!(name, "$clog2");
+	assert!(value.contains("See IEEE 1800-2017 § 20.8.1"));
+	assert!(value.contains("Added in Verilog 2005 (IEEE 1364-2005)"));
+
+	let (name, value) = describe(offset(TOP, "always_ff", 0));
+	assert_eq!(name, "always_ff");
+	assert!(value.contains("Added in System Verilog 2005 (IEEE 1800-2005)"));
+
+	let (name, value) = describe(offset(TOP, "`define", 0));
+	assert_eq!(name, "`define");
+	assert!(value.contains("See IEEE 1800-2017 § 22.5.1"));
+	assert!(value.contains("Added in Verilog 1995 (IEEE 1364-1995)"));
+
+	// Operators and numbers have nothing to describe
+	let mut db = Database::new();
+	let file = db.add_file(VERILOG, TOP.as_bytes().into());
+	assert!(hover(&mut db, file, offset(TOP, "<=", 0)).is_none());
+}
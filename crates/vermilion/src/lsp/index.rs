@@ -0,0 +1,316 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Discovering and indexing the HDL files under the workspace root
+//!
+//! When the language server is initialized every HDL file under the workspace folders is found,
+//! read, and parsed in the background, reporting progress to the client as it goes. The parsed
+//! files are handed back to the language server to add to its [`Workspace`], and from then on the
+//! client tells us about files being created, changed, or deleted through the file watchers we
+//! register.
+//!
+//! [`Workspace`]: super::workspace::Workspace
+
+use std::{
+	path::{Path, PathBuf},
+	sync::atomic::{AtomicUsize, Ordering},
+};
+
+use fluent_uri::pct_enc::{EString, encoder::Path as PathEncoder};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{debug, warn};
+use vermilion_io::path::ParallelWalk;
+use vermilion_lang::AtomicByteTendril;
+use vermilion_lsp::{
+	prelude::{Message, Notification},
+	types::{
+		FileSystemWatcher, GlobPattern, ProgressToken, Registration, Uri, WorkDoneProgressBegin,
+		WorkDoneProgressEnd, WorkDoneProgressReport,
+		options::DidChangeWatchedFilesRegistrationOptions, params::ProgressParams,
+	},
+};
+use vermilion_verilog::LanguageStd as VerilogStd;
+use vermilion_vhdl::LanguageStd as VhdlStd;
+
+use crate::{
+	lang::{Ast, Language},
+	workspace::{Sources, Workspace},
+};
+
+/// How many files are indexed between each progress report
+const REPORT_INTERVAL: usize = 64;
+
+/// A file found under the workspace root, read and parsed
+pub(crate) struct IndexedFile {
+	pub path:     PathBuf,
+	pub language: Language,
+	pub content:  AtomicByteTendril,
+	pub ast:      Ast,
+}
+
+/// The language of the file at `path`, if it is an HDL file at all
+///
+/// Which language it is goes by the extension, the standard comes from the settings of `workspace`
+/// for the language, or is the first one of the language when there are none.
+pub(crate) fn language_for_path(path: &Path, workspace: &Workspace) -> Option<Language> {
+	let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+	let ext = ext.as_str();
+
+	// NOTE(aki): VHDL-AMS shares its extensions with VHDL, so they are all treated as plain VHDL
+	let language = if VerilogStd::VERILOG_KNOWN_EXTS.contains(&ext) {
+		Language::Verilog(VerilogStd::Vl95)
+	} else if VerilogStd::SYSTEM_VERILOG_KNOWN_EXTS.contains(&ext) {
+		Language::Verilog(VerilogStd::Sv05)
+	} else if VerilogStd::VERILOG_AMS_KNOWN_EXTS.contains(&ext) {
+		Language::Verilog(VerilogStd::Vams09)
+	} else if VhdlStd::VHDL_KNOWN_EXTS.contains(&ext) {
+		Language::Vhdl(VhdlStd::Vh87)
+	} else if VhdlStd::BSDL_KNOWN_EXTS.contains(&ext) {
+		Language::Vhdl(VhdlStd::Bsdl)
+	} else {
+		return None;
+	};
+
+	Some(workspace.language_for(path, language))
+}
+
+/// The local path a `file://` URI refers to
+pub(crate) fn path_of(uri: &Uri) -> Option<PathBuf> {
+	if !uri.scheme().as_str().eq_ignore_ascii_case("file") {
+		return None;
+	}
+
+	Some(PathBuf::from(
+		uri.path().decode().to_string_lossy().into_owned(),
+	))
+}
+
+/// The `file://` URI of a local path
+pub(crate) fn uri_of(path: &Path) -> Option<Uri> {
+	let mut encoded = EString::<PathEncoder>::new();
+	encoded.encode_str::<PathEncoder>(path.to_str()?);
+
+	Uri::parse(format!("file://{}", encoded.into_string())).ok()
+}
+
+/// Read the file at `path`, if it is an HDL file
+pub(crate) fn read_file(
+	path: &Path,
+	workspace: &Workspace,
+) -> Option<(Language, AtomicByteTendril)> {
+	let language = language_for_path(path, workspace)?;
+
+	match std::fs::read(path) {
+		Ok(content) => Some((language, AtomicByteTendril::from_slice(&content))),
+		Err(err) => {
+			warn!("Unable to read {}: {err}", path.display());
+			None
+		},
+	}
+}
+
+/// Find, read, and parse every HDL file under `roots`, along with the ones the command files of
+/// `sources` describe the design with, sending each of them to `indexed`
+///
+/// This blocks until the whole workspace is indexed, so it is meant to be run on its own thread.
+/// If the client gave us a progress `token` the progress is reported on it.
+pub(crate) fn index_workspace(
+	roots: Vec<PathBuf>,
+	sources: &Sources,
+	token: Option<ProgressToken>,
+	response_channel: UnboundedSender<Message>,
+	indexed: UnboundedSender<IndexedFile>,
+) {
+	let progress = |value: serde_json::Result<serde_json::Value>| {
+		if let Some(token) = &token &&
+			let Ok(value) = value
+		{
+			let _ = response_channel.send(
+				Notification::Progress(ProgressParams { token: token.clone(), value }).into(),
+			);
+		}
+	};
+
+	progress(serde_json::to_value(
+		WorkDoneProgressBegin::new("Indexing".to_string())
+			.with_message("Finding HDL files".to_string())
+			.with_percentage(0),
+	));
+
+	let is_hdl_file =
+		|path: &PathBuf| path.is_file() && language_for_path(path, &sources.workspace).is_some();
+
+	let mut paths = roots
+		.iter()
+		.flat_map(|root| {
+			root.par_walk()
+				.filter(is_hdl_file)
+				.collect::<Vec<_>>()
+		})
+		.collect::<Vec<_>>();
+	// NOTE(aki): The sources of the design may well live outside of the workspace folders
+	paths.extend(
+		sources
+			.command_file
+			.source_files()
+			.into_iter()
+			.filter(is_hdl_file),
+	);
+	paths.sort();
+	paths.dedup();
+	debug!("Found {} HDL files in the workspace", paths.len());
+
+	let total = paths.len();
+	let done = AtomicUsize::new(0);
+	paths.par_iter().for_each(|path| {
+		if let Some((language, content)) = read_file(path, &sources.workspace) {
+			let ast = language.parse_file_at(path, content.clone(), sources, &[]);
+			let _ = indexed.send(IndexedFile { path: path.clone(), language, content, ast });
+		}
+
+		let done = done.fetch_add(1, Ordering::Relaxed) + 1;
+		if done.is_multiple_of(REPORT_INTERVAL) {
+			progress(serde_json::to_value(
+				WorkDoneProgressReport::new()
+					.with_message(format!("{done}/{total} files"))
+					.with_percentage(u32::try_from(done * 100 / total).unwrap_or(100)),
+			));
+		}
+	});
+
+	progress(serde_json::to_value(
+		WorkDoneProgressEnd::new().with_message(format!("Indexed {total} files")),
+	));
+}
+
+/// The registration of the file watchers for every HDL file in the workspace
+pub(crate) fn file_watchers() -> serde_json::Result<Registration> {
+	let exts = VerilogStd::VERILOG_KNOWN_EXTS
+		.iter()
+		.chain(&VerilogStd::SYSTEM_VERILOG_KNOWN_EXTS)
+		.chain(&VerilogStd::VERILOG_AMS_KNOWN_EXTS)
+		.chain(&VhdlStd::VHDL_KNOWN_EXTS)
+		.chain(&VhdlStd::BSDL_KNOWN_EXTS)
+		.copied()
+		.collect::<Vec<_>>();
+
+	let options = DidChangeWatchedFilesRegistrationOptions {
+		watchers: vec![FileSystemWatcher::new(GlobPattern::Pattern(format!(
+			"**/*.{{{}}}",
+			exts.join(",")
+		)))],
+	};
+
+	Ok(Registration::new(
+		"vermilion/watched-files".to_string(),
+		"workspace/didChangeWatchedFiles".to_string(),
+	)
+	.with_register_options(serde_json::to_value(options)?))
+}
+
+#[cfg(test)]
+mod tests {
+	use vermilion_verilog::workspace::{StdOverride, SystemVerilogWorkspace, VerilogSearchSettings};
+	use vermilion_vhdl::workspace::VhdlWorkspace;
+
+	use super::*;
+	use crate::{command_file::CommandFile, scratch::ScratchDir};
+
+	#[test]
+	fn test_language_for_path() {
+		let workspace = Workspace::default();
+		assert_eq!(
+			language_for_path(Path::new("rtl/top.sv"), &workspace),
+			Some(Language::Verilog(VerilogStd::Sv05))
+		);
+		assert_eq!(
+			language_for_path(Path::new("rtl/core.V"), &workspace),
+			Some(Language::Verilog(VerilogStd::Vl95))
+		);
+		assert_eq!(
+			language_for_path(Path::new("rtl/alu.vhdl"), &workspace),
+			Some(Language::Vhdl(VhdlStd::Vh87))
+		);
+		assert_eq!(language_for_path(Path::new("README.md"), &workspace), None);
+		assert_eq!(language_for_path(Path::new("Makefile"), &workspace), None);
+
+		// The standard comes from the workspace settings where there are any
+		let workspace = Workspace {
+			system_verilog: Some(SystemVerilogWorkspace {
+				std: VerilogStd::Sv17,
+				search: VerilogSearchSettings {
+					overrides: vec![
+						StdOverride::new("rtl/legacy/**/*.sv", VerilogStd::Sv09)
+							.unwrap_or_else(|err| panic!("{err}")),
+					],
+					..Default::default()
+				},
+				..Default::default()
+			}),
+			vhdl: Some(VhdlWorkspace { std: VhdlStd::Vh08, ..Default::default() }),
+			root: PathBuf::from("/work"),
+			..Default::default()
+		};
+
+		assert_eq!(
+			language_for_path(Path::new("/work/rtl/top.sv"), &workspace),
+			Some(Language::Verilog(VerilogStd::Sv17))
+		);
+		assert_eq!(
+			language_for_path(Path::new("/work/rtl/legacy/old/core.sv"), &workspace),
+			Some(Language::Verilog(VerilogStd::Sv09))
+		);
+		assert_eq!(
+			language_for_path(Path::new("/work/rtl/alu.vhd"), &workspace),
+			Some(Language::Vhdl(VhdlStd::Vh08))
+		);
+
+		// Languages without any settings still go by the extension alone
+		assert_eq!(
+			language_for_path(Path::new("/work/rtl/core.v"), &workspace),
+			Some(Language::Verilog(VerilogStd::Vl95))
+		);
+	}
+
+	#[test]
+	fn test_uri_round_trip() {
+		let path = Path::new("/work/my design/top.sv");
+		let uri = uri_of(path).unwrap_or_else(|| panic!("Failed to make a URI"));
+
+		assert_eq!(uri.as_str(), "file:///work/my%20design/top.sv");
+		assert_eq!(path_of(&uri).as_deref(), Some(path));
+	}
+	#[test]
+	fn test_index_command_files() {
+		let dir = ScratchDir::new("index");
+		let top = dir.write("rtl/top.sv", "module top;\nendmodule\n");
+		let cells = dir.write("cells/cells.v", "module and2;\nendmodule\n");
+		let mid = dir.write("lib/mid.sv", "module mid;\nendmodule\n");
+		dir.write("lib/mid.sv.bak", "module mid;\nendmodule\n");
+		dir.write("lib/notes.txt", "");
+
+		let sources = Sources {
+			command_file: CommandFile {
+				files: vec![top.clone()],
+				library_files: vec![cells.clone()],
+				library_dirs: vec![dir.join("lib")],
+				library_extensions: vec![".sv".to_string()],
+				..Default::default()
+			},
+			..Default::default()
+		};
+
+		// None of the files are under the workspace folders, they all come from the command files
+		let (response_channel, _) = tokio::sync::mpsc::unbounded_channel();
+		let (indexed, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+		index_workspace(Vec::new(), &sources, None, response_channel, indexed);
+
+		let mut paths = Vec::new();
+		while let Ok(file) = receiver.try_recv() {
+			paths.push(file.path);
+		}
+		paths.sort();
+
+		assert_eq!(paths, [cells, mid, top]);
+	}
+}
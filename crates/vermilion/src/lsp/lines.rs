@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Converting between byte offsets into a text and LSP positions
+//!
+//! LSP positions are a line along with a character offset into it, counted in UTF-16 code units,
+//! while everything else works with byte offsets. The [`LineIndex`] of a text records where each
+//! of its lines start, so only the bytes of the line a position is on need to be looked at.
+//!
+//! Files aren't always valid UTF-8, so every byte that isn't part of a valid character counts as
+//! one code unit on its own.
+
+use vermilion_lsp::types::Position;
+
+/// The byte offsets each line of a text starts at
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct LineIndex {
+	starts: Vec<u32>,
+}
+
+impl LineIndex {
+	pub fn new(content: &[u8]) -> Self {
+		let starts = std::iter::once(0)
+			.chain(
+				content
+					.iter()
+					.enumerate()
+					.filter(|(_, byte)| **byte == b'\n')
+					.map(|(idx, _)| u32::try_from(idx + 1).unwrap_or(u32::MAX)),
+			)
+			.collect();
+
+		Self { starts }
+	}
+
+	/// The byte offset into `content` of the LSP `position`, if the line is in the text
+	///
+	/// Positions past the end of their line are at the end of the line, the same as clients treat
+	/// them.
+	pub fn offset(&self, content: &[u8], position: &Position) -> Option<u32> {
+		let start = *self.starts.get(*position.line() as usize)?;
+		let line = line_at(content, start);
+
+		let mut units = 0;
+		let mut offset = 0;
+		for (len, width) in characters(line) {
+			if units >= *position.character() {
+				break;
+			}
+
+			units += width;
+			offset += len;
+		}
+
+		Some(start + offset)
+	}
+
+	/// The LSP position of the byte offset `offset` into `content`
+	pub fn position(&self, content: &[u8], offset: u32) -> Position {
+		let offset = offset.min(u32::try_from(content.len()).unwrap_or(u32::MAX));
+		let line = self
+			.starts
+			.partition_point(|start| *start <= offset)
+			.saturating_sub(1);
+		let start = self.starts.get(line).copied().unwrap_or_default();
+
+		let prefix = content
+			.get(start as usize..offset as usize)
+			.unwrap_or_default();
+		let character = characters(prefix).map(|(_, width)| width).sum();
+
+		Position::new(u32::try_from(line).unwrap_or(u32::MAX), character)
+	}
+}
+
+/// The bytes of the line starting at `start` in `content`, without the newline ending it
+fn line_at(content: &[u8], start: u32) -> &[u8] {
+	let rest = content.get(start as usize..).unwrap_or_default();
+	let end = rest
+		.iter()
+		.position(|byte| *byte == b'\n')
+		.unwrap_or(rest.len());
+
+	&rest[..end]
+}
+
+/// The length in bytes and the width in UTF-16 code units of each character of `bytes`, with every
+/// invalid byte being a character of its own
+fn characters(bytes: &[u8]) -> impl Iterator<Item = (u32, u32)> + '_ {
+	bytes.utf8_chunks().flat_map(|chunk| {
+		chunk
+			.valid()
+			.chars()
+			.map(|chr| (chr.len_utf8() as u32, chr.len_utf16() as u32))
+			.chain(chunk.invalid().iter().map(|_| (1, 1)))
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_positions() {
+		let content = "module top;\n\twire α; // 𝄞\n\nendmodule".as_bytes();
+		let lines = LineIndex::new(content);
+
+		let round_trip = |line, character, offset| {
+			let position = Position::new(line, character);
+			assert_eq!(lines.offset(content, &position), Some(offset));
+			assert_eq!(lines.position(content, offset), position);
+		};
+		round_trip(0, 0, 0);
+		round_trip(0, 11, 11);
+		round_trip(1, 6, 18);
+		round_trip(1, 7, 20);
+		// NOTE(aki): The clef is outside of the BMP, so it is two UTF-16 code units but four bytes
+		round_trip(1, 12, 25);
+		round_trip(1, 14, 29);
+		round_trip(2, 0, 30);
+		round_trip(3, 9, 40);
+
+		// Positions past the end of a line are at its end, and lines past the end aren't anywhere
+		assert_eq!(lines.offset(content, &Position::new(0, 100)), Some(11));
+		assert_eq!(lines.offset(content, &Position::new(4, 0)), None);
+		assert_eq!(lines.position(content, 100), Position::new(3, 9));
+	}
+
+	#[test]
+	fn test_invalid_utf8() {
+		let content = b"a\xff\xfeb\nc\xe2\x82d";
+		let lines = LineIndex::new(content);
+
+		// Each invalid byte is one code unit, so nothing after them drifts
+		assert_eq!(lines.offset(content, &Position::new(0, 3)), Some(3));
+		assert_eq!(lines.position(content, 4), Position::new(0, 4));
+		assert_eq!(lines.offset(content, &Position::new(1, 3)), Some(8));
+		assert_eq!(lines.position(content, 9), Position::new(1, 4));
+	}
+}
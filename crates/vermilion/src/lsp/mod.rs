@@ -1,12 +1,24 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
+mod bsdl;
+mod completion;
+mod db;
+#[cfg(test)]
+mod fixture;
+mod hover;
+mod index;
+mod lines;
+mod navigation;
+mod outline;
 mod semantic_tokens;
+mod signature;
 mod workspace;
 
 use std::{
+	path::PathBuf,
 	sync::{
 		OnceLock,
-		atomic::{AtomicBool, AtomicUsize, Ordering},
+		atomic::{AtomicBool, AtomicI32, AtomicUsize, Ordering},
 	},
 	time::Duration,
 };
@@ -20,23 +32,53 @@ use tokio::{
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, trace, warn};
 use vermilion_lsp::{
-	prelude::{Message, Notification, Request, Response},
+	prelude::{Id, Message, Notification, Request, Response},
 	request::RequestType,
 	transports::{
 		LSPTransport, TransportType, pipe::PipeTransport, socket::SocketTransport,
 		stdio::StdioTransport, trace::TraceTransport,
 	},
 	types::{
-		InitializeResult, ServerInfo, TextDocumentSyncKind,
+		Declaration, Definition, InitializeResult, OptionsOrBool, ProgressToken,
+		RegistrationOptionsOrBool, ServerInfo, TextDocumentSyncKind,
 		capabilities::server::{ServerCapabilities, TextDocumentSyncServerCapability},
+		options::{CompletionOptions, SignatureHelpOptions, WorkDoneProgressOptions},
+		params::{InitializedParams, RegistrationParams, WorkDoneProgressCreateParams},
 	},
 };
 
-use self::workspace::Workspace;
-use crate::workspace::Workspace as WorkspaceConfig;
+use self::{navigation::Goto, workspace::Workspace};
+use crate::{
+	command_file::CommandFile,
+	workspace::{Sources, Workspace as WorkspaceConfig},
+};
 
 static LSP_INITIALIZED: AtomicBool = AtomicBool::new(false);
 static SHUTDOWN_SENDER: OnceLock<UnboundedSender<()>> = OnceLock::new();
+static NEXT_REQUEST_ID: AtomicI32 = AtomicI32::new(0);
+
+/// The ID of the next request we send to the client
+fn next_request_id() -> Id {
+	Id::Integer(NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+/// The local directories of the workspace, preferring the workspace folders over the root
+fn workspace_roots(params: &InitializedParams) -> Vec<PathBuf> {
+	if let Some(folders) = &params.workspace_folder_initialize_params.workspace_folders {
+		return folders
+			.iter()
+			.filter_map(|folder| index::path_of(folder.uri()))
+			.collect();
+	}
+
+	params
+		.root_uri
+		.as_ref()
+		.and_then(index::path_of)
+		.or_else(|| params.root_path.as_ref().map(PathBuf::from))
+		.into_iter()
+		.collect()
+}
 
 // Handler for shutting down the runtime externally, should only be used in the panic hook
 pub(crate) fn shutdown_runtime() -> eyre::Result<()> {
@@ -118,7 +160,7 @@ pub(crate) fn start(
 }
 
 pub fn process_lsp_request(
-	workspace: &Workspace,
+	workspace: &mut Workspace,
 	request: Box<Request>,
 	response_channel: &UnboundedSender<Message>,
 	_shutdown_channel: &UnboundedSender<()>,
@@ -135,9 +177,34 @@ pub fn process_lsp_request(
 
 			let capabilities = ServerCapabilities::default()
 				.with_text_document_sync(TextDocumentSyncServerCapability::Kind(
-					TextDocumentSyncKind::Full,
+					TextDocumentSyncKind::Incremental,
 				))
-				.with_semantic_tokens_provider(semantic_tokens::capabilities());
+				.with_semantic_tokens_provider(semantic_tokens::capabilities())
+				.with_hover_provider(OptionsOrBool::Bool(true))
+				.with_completion_provider(CompletionOptions {
+					trigger_characters:         Some(
+						["`", "$", ".", ":"].map(str::to_string).to_vec(),
+					),
+					all_commit_characters:      None,
+					resolve_provider:           Some(true),
+					completion_item:            None,
+					work_done_progress_options: WorkDoneProgressOptions {
+						work_done_progress: None,
+					},
+				})
+				.with_signature_help_provider(SignatureHelpOptions {
+					trigger_characters:         Some(["(", ","].map(str::to_string).to_vec()),
+					retrigger_characters:       None,
+					work_done_progress_options: WorkDoneProgressOptions {
+						work_done_progress: None,
+					},
+				})
+				.with_document_symbol_provider(OptionsOrBool::Bool(true))
+				.with_definition_provider(OptionsOrBool::Bool(true))
+				.with_declaration_provider(RegistrationOptionsOrBool::Bool(true))
+				.with_type_definition_provider(RegistrationOptionsOrBool::Bool(true))
+				.with_references_provider(OptionsOrBool::Bool(true))
+				.with_workspace_symbol_provider(OptionsOrBool::Bool(true));
 
 			response_channel.send(
 				request
@@ -150,6 +217,49 @@ pub fn process_lsp_request(
 					)?
 					.into(),
 			)?;
+
+			let window = params.capabilities.window();
+			let token = window
+				.and_then(|window| window.work_done_progress())
+				.is_some_and(|supported| *supported)
+				.then(|| ProgressToken::String("vermilion/index".to_string()));
+
+			let roots = workspace_roots(params);
+			debug!("Indexing workspace roots {roots:?}");
+			// NOTE(aki): Progress can only be reported once the client has created the token
+			match token {
+				Some(token) => {
+					let id = next_request_id();
+					let create = WorkDoneProgressCreateParams { token: token.clone() };
+					response_channel.send(
+						Request::new(
+							id.clone(),
+							RequestType::WindowWorkDoneProgressCreate(create),
+						)
+						.into(),
+					)?;
+					workspace.index_when_created(id, roots, token);
+				},
+				None => workspace.index(roots, None, response_channel.clone()),
+			}
+
+			if params
+				.capabilities
+				.workspace()
+				.and_then(|workspace| workspace.did_change_watched_files())
+				.and_then(|watched| watched.dynamic_registration())
+				.is_some_and(|supported| *supported)
+			{
+				response_channel.send(
+					Request::new(
+						next_request_id(),
+						RequestType::ClientRegisterCapability(RegistrationParams {
+							registrations: vec![index::file_watchers()?],
+						}),
+					)
+					.into(),
+				)?;
+			}
 		},
 		RequestType::TextDocumentSemanticTokensFull(params) => {
 			let document = workspace.find_document(params.text_document.uri());
@@ -165,6 +275,105 @@ pub fn process_lsp_request(
 					.into(),
 			)?;
 		},
+		RequestType::TextDocumentHover(params) => {
+			let position = &params.text_document_position_params;
+			let hover = workspace.hover(position.text_document.uri(), &position.position);
+
+			response_channel.send(request.response().with_result(hover)?.into())?;
+		},
+		RequestType::TextDocumentCompletion(params) => {
+			let position = &params.text_document_position_params;
+			let completions =
+				workspace.completion(position.text_document.uri(), &position.position);
+
+			response_channel.send(request.response().with_result(completions)?.into())?;
+		},
+		RequestType::CompletionItemResolve(item) => {
+			let item = workspace.resolve_completion(item.clone());
+
+			response_channel.send(request.response().with_result(item)?.into())?;
+		},
+		RequestType::TextDocumentSignatureHelp(params) => {
+			let position = &params.text_document_position_params;
+			let signature =
+				workspace.signature_help(position.text_document.uri(), &position.position);
+
+			response_channel.send(request.response().with_result(signature)?.into())?;
+		},
+		RequestType::TextDocumentDocumentSymbol(params) => {
+			let document = workspace.find_document(params.text_document.uri());
+
+			response_channel.send(
+				request
+					.response()
+					.with_result(
+						document
+							.ok_or_eyre("Failed to find document")?
+							.document_symbols(),
+					)?
+					.into(),
+			)?;
+		},
+		RequestType::WorkspaceSymbol(params) => {
+			let symbols = workspace.workspace_symbols(&params.query);
+
+			response_channel.send(request.response().with_result(symbols)?.into())?;
+		},
+		RequestType::TextDocumentDefinition(params) => {
+			let position = &params.text_document_position_params;
+			let locations = workspace.goto(
+				position.text_document.uri(),
+				&position.position,
+				Goto::Definition,
+			);
+
+			response_channel.send(
+				request
+					.response()
+					.with_result(Definition::LocationArray(locations))?
+					.into(),
+			)?;
+		},
+		RequestType::TextDocumentDeclaration(params) => {
+			let position = &params.text_document_position_params;
+			let locations = workspace.goto(
+				position.text_document.uri(),
+				&position.position,
+				Goto::Declaration,
+			);
+
+			response_channel.send(
+				request
+					.response()
+					.with_result(Declaration::LocationArray(locations))?
+					.into(),
+			)?;
+		},
+		RequestType::TextDocumentTypeDefinition(params) => {
+			let position = &params.text_document_position_params;
+			let locations = workspace.goto(
+				position.text_document.uri(),
+				&position.position,
+				Goto::TypeDefinition,
+			);
+
+			response_channel.send(
+				request
+					.response()
+					.with_result(Definition::LocationArray(locations))?
+					.into(),
+			)?;
+		},
+		RequestType::TextDocumentReferences(params) => {
+			let position = &params.text_document_position_params;
+			let locations = workspace.references(
+				position.text_document.uri(),
+				&position.position,
+				*params.context.include_declaration(),
+			);
+
+			response_channel.send(request.response().with_result(locations)?.into())?;
+		},
 		_ => unimplemented!(),
 	}
 
@@ -172,10 +381,13 @@ pub fn process_lsp_request(
 }
 
 pub fn process_lsp_response(
-	_response: Response,
-	_response_channel: &UnboundedSender<Message>,
+	workspace: &mut Workspace,
+	response: Response,
+	response_channel: &UnboundedSender<Message>,
 	_shutdown_channel: &UnboundedSender<()>,
 ) -> eyre::Result<()> {
+	workspace.progress_created(&response, response_channel.clone());
+
 	Ok(())
 }
 
@@ -202,6 +414,9 @@ pub fn process_lsp_notification(
 			params.text_document.text_document_identifier().uri(),
 			params.content_changes,
 		),
+		Notification::WorkspaceWatchedFilesChanged(params) => {
+			workspace.change_watched_files(params.changes)
+		},
 		_ => unimplemented!(),
 	}
 	Ok(())
@@ -235,7 +450,7 @@ fn process_lsp_message(
 			process_lsp_request(workspace, request, response_channel, shutdown_channel)
 		},
 		Message::Response(response) => {
-			process_lsp_response(response, response_channel, shutdown_channel)
+			process_lsp_response(workspace, response, response_channel, shutdown_channel)
 		},
 		Message::Notification(notification) => {
 			process_lsp_notification(workspace, notification, response_channel, shutdown_channel)
@@ -245,12 +460,20 @@ fn process_lsp_message(
 
 async fn lsp_server(
 	transport: TransportType,
-	_workspace_config: Option<WorkspaceConfig>,
+	workspace_config: Option<WorkspaceConfig>,
 	cancellation_token: CancellationToken,
 	shutdown_channel: UnboundedSender<()>,
 	trace_transport: Option<TraceTransport>,
 ) -> eyre::Result<()> {
-	let mut workspace: Workspace = Workspace::new();
+	// NOTE(aki): A broken command file shouldn't stop the language server from starting
+	let workspace_config = workspace_config.unwrap_or_default();
+	let command_file = workspace_config.command_files().unwrap_or_else(|err| {
+		warn!("Unable to read the workspace command files: {err}");
+		CommandFile::default()
+	});
+	let sources = Sources { workspace: workspace_config, command_file };
+	let (indexer, mut indexed) = mpsc::unbounded_channel();
+	let mut workspace: Workspace = Workspace::new(sources, indexer);
 
 	let (mut reader, writer, tasks) = match transport {
 		TransportType::Stdio => {
@@ -294,6 +517,7 @@ async fn lsp_server(
 					break;
 				}
 			},
+			Some(file) = indexed.recv() => workspace.add_indexed(file),
 		}
 	}
 
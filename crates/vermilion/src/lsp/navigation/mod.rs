@@ -0,0 +1,691 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Finding where things are declared and used, for going to definitions and finding references
+//!
+//! Verilog names are resolved with the [`SymbolTable`] of the file and the files it uses, so a
+//! name resolves the same way the rest of the language server sees it. Finding the references to
+//! something looks through every file that uses the file it is declared in.
+//!
+//! Text macro usages are followed through the [`SourceMap`] of the preprocessed file to the
+//! `` `define `` they were expanded from. Macros that weren't defined while preprocessing the file
+//! on its own, such as ones defined by a file compiled before it, are found by name through the
+//! `` `define ``s of the whole workspace, as which of them is in effect depends on the order the
+//! files are compiled in.
+//!
+//! VHDL design units are resolved by name, with a component being bound to the entity of the
+//! same name as the default binding would.
+//!
+//! [`SymbolTable`]: vermilion_verilog::lang::semantic::SymbolTable
+//! [`SourceMap`]: vermilion_verilog::lang::preprocessor::SourceMap
+
+use std::path::PathBuf;
+
+use vermilion_loc::{Expansion, FileId, Location, Span, Spanned};
+use vermilion_verilog::lang::preprocessor::SourceMap;
+use vermilion_vhdl::lang::ast::{
+	BindingIndication, BlockConfiguration, ConcurrentStatement, ConcurrentStatementKind,
+	ConfigurationItem, ContextItem, Declaration, EntityAspect, GenerateBody, Identifier,
+	InstantiatedUnit, LibraryUnit, Name, Suffix,
+};
+
+use super::db::{Database, queries::macros};
+use crate::lang::{Ast, Language, VhdlAst};
+
+/// Which of the places a name is declared in to go to
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Goto {
+	/// Where the thing named is defined, such as the entity a VHDL component is bound to
+	Definition,
+	/// Where the name is declared, such as the declaration of a VHDL component
+	Declaration,
+	/// Where the type of the thing named is defined, such as the module of an instance
+	TypeDefinition,
+}
+
+/// A text macro usage or name at a position in a file
+struct MacroAt {
+	name:   String,
+	/// The `` `define `` of the macro, if it is known which one it is
+	target: Option<Location<()>>,
+}
+
+/// Where a macro used in a file was defined, as found in its [`SourceMap`]
+struct MacroDefinition {
+	/// The file the macro was defined in, `None` if it is the file itself
+	path: Option<PathBuf>,
+	span: Span,
+}
+
+/// What a VHDL [`UnitName`] names
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum UnitKind {
+	Entity,
+	Component,
+	Package,
+	Configuration,
+	Context,
+}
+
+/// A declaration of or reference to a VHDL design unit or component
+#[derive(Clone, Debug)]
+struct UnitName {
+	/// The normalized name
+	name:        String,
+	kind:        UnitKind,
+	span:        Span,
+	declaration: bool,
+}
+
+/// The locations to go to from the name at `offset` in `file`
+pub(crate) fn goto(db: &mut Database, file: FileId, offset: u32, goto: Goto) -> Vec<Location<()>> {
+	if let Some(text_macro) = macro_at(db, file, offset) {
+		return match (goto, text_macro.target) {
+			(Goto::TypeDefinition, _) => Vec::new(),
+			(Goto::Definition | Goto::Declaration, Some(target)) => vec![target],
+			(Goto::Definition | Goto::Declaration, None) => {
+				macro_definitions(db, file, &text_macro.name)
+			},
+		};
+	}
+
+	match db.language(file) {
+		Some(Language::Verilog(_)) => verilog_goto(db, file, offset, goto),
+		Some(Language::Vhdl(_)) => vhdl_goto(db, file, offset, goto),
+		None => Vec::new(),
+	}
+}
+
+/// The uses of the name at `offset` in `file` throughout the workspace, along with its
+/// declarations if `include_declaration` is set
+pub(crate) fn references(
+	db: &mut Database,
+	file: FileId,
+	offset: u32,
+	include_declaration: bool,
+) -> Vec<Location<()>> {
+	let mut locations = if let Some(text_macro) = macro_at(db, file, offset) {
+		let mut locations = Vec::new();
+		for other in db.files() {
+			locations.extend(macro_uses(
+				db,
+				other,
+				&text_macro.name,
+				text_macro.target.as_ref(),
+			));
+		}
+
+		if include_declaration {
+			match text_macro.target {
+				Some(target) => locations.push(target),
+				None => locations.extend(macro_definitions(db, file, &text_macro.name)),
+			}
+		}
+		locations
+	} else {
+		match db.language(file) {
+			Some(Language::Verilog(_)) => verilog_references(db, file, offset, include_declaration),
+			Some(Language::Vhdl(_)) => vhdl_references(db, file, offset, include_declaration),
+			None => Vec::new(),
+		}
+	};
+
+	locations.sort_by_key(|location| (*location.file_id(), *location.begin()));
+	locations.dedup_by_key(|location| (*location.file_id(), *location.span()));
+	locations
+}
+
+fn verilog_goto(db: &mut Database, file: FileId, offset: u32, goto: Goto) -> Vec<Location<()>> {
+	let Some(table) = db.scopes(file) else {
+		return Vec::new();
+	};
+
+	let symbol = table.symbol_at(file, offset).and_then(|symbol| match goto {
+		Goto::Definition | Goto::Declaration => Some(symbol),
+		Goto::TypeDefinition => table.type_of(symbol),
+	});
+
+	symbol
+		.map(|symbol| table.symbol(symbol).location)
+		.into_iter()
+		.collect()
+}
+
+fn verilog_references(
+	db: &mut Database,
+	file: FileId,
+	offset: u32,
+	include_declaration: bool,
+) -> Vec<Location<()>> {
+	let Some(target) = db.scopes(file).and_then(|table| {
+		table
+			.symbol_at(file, offset)
+			.map(|symbol| table.symbol(symbol).location)
+	}) else {
+		return Vec::new();
+	};
+
+	let mut locations = Vec::new();
+	if include_declaration {
+		locations.push(target);
+	}
+
+	// NOTE(aki): Each file only sees the files it uses, so the references from a file are taken
+	// from its own symbol table, and only from files that can see the declaration at all
+	for other in db.files() {
+		if !matches!(db.language(other), Some(Language::Verilog(_))) ||
+			!db.related_files(other).contains(target.file_id())
+		{
+			continue;
+		}
+		let Some(table) = db.scopes(other) else {
+			continue;
+		};
+
+		locations.extend(
+			table
+				.references()
+				.filter(|(_, reference)| {
+					*reference.location.file_id() == other &&
+						reference.target.is_some_and(|symbol| {
+							same_location(&table.symbol(symbol).location, &target)
+						})
+				})
+				.map(|(_, reference)| reference.location),
+		);
+	}
+
+	locations
+}
+
+/// The text macro used or named at `offset` in `file`, if there is one
+///
+/// A usage the preprocessor expanded is resolved to the `` `define `` it was expanded from, the
+/// name of a `` `define `` is its own target.
+fn macro_at(db: &mut Database, file: FileId, offset: u32) -> Option<MacroAt> {
+	let Some(Ast::Verilog(ast)) = db.parse(file) else {
+		return None;
+	};
+	let source_map = ast.source_map();
+
+	// NOTE(aki): The cursor can be just past the end of the usage, as with any other name
+	let usage = source_map
+		.usage_at(offset)
+		.or_else(|| source_map.usage_at(offset.checked_sub(1)?));
+	if let Some(usage) = usage {
+		let name = usage.name().to_string();
+		let definition = macro_definition(source_map, usage);
+
+		return Some(MacroAt {
+			name,
+			target: definition.and_then(|definition| resolve_definition(db, file, definition)),
+		});
+	}
+
+	macros(ast)
+		.find(|text_macro| contains(&text_macro.span, offset))
+		.map(|text_macro| MacroAt {
+			target: text_macro
+				.defined
+				.then(|| Location::new(file, (), text_macro.span)),
+			name:   text_macro.name,
+		})
+}
+
+/// Where the macro expanded by `usage` was defined, `None` if it wasn't defined in a source file
+fn macro_definition(source_map: &SourceMap, usage: &Expansion) -> Option<MacroDefinition> {
+	let definition = usage.definition()?;
+	let path = if *definition.file_id() == source_map.file() {
+		None
+	} else {
+		Some(source_map.path(*definition.file_id())?.to_path_buf())
+	};
+
+	Some(MacroDefinition { path, span: *definition.span() })
+}
+
+/// The location of a macro definition in the workspace, `None` if the file it is in isn't part of
+/// the workspace
+fn resolve_definition(
+	db: &mut Database,
+	file: FileId,
+	definition: MacroDefinition,
+) -> Option<Location<()>> {
+	let file = match definition.path {
+		Some(path) => db.file_at(&path)?,
+		None => file,
+	};
+
+	Some(Location::new(file, (), definition.span))
+}
+
+/// The definitions of the text macro `name` found by name
+///
+/// The definitions in `file` itself are preferred, the ones in the rest of the workspace are only
+/// used if it has none.
+fn macro_definitions(db: &mut Database, file: FileId, name: &str) -> Vec<Location<()>> {
+	let local = db
+		.item_tree(file)
+		.is_some_and(|tree| tree.macros.contains(name));
+	let files = if local {
+		vec![file]
+	} else {
+		db.definitions().macro_files(name).to_vec()
+	};
+
+	let mut locations = Vec::new();
+	for other in files {
+		if let Some(Ast::Verilog(ast)) = db.parse(other) {
+			locations.extend(
+				macros(ast)
+					.filter(|text_macro| text_macro.defined && text_macro.name == name)
+					.map(|text_macro| Location::new(other, (), text_macro.span)),
+			);
+		}
+	}
+
+	locations
+}
+
+/// The uses of the text macro `name` in `file`, only those expanded from `target` if it is known
+///
+/// Usages that weren't expanded, as the macro wasn't defined while preprocessing the file, and
+/// the names in conditional compilation directives could be of any definition, so they are
+/// always included.
+fn macro_uses(
+	db: &mut Database,
+	file: FileId,
+	name: &str,
+	target: Option<&Location<()>>,
+) -> Vec<Location<()>> {
+	let Some(Ast::Verilog(ast)) = db.parse(file) else {
+		return Vec::new();
+	};
+	let source_map = ast.source_map();
+
+	let mut locations = macros(ast)
+		.filter(|text_macro| {
+			!text_macro.defined &&
+				text_macro.name == name &&
+				source_map.usage_at(*text_macro.span.begin()).is_none()
+		})
+		.map(|text_macro| Location::new(file, (), text_macro.span))
+		.collect::<Vec<_>>();
+
+	// The call site of a usage covers its arguments too, only the `` `name `` itself is the use
+	let expanded = source_map
+		.usages()
+		.filter(|usage| usage.name() == name)
+		.map(|usage| {
+			let call_site = usage.call_site();
+			let span = Span::from_position(
+				*call_site.begin(),
+				*call_site.begin() + 1 + u32::try_from(name.len()).unwrap_or_default(),
+				call_site.span().get_position(),
+			);
+			(span, macro_definition(source_map, usage))
+		})
+		.collect::<Vec<_>>();
+
+	for (span, definition) in expanded {
+		let matches = target.is_none_or(|target| {
+			definition
+				.and_then(|definition| resolve_definition(db, file, definition))
+				.is_some_and(|definition| same_location(&definition, target))
+		});
+		if matches {
+			locations.push(Location::new(file, (), span));
+		}
+	}
+
+	locations
+}
+
+fn vhdl_goto(db: &mut Database, file: FileId, offset: u32, goto: Goto) -> Vec<Location<()>> {
+	let Some(Ast::Vhdl(ast)) = db.parse(file) else {
+		return Vec::new();
+	};
+	let Some(target) = unit_names(ast)
+		.into_iter()
+		.find(|name| contains(&name.span, offset))
+	else {
+		return Vec::new();
+	};
+
+	let declarations = vhdl_names(db, &target.name)
+		.into_iter()
+		.filter(|(_, name)| name.declaration)
+		.collect::<Vec<_>>();
+	let of_kind = |kinds: &[UnitKind]| {
+		declarations
+			.iter()
+			.filter(|(_, name)| kinds.contains(&name.kind))
+			.map(|(other, name)| Location::new(*other, (), name.span))
+			.collect::<Vec<_>>()
+	};
+
+	// A component goes to its entity, or its own declaration if there is no such entity, and
+	// the other way around when going to the declaration
+	let kinds = match (target.kind, goto) {
+		(UnitKind::Entity | UnitKind::Component, Goto::Declaration) => {
+			[UnitKind::Component, UnitKind::Entity]
+		},
+		(UnitKind::Entity | UnitKind::Component, _) => [UnitKind::Entity, UnitKind::Component],
+		(kind, _) => [kind, kind],
+	};
+
+	let locations = of_kind(&kinds[..1]);
+	if locations.is_empty() {
+		of_kind(&kinds[1..])
+	} else {
+		locations
+	}
+}
+
+fn vhdl_references(
+	db: &mut Database,
+	file: FileId,
+	offset: u32,
+	include_declaration: bool,
+) -> Vec<Location<()>> {
+	let Some(Ast::Vhdl(ast)) = db.parse(file) else {
+		return Vec::new();
+	};
+	let Some(target) = unit_names(ast)
+		.into_iter()
+		.find(|name| contains(&name.span, offset))
+	else {
+		return Vec::new();
+	};
+
+	// Entities and components of the same name are one and the same as far as the default
+	// binding is concerned
+	let family = |kind: UnitKind| match kind {
+		UnitKind::Component => UnitKind::Entity,
+		kind => kind,
+	};
+
+	vhdl_names(db, &target.name)
+		.into_iter()
+		.filter(|(_, name)| {
+			family(name.kind) == family(target.kind) && (include_declaration || !name.declaration)
+		})
+		.map(|(other, name)| Location::new(other, (), name.span))
+		.collect()
+}
+
+/// The declarations of and references to the design units and components named `name` in every
+/// VHDL file of the workspace
+fn vhdl_names(db: &mut Database, name: &str) -> Vec<(FileId, UnitName)> {
+	let mut names = Vec::new();
+
+	for file in db.files() {
+		if let Some(Ast::Vhdl(ast)) = db.parse(file) {
+			names.extend(
+				unit_names(ast)
+					.into_iter()
+					.filter(|unit| unit.name == name)
+					.map(|unit| (file, unit)),
+			);
+		}
+	}
+
+	names
+}
+
+/// The declarations of and references to design units and components in a VHDL file
+fn unit_names(ast: &VhdlAst) -> Vec<UnitName> {
+	let mut collector = UnitNames::default();
+
+	for unit in ast.units() {
+		collector.context(&unit.context);
+
+		match &unit.inner().unit {
+			LibraryUnit::Entity(entity) => {
+				collector.declare(&entity.name, UnitKind::Entity);
+				collector.declarations(&entity.declarations);
+				collector.statements(&entity.statements);
+			},
+			LibraryUnit::Architecture(architecture) => {
+				collector.reference(&architecture.entity, UnitKind::Entity);
+				collector.declarations(&architecture.declarations);
+				collector.statements(&architecture.statements);
+			},
+			LibraryUnit::Package(package) => {
+				collector.declare(&package.name, UnitKind::Package);
+				collector.declarations(&package.declarations);
+			},
+			LibraryUnit::PackageBody(body) => {
+				collector.reference(&body.name, UnitKind::Package);
+				collector.declarations(&body.declarations);
+			},
+			LibraryUnit::PackageInstance(instance) => {
+				collector.declare(&instance.name, UnitKind::Package);
+				collector.name(&instance.package, UnitKind::Package);
+			},
+			LibraryUnit::Configuration(configuration) => {
+				collector.declare(&configuration.name, UnitKind::Configuration);
+				collector.name(&configuration.entity, UnitKind::Entity);
+				collector.declarations(&configuration.declarations);
+				collector.block_configuration(configuration.block.inner());
+			},
+			LibraryUnit::Context(context) => {
+				collector.declare(&context.name, UnitKind::Context);
+				collector.context(&context.items);
+			},
+			LibraryUnit::VerificationUnit(_) | LibraryUnit::Error => {},
+		}
+	}
+
+	collector.names
+}
+
+/// Collects the [`UnitName`]s of a VHDL file
+#[derive(Default)]
+struct UnitNames {
+	names: Vec<UnitName>,
+}
+
+impl UnitNames {
+	fn declare(&mut self, name: &Spanned<Identifier>, kind: UnitKind) {
+		self.names.push(UnitName {
+			name: name.inner().normalized(),
+			kind,
+			span: *name.span(),
+			declaration: true,
+		});
+	}
+
+	fn reference(&mut self, name: &Spanned<Identifier>, kind: UnitKind) {
+		self.names.push(UnitName {
+			name: name.inner().normalized(),
+			kind,
+			span: *name.span(),
+			declaration: false,
+		});
+	}
+
+	/// Add a reference to the design unit `name`, a selected name is taken to be
+	/// `library.unit`, and a component is always the last part of the name
+	fn name(&mut self, name: &Spanned<Name>, kind: UnitKind) {
+		let mut path = Vec::new();
+		if !name_path(name, &mut path) {
+			return;
+		}
+
+		let unit = match (kind, path.as_slice()) {
+			(UnitKind::Component, [.., unit]) | (_, [unit] | [_, unit, ..]) => unit,
+			_ => return,
+		};
+
+		self.names.push(UnitName {
+			name: unit.0.clone(),
+			kind,
+			span: unit.1,
+			declaration: false,
+		});
+	}
+
+	/// `use` clauses only name a design unit when selecting from a library
+	fn use_clause(&mut self, names: &[Spanned<Name>]) {
+		for name in names {
+			let mut path = Vec::new();
+			if name_path(name, &mut path) && path.len() > 1 {
+				self.name(name, UnitKind::Package);
+			}
+		}
+	}
+
+	fn context(&mut self, items: &[Spanned<ContextItem>]) {
+		for item in items {
+			match item.inner() {
+				ContextItem::Library(_) => {},
+				ContextItem::Use(names) => self.use_clause(names),
+				ContextItem::Context(names) => {
+					for name in names {
+						self.name(name, UnitKind::Context);
+					}
+				},
+			}
+		}
+	}
+
+	fn declarations(&mut self, declarations: &[Spanned<Declaration>]) {
+		for declaration in declarations {
+			match declaration.inner() {
+				Declaration::Component(component) => {
+					self.declare(&component.name, UnitKind::Component);
+				},
+				Declaration::Use(names) => self.use_clause(names),
+				Declaration::ConfigurationSpecification(specification) => {
+					self.name(&specification.specification.component, UnitKind::Component);
+					self.binding(&specification.binding);
+				},
+				Declaration::SubprogramBody(body) => self.declarations(&body.declarations),
+				Declaration::Package(package) => {
+					self.declare(&package.name, UnitKind::Package);
+					self.declarations(&package.declarations);
+				},
+				Declaration::PackageBody(body) => self.declarations(&body.declarations),
+				Declaration::PackageInstance(instance) => {
+					self.name(&instance.package, UnitKind::Package);
+				},
+				_ => {},
+			}
+		}
+	}
+
+	fn statements(&mut self, statements: &[Spanned<ConcurrentStatement>]) {
+		for statement in statements {
+			match &statement.inner().kind {
+				ConcurrentStatementKind::Block(block) => {
+					self.declarations(&block.declarations);
+					self.statements(&block.statements);
+				},
+				ConcurrentStatementKind::Process(process) => {
+					self.declarations(&process.declarations);
+				},
+				ConcurrentStatementKind::Instantiation(instantiation) => {
+					match &instantiation.unit {
+						InstantiatedUnit::Component(name) => self.name(name, UnitKind::Component),
+						InstantiatedUnit::Entity { name, .. } => self.name(name, UnitKind::Entity),
+						InstantiatedUnit::Configuration(name) => {
+							self.name(name, UnitKind::Configuration);
+						},
+					}
+				},
+				ConcurrentStatementKind::ForGenerate { body, .. } => self.generate(body),
+				ConcurrentStatementKind::IfGenerate(branches) => {
+					for branch in branches {
+						self.generate(&branch.inner().body);
+					}
+				},
+				ConcurrentStatementKind::CaseGenerate { alternatives, .. } => {
+					for alternative in alternatives {
+						self.generate(&alternative.inner().body);
+					}
+				},
+				ConcurrentStatementKind::SimultaneousIf(branches) => {
+					for branch in branches {
+						self.statements(&branch.inner().value);
+					}
+				},
+				ConcurrentStatementKind::SimultaneousCase { alternatives, .. } => {
+					for alternative in alternatives {
+						self.statements(&alternative.inner().value);
+					}
+				},
+				ConcurrentStatementKind::SimultaneousProcedural(procedural) => {
+					self.declarations(&procedural.declarations);
+				},
+				_ => {},
+			}
+		}
+	}
+
+	fn generate(&mut self, body: &GenerateBody) {
+		self.declarations(&body.declarations);
+		self.statements(&body.statements);
+	}
+
+	fn block_configuration(&mut self, block: &BlockConfiguration) {
+		self.use_clause(&block.uses);
+
+		for item in &block.items {
+			match item.inner() {
+				ConfigurationItem::Block(block) => self.block_configuration(block),
+				ConfigurationItem::Component(component) => {
+					self.name(&component.specification.component, UnitKind::Component);
+
+					if let Some(binding) = &component.binding {
+						self.binding(binding);
+					}
+
+					if let Some(block) = &component.block {
+						self.block_configuration(block.inner());
+					}
+				},
+			}
+		}
+	}
+
+	fn binding(&mut self, binding: &BindingIndication) {
+		match binding.entity.as_ref().map(Spanned::inner) {
+			Some(EntityAspect::Entity { name, .. }) => self.name(name, UnitKind::Entity),
+			Some(EntityAspect::Configuration(name)) => self.name(name, UnitKind::Configuration),
+			Some(EntityAspect::Open) | None => {},
+		}
+	}
+}
+
+/// Flatten a simple or selected name into its normalized identifiers and their spans, stopping
+/// at a `.all` or non-identifier suffix, returns false if the name is not made up of identifiers
+fn name_path(name: &Spanned<Name>, path: &mut Vec<(String, Span)>) -> bool {
+	match name.inner() {
+		Name::Simple(ident) => {
+			path.push((ident.normalized(), *name.span()));
+			true
+		},
+		Name::Selected { prefix, suffix } => {
+			if !name_path(prefix, path) {
+				return false;
+			}
+
+			if let Suffix::Identifier(ident) = suffix.inner() {
+				path.push((ident.normalized(), *suffix.span()));
+			}
+			true
+		},
+		_ => false,
+	}
+}
+
+fn contains(span: &Span, offset: u32) -> bool {
+	*span.begin() <= offset && offset <= *span.end()
+}
+
+fn same_location(lhs: &Location<()>, rhs: &Location<()>) -> bool {
+	lhs.file_id() == rhs.file_id() && lhs.span() == rhs.span()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_verilog::LanguageStd as VerilogStd;
+use vermilion_vhdl::LanguageStd as VhdlStd;
+
+use super::*;
+use crate::{lsp::fixture::offset, scratch::ScratchDir};
+
+const VERILOG: Language = Language::Verilog(VerilogStd::Sv17);
+const VHDL: Language = Language::Vhdl(VhdlStd::Vh08);
+
+const TOP: &str = "`include \"defs.svh\"
+module top;
+	wire clk;
+	core u_core(.clk(clk));
+	wire [`WIDTH - 1:0] data;
+endmodule
+";
+
+const CORE: &str = "module core(input clk);
+	reg q;
+	always @(posedge clk) q <= ~q;
+endmodule
+";
+
+const DEFS: &str = "`define WIDTH 8
+";
+
+const REDEFINED: &str = "`define W 4
+wire [`W - 1:0] narrow;
+`undef W
+`define W 8
+wire [`W - 1:0] wide;
+";
+
+const ENTITY: &str = "entity counter is
+	port (clk : in bit);
+end entity;
+";
+
+const ARCHITECTURE: &str = "architecture rtl of top is
+	component counter is
+		port (clk : in bit);
+	end component;
+	signal clk : bit;
+begin
+	u_counter : counter port map (clk => clk);
+	u_direct : entity work.Counter port map (clk => clk);
+end architecture;
+";
+
+/// The files, and the text each location in `locations` spans
+fn spans(db: &mut Database, locations: &[Location<()>]) -> Vec<(FileId, String)> {
+	locations
+		.iter()
+		.map(|location| {
+			let text = db.text(*location.file_id()).cloned().unwrap_or_default();
+			let text = &text[*location.begin() as usize..*location.end() as usize];
+			(
+				*location.file_id(),
+				String::from_utf8_lossy(text).into_owned(),
+			)
+		})
+		.collect()
+}
+
+fn workspace(files: &[(Language, &str)]) -> (Database, Vec<FileId>) {
+	let mut db = Database::new();
+	let files = files
+		.iter()
+		.map(|(language, text)| db.add_file(*language, text.as_bytes().into()))
+		.collect();
+
+	(db, files)
+}
+
+#[test]
+fn test_verilog_goto() {
+	let (mut db, files) = workspace(&[(VERILOG, TOP), (VERILOG, CORE)]);
+	let [top, core] = files[..] else {
+		panic!("Expected two files");
+	};
+
+	let module = goto(&mut db, top, offset(TOP, "core", 0), Goto::Definition);
+	assert_eq!(spans(&mut db, &module), [(core, "core".to_string())]);
+
+	let port = goto(&mut db, top, offset(TOP, ".clk", 0) + 1, Goto::Declaration);
+	assert_eq!(spans(&mut db, &port), [(core, "clk".to_string())]);
+
+	let instance = goto(&mut db, top, offset(TOP, "u_core", 0), Goto::TypeDefinition);
+	assert_eq!(spans(&mut db, &instance), [(core, "core".to_string())]);
+
+	assert!(goto(&mut db, top, offset(TOP, "clk", 0), Goto::TypeDefinition).is_empty());
+}
+
+#[test]
+fn test_verilog_references() {
+	let (mut db, files) = workspace(&[(VERILOG, TOP), (VERILOG, CORE)]);
+	let [top, core] = files[..] else {
+		panic!("Expected two files");
+	};
+
+	// The named port connection in `top` is found from the declaration of the port in `core`
+	let port = references(&mut db, core, offset(CORE, "clk", 0), true);
+	assert_eq!(
+		spans(&mut db, &port),
+		[(top, "clk".to_string()), (core, "clk".to_string()), (core, "clk".to_string())]
+	);
+
+	let module = references(&mut db, core, offset(CORE, "core", 0), false);
+	assert_eq!(spans(&mut db, &module), [(top, "core".to_string())]);
+}
+
+/// Add the files to a workspace in a scratch directory called `name`, so `` `include ``s between
+/// them are found
+fn workspace_on_disk(name: &str, files: &[(&str, &str)]) -> (ScratchDir, Database, Vec<FileId>) {
+	let dir = ScratchDir::new(name);
+
+	let mut db = Database::new();
+	let files = files
+		.iter()
+		.map(|(name, text)| {
+			let file = db.add_file(VERILOG, text.as_bytes().into());
+			db.set_path(file, dir.write(name, text));
+			file
+		})
+		.collect();
+
+	(dir, db, files)
+}
+
+#[test]
+fn test_macros() {
+	let (_dir, mut db, files) = workspace_on_disk("macros", &[("top.sv", TOP), ("defs.svh", DEFS)]);
+	let [top, defs] = files[..] else {
+		panic!("Expected two files");
+	};
+
+	let definition = goto(&mut db, top, offset(TOP, "`WIDTH", 0), Goto::Definition);
+	assert_eq!(spans(&mut db, &definition), [(defs, "WIDTH".to_string())]);
+
+	let uses = references(&mut db, defs, offset(DEFS, "WIDTH", 0), true);
+	assert_eq!(
+		spans(&mut db, &uses),
+		[(top, "`WIDTH".to_string()), (defs, "WIDTH".to_string())]
+	);
+}
+
+#[test]
+fn test_macros_by_name() {
+	// Without the file on disk the `` `include `` isn't found, so the macro is found by name
+	let (mut db, files) = workspace(&[(VERILOG, TOP), (VERILOG, DEFS)]);
+	let [top, defs] = files[..] else {
+		panic!("Expected two files");
+	};
+
+	let definition = goto(&mut db, top, offset(TOP, "`WIDTH", 0), Goto::Definition);
+	assert_eq!(spans(&mut db, &definition), [(defs, "WIDTH".to_string())]);
+
+	let uses = references(&mut db, defs, offset(DEFS, "WIDTH", 0), false);
+	assert_eq!(spans(&mut db, &uses), [(top, "`WIDTH".to_string())]);
+}
+
+#[test]
+fn test_macro_redefinition() {
+	let (mut db, files) = workspace(&[(VERILOG, REDEFINED)]);
+	let [file] = files[..] else {
+		panic!("Expected one file");
+	};
+	let narrow = Location::new(file, (), Span::new(8, 9, 0, 8));
+	let wide = Location::new(file, (), Span::new(53, 54, 3, 8));
+
+	// Each usage goes to the `` `define `` it was expanded from
+	let definition = goto(
+		&mut db,
+		file,
+		offset(REDEFINED, "`W", 0) + 1,
+		Goto::Definition,
+	);
+	assert_eq!(definition, [narrow]);
+	let definition = goto(
+		&mut db,
+		file,
+		offset(REDEFINED, "`W", 1) + 1,
+		Goto::Definition,
+	);
+	assert_eq!(definition, [wide]);
+
+	// Only the usage of the second definition is found, along with the name in the `` `undef ``
+	let uses = references(&mut db, file, offset(REDEFINED, "`W", 1), true);
+	assert_eq!(
+		spans(&mut db, &uses),
+		[(file, "W".to_string()), (file, "W".to_string()), (file, "`W".to_string())]
+	);
+	assert_eq!(uses[1], wide);
+}
+
+#[test]
+fn test_vhdl() {
+	let (mut db, files) = workspace(&[(VHDL, ENTITY), (VHDL, ARCHITECTURE)]);
+	let [entity, architecture] = files[..] else {
+		panic!("Expected two files");
+	};
+	let instance = offset(ARCHITECTURE, ": counter", 0) + 2;
+
+	// A component instance goes to the entity it is bound to, or the component declaration
+	let definition = goto(&mut db, architecture, instance, Goto::Definition);
+	assert_eq!(
+		spans(&mut db, &definition),
+		[(entity, "counter".to_string())]
+	);
+
+	let declaration = goto(&mut db, architecture, instance, Goto::Declaration);
+	assert_eq!(
+		spans(&mut db, &declaration),
+		[(architecture, "counter".to_string())]
+	);
+
+	let uses = references(&mut db, entity, offset(ENTITY, "counter", 0), false);
+	assert_eq!(
+		spans(&mut db, &uses),
+		[(architecture, "counter".to_string()), (architecture, "Counter".to_string())]
+	);
+}
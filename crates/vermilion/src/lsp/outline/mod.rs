@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! The outline of Verilog and VHDL documents, and searching it across the workspace
+//!
+//! Each design unit is a symbol with what is declared in it nested under it, such as the
+//! parameters, ports, nets, and instances of a module along with its processes and named
+//! generate blocks. Processes without a label are named after the keyword that starts them.
+//! Generate blocks without a name have their contents folded into whatever they are in.
+//!
+//! The workspace symbols are the outlines of every file in the workspace flattened, with each
+//! symbol knowing the name of the one it was nested under.
+
+use vermilion_loc::Span;
+use vermilion_lsp::types::{
+	BaseSymbolInformation, DocumentSymbol, Location, LocationOrUri, SymbolKind, Uri,
+	WorkspaceSymbol,
+};
+
+use super::workspace::Document;
+
+pub(super) mod verilog;
+pub(super) mod vhdl;
+
+/// A symbol spanning `span`, named by what is at `selection`
+fn symbol(
+	document: &Document,
+	name: &str,
+	kind: SymbolKind,
+	span: &Span,
+	selection: &Span,
+) -> DocumentSymbol {
+	DocumentSymbol::new(
+		name.to_string(),
+		kind,
+		document.range_of(span),
+		document.range_of(selection),
+	)
+}
+
+/// Set the detail of `symbol` unless there is nothing to show
+fn detailed(symbol: DocumentSymbol, detail: String) -> DocumentSymbol {
+	if detail.is_empty() {
+		symbol
+	} else {
+		symbol.with_detail(detail)
+	}
+}
+
+/// Set the children of `symbol` unless there are none
+fn nested(symbol: DocumentSymbol, children: Vec<DocumentSymbol>) -> DocumentSymbol {
+	if children.is_empty() {
+		symbol
+	} else {
+		symbol.with_children(children)
+	}
+}
+
+/// Whether `name` has all of the characters of `query` in order, ignoring case
+fn matches(name: &str, query: &str) -> bool {
+	let mut name = name.chars().flat_map(char::to_lowercase);
+	query
+		.chars()
+		.flat_map(char::to_lowercase)
+		.all(|wanted| name.any(|chr| chr == wanted))
+}
+
+/// Add the symbols in `outline` whose names match `query`, and those nested in them, to
+/// `symbols` as workspace symbols of the document at `uri`
+pub(super) fn workspace_symbols(
+	outline: &[DocumentSymbol],
+	uri: &Uri,
+	container: Option<&str>,
+	query: &str,
+	symbols: &mut Vec<WorkspaceSymbol>,
+) {
+	for symbol in outline {
+		if matches(symbol.name(), query) {
+			let mut information =
+				BaseSymbolInformation::new(symbol.name().clone(), symbol.kind().clone());
+			if let Some(container) = container {
+				information = information.with_container_name(container.to_string());
+			}
+
+			symbols.push(WorkspaceSymbol::new(
+				LocationOrUri::Location(Location::new(
+					uri.clone(),
+					symbol.selection_range().clone(),
+				)),
+				information,
+			));
+		}
+
+		if let Some(children) = symbol.children() {
+			workspace_symbols(children, uri, Some(symbol.name()), query, symbols);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests;
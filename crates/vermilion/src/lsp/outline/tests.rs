@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::path::Path;
+
+use vermilion_verilog::LanguageStd as VerilogStd;
+use vermilion_vhdl::LanguageStd as VhdlStd;
+
+use super::*;
+use crate::{
+	lang::Language,
+	lsp::{db::Database, index::uri_of},
+};
+
+const CORE: &str = "module core #(parameter DEPTH = 4) (input wire clk, output [7:0] q);
+	reg [7:0] count;
+	always_ff @(posedge clk) count <= count + 1;
+	always @* begin : decode
+	end
+	genvar i;
+	for (i = 0; i < DEPTH; i = i + 1) begin : g_lane
+		wire lane;
+	end
+	function automatic logic [7:0] add(input logic [7:0] a);
+		return a;
+	endfunction
+	sub u_sub ();
+endmodule
+";
+
+const COUNTER: &str = "entity counter is
+	generic (WIDTH : natural := 8);
+	port (clk : in bit; q : out bit_vector(WIDTH - 1 downto 0));
+end entity;
+
+architecture rtl of counter is
+	signal count : natural;
+begin
+	tick : process (clk)
+	begin
+	end process;
+	u_sub : entity work.sub port map (clk => clk);
+end architecture;
+";
+
+/// The outline of `text`
+fn outline(language: Language, text: &str) -> Vec<DocumentSymbol> {
+	let mut db = Database::new();
+	let file = db.add_file(language, text.as_bytes().into());
+	let Some(content) = db.text(file).cloned() else {
+		panic!("No text for the file");
+	};
+	let Some(ast) = db.parse(file) else {
+		panic!("Failed to parse the file");
+	};
+
+	Document::new(content, ast).document_symbols()
+}
+
+/// Render `outline` as one `name: detail` line per symbol, indented by how deeply it is nested
+fn render(outline: &[DocumentSymbol], depth: usize, lines: &mut Vec<String>) {
+	for symbol in outline {
+		let indent = "\t".repeat(depth);
+		lines.push(match symbol.detail() {
+			Some(detail) => format!("{indent}{}: {detail}", symbol.name()),
+			None => format!("{indent}{}", symbol.name()),
+		});
+
+		if let Some(children) = symbol.children() {
+			render(children, depth + 1, lines);
+		}
+	}
+}
+
+fn rendered(outline: &[DocumentSymbol]) -> Vec<String> {
+	let mut lines = Vec::new();
+	render(outline, 0, &mut lines);
+	lines
+}
+
+#[test]
+fn test_verilog() {
+	let outline = outline(Language::Verilog(VerilogStd::Sv17), CORE);
+
+	assert_eq!(
+		rendered(&outline),
+		[
+			"core",
+			"\tDEPTH: parameter",
+			"\tclk: input wire",
+			"\tq: output [7:0]",
+			"\tcount: reg [7:0]",
+			"\talways_ff: @(posedge clk)",
+			"\tdecode: always @*",
+			"\ti: genvar",
+			"\tg_lane: for",
+			"\t\tlane: wire",
+			"\tadd: function automatic logic [7:0]",
+			"\tu_sub: sub",
+		]
+	);
+	assert_eq!(outline[0].kind(), &SymbolKind::Module);
+}
+
+#[test]
+fn test_vhdl() {
+	let outline = outline(Language::Vhdl(VhdlStd::Vh08), COUNTER);
+
+	assert_eq!(
+		rendered(&outline),
+		[
+			"counter",
+			"\tWIDTH: natural",
+			"\tclk: in bit",
+			"\tq: out bit_vector(WIDTH - 1 downto 0)",
+			"rtl: of counter",
+			"\tcount: signal natural",
+			"\ttick: process (clk)",
+			"\tu_sub: entity work.sub",
+		]
+	);
+}
+
+#[test]
+fn test_workspace_symbols() {
+	let outline = outline(Language::Verilog(VerilogStd::Sv17), CORE);
+	let Some(uri) = uri_of(Path::new("/rtl/core.sv")) else {
+		panic!("Failed to make a URI");
+	};
+
+	let mut symbols = Vec::new();
+	workspace_symbols(&outline, &uri, None, "glane", &mut symbols);
+	let [symbol] = symbols.as_slice() else {
+		panic!("Expected just `g_lane`, got {symbols:?}");
+	};
+	assert_eq!(symbol.base_symbol_information().name(), "g_lane");
+	assert_eq!(
+		symbol.base_symbol_information().container_name.as_deref(),
+		Some("core")
+	);
+
+	let mut symbols = Vec::new();
+	workspace_symbols(&outline, &uri, None, "", &mut symbols);
+	assert_eq!(symbols.len(), 12);
+
+	assert!(matches("u_core", "UC"));
+	assert!(!matches("u_core", "cu"));
+}
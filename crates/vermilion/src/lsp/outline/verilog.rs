@@ -0,0 +1,267 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::{Span, Spanned};
+use vermilion_lsp::types::{DocumentSymbol, SymbolKind};
+use vermilion_verilog::lang::{
+	ast::{
+		Class, Declarator, Description, GenerateBlock, Item, ItemKind, ModuleKeyword, Ports,
+		Statement, StatementKind,
+	},
+	types::Identifier,
+};
+
+use super::{Document, detailed, nested, symbol};
+use crate::lang::VerilogAst;
+
+/// Build the outline of a Verilog file, with the modules, interfaces, packages, and classes at
+/// the top
+pub(crate) fn document_symbols(document: &Document, ast: &VerilogAst) -> Vec<DocumentSymbol> {
+	ast.descriptions()
+		.iter()
+		.flat_map(|description| {
+			let span = description.span();
+
+			match description.inner() {
+				Description::Module(module) => {
+					let kind = match module.keyword {
+						ModuleKeyword::Interface => SymbolKind::Interface,
+						_ => SymbolKind::Module,
+					};
+
+					let mut children = Vec::new();
+					for parameter in module.parameters.iter().flatten() {
+						children.extend(declarators(
+							document,
+							parameter.span(),
+							&parameter.inner().declarators,
+							SymbolKind::Constant,
+						));
+					}
+					if let Ports::Ansi(ports) = &module.ports {
+						for port in ports {
+							children.extend(declarators(
+								document,
+								port.span(),
+								&port.inner().declarators,
+								SymbolKind::Field,
+							));
+						}
+					}
+					children.extend(items(document, &module.items));
+
+					vec![nested(named(document, &module.name, kind, span), children)]
+				},
+				Description::Primitive(udp) => vec![nested(
+					named(document, &udp.name, SymbolKind::Module, span),
+					items(document, &udp.declarations),
+				)],
+				Description::Package(package) => vec![nested(
+					named(document, &package.name, SymbolKind::Package, span),
+					items(document, &package.items),
+				)],
+				Description::Class(class) => vec![class_symbol(document, span, class)],
+				Description::Item(item) => item_symbols(document, span, item),
+				_ => Vec::new(),
+			}
+		})
+		.collect()
+}
+
+/// A symbol spanning `span` named by the identifier `name`
+fn named(
+	document: &Document,
+	name: &Spanned<Identifier>,
+	kind: SymbolKind,
+	span: &Span,
+) -> DocumentSymbol {
+	symbol(document, name.inner().as_str(), kind, span, name.span())
+}
+
+fn class_symbol(document: &Document, span: &Span, class: &Class) -> DocumentSymbol {
+	nested(
+		named(document, &class.name, SymbolKind::Class, span),
+		class
+			.items
+			.iter()
+			.flat_map(|item| item_symbols(document, item.span(), &item.inner().item))
+			.collect(),
+	)
+}
+
+fn items(document: &Document, items: &[Spanned<Item>]) -> Vec<DocumentSymbol> {
+	items
+		.iter()
+		.flat_map(|item| item_symbols(document, item.span(), item.inner()))
+		.collect()
+}
+
+/// The symbols `item` declares, most declare one per declarator
+fn item_symbols(document: &Document, span: &Span, item: &Item) -> Vec<DocumentSymbol> {
+	match &item.kind {
+		ItemKind::Port(port) => declarators(document, span, &port.declarators, SymbolKind::Field),
+		ItemKind::Net(net) => declarators(document, span, &net.declarators, SymbolKind::Variable),
+		ItemKind::Variable(variable) => {
+			declarators(document, span, &variable.declarators, SymbolKind::Variable)
+		},
+		ItemKind::Parameter(parameter) => {
+			declarators(document, span, &parameter.declarators, SymbolKind::Constant)
+		},
+		ItemKind::Genvar(names) => names
+			.iter()
+			.map(|name| {
+				named(document, name, SymbolKind::Variable, span).with_detail("genvar".to_string())
+			})
+			.collect(),
+		ItemKind::Typedef(typedef) => vec![
+			named(document, &typedef.name, SymbolKind::TypeParameter, span)
+				.with_detail("typedef".to_string()),
+		],
+		ItemKind::Instantiation(instantiation) => instantiation
+			.instances
+			.iter()
+			.filter_map(|instance| {
+				let name = instance.inner().name.as_ref()?;
+				Some(
+					named(document, name, SymbolKind::Object, instance.span())
+						.with_detail(instantiation.module.inner().as_str().to_string()),
+				)
+			})
+			.collect(),
+		ItemKind::Function(function) | ItemKind::AnalogFunction(function) => {
+			vec![subroutine(document, span, &function.name)]
+		},
+		ItemKind::Task(task) => vec![subroutine(document, span, &task.name)],
+		ItemKind::Initial(statement) => vec![process(document, span, "initial", statement)],
+		ItemKind::Always(statement) => vec![process(document, span, "always", statement)],
+		ItemKind::AlwaysComb(statement) => vec![process(document, span, "always_comb", statement)],
+		ItemKind::AlwaysFF(statement) => vec![process(document, span, "always_ff", statement)],
+		ItemKind::AlwaysLatch(statement) => {
+			vec![process(document, span, "always_latch", statement)]
+		},
+		ItemKind::Final(statement) => vec![process(document, span, "final", statement)],
+		ItemKind::Analog(statement) => vec![process(document, span, "analog", statement)],
+		ItemKind::AnalogInitial(statement) => {
+			vec![process(document, span, "analog initial", statement)]
+		},
+		ItemKind::GenerateRegion(region) => items(document, region),
+		ItemKind::GenerateBlock(block) => generate_block(document, span, block, "begin"),
+		ItemKind::GenerateFor(generate) => generate_block(
+			document,
+			generate.block.span(),
+			generate.block.inner(),
+			"for",
+		),
+		ItemKind::GenerateIf(generate) => [("if", &generate.if_true), ("else", &generate.if_false)]
+			.into_iter()
+			.filter_map(|(keyword, block)| {
+				block
+					.as_ref()
+					.map(|block| generate_block(document, block.span(), block.inner(), keyword))
+			})
+			.flatten()
+			.collect(),
+		ItemKind::GenerateCase(generate) => generate
+			.items
+			.iter()
+			.filter_map(|item| item.inner().block.as_ref())
+			.flat_map(|block| generate_block(document, block.span(), block.inner(), "case"))
+			.collect(),
+		ItemKind::Modport(modports) => modports
+			.iter()
+			.map(|modport| {
+				named(
+					document,
+					&modport.inner().name,
+					SymbolKind::Interface,
+					modport.span(),
+				)
+				.with_detail("modport".to_string())
+			})
+			.collect(),
+		ItemKind::Class(class) => vec![class_symbol(document, span, class)],
+		_ => Vec::new(),
+	}
+}
+
+/// A symbol for each of `declarators` of the declaration at `span`, detailed with what the
+/// declaration has before the first of them, such as `input wire [7:0]`
+fn declarators(
+	document: &Document,
+	span: &Span,
+	declarators: &[Spanned<Declarator>],
+	kind: SymbolKind,
+) -> Vec<DocumentSymbol> {
+	let Some(first) = declarators.first() else {
+		return Vec::new();
+	};
+	let detail = document.text_of(&Span::from((*span.begin(), *first.span().begin())));
+
+	declarators
+		.iter()
+		.map(|declarator| {
+			detailed(
+				named(document, &declarator.inner().name, kind.clone(), span),
+				detail.clone(),
+			)
+		})
+		.collect()
+}
+
+/// A function or task, detailed with everything before its name, such as
+/// `function automatic logic [7:0]`
+fn subroutine(document: &Document, span: &Span, name: &Spanned<Identifier>) -> DocumentSymbol {
+	detailed(
+		named(document, name, SymbolKind::Function, span),
+		document.text_of(&Span::from((*span.begin(), *name.span().begin()))),
+	)
+}
+
+/// An `initial`, `always`, or `final` procedure, named after its block if it is a named one
+fn process(
+	document: &Document,
+	span: &Span,
+	keyword: &str,
+	statement: &Spanned<Statement>,
+) -> DocumentSymbol {
+	let (control, body) = match &statement.inner().kind {
+		StatementKind::TimingControl { control, statement } => (Some(control), &**statement),
+		_ => (None, statement),
+	};
+	let label = match &body.inner().kind {
+		StatementKind::Block(block) => block.name.as_ref(),
+		_ => None,
+	};
+	let control = control
+		.map(|control| document.text_of(control.span()))
+		.unwrap_or_default();
+
+	match label {
+		Some(label) => detailed(
+			named(document, label, SymbolKind::Event, span),
+			format!("{keyword} {control}").trim_end().to_string(),
+		),
+		None => detailed(
+			symbol(document, keyword, SymbolKind::Event, span, span),
+			control,
+		),
+	}
+}
+
+/// A named generate block with its contents nested under it, or just its contents if it has
+/// no name
+fn generate_block(
+	document: &Document,
+	span: &Span,
+	block: &GenerateBlock,
+	keyword: &str,
+) -> Vec<DocumentSymbol> {
+	let children = items(document, &block.items);
+
+	match &block.name {
+		Some(name) => vec![nested(
+			named(document, name, SymbolKind::Namespace, span).with_detail(keyword.to_string()),
+			children,
+		)],
+		None => children,
+	}
+}
@@ -0,0 +1,369 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_loc::{Span, Spanned};
+use vermilion_lsp::types::{DocumentSymbol, SymbolKind};
+use vermilion_vhdl::lang::ast::{
+	ConcurrentStatement, ConcurrentStatementKind, Declaration, GenerateBody, Identifier,
+	InstantiatedUnit, InterfaceDeclaration, LibraryUnit, Mode, ObjectClass, Package,
+	SensitivityList, SubprogramKind, SubprogramSpecification, TypeDefinition,
+};
+
+use super::{Document, detailed, nested, symbol};
+use crate::lang::VhdlAst;
+
+/// Build the outline of a VHDL file, with its design units at the top
+pub(crate) fn document_symbols(document: &Document, ast: &VhdlAst) -> Vec<DocumentSymbol> {
+	ast.units()
+		.iter()
+		.filter_map(|unit| library_unit(document, unit.span(), &unit.inner().unit))
+		.collect()
+}
+
+/// A symbol spanning `span` named by the identifier `name`
+fn named(
+	document: &Document,
+	name: &Spanned<Identifier>,
+	kind: SymbolKind,
+	span: &Span,
+) -> DocumentSymbol {
+	symbol(document, name.inner().as_str(), kind, span, name.span())
+}
+
+fn library_unit(document: &Document, span: &Span, unit: &LibraryUnit) -> Option<DocumentSymbol> {
+	Some(match unit {
+		LibraryUnit::Entity(entity) => {
+			let mut children =
+				interfaces(document, entity.generics.as_deref(), SymbolKind::Constant);
+			children.extend(interfaces(
+				document,
+				entity.ports.as_deref(),
+				SymbolKind::Field,
+			));
+			children.extend(declarations(document, &entity.declarations));
+			children.extend(statements(document, &entity.statements));
+
+			nested(
+				named(document, &entity.name, SymbolKind::Module, span),
+				children,
+			)
+		},
+		LibraryUnit::Architecture(architecture) => {
+			let mut children = declarations(document, &architecture.declarations);
+			children.extend(statements(document, &architecture.statements));
+
+			nested(
+				named(document, &architecture.name, SymbolKind::Namespace, span)
+					.with_detail(format!("of {}", architecture.entity.inner().as_str())),
+				children,
+			)
+		},
+		LibraryUnit::Package(package) => package_symbol(document, span, package),
+		LibraryUnit::PackageBody(body) => nested(
+			named(document, &body.name, SymbolKind::Package, span).with_detail("body".to_string()),
+			declarations(document, &body.declarations),
+		),
+		LibraryUnit::PackageInstance(instance) => {
+			named(document, &instance.name, SymbolKind::Package, span).with_detail(format!(
+				"is new {}",
+				document.text_of(instance.package.span())
+			))
+		},
+		LibraryUnit::Configuration(configuration) => {
+			named(document, &configuration.name, SymbolKind::Namespace, span).with_detail(format!(
+				"of {}",
+				document.text_of(configuration.entity.span())
+			))
+		},
+		LibraryUnit::Context(context) => {
+			named(document, &context.name, SymbolKind::Namespace, span)
+				.with_detail("context".to_string())
+		},
+		LibraryUnit::VerificationUnit(unit) => {
+			named(document, &unit.name, SymbolKind::Namespace, span)
+		},
+		LibraryUnit::Error => return None,
+	})
+}
+
+fn package_symbol(document: &Document, span: &Span, package: &Package) -> DocumentSymbol {
+	let mut children = interfaces(document, package.generics.as_deref(), SymbolKind::Constant);
+	children.extend(declarations(document, &package.declarations));
+
+	nested(
+		named(document, &package.name, SymbolKind::Package, span),
+		children,
+	)
+}
+
+/// A symbol for each name declared by a generic or port list, with the objects detailed with
+/// their mode and subtype, such as `in std_logic_vector(7 downto 0)`
+fn interfaces(
+	document: &Document,
+	list: Option<&[Spanned<InterfaceDeclaration>]>,
+	kind: SymbolKind,
+) -> Vec<DocumentSymbol> {
+	list.into_iter()
+		.flatten()
+		.flat_map(|interface| {
+			let span = interface.span();
+
+			match interface.inner() {
+				InterfaceDeclaration::Object(object) => {
+					let subtype = document.text_of(object.subtype.span());
+					let detail = match object.mode {
+						Some(mode) => format!("{} {subtype}", mode_name(mode)),
+						None => subtype,
+					};
+
+					object
+						.names
+						.iter()
+						.map(|name| {
+							named(document, name, kind.clone(), span).with_detail(detail.clone())
+						})
+						.collect()
+				},
+				InterfaceDeclaration::Type(name) => vec![
+					named(document, name, SymbolKind::TypeParameter, span)
+						.with_detail("type".to_string()),
+				],
+				InterfaceDeclaration::Subprogram { specification, .. } => {
+					vec![subprogram(document, span, specification)]
+				},
+				InterfaceDeclaration::Package { name, .. } => {
+					vec![named(document, name, SymbolKind::Package, span)]
+				},
+				InterfaceDeclaration::Terminal { names, .. } => names
+					.iter()
+					.map(|name| {
+						named(document, name, SymbolKind::Variable, span)
+							.with_detail("terminal".to_string())
+					})
+					.collect(),
+			}
+		})
+		.collect()
+}
+
+fn mode_name(mode: Mode) -> &'static str {
+	match mode {
+		Mode::In => "in",
+		Mode::Out => "out",
+		Mode::InOut => "inout",
+		Mode::Buffer => "buffer",
+		Mode::Linkage => "linkage",
+	}
+}
+
+fn declarations(document: &Document, items: &[Spanned<Declaration>]) -> Vec<DocumentSymbol> {
+	items
+		.iter()
+		.flat_map(|declaration| {
+			let span = declaration.span();
+
+			match declaration.inner() {
+				Declaration::Type(declaration) => {
+					let kind = match declaration.definition.as_ref().map(Spanned::inner) {
+						Some(TypeDefinition::Enumeration(_)) => SymbolKind::Enum,
+						Some(TypeDefinition::Record(_)) => SymbolKind::Struct,
+						Some(TypeDefinition::Protected(_) | TypeDefinition::ProtectedBody(_)) => {
+							SymbolKind::Class
+						},
+						_ => SymbolKind::TypeParameter,
+					};
+					vec![
+						named(document, &declaration.name, kind, span)
+							.with_detail("type".to_string()),
+					]
+				},
+				Declaration::Subtype { name, subtype } => vec![
+					named(document, name, SymbolKind::TypeParameter, span)
+						.with_detail(format!("subtype {}", document.text_of(subtype.span()))),
+				],
+				Declaration::Object(object) => {
+					let (kind, class) = match object.class {
+						ObjectClass::Constant => (SymbolKind::Constant, "constant"),
+						ObjectClass::Signal => (SymbolKind::Variable, "signal"),
+						ObjectClass::Variable => (SymbolKind::Variable, "variable"),
+						ObjectClass::File => (SymbolKind::Variable, "file"),
+						ObjectClass::Quantity => (SymbolKind::Variable, "quantity"),
+					};
+					let detail = format!("{class} {}", document.text_of(object.subtype.span()));
+
+					object
+						.names
+						.iter()
+						.map(|name| {
+							named(document, name, kind.clone(), span).with_detail(detail.clone())
+						})
+						.collect()
+				},
+				Declaration::Component(component) => {
+					let mut children = interfaces(
+						document,
+						component.generics.as_deref(),
+						SymbolKind::Constant,
+					);
+					children.extend(interfaces(
+						document,
+						component.ports.as_deref(),
+						SymbolKind::Field,
+					));
+
+					vec![nested(
+						named(document, &component.name, SymbolKind::Interface, span)
+							.with_detail("component".to_string()),
+						children,
+					)]
+				},
+				Declaration::Subprogram(specification) => {
+					vec![subprogram(document, span, specification)]
+				},
+				Declaration::SubprogramBody(body) => {
+					vec![subprogram(document, span, &body.specification)]
+				},
+				Declaration::Package(package) => vec![package_symbol(document, span, package)],
+				Declaration::PackageBody(body) => vec![nested(
+					named(document, &body.name, SymbolKind::Package, span)
+						.with_detail("body".to_string()),
+					declarations(document, &body.declarations),
+				)],
+				_ => Vec::new(),
+			}
+		})
+		.collect()
+}
+
+fn subprogram(
+	document: &Document,
+	span: &Span,
+	specification: &SubprogramSpecification,
+) -> DocumentSymbol {
+	let detail = match specification.kind {
+		SubprogramKind::Procedure => "procedure".to_string(),
+		_ => match &specification.return_type {
+			Some(return_type) => {
+				format!("function return {}", document.text_of(return_type.span()))
+			},
+			None => "function".to_string(),
+		},
+	};
+
+	symbol(
+		document,
+		&document.text_of(specification.designator.span()),
+		SymbolKind::Function,
+		span,
+		specification.designator.span(),
+	)
+	.with_detail(detail)
+}
+
+fn statements(
+	document: &Document,
+	statements: &[Spanned<ConcurrentStatement>],
+) -> Vec<DocumentSymbol> {
+	statements
+		.iter()
+		.flat_map(|statement| concurrent(document, statement.span(), statement.inner()))
+		.collect()
+}
+
+/// The processes, blocks, instances, and generate statements, named after their label or the
+/// keyword that starts them if they have none
+fn concurrent(
+	document: &Document,
+	span: &Span,
+	statement: &ConcurrentStatement,
+) -> Vec<DocumentSymbol> {
+	let labelled = |keyword: &str, kind: SymbolKind| match &statement.label {
+		Some(label) => named(document, label, kind, span),
+		None => symbol(document, keyword, kind, span, span),
+	};
+
+	match &statement.kind {
+		ConcurrentStatementKind::Process(process) => {
+			let sensitivity = match &process.sensitivity {
+				Some(SensitivityList::Names(names)) => format!(
+					"process ({})",
+					names
+						.iter()
+						.map(|name| document.text_of(name.span()))
+						.collect::<Vec<_>>()
+						.join(", ")
+				),
+				Some(SensitivityList::All) => "process (all)".to_string(),
+				None => "process".to_string(),
+			};
+
+			vec![nested(
+				detailed(labelled("process", SymbolKind::Event), sensitivity),
+				declarations(document, &process.declarations),
+			)]
+		},
+		ConcurrentStatementKind::Block(block) => {
+			let mut children =
+				interfaces(document, block.generics.as_deref(), SymbolKind::Constant);
+			children.extend(interfaces(
+				document,
+				block.ports.as_deref(),
+				SymbolKind::Field,
+			));
+			children.extend(declarations(document, &block.declarations));
+			children.extend(statements(document, &block.statements));
+
+			vec![nested(
+				labelled("block", SymbolKind::Namespace).with_detail("block".to_string()),
+				children,
+			)]
+		},
+		ConcurrentStatementKind::Instantiation(instantiation) => {
+			let unit = match &instantiation.unit {
+				InstantiatedUnit::Component(name) => document.text_of(name.span()),
+				InstantiatedUnit::Entity { name, architecture } => match architecture {
+					Some(architecture) => format!(
+						"entity {}({})",
+						document.text_of(name.span()),
+						architecture.inner().as_str()
+					),
+					None => format!("entity {}", document.text_of(name.span())),
+				},
+				InstantiatedUnit::Configuration(name) => {
+					format!("configuration {}", document.text_of(name.span()))
+				},
+			};
+
+			vec![labelled("instance", SymbolKind::Object).with_detail(unit)]
+		},
+		ConcurrentStatementKind::ForGenerate { parameter, range, body } => vec![nested(
+			labelled("generate", SymbolKind::Namespace).with_detail(format!(
+				"for {} in {}",
+				parameter.inner().as_str(),
+				document.text_of(range.span())
+			)),
+			generate_body(document, body),
+		)],
+		ConcurrentStatementKind::IfGenerate(branches) => vec![nested(
+			labelled("generate", SymbolKind::Namespace).with_detail("if".to_string()),
+			branches
+				.iter()
+				.flat_map(|branch| generate_body(document, &branch.inner().body))
+				.collect(),
+		)],
+		ConcurrentStatementKind::CaseGenerate { alternatives, .. } => vec![nested(
+			labelled("generate", SymbolKind::Namespace).with_detail("case".to_string()),
+			alternatives
+				.iter()
+				.flat_map(|alternative| generate_body(document, &alternative.inner().body))
+				.collect(),
+		)],
+		_ => Vec::new(),
+	}
+}
+
+fn generate_body(document: &Document, body: &GenerateBody) -> Vec<DocumentSymbol> {
+	let mut children = declarations(document, &body.declarations);
+	children.extend(statements(document, &body.statements));
+
+	children
+}
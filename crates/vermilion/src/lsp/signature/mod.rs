@@ -0,0 +1,465 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Showing the parameters of what is being instantiated or called at the cursor
+//!
+//! The innermost parenthesis the cursor is in decides what the signature is of. After `name #` it
+//! opens the parameter value assignments of an instantiation of the module `name`, after
+//! `name instance` the port connections of one, after a `$` name the arguments of that system task
+//! or function, and after any other name those of the function or task it names. Ports and
+//! parameters are labelled with the source text they were declared with, so a port shows up as
+//! `output [7:0] q` rather than just its name.
+//!
+//! The active parameter is the one the cursor is in going by the commas before it, or the one
+//! named by a named port connection or parameter value assignment.
+
+use vermilion_loc::{FileId, Span, Spanned};
+use vermilion_lsp::types::{
+	MarkupContent, MarkupKind, MarkupOrString, ParameterInformation, SignatureHelp,
+	SignatureInformation, StringOrTuple,
+};
+use vermilion_verilog::lang::{
+	ast::{Class, Declarator, Description, Item, ItemKind, Module, PortDeclaration, Ports},
+	semantic::SymbolKind,
+	sysfuncs::{BuiltinSysFunc, get_builtin_sysfunc, is_builtin_sysfunc},
+};
+
+use super::{
+	completion::{defining_file, instantiated, is_word, trim_end, unclosed, word_before},
+	db::Database,
+	hover::describe_sysfunc,
+};
+use crate::lang::{Ast, Language};
+
+mod sysfuncs;
+
+/// A port, parameter, or argument in a signature
+#[derive(Clone, Debug)]
+struct Argument {
+	/// The name a named connection refers to it by
+	name:  String,
+	label: String,
+}
+
+/// What is being instantiated or called
+#[derive(Clone, Debug)]
+struct Signature {
+	/// What goes before the parenthesized list of arguments in the label
+	head:          String,
+	arguments:     Vec<Argument>,
+	/// Whether the last argument may be repeated any number of times
+	variadic:      bool,
+	documentation: Option<String>,
+}
+
+impl Signature {
+	/// The signature help showing this signature with the argument at `index`, or the one
+	/// called `name`, active
+	fn help(self, index: usize, name: Option<&str>) -> SignatureHelp {
+		let mut label = format!("{}(", self.head);
+		let mut parameters = Vec::with_capacity(self.arguments.len());
+
+		// NOTE(aki): The offsets into the label are in UTF-16 code units like positions are
+		for (idx, argument) in self.arguments.iter().enumerate() {
+			if idx > 0 {
+				label.push_str(", ");
+			}
+			let begin = utf16_len(&label);
+			label.push_str(&argument.label);
+			parameters.push(ParameterInformation::new(StringOrTuple::Tuple((
+				begin,
+				utf16_len(&label),
+			))));
+		}
+		label.push(')');
+
+		let active = name
+			.and_then(|name| {
+				self.arguments
+					.iter()
+					.position(|argument| argument.name == name)
+			})
+			.unwrap_or(if self.variadic {
+				index.min(self.arguments.len().saturating_sub(1))
+			} else {
+				index
+			});
+
+		let mut information = SignatureInformation::new(label).with_parameters(parameters);
+		if let Some(documentation) = self.documentation {
+			information = information.with_documentation(MarkupOrString::Markup(
+				MarkupContent::new(MarkupKind::Markdown, documentation),
+			));
+		}
+
+		SignatureHelp::new(vec![information])
+			.with_active_signature(0)
+			.with_active_parameter(u32::try_from(active).unwrap_or(u32::MAX))
+	}
+}
+
+fn utf16_len(text: &str) -> u32 {
+	u32::try_from(text.encode_utf16().count()).unwrap_or(u32::MAX)
+}
+
+/// The signature of what the argument list the cursor at `offset` in `file` is in belongs to
+pub(crate) fn signature_help(
+	db: &mut Database,
+	file: FileId,
+	offset: u32,
+) -> Option<SignatureHelp> {
+	let Language::Verilog(_) = db.language(file)? else {
+		return None;
+	};
+	let text = db.text(file)?.clone();
+	let offset = (offset as usize).min(text.len());
+
+	let mut open = unclosed(&text, offset, b'(', b')')?;
+	// The expression of a named connection is part of the list the connection is in
+	if let Some(dot) = named_connection(&text, open) {
+		open = unclosed(&text, dot, b'(', b')')?;
+	}
+	let (index, name) = argument(&text, open, offset);
+
+	let signature = if let Some(sysfunc) = system_function(&text, open) {
+		system_signature(sysfunc)?
+	} else if let Some(signature) = instantiated(&text, open)
+		.and_then(|(module, parameters)| definition(db, file, &module, parameters))
+	{
+		signature
+	} else {
+		call(db, file, &text, open)?
+	};
+
+	Some(signature.help(index, name.as_deref()))
+}
+
+/// The offset of the `.` if the `(` at `open` opens the expression of a named connection
+fn named_connection(text: &[u8], open: usize) -> Option<usize> {
+	let (begin, _) = word_before(text, open)?;
+	let end = trim_end(text, begin);
+
+	// NOTE(aki): `object.method(` calls a method rather than naming a connection
+	(text[..end].ends_with(b".") && word_before(text, end - 1).is_none()).then(|| end - 1)
+}
+
+/// The index of the argument `offset` is in, in the list opened by the `(` at `open`, and the
+/// name it connects to if it is a named connection
+fn argument(text: &[u8], open: usize, offset: usize) -> (usize, Option<String>) {
+	let mut index = 0;
+	let mut depth = 0usize;
+	let mut start = open + 1;
+	let mut string = false;
+	let mut escaped = false;
+
+	for (idx, chr) in text.iter().enumerate().take(offset).skip(open + 1) {
+		if string {
+			match chr {
+				_ if escaped => escaped = false,
+				b'\\' => escaped = true,
+				b'"' => string = false,
+				_ => (),
+			}
+			continue;
+		}
+
+		match chr {
+			b'"' => string = true,
+			b'(' | b'[' | b'{' => depth += 1,
+			b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+			b',' if depth == 0 => {
+				index += 1;
+				start = idx + 1;
+			},
+			_ => (),
+		}
+	}
+
+	let current = text[start..offset].trim_ascii_start();
+	let name = current.strip_prefix(b".").map(|rest| {
+		let end = rest
+			.iter()
+			.position(|chr| !is_word(*chr))
+			.unwrap_or(rest.len());
+		String::from_utf8_lossy(&rest[..end]).into_owned()
+	});
+
+	(index, name)
+}
+
+/// The system task or function whose arguments the `(` at `open` opens
+fn system_function(text: &[u8], open: usize) -> Option<BuiltinSysFunc> {
+	let (begin, name) = word_before(text, open)?;
+
+	// NOTE(aki): A `$` after a word is part of that identifier, not a system function
+	let dollar = begin.checked_sub(1)?;
+	if text[dollar] != b'$' || dollar.checked_sub(1).is_some_and(|idx| is_word(text[idx])) {
+		return None;
+	}
+
+	get_builtin_sysfunc(&name, is_builtin_sysfunc(&name).earliest())
+}
+
+fn system_signature(sysfunc: BuiltinSysFunc) -> Option<Signature> {
+	let arguments = sysfuncs::arguments(sysfunc)?;
+
+	Some(Signature {
+		head:          format!("${}", sysfunc.as_str()),
+		arguments:     arguments
+			.iter()
+			.map(|argument| Argument {
+				name:  argument.trim_end_matches("...").to_string(),
+				label: (*argument).to_string(),
+			})
+			.collect(),
+		variadic:      arguments
+			.last()
+			.is_some_and(|argument| argument.ends_with("...")),
+		documentation: Some(describe_sysfunc(sysfunc)),
+	})
+}
+
+/// The signature of the parameter value assignments, or port connections, of an instantiation
+/// of `module` in `file`
+fn definition(
+	db: &mut Database,
+	file: FileId,
+	module: &str,
+	parameters: bool,
+) -> Option<Signature> {
+	let source = defining_file(db, file, module)?;
+	let location = {
+		let table = db.scopes(source)?;
+		table.symbol(table.definition(module)?).location
+	};
+	let text = db.text(*location.file_id())?.clone();
+	let Some(Ast::Verilog(ast)) = db.parse(*location.file_id()) else {
+		return None;
+	};
+
+	let module = ast
+		.descriptions()
+		.iter()
+		.find_map(|description| match description.inner() {
+			Description::Module(module) if module.name.span() == location.span() => Some(module),
+			_ => None,
+		})?;
+	let name = source_text(&text, *module.name.span());
+
+	Some(if parameters {
+		Signature {
+			head:          format!("{name} #"),
+			arguments:     module_parameters(&text, module),
+			variadic:      false,
+			documentation: None,
+		}
+	} else {
+		Signature {
+			head:          format!("{name} "),
+			arguments:     module_ports(&text, module),
+			variadic:      false,
+			documentation: None,
+		}
+	})
+}
+
+/// The parameters of `module` that can be overridden, in the order they are declared
+fn module_parameters(text: &[u8], module: &Module) -> Vec<Argument> {
+	match &module.parameters {
+		Some(parameters) => parameters
+			.iter()
+			.filter(|parameter| !parameter.inner().local)
+			.flat_map(|parameter| {
+				arguments(text, *parameter.span(), &parameter.inner().declarators)
+			})
+			.collect(),
+		// NOTE(aki): Without a parameter port list the parameters in the body can be overridden
+		None => module
+			.items
+			.iter()
+			.filter_map(|item| match &item.inner().kind {
+				ItemKind::Parameter(parameter) if !parameter.local => {
+					Some(arguments(text, *item.span(), &parameter.declarators))
+				},
+				_ => None,
+			})
+			.flatten()
+			.collect(),
+	}
+}
+
+/// The ports of `module` in the order they are listed in its header
+fn module_ports(text: &[u8], module: &Module) -> Vec<Argument> {
+	match &module.ports {
+		Ports::None => Vec::new(),
+		Ports::Ansi(ports) => port_arguments(text, Some(ports), &[]),
+		Ports::NonAnsi(ports) => {
+			let declared = port_arguments(text, None, &module.items);
+
+			ports
+				.iter()
+				.map(|port| {
+					let name = port.inner().name.as_ref().map_or_else(
+						|| source_text(text, *port.span()),
+						|name| source_text(text, *name.span()),
+					);
+
+					declared
+						.iter()
+						.find(|argument| argument.name == name)
+						.cloned()
+						.unwrap_or(Argument { label: name.clone(), name })
+				})
+				.collect()
+		},
+	}
+}
+
+/// The signature of the function or task called by the `(` at `open` in `file`
+fn call(db: &mut Database, file: FileId, text: &[u8], open: usize) -> Option<Signature> {
+	let (begin, name) = word_before(text, open)?;
+	let end = trim_end(text, begin);
+
+	// The scope the name is looked up in, that of the package or class in `scope::name`
+	let (source, scope) = if text[..end].ends_with(b"::") {
+		let (_, scope) = word_before(text, end - 2)?;
+		let source = defining_file(db, file, &scope).unwrap_or(file);
+		let table = db.scopes(source)?;
+		let symbol = table.package(&scope).or_else(|| {
+			table
+				.scope_at(file, begin as u32)
+				.and_then(|current| table.lookup(current, &scope))
+		})?;
+
+		(source, table.symbol(symbol).body?)
+	} else {
+		(file, db.scopes(file)?.scope_at(file, begin as u32)?)
+	};
+
+	let location = {
+		let table = db.scopes(source)?;
+		let symbol = table.symbol(table.lookup(scope, &name)?);
+		matches!(symbol.kind, SymbolKind::Function | SymbolKind::Task).then_some(symbol.location)?
+	};
+	let text = db.text(*location.file_id())?.clone();
+	let Some(Ast::Verilog(ast)) = db.parse(*location.file_id()) else {
+		return None;
+	};
+	let name = *location.span();
+
+	ast.descriptions()
+		.iter()
+		.find_map(|description| match description.inner() {
+			Description::Module(module) => in_items(&text, &module.items, name),
+			Description::Package(package) => in_items(&text, &package.items, name),
+			Description::Class(class) => in_class(&text, class, name),
+			Description::Item(item) => in_item(&text, *description.span(), item, name),
+			_ => None,
+		})
+}
+
+fn in_items(text: &[u8], items: &[Spanned<Item>], name: Span) -> Option<Signature> {
+	items
+		.iter()
+		.find_map(|item| in_item(text, *item.span(), item.inner(), name))
+}
+
+fn in_class(text: &[u8], class: &Class, name: Span) -> Option<Signature> {
+	class
+		.items
+		.iter()
+		.find_map(|item| in_item(text, *item.span(), &item.inner().item, name))
+}
+
+/// The signature of the function or task whose name is at `name`, if `item` or anything in it
+/// declares it
+fn in_item(text: &[u8], span: Span, item: &Item, name: Span) -> Option<Signature> {
+	match &item.kind {
+		ItemKind::Function(function) if *function.name.span() == name => Some(subroutine(
+			text,
+			span,
+			name,
+			port_arguments(text, function.ports.as_deref(), &function.items),
+		)),
+		ItemKind::Task(task) if *task.name.span() == name => Some(subroutine(
+			text,
+			span,
+			name,
+			port_arguments(text, task.ports.as_deref(), &task.items),
+		)),
+		ItemKind::GenerateRegion(items) => in_items(text, items, name),
+		ItemKind::GenerateBlock(block) => in_items(text, &block.items, name),
+		ItemKind::Class(class) => in_class(text, class, name),
+		_ => None,
+	}
+}
+
+/// The signature of the function or task declared at `span` with its name at `name`
+fn subroutine(text: &[u8], span: Span, name: Span, arguments: Vec<Argument>) -> Signature {
+	Signature {
+		head: source_text(text, Span::from((*span.begin(), *name.end()))),
+		arguments,
+		variadic: false,
+		documentation: None,
+	}
+}
+
+/// The arguments for the ports declared in a port list, or if there is none for those declared
+/// with port declarations in `items`
+fn port_arguments(
+	text: &[u8],
+	ports: Option<&[Spanned<PortDeclaration>]>,
+	items: &[Spanned<Item>],
+) -> Vec<Argument> {
+	match ports {
+		Some(ports) => ports
+			.iter()
+			.flat_map(|port| arguments(text, *port.span(), &port.inner().declarators))
+			.collect(),
+		None => items
+			.iter()
+			.filter_map(|item| match &item.inner().kind {
+				ItemKind::Port(port) => Some(arguments(text, *item.span(), &port.declarators)),
+				_ => None,
+			})
+			.flatten()
+			.collect(),
+	}
+}
+
+/// The arguments for each of `declarators` of the declaration at `span`, labelled with what
+/// the declaration has before the first of them followed by the declarator itself
+fn arguments(text: &[u8], span: Span, declarators: &[Spanned<Declarator>]) -> Vec<Argument> {
+	let Some(first) = declarators.first() else {
+		return Vec::new();
+	};
+	let prefix = source_text(text, Span::from((*span.begin(), *first.span().begin())));
+
+	declarators
+		.iter()
+		.map(|declarator| {
+			let source = source_text(text, *declarator.span());
+			Argument {
+				name:  source_text(text, *declarator.inner().name.span()),
+				label: if prefix.is_empty() {
+					source
+				} else {
+					format!("{prefix} {source}")
+				},
+			}
+		})
+		.collect()
+}
+
+/// The text at `span` with any runs of whitespace in it collapsed to a single space
+fn source_text(text: &[u8], span: Span) -> String {
+	let end = (*span.end() as usize).min(text.len());
+	let begin = (*span.begin() as usize).min(end);
+
+	String::from_utf8_lossy(&text[begin..end])
+		.split_whitespace()
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
+#[cfg(test)]
+mod tests;
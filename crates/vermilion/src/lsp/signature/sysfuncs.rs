@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_verilog::lang::sysfuncs::BuiltinSysFunc;
+
+/// The arguments of a system task or function as the LRM names them, a trailing `...` marks
+/// an argument that may be repeated
+///
+/// Those without an entry either take no arguments or have too many forms to show as one.
+pub(super) fn arguments(sysfunc: BuiltinSysFunc) -> Option<&'static [&'static str]> {
+	Some(match sysfunc {
+		BuiltinSysFunc::Display |
+		BuiltinSysFunc::DisplayB |
+		BuiltinSysFunc::DisplayH |
+		BuiltinSysFunc::DisplayO |
+		BuiltinSysFunc::Write |
+		BuiltinSysFunc::WriteB |
+		BuiltinSysFunc::WriteH |
+		BuiltinSysFunc::WriteO |
+		BuiltinSysFunc::Strobe |
+		BuiltinSysFunc::StrobeB |
+		BuiltinSysFunc::StrobeH |
+		BuiltinSysFunc::StrobeO |
+		BuiltinSysFunc::Monitor |
+		BuiltinSysFunc::MonitorB |
+		BuiltinSysFunc::MonitorH |
+		BuiltinSysFunc::MonitorO |
+		BuiltinSysFunc::Info |
+		BuiltinSysFunc::Warning |
+		BuiltinSysFunc::Error => &["format", "args..."],
+		BuiltinSysFunc::Fatal => &["finish_number", "format", "args..."],
+		BuiltinSysFunc::Fdisplay |
+		BuiltinSysFunc::FdisplayB |
+		BuiltinSysFunc::FdisplayH |
+		BuiltinSysFunc::FdisplayO |
+		BuiltinSysFunc::Fwrite |
+		BuiltinSysFunc::FwriteB |
+		BuiltinSysFunc::FwriteH |
+		BuiltinSysFunc::FwriteO |
+		BuiltinSysFunc::Fstrobe |
+		BuiltinSysFunc::FstrobeB |
+		BuiltinSysFunc::FstrobeH |
+		BuiltinSysFunc::FstrobeO |
+		BuiltinSysFunc::Fmonitor |
+		BuiltinSysFunc::FmonitorB |
+		BuiltinSysFunc::FmonitorH |
+		BuiltinSysFunc::FmonitorO => &["fd", "format", "args..."],
+		BuiltinSysFunc::Swrite |
+		BuiltinSysFunc::SwriteB |
+		BuiltinSysFunc::SwriteH |
+		BuiltinSysFunc::SwriteO => &["output_var", "args..."],
+		BuiltinSysFunc::Sformat => &["output_var", "format", "args..."],
+		BuiltinSysFunc::Sformatf => &["format", "args..."],
+		BuiltinSysFunc::Fopen => &["filename", "type"],
+		BuiltinSysFunc::Fclose | BuiltinSysFunc::Fflush | BuiltinSysFunc::Feof => &["fd"],
+		BuiltinSysFunc::Fgetc | BuiltinSysFunc::Ftell | BuiltinSysFunc::Rewind => &["fd"],
+		BuiltinSysFunc::Ungetc => &["c", "fd"],
+		BuiltinSysFunc::Fgets => &["str", "fd"],
+		BuiltinSysFunc::Fscanf => &["fd", "format", "args..."],
+		BuiltinSysFunc::Sscanf => &["str", "format", "args..."],
+		BuiltinSysFunc::Fread => &["integral_var", "fd"],
+		BuiltinSysFunc::Fseek => &["fd", "offset", "operation"],
+		BuiltinSysFunc::Ferror => &["fd", "str"],
+		BuiltinSysFunc::ReadmemB |
+		BuiltinSysFunc::ReadmemH |
+		BuiltinSysFunc::WritememB |
+		BuiltinSysFunc::WritememH => &["filename", "memory_name", "start_addr", "finish_addr"],
+		BuiltinSysFunc::Finish | BuiltinSysFunc::Stop | BuiltinSysFunc::Exit => &["finish_number"],
+		BuiltinSysFunc::TimeFormat => {
+			&["units_number", "precision_number", "suffix_string", "minimum_field_width"]
+		},
+		BuiltinSysFunc::PrintTimescale => &["hierarchical_identifier"],
+		BuiltinSysFunc::DumpFile => &["filename"],
+		BuiltinSysFunc::DumpVars => &["levels", "module_or_variable..."],
+		BuiltinSysFunc::DumpLimit => &["file_size"],
+		BuiltinSysFunc::TestPlusArgs => &["string"],
+		BuiltinSysFunc::ValuePlusArgs => &["user_string", "variable"],
+		BuiltinSysFunc::Random => &["seed"],
+		BuiltinSysFunc::Urandom => &["seed"],
+		BuiltinSysFunc::UrandomRange => &["maxval", "minval"],
+		BuiltinSysFunc::DistUniform => &["seed", "start", "end"],
+		BuiltinSysFunc::DistNormal => &["seed", "mean", "standard_deviation"],
+		BuiltinSysFunc::DistExponential | BuiltinSysFunc::DistPoisson => &["seed", "mean"],
+		BuiltinSysFunc::DistChiSquare | BuiltinSysFunc::DistT => &["seed", "degree_of_freedom"],
+		BuiltinSysFunc::DistErlang => &["seed", "k_stage", "mean"],
+		BuiltinSysFunc::Signed |
+		BuiltinSysFunc::Unsigned |
+		BuiltinSysFunc::Clog2 |
+		BuiltinSysFunc::CountOnes |
+		BuiltinSysFunc::OneHot |
+		BuiltinSysFunc::OneHot0 |
+		BuiltinSysFunc::IsUnknown => &["expression"],
+		BuiltinSysFunc::CountBits => &["expression", "control_bit..."],
+		BuiltinSysFunc::Bits | BuiltinSysFunc::TypeName => &["expression"],
+		BuiltinSysFunc::Cast => &["dest_var", "source_exp"],
+		BuiltinSysFunc::Itor | BuiltinSysFunc::BitsToReal | BuiltinSysFunc::BitsToShortReal => {
+			&["bit_val"]
+		},
+		BuiltinSysFunc::Rtoi | BuiltinSysFunc::RealToBits | BuiltinSysFunc::ShortRealToBits => {
+			&["real_val"]
+		},
+		BuiltinSysFunc::Left |
+		BuiltinSysFunc::Right |
+		BuiltinSysFunc::Low |
+		BuiltinSysFunc::High |
+		BuiltinSysFunc::Increment |
+		BuiltinSysFunc::Size => &["array", "dimension"],
+		BuiltinSysFunc::Dimensions | BuiltinSysFunc::UnpackedDimensions => &["array"],
+		BuiltinSysFunc::Ln |
+		BuiltinSysFunc::Log10 |
+		BuiltinSysFunc::Exp |
+		BuiltinSysFunc::Sqrt |
+		BuiltinSysFunc::Floor |
+		BuiltinSysFunc::Ceil |
+		BuiltinSysFunc::Sin |
+		BuiltinSysFunc::Cos |
+		BuiltinSysFunc::Tan |
+		BuiltinSysFunc::Asin |
+		BuiltinSysFunc::Acos |
+		BuiltinSysFunc::Atan |
+		BuiltinSysFunc::Sinh |
+		BuiltinSysFunc::Cosh |
+		BuiltinSysFunc::Tanh |
+		BuiltinSysFunc::Asinh |
+		BuiltinSysFunc::Acosh |
+		BuiltinSysFunc::Atanh => &["x"],
+		BuiltinSysFunc::Pow => &["x", "y"],
+		BuiltinSysFunc::Atan2 | BuiltinSysFunc::Hypot => &["y", "x"],
+		BuiltinSysFunc::Rose |
+		BuiltinSysFunc::Fell |
+		BuiltinSysFunc::Stable |
+		BuiltinSysFunc::Changed => &["expression", "clocking_event"],
+		BuiltinSysFunc::Past => &["expression", "number_of_ticks", "expression2", "clocking_event"],
+		BuiltinSysFunc::Sampled => &["expression"],
+		BuiltinSysFunc::AssertControl => {
+			&["control_type", "assertion_type", "directive_type", "levels", "list..."]
+		},
+		BuiltinSysFunc::Setup => &["data_event", "reference_event", "limit", "notifier"],
+		BuiltinSysFunc::Hold => &["reference_event", "data_event", "limit", "notifier"],
+		BuiltinSysFunc::Setuphold => {
+			&["reference_event", "data_event", "setup_limit", "hold_limit", "notifier"]
+		},
+		BuiltinSysFunc::Recovery => &["reference_event", "data_event", "limit", "notifier"],
+		BuiltinSysFunc::Width => &["reference_event", "limit", "threshold", "notifier"],
+		BuiltinSysFunc::Period => &["reference_event", "limit", "notifier"],
+		BuiltinSysFunc::Skew => &["reference_event", "data_event", "limit", "notifier"],
+		BuiltinSysFunc::SdfAnnotate => &[
+			"sdf_file",
+			"module_instance",
+			"config_file",
+			"log_file",
+			"mtm_spec",
+			"scale_factors",
+			"scale_type",
+		],
+		_ => return None,
+	})
+}
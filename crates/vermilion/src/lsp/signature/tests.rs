@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+use vermilion_verilog::LanguageStd;
+
+use super::*;
+use crate::lsp::fixture::{cursor, offset};
+
+const VERILOG: Language = Language::Verilog(LanguageStd::Sv17);
+
+const CORE: &str = "module core #(parameter DEPTH = 4, localparam LAST = DEPTH - 1) (
+	input        clk,
+	output [7:0] q
+);
+endmodule
+";
+
+const PKG: &str = "package pkg;
+	function automatic logic [7:0] add(input logic [7:0] a, b);
+		return a + b;
+	endfunction
+endpackage
+";
+
+/// The signature at the `|` in `text` with `CORE` and `PKG` in the workspace, its parameter
+/// labels, and the label of the active one
+fn signature_at(text: &str) -> (String, Vec<String>, Option<String>) {
+	let (text, offset) = cursor(text);
+
+	let mut db = Database::new();
+	let file = db.add_file(VERILOG, text.as_bytes().into());
+	db.add_file(VERILOG, CORE.as_bytes().into());
+	db.add_file(VERILOG, PKG.as_bytes().into());
+
+	let Some(help) = signature_help(&mut db, file, offset) else {
+		panic!("No signature help in {text:?}");
+	};
+	let Some(signature) = help.signatures().first() else {
+		panic!("No signature in {text:?}");
+	};
+
+	// The labels are ASCII here, so the UTF-16 offsets are byte offsets
+	let label = signature.label().clone();
+	let parameters = signature
+		.parameters()
+		.into_iter()
+		.flatten()
+		.map(|parameter| match parameter.label() {
+			StringOrTuple::Tuple((begin, end)) => label[*begin as usize..*end as usize].to_string(),
+			StringOrTuple::String(label) => label.clone(),
+		})
+		.collect::<Vec<_>>();
+	let active = help
+		.active_parameter()
+		.and_then(|active| parameters.get(*active as usize).cloned());
+
+	(label, parameters, active)
+}
+
+#[test]
+fn test_ports() {
+	let (label, parameters, active) =
+		signature_at("module top;\n\tcore u_core (clk, |);\nendmodule\n");
+	assert_eq!(label, "core (input clk, output [7:0] q)");
+	assert_eq!(parameters, ["input clk", "output [7:0] q"]);
+	assert_eq!(active.as_deref(), Some("output [7:0] q"));
+
+	// Named connections are matched by name, even from inside their expression
+	let (_, _, active) = signature_at(
+		"module top;\n\tcore #(.DEPTH(2)) u_core [1:0] (\n\t\t.q(),\n\t\t.clk(a[0] \
+		 |\n\t);\nendmodule\n",
+	);
+	assert_eq!(active.as_deref(), Some("input clk"));
+
+	let (label, ..) = signature_at(
+		"module legacy (a, b);\n\tinput a;\n\toutput reg b;\nendmodule\nmodule top;\n\tlegacy u \
+		 (|);\nendmodule\n",
+	);
+	assert_eq!(label, "legacy (input a, output reg b)");
+}
+
+#[test]
+fn test_parameters() {
+	let (label, parameters, active) =
+		signature_at("module top;\n\tcore #(|) u_core ();\nendmodule\n");
+	assert_eq!(label, "core #(parameter DEPTH = 4)");
+	assert_eq!(parameters, ["parameter DEPTH = 4"]);
+	assert_eq!(active.as_deref(), Some("parameter DEPTH = 4"));
+}
+
+#[test]
+fn test_subroutines() {
+	let (label, parameters, active) = signature_at(
+		"module top;\n\ttask automatic send(input int id, input string \
+		 name);\n\tendtask\n\tinitial send(1, \"a, b\"|);\nendmodule\n",
+	);
+	assert_eq!(
+		label,
+		"task automatic send(input int id, input string name)"
+	);
+	assert_eq!(parameters, ["input int id", "input string name"]);
+	assert_eq!(active.as_deref(), Some("input string name"));
+
+	let (label, _, active) =
+		signature_at("module top;\n\tassign x = pkg::add(f(1, 2), |);\nendmodule\n");
+	assert_eq!(
+		label,
+		"function automatic logic [7:0] add(input logic [7:0] a, input logic [7:0] b)"
+	);
+	assert_eq!(active.as_deref(), Some("input logic [7:0] b"));
+}
+
+#[test]
+fn test_system_functions() {
+	let (label, _, active) =
+		signature_at("module top;\n\tinitial $fdisplay(fd, \"%d %d\", a, b|);\nendmodule\n");
+	assert_eq!(label, "$fdisplay(fd, format, args...)");
+	// Any number of arguments can follow the format
+	assert_eq!(active.as_deref(), Some("args..."));
+
+	let mut db = Database::new();
+	let file = db.add_file(VERILOG, CORE.as_bytes().into());
+	assert!(signature_help(&mut db, file, offset(CORE, "input", 0)).is_none());
+}
@@ -1,27 +1,61 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
-use std::collections::HashMap;
+use std::{
+	collections::HashMap,
+	path::{Path, PathBuf},
+};
 
+use tokio::sync::mpsc::UnboundedSender;
 use tracing::{debug, warn};
 use vermilion_lang::AtomicByteTendril;
-use vermilion_lsp::types::{
-	Diagnostic, LanguageId, TextDocumentContentChangeEvent, TextDocumentItem, Uri,
-	semantic_tokens::{SemanticToken, SemanticTokens},
+use vermilion_loc::{FileId, Span};
+use vermilion_lsp::{
+	prelude::{Id, Message, Response},
+	types::{
+		CompletionItem, CompletionList, DocumentSymbol, FileChangeType, FileEvent, Hover,
+		HoverContents, ItemDefaults, LanguageId, Location, MarkupContent, MarkupKind, Position,
+		ProgressToken, Range, SignatureHelp, TextDocumentContentChangeEvent, TextDocumentItem, Uri,
+		WorkspaceSymbol,
+		semantic_tokens::{SemanticToken, SemanticTokens},
+	},
 };
-use vermilion_verilog::LanguageStd as VerilogStd;
+use vermilion_verilog::{LanguageStd as VerilogStd, lang::incremental::TextEdit};
 use vermilion_vhdl::LanguageStd as VhdlStd;
 
-use crate::lang::{Ast, Language, VerilogAst, VhdlAst};
+use super::{
+	bsdl, completion,
+	db::Database,
+	hover,
+	index::{self, IndexedFile},
+	lines::LineIndex,
+	navigation::{self, Goto},
+	outline, signature,
+};
+use crate::{
+	lang::{Ast, Language, VerilogAst, VhdlAst},
+	workspace::Sources,
+};
 
 pub struct Workspace {
-	documents: HashMap<Uri, Document>,
+	db:      Database,
+	/// The files of the open documents in the database
+	files:   HashMap<Uri, FileId>,
+	/// The files of the HDL files on disk under the workspace root
+	paths:   HashMap<PathBuf, FileId>,
+	/// The URI of each file, which is the one it was opened with if it is open
+	uris:    HashMap<FileId, Uri>,
+	/// Where the files indexed in the background are sent to be added to the workspace
+	indexer: UnboundedSender<IndexedFile>,
+	/// The roots to index once the client answers the request with the ID creating the progress
+	/// token to report the indexing on
+	pending: Option<(Id, Vec<PathBuf>, ProgressToken)>,
 }
 
-pub struct Document {
-	content:      AtomicByteTendril,
-	_language:    Language,
-	ast:          Ast,
-	_diagnostics: Vec<Diagnostic>,
+/// A view of a single document in the workspace
+pub struct Document<'a> {
+	content: AtomicByteTendril,
+	lines:   LineIndex,
+	ast:     &'a Ast,
 }
 
 fn language_for(id: &LanguageId) -> Option<Language> {
@@ -32,6 +66,7 @@ fn language_for(id: &LanguageId) -> Option<Language> {
 			"system-verilog" => Some(Language::Verilog(VerilogStd::Sv05)),
 			"verilog-ams" => Some(Language::Verilog(VerilogStd::Vams09)),
 			"vhdl-ams" => Some(Language::Vhdl(VhdlStd::Vhams99)),
+			"bsdl" => Some(Language::Vhdl(VhdlStd::Bsdl)),
 			&_ => None,
 		},
 		_ => None,
@@ -39,12 +74,60 @@ fn language_for(id: &LanguageId) -> Option<Language> {
 }
 
 impl Workspace {
-	pub fn new() -> Self {
-		Self { documents: HashMap::new() }
+	pub fn new(sources: Sources, indexer: UnboundedSender<IndexedFile>) -> Self {
+		let mut db = Database::new();
+		db.set_sources(sources);
+
+		Self {
+			db,
+			files: HashMap::new(),
+			paths: HashMap::new(),
+			uris: HashMap::new(),
+			indexer,
+			pending: None,
+		}
+	}
+
+	/// The file of the document at `uri`, be it open or only on disk
+	fn file_for(&self, uri: &Uri) -> Option<FileId> {
+		self.files
+			.get(uri)
+			.or_else(|| self.paths.get(&index::path_of(uri)?))
+			.copied()
 	}
 
-	pub fn find_document(&self, uri: &Uri) -> Option<&Document> {
-		self.documents.get(uri)
+	fn is_open(&self, file: FileId) -> bool {
+		self.files.values().any(|open| *open == file)
+	}
+
+	pub fn find_document(&mut self, uri: &Uri) -> Option<Document<'_>> {
+		let file = self.file_for(uri)?;
+		let content = self.db.text(file)?.clone();
+		let ast = self.db.parse(file)?;
+
+		Some(Document::new(content, ast))
+	}
+
+	/// The symbols of every file in the workspace whose names match `query`
+	pub fn workspace_symbols(&mut self, query: &str) -> Vec<WorkspaceSymbol> {
+		let mut symbols = Vec::new();
+
+		for file in self.db.files() {
+			let Some(uri) = self.uri_of(file) else {
+				continue;
+			};
+			let Some(content) = self.db.text(file).cloned() else {
+				continue;
+			};
+			let Some(ast) = self.db.parse(file) else {
+				continue;
+			};
+
+			let outline = Document::new(content, ast).document_symbols();
+			outline::workspace_symbols(&outline, &uri, None, query, &mut symbols);
+		}
+
+		symbols
 	}
 
 	pub fn open_document(&mut self, document: TextDocumentItem) {
@@ -59,58 +142,385 @@ impl Workspace {
 			return;
 		};
 
+		// Documents on disk use the standard from the workspace settings, like the files indexed
+		let path = index::path_of(document.uri());
+		let language = match &path {
+			Some(path) => self.db.sources().workspace.language_for(path, language),
+			None => language,
+		};
+
+		// NOTE(aki): The open document takes the place of the file on disk until it is closed
 		let content = AtomicByteTendril::from_slice(document.text().as_bytes());
-		let ast = language.parse_file(content.clone());
-
-		self.documents.insert(
-			document.uri().clone(),
-			Document {
-				content,
-				_language: language,
-				ast,
-				_diagnostics: Vec::new(),
+		let file = match self.file_for(document.uri()) {
+			Some(file) => {
+				self.db.set_text(file, language, content);
+				file
 			},
-		);
+			None => self.db.add_file(language, content),
+		};
+		if let Some(path) = path {
+			self.db.set_path(file, path);
+		}
+		self.files.insert(document.uri().clone(), file);
+		self.uris.insert(file, document.uri().clone());
 	}
 
 	pub fn close_document(&mut self, uri: &Uri) {
-		self.documents.remove(uri);
+		let Some(file) = self.files.remove(uri) else {
+			return;
+		};
+
+		// Files in the workspace go back to what is on disk, anything else is forgotten
+		let path = index::path_of(uri);
+		match path
+			.as_deref()
+			.filter(|path| self.paths.get(*path) == Some(&file))
+		{
+			Some(path) => self.reload(path, file),
+			None => self.remove_file(file),
+		}
+	}
+
+	/// Index all of the HDL files under `roots` in the background
+	///
+	/// The files are sent back through the `indexer` as they are parsed, to be added to the
+	/// workspace with [`Self::add_indexed`].
+	pub fn index(
+		&mut self,
+		roots: Vec<PathBuf>,
+		token: Option<ProgressToken>,
+		response_channel: UnboundedSender<Message>,
+	) {
+		let indexer = self.indexer.clone();
+		let sources = self.db.sources().clone();
+		tokio::task::spawn_blocking(move || {
+			index::index_workspace(roots, &sources, token, response_channel, indexer);
+		});
+	}
+
+	/// Index all of the HDL files under `roots` in the background once the client answers the
+	/// request `id` creating the progress `token`, see [`Self::progress_created`]
+	pub fn index_when_created(&mut self, id: Id, roots: Vec<PathBuf>, token: ProgressToken) {
+		self.pending = Some((id, roots, token));
+	}
+
+	/// Start the indexing waiting on `response`, if it is the answer to the request creating its
+	/// progress token, which is only reported on if the client did create it
+	pub fn progress_created(
+		&mut self,
+		response: &Response,
+		response_channel: UnboundedSender<Message>,
+	) {
+		let Some((_, roots, token)) = self.pending.take_if(|(id, ..)| id == response.id()) else {
+			return;
+		};
+
+		let token = match response.error() {
+			Some(err) => {
+				warn!("Unable to create the indexing progress: {err}");
+				None
+			},
+			None => Some(token),
+		};
+		self.index(roots, token, response_channel);
+	}
+
+	/// Add a file indexed in the background, unless it is already known
+	pub fn add_indexed(&mut self, indexed: IndexedFile) {
+		if self.paths.contains_key(&indexed.path) {
+			return;
+		}
+
+		let open = self.files.iter().find_map(|(uri, file)| {
+			index::path_of(uri)
+				.is_some_and(|path| path == indexed.path)
+				.then_some(*file)
+		});
+		let file = open.unwrap_or_else(|| {
+			self.db.add_parsed_file(
+				indexed.path.clone(),
+				indexed.language,
+				indexed.content,
+				indexed.ast,
+			)
+		});
+		if let Some(uri) = index::uri_of(&indexed.path) {
+			self.uris.entry(file).or_insert(uri);
+		}
+		self.paths.insert(indexed.path, file);
+	}
+
+	/// Bring the workspace up to date with files on disk being created, changed, or deleted
+	pub fn change_watched_files(&mut self, changes: Vec<FileEvent>) {
+		for change in changes {
+			let Some(path) = index::path_of(change.uri()) else {
+				continue;
+			};
+
+			match (change.typ(), self.paths.get(&path).copied()) {
+				(FileChangeType::Created | FileChangeType::Changed, Some(file)) => {
+					if !self.is_open(file) {
+						self.reload(&path, file);
+					}
+				},
+				(FileChangeType::Created | FileChangeType::Changed, None) => {
+					let workspace = &self.db.sources().workspace;
+					if let Some((language, content)) = index::read_file(&path, workspace) {
+						let file = self.db.add_file(language, content);
+						self.db.set_path(file, path.clone());
+						self.paths.insert(path, file);
+						self.uris.insert(file, change.uri().clone());
+					}
+				},
+				(FileChangeType::Deleted, Some(file)) => {
+					self.paths.remove(&path);
+					if !self.is_open(file) {
+						self.remove_file(file);
+					}
+				},
+				(FileChangeType::Deleted, None) => (),
+			}
+		}
+	}
+
+	/// The locations to go to from `position` in the document at `uri`
+	pub fn goto(&mut self, uri: &Uri, position: &Position, goto: Goto) -> Vec<Location> {
+		let Some((file, offset)) = self.offset_at(uri, position) else {
+			return Vec::new();
+		};
+
+		navigation::goto(&mut self.db, file, offset, goto)
+			.iter()
+			.filter_map(|location| self.location_of(location))
+			.collect()
+	}
+
+	/// The uses of the name at `position` in the document at `uri` throughout the workspace
+	pub fn references(
+		&mut self,
+		uri: &Uri,
+		position: &Position,
+		include_declaration: bool,
+	) -> Vec<Location> {
+		let Some((file, offset)) = self.offset_at(uri, position) else {
+			return Vec::new();
+		};
+
+		navigation::references(&mut self.db, file, offset, include_declaration)
+			.iter()
+			.filter_map(|location| self.location_of(location))
+			.collect()
+	}
+
+	/// The description of what is at `position` in the document at `uri`
+	pub fn hover(&mut self, uri: &Uri, position: &Position) -> Option<Hover> {
+		let (file, offset) = self.offset_at(uri, position)?;
+
+		let Some((span, value)) = hover::hover(&mut self.db, file, offset) else {
+			// NOTE(aki): BSDL descriptions are described from the document alone
+			return self.find_document(uri)?.hover(position);
+		};
+
+		Some(
+			Hover::new(HoverContents::MarkupContent(MarkupContent::new(
+				MarkupKind::Markdown,
+				value,
+			)))
+			.with_range(self.range_in(file, &span)?),
+		)
+	}
+
+	/// The completions of the name being typed at `position` in the document at `uri`
+	pub fn completion(&mut self, uri: &Uri, position: &Position) -> Option<CompletionList> {
+		let (file, offset) = self.offset_at(uri, position)?;
+		let (span, items) = completion::completions(&mut self.db, file, offset)?;
+
+		// NOTE(aki): The items replace what has been typed of the name so far, which for system
+		// functions and text macros doesn't include the `$` or grave accent
+		Some(CompletionList::new(false, items).with_item_defaults(
+			ItemDefaults::new().with_edit_range(self.range_in(file, &span)?),
+		))
+	}
+
+	/// Fill in the documentation of a completion item the client is about to show
+	pub fn resolve_completion(&mut self, item: CompletionItem) -> CompletionItem {
+		completion::resolve(&mut self.db, item)
+	}
+
+	/// The signature of what is being instantiated or called at `position` in the document at
+	/// `uri`
+	pub fn signature_help(&mut self, uri: &Uri, position: &Position) -> Option<SignatureHelp> {
+		let (file, offset) = self.offset_at(uri, position)?;
+		signature::signature_help(&mut self.db, file, offset)
+	}
+
+	/// The file of the document at `uri` and the byte offset of `position` into it
+	fn offset_at(&mut self, uri: &Uri, position: &Position) -> Option<(FileId, u32)> {
+		let file = self.file_for(uri)?;
+		let (content, lines) = self.db.lines(file)?;
+		let offset = lines.offset(content, position)?;
+
+		Some((file, offset))
+	}
+
+	/// The LSP range covering `span` in `file`
+	fn range_in(&mut self, file: FileId, span: &Span) -> Option<Range> {
+		let (content, lines) = self.db.lines(file)?;
+
+		Some(Range::new(
+			lines.position(content, *span.begin()),
+			lines.position(content, *span.end()),
+		))
+	}
+
+	/// The URI of `file`, preferring the one it was opened with
+	fn uri_of(&self, file: FileId) -> Option<Uri> {
+		self.uris.get(&file).cloned()
+	}
+
+	/// The LSP location of a span in a file of the workspace
+	fn location_of(&mut self, location: &vermilion_loc::Location<()>) -> Option<Location> {
+		let uri = self.uri_of(*location.file_id())?;
+		let range = self.range_in(*location.file_id(), location.span())?;
+
+		Some(Location::new(uri, range))
+	}
+
+	/// Replace the text of `file` with what is on disk at `path`, removing it if it is gone
+	fn reload(&mut self, path: &Path, file: FileId) {
+		match index::read_file(path, &self.db.sources().workspace) {
+			Some((language, content)) => self.db.set_text(file, language, content),
+			None => {
+				self.paths.remove(path);
+				self.remove_file(file);
+			},
+		}
+	}
+
+	/// Remove `file` from the workspace altogether
+	fn remove_file(&mut self, file: FileId) {
+		self.uris.remove(&file);
+		self.db.remove_file(file);
 	}
 
+	/// Apply the changes to the document in order, so only what each of them touched is
+	/// re-parsed
 	pub fn change_document(&mut self, uri: &Uri, changes: Vec<TextDocumentContentChangeEvent>) {
-		match self.documents.get_mut(uri) {
-			Some(document) => document.apply_changes(changes),
-			None => warn!("Got document changes for unknown/unopened document {uri}"),
+		let Some(file) = self.files.get(uri).copied() else {
+			warn!("Got document changes for unknown/unopened document {uri}");
+			return;
+		};
+
+		for change in changes {
+			let Some(language) = self.db.language(file) else {
+				return;
+			};
+
+			let Some(range) = change.range() else {
+				let content = AtomicByteTendril::from_slice(change.text().as_bytes());
+				self.db.set_text(file, language, content);
+				continue;
+			};
+
+			let Some((content, lines)) = self.db.lines(file) else {
+				return;
+			};
+			let (Some(begin), Some(end)) = (
+				lines.offset(content, range.start()),
+				lines.offset(content, range.end()),
+			) else {
+				warn!("Ignoring change to out of bounds range {range:?}");
+				continue;
+			};
+			let end = end.max(begin);
+			let content = content.clone();
+
+			let mut edited = content.subtendril(0, begin);
+			edited.push_slice(change.text().as_bytes());
+			edited.push_tendril(&content.subtendril(end, content.len32() - end));
+
+			let edit = TextEdit {
+				begin,
+				end,
+				length: u32::try_from(change.text().len()).unwrap_or(u32::MAX),
+			};
+			self.db.edit_text(file, edited, edit);
 		}
 	}
 }
 
-impl Document {
+impl<'a> Document<'a> {
+	pub(super) fn new(content: AtomicByteTendril, ast: &'a Ast) -> Self {
+		let lines = LineIndex::new(&content);
+		Self { content, lines, ast }
+	}
+
 	fn vhdl_semantic_tokens(&self, _ast: &VhdlAst) -> Vec<SemanticToken> {
-		todo!();
+		Vec::new()
 	}
 
 	fn verilog_semantic_tokens(&self, _ast: &VerilogAst) -> Vec<SemanticToken> {
-		let tokens = Vec::new();
-
-		debug!("meow - have to convert ast into tokens here");
-
-		tokens
+		Vec::new()
 	}
 
 	pub fn semantic_tokens(&self) -> SemanticTokens {
-		SemanticTokens::new(match &self.ast {
+		SemanticTokens::new(match self.ast {
 			Ast::Vhdl(ast) => self.vhdl_semantic_tokens(ast),
 			Ast::Verilog(ast) => self.verilog_semantic_tokens(ast),
+			// NOTE(aki): BSDL descriptions are mostly strings, so there is nothing to highlight
+			Ast::Bsdl(_) => Vec::new(),
 		})
 	}
 
-	pub fn apply_changes(&mut self, changes: Vec<TextDocumentContentChangeEvent>) {
-		for change in changes {
-			match change.range() {
-				Some(range) => unimplemented!("Partial update attempted for range {range:?}"),
-				None => self.content = AtomicByteTendril::from_slice(change.text().as_bytes()),
-			}
+	pub fn hover(&self, position: &Position) -> Option<Hover> {
+		match self.ast {
+			Ast::Bsdl(description) => bsdl::hover(self, description, self.offset_at(position)?),
+			_ => None,
 		}
 	}
+
+	pub fn document_symbols(&self) -> Vec<DocumentSymbol> {
+		match self.ast {
+			Ast::Verilog(ast) => outline::verilog::document_symbols(self, ast),
+			Ast::Vhdl(ast) => outline::vhdl::document_symbols(self, ast),
+			Ast::Bsdl(description) => bsdl::document_symbols(self, description),
+		}
+	}
+
+	/// The source text covered by `span` with any runs of whitespace collapsed to a single space
+	pub(super) fn text_of(&self, span: &Span) -> String {
+		let end = (*span.end() as usize).min(self.content.len());
+		let begin = (*span.begin() as usize).min(end);
+
+		String::from_utf8_lossy(&self.content[begin..end])
+			.split_whitespace()
+			.collect::<Vec<_>>()
+			.join(" ")
+	}
+
+	/// The byte offset into the document of the LSP `position`, which counts UTF-16 code units
+	pub(super) fn offset_at(&self, position: &Position) -> Option<u32> {
+		self.lines.offset(&self.content, position)
+	}
+
+	/// The LSP position of the byte offset `offset` into the document
+	pub(super) fn position_at(&self, offset: u32) -> Position {
+		self.lines.position(&self.content, offset)
+	}
+
+	/// The LSP range covering `span`
+	pub(super) fn range_of(&self, span: &Span) -> Range {
+		Range::new(
+			self.position_at(*span.begin()),
+			self.position_at(*span.end()),
+		)
+	}
+
+	/// The LSP range covering the whole document
+	pub(super) fn full_range(&self) -> Range {
+		Range::new(
+			Position::new(0, 0),
+			self.position_at(u32::try_from(self.content.len()).unwrap_or(u32::MAX)),
+		)
+	}
 }
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Scratch directories for tests that need real files on disk
+
+use std::{
+	fs,
+	ops::Deref,
+	path::{Path, PathBuf},
+};
+
+use tempfile::TempDir;
+
+/// An empty directory in the system temporary directory
+///
+/// The directory is removed along with everything in it when this is dropped, so nothing is left
+/// behind even when the test using it fails.
+pub(crate) struct ScratchDir(TempDir);
+
+impl ScratchDir {
+	/// Create a scratch directory, with its name starting with `vermilion-{name}-`
+	pub fn new(name: &str) -> Self {
+		match tempfile::Builder::new()
+			.prefix(&format!("vermilion-{name}-"))
+			.tempdir()
+		{
+			Ok(dir) => Self(dir),
+			Err(err) => panic!("Unable to create a scratch directory: {err}"),
+		}
+	}
+
+	/// Write `content` to `path` in the scratch directory, creating any directories leading up to
+	/// it, and return the full path of the file
+	pub fn write(&self, path: impl AsRef<Path>, content: &str) -> PathBuf {
+		let path = self.join(path);
+
+		if let Some(parent) = path.parent() &&
+			let Err(err) = fs::create_dir_all(parent)
+		{
+			panic!("Unable to create {}: {err}", parent.display());
+		}
+		if let Err(err) = fs::write(&path, content) {
+			panic!("Unable to write {}: {err}", path.display());
+		}
+
+		path
+	}
+}
+
+impl Deref for ScratchDir {
+	type Target = Path;
+
+	fn deref(&self) -> &Path {
+		self.0.path()
+	}
+}
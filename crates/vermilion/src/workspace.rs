@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: BSD-3-Clause
 
-use std::fs;
+use std::{
+	fs,
+	path::{Path, PathBuf},
+};
 
 use clap::ArgMatches;
 use schemars::JsonSchema;
@@ -8,16 +11,30 @@ use serde::{Deserialize, Serialize};
 use tracing::debug;
 use vermilion_constraints::workspace::ConstraintWorkspace;
 use vermilion_liberty::workspace::LibertyWorkspace;
-use vermilion_verilog::workspace::{SystemVerilogWorkspace, VerilogAmsWorkspace, VerilogWorkspace};
-use vermilion_vhdl::workspace::{VhdlAmsWorkspace, VhdlWorkspace};
+use vermilion_verilog::{
+	LanguageStd as VerilogStd,
+	lang::preprocessor::Preprocessor,
+	workspace::{
+		SystemVerilogWorkspace, VerilogAmsWorkspace, VerilogSearchSettings, VerilogWorkspace,
+		find_library_module,
+	},
+};
+use vermilion_vhdl::{
+	LanguageStd as VhdlStd,
+	workspace::{VhdlAmsWorkspace, VhdlWorkspace},
+};
 use vermilion_xact::workspace::XactWorkspace;
 
-use crate::paths;
+use crate::{command_file::CommandFile, lang::Language, paths};
 
 /// General workspace settings
 #[derive(Clone, Debug, Default, Deserialize, JsonSchema, Serialize)]
 #[serde(deny_unknown_fields)]
-pub struct WorkspaceGeneral {}
+pub struct WorkspaceGeneral {
+	/// Simulator style command files (`*.f`) describing the sources of the design
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub command_files: Vec<PathBuf>,
+}
 
 /// Vermilion workspace configuration
 ///
@@ -53,6 +70,17 @@ pub struct Workspace {
 	pub liberty:        Option<LibertyWorkspace>,
 	/// IP-XACT specific workspace settings
 	pub xact:           Option<XactWorkspace>,
+	/// The directory the workspace file is in, which the paths in the settings are relative to
+	#[serde(skip)]
+	pub root:           PathBuf,
+}
+
+/// The workspace settings along with the sources from the command files, which together say how
+/// the source files of the design are found and preprocessed
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Sources {
+	pub workspace:    Workspace,
+	pub command_file: CommandFile,
 }
 
 impl Workspace {
@@ -61,6 +89,67 @@ impl Workspace {
 		schemars::schema_for!(Self)
 	}
 
+	/// Read all of the command files listed in the workspace settings
+	pub fn command_files(&self) -> eyre::Result<CommandFile> {
+		let mut sources = CommandFile::default();
+
+		for path in self
+			.vermilion
+			.iter()
+			.flat_map(|general| &general.command_files)
+		{
+			sources.extend(CommandFile::load(path, &self.root)?);
+		}
+
+		Ok(sources)
+	}
+
+	/// The standard and search settings for the Verilog based language `std` is part of, if there
+	/// are any settings for it
+	fn verilog_settings(&self, std: VerilogStd) -> Option<(VerilogStd, &VerilogSearchSettings)> {
+		if VerilogStd::VERILOG_AMS_STDS.contains(std) {
+			self.verilog_ams
+				.as_ref()
+				.map(|settings| (settings.std, &settings.search))
+		} else if VerilogStd::SYSTEM_VERILOG_STDS.contains(std) {
+			self.system_verilog
+				.as_ref()
+				.map(|settings| (settings.std, &settings.search))
+		} else {
+			self.verilog
+				.as_ref()
+				.map(|settings| (settings.std, &settings.search))
+		}
+	}
+
+	/// The language to use for the file at `path`, which is `language` going by its extension
+	///
+	/// The standard comes from the settings for the language, along with their overrides for
+	/// specific files, and is only left as the one of `language` when there are no settings for it.
+	/// VHDL files use the VHDL-AMS settings when there are no VHDL ones, as the two share their
+	/// extensions.
+	pub(crate) fn language_for(&self, path: &Path, language: Language) -> Language {
+		let relative = path.strip_prefix(&self.root).unwrap_or(path);
+
+		match language {
+			Language::Verilog(std) => Language::Verilog(
+				self.verilog_settings(std)
+					.map_or(std, |(std, search)| search.std_for(std, relative)),
+			),
+			Language::Vhdl(VhdlStd::Bsdl) => language,
+			Language::Vhdl(std) => {
+				let vhdl = self.vhdl.as_ref().map(|settings| settings.std);
+				let vhdl_ams = self.vhdl_ams.as_ref().map(|settings| settings.std);
+
+				Language::Vhdl(if VhdlStd::VHDL_AMS_STDS.contains(std) {
+					vhdl_ams.unwrap_or(std)
+				} else {
+					vhdl.or(vhdl_ams).unwrap_or(std)
+				})
+			},
+		}
+	}
+
 	/// Dump the Vermilion [`Workspace`] schema to a [`String`]
 	pub fn dump_schema() -> eyre::Result<String> {
 		Ok(serde_json::to_string_pretty(&schemars::schema_for!(Self))?)
@@ -83,7 +172,7 @@ impl Workspace {
 	pub fn load(args: &ArgMatches) -> eyre::Result<Option<Self>> {
 		// If we were passed a workspace file on the command line, try that
 		if let Ok(Some(workspace_file)) = args.try_get_one::<String>("workspace") {
-			return Ok(toml::from_slice(&fs::read(workspace_file)?)?);
+			return Self::read(Path::new(workspace_file)).map(Some);
 		}
 
 		let current_dir = std::env::current_dir()?;
@@ -95,7 +184,7 @@ impl Workspace {
 		// Search up to either the root or the upper-most mount-point for a valid configuration
 		// file.
 		while paths::same_fs(&current_dir, &search_dir)? && workspace_file.is_none() {
-			workspace_file = current_dir
+			workspace_file = search_dir
 				.read_dir()?
 				.flatten()
 				.filter(|f| f.file_name() == "vermilion.toml")
@@ -105,7 +194,7 @@ impl Workspace {
 			// Check to see if we found a configuration file, if not try to go up a directory if
 			// possible
 			if let Some(ref path) = workspace_file {
-				workspace = Some(toml::from_slice(&fs::read(path)?)?);
+				workspace = Some(Self::read(path)?);
 				break;
 			} else if let Some(parent) = search_dir.parent() {
 				search_dir = parent.to_path_buf();
@@ -121,4 +210,114 @@ impl Workspace {
 
 		Ok(workspace)
 	}
+
+	/// Read the workspace file at `path`, rooting the workspace at the directory it is in
+	fn read(path: &Path) -> eyre::Result<Self> {
+		let mut workspace: Self = toml::from_slice(&fs::read(path)?)?;
+		workspace.root = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+		Ok(workspace)
+	}
+}
+
+impl Sources {
+	/// Read the command files listed in the workspace settings, if there are any
+	pub fn load(workspace: Option<Workspace>) -> eyre::Result<Self> {
+		let workspace = workspace.unwrap_or_default();
+		let command_file = workspace.command_files()?;
+
+		Ok(Self { workspace, command_file })
+	}
+
+	/// Create a Verilog preprocessor with the include directories and macros from the workspace
+	/// settings for the language of `std`, followed by the ones from the command files
+	pub fn verilog_preprocessor(&self, std: VerilogStd) -> Preprocessor {
+		let mut preprocessor = Preprocessor::new(std);
+
+		if let Some((_, search)) = self.workspace.verilog_settings(std) {
+			search.configure_preprocessor(&self.workspace.root, &mut preprocessor);
+		}
+		self.command_file.configure_preprocessor(&mut preprocessor);
+
+		preprocessor
+	}
+
+	/// The standard to use for the Verilog file at `path` in a design using `std`, which is `std`
+	/// unless the workspace settings for the language override it for the file
+	pub fn verilog_std_for(&self, path: &Path, std: VerilogStd) -> VerilogStd {
+		let relative = path.strip_prefix(&self.workspace.root).unwrap_or(path);
+
+		self.workspace
+			.verilog_settings(std)
+			.map_or(std, |(_, search)| search.std_for(std, relative))
+	}
+
+	/// Find the file holding the library module `name`, first in the library directories of the
+	/// workspace settings for the language of `std` and then in those of the command files
+	pub fn find_library_module(&self, std: VerilogStd, name: &str) -> Option<PathBuf> {
+		self.workspace
+			.verilog_settings(std)
+			.and_then(|(_, search)| search.find_library_module(&self.workspace.root, name))
+			.or_else(|| {
+				// NOTE(aki): The library directories of the command files are already absolute
+				find_library_module(
+					Path::new(""),
+					&self.command_file.library_dirs,
+					&self.command_file.library_extensions,
+					name,
+				)
+			})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_verilog_search_settings() {
+		let workspace: Workspace = toml::from_str(
+			r#"
+			[system_verilog]
+			std = "Sv17"
+			include_dirs = [ "include" ]
+			library_dirs = [ "lib" ]
+			defines = { SYNTHESIS = "" }
+
+			[[system_verilog.overrides]]
+			files = "legacy/**/*.v"
+			std = "Sv05"
+			"#,
+		)
+		.unwrap_or_else(|err| panic!("{err}"));
+
+		let Some(settings) = &workspace.system_verilog else {
+			panic!("No SystemVerilog settings");
+		};
+		assert_eq!(settings.std, VerilogStd::Sv17);
+		assert_eq!(settings.search.include_dirs, [PathBuf::from("include")]);
+		assert_eq!(settings.search.library_dirs, [PathBuf::from("lib")]);
+		assert!(settings.search.defines.contains_key("SYNTHESIS"));
+		assert_eq!(
+			settings
+				.search
+				.std_for(settings.std, Path::new("legacy/cpu/alu.v")),
+			VerilogStd::Sv05
+		);
+
+		// Misspelt settings are still rejected with the search settings flattened in
+		let misspelt = toml::from_str::<Workspace>("[verilog]\nstd = \"Vl05\"\nlibrary_dir = []\n");
+		assert!(misspelt.is_err());
+
+		// As are overrides with globs that aren't valid
+		let Err(err) = toml::from_str::<Workspace>(
+			concat!(
+				"[verilog]\nstd = \"Vl05\"\n\n",
+				"[[verilog.overrides]]\nfiles = \"rtl/[\"\nstd = \"Vl95\"\n",
+			),
+		) else {
+			panic!("Invalid glob accepted");
+		};
+		assert!(err.to_string().contains("rtl/["), "{err}");
+	}
 }